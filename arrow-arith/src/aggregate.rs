@@ -102,7 +102,34 @@ pub fn max_boolean(array: &BooleanArray) -> Option<bool> {
 }
 
 /// Helper to compute min/max of [`ArrayAccessor`].
+///
+/// On x86_64 this is compiled for AVX2, and dispatched to at runtime if supported by the CPU
 fn min_max_helper<T, A: ArrayAccessor<Item = T>, F>(array: A, cmp: F) -> Option<T>
+where
+    F: Fn(&T, &T) -> bool,
+{
+    #[cfg(target_arch = "x86_64")]
+    if std::arch::is_x86_feature_detected!("avx2") {
+        // SAFETY: verified the CPU supports AVX2
+        return unsafe { min_max_helper_avx2(array, cmp) };
+    }
+    min_max_helper_impl(array, cmp)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn min_max_helper_avx2<T, A: ArrayAccessor<Item = T>, F>(
+    array: A,
+    cmp: F,
+) -> Option<T>
+where
+    F: Fn(&T, &T) -> bool,
+{
+    min_max_helper_impl(array, cmp)
+}
+
+#[inline(always)]
+fn min_max_helper_impl<T, A: ArrayAccessor<Item = T>, F>(array: A, cmp: F) -> Option<T>
 where
     F: Fn(&T, &T) -> bool,
 {
@@ -273,8 +300,35 @@ where
 ///
 /// This doesn't detect overflow. Once overflowing, the result will wrap around.
 /// For an overflow-checking variant, use `sum_checked` instead.
+///
+/// On x86_64 this is compiled for AVX2, and dispatched to at runtime if supported by the CPU
 #[cfg(not(feature = "simd"))]
 pub fn sum<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    #[cfg(target_arch = "x86_64")]
+    if std::arch::is_x86_feature_detected!("avx2") {
+        // SAFETY: verified the CPU supports AVX2
+        return unsafe { sum_avx2(array) };
+    }
+    sum_impl(array)
+}
+
+#[cfg(all(not(feature = "simd"), target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+unsafe fn sum_avx2<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    sum_impl(array)
+}
+
+#[cfg(not(feature = "simd"))]
+#[inline(always)]
+fn sum_impl<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
 where
     T: ArrowNumericType,
     T::Native: ArrowNativeTypeOp,
@@ -423,7 +477,7 @@ pub fn bool_and(array: &BooleanArray) -> Option<bool> {
     if array.null_count() == array.len() {
         return None;
     }
-    Some(array.false_count() == 0)
+    Some(!contains_valid(array, false))
 }
 
 /// Returns true if any non-null input value is true, otherwise false.
@@ -433,7 +487,72 @@ pub fn bool_or(array: &BooleanArray) -> Option<bool> {
     if array.null_count() == array.len() {
         return None;
     }
-    Some(array.true_count() != 0)
+    Some(contains_valid(array, true))
+}
+
+/// Returns the logical and of all input values with Kleene logic
+///
+/// Returns false if any value is false, otherwise `None` if any value is null,
+/// otherwise true. This is the result of folding the values with
+/// [`and_kleene`](crate::boolean::and_kleene), and so is true for an empty array.
+///
+/// ```
+/// # use arrow_array::BooleanArray;
+/// # use arrow_arith::aggregate::bool_and_kleene;
+/// assert_eq!(bool_and_kleene(&BooleanArray::from(vec![Some(true), None])), None);
+/// assert_eq!(bool_and_kleene(&BooleanArray::from(vec![Some(false), None])), Some(false));
+/// ```
+pub fn bool_and_kleene(array: &BooleanArray) -> Option<bool> {
+    match contains_valid(array, false) {
+        true => Some(false),
+        false => (array.null_count() == 0).then_some(true),
+    }
+}
+
+/// Returns the logical or of all input values with Kleene logic
+///
+/// Returns true if any value is true, otherwise `None` if any value is null,
+/// otherwise false. This is the result of folding the values with
+/// [`or_kleene`](crate::boolean::or_kleene), and so is false for an empty array.
+///
+/// ```
+/// # use arrow_array::BooleanArray;
+/// # use arrow_arith::aggregate::bool_or_kleene;
+/// assert_eq!(bool_or_kleene(&BooleanArray::from(vec![Some(false), None])), None);
+/// assert_eq!(bool_or_kleene(&BooleanArray::from(vec![Some(true), None])), Some(true));
+/// ```
+pub fn bool_or_kleene(array: &BooleanArray) -> Option<bool> {
+    match contains_valid(array, true) {
+        true => Some(true),
+        false => (array.null_count() == 0).then_some(false),
+    }
+}
+
+/// Returns true if any non-null value of `array` is equal to `value`
+///
+/// Scans 64 values at a time, returning as soon as a matching value is found
+fn contains_valid(array: &BooleanArray, value: bool) -> bool {
+    let values = array.values().bit_chunks();
+    let matches = |chunk: u64| match value {
+        true => chunk,
+        false => !chunk,
+    };
+    let remainder_mask = (1_u64 << values.remainder_len()) - 1;
+
+    match array.nulls().filter(|n| n.null_count() > 0) {
+        Some(nulls) => {
+            let nulls = nulls.inner().bit_chunks();
+            values
+                .iter()
+                .zip(nulls.iter())
+                .any(|(v, n)| matches(v) & n != 0)
+                || matches(values.remainder_bits()) & nulls.remainder_bits() != 0
+        }
+        None => {
+            values.iter().any(|v| matches(v) != 0)
+                || matches(values.remainder_bits()) & remainder_mask != 0
+        }
+    }
 }
 
 /// Returns the sum of values in the primitive array.
@@ -868,7 +987,7 @@ where
 mod tests {
     use super::*;
     use arrow_array::types::*;
-    use arrow_buffer::NullBuffer;
+    use arrow_buffer::{BooleanBuffer, NullBuffer};
     use std::sync::Arc;
 
     #[test]
@@ -1020,6 +1139,62 @@ mod tests {
         assert_eq!(None, bool_or(&a));
     }
 
+    #[test]
+    fn test_bool_and_or_sliced() {
+        // Arrays sliced from between two false values
+        let sliced = |values: &[bool]| {
+            let padded: Vec<bool> = [false]
+                .iter()
+                .chain(values)
+                .chain(&[false])
+                .copied()
+                .collect();
+            BooleanArray::from(padded).slice(1, values.len())
+        };
+
+        for len in [1, 63, 64, 65, 130] {
+            let a = sliced(&vec![true; len]);
+            assert_eq!(bool_and(&a), Some(true));
+            assert_eq!(bool_or_kleene(&a), Some(true));
+
+            // A single false value at different positions
+            for idx in [0, len / 2, len - 1] {
+                let values: Vec<_> = (0..len).map(|i| i != idx).collect();
+                let a = sliced(&values);
+                assert_eq!(bool_and(&a), Some(false));
+                assert_eq!(bool_and_kleene(&a), Some(false));
+                assert_eq!(bool_or(&a), Some(len > 1));
+
+                // Only the false value is valid
+
+                let nulls =
+                    NullBuffer::from(values.iter().map(|v| !v).collect::<Vec<_>>());
+                let a = BooleanArray::new(BooleanBuffer::new_unset(len), Some(nulls));
+                assert_eq!(bool_and(&a), Some(false));
+                assert_eq!(bool_or(&a), Some(false));
+                assert_eq!(bool_or_kleene(&a), (len == 1).then_some(false));
+            }
+        }
+    }
+
+    #[test]
+    fn test_bool_and_or_kleene() {
+        let cases = [
+            (vec![], Some(true), Some(false)),
+            (vec![None], None, None),
+            (vec![Some(true), None], None, Some(true)),
+            (vec![Some(false), None], Some(false), None),
+            (vec![Some(true), Some(false)], Some(false), Some(true)),
+            (vec![Some(true), Some(true)], Some(true), Some(true)),
+            (vec![Some(false), Some(false)], Some(false), Some(false)),
+        ];
+        for (values, and, or) in cases {
+            let a = BooleanArray::from(values);
+            assert_eq!(bool_and_kleene(&a), and, "{a:?}");
+            assert_eq!(bool_or_kleene(&a), or, "{a:?}");
+        }
+    }
+
     #[test]
     fn test_primitive_array_min_max() {
         let a = Int32Array::from(vec![5, 6, 7, 8, 9]);
@@ -24,7 +24,7 @@
 
 use arrow_array::*;
 use arrow_buffer::buffer::{bitwise_bin_op_helper, bitwise_quaternary_op_helper};
-use arrow_buffer::{BooleanBuffer, NullBuffer};
+use arrow_buffer::{bit_util, BooleanBuffer, Buffer, MutableBuffer, NullBuffer};
 use arrow_schema::{ArrowError, DataType};
 
 /// Logical 'and' boolean values with Kleene logic
@@ -346,6 +346,136 @@ pub fn is_not_null(input: &dyn Array) -> Result<BooleanArray, ArrowError> {
     Ok(BooleanArray::new(values, None))
 }
 
+/// Returns the logical and of `masks`, treating null values as false as when
+/// filtering, such that the result contains no nulls
+///
+/// The masks are combined 64 values at a time, skipping the remaining masks for
+/// values already known to be false.
+///
+/// # Error
+///
+/// Returns an error if no masks are provided or they have different lengths
+///
+/// # Example
+///
+/// ```rust
+/// # use arrow_array::BooleanArray;
+/// # use arrow_arith::boolean::and_all;
+/// let a = BooleanArray::from(vec![Some(true), Some(true), None, Some(true)]);
+/// let b = BooleanArray::from(vec![Some(true), Some(false), Some(true), Some(true)]);
+/// let c = BooleanArray::from(vec![Some(false), Some(true), Some(true), Some(true)]);
+/// let result = and_all(&[&a, &b, &c]).unwrap();
+/// assert_eq!(result, BooleanArray::from(vec![false, false, false, true]));
+/// ```
+pub fn and_all(masks: &[&BooleanArray]) -> Result<BooleanArray, ArrowError> {
+    let len = match masks.first() {
+        Some(mask) => mask.len(),
+        None => {
+            return Err(ArrowError::InvalidArgumentError(
+                "and_all requires at least one mask".to_string(),
+            ))
+        }
+    };
+    if masks.iter().any(|m| m.len() != len) {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform bitwise operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let bitmaps: Vec<_> = masks
+        .iter()
+        .map(|m| (m.values().sliced(), m.nulls().map(|n| n.inner().sliced())))
+        .collect();
+
+    let num_words = bit_util::ceil(len, 64);
+    let mut out = MutableBuffer::new(num_words * 8);
+    for idx in 0..num_words {
+        let mut word = u64::MAX;
+        for (values, nulls) in &bitmaps {
+            word &= read_word(values, idx);
+            if let Some(nulls) = nulls {
+                word &= read_word(nulls, idx);
+            }
+            if word == 0 {
+                break;
+            }
+        }
+        out.push(word & tail_mask(len, idx));
+    }
+    Ok(BooleanArray::new(
+        BooleanBuffer::new(out.into(), 0, len),
+        None,
+    ))
+}
+
+/// Evaluates `predicate` for the index of each true value of `mask`, returning
+/// the logical and of `mask` and the result of `predicate`
+///
+/// Null values of `mask` are treated as false, as when filtering, and the result
+/// contains no nulls. The mask is processed 64 values at a time, skipping those
+/// that are all false, allowing a chain of predicates to only be evaluated for
+/// the values that satisfy the preceding predicates.
+///
+/// # Example
+///
+/// ```rust
+/// # use arrow_array::{BooleanArray, Int32Array};
+/// # use arrow_arith::boolean::and_then;
+/// let values = Int32Array::from(vec![1, 10, 100, 1000]);
+/// let mask = BooleanArray::from(vec![Some(true), Some(true), None, Some(true)]);
+/// let result = and_then(&mask, |idx| values.value(idx) >= 10);
+/// assert_eq!(result, BooleanArray::from(vec![false, true, false, true]));
+/// ```
+pub fn and_then<F>(mask: &BooleanArray, mut predicate: F) -> BooleanArray
+where
+    F: FnMut(usize) -> bool,
+{
+    let len = mask.len();
+    let values = mask.values().sliced();
+    let nulls = mask.nulls().map(|n| n.inner().sliced());
+
+    let num_words = bit_util::ceil(len, 64);
+    let mut out = MutableBuffer::new(num_words * 8);
+    for idx in 0..num_words {
+        let mut word = read_word(&values, idx);
+        if let Some(nulls) = &nulls {
+            word &= read_word(nulls, idx);
+        }
+        // Ignore any bits beyond the end of the mask
+        word &= tail_mask(len, idx);
+
+        let mut set = word;
+        while set != 0 {
+            let bit = set.trailing_zeros();
+            if !predicate(idx * 64 + bit as usize) {
+                word &= !(1 << bit);
+            }
+            set &= set - 1;
+        }
+        out.push(word);
+    }
+    BooleanArray::new(BooleanBuffer::new(out.into(), 0, len), None)
+}
+
+/// Returns a mask of the bits of the 64-bit word at `idx` within a bitmap of `len` bits
+fn tail_mask(len: usize, idx: usize) -> u64 {
+    match len - idx * 64 {
+        remaining if remaining < 64 => (1 << remaining) - 1,
+        _ => u64::MAX,
+    }
+}
+
+/// Reads the 64-bit word at `idx` of the bitmap `buffer`, padding any missing
+/// trailing bytes with zeros
+fn read_word(buffer: &Buffer, idx: usize) -> u64 {
+    let bytes = buffer.as_slice();
+    let start = (idx * 8).min(bytes.len());
+    let end = (start + 8).min(bytes.len());
+    let mut word = [0_u8; 8];
+    word[..end - start].copy_from_slice(&bytes[start..end]);
+    u64::from_le_bytes(word)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -903,4 +1033,67 @@ mod tests {
         assert_eq!(expected, res);
         assert!(res.nulls().is_none());
     }
+
+    #[test]
+    fn test_and_all() {
+        let a = BooleanArray::from(vec![Some(true), None, Some(true), Some(true)]);
+        let b = BooleanArray::from(vec![Some(true), Some(true), Some(false), Some(true)]);
+        let result = and_all(&[&a, &b]).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![true, false, false, true]));
+        assert_eq!(
+            and_all(&[&a]).unwrap(),
+            BooleanArray::from(vec![true, false, true, true])
+        );
+
+        // Sliced masks spanning multiple words
+        let a: BooleanArray = (0..200).map(|i| Some(i % 3 != 0)).collect();
+        let b: BooleanArray = (0..200)
+            .map(|i| (i % 7 != 0).then_some(i % 2 == 0))
+            .collect();
+        let (a, b) = (a.slice(3, 150), b.slice(11, 150));
+        let expected: BooleanArray = a
+            .iter()
+            .zip(b.iter())
+            .map(|(a, b)| Some(a.unwrap_or_default() && b.unwrap_or_default()))
+            .collect();
+        let result = and_all(&[&a, &b]).unwrap();
+        assert_eq!(result, expected);
+        assert!(result.nulls().is_none());
+
+        let err = and_all(&[]).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: and_all requires at least one mask"
+        );
+        assert!(and_all(&[&a, &a.slice(0, 10)]).is_err());
+    }
+
+    #[test]
+    fn test_and_then() {
+        let mask: BooleanArray = (0..200)
+            .map(|i| (i % 5 != 0).then_some(i % 4 != 0))
+            .collect();
+        let mask = mask.slice(7, 140);
+
+        let mut evaluated = vec![];
+        let result = and_then(&mask, |idx| {
+            evaluated.push(idx);
+            idx % 3 == 0
+        });
+
+        let expected: Vec<_> = (0..mask.len())
+            .filter(|i| mask.value(*i) && mask.is_valid(*i))
+            .collect();
+        assert_eq!(evaluated, expected);
+        let expected: BooleanArray = (0..mask.len())
+            .map(|i| Some(mask.is_valid(i) && mask.value(i) && i % 3 == 0))
+            .collect();
+        assert_eq!(result, expected);
+        assert!(result.nulls().is_none());
+
+        // The predicate is never evaluated for an all false mask
+        let mask = BooleanArray::from(vec![false; 100]);
+        let result = and_then(&mask, |_| unreachable!());
+        assert_eq!(result.true_count(), 0);
+    }
 }
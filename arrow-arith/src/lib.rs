@@ -23,5 +23,7 @@ pub mod arithmetic;
 pub mod arity;
 pub mod bitwise;
 pub mod boolean;
+pub mod math;
 pub mod numeric;
 pub mod temporal;
+pub mod vector;
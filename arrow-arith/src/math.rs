@@ -0,0 +1,804 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines elementary math kernels on floating point arrays, such as [`sqrt`] and [`sin`]
+//!
+//! Null inputs produce null outputs. Inputs outside the domain of a function, such as
+//! negative values passed to [`sqrt`], are handled according to a [`DomainErrorPolicy`].
+//! NaN inputs are within the domain of all functions, and produce NaN outputs.
+//!
+//! [`sqrt`] and [`pow`] additionally support decimal arrays.
+
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{ArrowNativeType, BooleanBuffer, BooleanBufferBuilder, NullBuffer};
+use arrow_schema::{ArrowError, DataType};
+use num::Float;
+
+/// How to handle inputs outside the domain of a function, such as `sqrt(-1)`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DomainErrorPolicy {
+    /// Return the IEEE 754 result, i.e. NaN or an infinity
+    ///
+    /// Decimal arrays cannot represent these, and so return an error instead
+    #[default]
+    NaN,
+    /// Return null
+    Null,
+    /// Return an error
+    Error,
+}
+
+impl DomainErrorPolicy {
+    /// Returns the result for an input outside the domain, where `ieee` is the
+    /// IEEE 754 result if the type can represent it
+    fn apply<N>(
+        self,
+        ieee: Option<N>,
+        err: impl FnOnce() -> ArrowError,
+    ) -> Result<Option<N>, ArrowError> {
+        match (self, ieee) {
+            (Self::NaN, Some(v)) => Ok(Some(v)),
+            (Self::Null, _) => Ok(None),
+            _ => Err(err()),
+        }
+    }
+}
+
+/// The values for which a unary function is defined
+#[derive(Debug, Clone, Copy)]
+enum Domain {
+    All,
+    NonNegative,
+    Positive,
+    /// `[-1, 1]`
+    UnitInterval,
+    /// `(-1, 1)`
+    OpenUnitInterval,
+    AtLeastOne,
+}
+
+impl Domain {
+    /// Returns true if `x` is outside this domain, NaN is never outside it
+    fn excludes<F: Float>(self, x: F) -> bool {
+        match self {
+            Self::All => false,
+            Self::NonNegative => x < F::zero(),
+            Self::Positive => x <= F::zero(),
+            Self::UnitInterval => x.abs() > F::one(),
+            Self::OpenUnitInterval => x.abs() >= F::one(),
+            Self::AtLeastOne => x < F::one(),
+        }
+    }
+}
+
+fn domain_error(name: &str, args: impl Debug) -> ArrowError {
+    ArrowError::ComputeError(format!("{name} is undefined for {args:?}"))
+}
+
+fn unsupported(name: &str, t: &DataType) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!("Invalid arithmetic operation: {name}({t})"))
+}
+
+/// Applies `op` to each element of the floating point `array`
+fn unary_math<T: ArrowPrimitiveType>(
+    name: &str,
+    array: &dyn Array,
+    op: fn(T::Native) -> T::Native,
+    domain: Domain,
+    policy: DomainErrorPolicy,
+) -> Result<ArrayRef, ArrowError>
+where
+    T::Native: Float,
+{
+    let a = array.as_primitive::<T>();
+    let r: PrimitiveArray<T> = match policy {
+        DomainErrorPolicy::NaN => a.unary(op),
+        DomainErrorPolicy::Null => a.unary_opt(|x| (!domain.excludes(x)).then(|| op(x))),
+        DomainErrorPolicy::Error => a.try_unary(|x| match domain.excludes(x) {
+            true => Err(domain_error(name, x)),
+            false => Ok(op(x)),
+        })?,
+    };
+    Ok(Arc::new(r))
+}
+
+/// Dispatch [`unary_math`] on the floating point type of `$array`
+macro_rules! float_math {
+    ($name:expr, $array:expr, $op:path, $domain:expr, $policy:expr) => {
+        match $array.data_type() {
+            DataType::Float16 => {
+                unary_math::<Float16Type>($name, $array, $op, $domain, $policy)
+            }
+            DataType::Float32 => {
+                unary_math::<Float32Type>($name, $array, $op, $domain, $policy)
+            }
+            DataType::Float64 => {
+                unary_math::<Float64Type>($name, $array, $op, $domain, $policy)
+            }
+            t => Err(unsupported($name, t)),
+        }
+    };
+}
+
+/// Defines a public unary kernel on floating point arrays, taking a
+/// [`DomainErrorPolicy`] if the function is not defined for all values
+macro_rules! math_kernel {
+    ($(#[$doc:meta])* $name:ident, $op:path, $domain:expr) => {
+        $(#[$doc])*
+        pub fn $name(
+            array: &dyn Array,
+            policy: DomainErrorPolicy,
+        ) -> Result<ArrayRef, ArrowError> {
+            float_math!(stringify!($name), array, $op, $domain, policy)
+        }
+    };
+    ($(#[$doc:meta])* $name:ident, $op:path) => {
+        $(#[$doc])*
+        pub fn $name(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+            float_math!(stringify!($name), array, $op, Domain::All, DomainErrorPolicy::NaN)
+        }
+    };
+}
+
+math_kernel!(
+    /// Returns the cube root of each element of `array`
+    cbrt,
+    Float::cbrt
+);
+math_kernel!(
+    /// Returns `e^x` for each element `x` of `array`
+    exp,
+    Float::exp
+);
+math_kernel!(
+    /// Returns `2^x` for each element `x` of `array`
+    exp2,
+    Float::exp2
+);
+math_kernel!(
+    /// Returns the natural logarithm of each element of `array`
+    ///
+    /// Values less than or equal to zero are outside the domain
+    ln,
+    Float::ln,
+    Domain::Positive
+);
+math_kernel!(
+    /// Returns the base 2 logarithm of each element of `array`
+    ///
+    /// Values less than or equal to zero are outside the domain
+    log2,
+    Float::log2,
+    Domain::Positive
+);
+math_kernel!(
+    /// Returns the base 10 logarithm of each element of `array`
+    ///
+    /// Values less than or equal to zero are outside the domain
+    log10,
+    Float::log10,
+    Domain::Positive
+);
+math_kernel!(
+    /// Returns the sine of each element of `array`, in radians
+    sin,
+    Float::sin
+);
+math_kernel!(
+    /// Returns the cosine of each element of `array`, in radians
+    cos,
+    Float::cos
+);
+math_kernel!(
+    /// Returns the tangent of each element of `array`, in radians
+    tan,
+    Float::tan
+);
+math_kernel!(
+    /// Returns the arcsine of each element of `array`, in radians
+    ///
+    /// Values outside of `[-1, 1]` are outside the domain
+    asin,
+    Float::asin,
+    Domain::UnitInterval
+);
+math_kernel!(
+    /// Returns the arccosine of each element of `array`, in radians
+    ///
+    /// Values outside of `[-1, 1]` are outside the domain
+    acos,
+    Float::acos,
+    Domain::UnitInterval
+);
+math_kernel!(
+    /// Returns the arctangent of each element of `array`, in radians
+    atan,
+    Float::atan
+);
+math_kernel!(
+    /// Returns the hyperbolic sine of each element of `array`
+    sinh,
+    Float::sinh
+);
+math_kernel!(
+    /// Returns the hyperbolic cosine of each element of `array`
+    cosh,
+    Float::cosh
+);
+math_kernel!(
+    /// Returns the hyperbolic tangent of each element of `array`
+    tanh,
+    Float::tanh
+);
+math_kernel!(
+    /// Returns the inverse hyperbolic sine of each element of `array`
+    asinh,
+    Float::asinh
+);
+math_kernel!(
+    /// Returns the inverse hyperbolic cosine of each element of `array`
+    ///
+    /// Values less than one are outside the domain
+    acosh,
+    Float::acosh,
+    Domain::AtLeastOne
+);
+math_kernel!(
+    /// Returns the inverse hyperbolic tangent of each element of `array`
+    ///
+    /// Values outside of `(-1, 1)` are outside the domain
+    atanh,
+    Float::atanh,
+    Domain::OpenUnitInterval
+);
+
+/// Returns the square root of each element of `array`
+///
+/// Negative values are outside the domain. Decimal results have the same precision
+/// and scale as `array`, rounded half to even.
+///
+/// ```
+/// # use arrow_array::{Decimal128Array, Float64Array};
+/// # use arrow_arith::math::{sqrt, DomainErrorPolicy};
+/// let a = Float64Array::from(vec![Some(4.), None, Some(-1.)]);
+/// let r = sqrt(&a, DomainErrorPolicy::Null).unwrap();
+/// assert_eq!(r.as_ref(), &Float64Array::from(vec![Some(2.), None, None]));
+///
+/// let err = sqrt(&a, DomainErrorPolicy::Error).unwrap_err();
+/// assert_eq!(err.to_string(), "Compute error: sqrt is undefined for -1.0");
+///
+/// let a = Decimal128Array::from(vec![200]).with_precision_and_scale(5, 2).unwrap();
+/// let r = sqrt(&a, DomainErrorPolicy::Error).unwrap();
+/// let expected = Decimal128Array::from(vec![141]).with_precision_and_scale(5, 2).unwrap();
+/// assert_eq!(r.as_ref(), &expected);
+/// ```
+pub fn sqrt(
+    array: &dyn Array,
+    policy: DomainErrorPolicy,
+) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Decimal32(_, _) => decimal_sqrt::<Decimal32Type>(array, policy),
+        DataType::Decimal64(_, _) => decimal_sqrt::<Decimal64Type>(array, policy),
+        DataType::Decimal128(_, _) => decimal_sqrt::<Decimal128Type>(array, policy),
+        DataType::Decimal256(_, _) => decimal_sqrt::<Decimal256Type>(array, policy),
+        _ => float_math!("sqrt", array, Float::sqrt, Domain::NonNegative, policy),
+    }
+}
+
+/// Raises each element of `base` to the power of `exponent`
+///
+/// For floating point arrays, `base` and `exponent` must have the same type. A negative
+/// base with a finite non-integer exponent, and a zero base with a negative exponent,
+/// are outside the domain.
+///
+/// For decimal arrays, `exponent` must be an [`Int64Array`], and a zero base with a
+/// negative exponent is outside the domain. Results have the same precision and scale
+/// as `base`, with intermediate products rounded half to even, returning an error if
+/// a result does not fit.
+pub fn pow(
+    base: &dyn Datum,
+    exponent: &dyn Datum,
+    policy: DomainErrorPolicy,
+) -> Result<ArrayRef, ArrowError> {
+    use DataType::*;
+    let (b, _) = base.get();
+    let (e, _) = exponent.get();
+    match (b.data_type(), e.data_type()) {
+        (Float16, Float16) => float_pow::<Float16Type>(base, exponent, policy),
+        (Float32, Float32) => float_pow::<Float32Type>(base, exponent, policy),
+        (Float64, Float64) => float_pow::<Float64Type>(base, exponent, policy),
+        (Decimal32(_, _), Int64) => decimal_pow::<Decimal32Type>(base, exponent, policy),
+        (Decimal64(_, _), Int64) => decimal_pow::<Decimal64Type>(base, exponent, policy),
+        (Decimal128(_, _), Int64) => {
+            decimal_pow::<Decimal128Type>(base, exponent, policy)
+        }
+        (Decimal256(_, _), Int64) => {
+            decimal_pow::<Decimal256Type>(base, exponent, policy)
+        }
+        (b, e) => Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid arithmetic operation: pow({b}, {e})"
+        ))),
+    }
+}
+
+/// Returns the four quadrant arctangent of each element of `y` and `x`, in radians
+///
+/// `y` and `x` must be floating point arrays of the same type
+pub fn atan2(y: &dyn Datum, x: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    use DataType::*;
+
+    fn atan2_impl<T: ArrowPrimitiveType>(
+        y: &dyn Datum,
+        x: &dyn Datum,
+    ) -> Result<ArrayRef, ArrowError>
+    where
+        T::Native: Float,
+    {
+        let r = try_binary_opt::<T, T, T, _>(y, x, |y, x| Ok(Some(y.atan2(x))))?;
+        Ok(Arc::new(r))
+    }
+
+    let (l, _) = y.get();
+    let (r, _) = x.get();
+    match (l.data_type(), r.data_type()) {
+        (Float16, Float16) => atan2_impl::<Float16Type>(y, x),
+        (Float32, Float32) => atan2_impl::<Float32Type>(y, x),
+        (Float64, Float64) => atan2_impl::<Float64Type>(y, x),
+        (l, r) => Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid arithmetic operation: atan2({l}, {r})"
+        ))),
+    }
+}
+
+/// Applies `op` to each pair of valid elements of two potentially scalar inputs,
+/// with `op` returning `None` for a null result
+fn try_binary_opt<A, B, O, F>(
+    l: &dyn Datum,
+    r: &dyn Datum,
+    mut op: F,
+) -> Result<PrimitiveArray<O>, ArrowError>
+where
+    A: ArrowPrimitiveType,
+    B: ArrowPrimitiveType,
+    O: ArrowPrimitiveType,
+    F: FnMut(A::Native, B::Native) -> Result<Option<O::Native>, ArrowError>,
+{
+    let (l, l_scalar) = l.get();
+    let (r, r_scalar) = r.get();
+    let len = match (l_scalar, r_scalar) {
+        (true, false) => r.len(),
+        (false, true) => l.len(),
+        _ if l.len() == r.len() => l.len(),
+        _ => {
+            return Err(ArrowError::ComputeError(
+                "Cannot perform a binary operation on arrays of different length"
+                    .to_string(),
+            ))
+        }
+    };
+    let l = l.as_primitive::<A>();
+    let r = r.as_primitive::<B>();
+
+    let mut values = Vec::with_capacity(len);
+    let mut nulls = BooleanBufferBuilder::new(len);
+    for idx in 0..len {
+        let l_idx = if l_scalar { 0 } else { idx };
+        let r_idx = if r_scalar { 0 } else { idx };
+        let value = match l.is_valid(l_idx) && r.is_valid(r_idx) {
+            true => op(l.value(l_idx), r.value(r_idx))?,
+            false => None,
+        };
+        nulls.append(value.is_some());
+        values.push(value.unwrap_or_default());
+    }
+    let nulls = Some(NullBuffer::new(nulls.finish())).filter(|n| n.null_count() > 0);
+    Ok(PrimitiveArray::new(values.into(), nulls))
+}
+
+fn float_pow<T: ArrowPrimitiveType>(
+    base: &dyn Datum,
+    exponent: &dyn Datum,
+    policy: DomainErrorPolicy,
+) -> Result<ArrayRef, ArrowError>
+where
+    T::Native: Float,
+{
+    let zero = T::Native::ZERO;
+    let r = try_binary_opt::<T, T, T, _>(base, exponent, |b, e| {
+        let undefined =
+            (b < zero && e.is_finite() && e.fract() != zero) || (b == zero && e < zero);
+        let result = b.powf(e);
+        match undefined {
+            true => policy.apply(Some(result), || domain_error("pow", (b, e))),
+            false => Ok(Some(result)),
+        }
+    })?;
+    Ok(Arc::new(r))
+}
+
+/// Returns the precision and scale of a decimal [`DataType`], returning an error
+/// for negative scales
+fn decimal_precision_scale(name: &str, t: &DataType) -> Result<(u8, u32), ArrowError> {
+    let (p, s) = match t {
+        DataType::Decimal32(p, s)
+        | DataType::Decimal64(p, s)
+        | DataType::Decimal128(p, s)
+        | DataType::Decimal256(p, s) => (*p, *s),
+        _ => unreachable!(),
+    };
+    match u32::try_from(s) {
+        Ok(s) => Ok((p, s)),
+        Err(_) => Err(unsupported(name, t)),
+    }
+}
+
+fn decimal_sqrt<T: DecimalType>(
+    array: &dyn Array,
+    policy: DomainErrorPolicy,
+) -> Result<ArrayRef, ArrowError> {
+    let (p, s) = decimal_precision_scale("sqrt", array.data_type())?;
+    // sqrt(x / 10^s) * 10^s = sqrt(x * 10^s)
+    let factor = T::Native::usize_as(10).pow_checked(s)?;
+    let zero = T::Native::ZERO;
+
+    let a = array.as_primitive::<T>();
+    let r = a.try_unary::<_, T, _>(|x| match x.is_lt(zero) {
+        true => policy
+            .apply::<T::Native>(None, || domain_error("sqrt", x))
+            .map(|_| zero),
+        false => Ok(isqrt(x.mul_checked(factor)?)),
+    })?;
+
+    let r = match policy {
+        DomainErrorPolicy::Null => {
+            let valid = BooleanBuffer::collect_bool(a.len(), |i| !a.value(i).is_lt(zero));
+            let nulls = NullBuffer::union(r.nulls(), Some(&NullBuffer::new(valid)));
+            PrimitiveArray::new(r.values().clone(), nulls)
+        }
+        _ => r,
+    };
+    Ok(Arc::new(r.with_precision_and_scale(p, s as i8)?))
+}
+
+/// Returns the square root of the non-negative integer `n`, rounded to the nearest integer
+fn isqrt<N: ArrowNativeTypeOp>(n: N) -> N {
+    let two = N::usize_as(2);
+    if n.is_lt(two) {
+        return n;
+    }
+    // Newton's method, starting from an estimate greater than the root
+    let mut x = n.div_wrapping(two).add_wrapping(N::ONE);
+    loop {
+        let y = x.add_wrapping(n.div_wrapping(x)).div_wrapping(two);
+        if !y.is_lt(x) {
+            break;
+        }
+        x = y;
+    }
+    // `x` is the floor of the root, which is rounded up if `n - x^2 > x`
+    match n.sub_wrapping(x.mul_wrapping(x)).is_gt(x) {
+        true => x.add_wrapping(N::ONE),
+        false => x,
+    }
+}
+
+fn decimal_pow<T: DecimalType>(
+    base: &dyn Datum,
+    exponent: &dyn Datum,
+    policy: DomainErrorPolicy,
+) -> Result<ArrayRef, ArrowError> {
+    let (p, s) = decimal_precision_scale("pow", base.get().0.data_type())?;
+    let one = T::Native::usize_as(10).pow_checked(s)?;
+
+    let r = try_binary_opt::<T, Int64Type, T, _>(base, exponent, |b, e| {
+        match b.is_zero() && e < 0 {
+            true => policy.apply(None, || domain_error("pow", (b, e))),
+            false => decimal_powi(b, e, one).map(Some),
+        }
+    })?
+    .with_precision_and_scale(p, s as i8)?;
+    r.validate_decimal_precision(p)?;
+    Ok(Arc::new(r))
+}
+
+/// Raises the decimal `x`, with a scale factor of `one`, to the power of `e`
+fn decimal_powi<N: ArrowNativeTypeOp>(x: N, e: i64, one: N) -> Result<N, ArrowError> {
+    let mul = |a: N, b: N| div_round(a.mul_checked(b)?, one);
+
+    // Exponentiation by squaring
+    let mut result = one;
+    let mut base = x;
+    let mut k = e.unsigned_abs();
+    while k > 0 {
+        if k & 1 == 1 {
+            result = mul(result, base)?;
+        }
+        k >>= 1;
+        if k > 0 {
+            base = mul(base, base)?;
+        }
+    }
+    match e < 0 {
+        true => div_round(one.mul_checked(one)?, result),
+        false => Ok(result),
+    }
+}
+
+/// Divides `n` by `d`, rounding half to even
+fn div_round<N: ArrowNativeTypeOp>(n: N, d: N) -> Result<N, ArrowError> {
+    let q = n.div_checked(d)?;
+    let r = n.mod_wrapping(d);
+    if r.is_zero() {
+        return Ok(q);
+    }
+    let zero = N::ZERO;
+    let abs = |x: N| match x.is_lt(zero) {
+        true => x.neg_wrapping(),
+        false => x,
+    };
+    let (r, d_abs) = (abs(r), abs(d));
+    let round_up = match r.compare(d_abs.sub_wrapping(r)) {
+        Ordering::Less => false,
+        Ordering::Greater => true,
+        Ordering::Equal => !q.mod_wrapping(N::usize_as(2)).is_zero(),
+    };
+    match (round_up, n.is_lt(zero) == d.is_lt(zero)) {
+        (false, _) => Ok(q),
+        (true, true) => q.add_checked(N::ONE),
+        (true, false) => q.sub_checked(N::ONE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_buffer::i256;
+    use half::f16;
+
+    #[test]
+    fn test_unary() {
+        let a = Float64Array::from(vec![Some(0.), None, Some(1.), Some(f64::NAN)]);
+        let r = exp(&a).unwrap();
+        let r = r.as_primitive::<Float64Type>();
+        assert_eq!(r.value(0), 1.);
+        assert!(r.is_null(1));
+        assert_eq!(r.value(2), std::f64::consts::E);
+        assert!(r.value(3).is_nan());
+
+        let r = sin(&a).unwrap();
+        assert_eq!(
+            &r.as_primitive::<Float64Type>().values()[..3],
+            &[0., 0., 1_f64.sin()]
+        );
+
+        let a = Float32Array::from(vec![8., -27.]);
+        let r = cbrt(&a).unwrap();
+        assert_eq!(r.as_ref(), &Float32Array::from(vec![2., -3.]));
+
+        let a = Float16Array::from(vec![f16::from_f32(4.), f16::from_f32(0.)]);
+        let r = exp2(&a).unwrap();
+        let expected = Float16Array::from(vec![f16::from_f32(16.), f16::from_f32(1.)]);
+        assert_eq!(r.as_ref(), &expected);
+
+        let err = tanh(&Int32Array::from(vec![1])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid arithmetic operation: tanh(Int32)"
+        );
+    }
+
+    #[test]
+    fn test_domain() {
+        let a = Float64Array::from(vec![
+            Some(100.),
+            Some(0.),
+            None,
+            Some(-1.),
+            Some(f64::NAN),
+        ]);
+
+        let r = log10(&a, DomainErrorPolicy::NaN).unwrap();
+        let r = r.as_primitive::<Float64Type>();
+        assert_eq!(r.value(0), 2.);
+        assert_eq!(r.value(1), f64::NEG_INFINITY);
+        assert!(r.is_null(2));
+        assert!(r.value(3).is_nan());
+        assert!(r.value(4).is_nan());
+
+        let r = log10(&a, DomainErrorPolicy::Null).unwrap();
+        let r = r.as_primitive::<Float64Type>();
+        assert_eq!(r.null_count(), 3);
+        assert_eq!(r.value(0), 2.);
+        assert!(r.value(4).is_nan());
+
+        let err = ln(&a, DomainErrorPolicy::Error).unwrap_err();
+        assert_eq!(err.to_string(), "Compute error: ln is undefined for 0.0");
+
+        let a = Float32Array::from(vec![-1., 0.5, 1.]);
+        let r = asin(&a, DomainErrorPolicy::Error).unwrap();
+        assert_eq!(r.null_count(), 0);
+        let r = atanh(&a, DomainErrorPolicy::Null).unwrap();
+        assert_eq!(
+            r.as_ref(),
+            &Float32Array::from(vec![None, Some(0.5_f32.atanh()), None])
+        );
+        let r = acosh(&a, DomainErrorPolicy::Null).unwrap();
+        assert_eq!(r.as_ref(), &Float32Array::from(vec![None, None, Some(0.)]));
+
+        let a = Float32Array::from(vec![1.5, -1.5]);
+        let err = acos(&a, DomainErrorPolicy::Error).unwrap_err();
+        assert_eq!(err.to_string(), "Compute error: acos is undefined for 1.5");
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let a = Float64Array::from(vec![Some(9.), Some(-0.), None, Some(-4.)]);
+        let r = sqrt(&a, DomainErrorPolicy::Null).unwrap();
+        let expected = Float64Array::from(vec![Some(3.), Some(-0.), None, None]);
+        assert_eq!(r.as_ref(), &expected);
+
+        let a = Decimal64Array::from(vec![Some(1000), None, Some(-1), Some(0)])
+            .with_precision_and_scale(10, 3)
+            .unwrap();
+        let r = sqrt(&a, DomainErrorPolicy::Null).unwrap();
+        assert_eq!(r.data_type(), a.data_type());
+        let expected = Decimal64Array::from(vec![Some(1000), None, None, Some(0)])
+            .with_precision_and_scale(10, 3)
+            .unwrap();
+        assert_eq!(r.as_ref(), &expected);
+
+        let err = sqrt(&a, DomainErrorPolicy::NaN).unwrap_err();
+        assert_eq!(err.to_string(), "Compute error: sqrt is undefined for -1");
+
+        // sqrt(2) = 1.41421356..., sqrt(3) = 1.7320508...
+        let a = Decimal256Array::from(vec![i256::from_i128(2), i256::from_i128(3)])
+            .with_precision_and_scale(20, 0)
+            .unwrap();
+        let r = sqrt(&a, DomainErrorPolicy::Error).unwrap();
+        let r = r.as_primitive::<Decimal256Type>();
+        assert_eq!(r.values(), &[i256::from_i128(1), i256::from_i128(2)]);
+
+        let a = Decimal128Array::from(vec![2, i128::MAX / 100])
+            .with_precision_and_scale(38, 6)
+            .unwrap();
+        let r = sqrt(&a.slice(0, 1), DomainErrorPolicy::Error).unwrap();
+        assert_eq!(r.as_primitive::<Decimal128Type>().values(), &[1414]);
+        let err = sqrt(&a, DomainErrorPolicy::Error).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Compute error: Overflow happened on"));
+
+        let a = Decimal32Array::from(vec![1])
+            .with_precision_and_scale(5, -2)
+            .unwrap();
+        let err = sqrt(&a, DomainErrorPolicy::Error).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid arithmetic operation: sqrt(Decimal32(5, -2))"
+        );
+    }
+
+    #[test]
+    fn test_pow() {
+        let base =
+            Float64Array::from(vec![Some(2.), Some(-8.), None, Some(0.), Some(-2.)]);
+        let exponent = Float64Array::from(vec![10., 1. / 3., 1., -1., 3.]);
+        let r = pow(&base, &exponent, DomainErrorPolicy::Null).unwrap();
+        let expected = Float64Array::from(vec![Some(1024.), None, None, None, Some(-8.)]);
+        assert_eq!(r.as_ref(), &expected);
+
+        let r = pow(&base, &exponent, DomainErrorPolicy::NaN).unwrap();
+        let r = r.as_primitive::<Float64Type>();
+        assert!(r.value(1).is_nan());
+        assert_eq!(r.value(3), f64::INFINITY);
+
+        let err = pow(&base, &exponent, DomainErrorPolicy::Error).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: pow is undefined for (-8.0, 0.3333333333333333)"
+        );
+
+        let r = pow(
+            &base,
+            &Float64Array::new_scalar(2.),
+            DomainErrorPolicy::Error,
+        )
+        .unwrap();
+        let expected =
+            Float64Array::from(vec![Some(4.), Some(64.), None, Some(0.), Some(4.)]);
+        assert_eq!(r.as_ref(), &expected);
+
+        let err = pow(&base, &Float64Array::from(vec![1.]), DomainErrorPolicy::NaN);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "Compute error: Cannot perform a binary operation on arrays of different length"
+        );
+        let err = pow(&base, &Float32Array::new_scalar(1.), DomainErrorPolicy::NaN);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "Invalid argument error: Invalid arithmetic operation: pow(Float64, Float32)"
+        );
+    }
+
+    #[test]
+    fn test_decimal_pow() {
+        // 1.5, 0.00, 1.01, -2.0
+        let base =
+            Decimal128Array::from(vec![Some(150), Some(0), Some(101), None, Some(-200)])
+                .with_precision_and_scale(10, 2)
+                .unwrap();
+        let exponent = Int64Array::from(vec![2, -1, 10, 1, -3]);
+        let r = pow(&base, &exponent, DomainErrorPolicy::Null).unwrap();
+        assert_eq!(r.data_type(), base.data_type());
+        // 1.01^10 = 1.1046..., -2^-3 = -0.125
+        let expected =
+            Decimal128Array::from(vec![Some(225), None, Some(110), None, Some(-12)])
+                .with_precision_and_scale(10, 2)
+                .unwrap();
+        assert_eq!(r.as_ref(), &expected);
+
+        let err = pow(&base, &exponent, DomainErrorPolicy::NaN).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: pow is undefined for (0, -1)"
+        );
+
+        let base = Decimal32Array::from(vec![9000])
+            .with_precision_and_scale(4, 0)
+            .unwrap();
+        let err = pow(&base, &Int64Array::new_scalar(2), DomainErrorPolicy::Error);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "Invalid argument error: 81000000 is too large to store in a Decimal32 of precision 4. Max is 9999"
+        );
+
+        let err = pow(&base, &Int32Array::new_scalar(2), DomainErrorPolicy::Error);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "Invalid argument error: Invalid arithmetic operation: pow(Decimal32(4, 0), Int32)"
+        );
+    }
+
+    #[test]
+    fn test_atan2() {
+        let y = Float64Array::from(vec![Some(1.), None, Some(-1.)]);
+        let r = atan2(&y, &Float64Array::new_scalar(1.)).unwrap();
+        let r = r.as_primitive::<Float64Type>();
+        assert_eq!(r.value(0), std::f64::consts::FRAC_PI_4);
+        assert!(r.is_null(1));
+        assert_eq!(r.value(2), -std::f64::consts::FRAC_PI_4);
+    }
+
+    #[test]
+    fn test_div_round() {
+        assert_eq!(div_round(25, 10).unwrap(), 2);
+        assert_eq!(div_round(35, 10).unwrap(), 4);
+        assert_eq!(div_round(-25, 10).unwrap(), -2);
+        assert_eq!(div_round(-26, 10).unwrap(), -3);
+        assert_eq!(div_round(26, -10).unwrap(), -3);
+        assert_eq!(div_round(14, 10).unwrap(), 1);
+        assert_eq!(isqrt(i64::MAX), 3037000500);
+        assert_eq!(isqrt(99_i32), 10);
+        assert_eq!(isqrt(90_i32), 9);
+    }
+}
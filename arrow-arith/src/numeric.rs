@@ -25,8 +25,9 @@ use arrow_array::cast::AsArray;
 use arrow_array::timezone::Tz;
 use arrow_array::types::*;
 use arrow_array::*;
-use arrow_buffer::ArrowNativeType;
+use arrow_buffer::{ArrowNativeType, NullBuffer, ScalarBuffer};
 use arrow_schema::{ArrowError, DataType, IntervalUnit, TimeUnit};
+use num::Float;
 
 use crate::arity::{binary, try_binary};
 
@@ -109,6 +110,20 @@ pub fn neg(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
         Float16 => neg_wrapping!(Float16Type, array),
         Float32 => neg_wrapping!(Float32Type, array),
         Float64 => neg_wrapping!(Float64Type, array),
+        Decimal32(p, s) => {
+            let a = array
+                .as_primitive::<Decimal32Type>()
+                .try_unary::<_, Decimal32Type, _>(|x| x.neg_checked())?;
+
+            Ok(Arc::new(a.with_precision_and_scale(*p, *s)?))
+        }
+        Decimal64(p, s) => {
+            let a = array
+                .as_primitive::<Decimal64Type>()
+                .try_unary::<_, Decimal64Type, _>(|x| x.neg_checked())?;
+
+            Ok(Arc::new(a.with_precision_and_scale(*p, *s)?))
+        }
         Decimal128(p, s) => {
             let a = array
                 .as_primitive::<Decimal128Type>()
@@ -167,6 +182,176 @@ pub fn neg_wrapping(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
     }
 }
 
+/// Dispatch `$int`, `$float` or `$decimal` with the [`ArrowPrimitiveType`] of the
+/// numeric [`DataType`] `$dt`, returning an error naming `$name` for other types
+macro_rules! numeric_kernel {
+    ($name:literal, $dt:expr, $int:ident, $float:ident, $decimal:ident, ($($args:expr),*)) => {{
+        use DataType::*;
+        match $dt {
+            Int8 => $int::<Int8Type>($($args),*),
+            Int16 => $int::<Int16Type>($($args),*),
+            Int32 => $int::<Int32Type>($($args),*),
+            Int64 => $int::<Int64Type>($($args),*),
+            UInt8 => $int::<UInt8Type>($($args),*),
+            UInt16 => $int::<UInt16Type>($($args),*),
+            UInt32 => $int::<UInt32Type>($($args),*),
+            UInt64 => $int::<UInt64Type>($($args),*),
+            Float16 => $float::<Float16Type>($($args),*),
+            Float32 => $float::<Float32Type>($($args),*),
+            Float64 => $float::<Float64Type>($($args),*),
+            Decimal32(_, _) => $decimal::<Decimal32Type>($($args),*),
+            Decimal64(_, _) => $decimal::<Decimal64Type>($($args),*),
+            Decimal128(_, _) => $decimal::<Decimal128Type>($($args),*),
+            Decimal256(_, _) => $decimal::<Decimal256Type>($($args),*),
+            t => Err(ArrowError::InvalidArgumentError(format!(
+                "Invalid arithmetic operation: {}({t})",
+                $name
+            ))),
+        }
+    }};
+}
+
+/// Perform `a * b + c`, returning an error on overflow
+///
+/// Floating point values are computed with a single rounding, see [`f64::mul_add`],
+/// decimal values follow the rules of [`mul`] and [`add`]
+pub fn mul_add(
+    a: &dyn Datum,
+    b: &dyn Datum,
+    c: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    mul_add_op(a, b, c, false)
+}
+
+/// Perform `a * b + c`, wrapping on overflow for [`DataType::is_integer`]
+pub fn mul_add_wrapping(
+    a: &dyn Datum,
+    b: &dyn Datum,
+    c: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    mul_add_op(a, b, c, true)
+}
+
+/// Limits each element of `values` to the range `[min, max]`
+///
+/// `min` and `max` may be scalars or arrays of the same type as `values`, returning
+/// an error if `min` is greater than `max`. Floating point values are compared
+/// according to their total order, and so NaN values are greater than `max`.
+pub fn clamp(
+    values: &dyn Datum,
+    min: &dyn Datum,
+    max: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    let (v, _) = values.get();
+    let (lo, _) = min.get();
+    let (hi, _) = max.get();
+    if v.data_type() != lo.data_type() || v.data_type() != hi.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid arithmetic operation: clamp({}, {}, {})",
+            v.data_type(),
+            lo.data_type(),
+            hi.data_type()
+        )));
+    }
+    numeric_kernel!(
+        "clamp",
+        v.data_type(),
+        clamp_op,
+        clamp_op,
+        clamp_op,
+        (values, min, max)
+    )
+}
+
+/// Returns the absolute value of each element of `array`, returning an error on overflow
+pub fn abs(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    numeric_kernel!(
+        "abs",
+        array.data_type(),
+        abs_op,
+        abs_op,
+        abs_op,
+        (array, false)
+    )
+}
+
+/// Returns the absolute value of each element of `array`, wrapping on overflow for
+/// [`DataType::is_integer`]
+pub fn abs_wrapping(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    numeric_kernel!(
+        "abs",
+        array.data_type(),
+        abs_op,
+        abs_op,
+        abs_op,
+        (array, true)
+    )
+}
+
+/// Returns `-1`, `0` or `1` for each element of `array` that is negative, zero or
+/// positive respectively, in the type of `array`
+///
+/// Floating point zeros and NaNs are returned unchanged. Decimal arrays must be able
+/// to represent `1`, i.e. have a scale that is non-negative and less than the precision
+pub fn sign(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    numeric_kernel!(
+        "sign",
+        array.data_type(),
+        sign_op,
+        sign_op,
+        sign_op,
+        (array)
+    )
+}
+
+/// Truncates each element of `array` towards zero, keeping `digits` decimal digits
+///
+/// A negative `digits` truncates to a multiple of a power of ten, e.g. `-2` truncates
+/// `1234` to `1200`. The result has the same type as `array`.
+pub fn trunc(array: &dyn Array, digits: i32) -> Result<ArrayRef, ArrowError> {
+    numeric_kernel!(
+        "trunc",
+        array.data_type(),
+        round_integer,
+        round_float,
+        round_decimal,
+        (array, digits, true)
+    )
+}
+
+/// Rounds each element of `array` to `digits` decimal digits, rounding ties to even
+///
+/// A negative `digits` rounds to a multiple of a power of ten, e.g. `-2` rounds
+/// `1250` to `1200`. The result has the same type as `array`, returning an error
+/// if a rounded value overflows it.
+///
+/// ```
+/// # use arrow_array::{Decimal128Array, Float64Array};
+/// # use arrow_arith::numeric::round;
+/// let a = Float64Array::from(vec![0.125, 0.135, -2.5]);
+/// let r = round(&a, 2).unwrap();
+/// assert_eq!(r.as_ref(), &Float64Array::from(vec![0.12, 0.14, -2.5]));
+///
+/// let a = Decimal128Array::from(vec![1250, 1350, -1251])
+///     .with_precision_and_scale(5, 2)
+///     .unwrap();
+/// let r = round(&a, 0).unwrap();
+/// let expected = Decimal128Array::from(vec![1200, 1400, -1300])
+///     .with_precision_and_scale(5, 2)
+///     .unwrap();
+/// assert_eq!(r.as_ref(), &expected);
+/// ```
+pub fn round(array: &dyn Array, digits: i32) -> Result<ArrayRef, ArrowError> {
+    numeric_kernel!(
+        "round",
+        array.data_type(),
+        round_integer,
+        round_float,
+        round_decimal,
+        (array, digits, false)
+    )
+}
+
 /// An enumeration of arithmetic operations
 ///
 /// This allows sharing the type dispatch logic across the various kernels
@@ -236,6 +421,8 @@ fn arithmetic_op(
         (Interval(MonthDayNano), Interval(MonthDayNano)) => interval_op::<IntervalMonthDayNanoType>(op, l, l_scalar, r, r_scalar),
         (Date32, _) => date_op::<Date32Type>(op, l, l_scalar, r, r_scalar),
         (Date64, _) => date_op::<Date64Type>(op, l, l_scalar, r, r_scalar),
+        (Decimal32(_, _), Decimal32(_, _)) => decimal_op::<Decimal32Type>(op, l, l_scalar, r, r_scalar),
+        (Decimal64(_, _), Decimal64(_, _)) => decimal_op::<Decimal64Type>(op, l, l_scalar, r, r_scalar),
         (Decimal128(_, _), Decimal128(_, _)) => decimal_op::<Decimal128Type>(op, l, l_scalar, r, r_scalar),
         (Decimal256(_, _), Decimal256(_, _)) => decimal_op::<Decimal256Type>(op, l, l_scalar, r, r_scalar),
         (l_t, r_t) => match (l_t, r_t) {
@@ -731,6 +918,8 @@ fn decimal_op<T: DecimalType>(
     let r = r.as_primitive::<T>();
 
     let (p1, s1, p2, s2) = match (l.data_type(), r.data_type()) {
+        (DataType::Decimal32(p1, s1), DataType::Decimal32(p2, s2)) => (p1, s1, p2, s2),
+        (DataType::Decimal64(p1, s1), DataType::Decimal64(p2, s2)) => (p1, s1, p2, s2),
         (DataType::Decimal128(p1, s1), DataType::Decimal128(p2, s2)) => (p1, s1, p2, s2),
         (DataType::Decimal256(p1, s1), DataType::Decimal256(p2, s2)) => (p1, s1, p2, s2),
         _ => unreachable!(),
@@ -850,12 +1039,345 @@ fn decimal_op<T: DecimalType>(
     Ok(Arc::new(array))
 }
 
+/// Applies `op` to each element of three potentially scalar inputs of type `T`
+fn try_ternary<T: ArrowPrimitiveType, F>(
+    a: &dyn Datum,
+    b: &dyn Datum,
+    c: &dyn Datum,
+    op: F,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    F: Fn(T::Native, T::Native, T::Native) -> Result<T::Native, ArrowError>,
+{
+    let inputs = [a.get(), b.get(), c.get()];
+
+    let mut len = None;
+    for (array, _) in inputs.iter().filter(|(_, scalar)| !scalar) {
+        match len {
+            Some(len) if len != array.len() => {
+                return Err(ArrowError::ComputeError(
+                    "Cannot perform a ternary operation on arrays of different length"
+                        .to_string(),
+                ))
+            }
+            _ => len = Some(array.len()),
+        }
+    }
+    let len = len.unwrap_or(1);
+
+    if inputs.iter().any(|(a, scalar)| *scalar && a.is_null(0)) {
+        return Ok(PrimitiveArray::new_null(len));
+    }
+    let nulls = inputs
+        .iter()
+        .filter(|(_, scalar)| !scalar)
+        .fold(None, |acc, (a, _)| {
+            NullBuffer::union(acc.as_ref(), a.nulls())
+        });
+
+    let [a, b, c] = inputs.map(|(a, scalar)| (a.as_primitive::<T>().values(), scalar));
+    let value = |(v, scalar): (&ScalarBuffer<T::Native>, bool), idx: usize| match scalar {
+        true => v[0],
+        false => v[idx],
+    };
+
+    let mut buffer = vec![T::Native::default(); len];
+    let apply = |idx: usize| {
+        buffer[idx] = op(value(a, idx), value(b, idx), value(c, idx))?;
+        Ok::<_, ArrowError>(())
+    };
+    match &nulls {
+        Some(n) if n.null_count() > 0 => n.try_for_each_valid_idx(apply)?,
+        _ => (0..len).try_for_each(apply)?,
+    }
+    Ok(PrimitiveArray::new(buffer.into(), nulls))
+}
+
+/// Dispatch `mul_add` on the type of its inputs
+fn mul_add_op(
+    a: &dyn Datum,
+    b: &dyn Datum,
+    c: &dyn Datum,
+    wrapping: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let (a_array, a_scalar) = a.get();
+    let (b_array, b_scalar) = b.get();
+    let (c_array, _) = c.get();
+    let (a_t, b_t, c_t) = (
+        a_array.data_type(),
+        b_array.data_type(),
+        c_array.data_type(),
+    );
+
+    if decimal_precision_scale(a_t).is_some() {
+        // Decimal results have a precision and scale derived from those of the inputs
+        let product = match wrapping {
+            true => mul_wrapping(a, b)?,
+            false => mul(a, b)?,
+        };
+        return match a_scalar && b_scalar {
+            true => add(&Scalar::new(product), c),
+            false => add(&product, c),
+        };
+    }
+    if a_t != b_t || a_t != c_t {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid arithmetic operation: {a_t} * {b_t} + {c_t}"
+        )));
+    }
+    numeric_kernel!(
+        "mul_add",
+        a_t,
+        mul_add_integer,
+        mul_add_float,
+        mul_add_integer,
+        (a, b, c, wrapping)
+    )
+}
+
+fn mul_add_integer<T: ArrowPrimitiveType>(
+    a: &dyn Datum,
+    b: &dyn Datum,
+    c: &dyn Datum,
+    wrapping: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let array = match wrapping {
+        true => {
+            try_ternary::<T, _>(a, b, c, |a, b, c| Ok(a.mul_wrapping(b).add_wrapping(c)))?
+        }
+        false => {
+            try_ternary::<T, _>(a, b, c, |a, b, c| a.mul_checked(b)?.add_checked(c))?
+        }
+    };
+    Ok(Arc::new(array))
+}
+
+fn mul_add_float<T: ArrowPrimitiveType>(
+    a: &dyn Datum,
+    b: &dyn Datum,
+    c: &dyn Datum,
+    _wrapping: bool,
+) -> Result<ArrayRef, ArrowError>
+where
+    T::Native: Float,
+{
+    let array = try_ternary::<T, _>(a, b, c, |a, b, c| Ok(a.mul_add(b, c)))?;
+    Ok(Arc::new(array))
+}
+
+fn clamp_op<T: ArrowPrimitiveType>(
+    values: &dyn Datum,
+    min: &dyn Datum,
+    max: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    let array = try_ternary::<T, _>(values, min, max, |v, min, max| {
+        if min.is_gt(max) {
+            return Err(ArrowError::ComputeError(format!(
+                "Cannot clamp to a minimum of {min:?} greater than the maximum of {max:?}"
+            )));
+        }
+        Ok(match (v.is_lt(min), v.is_gt(max)) {
+            (true, _) => min,
+            (_, true) => max,
+            _ => v,
+        })
+    })?;
+    Ok(Arc::new(
+        array.with_data_type(values.get().0.data_type().clone()),
+    ))
+}
+
+fn abs_op<T: ArrowPrimitiveType>(
+    array: &dyn Array,
+    wrapping: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let a = array.as_primitive::<T>();
+    let zero = T::Native::ZERO;
+    let r = match wrapping {
+        true => a.unary::<_, T>(|x| match x.is_lt(zero) {
+            true => x.neg_wrapping(),
+            false => x,
+        }),
+        false => a.try_unary::<_, T, _>(|x| match x.is_lt(zero) {
+            true => x.neg_checked(),
+            false => Ok(x),
+        })?,
+    };
+    Ok(Arc::new(r.with_data_type(array.data_type().clone())))
+}
+
+fn sign_op<T: ArrowPrimitiveType>(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    let one = match decimal_precision_scale(array.data_type()) {
+        Some((p, s)) if s < 0 || s as u8 >= p => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot represent the sign of {} values",
+                array.data_type()
+            )))
+        }
+        Some((_, s)) => T::Native::usize_as(10).pow_checked(s as _)?,
+        None => T::Native::ONE,
+    };
+    let zero = T::Native::ZERO;
+
+    let a = array.as_primitive::<T>();
+    // Uses partial_cmp to return NaNs unchanged
+    let r = a.unary::<_, T>(|x| match x.partial_cmp(&zero) {
+        Some(Ordering::Less) => one.neg_wrapping(),
+        Some(Ordering::Greater) => one,
+        _ => x,
+    });
+    Ok(Arc::new(r.with_data_type(array.data_type().clone())))
+}
+
+/// Returns the precision and scale of a decimal [`DataType`]
+fn decimal_precision_scale(t: &DataType) -> Option<(u8, i8)> {
+    match t {
+        DataType::Decimal32(p, s)
+        | DataType::Decimal64(p, s)
+        | DataType::Decimal128(p, s)
+        | DataType::Decimal256(p, s) => Some((*p, *s)),
+        _ => None,
+    }
+}
+
+fn round_integer<T: ArrowPrimitiveType>(
+    array: &dyn Array,
+    digits: i32,
+    trunc: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let a = array.as_primitive::<T>();
+    let r = round_to_power_of_ten(a, -(digits as i64), trunc)?;
+    Ok(Arc::new(r))
+}
+
+fn round_decimal<T: DecimalType>(
+    array: &dyn Array,
+    digits: i32,
+    trunc: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let (p, s) = decimal_precision_scale(array.data_type()).unwrap();
+    let a = array.as_primitive::<T>();
+    let r = round_to_power_of_ten(a, s as i64 - digits as i64, trunc)?
+        .with_precision_and_scale(p, s)?;
+    // Rounding away from zero may require an additional digit
+    if !trunc {
+        r.validate_decimal_precision(p)?;
+    }
+    Ok(Arc::new(r))
+}
+
+/// Rounds the integers in `array` to a multiple of `10^exp`, towards zero if `trunc`,
+/// otherwise to the nearest multiple with ties to even
+fn round_to_power_of_ten<T: ArrowPrimitiveType>(
+    array: &PrimitiveArray<T>,
+    exp: i64,
+    trunc: bool,
+) -> Result<PrimitiveArray<T>, ArrowError> {
+    if exp <= 0 {
+        return Ok(array.clone());
+    }
+    let exp = u32::try_from(exp).unwrap_or(u32::MAX);
+    let zero = T::Native::ZERO;
+    let ten = T::Native::usize_as(10);
+
+    let factor = match ten.pow_checked(exp) {
+        Ok(factor) => factor,
+        // All values are closer to zero than to `10^exp`
+        Err(_) if trunc => return Ok(array.unary(|_| zero)),
+        Err(_) => {
+            // Values beyond half of `10^exp` round to it, and so overflow
+            let half = ten
+                .pow_checked(exp - 1)
+                .and_then(|x| x.mul_checked(T::Native::usize_as(5)));
+            return match half {
+                Ok(half) => array.try_unary(|x| {
+                    let overflow = match x.is_lt(zero) {
+                        true => x.is_lt(half.neg_wrapping()),
+                        false => x.is_gt(half),
+                    };
+                    match overflow {
+                        true => Err(ArrowError::ComputeError(format!(
+                            "Overflow happened on: round({x:?})"
+                        ))),
+                        false => Ok(zero),
+                    }
+                }),
+                Err(_) => Ok(array.unary(|_| zero)),
+            };
+        }
+    };
+
+    let two = T::Native::usize_as(2);
+    array.try_unary(|x| {
+        let remainder = x.mod_wrapping(factor);
+        let truncated = x.sub_wrapping(remainder);
+        if trunc || remainder.is_zero() {
+            return Ok(truncated);
+        }
+        let remainder = match remainder.is_lt(zero) {
+            true => remainder.neg_wrapping(),
+            false => remainder,
+        };
+        let round_up = match remainder.compare(factor.sub_wrapping(remainder)) {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+            Ordering::Equal => !x.div_wrapping(factor).mod_wrapping(two).is_zero(),
+        };
+        match (round_up, x.is_lt(zero)) {
+            (false, _) => Ok(truncated),
+            (true, false) => truncated.add_checked(factor),
+            (true, true) => truncated.sub_checked(factor),
+        }
+    })
+}
+
+fn round_float<T: ArrowPrimitiveType>(
+    array: &dyn Array,
+    digits: i32,
+    trunc: bool,
+) -> Result<ArrayRef, ArrowError>
+where
+    T::Native: Float,
+{
+    let one = T::Native::ONE;
+    let two = one + one;
+    let half = one / two;
+    let round = |x: T::Native| match trunc {
+        true => x.trunc(),
+        false => {
+            let rounded = x.round();
+            // `round` rounds ties away from zero
+            match (rounded - x).abs() == half {
+                true => (x / two).round() * two,
+                false => rounded,
+            }
+        }
+    };
+
+    let ten = <T::Native as num::NumCast>::from(10).unwrap();
+    let factor = ten.powi(digits.saturating_abs());
+    let a = array.as_primitive::<T>();
+    let r = match digits >= 0 {
+        true => a.unary::<_, T>(|x| match (x * factor).is_finite() {
+            true => round(x * factor) / factor,
+            // No digits beyond `digits`
+            false => x,
+        }),
+        false => a.unary::<_, T>(|x| match factor.is_finite() {
+            true => round(x / factor) * factor,
+            false => x * T::Native::ZERO,
+        }),
+    };
+    Ok(Arc::new(r))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use arrow_array::temporal_conversions::{as_date, as_datetime};
     use arrow_buffer::{i256, ScalarBuffer};
     use chrono::{DateTime, NaiveDate};
+    use half::f16;
 
     fn test_neg_primitive<T: ArrowPrimitiveType>(
         input: &[T::Native],
@@ -1092,6 +1614,41 @@ mod tests {
         assert!(r.value(5).is_nan());
     }
 
+    #[test]
+    fn test_decimal32_decimal64() {
+        // 1.50 -0.25 9.99
+        let a = Decimal32Array::from(vec![150, -25, 999])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        // 0.5 1.0 0.1
+        let b = Decimal32Array::from(vec![5, 10, 1])
+            .with_precision_and_scale(3, 1)
+            .unwrap();
+
+        let result = add(&a, &b).unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal32(6, 2));
+        assert_eq!(
+            result.as_primitive::<Decimal32Type>().values(),
+            &[200, 75, 1009]
+        );
+
+        let result = mul(&a, &b).unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal32(9, 3));
+        assert_eq!(
+            result.as_primitive::<Decimal32Type>().values(),
+            &[750, -250, 999]
+        );
+
+        let a = Decimal64Array::from(vec![i64::MAX, 1])
+            .with_precision_and_scale(18, 0)
+            .unwrap();
+        let err = add(&a, &a).unwrap_err().to_string();
+        assert!(err.contains("Overflow happened"), "{err}");
+
+        let r = neg(&a.slice(1, 1)).unwrap();
+        assert_eq!(r.as_primitive::<Decimal64Type>().values(), &[-1]);
+    }
+
     #[test]
     fn test_decimal() {
         // 0.015 7.842 -0.577 0.334 -0.078 0.003
@@ -1493,6 +2050,289 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mul_add() {
+        let a = Int32Array::from(vec![Some(2), None, Some(-3), Some(4)]);
+        let b = Int32Array::from(vec![3, 4, 5, 6]);
+        let c = Int32Array::new_scalar(1);
+        let r = mul_add(&a, &b, &c).unwrap();
+        let expected = Int32Array::from(vec![Some(7), None, Some(-14), Some(25)]);
+        assert_eq!(r.as_ref(), &expected);
+
+        let r = mul_add(&Int32Array::new_scalar(2), &b, &a).unwrap();
+        let expected = Int32Array::from(vec![Some(8), None, Some(7), Some(16)]);
+        assert_eq!(r.as_ref(), &expected);
+
+        let null = Scalar::new(Int32Array::new_null(1));
+        let r = mul_add(&a, &b, &null).unwrap();
+        assert_eq!(r.null_count(), 4);
+
+        let big = Int32Array::from(vec![i32::MAX]);
+        let err = mul_add(&big, &Int32Array::from(vec![2]), &c).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Overflow happened on: 2147483647 * 2"
+        );
+        let r = mul_add_wrapping(&big, &Int32Array::from(vec![2]), &c).unwrap();
+        assert_eq!(r.as_primitive::<Int32Type>().value(0), -1);
+
+        // Overflow in null slots is ignored
+        let a = Int32Array::from(vec![Some(1), None]);
+        let b = Int32Array::from(vec![1, i32::MAX]);
+        let r = mul_add(&a, &b, &Int32Array::from(vec![1, 2])).unwrap();
+        assert_eq!(r.as_ref(), &Int32Array::from(vec![Some(2), None]));
+
+        // Floats are computed with a single rounding
+        let x = 0.1_f64;
+        let a = Float64Array::from(vec![x]);
+        let r = mul_add(&a, &a, &Float64Array::from(vec![-0.01])).unwrap();
+        assert_eq!(
+            r.as_primitive::<Float64Type>().value(0),
+            x.mul_add(x, -0.01)
+        );
+
+        let a = Decimal128Array::from(vec![150, 25])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let b = Decimal128Array::from(vec![2, -4])
+            .with_precision_and_scale(3, 0)
+            .unwrap();
+        let c = Scalar::new(
+            Decimal128Array::from(vec![1])
+                .with_precision_and_scale(3, 1)
+                .unwrap(),
+        );
+        let r = mul_add(&a, &b, &c).unwrap();
+        assert_eq!(r.data_type(), &DataType::Decimal128(10, 2));
+        assert_eq!(r.as_primitive::<Decimal128Type>().values(), &[310, -90]);
+
+        let err = mul_add(&Int32Array::from(vec![1]), &b, &c).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid arithmetic operation: Int32 * Decimal128(3, 0) + Decimal128(3, 1)"
+        );
+        let err = mul_add(&b, &b, &Decimal128Array::from(vec![1, 2, 3])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot perform a binary operation on arrays of different length"
+        );
+        let b = Int32Array::from(vec![1, 2]);
+        let err = mul_add(&b, &b, &Int32Array::from(vec![1, 2, 3])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot perform a ternary operation on arrays of different length"
+        );
+    }
+
+    #[test]
+    fn test_clamp() {
+        let a = Int64Array::from(vec![Some(-5), Some(3), None, Some(12)]);
+        let r =
+            clamp(&a, &Int64Array::new_scalar(0), &Int64Array::new_scalar(10)).unwrap();
+        let expected = Int64Array::from(vec![Some(0), Some(3), None, Some(10)]);
+        assert_eq!(r.as_ref(), &expected);
+
+        let min = Int64Array::from(vec![-10, 4, 0, 0]);
+        let r = clamp(&a, &min, &Int64Array::new_scalar(10)).unwrap();
+        let expected = Int64Array::from(vec![Some(-5), Some(4), None, Some(10)]);
+        assert_eq!(r.as_ref(), &expected);
+
+        let a = Float32Array::from(vec![f32::NAN, -1.5, f32::NEG_INFINITY]);
+        let min = Float32Array::new_scalar(-1.);
+        let r = clamp(&a, &min, &Float32Array::new_scalar(1.)).unwrap();
+        assert_eq!(r.as_primitive::<Float32Type>().values(), &[1., -1., -1.]);
+
+        let a = Decimal128Array::from(vec![150, -25])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let min = Scalar::new(
+            Decimal128Array::from(vec![0])
+                .with_precision_and_scale(5, 2)
+                .unwrap(),
+        );
+        let max = Scalar::new(
+            Decimal128Array::from(vec![100])
+                .with_precision_and_scale(5, 2)
+                .unwrap(),
+        );
+        let r = clamp(&a, &min, &max).unwrap();
+        assert_eq!(r.data_type(), a.data_type());
+        assert_eq!(r.as_primitive::<Decimal128Type>().values(), &[100, 0]);
+
+        let err = clamp(&a, &max, &min).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot clamp to a minimum of 100 greater than the maximum of 0"
+        );
+        let err = clamp(&a, &Int32Array::new_scalar(0), &max).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid arithmetic operation: clamp(Decimal128(5, 2), Int32, Decimal128(5, 2))"
+        );
+    }
+
+    #[test]
+    fn test_abs_sign() {
+        let a = Int8Array::from(vec![Some(-5), None, Some(0), Some(7)]);
+        let r = abs(&a).unwrap();
+        assert_eq!(
+            r.as_ref(),
+            &Int8Array::from(vec![Some(5), None, Some(0), Some(7)])
+        );
+        let r = sign(&a).unwrap();
+        assert_eq!(
+            r.as_ref(),
+            &Int8Array::from(vec![Some(-1), None, Some(0), Some(1)])
+        );
+
+        let a = Int8Array::from(vec![i8::MIN]);
+        let err = abs(&a).unwrap_err();
+        assert_eq!(err.to_string(), "Compute error: Overflow happened on: -128");
+        let r = abs_wrapping(&a).unwrap();
+        assert_eq!(r.as_ref(), &a);
+
+        let a = UInt16Array::from(vec![0, 5]);
+        assert_eq!(abs(&a).unwrap().as_ref(), &a);
+        assert_eq!(sign(&a).unwrap().as_ref(), &UInt16Array::from(vec![0, 1]));
+
+        let a = Float64Array::from(vec![-1.5, -0.0, f64::NAN, f64::NEG_INFINITY]);
+        let r = abs(&a).unwrap();
+        let r = r.as_primitive::<Float64Type>();
+        assert_eq!(r.value(0), 1.5);
+        assert!(r.value(1).is_sign_positive());
+        assert!(r.value(2).is_nan());
+        assert_eq!(r.value(3), f64::INFINITY);
+        let r = sign(&a).unwrap();
+        let r = r.as_primitive::<Float64Type>();
+        assert_eq!(r.value(0), -1.);
+        assert!(r.value(1) == 0. && r.value(1).is_sign_negative());
+        assert!(r.value(2).is_nan());
+        assert_eq!(r.value(3), -1.);
+
+        let a = Decimal256Array::from(vec![i256::from_i128(-342), i256::ZERO])
+            .with_precision_and_scale(9, 2)
+            .unwrap();
+        let r = abs(&a).unwrap();
+        assert_eq!(r.data_type(), a.data_type());
+        let values = r.as_primitive::<Decimal256Type>().values();
+        assert_eq!(values, &[i256::from_i128(342), i256::ZERO]);
+        let r = sign(&a).unwrap();
+        assert_eq!(r.data_type(), a.data_type());
+        let values = r.as_primitive::<Decimal256Type>().values();
+        assert_eq!(values, &[i256::from_i128(-100), i256::ZERO]);
+
+        let a = Decimal128Array::from(vec![1])
+            .with_precision_and_scale(2, 2)
+            .unwrap();
+        let err = sign(&a).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot represent the sign of Decimal128(2, 2) values"
+        );
+
+        let err = abs(&StringArray::from(vec!["a"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid arithmetic operation: abs(Utf8)"
+        );
+    }
+
+    #[test]
+    fn test_round_trunc_integer() {
+        let a =
+            Int32Array::from(vec![Some(1250), Some(1350), None, Some(-1251), Some(-49)]);
+        assert_eq!(round(&a, 2).unwrap().as_ref(), &a);
+        let r = round(&a, -2).unwrap();
+        let expected =
+            Int32Array::from(vec![Some(1200), Some(1400), None, Some(-1300), Some(0)]);
+        assert_eq!(r.as_ref(), &expected);
+        let r = trunc(&a, -2).unwrap();
+        let expected =
+            Int32Array::from(vec![Some(1200), Some(1300), None, Some(-1200), Some(0)]);
+        assert_eq!(r.as_ref(), &expected);
+
+        let a = Int8Array::from(vec![127, -128, 50]);
+        let err = round(&a, -1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Overflow happened on: 120 + 10"
+        );
+        let r = trunc(&a, -1).unwrap();
+        assert_eq!(r.as_ref(), &Int8Array::from(vec![120, -120, 50]));
+        let r = round(&a, -3).unwrap();
+        assert_eq!(r.as_ref(), &Int8Array::from(vec![0, 0, 0]));
+
+        // 10^5 overflows UInt16, but 50000 does not
+        let a = UInt16Array::from(vec![50000, 49999]);
+        let r = round(&a, -5).unwrap();
+        assert_eq!(r.as_ref(), &UInt16Array::from(vec![0, 0]));
+        let a = UInt16Array::from(vec![50001]);
+        let err = round(&a, -5).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Overflow happened on: round(50001)"
+        );
+        let r = round(&a, i32::MIN).unwrap();
+        assert_eq!(r.as_ref(), &UInt16Array::from(vec![0]));
+    }
+
+    #[test]
+    fn test_round_trunc_float() {
+        let a = Float64Array::from(vec![2.5, 3.5, -2.5, 1.234, f64::NAN]);
+        let r = round(&a, 0).unwrap();
+        let r = r.as_primitive::<Float64Type>();
+        assert_eq!(&r.values()[..4], &[2., 4., -2., 1.]);
+        assert!(r.value(4).is_nan());
+        let r = trunc(&a, 1).unwrap();
+        let r = r.as_primitive::<Float64Type>();
+        assert_eq!(&r.values()[..4], &[2.5, 3.5, -2.5, 1.2]);
+
+        let a = Float32Array::from(vec![1250., 1350., f32::MAX, 1e-40]);
+        let r = round(&a, -2).unwrap();
+        let r = r.as_primitive::<Float32Type>();
+        assert_eq!(&r.values()[..2], &[1200., 1400.]);
+        let r = round(&a, 40).unwrap();
+        assert_eq!(r.as_ref(), &a);
+        let r = round(&a, -40).unwrap();
+        assert_eq!(r.as_primitive::<Float32Type>().values(), &[0.; 4]);
+
+        let a = Float16Array::from(vec![f16::from_f32(2.5), f16::from_f32(-1.75)]);
+        let r = round(&a, 1).unwrap();
+        let expected = Float16Array::from(vec![f16::from_f32(2.5), f16::from_f32(-1.8)]);
+        assert_eq!(r.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_round_trunc_decimal() {
+        let a = Decimal64Array::from(vec![Some(12345), Some(-12355), None])
+            .with_precision_and_scale(6, 3)
+            .unwrap();
+        let r = round(&a, 2).unwrap();
+        assert_eq!(r.data_type(), a.data_type());
+        let expected = Decimal64Array::from(vec![Some(12340), Some(-12360), None])
+            .with_precision_and_scale(6, 3)
+            .unwrap();
+        assert_eq!(r.as_ref(), &expected);
+
+        let r = trunc(&a, 0).unwrap();
+        let expected = Decimal64Array::from(vec![Some(12000), Some(-12000), None])
+            .with_precision_and_scale(6, 3)
+            .unwrap();
+        assert_eq!(r.as_ref(), &expected);
+
+        let r = round(&a, 5).unwrap();
+        assert_eq!(r.as_ref(), &a);
+
+        let a = Decimal128Array::from(vec![999])
+            .with_precision_and_scale(3, 1)
+            .unwrap();
+        let err = round(&a, 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: 1000 is too large to store in a Decimal128 of precision 3. Max is 999"
+        );
+    }
+
     #[test]
     fn test_date() {
         test_date_impl::<Date32Type, _>(Date32Type::from_naive_date);
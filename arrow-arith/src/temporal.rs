@@ -19,9 +19,12 @@
 
 use std::sync::Arc;
 
-use chrono::{DateTime, Datelike, NaiveDateTime, NaiveTime, Offset, Timelike};
+use chrono::{
+    DateTime, Datelike, LocalResult, NaiveDateTime, NaiveTime, Offset, TimeZone, Timelike,
+};
 
 use arrow_array::builder::*;
+use arrow_array::cast::AsArray;
 use arrow_array::iterator::ArrayIter;
 use arrow_array::temporal_conversions::{
     as_datetime, as_datetime_with_timezone, as_time,
@@ -30,7 +33,7 @@ use arrow_array::timezone::Tz;
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::ArrowNativeType;
-use arrow_schema::{ArrowError, DataType};
+use arrow_schema::{ArrowError, DataType, TimeUnit};
 
 /// This function takes an `ArrayIter` of input array and an extractor `op` which takes
 /// an input `NaiveTime` and returns time component (e.g. hour) as `i32` value.
@@ -172,7 +175,6 @@ pub fn using_chrono_tz_and_utc_naive_date_time(
     tz: &str,
     utc: NaiveDateTime,
 ) -> Option<chrono::offset::FixedOffset> {
-    use chrono::TimeZone;
     let tz: Tz = tz.parse().ok()?;
     Some(tz.offset_from_utc_datetime(&utc).fix())
 }
@@ -523,6 +525,258 @@ pub fn second_dyn(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
     time_fraction_dyn(array, "second", |t| t.second() as i32)
 }
 
+/// How [`assume_timezone`] handles a local time that occurs twice in the timezone,
+/// such as when clocks are turned back at the end of daylight saving time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AmbiguousTime {
+    /// Return an error
+    #[default]
+    Raise,
+    /// Use the earlier of the two instants
+    Earliest,
+    /// Use the later of the two instants
+    Latest,
+    /// Return null
+    Null,
+}
+
+/// How [`assume_timezone`] handles a local time that does not exist in the timezone,
+/// such as when clocks are turned forward at the start of daylight saving time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NonexistentTime {
+    /// Return an error
+    #[default]
+    Raise,
+    /// Use the last instant before the gap in local time
+    Earliest,
+    /// Use the first instant after the gap in local time
+    Latest,
+    /// Return null
+    Null,
+}
+
+/// Invokes `$f::<T>` with the array downcast to the timestamp type of its unit
+macro_rules! timestamp_dispatch {
+    ($name:literal, $array:expr, $f:ident, $($args:expr),*) => {
+        match $array.data_type() {
+            DataType::Timestamp(TimeUnit::Second, _) => {
+                $f($array.as_primitive::<TimestampSecondType>(), $($args),*)
+            }
+            DataType::Timestamp(TimeUnit::Millisecond, _) => {
+                $f($array.as_primitive::<TimestampMillisecondType>(), $($args),*)
+            }
+            DataType::Timestamp(TimeUnit::Microsecond, _) => {
+                $f($array.as_primitive::<TimestampMicrosecondType>(), $($args),*)
+            }
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+                $f($array.as_primitive::<TimestampNanosecondType>(), $($args),*)
+            }
+            dt => return_compute_error_with!(concat!($name, " does not support"), dt),
+        }
+    };
+}
+
+/// Replaces the timezone of a timestamp array without modifying its values
+///
+/// As timestamps with a timezone are stored relative to UTC, this preserves the
+/// instants of an array with a timezone, and interprets the values of an array
+/// without a timezone as UTC. See [`assume_timezone`] to instead interpret values
+/// as local times in `tz`.
+///
+/// Returns an error if `array` is not a timestamp array or `tz` is not a valid
+/// timezone.
+pub fn with_timezone(
+    array: &dyn Array,
+    tz: Option<&str>,
+) -> Result<ArrayRef, ArrowError> {
+    if let Some(tz) = tz {
+        tz.parse::<Tz>()?;
+    }
+    fn set<T: ArrowTimestampType>(
+        array: &PrimitiveArray<T>,
+        tz: Option<&str>,
+    ) -> Result<ArrayRef, ArrowError> {
+        Ok(Arc::new(array.clone().with_timezone_opt(tz)))
+    }
+    timestamp_dispatch!("with_timezone", array, set, tz)
+}
+
+/// Converts a timestamp array to the timezone `tz`, preserving the instant of
+/// each value
+///
+/// If `tz` is `None`, the values are converted to the local time in the timezone of
+/// `array`, returning a timestamp array without a timezone.
+///
+/// Returns an error if `array` is not a timestamp array with a timezone, unless `tz`
+/// is also `None`, as the instants of a timestamp without a timezone are not known.
+/// See [`assume_timezone`] for converting a timestamp without a timezone.
+pub fn convert_timezone(
+    array: &dyn Array,
+    tz: Option<&str>,
+) -> Result<ArrayRef, ArrowError> {
+    fn convert<T: ArrowTimestampType>(
+        array: &PrimitiveArray<T>,
+        to_tz: Option<&str>,
+    ) -> Result<ArrayRef, ArrowError> {
+        let from_tz = match array.data_type() {
+            DataType::Timestamp(_, from_tz) => from_tz.as_deref(),
+            _ => unreachable!(),
+        };
+        match (from_tz, to_tz) {
+            (Some(_), Some(to_tz)) => {
+                to_tz.parse::<Tz>()?;
+                Ok(Arc::new(array.clone().with_timezone(to_tz)))
+            }
+            (None, Some(_)) => Err(ArrowError::ComputeError(
+                "convert_timezone requires a timestamp with a timezone, use assume_timezone"
+                    .to_string(),
+            )),
+            (None, None) => Ok(Arc::new(array.clone())),
+            (Some(from_tz), None) => {
+                let from_tz: Tz = from_tz.parse()?;
+                let local = array.try_unary::<_, T, _>(|v| {
+                    as_datetime_with_timezone::<T>(v, from_tz)
+                        .and_then(|dt| T::make_value(dt.naive_local()))
+                        .ok_or_else(|| {
+                            ArrowError::ComputeError(format!(
+                                "Unable to convert {v} to local time in {from_tz:?}"
+                            ))
+                        })
+                })?;
+                Ok(Arc::new(local))
+            }
+        }
+    }
+    timestamp_dispatch!("convert_timezone", array, convert, tz)
+}
+
+/// Interprets the values of a timestamp array without a timezone as local times in
+/// the timezone `tz`, returning a timestamp array with timezone `tz` containing the
+/// corresponding instants
+///
+/// Local times that are ambiguous or do not exist in `tz`, due to daylight saving
+/// time transitions, are handled according to `ambiguous` and `nonexistent`.
+///
+/// Returns an error if `array` is not a timestamp array without a timezone, or `tz`
+/// is not a valid timezone.
+pub fn assume_timezone(
+    array: &dyn Array,
+    tz: &str,
+    ambiguous: AmbiguousTime,
+    nonexistent: NonexistentTime,
+) -> Result<ArrayRef, ArrowError> {
+    if let DataType::Timestamp(_, Some(from_tz)) = array.data_type() {
+        return Err(ArrowError::ComputeError(format!(
+            "assume_timezone requires a timestamp without a timezone, got timezone {from_tz}"
+        )));
+    }
+    let parsed: Tz = tz.parse()?;
+    timestamp_dispatch!(
+        "assume_timezone",
+        array,
+        assume_timezone_impl,
+        tz,
+        parsed,
+        ambiguous,
+        nonexistent
+    )
+}
+
+fn assume_timezone_impl<T: ArrowTimestampType>(
+    array: &PrimitiveArray<T>,
+    tz_name: &str,
+    tz: Tz,
+    ambiguous: AmbiguousTime,
+    nonexistent: NonexistentTime,
+) -> Result<ArrayRef, ArrowError> {
+    let out_of_range =
+        |v: i64| ArrowError::ComputeError(format!("Timestamp {v} is out of range"));
+
+    let mut builder = PrimitiveBuilder::<T>::with_capacity(array.len());
+    for v in array.iter() {
+        let Some(v) = v else {
+            builder.append_null();
+            continue;
+        };
+        let local = as_datetime::<T>(v).ok_or_else(|| out_of_range(v))?;
+        let instant = |offset: &<Tz as TimeZone>::Offset| {
+            T::make_value(local - offset.fix()).ok_or_else(|| out_of_range(v))
+        };
+
+        let value = match tz.offset_from_local_datetime(&local) {
+            LocalResult::Single(offset) => Some(instant(&offset)?),
+            LocalResult::Ambiguous(a, b) => {
+                let (a, b) = (instant(&a)?, instant(&b)?);
+                match ambiguous {
+                    AmbiguousTime::Earliest => Some(a.min(b)),
+                    AmbiguousTime::Latest => Some(a.max(b)),
+                    AmbiguousTime::Null => None,
+                    AmbiguousTime::Raise => {
+                        return Err(ArrowError::ComputeError(format!(
+                            "Local time {local} is ambiguous in timezone {tz_name}"
+                        )))
+                    }
+                }
+            }
+            LocalResult::None => {
+                let gap_end = || {
+                    let secs =
+                        gap_end_seconds(&tz, &local).ok_or_else(|| out_of_range(v))?;
+                    secs.checked_mul(unit_scale(T::UNIT))
+                        .ok_or_else(|| out_of_range(v))
+                };
+                match nonexistent {
+                    NonexistentTime::Earliest => Some(gap_end()? - 1),
+                    NonexistentTime::Latest => Some(gap_end()?),
+                    NonexistentTime::Null => None,
+                    NonexistentTime::Raise => {
+                        return Err(ArrowError::ComputeError(format!(
+                            "Local time {local} does not exist in timezone {tz_name}"
+                        )))
+                    }
+                }
+            }
+        };
+        builder.append_option(value);
+    }
+    Ok(Arc::new(builder.finish().with_timezone(tz_name)))
+}
+
+/// Returns the number of units of `unit` in a second
+fn unit_scale(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => 1_000_000_000,
+    }
+}
+
+/// Returns the first instant, in seconds since the epoch, after the gap in the local
+/// time of `tz` containing the nonexistent local time `local`
+fn gap_end_seconds(tz: &Tz, local: &NaiveDateTime) -> Option<i64> {
+    const SECONDS_IN_DAY: i64 = 86_400;
+
+    let local_secs = local.timestamp();
+    let wall_clock = |secs: i64| {
+        let utc = as_datetime::<TimestampSecondType>(secs)?;
+        let offset = tz.offset_from_utc_datetime(&utc).fix();
+        Some(secs + offset.local_minus_utc() as i64)
+    };
+
+    // Offsets are less than a day, and so the transition lies within this range,
+    // with the local time before the transition <= local_secs < after
+    let (mut lo, mut hi) = (local_secs - SECONDS_IN_DAY, local_secs + SECONDS_IN_DAY);
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        match wall_clock(mid)? > local_secs {
+            true => hi = mid,
+            false => lo = mid,
+        }
+    }
+    Some(hi)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1139,4 +1393,76 @@ mod tests {
         let expected = Arc::new(expected_dict) as ArrayRef;
         assert_eq!(&expected, &b);
     }
+
+    #[test]
+    fn test_with_timezone() {
+        let a = TimestampSecondArray::from(vec![Some(3600), None]);
+        let b = with_timezone(&a, Some("+05:00")).unwrap();
+        let b = b.as_primitive::<TimestampSecondType>();
+        assert_eq!(b.timezone(), Some("+05:00"));
+        assert_eq!(b.values(), a.values());
+        assert!(b.is_null(1));
+
+        let c = with_timezone(b, None).unwrap();
+        assert_eq!(c.as_ref(), &a as &dyn Array);
+
+        let err = with_timezone(&a, Some("foo")).unwrap_err();
+        assert!(err.to_string().contains("Invalid timezone"), "{err}");
+        let err = with_timezone(&Int64Array::from(vec![1]), None).unwrap_err();
+        assert!(
+            err.to_string().contains("with_timezone does not support"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_convert_timezone() {
+        let a = TimestampMillisecondArray::from(vec![Some(3_600_000), None])
+            .with_timezone("+01:00");
+
+        let b = convert_timezone(&a, Some("-02:00")).unwrap();
+        let b = b.as_primitive::<TimestampMillisecondType>();
+        assert_eq!(b.timezone(), Some("-02:00"));
+        assert_eq!(b.values(), a.values());
+
+        // The local time in -02:00 of 01:00 UTC is 23:00 the previous day
+        let local = convert_timezone(b, None).unwrap();
+        let local = local.as_primitive::<TimestampMillisecondType>();
+        assert_eq!(local.timezone(), None);
+        assert_eq!(local.value(0), -3_600_000);
+        assert!(local.is_null(1));
+
+        let err = convert_timezone(local, Some("+01:00")).unwrap_err();
+        assert!(err.to_string().contains("use assume_timezone"), "{err}");
+    }
+
+    #[test]
+    fn test_assume_timezone_fixed_offset() {
+        let a = TimestampMicrosecondArray::from(vec![Some(0), None, Some(1_500_000)]);
+        let b = assume_timezone(
+            &a,
+            "+01:00",
+            AmbiguousTime::default(),
+            NonexistentTime::default(),
+        )
+        .unwrap();
+        let b = b.as_primitive::<TimestampMicrosecondType>();
+        assert_eq!(b.timezone(), Some("+01:00"));
+        assert_eq!(b.value(0), -3_600_000_000);
+        assert!(b.is_null(1));
+        assert_eq!(b.value(2), -3_598_500_000);
+
+        // Converting back to local time is the inverse
+        let local = convert_timezone(b, None).unwrap();
+        assert_eq!(local.as_ref(), &a as &dyn Array);
+
+        let err = assume_timezone(
+            b,
+            "+01:00",
+            AmbiguousTime::default(),
+            NonexistentTime::default(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("without a timezone"), "{err}");
+    }
 }
@@ -0,0 +1,501 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels on vectors, such as embeddings, stored as a [`FixedSizeListArray`] of
+//! `Float32` or `Float64` values
+//!
+//! Binary kernels accept a [`Datum`], and so either compute the result for each pair of
+//! vectors, or of each vector with a single [`Scalar`] vector. Both sides must have the
+//! same element type and vector length.
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{Float32Array, FixedSizeListArray, Scalar};
+//! # use arrow_array::cast::AsArray;
+//! # use arrow_array::types::Float32Type;
+//! # use arrow_arith::vector::l2_distance;
+//! # use arrow_schema::{DataType, Field};
+//! let field = Arc::new(Field::new("item", DataType::Float32, true));
+//! let values = Float32Array::from(vec![0., 0., 3., 4., 1., 1.]);
+//! let vectors = FixedSizeListArray::new(field.clone(), 2, Arc::new(values), None);
+//!
+//! let query = Float32Array::from(vec![0., 0.]);
+//! let query = Scalar::new(FixedSizeListArray::new(field, 2, Arc::new(query), None));
+//!
+//! let distances = l2_distance(&vectors, &query).unwrap();
+//! let distances = distances.as_primitive::<Float32Type>();
+//! assert_eq!(distances.values(), &[0., 5., 2_f32.sqrt()]);
+//! ```
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{BooleanBuffer, NullBuffer, ScalarBuffer};
+use arrow_schema::{ArrowError, DataType};
+use num::{Float, NumCast};
+
+/// Adds two [`Datum`] of vectors elementwise
+///
+/// The result is null where either vector is null, and an element of the result
+/// is null where either element is null
+pub fn vector_add(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    let (l, l_s, r, r_s, len) = binary_vectors("add", lhs, rhs)?;
+    match l.value_type() {
+        DataType::Float32 => add::<Float32Type>(l, l_s, r, r_s, len),
+        DataType::Float64 => add::<Float64Type>(l, l_s, r, r_s, len),
+        _ => unreachable!(),
+    }
+}
+
+/// Multiplies each element of the vectors in `array` by `factor`
+pub fn vector_scale(array: &dyn Array, factor: f64) -> Result<ArrayRef, ArrowError> {
+    let array = as_vectors("scale", array)?;
+    match array.value_type() {
+        DataType::Float32 => scale::<Float32Type>(array, factor),
+        DataType::Float64 => scale::<Float64Type>(array, factor),
+        _ => unreachable!(),
+    }
+}
+
+/// Computes the dot product of two [`Datum`] of vectors
+///
+/// The result has the element type of the vectors, and is null where either vector,
+/// or any of its elements, is null
+pub fn dot_product(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    reduce("dot product", lhs, rhs, Dot)
+}
+
+/// Computes the Euclidean distance between two [`Datum`] of vectors
+///
+/// The result has the element type of the vectors, and is null where either vector,
+/// or any of its elements, is null
+pub fn l2_distance(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    reduce("L2 distance", lhs, rhs, L2)
+}
+
+/// Computes the cosine distance, i.e. one minus the cosine similarity, between two
+/// [`Datum`] of vectors
+///
+/// The result has the element type of the vectors, and is null where either vector,
+/// or any of its elements, is null. The distance from a vector of zeros is NaN
+pub fn cosine_distance(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    reduce("cosine distance", lhs, rhs, Cosine)
+}
+
+/// Returns `array` as a [`FixedSizeListArray`] of floats
+fn as_vectors<'a>(
+    op: &str,
+    array: &'a dyn Array,
+) -> Result<&'a FixedSizeListArray, ArrowError> {
+    match array.data_type() {
+        DataType::FixedSizeList(f, _)
+            if matches!(f.data_type(), DataType::Float32 | DataType::Float64) =>
+        {
+            Ok(array.as_fixed_size_list())
+        }
+        t => Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot compute vector {op} of {t}, expected FixedSizeList of Float32 or Float64"
+        ))),
+    }
+}
+
+/// Returns the vectors of `lhs` and `rhs`, whether each is a scalar, and the
+/// length of the result
+fn binary_vectors<'a>(
+    op: &str,
+    lhs: &'a dyn Datum,
+    rhs: &'a dyn Datum,
+) -> Result<
+    (
+        &'a FixedSizeListArray,
+        bool,
+        &'a FixedSizeListArray,
+        bool,
+        usize,
+    ),
+    ArrowError,
+> {
+    let (l, l_s) = lhs.get();
+    let (r, r_s) = rhs.get();
+    let l = as_vectors(op, l)?;
+    let r = as_vectors(op, r)?;
+
+    if l.value_type() != r.value_type() || l.value_length() != r.value_length() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot compute vector {op} of {} and {}",
+            l.data_type(),
+            r.data_type()
+        )));
+    }
+
+    let len = match (l_s, r_s) {
+        (true, false) => r.len(),
+        (false, true) => l.len(),
+        _ if l.len() == r.len() => l.len(),
+        _ => {
+            return Err(ArrowError::ComputeError(
+                "Cannot perform a binary operation on arrays of different length"
+                    .to_string(),
+            ))
+        }
+    };
+    Ok((l, l_s, r, r_s, len))
+}
+
+/// Returns `nulls` if `scalar` is false, otherwise repeats its first `size` values
+/// `len` times
+fn broadcast_nulls(
+    nulls: Option<&NullBuffer>,
+    scalar: bool,
+    size: usize,
+    len: usize,
+) -> Option<NullBuffer> {
+    let nulls = nulls.filter(|n| n.null_count() > 0)?;
+    match scalar {
+        false => Some(nulls.clone()),
+        true => {
+            let valid =
+                BooleanBuffer::collect_bool(size * len, |i| nulls.is_valid(i % size));
+            Some(NullBuffer::new(valid))
+        }
+    }
+}
+
+/// Returns the elements of the `idx` vector of `values`, or of the first if `scalar`
+#[inline]
+fn vector<N>(values: &[N], scalar: bool, size: usize, idx: usize) -> &[N] {
+    let idx = if scalar { 0 } else { idx };
+    &values[idx * size..(idx + 1) * size]
+}
+
+fn add<T>(
+    l: &FixedSizeListArray,
+    l_s: bool,
+    r: &FixedSizeListArray,
+    r_s: bool,
+    len: usize,
+) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    T::Native: Float,
+{
+    let size = l.value_length() as usize;
+    let l_values = l.values().as_primitive::<T>();
+    let r_values = r.values().as_primitive::<T>();
+
+    let mut values = Vec::with_capacity(len * size);
+    for i in 0..len {
+        let a = vector(l_values.values(), l_s, size, i);
+        let b = vector(r_values.values(), r_s, size, i);
+        values.extend(a.iter().zip(b).map(|(a, b)| *a + *b));
+    }
+
+    let element_nulls = NullBuffer::union(
+        broadcast_nulls(l_values.nulls(), l_s, size, len).as_ref(),
+        broadcast_nulls(r_values.nulls(), r_s, size, len).as_ref(),
+    );
+    let nulls = NullBuffer::union(
+        broadcast_nulls(l.nulls(), l_s, 1, len).as_ref(),
+        broadcast_nulls(r.nulls(), r_s, 1, len).as_ref(),
+    );
+
+    let field = match l.data_type() {
+        DataType::FixedSizeList(f, _) => f.clone(),
+        _ => unreachable!(),
+    };
+    let values = PrimitiveArray::<T>::new(ScalarBuffer::from(values), element_nulls);
+    Ok(Arc::new(FixedSizeListArray::try_new(
+        field,
+        size as i32,
+        Arc::new(values),
+        nulls,
+    )?))
+}
+
+fn scale<T>(array: &FixedSizeListArray, factor: f64) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    T::Native: Float,
+{
+    let factor = <T::Native as NumCast>::from(factor).unwrap();
+    let values = array
+        .values()
+        .as_primitive::<T>()
+        .unary::<_, T>(|x| x * factor);
+    let (field, size, _, nulls) = array.clone().into_parts();
+    Ok(Arc::new(FixedSizeListArray::try_new(
+        field,
+        size,
+        Arc::new(values),
+        nulls,
+    )?))
+}
+
+/// A function reducing a pair of vectors to a single value
+trait Reduce: Copy {
+    fn reduce<N: Float>(self, a: &[N], b: &[N]) -> N;
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Dot;
+
+impl Reduce for Dot {
+    fn reduce<N: Float>(self, a: &[N], b: &[N]) -> N {
+        a.iter().zip(b).fold(N::zero(), |acc, (a, b)| acc + *a * *b)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct L2;
+
+impl Reduce for L2 {
+    fn reduce<N: Float>(self, a: &[N], b: &[N]) -> N {
+        let sum = a.iter().zip(b).fold(N::zero(), |acc, (a, b)| {
+            let d = *a - *b;
+            acc + d * d
+        });
+        sum.sqrt()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Cosine;
+
+impl Reduce for Cosine {
+    fn reduce<N: Float>(self, a: &[N], b: &[N]) -> N {
+        let (dot, a_sq, b_sq) = a.iter().zip(b).fold(
+            (N::zero(), N::zero(), N::zero()),
+            |(dot, a_sq, b_sq), (a, b)| (dot + *a * *b, a_sq + *a * *a, b_sq + *b * *b),
+        );
+        N::one() - dot / (a_sq.sqrt() * b_sq.sqrt())
+    }
+}
+
+fn reduce(
+    op: &str,
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    f: impl Reduce,
+) -> Result<ArrayRef, ArrowError> {
+    let (l, l_s, r, r_s, len) = binary_vectors(op, lhs, rhs)?;
+    Ok(match l.value_type() {
+        DataType::Float32 => Arc::new(reduce_impl::<Float32Type>(l, l_s, r, r_s, len, f)),
+        DataType::Float64 => Arc::new(reduce_impl::<Float64Type>(l, l_s, r, r_s, len, f)),
+        _ => unreachable!(),
+    })
+}
+
+fn reduce_impl<T>(
+    l: &FixedSizeListArray,
+    l_s: bool,
+    r: &FixedSizeListArray,
+    r_s: bool,
+    len: usize,
+    f: impl Reduce,
+) -> PrimitiveArray<T>
+where
+    T: ArrowPrimitiveType,
+    T::Native: Float,
+{
+    let size = l.value_length() as usize;
+    let l_values = l.values().as_primitive::<T>();
+    let r_values = r.values().as_primitive::<T>();
+
+    let values: ScalarBuffer<T::Native> = (0..len)
+        .map(|i| {
+            let a = vector(l_values.values(), l_s, size, i);
+            let b = vector(r_values.values(), r_s, size, i);
+            f.reduce(a, b)
+        })
+        .collect();
+
+    // A vector containing a null element yields a null result
+    let element_nulls = NullBuffer::union(
+        broadcast_nulls(l_values.nulls(), l_s, size, len).as_ref(),
+        broadcast_nulls(r_values.nulls(), r_s, size, len).as_ref(),
+    )
+    .map(|n| {
+        let valid = BooleanBuffer::collect_bool(len, |i| {
+            (i * size..(i + 1) * size).all(|j| n.is_valid(j))
+        });
+        NullBuffer::new(valid)
+    });
+    let nulls = NullBuffer::union(
+        broadcast_nulls(l.nulls(), l_s, 1, len).as_ref(),
+        broadcast_nulls(r.nulls(), r_s, 1, len).as_ref(),
+    );
+    let nulls = NullBuffer::union(nulls.as_ref(), element_nulls.as_ref());
+    PrimitiveArray::new(values, nulls)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_schema::Field;
+
+    fn vectors<T: ArrowPrimitiveType>(
+        size: i32,
+        values: Vec<Option<T::Native>>,
+        nulls: Option<Vec<bool>>,
+    ) -> FixedSizeListArray {
+        let field = Arc::new(Field::new("item", T::DATA_TYPE, true));
+        let values: PrimitiveArray<T> = values.into_iter().collect();
+        FixedSizeListArray::new(field, size, Arc::new(values), nulls.map(Into::into))
+    }
+
+    #[test]
+    fn test_vector_add_scale() {
+        let a = vectors::<Float32Type>(
+            2,
+            vec![Some(1.), Some(2.), Some(3.), None, Some(5.), Some(6.)],
+            Some(vec![true, true, false]),
+        );
+        let b = vectors::<Float32Type>(
+            2,
+            vec![
+                Some(10.),
+                Some(20.),
+                Some(30.),
+                Some(40.),
+                Some(50.),
+                Some(60.),
+            ],
+            None,
+        );
+        let r = vector_add(&a, &b).unwrap();
+        let r = r.as_fixed_size_list();
+        assert_eq!(r.value_length(), 2);
+        assert_eq!(r.null_count(), 1);
+        let v = r.values().as_primitive::<Float32Type>();
+        assert_eq!(&v.values()[..3], &[11., 22., 33.]);
+        assert!(v.is_null(3));
+        assert!(r.is_null(2));
+
+        // Scalar vectors are added to each vector
+        let s = Scalar::new(b.slice(1, 1));
+        let r = vector_add(&s, &a).unwrap();
+        let r = r.as_fixed_size_list();
+        assert_eq!(r.len(), 3);
+        let v = r.values().as_primitive::<Float32Type>();
+        assert_eq!(v.values()[0..2], [31., 42.]);
+        assert!(v.is_null(3));
+        assert!(r.is_null(2));
+
+        let r = vector_scale(&a, 0.5).unwrap();
+        let r = r.as_fixed_size_list();
+        assert_eq!(r.nulls(), a.nulls());
+        let v = r.values().as_primitive::<Float32Type>();
+        assert_eq!(&v.values()[..3], &[0.5, 1., 1.5]);
+        assert!(v.is_null(3));
+    }
+
+    #[test]
+    fn test_vector_distance() {
+        let a = vectors::<Float64Type>(
+            2,
+            vec![
+                Some(1.),
+                Some(0.),
+                Some(3.),
+                Some(4.),
+                Some(0.),
+                Some(0.),
+                Some(1.),
+                None,
+            ],
+            Some(vec![true, true, true, true]),
+        );
+        let b = vectors::<Float64Type>(
+            2,
+            vec![
+                Some(0.),
+                Some(1.),
+                Some(3.),
+                Some(4.),
+                Some(1.),
+                Some(1.),
+                Some(1.),
+                Some(1.),
+            ],
+            None,
+        );
+
+        let r = dot_product(&a, &b).unwrap();
+        let r = r.as_primitive::<Float64Type>();
+        assert_eq!(&r.values()[..3], &[0., 25., 0.]);
+        assert!(r.is_null(3));
+
+        let r = l2_distance(&a, &b).unwrap();
+        let r = r.as_primitive::<Float64Type>();
+        assert_eq!(&r.values()[..3], &[2_f64.sqrt(), 0., 2_f64.sqrt()]);
+        assert!(r.is_null(3));
+
+        let r = cosine_distance(&a, &b).unwrap();
+        let r = r.as_primitive::<Float64Type>();
+        assert_eq!(r.value(0), 1.);
+        assert!(r.value(1).abs() < 1e-12);
+        assert!(r.value(2).is_nan());
+        assert!(r.is_null(3));
+
+        // Distances from a scalar vector
+        let s = Scalar::new(b.slice(1, 1));
+        let r = dot_product(&a, &s).unwrap();
+        let r = r.as_primitive::<Float64Type>();
+        assert_eq!(&r.values()[..3], &[3., 25., 0.]);
+        assert_eq!(r.null_count(), 1);
+
+        let null = Scalar::new(FixedSizeListArray::new_null(
+            Arc::new(Field::new("item", DataType::Float64, true)),
+            2,
+            1,
+        ));
+        let r = l2_distance(&null, &a).unwrap();
+        assert_eq!(r.len(), 4);
+        assert_eq!(r.null_count(), 4);
+    }
+
+    #[test]
+    fn test_vector_errors() {
+        let a = vectors::<Float32Type>(2, vec![Some(1.), Some(2.)], None);
+        let b = vectors::<Float32Type>(1, vec![Some(1.), Some(2.)], None);
+        let err = dot_product(&a, &b).unwrap_err().to_string();
+        assert!(
+            err.contains("Cannot compute vector dot product of FixedSizeList"),
+            "{err}"
+        );
+
+        let err = l2_distance(&b, &a.slice(0, 0)).unwrap_err().to_string();
+        assert!(err.contains("Cannot compute vector L2 distance"), "{err}");
+
+        let b =
+            vectors::<Float32Type>(2, vec![Some(1.), Some(2.), Some(3.), Some(4.)], None);
+        let err = vector_add(&a, &b).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Compute error: Cannot perform a binary operation on arrays of different length"
+        );
+
+        let ints = vectors::<Int32Type>(1, vec![Some(1)], None);
+        let err = vector_scale(&ints, 2.).unwrap_err().to_string();
+        assert!(
+            err.contains("expected FixedSizeList of Float32 or Float64"),
+            "{err}"
+        );
+    }
+}
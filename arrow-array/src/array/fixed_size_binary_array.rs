@@ -17,7 +17,7 @@
 
 use crate::array::print_long_array;
 use crate::iterator::FixedSizeBinaryIter;
-use crate::{Array, ArrayAccessor, ArrayRef, FixedSizeListArray};
+use crate::{Array, ArrayAccessor, ArrayRef, FixedSizeListArray, Scalar};
 use arrow_buffer::buffer::NullBuffer;
 use arrow_buffer::{bit_util, ArrowNativeType, BooleanBuffer, Buffer, MutableBuffer};
 use arrow_data::{ArrayData, ArrayDataBuilder};
@@ -107,6 +107,23 @@ impl FixedSizeBinaryArray {
         })
     }
 
+    /// Create a new [`Scalar`] from `value`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `value` exceeds `i32::MAX`
+    pub fn new_scalar(value: impl AsRef<[u8]>) -> Scalar<Self> {
+        let v = value.as_ref();
+        let size = i32::try_from(v.len()).expect("value length overflows i32");
+        Scalar::new(Self {
+            data_type: DataType::FixedSizeBinary(size),
+            value_data: Buffer::from_slice_ref(v),
+            nulls: None,
+            value_length: size,
+            len: 1,
+        })
+    }
+
     /// Create a new [`FixedSizeBinaryArray`] of length `len` where all values are null
     ///
     /// # Panics
@@ -183,6 +183,7 @@ impl FixedSizeListArray {
 
         if let Some(a) = values.nulls() {
             let nulls_valid = field.is_nullable()
+                || a.null_count() == 0
                 || nulls
                     .as_ref()
                     .map(|n| n.expand(size as _).contains(a))
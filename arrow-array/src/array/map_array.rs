@@ -232,6 +232,112 @@ impl MapArray {
     pub fn iter(&self) -> MapArrayIter<'_> {
         MapArrayIter::new(self)
     }
+
+    /// Downcast the keys and values of this map, returning a [`TypedMapArray`]
+    ///
+    /// Returns `None` if the keys are not of type `K`, or the values not of type `V`
+    ///
+    /// ```
+    /// # use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+    /// # use arrow_array::{Int32Array, StringArray};
+    /// let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+    /// builder.keys().append_value("a");
+    /// builder.values().append_value(1);
+    /// builder.keys().append_value("b");
+    /// builder.values().append_null();
+    /// builder.append(true).unwrap();
+    /// let map = builder.finish();
+    ///
+    /// let typed = map.downcast_map::<StringArray, Int32Array>().unwrap();
+    /// assert_eq!(typed.keys().value(1), "b");
+    ///
+    /// let entries: Vec<_> = typed.entries(0).collect();
+    /// assert_eq!(entries, vec![("a", Some(1)), ("b", None)]);
+    /// ```
+    pub fn downcast_map<K: 'static, V: 'static>(
+        &self,
+    ) -> Option<TypedMapArray<'_, K, V>> {
+        Some(TypedMapArray {
+            map: self,
+            keys: self.keys().as_any().downcast_ref()?,
+            values: self.values().as_any().downcast_ref()?,
+        })
+    }
+}
+
+/// A [`MapArray`] with its keys and values downcast to `K` and `V`
+///
+/// See [`MapArray::downcast_map`]
+pub struct TypedMapArray<'a, K, V> {
+    /// The map array
+    map: &'a MapArray,
+    /// The keys of the map
+    keys: &'a K,
+    /// The values of the map
+    values: &'a V,
+}
+
+// Manually implement `Clone` to avoid `K: Clone` and `V: Clone` type constraints
+impl<'a, K, V> Clone for TypedMapArray<'a, K, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, K, V> Copy for TypedMapArray<'a, K, V> {}
+
+impl<'a, K, V> std::fmt::Debug for TypedMapArray<'a, K, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "TypedMapArray({:?})", self.map)
+    }
+}
+
+impl<'a, K, V> TypedMapArray<'a, K, V> {
+    /// Returns the underlying [`MapArray`]
+    pub fn map(&self) -> &'a MapArray {
+        self.map
+    }
+
+    /// Returns the keys of this [`TypedMapArray`]
+    pub fn keys(&self) -> &'a K {
+        self.keys
+    }
+
+    /// Returns the values of this [`TypedMapArray`]
+    pub fn values(&self) -> &'a V {
+        self.values
+    }
+}
+
+impl<'a, K, V> TypedMapArray<'a, K, V>
+where
+    &'a K: ArrayAccessor,
+    &'a V: ArrayAccessor,
+{
+    /// Returns an iterator over the key-value pairs of the map at index `i`
+    ///
+    /// Values that are null are returned as `None`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= self.map().len()`
+    pub fn entries(
+        &self,
+        i: usize,
+    ) -> impl Iterator<
+        Item = (
+            <&'a K as ArrayAccessor>::Item,
+            Option<<&'a V as ArrayAccessor>::Item>,
+        ),
+    > + 'a {
+        let offsets = self.map.value_offsets();
+        let (start, end) = (offsets[i].as_usize(), offsets[i + 1].as_usize());
+        let (keys, values) = (self.keys, self.values);
+        (start..end).map(move |idx| {
+            let value = values.is_valid(idx).then(|| values.value(idx));
+            (keys.value(idx), value)
+        })
+    }
 }
 
 impl From<ArrayData> for MapArray {
@@ -809,4 +915,25 @@ mod tests {
             "Invalid argument error: MapArray entries must contain two children, got 3"
         );
     }
+
+    #[test]
+    fn test_downcast_map() {
+        let keys = vec!["a", "b", "c", "d"];
+        let values = UInt32Array::from(vec![Some(0), None, Some(2), Some(3)]);
+        let map =
+            MapArray::new_from_strings(keys.into_iter(), &values, &[0, 1, 1, 4]).unwrap();
+
+        assert!(map.downcast_map::<StringArray, Int32Array>().is_none());
+        let typed = map.downcast_map::<StringArray, UInt32Array>().unwrap();
+        assert_eq!(typed.values(), &values);
+        assert_eq!(typed.entries(1).count(), 0);
+        assert_eq!(
+            typed.entries(2).collect::<Vec<_>>(),
+            vec![("b", None), ("c", Some(2)), ("d", Some(3))]
+        );
+
+        let sliced = map.slice(2, 1);
+        let typed = sliced.downcast_map::<StringArray, UInt32Array>().unwrap();
+        assert_eq!(typed.entries(0).count(), 3);
+    }
 }
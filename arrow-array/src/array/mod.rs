@@ -20,9 +20,10 @@
 mod binary_array;
 
 use crate::types::*;
+use arrow_buffer::pool::MemoryPool;
 use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer, ScalarBuffer};
 use arrow_data::ArrayData;
-use arrow_schema::{DataType, IntervalUnit, TimeUnit};
+use arrow_schema::{ArrowError, DataType, IntervalUnit, TimeUnit};
 use std::any::Any;
 use std::sync::Arc;
 
@@ -46,6 +47,9 @@ pub use fixed_size_list_array::*;
 mod list_array;
 pub use list_array::*;
 
+mod list_view_array;
+pub use list_view_array::*;
+
 mod map_array;
 pub use map_array::*;
 
@@ -234,6 +238,31 @@ pub trait Array: std::fmt::Debug + Send + Sync {
     /// This value will always be greater than returned by `get_buffer_memory_size()` and
     /// includes the overhead of the data structures that contain the pointers to the various buffers.
     fn get_array_memory_size(&self) -> usize;
+
+    /// Returns the number of bytes of memory needed to store only the data of this
+    /// slice of the array, in contrast to [`Self::get_buffer_memory_size`] which
+    /// includes any data outside of the slice retained by its buffers
+    ///
+    /// See [`ArrayData::get_slice_memory_size`] for more information
+    ///
+    /// ```
+    /// # use arrow_array::{Array, Int64Array};
+    /// let array = Int64Array::from_iter_values(0..100);
+    /// let slice = array.slice(0, 10);
+    ///
+    /// assert_eq!(slice.get_buffer_memory_size(), array.get_buffer_memory_size());
+    /// assert_eq!(slice.get_slice_memory_size().unwrap(), 10 * 8);
+    /// ```
+    fn get_slice_memory_size(&self) -> Result<usize, ArrowError> {
+        self.to_data().get_slice_memory_size()
+    }
+
+    /// Claim the memory used by the buffers of this array against `pool`
+    ///
+    /// See [`ArrayData::claim`] and [`arrow_buffer::pool`] for more information
+    fn claim(&self, pool: &dyn MemoryPool) -> Result<(), ArrowError> {
+        self.to_data().claim(pool)
+    }
 }
 
 /// A reference-counted reference to a generic `Array`
@@ -296,6 +325,14 @@ impl Array for ArrayRef {
     fn get_array_memory_size(&self) -> usize {
         self.as_ref().get_array_memory_size()
     }
+
+    fn get_slice_memory_size(&self) -> Result<usize, ArrowError> {
+        self.as_ref().get_slice_memory_size()
+    }
+
+    fn claim(&self, pool: &dyn MemoryPool) -> Result<(), ArrowError> {
+        self.as_ref().claim(pool)
+    }
 }
 
 impl<'a, T: Array> Array for &'a T {
@@ -354,6 +391,14 @@ impl<'a, T: Array> Array for &'a T {
     fn get_array_memory_size(&self) -> usize {
         T::get_array_memory_size(self)
     }
+
+    fn get_slice_memory_size(&self) -> Result<usize, ArrowError> {
+        T::get_slice_memory_size(self)
+    }
+
+    fn claim(&self, pool: &dyn MemoryPool) -> Result<(), ArrowError> {
+        T::claim(self, pool)
+    }
 }
 
 /// A generic trait for accessing the values of an [`Array`]
@@ -440,6 +485,12 @@ impl<OffsetSize: OffsetSizeTrait> PartialEq for GenericListArray<OffsetSize> {
     }
 }
 
+impl<OffsetSize: OffsetSizeTrait> PartialEq for GenericListViewArray<OffsetSize> {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_data().eq(&other.to_data())
+    }
+}
+
 impl PartialEq for MapArray {
     fn eq(&self, other: &Self) -> bool {
         self.to_data().eq(&other.to_data())
@@ -530,6 +581,10 @@ pub fn make_array(data: ArrayData) -> ArrayRef {
         DataType::LargeUtf8 => Arc::new(LargeStringArray::from(data)) as ArrayRef,
         DataType::List(_) => Arc::new(ListArray::from(data)) as ArrayRef,
         DataType::LargeList(_) => Arc::new(LargeListArray::from(data)) as ArrayRef,
+        DataType::ListView(_) => Arc::new(ListViewArray::from(data)) as ArrayRef,
+        DataType::LargeListView(_) => {
+            Arc::new(LargeListViewArray::from(data)) as ArrayRef
+        }
         DataType::Struct(_) => Arc::new(StructArray::from(data)) as ArrayRef,
         DataType::Map(_, _) => Arc::new(MapArray::from(data)) as ArrayRef,
         DataType::Union(_, _) => Arc::new(UnionArray::from(data)) as ArrayRef,
@@ -578,6 +633,8 @@ pub fn make_array(data: ArrayData) -> ArrayRef {
             }
         }
         DataType::Null => Arc::new(NullArray::from(data)) as ArrayRef,
+        DataType::Decimal32(_, _) => Arc::new(Decimal32Array::from(data)) as ArrayRef,
+        DataType::Decimal64(_, _) => Arc::new(Decimal64Array::from(data)) as ArrayRef,
         DataType::Decimal128(_, _) => Arc::new(Decimal128Array::from(data)) as ArrayRef,
         DataType::Decimal256(_, _) => Arc::new(Decimal256Array::from(data)) as ArrayRef,
         dt => panic!("Unexpected data type {dt:?}"),
@@ -995,6 +1052,26 @@ mod tests {
         !arr.is_empty()
     }
 
+    #[test]
+    fn test_claim_dictionary() {
+        let pool = arrow_buffer::pool::TrackingMemoryPool::new();
+        let dict: DictionaryArray<Int32Type> =
+            vec!["a", "b", "a", "c"].into_iter().collect();
+        let array: ArrayRef = Arc::new(dict.clone());
+        array.claim(&pool).unwrap();
+        assert_eq!(pool.used(), dict.get_buffer_memory_size());
+
+        // Claims are shared with clones of the array
+        let used = pool.used();
+        dict.claim(&pool).unwrap();
+        assert_eq!(pool.used(), used);
+
+        drop(array);
+        assert_eq!(pool.used(), used);
+        drop(dict);
+        assert_eq!(pool.used(), 0);
+    }
+
     #[test]
     fn test_array_ref_as_array() {
         let arr: Int32Array = vec![1, 2, 3].into_iter().map(Some).collect();
@@ -145,6 +145,7 @@ impl StructArray {
 
             if let Some(a) = a.nulls() {
                 let nulls_valid = f.is_nullable()
+                    || a.null_count() == 0
                     || nulls.as_ref().map(|n| n.contains(a)).unwrap_or_default();
 
                 if !nulls_valid {
@@ -726,4 +727,16 @@ mod tests {
             Arc::new(Int32Array::from(vec![Some(42), None, Some(19)])) as ArrayRef,
         )]));
     }
+
+    #[test]
+    fn test_struct_array_non_nullable_without_nulls() {
+        // A null buffer containing no nulls is permitted for a non-nullable field
+        let values =
+            Int32Array::new(vec![1, 2, 3].into(), Some(NullBuffer::new_valid(3)));
+        let fields = Fields::from(vec![Field::new("c", DataType::Int32, false)]);
+        let array =
+            StructArray::try_new(fields, vec![Arc::new(values) as ArrayRef], None)
+                .unwrap();
+        assert_eq!(array.len(), 3);
+    }
 }
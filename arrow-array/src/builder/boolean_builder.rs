@@ -16,7 +16,7 @@
 // under the License.
 
 use crate::builder::{ArrayBuilder, BooleanBufferBuilder};
-use crate::{ArrayRef, BooleanArray};
+use crate::{Array, ArrayRef, BooleanArray};
 use arrow_buffer::Buffer;
 use arrow_buffer::NullBufferBuilder;
 use arrow_data::ArrayData;
@@ -143,6 +143,17 @@ impl BooleanBuilder {
         }
     }
 
+    /// Appends the values and validity of `array` into the builder
+    ///
+    /// To append a range of `array` first [`slice`](BooleanArray::slice) it
+    pub fn append_array(&mut self, array: &BooleanArray) {
+        match array.nulls() {
+            Some(nulls) => self.null_buffer_builder.append_buffer(nulls),
+            None => self.null_buffer_builder.append_n_non_nulls(array.len()),
+        }
+        self.values_builder.append_buffer(array.values());
+    }
+
     /// Builds the [BooleanArray] and reset this builder.
     pub fn finish(&mut self) -> BooleanArray {
         let len = self.len();
@@ -220,7 +231,6 @@ impl Extend<Option<bool>> for BooleanBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Array;
     use arrow_buffer::Buffer;
 
     #[test]
@@ -323,4 +333,27 @@ mod tests {
             &[false, false, true, false, false, true, true, false]
         )
     }
+
+    #[test]
+    fn test_append_array() {
+        let a = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        let b = BooleanArray::from(vec![false, true, true]);
+
+        let mut builder = BooleanBuilder::new();
+        builder.append_value(false);
+        builder.append_array(&a.slice(1, 3));
+        builder.append_array(&b);
+        let array = builder.finish();
+
+        let expected = BooleanArray::from(vec![
+            Some(false),
+            None,
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(true),
+            Some(true),
+        ]);
+        assert_eq!(array, expected);
+    }
 }
@@ -16,7 +16,7 @@
 // under the License.
 
 use crate::builder::{ArrayBuilder, UInt8BufferBuilder};
-use crate::{ArrayRef, FixedSizeBinaryArray};
+use crate::{Array, ArrayRef, FixedSizeBinaryArray};
 use arrow_buffer::Buffer;
 use arrow_buffer::NullBufferBuilder;
 use arrow_data::ArrayData;
@@ -92,6 +92,32 @@ impl FixedSizeBinaryBuilder {
         self.null_buffer_builder.append_null();
     }
 
+    /// Appends the values and validity of `array` into the builder
+    ///
+    /// Returns an error if `array` does not have the same value length as this builder.
+    /// To append a range of `array` first [`slice`](FixedSizeBinaryArray::slice) it
+    pub fn append_array(
+        &mut self,
+        array: &FixedSizeBinaryArray,
+    ) -> Result<(), ArrowError> {
+        if self.value_length != array.value_length() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "FixedSizeBinaryArray with value length {} does not match FixedSizeBinaryBuilder value length {}",
+                array.value_length(),
+                self.value_length
+            )));
+        }
+
+        let data_len = array.len() * self.value_length as usize;
+        self.values_builder
+            .append_slice(&array.value_data()[..data_len]);
+        match array.nulls() {
+            Some(nulls) => self.null_buffer_builder.append_buffer(nulls),
+            None => self.null_buffer_builder.append_n_non_nulls(array.len()),
+        }
+        Ok(())
+    }
+
     /// Builds the [`FixedSizeBinaryArray`] and reset this builder.
     pub fn finish(&mut self) -> FixedSizeBinaryArray {
         let array_length = self.len();
@@ -154,7 +180,6 @@ impl ArrayBuilder for FixedSizeBinaryBuilder {
 mod tests {
     use super::*;
 
-    use crate::Array;
     use crate::FixedSizeBinaryArray;
     use arrow_schema::DataType;
 
@@ -232,6 +257,33 @@ mod tests {
         let mut builder = FixedSizeBinaryBuilder::with_capacity(1, 4);
         builder.append_value(b"hello").unwrap();
     }
+
+    #[test]
+    fn test_fixed_size_binary_builder_append_array() {
+        let a = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            vec![Some(b"ab"), None, Some(b"cd"), Some(b"ef")].into_iter(),
+            2,
+        )
+        .unwrap();
+
+        let mut builder = FixedSizeBinaryBuilder::new(2);
+        builder.append_value(b"xy").unwrap();
+        builder.append_array(&a.slice(1, 3)).unwrap();
+        let array = builder.finish();
+
+        assert_eq!(array.len(), 4);
+        assert_eq!(array.null_count(), 1);
+        assert!(array.is_null(1));
+        assert_eq!(array.value(0), b"xy");
+        assert_eq!(array.value(2), b"cd");
+        assert_eq!(array.value(3), b"ef");
+
+        let other =
+            FixedSizeBinaryArray::try_from_iter(vec![b"abc"].into_iter()).unwrap();
+        let err = builder.append_array(&other).unwrap_err();
+        assert!(err.to_string().contains("does not match"));
+    }
+
     #[test]
     fn test_fixed_size_binary_builder_empty() {
         let mut builder = FixedSizeBinaryBuilder::new(5);
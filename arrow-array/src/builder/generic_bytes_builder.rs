@@ -17,7 +17,7 @@
 
 use crate::builder::{ArrayBuilder, BufferBuilder, UInt8BufferBuilder};
 use crate::types::{ByteArrayType, GenericBinaryType, GenericStringType};
-use crate::{ArrayRef, GenericByteArray, OffsetSizeTrait};
+use crate::{Array, ArrayRef, GenericByteArray, OffsetSizeTrait};
 use arrow_buffer::NullBufferBuilder;
 use arrow_buffer::{ArrowNativeType, Buffer, MutableBuffer};
 use arrow_data::ArrayDataBuilder;
@@ -116,6 +116,40 @@ impl<T: ByteArrayType> GenericByteBuilder<T> {
         self.offsets_builder.append(self.next_offset());
     }
 
+    /// Appends the values and validity of `array` into the builder
+    ///
+    /// The value bytes are copied in a single operation, with the offsets of `array`
+    /// rebased onto this builder. To append a range of `array` first
+    /// [`slice`](GenericByteArray::slice) it
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting length of [`Self::values_slice`] would exceed `T::Offset::MAX`
+    pub fn append_array(&mut self, array: &GenericByteArray<T>) {
+        if array.is_empty() {
+            return;
+        }
+
+        let offsets = array.value_offsets();
+        let start = offsets[0].as_usize();
+        let end = offsets[array.len()].as_usize();
+        let base = self.value_builder.len();
+        T::Offset::from_usize(base + end - start).expect("byte array offset overflow");
+
+        let iter = offsets[1..]
+            .iter()
+            .map(|o| T::Offset::usize_as(o.as_usize() - start + base));
+        // Safety: iterator over a slice is trusted length
+        unsafe { self.offsets_builder.append_trusted_len_iter(iter) };
+        self.value_builder
+            .append_slice(&array.value_data()[start..end]);
+
+        match array.nulls() {
+            Some(nulls) => self.null_buffer_builder.append_buffer(nulls),
+            None => self.null_buffer_builder.append_n_non_nulls(array.len()),
+        }
+    }
+
     /// Builds the [`GenericByteArray`] and reset this builder.
     pub fn finish(&mut self) -> GenericByteArray<T> {
         let array_type = T::DATA_TYPE;
@@ -268,7 +302,7 @@ pub type GenericBinaryBuilder<O> = GenericByteBuilder<GenericBinaryType<O>>;
 mod tests {
     use super::*;
     use crate::array::{Array, OffsetSizeTrait};
-    use crate::GenericStringArray;
+    use crate::{GenericStringArray, StringArray};
 
     fn _test_generic_binary_builder<O: OffsetSizeTrait>() {
         let mut builder = GenericBinaryBuilder::<O>::new();
@@ -484,4 +518,30 @@ mod tests {
         let r: Vec<_> = a.iter().map(|x| x.unwrap()).collect();
         assert_eq!(r, &["foo", "bar\n", "fizbuz"])
     }
+
+    #[test]
+    fn test_append_array() {
+        let a = StringArray::from(vec![Some("hello"), None, Some("arrow"), Some("")]);
+        let b = StringArray::from(vec!["rust", "builder"]);
+
+        let mut builder = GenericStringBuilder::<i32>::new();
+        builder.append_value("a");
+        builder.append_array(&a.slice(1, 3));
+        builder.append_array(&b);
+        builder.append_array(&b.slice(2, 0));
+        builder.append_null();
+        let array = builder.finish();
+
+        let expected = StringArray::from(vec![
+            Some("a"),
+            None,
+            Some("arrow"),
+            Some(""),
+            Some("rust"),
+            Some("builder"),
+            None,
+        ]);
+        assert_eq!(array, expected);
+        assert_eq!(array.value_data(), b"aarrowrustbuilder");
+    }
 }
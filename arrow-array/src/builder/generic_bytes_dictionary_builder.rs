@@ -209,12 +209,9 @@ where
     K: ArrowDictionaryKeyType,
     T: ByteArrayType,
 {
-    /// Append a value to the array. Return an existing index
-    /// if already present in the values array or a new index if the
-    /// value is appended to the values array.
-    ///
-    /// Returns an error if the new index would overflow the key type.
-    pub fn append(
+    /// Returns the key of `value` in the values array, appending it to the
+    /// values array if not already present
+    fn get_or_insert_key(
         &mut self,
         value: impl AsRef<T::Native>,
     ) -> Result<K::Native, ArrowError> {
@@ -244,11 +241,59 @@ where
                     .ok_or(ArrowError::DictionaryKeyOverflowError)?
             }
         };
-        self.keys_builder.append_value(key);
 
         Ok(key)
     }
 
+    /// Append a value to the array. Return an existing index
+    /// if already present in the values array or a new index if the
+    /// value is appended to the values array.
+    ///
+    /// Returns an error if the new index would overflow the key type.
+    pub fn append(
+        &mut self,
+        value: impl AsRef<T::Native>,
+    ) -> Result<K::Native, ArrowError> {
+        let key = self.get_or_insert_key(value)?;
+        self.keys_builder.append_value(key);
+        Ok(key)
+    }
+
+    /// Appends the logical values of the [`DictionaryArray`] `array` into the builder
+    ///
+    /// Each value of the dictionary of `array` is interned once, after which the keys
+    /// of `array` are remapped onto this builder's dictionary. To append a range of
+    /// `array` first [`slice`](DictionaryArray::slice) it.
+    ///
+    /// Returns an error if the values of `array` are not of type `T`, or if the new
+    /// indices would overflow the key type.
+    pub fn append_array(&mut self, array: &DictionaryArray<K>) -> Result<(), ArrowError> {
+        let values = array
+            .values()
+            .as_any()
+            .downcast_ref::<GenericByteArray<T>>()
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Expected dictionary values of type {}, got {}",
+                    T::DATA_TYPE,
+                    array.values().data_type()
+                ))
+            })?;
+
+        let mapping = values
+            .iter()
+            .map(|v| v.map(|v| self.get_or_insert_key(v)).transpose())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for key in array.keys() {
+            match key.and_then(|k| mapping[k.as_usize()]) {
+                Some(key) => self.keys_builder.append_value(key),
+                None => self.keys_builder.append_null(),
+            }
+        }
+        Ok(())
+    }
+
     /// Infallibly append a value to this builder
     ///
     /// # Panics
@@ -648,4 +693,42 @@ mod tests {
         assert_eq!(dict.keys().values(), &[0, 1, 2, 0, 1, 2, 2, 3, 0]);
         assert_eq!(dict.values().len(), 4);
     }
+
+    #[test]
+    fn test_append_array() {
+        let values = StringArray::from(vec![Some("b"), None, Some("c"), Some("a")]);
+        let keys =
+            Int8Array::from(vec![Some(0), Some(2), None, Some(1), Some(3), Some(0)]);
+        let a = DictionaryArray::new(keys, Arc::new(values));
+
+        let mut builder = GenericByteDictionaryBuilder::<Int8Type, Utf8Type>::new();
+        builder.append_value("a");
+        builder.append_array(&a.slice(1, 5)).unwrap();
+        let dict = builder.finish();
+
+        let values = dict
+            .values()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        let actual: Vec<_> = dict
+            .keys()
+            .iter()
+            .map(|k| k.map(|k| values.value(k as usize)))
+            .collect();
+        assert_eq!(
+            actual,
+            vec![Some("a"), Some("c"), None, None, Some("a"), Some("b")]
+        );
+        assert_eq!(dict.values().len(), 3);
+
+        let binary = DictionaryArray::new(
+            Int8Array::from(vec![0]),
+            Arc::new(BinaryArray::from_iter_values([b"a"])),
+        );
+        let err = builder.append_array(&binary).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Expected dictionary values of type Utf8"));
+    }
 }
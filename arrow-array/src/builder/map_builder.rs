@@ -20,7 +20,7 @@ use crate::{Array, ArrayRef, MapArray, StructArray};
 use arrow_buffer::Buffer;
 use arrow_buffer::{NullBuffer, NullBufferBuilder};
 use arrow_data::ArrayData;
-use arrow_schema::{ArrowError, DataType, Field};
+use arrow_schema::{ArrowError, DataType, Field, FieldRef};
 use std::any::Any;
 use std::sync::Arc;
 
@@ -61,6 +61,8 @@ pub struct MapBuilder<K: ArrayBuilder, V: ArrayBuilder> {
     field_names: MapFieldNames,
     key_builder: K,
     value_builder: V,
+    key_field: Option<FieldRef>,
+    value_field: Option<FieldRef>,
 }
 
 /// The [`Field`] names for a [`MapArray`]
@@ -110,6 +112,38 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
             field_names: field_names.unwrap_or_default(),
             key_builder,
             value_builder,
+            key_field: None,
+            value_field: None,
+        }
+    }
+
+    /// Override the field used for the keys of the map
+    ///
+    /// By default a non-nullable field named [`MapFieldNames::key`] is created with
+    /// the data type of the finished keys. This allows, for example, preserving the
+    /// metadata of the field.
+    ///
+    /// Note: [`Self::finish`] and [`Self::finish_cloned`] will panic if the field's
+    /// data type does not match that of `K`, or if the field is nullable
+    pub fn with_keys_field(self, field: impl Into<FieldRef>) -> Self {
+        Self {
+            key_field: Some(field.into()),
+            ..self
+        }
+    }
+
+    /// Override the field used for the values of the map
+    ///
+    /// By default a nullable field named [`MapFieldNames::value`] is created with
+    /// the data type of the finished values. This allows, for example, declaring the
+    /// values non-nullable, or preserving the metadata of the field.
+    ///
+    /// Note: [`Self::finish`] and [`Self::finish_cloned`] will panic if the field's
+    /// data type does not match that of `V`
+    pub fn with_values_field(self, field: impl Into<FieldRef>) -> Self {
+        Self {
+            value_field: Some(field.into()),
+            ..self
         }
     }
 
@@ -178,16 +212,25 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
             keys_arr.null_count()
         );
 
-        let keys_field = Arc::new(Field::new(
-            self.field_names.key.as_str(),
-            keys_arr.data_type().clone(),
-            false, // always non-nullable
-        ));
-        let values_field = Arc::new(Field::new(
-            self.field_names.value.as_str(),
-            values_arr.data_type().clone(),
-            true,
-        ));
+        let keys_field = match &self.key_field {
+            Some(f) => {
+                assert!(!f.is_nullable(), "Keys field must not be nullable");
+                f.clone()
+            }
+            None => Arc::new(Field::new(
+                self.field_names.key.as_str(),
+                keys_arr.data_type().clone(),
+                false, // always non-nullable
+            )),
+        };
+        let values_field = match &self.value_field {
+            Some(f) => f.clone(),
+            None => Arc::new(Field::new(
+                self.field_names.value.as_str(),
+                values_arr.data_type().clone(),
+                true,
+            )),
+        };
 
         let struct_array =
             StructArray::from(vec![(keys_field, keys_arr), (values_field, values_arr)]);
@@ -238,7 +281,10 @@ impl<K: ArrayBuilder, V: ArrayBuilder> ArrayBuilder for MapBuilder<K, V> {
 
 #[cfg(test)]
 mod tests {
-    use crate::builder::{Int32Builder, StringBuilder};
+    use crate::builder::{Int32Builder, ListBuilder, StringBuilder};
+    use crate::cast::AsArray;
+    use crate::types::Int32Type;
+    use std::collections::HashMap;
 
     use super::*;
 
@@ -255,4 +301,61 @@ mod tests {
 
         builder.finish();
     }
+
+    #[test]
+    fn test_map_builder_with_fields() {
+        let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+        let keys_field =
+            Field::new("my_keys", DataType::Utf8, false).with_metadata(metadata.clone());
+        let values_field = Field::new("my_values", DataType::Int32, false);
+
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new())
+                .with_keys_field(keys_field.clone())
+                .with_values_field(values_field.clone());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.append(true).unwrap();
+        let map = builder.finish();
+
+        assert_eq!(map.entries().fields()[0].as_ref(), &keys_field);
+        assert_eq!(map.entries().fields()[1].as_ref(), &values_field);
+        let DataType::Map(field, _) = map.data_type() else {
+            unreachable!()
+        };
+        assert_eq!(field.name(), "entries");
+    }
+
+    #[test]
+    #[should_panic(expected = "Keys field must not be nullable")]
+    fn test_map_builder_nullable_keys_field_panics() {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new())
+                .with_keys_field(Field::new("keys", DataType::Utf8, true));
+        builder.append(true).unwrap();
+        builder.finish();
+    }
+
+    #[test]
+    fn test_map_builder_nested_values() {
+        // Construct `[{"a": [1, 2]}, {"b": null, "c": []}]`
+        let values = ListBuilder::new(Int32Builder::new());
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), values);
+        builder.keys().append_value("a");
+        builder.values().append_value([Some(1), Some(2)]);
+        builder.append(true).unwrap();
+        builder.keys().append_value("b");
+        builder.values().append_null();
+        builder.keys().append_value("c");
+        builder.values().append(true);
+        builder.append(true).unwrap();
+        let map = builder.finish();
+
+        assert_eq!(map.value_offsets(), &[0, 1, 3]);
+        let values = map.values().as_list::<i32>();
+        assert_eq!(values.null_count(), 1);
+        let first = values.value(0);
+        assert_eq!(first.as_primitive::<Int32Type>().values(), &[1, 2]);
+        assert_eq!(values.value_length(2), 0);
+    }
 }
@@ -17,7 +17,7 @@
 
 use crate::builder::{ArrayBuilder, BufferBuilder};
 use crate::types::*;
-use crate::{ArrayRef, ArrowPrimitiveType, PrimitiveArray};
+use crate::{Array, ArrayRef, ArrowPrimitiveType, PrimitiveArray};
 use arrow_buffer::NullBufferBuilder;
 use arrow_buffer::{Buffer, MutableBuffer};
 use arrow_data::ArrayData;
@@ -87,6 +87,10 @@ pub type DurationMicrosecondBuilder = PrimitiveBuilder<DurationMicrosecondType>;
 /// An elapsed time in nanoseconds array builder.
 pub type DurationNanosecondBuilder = PrimitiveBuilder<DurationNanosecondType>;
 
+/// A decimal 32 array builder
+pub type Decimal32Builder = PrimitiveBuilder<Decimal32Type>;
+/// A decimal 64 array builder
+pub type Decimal64Builder = PrimitiveBuilder<Decimal64Type>;
 /// A decimal 128 array builder
 pub type Decimal128Builder = PrimitiveBuilder<Decimal128Type>;
 /// A decimal 256 array builder
@@ -177,7 +181,7 @@ impl<T: ArrowPrimitiveType> PrimitiveBuilder<T> {
     /// data type of the generated array.
     ///
     /// This method allows overriding the data type, to allow specifying timezones
-    /// for [`DataType::Timestamp`] or precision and scale for [`DataType::Decimal128`] and [`DataType::Decimal256`]
+    /// for [`DataType::Timestamp`] or precision and scale for decimal types such as [`DataType::Decimal128`]
     ///
     /// # Panics
     ///
@@ -250,6 +254,20 @@ impl<T: ArrowPrimitiveType> PrimitiveBuilder<T> {
         self.values_builder.append_slice(values);
     }
 
+    /// Appends the values and validity of `array` into the builder
+    ///
+    /// This copies the underlying buffers directly, and is therefore considerably
+    /// faster than appending each value individually. To append a range of `array`
+    /// first [`slice`](PrimitiveArray::slice) it, which is zero-copy
+    #[inline]
+    pub fn append_array(&mut self, array: &PrimitiveArray<T>) {
+        match array.nulls() {
+            Some(nulls) => self.null_buffer_builder.append_buffer(nulls),
+            None => self.null_buffer_builder.append_n_non_nulls(array.len()),
+        }
+        self.values_builder.append_slice(array.values());
+    }
+
     /// Appends values from a trusted length iterator.
     ///
     /// # Safety
@@ -623,4 +641,29 @@ mod tests {
         let array = builder.finish();
         assert_eq!(array.values(), &[1, 2, 3, 5, 2, 4, 4, 2, 4, 6, 2]);
     }
+
+    #[test]
+    fn test_append_array() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3), Some(4)]);
+        let b = Int32Array::from(vec![5, 6, 7]);
+
+        let mut builder = Int32Builder::new();
+        builder.append_value(0);
+        builder.append_array(&a.slice(1, 3));
+        builder.append_array(&b);
+        builder.append_null();
+        let array = builder.finish();
+
+        let expected = Int32Array::from(vec![
+            Some(0),
+            None,
+            Some(3),
+            Some(4),
+            Some(5),
+            Some(6),
+            Some(7),
+            None,
+        ]);
+        assert_eq!(array, expected);
+    }
 }
@@ -17,7 +17,7 @@
 
 use crate::builder::{ArrayBuilder, PrimitiveBuilder};
 use crate::types::ArrowDictionaryKeyType;
-use crate::{Array, ArrayRef, ArrowPrimitiveType, DictionaryArray};
+use crate::{Array, ArrayRef, ArrowPrimitiveType, DictionaryArray, PrimitiveArray};
 use arrow_buffer::{ArrowNativeType, ToByteSlice};
 use arrow_schema::{ArrowError, DataType};
 use std::any::Any;
@@ -210,11 +210,10 @@ where
     K: ArrowDictionaryKeyType,
     V: ArrowPrimitiveType,
 {
-    /// Append a primitive value to the array. Return an existing index
-    /// if already present in the values array or a new index if the
-    /// value is appended to the values array.
+    /// Returns the key of `value` in the values array, appending it to the
+    /// values array if not already present
     #[inline]
-    pub fn append(&mut self, value: V::Native) -> Result<K::Native, ArrowError> {
+    fn get_or_insert_key(&mut self, value: V::Native) -> Result<K::Native, ArrowError> {
         let key = match self.map.entry(Value(value)) {
             Entry::Vacant(vacant) => {
                 // Append new value.
@@ -226,11 +225,54 @@ where
             }
             Entry::Occupied(o) => K::Native::usize_as(*o.get()),
         };
+        Ok(key)
+    }
 
+    /// Append a primitive value to the array. Return an existing index
+    /// if already present in the values array or a new index if the
+    /// value is appended to the values array.
+    #[inline]
+    pub fn append(&mut self, value: V::Native) -> Result<K::Native, ArrowError> {
+        let key = self.get_or_insert_key(value)?;
         self.keys_builder.append_value(key);
         Ok(key)
     }
 
+    /// Appends the logical values of the [`DictionaryArray`] `array` into the builder
+    ///
+    /// Each value of the dictionary of `array` is interned once, after which the keys
+    /// of `array` are remapped onto this builder's dictionary. To append a range of
+    /// `array` first [`slice`](DictionaryArray::slice) it.
+    ///
+    /// Returns an error if the values of `array` are not of type `V`, or if the new
+    /// indices would overflow the key type.
+    pub fn append_array(&mut self, array: &DictionaryArray<K>) -> Result<(), ArrowError> {
+        let values = array
+            .values()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<V>>()
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Expected dictionary values of type {}, got {}",
+                    V::DATA_TYPE,
+                    array.values().data_type()
+                ))
+            })?;
+
+        let mapping = values
+            .iter()
+            .map(|v| v.map(|v| self.get_or_insert_key(v)).transpose())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for key in array.keys() {
+            match key.and_then(|k| mapping[k.as_usize()]) {
+                Some(key) => self.keys_builder.append_value(key),
+                None => self.keys_builder.append_null(),
+            }
+        }
+        Ok(())
+    }
+
     /// Infallibly append a value to this builder
     ///
     /// # Panics
@@ -327,6 +369,7 @@ mod tests {
     use crate::array::UInt32Array;
     use crate::array::UInt8Array;
     use crate::builder::Decimal128Builder;
+    use crate::cast::AsArray;
     use crate::types::{Decimal128Type, Int32Type, UInt32Type, UInt8Type};
 
     #[test]
@@ -401,4 +444,28 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_append_array() {
+        let values = UInt32Array::from(vec![Some(10), None, Some(20), Some(30)]);
+        let keys = UInt8Array::from(vec![Some(3), Some(0), None, Some(1), Some(2)]);
+        let a = DictionaryArray::new(keys, Arc::new(values));
+
+        let mut builder = PrimitiveDictionaryBuilder::<UInt8Type, UInt32Type>::new();
+        builder.append_value(20);
+        builder.append_array(&a).unwrap();
+        let dict = builder.finish();
+
+        let values = dict.values().as_primitive::<UInt32Type>();
+        let actual: Vec<_> = dict
+            .keys()
+            .iter()
+            .map(|k| k.map(|k| values.value(k as usize)))
+            .collect();
+        assert_eq!(
+            actual,
+            vec![Some(20), Some(30), Some(10), None, None, Some(20)]
+        );
+        assert_eq!(values.values(), &[20, 10, 30]);
+    }
 }
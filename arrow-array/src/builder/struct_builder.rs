@@ -112,6 +112,14 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilde
         DataType::FixedSizeBinary(len) => {
             Box::new(FixedSizeBinaryBuilder::with_capacity(capacity, *len))
         }
+        DataType::Decimal32(p, s) => Box::new(
+            Decimal32Builder::with_capacity(capacity)
+                .with_data_type(DataType::Decimal32(*p, *s)),
+        ),
+        DataType::Decimal64(p, s) => Box::new(
+            Decimal64Builder::with_capacity(capacity)
+                .with_data_type(DataType::Decimal64(*p, *s)),
+        ),
         DataType::Decimal128(p, s) => Box::new(
             Decimal128Builder::with_capacity(capacity)
                 .with_data_type(DataType::Decimal128(*p, *s)),
@@ -178,6 +186,30 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilde
         DataType::Struct(fields) => {
             Box::new(StructBuilder::from_fields(fields.clone(), capacity))
         }
+        DataType::Map(field, _) => match field.data_type() {
+            DataType::Struct(fields) if fields.len() == 2 => {
+                let map_field_names = MapFieldNames {
+                    key: fields[0].name().clone(),
+                    value: fields[1].name().clone(),
+                    entry: field.name().clone(),
+                };
+                let key_builder = make_builder(fields[0].data_type(), capacity);
+                let value_builder = make_builder(fields[1].data_type(), capacity);
+                Box::new(
+                    MapBuilder::with_capacity(
+                        Some(map_field_names),
+                        key_builder,
+                        value_builder,
+                        capacity,
+                    )
+                    .with_keys_field(fields[0].clone())
+                    .with_values_field(fields[1].clone()),
+                )
+            }
+            t => {
+                panic!("The entries of a Map must be a Struct with two fields, got {t:?}")
+            }
+        },
         t => panic!("Data type {t:?} is not currently supported"),
     }
 }
@@ -275,6 +307,8 @@ mod tests {
     use arrow_schema::Field;
 
     use crate::array::Array;
+    use crate::cast::AsArray;
+    use crate::types::Int32Type;
 
     #[test]
     fn test_struct_array_builder() {
@@ -521,6 +555,43 @@ mod tests {
         let _ = StructBuilder::from_fields(fields, 5);
     }
 
+    #[test]
+    fn test_struct_array_builder_from_schema_map() {
+        let entries = Fields::from(vec![
+            Field::new("k", DataType::Utf8, false),
+            Field::new("v", DataType::Int32, false),
+        ]);
+        let map_type = DataType::Map(
+            Arc::new(Field::new("e", DataType::Struct(entries), false)),
+            false,
+        );
+        let fields = vec![Field::new("m", map_type.clone(), true)];
+        let mut builder = StructBuilder::from_fields(fields, 1);
+
+        type DynMapBuilder = MapBuilder<Box<dyn ArrayBuilder>, Box<dyn ArrayBuilder>>;
+        let map_builder = builder.field_builder::<DynMapBuilder>(0).unwrap();
+        map_builder
+            .keys()
+            .as_any_mut()
+            .downcast_mut::<StringBuilder>()
+            .unwrap()
+            .append_value("a");
+        map_builder
+            .values()
+            .as_any_mut()
+            .downcast_mut::<Int32Builder>()
+            .unwrap()
+            .append_value(1);
+        map_builder.append(true).unwrap();
+        builder.append(true);
+
+        let array = builder.finish();
+        assert_eq!(array.column(0).data_type(), &map_type);
+        let map = array.column(0).as_map();
+        assert_eq!(map.keys().as_string::<i32>().value(0), "a");
+        assert_eq!(map.values().as_primitive::<Int32Type>().value(0), 1);
+    }
+
     #[test]
     fn test_struct_array_builder_field_builder_type_mismatch() {
         let int_builder = Int32Builder::with_capacity(10);
@@ -368,6 +368,8 @@ make_numeric_type!(DurationSecondType, i64, i64x8, m64x8);
 make_numeric_type!(DurationMillisecondType, i64, i64x8, m64x8);
 make_numeric_type!(DurationMicrosecondType, i64, i64x8, m64x8);
 make_numeric_type!(DurationNanosecondType, i64, i64x8, m64x8);
+make_numeric_type!(Decimal32Type, i32, i32x16, m32x16);
+make_numeric_type!(Decimal64Type, i64, i64x8, m64x8);
 make_numeric_type!(Decimal128Type, i128, i128x4, m128x4);
 
 #[cfg(not(feature = "simd"))]
@@ -18,8 +18,12 @@
 //! A two-dimensional batch of column-oriented data with a defined
 //! [schema](arrow_schema::Schema).
 
-use crate::{new_empty_array, Array, ArrayRef, StructArray};
-use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaBuilder, SchemaRef};
+use crate::cast::AsArray;
+use crate::{make_array, new_empty_array, Array, ArrayRef, StructArray};
+use arrow_buffer::NullBuffer;
+use arrow_schema::{
+    ArrowError, DataType, Field, FieldRef, Schema, SchemaBuilder, SchemaRef,
+};
 use std::ops::Index;
 use std::sync::Arc;
 
@@ -443,6 +447,263 @@ impl RecordBatch {
             .map(|array| array.get_array_memory_size())
             .sum()
     }
+
+    /// Returns the number of bytes of memory needed to store only the rows of this
+    /// batch, excluding any data outside of its slice retained by the columns
+    ///
+    /// A small slice of a large batch may retain all of the buffers of the original,
+    /// in which case [`Self::get_array_memory_size`] will be much larger than this.
+    /// See [`Array::get_slice_memory_size`]
+    pub fn get_slice_memory_size(&self) -> Result<usize, ArrowError> {
+        self.columns()
+            .iter()
+            .map(|array| array.get_slice_memory_size())
+            .sum()
+    }
+
+    /// Returns a copy of this batch with each column copied into new buffers
+    /// containing only the rows of this batch
+    ///
+    /// This releases any memory retained by a slice of a larger batch, see
+    /// [`Self::get_slice_memory_size`] and [`ArrayData::deep_copy_sliced`]
+    ///
+    /// [`ArrayData::deep_copy_sliced`]: arrow_data::ArrayData::deep_copy_sliced
+    pub fn deep_copy_sliced(&self) -> Result<RecordBatch, ArrowError> {
+        let columns = self
+            .columns
+            .iter()
+            .map(|c| Ok(make_array(c.to_data().deep_copy_sliced()?)))
+            .collect::<Result<Vec<_>, ArrowError>>()?;
+
+        Ok(RecordBatch {
+            schema: self.schema.clone(),
+            columns,
+            row_count: self.row_count,
+        })
+    }
+
+    /// Flattens the [`StructArray`] columns of this batch into top-level columns
+    ///
+    /// The children of a struct column replace it, named by joining the name of the
+    /// struct and that of the child with `separator`. Flattening is applied
+    /// recursively up to `max_level` levels of nesting, or to all levels if `None`.
+    /// Nulls of a struct are propagated to its children, which become nullable if
+    /// the struct is nullable.
+    ///
+    /// See [`Self::nest`] for the inverse operation
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray, StructArray};
+    /// # use arrow_schema::{DataType, Field};
+    /// let point = StructArray::from(vec![
+    ///     (Arc::new(Field::new("x", DataType::Int32, false)), Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+    ///     (Arc::new(Field::new("y", DataType::Int32, false)), Arc::new(Int32Array::from(vec![3, 4])) as ArrayRef),
+    /// ]);
+    /// let name = StringArray::from(vec!["a", "b"]);
+    /// let batch = RecordBatch::try_from_iter_with_nullable([
+    ///     ("name", Arc::new(name) as ArrayRef, true),
+    ///     ("point", Arc::new(point) as ArrayRef, false),
+    /// ]).unwrap();
+    ///
+    /// let normalized = batch.normalize(".", None).unwrap();
+    /// let names: Vec<_> = normalized.schema().fields().iter().map(|f| f.name().clone()).collect();
+    /// assert_eq!(names, ["name", "point.x", "point.y"]);
+    ///
+    /// assert_eq!(normalized.nest(".").unwrap(), batch);
+    /// ```
+    pub fn normalize(
+        &self,
+        separator: &str,
+        max_level: Option<usize>,
+    ) -> Result<RecordBatch, ArrowError> {
+        let max_level = max_level.unwrap_or(usize::MAX);
+        let mut fields = Vec::with_capacity(self.columns.len());
+        let mut columns = Vec::with_capacity(self.columns.len());
+
+        for (field, column) in self.schema.fields().iter().zip(&self.columns) {
+            normalize_column(
+                field,
+                column,
+                field.name(),
+                separator,
+                max_level,
+                &mut fields,
+                &mut columns,
+            )?;
+        }
+
+        let schema = Schema::new_with_metadata(fields, self.schema.metadata().clone());
+        RecordBatch::try_new_with_options(
+            Arc::new(schema),
+            columns,
+            &RecordBatchOptions::new().with_row_count(Some(self.row_count)),
+        )
+    }
+
+    /// Nests the columns of this batch whose names contain `separator` into
+    /// [`StructArray`] columns, the inverse of [`Self::normalize`]
+    ///
+    /// Column names are split on `separator`, with columns sharing a prefix grouped
+    /// into a struct of that name, in the order the prefix first appears. The
+    /// created structs are non-nullable, and so nulls propagated to the children
+    /// of a struct by [`Self::normalize`] are not restored to the struct itself.
+    ///
+    /// Returns an error if a column name is also the prefix of another column,
+    /// e.g. `a` and `a.b`
+    pub fn nest(&self, separator: &str) -> Result<RecordBatch, ArrowError> {
+        let mut root = NestedColumns::default();
+        for (field, column) in self.schema.fields().iter().zip(&self.columns) {
+            let conflict = || {
+                ArrowError::InvalidArgumentError(format!(
+                    "Cannot nest column \"{}\" as it conflicts with another column",
+                    field.name()
+                ))
+            };
+
+            let mut path = field.name().split(separator).peekable();
+            let mut node = &mut root;
+            while let Some(name) = path.next() {
+                let is_leaf = path.peek().is_none();
+                let existing = node.0.iter().position(|(n, _)| n == name);
+                node = match (existing, is_leaf) {
+                    (None, true) => {
+                        let leaf = NestedColumn::Leaf(field.clone(), column.clone());
+                        node.0.push((name.to_string(), leaf));
+                        break;
+                    }
+                    (None, false) => {
+                        let child = NestedColumn::Struct(NestedColumns::default());
+                        node.0.push((name.to_string(), child));
+                        match &mut node.0.last_mut().unwrap().1 {
+                            NestedColumn::Struct(children) => children,
+                            NestedColumn::Leaf(_, _) => unreachable!(),
+                        }
+                    }
+                    (Some(idx), false) => match &mut node.0[idx].1 {
+                        NestedColumn::Struct(children) => children,
+                        NestedColumn::Leaf(_, _) => return Err(conflict()),
+                    },
+                    (Some(_), true) => return Err(conflict()),
+                };
+            }
+        }
+
+        let (fields, columns) = root.finish()?;
+        let schema = Schema::new_with_metadata(fields, self.schema.metadata().clone());
+        RecordBatch::try_new_with_options(
+            Arc::new(schema),
+            columns,
+            &RecordBatchOptions::new().with_row_count(Some(self.row_count)),
+        )
+    }
+}
+
+/// Appends the columns produced by flattening `column` to `fields` and `columns`,
+/// see [`RecordBatch::normalize`]
+fn normalize_column(
+    field: &FieldRef,
+    column: &ArrayRef,
+    name: &str,
+    separator: &str,
+    max_level: usize,
+    fields: &mut Vec<FieldRef>,
+    columns: &mut Vec<ArrayRef>,
+) -> Result<(), ArrowError> {
+    let children = match field.data_type() {
+        DataType::Struct(children) if max_level > 0 => children,
+        _ => {
+            let field = match field.name() == name {
+                true => field.clone(),
+                false => Arc::new(field.as_ref().clone().with_name(name)),
+            };
+            fields.push(field);
+            columns.push(column.clone());
+            return Ok(());
+        }
+    };
+
+    let array = column.as_struct();
+    for (child_field, child) in children.iter().zip(array.columns()) {
+        let child = match array.nulls().filter(|n| n.null_count() > 0) {
+            Some(nulls) => with_parent_nulls(child, nulls)?,
+            None => child.clone(),
+        };
+        let child_field = match field.is_nullable() && !child_field.is_nullable() {
+            true => Arc::new(child_field.as_ref().clone().with_nullable(true)),
+            false => child_field.clone(),
+        };
+        let child_name = format!("{name}{separator}{}", child_field.name());
+        normalize_column(
+            &child_field,
+            &child,
+            &child_name,
+            separator,
+            max_level - 1,
+            fields,
+            columns,
+        )?;
+    }
+    Ok(())
+}
+
+/// Returns `child` with the nulls of its parent `nulls` applied
+fn with_parent_nulls(
+    child: &ArrayRef,
+    nulls: &NullBuffer,
+) -> Result<ArrayRef, ArrowError> {
+    match child.data_type() {
+        DataType::Null => Ok(child.clone()),
+        DataType::Union(_, _) | DataType::RunEndEncoded(_, _) => {
+            Err(ArrowError::NotYetImplemented(format!(
+                "Cannot propagate nulls of a struct to a child of type {}",
+                child.data_type()
+            )))
+        }
+        _ => {
+            let nulls = NullBuffer::union(Some(nulls), child.nulls());
+            let data = child.to_data().into_builder().nulls(nulls);
+            // SAFETY: only the validity of the array has changed
+            Ok(make_array(unsafe { data.build_unchecked() }))
+        }
+    }
+}
+
+/// The columns of a [`RecordBatch`] grouped by [`RecordBatch::nest`]
+#[derive(Default)]
+struct NestedColumns(Vec<(String, NestedColumn)>);
+
+enum NestedColumn {
+    Leaf(FieldRef, ArrayRef),
+    Struct(NestedColumns),
+}
+
+impl NestedColumns {
+    fn finish(self) -> Result<(Vec<FieldRef>, Vec<ArrayRef>), ArrowError> {
+        let mut fields = Vec::with_capacity(self.0.len());
+        let mut columns = Vec::with_capacity(self.0.len());
+        for (name, column) in self.0 {
+            let (field, column) = match column {
+                NestedColumn::Leaf(field, column) => {
+                    let field = match field.name() == &name {
+                        true => field,
+                        false => Arc::new(field.as_ref().clone().with_name(name)),
+                    };
+                    (field, column)
+                }
+                NestedColumn::Struct(children) => {
+                    let (child_fields, child_columns) = children.finish()?;
+                    let array =
+                        StructArray::try_new(child_fields.into(), child_columns, None)?;
+                    let field = Field::new(name, array.data_type().clone(), false);
+                    (Arc::new(field), Arc::new(array) as ArrayRef)
+                }
+            };
+            fields.push(field);
+            columns.push(column);
+        }
+        Ok((fields, columns))
+    }
 }
 
 /// Options that control the behaviour used when creating a [`RecordBatch`].
@@ -627,6 +888,139 @@ mod tests {
         assert_eq!(record_batch.get_array_memory_size(), 364);
     }
 
+    fn nested_batch() -> RecordBatch {
+        let inner = StructArray::from(vec![(
+            Arc::new(Field::new("c", DataType::Int32, false)),
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        )]);
+        let inner_type = inner.data_type().clone();
+        let outer = StructArray::try_new(
+            Fields::from(vec![
+                Field::new("b", DataType::Utf8, true),
+                Field::new("inner", inner_type, false),
+            ]),
+            vec![
+                Arc::new(StringArray::from(vec![Some("x"), None, Some("z")])),
+                Arc::new(inner),
+            ],
+            Some(NullBuffer::from(vec![true, true, false])),
+        )
+        .unwrap();
+
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("outer", outer.data_type().clone(), true),
+        ])
+        .with_metadata([("k".to_string(), "v".to_string())].into());
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(Int64Array::from(vec![4, 5, 6])), Arc::new(outer)],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn normalize() {
+        let batch = nested_batch();
+        let normalized = batch.normalize("_", None).unwrap();
+        let schema = normalized.schema();
+        assert_eq!(schema.metadata(), batch.schema().metadata());
+        let fields: Vec<_> = schema
+            .fields()
+            .iter()
+            .map(|f| (f.name().as_str(), f.is_nullable()))
+            .collect();
+        assert_eq!(
+            fields,
+            [("a", false), ("outer_b", true), ("outer_inner_c", true),]
+        );
+
+        // Nulls of the struct are propagated to its children
+        let b = normalized.column(1).as_string::<i32>();
+        assert_eq!(b.iter().collect::<Vec<_>>(), [Some("x"), None, None]);
+        let c = normalized
+            .column(2)
+            .as_primitive::<crate::types::Int32Type>();
+        assert_eq!(c.iter().collect::<Vec<_>>(), [Some(1), Some(2), None]);
+
+        let normalized = batch.normalize(".", Some(1)).unwrap();
+        let names: Vec<_> = normalized
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect();
+        assert_eq!(names, ["a", "outer.b", "outer.inner"]);
+        assert_eq!(normalized.column(2).null_count(), 1);
+
+        assert_eq!(batch.normalize(".", Some(0)).unwrap(), batch);
+
+        let sliced = batch.slice(1, 2).normalize(".", None).unwrap();
+        assert_eq!(sliced, batch.normalize(".", None).unwrap().slice(1, 2));
+    }
+
+    #[test]
+    fn nest() {
+        let batch = nested_batch();
+        let nested = batch.normalize(".", None).unwrap().nest(".").unwrap();
+        assert_eq!(nested.schema().metadata(), batch.schema().metadata());
+
+        let outer = nested.column(1).as_struct();
+        assert_eq!(nested.schema().field(1).name(), "outer");
+        assert_eq!(outer.null_count(), 0);
+        assert_eq!(outer.column_names(), ["b", "inner"]);
+        let inner = outer.column(1).as_struct();
+        assert_eq!(inner.column_names(), ["c"]);
+        assert_eq!(inner.column(0).null_count(), 1);
+
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        let batch = RecordBatch::try_from_iter([
+            ("x/a", a.clone()),
+            ("y", a.clone()),
+            ("x/b", a.clone()),
+        ])
+        .unwrap();
+        let nested = batch.nest("/").unwrap();
+        assert_eq!(nested.num_columns(), 2);
+        assert_eq!(nested.column(0).as_struct().column_names(), ["a", "b"]);
+
+        for names in [["x", "x.a"], ["x.a", "x"], ["x", "x"]] {
+            let batch =
+                RecordBatch::try_from_iter(names.iter().map(|n| (n, a.clone()))).unwrap();
+            let err = batch.nest(".").unwrap_err().to_string();
+            assert!(err.contains("conflicts with another column"), "{err}");
+        }
+    }
+
+    #[test]
+    fn slice_memory_size() {
+        let a = Int64Array::from_iter_values(0..1000);
+        let b = StringArray::from_iter_values((0..1000).map(|x| x.to_string()));
+        let batch = RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b)),
+        ])
+        .unwrap();
+
+        let slice = batch.slice(10, 2);
+        assert_eq!(slice.get_array_memory_size(), batch.get_array_memory_size());
+        // 2 * 8 bytes of "a", 2 * 4 bytes of offsets and 4 bytes of data of "b"
+        assert_eq!(slice.get_slice_memory_size().unwrap(), 16 + 8 + 4);
+
+        let copy = slice.deep_copy_sliced().unwrap();
+        assert_eq!(copy, slice);
+        assert!(copy.get_array_memory_size() < batch.get_array_memory_size() / 10);
+        assert_eq!(copy.get_slice_memory_size().unwrap(), 16 + 8 + 4);
+
+        let empty = RecordBatch::try_new_with_options(
+            Arc::new(Schema::empty()),
+            vec![],
+            &RecordBatchOptions::new().with_row_count(Some(10)),
+        )
+        .unwrap();
+        assert_eq!(empty.deep_copy_sliced().unwrap().num_rows(), 10);
+    }
+
     fn check_batch(record_batch: RecordBatch, num_rows: usize) {
         assert_eq!(num_rows, record_batch.num_rows());
         assert_eq!(2, record_batch.num_columns());
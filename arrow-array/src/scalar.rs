@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::Array;
+use crate::{new_null_array, Array, ArrayRef};
 
 /// A possibly [`Scalar`] [`Array`]
 ///
@@ -100,23 +100,62 @@ impl Datum for &dyn Array {
 
 /// A wrapper around a single value [`Array`] indicating kernels should treat it as a scalar value
 ///
+/// As any [`Array`] can be wrapped, this can represent scalars of any [`DataType`], including
+/// nested types. Scalars of primitive, boolean and byte types can be created directly from
+/// their native values with the `new_scalar` constructors of the corresponding arrays
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::*;
+/// # use arrow_schema::DataType;
+/// // Created from a native value
+/// let a = Int32Array::new_scalar(5);
+/// let b = StringArray::new_scalar("hello");
+///
+/// // Created from an existing array
+/// let array = Arc::new(Int32Array::from(vec![1])) as ArrayRef;
+/// let c = Scalar::new(array);
+///
+/// // A null scalar of any type
+/// let d = Scalar::new_null(&DataType::Utf8);
+/// assert!(d.into_inner().is_null(0));
+/// ```
+///
 /// See [`Datum`] for more information
-pub struct Scalar<'a>(&'a dyn Array);
+///
+/// [`DataType`]: arrow_schema::DataType
+#[derive(Debug, Copy, Clone)]
+pub struct Scalar<T: Array>(T);
 
-impl<'a> Scalar<'a> {
+impl<T: Array> Scalar<T> {
     /// Create a new [`Scalar`] from an [`Array`]
     ///
     /// # Panics
     ///
     /// Panics if `array.len() != 1`
-    pub fn new(array: &'a dyn Array) -> Self {
+    pub fn new(array: T) -> Self {
         assert_eq!(array.len(), 1);
         Self(array)
     }
+
+    /// Returns the inner array
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl Scalar<ArrayRef> {
+    /// Create a new null [`Scalar`] of the provided [`DataType`]
+    ///
+    /// [`DataType`]: arrow_schema::DataType
+    pub fn new_null(data_type: &arrow_schema::DataType) -> Self {
+        Self(new_null_array(data_type, 1))
+    }
 }
 
-impl<'a> Datum for Scalar<'a> {
+impl<T: Array> Datum for Scalar<T> {
     fn get(&self) -> (&dyn Array, bool) {
-        (self.0, true)
+        (&self.0, true)
     }
 }
@@ -0,0 +1,590 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Mapping between Avro and Arrow schemas
+//!
+//! | Avro                          | Arrow                               |
+//! |-------------------------------|-------------------------------------|
+//! | `null`                        | `Null`                              |
+//! | `boolean`                     | `Boolean`                           |
+//! | `int`                         | `Int32`                             |
+//! | `long`                        | `Int64`                             |
+//! | `float` / `double`            | `Float32` / `Float64`               |
+//! | `bytes`                       | `Binary`                            |
+//! | `string` / `uuid`             | `Utf8`                              |
+//! | `fixed`                       | `FixedSizeBinary`                   |
+//! | `enum`                        | `Dictionary(Int32, Utf8)`           |
+//! | `array`                       | `List`                              |
+//! | `map`                         | `Map` with `Utf8` keys              |
+//! | `record`                      | `Struct`                            |
+//! | `["null", T]`                 | nullable `T`                        |
+//! | `decimal`                     | `Decimal128`, or `Decimal256` if the precision exceeds 38 |
+//! | `date`                        | `Date32`                            |
+//! | `time-millis` / `time-micros` | `Time32(Millisecond)` / `Time64(Microsecond)` |
+//! | `timestamp-*`                 | `Timestamp(_, Some("+00:00"))`      |
+//! | `local-timestamp-*`           | `Timestamp(_, None)`                |
+//! | `duration`                    | `Interval(MonthDayNano)`            |
+//!
+//! Unions other than a nullable union of two variants are not supported
+
+use crate::schema::{Fixed, LogicalType, Record, RecordField, Schema as AvroSchema};
+use arrow_schema::{
+    ArrowError, DataType, Field, FieldRef, Fields, IntervalUnit, Schema, TimeUnit,
+    DECIMAL128_MAX_PRECISION, DECIMAL256_MAX_PRECISION,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The name of the top-level record written by [`arrow_to_avro_schema`]
+const TOP_LEVEL_RECORD: &str = "topLevelRecord";
+
+/// The position of `null` within a nullable union
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Nullability {
+    /// The union is `["null", T]`
+    NullFirst,
+    /// The union is `[T, "null"]`
+    NullSecond,
+}
+
+/// An Avro type, along with the Arrow type it decodes to
+#[derive(Debug, Clone)]
+pub struct AvroDataType {
+    nullability: Option<Nullability>,
+    codec: Codec,
+}
+
+impl AvroDataType {
+    /// Returns the [`Nullability`] of this type, if it is a nullable union
+    pub fn nullability(&self) -> Option<Nullability> {
+        self.nullability
+    }
+
+    /// Returns the [`Codec`] of this type
+    pub fn codec(&self) -> &Codec {
+        &self.codec
+    }
+
+    /// Returns an Arrow [`Field`] with the given name
+    pub fn field_with_name(&self, name: &str) -> Field {
+        let nullable = self.nullability.is_some() || matches!(self.codec, Codec::Null);
+        Field::new(name, self.codec.data_type(), nullable)
+    }
+}
+
+/// A named field of an Avro record
+#[derive(Debug, Clone)]
+pub struct AvroField {
+    name: String,
+    data_type: AvroDataType,
+}
+
+impl AvroField {
+    /// Returns the name of this field
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the [`AvroDataType`] of this field
+    pub fn data_type(&self) -> &AvroDataType {
+        &self.data_type
+    }
+
+    /// Returns the Arrow [`Field`] of this field
+    pub fn field(&self) -> Field {
+        self.data_type.field_with_name(&self.name)
+    }
+}
+
+/// The physical encoding of an Avro type, along with its Arrow representation
+#[derive(Debug, Clone)]
+pub enum Codec {
+    /// `null`
+    Null,
+    /// `boolean`
+    Boolean,
+    /// `int`
+    Int32,
+    /// `long`
+    Int64,
+    /// `float`
+    Float32,
+    /// `double`
+    Float64,
+    /// `bytes`
+    Binary,
+    /// `string`
+    Utf8,
+    /// `int` days since the UNIX epoch
+    Date32,
+    /// `int` milliseconds after midnight
+    TimeMillis,
+    /// `long` microseconds after midnight
+    TimeMicros,
+    /// `long` timestamp of the given unit, with `true` if UTC
+    Timestamp(TimeUnit, bool),
+    /// `fixed` of the given size
+    Fixed(usize),
+    /// A decimal with the given precision and scale, stored in a `fixed` of the
+    /// given size, or as `bytes` if `None`
+    Decimal(usize, usize, Option<usize>),
+    /// A `fixed` of 12 bytes containing months, days and milliseconds
+    Interval,
+    /// An `int` index into the given symbols
+    Enum(Arc<[String]>),
+    /// An array of the given items
+    List(Arc<AvroDataType>),
+    /// A map with `string` keys and the given values
+    Map(Arc<AvroDataType>),
+    /// A record with the given fields
+    Struct(Arc<[AvroField]>),
+}
+
+impl Codec {
+    /// Returns the Arrow [`DataType`] this codec decodes to
+    pub fn data_type(&self) -> DataType {
+        match self {
+            Self::Null => DataType::Null,
+            Self::Boolean => DataType::Boolean,
+            Self::Int32 => DataType::Int32,
+            Self::Int64 => DataType::Int64,
+            Self::Float32 => DataType::Float32,
+            Self::Float64 => DataType::Float64,
+            Self::Binary => DataType::Binary,
+            Self::Utf8 => DataType::Utf8,
+            Self::Date32 => DataType::Date32,
+            Self::TimeMillis => DataType::Time32(TimeUnit::Millisecond),
+            Self::TimeMicros => DataType::Time64(TimeUnit::Microsecond),
+            Self::Timestamp(unit, utc) => {
+                DataType::Timestamp(unit.clone(), utc.then(|| "+00:00".into()))
+            }
+            Self::Fixed(size) => DataType::FixedSizeBinary(*size as i32),
+            Self::Decimal(precision, scale, _) => {
+                if *precision <= DECIMAL128_MAX_PRECISION as usize {
+                    DataType::Decimal128(*precision as u8, *scale as i8)
+                } else {
+                    DataType::Decimal256(*precision as u8, *scale as i8)
+                }
+            }
+            Self::Interval => DataType::Interval(IntervalUnit::MonthDayNano),
+            Self::Enum(_) => {
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+            }
+            Self::List(item) => DataType::List(Arc::new(item.field_with_name("item"))),
+            Self::Map(values) => DataType::Map(
+                Arc::new(Field::new(
+                    "entries",
+                    DataType::Struct(Fields::from(vec![
+                        Field::new("key", DataType::Utf8, false),
+                        values.field_with_name("value"),
+                    ])),
+                    false,
+                )),
+                false,
+            ),
+            Self::Struct(fields) => {
+                DataType::Struct(fields.iter().map(|f| f.field()).collect())
+            }
+        }
+    }
+}
+
+/// Returns the [`AvroField`] for each field of the top-level Avro record `schema`
+pub fn avro_fields(schema: &AvroSchema) -> Result<Arc<[AvroField]>, ArrowError> {
+    let mut resolver = Resolver::default();
+    let data_type = resolver.make_data_type(schema)?;
+    match data_type.codec {
+        Codec::Struct(fields) if data_type.nullability.is_none() => Ok(fields),
+        _ => Err(ArrowError::SchemaError(
+            "Expected top-level Avro schema to be a record".to_string(),
+        )),
+    }
+}
+
+/// Converts the top-level Avro record `schema` to an Arrow [`Schema`]
+pub fn avro_to_arrow_schema(schema: &AvroSchema) -> Result<Schema, ArrowError> {
+    let fields = avro_fields(schema)?;
+    Ok(Schema::new(
+        fields.iter().map(|f| f.field()).collect::<Vec<_>>(),
+    ))
+}
+
+/// Resolves references to named types
+#[derive(Debug, Default)]
+struct Resolver {
+    names: HashMap<String, Option<AvroDataType>>,
+}
+
+impl Resolver {
+    fn make_data_type(
+        &mut self,
+        schema: &AvroSchema,
+    ) -> Result<AvroDataType, ArrowError> {
+        let codec = match schema {
+            AvroSchema::Null => Codec::Null,
+            AvroSchema::Boolean => Codec::Boolean,
+            AvroSchema::Int => Codec::Int32,
+            AvroSchema::Long => Codec::Int64,
+            AvroSchema::Float => Codec::Float32,
+            AvroSchema::Double => Codec::Float64,
+            AvroSchema::Bytes => Codec::Binary,
+            AvroSchema::String => Codec::Utf8,
+            AvroSchema::Record(r) => {
+                self.names.insert(r.name.clone(), None);
+                let fields = r
+                    .fields
+                    .iter()
+                    .map(|f| {
+                        Ok(AvroField {
+                            name: f.name.clone(),
+                            data_type: self.make_data_type(&f.schema)?,
+                        })
+                    })
+                    .collect::<Result<_, ArrowError>>()?;
+                return Ok(self.register(&r.name, Codec::Struct(fields)));
+            }
+            AvroSchema::Enum(e) => {
+                return Ok(self.register(&e.name, Codec::Enum(e.symbols.clone().into())))
+            }
+            AvroSchema::Fixed(f) => {
+                return Ok(self.register(&f.name, Codec::Fixed(f.size)))
+            }
+            AvroSchema::Array(items) => {
+                Codec::List(Arc::new(self.make_data_type(items)?))
+            }
+            AvroSchema::Map(values) => Codec::Map(Arc::new(self.make_data_type(values)?)),
+            AvroSchema::Ref(name) => {
+                return match self.names.get(name) {
+                    Some(Some(data_type)) => Ok(data_type.clone()),
+                    Some(None) => Err(ArrowError::NotYetImplemented(format!(
+                        "Recursive Avro type {name} is not supported"
+                    ))),
+                    None => {
+                        Err(ArrowError::SchemaError(format!("Unknown Avro type {name}")))
+                    }
+                }
+            }
+            AvroSchema::Union(variants) => {
+                return match variants.as_slice() {
+                    [v] => self.make_data_type(v),
+                    [AvroSchema::Null, v] | [v, AvroSchema::Null]
+                        if !matches!(v, AvroSchema::Null) =>
+                    {
+                        let nullability = match variants[0] {
+                            AvroSchema::Null => Nullability::NullFirst,
+                            _ => Nullability::NullSecond,
+                        };
+                        let mut data_type = self.make_data_type(v)?;
+                        data_type.nullability = Some(nullability);
+                        Ok(data_type)
+                    }
+                    _ => Err(ArrowError::NotYetImplemented(format!(
+                        "Avro union {} is not supported",
+                        schema.to_json()
+                    ))),
+                };
+            }
+            AvroSchema::Logical(logical, base) => {
+                let fixed = match base.as_ref() {
+                    AvroSchema::Fixed(f) => Some(f),
+                    _ => None,
+                };
+                let codec = match logical {
+                    LogicalType::Decimal { precision, scale } => {
+                        if *precision > DECIMAL256_MAX_PRECISION as usize {
+                            return Err(ArrowError::SchemaError(format!(
+                                "Avro decimal precision {precision} exceeds maximum of {DECIMAL256_MAX_PRECISION}"
+                            )));
+                        }
+                        Codec::Decimal(*precision, *scale, fixed.map(|f| f.size))
+                    }
+                    LogicalType::Uuid => Codec::Utf8,
+                    LogicalType::Date => Codec::Date32,
+                    LogicalType::TimeMillis => Codec::TimeMillis,
+                    LogicalType::TimeMicros => Codec::TimeMicros,
+                    LogicalType::TimestampMillis => {
+                        Codec::Timestamp(TimeUnit::Millisecond, true)
+                    }
+                    LogicalType::TimestampMicros => {
+                        Codec::Timestamp(TimeUnit::Microsecond, true)
+                    }
+                    LogicalType::TimestampNanos => {
+                        Codec::Timestamp(TimeUnit::Nanosecond, true)
+                    }
+                    LogicalType::LocalTimestampMillis => {
+                        Codec::Timestamp(TimeUnit::Millisecond, false)
+                    }
+                    LogicalType::LocalTimestampMicros => {
+                        Codec::Timestamp(TimeUnit::Microsecond, false)
+                    }
+                    LogicalType::LocalTimestampNanos => {
+                        Codec::Timestamp(TimeUnit::Nanosecond, false)
+                    }
+                    LogicalType::Duration => Codec::Interval,
+                };
+                match fixed {
+                    // Register the name, so it can be referenced
+                    Some(f) => return Ok(self.register(&f.name, codec)),
+                    None => codec,
+                }
+            }
+        };
+        Ok(AvroDataType {
+            nullability: None,
+            codec,
+        })
+    }
+
+    fn register(&mut self, name: &str, codec: Codec) -> AvroDataType {
+        let data_type = AvroDataType {
+            nullability: None,
+            codec,
+        };
+        self.names.insert(name.to_string(), Some(data_type.clone()));
+        data_type
+    }
+}
+
+/// Converts an Arrow [`Schema`] to an Avro record schema
+///
+/// The top-level record is named `topLevelRecord`, with nested named types named
+/// by their path from the top-level record. Nullable fields are written as a union
+/// of `null` and the non-null type
+pub fn arrow_to_avro_schema(schema: &Schema) -> Result<AvroSchema, ArrowError> {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|f| arrow_to_avro_field(f, TOP_LEVEL_RECORD))
+        .collect::<Result<_, _>>()?;
+    Ok(AvroSchema::Record(Record {
+        name: TOP_LEVEL_RECORD.to_string(),
+        doc: None,
+        fields,
+    }))
+}
+
+fn arrow_to_avro_field(field: &FieldRef, path: &str) -> Result<RecordField, ArrowError> {
+    let path = format!("{path}.{}", field.name());
+    Ok(RecordField {
+        name: field.name().clone(),
+        doc: None,
+        schema: arrow_to_avro_type(field.data_type(), field.is_nullable(), &path)?,
+        default: None,
+    })
+}
+
+fn arrow_to_avro_type(
+    data_type: &DataType,
+    nullable: bool,
+    path: &str,
+) -> Result<AvroSchema, ArrowError> {
+    let logical = |l, base| AvroSchema::Logical(l, Box::new(base));
+    let schema = match data_type {
+        DataType::Null => return Ok(AvroSchema::Null),
+        DataType::Boolean => AvroSchema::Boolean,
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::UInt8
+        | DataType::UInt16 => AvroSchema::Int,
+        DataType::Int64 | DataType::UInt32 => AvroSchema::Long,
+        DataType::Float32 => AvroSchema::Float,
+        DataType::Float64 => AvroSchema::Double,
+        DataType::Binary | DataType::LargeBinary => AvroSchema::Bytes,
+        DataType::Utf8 | DataType::LargeUtf8 => AvroSchema::String,
+        DataType::FixedSizeBinary(size) => AvroSchema::Fixed(Fixed {
+            name: path.to_string(),
+            size: *size as usize,
+        }),
+        DataType::Date32 => logical(LogicalType::Date, AvroSchema::Int),
+        DataType::Time32(TimeUnit::Millisecond) => {
+            logical(LogicalType::TimeMillis, AvroSchema::Int)
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            logical(LogicalType::TimeMicros, AvroSchema::Long)
+        }
+        DataType::Timestamp(unit, tz) => {
+            let l = match (unit, tz.is_some()) {
+                (TimeUnit::Millisecond, true) => LogicalType::TimestampMillis,
+                (TimeUnit::Microsecond, true) => LogicalType::TimestampMicros,
+                (TimeUnit::Nanosecond, true) => LogicalType::TimestampNanos,
+                (TimeUnit::Millisecond, false) => LogicalType::LocalTimestampMillis,
+                (TimeUnit::Microsecond, false) => LogicalType::LocalTimestampMicros,
+                (TimeUnit::Nanosecond, false) => LogicalType::LocalTimestampNanos,
+                (TimeUnit::Second, _) => return Err(unsupported(data_type)),
+            };
+            logical(l, AvroSchema::Long)
+        }
+        DataType::Decimal128(precision, scale)
+        | DataType::Decimal256(precision, scale)
+            if *scale >= 0 =>
+        {
+            let l = LogicalType::Decimal {
+                precision: *precision as usize,
+                scale: *scale as usize,
+            };
+            logical(l, AvroSchema::Bytes)
+        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            let fixed = AvroSchema::Fixed(Fixed {
+                name: path.to_string(),
+                size: 12,
+            });
+            logical(LogicalType::Duration, fixed)
+        }
+        DataType::List(f) | DataType::LargeList(f) => {
+            let path = format!("{path}.{}", f.name());
+            let items = arrow_to_avro_type(f.data_type(), f.is_nullable(), &path)?;
+            AvroSchema::Array(Box::new(items))
+        }
+        DataType::Map(entries, _) => match entries.data_type() {
+            DataType::Struct(f)
+                if f.len() == 2 && f[0].data_type() == &DataType::Utf8 =>
+            {
+                let path = format!("{path}.{}", f[1].name());
+                let values =
+                    arrow_to_avro_type(f[1].data_type(), f[1].is_nullable(), &path)?;
+                AvroSchema::Map(Box::new(values))
+            }
+            _ => return Err(unsupported(data_type)),
+        },
+        DataType::Struct(fields) => AvroSchema::Record(Record {
+            name: path.to_string(),
+            doc: None,
+            fields: fields
+                .iter()
+                .map(|f| arrow_to_avro_field(f, path))
+                .collect::<Result<_, _>>()?,
+        }),
+        DataType::Dictionary(_, values)
+            if matches!(values.as_ref(), DataType::Utf8 | DataType::LargeUtf8) =>
+        {
+            AvroSchema::String
+        }
+        _ => return Err(unsupported(data_type)),
+    };
+
+    Ok(match nullable {
+        true => AvroSchema::Union(vec![AvroSchema::Null, schema]),
+        false => schema,
+    })
+}
+
+fn unsupported(data_type: &DataType) -> ArrowError {
+    ArrowError::NotYetImplemented(format!("Writing {data_type} to Avro is not supported"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_avro_to_arrow() {
+        let schema = AvroSchema::parse(
+            r#"{
+                "type": "record",
+                "name": "test",
+                "fields": [
+                    {"name": "a", "type": ["null", "long"]},
+                    {"name": "b", "type": {"type": "enum", "name": "e", "symbols": ["X", "Y"]}},
+                    {"name": "c", "type": {"type": "bytes", "logicalType": "decimal", "precision": 40, "scale": 2}},
+                    {"name": "d", "type": {"type": "long", "logicalType": "local-timestamp-micros"}},
+                    {"name": "e", "type": {"type": "map", "values": ["double", "null"]}},
+                    {"name": "f", "type": {"type": "array", "items": "e"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let arrow = avro_to_arrow_schema(&schema).unwrap();
+        let dict =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", dict.clone(), false),
+            Field::new("c", DataType::Decimal256(40, 2), false),
+            Field::new("d", DataType::Timestamp(TimeUnit::Microsecond, None), false),
+            Field::new_map(
+                "e",
+                "entries",
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Float64, true),
+                false,
+                false,
+            ),
+            Field::new_list("f", Field::new("item", dict, false), false),
+        ]);
+        assert_eq!(arrow, expected);
+
+        let err = avro_to_arrow_schema(&AvroSchema::Int).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Expected top-level Avro schema to be a record"
+        );
+
+        let union = AvroSchema::parse(
+            r#"{"type": "record", "name": "a", "fields": [{"name": "b", "type": ["int", "string"]}]}"#,
+        )
+        .unwrap();
+        let err = avro_to_arrow_schema(&union).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: Avro union [\"int\",\"string\"] is not supported"
+        );
+    }
+
+    #[test]
+    fn test_arrow_to_avro() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new(
+                "b",
+                DataType::Struct(Fields::from(vec![Field::new(
+                    "c",
+                    DataType::FixedSizeBinary(4),
+                    false,
+                )])),
+                false,
+            ),
+            Field::new(
+                "d",
+                DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+                false,
+            ),
+        ]);
+        let avro = arrow_to_avro_schema(&schema).unwrap();
+        assert_eq!(
+            avro.canonical_form(),
+            r#"{"name":"topLevelRecord","type":"record","fields":[{"name":"a","type":["null","int"]},{"name":"b","type":{"name":"topLevelRecord.b","type":"record","fields":[{"name":"c","type":{"name":"topLevelRecord.b.c","type":"fixed","size":4}}]}},{"name":"d","type":"long"}]}"#
+        );
+
+        // Round trip back to Arrow
+        let arrow = avro_to_arrow_schema(&avro).unwrap();
+        assert_eq!(arrow.field(0), schema.field(0));
+        assert_eq!(arrow.field(1), schema.field(1));
+        assert_eq!(
+            arrow.field(2).data_type(),
+            &DataType::Timestamp(TimeUnit::Millisecond, Some("+00:00".into()))
+        );
+
+        let schema = Schema::new(vec![Field::new("a", DataType::UInt64, false)]);
+        let err = arrow_to_avro_schema(&schema).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: Writing UInt64 to Avro is not supported"
+        );
+    }
+}
@@ -0,0 +1,161 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Compression codecs for the blocks of an Avro Object Container File
+
+use arrow_schema::ArrowError;
+
+/// The compression codec of an Avro Object Container File
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CompressionCodec {
+    /// Raw deflate, as defined by RFC 1951
+    Deflate,
+    /// Snappy, with each block followed by the big-endian CRC32 of the uncompressed data
+    Snappy,
+    /// Zstandard
+    Zstandard,
+}
+
+impl CompressionCodec {
+    /// Parses the codec from the `avro.codec` metadata, returning `None` for `null`
+    pub(crate) fn from_metadata(name: &[u8]) -> Result<Option<Self>, ArrowError> {
+        match name {
+            b"null" => Ok(None),
+            b"deflate" => Ok(Some(Self::Deflate)),
+            b"snappy" => Ok(Some(Self::Snappy)),
+            b"zstandard" => Ok(Some(Self::Zstandard)),
+            other => Err(ArrowError::ParseError(format!(
+                "Unsupported Avro codec {}",
+                String::from_utf8_lossy(other)
+            ))),
+        }
+    }
+
+    /// The value of the `avro.codec` metadata for this codec
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Self::Deflate => "deflate",
+            Self::Snappy => "snappy",
+            Self::Zstandard => "zstandard",
+        }
+    }
+
+    /// Decompress `block`
+    pub(crate) fn decompress(&self, block: &[u8]) -> Result<Vec<u8>, ArrowError> {
+        match self {
+            #[cfg(feature = "deflate")]
+            Self::Deflate => {
+                use std::io::Read;
+                let mut decoder = flate2::read::DeflateDecoder::new(block);
+                let mut out = Vec::new();
+                decoder.read_to_end(&mut out)?;
+                Ok(out)
+            }
+            #[cfg(feature = "snappy")]
+            Self::Snappy => {
+                if block.len() < 4 {
+                    return Err(ArrowError::ParseError(
+                        "Snappy block too short".to_string(),
+                    ));
+                }
+                let (block, crc) = block.split_at(block.len() - 4);
+                let out = snap::raw::Decoder::new()
+                    .decompress_vec(block)
+                    .map_err(|e| ArrowError::ExternalError(Box::new(e)))?;
+                let expected = u32::from_be_bytes(crc.try_into().unwrap());
+                if crc32fast::hash(&out) != expected {
+                    return Err(ArrowError::ParseError(
+                        "Snappy block checksum mismatch".to_string(),
+                    ));
+                }
+                Ok(out)
+            }
+            #[cfg(feature = "zstd")]
+            Self::Zstandard => {
+                let mut out = Vec::new();
+                zstd::stream::copy_decode(block, &mut out)?;
+                Ok(out)
+            }
+            #[allow(unreachable_patterns)]
+            _ => Err(self.disabled()),
+        }
+    }
+
+    /// Compress `block`
+    pub(crate) fn compress(&self, block: &[u8]) -> Result<Vec<u8>, ArrowError> {
+        match self {
+            #[cfg(feature = "deflate")]
+            Self::Deflate => {
+                use std::io::Write;
+                let mut encoder = flate2::write::DeflateEncoder::new(
+                    Vec::new(),
+                    flate2::Compression::default(),
+                );
+                encoder.write_all(block)?;
+                Ok(encoder.finish()?)
+            }
+            #[cfg(feature = "snappy")]
+            Self::Snappy => {
+                let mut out = snap::raw::Encoder::new()
+                    .compress_vec(block)
+                    .map_err(|e| ArrowError::ExternalError(Box::new(e)))?;
+                out.extend_from_slice(&crc32fast::hash(block).to_be_bytes());
+                Ok(out)
+            }
+            #[cfg(feature = "zstd")]
+            Self::Zstandard => Ok(zstd::stream::encode_all(block, 0)?),
+            #[allow(unreachable_patterns)]
+            _ => Err(self.disabled()),
+        }
+    }
+
+    #[allow(dead_code)]
+    fn disabled(&self) -> ArrowError {
+        ArrowError::InvalidArgumentError(format!(
+            "Avro codec {} requires the {} feature",
+            self.name(),
+            match self {
+                Self::Deflate => "deflate",
+                Self::Snappy => "snappy",
+                Self::Zstandard => "zstd",
+            }
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let data = b"hello hello hello hello world".repeat(10);
+        for codec in [
+            CompressionCodec::Deflate,
+            CompressionCodec::Snappy,
+            CompressionCodec::Zstandard,
+        ] {
+            let compressed = codec.compress(&data).unwrap();
+            assert!(compressed.len() < data.len());
+            assert_eq!(codec.decompress(&compressed).unwrap(), data);
+            assert_eq!(
+                CompressionCodec::from_metadata(codec.name().as_bytes()).unwrap(),
+                Some(codec)
+            );
+        }
+    }
+}
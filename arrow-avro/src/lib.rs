@@ -0,0 +1,67 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Convert data to / from the [Apache Arrow] memory format and [Apache Avro]
+//!
+//! Both Avro [Object Container Files] and [single-object encoded] messages are
+//! supported, see [`reader`] and [`writer`]
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{Int32Array, RecordBatch, StringArray};
+//! # use arrow_avro::reader::ReaderBuilder;
+//! # use arrow_avro::writer::WriterBuilder;
+//! let batch = RecordBatch::try_from_iter([
+//!     ("a", Arc::new(Int32Array::from(vec![Some(1), None])) as _),
+//!     ("b", Arc::new(StringArray::from(vec!["foo", "bar"])) as _),
+//! ])
+//! .unwrap();
+//!
+//! let mut writer = WriterBuilder::new().build(vec![], &batch.schema()).unwrap();
+//! writer.write(&batch).unwrap();
+//! let buf = writer.into_inner().unwrap();
+//!
+//! let mut reader = ReaderBuilder::new().build(buf.as_slice()).unwrap();
+//! let read = reader.next().unwrap().unwrap();
+//! assert_eq!(read, batch);
+//! ```
+//!
+//! [Apache Arrow]: https://arrow.apache.org
+//! [Apache Avro]: https://avro.apache.org
+//! [Object Container Files]: https://avro.apache.org/docs/1.11.1/specification/#object-container-files
+//! [single-object encoded]: https://avro.apache.org/docs/1.11.1/specification/#single-object-encoding
+
+#![warn(missing_docs)]
+
+pub mod codec;
+pub mod compression;
+pub mod reader;
+#[allow(missing_docs)]
+pub mod schema;
+pub mod writer;
+
+/// The magic bytes at the start of an Avro Object Container File
+const MAGIC: &[u8; 4] = b"Obj\x01";
+
+/// The marker bytes at the start of a single-object encoded message
+const SINGLE_OBJECT_MAGIC: [u8; 2] = [0xC3, 0x01];
+
+/// The metadata key of the writer schema in an Object Container File
+const SCHEMA_METADATA_KEY: &str = "avro.schema";
+
+/// The metadata key of the compression codec in an Object Container File
+const CODEC_METADATA_KEY: &str = "avro.codec";
@@ -0,0 +1,179 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow_schema::ArrowError;
+use std::io::Read;
+
+/// Decodes a zigzag encoded integer
+#[inline]
+fn zigzag(v: u64) -> i64 {
+    (v >> 1) as i64 ^ -((v & 1) as i64)
+}
+
+/// Reads a variable-length zigzag encoded long from `reader`
+///
+/// Returns `None` if `reader` is at EOF
+pub(crate) fn read_long<R: Read>(reader: &mut R) -> Result<Option<i64>, ArrowError> {
+    let mut value = 0_u64;
+    let mut byte = [0_u8; 1];
+    for shift in (0..64).step_by(7) {
+        if reader.read(&mut byte)? == 0 {
+            return match shift {
+                0 => Ok(None),
+                _ => Err(eof()),
+            };
+        }
+        value |= ((byte[0] & 0x7F) as u64) << shift;
+        if byte[0] & 0x80 == 0 {
+            return Ok(Some(zigzag(value)));
+        }
+    }
+    Err(ArrowError::ParseError("Avro varint overflow".to_string()))
+}
+
+fn eof() -> ArrowError {
+    ArrowError::ParseError("Unexpected EOF reading Avro data".to_string())
+}
+
+/// A cursor over the binary encoding of Avro values
+#[derive(Debug)]
+pub(crate) struct AvroCursor<'a> {
+    buf: &'a [u8],
+    start_len: usize,
+}
+
+impl<'a> AvroCursor<'a> {
+    pub(crate) fn new(buf: &'a [u8]) -> Self {
+        Self {
+            buf,
+            start_len: buf.len(),
+        }
+    }
+
+    /// Returns the number of bytes read so far
+    #[inline]
+    pub(crate) fn position(&self) -> usize {
+        self.start_len - self.buf.len()
+    }
+
+    #[inline]
+    pub(crate) fn get_bool(&mut self) -> Result<bool, ArrowError> {
+        match self.get_fixed(1)?[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            v => Err(ArrowError::ParseError(format!("Invalid Avro boolean {v}"))),
+        }
+    }
+
+    #[inline]
+    fn read_vlq(&mut self) -> Result<u64, ArrowError> {
+        let mut value = 0_u64;
+        for (idx, byte) in self.buf.iter().take(10).enumerate() {
+            value |= ((byte & 0x7F) as u64) << (idx * 7);
+            if byte & 0x80 == 0 {
+                self.buf = &self.buf[idx + 1..];
+                return Ok(value);
+            }
+        }
+        match self.buf.len() < 10 {
+            true => Err(eof()),
+            false => Err(ArrowError::ParseError("Avro varint overflow".to_string())),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn get_int(&mut self) -> Result<i32, ArrowError> {
+        let value = self.get_long()?;
+        value
+            .try_into()
+            .map_err(|_| ArrowError::ParseError(format!("Avro int {value} overflow")))
+    }
+
+    #[inline]
+    pub(crate) fn get_long(&mut self) -> Result<i64, ArrowError> {
+        self.read_vlq().map(zigzag)
+    }
+
+    /// Reads a length followed by that many bytes
+    pub(crate) fn get_bytes(&mut self) -> Result<&'a [u8], ArrowError> {
+        let len = self.get_long()?;
+        let len = usize::try_from(len).map_err(|_| {
+            ArrowError::ParseError(format!("Invalid Avro bytes length {len}"))
+        })?;
+        self.get_fixed(len)
+    }
+
+    #[inline]
+    pub(crate) fn get_float(&mut self) -> Result<f32, ArrowError> {
+        let bytes = self.get_fixed(4)?;
+        Ok(f32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    #[inline]
+    pub(crate) fn get_double(&mut self) -> Result<f64, ArrowError> {
+        let bytes = self.get_fixed(8)?;
+        Ok(f64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    /// Reads exactly `n` bytes
+    #[inline]
+    pub(crate) fn get_fixed(&mut self, n: usize) -> Result<&'a [u8], ArrowError> {
+        if self.buf.len() < n {
+            return Err(eof());
+        }
+        let (ret, remaining) = self.buf.split_at(n);
+        self.buf = remaining;
+        Ok(ret)
+    }
+
+    /// Reads the item count of the next block of an array or map, returning `0` at the end
+    pub(crate) fn get_block_count(&mut self) -> Result<usize, ArrowError> {
+        let count = self.get_long()?;
+        if count < 0 {
+            // A negative count is followed by the size of the block in bytes
+            self.get_long()?;
+        }
+        Ok(count.unsigned_abs() as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_varint() {
+        let data = [0x00, 0x01, 0x02, 0x7F, 0x80, 0x01, 0xFF, 0x01];
+        let mut cursor = AvroCursor::new(&data);
+        assert_eq!(cursor.get_long().unwrap(), 0);
+        assert_eq!(cursor.get_long().unwrap(), -1);
+        assert_eq!(cursor.get_long().unwrap(), 1);
+        assert_eq!(cursor.get_long().unwrap(), -64);
+        assert_eq!(cursor.get_long().unwrap(), 64);
+        assert_eq!(cursor.get_long().unwrap(), -128);
+        assert_eq!(cursor.position(), data.len());
+        cursor.get_long().unwrap_err();
+
+        let mut reader = &data[..];
+        let mut values = vec![];
+        while let Some(v) = read_long(&mut reader).unwrap() {
+            values.push(v);
+        }
+        assert_eq!(values, vec![0, -1, 1, -64, 64, -128]);
+        read_long(&mut &[0x80][..]).unwrap_err();
+    }
+}
@@ -0,0 +1,107 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::compression::CompressionCodec;
+use crate::reader::cursor::read_long;
+use crate::schema::Schema;
+use crate::{CODEC_METADATA_KEY, MAGIC, SCHEMA_METADATA_KEY};
+use arrow_schema::ArrowError;
+use std::collections::HashMap;
+use std::io::Read;
+
+/// The header of an Avro Object Container File
+#[derive(Debug, Clone)]
+pub struct Header {
+    metadata: HashMap<String, Vec<u8>>,
+    sync: [u8; 16],
+}
+
+impl Header {
+    /// Returns the file metadata
+    pub fn metadata(&self) -> &HashMap<String, Vec<u8>> {
+        &self.metadata
+    }
+
+    /// Returns the 16-byte sync marker following each block
+    pub fn sync(&self) -> [u8; 16] {
+        self.sync
+    }
+
+    /// Returns the writer schema
+    pub fn schema(&self) -> Result<Schema, ArrowError> {
+        let schema = self.metadata.get(SCHEMA_METADATA_KEY).ok_or_else(|| {
+            ArrowError::ParseError("Avro file is missing schema".to_string())
+        })?;
+        let schema = std::str::from_utf8(schema).map_err(|_| {
+            ArrowError::ParseError("Avro schema is not valid UTF-8".to_string())
+        })?;
+        Schema::parse(schema)
+    }
+
+    /// Returns the [`CompressionCodec`] of the blocks, if any
+    pub fn compression(&self) -> Result<Option<CompressionCodec>, ArrowError> {
+        match self.metadata.get(CODEC_METADATA_KEY) {
+            Some(codec) => CompressionCodec::from_metadata(codec),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Reads a length-prefixed byte sequence from `reader`
+fn read_bytes<R: Read>(reader: &mut R) -> Result<Vec<u8>, ArrowError> {
+    let len = read_long(reader)?.unwrap_or_default();
+    let len = usize::try_from(len)
+        .map_err(|_| ArrowError::ParseError(format!("Invalid Avro length {len}")))?;
+    let mut buf = vec![0; len];
+    reader.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+/// Reads the [`Header`] from the start of an Avro Object Container File
+pub(crate) fn read_header<R: Read>(reader: &mut R) -> Result<Header, ArrowError> {
+    let mut magic = [0_u8; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(ArrowError::ParseError(
+            "Avro file does not start with magic bytes".to_string(),
+        ));
+    }
+
+    let mut metadata = HashMap::new();
+    loop {
+        let count = read_long(reader)?.ok_or_else(|| {
+            ArrowError::ParseError("Unexpected EOF reading Avro header".to_string())
+        })?;
+        if count == 0 {
+            break;
+        }
+        if count < 0 {
+            // Skip the block size
+            read_long(reader)?;
+        }
+        for _ in 0..count.unsigned_abs() {
+            let key = String::from_utf8(read_bytes(reader)?).map_err(|_| {
+                ArrowError::ParseError("Avro metadata key is not valid UTF-8".to_string())
+            })?;
+            metadata.insert(key, read_bytes(reader)?);
+        }
+    }
+
+    let mut sync = [0_u8; 16];
+    reader.read_exact(&mut sync)?;
+    Ok(Header { metadata, sync })
+}
@@ -0,0 +1,271 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Read Avro data to Arrow
+//!
+//! [`Reader`] reads an Avro Object Container File, whereas [`Decoder`] decodes
+//! [single-object encoded] messages of a known writer schema
+//!
+//! [single-object encoded]: https://avro.apache.org/docs/1.11.1/specification/#single-object-encoding
+
+use crate::codec::avro_fields;
+use crate::compression::CompressionCodec;
+use crate::reader::cursor::read_long;
+use crate::reader::record::RecordDecoder;
+use crate::schema::Schema as AvroSchema;
+use crate::SINGLE_OBJECT_MAGIC;
+use arrow_array::{RecordBatch, RecordBatchReader};
+use arrow_schema::{ArrowError, SchemaRef};
+use std::io::BufRead;
+
+mod cursor;
+mod header;
+mod record;
+
+pub use header::Header;
+
+/// A builder for [`Reader`] and [`Decoder`]
+#[derive(Debug, Clone)]
+pub struct ReaderBuilder {
+    batch_size: usize,
+}
+
+impl Default for ReaderBuilder {
+    fn default() -> Self {
+        Self { batch_size: 1024 }
+    }
+}
+
+impl ReaderBuilder {
+    /// Create a new [`ReaderBuilder`] with the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of rows per [`RecordBatch`], defaults to 1024
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self {
+            batch_size: batch_size.max(1),
+        }
+    }
+
+    /// Create a [`Reader`] of the Avro Object Container File read from `reader`
+    ///
+    /// The Arrow schema is derived from the writer schema in the file header
+    pub fn build<R: BufRead>(self, mut reader: R) -> Result<Reader<R>, ArrowError> {
+        let header = header::read_header(&mut reader)?;
+        let avro_schema = header.schema()?;
+        let compression = header.compression()?;
+        let decoder = RecordDecoder::try_new(&avro_fields(&avro_schema)?)?;
+        Ok(Reader {
+            reader,
+            header,
+            avro_schema,
+            compression,
+            decoder,
+            batch_size: self.batch_size,
+            block: vec![],
+            block_offset: 0,
+            block_remaining: 0,
+            finished: false,
+        })
+    }
+
+    /// Create a [`Decoder`] of single-object encoded messages written with `schema`
+    pub fn build_decoder(self, schema: &AvroSchema) -> Result<Decoder, ArrowError> {
+        Ok(Decoder {
+            decoder: RecordDecoder::try_new(&avro_fields(schema)?)?,
+            fingerprint: schema.fingerprint(),
+            batch_size: self.batch_size,
+        })
+    }
+}
+
+/// Reads [`RecordBatch`] from an Avro Object Container File
+#[derive(Debug)]
+pub struct Reader<R> {
+    reader: R,
+    header: Header,
+    avro_schema: AvroSchema,
+    compression: Option<CompressionCodec>,
+    decoder: RecordDecoder,
+    batch_size: usize,
+
+    /// The decompressed data of the current block
+    block: Vec<u8>,
+    /// The offset of the next record in `block`
+    block_offset: usize,
+    /// The number of records remaining in `block`
+    block_remaining: usize,
+    finished: bool,
+}
+
+impl<R: BufRead> Reader<R> {
+    /// Returns the [`Header`] of the file
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the Avro schema the file was written with
+    pub fn avro_schema(&self) -> &AvroSchema {
+        &self.avro_schema
+    }
+
+    /// Reads the next block, returning `false` at EOF
+    fn read_block(&mut self) -> Result<bool, ArrowError> {
+        let count = match read_long(&mut self.reader)? {
+            Some(count) => count,
+            None => return Ok(false),
+        };
+        let size = read_long(&mut self.reader)?.unwrap_or(-1);
+        let (count, size) = match (usize::try_from(count), usize::try_from(size)) {
+            (Ok(count), Ok(size)) => (count, size),
+            _ => {
+                return Err(ArrowError::ParseError(format!(
+                    "Invalid Avro block of {count} records and {size} bytes"
+                )))
+            }
+        };
+
+        let mut data = vec![0; size];
+        self.reader.read_exact(&mut data)?;
+        let mut sync = [0_u8; 16];
+        self.reader.read_exact(&mut sync)?;
+        if sync != self.header.sync() {
+            return Err(ArrowError::ParseError(
+                "Avro block sync marker mismatch".to_string(),
+            ));
+        }
+
+        self.block = match &self.compression {
+            Some(codec) => codec.decompress(&data)?,
+            None => data,
+        };
+        self.block_offset = 0;
+        self.block_remaining = count;
+        Ok(true)
+    }
+
+    fn read(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        while !self.finished && self.decoder.len() < self.batch_size {
+            if self.block_remaining == 0 {
+                if !self.read_block()? {
+                    self.finished = true;
+                }
+                continue;
+            }
+
+            let to_read = self
+                .block_remaining
+                .min(self.batch_size - self.decoder.len());
+            let read = self
+                .decoder
+                .decode(&self.block[self.block_offset..], to_read)?;
+            self.block_offset += read;
+            self.block_remaining -= to_read;
+        }
+
+        match self.decoder.len() {
+            0 => Ok(None),
+            _ => self.decoder.flush().map(Some),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Reader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
+
+impl<R: BufRead> RecordBatchReader for Reader<R> {
+    fn schema(&self) -> SchemaRef {
+        self.decoder.schema().clone()
+    }
+}
+
+/// Decodes [single-object encoded] Avro messages to [`RecordBatch`]
+///
+/// Each message consists of the marker bytes `C3 01`, the little-endian
+/// [`AvroSchema::fingerprint`] of the writer schema, and the binary encoded record
+///
+/// [single-object encoded]: https://avro.apache.org/docs/1.11.1/specification/#single-object-encoding
+#[derive(Debug)]
+pub struct Decoder {
+    decoder: RecordDecoder,
+    fingerprint: u64,
+    batch_size: usize,
+}
+
+impl Decoder {
+    /// Returns the Arrow schema of the decoded [`RecordBatch`]
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.schema().clone()
+    }
+
+    /// Decode a single message, returning an error if it is not a complete
+    /// single-object encoding of a record with the expected schema
+    pub fn decode(&mut self, message: &[u8]) -> Result<(), ArrowError> {
+        if message.len() < 10 || message[..2] != SINGLE_OBJECT_MAGIC {
+            return Err(ArrowError::ParseError(
+                "Avro message is not single-object encoded".to_string(),
+            ));
+        }
+        let fingerprint = u64::from_le_bytes(message[2..10].try_into().unwrap());
+        if fingerprint != self.fingerprint {
+            return Err(ArrowError::ParseError(format!(
+                "Avro message schema fingerprint {fingerprint:#x} does not match {:#x}",
+                self.fingerprint
+            )));
+        }
+
+        let body = &message[10..];
+        let read = self.decoder.decode(body, 1)?;
+        if read != body.len() {
+            return Err(ArrowError::ParseError(format!(
+                "Avro message contained {} trailing bytes",
+                body.len() - read
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the number of buffered records
+    pub fn len(&self) -> usize {
+        self.decoder.len()
+    }
+
+    /// Returns true if there are no buffered records
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the number of buffered records has reached the batch size
+    pub fn capacity_reached(&self) -> bool {
+        self.len() >= self.batch_size
+    }
+
+    /// Flush the buffered records into a [`RecordBatch`], if any
+    pub fn flush(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        match self.decoder.len() {
+            0 => Ok(None),
+            _ => self.decoder.flush().map(Some),
+        }
+    }
+}
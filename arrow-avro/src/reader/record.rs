@@ -0,0 +1,445 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::codec::{AvroDataType, AvroField, Codec, Nullability};
+use crate::reader::cursor::AvroCursor;
+use arrow_array::types::{Int32Type, IntervalMonthDayNanoType};
+use arrow_array::{
+    make_array, ArrayRef, BinaryArray, BooleanArray, DictionaryArray,
+    FixedSizeBinaryArray, ListArray, MapArray, NullArray, PrimitiveArray, RecordBatch,
+    RecordBatchOptions, StringArray, StructArray,
+};
+use arrow_buffer::{
+    i256, ArrowNativeType, BooleanBufferBuilder, Buffer, NullBuffer, NullBufferBuilder,
+    OffsetBuffer, ScalarBuffer,
+};
+use arrow_data::ArrayDataBuilder;
+use arrow_schema::{ArrowError, DataType, FieldRef, Fields, Schema, SchemaRef};
+use std::sync::Arc;
+
+/// Decodes Avro records into a [`RecordBatch`]
+#[derive(Debug)]
+pub(crate) struct RecordDecoder {
+    schema: SchemaRef,
+    fields: Vec<Decoder>,
+    len: usize,
+}
+
+impl RecordDecoder {
+    pub(crate) fn try_new(fields: &[AvroField]) -> Result<Self, ArrowError> {
+        let schema = Schema::new(fields.iter().map(|f| f.field()).collect::<Vec<_>>());
+        Ok(Self {
+            schema: Arc::new(schema),
+            fields: fields
+                .iter()
+                .map(|f| Decoder::try_new(f.data_type()))
+                .collect::<Result<_, _>>()?,
+            len: 0,
+        })
+    }
+
+    pub(crate) fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// The number of records decoded but not yet flushed
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Decode `count` records from `buf`, returning the number of bytes read
+    pub(crate) fn decode(
+        &mut self,
+        buf: &[u8],
+        count: usize,
+    ) -> Result<usize, ArrowError> {
+        let mut cursor = AvroCursor::new(buf);
+        for _ in 0..count {
+            for field in &mut self.fields {
+                field.decode(&mut cursor)?;
+            }
+        }
+        self.len += count;
+        Ok(cursor.position())
+    }
+
+    /// Flush the decoded records into a [`RecordBatch`]
+    pub(crate) fn flush(&mut self) -> Result<RecordBatch, ArrowError> {
+        let columns = self
+            .fields
+            .iter_mut()
+            .map(|f| f.flush(None))
+            .collect::<Result<Vec<_>, _>>()?;
+        let options = RecordBatchOptions::new().with_row_count(Some(self.len));
+        self.len = 0;
+        RecordBatch::try_new_with_options(self.schema.clone(), columns, &options)
+    }
+}
+
+#[derive(Debug)]
+enum Decoder {
+    Null(usize),
+    Boolean(BooleanBufferBuilder),
+    /// An `int` decoded to the given [`DataType`]
+    Int32(DataType, Vec<i32>),
+    /// A `long` decoded to the given [`DataType`]
+    Int64(DataType, Vec<i64>),
+    Float32(Vec<f32>),
+    Float64(Vec<f64>),
+    Binary(Vec<i32>, Vec<u8>),
+    String(Vec<i32>, Vec<u8>),
+    Fixed(usize, Vec<u8>),
+    Decimal128(DataType, Option<usize>, Vec<i128>),
+    Decimal256(DataType, Option<usize>, Vec<i256>),
+    Interval(Vec<i128>),
+    Enum(Arc<[String]>, Vec<i32>),
+    List(FieldRef, Vec<i32>, Box<Decoder>),
+    /// The entries field, the offsets, the key offsets, the key data and the values
+    Map(FieldRef, Vec<i32>, Vec<i32>, Vec<u8>, Box<Decoder>),
+    Record(Fields, Vec<Decoder>),
+    Nullable(Nullability, NullBufferBuilder, Box<Decoder>),
+}
+
+impl Decoder {
+    fn try_new(data_type: &AvroDataType) -> Result<Self, ArrowError> {
+        let decoder = match data_type.codec() {
+            Codec::Null => Self::Null(0),
+            Codec::Boolean => Self::Boolean(BooleanBufferBuilder::new(1024)),
+            Codec::Int32 | Codec::Date32 | Codec::TimeMillis => {
+                Self::Int32(data_type.codec().data_type(), vec![])
+            }
+            Codec::Int64 | Codec::TimeMicros | Codec::Timestamp(_, _) => {
+                Self::Int64(data_type.codec().data_type(), vec![])
+            }
+            Codec::Float32 => Self::Float32(vec![]),
+            Codec::Float64 => Self::Float64(vec![]),
+            Codec::Binary => Self::Binary(vec![0], vec![]),
+            Codec::Utf8 => Self::String(vec![0], vec![]),
+            Codec::Fixed(size) => Self::Fixed(*size, vec![]),
+            Codec::Decimal(_, _, size) => match data_type.codec().data_type() {
+                d @ DataType::Decimal128(_, _) => Self::Decimal128(d, *size, vec![]),
+                d => Self::Decimal256(d, *size, vec![]),
+            },
+            Codec::Interval => Self::Interval(vec![]),
+            Codec::Enum(symbols) => Self::Enum(symbols.clone(), vec![]),
+            Codec::List(item) => {
+                let field = Arc::new(item.field_with_name("item"));
+                Self::List(field, vec![0], Box::new(Self::try_new(item)?))
+            }
+            Codec::Map(values) => {
+                let field = match data_type.codec().data_type() {
+                    DataType::Map(field, _) => field,
+                    _ => unreachable!(),
+                };
+                let values = Box::new(Self::try_new(values)?);
+                Self::Map(field, vec![0], vec![0], vec![], values)
+            }
+            Codec::Struct(fields) => {
+                let arrow_fields = fields.iter().map(|f| f.field()).collect();
+                let decoders = fields
+                    .iter()
+                    .map(|f| Self::try_new(f.data_type()))
+                    .collect::<Result<_, _>>()?;
+                Self::Record(arrow_fields, decoders)
+            }
+        };
+
+        Ok(match data_type.nullability() {
+            Some(nullability) => Self::Nullable(
+                nullability,
+                NullBufferBuilder::new(1024),
+                Box::new(decoder),
+            ),
+            None => decoder,
+        })
+    }
+
+    /// Append a null, or a placeholder value masked by a parent null
+    fn append_null(&mut self) {
+        match self {
+            Self::Null(count) => *count += 1,
+            Self::Boolean(b) => b.append(false),
+            Self::Int32(_, v) | Self::Enum(_, v) => v.push(0),
+            Self::Int64(_, v) => v.push(0),
+            Self::Float32(v) => v.push(0.),
+            Self::Float64(v) => v.push(0.),
+            Self::Binary(offsets, _) | Self::String(offsets, _) => {
+                offsets.push(*offsets.last().unwrap())
+            }
+            Self::Fixed(size, v) => v.resize(v.len() + *size, 0),
+            Self::Decimal128(_, _, v) | Self::Interval(v) => v.push(0),
+            Self::Decimal256(_, _, v) => v.push(i256::ZERO),
+            Self::List(_, offsets, _) | Self::Map(_, offsets, _, _, _) => {
+                offsets.push(*offsets.last().unwrap())
+            }
+            Self::Record(_, children) => {
+                children.iter_mut().for_each(|c| c.append_null())
+            }
+            Self::Nullable(_, nulls, inner) => {
+                nulls.append_null();
+                inner.append_null()
+            }
+        }
+    }
+
+    fn decode(&mut self, cursor: &mut AvroCursor<'_>) -> Result<(), ArrowError> {
+        match self {
+            Self::Null(count) => *count += 1,
+            Self::Boolean(b) => b.append(cursor.get_bool()?),
+            Self::Int32(_, v) => v.push(cursor.get_int()?),
+            Self::Int64(_, v) => v.push(cursor.get_long()?),
+            Self::Float32(v) => v.push(cursor.get_float()?),
+            Self::Float64(v) => v.push(cursor.get_double()?),
+            Self::Binary(offsets, values) | Self::String(offsets, values) => {
+                values.extend_from_slice(cursor.get_bytes()?);
+                offsets.push(to_offset(values.len())?);
+            }
+            Self::Fixed(size, v) => v.extend_from_slice(cursor.get_fixed(*size)?),
+            Self::Decimal128(_, size, v) => {
+                let raw = match size {
+                    Some(size) => cursor.get_fixed(*size)?,
+                    None => cursor.get_bytes()?,
+                };
+                v.push(i128::from_be_bytes(sign_extend(raw)?));
+            }
+            Self::Decimal256(_, size, v) => {
+                let raw = match size {
+                    Some(size) => cursor.get_fixed(*size)?,
+                    None => cursor.get_bytes()?,
+                };
+                v.push(i256::from_be_bytes(sign_extend(raw)?));
+            }
+            Self::Interval(v) => {
+                let raw = cursor.get_fixed(12)?;
+                let get = |idx: usize| {
+                    u32::from_le_bytes(raw[idx * 4..idx * 4 + 4].try_into().unwrap())
+                };
+                v.push(IntervalMonthDayNanoType::make_value(
+                    get(0) as i32,
+                    get(1) as i32,
+                    get(2) as i64 * 1_000_000,
+                ));
+            }
+            Self::Enum(symbols, v) => {
+                let idx = cursor.get_int()?;
+                if idx < 0 || idx as usize >= symbols.len() {
+                    return Err(ArrowError::ParseError(format!(
+                        "Avro enum index {idx} out of bounds for {} symbols",
+                        symbols.len()
+                    )));
+                }
+                v.push(idx);
+            }
+            Self::List(_, offsets, items) => {
+                let mut len = *offsets.last().unwrap() as usize;
+                loop {
+                    let count = cursor.get_block_count()?;
+                    if count == 0 {
+                        break;
+                    }
+                    for _ in 0..count {
+                        items.decode(cursor)?;
+                    }
+                    len += count;
+                }
+                offsets.push(to_offset(len)?);
+            }
+            Self::Map(_, offsets, key_offsets, keys, values) => {
+                let mut len = *offsets.last().unwrap() as usize;
+                loop {
+                    let count = cursor.get_block_count()?;
+                    if count == 0 {
+                        break;
+                    }
+                    for _ in 0..count {
+                        keys.extend_from_slice(cursor.get_bytes()?);
+                        key_offsets.push(to_offset(keys.len())?);
+                        values.decode(cursor)?;
+                    }
+                    len += count;
+                }
+                offsets.push(to_offset(len)?);
+            }
+            Self::Record(_, children) => {
+                for child in children {
+                    child.decode(cursor)?;
+                }
+            }
+            Self::Nullable(nullability, nulls, inner) => {
+                let null_idx = match nullability {
+                    Nullability::NullFirst => 0,
+                    Nullability::NullSecond => 1,
+                };
+                match cursor.get_long()? {
+                    idx if idx == null_idx => {
+                        nulls.append_null();
+                        inner.append_null();
+                    }
+                    idx if idx == 1 - null_idx => {
+                        nulls.append_non_null();
+                        inner.decode(cursor)?;
+                    }
+                    idx => {
+                        return Err(ArrowError::ParseError(format!(
+                            "Invalid Avro union index {idx}"
+                        )))
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self, nulls: Option<NullBuffer>) -> Result<ArrayRef, ArrowError> {
+        Ok(match self {
+            Self::Null(count) => Arc::new(NullArray::new(std::mem::take(count))),
+            Self::Boolean(b) => Arc::new(BooleanArray::new(b.finish(), nulls)),
+            Self::Int32(d, v) => flush_primitive(d, v, nulls)?,
+            Self::Int64(d, v) => flush_primitive(d, v, nulls)?,
+            Self::Float32(v) => flush_primitive(&DataType::Float32, v, nulls)?,
+            Self::Float64(v) => flush_primitive(&DataType::Float64, v, nulls)?,
+            Self::Decimal128(d, _, v) => flush_primitive(d, v, nulls)?,
+            Self::Decimal256(d, _, v) => flush_primitive(d, v, nulls)?,
+            Self::Interval(v) => flush_primitive(
+                &DataType::Interval(arrow_schema::IntervalUnit::MonthDayNano),
+                v,
+                nulls,
+            )?,
+            Self::Binary(offsets, values) => {
+                let offsets = flush_offsets(offsets);
+                let values = Buffer::from_vec(std::mem::take(values));
+                Arc::new(BinaryArray::try_new(offsets, values, nulls)?)
+            }
+            Self::String(offsets, values) => {
+                let offsets = flush_offsets(offsets);
+                let values = Buffer::from_vec(std::mem::take(values));
+                Arc::new(StringArray::try_new(offsets, values, nulls)?)
+            }
+            Self::Fixed(size, v) => {
+                let values = Buffer::from_vec(std::mem::take(v));
+                Arc::new(FixedSizeBinaryArray::try_new(*size as i32, values, nulls)?)
+            }
+            Self::Enum(symbols, v) => {
+                let keys = PrimitiveArray::<Int32Type>::new(
+                    ScalarBuffer::from(std::mem::take(v)),
+                    nulls,
+                );
+                let values = Arc::new(StringArray::from_iter_values(symbols.iter()));
+                Arc::new(DictionaryArray::try_new(keys, values)?)
+            }
+            Self::List(field, offsets, items) => {
+                let values = items.flush(None)?;
+                let offsets = flush_offsets(offsets);
+                Arc::new(ListArray::try_new(field.clone(), offsets, values, nulls)?)
+            }
+            Self::Map(field, offsets, key_offsets, keys, values) => {
+                let entry_fields = match field.data_type() {
+                    DataType::Struct(fields) => fields.clone(),
+                    _ => unreachable!(),
+                };
+                let keys = StringArray::try_new(
+                    flush_offsets(key_offsets),
+                    Buffer::from_vec(std::mem::take(keys)),
+                    None,
+                )?;
+                let values = values.flush(None)?;
+                let entries = StructArray::try_new(
+                    entry_fields,
+                    vec![Arc::new(keys), values],
+                    None,
+                )?;
+                let offsets = flush_offsets(offsets);
+                Arc::new(MapArray::try_new(
+                    field.clone(),
+                    offsets,
+                    entries,
+                    nulls,
+                    false,
+                )?)
+            }
+            Self::Record(fields, children) => {
+                let arrays = children
+                    .iter_mut()
+                    .map(|c| c.flush(None))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Arc::new(StructArray::try_new(fields.clone(), arrays, nulls)?)
+            }
+            Self::Nullable(_, nulls, inner) => inner.flush(nulls.finish())?,
+        })
+    }
+}
+
+fn to_offset(len: usize) -> Result<i32, ArrowError> {
+    i32::try_from(len).map_err(|_| ArrowError::ParseError("offset overflow".to_string()))
+}
+
+/// Take the offsets from `offsets`, leaving it containing a single zero offset
+fn flush_offsets(offsets: &mut Vec<i32>) -> OffsetBuffer<i32> {
+    let taken = std::mem::replace(offsets, vec![0]);
+    OffsetBuffer::new(ScalarBuffer::from(taken))
+}
+
+fn flush_primitive<T: ArrowNativeType>(
+    data_type: &DataType,
+    values: &mut Vec<T>,
+    nulls: Option<NullBuffer>,
+) -> Result<ArrayRef, ArrowError> {
+    let values = std::mem::take(values);
+    let data = ArrayDataBuilder::new(data_type.clone())
+        .len(values.len())
+        .add_buffer(Buffer::from_vec(values))
+        .nulls(nulls)
+        .build()?;
+    Ok(make_array(data))
+}
+
+/// Sign-extends a big-endian two's complement integer to `N` bytes
+fn sign_extend<const N: usize>(raw: &[u8]) -> Result<[u8; N], ArrowError> {
+    if raw.len() > N {
+        return Err(ArrowError::ParseError(format!(
+            "Avro decimal of {} bytes exceeds {N} bytes",
+            raw.len()
+        )));
+    }
+    let fill = match raw.first() {
+        Some(b) if b & 0x80 != 0 => 0xFF,
+        _ => 0,
+    };
+    let mut out = [fill; N];
+    out[N - raw.len()..].copy_from_slice(raw);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_extend() {
+        assert_eq!(i128::from_be_bytes(sign_extend(&[]).unwrap()), 0);
+        assert_eq!(i128::from_be_bytes(sign_extend(&[0xFF]).unwrap()), -1);
+        assert_eq!(
+            i128::from_be_bytes(sign_extend(&[0x00, 0xFF]).unwrap()),
+            255
+        );
+        assert_eq!(
+            i128::from_be_bytes(sign_extend(&[0xFF, 0x01]).unwrap()),
+            -255
+        );
+        sign_extend::<16>(&[0; 17]).unwrap_err();
+    }
+}
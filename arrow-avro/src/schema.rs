@@ -0,0 +1,673 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Avro schema definitions, see the [specification]
+//!
+//! [specification]: https://avro.apache.org/docs/1.11.1/specification/
+
+use arrow_schema::ArrowError;
+use serde_json::{Map, Value};
+use std::fmt::Write;
+
+/// An Avro schema
+#[derive(Debug, Clone, PartialEq)]
+pub enum Schema {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Record(Record),
+    Enum(Enum),
+    Array(Box<Schema>),
+    Map(Box<Schema>),
+    Fixed(Fixed),
+    Union(Vec<Schema>),
+    /// A reference to a named type, by its fullname, defined earlier in the schema
+    Ref(String),
+    /// A type annotated with a [`LogicalType`]
+    Logical(LogicalType, Box<Schema>),
+}
+
+/// An Avro record
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    /// The fullname of this record
+    pub name: String,
+    pub doc: Option<String>,
+    pub fields: Vec<RecordField>,
+}
+
+/// A field within an Avro [`Record`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordField {
+    pub name: String,
+    pub doc: Option<String>,
+    pub schema: Schema,
+    pub default: Option<Value>,
+}
+
+/// An Avro enumeration
+#[derive(Debug, Clone, PartialEq)]
+pub struct Enum {
+    /// The fullname of this enumeration
+    pub name: String,
+    pub symbols: Vec<String>,
+}
+
+/// An Avro fixed size binary
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fixed {
+    /// The fullname of this fixed
+    pub name: String,
+    pub size: usize,
+}
+
+/// The logical types defined by the specification
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LogicalType {
+    /// An arbitrary-precision signed decimal, annotating `bytes` or `fixed`
+    Decimal { precision: usize, scale: usize },
+    /// A UUID, annotating `string`
+    Uuid,
+    /// Days since the UNIX epoch, annotating `int`
+    Date,
+    /// Milliseconds after midnight, annotating `int`
+    TimeMillis,
+    /// Microseconds after midnight, annotating `long`
+    TimeMicros,
+    /// Milliseconds since the UNIX epoch in UTC, annotating `long`
+    TimestampMillis,
+    /// Microseconds since the UNIX epoch in UTC, annotating `long`
+    TimestampMicros,
+    /// Nanoseconds since the UNIX epoch in UTC, annotating `long`
+    TimestampNanos,
+    /// Milliseconds since the UNIX epoch in an unspecified timezone, annotating `long`
+    LocalTimestampMillis,
+    /// Microseconds since the UNIX epoch in an unspecified timezone, annotating `long`
+    LocalTimestampMicros,
+    /// Nanoseconds since the UNIX epoch in an unspecified timezone, annotating `long`
+    LocalTimestampNanos,
+    /// Months, days and milliseconds, annotating a `fixed` of size 12
+    Duration,
+}
+
+impl LogicalType {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Decimal { .. } => "decimal",
+            Self::Uuid => "uuid",
+            Self::Date => "date",
+            Self::TimeMillis => "time-millis",
+            Self::TimeMicros => "time-micros",
+            Self::TimestampMillis => "timestamp-millis",
+            Self::TimestampMicros => "timestamp-micros",
+            Self::TimestampNanos => "timestamp-nanos",
+            Self::LocalTimestampMillis => "local-timestamp-millis",
+            Self::LocalTimestampMicros => "local-timestamp-micros",
+            Self::LocalTimestampNanos => "local-timestamp-nanos",
+            Self::Duration => "duration",
+        }
+    }
+
+    /// Parses the logical type of `object`, returning `None` if it is unknown or
+    /// invalid for `base`, in which case it should be ignored
+    fn parse(object: &Map<String, Value>, base: &Schema) -> Option<Self> {
+        let name = object.get("logicalType")?.as_str()?;
+        let logical = match name {
+            "decimal" => {
+                let precision = object.get("precision")?.as_u64()? as usize;
+                let scale = match object.get("scale") {
+                    Some(scale) => scale.as_u64()? as usize,
+                    None => 0,
+                };
+                if precision == 0 || scale > precision {
+                    return None;
+                }
+                Self::Decimal { precision, scale }
+            }
+            "uuid" => Self::Uuid,
+            "date" => Self::Date,
+            "time-millis" => Self::TimeMillis,
+            "time-micros" => Self::TimeMicros,
+            "timestamp-millis" => Self::TimestampMillis,
+            "timestamp-micros" => Self::TimestampMicros,
+            "timestamp-nanos" => Self::TimestampNanos,
+            "local-timestamp-millis" => Self::LocalTimestampMillis,
+            "local-timestamp-micros" => Self::LocalTimestampMicros,
+            "local-timestamp-nanos" => Self::LocalTimestampNanos,
+            "duration" => Self::Duration,
+            _ => return None,
+        };
+
+        let valid = match (logical, base) {
+            (Self::Decimal { .. }, Schema::Bytes) => true,
+            (Self::Decimal { precision, .. }, Schema::Fixed(f)) => {
+                // The maximum precision that can be stored in `size` bytes
+                let max = ((2_f64.powi(8 * f.size as i32 - 1) - 1.).log10()).floor();
+                precision as f64 <= max
+            }
+            (Self::Uuid, Schema::String) => true,
+            (Self::Date | Self::TimeMillis, Schema::Int) => true,
+            (
+                Self::TimeMicros
+                | Self::TimestampMillis
+                | Self::TimestampMicros
+                | Self::TimestampNanos
+                | Self::LocalTimestampMillis
+                | Self::LocalTimestampMicros
+                | Self::LocalTimestampNanos,
+                Schema::Long,
+            ) => true,
+            (Self::Duration, Schema::Fixed(f)) => f.size == 12,
+            _ => false,
+        };
+        valid.then_some(logical)
+    }
+}
+
+impl Schema {
+    /// Parses an Avro schema from its JSON representation
+    pub fn parse(json: &str) -> Result<Self, ArrowError> {
+        let value: Value = serde_json::from_str(json).map_err(|e| {
+            ArrowError::ParseError(format!("Failed to parse Avro schema: {e}"))
+        })?;
+        Self::from_json(&value)
+    }
+
+    /// Parses an Avro schema from a [`Value`]
+    pub fn from_json(value: &Value) -> Result<Self, ArrowError> {
+        Parser::default().parse(value, None)
+    }
+
+    /// Returns the JSON representation of this schema
+    pub fn to_json(&self) -> Value {
+        let mut object = Map::new();
+        match self {
+            Self::Null
+            | Self::Boolean
+            | Self::Int
+            | Self::Long
+            | Self::Float
+            | Self::Double
+            | Self::Bytes
+            | Self::String => return Value::String(self.primitive_name().into()),
+            Self::Ref(name) => return Value::String(name.clone()),
+            Self::Union(variants) => {
+                return Value::Array(variants.iter().map(|v| v.to_json()).collect())
+            }
+            Self::Record(r) => {
+                object.insert("type".into(), "record".into());
+                object.insert("name".into(), r.name.clone().into());
+                if let Some(doc) = &r.doc {
+                    object.insert("doc".into(), doc.clone().into());
+                }
+                let fields = r.fields.iter().map(|f| {
+                    let mut field = Map::new();
+                    field.insert("name".into(), f.name.clone().into());
+                    if let Some(doc) = &f.doc {
+                        field.insert("doc".into(), doc.clone().into());
+                    }
+                    field.insert("type".into(), f.schema.to_json());
+                    if let Some(default) = &f.default {
+                        field.insert("default".into(), default.clone());
+                    }
+                    Value::Object(field)
+                });
+                object.insert("fields".into(), Value::Array(fields.collect()));
+            }
+            Self::Enum(e) => {
+                object.insert("type".into(), "enum".into());
+                object.insert("name".into(), e.name.clone().into());
+                let symbols = e.symbols.iter().map(|s| Value::String(s.clone()));
+                object.insert("symbols".into(), Value::Array(symbols.collect()));
+            }
+            Self::Array(items) => {
+                object.insert("type".into(), "array".into());
+                object.insert("items".into(), items.to_json());
+            }
+            Self::Map(values) => {
+                object.insert("type".into(), "map".into());
+                object.insert("values".into(), values.to_json());
+            }
+            Self::Fixed(f) => {
+                object.insert("type".into(), "fixed".into());
+                object.insert("name".into(), f.name.clone().into());
+                object.insert("size".into(), f.size.into());
+            }
+            Self::Logical(logical, base) => {
+                object = match base.to_json() {
+                    Value::Object(object) => object,
+                    other => {
+                        let mut object = Map::new();
+                        object.insert("type".into(), other);
+                        object
+                    }
+                };
+                object.insert("logicalType".into(), logical.name().into());
+                if let LogicalType::Decimal { precision, scale } = logical {
+                    object.insert("precision".into(), (*precision).into());
+                    object.insert("scale".into(), (*scale).into());
+                }
+            }
+        }
+        Value::Object(object)
+    }
+
+    /// Returns the [Parsing Canonical Form] of this schema
+    ///
+    /// [Parsing Canonical Form]: https://avro.apache.org/docs/1.11.1/specification/#parsing-canonical-form-for-schemas
+    pub fn canonical_form(&self) -> String {
+        let mut out = String::new();
+        self.write_canonical(&mut out);
+        out
+    }
+
+    /// Returns the 64-bit Rabin fingerprint of the [`Self::canonical_form`] of this schema
+    pub fn fingerprint(&self) -> u64 {
+        rabin_fingerprint(self.canonical_form().as_bytes())
+    }
+
+    fn primitive_name(&self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Boolean => "boolean",
+            Self::Int => "int",
+            Self::Long => "long",
+            Self::Float => "float",
+            Self::Double => "double",
+            Self::Bytes => "bytes",
+            Self::String => "string",
+            _ => unreachable!(),
+        }
+    }
+
+    fn write_canonical(&self, out: &mut String) {
+        let quote = |s: &str| Value::String(s.to_string()).to_string();
+        match self {
+            Self::Null
+            | Self::Boolean
+            | Self::Int
+            | Self::Long
+            | Self::Float
+            | Self::Double
+            | Self::Bytes
+            | Self::String => write!(out, "\"{}\"", self.primitive_name()).unwrap(),
+            Self::Ref(name) => out.push_str(&quote(name)),
+            Self::Logical(_, base) => base.write_canonical(out),
+            Self::Union(variants) => {
+                out.push('[');
+                for (idx, v) in variants.iter().enumerate() {
+                    if idx != 0 {
+                        out.push(',');
+                    }
+                    v.write_canonical(out);
+                }
+                out.push(']');
+            }
+            Self::Record(r) => {
+                write!(
+                    out,
+                    "{{\"name\":{},\"type\":\"record\",\"fields\":[",
+                    quote(&r.name)
+                )
+                .unwrap();
+                for (idx, f) in r.fields.iter().enumerate() {
+                    if idx != 0 {
+                        out.push(',');
+                    }
+                    write!(out, "{{\"name\":{},\"type\":", quote(&f.name)).unwrap();
+                    f.schema.write_canonical(out);
+                    out.push('}');
+                }
+                out.push_str("]}");
+            }
+            Self::Enum(e) => {
+                write!(
+                    out,
+                    "{{\"name\":{},\"type\":\"enum\",\"symbols\":[",
+                    quote(&e.name)
+                )
+                .unwrap();
+                for (idx, s) in e.symbols.iter().enumerate() {
+                    if idx != 0 {
+                        out.push(',');
+                    }
+                    out.push_str(&quote(s));
+                }
+                out.push_str("]}");
+            }
+            Self::Array(items) => {
+                out.push_str("{\"type\":\"array\",\"items\":");
+                items.write_canonical(out);
+                out.push('}');
+            }
+            Self::Map(values) => {
+                out.push_str("{\"type\":\"map\",\"values\":");
+                values.write_canonical(out);
+                out.push('}');
+            }
+            Self::Fixed(f) => write!(
+                out,
+                "{{\"name\":{},\"type\":\"fixed\",\"size\":{}}}",
+                quote(&f.name),
+                f.size
+            )
+            .unwrap(),
+        }
+    }
+}
+
+/// The empty fingerprint of the CRC-64-AVRO algorithm
+const EMPTY_FINGERPRINT: u64 = 0xc15d213aa4d7a795;
+
+/// Computes the 64-bit Rabin fingerprint of `data`, as defined by the specification
+pub fn rabin_fingerprint(data: &[u8]) -> u64 {
+    let mut table = [0_u64; 256];
+    for (i, entry) in table.iter_mut().enumerate() {
+        let mut fp = i as u64;
+        for _ in 0..8 {
+            fp = (fp >> 1) ^ (EMPTY_FINGERPRINT & (fp & 1).wrapping_neg());
+        }
+        *entry = fp;
+    }
+
+    data.iter().fold(EMPTY_FINGERPRINT, |fp, b| {
+        (fp >> 8) ^ table[((fp ^ *b as u64) & 0xff) as usize]
+    })
+}
+
+/// Parses [`Schema`] tracking the named types defined so far
+#[derive(Debug, Default)]
+struct Parser {
+    names: Vec<String>,
+}
+
+impl Parser {
+    fn parse(
+        &mut self,
+        value: &Value,
+        namespace: Option<&str>,
+    ) -> Result<Schema, ArrowError> {
+        match value {
+            Value::String(name) => self.parse_name(name, namespace),
+            Value::Array(variants) => {
+                let variants = variants
+                    .iter()
+                    .map(|v| self.parse(v, namespace))
+                    .collect::<Result<Vec<_>, _>>()?;
+                if variants.iter().any(|v| matches!(v, Schema::Union(_))) {
+                    return Err(invalid(
+                        "Unions may not immediately contain other unions",
+                    ));
+                }
+                Ok(Schema::Union(variants))
+            }
+            Value::Object(object) => {
+                let base = self.parse_object(object, namespace)?;
+                Ok(match LogicalType::parse(object, &base) {
+                    Some(logical) => Schema::Logical(logical, Box::new(base)),
+                    None => base,
+                })
+            }
+            _ => Err(invalid(format!("Unexpected Avro schema {value}"))),
+        }
+    }
+
+    fn parse_name(
+        &mut self,
+        name: &str,
+        namespace: Option<&str>,
+    ) -> Result<Schema, ArrowError> {
+        Ok(match name {
+            "null" => Schema::Null,
+            "boolean" => Schema::Boolean,
+            "int" => Schema::Int,
+            "long" => Schema::Long,
+            "float" => Schema::Float,
+            "double" => Schema::Double,
+            "bytes" => Schema::Bytes,
+            "string" => Schema::String,
+            name => {
+                let fullname = fullname(name, namespace);
+                if self.names.contains(&fullname) {
+                    Schema::Ref(fullname)
+                } else if self.names.iter().any(|n| n == name) {
+                    Schema::Ref(name.to_string())
+                } else {
+                    return Err(invalid(format!("Unknown Avro type {name}")));
+                }
+            }
+        })
+    }
+
+    /// Registers a named type returning its fullname and namespace
+    fn register(
+        &mut self,
+        object: &Map<String, Value>,
+        namespace: Option<&str>,
+    ) -> Result<(String, Option<String>), ArrowError> {
+        let name = get_str(object, "name")?;
+        let namespace = match object.get("namespace").and_then(|n| n.as_str()) {
+            Some(n) if !name.contains('.') => Some(n),
+            _ => namespace,
+        };
+        let fullname = fullname(name, namespace);
+        if self.names.contains(&fullname) {
+            return Err(invalid(format!("Duplicate Avro type {fullname}")));
+        }
+        self.names.push(fullname.clone());
+        let namespace = fullname.rsplit_once('.').map(|(ns, _)| ns.to_string());
+        Ok((fullname, namespace))
+    }
+
+    fn parse_object(
+        &mut self,
+        object: &Map<String, Value>,
+        namespace: Option<&str>,
+    ) -> Result<Schema, ArrowError> {
+        let doc = object.get("doc").and_then(|d| d.as_str()).map(String::from);
+        match object.get("type") {
+            Some(Value::String(t)) => match t.as_str() {
+                "record" | "error" => {
+                    let (name, namespace) = self.register(object, namespace)?;
+                    let fields = match object.get("fields") {
+                        Some(Value::Array(fields)) => fields,
+                        _ => return Err(invalid("Avro record must contain fields")),
+                    };
+                    let fields = fields
+                        .iter()
+                        .map(|field| {
+                            let field = match field {
+                                Value::Object(field) => field,
+                                _ => return Err(invalid("Avro field must be an object")),
+                            };
+                            let schema = field
+                                .get("type")
+                                .ok_or_else(|| invalid("Avro field must contain type"))?;
+                            Ok(RecordField {
+                                name: get_str(field, "name")?.to_string(),
+                                doc: field
+                                    .get("doc")
+                                    .and_then(|d| d.as_str())
+                                    .map(String::from),
+                                schema: self.parse(schema, namespace.as_deref())?,
+                                default: field.get("default").cloned(),
+                            })
+                        })
+                        .collect::<Result<_, ArrowError>>()?;
+                    Ok(Schema::Record(Record { name, doc, fields }))
+                }
+                "enum" => {
+                    let (name, _) = self.register(object, namespace)?;
+                    let symbols = match object.get("symbols") {
+                        Some(Value::Array(symbols)) => symbols
+                            .iter()
+                            .map(|s| match s {
+                                Value::String(s) => Ok(s.clone()),
+                                _ => Err(invalid("Avro enum symbols must be strings")),
+                            })
+                            .collect::<Result<_, _>>()?,
+                        _ => return Err(invalid("Avro enum must contain symbols")),
+                    };
+                    Ok(Schema::Enum(Enum { name, symbols }))
+                }
+                "fixed" => {
+                    let (name, _) = self.register(object, namespace)?;
+                    let size = object
+                        .get("size")
+                        .and_then(|s| s.as_u64())
+                        .ok_or_else(|| invalid("Avro fixed must contain size"))?;
+                    Ok(Schema::Fixed(Fixed {
+                        name,
+                        size: size as usize,
+                    }))
+                }
+                "array" => {
+                    let items = object
+                        .get("items")
+                        .ok_or_else(|| invalid("Avro array must contain items"))?;
+                    Ok(Schema::Array(Box::new(self.parse(items, namespace)?)))
+                }
+                "map" => {
+                    let values = object
+                        .get("values")
+                        .ok_or_else(|| invalid("Avro map must contain values"))?;
+                    Ok(Schema::Map(Box::new(self.parse(values, namespace)?)))
+                }
+                name => self.parse_name(name, namespace),
+            },
+            Some(other) => self.parse(other, namespace),
+            None => Err(invalid("Avro schema object must contain type")),
+        }
+    }
+}
+
+fn fullname(name: &str, namespace: Option<&str>) -> String {
+    match namespace {
+        Some(ns) if !name.contains('.') && !ns.is_empty() => format!("{ns}.{name}"),
+        _ => name.to_string(),
+    }
+}
+
+fn get_str<'a>(object: &'a Map<String, Value>, key: &str) -> Result<&'a str, ArrowError> {
+    object
+        .get(key)
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| invalid(format!("Avro schema object must contain {key}")))
+}
+
+fn invalid(msg: impl Into<String>) -> ArrowError {
+    ArrowError::SchemaError(msg.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let schema = Schema::parse(
+            r#"{
+                "type": "record",
+                "name": "test",
+                "namespace": "org.example",
+                "fields": [
+                    {"name": "a", "type": "long", "doc": "a field"},
+                    {"name": "b", "type": ["null", "string"], "default": null},
+                    {"name": "c", "type": {"type": "array", "items": {
+                        "type": "record", "name": "inner", "fields": [
+                            {"name": "d", "type": {"type": "int", "logicalType": "date"}}
+                        ]
+                    }}},
+                    {"name": "e", "type": "inner"},
+                    {"name": "f", "type": {
+                        "type": "fixed", "name": "dec", "size": 8,
+                        "logicalType": "decimal", "precision": 10, "scale": 2
+                    }},
+                    {"name": "g", "type": {"type": "enum", "name": "e", "symbols": ["A", "B"]}},
+                    {"name": "h", "type": {"type": "string", "logicalType": "unknown"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let record = match &schema {
+            Schema::Record(r) => r,
+            _ => unreachable!(),
+        };
+        assert_eq!(record.name, "org.example.test");
+        assert_eq!(record.fields[0].doc.as_deref(), Some("a field"));
+        assert_eq!(
+            record.fields[1].schema,
+            Schema::Union(vec![Schema::Null, Schema::String])
+        );
+        assert_eq!(record.fields[1].default, Some(Value::Null));
+        assert_eq!(
+            record.fields[3].schema,
+            Schema::Ref("org.example.inner".to_string())
+        );
+        assert_eq!(
+            record.fields[4].schema,
+            Schema::Logical(
+                LogicalType::Decimal {
+                    precision: 10,
+                    scale: 2
+                },
+                Box::new(Schema::Fixed(Fixed {
+                    name: "org.example.dec".to_string(),
+                    size: 8
+                }))
+            )
+        );
+        assert_eq!(record.fields[6].schema, Schema::String);
+
+        // Round trip through JSON
+        assert_eq!(Schema::from_json(&schema.to_json()).unwrap(), schema);
+
+        assert_eq!(
+            schema.canonical_form(),
+            r#"{"name":"org.example.test","type":"record","fields":[{"name":"a","type":"long"},{"name":"b","type":["null","string"]},{"name":"c","type":{"type":"array","items":{"name":"org.example.inner","type":"record","fields":[{"name":"d","type":"int"}]}}},{"name":"e","type":"org.example.inner"},{"name":"f","type":{"name":"org.example.dec","type":"fixed","size":8}},{"name":"g","type":{"name":"org.example.e","type":"enum","symbols":["A","B"]}},{"name":"h","type":"string"}]}"#
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Schema::parse(
+            r#"{"type": "record", "name": "a", "fields": [{"name": "b", "type": "c"}]}"#,
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "Schema error: Unknown Avro type c");
+
+        let err = Schema::parse(r#"[["int"], "long"]"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Unions may not immediately contain other unions"
+        );
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        // Fingerprints from the Java implementation
+        assert_eq!(Schema::Null.fingerprint(), 0x63dd24e7cc258f8a);
+        assert_eq!(Schema::Long.fingerprint(), 0xd054e14493f41db7);
+        assert_eq!(Schema::String.fingerprint(), 0x8f014872634503c7);
+    }
+}
@@ -0,0 +1,547 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Encodes arrow arrays directly to the Avro binary encoding
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{i256, ArrowNativeType, NullBuffer, OffsetBuffer, ScalarBuffer};
+use arrow_schema::{ArrowError, DataType, FieldRef, IntervalUnit, TimeUnit};
+
+/// Appends the zigzag variable-length encoding of `v` to `out`
+#[inline]
+pub(crate) fn write_long(out: &mut Vec<u8>, v: i64) {
+    let mut n = ((v << 1) ^ (v >> 63)) as u64;
+    while n >= 0x80 {
+        out.push((n as u8) | 0x80);
+        n >>= 7;
+    }
+    out.push(n as u8);
+}
+
+/// Appends the length of `bytes` followed by `bytes` to `out`
+#[inline]
+pub(crate) fn write_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    write_long(out, bytes.len() as i64);
+    out.extend_from_slice(bytes);
+}
+
+/// Encodes the values of an array as Avro
+pub(crate) trait Encoder {
+    /// Appends the Avro encoding of the value at `idx` to `out`
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>);
+}
+
+/// Returns an [`Encoder`] for `array`
+///
+/// If `nullable` each value is encoded as a union of `null` and the value type,
+/// otherwise `array` must not contain nulls
+pub(crate) fn make_encoder<'a>(
+    array: &'a dyn Array,
+    nullable: bool,
+    name: &str,
+) -> Result<Box<dyn Encoder + 'a>, ArrowError> {
+    if array.data_type() == &DataType::Null {
+        return Ok(Box::new(NullEncoder));
+    }
+
+    let (encoder, nulls) = make_encoder_impl(array)?;
+    match (nullable, nulls) {
+        (true, nulls) => Ok(Box::new(NullableEncoder { encoder, nulls })),
+        (false, Some(nulls)) if nulls.null_count() > 0 => {
+            Err(ArrowError::InvalidArgumentError(format!(
+                "Found null values in non-nullable Avro field {name}"
+            )))
+        }
+        (false, _) => Ok(encoder),
+    }
+}
+
+/// Returns an [`Encoder`] encoding each row of `columns` as an Avro record
+pub(crate) fn make_struct_encoder<'a>(
+    fields: &'a [FieldRef],
+    columns: &'a [ArrayRef],
+) -> Result<Box<dyn Encoder + 'a>, ArrowError> {
+    Ok(Box::new(StructEncoder::try_new(fields, columns)?))
+}
+
+macro_rules! long_helper {
+    ($t:ty, $array:ident) => {{
+        let array = $array.as_primitive::<$t>();
+        (
+            Box::new(LongEncoder(array.values())) as _,
+            array.nulls().cloned(),
+        )
+    }};
+}
+
+macro_rules! dictionary_helper {
+    ($t:ty, $array:ident) => {{
+        let array = $array.as_dictionary::<$t>();
+        let encoder = DictionaryEncoder::<$t> {
+            keys: array.keys().values(),
+            values: make_encoder_impl(array.values().as_ref())?.0,
+        };
+        (Box::new(encoder) as _, array.nulls().cloned())
+    }};
+}
+
+/// Returns an [`Encoder`] for `array` that assumes the value is valid, along
+/// with the null mask of `array`
+fn make_encoder_impl<'a>(
+    array: &'a dyn Array,
+) -> Result<(Box<dyn Encoder + 'a>, Option<NullBuffer>), ArrowError> {
+    let unsupported = || {
+        ArrowError::NotYetImplemented(format!(
+            "Writing {} to Avro is not supported",
+            array.data_type()
+        ))
+    };
+
+    Ok(match array.data_type() {
+        DataType::Boolean => {
+            let array = array.as_boolean();
+            (Box::new(BooleanEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::Int8 => long_helper!(Int8Type, array),
+        DataType::Int16 => long_helper!(Int16Type, array),
+        DataType::Int32 => long_helper!(Int32Type, array),
+        DataType::Int64 => long_helper!(Int64Type, array),
+        DataType::UInt8 => long_helper!(UInt8Type, array),
+        DataType::UInt16 => long_helper!(UInt16Type, array),
+        DataType::UInt32 => long_helper!(UInt32Type, array),
+        DataType::Date32 => long_helper!(Date32Type, array),
+        DataType::Time32(TimeUnit::Millisecond) => {
+            long_helper!(Time32MillisecondType, array)
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            long_helper!(Time64MicrosecondType, array)
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            long_helper!(TimestampMillisecondType, array)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            long_helper!(TimestampMicrosecondType, array)
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            long_helper!(TimestampNanosecondType, array)
+        }
+        DataType::Float32 => {
+            let array = array.as_primitive::<Float32Type>();
+            (
+                Box::new(FloatEncoder(array.values())) as _,
+                array.nulls().cloned(),
+            )
+        }
+        DataType::Float64 => {
+            let array = array.as_primitive::<Float64Type>();
+            (
+                Box::new(FloatEncoder(array.values())) as _,
+                array.nulls().cloned(),
+            )
+        }
+        DataType::Binary => {
+            let array = array.as_binary::<i32>();
+            (Box::new(BytesEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::LargeBinary => {
+            let array = array.as_binary::<i64>();
+            (Box::new(BytesEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::Utf8 => {
+            let array = array.as_string::<i32>();
+            (Box::new(BytesEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_string::<i64>();
+            (Box::new(BytesEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::FixedSizeBinary(_) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            (Box::new(FixedEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::Decimal128(_, _) => {
+            let array = array.as_primitive::<Decimal128Type>();
+            let encoder = DecimalEncoder(array.values());
+            (Box::new(encoder) as _, array.nulls().cloned())
+        }
+        DataType::Decimal256(_, _) => {
+            let array = array.as_primitive::<Decimal256Type>();
+            let encoder = DecimalEncoder(array.values());
+            (Box::new(encoder) as _, array.nulls().cloned())
+        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            let array = array.as_primitive::<IntervalMonthDayNanoType>();
+            let encoder = DurationEncoder::try_new(array)?;
+            (Box::new(encoder) as _, array.nulls().cloned())
+        }
+        DataType::List(f) => {
+            let array = array.as_list::<i32>();
+            let encoder = ListEncoder {
+                offsets: array.offsets(),
+                values: make_encoder(array.values().as_ref(), f.is_nullable(), f.name())?,
+            };
+            (Box::new(encoder) as _, array.nulls().cloned())
+        }
+        DataType::LargeList(f) => {
+            let array = array.as_list::<i64>();
+            let encoder = ListEncoder {
+                offsets: array.offsets(),
+                values: make_encoder(array.values().as_ref(), f.is_nullable(), f.name())?,
+            };
+            (Box::new(encoder) as _, array.nulls().cloned())
+        }
+        DataType::Map(_, _) => {
+            let array = array.as_map();
+            let keys = match array.keys().data_type() {
+                DataType::Utf8 => array.keys().as_string::<i32>(),
+                _ => return Err(unsupported()),
+            };
+            let value_field = match array.entries().data_type() {
+                DataType::Struct(fields) => &fields[1],
+                _ => unreachable!(),
+            };
+            let encoder = MapEncoder {
+                offsets: array.offsets(),
+                keys,
+                values: make_encoder(
+                    array.values().as_ref(),
+                    value_field.is_nullable(),
+                    value_field.name(),
+                )?,
+            };
+            (Box::new(encoder) as _, array.nulls().cloned())
+        }
+        DataType::Struct(fields) => {
+            let array = array.as_struct();
+            let encoder = StructEncoder::try_new(fields, array.columns())?;
+            (Box::new(encoder) as _, array.nulls().cloned())
+        }
+        DataType::Dictionary(k, v)
+            if matches!(v.as_ref(), DataType::Utf8 | DataType::LargeUtf8) =>
+        {
+            match k.as_ref() {
+                DataType::Int8 => dictionary_helper!(Int8Type, array),
+                DataType::Int16 => dictionary_helper!(Int16Type, array),
+                DataType::Int32 => dictionary_helper!(Int32Type, array),
+                DataType::Int64 => dictionary_helper!(Int64Type, array),
+                DataType::UInt8 => dictionary_helper!(UInt8Type, array),
+                DataType::UInt16 => dictionary_helper!(UInt16Type, array),
+                DataType::UInt32 => dictionary_helper!(UInt32Type, array),
+                DataType::UInt64 => dictionary_helper!(UInt64Type, array),
+                _ => return Err(unsupported()),
+            }
+        }
+        _ => return Err(unsupported()),
+    })
+}
+
+struct NullEncoder;
+
+impl Encoder for NullEncoder {
+    fn encode(&mut self, _idx: usize, _out: &mut Vec<u8>) {}
+}
+
+/// Encodes a union of `null` and the value type, with `null` first
+struct NullableEncoder<'a> {
+    encoder: Box<dyn Encoder + 'a>,
+    nulls: Option<NullBuffer>,
+}
+
+impl<'a> Encoder for NullableEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        match &self.nulls {
+            Some(n) if n.is_null(idx) => write_long(out, 0),
+            _ => {
+                write_long(out, 1);
+                self.encoder.encode(idx, out)
+            }
+        }
+    }
+}
+
+struct BooleanEncoder<'a>(&'a BooleanArray);
+
+impl<'a> Encoder for BooleanEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        out.push(self.0.value(idx) as u8)
+    }
+}
+
+/// An integer type that can be losslessly encoded as an Avro `int` or `long`
+trait AvroLong: ArrowNativeType {
+    fn to_long(self) -> i64;
+}
+
+macro_rules! avro_long {
+    ($($t:ty),*) => {
+        $(impl AvroLong for $t {
+            #[inline]
+            fn to_long(self) -> i64 {
+                self as i64
+            }
+        })*
+    };
+}
+
+avro_long!(i8, i16, i32, i64, u8, u16, u32);
+
+struct LongEncoder<'a, N: AvroLong>(&'a ScalarBuffer<N>);
+
+impl<'a, N: AvroLong> Encoder for LongEncoder<'a, N> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        write_long(out, self.0[idx].to_long())
+    }
+}
+
+/// A floating point type, encoded as little-endian bytes
+trait AvroFloat: ArrowNativeType {
+    fn write(self, out: &mut Vec<u8>);
+}
+
+impl AvroFloat for f32 {
+    fn write(self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes())
+    }
+}
+
+impl AvroFloat for f64 {
+    fn write(self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes())
+    }
+}
+
+struct FloatEncoder<'a, N: AvroFloat>(&'a ScalarBuffer<N>);
+
+impl<'a, N: AvroFloat> Encoder for FloatEncoder<'a, N> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        self.0[idx].write(out)
+    }
+}
+
+struct BytesEncoder<'a, T: ByteArrayType>(&'a GenericByteArray<T>);
+
+impl<'a, T: ByteArrayType> Encoder for BytesEncoder<'a, T> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        let value: &[u8] = self.0.value(idx).as_ref();
+        write_bytes(out, value)
+    }
+}
+
+struct FixedEncoder<'a>(&'a FixedSizeBinaryArray);
+
+impl<'a> Encoder for FixedEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.0.value(idx))
+    }
+}
+
+/// A decimal type, with a big-endian two's complement representation
+trait AvroDecimal: ArrowNativeType {
+    type Bytes: AsRef<[u8]>;
+
+    fn to_be_bytes(self) -> Self::Bytes;
+}
+
+impl AvroDecimal for i128 {
+    type Bytes = [u8; 16];
+
+    fn to_be_bytes(self) -> Self::Bytes {
+        i128::to_be_bytes(self)
+    }
+}
+
+impl AvroDecimal for i256 {
+    type Bytes = [u8; 32];
+
+    fn to_be_bytes(self) -> Self::Bytes {
+        i256::to_be_bytes(self)
+    }
+}
+
+/// Encodes decimals as the minimal big-endian two's complement representation
+struct DecimalEncoder<'a, N: AvroDecimal>(&'a ScalarBuffer<N>);
+
+impl<'a, N: AvroDecimal> Encoder for DecimalEncoder<'a, N> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        let bytes = self.0[idx].to_be_bytes();
+        write_bytes(out, minimal_twos_complement(bytes.as_ref()))
+    }
+}
+
+/// Strips the redundant sign-extension bytes from a big-endian two's complement integer
+fn minimal_twos_complement(bytes: &[u8]) -> &[u8] {
+    let mut start = 0;
+    while start + 1 < bytes.len() {
+        let redundant = match bytes[start] {
+            0x00 => bytes[start + 1] & 0x80 == 0,
+            0xFF => bytes[start + 1] & 0x80 != 0,
+            _ => false,
+        };
+        if !redundant {
+            break;
+        }
+        start += 1;
+    }
+    &bytes[start..]
+}
+
+/// Encodes intervals as a `duration`, a `fixed` of 12 bytes containing the
+/// little-endian unsigned months, days and milliseconds
+struct DurationEncoder<'a>(&'a ScalarBuffer<i128>);
+
+impl<'a> DurationEncoder<'a> {
+    fn try_new(
+        array: &'a PrimitiveArray<IntervalMonthDayNanoType>,
+    ) -> Result<Self, ArrowError> {
+        for v in array.iter().flatten() {
+            let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(v);
+            let millis = nanos / 1_000_000;
+            if months < 0
+                || days < 0
+                || nanos % 1_000_000 != 0
+                || u32::try_from(millis).is_err()
+            {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Interval of {months} months, {days} days and {nanos} nanoseconds cannot be represented as an Avro duration"
+                )));
+            }
+        }
+        Ok(Self(array.values()))
+    }
+}
+
+impl<'a> Encoder for DurationEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(self.0[idx]);
+        out.extend_from_slice(&(months as u32).to_le_bytes());
+        out.extend_from_slice(&(days as u32).to_le_bytes());
+        out.extend_from_slice(&((nanos / 1_000_000) as u32).to_le_bytes());
+    }
+}
+
+/// Encodes a list as a single block of items followed by the terminating empty block
+struct ListEncoder<'a, O: OffsetSizeTrait> {
+    offsets: &'a OffsetBuffer<O>,
+    values: Box<dyn Encoder + 'a>,
+}
+
+impl<'a, O: OffsetSizeTrait> Encoder for ListEncoder<'a, O> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        let start = self.offsets[idx].as_usize();
+        let end = self.offsets[idx + 1].as_usize();
+        if end > start {
+            write_long(out, (end - start) as i64);
+            for i in start..end {
+                self.values.encode(i, out);
+            }
+        }
+        write_long(out, 0);
+    }
+}
+
+struct MapEncoder<'a> {
+    offsets: &'a OffsetBuffer<i32>,
+    keys: &'a StringArray,
+    values: Box<dyn Encoder + 'a>,
+}
+
+impl<'a> Encoder for MapEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        let start = self.offsets[idx].as_usize();
+        let end = self.offsets[idx + 1].as_usize();
+        if end > start {
+            write_long(out, (end - start) as i64);
+            for i in start..end {
+                write_bytes(out, self.keys.value(i).as_bytes());
+                self.values.encode(i, out);
+            }
+        }
+        write_long(out, 0);
+    }
+}
+
+struct StructEncoder<'a> {
+    encoders: Vec<Box<dyn Encoder + 'a>>,
+}
+
+impl<'a> StructEncoder<'a> {
+    fn try_new(
+        fields: &'a [FieldRef],
+        columns: &'a [ArrayRef],
+    ) -> Result<Self, ArrowError> {
+        let encoders = fields
+            .iter()
+            .zip(columns)
+            .map(|(f, c)| make_encoder(c.as_ref(), f.is_nullable(), f.name()))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { encoders })
+    }
+}
+
+impl<'a> Encoder for StructEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        for encoder in &mut self.encoders {
+            encoder.encode(idx, out);
+        }
+    }
+}
+
+struct DictionaryEncoder<'a, K: ArrowDictionaryKeyType> {
+    keys: &'a ScalarBuffer<K::Native>,
+    values: Box<dyn Encoder + 'a>,
+}
+
+impl<'a, K: ArrowDictionaryKeyType> Encoder for DictionaryEncoder<'a, K> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        self.values.encode(self.keys[idx].as_usize(), out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_long() {
+        let mut out = vec![];
+        for v in [0, -1, 1, -64, 64, -128, i64::MAX, i64::MIN] {
+            write_long(&mut out, v);
+        }
+        assert_eq!(&out[..8], &[0x00, 0x01, 0x02, 0x7F, 0x80, 0x01, 0xFF, 0x01]);
+        assert_eq!(out.len(), 8 + 10 + 10);
+    }
+
+    #[test]
+    fn test_minimal_twos_complement() {
+        let cases: [(i128, &[u8]); 6] = [
+            (0, &[0x00]),
+            (-1, &[0xFF]),
+            (127, &[0x7F]),
+            (128, &[0x00, 0x80]),
+            (-128, &[0x80]),
+            (-129, &[0xFF, 0x7F]),
+        ];
+        for (v, expected) in cases {
+            assert_eq!(minimal_twos_complement(&v.to_be_bytes()), expected);
+        }
+    }
+}
@@ -0,0 +1,539 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Write Arrow data to Avro
+//!
+//! [`Writer`] writes an Avro Object Container File, whereas [`Encoder`] encodes
+//! each row as a [single-object encoded] message
+//!
+//! The Avro schema is derived from the Arrow schema with [`arrow_to_avro_schema`]
+//!
+//! [single-object encoded]: https://avro.apache.org/docs/1.11.1/specification/#single-object-encoding
+
+use crate::codec::arrow_to_avro_schema;
+use crate::compression::CompressionCodec;
+use crate::schema::Schema as AvroSchema;
+use crate::writer::encoder::{make_struct_encoder, write_bytes, write_long};
+use crate::{CODEC_METADATA_KEY, MAGIC, SCHEMA_METADATA_KEY, SINGLE_OBJECT_MAGIC};
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, Schema, SchemaRef};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io::Write;
+use std::sync::Arc;
+
+mod encoder;
+
+/// A builder for [`Writer`] and [`Encoder`]
+#[derive(Debug, Clone, Default)]
+pub struct WriterBuilder {
+    compression: Option<CompressionCodec>,
+}
+
+impl WriterBuilder {
+    /// Create a new [`WriterBuilder`] with the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the [`CompressionCodec`] of the file blocks, defaults to no compression
+    pub fn with_compression(self, compression: Option<CompressionCodec>) -> Self {
+        Self { compression }
+    }
+
+    /// Create a [`Writer`] of an Avro Object Container File to `writer`
+    ///
+    /// The file header is written immediately
+    pub fn build<W: Write>(
+        self,
+        mut writer: W,
+        schema: &Schema,
+    ) -> Result<Writer<W>, ArrowError> {
+        let avro_schema = arrow_to_avro_schema(schema)?;
+        let sync = sync_marker();
+
+        let mut header = MAGIC.to_vec();
+        let codec = self.compression.map(|c| c.name()).unwrap_or("null");
+        write_long(&mut header, 2);
+        write_bytes(&mut header, SCHEMA_METADATA_KEY.as_bytes());
+        write_bytes(&mut header, avro_schema.to_json().to_string().as_bytes());
+        write_bytes(&mut header, CODEC_METADATA_KEY.as_bytes());
+        write_bytes(&mut header, codec.as_bytes());
+        write_long(&mut header, 0);
+        header.extend_from_slice(&sync);
+        writer.write_all(&header)?;
+
+        Ok(Writer {
+            writer,
+            schema: Arc::new(schema.clone()),
+            avro_schema,
+            compression: self.compression,
+            sync,
+            buffer: vec![],
+        })
+    }
+
+    /// Create an [`Encoder`] of single-object encoded messages
+    pub fn build_encoder(self, schema: &Schema) -> Result<Encoder, ArrowError> {
+        let avro_schema = arrow_to_avro_schema(schema)?;
+        let mut prefix = [0; 10];
+        prefix[..2].copy_from_slice(&SINGLE_OBJECT_MAGIC);
+        prefix[2..].copy_from_slice(&avro_schema.fingerprint().to_le_bytes());
+        Ok(Encoder {
+            schema: Arc::new(schema.clone()),
+            avro_schema,
+            prefix,
+        })
+    }
+}
+
+/// Returns a random sync marker
+fn sync_marker() -> [u8; 16] {
+    let state = RandomState::new();
+    let mut sync = [0; 16];
+    for (idx, chunk) in sync.chunks_mut(8).enumerate() {
+        let mut hasher = state.build_hasher();
+        hasher.write_usize(idx);
+        chunk.copy_from_slice(&hasher.finish().to_le_bytes());
+    }
+    sync
+}
+
+fn check_schema(expected: &SchemaRef, batch: &RecordBatch) -> Result<(), ArrowError> {
+    if batch.schema().fields() != expected.fields() {
+        return Err(ArrowError::SchemaError(
+            "RecordBatch schema does not match the writer schema".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Writes [`RecordBatch`] to an Avro Object Container File, with a block per batch
+#[derive(Debug)]
+pub struct Writer<W: Write> {
+    writer: W,
+    schema: SchemaRef,
+    avro_schema: AvroSchema,
+    compression: Option<CompressionCodec>,
+    sync: [u8; 16],
+    /// Scratch space for the encoded block
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> Writer<W> {
+    /// Returns the Avro schema the file is written with
+    pub fn avro_schema(&self) -> &AvroSchema {
+        &self.avro_schema
+    }
+
+    /// Write a single [`RecordBatch`] as a block
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        check_schema(&self.schema, batch)?;
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        self.buffer.clear();
+        let mut encoder = make_struct_encoder(self.schema.fields(), batch.columns())?;
+        for idx in 0..batch.num_rows() {
+            encoder.encode(idx, &mut self.buffer);
+        }
+
+        let compressed;
+        let data = match &self.compression {
+            Some(codec) => {
+                compressed = codec.compress(&self.buffer)?;
+                &compressed
+            }
+            None => &self.buffer,
+        };
+
+        let mut header = Vec::with_capacity(20);
+        write_long(&mut header, batch.num_rows() as i64);
+        write_long(&mut header, data.len() as i64);
+        self.writer.write_all(&header)?;
+        self.writer.write_all(data)?;
+        self.writer.write_all(&self.sync)?;
+        Ok(())
+    }
+
+    /// Flush the underlying writer
+    pub fn finish(&mut self) -> Result<(), ArrowError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Flush and return the underlying writer
+    pub fn into_inner(mut self) -> Result<W, ArrowError> {
+        self.finish()?;
+        Ok(self.writer)
+    }
+}
+
+/// Encodes the rows of [`RecordBatch`] as [single-object encoded] Avro messages
+///
+/// [single-object encoded]: https://avro.apache.org/docs/1.11.1/specification/#single-object-encoding
+#[derive(Debug)]
+pub struct Encoder {
+    schema: SchemaRef,
+    avro_schema: AvroSchema,
+    /// The marker bytes followed by the schema fingerprint
+    prefix: [u8; 10],
+}
+
+impl Encoder {
+    /// Returns the Avro schema the messages are written with
+    pub fn avro_schema(&self) -> &AvroSchema {
+        &self.avro_schema
+    }
+
+    /// Encode each row of `batch` as a message
+    pub fn encode(&self, batch: &RecordBatch) -> Result<Vec<Vec<u8>>, ArrowError> {
+        check_schema(&self.schema, batch)?;
+        let mut encoder = make_struct_encoder(self.schema.fields(), batch.columns())?;
+        Ok((0..batch.num_rows())
+            .map(|idx| {
+                let mut out = self.prefix.to_vec();
+                encoder.encode(idx, &mut out);
+                out
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderBuilder;
+    use arrow_array::builder::{Int32Builder, MapBuilder, MapFieldNames, StringBuilder};
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::*;
+    use arrow_array::*;
+    use arrow_buffer::i256;
+    use arrow_schema::{DataType, Field, Fields};
+    use arrow_select::concat::concat_batches;
+
+    fn round_trip(
+        batch: &RecordBatch,
+        compression: Option<CompressionCodec>,
+    ) -> RecordBatch {
+        let mut writer = WriterBuilder::new()
+            .with_compression(compression)
+            .build(vec![], &batch.schema())
+            .unwrap();
+        writer.write(&batch.slice(0, 2)).unwrap();
+        writer.write(&batch.slice(2, batch.num_rows() - 2)).unwrap();
+        let buf = writer.into_inner().unwrap();
+
+        let reader = ReaderBuilder::new()
+            .with_batch_size(3)
+            .build(buf.as_slice())
+            .unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert!(batches.iter().all(|b| b.num_rows() <= 3));
+        concat_batches(&batches[0].schema(), &batches).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let names = MapFieldNames {
+            entry: "entries".to_string(),
+            key: "key".to_string(),
+            value: "value".to_string(),
+        };
+        let mut map =
+            MapBuilder::new(Some(names), StringBuilder::new(), Int32Builder::new());
+        for i in 0..5 {
+            for j in 0..i {
+                map.keys().append_value(format!("k{j}"));
+                map.values().append_option((j % 2 == 0).then_some(j));
+            }
+            map.append(i != 3).unwrap();
+        }
+        let map = map.finish();
+
+        let list = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4)]),
+            Some(vec![None, None, Some(7)]),
+        ]);
+
+        let struct_fields = Fields::from(vec![
+            Field::new("x", DataType::Float64, false),
+            Field::new("y", DataType::Utf8, true),
+        ]);
+        let structs = StructArray::new(
+            struct_fields.clone(),
+            vec![
+                Arc::new(Float64Array::from(vec![1.5, 0., 2.5, 3.5, -1.])),
+                Arc::new(StringArray::from(vec![
+                    Some("a"),
+                    None,
+                    None,
+                    Some("d"),
+                    Some(""),
+                ])),
+            ],
+            Some(vec![true, false, true, true, true].into()),
+        );
+
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("bool", DataType::Boolean, true),
+                Field::new("int", DataType::Int32, false),
+                Field::new("long", DataType::Int64, true),
+                Field::new("float", DataType::Float32, false),
+                Field::new("binary", DataType::Binary, true),
+                Field::new("fixed", DataType::FixedSizeBinary(2), true),
+                Field::new("null", DataType::Null, true),
+                Field::new("list", list.data_type().clone(), true),
+                Field::new("struct", DataType::Struct(struct_fields), true),
+                Field::new("map", map.data_type().clone(), true),
+            ])),
+            vec![
+                Arc::new(BooleanArray::from(vec![
+                    Some(true),
+                    None,
+                    Some(false),
+                    Some(true),
+                    None,
+                ])),
+                Arc::new(Int32Array::from(vec![1, -2, 3, i32::MAX, i32::MIN])),
+                Arc::new(Int64Array::from(vec![
+                    Some(i64::MIN),
+                    None,
+                    Some(0),
+                    Some(i64::MAX),
+                    Some(-1),
+                ])),
+                Arc::new(Float32Array::from(vec![1., 2.5, f32::MAX, 0., -0.5])),
+                Arc::new(BinaryArray::from_opt_vec(vec![
+                    Some(b"foo"),
+                    None,
+                    Some(b""),
+                    Some(b"\x00\xFF"),
+                    None,
+                ])),
+                Arc::new(
+                    FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+                        vec![Some(b"ab"), None, Some(b"cd"), Some(b"ef"), Some(b"gh")]
+                            .into_iter(),
+                        2,
+                    )
+                    .unwrap(),
+                ),
+                Arc::new(NullArray::new(5)),
+                Arc::new(list),
+                Arc::new(structs),
+                Arc::new(map),
+            ],
+        )
+        .unwrap();
+
+        for compression in [
+            None,
+            Some(CompressionCodec::Deflate),
+            Some(CompressionCodec::Snappy),
+            Some(CompressionCodec::Zstandard),
+        ] {
+            let read = round_trip(&batch, compression);
+            assert_eq!(read.num_rows(), batch.num_rows());
+            for (a, b) in read.columns().iter().zip(batch.columns()) {
+                assert_eq!(a.as_ref(), b.as_ref());
+            }
+        }
+    }
+
+    #[test]
+    fn test_logical_types() {
+        let interval = IntervalMonthDayNanoType::make_value(1, 2, 3_000_000);
+        let batch = RecordBatch::try_from_iter([
+            (
+                "date",
+                Arc::new(Date32Array::from(vec![0, 19000, -1, 1])) as ArrayRef,
+            ),
+            (
+                "time_ms",
+                Arc::new(Time32MillisecondArray::from(vec![0, 1, 2, 86_399_999])) as _,
+            ),
+            (
+                "time_us",
+                Arc::new(Time64MicrosecondArray::from(vec![0, 1, 2, 3])) as _,
+            ),
+            (
+                "ts_utc",
+                Arc::new(
+                    TimestampMicrosecondArray::from(vec![1, 2, 3, 4])
+                        .with_timezone("+00:00"),
+                ) as _,
+            ),
+            (
+                "ts_local",
+                Arc::new(TimestampNanosecondArray::from(vec![-1, 0, 1, i64::MAX])) as _,
+            ),
+            (
+                "dec128",
+                Arc::new(
+                    Decimal128Array::from(vec![0, -1, 12345, i128::MAX / 10])
+                        .with_precision_and_scale(38, 2)
+                        .unwrap(),
+                ) as _,
+            ),
+            (
+                "dec256",
+                Arc::new(
+                    Decimal256Array::from(vec![
+                        i256::ZERO,
+                        i256::MINUS_ONE,
+                        i256::from_i128(i128::MIN),
+                        i256::from_i128(255),
+                    ])
+                    .with_precision_and_scale(50, 0)
+                    .unwrap(),
+                ) as _,
+            ),
+            (
+                "interval",
+                Arc::new(IntervalMonthDayNanoArray::from(vec![0, interval, 0, 0])) as _,
+            ),
+            (
+                "dict",
+                Arc::new(
+                    vec!["a", "b", "a", "c"]
+                        .into_iter()
+                        .collect::<DictionaryArray<Int8Type>>(),
+                ) as _,
+            ),
+        ])
+        .unwrap();
+
+        let read = round_trip(&batch, None);
+        for (idx, (a, b)) in read.columns().iter().zip(batch.columns()).enumerate() {
+            match idx {
+                // Dictionaries are written as strings
+                8 => {
+                    let a = a.as_string::<i32>();
+                    let expected: Vec<_> =
+                        vec!["a", "b", "a", "c"].into_iter().map(Some).collect();
+                    assert_eq!(a.iter().collect::<Vec<_>>(), expected);
+                }
+                _ => assert_eq!(a.as_ref(), b.as_ref(), "column {idx}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_write_errors() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let nullable = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        let batch = RecordBatch::try_new(
+            Arc::new(nullable.clone()),
+            vec![Arc::new(Int32Array::from(vec![Some(1), None]))],
+        )
+        .unwrap();
+
+        let mut writer = WriterBuilder::new().build(vec![], &schema).unwrap();
+        let err = writer.write(&batch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: RecordBatch schema does not match the writer schema"
+        );
+
+        // Nullable field in the schema with nulls in a non-nullable struct child
+        let child = Field::new("b", DataType::Int32, false);
+        let structs = StructArray::new(
+            Fields::from(vec![child.clone()]),
+            vec![Arc::new(Int32Array::from(vec![Some(1), None]))],
+            Some(vec![true, false].into()),
+        );
+        let batch =
+            RecordBatch::try_from_iter([("a", Arc::new(structs) as ArrayRef)]).unwrap();
+        let mut writer = WriterBuilder::new().build(vec![], &batch.schema()).unwrap();
+        let err = writer.write(&batch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Found null values in non-nullable Avro field b"
+        );
+
+        let interval = IntervalMonthDayNanoType::make_value(0, 0, 1);
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(IntervalMonthDayNanoArray::from(vec![interval])) as ArrayRef,
+        )])
+        .unwrap();
+        let mut writer = WriterBuilder::new().build(vec![], &batch.schema()).unwrap();
+        let err = writer.write(&batch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Interval of 0 months, 0 days and 1 nanoseconds cannot be represented as an Avro duration"
+        );
+    }
+
+    #[test]
+    fn test_single_object() {
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef),
+            (
+                "b",
+                Arc::new(StringArray::from(vec![Some("x"), None, Some("z")])) as _,
+            ),
+        ])
+        .unwrap();
+
+        let encoder = WriterBuilder::new().build_encoder(&batch.schema()).unwrap();
+        let messages = encoder.encode(&batch).unwrap();
+        assert_eq!(messages.len(), 3);
+        let fingerprint = encoder.avro_schema().fingerprint();
+        for message in &messages {
+            assert_eq!(&message[..2], &[0xC3, 0x01]);
+            assert_eq!(message[2..10], fingerprint.to_le_bytes());
+        }
+
+        let mut decoder = ReaderBuilder::new()
+            .with_batch_size(2)
+            .build_decoder(encoder.avro_schema())
+            .unwrap();
+        for message in &messages[..2] {
+            decoder.decode(message).unwrap();
+        }
+        assert!(decoder.capacity_reached());
+        let first = decoder.flush().unwrap().unwrap();
+        assert_eq!(first, batch.slice(0, 2));
+        assert!(decoder.flush().unwrap().is_none());
+
+        decoder.decode(&messages[2]).unwrap();
+        assert_eq!(decoder.flush().unwrap().unwrap(), batch.slice(2, 1));
+
+        let mut truncated = messages[0].clone();
+        truncated.pop();
+        decoder.decode(&truncated).unwrap_err();
+
+        let mut extended = messages[0].clone();
+        extended.push(0);
+        let err = decoder.decode(&extended).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Avro message contained 1 trailing bytes"
+        );
+
+        let mut other = messages[0].clone();
+        other[2] ^= 1;
+        decoder.decode(&other).unwrap_err();
+    }
+}
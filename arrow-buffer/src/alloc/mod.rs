@@ -17,7 +17,7 @@
 
 //! Defines the low-level [`Allocation`] API for shared memory regions
 
-use std::alloc::Layout;
+use std::alloc::{GlobalAlloc, Layout};
 use std::fmt::{Debug, Formatter};
 use std::panic::RefUnwindSafe;
 use std::sync::Arc;
@@ -32,10 +32,23 @@ pub trait Allocation: RefUnwindSafe + Send + Sync {}
 
 impl<T: RefUnwindSafe + Send + Sync> Allocation for T {}
 
+/// A memory allocator that can be used by a [`MutableBuffer`](crate::MutableBuffer)
+///
+/// This is implemented for any [`GlobalAlloc`], allowing buffers to be allocated from
+/// a different allocator than the global allocator, for example one backed by huge pages
+/// or pinned memory. The allocator is retained by [`Buffer`](crate::Buffer)s created from
+/// the [`MutableBuffer`](crate::MutableBuffer), and used to free the memory once it is no
+/// longer referenced.
+pub trait Allocator: GlobalAlloc + RefUnwindSafe + Send + Sync + Debug {}
+
+impl<T: GlobalAlloc + RefUnwindSafe + Send + Sync + Debug> Allocator for T {}
+
 /// Mode of deallocating memory regions
 pub(crate) enum Deallocation {
     /// An allocation using [`std::alloc`]
     Standard(Layout),
+    /// An allocation using a custom [`Allocator`]
+    Allocator(Layout, Arc<dyn Allocator>),
     /// An allocation from an external source like the FFI interface
     /// Deallocation will happen on `Allocation::drop`
     Custom(Arc<dyn Allocation>),
@@ -47,6 +60,9 @@ impl Debug for Deallocation {
             Deallocation::Standard(layout) => {
                 write!(f, "Deallocation::Standard {layout:?}")
             }
+            Deallocation::Allocator(layout, allocator) => {
+                write!(f, "Deallocation::Allocator {layout:?} {allocator:?}")
+            }
             Deallocation::Custom(_) => {
                 write!(f, "Deallocation::Custom {{ capacity: unknown }}")
             }
@@ -18,10 +18,11 @@
 use crate::bit_chunk_iterator::BitChunks;
 use crate::bit_iterator::{BitIndexIterator, BitIterator, BitSliceIterator};
 use crate::{
-    bit_util, buffer_bin_and, buffer_bin_or, buffer_bin_xor, buffer_unary_not,
-    BooleanBufferBuilder, Buffer, MutableBuffer,
+    bit_util, bitwise_bin_op_helper, bitwise_unary_op_helper, buffer_bin_and,
+    buffer_bin_or, buffer_bin_xor, buffer_unary_not, BooleanBufferBuilder, Buffer,
+    MutableBuffer,
 };
-use std::ops::{BitAnd, BitOr, BitXor, Not};
+use std::ops::{BitAnd, BitOr, BitXor, Not, Range};
 
 /// A slice-able [`Buffer`] containing bit-packed booleans
 #[derive(Debug, Clone, Eq)]
@@ -203,6 +204,106 @@ impl BooleanBuffer {
     pub fn set_slices(&self) -> BitSliceIterator<'_> {
         BitSliceIterator::new(self.values(), self.offset, self.len)
     }
+
+    /// Returns the number of set bits within `range`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds of this buffer
+    pub fn count_set_bits_in(&self, range: Range<usize>) -> usize {
+        assert!(
+            range.start <= range.end && range.end <= self.len,
+            "range {range:?} out of bounds for BooleanBuffer of length {}",
+            self.len
+        );
+        let len = range.end - range.start;
+        self.buffer
+            .count_set_bits_offset(self.offset + range.start, len)
+    }
+
+    /// Returns an iterator over the indices of the set bits within `range`
+    ///
+    /// The yielded indices are relative to the start of this buffer, not `range`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds of this buffer
+    pub fn set_indices_in(
+        &self,
+        range: Range<usize>,
+    ) -> impl Iterator<Item = usize> + '_ {
+        assert!(
+            range.start <= range.end && range.end <= self.len,
+            "range {range:?} out of bounds for BooleanBuffer of length {}",
+            self.len
+        );
+        let len = range.end - range.start;
+        BitIndexIterator::new(self.values(), self.offset + range.start, len)
+            .map(move |idx| idx + range.start)
+    }
+
+    /// Returns a new [`BooleanBuffer`] containing the result of applying `op` to
+    /// each 64-bit chunk of this buffer
+    ///
+    /// The chunks are aligned to the start of this buffer, regardless of its offset,
+    /// with any bits beyond its length in the final chunk unspecified
+    ///
+    /// ```
+    /// # use arrow_buffer::BooleanBuffer;
+    /// let buffer = BooleanBuffer::from(vec![true, false, true]).slice(1, 2);
+    /// let inverted = buffer.bitwise_unary(|a| !a);
+    /// assert_eq!(inverted, BooleanBuffer::from(vec![true, false]));
+    /// ```
+    pub fn bitwise_unary<F>(&self, op: F) -> Self
+    where
+        F: FnMut(u64) -> u64,
+    {
+        let buffer = bitwise_unary_op_helper(&self.buffer, self.offset, self.len, op);
+        Self::new(buffer, 0, self.len)
+    }
+
+    /// Returns a new [`BooleanBuffer`] containing the result of applying `op` to
+    /// each pair of 64-bit chunks of this buffer and `rhs`
+    ///
+    /// The chunks are aligned to the start of each buffer, regardless of their offsets,
+    /// with any bits beyond their length in the final chunk unspecified
+    ///
+    /// ```
+    /// # use arrow_buffer::BooleanBuffer;
+    /// let a = BooleanBuffer::from(vec![true, true, false, false]);
+    /// let b = BooleanBuffer::from(vec![false, true, false, true, false]).slice(1, 4);
+    /// let and_not = a.bitwise_binary(&b, |a, b| a & !b);
+    /// assert_eq!(and_not, BooleanBuffer::from(vec![false, true, false, false]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of `self` and `rhs` differ
+    pub fn bitwise_binary<F>(&self, rhs: &BooleanBuffer, op: F) -> Self
+    where
+        F: FnMut(u64, u64) -> u64,
+    {
+        assert_eq!(self.len, rhs.len);
+        let buffer = bitwise_bin_op_helper(
+            &self.buffer,
+            self.offset,
+            &rhs.buffer,
+            rhs.offset,
+            self.len,
+            op,
+        );
+        Self::new(buffer, 0, self.len)
+    }
+
+    /// Returns a new [`BooleanBuffer`] with the bits set in `self` but not in `rhs`,
+    /// i.e. `self & !rhs`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of `self` and `rhs` differ
+    pub fn and_not(&self, rhs: &BooleanBuffer) -> Self {
+        self.bitwise_binary(rhs, |a, b| a & !b)
+    }
 }
 
 impl Not for &BooleanBuffer {
@@ -432,4 +533,53 @@ mod tests {
             BooleanBuffer::new(Buffer::from(&[255, 254, 254, 255, 255]), offset, len);
         assert_eq!(!boolean_buf, expected);
     }
+
+    #[test]
+    fn test_set_bits_in_range() {
+        let values: Vec<_> = (0..200).map(|i| i % 3 == 0 || i % 7 == 0).collect();
+        let buffer = BooleanBuffer::from(values.clone());
+
+        for (offset, len) in [(0, 200), (3, 150), (65, 70), (127, 0)] {
+            let sliced = buffer.slice(offset, len);
+            let expected = &values[offset..offset + len];
+
+            for range in [0..len, len / 3..len, 0..len / 2, len / 2..len / 2] {
+                let count = expected[range.clone()].iter().filter(|x| **x).count();
+                assert_eq!(sliced.count_set_bits_in(range.clone()), count);
+
+                let indices: Vec<_> = sliced.set_indices_in(range.clone()).collect();
+                let expected_indices: Vec<_> = range.filter(|i| expected[*i]).collect();
+                assert_eq!(indices, expected_indices);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds for BooleanBuffer of length 10")]
+    fn test_count_set_bits_in_oob() {
+        BooleanBuffer::new_set(10).count_set_bits_in(5..11);
+    }
+
+    #[test]
+    fn test_bitwise_unaligned() {
+        let a_values: Vec<_> = (0..150).map(|i| i % 2 == 0).collect();
+        let b_values: Vec<_> = (0..150).map(|i| i % 5 < 2).collect();
+        let a = BooleanBuffer::from(a_values.clone()).slice(3, 140);
+        let b = BooleanBuffer::from(b_values.clone()).slice(7, 140);
+
+        let expected: BooleanBuffer = (0..140)
+            .map(|i| a_values[i + 3] && !b_values[i + 7])
+            .collect();
+        assert_eq!(a.and_not(&b), expected);
+
+        let expected: BooleanBuffer = (0..140)
+            .map(|i| a_values[i + 3] | b_values[i + 7])
+            .collect();
+        assert_eq!(a.bitwise_binary(&b, |a, b| a | b), expected);
+
+        let expected: BooleanBuffer = (0..140).map(|i| !a_values[i + 3]).collect();
+        let inverted = a.bitwise_unary(|a| !a);
+        assert_eq!(inverted, expected);
+        assert_eq!(inverted.count_set_bits(), 70);
+    }
 }
@@ -22,6 +22,7 @@ use std::ptr::NonNull;
 use std::sync::Arc;
 
 use crate::alloc::{Allocation, Deallocation, ALIGNMENT};
+use crate::pool::{MemoryPool, MemoryPoolError};
 use crate::util::bit_chunk_iterator::{BitChunks, UnalignedBitChunk};
 use crate::{bytes::Bytes, native::ArrowNativeType};
 
@@ -285,6 +286,17 @@ impl Buffer {
             })
     }
 
+    /// Claim the memory used by this buffer against `pool`
+    ///
+    /// The claim is released once the underlying allocation is freed. As the
+    /// allocation may be shared with other buffers, e.g. as a result of [`Self::slice`],
+    /// claiming an allocation that has already been claimed, by any pool, is a no-op.
+    ///
+    /// See [`crate::pool`] for more information
+    pub fn claim(&self, pool: &dyn MemoryPool) -> Result<(), MemoryPoolError> {
+        self.data.claim(pool)
+    }
+
     /// Returns `Vec` for mutating the buffer
     ///
     /// Returns `Err(self)` if this buffer does not have the same [`Layout`] as
@@ -310,7 +322,9 @@ impl Buffer {
         let v_len = self.length / std::mem::size_of::<T>();
 
         Arc::try_unwrap(self.data)
-            .map(|bytes| unsafe {
+            .map(|mut bytes| unsafe {
+                // A Vec cannot be tracked by a MemoryPool, release any claim
+                drop(bytes.take_reservation());
                 let ptr = bytes.ptr().as_ptr() as _;
                 std::mem::forget(bytes);
                 // Safety
@@ -18,8 +18,10 @@
 use std::alloc::{handle_alloc_error, Layout};
 use std::mem;
 use std::ptr::NonNull;
+use std::sync::Arc;
 
-use crate::alloc::{Deallocation, ALIGNMENT};
+use crate::alloc::{Allocator, Deallocation, ALIGNMENT};
+use crate::pool::{MemoryPool, MemoryPoolError, MemoryReservation};
 use crate::{
     bytes::Bytes,
     native::{ArrowNativeType, ToByteSlice},
@@ -57,6 +59,10 @@ pub struct MutableBuffer {
     // invariant: len <= capacity
     len: usize,
     layout: Layout,
+    // the allocator of data, or the global allocator if None
+    allocator: Option<Arc<dyn Allocator>>,
+    // tracks the capacity of this buffer, if claimed by a MemoryPool
+    reservation: Option<Box<dyn MemoryReservation>>,
 }
 
 impl MutableBuffer {
@@ -69,13 +75,81 @@ impl MutableBuffer {
     /// Allocate a new [MutableBuffer] with initial capacity to be at least `capacity`.
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_impl(capacity, ALIGNMENT, None)
+    }
+
+    /// Allocate a new [MutableBuffer] with initial capacity to be at least `capacity`,
+    /// and with its memory aligned to `alignment` bytes
+    ///
+    /// The alignment is preserved when the buffer grows, and by any [`Buffer`] created
+    /// from it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alignment` is not a power of two
+    ///
+    /// # Example
+    /// ```
+    /// # use arrow_buffer::buffer::{Buffer, MutableBuffer};
+    /// let mut buffer = MutableBuffer::with_capacity_and_alignment(10, 4096);
+    /// buffer.extend_from_slice(&[0_u8; 8192]);
+    /// assert_eq!(buffer.alignment(), 4096);
+    ///
+    /// let buffer: Buffer = buffer.into();
+    /// assert_eq!(buffer.as_ptr().align_offset(4096), 0);
+    /// ```
+    pub fn with_capacity_and_alignment(capacity: usize, alignment: usize) -> Self {
+        Self::with_capacity_impl(capacity, alignment, None)
+    }
+
+    /// Allocate a new [MutableBuffer] using `allocator`, with initial capacity to be at
+    /// least `capacity`, and with its memory aligned to `alignment` bytes
+    ///
+    /// The memory of this buffer, including any reallocations as it grows, is provided
+    /// by `allocator`, and any [`Buffer`] created from it will free its memory with
+    /// `allocator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alignment` is not a power of two
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use std::alloc::System;
+    /// # use arrow_buffer::alloc::ALIGNMENT;
+    /// # use arrow_buffer::buffer::{Buffer, MutableBuffer};
+    /// let mut buffer = MutableBuffer::with_capacity_in(64, ALIGNMENT, Arc::new(System));
+    /// buffer.push(1_u32);
+    /// let buffer: Buffer = buffer.into();
+    /// assert_eq!(buffer.as_slice(), &[1, 0, 0, 0]);
+    /// ```
+    pub fn with_capacity_in(
+        capacity: usize,
+        alignment: usize,
+        allocator: Arc<dyn Allocator>,
+    ) -> Self {
+        Self::with_capacity_impl(capacity, alignment, Some(allocator))
+    }
+
+    #[inline]
+    fn with_capacity_impl(
+        capacity: usize,
+        alignment: usize,
+        allocator: Option<Arc<dyn Allocator>>,
+    ) -> Self {
         let capacity = bit_util::round_upto_multiple_of_64(capacity);
-        let layout = Layout::from_size_align(capacity, ALIGNMENT).unwrap();
+        let layout = Layout::from_size_align(capacity, alignment).unwrap();
         let data = match layout.size() {
-            0 => dangling_ptr(),
+            0 => dangling_ptr(alignment),
             _ => {
                 // Safety: Verified size != 0
-                let raw_ptr = unsafe { std::alloc::alloc(layout) };
+                let raw_ptr = unsafe {
+                    match &allocator {
+                        Some(allocator) => allocator.alloc(layout),
+                        None => std::alloc::alloc(layout),
+                    }
+                };
                 NonNull::new(raw_ptr).unwrap_or_else(|| handle_alloc_error(layout))
             }
         };
@@ -83,6 +157,8 @@ impl MutableBuffer {
             data,
             len: 0,
             layout,
+            allocator,
+            reservation: None,
         }
     }
 
@@ -100,14 +176,20 @@ impl MutableBuffer {
     pub fn from_len_zeroed(len: usize) -> Self {
         let layout = Layout::from_size_align(len, ALIGNMENT).unwrap();
         let data = match layout.size() {
-            0 => dangling_ptr(),
+            0 => dangling_ptr(ALIGNMENT),
             _ => {
                 // Safety: Verified size != 0
                 let raw_ptr = unsafe { std::alloc::alloc_zeroed(layout) };
                 NonNull::new(raw_ptr).unwrap_or_else(|| handle_alloc_error(layout))
             }
         };
-        Self { data, len, layout }
+        Self {
+            data,
+            len,
+            layout,
+            allocator: None,
+            reservation: None,
+        }
     }
 
     /// Create a [`MutableBuffer`] from the provided [`Vec`] without copying
@@ -122,21 +204,38 @@ impl MutableBuffer {
         // This is based on `RawVec::current_memory`
         let layout = unsafe { Layout::array::<T>(vec.capacity()).unwrap_unchecked() };
         mem::forget(vec);
-        Self { data, len, layout }
+        Self {
+            data,
+            len,
+            layout,
+            allocator: None,
+            reservation: None,
+        }
     }
 
     /// Allocates a new [MutableBuffer] from given `Bytes`.
-    pub(crate) fn from_bytes(bytes: Bytes) -> Result<Self, Bytes> {
-        let layout = match bytes.deallocation() {
-            Deallocation::Standard(layout) => *layout,
+    pub(crate) fn from_bytes(mut bytes: Bytes) -> Result<Self, Bytes> {
+        let (layout, allocator) = match bytes.deallocation() {
+            Deallocation::Standard(layout) => (*layout, None),
+            Deallocation::Allocator(layout, allocator) => {
+                (*layout, Some(Arc::clone(allocator)))
+            }
             _ => return Err(bytes),
         };
 
         let len = bytes.len();
         let data = bytes.ptr();
+        let reservation = bytes.take_reservation();
+        // Safety: the allocator, if any, is cloned above and so not leaked
         mem::forget(bytes);
 
-        Ok(Self { data, len, layout })
+        Ok(Self {
+            data,
+            len,
+            layout,
+            allocator,
+            reservation,
+        })
     }
 
     /// creates a new [MutableBuffer] with capacity and length capable of holding `len` bits.
@@ -204,28 +303,91 @@ impl MutableBuffer {
         }
     }
 
+    /// Tries to reserve capacity for at least `additional` more bytes, returning an
+    /// error if this buffer has been [claimed](Self::claim) by a [`MemoryPool`] and
+    /// the resulting capacity would exceed the limit of that pool
+    ///
+    /// If this buffer has not been claimed, this is equivalent to [`Self::reserve`]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), MemoryPoolError> {
+        let required_cap = self.len + additional;
+        if required_cap > self.layout.size() {
+            let new_capacity = bit_util::round_upto_multiple_of_64(required_cap);
+            let new_capacity = std::cmp::max(new_capacity, self.layout.size() * 2);
+            if let Some(reservation) = self.reservation.as_mut() {
+                reservation.try_resize(new_capacity)?;
+            }
+            self.reallocate(new_capacity)
+        }
+        Ok(())
+    }
+
+    /// Claim the memory used by this buffer against `pool`
+    ///
+    /// The pool tracks the capacity of this buffer as it grows or shrinks, and the
+    /// claim is transferred to any [`Buffer`] created from it. Claiming a buffer that
+    /// has already been claimed is a no-op.
+    ///
+    /// Growing a claimed buffer with [`Self::reserve`] or the methods that call it is
+    /// tracked, but does not enforce the limit of `pool`; use [`Self::try_reserve`] to
+    /// grow the buffer only if it would remain within the limit.
+    ///
+    /// See [`crate::pool`] for more information
+    pub fn claim(&mut self, pool: &dyn MemoryPool) -> Result<(), MemoryPoolError> {
+        if self.reservation.is_none() {
+            self.reservation = Some(pool.try_reserve(self.layout.size())?);
+        }
+        Ok(())
+    }
+
     #[cold]
     fn reallocate(&mut self, capacity: usize) {
+        if let Some(reservation) = self.reservation.as_mut() {
+            reservation.resize(capacity);
+        }
         let new_layout = Layout::from_size_align(capacity, self.layout.align()).unwrap();
         if new_layout.size() == 0 {
             if self.layout.size() != 0 {
                 // Safety: data was allocated with layout
-                unsafe { std::alloc::dealloc(self.as_mut_ptr(), self.layout) };
+                unsafe { self.dealloc() };
                 self.layout = new_layout
             }
             return;
         }
 
-        let data = match self.layout.size() {
+        let ptr = self.as_mut_ptr();
+        let data = match (self.layout.size(), &self.allocator) {
             // Safety: new_layout is not empty
-            0 => unsafe { std::alloc::alloc(new_layout) },
+            (0, Some(allocator)) => unsafe { allocator.alloc(new_layout) },
+            (0, None) => unsafe { std::alloc::alloc(new_layout) },
             // Safety: verified new layout is valid and not empty
-            _ => unsafe { std::alloc::realloc(self.as_mut_ptr(), self.layout, capacity) },
+            (_, Some(allocator)) => unsafe {
+                allocator.realloc(ptr, self.layout, capacity)
+            },
+            (_, None) => unsafe { std::alloc::realloc(ptr, self.layout, capacity) },
         };
         self.data = NonNull::new(data).unwrap_or_else(|| handle_alloc_error(new_layout));
         self.layout = new_layout;
     }
 
+    /// Deallocates the memory of this buffer
+    ///
+    /// # Safety
+    ///
+    /// The capacity of this buffer must not be zero, and its memory must not be
+    /// accessed afterwards
+    unsafe fn dealloc(&mut self) {
+        match &self.allocator {
+            Some(allocator) => allocator.dealloc(self.data.as_ptr(), self.layout),
+            None => std::alloc::dealloc(self.data.as_ptr(), self.layout),
+        }
+    }
+
+    /// Returns the alignment of the memory of this buffer, in bytes
+    #[inline]
+    pub fn alignment(&self) -> usize {
+        self.layout.align()
+    }
+
     /// Truncates this buffer to `len` bytes
     ///
     /// If `len` is greater than the buffer's current length, this has no effect
@@ -341,10 +503,13 @@ impl MutableBuffer {
     }
 
     #[inline]
-    pub(super) fn into_buffer(self) -> Buffer {
-        let bytes = unsafe {
-            Bytes::new(self.data, self.len, Deallocation::Standard(self.layout))
+    pub(super) fn into_buffer(mut self) -> Buffer {
+        let deallocation = match self.allocator.take() {
+            Some(allocator) => Deallocation::Allocator(self.layout, allocator),
+            None => Deallocation::Standard(self.layout),
         };
+        let mut bytes = unsafe { Bytes::new(self.data, self.len, deallocation) };
+        bytes.set_reservation(self.reservation.take());
         std::mem::forget(self);
         Buffer::from_bytes(bytes)
     }
@@ -487,11 +652,11 @@ impl MutableBuffer {
 }
 
 #[inline]
-fn dangling_ptr() -> NonNull<u8> {
-    // SAFETY: ALIGNMENT is a non-zero usize which is then casted
-    // to a *mut T. Therefore, `ptr` is not null and the conditions for
+fn dangling_ptr(alignment: usize) -> NonNull<u8> {
+    // SAFETY: alignment is a non-zero usize, as validated by Layout, which is then
+    // casted to a *mut T. Therefore, `ptr` is not null and the conditions for
     // calling new_unchecked() are respected.
-    unsafe { NonNull::new_unchecked(ALIGNMENT as *mut u8) }
+    unsafe { NonNull::new_unchecked(alignment as *mut u8) }
 }
 
 impl<A: ArrowNativeType> Extend<A> for MutableBuffer {
@@ -673,8 +838,8 @@ impl std::ops::DerefMut for MutableBuffer {
 impl Drop for MutableBuffer {
     fn drop(&mut self) {
         if self.layout.size() != 0 {
-            // Safety: data was allocated with standard allocator with given layout
-            unsafe { std::alloc::dealloc(self.data.as_ptr() as _, self.layout) };
+            // Safety: data was allocated with the allocator of this buffer with given layout
+            unsafe { self.dealloc() };
         }
     }
 }
@@ -782,6 +947,8 @@ impl<T: ArrowNativeType> std::iter::FromIterator<T> for MutableBuffer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::alloc::GlobalAlloc;
+    use std::sync::atomic::Ordering;
 
     #[test]
     fn test_mutable_new() {
@@ -1000,4 +1167,73 @@ mod tests {
         assert_eq!(buffer.len(), 4 * mem::size_of::<u16>());
         assert_eq!(buffer.as_slice(), &[1, 0, 2, 0, 3, 0, 4, 0]);
     }
+
+    #[test]
+    fn test_mutable_alignment() {
+        let mut buffer = MutableBuffer::with_capacity_and_alignment(0, 1024);
+        assert_eq!(buffer.alignment(), 1024);
+        assert_eq!(buffer.as_ptr().align_offset(1024), 0);
+
+        for i in 0..1000_u32 {
+            buffer.push(i);
+            assert_eq!(buffer.as_ptr().align_offset(1024), 0);
+        }
+        buffer.shrink_to_fit();
+        assert_eq!(buffer.alignment(), 1024);
+
+        let buffer: Buffer = buffer.into();
+        assert_eq!(buffer.as_ptr().align_offset(1024), 0);
+        assert_eq!(buffer.typed_data::<u32>()[999], 999);
+
+        let buffer = buffer.into_mutable().unwrap();
+        assert_eq!(buffer.alignment(), 1024);
+    }
+
+    #[derive(Debug, Default)]
+    struct CountingAllocator {
+        allocated: std::sync::atomic::AtomicUsize,
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            self.allocated.fetch_add(layout.size(), Ordering::SeqCst);
+            std::alloc::System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            self.allocated.fetch_sub(layout.size(), Ordering::SeqCst);
+            std::alloc::System.dealloc(ptr, layout)
+        }
+    }
+
+    #[test]
+    fn test_mutable_allocator() {
+        let allocator = Arc::new(CountingAllocator::default());
+        let allocated = || allocator.allocated.load(Ordering::SeqCst);
+
+        let mut buffer = MutableBuffer::with_capacity_in(64, 256, allocator.clone());
+        assert_eq!(allocated(), 64);
+        buffer.extend_from_slice(&[1_u8; 100]);
+        assert_eq!(allocated(), 128);
+        assert_eq!(buffer.as_ptr().align_offset(256), 0);
+
+        // Memory is freed by the allocator once the buffer is no longer referenced
+        let buffer: Buffer = buffer.into();
+        let sliced = buffer.slice(10);
+        drop(buffer);
+        assert_eq!(allocated(), 128);
+
+        // Cannot be converted to a Vec, as that requires the global allocator
+        let sliced = sliced.into_vec::<u8>().unwrap_err();
+        drop(sliced);
+        assert_eq!(allocated(), 0);
+
+        let buffer: Buffer =
+            MutableBuffer::with_capacity_in(8, 64, allocator.clone()).into();
+        let mut buffer = buffer.into_mutable().unwrap();
+        buffer.extend_from_slice(&[0_u8; 65]);
+        assert_eq!(allocated(), 128);
+        drop(buffer);
+        assert_eq!(allocated(), 0);
+    }
 }
@@ -18,6 +18,7 @@
 use crate::bit_iterator::{BitIndexIterator, BitIterator, BitSliceIterator};
 use crate::buffer::BooleanBuffer;
 use crate::{Buffer, MutableBuffer};
+use std::ops::Range;
 
 /// A [`BooleanBuffer`] used to encode validity for arrow arrays
 ///
@@ -82,6 +83,30 @@ impl NullBuffer {
         }
     }
 
+    /// Computes the intersection of the nulls in two optional [`NullBuffer`]
+    ///
+    /// The result is NULL only if both of the input values are NULL, as is commonly
+    /// required by operations such as `COALESCE` or a logical `OR` of validity
+    pub fn intersection(
+        lhs: Option<&NullBuffer>,
+        rhs: Option<&NullBuffer>,
+    ) -> Option<NullBuffer> {
+        match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => Some(Self::new(lhs.inner() | rhs.inner())),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of nulls within `range`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds of this buffer
+    pub fn null_count_in(&self, range: Range<usize>) -> usize {
+        let len = range.end.saturating_sub(range.start);
+        len - self.buffer.count_set_bits_in(range)
+    }
+
     /// Returns true if all nulls in `other` also exist in self
     pub fn contains(&self, other: &NullBuffer) -> bool {
         if other.null_count == 0 {
@@ -261,4 +286,26 @@ mod tests {
             std::mem::size_of::<Option<NullBuffer>>()
         );
     }
+
+    #[test]
+    fn test_set_operations() {
+        let a = NullBuffer::from(vec![true, false, true, false, true]).slice(1, 4);
+        let b = NullBuffer::from(vec![true, true, false, false]);
+
+        let union = NullBuffer::union(Some(&a), Some(&b)).unwrap();
+        assert_eq!(union, NullBuffer::from(vec![false, true, false, false]));
+        assert_eq!(union.null_count(), 3);
+
+        let intersection = NullBuffer::intersection(Some(&a), Some(&b)).unwrap();
+        assert_eq!(
+            intersection,
+            NullBuffer::from(vec![true, true, false, true])
+        );
+        assert_eq!(intersection.null_count(), 1);
+        assert!(NullBuffer::intersection(Some(&a), None).is_none());
+
+        assert_eq!(a.null_count_in(0..4), 2);
+        assert_eq!(a.null_count_in(1..3), 1);
+        assert_eq!(a.null_count_in(2..2), 0);
+    }
 }
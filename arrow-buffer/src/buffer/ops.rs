@@ -60,7 +60,68 @@ where
 
 /// Apply a bitwise operation `op` to two inputs and return the result as a Buffer.
 /// The inputs are treated as bitmaps, meaning that offsets and length are specified in number of bits.
+///
+/// On x86_64 this is compiled for AVX2, and dispatched to at runtime if supported by the CPU
 pub fn bitwise_bin_op_helper<F>(
+    left: &Buffer,
+    left_offset_in_bits: usize,
+    right: &Buffer,
+    right_offset_in_bits: usize,
+    len_in_bits: usize,
+    op: F,
+) -> Buffer
+where
+    F: FnMut(u64, u64) -> u64,
+{
+    #[cfg(target_arch = "x86_64")]
+    if std::arch::is_x86_feature_detected!("avx2") {
+        // SAFETY: verified the CPU supports AVX2
+        return unsafe {
+            bitwise_bin_op_avx2(
+                left,
+                left_offset_in_bits,
+                right,
+                right_offset_in_bits,
+                len_in_bits,
+                op,
+            )
+        };
+    }
+    bitwise_bin_op_impl(
+        left,
+        left_offset_in_bits,
+        right,
+        right_offset_in_bits,
+        len_in_bits,
+        op,
+    )
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn bitwise_bin_op_avx2<F>(
+    left: &Buffer,
+    left_offset_in_bits: usize,
+    right: &Buffer,
+    right_offset_in_bits: usize,
+    len_in_bits: usize,
+    op: F,
+) -> Buffer
+where
+    F: FnMut(u64, u64) -> u64,
+{
+    bitwise_bin_op_impl(
+        left,
+        left_offset_in_bits,
+        right,
+        right_offset_in_bits,
+        len_in_bits,
+        op,
+    )
+}
+
+#[inline(always)]
+fn bitwise_bin_op_impl<F>(
     left: &Buffer,
     left_offset_in_bits: usize,
     right: &Buffer,
@@ -93,7 +154,41 @@ where
 
 /// Apply a bitwise operation `op` to one input and return the result as a Buffer.
 /// The input is treated as a bitmap, meaning that offset and length are specified in number of bits.
+///
+/// On x86_64 this is compiled for AVX2, and dispatched to at runtime if supported by the CPU
 pub fn bitwise_unary_op_helper<F>(
+    left: &Buffer,
+    offset_in_bits: usize,
+    len_in_bits: usize,
+    op: F,
+) -> Buffer
+where
+    F: FnMut(u64) -> u64,
+{
+    #[cfg(target_arch = "x86_64")]
+    if std::arch::is_x86_feature_detected!("avx2") {
+        // SAFETY: verified the CPU supports AVX2
+        return unsafe { bitwise_unary_op_avx2(left, offset_in_bits, len_in_bits, op) };
+    }
+    bitwise_unary_op_impl(left, offset_in_bits, len_in_bits, op)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn bitwise_unary_op_avx2<F>(
+    left: &Buffer,
+    offset_in_bits: usize,
+    len_in_bits: usize,
+    op: F,
+) -> Buffer
+where
+    F: FnMut(u64) -> u64,
+{
+    bitwise_unary_op_impl(left, offset_in_bits, len_in_bits, op)
+}
+
+#[inline(always)]
+fn bitwise_unary_op_impl<F>(
     left: &Buffer,
     offset_in_bits: usize,
     len_in_bits: usize,
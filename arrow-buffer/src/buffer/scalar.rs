@@ -122,7 +122,7 @@ impl<T: ArrowNativeType> From<Buffer> for ScalarBuffer<T> {
         let is_aligned = buffer.as_ptr().align_offset(align) == 0;
 
         match buffer.deallocation() {
-            Deallocation::Standard(_) => assert!(
+            Deallocation::Standard(_) | Deallocation::Allocator(_, _) => assert!(
                 is_aligned,
                 "Memory pointer is not aligned with the specified scalar type"
             ),
@@ -125,6 +125,19 @@ impl NullBufferBuilder {
         }
     }
 
+    /// Appends a [`NullBuffer`] to this builder, only materializing the
+    /// bitmap if `buffer` contains nulls
+    pub fn append_buffer(&mut self, buffer: &NullBuffer) {
+        if buffer.null_count() > 0 {
+            self.materialize_if_needed();
+        }
+        if let Some(buf) = self.bitmap_builder.as_mut() {
+            buf.append_buffer(buffer.inner())
+        } else {
+            self.len += buffer.len();
+        }
+    }
+
     /// Builds the null buffer and resets the builder.
     /// Returns `None` if the builder only contains `true`s.
     pub fn finish(&mut self) -> Option<NullBuffer> {
@@ -234,4 +247,19 @@ mod tests {
         let buf = builder.finish().unwrap();
         assert_eq!(&[0b1011_u8], buf.validity());
     }
+
+    #[test]
+    fn test_null_buffer_builder_append_buffer() {
+        let mut builder = NullBufferBuilder::new(0);
+        builder.append_buffer(&NullBuffer::new_valid(3));
+        assert_eq!(builder.len(), 3);
+        assert!(builder.finish_cloned().is_none());
+
+        let nulls = NullBuffer::from(vec![true, false, true, true, false]);
+        builder.append_buffer(&nulls.slice(1, 3));
+        assert_eq!(builder.len(), 6);
+
+        let buf = builder.finish().unwrap();
+        assert_eq!(&[0b110111_u8], buf.validity());
+    }
 }
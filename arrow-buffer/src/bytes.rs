@@ -21,9 +21,11 @@
 
 use core::slice;
 use std::ptr::NonNull;
+use std::sync::Mutex;
 use std::{fmt::Debug, fmt::Formatter};
 
 use crate::alloc::Deallocation;
+use crate::pool::{MemoryPool, MemoryPoolError, MemoryReservation};
 
 /// A continuous, fixed-size, immutable memory region that knows how to de-allocate itself.
 ///
@@ -44,6 +46,9 @@ pub struct Bytes {
 
     /// how to deallocate this region
     deallocation: Deallocation,
+
+    /// the [`MemoryReservation`] tracking this region, if any
+    reservation: Mutex<Option<Box<dyn MemoryReservation>>>,
 }
 
 impl Bytes {
@@ -69,6 +74,7 @@ impl Bytes {
             ptr,
             len,
             deallocation,
+            reservation: Mutex::new(None),
         }
     }
 
@@ -93,7 +99,9 @@ impl Bytes {
 
     pub fn capacity(&self) -> usize {
         match self.deallocation {
-            Deallocation::Standard(layout) => layout.size(),
+            Deallocation::Standard(layout) | Deallocation::Allocator(layout, _) => {
+                layout.size()
+            }
             // we cannot determine this in general,
             // and thus we state that this is externally-owned memory
             Deallocation::Custom(_) => 0,
@@ -104,6 +112,37 @@ impl Bytes {
     pub(crate) fn deallocation(&self) -> &Deallocation {
         &self.deallocation
     }
+
+    /// Claim this region against `pool`, if it is not already claimed
+    ///
+    /// Regions allocated by an external source, for which the capacity is unknown,
+    /// are accounted for by their length
+    pub(crate) fn claim(&self, pool: &dyn MemoryPool) -> Result<(), MemoryPoolError> {
+        let mut reservation = self.reservation.lock().unwrap();
+        if reservation.is_none() {
+            let size = match self.deallocation {
+                Deallocation::Standard(layout) | Deallocation::Allocator(layout, _) => {
+                    layout.size()
+                }
+                Deallocation::Custom(_) => self.len,
+            };
+            *reservation = Some(pool.try_reserve(size)?);
+        }
+        Ok(())
+    }
+
+    /// Sets the [`MemoryReservation`] tracking this region
+    pub(crate) fn set_reservation(
+        &mut self,
+        reservation: Option<Box<dyn MemoryReservation>>,
+    ) {
+        *self.reservation.get_mut().unwrap() = reservation;
+    }
+
+    /// Takes the [`MemoryReservation`] tracking this region
+    pub(crate) fn take_reservation(&mut self) -> Option<Box<dyn MemoryReservation>> {
+        self.reservation.get_mut().unwrap().take()
+    }
 }
 
 // Deallocation is Send + Sync, repeating the bound here makes that refactoring safe
@@ -119,6 +158,10 @@ impl Drop for Bytes {
                 0 => {} // Nothing to do
                 _ => unsafe { std::alloc::dealloc(self.ptr.as_ptr(), *layout) },
             },
+            Deallocation::Allocator(layout, allocator) => match layout.size() {
+                0 => {} // Nothing to do
+                _ => unsafe { allocator.dealloc(self.ptr.as_ptr(), *layout) },
+            },
             // The automatic drop implementation will free the memory once the reference count reaches zero
             Deallocation::Custom(_allocation) => (),
         }
@@ -29,6 +29,8 @@ mod bytes;
 mod native;
 pub use bigint::i256;
 
+pub mod pool;
+
 pub use native::*;
 mod util;
 pub use util::*;
@@ -0,0 +1,332 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the [`MemoryPool`] API for tracking and limiting the memory used by buffers
+//!
+//! Memory is accounted for by claiming a [`Buffer`](crate::Buffer) or
+//! [`MutableBuffer`](crate::MutableBuffer) against a [`MemoryPool`]. The claim is
+//! released once the underlying allocation is freed, and follows the allocation
+//! as it is converted between [`MutableBuffer`](crate::MutableBuffer) and
+//! [`Buffer`](crate::Buffer), and as a [`MutableBuffer`](crate::MutableBuffer) grows.
+//!
+//! ```
+//! # use arrow_buffer::{Buffer, MutableBuffer};
+//! # use arrow_buffer::pool::{MemoryPool, TrackingMemoryPool};
+//! let pool = TrackingMemoryPool::with_limit(1024);
+//!
+//! let mut buffer = MutableBuffer::new(64);
+//! buffer.claim(&pool).unwrap();
+//! assert_eq!(pool.used(), 64);
+//!
+//! // Growing a claimed buffer is tracked by the pool
+//! buffer.extend_from_slice(&[0_u8; 128]);
+//! assert_eq!(pool.used(), 128);
+//!
+//! // Fallible reservation respects the limit
+//! assert!(buffer.try_reserve(2048).is_err());
+//!
+//! // The claim follows the allocation, and is released when it is freed
+//! let buffer: Buffer = buffer.into();
+//! assert_eq!(pool.used(), 128);
+//! drop(buffer);
+//! assert_eq!(pool.used(), 0);
+//! ```
+
+use std::fmt::{Debug, Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// A pool of memory against which allocations can be claimed
+pub trait MemoryPool: Debug + Send + Sync {
+    /// Reserve `size` bytes from this pool, returning a [`MemoryReservation`] that
+    /// releases the reserved memory when dropped
+    ///
+    /// Returns an error if this would exceed the limit of this pool
+    fn try_reserve(
+        &self,
+        size: usize,
+    ) -> Result<Box<dyn MemoryReservation>, MemoryPoolError>;
+
+    /// Returns the number of bytes currently reserved from this pool
+    fn used(&self) -> usize;
+
+    /// Returns the maximum number of bytes that may be reserved from this pool, if any
+    fn limit(&self) -> Option<usize>;
+}
+
+/// A reservation of memory from a [`MemoryPool`], released when dropped
+pub trait MemoryReservation: Debug + Send + Sync {
+    /// Returns the number of bytes reserved
+    fn size(&self) -> usize;
+
+    /// Resize this reservation to `new_size` bytes, regardless of the limit of the pool
+    ///
+    /// This is used to track memory that has already been allocated
+    fn resize(&mut self, new_size: usize);
+
+    /// Resize this reservation to `new_size` bytes, returning an error if this would
+    /// exceed the limit of the pool
+    fn try_resize(&mut self, new_size: usize) -> Result<(), MemoryPoolError>;
+}
+
+/// An error returned when a reservation would exceed the limit of a [`MemoryPool`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryPoolError {
+    /// The number of additional bytes requested
+    pub requested: usize,
+    /// The number of bytes reserved at the time of the request
+    pub used: usize,
+    /// The limit of the pool
+    pub limit: usize,
+}
+
+impl Display for MemoryPoolError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Failed to reserve {} bytes, {} of {} bytes already reserved",
+            self.requested, self.used, self.limit
+        )
+    }
+}
+
+impl std::error::Error for MemoryPoolError {}
+
+#[derive(Debug)]
+struct TrackingMemoryPoolInner {
+    used: AtomicUsize,
+    limit: Option<usize>,
+}
+
+impl TrackingMemoryPoolInner {
+    fn grow(&self, additional: usize) {
+        self.used.fetch_add(additional, Ordering::Relaxed);
+    }
+
+    fn try_grow(&self, additional: usize) -> Result<(), MemoryPoolError> {
+        let limit = match self.limit {
+            Some(limit) => limit,
+            None => {
+                self.grow(additional);
+                return Ok(());
+            }
+        };
+        self.used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                used.checked_add(additional).filter(|x| *x <= limit)
+            })
+            .map(|_| ())
+            .map_err(|used| MemoryPoolError {
+                requested: additional,
+                used,
+                limit,
+            })
+    }
+
+    fn shrink(&self, size: usize) {
+        self.used.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+/// A [`MemoryPool`] that tracks the number of bytes reserved, optionally enforcing
+/// a limit
+///
+/// Cloning a [`TrackingMemoryPool`] returns a handle to the same pool
+#[derive(Debug, Clone)]
+pub struct TrackingMemoryPool {
+    inner: Arc<TrackingMemoryPoolInner>,
+}
+
+impl Default for TrackingMemoryPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TrackingMemoryPool {
+    /// Create a new [`TrackingMemoryPool`] with no limit
+    pub fn new() -> Self {
+        Self::new_impl(None)
+    }
+
+    /// Create a new [`TrackingMemoryPool`] that limits reservations to `limit` bytes
+    pub fn with_limit(limit: usize) -> Self {
+        Self::new_impl(Some(limit))
+    }
+
+    fn new_impl(limit: Option<usize>) -> Self {
+        let inner = TrackingMemoryPoolInner {
+            used: AtomicUsize::new(0),
+            limit,
+        };
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+}
+
+impl MemoryPool for TrackingMemoryPool {
+    fn try_reserve(
+        &self,
+        size: usize,
+    ) -> Result<Box<dyn MemoryReservation>, MemoryPoolError> {
+        self.inner.try_grow(size)?;
+        Ok(Box::new(TrackingReservation {
+            pool: Arc::clone(&self.inner),
+            size,
+        }))
+    }
+
+    fn used(&self) -> usize {
+        self.inner.used.load(Ordering::Relaxed)
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.inner.limit
+    }
+}
+
+#[derive(Debug)]
+struct TrackingReservation {
+    pool: Arc<TrackingMemoryPoolInner>,
+    size: usize,
+}
+
+impl MemoryReservation for TrackingReservation {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn resize(&mut self, new_size: usize) {
+        match new_size > self.size {
+            true => self.pool.grow(new_size - self.size),
+            false => self.pool.shrink(self.size - new_size),
+        }
+        self.size = new_size;
+    }
+
+    fn try_resize(&mut self, new_size: usize) -> Result<(), MemoryPoolError> {
+        match new_size > self.size {
+            true => self.pool.try_grow(new_size - self.size)?,
+            false => self.pool.shrink(self.size - new_size),
+        }
+        self.size = new_size;
+        Ok(())
+    }
+}
+
+impl Drop for TrackingReservation {
+    fn drop(&mut self) {
+        self.pool.shrink(self.size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Buffer, MutableBuffer};
+
+    #[test]
+    fn test_tracking_pool() {
+        let pool = TrackingMemoryPool::with_limit(100);
+        let mut a = pool.try_reserve(40).unwrap();
+        let b = pool.try_reserve(60).unwrap();
+        assert_eq!(pool.used(), 100);
+
+        let err = pool.try_reserve(1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to reserve 1 bytes, 100 of 100 bytes already reserved"
+        );
+
+        a.try_resize(10).unwrap();
+        assert_eq!(pool.used(), 70);
+        assert!(a.try_resize(41).is_err());
+        assert_eq!(a.size(), 10);
+
+        // Infallible resize may exceed the limit
+        a.resize(50);
+        assert_eq!(pool.used(), 110);
+
+        drop(b);
+        drop(a);
+        assert_eq!(pool.used(), 0);
+    }
+
+    #[test]
+    fn test_claim_buffer() {
+        let pool = TrackingMemoryPool::new();
+        let buffer = Buffer::from_vec(vec![0_u64; 8]);
+        let cloned = buffer.clone();
+        let sliced = buffer.slice(8);
+
+        buffer.claim(&pool).unwrap();
+        assert_eq!(pool.used(), 64);
+
+        // Claiming a shared allocation is a no-op
+        cloned.claim(&pool).unwrap();
+        sliced.claim(&pool).unwrap();
+        assert_eq!(pool.used(), 64);
+
+        drop(buffer);
+        drop(cloned);
+        assert_eq!(pool.used(), 64);
+        drop(sliced);
+        assert_eq!(pool.used(), 0);
+    }
+
+    #[test]
+    fn test_claim_mutable_buffer() {
+        let pool = TrackingMemoryPool::with_limit(256);
+        let mut buffer = MutableBuffer::new(0);
+        buffer.claim(&pool).unwrap();
+        assert_eq!(pool.used(), 0);
+
+        buffer.extend_from_slice(&[1_u8; 100]);
+        assert_eq!(pool.used(), buffer.capacity());
+
+        let err = buffer.try_reserve(1000).unwrap_err();
+        assert_eq!(err.limit, 256);
+        assert_eq!(pool.used(), buffer.capacity());
+
+        buffer.try_reserve(100).unwrap();
+        assert_eq!(pool.used(), 256);
+
+        buffer.shrink_to_fit();
+        assert_eq!(pool.used(), 128);
+
+        // The reservation follows the allocation across conversions
+        let buffer: Buffer = buffer.into();
+        assert_eq!(pool.used(), 128);
+        let buffer = buffer.into_mutable().unwrap();
+        assert_eq!(pool.used(), 128);
+        drop(buffer);
+        assert_eq!(pool.used(), 0);
+    }
+
+    #[test]
+    fn test_claim_limit() {
+        let pool = TrackingMemoryPool::with_limit(10);
+        let buffer = Buffer::from_vec(vec![0_u8; 20]);
+        assert!(buffer.claim(&pool).is_err());
+        assert_eq!(pool.used(), 0);
+
+        let mut buffer = MutableBuffer::new(64);
+        assert!(buffer.claim(&pool).is_err());
+        assert_eq!(pool.used(), 0);
+    }
+}
@@ -0,0 +1,295 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Adapt [`RecordBatch`] to a target [`Schema`]
+//!
+//! Datasets whose schema evolves over time, such as those stored in table
+//! formats, contain files written with different schemas. [`SchemaAdapter`]
+//! maps batches read from such files into a single target schema, usually
+//! computed with [`Schema::try_merge`].
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+//! # use arrow_array::cast::AsArray;
+//! # use arrow_array::types::Int64Type;
+//! # use arrow_cast::adapter::SchemaAdapter;
+//! # use arrow_schema::{DataType, Field, Schema};
+//! let target = Schema::new(vec![
+//!     Field::new("id", DataType::Int64, false),
+//!     Field::new("name", DataType::Utf8, true),
+//! ]);
+//! let adapter = SchemaAdapter::new(Arc::new(target));
+//!
+//! // A batch written before "name" was added, with "id" stored as Int32
+//! let id = Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef;
+//! let batch = RecordBatch::try_from_iter([("id", id)]).unwrap();
+//!
+//! let adapted = adapter.adapt(&batch).unwrap();
+//! assert_eq!(adapted.schema(), adapter.target_schema().clone());
+//! assert_eq!(adapted.column(0).as_primitive::<Int64Type>().values(), &[1, 2]);
+//! assert_eq!(adapted.column(1).null_count(), 2);
+//! ```
+
+use crate::{can_cast_types, cast_with_options, CastOptions};
+use arrow_array::{new_null_array, ArrayRef, RecordBatch, RecordBatchOptions};
+use arrow_schema::{ArrowError, Schema, SchemaRef};
+
+/// Maps [`RecordBatch`] with differing schemas into a target schema
+///
+/// Columns are matched to the fields of the target schema by name, such that:
+///
+/// * Columns are reordered to match the target schema
+/// * Columns not present in the target schema are dropped
+/// * Nullable fields of the target schema missing from the source are filled with nulls
+/// * Columns of a different type are cast to the target type, with the children of
+///   struct columns matched by name in the same way
+///
+/// The output uses the target schema, including its field and schema metadata.
+///
+/// See the [module documentation](self) for an example
+#[derive(Debug, Clone)]
+pub struct SchemaAdapter {
+    target: SchemaRef,
+    cast_options: CastOptions<'static>,
+}
+
+impl SchemaAdapter {
+    /// Creates a new [`SchemaAdapter`] mapping to `target`
+    pub fn new(target: SchemaRef) -> Self {
+        Self {
+            target,
+            cast_options: CastOptions::new().with_safe(false),
+        }
+    }
+
+    /// Sets the [`CastOptions`] used to cast columns to the target type
+    ///
+    /// Defaults to returning an error if a value cannot be cast
+    pub fn with_cast_options(self, cast_options: CastOptions<'static>) -> Self {
+        Self {
+            cast_options,
+            ..self
+        }
+    }
+
+    /// Returns the target schema of this [`SchemaAdapter`]
+    pub fn target_schema(&self) -> &SchemaRef {
+        &self.target
+    }
+
+    /// Computes the [`SchemaMapping`] from `source` to the target schema
+    ///
+    /// Returns an error if a non-nullable field of the target schema is missing
+    /// from `source`, or a column cannot be cast to the target type
+    pub fn map_schema(&self, source: &Schema) -> Result<SchemaMapping, ArrowError> {
+        let columns = self
+            .target
+            .fields()
+            .iter()
+            .map(|field| match source.fields().find(field.name()) {
+                Some((idx, source_field)) => {
+                    let from = source_field.data_type();
+                    if from != field.data_type()
+                        && !can_cast_types(from, field.data_type())
+                    {
+                        return Err(ArrowError::SchemaError(format!(
+                            "Cannot adapt field '{}' from {} to {}",
+                            field.name(),
+                            from,
+                            field.data_type()
+                        )));
+                    }
+                    Ok(Some(idx))
+                }
+                None if field.is_nullable() => Ok(None),
+                None => Err(ArrowError::SchemaError(format!(
+                    "Cannot adapt schema as non-nullable field '{}' is missing",
+                    field.name()
+                ))),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(SchemaMapping {
+            target: self.target.clone(),
+            cast_options: self.cast_options.clone(),
+            columns,
+        })
+    }
+
+    /// Maps `batch` to the target schema
+    ///
+    /// When adapting many batches with the same schema, it is more efficient to
+    /// compute the [`SchemaMapping`] once with [`Self::map_schema`]
+    pub fn adapt(&self, batch: &RecordBatch) -> Result<RecordBatch, ArrowError> {
+        self.map_schema(batch.schema().as_ref())?.map_batch(batch)
+    }
+}
+
+/// A mapping from a source schema to the target schema of a [`SchemaAdapter`]
+#[derive(Debug, Clone)]
+pub struct SchemaMapping {
+    target: SchemaRef,
+    cast_options: CastOptions<'static>,
+    /// The index of the source column for each target field, if any
+    columns: Vec<Option<usize>>,
+}
+
+impl SchemaMapping {
+    /// Maps `batch`, which must have the source schema of this mapping, to the
+    /// target schema
+    pub fn map_batch(&self, batch: &RecordBatch) -> Result<RecordBatch, ArrowError> {
+        let num_rows = batch.num_rows();
+        let columns = self
+            .target
+            .fields()
+            .iter()
+            .zip(&self.columns)
+            .map(|(field, idx)| match idx {
+                Some(idx) => {
+                    let column = batch.column(*idx);
+                    match column.data_type() == field.data_type() {
+                        true => Ok(column.clone()),
+                        false => cast_with_options(
+                            column,
+                            field.data_type(),
+                            &self.cast_options,
+                        ),
+                    }
+                }
+                None => Ok(new_null_array(field.data_type(), num_rows)),
+            })
+            .collect::<Result<Vec<ArrayRef>, _>>()?;
+
+        let options = RecordBatchOptions::new().with_row_count(Some(num_rows));
+        RecordBatch::try_new_with_options(self.target.clone(), columns, &options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Float64Type, Int32Type, Int64Type};
+    use arrow_array::{Array, Float64Array, Int32Array, StringArray, StructArray};
+    use arrow_schema::{DataType, Field, Fields};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_adapt() {
+        let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+        let target = Schema::new_with_metadata(
+            vec![
+                Field::new("c", DataType::Float64, true),
+                Field::new("a", DataType::Int32, false),
+                Field::new("b", DataType::Utf8, true),
+            ],
+            metadata,
+        );
+        let adapter = SchemaAdapter::new(Arc::new(target));
+
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+            (
+                "d",
+                Arc::new(StringArray::from(vec!["x", "y", "z"])) as ArrayRef,
+            ),
+            (
+                "c",
+                Arc::new(Int32Array::from(vec![Some(4), None, Some(6)])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let mapping = adapter.map_schema(&batch.schema()).unwrap();
+        for batch in [batch.clone(), batch.slice(1, 2)] {
+            let adapted = mapping.map_batch(&batch).unwrap();
+            assert_eq!(&adapted.schema(), adapter.target_schema());
+            assert_eq!(adapted.num_rows(), batch.num_rows());
+            assert_eq!(adapted, adapter.adapt(&batch).unwrap());
+
+            let c = adapted.column(0).as_primitive::<Float64Type>();
+            let expected: Float64Array = batch
+                .column(2)
+                .as_primitive::<Int32Type>()
+                .iter()
+                .map(|x| x.map(|x| x as f64))
+                .collect();
+            assert_eq!(c, &expected);
+            assert_eq!(adapted.column(1).as_ref(), batch.column(0).as_ref());
+            assert_eq!(adapted.column(2).null_count(), batch.num_rows());
+        }
+    }
+
+    #[test]
+    fn test_adapt_nested() {
+        let target_struct = Fields::from(vec![
+            Field::new("x", DataType::Int64, true),
+            Field::new("y", DataType::Utf8, true),
+        ]);
+        let target = Schema::new(vec![Field::new(
+            "s",
+            DataType::Struct(target_struct.clone()),
+            true,
+        )]);
+        let adapter = SchemaAdapter::new(Arc::new(target));
+
+        let s = StructArray::from(vec![(
+            Arc::new(Field::new("x", DataType::Int32, false)),
+            Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+        )]);
+        let batch = RecordBatch::try_from_iter([("s", Arc::new(s) as ArrayRef)]).unwrap();
+
+        let adapted = adapter.adapt(&batch).unwrap();
+        let s = adapted.column(0).as_struct();
+        assert_eq!(s.fields(), &target_struct);
+        assert_eq!(s.column(0).as_primitive::<Int64Type>().values(), &[1, 2]);
+        assert_eq!(s.column(1).null_count(), 2);
+    }
+
+    #[test]
+    fn test_adapt_errors() {
+        let target = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        let adapter = SchemaAdapter::new(Arc::new(target));
+
+        let source = Schema::new(vec![Field::new("b", DataType::Int32, false)]);
+        let err = adapter.map_schema(&source).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Schema error: Cannot adapt schema as non-nullable field 'a' is missing"
+        );
+
+        let list = DataType::List(Arc::new(Field::new("item", DataType::Int32, true)));
+        let source = Schema::new(vec![Field::new("a", list, false)]);
+        let err = adapter.map_schema(&source).unwrap_err().to_string();
+        assert!(err.contains("Cannot adapt field 'a' from List"), "{err}");
+
+        // Values that cannot be cast are an error by default
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(StringArray::from(vec!["1", "foo"])) as ArrayRef,
+        )])
+        .unwrap();
+        assert!(adapter.adapt(&batch).is_err());
+
+        // Unless safe casting is enabled, but the target field is non-nullable
+        let adapter = adapter.with_cast_options(CastOptions::default());
+        let err = adapter.adapt(&batch).unwrap_err().to_string();
+        assert!(err.contains("non-nullable"), "{err}");
+    }
+}
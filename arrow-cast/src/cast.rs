@@ -43,13 +43,15 @@ use std::sync::Arc;
 
 use crate::display::{array_value_to_string, ArrayFormatter, FormatOptions};
 use crate::parse::{
-    parse_interval_day_time, parse_interval_month_day_nano, parse_interval_year_month,
-    string_to_datetime, Parser,
+    expand_decimal_exponent, parse_interval_day_time, parse_interval_month_day_nano,
+    parse_interval_year_month, string_to_datetime_formatted, Parser,
 };
 use arrow_array::{
     builder::*, cast::*, temporal_conversions::*, timezone::Tz, types::*, *,
 };
-use arrow_buffer::{i256, ArrowNativeType, Buffer, OffsetBuffer, ScalarBuffer};
+use arrow_buffer::{
+    i256, ArrowNativeType, Buffer, MutableBuffer, NullBuffer, OffsetBuffer, ScalarBuffer,
+};
 use arrow_data::ArrayData;
 use arrow_schema::*;
 use arrow_select::take::take;
@@ -63,13 +65,128 @@ pub struct CastOptions<'a> {
     pub safe: bool,
     /// Formatting options when casting from temporal types to string
     pub format_options: FormatOptions<'a>,
+    /// See [`CastOptions::with_parse_formats`]
+    parse_formats: &'a [&'a str],
+    /// See [`CastOptions::with_decimal_rounding`]
+    decimal_rounding: DecimalRoundingMode,
 }
 
 impl<'a> Default for CastOptions<'a> {
     fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> CastOptions<'a> {
+    /// Returns the default [`CastOptions`]
+    pub const fn new() -> Self {
         Self {
             safe: true,
-            format_options: FormatOptions::default(),
+            format_options: FormatOptions::new(),
+            parse_formats: &[],
+            decimal_rounding: DecimalRoundingMode::HalfUp,
+        }
+    }
+
+    /// Overrides how cast failures are handled, see [`CastOptions::safe`]
+    pub const fn with_safe(self, safe: bool) -> Self {
+        Self { safe, ..self }
+    }
+
+    /// Overrides the formatting used when casting to string
+    pub const fn with_format_options(self, format_options: FormatOptions<'a>) -> Self {
+        Self {
+            format_options,
+            ..self
+        }
+    }
+
+    /// Overrides the [`chrono::format::strftime`] formats used when casting from
+    /// string to timestamp or date types
+    ///
+    /// Each format is tried in order, and the first to successfully parse a value
+    /// is used. If empty, which is the default, RFC3339-style parsing rules are used
+    pub const fn with_parse_formats(self, parse_formats: &'a [&'a str]) -> Self {
+        Self {
+            parse_formats,
+            ..self
+        }
+    }
+
+    /// Overrides how values are rounded when casting to a decimal type with fewer
+    /// fractional digits than the input, including casts from floating point types
+    ///
+    /// Defaults to [`DecimalRoundingMode::HalfUp`]
+    pub const fn with_decimal_rounding(
+        self,
+        decimal_rounding: DecimalRoundingMode,
+    ) -> Self {
+        Self {
+            decimal_rounding,
+            ..self
+        }
+    }
+}
+
+/// The rounding applied when a cast to a decimal type discards fractional digits
+///
+/// Values that do not fit in the precision of the target type after rounding
+/// are handled according to [`CastOptions::safe`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DecimalRoundingMode {
+    /// Round to the nearest value, rounding ties away from zero
+    ///
+    /// e.g. `1.25` and `-1.25` become `1.3` and `-1.3`
+    #[default]
+    HalfUp,
+    /// Round to the nearest value, rounding ties to the nearest even value
+    ///
+    /// e.g. `1.25` and `1.35` become `1.2` and `1.4`
+    HalfEven,
+    /// Round towards zero, discarding the excess digits
+    ///
+    /// e.g. `1.29` and `-1.29` become `1.2` and `-1.2`
+    Truncate,
+}
+
+impl DecimalRoundingMode {
+    /// Divides `x` by `div`, which must be at least 2, rounding the result
+    fn div<T: ArrowNativeTypeOp>(self, x: T, div: T) -> T {
+        // div is >= 2 and so this cannot overflow
+        let d = x.div_wrapping(div);
+        let r = x.mod_wrapping(div);
+        let r = match r.is_lt(T::ZERO) {
+            true => r.neg_wrapping(),
+            false => r,
+        };
+        let away = match self {
+            Self::HalfUp => !r.is_lt(div.sub_wrapping(r)),
+            Self::HalfEven => match r.compare(div.sub_wrapping(r)) {
+                Ordering::Less => false,
+                Ordering::Equal => !d.mod_wrapping(T::ONE.add_wrapping(T::ONE)).is_zero(),
+                Ordering::Greater => true,
+            },
+            Self::Truncate => false,
+        };
+        match (away, x.is_lt(T::ZERO)) {
+            (false, _) => d,
+            (true, false) => d.add_wrapping(T::ONE),
+            (true, true) => d.sub_wrapping(T::ONE),
+        }
+    }
+
+    /// Rounds `x` to an integral value
+    fn round(self, x: f64) -> f64 {
+        match self {
+            Self::HalfUp => x.round(),
+            Self::HalfEven => {
+                let r = x.round();
+                match (r - x).abs() == 0.5 {
+                    true => 2. * (x / 2.).round(),
+                    false => r,
+                }
+            }
+            Self::Truncate => x.trunc(),
         }
     }
 }
@@ -125,6 +242,27 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         }
         (Dictionary(_, value_type), _) => can_cast_types(value_type, to_type),
         (_, Dictionary(_, value_type)) => can_cast_types(from_type, value_type),
+        (Struct(from_fields), Struct(to_fields)) => {
+            // StructArray requires at least one field
+            !to_fields.is_empty()
+                && to_fields.iter().all(|to| match from_fields.find(to.name()) {
+                    Some((_, from)) => can_cast_types(from.data_type(), to.data_type()),
+                    None => to.is_nullable(),
+                })
+        }
+        (Map(map_from, _), Map(map_to, _))
+        | (Map(map_from, _), List(map_to) | LargeList(map_to))
+        | (List(map_from) | LargeList(map_from), Map(map_to, _)) => {
+            can_cast_types(map_from.data_type(), map_to.data_type())
+        }
+        (FixedSizeList(list_from, size_from), FixedSizeList(list_to, size_to)) => {
+            size_from == size_to
+                && can_cast_types(list_from.data_type(), list_to.data_type())
+        }
+        (FixedSizeList(list_from, _), List(list_to) | LargeList(list_to))
+        | (List(list_from) | LargeList(list_from), FixedSizeList(list_to, _)) => {
+            can_cast_types(list_from.data_type(), list_to.data_type())
+        }
         (LargeList(list_from), LargeList(list_to)) => {
             can_cast_types(list_from.data_type(), list_to.data_type())
         }
@@ -140,36 +278,34 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (List(list_from) | LargeList(list_from), Utf8 | LargeUtf8) => {
             can_cast_types(list_from.data_type(), to_type)
         }
-        (List(_), _) => false,
-        (FixedSizeList(list_from,_), List(list_to)) => {
-            list_from.data_type() == list_to.data_type()
-        }
-        (FixedSizeList(list_from,_), LargeList(list_to)) => {
-            list_from.data_type() == list_to.data_type()
+        (List(list_from), ListView(list_to))
+        | (LargeList(list_from), LargeListView(list_to))
+        | (ListView(list_from), List(list_to))
+        | (LargeListView(list_from), LargeList(list_to)) => {
+            can_cast_types(list_from.data_type(), list_to.data_type())
         }
+        (List(_), _) => false,
         (_, List(list_to)) => can_cast_types(from_type, list_to.data_type()),
         (_, LargeList(list_to)) => can_cast_types(from_type, list_to.data_type()),
         // cast one decimal type to another decimal type
-        (Decimal128(_, _), Decimal128(_, _)) => true,
-        (Decimal256(_, _), Decimal256(_, _)) => true,
-        (Decimal128(_, _), Decimal256(_, _)) => true,
-        (Decimal256(_, _), Decimal128(_, _)) => true,
+        (Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _),
+         Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _)) => true,
         // unsigned integer to decimal
-        (UInt8 | UInt16 | UInt32 | UInt64, Decimal128(_, _)) |
-        (UInt8 | UInt16 | UInt32 | UInt64, Decimal256(_, _)) |
+        (UInt8 | UInt16 | UInt32 | UInt64, Decimal32(_, _) | Decimal64(_, _)) |
+        (UInt8 | UInt16 | UInt32 | UInt64, Decimal128(_, _) | Decimal256(_, _)) |
         // signed numeric to decimal
-        (Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64, Decimal128(_, _)) |
-        (Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64, Decimal256(_, _)) |
+        (Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64, Decimal32(_, _) | Decimal64(_, _)) |
+        (Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64, Decimal128(_, _) | Decimal256(_, _)) |
         // decimal to unsigned numeric
-        (Decimal128(_, _) | Decimal256(_, _), UInt8 | UInt16 | UInt32 | UInt64) |
+        (Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _), UInt8 | UInt16 | UInt32 | UInt64) |
         // decimal to signed numeric
-        (Decimal128(_, _) | Decimal256(_, _), Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64) => true,
+        (Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _), Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64) => true,
         // decimal to Utf8
-        (Decimal128(_, _) | Decimal256(_, _), Utf8 | LargeUtf8) => true,
+        (Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _), Utf8 | LargeUtf8) => true,
         // Utf8 to decimal
-        (Utf8 | LargeUtf8, Decimal128(_, _) | Decimal256(_, _)) => true,
-        (Decimal128(_, _) | Decimal256(_, _), _) => false,
-        (_, Decimal128(_, _) | Decimal256(_, _)) => false,
+        (Utf8 | LargeUtf8, Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _)) => true,
+        (Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _), _) => false,
+        (_, Decimal32(_, _) | Decimal64(_, _) | Decimal128(_, _) | Decimal256(_, _)) => false,
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
         (_, Boolean) => {
@@ -287,15 +423,61 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
 /// * Casting from `float32/float64` to `Decimal(precision, scale)` rounds to the `scale` decimals
 ///   (i.e. casting 6.4999 to Decimal(10, 1) becomes 6.5). This is the breaking change from `26.0.0`.
 ///   It used to truncate it instead of round (i.e. outputs 6.4 instead)
+/// * Casting to a decimal with a smaller scale rounds according to [`CastOptions::with_decimal_rounding`],
+///   and values that exceed the precision of the target type return null or error
+/// * Struct to Struct: children are matched by name, with missing nullable fields set to null
+/// * Map to List and List to Map: the entries are cast to the target struct type
+/// * List to FixedSizeList: lists whose length does not match the size return null or error
 ///
 /// Unsupported Casts
-/// * To or from `StructArray`
+/// * To or from `StructArray`, except Struct to Struct
 /// * List to primitive
 /// * Interval and duration
 pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<ArrayRef, ArrowError> {
     cast_with_options(array, to_type, &CastOptions::default())
 }
 
+/// Cast `array` to the provided data type, returning the indices of any values
+/// that could not be cast alongside the result
+///
+/// Rather than failing the whole cast, values that cannot be cast are set to null
+/// in the returned array, and their indices returned in ascending order. This is
+/// useful when ingesting data, such as CSV, where a small number of malformed values
+/// should be reported rather than discarded silently or aborting the cast.
+///
+/// [`CastOptions::safe`] is ignored
+///
+/// ```
+/// # use arrow_array::{Array, StringArray};
+/// # use arrow_cast::{cast_with_errors, CastOptions};
+/// # use arrow_schema::{DataType, TimeUnit};
+/// let array = StringArray::from(vec![Some("01/02/2023"), Some("foo"), None]);
+/// let options = CastOptions::new().with_parse_formats(&["%d/%m/%Y"]);
+/// let to_type = DataType::Timestamp(TimeUnit::Second, None);
+/// let (casted, errors) = cast_with_errors(&array, &to_type, &options).unwrap();
+/// assert_eq!(casted.null_count(), 2);
+/// assert_eq!(errors, vec![1]);
+/// ```
+pub fn cast_with_errors(
+    array: &dyn Array,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<(ArrayRef, Vec<usize>), ArrowError> {
+    let cast_options = CastOptions {
+        safe: true,
+        ..cast_options.clone()
+    };
+    let casted = cast_with_options(array, to_type, &cast_options)?;
+
+    let errors = match casted.nulls() {
+        Some(nulls) if casted.null_count() > array.null_count() => (0..array.len())
+            .filter(|idx| nulls.is_null(*idx) && array.is_valid(*idx))
+            .collect(),
+        _ => vec![],
+    };
+    Ok((casted, errors))
+}
+
 fn cast_integer_to_decimal<
     T: ArrowPrimitiveType,
     D: DecimalType + ArrowPrimitiveType<Native = M>,
@@ -361,16 +543,23 @@ where
     <T as ArrowPrimitiveType>::Native: AsPrimitive<f64>,
 {
     let mul = 10_f64.powi(scale as i32);
+    let rounding = cast_options.decimal_rounding;
+    let f = |v: T::Native| {
+        rounding
+            .round(mul * v.as_())
+            .to_i128()
+            .filter(|x| Decimal128Type::validate_decimal_precision(*x, precision).is_ok())
+    };
 
     if cast_options.safe {
         array
-            .unary_opt::<_, Decimal128Type>(|v| (mul * v.as_()).round().to_i128())
+            .unary_opt::<_, Decimal128Type>(f)
             .with_precision_and_scale(precision, scale)
             .map(|a| Arc::new(a) as ArrayRef)
     } else {
         array
             .try_unary::<_, Decimal128Type, _>(|v| {
-                (mul * v.as_()).round().to_i128().ok_or_else(|| {
+                f(v).ok_or_else(|| {
                     ArrowError::CastError(format!(
                         "Cannot cast to {}({}, {}). Overflowing on {:?}",
                         Decimal128Type::PREFIX,
@@ -395,16 +584,21 @@ where
     <T as ArrowPrimitiveType>::Native: AsPrimitive<f64>,
 {
     let mul = 10_f64.powi(scale as i32);
+    let rounding = cast_options.decimal_rounding;
+    let f = |v: T::Native| {
+        i256::from_f64(rounding.round(v.as_() * mul))
+            .filter(|x| Decimal256Type::validate_decimal_precision(*x, precision).is_ok())
+    };
 
     if cast_options.safe {
         array
-            .unary_opt::<_, Decimal256Type>(|v| i256::from_f64((v.as_() * mul).round()))
+            .unary_opt::<_, Decimal256Type>(f)
             .with_precision_and_scale(precision, scale)
             .map(|a| Arc::new(a) as ArrayRef)
     } else {
         array
             .try_unary::<_, Decimal256Type, _>(|v| {
-                i256::from_f64((v.as_() * mul).round()).ok_or_else(|| {
+                f(v).ok_or_else(|| {
                     ArrowError::CastError(format!(
                         "Cannot cast to {}({}, {}). Overflowing on {:?}",
                         Decimal256Type::PREFIX,
@@ -701,9 +895,12 @@ fn as_time_res_with_timezone<T: ArrowPrimitiveType>(
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Struct to Struct: children are matched by name, with missing nullable fields set to null
+/// * Map to List and List to Map: the entries are cast to the target struct type
+/// * List to FixedSizeList: lists whose length does not match the size return null or error
 ///
 /// Unsupported Casts
-/// * To or from `StructArray`
+/// * To or from `StructArray`, except Struct to Struct
 /// * List to primitive
 pub fn cast_with_options(
     array: &dyn Array,
@@ -775,6 +972,44 @@ pub fn cast_with_options(
                 "Casting from type {from_type:?} to dictionary type {to_type:?} not supported",
             ))),
         },
+        (Struct(_), Struct(to_fields)) => {
+            cast_struct_to_struct(array.as_struct(), to_fields, cast_options)
+        }
+        (Map(_, _), Map(to, ordered)) => {
+            cast_map_to_map(array.as_map(), to, *ordered, cast_options)
+        }
+        (Map(_, _), List(to)) => cast_map_to_list::<i32>(array.as_map(), to, cast_options),
+        (Map(_, _), LargeList(to)) => {
+            cast_map_to_list::<i64>(array.as_map(), to, cast_options)
+        }
+        (List(_), Map(to, ordered)) => {
+            cast_list_to_map::<i32>(array.as_list(), to, *ordered, cast_options)
+        }
+        (LargeList(_), Map(to, ordered)) => {
+            cast_list_to_map::<i64>(array.as_list(), to, *ordered, cast_options)
+        }
+        (FixedSizeList(_, size_from), FixedSizeList(to, size_to)) => {
+            if size_from != size_to {
+                Err(ArrowError::CastError(
+                    "cannot cast fixed-size-list to fixed-size-list with different size"
+                        .into(),
+                ))
+            } else {
+                cast_fixed_size_list_inner(array.as_fixed_size_list(), to, cast_options)
+            }
+        }
+        (List(_), FixedSizeList(to, size)) => cast_list_to_fixed_size_list::<i32>(
+            array.as_list(),
+            to,
+            *size,
+            cast_options,
+        ),
+        (LargeList(_), FixedSizeList(to, size)) => cast_list_to_fixed_size_list::<i64>(
+            array.as_list(),
+            to,
+            *size,
+            cast_options,
+        ),
         (List(_), List(ref to)) => {
             cast_list_inner::<i32>(array, to, to_type, cast_options)
         }
@@ -799,6 +1034,14 @@ pub fn cast_with_options(
                 cast_list_container::<i64, i32>(array, cast_options)
             }
         }
+        (List(_), ListView(to)) => cast_list_to_list_view::<i32>(array, to, cast_options),
+        (LargeList(_), LargeListView(to)) => {
+            cast_list_to_list_view::<i64>(array, to, cast_options)
+        }
+        (ListView(_), List(to)) => cast_list_view_to_list::<i32>(array, to, cast_options),
+        (LargeListView(_), LargeList(to)) => {
+            cast_list_view_to_list::<i64>(array, to, cast_options)
+        }
         (List(_) | LargeList(_), _) => match to_type {
             Utf8 => cast_list_to_string!(array, i32),
             LargeUtf8 => cast_list_to_string!(array, i64),
@@ -806,23 +1049,11 @@ pub fn cast_with_options(
                 "Cannot cast list to non-list data types".to_string(),
             )),
         },
-        (FixedSizeList(list_from, _), List(list_to)) => {
-            if list_to.data_type() != list_from.data_type() {
-                Err(ArrowError::CastError(
-                    "cannot cast fixed-size-list to list with different child data".into(),
-                ))
-            } else {
-                cast_fixed_size_list_to_list::<i32>(array)
-            }
+        (FixedSizeList(_, _), List(to)) => {
+            cast_fixed_size_list_to_list::<i32>(array, to, cast_options)
         }
-        (FixedSizeList(list_from, _), LargeList(list_to)) => {
-            if list_to.data_type() != list_from.data_type() {
-                Err(ArrowError::CastError(
-                    "cannot cast fixed-size-list to largelist with different child data".into(),
-                ))
-            } else {
-                cast_fixed_size_list_to_list::<i64>(array)
-            }
+        (FixedSizeList(_, _), LargeList(to)) => {
+            cast_fixed_size_list_to_list::<i64>(array, to, cast_options)
         }
 
         (_, List(ref to)) => cast_values_to_list::<i32>(array, to, cast_options),
@@ -863,6 +1094,164 @@ pub fn cast_with_options(
                 cast_options,
             )
         }
+        (Decimal32(_, s1), Decimal32(p2, s2)) => {
+            cast_decimal_to_decimal_same_type::<Decimal32Type>(
+                array.as_primitive(),
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal32(_, s1), Decimal64(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal32Type, Decimal64Type>(
+                array.as_primitive(),
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal32(_, s1), Decimal128(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal32Type, Decimal128Type>(
+                array.as_primitive(),
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal32(_, s1), Decimal256(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal32Type, Decimal256Type>(
+                array.as_primitive(),
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal64(_, s1), Decimal32(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal64Type, Decimal32Type>(
+                array.as_primitive(),
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal64(_, s1), Decimal64(p2, s2)) => {
+            cast_decimal_to_decimal_same_type::<Decimal64Type>(
+                array.as_primitive(),
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal64(_, s1), Decimal128(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal64Type, Decimal128Type>(
+                array.as_primitive(),
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal64(_, s1), Decimal256(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal64Type, Decimal256Type>(
+                array.as_primitive(),
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal128(_, s1), Decimal32(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal128Type, Decimal32Type>(
+                array.as_primitive(),
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal128(_, s1), Decimal64(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal128Type, Decimal64Type>(
+                array.as_primitive(),
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal256(_, s1), Decimal32(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal256Type, Decimal32Type>(
+                array.as_primitive(),
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal256(_, s1), Decimal64(p2, s2)) => {
+            cast_decimal_to_decimal::<Decimal256Type, Decimal64Type>(
+                array.as_primitive(),
+                *s1,
+                *p2,
+                *s2,
+                cast_options,
+            )
+        }
+        (Decimal32(precision, scale), _) => {
+            // cast via the lossless widening to Decimal128
+            let array = widen_decimal::<Decimal32Type>(array, *precision, *scale)?;
+            cast_with_options(&array, to_type, cast_options)
+        }
+        (Decimal64(precision, scale), _) => {
+            // cast via the lossless widening to Decimal128
+            let array = widen_decimal::<Decimal64Type>(array, *precision, *scale)?;
+            cast_with_options(&array, to_type, cast_options)
+        }
+        (_, Decimal32(precision, scale)) => match from_type {
+            Utf8 => cast_string_to_decimal::<Decimal32Type, i32>(
+                array,
+                *precision,
+                *scale,
+                cast_options,
+            ),
+            LargeUtf8 => cast_string_to_decimal::<Decimal32Type, i64>(
+                array,
+                *precision,
+                *scale,
+                cast_options,
+            ),
+            _ => cast_to_narrow_decimal::<Decimal32Type>(
+                array,
+                *precision,
+                *scale,
+                cast_options,
+            ),
+        },
+        (_, Decimal64(precision, scale)) => match from_type {
+            Utf8 => cast_string_to_decimal::<Decimal64Type, i32>(
+                array,
+                *precision,
+                *scale,
+                cast_options,
+            ),
+            LargeUtf8 => cast_string_to_decimal::<Decimal64Type, i64>(
+                array,
+                *precision,
+                *scale,
+                cast_options,
+            ),
+            _ => cast_to_narrow_decimal::<Decimal64Type>(
+                array,
+                *precision,
+                *scale,
+                cast_options,
+            ),
+        },
         (Decimal128(_, scale), _) => {
             // cast decimal to other type
             match to_type {
@@ -1180,10 +1569,10 @@ pub fn cast_with_options(
             }
         }
         (Struct(_), _) => Err(ArrowError::CastError(
-            "Cannot cast from struct to other types".to_string(),
+            "Cannot cast from struct to other types except struct".to_string(),
         )),
         (_, Struct(_)) => Err(ArrowError::CastError(
-            "Cannot cast to struct from other types".to_string(),
+            "Cannot cast to struct from other types except struct".to_string(),
         )),
         (_, Boolean) => match from_type {
             UInt8 => cast_numeric_to_bool::<UInt8Type>(array),
@@ -1374,8 +1763,8 @@ pub fn cast_with_options(
                 "Casting from {from_type:?} to {to_type:?} not supported",
             ))),
         },
-        (from_type, LargeUtf8) if from_type.is_primitive() => value_to_string::<i64>(array, Some(&cast_options.format_options)),
-        (from_type, Utf8) if from_type.is_primitive() => value_to_string::<i32>(array, Some(&cast_options.format_options)),
+        (from_type, LargeUtf8) if from_type.is_primitive() => primitive_to_string::<i64>(array, &cast_options.format_options),
+        (from_type, Utf8) if from_type.is_primitive() => primitive_to_string::<i32>(array, &cast_options.format_options),
         // start numeric casts
         (UInt8, UInt16) => {
             cast_numeric_arrays::<UInt8Type, UInt16Type>(array, cast_options)
@@ -2198,6 +2587,10 @@ const fn time_unit_multiple(unit: &TimeUnit) -> i64 {
 /// A utility trait that provides checked conversions between
 /// decimal types inspired by [`NumCast`]
 trait DecimalCast: Sized {
+    fn to_i32(self) -> Option<i32>;
+
+    fn to_i64(self) -> Option<i64>;
+
     fn to_i128(self) -> Option<i128>;
 
     fn to_i256(self) -> Option<i256>;
@@ -2205,7 +2598,59 @@ trait DecimalCast: Sized {
     fn from_decimal<T: DecimalCast>(n: T) -> Option<Self>;
 }
 
+impl DecimalCast for i32 {
+    fn to_i32(self) -> Option<i32> {
+        Some(self)
+    }
+
+    fn to_i64(self) -> Option<i64> {
+        Some(self as i64)
+    }
+
+    fn to_i128(self) -> Option<i128> {
+        Some(self as i128)
+    }
+
+    fn to_i256(self) -> Option<i256> {
+        Some(i256::from_i128(self as i128))
+    }
+
+    fn from_decimal<T: DecimalCast>(n: T) -> Option<Self> {
+        n.to_i32()
+    }
+}
+
+impl DecimalCast for i64 {
+    fn to_i32(self) -> Option<i32> {
+        self.try_into().ok()
+    }
+
+    fn to_i64(self) -> Option<i64> {
+        Some(self)
+    }
+
+    fn to_i128(self) -> Option<i128> {
+        Some(self as i128)
+    }
+
+    fn to_i256(self) -> Option<i256> {
+        Some(i256::from_i128(self as i128))
+    }
+
+    fn from_decimal<T: DecimalCast>(n: T) -> Option<Self> {
+        n.to_i64()
+    }
+}
+
 impl DecimalCast for i128 {
+    fn to_i32(self) -> Option<i32> {
+        self.try_into().ok()
+    }
+
+    fn to_i64(self) -> Option<i64> {
+        self.try_into().ok()
+    }
+
     fn to_i128(self) -> Option<i128> {
         Some(self)
     }
@@ -2220,6 +2665,14 @@ impl DecimalCast for i128 {
 }
 
 impl DecimalCast for i256 {
+    fn to_i32(self) -> Option<i32> {
+        self.to_i128().and_then(|x| x.try_into().ok())
+    }
+
+    fn to_i64(self) -> Option<i64> {
+        self.to_i128().and_then(|x| x.try_into().ok())
+    }
+
     fn to_i128(self) -> Option<i128> {
         self.to_i128()
     }
@@ -2272,21 +2725,10 @@ where
         .unwrap()
         .pow_checked((input_scale - output_scale) as u32)?;
 
-    let half = div.div_wrapping(I::Native::from_usize(2).unwrap());
-    let half_neg = half.neg_wrapping();
-
+    let rounding = cast_options.decimal_rounding;
     let f = |x: I::Native| {
-        // div is >= 10 and so this cannot overflow
-        let d = x.div_wrapping(div);
-        let r = x.mod_wrapping(div);
-
-        // Round result
-        let adjusted = match x >= I::Native::ZERO {
-            true if r >= half => d.add_wrapping(I::Native::ONE),
-            false if r <= half_neg => d.sub_wrapping(I::Native::ONE),
-            _ => d,
-        };
-        O::Native::from_decimal(adjusted)
+        O::Native::from_decimal(rounding.div(x, div))
+            .filter(|x| O::validate_decimal_precision(*x, output_precision).is_ok())
     };
 
     Ok(match cast_options.safe {
@@ -2313,7 +2755,11 @@ where
         .unwrap()
         .pow_checked((output_scale - input_scale) as u32)?;
 
-    let f = |x| O::Native::from_decimal(x).and_then(|x| x.mul_checked(mul).ok());
+    let f = |x| {
+        O::Native::from_decimal(x)
+            .and_then(|x| x.mul_checked(mul).ok())
+            .filter(|x| O::validate_decimal_precision(*x, output_precision).is_ok())
+    };
 
     Ok(match cast_options.safe {
         true => array.unary_opt(f),
@@ -2334,10 +2780,6 @@ where
     T::Native: DecimalCast + ArrowNativeTypeOp,
 {
     let array: PrimitiveArray<T> = match input_scale.cmp(&output_scale) {
-        Ordering::Equal => {
-            // the scale doesn't change, the native value don't need to be changed
-            array.clone()
-        }
         Ordering::Greater => convert_to_smaller_scale_decimal::<T, T>(
             array,
             input_scale,
@@ -2345,8 +2787,8 @@ where
             output_scale,
             cast_options,
         )?,
-        Ordering::Less => {
-            // input_scale < output_scale
+        Ordering::Less | Ordering::Equal => {
+            // input_scale <= output_scale
             convert_to_bigger_or_equal_scale_decimal::<T, T>(
                 array,
                 input_scale,
@@ -2401,6 +2843,48 @@ where
     )?))
 }
 
+/// Losslessly widens a [`Decimal32Type`] or [`Decimal64Type`] array to a
+/// [`Decimal128Type`] array with the same precision and scale
+fn widen_decimal<D>(
+    array: &dyn Array,
+    precision: u8,
+    scale: i8,
+) -> Result<ArrayRef, ArrowError>
+where
+    D: DecimalType,
+    D::Native: DecimalCast + ArrowNativeTypeOp,
+{
+    let array = array.as_primitive::<D>();
+    let widened = array.unary::<_, Decimal128Type>(|x| i128::from_decimal(x).unwrap());
+    Ok(Arc::new(
+        widened.with_precision_and_scale(precision, scale)?,
+    ))
+}
+
+/// Casts a non-decimal array to a [`Decimal32Type`] or [`Decimal64Type`] array,
+/// by first casting to a [`Decimal128Type`] array of the same precision and scale
+fn cast_to_narrow_decimal<D>(
+    array: &dyn Array,
+    precision: u8,
+    scale: i8,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    D: DecimalType,
+    D::Native: DecimalCast + ArrowNativeTypeOp,
+{
+    validate_decimal_precision_and_scale::<D>(precision, scale)?;
+    let wide =
+        cast_with_options(array, &DataType::Decimal128(precision, scale), cast_options)?;
+    cast_decimal_to_decimal::<Decimal128Type, D>(
+        wide.as_primitive(),
+        scale,
+        precision,
+        scale,
+        cast_options,
+    )
+}
+
 /// Convert Array into a PrimitiveArray of type, and apply numeric cast
 fn cast_numeric_arrays<FROM, TO>(
     from: &dyn Array,
@@ -2483,14 +2967,93 @@ fn value_to_string<O: OffsetSizeTrait>(
     Ok(Arc::new(builder.finish()))
 }
 
+/// Cast a primitive array to a string array, formatting integers and floats
+/// directly with lexical-core when `options` permit
+fn primitive_to_string<O: OffsetSizeTrait>(
+    array: &dyn Array,
+    options: &FormatOptions,
+) -> Result<ArrayRef, ArrowError> {
+    if !options.is_plain_numeric() {
+        return value_to_string::<O>(array, Some(options));
+    }
+
+    match array.data_type() {
+        DataType::Int8 => lexical_to_string_array::<Int8Type, O>(array),
+        DataType::Int16 => lexical_to_string_array::<Int16Type, O>(array),
+        DataType::Int32 => lexical_to_string_array::<Int32Type, O>(array),
+        DataType::Int64 => lexical_to_string_array::<Int64Type, O>(array),
+        DataType::UInt8 => lexical_to_string_array::<UInt8Type, O>(array),
+        DataType::UInt16 => lexical_to_string_array::<UInt16Type, O>(array),
+        DataType::UInt32 => lexical_to_string_array::<UInt32Type, O>(array),
+        DataType::UInt64 => lexical_to_string_array::<UInt64Type, O>(array),
+        DataType::Float32 => lexical_to_string_array::<Float32Type, O>(array),
+        DataType::Float64 => lexical_to_string_array::<Float64Type, O>(array),
+        _ => value_to_string::<O>(array, Some(options)),
+    }
+}
+
+/// Formats the values of a [`PrimitiveArray`] directly into the values buffer
+/// of a string array, avoiding the per-value overheads of [`ArrayFormatter`]
+///
+/// The output is identical to that of [`ArrayFormatter`] when
+/// [`FormatOptions::is_plain_numeric`] is `true`
+fn lexical_to_string_array<T, O>(array: &dyn Array) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    T::Native: lexical_core::ToLexical,
+    O: OffsetSizeTrait,
+{
+    let array = array.as_primitive::<T>();
+    let max_size = <T::Native as lexical_core::FormattedSize>::FORMATTED_SIZE_DECIMAL;
+
+    let mut offsets = Vec::with_capacity(array.len() + 1);
+    offsets.push(O::usize_as(0));
+    // Most values are far shorter than the maximum formatted size
+    let mut values = MutableBuffer::new(array.len() * 8);
+
+    let overflow = || {
+        ArrowError::CastError(format!(
+            "Cannot cast to {:?}: offset overflow",
+            GenericStringArray::<O>::DATA_TYPE
+        ))
+    };
+
+    for idx in 0..array.len() {
+        if array.is_valid(idx) {
+            let start = values.len();
+            values.resize(start + max_size, 0);
+            let written = lexical_core::write(array.value(idx), &mut values[start..]);
+            let len = written.len();
+            values.truncate(start + len);
+        }
+        offsets.push(O::from_usize(values.len()).ok_or_else(overflow)?);
+    }
+
+    // SAFETY:
+    // Offsets are monotonically increasing and lexical-core produces valid UTF-8
+    let array = unsafe {
+        GenericStringArray::<O>::new_unchecked(
+            OffsetBuffer::new_unchecked(offsets.into()),
+            values.into(),
+            array.nulls().cloned(),
+        )
+    };
+    Ok(Arc::new(array))
+}
+
 /// Parse UTF-8
 fn parse_string<P: Parser, O: OffsetSizeTrait>(
     array: &dyn Array,
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
     let string_array = array.as_string::<O>();
+    let formats = cast_options.parse_formats;
+    let parse = |v: &str| match formats.is_empty() {
+        true => P::parse(v),
+        false => formats.iter().find_map(|f| P::parse_formatted(v, f)),
+    };
     let array = if cast_options.safe {
-        let iter = string_array.iter().map(|x| x.and_then(P::parse));
+        let iter = string_array.iter().map(|x| x.and_then(parse));
 
         // Benefit:
         //     20% performance improvement
@@ -2501,7 +3064,7 @@ fn parse_string<P: Parser, O: OffsetSizeTrait>(
         let v = string_array
             .iter()
             .map(|x| match x {
-                Some(v) => P::parse(v).ok_or_else(|| {
+                Some(v) => parse(v).ok_or_else(|| {
                     ArrowError::CastError(format!(
                         "Cannot cast string '{}' to value of {:?} type",
                         v,
@@ -2543,10 +3106,13 @@ fn cast_string_to_timestamp_impl<
     tz: &Tz,
     cast_options: &CastOptions,
 ) -> Result<PrimitiveArray<T>, ArrowError> {
+    let formats = cast_options.parse_formats;
     if cast_options.safe {
         let iter = array.iter().map(|v| {
             v.and_then(|v| {
-                let naive = string_to_datetime(tz, v).ok()?.naive_utc();
+                let naive = string_to_datetime_formatted(tz, v, formats)
+                    .ok()?
+                    .naive_utc();
                 T::make_value(naive)
             })
         });
@@ -2561,7 +3127,7 @@ fn cast_string_to_timestamp_impl<
             .iter()
             .map(|v| {
                 v.map(|v| {
-                    let naive = string_to_datetime(tz, v)?.naive_utc();
+                    let naive = string_to_datetime_formatted(tz, v, formats)?.naive_utc();
                     T::make_value(naive).ok_or_else(|| {
                         ArrowError::CastError(format!(
                             "Overflow converting {naive} to {:?}",
@@ -2750,6 +3316,19 @@ where
     T::Native: DecimalCast + ArrowNativeTypeOp,
 {
     let value_str = value_str.trim();
+    let value_str = value_str.strip_prefix('+').unwrap_or(value_str);
+    let expanded;
+    let value_str = match value_str.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => {
+            expanded = expand_decimal_exponent(mantissa, exponent).ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Invalid decimal format: {value_str:?}"
+                ))
+            })?;
+            expanded.as_str()
+        }
+        None => value_str,
+    };
     let parts: Vec<&str> = value_str.split('.').collect();
     if parts.len() > 2 {
         return Err(ArrowError::InvalidArgumentError(format!(
@@ -3248,6 +3827,62 @@ fn cast_list_inner<OffsetSize: OffsetSizeTrait>(
     Ok(Arc::new(list) as ArrayRef)
 }
 
+/// Cast a [`GenericListArray`] to a [`GenericListViewArray`] with the same offset size,
+/// reusing the offsets of the list and casting the child values to `to`
+fn cast_list_to_list_view<O: OffsetSizeTrait>(
+    array: &dyn Array,
+    to: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let list = GenericListViewArray::<O>::from(array.as_list::<O>().clone());
+    let (_, offsets, sizes, values, nulls) = list.into_parts();
+    let values = cast_with_options(values.as_ref(), to.data_type(), cast_options)?;
+    let list =
+        GenericListViewArray::<O>::try_new(to.clone(), offsets, sizes, values, nulls)?;
+    Ok(Arc::new(list))
+}
+
+/// Cast a [`GenericListViewArray`] to a [`GenericListArray`] with the same offset size
+///
+/// As the views of a list view may be out of order or overlap, the child values
+/// are rearranged so that each list occupies a contiguous range
+fn cast_list_view_to_list<O: OffsetSizeTrait>(
+    array: &dyn Array,
+    to: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let list = array.as_list_view::<O>();
+
+    let mut indices = Vec::new();
+    let mut offsets = Vec::with_capacity(list.len() + 1);
+    offsets.push(O::usize_as(0));
+    for i in 0..list.len() {
+        if list.is_valid(i) {
+            let start = list.value_offset(i).as_usize();
+            let end = start + list.value_size(i).as_usize();
+            indices.extend((start..end).map(|x| x as u64));
+        }
+        let offset = O::from_usize(indices.len()).ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Offset overflow casting {} to list",
+                list.data_type()
+            ))
+        })?;
+        offsets.push(offset);
+    }
+
+    let values = take(list.values().as_ref(), &UInt64Array::from(indices), None)?;
+    let values = cast_with_options(values.as_ref(), to.data_type(), cast_options)?;
+    let offsets = OffsetBuffer::new(offsets.into());
+    let list = GenericListArray::<O>::try_new(
+        to.clone(),
+        offsets,
+        values,
+        list.nulls().cloned(),
+    )?;
+    Ok(Arc::new(list))
+}
+
 /// A specified helper to cast from `GenericBinaryArray` to `GenericStringArray` when they have same
 /// offset size so re-encoding offset is unnecessary.
 fn cast_binary_to_string<O: OffsetSizeTrait>(
@@ -3394,13 +4029,190 @@ where
 
 fn cast_fixed_size_list_to_list<OffsetSize>(
     array: &dyn Array,
+    to: &FieldRef,
+    cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError>
 where
     OffsetSize: OffsetSizeTrait,
 {
     let fixed_size_list: &FixedSizeListArray = array.as_fixed_size_list();
     let list: GenericListArray<OffsetSize> = fixed_size_list.clone().into();
-    Ok(Arc::new(list))
+    let (_, offsets, values, nulls) = list.into_parts();
+    let values = cast_with_options(values.as_ref(), to.data_type(), cast_options)?;
+    Ok(Arc::new(GenericListArray::<OffsetSize>::try_new(
+        to.clone(),
+        offsets,
+        values,
+        nulls,
+    )?))
+}
+
+/// Cast the values of a [`FixedSizeListArray`], preserving its size
+fn cast_fixed_size_list_inner(
+    array: &FixedSizeListArray,
+    to: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let values =
+        cast_with_options(array.values().as_ref(), to.data_type(), cast_options)?;
+    Ok(Arc::new(FixedSizeListArray::try_new(
+        to.clone(),
+        array.value_length(),
+        values,
+        array.nulls().cloned(),
+    )?))
+}
+
+/// Cast a [`GenericListArray`] to a [`FixedSizeListArray`] of `size`
+///
+/// Null slots are permitted to have any length. A non-null slot whose length is not
+/// `size` is set to null if [`CastOptions::safe`], otherwise an error is returned
+fn cast_list_to_fixed_size_list<OffsetSize: OffsetSizeTrait>(
+    array: &GenericListArray<OffsetSize>,
+    to: &FieldRef,
+    size: i32,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let cap = array.len() * size as usize;
+    let mut indices = Vec::with_capacity(cap);
+    let mut nulls = BooleanBufferBuilder::new(array.len());
+
+    for (idx, w) in array.value_offsets().windows(2).enumerate() {
+        let (start, end) = (w[0].as_usize(), w[1].as_usize());
+        let valid = array.is_valid(idx);
+        if valid && end - start == size as usize {
+            indices.extend((start..end).map(|x| Some(x as u64)));
+            nulls.append(true);
+            continue;
+        }
+
+        if valid && !cast_options.safe {
+            return Err(ArrowError::CastError(format!(
+                "Cannot cast list of length {} to fixed-size-list of size {size}",
+                end - start
+            )));
+        }
+        indices.extend(std::iter::repeat(None).take(size as usize));
+        nulls.append(false);
+    }
+
+    let indices = UInt64Array::from(indices);
+    let values = take(array.values().as_ref(), &indices, None)?;
+    let values = cast_with_options(values.as_ref(), to.data_type(), cast_options)?;
+    let nulls = NullBuffer::new(nulls.finish());
+    Ok(Arc::new(FixedSizeListArray::try_new(
+        to.clone(),
+        size,
+        values,
+        (nulls.null_count() > 0).then_some(nulls),
+    )?))
+}
+
+/// Cast a [`StructArray`] to the provided `to_fields`, matching children by name
+///
+/// Children not present in `to_fields` are dropped, and fields in `to_fields` not
+/// present in `array` are filled with nulls
+fn cast_struct_to_struct(
+    array: &StructArray,
+    to_fields: &Fields,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let columns = to_fields
+        .iter()
+        .map(|to| match array.fields().find(to.name()) {
+            Some((idx, _)) => cast_with_options(
+                array.column(idx).as_ref(),
+                to.data_type(),
+                cast_options,
+            ),
+            None if to.is_nullable() => Ok(new_null_array(to.data_type(), array.len())),
+            None => Err(ArrowError::CastError(format!(
+                "Cannot cast struct to struct with missing non-nullable field {:?}",
+                to.name()
+            ))),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if columns.is_empty() {
+        return Err(ArrowError::CastError(
+            "Cannot cast to struct with no fields".to_string(),
+        ));
+    }
+
+    Ok(Arc::new(StructArray::try_new(
+        to_fields.clone(),
+        columns,
+        array.nulls().cloned(),
+    )?))
+}
+
+/// Cast the entries of a [`MapArray`] to a list of `to`
+fn cast_map_to_list<OffsetSize: OffsetSizeTrait>(
+    array: &MapArray,
+    to: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let entries = cast_with_options(array.entries(), to.data_type(), cast_options)?;
+    let offsets = array
+        .offsets()
+        .iter()
+        .map(|x| OffsetSize::usize_as(x.as_usize()));
+    // Safety: offsets were already valid
+    let offsets = unsafe { OffsetBuffer::new_unchecked(offsets.collect()) };
+
+    Ok(Arc::new(GenericListArray::<OffsetSize>::try_new(
+        to.clone(),
+        offsets,
+        entries,
+        array.nulls().cloned(),
+    )?))
+}
+
+/// Cast a list of structs to a [`MapArray`] with entries of `to`
+fn cast_list_to_map<OffsetSize: OffsetSizeTrait>(
+    array: &GenericListArray<OffsetSize>,
+    to: &FieldRef,
+    ordered: bool,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let entries =
+        cast_with_options(array.values().as_ref(), to.data_type(), cast_options)?;
+    let offsets = array
+        .offsets()
+        .iter()
+        .map(|x| {
+            i32::try_from(x.as_usize()).map_err(|_| {
+                ArrowError::ComputeError("LargeList too large to cast to Map".into())
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    // Safety: offsets were already valid
+    let offsets = unsafe { OffsetBuffer::new_unchecked(offsets.into()) };
+
+    Ok(Arc::new(MapArray::try_new(
+        to.clone(),
+        offsets,
+        entries.as_struct().clone(),
+        array.nulls().cloned(),
+        ordered,
+    )?))
+}
+
+/// Cast the entries of a [`MapArray`] to `to`
+fn cast_map_to_map(
+    array: &MapArray,
+    to: &FieldRef,
+    ordered: bool,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let entries = cast_with_options(array.entries(), to.data_type(), cast_options)?;
+    Ok(Arc::new(MapArray::try_new(
+        to.clone(),
+        array.offsets().clone(),
+        entries.as_struct().clone(),
+        array.nulls().cloned(),
+        ordered,
+    )?))
 }
 
 /// Cast the container type of List/Largelist array but not the inner types.
@@ -3500,6 +4312,7 @@ mod tests {
             let cast_option = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let casted_array_with_option =
                 cast_with_options($INPUT_ARRAY, $OUTPUT_TYPE, &cast_option).unwrap();
@@ -3670,6 +4483,163 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cast_decimal_to_decimal_rounding_mode() {
+        let array = vec![
+            Some(1125),
+            Some(1135),
+            Some(1129),
+            Some(-1125),
+            Some(-1135),
+            Some(-1129),
+            None,
+        ];
+        let array = create_decimal_array(array, 10, 3).unwrap();
+        let cases = [
+            (
+                DecimalRoundingMode::HalfUp,
+                [113, 114, 113, -113, -114, -113],
+            ),
+            (
+                DecimalRoundingMode::HalfEven,
+                [112, 114, 113, -112, -114, -113],
+            ),
+            (
+                DecimalRoundingMode::Truncate,
+                [112, 113, 112, -112, -113, -112],
+            ),
+        ];
+        for (decimal_rounding, expected) in cases {
+            let options = CastOptions {
+                decimal_rounding,
+                ..Default::default()
+            };
+            let expected: Vec<_> = expected.into_iter().map(Some).chain([None]).collect();
+
+            let to_type = DataType::Decimal128(10, 2);
+            let result = cast_with_options(&array, &to_type, &options).unwrap();
+            let result = result.as_primitive::<Decimal128Type>();
+            assert_eq!(result.iter().collect::<Vec<_>>(), expected);
+
+            let to_type = DataType::Decimal256(10, 2);
+            let result = cast_with_options(&array, &to_type, &options).unwrap();
+            let result = result.as_primitive::<Decimal256Type>();
+            let expected: Vec<_> =
+                expected.iter().map(|x| x.map(i256::from_i128)).collect();
+            assert_eq!(result.iter().collect::<Vec<_>>(), expected);
+
+            let to_type = DataType::Decimal64(10, 2);
+            let result = cast_with_options(&array, &to_type, &options).unwrap();
+            let result = result.as_primitive::<Decimal64Type>();
+            let expected: Vec<_> = expected
+                .iter()
+                .map(|x| x.map(|x| x.as_i128() as i64))
+                .collect();
+            assert_eq!(result.iter().collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn test_cast_decimal_to_decimal_precision_overflow() {
+        let array =
+            create_decimal_array(vec![Some(1234), Some(-9995), None], 10, 2).unwrap();
+
+        // -99.95 rounds to -100.0, which exceeds Decimal128(3, 1)
+        let to_type = DataType::Decimal128(3, 1);
+        let result = cast(&array, &to_type).unwrap();
+        let result = result.as_primitive::<Decimal128Type>();
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            vec![Some(123), None, None]
+        );
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast to Decimal128(3, 1). Overflowing on -9995"
+        );
+
+        // Truncating -99.95 gives -99.9, which fits in Decimal128(3, 1)
+        let options = CastOptions::new()
+            .with_safe(false)
+            .with_decimal_rounding(DecimalRoundingMode::Truncate);
+        let result = cast_with_options(&array, &to_type, &options).unwrap();
+        let result = result.as_primitive::<Decimal128Type>();
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            vec![Some(123), Some(-999), None]
+        );
+
+        // Casts that do not reduce the scale also check the precision
+        let to_type = DataType::Decimal256(3, 2);
+        let result = cast(&array, &to_type).unwrap();
+        assert_eq!(result.null_count(), 3);
+
+        let to_type = DataType::Decimal128(5, 3);
+        let result = cast(&array, &to_type).unwrap();
+        let result = result.as_primitive::<Decimal128Type>();
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            vec![Some(12340), Some(-99950), None]
+        );
+        let to_type = DataType::Decimal128(4, 3);
+        let result = cast(&array, &to_type).unwrap();
+        assert_eq!(result.null_count(), 3);
+    }
+
+    #[test]
+    fn test_cast_floating_point_to_decimal_rounding_mode() {
+        let array = Float64Array::from(vec![
+            Some(1.25),
+            Some(1.35),
+            Some(-1.25),
+            Some(-1.29),
+            Some(123.4),
+            None,
+        ]);
+        let cases = [
+            (DecimalRoundingMode::HalfUp, [13, 14, -13, -13]),
+            (DecimalRoundingMode::HalfEven, [12, 14, -12, -13]),
+            (DecimalRoundingMode::Truncate, [12, 13, -12, -12]),
+        ];
+        for (decimal_rounding, expected) in cases {
+            let options = CastOptions {
+                decimal_rounding,
+                ..Default::default()
+            };
+            // 123.4 exceeds the precision of Decimal(3, 1)
+            let expected: Vec<_> =
+                expected.into_iter().map(Some).chain([None, None]).collect();
+
+            let to_type = DataType::Decimal128(3, 1);
+            let result = cast_with_options(&array, &to_type, &options).unwrap();
+            let result = result.as_primitive::<Decimal128Type>();
+            assert_eq!(result.iter().collect::<Vec<_>>(), expected);
+
+            let to_type = DataType::Decimal256(3, 1);
+            let result = cast_with_options(&array, &to_type, &options).unwrap();
+            let result = result.as_primitive::<Decimal256Type>();
+            let expected: Vec<_> =
+                expected.iter().map(|x| x.map(i256::from_i128)).collect();
+            assert_eq!(result.iter().collect::<Vec<_>>(), expected);
+        }
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let to_type = DataType::Decimal128(3, 1);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast to Decimal128(3, 1). Overflowing on 123.4"
+        );
+    }
+
     #[test]
     fn test_cast_decimal128_to_decimal128() {
         let input_type = DataType::Decimal128(20, 3);
@@ -3695,9 +4665,7 @@ mod tests {
         assert!(result.is_ok());
         let array = result.unwrap();
         let array: &Decimal128Array = array.as_primitive();
-        let err = array.validate_decimal_precision(2);
-        assert_eq!("Invalid argument error: 12345600 is too large to store in a Decimal128 of precision 2. Max is 99",
-                   err.unwrap_err().to_string());
+        assert!(array.is_null(0));
     }
 
     #[test]
@@ -3714,6 +4682,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal128(38, 38). Overflowing on 170141183460469231731687303715884105727",
@@ -3734,12 +4703,100 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal256(76, 76). Overflowing on 170141183460469231731687303715884105727",
                    result.unwrap_err().to_string());
     }
 
+    #[test]
+    fn test_cast_decimal32_decimal64() {
+        let array = Decimal32Array::from(vec![Some(12345), None, Some(-1)])
+            .with_precision_and_scale(7, 2)
+            .unwrap();
+        let array = Arc::new(array) as ArrayRef;
+
+        // widen to every other decimal type
+        for to_type in [
+            DataType::Decimal64(12, 3),
+            DataType::Decimal128(20, 3),
+            DataType::Decimal256(40, 3),
+        ] {
+            assert!(can_cast_types(array.data_type(), &to_type));
+            let casted = cast(&array, &to_type).unwrap();
+            assert_eq!(casted.data_type(), &to_type);
+            let strings = cast(&casted, &DataType::Utf8).unwrap();
+            let strings = strings.as_string::<i32>();
+            assert_eq!(strings.value(0), "123.450");
+            assert!(strings.is_null(1));
+            assert_eq!(strings.value(2), "-0.010");
+        }
+
+        // narrow to a smaller scale, rounding half away from zero
+        let casted = cast(&array, &DataType::Decimal32(5, 1)).unwrap();
+        let casted = casted.as_primitive::<Decimal32Type>();
+        assert_eq!(casted.value(0), 1235);
+        assert!(casted.is_null(1));
+        assert_eq!(casted.value(2), 0);
+
+        // to and from numeric and string types
+        let ints = cast(&array, &DataType::Int64).unwrap();
+        assert_eq!(ints.as_primitive::<Int64Type>().value(0), 123);
+        let floats = cast(&array, &DataType::Float64).unwrap();
+        assert_eq!(floats.as_primitive::<Float64Type>().value(0), 123.45);
+
+        let ints = Int32Array::from(vec![Some(7), None, Some(-12)]);
+        let casted = cast(&ints, &DataType::Decimal64(10, 2)).unwrap();
+        let casted = casted.as_primitive::<Decimal64Type>();
+        assert_eq!(casted.data_type(), &DataType::Decimal64(10, 2));
+        assert_eq!(casted.value(0), 700);
+        assert!(casted.is_null(1));
+        assert_eq!(casted.value(2), -1200);
+
+        let strings = StringArray::from(vec![Some("1.125"), Some("x"), Some("-0.5")]);
+        let casted = cast(&strings, &DataType::Decimal32(9, 2)).unwrap();
+        let casted = casted.as_primitive::<Decimal32Type>();
+        assert_eq!(casted.value(0), 113);
+        assert!(casted.is_null(1));
+        assert_eq!(casted.value(2), -50);
+    }
+
+    #[test]
+    fn test_cast_to_decimal32_overflow() {
+        let array = Decimal64Array::from(vec![Some(i64::MAX), Some(1)])
+            .with_precision_and_scale(18, 0)
+            .unwrap();
+        let array = Arc::new(array) as ArrayRef;
+
+        let casted = cast(&array, &DataType::Decimal32(9, 0)).unwrap();
+        let casted = casted.as_primitive::<Decimal32Type>();
+        assert!(casted.is_null(0));
+        assert_eq!(casted.value(1), 1);
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&array, &DataType::Decimal32(9, 0), &options)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "Cast error: Cannot cast to Decimal32(9, 0). Overflowing on 9223372036854775807"
+        );
+
+        let ints = Int64Array::from(vec![i64::MAX]);
+        let casted = cast(&ints, &DataType::Decimal32(9, 0)).unwrap();
+        assert!(casted.is_null(0));
+
+        let err = cast(&ints, &DataType::Decimal32(10, 0)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: precision 10 is greater than max 9"
+        );
+    }
+
     #[test]
     fn test_cast_decimal128_to_decimal256() {
         let input_type = DataType::Decimal128(20, 3);
@@ -3773,6 +4830,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal128(38, 7). Overflowing on 170141183460469231731687303715884105727",
@@ -3792,6 +4850,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal256(76, 55). Overflowing on 170141183460469231731687303715884105727",
@@ -3945,6 +5004,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -3958,6 +5018,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -3972,6 +5033,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -3985,6 +5047,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -4148,6 +5211,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -4161,6 +5225,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -4578,6 +5643,7 @@ mod tests {
         let cast_option = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let result = cast_with_options(&array, &DataType::UInt8, &cast_option);
         assert!(result.is_err());
@@ -4708,6 +5774,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         match result {
@@ -4741,6 +5808,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         match casted {
@@ -4950,6 +6018,7 @@ mod tests {
                 let options = CastOptions {
                     safe: false,
                     format_options: FormatOptions::default(),
+                    ..Default::default()
                 };
                 let err = cast_with_options(array, &to_type, &options).unwrap_err();
                 assert_eq!(
@@ -4968,6 +6037,71 @@ mod tests {
         assert_eq!(result.values(), &[247112596800]);
     }
 
+    #[test]
+    fn test_cast_string_to_timestamp_formatted() {
+        let array = StringArray::from(vec![
+            Some("08/09/2020 13:42"),
+            Some("20200908"),
+            Some("2020-09-08T13:42:29+01:00"),
+            Some("2020-09-08T13:42:29"),
+            None,
+        ]);
+        let options = CastOptions {
+            parse_formats: &["%d/%m/%Y %H:%M", "%Y%m%d", "%Y-%m-%dT%H:%M:%S%:z"],
+            ..Default::default()
+        };
+        let to_type = DataType::Timestamp(TimeUnit::Second, None);
+        let result = cast_with_options(&array, &to_type, &options).unwrap();
+        let result = result.as_primitive::<TimestampSecondType>();
+        assert_eq!(result.value(0), 1599572520);
+        assert_eq!(result.value(1), 1599523200);
+        assert_eq!(result.value(2), 1599568949);
+        assert!(result.is_null(3));
+        assert!(result.is_null(4));
+
+        let options = CastOptions {
+            safe: false,
+            ..options
+        };
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Error parsing timestamp from '2020-09-08T13:42:29'"));
+    }
+
+    #[test]
+    fn test_cast_string_to_date_formatted() {
+        let array = StringArray::from(vec![Some("25/12/2018"), Some("2018-12-25"), None]);
+        let options = CastOptions {
+            parse_formats: &["%d/%m/%Y"],
+            ..Default::default()
+        };
+        let result = cast_with_options(&array, &DataType::Date32, &options).unwrap();
+        let result = result.as_primitive::<Date32Type>();
+        assert_eq!(result.value(0), 17890);
+        assert!(result.is_null(1));
+        assert!(result.is_null(2));
+    }
+
+    #[test]
+    fn test_cast_with_errors() {
+        let array = StringArray::from(vec![Some("1"), Some("a"), None, Some("b")]);
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let (result, errors) =
+            cast_with_errors(&array, &DataType::Int32, &options).unwrap();
+        let result = result.as_primitive::<Int32Type>();
+        assert_eq!(result.value(0), 1);
+        assert_eq!(result.null_count(), 3);
+        assert_eq!(errors, vec![1, 3]);
+
+        let array = Int32Array::from(vec![Some(1), None]);
+        let (_, errors) = cast_with_errors(&array, &DataType::Int64, &options).unwrap();
+        assert!(errors.is_empty());
+    }
+
     #[test]
     fn test_cast_string_to_date32() {
         let a1 = Arc::new(StringArray::from(vec![
@@ -4991,6 +6125,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid date' to value of Date32 type");
@@ -5026,6 +6161,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Second) type");
@@ -5061,6 +6197,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Millisecond) type");
@@ -5090,6 +6227,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Microsecond) type");
@@ -5119,6 +6257,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Nanosecond) type");
@@ -5148,6 +6287,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid date' to value of Date64 type");
@@ -5162,6 +6302,7 @@ mod tests {
             let options = CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
 
             let target_interval_array = cast_with_options(
@@ -5289,6 +6430,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let arrow_err = cast_with_options(
                 &string_array.clone(),
@@ -5395,6 +6537,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(array_ref.is_err());
@@ -5405,6 +6548,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(array_ref.is_err());
@@ -5493,6 +6637,7 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let b = cast_with_options(&array, &DataType::Date64, &options);
         assert!(b.is_err());
@@ -5868,6 +7013,7 @@ mod tests {
             format_options: FormatOptions::default()
                 .with_timestamp_format(Some(ts_format))
                 .with_timestamp_tz_format(Some(ts_format)),
+            ..Default::default()
         };
         // "2018-12-25T00:00:02.001", "1997-05-19T00:00:03.005", None
         let array_without_tz = TimestampMillisecondArray::from(vec![
@@ -6022,6 +7168,73 @@ mod tests {
         assert_eq!(out, vec![Some("1"), Some("2"), Some("3")]);
     }
 
+    #[test]
+    fn test_cast_numeric_to_strings_matches_formatter() {
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(Int8Array::from(vec![
+                Some(i8::MIN),
+                None,
+                Some(0),
+                Some(i8::MAX),
+            ])),
+            Arc::new(UInt64Array::from(vec![Some(0), Some(u64::MAX), None])),
+            Arc::new(Int64Array::from(vec![Some(i64::MIN), Some(-1), None])),
+            Arc::new(Float32Array::from(vec![
+                Some(1.5),
+                None,
+                Some(f32::NAN),
+                Some(f32::NEG_INFINITY),
+                Some(-0.0),
+                Some(1e-10),
+            ])),
+            Arc::new(Float64Array::from(vec![
+                Some(f64::MAX),
+                Some(f64::MIN_POSITIVE),
+                Some(100.0),
+                None,
+                Some(f64::INFINITY),
+            ])),
+            Arc::new(Int32Array::from(vec![1, 22, 333, 4444]).slice(1, 3)),
+        ];
+
+        let options = [
+            FormatOptions::default(),
+            FormatOptions::default().with_thousands_separator(Some(',')),
+            FormatOptions::default()
+                .with_float_format(crate::display::FloatFormat::Fixed(2)),
+        ];
+
+        for array in &arrays {
+            for format_options in &options {
+                let cast_options = CastOptions {
+                    safe: true,
+                    format_options: format_options.clone(),
+                    ..Default::default()
+                };
+                let formatter = ArrayFormatter::try_new(array, format_options).unwrap();
+                let expected: Vec<_> = (0..array.len())
+                    .map(|i| array.is_valid(i).then(|| formatter.value(i).to_string()))
+                    .collect();
+
+                let out =
+                    cast_with_options(array, &DataType::Utf8, &cast_options).unwrap();
+                let out: Vec<_> = out.as_string::<i32>().iter().collect();
+                assert_eq!(
+                    out,
+                    expected.iter().map(|x| x.as_deref()).collect::<Vec<_>>()
+                );
+
+                let out = cast_with_options(array, &DataType::LargeUtf8, &cast_options)
+                    .unwrap();
+                let out: Vec<_> = out.as_string::<i64>().iter().collect();
+                assert_eq!(
+                    out,
+                    expected.iter().map(|x| x.as_deref()).collect::<Vec<_>>()
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_str_to_str_casts() {
         for data in vec![
@@ -7493,6 +8706,181 @@ mod tests {
         assert_eq!(expected.values(), actual.values());
     }
 
+    #[test]
+    fn test_cast_fixed_size_list_to_list_with_values() {
+        let array = Arc::new(make_fixed_size_list_array()) as ArrayRef;
+        let to_type = DataType::List(Arc::new(Field::new("item", DataType::Utf8, true)));
+        assert!(can_cast_types(array.data_type(), &to_type));
+
+        let result = cast(&array, &to_type).unwrap();
+        let list = result.as_list::<i32>();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.value_offsets(), &[0, 4, 8]);
+        let values = list.values().as_string::<i32>();
+        assert_eq!(values.value(0), "0");
+        assert_eq!(values.value(7), "7");
+    }
+
+    #[test]
+    fn test_cast_list_to_fixed_size_list() {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3), None]),
+            Some(vec![Some(4)]),
+        ]);
+        let to_type = DataType::FixedSizeList(
+            Arc::new(Field::new("item", DataType::Int64, true)),
+            2,
+        );
+        assert!(can_cast_types(list.data_type(), &to_type));
+
+        let result = cast(&list, &to_type).unwrap();
+        let result = result.as_fixed_size_list();
+        assert_eq!(result.len(), 4);
+        assert_eq!(result.null_count(), 2);
+        assert!(result.is_null(1));
+        assert!(result.is_null(3));
+
+        let values = result.values().as_primitive::<Int64Type>();
+        assert_eq!(values.len(), 8);
+        assert_eq!(values.value(0), 1);
+        assert_eq!(values.value(1), 2);
+        assert_eq!(values.value(4), 3);
+        assert!(values.is_null(5));
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&list, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast list of length 1 to fixed-size-list of size 2"
+        );
+
+        let back = cast(result, list.data_type()).unwrap();
+        let back = back.as_list::<i32>();
+        assert_eq!(back.value_offsets(), &[0, 2, 4, 6, 8]);
+        assert_eq!(back.nulls(), result.nulls());
+    }
+
+    #[test]
+    fn test_cast_fixed_size_list_to_fixed_size_list() {
+        let array = make_fixed_size_list_array();
+        let to_type = DataType::FixedSizeList(
+            Arc::new(Field::new("item", DataType::Int64, true)),
+            4,
+        );
+        let result = cast(&array, &to_type).unwrap();
+        assert_eq!(result.data_type(), &to_type);
+        let values = result
+            .as_fixed_size_list()
+            .values()
+            .as_primitive::<Int64Type>();
+        assert_eq!(values.values(), &[0, 1, 2, 3, 4, 5, 6, 7]);
+
+        let to_type = DataType::FixedSizeList(
+            Arc::new(Field::new("item", DataType::Int64, true)),
+            2,
+        );
+        assert!(!can_cast_types(array.data_type(), &to_type));
+        assert!(cast(&array, &to_type).is_err());
+    }
+
+    #[test]
+    fn test_cast_struct_to_struct() {
+        let struct_array = StructArray::try_new(
+            Fields::from(vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, true),
+            ]),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])),
+                Arc::new(StringArray::from(vec!["x", "y", "z"])),
+            ],
+            Some(NullBuffer::from(vec![true, true, false])),
+        )
+        .unwrap();
+
+        let to_fields = Fields::from(vec![
+            Field::new("c", DataType::Boolean, true),
+            Field::new("a", DataType::Int64, true),
+        ]);
+        let to_type = DataType::Struct(to_fields.clone());
+        assert!(can_cast_types(struct_array.data_type(), &to_type));
+
+        let result = cast(&struct_array, &to_type).unwrap();
+        let result = result.as_struct();
+        assert_eq!(result.fields(), &to_fields);
+        assert_eq!(result.nulls(), struct_array.nulls());
+        assert_eq!(result.column(0).null_count(), 3);
+        let a = result.column(1).as_primitive::<Int64Type>();
+        assert_eq!(a, &Int64Array::from(vec![Some(1), None, Some(3)]));
+
+        // Missing non-nullable field
+        let to_type =
+            DataType::Struct(vec![Field::new("c", DataType::Boolean, false)].into());
+        assert!(!can_cast_types(struct_array.data_type(), &to_type));
+        let err = cast(&struct_array, &to_type).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast struct to struct with missing non-nullable field \"c\""
+        );
+
+        // No fields
+        let to_type = DataType::Struct(Fields::empty());
+        assert!(!can_cast_types(struct_array.data_type(), &to_type));
+        let err = cast(&struct_array, &to_type).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast to struct with no fields"
+        );
+    }
+
+    #[test]
+    fn test_cast_map_to_list_and_back() {
+        let keys = vec!["a", "b", "c"];
+        let values = Int32Array::from(vec![1, 2, 3]);
+        let map =
+            MapArray::new_from_strings(keys.into_iter(), &values, &[0, 2, 3]).unwrap();
+
+        let entries = Fields::from(vec![
+            Field::new("keys", DataType::Utf8, false),
+            Field::new("values", DataType::Int64, true),
+        ]);
+        let list_type = DataType::List(Arc::new(Field::new(
+            "entries",
+            DataType::Struct(entries.clone()),
+            false,
+        )));
+        assert!(can_cast_types(map.data_type(), &list_type));
+
+        let list = cast(&map, &list_type).unwrap();
+        let list = list.as_list::<i32>();
+        assert_eq!(list.value_offsets(), &[0, 2, 3]);
+        let list_values = list.values().as_struct();
+        assert_eq!(
+            list_values.column(1).as_primitive::<Int64Type>().values(),
+            &[1, 2, 3]
+        );
+
+        let map_fields = Fields::from(vec![
+            Field::new("keys", DataType::Utf8, false),
+            Field::new("values", DataType::Int32, false),
+        ]);
+        let map_type = DataType::Map(
+            Arc::new(Field::new("entries", DataType::Struct(map_fields), false)),
+            false,
+        );
+        assert!(can_cast_types(&list_type, &map_type));
+        let back = cast(list, &map_type).unwrap();
+        let back = back.as_map();
+        assert_eq!(back.value_offsets(), map.value_offsets());
+        assert_eq!(back.keys(), map.keys());
+        assert_eq!(back.values(), map.values());
+    }
+
     #[test]
     fn test_cast_list_containers() {
         // large-list to list
@@ -7527,6 +8915,58 @@ mod tests {
         assert_eq!(&expected.value(2), &actual.value(2));
     }
 
+    #[test]
+    fn test_cast_list_view() {
+        // list view with out of order and overlapping views:
+        //  [[2, 3], null, [0, 1, 2], []]
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        let values = Arc::new(Int32Array::from(vec![0, 1, 2, 3]));
+        let list_view = ListViewArray::new(
+            field,
+            ScalarBuffer::from(vec![2, 0, 0, 4]),
+            ScalarBuffer::from(vec![2, 0, 3, 0]),
+            values,
+            Some(NullBuffer::from(vec![true, false, true, true])),
+        );
+
+        let to_field = Arc::new(Field::new("item", DataType::Int64, true));
+        let list = cast(&list_view, &DataType::List(to_field.clone())).unwrap();
+        let list = list.as_list::<i32>();
+        assert_eq!(list.value_offsets(), &[0, 2, 2, 5, 5]);
+        assert_eq!(list.nulls(), list_view.nulls());
+        assert_eq!(
+            list.values().as_primitive::<Int64Type>().values(),
+            &[2, 3, 0, 1, 2]
+        );
+
+        let back = cast(list, &DataType::ListView(to_field)).unwrap();
+        let back = back.as_list_view::<i32>();
+        assert_eq!(back.offsets(), &[0, 2, 2, 5]);
+        assert_eq!(back.sizes(), &[2, 0, 3, 0]);
+        assert_eq!(back.nulls(), list_view.nulls());
+
+        let large = Arc::new(make_large_list_array()) as ArrayRef;
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        assert!(can_cast_types(
+            large.data_type(),
+            &DataType::LargeListView(field.clone())
+        ));
+        assert!(!can_cast_types(
+            large.data_type(),
+            &DataType::ListView(field)
+        ));
+        let view = cast(
+            &large,
+            &DataType::LargeListView(Arc::new(Field::new("item", DataType::Int32, true))),
+        )
+        .unwrap();
+        let view = view.as_list_view::<i64>();
+        assert_eq!(
+            view.value(2).as_ref(),
+            large.as_list::<i64>().value(2).as_ref()
+        );
+    }
+
     fn make_list_array() -> ListArray {
         // Construct a value array
         let value_data = ArrayData::builder(DataType::Int32)
@@ -7755,6 +9195,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -7766,6 +9207,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -7781,6 +9223,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -7792,6 +9235,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -7807,6 +9251,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -7818,6 +9263,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -7838,6 +9284,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -7849,6 +9296,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -7956,7 +9404,7 @@ mod tests {
     #[test]
     fn test_cast_decimal128_to_decimal256_negative() {
         let input_type = DataType::Decimal128(10, 3);
-        let output_type = DataType::Decimal256(10, 5);
+        let output_type = DataType::Decimal256(76, 5);
         assert!(can_cast_types(&input_type, &output_type));
         let array = vec![Some(i128::MAX), Some(i128::MIN)];
         let input_decimal_array = create_decimal_array(array, 10, 3).unwrap();
@@ -8178,6 +9626,7 @@ mod tests {
         let option = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let casted_err = cast_with_options(&array, &output_type, &option).unwrap_err();
         assert!(casted_err
@@ -8192,6 +9641,28 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_cast_utf8_to_decimal_exponent() {
+        let array = StringArray::from(vec![
+            Some("1.2345e2"),
+            Some("+1.5E-1"),
+            Some("-12e1"),
+            Some("+42"),
+            Some("5e-4"),
+            Some("1e"),
+            Some("1e1000"),
+        ]);
+        let casted = cast(&array, &DataType::Decimal128(38, 2)).unwrap();
+        let decimal_arr = casted.as_primitive::<Decimal128Type>();
+        assert_eq!("123.45", decimal_arr.value_as_string(0));
+        assert_eq!("0.15", decimal_arr.value_as_string(1));
+        assert_eq!("-120.00", decimal_arr.value_as_string(2));
+        assert_eq!("42.00", decimal_arr.value_as_string(3));
+        assert_eq!("0.00", decimal_arr.value_as_string(4));
+        assert!(decimal_arr.is_null(5));
+        assert!(decimal_arr.is_null(6));
+    }
+
     fn test_cast_string_to_decimal128_overflow(overflow_array: ArrayRef) {
         let output_type = DataType::Decimal128(38, 2);
         let casted_array = cast(&overflow_array, &output_type).unwrap();
@@ -8408,6 +9879,7 @@ mod tests {
                 &CastOptions {
                     safe: false,
                     format_options: FormatOptions::default(),
+                    ..Default::default()
                 },
             )
             .unwrap();
@@ -8460,6 +9932,7 @@ mod tests {
         let options = CastOptions {
             safe: true,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let array = cast_with_options(&s, &DataType::Utf8, &options).unwrap();
         let a = array.as_string::<i32>();
@@ -8557,6 +10030,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8568,6 +10042,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Invalid argument error: 1234567000 is too large to store in a Decimal128 of precision 7. Max is 9999999", err.unwrap_err().to_string());
@@ -8583,6 +10058,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -8594,6 +10070,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!("Invalid argument error: 1234567000 is too large to store in a Decimal256 of precision 7. Max is 9999999", err.unwrap_err().to_string());
@@ -8640,6 +10117,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -8670,6 +10148,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -8700,6 +10179,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -8723,6 +10203,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         )
         .unwrap();
@@ -8753,6 +10234,7 @@ mod tests {
         let fallible = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
 
         // from interval month day nano to duration second
@@ -8921,6 +10403,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         )
         .unwrap();
@@ -8963,6 +10446,7 @@ mod tests {
     const CAST_OPTIONS: CastOptions<'static> = CastOptions {
         safe: true,
         format_options: FormatOptions::new(),
+        ..CastOptions::new()
     };
 
     #[test]
@@ -44,6 +44,82 @@ pub enum DurationFormat {
     Pretty,
 }
 
+/// Format for displaying floating point values
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IntervalFormat {
+    /// A human readable representation with every field -
+    /// `1 years 2 mons 3 days 4 hours 5 mins 6.500000000 secs`
+    Pretty,
+    /// The Postgres `postgres` interval style - `1 year 2 mons 3 days 04:05:06.5`
+    Postgres,
+    /// The Postgres `postgres_verbose` interval style -
+    /// `@ 1 year 2 mons 3 days 4 hours 5 mins 6.5 secs`
+    PostgresVerbose,
+    /// The SQL standard - `+1-2 +3 +4:05:06.5`, or `1-2` and `3 4:05:06.5` for
+    /// intervals of only years and months, or days and time
+    SqlStandard,
+    /// ISO 8601 - `P1Y2M3DT4H5M6.5S`
+    ISO8601,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FloatFormat {
+    /// The shortest representation that roundtrips - `1234.5`, `1e-7`
+    Shortest,
+    /// Positional notation with the given number of decimal places - `1234.50`
+    Fixed(usize),
+    /// Scientific notation with the given number of decimal places - `1.23e3`
+    Scientific(usize),
+}
+
+/// Format for displaying nested values, such as lists, structs, maps and unions
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NestedFormat {
+    /// A human readable representation - `{a: [1, 2], b: foo}`
+    Native,
+    /// A compact JSON literal - `{"a":[1,2],"b":"foo"}`
+    ///
+    /// Nested nulls are written as `null`, and nested string, binary and temporal
+    /// values as JSON strings. Map keys are always written as JSON strings.
+    Json,
+}
+
+impl NestedFormat {
+    /// Returns the separator between the elements of a nested value
+    fn item_separator(self) -> &'static str {
+        match self {
+            Self::Native => ", ",
+            Self::Json => ",",
+        }
+    }
+
+    /// Returns the separator between a key and its value
+    fn key_separator(self) -> &'static str {
+        match self {
+            Self::Native => ": ",
+            Self::Json => ":",
+        }
+    }
+}
+
+/// Layout used when pretty printing record batches as a table
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TableFormat {
+    /// An ASCII table with borders
+    Table,
+    /// A markdown table, escaping `|` within values
+    Markdown,
+    /// Values separated by the given delimiter, e.g. `'\t'` or `','`
+    ///
+    /// Values containing the delimiter, a `"` or a newline are quoted, with
+    /// any `"` within the value doubled
+    Delimited(char),
+}
+
 /// Options for formatting arrays
 ///
 /// By default nulls are formatted as `""` and temporal types formatted
@@ -68,6 +144,22 @@ pub struct FormatOptions<'a> {
     time_format: TimeFormat<'a>,
     /// Duration format
     duration_format: DurationFormat,
+    /// Interval format
+    interval_format: IntervalFormat,
+    /// Float format
+    float_format: FloatFormat,
+    /// Separator to insert between groups of thousands for numeric arrays
+    thousands_separator: Option<char>,
+    /// Format for nested values
+    nested_format: NestedFormat,
+    /// Maximum number of characters of a pretty printed value
+    max_column_width: Option<usize>,
+    /// Maximum number of pretty printed rows
+    max_rows: Option<usize>,
+    /// If the header of pretty printed tables should be included
+    header: bool,
+    /// Layout of pretty printed tables
+    table_format: TableFormat,
 }
 
 impl<'a> Default for FormatOptions<'a> {
@@ -87,6 +179,14 @@ impl<'a> FormatOptions<'a> {
             timestamp_tz_format: None,
             time_format: None,
             duration_format: DurationFormat::ISO8601,
+            interval_format: IntervalFormat::Pretty,
+            float_format: FloatFormat::Shortest,
+            thousands_separator: None,
+            nested_format: NestedFormat::Native,
+            max_column_width: None,
+            max_rows: None,
+            header: true,
+            table_format: TableFormat::Table,
         }
     }
 
@@ -156,6 +256,120 @@ impl<'a> FormatOptions<'a> {
             ..self
         }
     }
+
+    /// Overrides the format used for [`DataType::Interval`] columns
+    ///
+    /// Defaults to [`IntervalFormat::Pretty`]
+    pub const fn with_interval_format(self, interval_format: IntervalFormat) -> Self {
+        Self {
+            interval_format,
+            ..self
+        }
+    }
+
+    /// Overrides the format used for [`DataType::Float16`], [`DataType::Float32`]
+    /// and [`DataType::Float64`] columns
+    ///
+    /// Defaults to [`FloatFormat::Shortest`]
+    pub const fn with_float_format(self, float_format: FloatFormat) -> Self {
+        Self {
+            float_format,
+            ..self
+        }
+    }
+
+    /// Returns `true` if integer and floating point values are formatted as
+    /// their shortest representation without any grouping, i.e. as written
+    /// by [`lexical_core::write`]
+    pub(crate) fn is_plain_numeric(&self) -> bool {
+        self.thousands_separator.is_none()
+            && matches!(self.float_format, FloatFormat::Shortest)
+    }
+
+    /// Overrides the separator inserted between groups of thousands in the integer
+    /// part of integer, floating point and decimal columns, e.g. `1,234,567.89`
+    ///
+    /// Defaults to `None`
+    pub const fn with_thousands_separator(
+        self,
+        thousands_separator: Option<char>,
+    ) -> Self {
+        Self {
+            thousands_separator,
+            ..self
+        }
+    }
+
+    /// Overrides the format used for [`DataType::List`], [`DataType::Struct`],
+    /// [`DataType::Map`], [`DataType::Union`] and other nested columns
+    ///
+    /// Defaults to [`NestedFormat::Native`]
+    pub const fn with_nested_format(self, nested_format: NestedFormat) -> Self {
+        Self {
+            nested_format,
+            ..self
+        }
+    }
+
+    /// Overrides the maximum number of characters of values and column names when
+    /// pretty printing, longer values are truncated and end with `…`
+    ///
+    /// Defaults to `None`
+    pub const fn with_max_column_width(self, max_column_width: Option<usize>) -> Self {
+        Self {
+            max_column_width,
+            ..self
+        }
+    }
+
+    /// Overrides the maximum number of rows when pretty printing, if there are
+    /// more rows they are replaced by a single row of `...`
+    ///
+    /// Defaults to `None`
+    pub const fn with_max_rows(self, max_rows: Option<usize>) -> Self {
+        Self { max_rows, ..self }
+    }
+
+    /// If set to `false` the column names are omitted when pretty printing
+    ///
+    /// Defaults to `true`
+    pub const fn with_header(self, header: bool) -> Self {
+        Self { header, ..self }
+    }
+
+    /// Overrides the layout used when pretty printing
+    ///
+    /// Defaults to [`TableFormat::Table`]
+    pub const fn with_table_format(self, table_format: TableFormat) -> Self {
+        Self {
+            table_format,
+            ..self
+        }
+    }
+
+    /// Returns the maximum number of characters of a pretty printed value
+    #[cfg(feature = "prettyprint")]
+    pub(crate) fn max_column_width(&self) -> Option<usize> {
+        self.max_column_width
+    }
+
+    /// Returns the maximum number of pretty printed rows
+    #[cfg(feature = "prettyprint")]
+    pub(crate) fn max_rows(&self) -> Option<usize> {
+        self.max_rows
+    }
+
+    /// Returns `true` if pretty printed tables include a header
+    #[cfg(feature = "prettyprint")]
+    pub(crate) fn header(&self) -> bool {
+        self.header
+    }
+
+    /// Returns the layout of pretty printed tables
+    #[cfg(feature = "prettyprint")]
+    pub(crate) fn table_format(&self) -> TableFormat {
+        self.table_format
+    }
 }
 
 /// Implements [`Display`] for a specific array value
@@ -302,6 +516,8 @@ fn make_formatter<'a>(
         }
         DataType::List(_) => array_format(as_generic_list_array::<i32>(array), options),
         DataType::LargeList(_) => array_format(as_generic_list_array::<i64>(array), options),
+        DataType::ListView(_) => array_format(array.as_list_view::<i32>(), options),
+        DataType::LargeListView(_) => array_format(array.as_list_view::<i64>(), options),
         DataType::FixedSizeList(_, _) => {
             let a = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
             array_format(a, options)
@@ -405,20 +621,51 @@ impl<'a> DisplayIndex for &'a NullArray {
     }
 }
 
+/// Writes the numeric string `s` to `f`, inserting `separator` between groups
+/// of thousands in its integer part, if any
+fn write_grouped(f: &mut dyn Write, s: &str, separator: Option<char>) -> FormatResult {
+    let separator = match separator {
+        Some(separator) => separator,
+        None => return Ok(f.write_str(s)?),
+    };
+
+    let (sign, rest) = match s.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", s),
+    };
+    let digits = rest.bytes().take_while(|b| b.is_ascii_digit()).count();
+    let (integer, remainder) = rest.split_at(digits);
+
+    f.write_str(sign)?;
+    for (idx, c) in integer.chars().enumerate() {
+        if idx != 0 && (digits - idx) % 3 == 0 {
+            f.write_char(separator)?;
+        }
+        f.write_char(c)?;
+    }
+    f.write_str(remainder)?;
+    Ok(())
+}
+
 macro_rules! primitive_display {
     ($($t:ty),+) => {
-        $(impl<'a> DisplayIndex for &'a PrimitiveArray<$t>
+        $(impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<$t>
         {
-            fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
+            type State = Option<char>;
+
+            fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+                Ok(options.thousands_separator)
+            }
+
+            fn write(&self, s: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
                 let value = self.value(idx);
                 let mut buffer = [0u8; <$t as ArrowPrimitiveType>::Native::FORMATTED_SIZE];
                 // SAFETY:
                 // buffer is T::FORMATTED_SIZE
                 let b = unsafe { lexical_core::write_unchecked(value, &mut buffer) };
                 // Lexical core produces valid UTF-8
-                let s = unsafe { std::str::from_utf8_unchecked(b) };
-                f.write_str(s)?;
-                Ok(())
+                let formatted = unsafe { std::str::from_utf8_unchecked(b) };
+                write_grouped(f, formatted, *s)
             }
         })+
     };
@@ -426,33 +673,100 @@ macro_rules! primitive_display {
 
 primitive_display!(Int8Type, Int16Type, Int32Type, Int64Type);
 primitive_display!(UInt8Type, UInt16Type, UInt32Type, UInt64Type);
-primitive_display!(Float32Type, Float64Type);
 
-impl<'a> DisplayIndex for &'a PrimitiveArray<Float16Type> {
-    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
-        write!(f, "{}", self.value(idx))?;
-        Ok(())
+macro_rules! float_display {
+    ($($t:ty),+) => {
+        $(impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<$t>
+        {
+            type State = (FloatFormat, Option<char>);
+
+            fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+                Ok((options.float_format, options.thousands_separator))
+            }
+
+            fn write(&self, s: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
+                let value = self.value(idx);
+                match s.0 {
+                    FloatFormat::Shortest => {
+                        let mut buffer = [0u8; <$t as ArrowPrimitiveType>::Native::FORMATTED_SIZE];
+                        // SAFETY:
+                        // buffer is T::FORMATTED_SIZE
+                        let b = unsafe { lexical_core::write_unchecked(value, &mut buffer) };
+                        // Lexical core produces valid UTF-8
+                        let formatted = unsafe { std::str::from_utf8_unchecked(b) };
+                        write_grouped(f, formatted, s.1)
+                    }
+                    FloatFormat::Fixed(precision) => match s.1 {
+                        Some(_) => write_grouped(f, &format!("{value:.precision$}"), s.1),
+                        None => {
+                            write!(f, "{value:.precision$}")?;
+                            Ok(())
+                        }
+                    },
+                    FloatFormat::Scientific(precision) => {
+                        write!(f, "{value:.precision$e}")?;
+                        Ok(())
+                    }
+                }
+            }
+        })+
+    };
+}
+
+float_display!(Float32Type, Float64Type);
+
+impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<Float16Type> {
+    type State = (FloatFormat, Option<char>);
+
+    fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+        Ok((options.float_format, options.thousands_separator))
+    }
+
+    fn write(&self, s: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
+        let value = self.value(idx);
+        match s.0 {
+            FloatFormat::Shortest => match s.1 {
+                Some(_) => write_grouped(f, &value.to_string(), s.1),
+                None => {
+                    write!(f, "{value}")?;
+                    Ok(())
+                }
+            },
+            FloatFormat::Fixed(precision) => match s.1 {
+                Some(_) => {
+                    write_grouped(f, &format!("{:.precision$}", value.to_f32()), s.1)
+                }
+                None => {
+                    write!(f, "{:.precision$}", value.to_f32())?;
+                    Ok(())
+                }
+            },
+            FloatFormat::Scientific(precision) => {
+                write!(f, "{:.precision$e}", value.to_f32())?;
+                Ok(())
+            }
+        }
     }
 }
 
 macro_rules! decimal_display {
     ($($t:ty),+) => {
         $(impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<$t> {
-            type State = (u8, i8);
+            type State = (u8, i8, Option<char>);
 
-            fn prepare(&self, _options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
-                Ok((self.precision(), self.scale()))
+            fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+                Ok((self.precision(), self.scale(), options.thousands_separator))
             }
 
             fn write(&self, s: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
-                write!(f, "{}", <$t>::format_decimal(self.values()[idx], s.0, s.1))?;
-                Ok(())
+                let formatted = <$t>::format_decimal(self.values()[idx], s.0, s.1);
+                write_grouped(f, &formatted, s.2)
             }
         })+
     };
 }
 
-decimal_display!(Decimal128Type, Decimal256Type);
+decimal_display!(Decimal32Type, Decimal64Type, Decimal128Type, Decimal256Type);
 
 fn write_timestamp(
     f: &mut dyn Write,
@@ -643,89 +957,288 @@ duration_display!(duration_ms_to_duration, DurationMillisecondType, 3);
 duration_display!(duration_us_to_duration, DurationMicrosecondType, 6);
 duration_display!(duration_ns_to_duration, DurationNanosecondType, 9);
 
-impl<'a> DisplayIndex for &'a PrimitiveArray<IntervalYearMonthType> {
-    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
-        let interval = self.value(idx) as f64;
-        let years = (interval / 12_f64).floor();
-        let month = interval - (years * 12_f64);
-
-        write!(
-            f,
-            "{years} years {month} mons 0 days 0 hours 0 mins 0.00 secs",
-        )?;
-        Ok(())
-    }
+fn interval_ym_pretty(value: i32, f: &mut dyn Write) -> FormatResult {
+    let interval = value as f64;
+    let years = (interval / 12_f64).floor();
+    let month = interval - (years * 12_f64);
+
+    write!(
+        f,
+        "{years} years {month} mons 0 days 0 hours 0 mins 0.00 secs",
+    )?;
+    Ok(())
 }
 
-impl<'a> DisplayIndex for &'a PrimitiveArray<IntervalDayTimeType> {
-    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
-        let value: u64 = self.value(idx) as u64;
+fn interval_dt_pretty(value: i64, f: &mut dyn Write) -> FormatResult {
+    let value: u64 = value as u64;
 
-        let days_parts: i32 = ((value & 0xFFFFFFFF00000000) >> 32) as i32;
-        let milliseconds_part: i32 = (value & 0xFFFFFFFF) as i32;
+    let days_parts: i32 = ((value & 0xFFFFFFFF00000000) >> 32) as i32;
+    let milliseconds_part: i32 = (value & 0xFFFFFFFF) as i32;
 
-        let secs = milliseconds_part / 1_000;
-        let mins = secs / 60;
-        let hours = mins / 60;
+    let secs = milliseconds_part / 1_000;
+    let mins = secs / 60;
+    let hours = mins / 60;
 
-        let secs = secs - (mins * 60);
-        let mins = mins - (hours * 60);
+    let secs = secs - (mins * 60);
+    let mins = mins - (hours * 60);
 
-        let milliseconds = milliseconds_part % 1_000;
+    let milliseconds = milliseconds_part % 1_000;
 
-        let secs_sign = if secs < 0 || milliseconds < 0 {
-            "-"
-        } else {
-            ""
-        };
+    let secs_sign = if secs < 0 || milliseconds < 0 {
+        "-"
+    } else {
+        ""
+    };
 
-        write!(
-            f,
-            "0 years 0 mons {} days {} hours {} mins {}{}.{:03} secs",
-            days_parts,
-            hours,
-            mins,
-            secs_sign,
-            secs.abs(),
-            milliseconds.abs(),
-        )?;
-        Ok(())
-    }
+    write!(
+        f,
+        "0 years 0 mons {} days {} hours {} mins {}{}.{:03} secs",
+        days_parts,
+        hours,
+        mins,
+        secs_sign,
+        secs.abs(),
+        milliseconds.abs(),
+    )?;
+    Ok(())
 }
 
-impl<'a> DisplayIndex for &'a PrimitiveArray<IntervalMonthDayNanoType> {
-    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
-        let value: u128 = self.value(idx) as u128;
+fn interval_mdn_pretty(value: i128, f: &mut dyn Write) -> FormatResult {
+    let value: u128 = value as u128;
 
-        let months_part: i32 =
-            ((value & 0xFFFFFFFF000000000000000000000000) >> 96) as i32;
-        let days_part: i32 = ((value & 0xFFFFFFFF0000000000000000) >> 64) as i32;
-        let nanoseconds_part: i64 = (value & 0xFFFFFFFFFFFFFFFF) as i64;
+    let months_part: i32 = ((value & 0xFFFFFFFF000000000000000000000000) >> 96) as i32;
+    let days_part: i32 = ((value & 0xFFFFFFFF0000000000000000) >> 64) as i32;
+    let nanoseconds_part: i64 = (value & 0xFFFFFFFFFFFFFFFF) as i64;
 
-        let secs = nanoseconds_part / 1_000_000_000;
-        let mins = secs / 60;
-        let hours = mins / 60;
+    let secs = nanoseconds_part / 1_000_000_000;
+    let mins = secs / 60;
+    let hours = mins / 60;
 
-        let secs = secs - (mins * 60);
-        let mins = mins - (hours * 60);
+    let secs = secs - (mins * 60);
+    let mins = mins - (hours * 60);
 
-        let nanoseconds = nanoseconds_part % 1_000_000_000;
-
-        let secs_sign = if secs < 0 || nanoseconds < 0 { "-" } else { "" };
-
-        write!(
-            f,
-            "0 years {} mons {} days {} hours {} mins {}{}.{:09} secs",
-            months_part,
-            days_part,
-            hours,
-            mins,
-            secs_sign,
-            secs.abs(),
-            nanoseconds.abs(),
-        )?;
-        Ok(())
+    let nanoseconds = nanoseconds_part % 1_000_000_000;
+
+    let secs_sign = if secs < 0 || nanoseconds < 0 { "-" } else { "" };
+
+    write!(
+        f,
+        "0 years {} mons {} days {} hours {} mins {}{}.{:09} secs",
+        months_part,
+        days_part,
+        hours,
+        mins,
+        secs_sign,
+        secs.abs(),
+        nanoseconds.abs(),
+    )?;
+    Ok(())
+}
+
+macro_rules! interval_display {
+    ($t:ty, $pretty:ident, $parts:expr) => {
+        impl<'a> DisplayIndexState<'a> for &'a PrimitiveArray<$t> {
+            type State = IntervalFormat;
+
+            fn prepare(
+                &self,
+                options: &FormatOptions<'a>,
+            ) -> Result<Self::State, ArrowError> {
+                Ok(options.interval_format)
+            }
+
+            fn write(
+                &self,
+                fmt: &Self::State,
+                idx: usize,
+                f: &mut dyn Write,
+            ) -> FormatResult {
+                let v = self.value(idx);
+                match fmt {
+                    IntervalFormat::Pretty => $pretty(v, f),
+                    fmt => {
+                        let (months, days, nanos) = $parts(v);
+                        interval_fmt(f, *fmt, months, days, nanos)
+                    }
+                }
+            }
+        }
+    };
+}
+
+interval_display!(IntervalYearMonthType, interval_ym_pretty, |v| (v, 0, 0));
+interval_display!(IntervalDayTimeType, interval_dt_pretty, |v| {
+    let (days, millis) = IntervalDayTimeType::to_parts(v);
+    (0, days, millis as i64 * 1_000_000)
+});
+interval_display!(IntervalMonthDayNanoType, interval_mdn_pretty, |v| {
+    IntervalMonthDayNanoType::to_parts(v)
+});
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+
+/// Splits `nanos` into hours, minutes, seconds and nanoseconds, all with the
+/// sign of `nanos`
+fn split_nanos(nanos: i64) -> (i64, i64, i64, i64) {
+    let secs = nanos / NANOS_PER_SECOND;
+    (
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60,
+        nanos % NANOS_PER_SECOND,
+    )
+}
+
+/// Writes the absolute value of `secs` and `subsec` nanoseconds, omitting any
+/// trailing zeros of the fractional part
+fn write_seconds(
+    f: &mut dyn Write,
+    secs: i64,
+    subsec: i64,
+    width: usize,
+) -> FormatResult {
+    write!(f, "{:0width$}", secs.unsigned_abs())?;
+    if subsec != 0 {
+        let frac = format!("{:09}", subsec.unsigned_abs());
+        write!(f, ".{}", frac.trim_end_matches('0'))?;
+    }
+    Ok(())
+}
+
+/// Writes `nanos` as `[-]h:mm:ss[.fffffffff]`, with the hours padded to `width`
+fn write_time(f: &mut dyn Write, nanos: i64, width: usize) -> FormatResult {
+    let (hours, mins, secs, subsec) = split_nanos(nanos);
+    let sign = if nanos < 0 { "-" } else { "" };
+    write!(
+        f,
+        "{sign}{:0width$}:{:02}:",
+        hours.unsigned_abs(),
+        mins.unsigned_abs()
+    )?;
+    write_seconds(f, secs, subsec, 2)
+}
+
+/// Formats an interval of `months`, `days` and `nanos` in the given [`IntervalFormat`]
+fn interval_fmt(
+    f: &mut dyn Write,
+    fmt: IntervalFormat,
+    months: i32,
+    days: i32,
+    nanos: i64,
+) -> FormatResult {
+    let years = months / 12;
+    let mons = months % 12;
+    let plural = |v: i64| if v == 1 { "" } else { "s" };
+
+    match fmt {
+        IntervalFormat::Postgres => {
+            let mut sep = "";
+            for (v, unit) in [(years, "year"), (mons, "mon"), (days, "day")] {
+                if v != 0 {
+                    write!(f, "{sep}{v} {unit}{}", plural(v as i64))?;
+                    sep = " ";
+                }
+            }
+            if nanos != 0 || sep.is_empty() {
+                // Postgres marks a positive time following a negative field
+                let negative_date = years < 0 || mons < 0 || days < 0;
+                let sign = if nanos > 0 && negative_date { "+" } else { "" };
+                write!(f, "{sep}{sign}")?;
+                write_time(f, nanos, 2)?;
+            }
+        }
+        IntervalFormat::PostgresVerbose => {
+            let (hours, mins, secs, subsec) = split_nanos(nanos);
+            let negative = months <= 0 && days <= 0 && nanos <= 0;
+            let sign = if negative { -1 } else { 1 };
+
+            write!(f, "@")?;
+            let fields = [
+                (years as i64, "year"),
+                (mons as i64, "mon"),
+                (days as i64, "day"),
+                (hours, "hour"),
+                (mins, "min"),
+            ];
+            for (v, unit) in fields {
+                if v != 0 {
+                    write!(f, " {} {unit}{}", v * sign, plural(v * sign))?;
+                }
+            }
+            if secs != 0 || subsec != 0 {
+                let secs_sign = if !negative && (secs < 0 || subsec < 0) {
+                    "-"
+                } else {
+                    ""
+                };
+                write!(f, " {secs_sign}")?;
+                write_seconds(f, secs, subsec, 1)?;
+                let one = secs.abs() == 1 && subsec == 0 && secs_sign.is_empty();
+                write!(f, " sec{}", if one { "" } else { "s" })?;
+            }
+            match (months, days, nanos) {
+                (0, 0, 0) => write!(f, " 0")?,
+                _ if negative => write!(f, " ago")?,
+                _ => {}
+            }
+        }
+        IntervalFormat::SqlStandard => {
+            let has_date_time = days != 0 || nanos != 0;
+            let mixed = (months != 0 && has_date_time)
+                || (days != 0 && nanos != 0 && (days < 0) != (nanos < 0));
+            let sign = |negative: bool| match (negative, mixed) {
+                (true, _) => "-",
+                (false, true) => "+",
+                (false, false) => "",
+            };
+
+            if months != 0 {
+                let (y, m) = (years.unsigned_abs(), mons.unsigned_abs());
+                write!(f, "{}{y}-{m}", sign(months < 0))?;
+                if !has_date_time {
+                    return Ok(());
+                }
+                write!(f, " ")?;
+            }
+            if days != 0 || mixed {
+                write!(f, "{}{} ", sign(days < 0), days.unsigned_abs())?;
+            }
+            match (months, days, nanos) {
+                (0, 0, 0) => write!(f, "0")?,
+                _ => {
+                    write!(f, "{}", sign(nanos < 0))?;
+                    write_time(f, nanos.abs(), 1)?;
+                }
+            }
+        }
+        IntervalFormat::ISO8601 => {
+            let (hours, mins, secs, subsec) = split_nanos(nanos);
+            write!(f, "P")?;
+            for (v, unit) in [(years, 'Y'), (mons, 'M'), (days, 'D')] {
+                if v != 0 {
+                    write!(f, "{v}{unit}")?;
+                }
+            }
+            if nanos != 0 {
+                write!(f, "T")?;
+                for (v, unit) in [(hours, 'H'), (mins, 'M')] {
+                    if v != 0 {
+                        write!(f, "{v}{unit}")?;
+                    }
+                }
+                if secs != 0 || subsec != 0 {
+                    let sign = if secs < 0 || subsec < 0 { "-" } else { "" };
+                    write!(f, "{sign}")?;
+                    write_seconds(f, secs, subsec, 1)?;
+                    write!(f, "S")?;
+                }
+            } else if months == 0 && days == 0 {
+                write!(f, "T0S")?;
+            }
+        }
+        IntervalFormat::Pretty => unreachable!("handled by interval_display"),
     }
+    Ok(())
 }
 
 impl<'a, O: OffsetSizeTrait> DisplayIndex for &'a GenericStringArray<O> {
@@ -768,17 +1281,107 @@ impl<'a, K: ArrowDictionaryKeyType> DisplayIndexState<'a> for &'a DictionaryArra
     }
 }
 
+/// Returns a [`DisplayIndex`] for the children of a nested array
+fn make_child_formatter<'a>(
+    array: &'a dyn Array,
+    options: &FormatOptions<'a>,
+) -> Result<Box<dyn DisplayIndex + 'a>, ArrowError> {
+    match options.nested_format {
+        NestedFormat::Native => make_formatter(array, options),
+        NestedFormat::Json => {
+            let options = json_child_options(options);
+            let format = make_formatter(array, &options)?;
+            match is_json_string(array.data_type()) {
+                true => Ok(Box::new(JsonString { array, format })),
+                false => Ok(format),
+            }
+        }
+    }
+}
+
+/// Returns the [`FormatOptions`] for the children of a nested array formatted
+/// as [`NestedFormat::Json`], such that nulls and numbers are valid JSON
+fn json_child_options<'a>(options: &FormatOptions<'a>) -> FormatOptions<'a> {
+    options
+        .clone()
+        .with_null("null")
+        .with_thousands_separator(None)
+}
+
+/// Returns `true` if values of `data_type` are written as JSON strings
+fn is_json_string(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Binary
+        | DataType::LargeBinary
+        | DataType::FixedSizeBinary(_) => true,
+        d if d.is_temporal() => true,
+        DataType::Dictionary(_, v) => is_json_string(v),
+        _ => false,
+    }
+}
+
+/// Writes the non-null values of `format` as JSON strings
+struct JsonString<'a> {
+    array: &'a dyn Array,
+    format: Box<dyn DisplayIndex + 'a>,
+}
+
+impl<'a> DisplayIndex for JsonString<'a> {
+    fn write(&self, idx: usize, f: &mut dyn Write) -> FormatResult {
+        if self.array.is_null(idx) {
+            f.write_str("null")?;
+            return Ok(());
+        }
+        let mut value = String::new();
+        self.format.write(idx, &mut value)?;
+        write_json_string(f, &value)
+    }
+}
+
+/// Writes `value` as a quoted and escaped JSON string
+fn write_json_string(f: &mut dyn Write, value: &str) -> FormatResult {
+    f.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')?;
+    Ok(())
+}
+
+/// Writes the key of a struct field or union variant
+fn write_name(f: &mut dyn Write, name: &str, format: NestedFormat) -> FormatResult {
+    match format {
+        NestedFormat::Native => f.write_str(name)?,
+        NestedFormat::Json => write_json_string(f, name)?,
+    }
+    Ok(())
+}
+
+/// A [`DisplayIndex`] for the values of a list, and the [`NestedFormat`]
+type ListDisplay<'a> = (Box<dyn DisplayIndex + 'a>, NestedFormat);
+
 fn write_list(
     f: &mut dyn Write,
     mut range: Range<usize>,
     values: &dyn DisplayIndex,
+    format: NestedFormat,
 ) -> FormatResult {
     f.write_char('[')?;
     if let Some(idx) = range.next() {
         values.write(idx, f)?;
     }
     for idx in range {
-        write!(f, ", ")?;
+        f.write_str(format.item_separator())?;
         values.write(idx, f)?;
     }
     f.write_char(']')?;
@@ -786,33 +1389,49 @@ fn write_list(
 }
 
 impl<'a, O: OffsetSizeTrait> DisplayIndexState<'a> for &'a GenericListArray<O> {
-    type State = Box<dyn DisplayIndex + 'a>;
+    type State = ListDisplay<'a>;
 
     fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
-        make_formatter(self.values().as_ref(), options)
+        let values = make_child_formatter(self.values().as_ref(), options)?;
+        Ok((values, options.nested_format))
     }
 
     fn write(&self, s: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
         let offsets = self.value_offsets();
         let end = offsets[idx + 1].as_usize();
         let start = offsets[idx].as_usize();
-        write_list(f, start..end, s.as_ref())
+        write_list(f, start..end, s.0.as_ref(), s.1)
+    }
+}
+
+impl<'a, O: OffsetSizeTrait> DisplayIndexState<'a> for &'a GenericListViewArray<O> {
+    type State = ListDisplay<'a>;
+
+    fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
+        let values = make_child_formatter(self.values().as_ref(), options)?;
+        Ok((values, options.nested_format))
+    }
+
+    fn write(&self, s: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
+        let start = self.value_offset(idx).as_usize();
+        let end = start + self.value_size(idx).as_usize();
+        write_list(f, start..end, s.0.as_ref(), s.1)
     }
 }
 
 impl<'a> DisplayIndexState<'a> for &'a FixedSizeListArray {
-    type State = (usize, Box<dyn DisplayIndex + 'a>);
+    type State = (usize, ListDisplay<'a>);
 
     fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
-        let values = make_formatter(self.values().as_ref(), options)?;
+        let values = make_child_formatter(self.values().as_ref(), options)?;
         let length = self.value_length();
-        Ok((length as usize, values))
+        Ok((length as usize, (values, options.nested_format)))
     }
 
     fn write(&self, s: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
         let start = idx * s.0;
         let end = start + s.0;
-        write_list(f, start..end, s.1.as_ref())
+        write_list(f, start..end, s.1 .0.as_ref(), s.1 .1)
     }
 }
 
@@ -820,7 +1439,7 @@ impl<'a> DisplayIndexState<'a> for &'a FixedSizeListArray {
 type FieldDisplay<'a> = (&'a str, Box<dyn DisplayIndex + 'a>);
 
 impl<'a> DisplayIndexState<'a> for &'a StructArray {
-    type State = Vec<FieldDisplay<'a>>;
+    type State = (Vec<FieldDisplay<'a>>, NestedFormat);
 
     fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
         let fields = match (*self).data_type() {
@@ -828,25 +1447,27 @@ impl<'a> DisplayIndexState<'a> for &'a StructArray {
             _ => unreachable!(),
         };
 
-        self.columns()
+        let fields = self
+            .columns()
             .iter()
             .zip(fields)
             .map(|(a, f)| {
-                let format = make_formatter(a.as_ref(), options)?;
+                let format = make_child_formatter(a.as_ref(), options)?;
                 Ok((f.name().as_str(), format))
             })
-            .collect()
+            .collect::<Result<_, ArrowError>>()?;
+        Ok((fields, options.nested_format))
     }
 
     fn write(&self, s: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
-        let mut iter = s.iter();
+        let (fields, format) = s;
         f.write_char('{')?;
-        if let Some((name, display)) = iter.next() {
-            write!(f, "{name}: ")?;
-            display.as_ref().write(idx, f)?;
-        }
-        for (name, display) in iter {
-            write!(f, ", {name}: ")?;
+        for (i, (name, display)) in fields.iter().enumerate() {
+            if i != 0 {
+                f.write_str(format.item_separator())?;
+            }
+            write_name(f, name, *format)?;
+            f.write_str(format.key_separator())?;
             display.as_ref().write(idx, f)?;
         }
         f.write_char('}')?;
@@ -855,44 +1476,51 @@ impl<'a> DisplayIndexState<'a> for &'a StructArray {
 }
 
 impl<'a> DisplayIndexState<'a> for &'a MapArray {
-    type State = (Box<dyn DisplayIndex + 'a>, Box<dyn DisplayIndex + 'a>);
+    type State = (
+        Box<dyn DisplayIndex + 'a>,
+        Box<dyn DisplayIndex + 'a>,
+        NestedFormat,
+    );
 
     fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
-        let keys = make_formatter(self.keys().as_ref(), options)?;
-        let values = make_formatter(self.values().as_ref(), options)?;
-        Ok((keys, values))
+        let keys = self.keys().as_ref();
+        let keys = match options.nested_format {
+            NestedFormat::Native => make_formatter(keys, options)?,
+            NestedFormat::Json => {
+                // JSON object keys must be strings
+                let format = make_formatter(keys, &json_child_options(options))?;
+                Box::new(JsonString {
+                    array: keys,
+                    format,
+                })
+            }
+        };
+        let values = make_child_formatter(self.values().as_ref(), options)?;
+        Ok((keys, values, options.nested_format))
     }
 
     fn write(&self, s: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
         let offsets = self.value_offsets();
         let end = offsets[idx + 1].as_usize();
         let start = offsets[idx].as_usize();
-        let mut iter = start..end;
+        let (keys, values, format) = s;
 
         f.write_char('{')?;
-        if let Some(idx) = iter.next() {
-            s.0.write(idx, f)?;
-            write!(f, ": ")?;
-            s.1.write(idx, f)?;
-        }
-
-        for idx in iter {
-            write!(f, ", ")?;
-            s.0.write(idx, f)?;
-            write!(f, ": ")?;
-            s.1.write(idx, f)?;
+        for idx in start..end {
+            if idx != start {
+                f.write_str(format.item_separator())?;
+            }
+            keys.write(idx, f)?;
+            f.write_str(format.key_separator())?;
+            values.write(idx, f)?;
         }
-
         f.write_char('}')?;
         Ok(())
     }
 }
 
 impl<'a> DisplayIndexState<'a> for &'a UnionArray {
-    type State = (
-        Vec<Option<(&'a str, Box<dyn DisplayIndex + 'a>)>>,
-        UnionMode,
-    );
+    type State = (Vec<Option<FieldDisplay<'a>>>, UnionMode, NestedFormat);
 
     fn prepare(&self, options: &FormatOptions<'a>) -> Result<Self::State, ArrowError> {
         let (fields, mode) = match (*self).data_type() {
@@ -903,10 +1531,10 @@ impl<'a> DisplayIndexState<'a> for &'a UnionArray {
         let max_id = fields.iter().map(|(id, _)| id).max().unwrap_or_default() as usize;
         let mut out: Vec<Option<FieldDisplay>> = (0..max_id + 1).map(|_| None).collect();
         for (i, field) in fields.iter() {
-            let formatter = make_formatter(self.child(i).as_ref(), options)?;
+            let formatter = make_child_formatter(self.child(i).as_ref(), options)?;
             out[i as usize] = Some((field.name().as_str(), formatter))
         }
-        Ok((out, *mode))
+        Ok((out, *mode, options.nested_format))
     }
 
     fn write(&self, s: &Self::State, idx: usize, f: &mut dyn Write) -> FormatResult {
@@ -917,7 +1545,12 @@ impl<'a> DisplayIndexState<'a> for &'a UnionArray {
         };
         let (name, field) = s.0[id as usize].as_ref().unwrap();
 
-        write!(f, "{{{name}=")?;
+        f.write_char('{')?;
+        write_name(f, name, s.2)?;
+        match s.2 {
+            NestedFormat::Native => f.write_char('=')?,
+            NestedFormat::Json => f.write_char(':')?,
+        }
         field.write(idx, f)?;
         f.write_char('}')?;
         Ok(())
@@ -959,6 +1592,8 @@ pub fn lexical_to_string<N: lexical_core::ToLexical>(n: N) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use arrow_buffer::Buffer;
+    use std::sync::Arc;
 
     /// Test to verify options can be constant. See #4580
     const TEST_CONST_OPTIONS: FormatOptions<'static> = FormatOptions::new()
@@ -1098,4 +1733,221 @@ mod tests {
         assert_eq!(iso[5], "-P45DT50554S");
         assert_eq!(pretty[5], "-45 days -14 hours -2 mins -34 secs");
     }
+
+    #[test]
+    fn test_array_value_to_string_interval_format() {
+        let nanos = ((4 * 60 + 5) * 60 + 6) * 1_000_000_000 + 500_000_000;
+        let array = IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNanoType::make_value(14, 3, nanos),
+            IntervalMonthDayNanoType::make_value(-14, -3, -nanos),
+            IntervalMonthDayNanoType::make_value(0, -1, 2 * 3_600_000_000_000),
+            IntervalMonthDayNanoType::make_value(14, 0, 0),
+            IntervalMonthDayNanoType::make_value(0, 3, 0),
+            IntervalMonthDayNanoType::make_value(0, 0, 0),
+        ]);
+        let format =
+            |fmt| format_array(&array, &FormatOptions::new().with_interval_format(fmt));
+
+        assert_eq!(
+            format(IntervalFormat::Postgres),
+            vec![
+                "1 year 2 mons 3 days 04:05:06.5",
+                "-1 years -2 mons -3 days -04:05:06.5",
+                "-1 days +02:00:00",
+                "1 year 2 mons",
+                "3 days",
+                "00:00:00",
+            ]
+        );
+        assert_eq!(
+            format(IntervalFormat::PostgresVerbose),
+            vec![
+                "@ 1 year 2 mons 3 days 4 hours 5 mins 6.5 secs",
+                "@ 1 year 2 mons 3 days 4 hours 5 mins 6.5 secs ago",
+                "@ -1 days 2 hours",
+                "@ 1 year 2 mons",
+                "@ 3 days",
+                "@ 0",
+            ]
+        );
+        assert_eq!(
+            format(IntervalFormat::SqlStandard),
+            vec![
+                "+1-2 +3 +4:05:06.5",
+                "-1-2 -3 -4:05:06.5",
+                "-1 +2:00:00",
+                "1-2",
+                "3 0:00:00",
+                "0",
+            ]
+        );
+        assert_eq!(
+            format(IntervalFormat::ISO8601),
+            vec![
+                "P1Y2M3DT4H5M6.5S",
+                "P-1Y-2M-3DT-4H-5M-6.5S",
+                "P-1DT2H",
+                "P1Y2M",
+                "P3D",
+                "PT0S",
+            ]
+        );
+
+        // All formats can be parsed
+        for fmt in [
+            IntervalFormat::Postgres,
+            IntervalFormat::PostgresVerbose,
+            IntervalFormat::SqlStandard,
+            IntervalFormat::ISO8601,
+        ] {
+            let parsed: Vec<_> = format(fmt)
+                .iter()
+                .map(|s| crate::parse::parse_interval_month_day_nano(s).unwrap())
+                .collect();
+            assert_eq!(parsed, array.values().to_vec(), "{fmt:?}");
+        }
+
+        let array = IntervalDayTimeArray::from(vec![
+            IntervalDayTimeType::make_value(3, 1_500),
+            IntervalDayTimeType::make_value(0, -61_000),
+        ]);
+        let options = FormatOptions::new().with_interval_format(IntervalFormat::Postgres);
+        assert_eq!(
+            format_array(&array, &options),
+            vec!["3 days 00:00:01.5", "-00:01:01"]
+        );
+
+        let array = IntervalYearMonthArray::from(vec![14, -1]);
+        let options = FormatOptions::new().with_interval_format(IntervalFormat::ISO8601);
+        assert_eq!(format_array(&array, &options), vec!["P1Y2M", "P-1M"]);
+    }
+
+    #[test]
+    fn test_array_value_to_string_float_format() {
+        let array = Float64Array::from(vec![1234.56, -0.000123, 1e20, f64::NAN]);
+
+        let shortest = format_array(&array, &FormatOptions::new());
+        assert_eq!(shortest[0], "1234.56");
+
+        let options = FormatOptions::new().with_float_format(FloatFormat::Fixed(2));
+        let fixed = format_array(&array, &options);
+        assert_eq!(
+            fixed,
+            vec!["1234.56", "-0.00", "100000000000000000000.00", "NaN"]
+        );
+
+        let options = FormatOptions::new().with_float_format(FloatFormat::Scientific(3));
+        let scientific = format_array(&array, &options);
+        assert_eq!(scientific, vec!["1.235e3", "-1.230e-4", "1.000e20", "NaN"]);
+
+        let array = Float16Array::from(vec![half::f16::from_f32(1.5)]);
+        let fixed = format_array(&array, &options);
+        assert_eq!(fixed, vec!["1.500e0"]);
+    }
+
+    #[test]
+    fn test_array_value_to_string_thousands_separator() {
+        let options = FormatOptions::new().with_thousands_separator(Some(','));
+
+        let array = Int64Array::from(vec![0, 123, -1234, 1234567, i64::MIN]);
+        let formatted = format_array(&array, &options);
+        assert_eq!(
+            formatted,
+            vec![
+                "0",
+                "123",
+                "-1,234",
+                "1,234,567",
+                "-9,223,372,036,854,775,808"
+            ]
+        );
+
+        let options = options.with_float_format(FloatFormat::Fixed(1));
+        let array = Float32Array::from(vec![-1234.56, 999.0]);
+        let formatted = format_array(&array, &options);
+        assert_eq!(formatted, vec!["-1,234.6", "999.0"]);
+
+        let array = Decimal128Array::from(vec![123456789, -100])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let formatted = format_array(&array, &options);
+        assert_eq!(formatted, vec!["1,234,567.89", "-1.00"]);
+    }
+
+    #[test]
+    fn test_array_value_to_string_nested_json() {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None, Some(1234)]),
+            None,
+            Some(vec![]),
+        ]);
+        let strings = StringArray::from(vec![Some("a\"b"), None, Some("c\nd")]);
+        let array = StructArray::from(vec![
+            (
+                Arc::new(Field::new("list", list.data_type().clone(), true)),
+                Arc::new(list) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("s", DataType::Utf8, true)),
+                Arc::new(strings) as ArrayRef,
+            ),
+        ]);
+
+        let options = FormatOptions::new()
+            .with_null("NULL")
+            .with_thousands_separator(Some(','));
+        assert_eq!(
+            format_array(&array, &options),
+            vec![
+                "{list: [1, NULL, 1,234], s: a\"b}",
+                "{list: NULL, s: NULL}",
+                "{list: [], s: c\nd}"
+            ]
+        );
+
+        let options = options.with_nested_format(NestedFormat::Json);
+        assert_eq!(
+            format_array(&array, &options),
+            vec![
+                r#"{"list":[1,null,1234],"s":"a\"b"}"#,
+                r#"{"list":null,"s":null}"#,
+                r#"{"list":[],"s":"c\nd"}"#
+            ]
+        );
+
+        let keys = vec!["a", "b", "c"];
+        let values = UInt32Array::from(vec![0u32, 10, 20]);
+        let map =
+            MapArray::new_from_strings(keys.into_iter(), &values, &[0, 2, 3]).unwrap();
+        assert_eq!(
+            format_array(&map, &options),
+            vec![r#"{"a":0,"b":10}"#, r#"{"c":20}"#]
+        );
+
+        let dates = Date32Array::from(vec![0]);
+        let dict = DictionaryArray::<Int8Type>::from_iter(["x"]);
+        let union = UnionArray::try_new(
+            &[0, 1],
+            Buffer::from_slice_ref([0_i8, 1]),
+            Some(Buffer::from_slice_ref([0_i32, 0])),
+            vec![
+                (Field::new("d", DataType::Date32, false), Arc::new(dates)),
+                (
+                    Field::new("e", dict.data_type().clone(), false),
+                    Arc::new(dict),
+                ),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            format_array(&union, &options),
+            vec![r#"{"d":"1970-01-01"}"#, r#"{"e":"x"}"#]
+        );
+
+        // Top-level values are not quoted
+        assert_eq!(
+            format_array(&StringArray::from(vec!["a"]), &options),
+            vec!["a"]
+        );
+    }
 }
@@ -17,6 +17,7 @@
 
 //! Cast kernel for [Apache Arrow](https://docs.rs/arrow)
 
+pub mod adapter;
 pub mod cast;
 pub use cast::*;
 pub mod display;
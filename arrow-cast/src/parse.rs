@@ -237,6 +237,56 @@ pub fn string_to_datetime<T: TimeZone>(
     Ok(DateTime::<Tz>::from_local(datetime, offset).with_timezone(timezone))
 }
 
+/// Parses a string to a [`DateTime`] using the first of the provided
+/// [`chrono::format::strftime`] `formats` that successfully parses `s`
+///
+/// Formats containing a timezone offset are converted to `timezone`, formats
+/// without one are interpreted as local time in `timezone`. Formats containing
+/// only a date are interpreted as midnight.
+///
+/// If `formats` is empty this is equivalent to [`string_to_datetime`]
+///
+/// ```
+/// # use arrow_cast::parse::string_to_datetime_formatted;
+/// # use chrono::Utc;
+/// let formats = ["%d/%m/%Y %H:%M", "%Y%m%d"];
+/// let date = string_to_datetime_formatted(&Utc, "23/11/2023 14:05", &formats).unwrap();
+/// assert_eq!(date.to_rfc3339(), "2023-11-23T14:05:00+00:00");
+///
+/// let date = string_to_datetime_formatted(&Utc, "20231123", &formats).unwrap();
+/// assert_eq!(date.to_rfc3339(), "2023-11-23T00:00:00+00:00");
+/// ```
+pub fn string_to_datetime_formatted<T: TimeZone>(
+    timezone: &T,
+    s: &str,
+    formats: &[&str],
+) -> Result<DateTime<T>, ArrowError> {
+    if formats.is_empty() {
+        return string_to_datetime(timezone, s);
+    }
+
+    let from_local =
+        |datetime: NaiveDateTime| timezone.from_local_datetime(&datetime).single();
+
+    formats
+        .iter()
+        .find_map(|format| {
+            if let Ok(datetime) = DateTime::parse_from_str(s, format) {
+                return Some(datetime.with_timezone(timezone));
+            }
+            if let Ok(datetime) = NaiveDateTime::parse_from_str(s, format) {
+                return from_local(datetime);
+            }
+            let date = NaiveDate::parse_from_str(s, format).ok()?;
+            from_local(date.and_time(NaiveTime::default()))
+        })
+        .ok_or_else(|| {
+            ArrowError::ParseError(format!(
+                "Error parsing timestamp from '{s}' using formats {formats:?}"
+            ))
+        })
+}
+
 /// Accepts a string in RFC3339 / ISO8601 standard format and some
 /// variants and converts it to a nanosecond precision timestamp.
 ///
@@ -691,6 +741,13 @@ pub fn parse_decimal<T: DecimalType>(
     precision: u8,
     scale: i8,
 ) -> Result<T::Native, ArrowError> {
+    if let Some((mantissa, exponent)) = s.split_once(['e', 'E']) {
+        let expanded = expand_decimal_exponent(mantissa, exponent).ok_or_else(|| {
+            ArrowError::ParseError(format!("can't parse the string value {s} to decimal"))
+        })?;
+        return parse_decimal::<T>(&expanded, precision, scale);
+    }
+
     let mut result = T::Native::usize_as(0);
     let mut fractionals = 0;
     let mut digits = 0;
@@ -776,6 +833,48 @@ pub fn parse_decimal<T: DecimalType>(
     })
 }
 
+/// Rewrites a decimal in scientific notation, i.e. `mantissa` x 10^`exponent`, in
+/// positional notation, e.g. `-1.5` and `-3` as `-0.0015`
+///
+/// Returns `None` if `mantissa` or `exponent` are not valid
+pub(crate) fn expand_decimal_exponent(mantissa: &str, exponent: &str) -> Option<String> {
+    let exponent: i32 = exponent.parse().ok()?;
+    // Larger exponents cannot yield a value representable by any decimal type
+    if exponent.unsigned_abs() > u8::MAX as u32 {
+        return None;
+    }
+
+    let (sign, mantissa) = match mantissa.strip_prefix('-') {
+        Some(mantissa) => ("-", mantissa),
+        None => ("", mantissa.strip_prefix('+').unwrap_or(mantissa)),
+    };
+    let (integers, decimals) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{integers}{decimals}");
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let point = integers.len() as i32 + exponent;
+    Some(if point <= 0 {
+        let zeros = "0".repeat(point.unsigned_abs() as usize);
+        format!("{sign}0.{zeros}{digits}")
+    } else if point as usize >= digits.len() {
+        let zeros = "0".repeat(point as usize - digits.len());
+        format!("{sign}{digits}{zeros}")
+    } else {
+        let (integers, decimals) = digits.split_at(point as usize);
+        format!("{sign}{integers}.{decimals}")
+    })
+}
+
+/// Parse `value` as an [`IntervalYearMonthType`] value
+///
+/// The following formats are accepted, see [`parse_interval_month_day_nano`]
+///
+/// * Postgres - `1 year 2 mons`, `1 yr 2 months`
+/// * Postgres verbose - `@ 1 year 2 mons ago`
+/// * SQL standard - `1-2`
+/// * ISO 8601 - `P1Y2M`
 pub fn parse_interval_year_month(
     value: &str,
 ) -> Result<<IntervalYearMonthType as ArrowPrimitiveType>::Native, ArrowError> {
@@ -789,6 +888,7 @@ pub fn parse_interval_year_month(
     Ok(IntervalYearMonthType::make_value(0, months))
 }
 
+/// Parse `value` as an [`IntervalDayTimeType`] value, see [`parse_interval_month_day_nano`]
 pub fn parse_interval_day_time(
     value: &str,
 ) -> Result<<IntervalDayTimeType as ArrowPrimitiveType>::Native, ArrowError> {
@@ -802,6 +902,21 @@ pub fn parse_interval_day_time(
     Ok(IntervalDayTimeType::make_value(days, millis))
 }
 
+/// Parse `value` as an [`IntervalMonthDayNanoType`] value
+///
+/// The following formats are accepted
+///
+/// * Postgres - `1 year 2 mons 3 days 04:05:06.5`, where units may be abbreviated
+///   and a time may be given as `[+-]hh:mm[:ss[.fffffffff]]`
+/// * Postgres verbose - `@ 1 year 2 mons 3 days 4 hours 5 mins 6.5 secs ago`,
+///   where `ago` negates the interval
+/// * SQL standard - `1-2` for years and months, `3 4:05:06.5` for days and time,
+///   or `+1-2 +3 +4:05:06.5` for both
+/// * ISO 8601 - `P1Y2M3DT4H5M6.5S`, where each amount may be negative or fractional
+///
+/// These are the formats written by [`IntervalFormat`]
+///
+/// [`IntervalFormat`]: crate::display::IntervalFormat
 pub fn parse_interval_month_day_nano(
     value: &str,
 ) -> Result<<IntervalMonthDayNanoType as ArrowPrimitiveType>::Native, ArrowError> {
@@ -845,16 +960,22 @@ impl FromStr for IntervalUnit {
         match s.to_lowercase().as_str() {
             "century" | "centuries" => Ok(Self::Century),
             "decade" | "decades" => Ok(Self::Decade),
-            "year" | "years" => Ok(Self::Year),
-            "month" | "months" => Ok(Self::Month),
-            "week" | "weeks" => Ok(Self::Week),
-            "day" | "days" => Ok(Self::Day),
-            "hour" | "hours" => Ok(Self::Hour),
-            "minute" | "minutes" => Ok(Self::Minute),
-            "second" | "seconds" => Ok(Self::Second),
-            "millisecond" | "milliseconds" => Ok(Self::Millisecond),
-            "microsecond" | "microseconds" => Ok(Self::Microsecond),
-            "nanosecond" | "nanoseconds" => Ok(Self::Nanosecond),
+            "year" | "years" | "yr" | "yrs" | "y" => Ok(Self::Year),
+            "month" | "months" | "mon" | "mons" => Ok(Self::Month),
+            "week" | "weeks" | "w" => Ok(Self::Week),
+            "day" | "days" | "d" => Ok(Self::Day),
+            "hour" | "hours" | "hr" | "hrs" | "h" => Ok(Self::Hour),
+            "minute" | "minutes" | "min" | "mins" | "m" => Ok(Self::Minute),
+            "second" | "seconds" | "sec" | "secs" | "s" => Ok(Self::Second),
+            "millisecond" | "milliseconds" | "msec" | "msecs" | "ms" => {
+                Ok(Self::Millisecond)
+            }
+            "microsecond" | "microseconds" | "usec" | "usecs" | "us" => {
+                Ok(Self::Microsecond)
+            }
+            "nanosecond" | "nanoseconds" | "nsec" | "nsecs" | "ns" => {
+                Ok(Self::Nanosecond)
+            }
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Unknown interval type: {s}"
             ))),
@@ -875,7 +996,6 @@ struct IntervalAmount {
     frac: i64,
 }
 
-#[cfg(test)]
 impl IntervalAmount {
     fn new(integer: i64, frac: i64) -> Self {
         Self { integer, frac }
@@ -999,19 +1119,51 @@ impl Interval {
     }
 
     /// Parse string value in traditional Postgres format such as
-    /// `1 year 2 months 3 days 4 hours 5 minutes 6 seconds`
+    /// `1 year 2 months 3 days 4 hours 5 minutes 6 seconds`, or any of the other
+    /// formats described in [`parse_interval_month_day_nano`]
     fn parse(value: &str, config: &IntervalParseConfig) -> Result<Self, ArrowError> {
-        let components = parse_interval_components(value, config)?;
+        let trimmed = value.trim();
+        let components = match trimmed.strip_prefix(['P', 'p']) {
+            Some(designators) => parse_iso8601_components(value, designators)?,
+            None => {
+                // Postgres verbose format, e.g. `@ 1 year 2 mons ago`
+                let trimmed = trimmed.strip_prefix('@').unwrap_or(trimmed);
+                let (trimmed, ago) = match trimmed.rsplit_once(char::is_whitespace) {
+                    Some((rest, last)) if last.eq_ignore_ascii_case("ago") => {
+                        (rest, true)
+                    }
+                    _ => (trimmed, false),
+                };
+
+                let result = Self::from_components(parse_interval_components(
+                    value, trimmed, config,
+                )?)?;
+                return match ago {
+                    true => result.negate(),
+                    false => Ok(result),
+                };
+            }
+        };
 
-        let result = components.into_iter().fold(
-            Ok(Self::default()),
-            |result, (amount, unit)| match result {
-                Ok(result) => result.add(amount, unit),
-                Err(e) => Err(e),
-            },
-        )?;
+        Self::from_components(components)
+    }
 
-        Ok(result)
+    fn from_components(
+        components: Vec<(IntervalAmount, IntervalUnit)>,
+    ) -> Result<Self, ArrowError> {
+        components
+            .into_iter()
+            .try_fold(Self::default(), |result, (amount, unit)| {
+                result.add(amount, unit)
+            })
+    }
+
+    fn negate(&self) -> Result<Self, ArrowError> {
+        Ok(Self::new(
+            self.months.neg_checked()?,
+            self.days.neg_checked()?,
+            self.nanos.neg_checked()?,
+        ))
     }
 
     /// Interval addition following Postgres behavior. Fractional units will be spilled into smaller units.
@@ -1193,65 +1345,205 @@ impl IntervalParseConfig {
     }
 }
 
+/// Records `unit` in the bitmask `observed`, returning an error if it was repeated
+fn observe_unit(
+    observed: &mut u16,
+    unit: IntervalUnit,
+    value: &str,
+    raw_unit: &str,
+) -> Result<(), ArrowError> {
+    if *observed & (unit as u16) != 0 {
+        return Err(ArrowError::ParseError(format!(
+            "Invalid input syntax for type interval: {value:?}. Repeated type '{raw_unit}'",
+        )));
+    }
+    *observed |= unit as u16;
+    Ok(())
+}
+
 /// parse the string into a vector of interval components i.e. (amount, unit) tuples
+///
+/// `value` is the original string used in error messages, and `s` the part of it
+/// containing the components
 fn parse_interval_components(
     value: &str,
+    s: &str,
     config: &IntervalParseConfig,
 ) -> Result<Vec<(IntervalAmount, IntervalUnit)>, ArrowError> {
-    let parts = value.split_whitespace();
+    let invalid = || {
+        ArrowError::ParseError(format!(
+            "Invalid input syntax for type interval: {value:?}"
+        ))
+    };
+
+    let mut tokens = s.split_whitespace().peekable();
+    let mut observed = 0;
+    let mut result = vec![];
+    let mut push = |components: Vec<(IntervalAmount, IntervalUnit)>, raw: &str| {
+        for (amount, unit) in components {
+            observe_unit(&mut observed, unit, value, raw)?;
+            result.push((amount, unit));
+        }
+        Ok::<_, ArrowError>(())
+    };
 
-    let raw_amounts = parts.clone().step_by(2);
-    let raw_units = parts.skip(1).step_by(2);
+    let mut count = 0;
+    while let Some(token) = tokens.next() {
+        count += 1;
+        if token.contains(':') {
+            push(parse_time_components(token).ok_or_else(invalid)?, token)?;
+            continue;
+        }
+        if let Some(components) = parse_year_month_components(token) {
+            push(components, token)?;
+            continue;
+        }
 
-    // parse amounts
-    let (amounts, invalid_amounts) = raw_amounts
-        .map(IntervalAmount::from_str)
-        .partition::<Vec<_>, _>(Result::is_ok);
+        let amount = IntervalAmount::from_str(token).map_err(|_| {
+            ArrowError::NotYetImplemented(format!(
+                "Unsupported Interval Expression with value {value:?}"
+            ))
+        })?;
 
-    // invalid amounts?
-    if !invalid_amounts.is_empty() {
-        return Err(ArrowError::NotYetImplemented(format!(
-            "Unsupported Interval Expression with value {value:?}"
-        )));
+        match tokens.peek() {
+            // SQL standard days followed by a time, e.g. `3 04:05:06`
+            Some(next) if next.contains(':') => {
+                push(vec![(amount, IntervalUnit::Day)], token)?
+            }
+            Some(next) => {
+                let unit = IntervalUnit::from_str(next).map_err(|_| invalid())?;
+                push(vec![(amount, unit)], next)?;
+                tokens.next();
+            }
+            // if only an amount is specified, use the default unit
+            None if count == 1 => push(vec![(amount, config.default_unit)], token)?,
+            None => return Err(invalid()),
+        }
     }
 
-    // parse units
-    let (units, invalid_units): (Vec<_>, Vec<_>) = raw_units
-        .clone()
-        .map(IntervalUnit::from_str)
-        .partition(Result::is_ok);
+    Ok(result)
+}
 
-    // invalid units?
-    if !invalid_units.is_empty() {
-        return Err(ArrowError::ParseError(format!(
-            "Invalid input syntax for type interval: {value:?}"
-        )));
+/// Parse a time of the form `[+-]hh:mm[:ss[.fffffffff]]`
+fn parse_time_components(token: &str) -> Option<Vec<(IntervalAmount, IntervalUnit)>> {
+    let (negative, time) = split_sign(token);
+    let mut parts = time.split(':');
+    let hours = parts.next()?;
+    let minutes = parts.next()?;
+    let seconds = parts.next();
+    if parts.next().is_some() || !is_digits(hours) || !is_digits(minutes) {
+        return None;
     }
 
-    // collect parsed results
-    let amounts = amounts.into_iter().map(Result::unwrap).collect::<Vec<_>>();
-    let units = units.into_iter().map(Result::unwrap).collect::<Vec<_>>();
+    let sign = if negative { -1 } else { 1 };
+    let hours: i64 = hours.parse().ok()?;
+    let minutes: i64 = minutes.parse().ok()?;
+    if minutes >= 60 {
+        return None;
+    }
+
+    let mut components = vec![
+        (IntervalAmount::new(sign * hours, 0), IntervalUnit::Hour),
+        (IntervalAmount::new(sign * minutes, 0), IntervalUnit::Minute),
+    ];
+    if let Some(seconds) = seconds {
+        let (integer, frac) = seconds.split_once('.').unwrap_or((seconds, "0"));
+        if !is_digits(integer) || !is_digits(frac) || integer.parse::<i64>().ok()? >= 60 {
+            return None;
+        }
+        let amount = match negative {
+            true => IntervalAmount::from_str(&format!("-{seconds}")),
+            false => IntervalAmount::from_str(seconds),
+        };
+        components.push((amount.ok()?, IntervalUnit::Second));
+    }
+    Some(components)
+}
 
-    // if only an amount is specified, use the default unit
-    if amounts.len() == 1 && units.is_empty() {
-        return Ok(vec![(amounts[0], config.default_unit)]);
+/// Parse a SQL standard year-month interval of the form `[+-]y-m`
+fn parse_year_month_components(
+    token: &str,
+) -> Option<Vec<(IntervalAmount, IntervalUnit)>> {
+    let (negative, year_month) = split_sign(token);
+    let (years, months) = year_month.split_once('-')?;
+    if !is_digits(years) || !is_digits(months) {
+        return None;
+    }
+
+    let sign = if negative { -1 } else { 1 };
+    let years: i64 = years.parse().ok()?;
+    let months: i64 = months.parse().ok()?;
+    if months >= 12 {
+        return None;
+    }
+    Some(vec![
+        (IntervalAmount::new(sign * years, 0), IntervalUnit::Year),
+        (IntervalAmount::new(sign * months, 0), IntervalUnit::Month),
+    ])
+}
+
+/// Parse the designators of an ISO 8601 duration, e.g. `1Y2M3DT4H5M6.5S` for
+/// `P1Y2M3DT4H5M6.5S`
+fn parse_iso8601_components(
+    value: &str,
+    designators: &str,
+) -> Result<Vec<(IntervalAmount, IntervalUnit)>, ArrowError> {
+    let invalid = || {
+        ArrowError::ParseError(format!(
+            "Invalid ISO 8601 duration for type interval: {value:?}"
+        ))
     };
 
-    // duplicate units?
-    let mut observed_interval_types = 0;
-    for (unit, raw_unit) in units.iter().zip(raw_units) {
-        if observed_interval_types & (*unit as u16) != 0 {
-            return Err(ArrowError::ParseError(format!(
-                "Invalid input syntax for type interval: {value:?}. Repeated type '{raw_unit}'",
-            )));
+    let (date, time) = match designators.split_once(['T', 't']) {
+        Some((_, "")) => return Err(invalid()),
+        Some((date, time)) => (date, time),
+        None => (designators, ""),
+    };
+    if date.is_empty() && time.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut observed = 0;
+    let mut result = vec![];
+    for (part, is_time) in [(date, false), (time, true)] {
+        let mut rest = part;
+        while !rest.is_empty() {
+            let idx = rest
+                .find(|c: char| c.is_ascii_alphabetic())
+                .ok_or_else(invalid)?;
+            let designator = rest[idx..].chars().next().unwrap();
+            let unit = match (designator.to_ascii_uppercase(), is_time) {
+                ('Y', false) => IntervalUnit::Year,
+                ('M', false) => IntervalUnit::Month,
+                ('W', false) => IntervalUnit::Week,
+                ('D', false) => IntervalUnit::Day,
+                ('H', true) => IntervalUnit::Hour,
+                ('M', true) => IntervalUnit::Minute,
+                ('S', true) => IntervalUnit::Second,
+                _ => return Err(invalid()),
+            };
+            // ISO 8601 permits a comma as the decimal separator
+            let amount = rest[..idx].replace(',', ".");
+            let amount = IntervalAmount::from_str(&amount).map_err(|_| invalid())?;
+            observe_unit(&mut observed, unit, value, &rest[idx..idx + 1])?;
+            result.push((amount, unit));
+            rest = &rest[idx + 1..];
         }
-
-        observed_interval_types |= *unit as u16;
     }
+    Ok(result)
+}
 
-    let result = amounts.iter().copied().zip(units.iter().copied());
+/// Splits an optional leading `+` or `-` from `s`, returning if it was negative
+fn split_sign(s: &str) -> (bool, &str) {
+    match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    }
+}
 
-    Ok(result.collect::<Vec<_>>())
+fn is_digits(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
 }
 
 #[cfg(test)]
@@ -2025,6 +2317,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_interval_formats() {
+        let config = IntervalParseConfig::new(IntervalUnit::Month);
+        let time = 4 * NANOS_PER_HOUR + 5 * NANOS_PER_MINUTE + 6 * NANOS_PER_SECOND;
+        let expected = Interval::new(14, 3, time + NANOS_PER_SECOND / 2);
+
+        let cases = [
+            "1 year 2 mons 3 days 04:05:06.5",
+            "1 yr 2 mon 3 d 4 h 5 min 6.5 s",
+            "@ 1 year 2 mons 3 days 4 hours 5 mins 6.5 secs",
+            "+1-2 +3 +4:05:06.5",
+            "P1Y2M3DT4H5M6.5S",
+            "p1y2m3dt4h5m6,5s",
+        ];
+        for case in cases {
+            assert_eq!(Interval::parse(case, &config).unwrap(), expected, "{case}");
+        }
+
+        let negated = Interval::new(-14, -3, -time - NANOS_PER_SECOND / 2);
+        let cases = [
+            "@ 1 year 2 mons 3 days 4 hours 5 mins 6.5 secs ago",
+            "-1 years -2 mons -3 days -04:05:06.5",
+            "-1-2 -3 -4:05:06.5",
+            "P-1Y-2M-3DT-4H-5M-6.5S",
+        ];
+        for case in cases {
+            assert_eq!(Interval::parse(case, &config).unwrap(), negated, "{case}");
+        }
+
+        let cases = [
+            ("1-2", Interval::new(14, 0, 0)),
+            ("3 4:05:06", Interval::new(0, 3, time)),
+            ("-1 days +02:00", Interval::new(0, -1, 2 * NANOS_PER_HOUR)),
+            ("P2W", Interval::new(0, 14, 0)),
+            ("PT0S", Interval::new(0, 0, 0)),
+            ("PT1.5M", Interval::new(0, 0, 90 * NANOS_PER_SECOND)),
+            ("@ 0", Interval::new(0, 0, 0)),
+        ];
+        for (case, expected) in cases {
+            assert_eq!(Interval::parse(case, &config).unwrap(), expected, "{case}");
+        }
+
+        let invalid = [
+            "P", "PT", "P1H", "PT1D", "P1Y1Y", "P1", "1:60", "1:2:3:4", "1-12", "1 day 5",
+        ];
+        for case in invalid {
+            assert!(Interval::parse(case, &config).is_err(), "{case}");
+        }
+
+        let err = Interval::parse("1 hour 04:05", &config).unwrap_err();
+        assert!(err.to_string().contains("Repeated type '04:05'"), "{err}");
+    }
+
     #[test]
     fn test_duplicate_interval_type() {
         let config = IntervalParseConfig::new(IntervalUnit::Month);
@@ -2220,6 +2565,12 @@ mod tests {
             ("-.123", -123i128),
             ("123.", 123000i128),
             ("-123.", -123000i128),
+            ("+123.1", 123100i128),
+            ("1.23e2", 123000i128),
+            ("1.23E-1", 123i128),
+            ("-1.5e+1", -15000i128),
+            ("+12e-4", 1i128),
+            ("1e-5", 0i128),
         ];
         for (s, i) in tests {
             let result_128 = parse_decimal::<Decimal128Type>(s, 20, 3);
@@ -2227,7 +2578,17 @@ mod tests {
             let result_256 = parse_decimal::<Decimal256Type>(s, 20, 3);
             assert_eq!(i256::from_i128(i), result_256.unwrap());
         }
-        let can_not_parse_tests = ["123,123", ".", "123.123.123", "", "+", "-"];
+        let can_not_parse_tests = [
+            "123,123",
+            ".",
+            "123.123.123",
+            "",
+            "+",
+            "-",
+            "1e",
+            "1e1.5",
+            "1.2ee3",
+        ];
         for s in can_not_parse_tests {
             let result_128 = parse_decimal::<Decimal128Type>(s, 20, 3);
             assert_eq!(
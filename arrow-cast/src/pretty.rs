@@ -18,10 +18,12 @@
 //! Utilities for pretty printing record batches. Note this module is not
 //! available unless `feature = "prettyprint"` is enabled.
 
-use crate::display::{ArrayFormatter, FormatOptions};
+use crate::display::{ArrayFormatter, FormatOptions, TableFormat};
 use arrow_array::{Array, ArrayRef, RecordBatch};
 use arrow_schema::ArrowError;
+use comfy_table::presets::ASCII_MARKDOWN;
 use comfy_table::{Cell, Table};
+use std::borrow::Cow;
 use std::fmt::Display;
 
 /// Create a visual representation of record batches
@@ -32,7 +34,30 @@ pub fn pretty_format_batches(
     pretty_format_batches_with_options(results, &options)
 }
 
-/// Create a visual representation of record batches
+/// Create a visual representation of record batches according to the provided
+/// [`FormatOptions`], which also control the layout of the table
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+/// # use arrow_cast::display::{FormatOptions, TableFormat};
+/// # use arrow_cast::pretty::pretty_format_batches_with_options;
+/// let batch = RecordBatch::try_from_iter([
+///     ("id", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+///     ("name", Arc::new(StringArray::from(vec![Some("alice"), None, Some("bob")])) as _),
+/// ])
+/// .unwrap();
+///
+/// let options = FormatOptions::default()
+///     .with_null("NULL")
+///     .with_max_rows(Some(2))
+///     .with_table_format(TableFormat::Markdown);
+/// let table = pretty_format_batches_with_options(&[batch], &options).unwrap();
+/// assert_eq!(
+///     table.to_string(),
+///     "| id  | name  |\n|-----|-------|\n| 1   | alice |\n| 2   | NULL  |\n| ... | ...   |"
+/// );
+/// ```
 pub fn pretty_format_batches_with_options(
     results: &[RecordBatch],
     options: &FormatOptions,
@@ -69,69 +94,136 @@ pub fn print_columns(col_name: &str, results: &[ArrayRef]) -> Result<(), ArrowEr
     Ok(())
 }
 
+/// A pretty printed table
+enum Output {
+    Table(Table),
+    Delimited(String),
+}
+
+impl Display for Output {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Table(table) => table.fmt(f),
+            Self::Delimited(s) => f.write_str(s),
+        }
+    }
+}
+
 /// Convert a series of record batches into a table
 fn create_table(
     results: &[RecordBatch],
     options: &FormatOptions,
-) -> Result<Table, ArrowError> {
-    let mut table = Table::new();
-    table.load_preset("||--+-++|    ++++++");
-
+) -> Result<Output, ArrowError> {
     if results.is_empty() {
-        return Ok(table);
+        return render(vec![], std::iter::empty(), options);
     }
 
     let schema = results[0].schema();
+    let header = schema.fields().iter().map(|f| f.name().as_str()).collect();
+    let batches = results.iter().map(|b| (b.num_rows(), b.columns()));
+    render(header, batches, options)
+}
 
-    let mut header = Vec::new();
-    for field in schema.fields() {
-        header.push(Cell::new(field.name()));
+fn create_column(
+    field: &str,
+    columns: &[ArrayRef],
+    options: &FormatOptions,
+) -> Result<Output, ArrowError> {
+    if columns.is_empty() {
+        return render(vec![], std::iter::empty(), options);
     }
-    table.set_header(header);
 
-    for batch in results {
-        let formatters = batch
-            .columns()
+    let batches = columns.iter().map(|c| (c.len(), std::slice::from_ref(c)));
+    render(vec![field], batches, options)
+}
+
+/// Renders the rows of `batches`, each a number of rows and the columns, with
+/// the provided header according to `options`
+fn render<'a>(
+    header: Vec<&str>,
+    batches: impl Iterator<Item = (usize, &'a [ArrayRef])>,
+    options: &FormatOptions,
+) -> Result<Output, ArrowError> {
+    let truncate = |s: &str| match options.max_column_width() {
+        Some(max) if s.chars().count() > max => {
+            let mut truncated: String = s.chars().take(max.saturating_sub(1)).collect();
+            truncated.push('…');
+            truncated
+        }
+        _ => s.to_string(),
+    };
+
+    let mut rows = Vec::new();
+    let max_rows = options.max_rows().unwrap_or(usize::MAX);
+    'outer: for (num_rows, columns) in batches {
+        let formatters = columns
             .iter()
             .map(|c| ArrayFormatter::try_new(c.as_ref(), options))
             .collect::<Result<Vec<_>, ArrowError>>()?;
 
-        for row in 0..batch.num_rows() {
-            let mut cells = Vec::new();
-            for formatter in &formatters {
-                cells.push(Cell::new(formatter.value(row)));
+        for row in 0..num_rows {
+            if rows.len() == max_rows {
+                rows.push(vec!["...".to_string(); header.len()]);
+                break 'outer;
             }
-            table.add_row(cells);
+            let values = formatters
+                .iter()
+                .map(|f| truncate(&f.value(row).to_string()));
+            rows.push(values.collect());
         }
     }
 
-    Ok(table)
-}
+    let header: Vec<_> = match options.header() {
+        true => header.into_iter().map(truncate).collect(),
+        false => vec![],
+    };
+
+    let format = options.table_format();
+    let preset = match format {
+        TableFormat::Delimited(delimiter) => {
+            let mut out = String::new();
+            let lines = (!header.is_empty()).then_some(&header).into_iter();
+            for line in lines.chain(&rows) {
+                for (idx, value) in line.iter().enumerate() {
+                    if idx != 0 {
+                        out.push(delimiter);
+                    }
+                    out.push_str(&quote_delimited(value, delimiter));
+                }
+                out.push('\n');
+            }
+            return Ok(Output::Delimited(out));
+        }
+        TableFormat::Markdown => ASCII_MARKDOWN,
+        TableFormat::Table => "||--+-++|    ++++++",
+    };
 
-fn create_column(
-    field: &str,
-    columns: &[ArrayRef],
-    options: &FormatOptions,
-) -> Result<Table, ArrowError> {
     let mut table = Table::new();
-    table.load_preset("||--+-++|    ++++++");
-
-    if columns.is_empty() {
-        return Ok(table);
-    }
-
-    let header = vec![Cell::new(field)];
-    table.set_header(header);
-
-    for col in columns {
-        let formatter = ArrayFormatter::try_new(col.as_ref(), options)?;
-        for row in 0..col.len() {
-            let cells = vec![Cell::new(formatter.value(row))];
-            table.add_row(cells);
+    table.load_preset(preset);
+    let cells = |line: Vec<String>| -> Vec<Cell> {
+        match format {
+            TableFormat::Markdown => line
+                .into_iter()
+                .map(|v| Cell::new(v.replace('|', "\\|")))
+                .collect(),
+            _ => line.into_iter().map(Cell::new).collect(),
         }
+    };
+    if !header.is_empty() {
+        table.set_header(cells(header));
     }
+    for row in rows {
+        table.add_row(cells(row));
+    }
+    Ok(Output::Table(table))
+}
 
-    Ok(table)
+/// Quotes `value` if it contains `delimiter`, a `"` or a newline
+fn quote_delimited(value: &str, delimiter: char) -> Cow<'_, str> {
+    match value.contains([delimiter, '"', '\n', '\r']) {
+        true => Cow::Owned(format!("\"{}\"", value.replace('"', "\"\""))),
+        false => Cow::Borrowed(value),
+    }
 }
 
 #[cfg(test)]
@@ -1020,4 +1112,73 @@ mod tests {
         let actual: Vec<&str> = batch.lines().collect();
         assert_eq!(expected, actual, "Actual result:\n{batch}");
     }
+
+    #[test]
+    fn test_format_table_options() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a_long_column_name",
+                Arc::new(StringArray::from(vec![
+                    Some("a,b"),
+                    Some("x|y"),
+                    None,
+                    Some("\"quoted\""),
+                ])) as _,
+            ),
+            ("b", Arc::new(Int32Array::from(vec![1, 22, 333, 4444])) as _),
+        ])
+        .unwrap();
+        let format = |options: &FormatOptions| {
+            let batches = [batch.slice(0, 2), batch.slice(2, 2)];
+            pretty_format_batches_with_options(&batches, options)
+                .unwrap()
+                .to_string()
+        };
+
+        let options = FormatOptions::default()
+            .with_max_column_width(Some(5))
+            .with_max_rows(Some(3));
+        let table = format(&options);
+        let expected = vec![
+            "+-------+-----+",
+            "| a_lo… | b   |",
+            "+-------+-----+",
+            "| a,b   | 1   |",
+            "| x|y   | 22  |",
+            "|       | 333 |",
+            "| ...   | ... |",
+            "+-------+-----+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+
+        let options = FormatOptions::default()
+            .with_header(false)
+            .with_table_format(TableFormat::Markdown);
+        let table = format(&options);
+        let expected = vec![
+            "| a,b      | 1    |",
+            "| x\\|y     | 22   |",
+            "|          | 333  |",
+            "| \"quoted\" | 4444 |",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+
+        let options = FormatOptions::default()
+            .with_null("NULL")
+            .with_table_format(TableFormat::Delimited(','));
+        let table = format(&options);
+        let expected = "a_long_column_name,b\n\"a,b\",1\nx|y,22\nNULL,333\n\"\"\"quoted\"\"\",4444\n";
+        assert_eq!(table, expected);
+
+        let options = FormatOptions::default()
+            .with_max_rows(Some(0))
+            .with_table_format(TableFormat::Delimited('\t'));
+        let column =
+            pretty_format_columns_with_options("c", &[batch.column(1).clone()], &options)
+                .unwrap()
+                .to_string();
+        assert_eq!(column, "c\n...\n");
+    }
 }
@@ -0,0 +1,253 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Provides `async` API for reading CSV from a stream of bytes
+//!
+//! The byte stream, such as that returned by `ObjectStore::get`, is split into
+//! chunks of complete records on the calling task, with each chunk then parsed
+//! into a [`RecordBatch`] on tokio's blocking thread pool. Up to `parallelism`
+//! chunks are parsed concurrently, with batches yielded in the order of the input.
+//!
+//! This must be called within the context of a tokio runtime.
+//!
+//! ```
+//! # #[tokio::main(flavor="current_thread")]
+//! # async fn main() {
+//! #
+//! use std::sync::Arc;
+//! use arrow_csv::async_reader::AsyncReaderBuilder;
+//! use arrow_csv::ReaderBuilder;
+//! use arrow_schema::{DataType, Field, Schema};
+//! use bytes::Bytes;
+//! use futures::TryStreamExt;
+//!
+//! let schema = Schema::new(vec![
+//!     Field::new("a", DataType::Int32, false),
+//!     Field::new("b", DataType::Utf8, false),
+//! ]);
+//! let builder = ReaderBuilder::new(Arc::new(schema)).with_batch_size(2);
+//!
+//! let data = ["1,foo\n2,b", "ar\n3,baz\n"];
+//! let input = futures::stream::iter(data.map(|s| Ok::<_, std::io::Error>(Bytes::from(s))));
+//!
+//! let stream = AsyncReaderBuilder::new(builder).with_parallelism(4).build(input);
+//! let batches: Vec<_> = stream.try_collect().await.unwrap();
+//! assert_eq!(batches.len(), 2);
+//! assert_eq!(batches[0].num_rows(), 2);
+//! # }
+//! ```
+
+use std::error::Error;
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::ArrowError;
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt, TryStreamExt};
+
+use crate::reader::split::Chunk;
+use crate::ReaderBuilder;
+
+/// A builder for an async CSV reader, see the [module docs](self)
+#[derive(Debug, Clone)]
+pub struct AsyncReaderBuilder {
+    builder: ReaderBuilder,
+    parallelism: usize,
+}
+
+impl AsyncReaderBuilder {
+    /// Create a new [`AsyncReaderBuilder`] with the options of the provided [`ReaderBuilder`]
+    ///
+    /// Each chunk contains up to the `batch_size` of `builder` records
+    pub fn new(builder: ReaderBuilder) -> Self {
+        Self {
+            builder,
+            parallelism: 1,
+        }
+    }
+
+    /// Set the maximum number of chunks to parse concurrently, defaults to 1
+    pub fn with_parallelism(mut self, parallelism: usize) -> Self {
+        self.parallelism = parallelism.max(1);
+        self
+    }
+
+    /// Build a stream of [`RecordBatch`] from the stream of bytes `input`
+    pub fn build<S, E>(
+        self,
+        input: S,
+    ) -> BoxStream<'static, Result<RecordBatch, ArrowError>>
+    where
+        S: Stream<Item = Result<Bytes, E>> + Send + 'static,
+        E: Error + Send + Sync + 'static,
+    {
+        let mut splitter = self.builder.build_splitter();
+        let builder = Arc::new(self.builder);
+
+        input
+            .map(|r| {
+                r.map(Some)
+                    .map_err(|e| ArrowError::ExternalError(Box::new(e)))
+            })
+            .chain(futures::stream::once(async { Ok(None) }))
+            .map(move |r| match r? {
+                Some(bytes) => splitter.push(&bytes),
+                None => Ok(splitter.finish()?.into_iter().collect()),
+            })
+            .map_ok(|chunks| {
+                futures::stream::iter(chunks.into_iter().map(Ok::<_, ArrowError>))
+            })
+            .try_flatten()
+            .map(move |chunk| {
+                let builder = Arc::clone(&builder);
+                async move {
+                    let chunk = chunk?;
+                    tokio::task::spawn_blocking(move || decode_chunk(&builder, chunk))
+                        .await
+                        .map_err(|e| ArrowError::ExternalError(Box::new(e)))?
+                }
+            })
+            .buffered(self.parallelism)
+            .try_filter_map(|batch| futures::future::ready(Ok(batch)))
+            .boxed()
+    }
+
+    /// Build a stream of [`RecordBatch`] from the object at `location` in `store`
+    #[cfg(feature = "object_store")]
+    pub async fn build_object_store(
+        self,
+        store: &dyn object_store::ObjectStore,
+        location: &object_store::path::Path,
+    ) -> Result<BoxStream<'static, Result<RecordBatch, ArrowError>>, ArrowError> {
+        let result = store
+            .get(location)
+            .await
+            .map_err(|e| ArrowError::ExternalError(Box::new(e)))?;
+        Ok(self.build(result.into_stream()))
+    }
+}
+
+/// Decodes the records in `chunk` to a [`RecordBatch`]
+fn decode_chunk(
+    builder: &ReaderBuilder,
+    chunk: Chunk,
+) -> Result<Option<RecordBatch>, ArrowError> {
+    let mut decoder = builder.build_chunk_decoder(chunk.line_number);
+    let mut buf = chunk.data.as_slice();
+    loop {
+        let read = decoder.decode(buf)?;
+        if read == 0 {
+            break;
+        }
+        buf = &buf[read..];
+    }
+    // Signal EOF, as the final record may not be terminated
+    decoder.decode(&[])?;
+    decoder.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_schema::{DataType, Field, Schema};
+
+    fn input(
+        data: &str,
+        chunk_size: usize,
+    ) -> impl Stream<Item = Result<Bytes, std::io::Error>> + Send + 'static {
+        let chunks: Vec<_> = data
+            .as_bytes()
+            .chunks(chunk_size)
+            .map(|c| Ok(Bytes::copy_from_slice(c)))
+            .collect();
+        futures::stream::iter(chunks)
+    }
+
+    #[tokio::test]
+    async fn test_async_reader() {
+        let mut data = "a,b\n".to_string();
+        for i in 0..100 {
+            data.push_str(&format!("{i},\"line\n{i}\"\n"));
+        }
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+
+        for (batch_size, chunk_size, parallelism) in
+            [(7, 3, 4), (100, 1000, 1), (1, 17, 8)]
+        {
+            let builder = ReaderBuilder::new(schema.clone())
+                .has_header(true)
+                .with_batch_size(batch_size);
+            let expected = builder
+                .clone()
+                .build(data.as_bytes())
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+
+            let batches: Vec<_> = AsyncReaderBuilder::new(builder)
+                .with_parallelism(parallelism)
+                .build(input(&data, chunk_size))
+                .try_collect()
+                .await
+                .unwrap();
+            assert_eq!(batches, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_bounds() {
+        let data = "# comment\n1\n2\n3\n4\n5";
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let builder = ReaderBuilder::new(schema)
+            .with_skip_rows(1)
+            .with_bounds(1, 4)
+            .with_batch_size(2);
+
+        let batches: Vec<_> = AsyncReaderBuilder::new(builder)
+            .build(input(data, 2))
+            .try_collect()
+            .await
+            .unwrap();
+        let values: Vec<_> = batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int64Type>().values().to_vec())
+            .collect();
+        assert_eq!(values, vec![2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_error() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let builder = ReaderBuilder::new(schema).with_batch_size(2);
+        let err = AsyncReaderBuilder::new(builder)
+            .build(input("1\n2\n3\nfoo\n", 3))
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Error while parsing value foo for column 0 at line 3"
+        );
+    }
+}
@@ -20,9 +20,13 @@
 pub mod reader;
 pub mod writer;
 
+#[cfg(feature = "async")]
+pub mod async_reader;
+
 pub use self::reader::infer_schema_from_files;
 pub use self::reader::Reader;
 pub use self::reader::ReaderBuilder;
+pub use self::writer::QuoteStyle;
 pub use self::writer::Writer;
 pub use self::writer::WriterBuilder;
 use arrow_schema::ArrowError;
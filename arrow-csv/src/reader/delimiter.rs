@@ -0,0 +1,266 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Support for multi-byte delimiters
+//!
+//! [`csv_core`] only supports single byte delimiters, multi-byte delimiters are
+//! therefore supported by rewriting unquoted occurrences of the delimiter to
+//! [`PLACEHOLDER`] before the data is passed to the parser
+
+use arrow_schema::ArrowError;
+use std::io::Read;
+
+/// The byte multi-byte delimiters are rewritten to
+///
+/// This byte can never occur in valid UTF-8, and so cannot collide with any field data
+pub const PLACEHOLDER: u8 = 0xFF;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum State {
+    /// At the start of a field
+    FieldStart,
+    /// Within an unquoted field
+    Unquoted,
+    /// Within a quoted field
+    Quoted,
+    /// Within a quoted field, following an escape character
+    QuotedEscape,
+    /// Within a quoted field, following a quote character
+    ///
+    /// This is either a doubled quote or the end of the quoted section
+    QuotedQuote,
+}
+
+/// Rewrites unquoted occurrences of a multi-byte delimiter to [`PLACEHOLDER`]
+///
+/// Input may be provided in arbitrarily split chunks, with a partially matched
+/// delimiter carried over to the next call to [`Self::translate`]
+#[derive(Debug)]
+pub struct DelimiterTranslator {
+    delimiter: Vec<u8>,
+    quote: u8,
+    escape: Option<u8>,
+    terminator: Option<u8>,
+    state: State,
+    /// The number of bytes of `delimiter` matched so far
+    matched: usize,
+}
+
+impl DelimiterTranslator {
+    pub fn new(
+        delimiter: Vec<u8>,
+        quote: u8,
+        escape: Option<u8>,
+        terminator: Option<u8>,
+    ) -> Self {
+        assert!(delimiter.len() > 1, "expected multi-byte delimiter");
+        Self {
+            delimiter,
+            quote,
+            escape,
+            terminator,
+            state: State::FieldStart,
+            matched: 0,
+        }
+    }
+
+    /// Translates `input` appending the output to `out`
+    pub fn translate(
+        &mut self,
+        input: &[u8],
+        out: &mut Vec<u8>,
+    ) -> Result<(), ArrowError> {
+        out.reserve(input.len());
+        input.iter().try_for_each(|b| self.push(*b, out))
+    }
+
+    /// Signals the end of input, writing any partially matched delimiter to `out`
+    pub fn finish(&mut self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.delimiter[..self.matched]);
+        self.matched = 0;
+        self.state = State::FieldStart;
+    }
+
+    fn is_terminator(&self, b: u8) -> bool {
+        match self.terminator {
+            Some(t) => b == t,
+            None => b == b'\n' || b == b'\r',
+        }
+    }
+
+    fn push(&mut self, b: u8, out: &mut Vec<u8>) -> Result<(), ArrowError> {
+        if b == PLACEHOLDER {
+            return Err(ArrowError::CsvError(
+                "Encountered invalid UTF-8 data".to_string(),
+            ));
+        }
+
+        match self.state {
+            State::Quoted => {
+                if Some(b) == self.escape {
+                    self.state = State::QuotedEscape
+                } else if b == self.quote {
+                    self.state = State::QuotedQuote
+                }
+                out.push(b);
+                return Ok(());
+            }
+            State::QuotedEscape => {
+                self.state = State::Quoted;
+                out.push(b);
+                return Ok(());
+            }
+            State::QuotedQuote if b == self.quote => {
+                self.state = State::Quoted;
+                out.push(b);
+                return Ok(());
+            }
+            State::QuotedQuote => self.state = State::Unquoted,
+            State::FieldStart if self.matched == 0 && b == self.quote => {
+                self.state = State::Quoted;
+                out.push(b);
+                return Ok(());
+            }
+            State::FieldStart | State::Unquoted => {}
+        }
+
+        if b == self.delimiter[self.matched] {
+            self.matched += 1;
+            if self.matched == self.delimiter.len() {
+                self.matched = 0;
+                self.state = State::FieldStart;
+                out.push(PLACEHOLDER);
+            }
+            return Ok(());
+        }
+
+        if self.matched != 0 {
+            // Not a delimiter, emit the first byte and replay the remainder
+            let matched = std::mem::replace(&mut self.matched, 0);
+            out.push(self.delimiter[0]);
+            self.state = State::Unquoted;
+            let replay = self.delimiter[1..matched].to_vec();
+            for b in replay {
+                self.push(b, out)?;
+            }
+            return self.push(b, out);
+        }
+
+        self.state = match self.is_terminator(b) {
+            true => State::FieldStart,
+            false => State::Unquoted,
+        };
+        out.push(b);
+        Ok(())
+    }
+}
+
+/// A [`Read`] that applies a [`DelimiterTranslator`] to the data read from `R`
+pub struct TranslatingReader<R> {
+    reader: R,
+    translator: DelimiterTranslator,
+    /// Scratch space for data read from `reader`
+    input: Vec<u8>,
+    /// Translated data not yet returned
+    buffer: Vec<u8>,
+    /// The offset into `buffer`
+    offset: usize,
+    finished: bool,
+}
+
+impl<R: Read> TranslatingReader<R> {
+    pub fn new(reader: R, translator: DelimiterTranslator) -> Self {
+        Self {
+            reader,
+            translator,
+            input: vec![0; 8 * 1024],
+            buffer: vec![],
+            offset: 0,
+            finished: false,
+        }
+    }
+}
+
+impl<R: Read> Read for TranslatingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.offset == self.buffer.len() && !self.finished {
+            self.buffer.clear();
+            self.offset = 0;
+            let read = self.reader.read(&mut self.input)?;
+            match read {
+                0 => {
+                    self.translator.finish(&mut self.buffer);
+                    self.finished = true;
+                }
+                _ => self
+                    .translator
+                    .translate(&self.input[..read], &mut self.buffer)
+                    .map_err(|e| {
+                        std::io::Error::new(std::io::ErrorKind::InvalidData, e)
+                    })?,
+            }
+        }
+
+        let remaining = &self.buffer[self.offset..];
+        let len = remaining.len().min(buf.len());
+        buf[..len].copy_from_slice(&remaining[..len]);
+        self.offset += len;
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translate(delimiter: &str, chunks: &[&str]) -> String {
+        let mut translator =
+            DelimiterTranslator::new(delimiter.as_bytes().to_vec(), b'"', None, None);
+        let mut out = vec![];
+        for chunk in chunks {
+            translator.translate(chunk.as_bytes(), &mut out).unwrap();
+        }
+        translator.finish(&mut out);
+        let out: Vec<_> = out
+            .into_iter()
+            .map(|b| if b == PLACEHOLDER { b'|' } else { b })
+            .collect();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_translate() {
+        assert_eq!(translate("::", &["a::b::c\n"]), "a|b|c\n");
+        assert_eq!(translate("::", &["a:", ":b:", ":c:"]), "a|b|c:");
+        assert_eq!(translate("::", &["a:b:::c"]), "a:b|:c");
+        assert_eq!(translate("aab", &["xaaaby"]), "xa|y");
+        assert_eq!(
+            translate("||", &["\"a||b\"||c\n\"d\"\"||\"||e"]),
+            "\"a||b\"|c\n\"d\"\"||\"|e"
+        );
+        assert_eq!(translate("::", &["a\"b::c"]), "a\"b|c");
+    }
+
+    #[test]
+    fn test_translating_reader() {
+        let translator = DelimiterTranslator::new(b"~~".to_vec(), b'"', None, None);
+        let mut reader = TranslatingReader::new("a~~b~".as_bytes(), translator);
+        let mut out = vec![];
+        reader.read_to_end(&mut out).unwrap();
+        assert_eq!(out, b"a\xFFb~");
+    }
+}
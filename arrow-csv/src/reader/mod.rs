@@ -123,23 +123,32 @@
 //! ```
 //!
 
+mod delimiter;
 mod records;
+#[cfg(feature = "async")]
+pub(crate) mod split;
 
-use arrow_array::builder::PrimitiveBuilder;
+use arrow_array::builder::{PrimitiveBuilder, StringDictionaryBuilder};
+use arrow_array::temporal_conversions::{
+    time_to_time32ms, time_to_time32s, time_to_time64ns, time_to_time64us,
+};
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_cast::parse::{parse_decimal, string_to_datetime, Parser};
 use arrow_schema::*;
-use chrono::{TimeZone, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 use csv::StringRecord;
 use lazy_static::lazy_static;
 use regex::RegexSet;
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fmt;
 use std::fs::File;
 use std::io::{BufRead, BufReader as StdBufReader, Read, Seek, SeekFrom};
 use std::sync::Arc;
 
 use crate::map_csv_error;
+use crate::reader::delimiter::{DelimiterTranslator, TranslatingReader, PLACEHOLDER};
 use crate::reader::records::{RecordDecoder, StringRecords};
 use arrow_array::timezone::Tz;
 
@@ -213,6 +222,10 @@ pub struct Format {
     escape: Option<u8>,
     quote: Option<u8>,
     terminator: Option<u8>,
+    comment: Option<u8>,
+    skip_rows: usize,
+    multi_byte_delimiter: Option<Vec<u8>>,
+    null_values: NullValues,
 }
 
 impl Format {
@@ -223,6 +236,27 @@ impl Format {
 
     pub fn with_delimiter(mut self, delimiter: u8) -> Self {
         self.delimiter = Some(delimiter);
+        self.multi_byte_delimiter = None;
+        self
+    }
+
+    /// Set the column delimiter to a string, which may be longer than a single byte
+    ///
+    /// Multi-byte delimiters are only recognised outside of quoted fields, and
+    /// require the data to be valid UTF-8
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delimiter` is empty
+    pub fn with_delimiter_str(mut self, delimiter: &str) -> Self {
+        match delimiter.as_bytes() {
+            [] => panic!("delimiter must not be empty"),
+            [b] => return self.with_delimiter(*b),
+            bytes => {
+                self.delimiter = Some(PLACEHOLDER);
+                self.multi_byte_delimiter = Some(bytes.to_vec());
+            }
+        }
         self
     }
 
@@ -241,6 +275,35 @@ impl Format {
         self
     }
 
+    /// Set a comment character, records starting with this byte will be ignored
+    pub fn with_comment(mut self, comment: u8) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+
+    /// Set the number of lines to skip at the start of the file, before any header
+    ///
+    /// Lines are delimited by `\n`, or the configured terminator, and are skipped
+    /// without being parsed, allowing files with a free-form preamble to be read
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+
+    /// Set the strings to interpret as null, e.g. `"NA"`, `"-"` or `""`
+    ///
+    /// This replaces the default, where only empty strings are null, and applies to
+    /// all columns, including string columns, before any type parsing
+    pub fn with_null_values<I, S>(mut self, null_values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let values = null_values.into_iter().map(Into::into).collect();
+        self.null_values = NullValues(Some(values));
+        self
+    }
+
     /// Infer schema of CSV records from the provided `reader`
     ///
     /// If `max_records` is `None`, all records will be read, otherwise up to `max_records`
@@ -252,8 +315,30 @@ impl Format {
         reader: R,
         max_records: Option<usize>,
     ) -> Result<(Schema, usize), ArrowError> {
-        let mut csv_reader = self.build_reader(reader);
+        let mut reader = StdBufReader::new(reader);
+        let mut line = vec![];
+        for _ in 0..self.skip_rows {
+            line.clear();
+            if reader.read_until(self.line_terminator(), &mut line)? == 0 {
+                break;
+            }
+        }
+
+        match self.build_translator() {
+            Some(translator) => {
+                let reader = TranslatingReader::new(reader, translator);
+                self.infer_records(self.build_reader(reader), max_records)
+            }
+            None => self.infer_records(self.build_reader(reader), max_records),
+        }
+    }
 
+    /// Infer schema from the records yielded by `csv_reader`
+    fn infer_records<R: Read>(
+        &self,
+        mut csv_reader: csv::Reader<R>,
+        max_records: Option<usize>,
+    ) -> Result<(Schema, usize), ArrowError> {
         // get or create header names
         // when has_header is false, creates default column names with column_ prefix
         let headers: Vec<String> = if self.has_header {
@@ -287,7 +372,7 @@ impl Format {
                 column_types.iter_mut().enumerate().take(header_length)
             {
                 if let Some(string) = record.get(i) {
-                    if !string.is_empty() {
+                    if !self.null_values.is_null(string) {
                         column_type.update(string)
                     }
                 }
@@ -319,9 +404,27 @@ impl Format {
         if let Some(t) = self.terminator {
             builder.terminator(csv::Terminator::Any(t));
         }
+        builder.comment(self.comment);
         builder.from_reader(reader)
     }
 
+    /// Build a [`DelimiterTranslator`] if this [`Format`] has a multi-byte delimiter
+    fn build_translator(&self) -> Option<DelimiterTranslator> {
+        let delimiter = self.multi_byte_delimiter.clone()?;
+        let quote = self.quote.unwrap_or(b'"');
+        Some(DelimiterTranslator::new(
+            delimiter,
+            quote,
+            self.escape,
+            self.terminator,
+        ))
+    }
+
+    /// The byte used to delimit the lines skipped by [`Self::with_skip_rows`]
+    fn line_terminator(&self) -> u8 {
+        self.terminator.unwrap_or(b'\n')
+    }
+
     /// Build a [`csv_core::Reader`] for this [`Format`]
     fn build_parser(&self) -> csv_core::Reader {
         let mut builder = csv_core::ReaderBuilder::new();
@@ -336,6 +439,7 @@ impl Format {
         if let Some(t) = self.terminator {
             builder.terminator(csv_core::Terminator::Any(t));
         }
+        builder.comment(self.comment);
         builder.build()
     }
 }
@@ -549,6 +653,27 @@ pub struct Decoder {
     /// Rows to skip
     to_skip: usize,
 
+    /// Lines to skip without parsing, see [`Format::with_skip_rows`]
+    skip_lines: usize,
+
+    /// The byte delimiting the lines in `skip_lines`
+    line_terminator: u8,
+
+    /// Rewrites multi-byte delimiters, see [`Format::with_delimiter_str`]
+    translator: Option<DelimiterTranslator>,
+
+    /// Translated data not yet consumed by `record_decoder`
+    translated: Vec<u8>,
+
+    /// The offset into `translated`
+    translated_offset: usize,
+
+    /// The strings to interpret as null
+    null_values: NullValues,
+
+    /// Custom parsers keyed by column index
+    column_parsers: HashMap<usize, ColumnParser>,
+
     /// Current line number
     line_number: usize,
 
@@ -570,21 +695,92 @@ impl Decoder {
     /// integration with arbitrary byte streams, such as that yielded by [`BufRead`] or
     /// network sources such as object storage
     pub fn decode(&mut self, buf: &[u8]) -> Result<usize, ArrowError> {
-        if self.to_skip != 0 {
+        if self.skip_lines != 0 {
+            if buf.is_empty() {
+                self.skip_lines = 0;
+            }
+            let mut consumed = 0;
+            while self.skip_lines != 0 {
+                let remaining = &buf[consumed..];
+                match remaining.iter().position(|b| *b == self.line_terminator) {
+                    Some(idx) => {
+                        consumed += idx + 1;
+                        self.skip_lines -= 1;
+                    }
+                    None => return Ok(buf.len()),
+                }
+            }
+            return Ok(consumed);
+        }
+
+        while self.to_skip != 0 {
             // Skip in units of `to_read` to avoid over-allocating buffers
             let to_skip = self.to_skip.min(self.batch_size);
-            let (skipped, bytes) = self.record_decoder.decode(buf, to_skip)?;
+            let (skipped, bytes) = self.decode_records(buf, to_skip)?;
             self.to_skip -= skipped;
             self.record_decoder.clear();
-            return Ok(bytes);
+            // Records may be skipped from previously translated data without
+            // consuming any of `buf`, in which case continue skipping
+            if bytes != 0 || skipped == 0 {
+                return Ok(bytes);
+            }
         }
 
         let to_read =
             self.batch_size.min(self.end - self.line_number) - self.record_decoder.len();
-        let (_, bytes) = self.record_decoder.decode(buf, to_read)?;
+        let (_, bytes) = self.decode_records(buf, to_read)?;
         Ok(bytes)
     }
 
+    /// Decodes up to `to_read` records from `buf`, returning the number of records
+    /// decoded and the number of bytes of `buf` consumed
+    fn decode_records(
+        &mut self,
+        buf: &[u8],
+        to_read: usize,
+    ) -> Result<(usize, usize), ArrowError> {
+        let translator = match self.translator.as_mut() {
+            Some(translator) => translator,
+            None => return self.record_decoder.decode(buf, to_read),
+        };
+
+        // Translated data is buffered internally, with `buf` only consumed once
+        // any previously translated data has been decoded
+        let mut consumed = 0;
+        let mut read = 0;
+        while read < to_read {
+            if self.translated_offset == self.translated.len() {
+                self.translated.clear();
+                self.translated_offset = 0;
+                if consumed < buf.len() {
+                    translator.translate(&buf[consumed..], &mut self.translated)?;
+                    consumed = buf.len();
+                } else if buf.is_empty() {
+                    translator.finish(&mut self.translated);
+                    if self.translated.is_empty() {
+                        // Signal EOF to the record decoder
+                        let (decoded, _) =
+                            self.record_decoder.decode(&[], to_read - read)?;
+                        return Ok((read + decoded, 0));
+                    }
+                } else {
+                    break;
+                }
+            }
+
+            // A partially matched delimiter may yield no translated data, which
+            // must not be passed to the record decoder as it would signal EOF
+            let pending = &self.translated[self.translated_offset..];
+            if pending.is_empty() {
+                break;
+            }
+            let (decoded, bytes) = self.record_decoder.decode(pending, to_read - read)?;
+            self.translated_offset += bytes;
+            read += decoded;
+        }
+        Ok((read, consumed))
+    }
+
     /// Flushes the currently buffered data to a [`RecordBatch`]
     ///
     /// This should only be called after [`Self::decode`] has returned `Ok(0)`,
@@ -603,6 +799,8 @@ impl Decoder {
             Some(self.schema.metadata.clone()),
             self.projection.as_ref(),
             self.line_number,
+            &self.null_values,
+            &self.column_parsers,
         )?;
         self.line_number += rows.len();
         Ok(Some(batch))
@@ -614,6 +812,108 @@ impl Decoder {
     }
 }
 
+/// A function rewriting a value before it is parsed, see [`ColumnParser::Map`]
+pub type ColumnMapFn = Arc<dyn Fn(&str) -> Option<String> + Send + Sync>;
+
+/// A custom parser for the values of a column, see [`ReaderBuilder::with_column_parser`]
+#[derive(Clone)]
+pub enum ColumnParser {
+    /// Parse temporal values using a [`chrono::format::strftime`] format string
+    ///
+    /// Supported for `Date32`, `Date64`, `Time32`, `Time64` and `Timestamp` columns.
+    /// Timestamps without an offset in the format are interpreted in the timezone of
+    /// the column, or as UTC if the column has no timezone
+    Format(String),
+    /// Parse boolean values from the provided sets of strings, e.g. `"Y"` and `"N"`
+    Boolean {
+        true_values: Vec<String>,
+        false_values: Vec<String>,
+    },
+    /// Parse numeric values with the provided decimal and thousands separators,
+    /// e.g. `1.234,5` with a decimal separator of `','` and thousands of `'.'`
+    Number {
+        decimal: char,
+        thousands: Option<char>,
+    },
+    /// Rewrite each value with a function before it is parsed as the column's type,
+    /// with `None` yielding a null
+    Map(ColumnMapFn),
+}
+
+impl std::fmt::Debug for ColumnParser {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Format(format) => f.debug_tuple("Format").field(format).finish(),
+            Self::Boolean {
+                true_values,
+                false_values,
+            } => f
+                .debug_struct("Boolean")
+                .field("true_values", true_values)
+                .field("false_values", false_values)
+                .finish(),
+            Self::Number { decimal, thousands } => f
+                .debug_struct("Number")
+                .field("decimal", decimal)
+                .field("thousands", thousands)
+                .finish(),
+            Self::Map(_) => f.write_str("Map"),
+        }
+    }
+}
+
+/// The set of strings to interpret as null, see [`Format::with_null_values`]
+///
+/// If not specified only empty strings are null, and only for non-string columns
+#[derive(Debug, Clone, Default)]
+struct NullValues(Option<Vec<String>>);
+
+impl NullValues {
+    fn is_null(&self, s: &str) -> bool {
+        match &self.0 {
+            Some(values) => values.iter().any(|v| v == s),
+            None => s.is_empty(),
+        }
+    }
+}
+
+/// The values of a column within [`StringRecords`], with null values and any
+/// [`ColumnParser`] pre-processing applied
+struct ColumnValues<'a, 'b> {
+    rows: &'b StringRecords<'a>,
+    col_idx: usize,
+    null_values: &'b NullValues,
+    parser: Option<&'b ColumnParser>,
+}
+
+impl<'a, 'b> ColumnValues<'a, 'b> {
+    fn iter(&self) -> impl Iterator<Item = Option<Cow<'a, str>>> + '_ {
+        self.rows.iter().map(move |row| {
+            let s = row.get(self.col_idx);
+            if self.null_values.is_null(s) {
+                return None;
+            }
+            match self.parser {
+                Some(ColumnParser::Number { decimal, thousands }) => {
+                    let normalized = s
+                        .chars()
+                        .filter(|c| Some(*c) != *thousands)
+                        .map(|c| if c == *decimal { '.' } else { c })
+                        .collect();
+                    Some(Cow::Owned(normalized))
+                }
+                Some(ColumnParser::Map(f)) => f(s).map(Cow::Owned),
+                _ => Some(Cow::Borrowed(s)),
+            }
+        })
+    }
+
+    /// Returns true if the values of a string column need to be pre-processed
+    fn is_string_transformed(&self) -> bool {
+        self.null_values.0.is_some() || self.parser.is_some()
+    }
+}
+
 /// Parses a slice of [`StringRecords`] into a [RecordBatch]
 fn parse(
     rows: &StringRecords<'_>,
@@ -621,6 +921,8 @@ fn parse(
     metadata: Option<std::collections::HashMap<String, String>>,
     projection: Option<&Vec<usize>>,
     line_number: usize,
+    null_values: &NullValues,
+    column_parsers: &HashMap<usize, ColumnParser>,
 ) -> Result<RecordBatch, ArrowError> {
     let projection: Vec<usize> = match projection {
         Some(v) => v.clone(),
@@ -632,13 +934,70 @@ fn parse(
         .map(|i| {
             let i = *i;
             let field = &fields[i];
+            let column = ColumnValues {
+                rows,
+                col_idx: i,
+                null_values,
+                parser: column_parsers.get(&i),
+            };
+            match (column.parser, field.data_type()) {
+                (Some(ColumnParser::Format(format)), data_type) => {
+                    return build_formatted_array(line_number, &column, data_type, format)
+                }
+                (
+                    Some(ColumnParser::Boolean {
+                        true_values,
+                        false_values,
+                    }),
+                    DataType::Boolean,
+                ) => {
+                    return build_boolean_array(line_number, &column, |s| {
+                        if true_values.iter().any(|v| v == s) {
+                            Some(true)
+                        } else if false_values.iter().any(|v| v == s) {
+                            Some(false)
+                        } else {
+                            None
+                        }
+                    })
+                }
+                (Some(ColumnParser::Boolean { .. }), data_type) => {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Boolean parser is not supported for column {i} of type {data_type}"
+                    )))
+                }
+                (Some(ColumnParser::Number { .. }), data_type)
+                    if !data_type.is_numeric() =>
+                {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Number parser is not supported for column {i} of type {data_type}"
+                    )))
+                }
+                _ => {}
+            }
+
             match field.data_type() {
-                DataType::Boolean => build_boolean_array(line_number, rows, i),
+                DataType::Boolean => build_boolean_array(line_number, &column, parse_bool),
+                DataType::Decimal32(precision, scale) => {
+                    build_decimal_array::<Decimal32Type>(
+                        line_number,
+                        &column,
+                        *precision,
+                        *scale,
+                    )
+                }
+                DataType::Decimal64(precision, scale) => {
+                    build_decimal_array::<Decimal64Type>(
+                        line_number,
+                        &column,
+                        *precision,
+                        *scale,
+                    )
+                }
                 DataType::Decimal128(precision, scale) => {
                     build_decimal_array::<Decimal128Type>(
                         line_number,
-                        rows,
-                        i,
+                        &column,
                         *precision,
                         *scale,
                     )
@@ -646,90 +1005,88 @@ fn parse(
                 DataType::Decimal256(precision, scale) => {
                     build_decimal_array::<Decimal256Type>(
                         line_number,
-                        rows,
-                        i,
+                        &column,
                         *precision,
                         *scale,
                     )
                 }
-                DataType::Int8 => build_primitive_array::<Int8Type>(line_number, rows, i),
+                DataType::Int8 => build_primitive_array::<Int8Type>(line_number, &column),
                 DataType::Int16 => {
-                    build_primitive_array::<Int16Type>(line_number, rows, i)
+                    build_primitive_array::<Int16Type>(line_number, &column)
                 }
                 DataType::Int32 => {
-                    build_primitive_array::<Int32Type>(line_number, rows, i)
+                    build_primitive_array::<Int32Type>(line_number, &column)
                 }
                 DataType::Int64 => {
-                    build_primitive_array::<Int64Type>(line_number, rows, i)
+                    build_primitive_array::<Int64Type>(line_number, &column)
                 }
                 DataType::UInt8 => {
-                    build_primitive_array::<UInt8Type>(line_number, rows, i)
+                    build_primitive_array::<UInt8Type>(line_number, &column)
                 }
                 DataType::UInt16 => {
-                    build_primitive_array::<UInt16Type>(line_number, rows, i)
+                    build_primitive_array::<UInt16Type>(line_number, &column)
                 }
                 DataType::UInt32 => {
-                    build_primitive_array::<UInt32Type>(line_number, rows, i)
+                    build_primitive_array::<UInt32Type>(line_number, &column)
                 }
                 DataType::UInt64 => {
-                    build_primitive_array::<UInt64Type>(line_number, rows, i)
+                    build_primitive_array::<UInt64Type>(line_number, &column)
                 }
                 DataType::Float32 => {
-                    build_primitive_array::<Float32Type>(line_number, rows, i)
+                    build_primitive_array::<Float32Type>(line_number, &column)
                 }
                 DataType::Float64 => {
-                    build_primitive_array::<Float64Type>(line_number, rows, i)
+                    build_primitive_array::<Float64Type>(line_number, &column)
                 }
                 DataType::Date32 => {
-                    build_primitive_array::<Date32Type>(line_number, rows, i)
+                    build_primitive_array::<Date32Type>(line_number, &column)
                 }
                 DataType::Date64 => {
-                    build_primitive_array::<Date64Type>(line_number, rows, i)
+                    build_primitive_array::<Date64Type>(line_number, &column)
                 }
                 DataType::Time32(TimeUnit::Second) => {
-                    build_primitive_array::<Time32SecondType>(line_number, rows, i)
+                    build_primitive_array::<Time32SecondType>(line_number, &column)
                 }
                 DataType::Time32(TimeUnit::Millisecond) => {
-                    build_primitive_array::<Time32MillisecondType>(line_number, rows, i)
+                    build_primitive_array::<Time32MillisecondType>(line_number, &column)
                 }
                 DataType::Time64(TimeUnit::Microsecond) => {
-                    build_primitive_array::<Time64MicrosecondType>(line_number, rows, i)
+                    build_primitive_array::<Time64MicrosecondType>(line_number, &column)
                 }
                 DataType::Time64(TimeUnit::Nanosecond) => {
-                    build_primitive_array::<Time64NanosecondType>(line_number, rows, i)
+                    build_primitive_array::<Time64NanosecondType>(line_number, &column)
                 }
                 DataType::Timestamp(TimeUnit::Second, tz) => {
                     build_timestamp_array::<TimestampSecondType>(
                         line_number,
-                        rows,
-                        i,
+                        &column,
                         tz.as_deref(),
                     )
                 }
                 DataType::Timestamp(TimeUnit::Millisecond, tz) => {
                     build_timestamp_array::<TimestampMillisecondType>(
                         line_number,
-                        rows,
-                        i,
+                        &column,
                         tz.as_deref(),
                     )
                 }
                 DataType::Timestamp(TimeUnit::Microsecond, tz) => {
                     build_timestamp_array::<TimestampMicrosecondType>(
                         line_number,
-                        rows,
-                        i,
+                        &column,
                         tz.as_deref(),
                     )
                 }
                 DataType::Timestamp(TimeUnit::Nanosecond, tz) => {
                     build_timestamp_array::<TimestampNanosecondType>(
                         line_number,
-                        rows,
-                        i,
+                        &column,
                         tz.as_deref(),
                     )
                 }
+                DataType::Utf8 if column.is_string_transformed() => {
+                    Ok(Arc::new(column.iter().collect::<StringArray>()) as ArrayRef)
+                }
                 DataType::Utf8 => Ok(Arc::new(
                     rows.iter()
                         .map(|row| Some(row.get(i)))
@@ -739,46 +1096,14 @@ fn parse(
                     if value_type.as_ref() == &DataType::Utf8 =>
                 {
                     match key_type.as_ref() {
-                        DataType::Int8 => Ok(Arc::new(
-                            rows.iter()
-                                .map(|row| row.get(i))
-                                .collect::<DictionaryArray<Int8Type>>(),
-                        ) as ArrayRef),
-                        DataType::Int16 => Ok(Arc::new(
-                            rows.iter()
-                                .map(|row| row.get(i))
-                                .collect::<DictionaryArray<Int16Type>>(),
-                        ) as ArrayRef),
-                        DataType::Int32 => Ok(Arc::new(
-                            rows.iter()
-                                .map(|row| row.get(i))
-                                .collect::<DictionaryArray<Int32Type>>(),
-                        ) as ArrayRef),
-                        DataType::Int64 => Ok(Arc::new(
-                            rows.iter()
-                                .map(|row| row.get(i))
-                                .collect::<DictionaryArray<Int64Type>>(),
-                        ) as ArrayRef),
-                        DataType::UInt8 => Ok(Arc::new(
-                            rows.iter()
-                                .map(|row| row.get(i))
-                                .collect::<DictionaryArray<UInt8Type>>(),
-                        ) as ArrayRef),
-                        DataType::UInt16 => Ok(Arc::new(
-                            rows.iter()
-                                .map(|row| row.get(i))
-                                .collect::<DictionaryArray<UInt16Type>>(),
-                        ) as ArrayRef),
-                        DataType::UInt32 => Ok(Arc::new(
-                            rows.iter()
-                                .map(|row| row.get(i))
-                                .collect::<DictionaryArray<UInt32Type>>(),
-                        ) as ArrayRef),
-                        DataType::UInt64 => Ok(Arc::new(
-                            rows.iter()
-                                .map(|row| row.get(i))
-                                .collect::<DictionaryArray<UInt64Type>>(),
-                        ) as ArrayRef),
+                        DataType::Int8 => build_dictionary_array::<Int8Type>(&column),
+                        DataType::Int16 => build_dictionary_array::<Int16Type>(&column),
+                        DataType::Int32 => build_dictionary_array::<Int32Type>(&column),
+                        DataType::Int64 => build_dictionary_array::<Int64Type>(&column),
+                        DataType::UInt8 => build_dictionary_array::<UInt8Type>(&column),
+                        DataType::UInt16 => build_dictionary_array::<UInt16Type>(&column),
+                        DataType::UInt32 => build_dictionary_array::<UInt32Type>(&column),
+                        DataType::UInt64 => build_dictionary_array::<UInt64Type>(&column),
                         _ => Err(ArrowError::ParseError(format!(
                             "Unsupported dictionary key type {key_type:?}"
                         ))),
@@ -820,29 +1145,49 @@ fn parse_bool(string: &str) -> Option<bool> {
     }
 }
 
+fn build_dictionary_array<K: ArrowDictionaryKeyType>(
+    column: &ColumnValues<'_, '_>,
+) -> Result<ArrayRef, ArrowError> {
+    if !column.is_string_transformed() {
+        let rows = column.rows.iter();
+        let array: DictionaryArray<K> = rows.map(|row| row.get(column.col_idx)).collect();
+        return Ok(Arc::new(array));
+    }
+
+    let mut builder = StringDictionaryBuilder::<K>::new();
+    for s in column.iter() {
+        match s {
+            Some(s) => {
+                builder.append(s)?;
+            }
+            None => builder.append_null(),
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
 // parse the column string to an Arrow Array
 fn build_decimal_array<T: DecimalType>(
     _line_number: usize,
-    rows: &StringRecords<'_>,
-    col_idx: usize,
+    column: &ColumnValues<'_, '_>,
     precision: u8,
     scale: i8,
 ) -> Result<ArrayRef, ArrowError> {
-    let mut decimal_builder = PrimitiveBuilder::<T>::with_capacity(rows.len());
-    for row in rows.iter() {
-        let s = row.get(col_idx);
-        if s.is_empty() {
+    let mut decimal_builder = PrimitiveBuilder::<T>::with_capacity(column.rows.len());
+    for s in column.iter() {
+        match s {
             // append null
-            decimal_builder.append_null();
-        } else {
-            let decimal_value: Result<T::Native, _> =
-                parse_decimal::<T>(s, precision, scale);
-            match decimal_value {
-                Ok(v) => {
-                    decimal_builder.append_value(v);
-                }
-                Err(e) => {
-                    return Err(e);
+            None => decimal_builder.append_null(),
+            Some(s) => {
+                let decimal_value: Result<T::Native, _> =
+                    parse_decimal::<T>(&s, precision, scale);
+                match decimal_value {
+                    Ok(v) => {
+                        decimal_builder.append_value(v);
+                    }
+                    Err(e) => {
+                        return Err(e);
+                    }
                 }
             }
         }
@@ -857,63 +1202,76 @@ fn build_decimal_array<T: DecimalType>(
 // parses a specific column (col_idx) into an Arrow Array.
 fn build_primitive_array<T: ArrowPrimitiveType + Parser>(
     line_number: usize,
-    rows: &StringRecords<'_>,
-    col_idx: usize,
+    column: &ColumnValues<'_, '_>,
 ) -> Result<ArrayRef, ArrowError> {
-    rows.iter()
+    build_primitive_array_with::<T, _>(line_number, column, T::parse)
+        .map(|e| Arc::new(e) as ArrayRef)
+}
+
+// parses a specific column (col_idx) into a [`PrimitiveArray`] with the function `parse`
+fn build_primitive_array_with<T, F>(
+    line_number: usize,
+    column: &ColumnValues<'_, '_>,
+    parse: F,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(&str) -> Option<T::Native>,
+{
+    column
+        .iter()
         .enumerate()
-        .map(|(row_index, row)| {
-            let s = row.get(col_idx);
-            if s.is_empty() {
-                return Ok(None);
-            }
+        .map(|(row_index, s)| {
+            let s = match s {
+                Some(s) => s,
+                None => return Ok(None),
+            };
 
-            match T::parse(s) {
+            match parse(&s) {
                 Some(e) => Ok(Some(e)),
                 None => Err(ArrowError::ParseError(format!(
                     // TODO: we should surface the underlying error here.
                     "Error while parsing value {} for column {} at line {}",
                     s,
-                    col_idx,
+                    column.col_idx,
                     line_number + row_index
                 ))),
             }
         })
-        .collect::<Result<PrimitiveArray<T>, ArrowError>>()
-        .map(|e| Arc::new(e) as ArrayRef)
+        .collect()
 }
 
 fn build_timestamp_array<T: ArrowTimestampType>(
     line_number: usize,
-    rows: &StringRecords<'_>,
-    col_idx: usize,
+    column: &ColumnValues<'_, '_>,
     timezone: Option<&str>,
 ) -> Result<ArrayRef, ArrowError> {
     Ok(Arc::new(match timezone {
         Some(timezone) => {
             let tz: Tz = timezone.parse()?;
-            build_timestamp_array_impl::<T, _>(line_number, rows, col_idx, &tz)?
+            build_timestamp_array_impl::<T, _>(line_number, column, &tz)?
                 .with_timezone(timezone)
         }
-        None => build_timestamp_array_impl::<T, _>(line_number, rows, col_idx, &Utc)?,
+        None => build_timestamp_array_impl::<T, _>(line_number, column, &Utc)?,
     }))
 }
 
 fn build_timestamp_array_impl<T: ArrowTimestampType, Tz: TimeZone>(
     line_number: usize,
-    rows: &StringRecords<'_>,
-    col_idx: usize,
+    column: &ColumnValues<'_, '_>,
     timezone: &Tz,
 ) -> Result<PrimitiveArray<T>, ArrowError> {
-    rows.iter()
+    let col_idx = column.col_idx;
+    column
+        .iter()
         .enumerate()
-        .map(|(row_index, row)| {
-            let s = row.get(col_idx);
-            if s.is_empty() {
-                return Ok(None);
-            }
+        .map(|(row_index, s)| {
+            let s = match s {
+                Some(s) => s,
+                None => return Ok(None),
+            };
 
-            let date = string_to_datetime(timezone, s).map_err(|e| {
+            let date = string_to_datetime(timezone, &s).map_err(|e| {
                 ArrowError::ParseError(format!(
                     "Error parsing column {col_idx} at line {}: {}",
                     line_number + row_index,
@@ -931,27 +1289,147 @@ fn build_timestamp_array_impl<T: ArrowTimestampType, Tz: TimeZone>(
         .collect()
 }
 
-// parses a specific column (col_idx) into an Arrow Array.
-fn build_boolean_array(
+/// Parses a temporal column using the chrono format string `format`
+fn build_formatted_array(
     line_number: usize,
-    rows: &StringRecords<'_>,
-    col_idx: usize,
+    column: &ColumnValues<'_, '_>,
+    data_type: &DataType,
+    format: &str,
 ) -> Result<ArrayRef, ArrowError> {
-    rows.iter()
-        .enumerate()
-        .map(|(row_index, row)| {
-            let s = row.get(col_idx);
-            if s.is_empty() {
-                return Ok(None);
+    let parse_datetime = |s: &str| {
+        NaiveDateTime::parse_from_str(s, format).ok().or_else(|| {
+            let date = NaiveDate::parse_from_str(s, format).ok()?;
+            date.and_hms_opt(0, 0, 0)
+        })
+    };
+    let parse_time = |s: &str| NaiveTime::parse_from_str(s, format).ok();
+
+    let array: ArrayRef = match data_type {
+        DataType::Date32 => Arc::new(build_primitive_array_with::<Date32Type, _>(
+            line_number,
+            column,
+            |s| Some(Date32Type::from_naive_date(parse_datetime(s)?.date())),
+        )?),
+        DataType::Date64 => Arc::new(build_primitive_array_with::<Date64Type, _>(
+            line_number,
+            column,
+            |s| Some(parse_datetime(s)?.timestamp_millis()),
+        )?),
+        DataType::Time32(TimeUnit::Second) => {
+            Arc::new(build_primitive_array_with::<Time32SecondType, _>(
+                line_number,
+                column,
+                |s| Some(time_to_time32s(parse_time(s)?)),
+            )?)
+        }
+        DataType::Time32(TimeUnit::Millisecond) => {
+            Arc::new(build_primitive_array_with::<Time32MillisecondType, _>(
+                line_number,
+                column,
+                |s| Some(time_to_time32ms(parse_time(s)?)),
+            )?)
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            Arc::new(build_primitive_array_with::<Time64MicrosecondType, _>(
+                line_number,
+                column,
+                |s| Some(time_to_time64us(parse_time(s)?)),
+            )?)
+        }
+        DataType::Time64(TimeUnit::Nanosecond) => {
+            Arc::new(build_primitive_array_with::<Time64NanosecondType, _>(
+                line_number,
+                column,
+                |s| Some(time_to_time64ns(parse_time(s)?)),
+            )?)
+        }
+        DataType::Timestamp(TimeUnit::Second, tz) => {
+            build_formatted_timestamp_array::<TimestampSecondType>(
+                line_number,
+                column,
+                format,
+                tz.as_deref(),
+            )?
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, tz) => {
+            build_formatted_timestamp_array::<TimestampMillisecondType>(
+                line_number,
+                column,
+                format,
+                tz.as_deref(),
+            )?
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, tz) => {
+            build_formatted_timestamp_array::<TimestampMicrosecondType>(
+                line_number,
+                column,
+                format,
+                tz.as_deref(),
+            )?
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, tz) => {
+            build_formatted_timestamp_array::<TimestampNanosecondType>(
+                line_number,
+                column,
+                format,
+                tz.as_deref(),
+            )?
+        }
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Format parser is not supported for column {} of type {data_type}",
+                column.col_idx
+            )))
+        }
+    };
+    Ok(array)
+}
+
+fn build_formatted_timestamp_array<T: ArrowTimestampType>(
+    line_number: usize,
+    column: &ColumnValues<'_, '_>,
+    format: &str,
+    timezone: Option<&str>,
+) -> Result<ArrayRef, ArrowError> {
+    let tz: Tz = timezone.unwrap_or("+00:00").parse()?;
+    let array = build_primitive_array_with::<T, _>(line_number, column, |s| {
+        let utc = match DateTime::parse_from_str(s, format) {
+            Ok(datetime) => datetime.naive_utc(),
+            Err(_) => {
+                let naive = NaiveDateTime::parse_from_str(s, format).ok()?;
+                tz.from_local_datetime(&naive).single()?.naive_utc()
             }
-            let parsed = parse_bool(s);
+        };
+        T::make_value(utc)
+    })?;
+    Ok(Arc::new(array.with_timezone_opt(timezone)))
+}
+
+// parses a specific column (col_idx) into an Arrow Array.
+fn build_boolean_array<F>(
+    line_number: usize,
+    column: &ColumnValues<'_, '_>,
+    parse: F,
+) -> Result<ArrayRef, ArrowError>
+where
+    F: Fn(&str) -> Option<bool>,
+{
+    column
+        .iter()
+        .enumerate()
+        .map(|(row_index, s)| {
+            let s = match s {
+                Some(s) => s,
+                None => return Ok(None),
+            };
+            let parsed = parse(&s);
             match parsed {
                 Some(e) => Ok(Some(e)),
                 None => Err(ArrowError::ParseError(format!(
                     // TODO: we should surface the underlying error here.
                     "Error while parsing value {} for column {} at line {}",
                     s,
-                    col_idx,
+                    column.col_idx,
                     line_number + row_index
                 ))),
             }
@@ -961,7 +1439,7 @@ fn build_boolean_array(
 }
 
 /// CSV file reader builder
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ReaderBuilder {
     /// Schema of the CSV file
     schema: SchemaRef,
@@ -975,6 +1453,8 @@ pub struct ReaderBuilder {
     bounds: Bounds,
     /// Optional projection for which columns to load (zero-based column indices)
     projection: Option<Vec<usize>>,
+    /// Custom parsers keyed by column index
+    column_parsers: HashMap<usize, ColumnParser>,
 }
 
 impl ReaderBuilder {
@@ -1006,6 +1486,7 @@ impl ReaderBuilder {
             batch_size: 1024,
             bounds: None,
             projection: None,
+            column_parsers: HashMap::new(),
         }
     }
 
@@ -1023,7 +1504,7 @@ impl ReaderBuilder {
 
     /// Set the CSV file's column delimiter as a byte character
     pub fn with_delimiter(mut self, delimiter: u8) -> Self {
-        self.format.delimiter = Some(delimiter);
+        self.format = self.format.with_delimiter(delimiter);
         self
     }
 
@@ -1042,6 +1523,65 @@ impl ReaderBuilder {
         self
     }
 
+    /// Set the CSV file's column delimiter as a string, see [`Format::with_delimiter_str`]
+    pub fn with_delimiter_str(mut self, delimiter: &str) -> Self {
+        self.format = self.format.with_delimiter_str(delimiter);
+        self
+    }
+
+    /// Set a comment character, records starting with this byte will be ignored
+    pub fn with_comment(mut self, comment: u8) -> Self {
+        self.format.comment = Some(comment);
+        self
+    }
+
+    /// Set the strings to interpret as null, see [`Format::with_null_values`]
+    pub fn with_null_values<I, S>(mut self, null_values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.format = self.format.with_null_values(null_values);
+        self
+    }
+
+    /// Set a [`ColumnParser`] for the column at index `col_idx` of the schema
+    ///
+    /// This allows parsing values that aren't supported by default directly into
+    /// the column's type, such as dates in a custom format, booleans such as
+    /// `"Y"` and `"N"`, or numbers with a comma as the decimal separator
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Float64Type;
+    /// # use arrow_csv::reader::ColumnParser;
+    /// # use arrow_csv::ReaderBuilder;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// let schema = Schema::new(vec![Field::new("price", DataType::Float64, true)]);
+    /// let parser = ColumnParser::Number {
+    ///     decimal: ',',
+    ///     thousands: Some('.'),
+    /// };
+    /// let mut reader = ReaderBuilder::new(Arc::new(schema))
+    ///     .with_delimiter(b';')
+    ///     .with_column_parser(0, parser)
+    ///     .build("1.234,5\n".as_bytes())
+    ///     .unwrap();
+    /// let batch = reader.next().unwrap().unwrap();
+    /// assert_eq!(batch.column(0).as_primitive::<Float64Type>().value(0), 1234.5);
+    /// ```
+    pub fn with_column_parser(mut self, col_idx: usize, parser: ColumnParser) -> Self {
+        self.column_parsers.insert(col_idx, parser);
+        self
+    }
+
+    /// Set the number of lines to skip before the header, see [`Format::with_skip_rows`]
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.format.skip_rows = skip_rows;
+        self
+    }
+
     /// Set the batch size (number of records to load at one time)
     pub fn with_batch_size(mut self, batch_size: usize) -> Self {
         self.batch_size = batch_size;
@@ -1084,6 +1624,7 @@ impl ReaderBuilder {
     pub fn build_decoder(self) -> Decoder {
         let delimiter = self.format.build_parser();
         let record_decoder = RecordDecoder::new(delimiter, self.schema.fields().len());
+        let translator = self.format.build_translator();
 
         let header = self.format.has_header as usize;
 
@@ -1095,6 +1636,13 @@ impl ReaderBuilder {
         Decoder {
             schema: self.schema,
             to_skip: start,
+            skip_lines: self.format.skip_rows,
+            line_terminator: self.format.line_terminator(),
+            translator,
+            translated: vec![],
+            translated_offset: 0,
+            null_values: self.format.null_values,
+            column_parsers: self.column_parsers,
             record_decoder,
             line_number: start,
             end,
@@ -1102,6 +1650,52 @@ impl ReaderBuilder {
             batch_size: self.batch_size,
         }
     }
+
+    /// Builds a [`RecordSplitter`](split::RecordSplitter) yielding chunks of at most
+    /// `batch_size` records, applying the bounds, header and skipped rows
+    #[cfg(feature = "async")]
+    pub(crate) fn build_splitter(&self) -> split::RecordSplitter {
+        let header = self.format.has_header as usize;
+        let (start, remaining) = match self.bounds {
+            Some((start, end)) => (start + header, end.saturating_sub(start)),
+            None => (header, usize::MAX),
+        };
+
+        split::RecordSplitter::new(
+            self.format.build_parser(),
+            self.format.build_translator(),
+            self.format.skip_rows,
+            self.format.line_terminator(),
+            start,
+            remaining,
+            self.batch_size,
+        )
+    }
+
+    /// Builds a [`Decoder`] for a [`Chunk`](split::Chunk) yielded by a splitter
+    /// returned by [`Self::build_splitter`]
+    #[cfg(feature = "async")]
+    pub(crate) fn build_chunk_decoder(&self, line_number: usize) -> Decoder {
+        let delimiter = self.format.build_parser();
+        let record_decoder = RecordDecoder::new(delimiter, self.schema.fields().len());
+
+        Decoder {
+            schema: self.schema.clone(),
+            to_skip: 0,
+            skip_lines: 0,
+            line_terminator: self.format.line_terminator(),
+            translator: None,
+            translated: vec![],
+            translated_offset: 0,
+            null_values: self.format.null_values.clone(),
+            column_parsers: self.column_parsers.clone(),
+            record_decoder,
+            line_number,
+            end: usize::MAX,
+            projection: self.projection.clone(),
+            batch_size: self.batch_size,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -2024,6 +2618,214 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_skip_rows_and_comments() {
+        let csv = "generated by a tool, with, extra, fields\n\nname,value\n# a comment\na,1\n#b,2\nc,3\n";
+
+        let format = Format::default()
+            .with_header(true)
+            .with_skip_rows(2)
+            .with_comment(b'#');
+        let (schema, records) = format.infer_schema(csv.as_bytes(), None).unwrap();
+        assert_eq!(records, 2);
+        assert_eq!(schema.field(0).name(), "name");
+        assert_eq!(schema.field(1).data_type(), &DataType::Int64);
+        let schema = Arc::new(schema);
+
+        for capacity in [1, 3, 100] {
+            let buffered = std::io::BufReader::with_capacity(capacity, csv.as_bytes());
+            let batches = ReaderBuilder::new(schema.clone())
+                .with_format(format.clone())
+                .with_batch_size(1)
+                .build_buffered(buffered)
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+
+            assert_eq!(batches.len(), 2);
+            let names = batches[0].column(0).as_string::<i32>();
+            assert_eq!(names.value(0), "a");
+            let names = batches[1].column(0).as_string::<i32>();
+            assert_eq!(names.value(0), "c");
+            let values = batches[1].column(1).as_primitive::<Int64Type>();
+            assert_eq!(values.value(0), 3);
+        }
+    }
+
+    #[test]
+    fn test_multi_byte_delimiter() {
+        let csv = "a::b::c\n1::\"x::y\"::3\n2::\"\"\"q\"\"\"::4:\n5::z:a::6\n";
+
+        let format = Format::default().with_header(true).with_delimiter_str("::");
+        let (schema, records) = format.infer_schema(csv.as_bytes(), None).unwrap();
+        assert_eq!(records, 3);
+        assert_eq!(schema.fields().len(), 3);
+        assert_eq!(schema.field(2).data_type(), &DataType::Utf8);
+        let schema = Arc::new(schema);
+
+        for capacity in [1, 2, 5, 100] {
+            for batch_size in [1, 2, 10] {
+                let buffered =
+                    std::io::BufReader::with_capacity(capacity, csv.as_bytes());
+                let batches = ReaderBuilder::new(schema.clone())
+                    .with_format(format.clone())
+                    .with_batch_size(batch_size)
+                    .build_buffered(buffered)
+                    .unwrap()
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap();
+
+                let rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+                assert_eq!(rows, 3);
+                let mut a: Vec<i64> = vec![];
+                let mut b = vec![];
+                let mut c = vec![];
+                for batch in &batches {
+                    a.extend(batch.column(0).as_primitive::<Int64Type>().values());
+                    b.extend(batch.column(1).as_string::<i32>().iter().flatten());
+                    c.extend(batch.column(2).as_string::<i32>().iter().flatten());
+                }
+                assert_eq!(a, &[1, 2, 5]);
+                assert_eq!(b, &["x::y", "\"q\"", "z:a"]);
+                assert_eq!(c, &["3", "4:", "6"]);
+            }
+        }
+
+        // Bounds are applied to translated records
+        let batch = ReaderBuilder::new(schema)
+            .with_format(format)
+            .with_bounds(1, 3)
+            .build(csv.as_bytes())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        let a = batch.column(0).as_primitive::<Int64Type>();
+        assert_eq!(a.values(), &[2, 5]);
+    }
+
+    #[test]
+    fn test_delimiter_str_single_byte() {
+        let format = Format::default().with_delimiter_str("|");
+        let (schema, _) = format.infer_schema("1|2\n".as_bytes(), None).unwrap();
+        assert_eq!(schema.fields().len(), 2);
+    }
+
+    #[test]
+    fn test_null_values() {
+        let csv = "a,b,c\n1,x,NA\n-,NA,2.5\n3,,-\n";
+        let format = Format::default()
+            .with_header(true)
+            .with_null_values(["NA", "-", ""]);
+        let (schema, _) = format.infer_schema(csv.as_bytes(), None).unwrap();
+        assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+        assert_eq!(schema.field(1).data_type(), &DataType::Utf8);
+        assert_eq!(schema.field(2).data_type(), &DataType::Float64);
+
+        let batch = ReaderBuilder::new(Arc::new(schema))
+            .with_format(format)
+            .build(csv.as_bytes())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+
+        let a = batch.column(0).as_primitive::<Int64Type>();
+        assert_eq!(a.iter().collect::<Vec<_>>(), vec![Some(1), None, Some(3)]);
+        let b = batch.column(1).as_string::<i32>();
+        assert_eq!(b.iter().collect::<Vec<_>>(), vec![Some("x"), None, None]);
+        let c = batch.column(2).as_primitive::<Float64Type>();
+        assert_eq!(c.iter().collect::<Vec<_>>(), vec![None, Some(2.5), None]);
+    }
+
+    #[test]
+    fn test_column_parsers() {
+        let csv = "19/03/2020,Y,\"1.234,5\",12:30,19/03/2020 10:00,foo\n\
+                   20/03/2020,N,\"-0,25\",01:00,20/03/2020 23:59,bar\n";
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("date", DataType::Date32, true),
+            Field::new("flag", DataType::Boolean, true),
+            Field::new("amount", DataType::Decimal128(10, 2), true),
+            Field::new("time", DataType::Time32(TimeUnit::Second), true),
+            Field::new(
+                "ts",
+                DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".into())),
+                true,
+            ),
+            Field::new("upper", DataType::Utf8, true),
+        ]));
+
+        let batch = ReaderBuilder::new(schema)
+            .with_column_parser(0, ColumnParser::Format("%d/%m/%Y".to_string()))
+            .with_column_parser(
+                1,
+                ColumnParser::Boolean {
+                    true_values: vec!["Y".to_string()],
+                    false_values: vec!["N".to_string()],
+                },
+            )
+            .with_column_parser(
+                2,
+                ColumnParser::Number {
+                    decimal: ',',
+                    thousands: Some('.'),
+                },
+            )
+            .with_column_parser(3, ColumnParser::Format("%H:%M".to_string()))
+            .with_column_parser(4, ColumnParser::Format("%d/%m/%Y %H:%M".to_string()))
+            .with_column_parser(
+                5,
+                ColumnParser::Map(Arc::new(|s| match s {
+                    "bar" => None,
+                    s => Some(s.to_uppercase()),
+                })),
+            )
+            .build(csv.as_bytes())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+
+        let date = batch.column(0).as_primitive::<Date32Type>();
+        assert_eq!(date.values(), &[18340, 18341]);
+        let flag = batch.column(1).as_boolean();
+        assert_eq!(
+            flag.iter().collect::<Vec<_>>(),
+            vec![Some(true), Some(false)]
+        );
+        let amount = batch.column(2).as_primitive::<Decimal128Type>();
+        assert_eq!(amount.values(), &[123450, -25]);
+        let time = batch.column(3).as_primitive::<Time32SecondType>();
+        assert_eq!(time.values(), &[45000, 3600]);
+        let ts = batch.column(4).as_primitive::<TimestampMillisecondType>();
+        assert_eq!(ts.timezone(), Some("+01:00"));
+        assert_eq!(ts.values(), &[1584608400000, 1584745140000]);
+        let upper = batch.column(5).as_string::<i32>();
+        assert_eq!(upper.iter().collect::<Vec<_>>(), vec![Some("FOO"), None]);
+    }
+
+    #[test]
+    fn test_column_parser_unsupported() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Utf8, true)]));
+        let err = ReaderBuilder::new(schema)
+            .with_column_parser(
+                0,
+                ColumnParser::Number {
+                    decimal: ',',
+                    thousands: None,
+                },
+            )
+            .build("x\n".as_bytes())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Number parser is not supported for column 0 of type Utf8"
+        );
+    }
+
     fn err_test(csv: &[u8], expected: &str) {
         let schema = Arc::new(Schema::new(vec![
             Field::new("text1", DataType::Utf8, false),
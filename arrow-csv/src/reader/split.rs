@@ -0,0 +1,229 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::reader::delimiter::DelimiterTranslator;
+use arrow_schema::ArrowError;
+use csv_core::{ReadRecordResult, Reader};
+
+/// A run of complete CSV records that can be decoded independently
+#[derive(Debug)]
+pub struct Chunk {
+    /// The record data, with any multi-byte delimiters already translated
+    pub data: Vec<u8>,
+    /// The line number of the first record in this chunk
+    pub line_number: usize,
+}
+
+/// Splits a CSV byte stream into [`Chunk`] of at most `chunk_size` records
+///
+/// Record boundaries are found with the same [`csv_core::Reader`] configuration used
+/// to decode the records, and so correctly handle quoted fields containing newlines
+#[derive(Debug)]
+pub struct RecordSplitter {
+    parser: Reader,
+    translator: Option<DelimiterTranslator>,
+
+    /// Lines to skip without parsing
+    skip_lines: usize,
+    /// The byte delimiting the lines in `skip_lines`
+    line_terminator: u8,
+    /// Records to skip, e.g. the header
+    to_skip: usize,
+    /// The number of records still to yield
+    remaining: usize,
+    /// The maximum number of records per chunk
+    chunk_size: usize,
+
+    /// Buffered data, with `buffer[start..]` not yet yielded or skipped
+    buffer: Vec<u8>,
+    start: usize,
+    /// The offset into `buffer` parsed so far
+    scan_offset: usize,
+    /// The number of complete records in `buffer[start..scan_offset]`
+    records: usize,
+    /// The line number of the record at `buffer[start]`
+    line_number: usize,
+
+    /// Scratch space for the parsed output, which is discarded
+    output: Vec<u8>,
+    ends: Vec<usize>,
+}
+
+impl RecordSplitter {
+    pub fn new(
+        parser: Reader,
+        translator: Option<DelimiterTranslator>,
+        skip_lines: usize,
+        line_terminator: u8,
+        to_skip: usize,
+        remaining: usize,
+        chunk_size: usize,
+    ) -> Self {
+        Self {
+            parser,
+            translator,
+            skip_lines,
+            line_terminator,
+            to_skip,
+            remaining,
+            chunk_size: chunk_size.max(1),
+            buffer: vec![],
+            start: 0,
+            scan_offset: 0,
+            records: 0,
+            line_number: to_skip,
+            output: vec![0; 1024],
+            ends: vec![0; 1024],
+        }
+    }
+
+    /// Push `data` returning any complete [`Chunk`]
+    pub fn push(&mut self, mut data: &[u8]) -> Result<Vec<Chunk>, ArrowError> {
+        while self.skip_lines != 0 && !data.is_empty() {
+            match data.iter().position(|b| *b == self.line_terminator) {
+                Some(idx) => {
+                    data = &data[idx + 1..];
+                    self.skip_lines -= 1;
+                }
+                None => data = &[],
+            }
+        }
+
+        if self.remaining == 0 {
+            return Ok(vec![]);
+        }
+
+        match self.translator.as_mut() {
+            Some(translator) => translator.translate(data, &mut self.buffer)?,
+            None => self.buffer.extend_from_slice(data),
+        }
+
+        let mut chunks = vec![];
+        self.scan(false, &mut chunks);
+
+        // Compact the buffer to avoid unbounded growth
+        if self.start != 0 {
+            self.buffer.drain(..self.start);
+            self.scan_offset -= self.start;
+            self.start = 0;
+        }
+        Ok(chunks)
+    }
+
+    /// Signal the end of the input, returning the final [`Chunk`] if any
+    pub fn finish(&mut self) -> Result<Option<Chunk>, ArrowError> {
+        self.skip_lines = 0;
+        if let Some(translator) = self.translator.as_mut() {
+            translator.finish(&mut self.buffer);
+        }
+
+        let mut chunks = vec![];
+        self.scan(true, &mut chunks);
+        if self.records != 0 {
+            chunks.push(self.take_chunk());
+        }
+        self.buffer.clear();
+        self.start = 0;
+        self.scan_offset = 0;
+
+        // At most one chunk can be yielded, as any full chunks are yielded by push
+        debug_assert!(chunks.len() <= 1);
+        Ok(chunks.pop())
+    }
+
+    fn take_chunk(&mut self) -> Chunk {
+        let data = self.buffer[self.start..self.scan_offset].to_vec();
+        let chunk = Chunk {
+            data,
+            line_number: self.line_number,
+        };
+        self.line_number += self.records;
+        self.start = self.scan_offset;
+        self.records = 0;
+        chunk
+    }
+
+    fn scan(&mut self, eof: bool, chunks: &mut Vec<Chunk>) {
+        while self.remaining != 0 {
+            let input = &self.buffer[self.scan_offset..];
+            if input.is_empty() && !eof {
+                return;
+            }
+
+            let (result, read, _, _) =
+                self.parser
+                    .read_record(input, &mut self.output, &mut self.ends);
+            self.scan_offset += read;
+
+            match result {
+                ReadRecordResult::InputEmpty => {
+                    if !eof {
+                        return;
+                    }
+                }
+                ReadRecordResult::End => return,
+                ReadRecordResult::OutputFull | ReadRecordResult::OutputEndsFull => {}
+                ReadRecordResult::Record if self.to_skip != 0 => {
+                    self.to_skip -= 1;
+                    self.start = self.scan_offset;
+                }
+                ReadRecordResult::Record => {
+                    self.records += 1;
+                    self.remaining -= 1;
+                    if self.records == self.chunk_size {
+                        chunks.push(self.take_chunk());
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(data: &[&str], to_skip: usize, remaining: usize) -> Vec<(String, usize)> {
+        let mut splitter =
+            RecordSplitter::new(Reader::new(), None, 0, b'\n', to_skip, remaining, 2);
+        let mut chunks = vec![];
+        for d in data {
+            chunks.extend(splitter.push(d.as_bytes()).unwrap());
+        }
+        chunks.extend(splitter.finish().unwrap());
+        chunks
+            .into_iter()
+            .map(|c| (String::from_utf8(c.data).unwrap(), c.line_number))
+            .collect()
+    }
+
+    #[test]
+    fn test_split() {
+        let expected = vec![
+            ("a,\"b\nc\"\nd,e\n".to_string(), 0),
+            ("f,g\nh,i".to_string(), 2),
+        ];
+        let data = "a,\"b\nc\"\nd,e\nf,g\nh,i";
+        assert_eq!(split(&[data], 0, usize::MAX), expected);
+
+        let chunks: Vec<_> = (0..data.len()).map(|i| &data[i..i + 1]).collect();
+        assert_eq!(split(&chunks, 0, usize::MAX), expected);
+
+        let expected = vec![("d,e\nf,g\n".to_string(), 1)];
+        assert_eq!(split(&[data], 1, 2), expected);
+    }
+}
@@ -18,7 +18,8 @@
 //! CSV Writer
 //!
 //! This CSV writer allows Arrow data (in record batches) to be written as CSV files.
-//! The writer does not support writing `ListArray` and `StructArray`.
+//! Nested columns, such as `ListArray` and `StructArray`, are written as compact
+//! JSON literals, see [`NestedFormat::Json`].
 //!
 //! Example:
 //!
@@ -143,16 +144,13 @@ impl<W: Write> Writer<W> {
             .with_datetime_format(self.datetime_format.as_deref())
             .with_timestamp_format(self.timestamp_format.as_deref())
             .with_timestamp_tz_format(self.timestamp_tz_format.as_deref())
-            .with_time_format(self.time_format.as_deref());
+            .with_time_format(self.time_format.as_deref())
+            .with_nested_format(NestedFormat::Json);
 
         let converters = batch
             .columns()
             .iter()
             .map(|a| match a.data_type() {
-                d if d.is_nested() => Err(ArrowError::CsvError(format!(
-                    "Nested type {} is not supported in CSV",
-                    a.data_type()
-                ))),
                 DataType::Binary | DataType::LargeBinary => Err(ArrowError::CsvError(
                     "Binary data cannot be written to CSV".to_string(),
                 )),
@@ -203,11 +201,45 @@ impl<W: Write> RecordBatchWriter for Writer<W> {
     }
 }
 
+/// The quoting style to use when writing CSV fields, see [`WriterBuilder::with_quote_style`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuoteStyle {
+    /// Quote all fields
+    Always,
+    /// Only quote fields containing a delimiter, quote or line terminator
+    #[default]
+    Necessary,
+    /// Quote all fields that are not numbers
+    NonNumeric,
+    /// Never quote fields, which may produce invalid CSV
+    Never,
+}
+
+impl From<QuoteStyle> for csv::QuoteStyle {
+    fn from(style: QuoteStyle) -> Self {
+        match style {
+            QuoteStyle::Always => csv::QuoteStyle::Always,
+            QuoteStyle::Necessary => csv::QuoteStyle::Necessary,
+            QuoteStyle::NonNumeric => csv::QuoteStyle::NonNumeric,
+            QuoteStyle::Never => csv::QuoteStyle::Never,
+        }
+    }
+}
+
 /// A CSV writer builder
 #[derive(Clone, Debug)]
 pub struct WriterBuilder {
     /// Optional column delimiter. Defaults to `b','`
     delimiter: Option<u8>,
+    /// The quoting style. Defaults to [`QuoteStyle::Necessary`]
+    quote_style: QuoteStyle,
+    /// Optional quote character. Defaults to `b'"'`
+    quote: Option<u8>,
+    /// Optional escape character for quotes within quoted fields. Defaults to
+    /// `None`, where quotes are escaped by doubling them
+    escape: Option<u8>,
+    /// Optional record terminator. Defaults to `\n`
+    terminator: Option<csv::Terminator>,
     /// Whether to write column names as file headers. Defaults to `true`
     has_headers: bool,
     /// Optional date format for date arrays
@@ -229,6 +261,10 @@ impl Default for WriterBuilder {
         Self {
             has_headers: true,
             delimiter: None,
+            quote_style: QuoteStyle::default(),
+            quote: None,
+            escape: None,
+            terminator: None,
             date_format: Some(DEFAULT_DATE_FORMAT.to_string()),
             datetime_format: Some(DEFAULT_TIMESTAMP_FORMAT.to_string()),
             time_format: Some(DEFAULT_TIME_FORMAT.to_string()),
@@ -276,6 +312,38 @@ impl WriterBuilder {
         self
     }
 
+    /// Set the [`QuoteStyle`] used to quote fields
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    /// Set the CSV file's quote character as a byte character
+    pub fn with_quote(mut self, quote: u8) -> Self {
+        self.quote = Some(quote);
+        self
+    }
+
+    /// Set the character used to escape quotes within quoted fields
+    ///
+    /// By default quotes are escaped by doubling them, as specified by RFC 4180
+    pub fn with_escape(mut self, escape: u8) -> Self {
+        self.escape = Some(escape);
+        self
+    }
+
+    /// Set the CSV file's record terminator as a byte character
+    pub fn with_terminator(mut self, terminator: u8) -> Self {
+        self.terminator = Some(csv::Terminator::Any(terminator));
+        self
+    }
+
+    /// Terminate records with `\r\n`, as specified by RFC 4180
+    pub fn with_crlf(mut self) -> Self {
+        self.terminator = Some(csv::Terminator::CRLF);
+        self
+    }
+
     /// Set the CSV file's date format
     pub fn with_date_format(mut self, format: String) -> Self {
         self.date_format = Some(format);
@@ -320,7 +388,19 @@ impl WriterBuilder {
     pub fn build<W: Write>(self, writer: W) -> Writer<W> {
         let delimiter = self.delimiter.unwrap_or(b',');
         let mut builder = csv::WriterBuilder::new();
-        let writer = builder.delimiter(delimiter).from_writer(writer);
+        builder
+            .delimiter(delimiter)
+            .quote_style(self.quote_style.into());
+        if let Some(quote) = self.quote {
+            builder.quote(quote);
+        }
+        if let Some(escape) = self.escape {
+            builder.escape(escape).double_quote(false);
+        }
+        if let Some(terminator) = self.terminator {
+            builder.terminator(terminator);
+        }
+        let writer = builder.from_writer(writer);
         Writer {
             writer,
             has_headers: self.has_headers,
@@ -671,4 +751,90 @@ sed do eiusmod tempor,-556132.25,1,,2019-04-18T02:45:55.555000000,23:46:03,foo
             String::from_utf8(buffer).unwrap()
         );
     }
+
+    #[test]
+    fn test_write_csv_quoting() {
+        let schema = Schema::new(vec![
+            Field::new("c1", DataType::Utf8, true),
+            Field::new("c2", DataType::Int32, true),
+        ]);
+        let c1 = StringArray::from(vec![Some("a \"b\""), None, Some("c,d")]);
+        let c2 = Int32Array::from(vec![Some(1), Some(2), None]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(c1), Arc::new(c2)])
+                .unwrap();
+
+        let write = |builder: WriterBuilder| {
+            let mut writer = builder.build(vec![]);
+            writer.write(&batch).unwrap();
+            String::from_utf8(writer.into_inner()).unwrap()
+        };
+
+        assert_eq!(
+            write(WriterBuilder::new()),
+            "c1,c2\n\"a \"\"b\"\"\",1\n,2\n\"c,d\",\n"
+        );
+        assert_eq!(
+            write(WriterBuilder::new().with_quote_style(QuoteStyle::Always)),
+            "\"c1\",\"c2\"\n\"a \"\"b\"\"\",\"1\"\n\"\",\"2\"\n\"c,d\",\"\"\n"
+        );
+        assert_eq!(
+            write(
+                WriterBuilder::new()
+                    .with_quote_style(QuoteStyle::NonNumeric)
+                    .with_null("NULL".to_string())
+            ),
+            "\"c1\",\"c2\"\n\"a \"\"b\"\"\",1\n\"NULL\",2\n\"c,d\",\"NULL\"\n"
+        );
+        assert_eq!(
+            write(WriterBuilder::new().with_quote_style(QuoteStyle::Never)),
+            "c1,c2\na \"b\",1\n,2\nc,d,\n"
+        );
+        assert_eq!(
+            write(
+                WriterBuilder::new()
+                    .with_escape(b'\\')
+                    .with_quote(b'\'')
+                    .with_crlf()
+                    .has_headers(false)
+            ),
+            "a \"b\",1\r\n,2\r\n'c,d',\r\n"
+        );
+        assert_eq!(
+            write(
+                WriterBuilder::new()
+                    .with_escape(b'\\')
+                    .with_terminator(b';')
+            ),
+            "c1,c2;\"a \\\"b\\\"\",1;,2;\"c,d\",;"
+        );
+    }
+
+    #[test]
+    fn test_write_csv_nested() {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+        ]);
+        let strings = StringArray::from(vec![Some("a,b"), None]);
+        let s = StructArray::from(vec![(
+            Arc::new(Field::new("s", DataType::Utf8, true)),
+            Arc::new(strings) as ArrayRef,
+        )]);
+        let batch = RecordBatch::try_from_iter([
+            ("list", Arc::new(list) as ArrayRef),
+            ("struct", Arc::new(s) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut writer = WriterBuilder::new()
+            .with_null("NULL".to_string())
+            .build(vec![]);
+        writer.write(&batch).unwrap();
+        let written = String::from_utf8(writer.into_inner()).unwrap();
+        assert_eq!(
+            written,
+            "list,struct\n\"[1,null]\",\"{\"\"s\"\":\"\"a,b\"\"}\"\nNULL,\"{\"\"s\"\":null}\"\n"
+        );
+    }
 }
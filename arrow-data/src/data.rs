@@ -20,6 +20,7 @@
 
 use crate::bit_iterator::BitSliceIterator;
 use arrow_buffer::buffer::{BooleanBuffer, NullBuffer};
+use arrow_buffer::pool::MemoryPool;
 use arrow_buffer::{bit_util, ArrowNativeType, Buffer, MutableBuffer};
 use arrow_schema::{ArrowError, DataType, UnionMode};
 use std::convert::TryInto;
@@ -28,6 +29,7 @@ use std::ops::Range;
 use std::sync::Arc;
 
 use crate::equal;
+use crate::transform::MutableArrayData;
 
 /// A collection of [`Buffer`]
 #[doc(hidden)]
@@ -123,6 +125,14 @@ pub(crate) fn new_buffers(data_type: &DataType, capacity: usize) -> [MutableBuff
             buffer.push(0i64);
             [buffer, empty_buffer]
         }
+        DataType::ListView(_) => [
+            MutableBuffer::new(capacity * mem::size_of::<i32>()),
+            MutableBuffer::new(capacity * mem::size_of::<i32>()),
+        ],
+        DataType::LargeListView(_) => [
+            MutableBuffer::new(capacity * mem::size_of::<i64>()),
+            MutableBuffer::new(capacity * mem::size_of::<i64>()),
+        ],
         DataType::FixedSizeBinary(size) => {
             [MutableBuffer::new(capacity * *size as usize), empty_buffer]
         }
@@ -133,7 +143,10 @@ pub(crate) fn new_buffers(data_type: &DataType, capacity: usize) -> [MutableBuff
         DataType::FixedSizeList(_, _)
         | DataType::Struct(_)
         | DataType::RunEndEncoded(_, _) => [empty_buffer, MutableBuffer::new(0)],
-        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => [
+        DataType::Decimal32(_, _)
+        | DataType::Decimal64(_, _)
+        | DataType::Decimal128(_, _)
+        | DataType::Decimal256(_, _) => [
             MutableBuffer::new(capacity * mem::size_of::<u8>()),
             empty_buffer,
         ],
@@ -162,7 +175,9 @@ pub(crate) fn into_buffers(
         DataType::Utf8
         | DataType::Binary
         | DataType::LargeUtf8
-        | DataType::LargeBinary => vec![buffer1.into(), buffer2.into()],
+        | DataType::LargeBinary
+        | DataType::ListView(_)
+        | DataType::LargeListView(_) => vec![buffer1.into(), buffer2.into()],
         DataType::Union(_, mode) => {
             match mode {
                 // Based on Union's DataTypeLayout
@@ -174,6 +189,27 @@ pub(crate) fn into_buffers(
     }
 }
 
+/// Returns `true` if `data_type` is or contains [`DataType::RunEndEncoded`]
+fn contains_run_end_encoded(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::RunEndEncoded(_, _) => true,
+        DataType::List(f)
+        | DataType::LargeList(f)
+        | DataType::ListView(f)
+        | DataType::LargeListView(f)
+        | DataType::FixedSizeList(f, _)
+        | DataType::Map(f, _) => contains_run_end_encoded(f.data_type()),
+        DataType::Struct(fields) => fields
+            .iter()
+            .any(|f| contains_run_end_encoded(f.data_type())),
+        DataType::Union(fields, _) => fields
+            .iter()
+            .any(|(_, f)| contains_run_end_encoded(f.data_type())),
+        DataType::Dictionary(_, v) => contains_run_end_encoded(v),
+        _ => false,
+    }
+}
+
 /// An generic representation of Arrow array data which encapsulates common attributes and
 /// operations for Arrow array. Specific operations for different arrays types (e.g.,
 /// primitive, list, struct) are implemented in `Array`.
@@ -494,12 +530,60 @@ impl ArrayData {
             result += bit_util::ceil(self.len, 8);
         }
 
-        for child in &self.child_data {
-            result += child.get_slice_memory_size()?;
+        // Only count the region of the children referenced by this slice
+        match &self.data_type {
+            DataType::List(_) | DataType::Map(_, _) => {
+                let offsets = self.typed_offsets::<i32>()?;
+                if let (Some(start), Some(end)) = (offsets.first(), offsets.last()) {
+                    let (start, end) = (*start as usize, *end as usize);
+                    let child = self.child_data[0].slice(start, end - start);
+                    result += child.get_slice_memory_size()?;
+                }
+            }
+            DataType::LargeList(_) => {
+                let offsets = self.typed_offsets::<i64>()?;
+                if let (Some(start), Some(end)) = (offsets.first(), offsets.last()) {
+                    let (start, end) = (*start as usize, *end as usize);
+                    let child = self.child_data[0].slice(start, end - start);
+                    result += child.get_slice_memory_size()?;
+                }
+            }
+            DataType::FixedSizeList(_, size) => {
+                let size = *size as usize;
+                let child = self.child_data[0].slice(self.offset * size, self.len * size);
+                result += child.get_slice_memory_size()?;
+            }
+            _ => {
+                for child in &self.child_data {
+                    result += child.get_slice_memory_size()?;
+                }
+            }
         }
         Ok(result)
     }
 
+    /// Returns a copy of this [`ArrayData`] with new [`Buffer`]s containing only the
+    /// data of this slice, see [`Self::get_slice_memory_size`]
+    ///
+    /// A slice of an [`ArrayData`] retains the [`Buffer`]s of the original, and so a
+    /// small slice may keep a much larger allocation alive. Copying the slice releases
+    /// its reference to these [`Buffer`]s. The values of dictionaries are not sliced,
+    /// as they may be referenced by any key, and are shared with the original.
+    ///
+    /// Returns an error for [`DataType::RunEndEncoded`], which is not yet supported
+    pub fn deep_copy_sliced(&self) -> Result<ArrayData, ArrowError> {
+        if contains_run_end_encoded(&self.data_type) {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Copying slices of {} is not yet supported",
+                self.data_type
+            )));
+        }
+
+        let mut copy = MutableArrayData::new(vec![self], false, self.len);
+        copy.extend(0, 0, self.len);
+        Ok(copy.freeze())
+    }
+
     /// Returns the total number of bytes of memory occupied
     /// physically by this [`ArrayData`] and all its [`Buffer`]s and
     /// children. (See also diagram on [`ArrayData`]).
@@ -526,6 +610,28 @@ impl ArrayData {
         size
     }
 
+    /// Claim the memory used by the [`Buffer`]s of this [`ArrayData`], including
+    /// those of its children, against `pool`
+    ///
+    /// Buffers already claimed, for example because they are shared with another
+    /// [`ArrayData`], are not claimed again. See [`arrow_buffer::pool`] for more
+    /// information
+    pub fn claim(&self, pool: &dyn MemoryPool) -> Result<(), ArrowError> {
+        let err = |e: arrow_buffer::pool::MemoryPoolError| {
+            ArrowError::MemoryError(e.to_string())
+        };
+        for buffer in &self.buffers {
+            buffer.claim(pool).map_err(err)?;
+        }
+        if let Some(nulls) = &self.nulls {
+            nulls.buffer().claim(pool).map_err(err)?;
+        }
+        for child in &self.child_data {
+            child.claim(pool)?;
+        }
+        Ok(())
+    }
+
     /// Creates a zero-copy slice of itself. This creates a new
     /// [`ArrayData`] pointing at the same underlying [`Buffer`]s with a
     /// different offset and len
@@ -603,6 +709,16 @@ impl ArrayData {
                     vec![ArrayData::new_empty(f.data_type())],
                     true,
                 ),
+                DataType::ListView(f) => (
+                    vec![zeroed(len * 4), zeroed(len * 4)],
+                    vec![ArrayData::new_empty(f.data_type())],
+                    true,
+                ),
+                DataType::LargeListView(f) => (
+                    vec![zeroed(len * 8), zeroed(len * 8)],
+                    vec![ArrayData::new_empty(f.data_type())],
+                    true,
+                ),
                 DataType::FixedSizeList(f, list_len) => (
                     vec![],
                     vec![ArrayData::new_null(f.data_type(), *list_len as usize * len)],
@@ -737,9 +853,12 @@ impl ArrayData {
         {
             match spec {
                 BufferSpec::FixedWidth { byte_width } => {
-                    let min_buffer_size = len_plus_offset
-                        .checked_mul(*byte_width)
-                        .expect("integer overflow computing min buffer size");
+                    let min_buffer_size =
+                        len_plus_offset.checked_mul(*byte_width).ok_or_else(|| {
+                            ArrowError::InvalidArgumentError(
+                                "integer overflow computing min buffer size".to_string(),
+                            )
+                        })?;
 
                     if buffer.len() < min_buffer_size {
                         return Err(ArrowError::InvalidArgumentError(format!(
@@ -867,7 +986,17 @@ impl ArrayData {
             )));
         }
 
-        Ok(&buffer.typed_data::<T>()[self.offset..self.offset + len])
+        // SAFETY: ArrowNativeType is valid for any bit pattern
+        let (prefix, values, _) = unsafe { buffer[..required_len].align_to::<T>() };
+        if !prefix.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Buffer {} of {} is not aligned to {} bytes",
+                idx,
+                self.data_type,
+                mem::align_of::<T>()
+            )));
+        }
+        Ok(&values[self.offset..self.offset + len])
     }
 
     /// Does a cheap sanity check that the `self.len` values in `buffer` are valid
@@ -933,6 +1062,10 @@ impl ArrayData {
                 self.validate_offsets::<i64>(values_data.len)?;
                 Ok(())
             }
+            DataType::ListView(field) | DataType::LargeListView(field) => {
+                self.get_single_valid_child_data(field.data_type())?;
+                Ok(())
+            }
             DataType::FixedSizeList(field, list_size) => {
                 let values_data = self.get_single_valid_child_data(field.data_type())?;
 
@@ -945,7 +1078,9 @@ impl ArrayData {
 
                 let expected_values_len = self.len
                     .checked_mul(list_size)
-                    .expect("integer overflow computing expected number of expected values in FixedListSize");
+                    .ok_or_else(|| ArrowError::InvalidArgumentError(
+                        "integer overflow computing expected number of expected values in FixedListSize".to_string()
+                    ))?;
 
                 if values_data.len < expected_values_len {
                     return Err(ArrowError::InvalidArgumentError(format!(
@@ -1144,7 +1279,11 @@ impl ArrayData {
         // space in the child. As such we permit nulls in the children in the corresponding
         // positions for such types
         match &self.data_type {
-            DataType::List(f) | DataType::LargeList(f) | DataType::Map(f, _) => {
+            DataType::List(f)
+            | DataType::LargeList(f)
+            | DataType::ListView(f)
+            | DataType::LargeListView(f)
+            | DataType::Map(f, _) => {
                 if !f.is_nullable() {
                     self.validate_non_nullable(None, &self.child_data[0])?
                 }
@@ -1225,6 +1364,14 @@ impl ArrayData {
                 let child = &self.child_data[0];
                 self.validate_offsets_full::<i64>(child.len)
             }
+            DataType::ListView(_) => {
+                let child = &self.child_data[0];
+                self.validate_list_view_full::<i32>(child.len)
+            }
+            DataType::LargeListView(_) => {
+                let child = &self.child_data[0];
+                self.validate_list_view_full::<i64>(child.len)
+            }
             DataType::Union(_, _) => {
                 // Validate Union Array as part of implementing new Union semantics
                 // See comments in `ArrayData::validate()`
@@ -1374,6 +1521,43 @@ impl ArrayData {
         })
     }
 
+    /// Validates that each non-null offset and size pair of a list view
+    /// (typed as T) describes a range within `[0, values_length]`
+    fn validate_list_view_full<T>(&self, values_length: usize) -> Result<(), ArrowError>
+    where
+        T: ArrowNativeType + TryInto<usize> + num::Num + std::fmt::Display,
+    {
+        let offsets = self.typed_buffer::<T>(0, self.len)?;
+        let sizes = self.typed_buffer::<T>(1, self.len)?;
+
+        offsets
+            .iter()
+            .zip(sizes)
+            .enumerate()
+            .try_for_each(|(i, (&offset, &size))| {
+                if self.is_null(i) {
+                    return Ok(());
+                }
+                let o: usize = offset.try_into().map_err(|_| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "Offset invariant failure: could not convert offset {offset} to usize at position {i}"
+                    ))
+                })?;
+                let s: usize = size.try_into().map_err(|_| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "Size invariant failure: could not convert size {size} to usize at position {i}"
+                    ))
+                })?;
+                match o.checked_add(s) {
+                    Some(end) if end <= values_length => Ok(()),
+                    _ => Err(ArrowError::InvalidArgumentError(format!(
+                        "Offset {o} plus size {s} at position {i} is out of bounds of values length {values_length} for {}",
+                        self.data_type
+                    ))),
+                }
+            })
+    }
+
     /// Validates that each value in self.buffers (typed as T)
     /// is within the range [0, max_value], inclusive
     fn check_bounds<T>(&self, max_value: i64) -> Result<(), ArrowError>
@@ -1536,6 +1720,8 @@ pub fn layout(data_type: &DataType) -> DataTypeLayout {
         DataType::List(_) => DataTypeLayout::new_fixed_width(size_of::<i32>()),
         DataType::FixedSizeList(_, _) => DataTypeLayout::new_empty(), // all in child data
         DataType::LargeList(_) => DataTypeLayout::new_fixed_width(size_of::<i64>()),
+        DataType::ListView(_) => DataTypeLayout::new_list_view(size_of::<i32>()),
+        DataType::LargeListView(_) => DataTypeLayout::new_list_view(size_of::<i64>()),
         DataType::Struct(_) => DataTypeLayout::new_empty(), // all in child data,
         DataType::RunEndEncoded(_, _) => DataTypeLayout::new_empty(), // all in child data,
         DataType::Union(_, mode) => {
@@ -1561,6 +1747,8 @@ pub fn layout(data_type: &DataType) -> DataTypeLayout {
             }
         }
         DataType::Dictionary(key_type, _value_type) => layout(key_type),
+        DataType::Decimal32(_, _) => DataTypeLayout::new_fixed_width(size_of::<i32>()),
+        DataType::Decimal64(_, _) => DataTypeLayout::new_fixed_width(size_of::<i64>()),
         DataType::Decimal128(_, _) => {
             // Decimals are always some fixed width; The rust implementation
             // always uses 16 bytes / size of i128
@@ -1623,6 +1811,20 @@ impl DataTypeLayout {
             can_contain_null_mask: true,
         }
     }
+
+    /// Describes a list view array which has an offset buffer and a size buffer,
+    /// each with elements of `byte_width` bytes
+    pub fn new_list_view(byte_width: usize) -> Self {
+        Self {
+            buffers: vec![
+                // offsets
+                BufferSpec::FixedWidth { byte_width },
+                // sizes
+                BufferSpec::FixedWidth { byte_width },
+            ],
+            can_contain_null_mask: true,
+        }
+    }
 }
 
 /// Layout specification for a single data type buffer
@@ -1768,6 +1970,18 @@ impl ArrayDataBuilder {
     /// Creates an array data, validating all inputs
     #[allow(clippy::let_and_return)]
     pub fn build(self) -> Result<ArrayData, ArrowError> {
+        // check the length of `null_bit_buffer` first, as it is used to
+        // construct the null buffer in `Self::build_unchecked`
+        if let Some(null_bit_buffer) = self.null_bit_buffer.as_ref() {
+            let needed_len = bit_util::ceil(self.len.saturating_add(self.offset), 8);
+            if null_bit_buffer.len() < needed_len {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "null_bit_buffer size too small. got {} needed {}",
+                    null_bit_buffer.len(),
+                    needed_len
+                )));
+            }
+        }
         let data = unsafe { self.build_unchecked() };
         #[cfg(not(feature = "force_validate"))]
         data.validate_data()?;
@@ -2019,6 +2233,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_slice_memory_size_nested() {
+        let values = ArrayData::builder(DataType::Int32)
+            .len(16)
+            .add_buffer(make_i32_buffer(16))
+            .build()
+            .unwrap();
+        let field = Arc::new(Field::new("item", DataType::Int32, false));
+        let list = ArrayData::builder(DataType::List(field.clone()))
+            .len(4)
+            .add_buffer(Buffer::from_slice_ref([0_i32, 2, 10, 12, 16]))
+            .add_child_data(values.clone())
+            .build()
+            .unwrap();
+
+        // Offsets are counted as one per element
+        assert_eq!(list.get_slice_memory_size().unwrap(), 4 * 4 + 16 * 4);
+        let slice = list.slice(1, 1);
+        assert_eq!(slice.get_slice_memory_size().unwrap(), 4 + 8 * 4);
+        assert_eq!(list.slice(4, 0).get_slice_memory_size().unwrap(), 0);
+
+        let fixed = ArrayData::builder(DataType::FixedSizeList(field, 4))
+            .len(4)
+            .add_child_data(values)
+            .build()
+            .unwrap();
+        assert_eq!(fixed.get_slice_memory_size().unwrap(), 16 * 4);
+        assert_eq!(fixed.slice(1, 2).get_slice_memory_size().unwrap(), 8 * 4);
+    }
+
+    #[test]
+    fn test_deep_copy_sliced() {
+        let data_buffer = Buffer::from_slice_ref("abcdef".as_bytes());
+        let offsets_buffer = Buffer::from_slice_ref([0_i32, 2_i32, 2_i32, 5_i32, 6_i32]);
+        let string_data = ArrayData::try_new(
+            DataType::Utf8,
+            4,
+            Some(Buffer::from_iter(vec![true, false, true, true])),
+            0,
+            vec![offsets_buffer, data_buffer],
+            vec![],
+        )
+        .unwrap();
+
+        let slice = string_data.slice(1, 2);
+        let copy = slice.deep_copy_sliced().unwrap();
+        copy.validate_full().unwrap();
+        assert_eq!(copy, slice);
+        assert_eq!(copy.offset(), 0);
+        assert_eq!(copy.buffers()[1].as_slice(), b"cde");
+        assert_eq!(copy.buffers()[0].typed_data::<i32>(), &[0, 0, 3]);
+
+        let run_ends = ArrayData::builder(DataType::Int32)
+            .len(1)
+            .add_buffer(Buffer::from_slice_ref([4_i32]))
+            .build()
+            .unwrap();
+        let run_array = ArrayData::builder(DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", DataType::Int32, false)),
+            Arc::new(Field::new("values", DataType::Utf8, true)),
+        ))
+        .len(4)
+        .add_child_data(run_ends)
+        .add_child_data(string_data.slice(0, 1))
+        .build()
+        .unwrap();
+        let err = run_array.deep_copy_sliced().unwrap_err();
+        assert!(err.to_string().contains("not yet supported"), "{err}");
+    }
+
     #[test]
     fn test_count_nulls() {
         let buffer = Buffer::from(vec![0b00010110, 0b10011111]);
@@ -2057,4 +2341,36 @@ mod tests {
             assert_eq!(buffers.len(), layout.buffers.len());
         }
     }
+
+    #[test]
+    fn test_claim() {
+        let pool = arrow_buffer::pool::TrackingMemoryPool::with_limit(1024);
+        let data = ArrayData::builder(DataType::Int32)
+            .len(16)
+            .add_buffer(Buffer::from_vec(vec![0_i32; 16]))
+            // A validity buffer without nulls is dropped, so include a null
+            .null_bit_buffer(Some(Buffer::from_vec(vec![0b1111_1110_u8, u8::MAX])))
+            .build()
+            .unwrap();
+        data.claim(&pool).unwrap();
+        assert_eq!(pool.used(), 66);
+
+        // Claiming again or claiming a slice is a no-op
+        data.slice(2, 4).claim(&pool).unwrap();
+        assert_eq!(pool.used(), 66);
+
+        let large = ArrayData::builder(DataType::Int32)
+            .len(512)
+            .add_buffer(Buffer::from_vec(vec![0_i32; 512]))
+            .build()
+            .unwrap();
+        let err = large.claim(&pool).unwrap_err().to_string();
+        assert!(
+            err.starts_with("Memory error: Failed to reserve 2048 bytes"),
+            "{err}"
+        );
+
+        drop(data);
+        assert_eq!(pool.used(), 0);
+    }
 }
@@ -20,7 +20,8 @@ use arrow_schema::ArrowError;
 
 pub use arrow_schema::{
     DECIMAL128_MAX_PRECISION, DECIMAL128_MAX_SCALE, DECIMAL256_MAX_PRECISION,
-    DECIMAL256_MAX_SCALE, DECIMAL_DEFAULT_SCALE,
+    DECIMAL256_MAX_SCALE, DECIMAL32_MAX_PRECISION, DECIMAL32_MAX_SCALE,
+    DECIMAL64_MAX_PRECISION, DECIMAL64_MAX_SCALE, DECIMAL_DEFAULT_SCALE,
 };
 
 // MAX decimal256 value of little-endian format for each precision.
@@ -729,6 +730,116 @@ pub const MIN_DECIMAL_FOR_EACH_PRECISION: [i128; 38] = [
     -99999999999999999999999999999999999999,
 ];
 
+/// `MAX_DECIMAL32_FOR_EACH_PRECISION[p-1]` holds the maximum `i32` value that can
+/// be stored in [arrow_schema::DataType::Decimal32] value of precision `p`
+pub const MAX_DECIMAL32_FOR_EACH_PRECISION: [i32; 9] = [
+    9, 99, 999, 9999, 99999, 999999, 9999999, 99999999, 999999999,
+];
+
+/// `MIN_DECIMAL32_FOR_EACH_PRECISION[p-1]` holds the minimum `i32` value that can
+/// be stored in a [arrow_schema::DataType::Decimal32] value of precision `p`
+pub const MIN_DECIMAL32_FOR_EACH_PRECISION: [i32; 9] = [
+    -9, -99, -999, -9999, -99999, -999999, -9999999, -99999999, -999999999,
+];
+
+/// `MAX_DECIMAL64_FOR_EACH_PRECISION[p-1]` holds the maximum `i64` value that can
+/// be stored in [arrow_schema::DataType::Decimal64] value of precision `p`
+pub const MAX_DECIMAL64_FOR_EACH_PRECISION: [i64; 18] = [
+    9,
+    99,
+    999,
+    9999,
+    99999,
+    999999,
+    9999999,
+    99999999,
+    999999999,
+    9999999999,
+    99999999999,
+    999999999999,
+    9999999999999,
+    99999999999999,
+    999999999999999,
+    9999999999999999,
+    99999999999999999,
+    999999999999999999,
+];
+
+/// `MIN_DECIMAL64_FOR_EACH_PRECISION[p-1]` holds the minimum `i64` value that can
+/// be stored in a [arrow_schema::DataType::Decimal64] value of precision `p`
+pub const MIN_DECIMAL64_FOR_EACH_PRECISION: [i64; 18] = [
+    -9,
+    -99,
+    -999,
+    -9999,
+    -99999,
+    -999999,
+    -9999999,
+    -99999999,
+    -999999999,
+    -9999999999,
+    -99999999999,
+    -999999999999,
+    -9999999999999,
+    -99999999999999,
+    -999999999999999,
+    -9999999999999999,
+    -99999999999999999,
+    -999999999999999999,
+];
+
+/// Validates that the specified `i32` value can be properly
+/// interpreted as a Decimal32 number with precision `precision`
+#[inline]
+pub fn validate_decimal32_precision(value: i32, precision: u8) -> Result<(), ArrowError> {
+    if precision > DECIMAL32_MAX_PRECISION {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Max precision of a Decimal32 is {DECIMAL32_MAX_PRECISION}, but got {precision}",
+        )));
+    }
+
+    let max = MAX_DECIMAL32_FOR_EACH_PRECISION[usize::from(precision) - 1];
+    let min = MIN_DECIMAL32_FOR_EACH_PRECISION[usize::from(precision) - 1];
+
+    if value > max {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "{value} is too large to store in a Decimal32 of precision {precision}. Max is {max}"
+        )))
+    } else if value < min {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "{value} is too small to store in a Decimal32 of precision {precision}. Min is {min}"
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Validates that the specified `i64` value can be properly
+/// interpreted as a Decimal64 number with precision `precision`
+#[inline]
+pub fn validate_decimal64_precision(value: i64, precision: u8) -> Result<(), ArrowError> {
+    if precision > DECIMAL64_MAX_PRECISION {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Max precision of a Decimal64 is {DECIMAL64_MAX_PRECISION}, but got {precision}",
+        )));
+    }
+
+    let max = MAX_DECIMAL64_FOR_EACH_PRECISION[usize::from(precision) - 1];
+    let min = MIN_DECIMAL64_FOR_EACH_PRECISION[usize::from(precision) - 1];
+
+    if value > max {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "{value} is too large to store in a Decimal64 of precision {precision}. Max is {max}"
+        )))
+    } else if value < min {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "{value} is too small to store in a Decimal64 of precision {precision}. Min is {min}"
+        )))
+    } else {
+        Ok(())
+    }
+}
+
 /// Validates that the specified `i128` value can be properly
 /// interpreted as a Decimal number with precision `precision`
 #[inline]
@@ -0,0 +1,58 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use super::{_MutableArrayData, Extend};
+use crate::ArrayData;
+use arrow_buffer::ArrowNativeType;
+
+pub(super) fn build_extend<T: ArrowNativeType>(array: &ArrayData) -> Extend<'_> {
+    let offsets = array.buffer::<T>(0);
+    let sizes = array.buffer::<T>(1);
+    Box::new(
+        move |mutable: &mut _MutableArrayData, index: usize, start: usize, len: usize| {
+            // as the offsets of a list view need not be contiguous, the values of
+            // each list are copied individually and re-based onto the end of the child
+            for i in start..start + len {
+                let size = match array.is_null(i) {
+                    true => 0,
+                    false => sizes[i].as_usize(),
+                };
+                let child_len = mutable.child_data[0].len();
+                let new_offset = T::from_usize(child_len).expect("offset overflow");
+
+                mutable.buffer1.push(new_offset);
+                mutable.buffer2.push(T::usize_as(size));
+
+                let offset = offsets[i].as_usize();
+                mutable.child_data[0].extend(index, offset, offset + size);
+            }
+        },
+    )
+}
+
+pub(super) fn extend_nulls<T: ArrowNativeType>(
+    mutable: &mut _MutableArrayData,
+    len: usize,
+) {
+    let child_len = mutable.child_data[0].len();
+    let offset = T::from_usize(child_len).expect("offset overflow");
+
+    (0..len).for_each(|_| {
+        mutable.buffer1.push(offset);
+        mutable.buffer2.push(T::usize_as(0));
+    })
+}
@@ -29,6 +29,7 @@ use tonic::{Request, Response, Status, Streaming};
 
 use arrow_array::builder::StringBuilder;
 use arrow_array::{ArrayRef, RecordBatch};
+use arrow_flight::decode::FlightRecordBatchStream;
 use arrow_flight::encode::FlightDataEncoderBuilder;
 use arrow_flight::sql::metadata::{
     SqlInfoData, SqlInfoDataBuilder, XdbcTypeInfo, XdbcTypeInfoData,
@@ -44,9 +45,10 @@ use arrow_flight::sql::{
     CommandGetCrossReference, CommandGetDbSchemas, CommandGetExportedKeys,
     CommandGetImportedKeys, CommandGetPrimaryKeys, CommandGetSqlInfo,
     CommandGetTableTypes, CommandGetTables, CommandGetXdbcTypeInfo,
-    CommandPreparedStatementQuery, CommandPreparedStatementUpdate, CommandStatementQuery,
-    CommandStatementSubstraitPlan, CommandStatementUpdate, Nullable, ProstMessageExt,
-    Searchable, SqlInfo, TicketStatementQuery, XdbcDataType,
+    CommandPreparedStatementQuery, CommandPreparedStatementUpdate,
+    CommandStatementIngest, CommandStatementQuery, CommandStatementSubstraitPlan,
+    CommandStatementUpdate, Nullable, ProstMessageExt, Searchable, SqlInfo,
+    TicketStatementQuery, XdbcDataType,
 };
 use arrow_flight::utils::batches_to_flight_data;
 use arrow_flight::{
@@ -74,6 +76,7 @@ static INSTANCE_SQL_DATA: Lazy<SqlInfoData> = Lazy::new(|| {
     builder.append(SqlInfo::FlightSqlServerVersion, "1");
     // 1.3 comes from https://github.com/apache/arrow/blob/f9324b79bf4fc1ec7e97b32e3cce16e75ef0f5e3/format/Schema.fbs#L24
     builder.append(SqlInfo::FlightSqlServerArrowVersion, "1.3");
+    builder.append(SqlInfo::FlightSqlServerBulkIngestion, true);
     builder.build().unwrap()
 });
 
@@ -607,6 +610,16 @@ impl FlightSqlService for FlightSqlServiceImpl {
         Ok(FAKE_UPDATE_RESULT)
     }
 
+    async fn do_put_statement_ingest(
+        &self,
+        _ticket: CommandStatementIngest,
+        request: Request<FlightRecordBatchStream>,
+    ) -> Result<i64, Status> {
+        let batches: Vec<RecordBatch> = request.into_inner().try_collect().await?;
+        let num_rows: usize = batches.iter().map(|batch| batch.num_rows()).sum();
+        Ok(num_rows as i64)
+    }
+
     async fn do_put_substrait_plan(
         &self,
         _ticket: CommandStatementSubstraitPlan,
@@ -788,6 +801,9 @@ mod tests {
 
     use arrow_cast::pretty::pretty_format_batches;
     use arrow_flight::sql::client::FlightSqlServiceClient;
+    use arrow_flight::sql::{
+        TableDefinitionOptions, TableExistsOption, TableNotExistOption,
+    };
     use arrow_flight::utils::flight_data_to_batches;
     use tonic::transport::server::TcpIncoming;
     use tonic::transport::{Certificate, Endpoint};
@@ -984,6 +1000,30 @@ mod tests {
         .await
     }
 
+    #[tokio::test]
+    async fn test_execute_ingest() {
+        test_all_clients(|mut client| async move {
+            auth_client(&mut client).await;
+            let batch = RecordBatch::try_from_iter(vec![(
+                "a",
+                Arc::new(arrow_array::Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+            )])
+            .unwrap();
+            let command = CommandStatementIngest {
+                table_definition_options: Some(TableDefinitionOptions {
+                    if_not_exist: TableNotExistOption::Create.into(),
+                    if_exists: TableExistsOption::Append.into(),
+                }),
+                table: "test".to_string(),
+                ..Default::default()
+            };
+            let stream = futures::stream::iter(vec![Ok(batch.clone()), Ok(batch)]);
+            let res = client.execute_ingest(command, stream).await.unwrap();
+            assert_eq!(res, 6);
+        })
+        .await
+    }
+
     #[tokio::test]
     async fn test_auth() {
         test_all_clients(|mut client| async move {
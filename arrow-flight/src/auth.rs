@@ -0,0 +1,495 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Authentication helpers for Arrow Flight clients and servers
+//!
+//! Flight implementations commonly authenticate as follows:
+//!
+//! 1. The client sends a [`Handshake`] request with an `authorization` header
+//!    containing HTTP basic credentials (see [`basic_auth_value`]).
+//! 2. The server validates the credentials (see [`extract_basic_auth`]) and
+//!    responds with an `authorization` header containing a bearer token.
+//! 3. The client attaches the bearer token to all subsequent requests, which
+//!    the server validates (see [`extract_bearer_token`]). The server may send
+//!    a new token in the response to any request, which replaces the old one.
+//!
+//! Servers may additionally use cookies, e.g. to route requests to the same
+//! backend, which the client is expected to send back with subsequent requests.
+//!
+//! On the client, [`ClientAuth`] tracks this state. It can be used as a tonic
+//! [`Interceptor`] to attach credentials to requests, or wrap a [`Channel`]
+//! in an [`AuthService`] to additionally pick up tokens and cookies sent by the
+//! server automatically.
+//!
+//! # Example
+//! ```no_run
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! use arrow_flight::auth::ClientAuth;
+//! use arrow_flight::flight_service_client::FlightServiceClient;
+//! use tonic::transport::Channel;
+//!
+//! let channel = Channel::from_static("http://localhost:1234").connect().await?;
+//!
+//! // Send the `x-tenant` header and any cookies set by the server with each request
+//! let auth = ClientAuth::new()
+//!     .with_header("x-tenant", "my_tenant")?
+//!     .with_cookies();
+//! auth.set_basic_auth("user", "password")?;
+//!
+//! // After the handshake, the bearer token returned by the server is sent instead
+//! let mut client = FlightServiceClient::new(auth.service(channel));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Handshake`]: crate::flight_service_server::FlightService::handshake
+//! [`Interceptor`]: tonic::service::Interceptor
+//! [`Channel`]: tonic::transport::Channel
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use tonic::codegen::http::header::{AUTHORIZATION, COOKIE, SET_COOKIE};
+use tonic::codegen::http::{HeaderMap, HeaderValue, Request, Response};
+use tonic::codegen::{BoxFuture, Service};
+use tonic::metadata::{AsciiMetadataKey, AsciiMetadataValue, MetadataMap};
+use tonic::service::Interceptor;
+use tonic::Status;
+
+use crate::error::{FlightError, Result};
+
+const BASIC_PREFIX: &str = "Basic ";
+const BEARER_PREFIX: &str = "Bearer ";
+
+/// Returns the value of an `authorization` header for HTTP basic authentication
+pub fn basic_auth_value(username: &str, password: &str) -> String {
+    let credentials = BASE64_STANDARD.encode(format!("{username}:{password}"));
+    format!("{BASIC_PREFIX}{credentials}")
+}
+
+/// Returns the value of an `authorization` header for bearer token authentication
+pub fn bearer_auth_value(token: &str) -> String {
+    format!("{BEARER_PREFIX}{token}")
+}
+
+/// Extracts the username and password from an HTTP basic `authorization`
+/// header, returning `None` if no such header is present
+///
+/// Returns an error if the header is present but malformed
+pub fn extract_basic_auth(
+    metadata: &MetadataMap,
+) -> std::result::Result<Option<(String, String)>, Status> {
+    let Some(value) = authorization_value(metadata, BASIC_PREFIX)? else {
+        return Ok(None);
+    };
+
+    let decoded = BASE64_STANDARD
+        .decode(value)
+        .map_err(|e| Status::unauthenticated(format!("Invalid basic auth: {e}")))?;
+    let decoded = String::from_utf8(decoded)
+        .map_err(|e| Status::unauthenticated(format!("Invalid basic auth: {e}")))?;
+
+    match decoded.split_once(':') {
+        Some((username, password)) => {
+            Ok(Some((username.to_string(), password.to_string())))
+        }
+        None => Err(Status::unauthenticated(
+            "Invalid basic auth: expected username:password",
+        )),
+    }
+}
+
+/// Extracts the token from a bearer token `authorization` header, returning
+/// `None` if no such header is present
+///
+/// Returns an error if the header is present but malformed
+pub fn extract_bearer_token(
+    metadata: &MetadataMap,
+) -> std::result::Result<Option<String>, Status> {
+    Ok(authorization_value(metadata, BEARER_PREFIX)?.map(|token| token.to_string()))
+}
+
+/// Returns a `set-cookie` header value setting the cookie `name` to `value`,
+/// for servers to send with a response
+pub fn set_cookie_value(name: &str, value: &str) -> Result<HeaderValue> {
+    HeaderValue::from_str(&format!("{name}={value}"))
+        .map_err(|e| FlightError::ExternalError(Box::new(e)))
+}
+
+/// Returns the value of the `authorization` header after `prefix`, if the
+/// header is present and uses the scheme identified by `prefix`
+fn authorization_value<'a>(
+    metadata: &'a MetadataMap,
+    prefix: &str,
+) -> std::result::Result<Option<&'a str>, Status> {
+    let Some(value) = metadata.get(AUTHORIZATION.as_str()) else {
+        return Ok(None);
+    };
+    let value = value
+        .to_str()
+        .map_err(|e| Status::unauthenticated(format!("Invalid authorization: {e}")))?;
+
+    // The authentication scheme is case insensitive
+    match value.get(..prefix.len()) {
+        Some(scheme) if scheme.eq_ignore_ascii_case(prefix) => {
+            Ok(Some(&value[prefix.len()..]))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Cookies received from a server, to send with subsequent requests
+///
+/// Only the cookie names and values are tracked, attributes such as
+/// `Path` or `Domain` are ignored as a gRPC channel connects to a single
+/// server. Cookies set with `Max-Age=0` are removed.
+///
+/// Clones share the same underlying cookies.
+#[derive(Debug, Clone, Default)]
+pub struct CookieJar {
+    cookies: Arc<Mutex<BTreeMap<String, String>>>,
+}
+
+impl CookieJar {
+    /// Create a new, empty, [`CookieJar`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the value of the cookie named `name`, if any
+    pub fn get(&self, name: &str) -> Option<String> {
+        self.cookies.lock().unwrap().get(name).cloned()
+    }
+
+    /// Update the cookies based on the `set-cookie` headers in `headers`
+    pub fn update(&self, headers: &HeaderMap) {
+        let mut cookies = self.cookies.lock().unwrap();
+        for value in headers.get_all(SET_COOKIE) {
+            let Ok(value) = value.to_str() else {
+                continue;
+            };
+            let mut parts = value.split(';');
+            let Some((name, value)) = parts.next().and_then(|c| c.split_once('=')) else {
+                continue;
+            };
+            let name = name.trim();
+            if name.is_empty() {
+                continue;
+            }
+
+            let expired = parts.any(|attribute| {
+                attribute
+                    .split_once('=')
+                    .map(|(k, v)| {
+                        k.trim().eq_ignore_ascii_case("max-age")
+                            && v.trim().parse::<i64>().map(|v| v <= 0).unwrap_or(false)
+                    })
+                    .unwrap_or(false)
+            });
+
+            if expired {
+                cookies.remove(name);
+            } else {
+                cookies.insert(name.to_string(), value.trim().to_string());
+            }
+        }
+    }
+
+    /// Returns the value of the `cookie` header to send, if there are any cookies
+    pub fn header_value(&self) -> Option<String> {
+        let cookies = self.cookies.lock().unwrap();
+        if cookies.is_empty() {
+            return None;
+        }
+        let pairs: Vec<_> = cookies.iter().map(|(k, v)| format!("{k}={v}")).collect();
+        Some(pairs.join("; "))
+    }
+}
+
+/// Client side authentication state: credentials, additional headers and
+/// cookies to send with each request
+///
+/// See the [module level documentation](self) for details and an example.
+///
+/// Clones share the same credentials and cookies, so that a token received
+/// by one is used by all.
+#[derive(Debug, Clone, Default)]
+pub struct ClientAuth {
+    /// Value of the `authorization` header to send, if any
+    authorization: Arc<Mutex<Option<AsciiMetadataValue>>>,
+    /// Additional headers to send with each request
+    headers: Vec<(AsciiMetadataKey, AsciiMetadataValue)>,
+    /// Cookies to send with each request, if enabled
+    cookies: Option<CookieJar>,
+}
+
+impl ClientAuth {
+    /// Create a new [`ClientAuth`] with no credentials
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send the header `key` with `value` with each request
+    pub fn with_header(mut self, key: &str, value: &str) -> Result<Self> {
+        let key = AsciiMetadataKey::from_bytes(key.as_bytes())
+            .map_err(|e| FlightError::ExternalError(Box::new(e)))?;
+        let value = value
+            .parse()
+            .map_err(|e| FlightError::ExternalError(Box::new(e)))?;
+        self.headers.push((key, value));
+        Ok(self)
+    }
+
+    /// Track cookies set by the server and send them with each request
+    ///
+    /// Note cookies are only received when used as an [`AuthService`]
+    pub fn with_cookies(self) -> Self {
+        self.with_cookie_jar(CookieJar::new())
+    }
+
+    /// Track cookies set by the server in `cookie_jar`, and send them with
+    /// each request
+    pub fn with_cookie_jar(mut self, cookie_jar: CookieJar) -> Self {
+        self.cookies = Some(cookie_jar);
+        self
+    }
+
+    /// Returns the [`CookieJar`], if enabled
+    pub fn cookie_jar(&self) -> Option<&CookieJar> {
+        self.cookies.as_ref()
+    }
+
+    /// Authenticate subsequent requests with HTTP basic authentication,
+    /// typically used for the initial handshake
+    pub fn set_basic_auth(&self, username: &str, password: &str) -> Result<()> {
+        self.set_authorization(&basic_auth_value(username, password))
+    }
+
+    /// Authenticate subsequent requests with the bearer `token`
+    pub fn set_bearer_token(&self, token: &str) -> Result<()> {
+        self.set_authorization(&bearer_auth_value(token))
+    }
+
+    /// Returns the current bearer token, if any
+    pub fn bearer_token(&self) -> Option<String> {
+        let authorization = self.authorization.lock().unwrap();
+        let value = authorization.as_ref()?.to_str().ok()?;
+        value
+            .strip_prefix(BEARER_PREFIX)
+            .map(|token| token.to_string())
+    }
+
+    /// Stop sending credentials with subsequent requests
+    pub fn clear_credentials(&self) {
+        *self.authorization.lock().unwrap() = None;
+    }
+
+    fn set_authorization(&self, value: &str) -> Result<()> {
+        let value = value
+            .parse()
+            .map_err(|e| FlightError::ExternalError(Box::new(e)))?;
+        *self.authorization.lock().unwrap() = Some(value);
+        Ok(())
+    }
+
+    /// Add the credentials, headers and cookies to `metadata`
+    pub fn apply(&self, metadata: &mut MetadataMap) {
+        for (key, value) in &self.headers {
+            metadata.insert(key.clone(), value.clone());
+        }
+        if let Some(value) = self.authorization.lock().unwrap().as_ref() {
+            metadata.insert(AUTHORIZATION.as_str(), value.clone());
+        }
+        let cookie = self.cookies.as_ref().and_then(|c| c.header_value());
+        if let Some(value) = cookie.and_then(|c| c.parse().ok()) {
+            metadata.insert(COOKIE.as_str(), value);
+        }
+    }
+
+    /// Update the state from the headers of a response received from the server
+    ///
+    /// If the server sent a bearer token, it is used for subsequent requests,
+    /// and if cookies are enabled, any cookies set are recorded.
+    pub fn update(&self, headers: &HeaderMap) {
+        if let Some(cookies) = &self.cookies {
+            cookies.update(headers);
+        }
+
+        let token = headers
+            .get(AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix(BEARER_PREFIX));
+        if let Some(token) = token {
+            // ignore invalid tokens, which cannot be sent in later requests
+            let _ = self.set_bearer_token(token);
+        }
+    }
+
+    /// Wrap `inner` in an [`AuthService`] using this [`ClientAuth`]
+    pub fn service<S>(&self, inner: S) -> AuthService<S> {
+        AuthService {
+            inner,
+            auth: self.clone(),
+        }
+    }
+}
+
+impl Interceptor for ClientAuth {
+    fn call(
+        &mut self,
+        mut request: tonic::Request<()>,
+    ) -> std::result::Result<tonic::Request<()>, Status> {
+        self.apply(request.metadata_mut());
+        Ok(request)
+    }
+}
+
+/// A [`Service`] that applies a [`ClientAuth`] to each request, and
+/// updates it from each response
+///
+/// Created with [`ClientAuth::service`]
+#[derive(Debug, Clone)]
+pub struct AuthService<S> {
+    inner: S,
+    auth: ClientAuth,
+}
+
+impl<S> AuthService<S> {
+    /// Returns the [`ClientAuth`] of this service
+    pub fn auth(&self) -> &ClientAuth {
+        &self.auth
+    }
+
+    /// Consume self and return the wrapped service
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for AuthService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<std::result::Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        let (mut parts, body) = request.into_parts();
+        let mut metadata = MetadataMap::from_headers(std::mem::take(&mut parts.headers));
+        self.auth.apply(&mut metadata);
+        parts.headers = metadata.into_headers();
+
+        let auth = self.auth.clone();
+        let response = self.inner.call(Request::from_parts(parts, body));
+        Box::pin(async move {
+            let response = response.await?;
+            auth.update(response.headers());
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basic_auth_roundtrip() {
+        let mut metadata = MetadataMap::new();
+        assert_eq!(extract_basic_auth(&metadata).unwrap(), None);
+
+        metadata.insert(
+            "authorization",
+            basic_auth_value("user", "pass:word").parse().unwrap(),
+        );
+        let (username, password) = extract_basic_auth(&metadata).unwrap().unwrap();
+        assert_eq!(username, "user");
+        assert_eq!(password, "pass:word");
+        assert_eq!(extract_bearer_token(&metadata).unwrap(), None);
+
+        metadata.insert("authorization", "basic !!!".parse().unwrap());
+        extract_basic_auth(&metadata).unwrap_err();
+    }
+
+    #[test]
+    fn test_bearer_token() {
+        let mut metadata = MetadataMap::new();
+        metadata.insert("authorization", bearer_auth_value("abc").parse().unwrap());
+        assert_eq!(extract_bearer_token(&metadata).unwrap().unwrap(), "abc");
+        assert_eq!(extract_basic_auth(&metadata).unwrap(), None);
+    }
+
+    #[test]
+    fn test_cookie_jar() {
+        let jar = CookieJar::new();
+        assert_eq!(jar.header_value(), None);
+
+        let mut headers = HeaderMap::new();
+        headers.append(SET_COOKIE, "a=1; Path=/; HttpOnly".parse().unwrap());
+        headers.append(SET_COOKIE, "b=2".parse().unwrap());
+        headers.append(SET_COOKIE, "invalid".parse().unwrap());
+        jar.update(&headers);
+        assert_eq!(jar.header_value().unwrap(), "a=1; b=2");
+
+        let mut headers = HeaderMap::new();
+        headers.append(SET_COOKIE, "a=3".parse().unwrap());
+        headers.append(SET_COOKIE, "b=; Max-Age=0".parse().unwrap());
+        jar.update(&headers);
+        assert_eq!(jar.header_value().unwrap(), "a=3");
+        assert_eq!(jar.get("b"), None);
+    }
+
+    #[test]
+    fn test_client_auth() {
+        let mut auth = ClientAuth::new()
+            .with_header("x-foo", "bar")
+            .unwrap()
+            .with_cookies();
+        auth.set_basic_auth("user", "password").unwrap();
+
+        let request = auth.call(tonic::Request::new(())).unwrap();
+        let metadata = request.metadata();
+        assert_eq!(metadata.get("x-foo").unwrap(), "bar");
+        let (username, _) = extract_basic_auth(metadata).unwrap().unwrap();
+        assert_eq!(username, "user");
+        assert!(metadata.get("cookie").is_none());
+
+        // server responds with a token and a cookie
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, "Bearer token".parse().unwrap());
+        headers.insert(SET_COOKIE, "session=1".parse().unwrap());
+        auth.clone().update(&headers);
+        assert_eq!(auth.bearer_token().unwrap(), "token");
+
+        let request = auth.call(tonic::Request::new(())).unwrap();
+        let metadata = request.metadata();
+        assert_eq!(extract_bearer_token(metadata).unwrap().unwrap(), "token");
+        assert_eq!(metadata.get("cookie").unwrap(), "session=1");
+    }
+}
@@ -18,8 +18,10 @@
 use std::task::Poll;
 
 use crate::{
-    decode::FlightRecordBatchStream, flight_service_client::FlightServiceClient, Action,
-    ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
+    auth::{basic_auth_value, bearer_auth_value, extract_bearer_token},
+    decode::FlightRecordBatchStream,
+    flight_service_client::FlightServiceClient,
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
     HandshakeRequest, PutResult, Ticket,
 };
 use arrow_schema::Schema;
@@ -30,7 +32,13 @@ use futures::{
     stream::{self, BoxStream},
     FutureExt, Stream, StreamExt, TryStreamExt,
 };
-use tonic::{metadata::MetadataMap, transport::Channel};
+use tonic::{
+    body::BoxBody,
+    client::GrpcService,
+    codegen::{Body, StdError},
+    metadata::MetadataMap,
+    transport::Channel,
+};
 
 use crate::error::{FlightError, Result};
 
@@ -64,29 +72,66 @@ use crate::error::{FlightError, Result};
 /// assert_eq!(response, Bytes::from("Ho"));
 /// # }
 /// ```
+///
+/// # Transports
+///
+/// By default the client uses a tonic [`Channel`], which can be
+/// configured with TLS and HTTP proxy settings using
+/// [`FlightChannelBuilder`](crate::transport::FlightChannelBuilder).
+/// Any other [`GrpcService`], such as a gRPC-web client service or a
+/// channel wrapped with an interceptor, can be used via
+/// [`FlightClient::new`] or [`FlightClient::new_from_inner`].
 #[derive(Debug)]
-pub struct FlightClient {
+pub struct FlightClient<T = Channel> {
     /// Optional grpc header metadata to include with each request
     metadata: MetadataMap,
 
     /// The inner client
-    inner: FlightServiceClient<Channel>,
+    inner: FlightServiceClient<T>,
 }
 
-impl FlightClient {
-    /// Creates a client client with the provided [`Channel`](tonic::transport::Channel)
-    pub fn new(channel: Channel) -> Self {
+impl<T> FlightClient<T>
+where
+    T: GrpcService<BoxBody>,
+    T::Error: Into<StdError>,
+    T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+{
+    /// Creates a client client with the provided transport, such as a
+    /// [`Channel`](tonic::transport::Channel)
+    pub fn new(channel: T) -> Self {
         Self::new_from_inner(FlightServiceClient::new(channel))
     }
 
     /// Creates a new higher level client with the provided lower level client
-    pub fn new_from_inner(inner: FlightServiceClient<Channel>) -> Self {
+    pub fn new_from_inner(inner: FlightServiceClient<T>) -> Self {
         Self {
             metadata: MetadataMap::new(),
             inner,
         }
     }
 
+    /// Set the maximum size, in bytes, of a message received from the
+    /// server. Defaults to 4MB.
+    ///
+    /// Servers that do not split large [`RecordBatch`]es (see
+    /// [`FlightDataEncoderBuilder::with_max_flight_data_size`]) may send
+    /// messages exceeding the default, resulting in `RESOURCE_EXHAUSTED` errors.
+    ///
+    /// [`RecordBatch`]: arrow_array::RecordBatch
+    /// [`FlightDataEncoderBuilder::with_max_flight_data_size`]: crate::encode::FlightDataEncoderBuilder::with_max_flight_data_size
+    pub fn with_max_decoding_message_size(mut self, limit: usize) -> Self {
+        self.inner = self.inner.max_decoding_message_size(limit);
+        self
+    }
+
+    /// Set the maximum size, in bytes, of a message sent to the server.
+    /// Defaults to `usize::MAX`.
+    pub fn with_max_encoding_message_size(mut self, limit: usize) -> Self {
+        self.inner = self.inner.max_encoding_message_size(limit);
+        self
+    }
+
     /// Return a reference to gRPC metadata included with each request
     pub fn metadata(&self) -> &MetadataMap {
         &self.metadata
@@ -118,19 +163,19 @@ impl FlightClient {
 
     /// Return a reference to the underlying tonic
     /// [`FlightServiceClient`]
-    pub fn inner(&self) -> &FlightServiceClient<Channel> {
+    pub fn inner(&self) -> &FlightServiceClient<T> {
         &self.inner
     }
 
     /// Return a mutable reference to the underlying tonic
     /// [`FlightServiceClient`]
-    pub fn inner_mut(&mut self) -> &mut FlightServiceClient<Channel> {
+    pub fn inner_mut(&mut self) -> &mut FlightServiceClient<T> {
         &mut self.inner
     }
 
     /// Consume this client and return the underlying tonic
     /// [`FlightServiceClient`]
-    pub fn into_inner(self) -> FlightServiceClient<Channel> {
+    pub fn into_inner(self) -> FlightServiceClient<T> {
         self.inner
     }
 
@@ -165,6 +210,43 @@ impl FlightClient {
         }
     }
 
+    /// Perform an Arrow Flight handshake with the server using HTTP
+    /// basic authentication, returning the [`HandshakeResponse`](crate::HandshakeResponse)
+    /// bytes returned from the server.
+    ///
+    /// If the server responds with a bearer token, it is included
+    /// with all subsequent requests, see [`crate::auth`] for details.
+    pub async fn handshake_basic_auth(
+        &mut self,
+        username: &str,
+        password: &str,
+    ) -> Result<Bytes> {
+        let request = HandshakeRequest {
+            protocol_version: 0,
+            payload: Bytes::new(),
+        };
+
+        let mut request = self.make_request(stream::once(ready(request)));
+        let value = basic_auth_value(username, password)
+            .parse()
+            .map_err(|e| FlightError::ExternalError(Box::new(e)))?;
+        request.metadata_mut().insert("authorization", value);
+
+        let response = self.inner.handshake(request).await?;
+        if let Some(token) = extract_bearer_token(response.metadata())? {
+            self.add_header("authorization", &bearer_auth_value(&token))?;
+        }
+
+        let responses: Vec<_> = response.into_inner().try_collect().await?;
+        match responses.as_slice() {
+            [] => Ok(Bytes::new()),
+            [response] => Ok(response.payload.clone()),
+            _ => Err(FlightError::protocol(
+                "Got unexpected second response from handshake",
+            )),
+        }
+    }
+
     /// Make a `DoGet` call to the server with the provided ticket,
     /// returning a [`FlightRecordBatchStream`] for reading
     /// [`RecordBatch`](arrow_array::RecordBatch)es.
@@ -554,7 +636,7 @@ impl FlightClient {
     }
 
     /// return a Request, adding any configured metadata
-    fn make_request<T>(&self, t: T) -> tonic::Request<T> {
+    fn make_request<R>(&self, t: R) -> tonic::Request<R> {
         // Pass along metadata
         let mut request = tonic::Request::new(t);
         *request.metadata_mut() = self.metadata.clone();
@@ -18,6 +18,7 @@
 use crate::{utils::flight_data_to_arrow_batch, FlightData};
 use arrow_array::{ArrayRef, RecordBatch};
 use arrow_schema::{Schema, SchemaRef};
+use arrow_select::concat::concat_batches;
 use bytes::Bytes;
 use futures::{ready, stream::BoxStream, Stream, StreamExt};
 use std::{
@@ -82,12 +83,24 @@ use crate::error::{FlightError, Result};
 #[derive(Debug)]
 pub struct FlightRecordBatchStream {
     inner: FlightDataDecoder,
+    /// If set, consecutive batches are concatenated until they have at
+    /// least this many rows
+    target_batch_rows: Option<usize>,
+    /// Batches received but not yet returned
+    buffered: Vec<RecordBatch>,
+    /// Total number of rows in `buffered`
+    buffered_rows: usize,
 }
 
 impl FlightRecordBatchStream {
     /// Create a new [`FlightRecordBatchStream`] from a decoded stream
     pub fn new(inner: FlightDataDecoder) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            target_batch_rows: None,
+            buffered: vec![],
+            buffered_rows: 0,
+        }
     }
 
     /// Create a new [`FlightRecordBatchStream`] from a stream of [`FlightData`]
@@ -95,9 +108,22 @@ impl FlightRecordBatchStream {
     where
         S: Stream<Item = Result<FlightData>> + Send + 'static,
     {
-        Self {
-            inner: FlightDataDecoder::new(inner),
-        }
+        Self::new(FlightDataDecoder::new(inner))
+    }
+
+    /// Concatenate consecutive [`RecordBatch`]es received until they
+    /// contain at least `target_batch_rows` rows, with any remaining rows
+    /// returned at the end of the stream.
+    ///
+    /// Senders split [`RecordBatch`]es into multiple messages to stay
+    /// within the gRPC maximum message size (see
+    /// [`FlightDataEncoderBuilder::with_max_flight_data_size`]), and this
+    /// can be used to reassemble the pieces into larger batches.
+    ///
+    /// [`FlightDataEncoderBuilder::with_max_flight_data_size`]: crate::encode::FlightDataEncoderBuilder::with_max_flight_data_size
+    pub fn with_target_batch_rows(mut self, target_batch_rows: usize) -> Self {
+        self.target_batch_rows = Some(target_batch_rows);
+        self
     }
 
     /// Has a message defining the schema been received yet?
@@ -112,9 +138,22 @@ impl FlightRecordBatchStream {
     }
 
     /// Consume self and return the wrapped [`FlightDataDecoder`]
+    ///
+    /// Note any [`RecordBatch`]es buffered by
+    /// [`Self::with_target_batch_rows`] are discarded
     pub fn into_inner(self) -> FlightDataDecoder {
         self.inner
     }
+
+    /// Concatenate and return the buffered batches, if any
+    fn take_buffered(&mut self) -> Option<Result<RecordBatch>> {
+        let first = self.buffered.first()?;
+        let schema = first.schema();
+        let batch = concat_batches(&schema, &self.buffered).map_err(Into::into);
+        self.buffered.clear();
+        self.buffered_rows = 0;
+        Some(batch)
+    }
 }
 impl futures::Stream for FlightRecordBatchStream {
     type Item = Result<RecordBatch>;
@@ -131,7 +170,7 @@ impl futures::Stream for FlightRecordBatchStream {
             match res {
                 // Inner exhausted
                 None => {
-                    return Poll::Ready(None);
+                    return Poll::Ready(self.take_buffered());
                 }
                 Some(Err(e)) => {
                     return Poll::Ready(Some(Err(e)));
@@ -147,7 +186,14 @@ impl futures::Stream for FlightRecordBatchStream {
                         // Need next message, poll inner again
                     }
                     DecodedPayload::RecordBatch(batch) => {
-                        return Poll::Ready(Some(Ok(batch)));
+                        let Some(target_batch_rows) = self.target_batch_rows else {
+                            return Poll::Ready(Some(Ok(batch)));
+                        };
+                        self.buffered_rows += batch.num_rows();
+                        self.buffered.push(batch);
+                        if self.buffered_rows >= target_batch_rows {
+                            return Poll::Ready(self.take_buffered());
+                        }
                     }
                     DecodedPayload::None => {
                         // Need next message
@@ -229,6 +275,12 @@ impl FlightDataDecoder {
     /// state as necessary.
     fn extract_message(&mut self, data: FlightData) -> Result<Option<DecodedFlightData>> {
         use arrow_ipc::MessageHeader;
+
+        // Messages carrying only application metadata have no IPC header
+        if data.data_header.is_empty() {
+            return Ok(Some(DecodedFlightData::new_none(data)));
+        }
+
         let message = arrow_ipc::root_as_message(&data.data_header[..]).map_err(|e| {
             FlightError::DecodeError(format!("Error decoding root message: {e}"))
         })?;
@@ -20,6 +20,7 @@ use std::{collections::VecDeque, fmt::Debug, pin::Pin, sync::Arc, task::Poll};
 use crate::{error::Result, FlightData, FlightDescriptor, SchemaAsIpc};
 use arrow_array::{ArrayRef, RecordBatch, RecordBatchOptions};
 use arrow_ipc::writer::{DictionaryTracker, IpcDataGenerator, IpcWriteOptions};
+use arrow_ipc::CompressionType;
 use arrow_schema::{DataType, Field, Fields, Schema, SchemaRef};
 use bytes::Bytes;
 use futures::{ready, stream::BoxStream, Stream, StreamExt};
@@ -128,6 +129,20 @@ impl FlightDataEncoderBuilder {
         self
     }
 
+    /// Compress the bodies of the encoded [`RecordBatch`]es using `compression`,
+    /// see [`IpcWriteOptions::try_with_compression`].
+    ///
+    /// Encoding will fail at runtime unless the `ipc_compression` feature of
+    /// this crate is enabled. The maximum message size set with
+    /// [`Self::with_max_flight_data_size`] applies to the compressed data.
+    pub fn try_with_compression(
+        mut self,
+        compression: Option<CompressionType>,
+    ) -> Result<Self> {
+        self.options = self.options.try_with_compression(compression)?;
+        Ok(self)
+    }
+
     /// Specify a schema for the RecordBatches being sent. If a schema
     /// is not specified, an encoded Schema message will be sent when
     /// the first [`RecordBatch`], if any, is encoded. Some clients
@@ -207,7 +222,7 @@ impl FlightDataEncoder {
             inner,
             schema: None,
             max_flight_data_size,
-            encoder: FlightIpcEncoder::new(options),
+            encoder: FlightIpcEncoder::new(options, true),
             app_metadata: Some(app_metadata),
             queue: VecDeque::new(),
             done: false,
@@ -266,13 +281,10 @@ impl FlightDataEncoder {
         // encode the batch
         let batch = prepare_batch_for_flight(&batch, schema)?;
 
-        for batch in split_batch_for_grpc_response(batch, self.max_flight_data_size) {
-            let (flight_dictionaries, flight_batch) =
-                self.encoder.encode_batch(&batch)?;
-
-            self.queue_messages(flight_dictionaries);
-            self.queue_message(flight_batch);
-        }
+        let flight_data = self
+            .encoder
+            .encode_batch_split(batch, self.max_flight_data_size)?;
+        self.queue_messages(flight_data);
 
         Ok(())
     }
@@ -354,7 +366,7 @@ fn prepare_schema_for_flight(schema: &Schema) -> Schema {
 ///
 /// Note: this method does not take into account already sliced
 /// arrays: <https://github.com/apache/arrow-rs/issues/3407>
-fn split_batch_for_grpc_response(
+pub(crate) fn split_batch_for_grpc_response(
     batch: RecordBatch,
     max_flight_data_size: usize,
 ) -> Vec<RecordBatch> {
@@ -387,15 +399,14 @@ fn split_batch_for_grpc_response(
 /// TODO: at allow dictionaries to be flushed / avoid building them
 ///
 /// TODO limit on the number of dictionaries???
-struct FlightIpcEncoder {
+pub(crate) struct FlightIpcEncoder {
     options: IpcWriteOptions,
     data_gen: IpcDataGenerator,
     dictionary_tracker: DictionaryTracker,
 }
 
 impl FlightIpcEncoder {
-    fn new(options: IpcWriteOptions) -> Self {
-        let error_on_replacement = true;
+    pub(crate) fn new(options: IpcWriteOptions, error_on_replacement: bool) -> Self {
         Self {
             options,
             data_gen: IpcDataGenerator::default(),
@@ -404,13 +415,13 @@ impl FlightIpcEncoder {
     }
 
     /// Encode a schema as a FlightData
-    fn encode_schema(&self, schema: &Schema) -> FlightData {
+    pub(crate) fn encode_schema(&self, schema: &Schema) -> FlightData {
         SchemaAsIpc::new(schema, &self.options).into()
     }
 
     /// Convert a `RecordBatch` to a Vec of `FlightData` representing
     /// dictionaries and a `FlightData` representing the batch
-    fn encode_batch(
+    pub(crate) fn encode_batch(
         &mut self,
         batch: &RecordBatch,
     ) -> Result<(Vec<FlightData>, FlightData)> {
@@ -426,6 +437,44 @@ impl FlightIpcEncoder {
 
         Ok((flight_dictionaries, flight_batch))
     }
+
+    /// Convert a `RecordBatch` to one or more `FlightData`, splitting it so
+    /// that each encoded record batch message is at most (approximately)
+    /// `max_flight_data_size` bytes.
+    ///
+    /// The batch is first split based on its in-memory size, and any piece
+    /// whose encoded size (which accounts for compression and slicing) still
+    /// exceeds the limit is split further. A single row larger than the limit
+    /// is sent as its own message.
+    pub(crate) fn encode_batch_split(
+        &mut self,
+        batch: RecordBatch,
+        max_flight_data_size: usize,
+    ) -> Result<Vec<FlightData>> {
+        let mut pending: VecDeque<_> =
+            split_batch_for_grpc_response(batch, max_flight_data_size).into();
+        let mut out = Vec::with_capacity(pending.len());
+
+        while let Some(batch) = pending.pop_front() {
+            let (flight_dictionaries, flight_batch) = self.encode_batch(&batch)?;
+            // Dictionaries are now tracked as sent, so must be sent even if
+            // the batch itself is re-encoded
+            out.extend(flight_dictionaries);
+
+            let num_rows = batch.num_rows();
+            let encoded_size =
+                flight_batch.data_header.len() + flight_batch.data_body.len();
+            if encoded_size > max_flight_data_size && num_rows > 1 {
+                let mid = num_rows / 2;
+                pending.push_front(batch.slice(mid, num_rows - mid));
+                pending.push_front(batch.slice(0, mid));
+            } else {
+                out.push(flight_batch);
+            }
+        }
+
+        Ok(out)
+    }
 }
 
 /// Prepares a RecordBatch for transport over the Arrow Flight protocol
@@ -652,7 +701,7 @@ mod tests {
         ])
         .unwrap();
 
-        verify_encoded_split(batch, 112).await;
+        verify_encoded_split(batch, 0).await;
     }
 
     #[tokio::test]
@@ -662,9 +711,8 @@ mod tests {
         let batch =
             RecordBatch::try_from_iter(vec![("data", Arc::new(array) as _)]).unwrap();
 
-        // overage is much higher than ideal
-        // https://github.com/apache/arrow-rs/issues/3478
-        verify_encoded_split(batch, 4304).await;
+        // overage is due to individual rows larger than the limit
+        verify_encoded_split(batch, 160).await;
     }
 
     #[tokio::test]
@@ -698,8 +746,7 @@ mod tests {
         .unwrap();
 
         // 5k over limit (which is 2x larger than limit of 5k)
-        // overage is much higher than ideal
-        // https://github.com/apache/arrow-rs/issues/3478
+        // overage is due to individual rows larger than the limit
         verify_encoded_split(batch, 5800).await;
     }
 
@@ -717,7 +764,7 @@ mod tests {
         let batch =
             RecordBatch::try_from_iter(vec![("a1", Arc::new(array) as _)]).unwrap();
 
-        verify_encoded_split(batch, 160).await;
+        verify_encoded_split(batch, 0).await;
     }
 
     #[tokio::test]
@@ -731,9 +778,8 @@ mod tests {
         let batch =
             RecordBatch::try_from_iter(vec![("a1", Arc::new(array) as _)]).unwrap();
 
-        // overage is much higher than ideal
-        // https://github.com/apache/arrow-rs/issues/3478
-        verify_encoded_split(batch, 3328).await;
+        // overage is due to individual rows larger than the limit
+        verify_encoded_split(batch, 1184).await;
     }
 
     #[tokio::test]
@@ -746,8 +792,7 @@ mod tests {
         let batch =
             RecordBatch::try_from_iter(vec![("a1", Arc::new(array) as _)]).unwrap();
 
-        // overage is much higher than ideal
-        // https://github.com/apache/arrow-rs/issues/3478
+        // overage is due to individual rows larger than the limit
         verify_encoded_split(batch, 5280).await;
     }
 
@@ -774,9 +819,8 @@ mod tests {
         ])
         .unwrap();
 
-        // overage is much higher than ideal
-        // https://github.com/apache/arrow-rs/issues/3478
-        verify_encoded_split(batch, 4128).await;
+        // overage is due to individual rows larger than the limit
+        verify_encoded_split(batch, 1520).await;
     }
 
     /// Return size, in memory of flight data
@@ -0,0 +1,349 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Encoding and decoding of bidirectional [`DoExchange`] streams
+//!
+//! [`FlightDataEncoder`] and [`FlightRecordBatchStream`] transfer a single stream of
+//! [`RecordBatch`]es with one schema. The [`ExchangeEncoder`] and [`ExchangeDecoder`]
+//! instead transfer a stream of [`ExchangeMessage`]s, allowing application metadata
+//! to be interleaved with the data, and the schema to change mid-stream, as commonly
+//! required by interactive protocols built on [`DoExchange`].
+//!
+//! # Example
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+//! # use arrow_flight::exchange::{ExchangeDecoder, ExchangeEncoderBuilder, ExchangeMessage};
+//! # use bytes::Bytes;
+//! # use futures::TryStreamExt;
+//! # async fn f() -> Result<(), arrow_flight::error::FlightError> {
+//! let batch = RecordBatch::try_from_iter([
+//!     ("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+//! ])?;
+//! let messages = vec![
+//!     Ok(ExchangeMessage::Metadata(Bytes::from("begin"))),
+//!     Ok(ExchangeMessage::RecordBatch(batch)),
+//!     Ok(ExchangeMessage::Metadata(Bytes::from("end"))),
+//! ];
+//!
+//! // Encode the messages as `FlightData`, e.g. to send with `do_exchange`
+//! let flight_data = ExchangeEncoderBuilder::new().build(futures::stream::iter(messages));
+//!
+//! // Decode the `FlightData` received back into messages
+//! let decoded: Vec<_> = ExchangeDecoder::new_from_flight_data(flight_data)
+//!     .try_collect()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`DoExchange`]: crate::flight_service_server::FlightService::do_exchange
+//! [`FlightDataEncoder`]: crate::encode::FlightDataEncoder
+//! [`FlightRecordBatchStream`]: crate::decode::FlightRecordBatchStream
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use arrow_array::RecordBatch;
+use arrow_ipc::writer::IpcWriteOptions;
+use arrow_schema::SchemaRef;
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::{ready, Stream, StreamExt};
+
+use crate::decode::{DecodedPayload, FlightDataDecoder};
+use crate::encode::{FlightIpcEncoder, GRPC_TARGET_MAX_FLIGHT_SIZE_BYTES};
+use crate::error::Result;
+use crate::{FlightData, FlightDescriptor};
+
+/// A message of a [`DoExchange`] stream
+///
+/// [`DoExchange`]: crate::flight_service_server::FlightService::do_exchange
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExchangeMessage {
+    /// The schema of subsequent [`ExchangeMessage::RecordBatch`]es
+    ///
+    /// This is sent implicitly before a record batch with a different schema to
+    /// the previous one, but may also be sent explicitly, e.g. to describe the
+    /// results of a request before any data is available
+    Schema(SchemaRef),
+    /// A batch of data
+    RecordBatch(RecordBatch),
+    /// Application specific metadata, sent without any data
+    Metadata(Bytes),
+}
+
+/// Builder for an [`ExchangeEncoder`]
+#[derive(Debug)]
+pub struct ExchangeEncoderBuilder {
+    max_flight_data_size: usize,
+    options: IpcWriteOptions,
+    descriptor: Option<FlightDescriptor>,
+}
+
+impl Default for ExchangeEncoderBuilder {
+    fn default() -> Self {
+        Self {
+            max_flight_data_size: GRPC_TARGET_MAX_FLIGHT_SIZE_BYTES,
+            options: IpcWriteOptions::default(),
+            descriptor: None,
+        }
+    }
+}
+
+impl ExchangeEncoderBuilder {
+    /// Create a new [`ExchangeEncoderBuilder`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the (approximate) maximum size, in bytes, of the [`FlightData`] produced
+    /// for a record batch, see [`FlightDataEncoderBuilder::with_max_flight_data_size`]
+    ///
+    /// [`FlightDataEncoderBuilder::with_max_flight_data_size`]: crate::encode::FlightDataEncoderBuilder::with_max_flight_data_size
+    pub fn with_max_flight_data_size(mut self, max_flight_data_size: usize) -> Self {
+        self.max_flight_data_size = max_flight_data_size;
+        self
+    }
+
+    /// Set the [`IpcWriteOptions`] used to encode the [`RecordBatch`]es for transport
+    pub fn with_options(mut self, options: IpcWriteOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Specify a flight descriptor to send in the first [`FlightData`] message, as
+    /// required of the first message a client sends to [`DoExchange`]
+    ///
+    /// [`DoExchange`]: crate::flight_service_server::FlightService::do_exchange
+    pub fn with_flight_descriptor(
+        mut self,
+        descriptor: Option<FlightDescriptor>,
+    ) -> Self {
+        self.descriptor = descriptor;
+        self
+    }
+
+    /// Return a [`Stream`] of [`FlightData`] encoding `input`
+    pub fn build<S>(self, input: S) -> ExchangeEncoder
+    where
+        S: Stream<Item = Result<ExchangeMessage>> + Send + 'static,
+    {
+        // The descriptor is sent immediately in a message of its own, allowing the
+        // server to identify the exchange before any data is available
+        let queue = self
+            .descriptor
+            .map(|descriptor| FlightData::new().with_descriptor(descriptor))
+            .into_iter()
+            .collect();
+
+        ExchangeEncoder {
+            inner: input.boxed(),
+            schema: None,
+            max_flight_data_size: self.max_flight_data_size,
+            encoder: FlightIpcEncoder::new(self.options.clone(), false),
+            options: self.options,
+            queue,
+            done: false,
+        }
+    }
+}
+
+/// Stream that encodes a stream of [`ExchangeMessage`]s to [`FlightData`]
+///
+/// Unlike [`FlightDataEncoder`], dictionaries are sent as is, rather than being
+/// converted to their underlying types, and are resent whenever their values change.
+/// As for IPC, each dictionary field should have a distinct
+/// [`dict_id`](arrow_schema::Field::dict_id).
+///
+/// See [`ExchangeEncoderBuilder`] for details.
+///
+/// [`FlightDataEncoder`]: crate::encode::FlightDataEncoder
+pub struct ExchangeEncoder {
+    /// Input stream
+    inner: BoxStream<'static, Result<ExchangeMessage>>,
+    /// The schema of the last schema message sent
+    schema: Option<SchemaRef>,
+    /// Target maximum size of flight data
+    max_flight_data_size: usize,
+    /// do the encoding / tracking of dictionaries
+    encoder: FlightIpcEncoder,
+    /// Ipc writer options, used to create a new encoder when the schema changes
+    options: IpcWriteOptions,
+    /// data queued up to send but not yet sent
+    queue: VecDeque<FlightData>,
+    /// Is this stream done (inner is empty or errored)
+    done: bool,
+}
+
+impl std::fmt::Debug for ExchangeEncoder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExchangeEncoder")
+            .field("schema", &self.schema)
+            .field("max_flight_data_size", &self.max_flight_data_size)
+            .field("queue", &self.queue)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ExchangeEncoder {
+    /// Encodes `message` into one or more `FlightData` in self.queue
+    fn encode_message(&mut self, message: ExchangeMessage) -> Result<()> {
+        match message {
+            ExchangeMessage::Schema(schema) => self.encode_schema(schema),
+            ExchangeMessage::RecordBatch(batch) => {
+                self.encode_schema(batch.schema());
+                let flight_data = self
+                    .encoder
+                    .encode_batch_split(batch, self.max_flight_data_size)?;
+                self.queue.extend(flight_data);
+            }
+            ExchangeMessage::Metadata(app_metadata) => {
+                self.queue
+                    .push_back(FlightData::new().with_app_metadata(app_metadata));
+            }
+        }
+        Ok(())
+    }
+
+    /// Encodes `schema` into self.queue, if it differs from the current schema
+    fn encode_schema(&mut self, schema: SchemaRef) {
+        if self.schema.as_ref() == Some(&schema) {
+            return;
+        }
+        // The receiver discards its dictionaries on receipt of a new schema
+        self.encoder = FlightIpcEncoder::new(self.options.clone(), false);
+        self.queue.push_back(self.encoder.encode_schema(&schema));
+        self.schema = Some(schema);
+    }
+}
+
+impl Stream for ExchangeEncoder {
+    type Item = Result<FlightData>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            // Any messages queued to send?
+            if let Some(data) = self.queue.pop_front() {
+                return Poll::Ready(Some(Ok(data)));
+            }
+
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            match ready!(self.inner.poll_next_unpin(cx)) {
+                None => {
+                    self.done = true;
+                    return Poll::Ready(None);
+                }
+                Some(Err(e)) => {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+                Some(Ok(message)) => {
+                    if let Err(e) = self.encode_message(message) {
+                        self.done = true;
+                        self.queue.clear();
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Decodes a [`Stream`] of [`FlightData`] into [`ExchangeMessage`]s
+///
+/// Every schema and record batch received is returned, followed by an
+/// [`ExchangeMessage::Metadata`] for any application metadata attached to it.
+/// Dictionaries are tracked by the underlying [`FlightDataDecoder`], and reset
+/// whenever a new schema is received.
+#[derive(Debug)]
+pub struct ExchangeDecoder {
+    inner: FlightDataDecoder,
+    /// Metadata to return after the previous message
+    metadata: Option<Bytes>,
+}
+
+impl ExchangeDecoder {
+    /// Create a new [`ExchangeDecoder`] from a [`FlightDataDecoder`]
+    pub fn new(inner: FlightDataDecoder) -> Self {
+        Self {
+            inner,
+            metadata: None,
+        }
+    }
+
+    /// Create a new [`ExchangeDecoder`] from a stream of [`FlightData`]
+    pub fn new_from_flight_data<S>(inner: S) -> Self
+    where
+        S: Stream<Item = Result<FlightData>> + Send + 'static,
+    {
+        Self::new(FlightDataDecoder::new(inner))
+    }
+
+    /// Returns the current schema, if any
+    pub fn schema(&self) -> Option<&SchemaRef> {
+        self.inner.schema()
+    }
+
+    /// Consume self and return the wrapped [`FlightDataDecoder`]
+    pub fn into_inner(self) -> FlightDataDecoder {
+        self.inner
+    }
+}
+
+impl Stream for ExchangeDecoder {
+    type Item = Result<ExchangeMessage>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(metadata) = self.metadata.take() {
+                return Poll::Ready(Some(Ok(ExchangeMessage::Metadata(metadata))));
+            }
+
+            let data = match ready!(self.inner.poll_next_unpin(cx)) {
+                Some(Ok(data)) => data,
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => return Poll::Ready(None),
+            };
+
+            let metadata = data.app_metadata();
+            self.metadata = (!metadata.is_empty()).then_some(metadata);
+
+            match data.payload {
+                DecodedPayload::Schema(schema) => {
+                    return Poll::Ready(Some(Ok(ExchangeMessage::Schema(schema))))
+                }
+                DecodedPayload::RecordBatch(batch) => {
+                    return Poll::Ready(Some(Ok(ExchangeMessage::RecordBatch(batch))))
+                }
+                // Dictionaries or metadata only
+                DecodedPayload::None => {}
+            }
+        }
+    }
+}
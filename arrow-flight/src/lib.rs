@@ -79,6 +79,9 @@ pub mod flight_service_server {
     pub use gen::flight_service_server::FlightServiceServer;
 }
 
+/// Authentication helpers, such as bearer tokens and cookies
+pub mod auth;
+
 /// Mid Level [`FlightClient`]
 pub mod client;
 pub use client::FlightClient;
@@ -94,6 +97,14 @@ pub mod encode;
 /// Common error types
 pub mod error;
 
+/// Encoder and decoder for bidirectional [`DoExchange`](flight_service_server::FlightService::do_exchange)
+/// streams. See [`ExchangeEncoderBuilder`](exchange::ExchangeEncoderBuilder).
+pub mod exchange;
+
+/// Configurable transports for Flight clients, such as HTTP proxies.
+/// See [`FlightChannelBuilder`](transport::FlightChannelBuilder).
+pub mod transport;
+
 pub use gen::Action;
 pub use gen::ActionType;
 pub use gen::BasicAuth;
@@ -720,4 +731,27 @@ mod tests {
         let des_schema: Schema = (&result).try_into().unwrap();
         assert_eq!(schema, des_schema);
     }
+
+    #[test]
+    fn corrupt_schema_flight_data_does_not_panic() {
+        let schema = Schema::new(vec![
+            Field::new("c1", DataType::Utf8, false),
+            Field::new_list("c2", Field::new("item", DataType::Int32, true), true),
+            Field::new_dictionary("c3", DataType::Int8, DataType::Utf8, true),
+        ]);
+        let option = IpcWriteOptions::default();
+        let data = FlightData::from(SchemaAsIpc::new(&schema, &option));
+        let batches = utils::flight_data_to_batches(std::slice::from_ref(&data));
+        assert_eq!(batches.unwrap(), vec![]);
+
+        // Corrupting any single byte must produce an error or a valid schema
+        for idx in 0..data.data_header.len() {
+            for value in [0, 0x7F, 0x80, 0xFF] {
+                let mut header = data.data_header.to_vec();
+                header[idx] = value;
+                let data = data.clone().with_data_header(header);
+                let _ = utils::flight_data_to_batches(&[data]);
+            }
+        }
+    }
 }
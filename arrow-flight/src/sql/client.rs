@@ -22,8 +22,13 @@ use base64::Engine;
 use bytes::Bytes;
 use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tonic::metadata::AsciiMetadataKey;
 
+use crate::decode::FlightRecordBatchStream;
+use crate::encode::FlightDataEncoderBuilder;
+use crate::error::FlightError;
 use crate::flight_service_client::FlightServiceClient;
 use crate::sql::server::{CLOSE_PREPARED_STATEMENT, CREATE_PREPARED_STATEMENT};
 use crate::sql::{
@@ -32,33 +37,64 @@ use crate::sql::{
     CommandGetCrossReference, CommandGetDbSchemas, CommandGetExportedKeys,
     CommandGetImportedKeys, CommandGetPrimaryKeys, CommandGetSqlInfo,
     CommandGetTableTypes, CommandGetTables, CommandGetXdbcTypeInfo,
-    CommandPreparedStatementQuery, CommandStatementQuery, CommandStatementUpdate,
-    DoPutUpdateResult, ProstMessageExt, SqlInfo,
+    CommandPreparedStatementQuery, CommandStatementIngest, CommandStatementQuery,
+    CommandStatementUpdate, DoPutUpdateResult, ProstMessageExt, SqlInfo,
 };
 use crate::{
-    Action, FlightData, FlightDescriptor, FlightInfo, HandshakeRequest,
+    Action, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo, HandshakeRequest,
     HandshakeResponse, IpcMessage, PutResult, Ticket,
 };
 use arrow_array::RecordBatch;
 use arrow_buffer::Buffer;
-use arrow_ipc::convert::fb_to_schema;
+use arrow_ipc::convert::try_fb_to_schema;
 use arrow_ipc::reader::read_record_batch;
 use arrow_ipc::{root_as_message, MessageHeader};
 use arrow_schema::{ArrowError, Schema, SchemaRef};
-use futures::{stream, TryStreamExt};
+use futures::stream::BoxStream;
+use futures::{stream, Stream, StreamExt, TryStreamExt};
 use prost::Message;
 use tonic::transport::Channel;
-use tonic::{IntoRequest, Streaming};
+use tonic::{Code, IntoRequest, Status, Streaming};
+
+/// The URI of a location indicating data can be fetched using the same connection
+const REUSE_CONNECTION_URI: &str = "arrow-flight-reuse-connection://?";
+
+/// The delay before retrying a request that failed as the server was unavailable,
+/// doubling with each subsequent retry up to [`MAX_RETRY_BACKOFF`]
+const INITIAL_RETRY_BACKOFF: Duration = Duration::from_millis(100);
+
+/// The maximum delay before retrying a request
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(5);
 
 /// A FlightSQLServiceClient is an endpoint for retrieving or storing Arrow data
 /// by FlightSQL protocol.
 #[derive(Debug, Clone)]
 pub struct FlightSqlServiceClient<T> {
-    token: Option<String>,
+    /// The auth token, shared with clones of this client such that a token obtained
+    /// by one of them is used by all
+    token: Arc<Mutex<Option<String>>>,
+    credentials: Option<Credentials>,
     headers: HashMap<String, String>,
+    max_retries: usize,
+    endpoint_concurrency: usize,
     flight_client: FlightServiceClient<T>,
 }
 
+/// The credentials of the last successful handshake, used to obtain a new token
+#[derive(Clone)]
+struct Credentials {
+    username: String,
+    password: String,
+}
+
+impl std::fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Credentials")
+            .field("username", &self.username)
+            .finish_non_exhaustive()
+    }
+}
+
 /// A FlightSql protocol client that can run queries against FlightSql servers
 /// This client is in the "experimental" stage. It is not guaranteed to follow the spec in all instances.
 /// Github issues are welcomed.
@@ -67,9 +103,12 @@ impl FlightSqlServiceClient<Channel> {
     pub fn new(channel: Channel) -> Self {
         let flight_client = FlightServiceClient::new(channel);
         FlightSqlServiceClient {
-            token: None,
+            token: Default::default(),
+            credentials: None,
             flight_client,
             headers: HashMap::default(),
+            max_retries: 3,
+            endpoint_concurrency: 1,
         }
     }
 
@@ -89,8 +128,10 @@ impl FlightSqlServiceClient<Channel> {
     }
 
     /// Set auth token to the given value.
+    ///
+    /// The token is shared with any clones of this client
     pub fn set_token(&mut self, token: String) {
-        self.token = Some(token);
+        *self.token.lock().unwrap() = Some(token);
     }
 
     /// Set header value.
@@ -100,6 +141,18 @@ impl FlightSqlServiceClient<Channel> {
         self.headers.insert(key, value);
     }
 
+    /// Set the maximum number of times [`Self::query`] retries a request that failed
+    /// as the server was unavailable or rejected the token, defaults to 3
+    pub fn set_max_retries(&mut self, max_retries: usize) {
+        self.max_retries = max_retries;
+    }
+
+    /// Set the maximum number of endpoints [`Self::query`] requests concurrently,
+    /// defaults to 1
+    pub fn set_endpoint_concurrency(&mut self, endpoint_concurrency: usize) {
+        self.endpoint_concurrency = endpoint_concurrency.max(1);
+    }
+
     async fn get_flight_info_for_command<M: ProstMessageExt>(
         &mut self,
         cmd: M,
@@ -128,6 +181,146 @@ impl FlightSqlServiceClient<Channel> {
         self.get_flight_info_for_command(cmd).await
     }
 
+    /// Execute a query on the server, returning a stream of its results
+    ///
+    /// Unlike [`Self::execute`], this fetches the data of every endpoint of the returned
+    /// [`FlightInfo`], from the first of its locations that can be connected to, or
+    /// this connection if it has none. Up to [`Self::set_endpoint_concurrency`]
+    /// endpoints are requested concurrently, with batches returned in endpoint order.
+    ///
+    /// Requests that fail as the server is unavailable are retried with exponential
+    /// backoff, as are requests that fail as unauthenticated, after a new token is
+    /// obtained with the credentials of the last [`Self::handshake`]. A new token is
+    /// also used by subsequent requests of this client.
+    ///
+    /// Neither the token nor the credentials are sent to locations other than
+    /// this connection, as these are chosen by the server and may refer to other hosts
+    pub async fn query(
+        &mut self,
+        query: String,
+        transaction_id: Option<Bytes>,
+    ) -> Result<BoxStream<'static, Result<RecordBatch, ArrowError>>, ArrowError> {
+        let cmd = CommandStatementQuery {
+            query,
+            transaction_id,
+        };
+        let descriptor = FlightDescriptor::new_cmd(cmd.as_any().encode_to_vec());
+
+        let mut attempt = 0;
+        let info = loop {
+            let req = self.set_request_headers(descriptor.clone().into_request())?;
+            match self.flight_client.get_flight_info(req).await {
+                Ok(response) => break response.into_inner(),
+                Err(status) => self.prepare_retry(status, &mut attempt).await?,
+            }
+        };
+
+        let client = self.clone();
+        let stream = stream::iter(info.endpoint)
+            .map(move |endpoint| {
+                let client = client.clone();
+                async move {
+                    let ticket = endpoint.ticket.clone().ok_or_else(|| {
+                        ArrowError::IoError("Endpoint has no ticket".to_string())
+                    })?;
+                    let mut client = client.endpoint_client(&endpoint).await?;
+                    let data = client.do_get_with_retries(ticket).await?;
+                    let batches = FlightRecordBatchStream::new_from_flight_data(
+                        data.map_err(FlightError::Tonic),
+                    );
+                    Ok::<_, ArrowError>(batches.map_err(flight_error_to_arrow_error))
+                }
+            })
+            .buffered(self.endpoint_concurrency)
+            .try_flatten();
+        Ok(stream.boxed())
+    }
+
+    /// Returns a client for fetching the data of `endpoint`
+    async fn endpoint_client(
+        &self,
+        endpoint: &FlightEndpoint,
+    ) -> Result<Self, ArrowError> {
+        let mut error = None;
+        for location in &endpoint.location {
+            if location.uri == REUSE_CONNECTION_URI {
+                return Ok(self.clone());
+            }
+            // Flight locations use grpc schemes, whereas tonic expects http(s)
+            let uri = location
+                .uri
+                .replacen("grpc+tls://", "https://", 1)
+                .replacen("grpc+tcp://", "http://", 1)
+                .replacen("grpc://", "http://", 1);
+            let channel = Channel::from_shared(uri).map_err(|e| {
+                ArrowError::IoError(format!("Invalid location {}: {e}", location.uri))
+            })?;
+            match channel.connect().await {
+                Ok(channel) => {
+                    // Only send credentials to the server of this connection
+                    let mut headers = self.headers.clone();
+                    headers.retain(|k, _| !k.eq_ignore_ascii_case("authorization"));
+                    return Ok(Self {
+                        token: Default::default(),
+                        credentials: None,
+                        headers,
+                        flight_client: FlightServiceClient::new(channel),
+                        ..self.clone()
+                    });
+                }
+                Err(e) => {
+                    error = Some(format!("Cannot connect to {}: {e}", location.uri))
+                }
+            }
+        }
+        match error {
+            Some(e) => Err(ArrowError::IoError(e)),
+            None => Ok(self.clone()),
+        }
+    }
+
+    /// Request the stream identified by `ticket`, retrying if appropriate
+    async fn do_get_with_retries(
+        &mut self,
+        ticket: Ticket,
+    ) -> Result<Streaming<FlightData>, ArrowError> {
+        let mut attempt = 0;
+        loop {
+            let req = self.set_request_headers(ticket.clone().into_request())?;
+            match self.flight_client.do_get(req).await {
+                Ok(response) => return Ok(response.into_inner()),
+                Err(status) => self.prepare_retry(status, &mut attempt).await?,
+            }
+        }
+    }
+
+    /// Prepare to retry a request that failed with `status`, returning an error if
+    /// it should not be retried
+    async fn prepare_retry(
+        &mut self,
+        status: Status,
+        attempt: &mut usize,
+    ) -> Result<(), ArrowError> {
+        if *attempt >= self.max_retries {
+            return Err(status_to_arrow_error(status));
+        }
+        *attempt += 1;
+        match (status.code(), self.credentials.clone()) {
+            (Code::Unavailable, _) => {
+                tokio::time::sleep(retry_backoff(*attempt)).await;
+                Ok(())
+            }
+            (Code::Unauthenticated, Some(credentials)) => {
+                // Clear the rejected token, as it would replace the basic auth header
+                *self.token.lock().unwrap() = None;
+                self.handshake(&credentials.username, &credentials.password)
+                    .await?;
+                Ok(())
+            }
+            _ => Err(status_to_arrow_error(status)),
+        }
+    }
+
     /// Perform a `handshake` with the server, passing credentials and establishing a session
     /// Returns arbitrary auth/handshake info binary blob
     pub async fn handshake(
@@ -160,7 +353,11 @@ impl FlightSqlServiceClient<Channel> {
                 Err(ArrowError::ParseError("Invalid auth header!".to_string()))?;
             }
             let auth = auth[bearer.len()..].to_string();
-            self.token = Some(auth);
+            *self.token.lock().unwrap() = Some(auth);
+            self.credentials = Some(Credentials {
+                username: username.to_string(),
+                password: password.to_string(),
+            });
         }
         let responses: Vec<HandshakeResponse> =
             resp.into_inner().try_collect().await.map_err(|_| {
@@ -211,6 +408,55 @@ impl FlightSqlServiceClient<Channel> {
         Ok(result.record_count)
     }
 
+    /// Bulk ingest a stream of [`RecordBatch`]es into the table described by
+    /// `command` on the server, and return the number of records ingested
+    ///
+    /// The stream should contain at least one [`RecordBatch`], as it is used
+    /// to send the schema of the data to the server.
+    pub async fn execute_ingest<S>(
+        &mut self,
+        command: CommandStatementIngest,
+        stream: S,
+    ) -> Result<i64, ArrowError>
+    where
+        S: Stream<Item = crate::error::Result<RecordBatch>> + Send + 'static,
+    {
+        let descriptor = FlightDescriptor::new_cmd(command.as_any().encode_to_vec());
+        let flight_data = FlightDataEncoderBuilder::new()
+            .with_flight_descriptor(Some(descriptor))
+            .build(stream);
+
+        // Stop sending on the first error, and report it rather than the
+        // response to the truncated request
+        let error = Arc::new(Mutex::new(None));
+        let captured = Arc::clone(&error);
+        let flight_data = flight_data.scan((), move |_, data| {
+            futures::future::ready(match data {
+                Ok(data) => Some(data),
+                Err(e) => {
+                    *captured.lock().unwrap() = Some(e);
+                    None
+                }
+            })
+        });
+
+        let result = self.do_put(flight_data).await;
+        if let Some(e) = error.lock().unwrap().take() {
+            return Err(flight_error_to_arrow_error(e));
+        }
+        let result = result?
+            .message()
+            .await
+            .map_err(status_to_arrow_error)?
+            .ok_or_else(|| ArrowError::IoError("No response from do_put".to_string()))?;
+        let any =
+            Any::decode(&*result.app_metadata).map_err(decode_error_to_arrow_error)?;
+        let result: DoPutUpdateResult = any.unpack()?.ok_or_else(|| {
+            ArrowError::ParseError("Expected DoPutUpdateResult".to_string())
+        })?;
+        Ok(result.record_count)
+    }
+
     /// Request a list of catalogs as tabular FlightInfo results
     pub async fn get_catalogs(&mut self) -> Result<FlightInfo, ArrowError> {
         self.get_flight_info_for_command(CommandGetCatalogs {})
@@ -397,7 +643,7 @@ impl FlightSqlServiceClient<Channel> {
             })?;
             req.metadata_mut().insert(k, v);
         }
-        if let Some(token) = &self.token {
+        if let Some(token) = self.token.lock().unwrap().as_ref() {
             let val = format!("Bearer {token}").parse().map_err(|e| {
                 ArrowError::IoError(format!("Cannot convert token to header value: {e}"))
             })?;
@@ -511,6 +757,22 @@ fn status_to_arrow_error(status: tonic::Status) -> ArrowError {
     ArrowError::IoError(format!("{status:?}"))
 }
 
+fn flight_error_to_arrow_error(err: FlightError) -> ArrowError {
+    match err {
+        FlightError::Arrow(e) => e,
+        FlightError::Tonic(status) => status_to_arrow_error(status),
+        e => ArrowError::ExternalError(Box::new(e)),
+    }
+}
+
+/// Returns the delay before the retry numbered `attempt`, starting from 1
+fn retry_backoff(attempt: usize) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(16) as u32;
+    INITIAL_RETRY_BACKOFF
+        .saturating_mul(1 << exponent)
+        .min(MAX_RETRY_BACKOFF)
+}
+
 // A polymorphic structure to natively represent different types of data contained in `FlightData`
 pub enum ArrowFlightData {
     RecordBatch(RecordBatch),
@@ -554,7 +816,7 @@ pub fn arrow_data_from_flight_data(
                 )
             })?;
 
-            let arrow_schema = fb_to_schema(ipc_schema);
+            let arrow_schema = try_fb_to_schema(ipc_schema)?;
             Ok(ArrowFlightData::Schema(arrow_schema))
         }
         MessageHeader::DictionaryBatch => {
@@ -21,20 +21,27 @@
 //! - [`GetCatalogsBuilder`] for building responses to [`CommandGetCatalogs`] queries.
 //! - [`GetDbSchemasBuilder`] for building responses to [`CommandGetDbSchemas`] queries.
 //! - [`GetTablesBuilder`]for building responses to [`CommandGetTables`] queries.
+//! - [`GetTableTypesBuilder`] for building responses to [`CommandGetTableTypes`] queries.
+//! - [`SqlMetadata`] for building all of the above from a description of the server.
 //!
 //! [`CommandGetCatalogs`]: crate::sql::CommandGetCatalogs
 //! [`CommandGetDbSchemas`]: crate::sql::CommandGetDbSchemas
 //! [`CommandGetTables`]: crate::sql::CommandGetTables
+//! [`CommandGetTableTypes`]: crate::sql::CommandGetTableTypes
 
 mod catalogs;
 mod db_schemas;
 mod sql_info;
+mod sql_metadata;
+mod table_types;
 mod tables;
 mod xdbc_info;
 
 pub use catalogs::GetCatalogsBuilder;
 pub use db_schemas::GetDbSchemasBuilder;
 pub use sql_info::{SqlInfoData, SqlInfoDataBuilder};
+pub use sql_metadata::SqlMetadata;
+pub use table_types::GetTableTypesBuilder;
 pub use tables::GetTablesBuilder;
 pub use xdbc_info::{XdbcTypeInfo, XdbcTypeInfoData, XdbcTypeInfoDataBuilder};
 
@@ -0,0 +1,275 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeSet;
+
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef;
+
+use super::{SqlInfoData, XdbcTypeInfoData};
+use crate::error::Result;
+use crate::sql::{
+    CommandGetCatalogs, CommandGetDbSchemas, CommandGetSqlInfo, CommandGetTableTypes,
+    CommandGetTables, CommandGetXdbcTypeInfo,
+};
+
+/// A table exposed by a FlightSQL server
+struct TableInfo {
+    catalog_name: String,
+    db_schema_name: String,
+    table_name: String,
+    table_type: String,
+    schema: SchemaRef,
+}
+
+/// Describes the catalogs, schemas, tables and capabilities of a FlightSQL server.
+///
+/// Responses to [`CommandGetCatalogs`], [`CommandGetDbSchemas`], [`CommandGetTables`],
+/// [`CommandGetTableTypes`], [`CommandGetSqlInfo`] and [`CommandGetXdbcTypeInfo`]
+/// are built from this description, applying any filters in the request.
+///
+/// Returning it from [`FlightSqlService::sql_metadata`] provides default
+/// implementations of these endpoints.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_schema::{DataType, Field, Schema};
+/// # use arrow_flight::sql::metadata::{SqlInfoDataBuilder, SqlMetadata};
+/// # use arrow_flight::sql::SqlInfo;
+/// let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+///
+/// let mut sql_info = SqlInfoDataBuilder::new();
+/// sql_info.append(SqlInfo::FlightSqlServerName, "server");
+///
+/// let metadata = SqlMetadata::new()
+///     .with_table("catalog", "schema", "table", "TABLE", schema)
+///     .with_db_schema("catalog", "empty_schema")
+///     .with_sql_info(sql_info.build().unwrap());
+/// ```
+///
+/// [`FlightSqlService::sql_metadata`]: crate::sql::server::FlightSqlService::sql_metadata
+#[derive(Default)]
+pub struct SqlMetadata {
+    db_schemas: BTreeSet<(String, String)>,
+    tables: Vec<TableInfo>,
+    sql_info: Option<SqlInfoData>,
+    xdbc_type_info: Option<XdbcTypeInfoData>,
+}
+
+impl SqlMetadata {
+    /// Create a new, empty [`SqlMetadata`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a table of type `table_type`, e.g. `"TABLE"` or `"VIEW"`, with `schema`
+    ///
+    /// Its catalog and schema are added if not already present, an empty string
+    /// denotes a table without a catalog or schema
+    pub fn with_table(
+        mut self,
+        catalog_name: impl Into<String>,
+        db_schema_name: impl Into<String>,
+        table_name: impl Into<String>,
+        table_type: impl Into<String>,
+        schema: SchemaRef,
+    ) -> Self {
+        let catalog_name = catalog_name.into();
+        let db_schema_name = db_schema_name.into();
+        self.db_schemas
+            .insert((catalog_name.clone(), db_schema_name.clone()));
+        self.tables.push(TableInfo {
+            catalog_name,
+            db_schema_name,
+            table_name: table_name.into(),
+            table_type: table_type.into(),
+            schema,
+        });
+        self
+    }
+
+    /// Add a schema that may not contain any tables
+    pub fn with_db_schema(
+        mut self,
+        catalog_name: impl Into<String>,
+        db_schema_name: impl Into<String>,
+    ) -> Self {
+        self.db_schemas
+            .insert((catalog_name.into(), db_schema_name.into()));
+        self
+    }
+
+    /// Set the [`SqlInfoData`] returned for [`CommandGetSqlInfo`]
+    pub fn with_sql_info(self, sql_info: SqlInfoData) -> Self {
+        Self {
+            sql_info: Some(sql_info),
+            ..self
+        }
+    }
+
+    /// Set the [`XdbcTypeInfoData`] returned for [`CommandGetXdbcTypeInfo`]
+    pub fn with_xdbc_type_info(self, xdbc_type_info: XdbcTypeInfoData) -> Self {
+        Self {
+            xdbc_type_info: Some(xdbc_type_info),
+            ..self
+        }
+    }
+
+    /// Returns the [`SqlInfoData`] if any
+    pub fn sql_info(&self) -> Option<&SqlInfoData> {
+        self.sql_info.as_ref()
+    }
+
+    /// Returns the [`XdbcTypeInfoData`] if any
+    pub fn xdbc_type_info(&self) -> Option<&XdbcTypeInfoData> {
+        self.xdbc_type_info.as_ref()
+    }
+
+    /// Build the response to a [`CommandGetCatalogs`]
+    pub fn get_catalogs(&self, query: CommandGetCatalogs) -> Result<RecordBatch> {
+        let mut builder = query.into_builder();
+        let catalogs: BTreeSet<_> = self.db_schemas.iter().map(|(c, _)| c).collect();
+        for catalog in catalogs {
+            builder.append(catalog);
+        }
+        builder.build()
+    }
+
+    /// Build the response to a [`CommandGetDbSchemas`]
+    pub fn get_db_schemas(&self, query: CommandGetDbSchemas) -> Result<RecordBatch> {
+        let mut builder = query.into_builder();
+        for (catalog_name, db_schema_name) in &self.db_schemas {
+            builder.append(catalog_name, db_schema_name);
+        }
+        builder.build()
+    }
+
+    /// Build the response to a [`CommandGetTables`]
+    pub fn get_tables(&self, query: CommandGetTables) -> Result<RecordBatch> {
+        let mut builder = query.into_builder();
+        for table in &self.tables {
+            builder.append(
+                &table.catalog_name,
+                &table.db_schema_name,
+                &table.table_name,
+                &table.table_type,
+                &table.schema,
+            )?;
+        }
+        builder.build()
+    }
+
+    /// Build the response to a [`CommandGetTableTypes`]
+    pub fn get_table_types(&self, query: CommandGetTableTypes) -> Result<RecordBatch> {
+        let mut builder = query.into_builder();
+        for table in &self.tables {
+            builder.append(&table.table_type);
+        }
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_schema::{DataType, Field, Schema};
+
+    use super::*;
+    use crate::sql::metadata::tests::assert_batches_eq;
+
+    fn metadata() -> SqlMetadata {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        SqlMetadata::new()
+            .with_table("b_catalog", "a_schema", "b_table", "VIEW", schema.clone())
+            .with_table("a_catalog", "a_schema", "a_table", "TABLE", schema.clone())
+            .with_table("a_catalog", "b_schema", "a_table", "TABLE", schema)
+            .with_db_schema("a_catalog", "c_schema")
+    }
+
+    #[test]
+    fn test_catalogs_and_schemas() {
+        let metadata = metadata();
+        let batch = metadata.get_catalogs(CommandGetCatalogs {}).unwrap();
+        assert_batches_eq(
+            &[batch],
+            &[
+                "+--------------+",
+                "| catalog_name |",
+                "+--------------+",
+                "| a_catalog    |",
+                "| b_catalog    |",
+                "+--------------+",
+            ],
+        );
+
+        let query = CommandGetDbSchemas {
+            catalog: Some("a_catalog".to_string()),
+            db_schema_filter_pattern: None,
+        };
+        let batch = metadata.get_db_schemas(query).unwrap();
+        assert_batches_eq(
+            &[batch],
+            &[
+                "+--------------+----------------+",
+                "| catalog_name | db_schema_name |",
+                "+--------------+----------------+",
+                "| a_catalog    | a_schema       |",
+                "| a_catalog    | b_schema       |",
+                "| a_catalog    | c_schema       |",
+                "+--------------+----------------+",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_tables_and_table_types() {
+        let metadata = metadata();
+        let query = CommandGetTables {
+            catalog: None,
+            db_schema_filter_pattern: Some("a%".to_string()),
+            table_name_filter_pattern: None,
+            table_types: vec![],
+            include_schema: false,
+        };
+        let batch = metadata.get_tables(query).unwrap();
+        assert_batches_eq(
+            &[batch],
+            &[
+                "+--------------+----------------+------------+------------+",
+                "| catalog_name | db_schema_name | table_name | table_type |",
+                "+--------------+----------------+------------+------------+",
+                "| a_catalog    | a_schema       | a_table    | TABLE      |",
+                "| b_catalog    | a_schema       | b_table    | VIEW       |",
+                "+--------------+----------------+------------+------------+",
+            ],
+        );
+
+        let batch = metadata.get_table_types(CommandGetTableTypes {}).unwrap();
+        assert_batches_eq(
+            &[batch],
+            &[
+                "+------------+",
+                "| table_type |",
+                "+------------+",
+                "| TABLE      |",
+                "| VIEW       |",
+                "+------------+",
+            ],
+        );
+    }
+}
@@ -0,0 +1,95 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use arrow_array::{RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use once_cell::sync::Lazy;
+
+use crate::error::Result;
+use crate::sql::CommandGetTableTypes;
+
+/// A builder for a [`CommandGetTableTypes`] response.
+///
+/// Builds rows like this:
+///
+/// * table_type: utf8 not null,
+pub struct GetTableTypesBuilder {
+    table_types: BTreeSet<String>,
+}
+
+impl CommandGetTableTypes {
+    /// Create a builder suitable for constructing a response
+    pub fn into_builder(self) -> GetTableTypesBuilder {
+        self.into()
+    }
+}
+
+impl From<CommandGetTableTypes> for GetTableTypesBuilder {
+    fn from(_: CommandGetTableTypes) -> Self {
+        Self::new()
+    }
+}
+
+impl Default for GetTableTypesBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GetTableTypesBuilder {
+    /// Create a new instance of [`GetTableTypesBuilder`]
+    pub fn new() -> Self {
+        Self {
+            table_types: BTreeSet::new(),
+        }
+    }
+
+    /// Append a row, duplicate table types are ignored
+    pub fn append(&mut self, table_type: impl Into<String>) {
+        self.table_types.insert(table_type.into());
+    }
+
+    /// builds a `RecordBatch` with the correct schema for a
+    /// [`CommandGetTableTypes`] response, ordered by table type
+    pub fn build(self) -> Result<RecordBatch> {
+        let Self { table_types } = self;
+
+        let batch = RecordBatch::try_new(
+            Arc::clone(&GET_TABLE_TYPES_SCHEMA),
+            vec![Arc::new(StringArray::from_iter_values(table_types)) as _],
+        )?;
+
+        Ok(batch)
+    }
+
+    /// Returns the schema that will result from [`CommandGetTableTypes`]
+    pub fn schema(&self) -> SchemaRef {
+        Arc::clone(&GET_TABLE_TYPES_SCHEMA)
+    }
+}
+
+/// The schema for GetTableTypes
+static GET_TABLE_TYPES_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![Field::new(
+        "table_type",
+        DataType::Utf8,
+        false,
+    )]))
+});
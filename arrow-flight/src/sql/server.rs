@@ -17,12 +17,19 @@
 
 //! Helper trait [`FlightSqlService`] for implementing a [`FlightService`] that implements FlightSQL.
 
+use std::collections::HashMap;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
-use futures::Stream;
+use arrow_array::RecordBatch;
+use arrow_schema::Schema;
+use bytes::Bytes;
+use futures::{Stream, StreamExt, TryStreamExt};
 use prost::Message;
 use tonic::{Request, Response, Status, Streaming};
 
+use super::metadata::SqlMetadata;
 use super::{
     ActionBeginSavepointRequest, ActionBeginSavepointResult,
     ActionBeginTransactionRequest, ActionBeginTransactionResult,
@@ -33,14 +40,18 @@ use super::{
     CommandGetCatalogs, CommandGetCrossReference, CommandGetDbSchemas,
     CommandGetExportedKeys, CommandGetImportedKeys, CommandGetPrimaryKeys,
     CommandGetSqlInfo, CommandGetTableTypes, CommandGetTables, CommandGetXdbcTypeInfo,
-    CommandPreparedStatementQuery, CommandPreparedStatementUpdate, CommandStatementQuery,
-    CommandStatementSubstraitPlan, CommandStatementUpdate, DoPutUpdateResult,
-    ProstMessageExt, SqlInfo, TicketStatementQuery,
+    CommandPreparedStatementQuery, CommandPreparedStatementUpdate,
+    CommandStatementIngest, CommandStatementQuery, CommandStatementSubstraitPlan,
+    CommandStatementUpdate, DoPutUpdateResult, ProstMessageExt, SqlInfo,
+    TicketStatementQuery,
 };
+use crate::decode::FlightRecordBatchStream;
+use crate::encode::FlightDataEncoderBuilder;
+use crate::error::FlightError;
 use crate::{
     flight_service_server::FlightService, Action, ActionType, Criteria, Empty,
-    FlightData, FlightDescriptor, FlightInfo, HandshakeRequest, HandshakeResponse,
-    PutResult, SchemaResult, Ticket,
+    FlightData, FlightDescriptor, FlightEndpoint, FlightInfo, HandshakeRequest,
+    HandshakeResponse, PutResult, SchemaResult, Ticket,
 };
 
 pub(crate) static CREATE_PREPARED_STATEMENT: &str = "CreatePreparedStatement";
@@ -58,6 +69,16 @@ pub trait FlightSqlService: Sync + Send + Sized + 'static {
     /// When impl FlightSqlService, you can always set FlightService to Self
     type FlightService: FlightService;
 
+    /// Returns a description of the catalogs, schemas, tables and capabilities of
+    /// this server, if any
+    ///
+    /// When provided, it is used by the default implementations of the endpoints for
+    /// [`CommandGetCatalogs`], [`CommandGetDbSchemas`], [`CommandGetTables`],
+    /// [`CommandGetTableTypes`], [`CommandGetSqlInfo`] and [`CommandGetXdbcTypeInfo`]
+    fn sql_metadata(&self) -> Option<&SqlMetadata> {
+        None
+    }
+
     /// Accept authentication and return a token
     /// <https://arrow.apache.org/docs/format/Flight.html#authentication>
     async fn do_handshake(
@@ -120,56 +141,86 @@ pub trait FlightSqlService: Sync + Send + Sized + 'static {
     /// Get a FlightInfo for listing catalogs.
     async fn get_flight_info_catalogs(
         &self,
-        _query: CommandGetCatalogs,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetCatalogs,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_catalogs has no default implementation",
-        ))
+        match self.sql_metadata() {
+            Some(_) => {
+                let schema = query.clone().into_builder().schema();
+                metadata_flight_info(&query, &schema, request)
+            }
+            None => Err(Status::unimplemented(
+                "get_flight_info_catalogs has no default implementation",
+            )),
+        }
     }
 
     /// Get a FlightInfo for listing schemas.
     async fn get_flight_info_schemas(
         &self,
-        _query: CommandGetDbSchemas,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetDbSchemas,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_schemas has no default implementation",
-        ))
+        match self.sql_metadata() {
+            Some(_) => {
+                let schema = query.clone().into_builder().schema();
+                metadata_flight_info(&query, &schema, request)
+            }
+            None => Err(Status::unimplemented(
+                "get_flight_info_schemas has no default implementation",
+            )),
+        }
     }
 
     /// Get a FlightInfo for listing tables.
     async fn get_flight_info_tables(
         &self,
-        _query: CommandGetTables,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetTables,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_tables has no default implementation",
-        ))
+        match self.sql_metadata() {
+            Some(_) => {
+                let schema = query.clone().into_builder().schema();
+                metadata_flight_info(&query, &schema, request)
+            }
+            None => Err(Status::unimplemented(
+                "get_flight_info_tables has no default implementation",
+            )),
+        }
     }
 
     /// Get a FlightInfo to extract information about the table types.
     async fn get_flight_info_table_types(
         &self,
-        _query: CommandGetTableTypes,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetTableTypes,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_table_types has no default implementation",
-        ))
+        match self.sql_metadata() {
+            Some(_) => {
+                let schema = query.clone().into_builder().schema();
+                metadata_flight_info(&query, &schema, request)
+            }
+            None => Err(Status::unimplemented(
+                "get_flight_info_table_types has no default implementation",
+            )),
+        }
     }
 
     /// Get a FlightInfo for retrieving other information (See SqlInfo).
     async fn get_flight_info_sql_info(
         &self,
-        _query: CommandGetSqlInfo,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetSqlInfo,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_sql_info has no default implementation",
-        ))
+        match self.sql_metadata().and_then(|m| m.sql_info()) {
+            Some(info) => {
+                let schema = query.clone().into_builder(info).schema();
+                metadata_flight_info(&query, &schema, request)
+            }
+            None => Err(Status::unimplemented(
+                "get_flight_info_sql_info has no default implementation",
+            )),
+        }
     }
 
     /// Get a FlightInfo to extract information about primary and foreign keys.
@@ -219,12 +270,18 @@ pub trait FlightSqlService: Sync + Send + Sized + 'static {
     /// Get a FlightInfo to extract information about the supported XDBC types.
     async fn get_flight_info_xdbc_type_info(
         &self,
-        _query: CommandGetXdbcTypeInfo,
-        _request: Request<FlightDescriptor>,
+        query: CommandGetXdbcTypeInfo,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_xdbc_type_info has no default implementation",
-        ))
+        match self.sql_metadata().and_then(|m| m.xdbc_type_info()) {
+            Some(info) => {
+                let schema = query.clone().into_builder(info).schema();
+                metadata_flight_info(&query, &schema, request)
+            }
+            None => Err(Status::unimplemented(
+                "get_flight_info_xdbc_type_info has no default implementation",
+            )),
+        }
     }
 
     // do_get
@@ -254,56 +311,71 @@ pub trait FlightSqlService: Sync + Send + Sized + 'static {
     /// Get a FlightDataStream containing the list of catalogs.
     async fn do_get_catalogs(
         &self,
-        _query: CommandGetCatalogs,
+        query: CommandGetCatalogs,
         _request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        Err(Status::unimplemented(
-            "do_get_catalogs has no default implementation",
-        ))
+        match self.sql_metadata() {
+            Some(metadata) => batch_stream(metadata.get_catalogs(query)?),
+            None => Err(Status::unimplemented(
+                "do_get_catalogs has no default implementation",
+            )),
+        }
     }
 
     /// Get a FlightDataStream containing the list of schemas.
     async fn do_get_schemas(
         &self,
-        _query: CommandGetDbSchemas,
+        query: CommandGetDbSchemas,
         _request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        Err(Status::unimplemented(
-            "do_get_schemas has no default implementation",
-        ))
+        match self.sql_metadata() {
+            Some(metadata) => batch_stream(metadata.get_db_schemas(query)?),
+            None => Err(Status::unimplemented(
+                "do_get_schemas has no default implementation",
+            )),
+        }
     }
 
     /// Get a FlightDataStream containing the list of tables.
     async fn do_get_tables(
         &self,
-        _query: CommandGetTables,
+        query: CommandGetTables,
         _request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        Err(Status::unimplemented(
-            "do_get_tables has no default implementation",
-        ))
+        match self.sql_metadata() {
+            Some(metadata) => batch_stream(metadata.get_tables(query)?),
+            None => Err(Status::unimplemented(
+                "do_get_tables has no default implementation",
+            )),
+        }
     }
 
     /// Get a FlightDataStream containing the data related to the table types.
     async fn do_get_table_types(
         &self,
-        _query: CommandGetTableTypes,
+        query: CommandGetTableTypes,
         _request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        Err(Status::unimplemented(
-            "do_get_table_types has no default implementation",
-        ))
+        match self.sql_metadata() {
+            Some(metadata) => batch_stream(metadata.get_table_types(query)?),
+            None => Err(Status::unimplemented(
+                "do_get_table_types has no default implementation",
+            )),
+        }
     }
 
     /// Get a FlightDataStream containing the list of SqlInfo results.
     async fn do_get_sql_info(
         &self,
-        _query: CommandGetSqlInfo,
+        query: CommandGetSqlInfo,
         _request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        Err(Status::unimplemented(
-            "do_get_sql_info has no default implementation",
-        ))
+        match self.sql_metadata().and_then(|m| m.sql_info()) {
+            Some(info) => batch_stream(query.into_builder(info).build()?),
+            None => Err(Status::unimplemented(
+                "do_get_sql_info has no default implementation",
+            )),
+        }
     }
 
     /// Get a FlightDataStream containing the data related to the primary and foreign keys.
@@ -353,12 +425,15 @@ pub trait FlightSqlService: Sync + Send + Sized + 'static {
     /// Get a FlightDataStream containing the data related to the supported XDBC types.
     async fn do_get_xdbc_type_info(
         &self,
-        _query: CommandGetXdbcTypeInfo,
+        query: CommandGetXdbcTypeInfo,
         _request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        Err(Status::unimplemented(
-            "do_get_xdbc_type_info has no default implementation",
-        ))
+        match self.sql_metadata().and_then(|m| m.xdbc_type_info()) {
+            Some(info) => batch_stream(query.into_builder(info).build()?),
+            None => Err(Status::unimplemented(
+                "do_get_xdbc_type_info has no default implementation",
+            )),
+        }
     }
 
     // do_put
@@ -386,6 +461,21 @@ pub trait FlightSqlService: Sync + Send + Sized + 'static {
         ))
     }
 
+    /// Bulk ingest a stream of record batches into a table, returning the
+    /// number of records ingested.
+    ///
+    /// The table to ingest into, and whether to create, append to or replace
+    /// it, are described by `ticket`.
+    async fn do_put_statement_ingest(
+        &self,
+        _ticket: CommandStatementIngest,
+        _request: Request<FlightRecordBatchStream>,
+    ) -> Result<i64, Status> {
+        Err(Status::unimplemented(
+            "do_put_statement_ingest has no default implementation",
+        ))
+    }
+
     /// Bind parameters to given prepared statement.
     async fn do_put_prepared_statement_query(
         &self,
@@ -539,6 +629,88 @@ pub trait FlightSqlService: Sync + Send + Sized + 'static {
     async fn register_sql_info(&self, id: i32, result: &SqlInfo);
 }
 
+/// A registry of the server-side state of prepared statements, identified by
+/// opaque handles
+///
+/// This can be used to implement [`FlightSqlService::do_action_create_prepared_statement`],
+/// returning the handle from [`Self::insert`] to the client, and to look up the statement
+/// when the client later refers to it by handle, e.g. in
+/// [`FlightSqlService::do_get_prepared_statement`]
+///
+/// ```
+/// # use arrow_flight::sql::server::PreparedStatementRegistry;
+/// let registry = PreparedStatementRegistry::new();
+/// let handle = registry.insert("SELECT 1".to_string());
+///
+/// assert_eq!(registry.get(&handle).unwrap().as_str(), "SELECT 1");
+/// registry.remove(&handle).unwrap();
+/// assert!(registry.get(&handle).is_err());
+/// ```
+#[derive(Debug)]
+pub struct PreparedStatementRegistry<T> {
+    next_id: AtomicU64,
+    statements: Mutex<HashMap<Bytes, Arc<T>>>,
+}
+
+impl<T> Default for PreparedStatementRegistry<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> PreparedStatementRegistry<T> {
+    /// Create a new, empty [`PreparedStatementRegistry`]
+    pub fn new() -> Self {
+        Self {
+            next_id: AtomicU64::new(0),
+            statements: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Register `statement`, returning the handle that identifies it
+    pub fn insert(&self, statement: T) -> Bytes {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let handle = Bytes::from(id.to_be_bytes().to_vec());
+        self.statements
+            .lock()
+            .unwrap()
+            .insert(handle.clone(), Arc::new(statement));
+        handle
+    }
+
+    /// Returns the statement identified by `handle`
+    pub fn get(&self, handle: &[u8]) -> Result<Arc<T>, Status> {
+        let statements = self.statements.lock().unwrap();
+        statements
+            .get(handle)
+            .cloned()
+            .ok_or_else(|| unknown_handle(handle))
+    }
+
+    /// Removes and returns the statement identified by `handle`, for instance in
+    /// [`FlightSqlService::do_action_close_prepared_statement`]
+    pub fn remove(&self, handle: &[u8]) -> Result<Arc<T>, Status> {
+        let mut statements = self.statements.lock().unwrap();
+        statements
+            .remove(handle)
+            .ok_or_else(|| unknown_handle(handle))
+    }
+
+    /// Returns the number of registered statements
+    pub fn len(&self) -> usize {
+        self.statements.lock().unwrap().len()
+    }
+
+    /// Returns true if there are no registered statements
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+fn unknown_handle(handle: &[u8]) -> Status {
+    Status::not_found(format!("Unknown prepared statement handle: {handle:?}"))
+}
+
 /// Implements the lower level interface to handle FlightSQL
 #[tonic::async_trait]
 impl<T: 'static> FlightService for T
@@ -690,10 +862,33 @@ where
         &self,
         mut request: Request<Streaming<FlightData>>,
     ) -> Result<Response<Self::DoPutStream>, Status> {
-        let cmd = request.get_mut().message().await?.unwrap();
-        let message = Any::decode(&*cmd.flight_descriptor.unwrap().cmd)
-            .map_err(decode_error_to_status)?;
+        let cmd = request
+            .get_mut()
+            .message()
+            .await?
+            .ok_or_else(|| Status::invalid_argument("Empty do_put request"))?;
+        let descriptor = cmd.flight_descriptor.as_ref().ok_or_else(|| {
+            Status::invalid_argument("Missing flight descriptor in do_put request")
+        })?;
+        let message = Any::decode(&*descriptor.cmd).map_err(decode_error_to_status)?;
         match Command::try_from(message).map_err(arrow_error_to_status)? {
+            Command::CommandStatementIngest(command) => {
+                // The first message may also contain the schema, so must be decoded
+                let (metadata, extensions, stream) = request.into_parts();
+                let stream = futures::stream::once(async { Ok(cmd) })
+                    .chain(stream.map_err(FlightError::from));
+                let request = Request::from_parts(
+                    metadata,
+                    extensions,
+                    FlightRecordBatchStream::new_from_flight_data(stream),
+                );
+                let record_count = self.do_put_statement_ingest(command, request).await?;
+                let result = DoPutUpdateResult { record_count };
+                let output = futures::stream::iter(vec![Ok(PutResult {
+                    app_metadata: result.as_any().encode_to_vec().into(),
+                })]);
+                Ok(Response::new(Box::pin(output)))
+            }
             Command::CommandStatementUpdate(command) => {
                 let record_count = self.do_put_statement_update(command, request).await?;
                 let result = DoPutUpdateResult { record_count };
@@ -950,6 +1145,38 @@ where
     }
 }
 
+/// Returns a [`FlightInfo`] for the metadata `query` with result `schema`, whose
+/// ticket is the encoded `query`
+fn metadata_flight_info(
+    query: &impl ProstMessageExt,
+    schema: &Schema,
+    request: Request<FlightDescriptor>,
+) -> Result<Response<FlightInfo>, Status> {
+    let ticket = Ticket::new(query.as_any().encode_to_vec());
+    let endpoint = FlightEndpoint::new().with_ticket(ticket);
+    let flight_info = FlightInfo::new()
+        .try_with_schema(schema)
+        .map_err(arrow_error_to_status)?
+        .with_endpoint(endpoint)
+        .with_descriptor(request.into_inner());
+    Ok(Response::new(flight_info))
+}
+
+/// Returns a stream of [`FlightData`] encoding `batch`
+#[allow(clippy::type_complexity)]
+fn batch_stream(
+    batch: RecordBatch,
+) -> Result<
+    Response<Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send + 'static>>>,
+    Status,
+> {
+    let stream = FlightDataEncoderBuilder::new()
+        .with_schema(batch.schema())
+        .build(futures::stream::once(async { Ok::<_, FlightError>(batch) }))
+        .map_err(Status::from);
+    Ok(Response::new(Box::pin(stream)))
+}
+
 fn decode_error_to_status(err: prost::DecodeError) -> Status {
     Status::invalid_argument(format!("{err:?}"))
 }
@@ -957,3 +1184,122 @@ fn decode_error_to_status(err: prost::DecodeError) -> Status {
 fn arrow_error_to_status(err: arrow_schema::ArrowError) -> Status {
     Status::internal(format!("{err:?}"))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_cast::pretty::pretty_format_batches;
+    use arrow_schema::{DataType, Field};
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::decode::FlightRecordBatchStream;
+    use crate::sql::metadata::SqlInfoDataBuilder;
+
+    struct TestService {
+        metadata: SqlMetadata,
+    }
+
+    #[tonic::async_trait]
+    impl FlightSqlService for TestService {
+        type FlightService = Self;
+
+        fn sql_metadata(&self) -> Option<&SqlMetadata> {
+            Some(&self.metadata)
+        }
+
+        async fn register_sql_info(&self, _id: i32, _result: &SqlInfo) {}
+    }
+
+    /// Calls `get_flight_info` for `cmd` followed by `do_get` with the returned ticket
+    async fn get(service: &TestService, cmd: impl ProstMessageExt) -> String {
+        let descriptor = FlightDescriptor::new_cmd(cmd.as_any().encode_to_vec());
+        let info = service
+            .get_flight_info(Request::new(descriptor))
+            .await
+            .unwrap()
+            .into_inner();
+        let ticket = info.endpoint[0].ticket.clone().unwrap();
+        let expected_schema = info.try_decode_schema().unwrap();
+
+        let stream = service.do_get(Request::new(ticket)).await.unwrap();
+        let batches: Vec<_> = FlightRecordBatchStream::new_from_flight_data(
+            stream.into_inner().map_err(FlightError::from),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+        assert_eq!(batches[0].schema().as_ref(), &expected_schema);
+        pretty_format_batches(&batches).unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_default_metadata() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let mut sql_info = SqlInfoDataBuilder::new();
+        sql_info.append(SqlInfo::FlightSqlServerName, "test");
+        let metadata = SqlMetadata::new()
+            .with_table("catalog", "schema", "table", "TABLE", schema)
+            .with_sql_info(sql_info.build().unwrap());
+        let service = TestService { metadata };
+
+        let tables = get(
+            &service,
+            CommandGetTables {
+                catalog: None,
+                db_schema_filter_pattern: None,
+                table_name_filter_pattern: None,
+                table_types: vec![],
+                include_schema: false,
+            },
+        )
+        .await;
+        let expected = [
+            "+--------------+----------------+------------+------------+",
+            "| catalog_name | db_schema_name | table_name | table_type |",
+            "+--------------+----------------+------------+------------+",
+            "| catalog      | schema         | table      | TABLE      |",
+            "+--------------+----------------+------------+------------+",
+        ];
+        assert_eq!(tables, expected.join("\n"));
+
+        let table_types = get(&service, CommandGetTableTypes {}).await;
+        let expected = [
+            "+------------+",
+            "| table_type |",
+            "+------------+",
+            "| TABLE      |",
+            "+------------+",
+        ];
+        assert_eq!(table_types, expected.join("\n"));
+
+        get(&service, CommandGetSqlInfo { info: vec![] }).await;
+
+        let descriptor = FlightDescriptor::new_cmd(
+            CommandGetXdbcTypeInfo { data_type: None }
+                .as_any()
+                .encode_to_vec(),
+        );
+        let err = service
+            .get_flight_info(Request::new(descriptor))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::Unimplemented);
+    }
+
+    #[test]
+    fn test_prepared_statement_registry() {
+        let registry = PreparedStatementRegistry::new();
+        let a = registry.insert("a");
+        let b = registry.insert("b");
+        assert_ne!(a, b);
+        assert_eq!(registry.len(), 2);
+
+        assert_eq!(*registry.get(&b).unwrap(), "b");
+        assert_eq!(*registry.remove(&a).unwrap(), "a");
+        let err = registry.get(&a).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+        assert_eq!(registry.len(), 1);
+    }
+}
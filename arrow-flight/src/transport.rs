@@ -0,0 +1,331 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Configurable transports for Flight clients
+//!
+//! [`FlightChannelBuilder`] creates a tonic [`Channel`] with timeouts, TLS
+//! and HTTP proxy settings, which can be passed to
+//! [`FlightClient::new`](crate::FlightClient::new) or
+//! `FlightSqlServiceClient::new`.
+//!
+//! Transports other than a [`Channel`], such as a gRPC-web client service
+//! for use in browsers, can be used with [`FlightClient`](crate::FlightClient)
+//! directly as it accepts any tonic [`GrpcService`].
+//!
+//! # Example
+//! ```no_run
+//! # async fn run() -> arrow_flight::error::Result<()> {
+//! use arrow_flight::transport::FlightChannelBuilder;
+//! use arrow_flight::FlightClient;
+//! use std::time::Duration;
+//!
+//! // Connect to the server via the HTTP proxy at `proxy.example.com:3128`
+//! let channel = FlightChannelBuilder::try_new("http://flight.example.com:8815")?
+//!     .with_connect_timeout(Duration::from_secs(5))
+//!     .try_with_http_proxy("http://proxy.example.com:3128")?
+//!     .with_proxy_basic_auth("user", "password")
+//!     .connect()
+//!     .await?;
+//!
+//! let mut client = FlightClient::new(channel);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`GrpcService`]: tonic::client::GrpcService
+
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+#[cfg(feature = "tls")]
+use tonic::transport::ClientTlsConfig;
+use tonic::{
+    codegen::{http::uri::Scheme, BoxFuture, Service, StdError},
+    transport::{Channel, Endpoint, Uri},
+};
+
+use crate::auth::basic_auth_value;
+use crate::error::{FlightError, Result};
+
+/// The maximum size of the response head returned by an HTTP proxy
+const MAX_PROXY_RESPONSE_SIZE: usize = 16 * 1024;
+
+/// Builds a tonic [`Channel`] for connecting to a Flight service
+///
+/// See the [module level documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct FlightChannelBuilder {
+    endpoint: Endpoint,
+    proxy: Option<HttpProxyConnector>,
+}
+
+impl FlightChannelBuilder {
+    /// Create a builder for a channel to the service at `uri`, such as
+    /// `http://localhost:8815`
+    pub fn try_new(uri: impl Into<String>) -> Result<Self> {
+        let endpoint = Endpoint::from_shared(uri.into()).map_err(transport_error)?;
+        Ok(Self::from_endpoint(endpoint))
+    }
+
+    /// Create a builder from an existing tonic [`Endpoint`], which allows
+    /// access to settings not exposed by this builder
+    pub fn from_endpoint(endpoint: Endpoint) -> Self {
+        Self {
+            endpoint,
+            proxy: None,
+        }
+    }
+
+    /// Return a reference to the underlying [`Endpoint`]
+    pub fn endpoint(&self) -> &Endpoint {
+        &self.endpoint
+    }
+
+    /// Set the timeout applied to each request
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.endpoint = self.endpoint.timeout(timeout);
+        self
+    }
+
+    /// Set the timeout for establishing a connection, including any
+    /// HTTP proxy tunnel
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.endpoint = self.endpoint.connect_timeout(timeout);
+        self
+    }
+
+    /// Set the TCP keepalive interval, or `None` to disable it
+    pub fn with_tcp_keepalive(mut self, keepalive: Option<Duration>) -> Self {
+        self.endpoint = self.endpoint.tcp_keepalive(keepalive);
+        self
+    }
+
+    /// Set the interval at which HTTP/2 keep alive pings are sent
+    pub fn with_http2_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.endpoint = self.endpoint.http2_keep_alive_interval(interval);
+        self
+    }
+
+    /// Set the `user-agent` header sent with each request
+    pub fn try_with_user_agent(mut self, user_agent: &str) -> Result<Self> {
+        self.endpoint = self
+            .endpoint
+            .user_agent(user_agent.to_string())
+            .map_err(transport_error)?;
+        Ok(self)
+    }
+
+    /// Set the TLS configuration, which is required for `https` URIs
+    #[cfg(feature = "tls")]
+    pub fn try_with_tls_config(mut self, config: ClientTlsConfig) -> Result<Self> {
+        self.endpoint = self.endpoint.tls_config(config).map_err(transport_error)?;
+        Ok(self)
+    }
+
+    /// Connect via the HTTP proxy at `uri`, such as `http://proxy:3128`
+    ///
+    /// The connection to the service is tunneled through the proxy using an
+    /// HTTP `CONNECT` request, and any TLS is negotiated end-to-end with the
+    /// service.
+    pub fn try_with_http_proxy(mut self, uri: impl Into<String>) -> Result<Self> {
+        let authorization = self.proxy.take().and_then(|p| p.authorization);
+        let mut proxy = HttpProxyConnector::try_new(uri)?;
+        proxy.authorization = authorization;
+        self.proxy = Some(proxy);
+        Ok(self)
+    }
+
+    /// Authenticate with the HTTP proxy using basic authentication
+    ///
+    /// Has no effect unless a proxy has been set with
+    /// [`Self::try_with_http_proxy`].
+    pub fn with_proxy_basic_auth(mut self, username: &str, password: &str) -> Self {
+        if let Some(proxy) = self.proxy.take() {
+            self.proxy = Some(proxy.with_basic_auth(username, password));
+        }
+        self
+    }
+
+    /// Connect to the service, returning an error if the connection
+    /// could not be established
+    pub async fn connect(self) -> Result<Channel> {
+        let channel = match self.proxy {
+            Some(proxy) => self.endpoint.connect_with_connector(proxy).await,
+            None => self.endpoint.connect().await,
+        };
+        channel.map_err(transport_error)
+    }
+
+    /// Return a [`Channel`] that connects to the service when first used
+    pub fn connect_lazy(self) -> Channel {
+        match self.proxy {
+            Some(proxy) => self.endpoint.connect_with_connector_lazy(proxy),
+            None => self.endpoint.connect_lazy(),
+        }
+    }
+}
+
+/// A connector that tunnels connections through an HTTP proxy using
+/// `CONNECT` requests
+///
+/// This is used by [`FlightChannelBuilder::try_with_http_proxy`], and can
+/// also be passed to [`Endpoint::connect_with_connector`] directly.
+#[derive(Debug, Clone)]
+pub struct HttpProxyConnector {
+    /// The `host:port` of the proxy
+    address: String,
+    /// Optional value of the `proxy-authorization` header
+    authorization: Option<String>,
+}
+
+impl HttpProxyConnector {
+    /// Create a connector for the HTTP proxy at `uri`
+    pub fn try_new(uri: impl Into<String>) -> Result<Self> {
+        let uri: Uri = uri
+            .into()
+            .parse()
+            .map_err(|e| FlightError::ExternalError(Box::new(e)))?;
+
+        if uri.scheme().map(|s| s != &Scheme::HTTP).unwrap_or(false) {
+            return Err(FlightError::NotYetImplemented(format!(
+                "Unsupported proxy scheme in {uri}, only http proxies are supported"
+            )));
+        }
+
+        let host = uri.host().ok_or_else(|| {
+            FlightError::ProtocolError(format!("Missing host in proxy uri {uri}"))
+        })?;
+        let port = uri.port_u16().unwrap_or(80);
+
+        Ok(Self {
+            address: format!("{host}:{port}"),
+            authorization: None,
+        })
+    }
+
+    /// Authenticate with the proxy using basic authentication
+    pub fn with_basic_auth(mut self, username: &str, password: &str) -> Self {
+        self.authorization = Some(basic_auth_value(username, password));
+        self
+    }
+
+    /// Open a connection to the proxy and request a tunnel to `uri`
+    async fn tunnel(self, uri: Uri) -> std::result::Result<TcpStream, StdError> {
+        let host = uri.host().ok_or("Missing host in uri")?;
+        let port = match uri.port_u16() {
+            Some(port) => port,
+            None if uri.scheme() == Some(&Scheme::HTTPS) => 443,
+            None => 80,
+        };
+        let target = format!("{host}:{port}");
+
+        let mut stream = TcpStream::connect(&self.address).await?;
+        stream.set_nodelay(true)?;
+
+        let mut request = format!("CONNECT {target} HTTP/1.1\r\nHost: {target}\r\n");
+        if let Some(authorization) = &self.authorization {
+            request.push_str(&format!("Proxy-Authorization: {authorization}\r\n"));
+        }
+        request.push_str("\r\n");
+        stream.write_all(request.as_bytes()).await?;
+
+        // Read one byte at a time so as not to consume data sent
+        // through the tunnel after the response head
+        let mut head = Vec::with_capacity(128);
+        while !head.ends_with(b"\r\n\r\n") {
+            if head.len() >= MAX_PROXY_RESPONSE_SIZE {
+                return Err("HTTP proxy response exceeded maximum size".into());
+            }
+            head.push(stream.read_u8().await?);
+        }
+
+        let head = String::from_utf8_lossy(&head);
+        let status_line = head.lines().next().unwrap_or_default();
+        let status = status_line.split_whitespace().nth(1).unwrap_or_default();
+
+        // Any 2xx response indicates the tunnel has been established
+        match status.len() == 3 && status.starts_with('2') {
+            true => Ok(stream),
+            false => Err(format!(
+                "HTTP proxy failed to connect to {target}: {status_line}"
+            )
+            .into()),
+        }
+    }
+}
+
+impl Service<Uri> for HttpProxyConnector {
+    type Response = TcpStream;
+    type Error = StdError;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(
+        &mut self,
+        _cx: &mut Context<'_>,
+    ) -> Poll<std::result::Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        Box::pin(self.clone().tunnel(uri))
+    }
+}
+
+fn transport_error(e: tonic::transport::Error) -> FlightError {
+    FlightError::ExternalError(Box::new(e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proxy_uri() {
+        let proxy = HttpProxyConnector::try_new("http://proxy:3128").unwrap();
+        assert_eq!(proxy.address, "proxy:3128");
+
+        let proxy = HttpProxyConnector::try_new("http://proxy").unwrap();
+        assert_eq!(proxy.address, "proxy:80");
+
+        let err = HttpProxyConnector::try_new("https://proxy").unwrap_err();
+        assert!(err.to_string().contains("only http proxies"), "{err}");
+    }
+
+    #[test]
+    fn test_proxy_basic_auth() {
+        let builder = FlightChannelBuilder::try_new("http://localhost:8815")
+            .unwrap()
+            .with_proxy_basic_auth("user", "password");
+        assert!(builder.proxy.is_none());
+
+        // credentials are retained when the proxy is changed
+        let builder = builder
+            .try_with_http_proxy("http://proxy:3128")
+            .unwrap()
+            .with_proxy_basic_auth("user", "password")
+            .try_with_http_proxy("http://other:3128")
+            .unwrap();
+        let proxy = builder.proxy.unwrap();
+        assert_eq!(proxy.address, "other:3128");
+        assert_eq!(
+            proxy.authorization.unwrap(),
+            basic_auth_value("user", "password")
+        );
+    }
+}
@@ -22,21 +22,34 @@ mod common {
 }
 use arrow_array::{RecordBatch, UInt64Array};
 use arrow_flight::{
-    decode::FlightRecordBatchStream, encode::FlightDataEncoderBuilder,
-    error::FlightError, Action, ActionType, Criteria, Empty, FlightClient, FlightData,
-    FlightDescriptor, FlightInfo, HandshakeRequest, HandshakeResponse, PutResult, Ticket,
+    auth::{basic_auth_value, extract_basic_auth, extract_bearer_token},
+    decode::FlightRecordBatchStream,
+    encode::FlightDataEncoderBuilder,
+    error::FlightError,
+    flight_service_client::FlightServiceClient,
+    transport::FlightChannelBuilder,
+    Action, ActionType, Criteria, Empty, FlightClient, FlightData, FlightDescriptor,
+    FlightInfo, HandshakeRequest, HandshakeResponse, PutResult, Ticket,
 };
 use arrow_schema::{DataType, Field, Schema};
 use bytes::Bytes;
 use common::server::TestFlightServer;
 use futures::{Future, StreamExt, TryStreamExt};
-use tokio::{net::TcpListener, task::JoinHandle};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
 use tonic::{
     transport::{Channel, Uri},
     Status,
 };
 
-use std::{net::SocketAddr, sync::Arc, time::Duration};
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 const DEFAULT_TIMEOUT_SECONDS: u64 = 30;
 
@@ -66,6 +79,136 @@ async fn test_handshake() {
     .await;
 }
 
+#[tokio::test]
+async fn test_handshake_basic_auth() {
+    do_test(|test_server, mut client| async move {
+        let response_payload = Bytes::from("bar-response-payload");
+        let response = HandshakeResponse {
+            payload: response_payload.clone(),
+            protocol_version: 0,
+        };
+
+        test_server.set_handshake_response(Ok(response));
+        let response = client
+            .handshake_basic_auth("user", "password")
+            .await
+            .unwrap();
+        assert_eq!(response, response_payload);
+
+        let metadata = test_server.take_last_request_metadata().unwrap();
+        let credentials = extract_basic_auth(&metadata).unwrap();
+        assert_eq!(
+            credentials,
+            Some(("user".to_string(), "password".to_string()))
+        );
+
+        // subsequent requests use the bearer token returned by the server
+        test_server.set_list_actions_response(vec![]);
+        let _: Vec<_> = client
+            .list_actions()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+
+        let metadata = test_server.take_last_request_metadata().unwrap();
+        let token = extract_bearer_token(&metadata).unwrap();
+        assert_eq!(token.as_deref(), Some("token-for-user"));
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_http_proxy() {
+    let test_server = TestFlightServer::new();
+    let fixture = TestFixture::new(&test_server).await;
+    let proxy = TestProxy::new().await;
+
+    let channel = FlightChannelBuilder::try_new(format!("http://{}", fixture.addr))
+        .unwrap()
+        .with_timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECONDS))
+        .try_with_http_proxy(format!("http://{}", proxy.addr))
+        .unwrap()
+        .with_proxy_basic_auth("proxy-user", "proxy-password")
+        .connect()
+        .await
+        .unwrap();
+    let mut client = FlightClient::new(channel);
+
+    let response_payload = Bytes::from("bar-response-payload");
+    test_server.set_handshake_response(Ok(HandshakeResponse {
+        payload: response_payload.clone(),
+        protocol_version: 0,
+    }));
+    let response = client.handshake("foo-request-payload").await.unwrap();
+    assert_eq!(response, response_payload);
+
+    let request = proxy.requests.lock().unwrap()[0].clone();
+    let mut lines = request.lines();
+    assert_eq!(
+        lines.next().unwrap(),
+        format!("CONNECT {} HTTP/1.1", fixture.addr)
+    );
+    let authorization = basic_auth_value("proxy-user", "proxy-password");
+    assert!(
+        lines.any(|l| l == format!("Proxy-Authorization: {authorization}")),
+        "{request}"
+    );
+
+    fixture.shutdown_and_wait().await
+}
+
+#[tokio::test]
+async fn test_http_proxy_rejected() {
+    let test_server = TestFlightServer::new();
+    let fixture = TestFixture::new(&test_server).await;
+    let proxy = TestProxy::new().await;
+    *proxy.status.lock().unwrap() = "407 Proxy Authentication Required";
+
+    let err = FlightChannelBuilder::try_new(format!("http://{}", fixture.addr))
+        .unwrap()
+        .try_with_http_proxy(format!("http://{}", proxy.addr))
+        .unwrap()
+        .connect()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, FlightError::ExternalError(_)), "{err}");
+
+    fixture.shutdown_and_wait().await
+}
+
+#[tokio::test]
+async fn test_custom_transport() {
+    let test_server = TestFlightServer::new();
+    let fixture = TestFixture::new(&test_server).await;
+
+    // any GrpcService can be used, here an intercepted channel
+    let inner = FlightServiceClient::with_interceptor(
+        fixture.channel().await,
+        |mut request: tonic::Request<()>| {
+            request
+                .metadata_mut()
+                .insert("x-interceptor", "intercepted".parse().unwrap());
+            Ok(request)
+        },
+    );
+    let mut client = FlightClient::new_from_inner(inner);
+
+    let response_payload = Bytes::from("bar-response-payload");
+    test_server.set_handshake_response(Ok(HandshakeResponse {
+        payload: response_payload.clone(),
+        protocol_version: 0,
+    }));
+    let response = client.handshake("foo-request-payload").await.unwrap();
+    assert_eq!(response, response_payload);
+
+    let metadata = test_server.take_last_request_metadata().unwrap();
+    assert_eq!(metadata.get("x-interceptor").unwrap(), "intercepted");
+
+    fixture.shutdown_and_wait().await
+}
+
 #[tokio::test]
 async fn test_handshake_error() {
     do_test(|test_server, mut client| async move {
@@ -900,6 +1043,60 @@ fn expect_status(error: FlightError, expected: Status) {
     );
 }
 
+/// A minimal HTTP proxy supporting `CONNECT` requests
+struct TestProxy {
+    /// Address the proxy is listening on
+    addr: SocketAddr,
+
+    /// The request heads received by the proxy
+    requests: Arc<Mutex<Vec<String>>>,
+
+    /// The status with which to respond to requests
+    status: Arc<Mutex<&'static str>>,
+}
+
+impl TestProxy {
+    async fn new() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests: Arc<Mutex<Vec<String>>> = Default::default();
+        let status = Arc::new(Mutex::new("200 Connection established"));
+
+        let (captured, response) = (Arc::clone(&requests), Arc::clone(&status));
+        tokio::task::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut head = Vec::new();
+                while !head.ends_with(b"\r\n\r\n") {
+                    head.push(stream.read_u8().await.unwrap());
+                }
+                let head = String::from_utf8(head).unwrap();
+                let target = head.split_whitespace().nth(1).unwrap().to_string();
+                captured.lock().unwrap().push(head);
+
+                let status = *response.lock().unwrap();
+                let reply = format!("HTTP/1.1 {status}\r\n\r\n");
+                stream.write_all(reply.as_bytes()).await.unwrap();
+                if !status.starts_with("200") {
+                    continue;
+                }
+
+                let mut upstream = TcpStream::connect(target).await.unwrap();
+                tokio::task::spawn(async move {
+                    tokio::io::copy_bidirectional(&mut stream, &mut upstream)
+                        .await
+                        .ok();
+                });
+            }
+        });
+
+        Self {
+            addr,
+            requests,
+            status,
+        }
+    }
+}
+
 /// Creates and manages a running TestServer with a background task
 struct TestFixture {
     /// channel to send shutdown command
@@ -23,6 +23,7 @@ use futures::{stream::BoxStream, StreamExt, TryStreamExt};
 use tonic::{metadata::MetadataMap, Request, Response, Status, Streaming};
 
 use arrow_flight::{
+    auth::{bearer_auth_value, extract_basic_auth},
     encode::FlightDataEncoderBuilder,
     flight_service_server::{FlightService, FlightServiceServer},
     Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
@@ -273,6 +274,9 @@ impl FlightService for TestFlightServer {
         request: Request<Streaming<HandshakeRequest>>,
     ) -> Result<Response<Self::HandshakeStream>, Status> {
         self.save_metadata(&request);
+        // respond to basic authentication with a bearer token
+        let token = extract_basic_auth(request.metadata())?
+            .map(|(username, _)| bearer_auth_value(&format!("token-for-{username}")));
         let handshake_request = request.into_inner().message().await?.unwrap();
 
         let mut state = self.state.lock().expect("mutex not poisoned");
@@ -284,7 +288,13 @@ impl FlightService for TestFlightServer {
 
         // turn into a streaming response
         let output = futures::stream::iter(std::iter::once(Ok(response)));
-        Ok(Response::new(output.boxed()))
+        let mut response = Response::new(output.boxed());
+        if let Some(token) = token {
+            response
+                .metadata_mut()
+                .insert("authorization", token.parse().unwrap());
+        }
+        Ok(response)
     }
 
     async fn list_flights(
@@ -20,7 +20,10 @@
 use std::{collections::HashMap, sync::Arc};
 
 use arrow_array::types::Int32Type;
-use arrow_array::{ArrayRef, DictionaryArray, Float64Array, RecordBatch, UInt8Array};
+use arrow_array::{
+    ArrayRef, DictionaryArray, FixedSizeBinaryArray, Float64Array, RecordBatch,
+    StringArray, UInt8Array,
+};
 use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::flight_descriptor::DescriptorType;
 use arrow_flight::FlightDescriptor;
@@ -28,7 +31,9 @@ use arrow_flight::{
     decode::{DecodedPayload, FlightDataDecoder, FlightRecordBatchStream},
     encode::FlightDataEncoderBuilder,
     error::FlightError,
+    exchange::{ExchangeDecoder, ExchangeEncoderBuilder, ExchangeMessage},
 };
+use arrow_schema::extension::{Json, Uuid};
 use arrow_schema::{DataType, Field, Fields, Schema, SchemaRef};
 use bytes::Bytes;
 use futures::{StreamExt, TryStreamExt};
@@ -77,6 +82,26 @@ async fn test_schema_metadata() {
     roundtrip(vec![batch]).await;
 }
 
+#[tokio::test]
+async fn test_extension_type_metadata() {
+    let uuid =
+        Field::new("uuid", DataType::FixedSizeBinary(16), true).with_extension_type(Uuid);
+    let json =
+        Field::new("json", DataType::Utf8, true).with_extension_type(Json::default());
+    let schema = Arc::new(Schema::new(vec![uuid, json]));
+
+    let uuids = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+        vec![Some([1_u8; 16]), None, Some([2_u8; 16])].into_iter(),
+        16,
+    )
+    .unwrap();
+    let json = StringArray::from(vec![Some(r#"{"a":1}"#), Some("[]"), None]);
+    let batch =
+        RecordBatch::try_new(schema, vec![Arc::new(uuids), Arc::new(json)]).unwrap();
+
+    roundtrip(vec![batch]).await;
+}
+
 #[tokio::test]
 async fn test_primitive_many() {
     roundtrip(vec![
@@ -274,6 +299,43 @@ async fn test_max_message_size_fuzz() {
     }
 }
 
+#[tokio::test]
+async fn test_target_batch_rows() {
+    let input = vec![make_primitive_batch(100), make_primitive_batch(50)];
+
+    // split into many small messages, then reassemble
+    let encoder = FlightDataEncoderBuilder::default().with_max_flight_data_size(64);
+    let input_batch_stream = futures::stream::iter(input.clone()).map(Ok);
+    let encode_stream = encoder.build(input_batch_stream);
+
+    let decode_stream = FlightRecordBatchStream::new_from_flight_data(encode_stream)
+        .with_target_batch_rows(120);
+    let output: Vec<_> = decode_stream.try_collect().await.expect("encode / decode");
+
+    let num_rows: Vec<_> = output.iter().map(|b| b.num_rows()).collect();
+    assert_eq!(num_rows.len(), 2);
+    assert!(num_rows[0] >= 120, "{num_rows:?}");
+    assert_eq!(num_rows.iter().sum::<usize>(), 150);
+
+    let a = pretty_format_batches(&input).unwrap().to_string();
+    let b = pretty_format_batches(&output).unwrap().to_string();
+    assert_eq!(a, b);
+}
+
+#[cfg(feature = "ipc_compression")]
+#[tokio::test]
+async fn test_compression() {
+    use arrow_ipc::CompressionType;
+
+    for compression in [CompressionType::LZ4_FRAME, CompressionType::ZSTD] {
+        let encoder = FlightDataEncoderBuilder::default()
+            .try_with_compression(Some(compression))
+            .unwrap();
+        let input = vec![make_primitive_batch(5), make_primitive_batch(200)];
+        roundtrip_with_encoder(encoder, input.clone(), input).await;
+    }
+}
+
 #[tokio::test]
 async fn test_mismatched_record_batch_schema() {
     // send 2 batches with different schemas
@@ -404,6 +466,65 @@ async fn test_mismatched_schema_message() {
 /// Example:
 /// i: 0, 1, None, 3, 4
 /// f: 5.0, 4.0, None, 2.0, 1.0
+#[tokio::test]
+async fn test_exchange_roundtrip() {
+    let primitive = make_primitive_batch(5);
+    let dictionary = make_dictionary_batch(5);
+    let messages = vec![
+        ExchangeMessage::Metadata(Bytes::from("start")),
+        ExchangeMessage::Schema(primitive.schema()),
+        ExchangeMessage::RecordBatch(primitive.clone()),
+        ExchangeMessage::Metadata(Bytes::from("switch")),
+        // change the schema mid-stream
+        ExchangeMessage::RecordBatch(dictionary.clone()),
+        // replace the dictionary
+        ExchangeMessage::RecordBatch(make_dictionary_batch(9)),
+        ExchangeMessage::RecordBatch(primitive.clone()),
+    ];
+
+    let descriptor = FlightDescriptor::new_cmd("exchange");
+    let input = futures::stream::iter(messages.clone().into_iter().map(Ok));
+    let flight_data: Vec<_> = ExchangeEncoderBuilder::new()
+        .with_flight_descriptor(Some(descriptor.clone()))
+        .build(input)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(flight_data[0].flight_descriptor, Some(descriptor));
+
+    let decoded: Vec<_> = ExchangeDecoder::new_from_flight_data(futures::stream::iter(
+        flight_data.into_iter().map(Ok),
+    ))
+    .try_collect()
+    .await
+    .unwrap();
+
+    // a schema message precedes batches with a different schema
+    let mut expected = messages;
+    expected.insert(4, ExchangeMessage::Schema(dictionary.schema()));
+    expected.insert(7, ExchangeMessage::Schema(primitive.schema()));
+    assert_eq!(decoded, expected);
+}
+
+#[tokio::test]
+async fn test_exchange_decode_attached_metadata() {
+    let batch = make_primitive_batch(3);
+    let encode_stream = FlightDataEncoderBuilder::new()
+        .with_metadata(Bytes::from("meta"))
+        .build(futures::stream::iter(vec![Ok(batch.clone())]));
+
+    let decoded: Vec<_> = ExchangeDecoder::new_from_flight_data(encode_stream)
+        .try_collect()
+        .await
+        .unwrap();
+    let expected = vec![
+        ExchangeMessage::Schema(batch.schema()),
+        ExchangeMessage::Metadata(Bytes::from("meta")),
+        ExchangeMessage::RecordBatch(batch),
+    ];
+    assert_eq!(decoded, expected);
+}
+
 fn make_primitive_batch(num_rows: usize) -> RecordBatch {
     let i: UInt8Array = (0..num_rows)
         .map(|i| {
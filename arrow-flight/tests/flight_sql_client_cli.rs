@@ -15,15 +15,22 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::{net::SocketAddr, pin::Pin, sync::Arc, time::Duration};
+use std::{
+    net::SocketAddr,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
 use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray};
+use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::{
     flight_service_server::{FlightService, FlightServiceServer},
     sql::{
-        server::FlightSqlService, ActionBeginSavepointRequest,
-        ActionBeginSavepointResult, ActionBeginTransactionRequest,
-        ActionBeginTransactionResult, ActionCancelQueryRequest, ActionCancelQueryResult,
+        client::FlightSqlServiceClient, server::FlightSqlService,
+        ActionBeginSavepointRequest, ActionBeginSavepointResult,
+        ActionBeginTransactionRequest, ActionBeginTransactionResult,
+        ActionCancelQueryRequest, ActionCancelQueryResult,
         ActionClosePreparedStatementRequest, ActionCreatePreparedStatementRequest,
         ActionCreatePreparedStatementResult, ActionCreatePreparedSubstraitPlanRequest,
         ActionEndSavepointRequest, ActionEndTransactionRequest, Any, CommandGetCatalogs,
@@ -40,16 +47,16 @@ use arrow_flight::{
 };
 use arrow_schema::{ArrowError, DataType, Field, Schema};
 use assert_cmd::Command;
-use futures::Stream;
+use futures::{Stream, TryStreamExt};
 use prost::Message;
 use tokio::{net::TcpListener, task::JoinHandle};
-use tonic::{Request, Response, Status, Streaming};
+use tonic::{transport::Channel, Request, Response, Status, Streaming};
 
 const QUERY: &str = "SELECT * FROM table;";
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_simple() {
-    let test_server = FlightSqlServiceImpl {};
+    let test_server = FlightSqlServiceImpl::default();
     let fixture = TestFixture::new(&test_server).await;
     let addr = fixture.addr;
 
@@ -87,11 +94,152 @@ async fn test_simple() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query() {
+    let test_server = FlightSqlServiceImpl::default();
+    let fixture = TestFixture::new(&test_server).await;
+
+    let channel = Channel::from_shared(format!("http://{}", fixture.addr))
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut client = FlightSqlServiceClient::new(channel);
+    client.set_endpoint_concurrency(2);
+
+    // The results are split across two endpoints
+    let batches: Vec<_> = client
+        .query(QUERY.to_string(), None)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    drop(client);
+
+    fixture.shutdown_and_wait().await;
+
+    assert_eq!(batches.len(), 2);
+    assert_eq!(
+        pretty_format_batches(&batches).unwrap().to_string(),
+        "+--------------+-----------+\
+        \n| field_string | field_int |\
+        \n+--------------+-----------+\
+        \n| Hello        | 42        |\
+        \n| lovely       |           |\
+        \n| FlightSQL!   | 1337      |\
+        \n+--------------+-----------+",
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_retries() {
+    // The second endpoint is served by another server
+    let other_server = FlightSqlServiceImpl::default();
+    let other_fixture = TestFixture::new(&other_server).await;
+
+    let test_server = FlightSqlServiceImpl {
+        part_2_location: Some(format!("grpc://{}", other_fixture.addr)),
+        ..Default::default()
+    };
+    let fixture = TestFixture::new(&test_server).await;
+
+    let channel = Channel::from_shared(format!("http://{}", fixture.addr))
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut client = FlightSqlServiceClient::new(channel);
+    client.set_header("database", "test");
+    client.set_header("Authorization", "Bearer header");
+    client.handshake("user", "password").await.unwrap();
+
+    // Fail the request for the flight info as unavailable, and the request for
+    // the first endpoint as unauthenticated
+    {
+        let mut state = test_server.state.lock().unwrap();
+        state.unavailable = 2;
+        state.unauthenticated = 1;
+    }
+
+    let start = Instant::now();
+    let batches: Vec<_> = client
+        .query(QUERY.to_string(), None)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    // Retried after 100ms and then 200ms
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert_eq!(batches.len(), 2);
+
+    // The token obtained by the new handshake is used by subsequent requests
+    client.execute(QUERY.to_string(), None).await.unwrap();
+    drop(client);
+
+    fixture.shutdown_and_wait().await;
+    other_fixture.shutdown_and_wait().await;
+
+    let state = test_server.state.lock().unwrap();
+    assert_eq!(state.handshakes, 2);
+    assert_eq!(
+        state.authorization,
+        [
+            // get_flight_info, failing as unavailable twice
+            "Bearer token_1",
+            "Bearer token_1",
+            "Bearer token_1",
+            // do_get for the first endpoint, failing as unauthenticated
+            "Bearer token_1",
+            "Bearer token_2",
+            // execute
+            "Bearer token_2",
+        ]
+    );
+
+    // Credentials are not sent to the other location
+    let other_state = other_server.state.lock().unwrap();
+    assert_eq!(other_state.handshakes, 0);
+    assert_eq!(other_state.authorization, [""]);
+    assert_eq!(other_state.headers, ["test"]);
+}
+
 /// All tests must complete within this many seconds or else the test server is shutdown
 const DEFAULT_TIMEOUT_SECONDS: u64 = 30;
 
-#[derive(Clone)]
-pub struct FlightSqlServiceImpl {}
+#[derive(Clone, Default)]
+pub struct FlightSqlServiceImpl {
+    /// The location of the second endpoint of query results, if not this server
+    part_2_location: Option<String>,
+    state: Arc<Mutex<TestState>>,
+}
+
+/// The requests received by a [`FlightSqlServiceImpl`], and the requests to fail
+#[derive(Debug, Default)]
+struct TestState {
+    /// The number of handshakes
+    handshakes: usize,
+    /// The authorization header of each request for query results
+    authorization: Vec<String>,
+    /// The database header of each request for query results
+    headers: Vec<String>,
+    /// The number of subsequent requests for flight info to fail as unavailable
+    unavailable: usize,
+    /// The number of subsequent requests for data to fail as unauthenticated
+    unauthenticated: usize,
+}
+
+impl TestState {
+    fn record<T>(&mut self, request: &Request<T>) {
+        let header = |key| match request.metadata().get(key) {
+            Some(value) => value.to_str().unwrap().to_string(),
+            None => String::new(),
+        };
+        self.authorization.push(header("authorization"));
+        self.headers.push(header("database"));
+    }
+}
 
 impl FlightSqlServiceImpl {
     /// Return an [`FlightServiceServer`] that can be used with a
@@ -129,14 +277,32 @@ impl FlightSqlService for FlightSqlServiceImpl {
         Response<Pin<Box<dyn Stream<Item = Result<HandshakeResponse, Status>> + Send>>>,
         Status,
     > {
-        Err(Status::unimplemented("do_handshake not implemented"))
+        let mut state = self.state.lock().unwrap();
+        state.handshakes += 1;
+        let token = format!("Bearer token_{}", state.handshakes);
+
+        let stream: Pin<Box<dyn Stream<Item = _> + Send>> =
+            Box::pin(futures::stream::empty());
+        let mut resp = Response::new(stream);
+        resp.metadata_mut()
+            .insert("authorization", token.parse().unwrap());
+        Ok(resp)
     }
 
     async fn do_get_fallback(
         &self,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
         message: Any,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        {
+            let mut state = self.state.lock().unwrap();
+            state.record(&request);
+            if state.unauthenticated > 0 {
+                state.unauthenticated -= 1;
+                return Err(Status::unauthenticated("token expired"));
+            }
+        }
+
         let part = message.unpack::<FetchResults>().unwrap().unwrap().handle;
         let batch = Self::fake_result().unwrap();
         let batch = match part.as_str() {
@@ -160,9 +326,28 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn get_flight_info_statement(
         &self,
         query: CommandStatementQuery,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         assert_eq!(query.query, QUERY);
+        {
+            let mut state = self.state.lock().unwrap();
+            state.record(&request);
+            if state.unavailable > 0 {
+                state.unavailable -= 1;
+                return Err(Status::unavailable("try again"));
+            }
+        }
+
+        let mut part_2 = FlightEndpoint::new().with_ticket(Ticket::new(
+            FetchResults {
+                handle: String::from("part_2"),
+            }
+            .as_any()
+            .encode_to_vec(),
+        ));
+        if let Some(location) = &self.part_2_location {
+            part_2 = part_2.with_location(location);
+        }
 
         let batch = Self::fake_result().unwrap();
 
@@ -178,15 +363,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
                     .encode_to_vec(),
                 )),
             )
-            .with_endpoint(
-                FlightEndpoint::new().with_ticket(Ticket::new(
-                    FetchResults {
-                        handle: String::from("part_2"),
-                    }
-                    .as_any()
-                    .encode_to_vec(),
-                )),
-            )
+            .with_endpoint(part_2)
             .with_total_records(batch.num_rows() as i64)
             .with_total_bytes(batch.get_array_memory_size() as i64)
             .with_ordered(false);
@@ -60,7 +60,11 @@ pub fn data_type_from_json(json: &serde_json::Value) -> Result<DataType> {
                     _ => 128, // Default bit width
                 };
 
-                if bit_width == 128 {
+                if bit_width == 32 {
+                    Ok(DataType::Decimal32(precision, scale))
+                } else if bit_width == 64 {
+                    Ok(DataType::Decimal64(precision, scale))
+                } else if bit_width == 128 {
                     Ok(DataType::Decimal128(precision, scale))
                 } else if bit_width == 256 {
                     Ok(DataType::Decimal256(precision, scale))
@@ -192,6 +196,14 @@ pub fn data_type_from_json(json: &serde_json::Value) -> Result<DataType> {
                 // return a largelist with any type as its child isn't defined in the map
                 Ok(DataType::LargeList(default_field))
             }
+            Some(s) if s == "listview" => {
+                // return a list view with any type as its child isn't defined in the map
+                Ok(DataType::ListView(default_field))
+            }
+            Some(s) if s == "largelistview" => {
+                // return a large list view with any type as its child isn't defined in the map
+                Ok(DataType::LargeListView(default_field))
+            }
             Some(s) if s == "fixedsizelist" => {
                 // return a list with any type as its child isn't defined in the map
                 if let Some(Value::Number(size)) = map.get("listSize") {
@@ -288,6 +300,8 @@ pub fn data_type_to_json(data_type: &DataType) -> serde_json::Value {
         DataType::Union(_, _) => json!({"name": "union"}),
         DataType::List(_) => json!({ "name": "list"}),
         DataType::LargeList(_) => json!({ "name": "largelist"}),
+        DataType::ListView(_) => json!({ "name": "listview"}),
+        DataType::LargeListView(_) => json!({ "name": "largelistview"}),
         DataType::FixedSizeList(_, length) => {
             json!({"name":"fixedsizelist", "listSize": length})
         }
@@ -341,6 +355,12 @@ pub fn data_type_to_json(data_type: &DataType) -> serde_json::Value {
             TimeUnit::Nanosecond => "NANOSECOND",
         }}),
         DataType::Dictionary(_, _) => json!({ "name": "dictionary"}),
+        DataType::Decimal32(precision, scale) => {
+            json!({"name": "decimal", "precision": precision, "scale": scale, "bitWidth": 32})
+        }
+        DataType::Decimal64(precision, scale) => {
+            json!({"name": "decimal", "precision": precision, "scale": scale, "bitWidth": 64})
+        }
         DataType::Decimal128(precision, scale) => {
             json!({"name": "decimal", "precision": precision, "scale": scale, "bitWidth": 128})
         }
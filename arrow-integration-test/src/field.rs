@@ -117,6 +117,8 @@ pub fn field_from_json(json: &serde_json::Value) -> Result<Field> {
             let data_type = match data_type {
                 DataType::List(_)
                 | DataType::LargeList(_)
+                | DataType::ListView(_)
+                | DataType::LargeListView(_)
                 | DataType::FixedSizeList(_, _) => match map.get("children") {
                     Some(Value::Array(values)) => {
                         if values.len() != 1 {
@@ -131,6 +133,12 @@ pub fn field_from_json(json: &serde_json::Value) -> Result<Field> {
                             DataType::LargeList(_) => DataType::LargeList(Arc::new(
                                 field_from_json(&values[0])?,
                             )),
+                            DataType::ListView(_) => {
+                                DataType::ListView(Arc::new(field_from_json(&values[0])?))
+                            }
+                            DataType::LargeListView(_) => DataType::LargeListView(
+                                Arc::new(field_from_json(&values[0])?),
+                            ),
                             DataType::FixedSizeList(_, int) => DataType::FixedSizeList(
                                 Arc::new(field_from_json(&values[0])?),
                                 int,
@@ -274,6 +282,8 @@ pub fn field_to_json(field: &Field) -> serde_json::Value {
         }
         DataType::List(field)
         | DataType::LargeList(field)
+        | DataType::ListView(field)
+        | DataType::LargeListView(field)
         | DataType::FixedSizeList(field, _)
         | DataType::Map(field, _) => vec![field_to_json(field)],
         _ => vec![],
@@ -0,0 +1,689 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Provides `async` API for reading the Arrow IPC stream and file formats
+//!
+//! The API is similar to the [`sync` API](crate::reader), with [`AsyncStreamReader`]
+//! reading from any [`AsyncRead`], and [`AsyncFileReader`] reading from any
+//! [`AsyncFileSource`], which is implemented for any [`AsyncRead`] + [`AsyncSeek`],
+//! and for object storage with the `object_store` feature.
+//!
+//! ```
+//! # #[tokio::main(flavor="current_thread")]
+//! # async fn main() {
+//! #
+//! use std::sync::Arc;
+//! use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+//! use arrow_ipc::async_reader::AsyncStreamReader;
+//! use arrow_ipc::writer::StreamWriter;
+//! use futures::TryStreamExt;
+//!
+//! let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+//! let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+//!
+//! let mut writer = StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+//! writer.write(&batch).unwrap();
+//! let data = writer.into_inner().unwrap();
+//!
+//! let source = std::io::Cursor::new(data);
+//! let reader = AsyncStreamReader::try_new(source, None).await.unwrap();
+//! let batches: Vec<_> = reader.into_stream().try_collect().await.unwrap();
+//! assert_eq!(batches, vec![batch]);
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::io::SeekFrom;
+use std::ops::Range;
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_buffer::{Buffer, MutableBuffer};
+use arrow_schema::{ArrowError, Schema, SchemaRef};
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use futures::{FutureExt, StreamExt};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+
+use crate::reader::{
+    checked_length, footer_custom_metadata, read_block, read_dictionary,
+    read_record_batch,
+};
+use crate::{MetadataVersion, ARROW_MAGIC, CONTINUATION_MARKER};
+
+#[cfg(feature = "object_store")]
+mod store;
+
+#[cfg(feature = "object_store")]
+pub use store::IpcObjectReader;
+
+/// Parses the [`crate::Message`] in `data`
+fn parse_message(data: &[u8]) -> Result<crate::Message<'_>, ArrowError> {
+    crate::root_as_message(data).map_err(|err| {
+        ArrowError::IoError(format!("Unable to get root as message: {err:?}"))
+    })
+}
+
+/// Converts [`Bytes`] into a [`Buffer`] without copying
+fn bytes_to_buffer(bytes: Bytes) -> Buffer {
+    let ptr = NonNull::from(bytes.as_ref()).cast::<u8>();
+    let len = bytes.len();
+    // SAFETY: ptr is valid for len bytes for as long as bytes is alive
+    unsafe { Buffer::from_custom_allocation(ptr, len, Arc::new(bytes)) }
+}
+
+/// Reads the metadata of the next message in `reader`, returning `None` if the
+/// stream has ended
+async fn read_message_metadata<R: AsyncRead + Unpin>(
+    reader: &mut R,
+) -> Result<Option<Vec<u8>>, ArrowError> {
+    let mut meta_size: [u8; 4] = [0; 4];
+    match reader.read_exact(&mut meta_size).await {
+        Ok(_) => {}
+        // Handle EOF without the "0xFFFFFFFF 0x00000000"
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    }
+
+    // If a continuation marker is encountered, skip over it and read
+    // the size from the next four bytes.
+    if meta_size == CONTINUATION_MARKER {
+        reader.read_exact(&mut meta_size).await?;
+    }
+    let meta_len = i32::from_le_bytes(meta_size);
+    if meta_len == 0 {
+        return Ok(None);
+    }
+    let meta_len = usize::try_from(meta_len).map_err(|_| {
+        ArrowError::IoError(format!("Invalid IPC message length {meta_len}"))
+    })?;
+
+    let mut meta_buffer = vec![0; meta_len];
+    reader.read_exact(&mut meta_buffer).await?;
+    Ok(Some(meta_buffer))
+}
+
+/// Arrow Stream reader for an [`AsyncRead`]
+///
+/// Reads the messages of the stream as they are requested, and so the rate of
+/// reading is determined by the consumer of the batches
+pub struct AsyncStreamReader<R> {
+    /// Stream reader
+    reader: R,
+
+    /// The schema that is read from the stream's first message
+    schema: SchemaRef,
+
+    /// Dictionaries for each schema field, which may be replaced or appended
+    /// to in the streaming format
+    dictionaries_by_id: HashMap<i64, ArrayRef>,
+
+    /// An indicator of whether the stream is complete
+    finished: bool,
+
+    /// Optional projection
+    projection: Option<(Vec<usize>, Schema)>,
+}
+
+impl<R> std::fmt::Debug for AsyncStreamReader<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncStreamReader")
+            .field("schema", &self.schema)
+            .field("dictionaries_by_id", &self.dictionaries_by_id)
+            .field("finished", &self.finished)
+            .field("projection", &self.projection)
+            .finish()
+    }
+}
+
+impl<R: AsyncRead + Unpin + Send> AsyncStreamReader<R> {
+    /// Try to create a new stream reader, reading the schema from the first
+    /// message of the stream
+    pub async fn try_new(
+        mut reader: R,
+        projection: Option<Vec<usize>>,
+    ) -> Result<Self, ArrowError> {
+        let meta_buffer = read_message_metadata(&mut reader).await?.ok_or_else(|| {
+            ArrowError::IoError("Expected schema message, found end of stream".into())
+        })?;
+        let message = parse_message(&meta_buffer)?;
+        // message header is a Schema, so read it
+        let ipc_schema = message.header_as_schema().ok_or_else(|| {
+            ArrowError::IoError("Unable to read IPC message as schema".to_string())
+        })?;
+        let schema = crate::convert::try_fb_to_schema(ipc_schema)?;
+
+        let projection = match projection {
+            Some(projection_indices) => {
+                let schema = schema.project(&projection_indices)?;
+                Some((projection_indices, schema))
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            reader,
+            schema: Arc::new(schema),
+            dictionaries_by_id: HashMap::new(),
+            finished: false,
+            projection,
+        })
+    }
+
+    /// Return the schema of the stream
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Check if the stream is finished
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Read the next [`RecordBatch`] from the stream, returning `None` once
+    /// the stream has ended
+    pub async fn next_batch(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        while !self.finished {
+            let meta_buffer = match read_message_metadata(&mut self.reader).await? {
+                Some(meta_buffer) => meta_buffer,
+                None => {
+                    self.finished = true;
+                    break;
+                }
+            };
+            let message = parse_message(&meta_buffer)?;
+
+            // read the block that makes up the message into a buffer
+            let body_len = checked_length("body", message.bodyLength(), usize::MAX)?;
+            let mut buf = MutableBuffer::from_len_zeroed(body_len);
+            self.reader.read_exact(&mut buf).await?;
+            let buf = buf.into();
+
+            match message.header_type() {
+                crate::MessageHeader::RecordBatch => {
+                    let batch = message.header_as_record_batch().ok_or_else(|| {
+                        ArrowError::IoError(
+                            "Unable to read IPC message as record batch".to_string(),
+                        )
+                    })?;
+                    return read_record_batch(
+                        &buf,
+                        batch,
+                        self.schema(),
+                        &self.dictionaries_by_id,
+                        self.projection.as_ref().map(|x| x.0.as_ref()),
+                        &message.version(),
+                    )
+                    .map(Some);
+                }
+                crate::MessageHeader::DictionaryBatch => {
+                    let batch = message.header_as_dictionary_batch().ok_or_else(|| {
+                        ArrowError::IoError(
+                            "Unable to read IPC message as dictionary batch".to_string(),
+                        )
+                    })?;
+                    read_dictionary(
+                        &buf,
+                        batch,
+                        &self.schema,
+                        &mut self.dictionaries_by_id,
+                        &message.version(),
+                    )?;
+                }
+                crate::MessageHeader::NONE => break,
+                t => {
+                    return Err(ArrowError::IoError(format!(
+                        "Reading types other than record batches not yet supported, unable to read {t:?}"
+                    )))
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Convert this reader into a [`Stream`](futures::Stream) of [`RecordBatch`]
+    pub fn into_stream(self) -> BoxStream<'static, Result<RecordBatch, ArrowError>>
+    where
+        R: 'static,
+    {
+        futures::stream::try_unfold(self, |mut reader| async move {
+            Ok(reader.next_batch().await?.map(|batch| (batch, reader)))
+        })
+        .boxed()
+    }
+
+    /// Gets a reference to the underlying reader
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Gets a mutable reference to the underlying reader
+    ///
+    /// It is inadvisable to directly read from the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Consumes this reader, returning the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+/// The asynchronous interface used by [`AsyncFileReader`] to read Arrow files
+///
+/// Implemented for any [`AsyncRead`] + [`AsyncSeek`], and for object storage with
+/// the `object_store` feature
+pub trait AsyncFileSource: Send {
+    /// Returns the size of the file in bytes
+    fn size(&mut self) -> BoxFuture<'_, Result<usize, ArrowError>>;
+
+    /// Retrieve the bytes in `range`
+    fn get_bytes(
+        &mut self,
+        range: Range<usize>,
+    ) -> BoxFuture<'_, Result<Bytes, ArrowError>>;
+}
+
+impl AsyncFileSource for Box<dyn AsyncFileSource> {
+    fn size(&mut self) -> BoxFuture<'_, Result<usize, ArrowError>> {
+        self.as_mut().size()
+    }
+
+    fn get_bytes(
+        &mut self,
+        range: Range<usize>,
+    ) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+        self.as_mut().get_bytes(range)
+    }
+}
+
+impl<T: AsyncRead + AsyncSeek + Unpin + Send> AsyncFileSource for T {
+    fn size(&mut self) -> BoxFuture<'_, Result<usize, ArrowError>> {
+        async move { Ok(self.seek(SeekFrom::End(0)).await? as usize) }.boxed()
+    }
+
+    fn get_bytes(
+        &mut self,
+        range: Range<usize>,
+    ) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+        async move {
+            self.seek(SeekFrom::Start(range.start as u64)).await?;
+
+            let mut buffer = vec![0; range.len()];
+            self.read_exact(&mut buffer).await?;
+            Ok(buffer.into())
+        }
+        .boxed()
+    }
+}
+
+/// Fetches the message and body of `block` from `source`, returning a [`Buffer`]
+/// containing them, and the [`crate::Block`] locating them within it
+async fn fetch_block<R: AsyncFileSource>(
+    source: &mut R,
+    block: &crate::Block,
+) -> Result<(Buffer, crate::Block), ArrowError> {
+    let invalid = || ArrowError::IoError(format!("Invalid block {block:?}"));
+    let start = usize::try_from(block.offset()).map_err(|_| invalid())?;
+    let meta_len = usize::try_from(block.metaDataLength()).map_err(|_| invalid())?;
+    let body_len = usize::try_from(block.bodyLength()).map_err(|_| invalid())?;
+    let end = start
+        .checked_add(meta_len)
+        .and_then(|x| x.checked_add(body_len))
+        .ok_or_else(invalid)?;
+
+    let bytes = source.get_bytes(start..end).await?;
+    let relative = crate::Block::new(0, block.metaDataLength(), block.bodyLength());
+    Ok((bytes_to_buffer(bytes), relative))
+}
+
+/// Arrow File reader for an [`AsyncFileSource`]
+///
+/// Fetches the footer, and any dictionaries, on creation, and each record batch
+/// as it is requested
+pub struct AsyncFileReader<R> {
+    /// The source of the file
+    source: R,
+
+    /// The schema that is read from the file footer
+    schema: SchemaRef,
+
+    /// The record batch blocks in the file
+    blocks: Vec<crate::Block>,
+
+    /// A counter to keep track of the current block that should be read
+    current_block: usize,
+
+    /// Dictionaries for each schema field
+    dictionaries_by_id: HashMap<i64, ArrayRef>,
+
+    /// Metadata version
+    metadata_version: MetadataVersion,
+
+    /// User defined metadata
+    custom_metadata: HashMap<String, String>,
+
+    /// Optional projection and projected_schema
+    projection: Option<(Vec<usize>, Schema)>,
+}
+
+impl<R> std::fmt::Debug for AsyncFileReader<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncFileReader")
+            .field("schema", &self.schema)
+            .field("blocks", &self.blocks)
+            .field("current_block", &self.current_block)
+            .field("dictionaries_by_id", &self.dictionaries_by_id)
+            .field("metadata_version", &self.metadata_version)
+            .field("projection", &self.projection)
+            .finish()
+    }
+}
+
+impl<R: AsyncFileSource> AsyncFileReader<R> {
+    /// Try to create a new file reader, fetching the footer and dictionaries
+    /// of the file from `source`
+    ///
+    /// Returns errors if the file does not meet the Arrow Format header and footer
+    /// requirements
+    pub async fn try_new(
+        mut source: R,
+        projection: Option<Vec<usize>>,
+    ) -> Result<Self, ArrowError> {
+        let size = source.size().await?;
+        if size < 16 || source.get_bytes(0..6).await?.as_ref() != ARROW_MAGIC {
+            return Err(ArrowError::IoError(
+                "Arrow file does not contain correct header".to_string(),
+            ));
+        }
+
+        let trailer = source.get_bytes(size - 10..size).await?;
+        if trailer[4..] != ARROW_MAGIC {
+            return Err(ArrowError::IoError(
+                "Arrow file does not contain correct footer".to_string(),
+            ));
+        }
+
+        // read footer length
+        let footer_len = i32::from_le_bytes(trailer[..4].try_into().unwrap());
+        let footer_start = usize::try_from(footer_len)
+            .ok()
+            .and_then(|len| (size - 10).checked_sub(len))
+            .ok_or_else(|| {
+                ArrowError::IoError(format!("Invalid Arrow footer length {footer_len}"))
+            })?;
+        let footer_data = source.get_bytes(footer_start..size - 10).await?;
+
+        let footer = crate::root_as_footer(&footer_data).map_err(|err| {
+            ArrowError::IoError(format!("Unable to get root as footer: {err:?}"))
+        })?;
+        let blocks: Vec<_> = footer
+            .recordBatches()
+            .ok_or_else(|| {
+                ArrowError::IoError(
+                    "Unable to get record batches from IPC Footer".to_string(),
+                )
+            })?
+            .iter()
+            .copied()
+            .collect();
+        let dictionary_blocks: Vec<_> = footer
+            .dictionaries()
+            .map(|d| d.iter().copied().collect())
+            .unwrap_or_default();
+
+        let ipc_schema = footer.schema().ok_or_else(|| {
+            ArrowError::IoError("Unable to get schema from IPC Footer".to_string())
+        })?;
+        let schema = crate::convert::try_fb_to_schema(ipc_schema)?;
+        let custom_metadata = footer_custom_metadata(&footer);
+        let metadata_version = footer.version();
+
+        let mut dictionaries_by_id = HashMap::new();
+        for block in &dictionary_blocks {
+            let (buf, block) = fetch_block(&mut source, block).await?;
+            let (message, body) = read_block(&buf, &block)?;
+            match message.header_as_dictionary_batch() {
+                Some(batch) => read_dictionary(
+                    &body,
+                    batch,
+                    &schema,
+                    &mut dictionaries_by_id,
+                    &message.version(),
+                )?,
+                None => {
+                    return Err(ArrowError::IoError(format!(
+                        "Expecting DictionaryBatch in dictionary blocks, found {:?}.",
+                        message.header_type()
+                    )));
+                }
+            }
+        }
+
+        let projection = match projection {
+            Some(projection_indices) => {
+                let schema = schema.project(&projection_indices)?;
+                Some((projection_indices, schema))
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            source,
+            schema: Arc::new(schema),
+            blocks,
+            current_block: 0,
+            dictionaries_by_id,
+            metadata_version,
+            custom_metadata,
+            projection,
+        })
+    }
+
+    /// Return user defined customized metadata
+    pub fn custom_metadata(&self) -> &HashMap<String, String> {
+        &self.custom_metadata
+    }
+
+    /// Return the number of batches in the file
+    pub fn num_batches(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Return the schema of the file
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Read a specific record batch
+    ///
+    /// Sets the current block to the index, allowing random reads
+    pub fn set_index(&mut self, index: usize) -> Result<(), ArrowError> {
+        if index >= self.blocks.len() {
+            Err(ArrowError::IoError(format!(
+                "Cannot set batch to index {} from {} total batches",
+                index,
+                self.blocks.len()
+            )))
+        } else {
+            self.current_block = index;
+            Ok(())
+        }
+    }
+
+    /// Read the next [`RecordBatch`] from the file, returning `None` once all
+    /// batches have been read
+    pub async fn next_batch(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        let block = match self.blocks.get(self.current_block) {
+            Some(block) => *block,
+            None => return Ok(None),
+        };
+        self.current_block += 1;
+
+        let (buf, block) = fetch_block(&mut self.source, &block).await?;
+        let (message, body) = read_block(&buf, &block)?;
+
+        // some old test data's footer metadata is not set, so we account for that
+        if self.metadata_version != MetadataVersion::V1
+            && message.version() != self.metadata_version
+        {
+            return Err(ArrowError::IoError(
+                "Could not read IPC message as metadata versions mismatch".to_string(),
+            ));
+        }
+
+        match message.header_type() {
+            crate::MessageHeader::RecordBatch => {
+                let batch = message.header_as_record_batch().ok_or_else(|| {
+                    ArrowError::IoError(
+                        "Unable to read IPC message as record batch".to_string(),
+                    )
+                })?;
+                read_record_batch(
+                    &body,
+                    batch,
+                    self.schema(),
+                    &self.dictionaries_by_id,
+                    self.projection.as_ref().map(|x| x.0.as_ref()),
+                    &message.version(),
+                )
+                .map(Some)
+            }
+            crate::MessageHeader::NONE => Ok(None),
+            t => Err(ArrowError::IoError(format!(
+                "Reading types other than record batches not yet supported, unable to read {t:?}"
+            ))),
+        }
+    }
+
+    /// Convert this reader into a [`Stream`](futures::Stream) of [`RecordBatch`]
+    pub fn into_stream(self) -> BoxStream<'static, Result<RecordBatch, ArrowError>>
+    where
+        R: 'static,
+    {
+        futures::stream::try_unfold(self, |mut reader| async move {
+            Ok(reader.next_batch().await?.map(|batch| (batch, reader)))
+        })
+        .boxed()
+    }
+
+    /// Consumes this reader, returning the underlying source
+    pub fn into_inner(self) -> R {
+        self.source
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::{FileWriter, StreamWriter};
+    use arrow_array::types::Int32Type;
+    use arrow_array::{DictionaryArray, Int64Array, StringArray};
+    use futures::TryStreamExt;
+
+    fn test_batches() -> Vec<RecordBatch> {
+        (0..3)
+            .map(|i| {
+                let dict: DictionaryArray<Int32Type> =
+                    vec!["a", "b", "a", "c"].into_iter().collect();
+                RecordBatch::try_from_iter([
+                    ("int", Arc::new(Int64Array::from(vec![i; 4])) as _),
+                    ("str", Arc::new(StringArray::from(vec!["x"; 4])) as _),
+                    ("dict", Arc::new(dict) as _),
+                ])
+                .unwrap()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_async_stream_reader() {
+        let batches = test_batches();
+        let mut writer = StreamWriter::try_new(vec![], &batches[0].schema()).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        let data = writer.into_inner().unwrap();
+
+        let mut reader = AsyncStreamReader::try_new(data.as_slice(), None)
+            .await
+            .unwrap();
+        assert_eq!(reader.schema(), batches[0].schema());
+        for batch in &batches {
+            assert_eq!(&reader.next_batch().await.unwrap().unwrap(), batch);
+        }
+        assert!(reader.next_batch().await.unwrap().is_none());
+        assert!(reader.is_finished());
+
+        let source = std::io::Cursor::new(data.clone());
+        let reader = AsyncStreamReader::try_new(source, Some(vec![2, 0]))
+            .await
+            .unwrap();
+        let read: Vec<_> = reader.into_stream().try_collect().await.unwrap();
+        let expected: Vec<_> = batches
+            .iter()
+            .map(|b| b.project(&[2, 0]).unwrap())
+            .collect();
+        assert_eq!(read, expected);
+
+        let err = AsyncStreamReader::try_new(&data[..0], None)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Io error: Expected schema message, found end of stream"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_async_file_reader() {
+        let batches = test_batches();
+        let mut writer = FileWriter::try_new(vec![], &batches[0].schema()).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        let data = writer.into_inner().unwrap();
+
+        let source = std::io::Cursor::new(data.clone());
+        let mut reader = AsyncFileReader::try_new(source, None).await.unwrap();
+        assert_eq!(reader.num_batches(), 3);
+        reader.set_index(1).unwrap();
+        assert_eq!(reader.next_batch().await.unwrap().unwrap(), batches[1]);
+        reader.set_index(0).unwrap();
+        let read: Vec<_> = reader.into_stream().try_collect().await.unwrap();
+        assert_eq!(read, batches);
+
+        let source: Box<dyn AsyncFileSource> =
+            Box::new(std::io::Cursor::new(data.clone()));
+        let reader = AsyncFileReader::try_new(source, Some(vec![1]))
+            .await
+            .unwrap();
+        let read: Vec<_> = reader.into_stream().try_collect().await.unwrap();
+        let expected: Vec<_> = batches.iter().map(|b| b.project(&[1]).unwrap()).collect();
+        assert_eq!(read, expected);
+
+        let source = std::io::Cursor::new(data[..data.len() - 1].to_vec());
+        let err = AsyncFileReader::try_new(source, None).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Io error: Arrow file does not contain correct footer"
+        );
+    }
+}
@@ -0,0 +1,95 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow_schema::ArrowError;
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use futures::{FutureExt, TryFutureExt};
+
+use object_store::{ObjectMeta, ObjectStore};
+
+use crate::async_reader::AsyncFileSource;
+
+/// Implements [`AsyncFileSource`] for an Arrow file in object storage
+///
+/// Arrow files can be written to object storage with an
+/// [`AsyncFileWriter`](crate::async_writer::AsyncFileWriter) wrapping the writer
+/// returned by [`ObjectStore::put_multipart`]
+#[derive(Clone, Debug)]
+pub struct IpcObjectReader {
+    store: Arc<dyn ObjectStore>,
+    meta: ObjectMeta,
+}
+
+impl IpcObjectReader {
+    /// Creates a new [`IpcObjectReader`] for the provided [`ObjectStore`] and [`ObjectMeta`]
+    ///
+    /// [`ObjectMeta`] can be obtained using [`ObjectStore::list`] or [`ObjectStore::head`]
+    pub fn new(store: Arc<dyn ObjectStore>, meta: ObjectMeta) -> Self {
+        Self { store, meta }
+    }
+}
+
+impl AsyncFileSource for IpcObjectReader {
+    fn size(&mut self) -> BoxFuture<'_, Result<usize, ArrowError>> {
+        futures::future::ready(Ok(self.meta.size)).boxed()
+    }
+
+    fn get_bytes(
+        &mut self,
+        range: Range<usize>,
+    ) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+        self.store
+            .get_range(&self.meta.location, range)
+            .map_err(|e| ArrowError::ExternalError(Box::new(e)))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::async_reader::AsyncFileReader;
+    use crate::writer::FileWriter;
+    use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    use futures::TryStreamExt;
+    use object_store::memory::InMemory;
+    use object_store::path::Path;
+
+    #[tokio::test]
+    async fn test_ipc_object_reader() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+        let mut writer = FileWriter::try_new(vec![], &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.write(&batch).unwrap();
+        let data = writer.into_inner().unwrap();
+
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let path = Path::from("file.arrow");
+        store.put(&path, data.into()).await.unwrap();
+        let meta = store.head(&path).await.unwrap();
+
+        let reader = IpcObjectReader::new(store, meta);
+        let reader = AsyncFileReader::try_new(reader, None).await.unwrap();
+        let read: Vec<_> = reader.into_stream().try_collect().await.unwrap();
+        assert_eq!(read, vec![batch.clone(), batch]);
+    }
+}
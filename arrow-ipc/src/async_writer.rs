@@ -0,0 +1,303 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Provides `async` API for writing the Arrow IPC stream and file formats
+//!
+//! The API is similar to the [`sync` API](crate::writer), with each batch encoded
+//! to an in-memory buffer, that is then written to the provided [`AsyncWrite`]
+//! before [`AsyncStreamWriter::write`] or [`AsyncFileWriter::write`] returns. A slow
+//! [`AsyncWrite`] therefore applies backpressure to the producer of the batches.
+//!
+//! ```
+//! # #[tokio::main(flavor="current_thread")]
+//! # async fn main() {
+//! #
+//! use std::sync::Arc;
+//! use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+//! use arrow_ipc::async_writer::AsyncStreamWriter;
+//! use arrow_ipc::reader::StreamReader;
+//!
+//! let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+//! let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+//!
+//! let mut buffer = Vec::new();
+//! let mut writer = AsyncStreamWriter::try_new(&mut buffer, &batch.schema()).unwrap();
+//! writer.write(&batch).await.unwrap();
+//! writer.finish().await.unwrap();
+//!
+//! let mut reader = StreamReader::try_new(buffer.as_slice(), None).unwrap();
+//! assert_eq!(reader.next().unwrap().unwrap(), batch);
+//! # }
+//! ```
+
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, Schema};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::writer::{FileWriter, IpcWriteOptions, StreamWriter};
+
+/// Writes the data in `buffer` to `writer` and flushes it, clearing `buffer`
+async fn flush_buffer<W: AsyncWrite + Unpin>(
+    buffer: &mut Vec<u8>,
+    writer: &mut W,
+) -> Result<(), ArrowError> {
+    if !buffer.is_empty() {
+        writer.write_all(buffer).await?;
+        buffer.clear();
+    }
+    writer.flush().await?;
+    Ok(())
+}
+
+/// Async Arrow Stream writer, see [`StreamWriter`]
+pub struct AsyncStreamWriter<W> {
+    /// Underlying sync writer, encoding to an in-memory buffer
+    sync_writer: StreamWriter<Vec<u8>>,
+
+    /// Async writer provided by caller
+    async_writer: W,
+
+    /// Whether the writer has been finished
+    finished: bool,
+}
+
+impl<W: AsyncWrite + Unpin + Send> AsyncStreamWriter<W> {
+    /// Try to create a new writer, with the schema written as part of the header
+    pub fn try_new(writer: W, schema: &Schema) -> Result<Self, ArrowError> {
+        Self::try_new_with_options(writer, schema, IpcWriteOptions::default())
+    }
+
+    /// Try to create a new writer with [`IpcWriteOptions`]
+    pub fn try_new_with_options(
+        writer: W,
+        schema: &Schema,
+        write_options: IpcWriteOptions,
+    ) -> Result<Self, ArrowError> {
+        let sync_writer =
+            StreamWriter::try_new_with_options(Vec::new(), schema, write_options)?;
+        Ok(Self {
+            sync_writer,
+            async_writer: writer,
+            finished: false,
+        })
+    }
+
+    /// Write a record batch to the stream, waiting for it to be written to the
+    /// underlying writer
+    pub async fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.sync_writer.write(batch)?;
+        self.sync_writer.flush()?;
+        flush_buffer(self.sync_writer.get_mut(), &mut self.async_writer).await
+    }
+
+    /// Write continuation bytes, and mark the stream as done
+    pub async fn finish(&mut self) -> Result<(), ArrowError> {
+        self.sync_writer.finish()?;
+        self.finished = true;
+        self.sync_writer.flush()?;
+        flush_buffer(self.sync_writer.get_mut(), &mut self.async_writer).await
+    }
+
+    /// Gets a reference to the underlying writer
+    pub fn get_ref(&self) -> &W {
+        &self.async_writer
+    }
+
+    /// Gets a mutable reference to the underlying writer
+    ///
+    /// It is inadvisable to directly write to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.async_writer
+    }
+
+    /// Unwraps the underlying writer, finishing the stream if necessary
+    pub async fn into_inner(mut self) -> Result<W, ArrowError> {
+        if !self.finished {
+            self.finish().await?;
+        }
+        Ok(self.async_writer)
+    }
+}
+
+/// Async Arrow File writer, see [`FileWriter`]
+pub struct AsyncFileWriter<W> {
+    /// Underlying sync writer, encoding to an in-memory buffer
+    sync_writer: FileWriter<Vec<u8>>,
+
+    /// Async writer provided by caller
+    async_writer: W,
+
+    /// Whether the writer has been finished
+    finished: bool,
+}
+
+impl<W: AsyncWrite + Unpin + Send> AsyncFileWriter<W> {
+    /// Try to create a new writer, with the schema written as part of the header
+    pub fn try_new(writer: W, schema: &Schema) -> Result<Self, ArrowError> {
+        Self::try_new_with_options(writer, schema, IpcWriteOptions::default())
+    }
+
+    /// Try to create a new writer with [`IpcWriteOptions`]
+    pub fn try_new_with_options(
+        writer: W,
+        schema: &Schema,
+        write_options: IpcWriteOptions,
+    ) -> Result<Self, ArrowError> {
+        let sync_writer =
+            FileWriter::try_new_with_options(Vec::new(), schema, write_options)?;
+        Ok(Self {
+            sync_writer,
+            async_writer: writer,
+            finished: false,
+        })
+    }
+
+    /// Write a record batch to the file, waiting for it to be written to the
+    /// underlying writer
+    pub async fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.sync_writer.write(batch)?;
+        self.sync_writer.flush()?;
+        flush_buffer(self.sync_writer.get_mut(), &mut self.async_writer).await
+    }
+
+    /// Write footer and closing tag, then mark the writer as done
+    pub async fn finish(&mut self) -> Result<(), ArrowError> {
+        self.sync_writer.finish()?;
+        self.finished = true;
+        self.sync_writer.flush()?;
+        flush_buffer(self.sync_writer.get_mut(), &mut self.async_writer).await
+    }
+
+    /// Gets a reference to the underlying writer
+    pub fn get_ref(&self) -> &W {
+        &self.async_writer
+    }
+
+    /// Gets a mutable reference to the underlying writer
+    ///
+    /// It is inadvisable to directly write to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.async_writer
+    }
+
+    /// Unwraps the underlying writer, finishing the file if necessary
+    pub async fn into_inner(mut self) -> Result<W, ArrowError> {
+        if !self.finished {
+            self.finish().await?;
+        }
+        Ok(self.async_writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::{FileReader, StreamReader};
+    use arrow_array::types::Int32Type;
+    use arrow_array::{ArrayRef, DictionaryArray, Int64Array};
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll};
+
+    fn test_batches() -> Vec<RecordBatch> {
+        (0..3)
+            .map(|i| {
+                let dict: DictionaryArray<Int32Type> =
+                    vec!["a", "b", "a"].into_iter().collect();
+                let int: ArrayRef = Arc::new(Int64Array::from(vec![i; 3]));
+                RecordBatch::try_from_iter([("int", int), ("dict", Arc::new(dict) as _)])
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    /// An [`AsyncWrite`] that records the number of bytes written when flushed
+    #[derive(Default)]
+    struct FlushRecorder {
+        data: Vec<u8>,
+        flushed: Vec<usize>,
+    }
+
+    impl AsyncWrite for FlushRecorder {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<std::io::Result<usize>> {
+            self.data.extend_from_slice(buf);
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(
+            mut self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+        ) -> Poll<std::io::Result<()>> {
+            let len = self.data.len();
+            self.flushed.push(len);
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown(
+            self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+        ) -> Poll<std::io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_stream_writer() {
+        let batches = test_batches();
+        let mut writer =
+            AsyncStreamWriter::try_new(FlushRecorder::default(), &batches[0].schema())
+                .unwrap();
+        for batch in &batches {
+            writer.write(batch).await.unwrap();
+        }
+        // Each batch should be written before write returns
+        assert_eq!(writer.get_ref().flushed.len(), 3);
+        let written = writer.into_inner().await.unwrap();
+        assert_eq!(written.flushed.len(), 4);
+        assert_eq!(*written.flushed.last().unwrap(), written.data.len());
+
+        let reader = StreamReader::try_new(written.data.as_slice(), None).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+    }
+
+    #[tokio::test]
+    async fn test_async_file_writer() {
+        let batches = test_batches();
+        let mut buffer = Vec::new();
+        let mut writer =
+            AsyncFileWriter::try_new(&mut buffer, &batches[0].schema()).unwrap();
+        for batch in &batches {
+            writer.write(batch).await.unwrap();
+        }
+        writer.finish().await.unwrap();
+        let err = writer.write(&batches[0]).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Io error: Cannot write record batch to file writer as it is closed"
+        );
+        drop(writer);
+
+        let reader = FileReader::try_new(std::io::Cursor::new(buffer), None).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+    }
+}
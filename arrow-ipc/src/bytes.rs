@@ -0,0 +1,211 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Convenience functions to serialize a single [`RecordBatch`] to and from bytes
+//!
+//! This is useful when storing batches in message queues or key-value stores,
+//! where setting up a [`StreamWriter`] or [`FileWriter`] for a single batch
+//! is unnecessarily verbose.
+//!
+//! The output of [`to_bytes`] is prefixed with a small header identifying the
+//! [`BatchFormat`] used, allowing [`from_bytes`] to decode it without any
+//! out-of-band information.
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{Int32Array, RecordBatch};
+//! # use arrow_ipc::bytes::{from_bytes, to_bytes, BatchFormat};
+//! let batch = RecordBatch::try_from_iter(vec![
+//!     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as _),
+//! ]).unwrap();
+//!
+//! let bytes = to_bytes(&batch, BatchFormat::Stream, None).unwrap();
+//! let decoded = from_bytes(&bytes).unwrap();
+//! assert_eq!(batch, decoded);
+//! ```
+
+use std::io::Cursor;
+
+use arrow_array::RecordBatch;
+use arrow_schema::ArrowError;
+
+use crate::reader::{FileReader, StreamReader};
+use crate::writer::{FileWriter, IpcWriteOptions, StreamWriter};
+use crate::CompressionType;
+
+/// Magic bytes identifying the output of [`to_bytes`]
+const BATCH_MAGIC: [u8; 3] = [b'A', b'R', b'B'];
+
+/// The version of the header written by [`to_bytes`]
+const BATCH_HEADER_VERSION: u8 = 1;
+
+/// The length of the header written by [`to_bytes`]
+const BATCH_HEADER_LEN: usize = BATCH_MAGIC.len() + 2;
+
+/// The IPC framing used to encode a [`RecordBatch`] with [`to_bytes`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchFormat {
+    /// The [IPC streaming format](https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format)
+    Stream,
+    /// The [IPC file format](https://arrow.apache.org/docs/format/Columnar.html#ipc-file-format)
+    File,
+}
+
+impl BatchFormat {
+    fn id(&self) -> u8 {
+        match self {
+            Self::Stream => 0,
+            Self::File => 1,
+        }
+    }
+
+    fn try_from_id(id: u8) -> Result<Self, ArrowError> {
+        match id {
+            0 => Ok(Self::Stream),
+            1 => Ok(Self::File),
+            _ => Err(ArrowError::ParseError(format!(
+                "Unknown record batch format identifier {id}"
+            ))),
+        }
+    }
+}
+
+/// Serializes `batch` to bytes using the provided [`BatchFormat`], optionally
+/// compressing the buffers with `compression`
+///
+/// The returned bytes are prefixed with a header identifying the format, and
+/// can be decoded with [`from_bytes`]. Use [`format_of`] to inspect the format
+/// without decoding the batch.
+pub fn to_bytes(
+    batch: &RecordBatch,
+    format: BatchFormat,
+    compression: Option<CompressionType>,
+) -> Result<Vec<u8>, ArrowError> {
+    let options = IpcWriteOptions::default().try_with_compression(compression)?;
+
+    let mut out = Vec::with_capacity(BATCH_HEADER_LEN + batch.get_array_memory_size());
+    out.extend_from_slice(&BATCH_MAGIC);
+    out.push(BATCH_HEADER_VERSION);
+    out.push(format.id());
+
+    let schema = batch.schema();
+    match format {
+        BatchFormat::Stream => {
+            let mut writer = StreamWriter::try_new_with_options(out, &schema, options)?;
+            writer.write(batch)?;
+            writer.into_inner()
+        }
+        BatchFormat::File => {
+            let mut writer = FileWriter::try_new_with_options(out, &schema, options)?;
+            writer.write(batch)?;
+            writer.into_inner()
+        }
+    }
+}
+
+/// Returns the [`BatchFormat`] of bytes written by [`to_bytes`]
+pub fn format_of(bytes: &[u8]) -> Result<BatchFormat, ArrowError> {
+    if bytes.len() < BATCH_HEADER_LEN || bytes[..BATCH_MAGIC.len()] != BATCH_MAGIC {
+        return Err(ArrowError::ParseError(
+            "Bytes do not contain a valid record batch header".to_string(),
+        ));
+    }
+
+    let version = bytes[BATCH_MAGIC.len()];
+    if version != BATCH_HEADER_VERSION {
+        return Err(ArrowError::ParseError(format!(
+            "Unsupported record batch header version {version}"
+        )));
+    }
+    BatchFormat::try_from_id(bytes[BATCH_MAGIC.len() + 1])
+}
+
+/// Deserializes a single [`RecordBatch`] from bytes written by [`to_bytes`]
+///
+/// Returns an error if the bytes do not contain exactly one record batch
+pub fn from_bytes(bytes: &[u8]) -> Result<RecordBatch, ArrowError> {
+    let format = format_of(bytes)?;
+    let data = &bytes[BATCH_HEADER_LEN..];
+
+    let mut batches = match format {
+        BatchFormat::Stream => StreamReader::try_new_unbuffered(data, None)?
+            .collect::<Result<Vec<_>, _>>()?,
+        BatchFormat::File => FileReader::try_new(Cursor::new(data), None)?
+            .collect::<Result<Vec<_>, _>>()?,
+    };
+
+    match batches.len() {
+        1 => Ok(batches.pop().unwrap()),
+        n => Err(ArrowError::ParseError(format!(
+            "Expected a single record batch, found {n}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Int32Array, StringArray};
+    use std::sync::Arc;
+
+    fn test_batch() -> RecordBatch {
+        RecordBatch::try_from_iter(vec![
+            (
+                "a",
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])) as _,
+            ),
+            ("b", Arc::new(StringArray::from(vec!["x", "y", "z"])) as _),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let batch = test_batch();
+        for format in [BatchFormat::Stream, BatchFormat::File] {
+            let bytes = to_bytes(&batch, format, None).unwrap();
+            assert_eq!(format_of(&bytes).unwrap(), format);
+            assert_eq!(from_bytes(&bytes).unwrap(), batch);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "zstd")]
+    fn test_roundtrip_compressed() {
+        let batch = test_batch();
+        for format in [BatchFormat::Stream, BatchFormat::File] {
+            let bytes = to_bytes(&batch, format, Some(CompressionType::ZSTD)).unwrap();
+            assert_eq!(from_bytes(&bytes).unwrap(), batch);
+        }
+    }
+
+    #[test]
+    fn test_invalid_header() {
+        let err = from_bytes(b"ARROW1").unwrap_err();
+        assert!(err.to_string().contains("valid record batch header"));
+
+        let err = format_of(&[b'A', b'R', b'B', BATCH_HEADER_VERSION, 7]).unwrap_err();
+        assert!(err.to_string().contains("Unknown record batch format"));
+
+        let mut bytes = to_bytes(&test_batch(), BatchFormat::Stream, None).unwrap();
+        bytes[BATCH_MAGIC.len()] = 2;
+        let err = from_bytes(&bytes).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unsupported record batch header version"));
+    }
+}
@@ -16,7 +16,7 @@
 // under the License.
 
 use crate::CompressionType;
-use arrow_buffer::Buffer;
+use arrow_buffer::{Buffer, MutableBuffer};
 use arrow_schema::ArrowError;
 
 const LENGTH_NO_COMPRESSED_DATA: i64 = -1;
@@ -66,7 +66,8 @@ impl CompressionCodec {
             // empty input, nothing to do
         } else {
             // write compressed data directly into the output buffer
-            output.extend_from_slice(&uncompressed_data_len.to_le_bytes());
+            // The length is always written as a 64-bit integer, regardless of platform
+            output.extend_from_slice(&(uncompressed_data_len as i64).to_le_bytes());
             self.compress(input, output)?;
 
             let compression_len = output.len() - original_output_len;
@@ -90,10 +91,20 @@ impl CompressionCodec {
     /// [8 bytes]:         uncompressed length
     /// [remaining bytes]: compressed data stream
     /// ```
+    ///
+    /// Returns an error if the input is truncated, or does not decompress to
+    /// exactly the uncompressed length
     pub(crate) fn decompress_to_buffer(
         &self,
         input: &Buffer,
     ) -> Result<Buffer, ArrowError> {
+        if input.len() < LENGTH_OF_PREFIX_DATA as usize {
+            return Err(ArrowError::IoError(format!(
+                "Compressed IPC buffer of length {} is too short to contain the uncompressed length",
+                input.len()
+            )));
+        }
+
         // read the first 8 bytes to determine if the data is
         // compressed
         let decompressed_length = read_uncompressed_size(input);
@@ -103,13 +114,18 @@ impl CompressionCodec {
         } else if decompressed_length == LENGTH_NO_COMPRESSED_DATA {
             // no compression
             input.slice(LENGTH_OF_PREFIX_DATA as usize)
+        } else if decompressed_length < 0 {
+            return Err(ArrowError::IoError(format!(
+                "Invalid uncompressed length {decompressed_length} for IPC buffer"
+            )));
         } else {
-            // decompress data using the codec
+            // decompress data using the codec into an aligned buffer, so that
+            // it can be used directly by arrays with any alignment requirement
             let mut uncompressed_buffer =
-                Vec::with_capacity(decompressed_length as usize);
+                MutableBuffer::from_len_zeroed(decompressed_length as usize);
             let input_data = &input[(LENGTH_OF_PREFIX_DATA as usize)..];
-            self.decompress(input_data, &mut uncompressed_buffer)?;
-            Buffer::from(uncompressed_buffer)
+            self.decompress(input_data, uncompressed_buffer.as_slice_mut())?;
+            uncompressed_buffer.into()
         };
         Ok(buffer)
     }
@@ -123,13 +139,11 @@ impl CompressionCodec {
         }
     }
 
-    /// Decompress the data in input buffer and write to output buffer
+    /// Decompress the data in input buffer, filling the output buffer
     /// using the specified compression
-    fn decompress(
-        &self,
-        input: &[u8],
-        output: &mut Vec<u8>,
-    ) -> Result<usize, ArrowError> {
+    ///
+    /// Returns an error if the decompressed data is not exactly the length of `output`
+    fn decompress(&self, input: &[u8], output: &mut [u8]) -> Result<(), ArrowError> {
         match self {
             CompressionCodec::Lz4Frame => decompress_lz4(input, output),
             CompressionCodec::Zstd => decompress_zstd(input, output),
@@ -137,6 +151,29 @@ impl CompressionCodec {
     }
 }
 
+/// Reads exactly `output.len()` bytes from `decoder`, returning an error if the
+/// decoded data is shorter or longer than this
+#[cfg(any(feature = "lz4", feature = "zstd"))]
+fn read_exact_len<R: std::io::Read>(
+    mut decoder: R,
+    output: &mut [u8],
+) -> Result<(), ArrowError> {
+    decoder.read_exact(output).map_err(|e| {
+        ArrowError::IoError(format!(
+            "Failed to decompress IPC buffer of uncompressed length {}: {e}",
+            output.len()
+        ))
+    })?;
+    let mut trailing = [0_u8; 1];
+    if decoder.read(&mut trailing)? != 0 {
+        return Err(ArrowError::IoError(format!(
+            "Decompressed IPC buffer exceeds uncompressed length {}",
+            output.len()
+        )));
+    }
+    Ok(())
+}
+
 #[cfg(feature = "lz4")]
 fn compress_lz4(input: &[u8], output: &mut Vec<u8>) -> Result<(), ArrowError> {
     use std::io::Write;
@@ -155,14 +192,12 @@ fn compress_lz4(_input: &[u8], _output: &mut Vec<u8>) -> Result<(), ArrowError>
 }
 
 #[cfg(feature = "lz4")]
-fn decompress_lz4(input: &[u8], output: &mut Vec<u8>) -> Result<usize, ArrowError> {
-    use std::io::Read;
-    Ok(lz4::Decoder::new(input)?.read_to_end(output)?)
+fn decompress_lz4(input: &[u8], output: &mut [u8]) -> Result<(), ArrowError> {
+    read_exact_len(lz4::Decoder::new(input)?, output)
 }
 
 #[cfg(not(feature = "lz4"))]
-#[allow(clippy::ptr_arg)]
-fn decompress_lz4(_input: &[u8], _output: &mut Vec<u8>) -> Result<usize, ArrowError> {
+fn decompress_lz4(_input: &[u8], _output: &mut [u8]) -> Result<(), ArrowError> {
     Err(ArrowError::InvalidArgumentError(
         "lz4 IPC decompression requires the lz4 feature".to_string(),
     ))
@@ -186,14 +221,12 @@ fn compress_zstd(_input: &[u8], _output: &mut Vec<u8>) -> Result<(), ArrowError>
 }
 
 #[cfg(feature = "zstd")]
-fn decompress_zstd(input: &[u8], output: &mut Vec<u8>) -> Result<usize, ArrowError> {
-    use std::io::Read;
-    Ok(zstd::Decoder::new(input)?.read_to_end(output)?)
+fn decompress_zstd(input: &[u8], output: &mut [u8]) -> Result<(), ArrowError> {
+    read_exact_len(zstd::Decoder::new(input)?, output)
 }
 
 #[cfg(not(feature = "zstd"))]
-#[allow(clippy::ptr_arg)]
-fn decompress_zstd(_input: &[u8], _output: &mut Vec<u8>) -> Result<usize, ArrowError> {
+fn decompress_zstd(_input: &[u8], _output: &mut [u8]) -> Result<(), ArrowError> {
     Err(ArrowError::InvalidArgumentError(
         "zstd IPC decompression requires the zstd feature".to_string(),
     ))
@@ -220,7 +253,7 @@ mod tests {
         let codec = super::CompressionCodec::Lz4Frame;
         let mut output_bytes: Vec<u8> = Vec::new();
         codec.compress(input_bytes, &mut output_bytes).unwrap();
-        let mut result_output_bytes: Vec<u8> = Vec::new();
+        let mut result_output_bytes = vec![0; input_bytes.len()];
         codec
             .decompress(output_bytes.as_slice(), &mut result_output_bytes)
             .unwrap();
@@ -234,10 +267,47 @@ mod tests {
         let codec = super::CompressionCodec::Zstd;
         let mut output_bytes: Vec<u8> = Vec::new();
         codec.compress(input_bytes, &mut output_bytes).unwrap();
-        let mut result_output_bytes: Vec<u8> = Vec::new();
+        let mut result_output_bytes = vec![0; input_bytes.len()];
         codec
             .decompress(output_bytes.as_slice(), &mut result_output_bytes)
             .unwrap();
         assert_eq!(input_bytes, result_output_bytes.as_slice());
     }
+
+    #[test]
+    #[cfg(all(feature = "lz4", feature = "zstd"))]
+    fn test_decompress_to_buffer() {
+        use super::*;
+
+        let input: Vec<u8> = (0..1000).map(|x| (x % 7) as u8).collect();
+        for codec in [CompressionCodec::Lz4Frame, CompressionCodec::Zstd] {
+            let mut compressed = vec![];
+            codec.compress_to_vec(&input, &mut compressed).unwrap();
+            assert_eq!(&compressed[..8], &1000_i64.to_le_bytes());
+            assert!(compressed.len() < input.len());
+
+            let compressed = Buffer::from_vec(compressed);
+            let output = codec.decompress_to_buffer(&compressed).unwrap();
+            assert_eq!(output.as_slice(), input.as_slice());
+            assert_eq!(output.as_ptr().align_offset(64), 0);
+
+            // Mismatched uncompressed length
+            for len in [999_i64, 1001] {
+                let mut invalid = compressed.as_slice().to_vec();
+                invalid[..8].copy_from_slice(&len.to_le_bytes());
+                let invalid = Buffer::from_vec(invalid);
+                assert!(codec.decompress_to_buffer(&invalid).is_err());
+            }
+
+            let invalid = Buffer::from_vec((-2_i64).to_le_bytes().to_vec());
+            let err = codec.decompress_to_buffer(&invalid).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Io error: Invalid uncompressed length -2 for IPC buffer"
+            );
+
+            let truncated = Buffer::from_vec(vec![1_u8, 2, 3]);
+            assert!(codec.decompress_to_buffer(&truncated).is_err());
+        }
+    }
 }
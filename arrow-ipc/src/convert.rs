@@ -24,6 +24,7 @@ use flatbuffers::{
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::reader::IpcError;
 use crate::{size_prefixed_root_as_message, KeyValue, CONTINUATION_MARKER};
 use DataType::*;
 
@@ -80,51 +81,73 @@ pub fn schema_to_fb_offset<'a>(
 }
 
 /// Convert an IPC Field to Arrow Field
+///
+/// # Panics
+///
+/// Panics if the field is invalid, see [`try_field_from_fb`] for a fallible version
 impl<'a> From<crate::Field<'a>> for Field {
     fn from(field: crate::Field) -> Field {
-        let arrow_field = if let Some(dictionary) = field.dictionary() {
-            Field::new_dict(
-                field.name().unwrap(),
-                get_data_type(field, true),
-                field.nullable(),
-                dictionary.id(),
-                dictionary.isOrdered(),
-            )
-        } else {
-            Field::new(
-                field.name().unwrap(),
-                get_data_type(field, true),
-                field.nullable(),
-            )
-        };
+        try_field_from_fb(field).unwrap()
+    }
+}
 
-        let mut metadata_map = HashMap::default();
-        if let Some(list) = field.custom_metadata() {
-            for kv in list {
-                if let (Some(k), Some(v)) = (kv.key(), kv.value()) {
-                    metadata_map.insert(k.to_string(), v.to_string());
-                }
+/// Try to convert an IPC Field to Arrow Field, returning an error if it is invalid
+pub fn try_field_from_fb(field: crate::Field) -> Result<Field, ArrowError> {
+    let name = field
+        .name()
+        .ok_or_else(|| schema_err("Field is missing its name"))?;
+    let arrow_field = if let Some(dictionary) = field.dictionary() {
+        Field::new_dict(
+            name,
+            get_data_type(field, true)?,
+            field.nullable(),
+            dictionary.id(),
+            dictionary.isOrdered(),
+        )
+    } else {
+        Field::new(name, get_data_type(field, true)?, field.nullable())
+    };
+
+    let mut metadata_map = HashMap::default();
+    if let Some(list) = field.custom_metadata() {
+        for kv in list {
+            if let (Some(k), Some(v)) = (kv.key(), kv.value()) {
+                metadata_map.insert(k.to_string(), v.to_string());
             }
         }
-
-        arrow_field.with_metadata(metadata_map)
     }
+
+    Ok(arrow_field.with_metadata(metadata_map))
 }
 
 /// Deserialize a Schema table from flat buffer format to Schema data type
+///
+/// # Panics
+///
+/// Panics if the schema is invalid, see [`try_fb_to_schema`] for a fallible version
 pub fn fb_to_schema(fb: crate::Schema) -> Schema {
+    try_fb_to_schema(fb).unwrap()
+}
+
+/// Try to deserialize a Schema table from flat buffer format to Schema data type,
+/// returning an error if it is invalid
+pub fn try_fb_to_schema(fb: crate::Schema) -> Result<Schema, ArrowError> {
     let mut fields: Vec<Field> = vec![];
-    let c_fields = fb.fields().unwrap();
+    let c_fields = fb
+        .fields()
+        .ok_or_else(|| schema_err("Schema is missing its fields"))?;
     let len = c_fields.len();
     for i in 0..len {
         let c_field: crate::Field = c_fields.get(i);
         match c_field.type_type() {
             crate::Type::Decimal if fb.endianness() == crate::Endianness::Big => {
-                unimplemented!("Big Endian is not supported for Decimal!")
+                return Err(ArrowError::NotYetImplemented(
+                    "Big Endian is not supported for Decimal!".to_string(),
+                ))
             }
             _ => (),
         };
-        fields.push(c_field.into());
+        fields.push(try_field_from_fb(c_field)?);
     }
 
     let mut metadata: HashMap<String, String> = HashMap::default();
@@ -141,14 +164,14 @@ pub fn fb_to_schema(fb: crate::Schema) -> Schema {
             }
         }
     }
-    Schema::new_with_metadata(fields, metadata)
+    Ok(Schema::new_with_metadata(fields, metadata))
 }
 
 /// Try deserialize flat buffer format bytes into a schema
 pub fn try_schema_from_flatbuffer_bytes(bytes: &[u8]) -> Result<Schema, ArrowError> {
     if let Ok(ipc) = crate::root_as_message(bytes) {
-        if let Some(schema) = ipc.header_as_schema().map(fb_to_schema) {
-            Ok(schema)
+        if let Some(schema) = ipc.header_as_schema() {
+            try_fb_to_schema(schema)
         } else {
             Err(ArrowError::IoError(
                 "Unable to get head as schema".to_string(),
@@ -197,7 +220,7 @@ pub fn try_schema_from_ipc_buffer(buffer: &[u8]) -> Result<Schema, ArrowError> {
                 "Unable to convert flight info to a schema".to_string(),
             )
         })?;
-        Ok(fb_to_schema(ipc_schema))
+        try_fb_to_schema(ipc_schema)
     } else {
         Err(ArrowError::ParseError(
             "The buffer length is less than 4 and missing the continuation maker or length of buffer".to_string()
@@ -205,11 +228,37 @@ pub fn try_schema_from_ipc_buffer(buffer: &[u8]) -> Result<Schema, ArrowError> {
     }
 }
 
+/// Returns an [`IpcError::InvalidSchema`] with the provided message
+fn schema_err(msg: impl Into<String>) -> ArrowError {
+    IpcError::InvalidSchema(msg.into()).into()
+}
+
+/// Returns the children of `field`, checking there are exactly `expected`
+fn children_of<'a>(
+    field: &crate::Field<'a>,
+    expected: usize,
+    name: &str,
+) -> Result<Vector<'a, ForwardsUOffset<crate::Field<'a>>>, ArrowError> {
+    match field.children() {
+        Some(children) if children.len() == expected => Ok(children),
+        Some(children) => Err(schema_err(format!(
+            "{name} type should have exactly {expected} children. Found {}",
+            children.len()
+        ))),
+        None => Err(schema_err(format!("{name} type is missing its children"))),
+    }
+}
+
 /// Get the Arrow data type from the flatbuffer Field table
-pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> DataType {
+pub(crate) fn get_data_type(
+    field: crate::Field,
+    may_be_dictionary: bool,
+) -> Result<DataType, ArrowError> {
     if let Some(dictionary) = field.dictionary() {
         if may_be_dictionary {
-            let int = dictionary.indexType().unwrap();
+            let int = dictionary
+                .indexType()
+                .ok_or_else(|| schema_err("Dictionary is missing its index type"))?;
             let index_type = match (int.bitWidth(), int.is_signed()) {
                 (8, true) => DataType::Int8,
                 (8, false) => DataType::UInt8,
@@ -219,20 +268,21 @@ pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> Dat
                 (32, false) => DataType::UInt32,
                 (64, true) => DataType::Int64,
                 (64, false) => DataType::UInt64,
-                _ => panic!("Unexpected bitwidth and signed"),
+                _ => return Err(schema_err("Unexpected bitwidth and signed")),
             };
-            return DataType::Dictionary(
+            return Ok(DataType::Dictionary(
                 Box::new(index_type),
-                Box::new(get_data_type(field, false)),
-            );
+                Box::new(get_data_type(field, false)?),
+            ));
         }
     }
 
-    match field.type_type() {
+    let missing = || schema_err(format!("Field is missing {:?} type", field.type_type()));
+    Ok(match field.type_type() {
         crate::Type::Null => DataType::Null,
         crate::Type::Bool => DataType::Boolean,
         crate::Type::Int => {
-            let int = field.type_as_int().unwrap();
+            let int = field.type_as_int().ok_or_else(missing)?;
             match (int.bitWidth(), int.is_signed()) {
                 (8, true) => DataType::Int8,
                 (8, false) => DataType::UInt8,
@@ -242,10 +292,12 @@ pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> Dat
                 (32, false) => DataType::UInt32,
                 (64, true) => DataType::Int64,
                 (64, false) => DataType::UInt64,
-                z => panic!(
-                    "Int type with bit width of {} and signed of {} not supported",
-                    z.0, z.1
-                ),
+                z => {
+                    return Err(schema_err(format!(
+                        "Int type with bit width of {} and signed of {} not supported",
+                        z.0, z.1
+                    )))
+                }
             }
         }
         crate::Type::Binary => DataType::Binary,
@@ -253,28 +305,36 @@ pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> Dat
         crate::Type::Utf8 => DataType::Utf8,
         crate::Type::LargeUtf8 => DataType::LargeUtf8,
         crate::Type::FixedSizeBinary => {
-            let fsb = field.type_as_fixed_size_binary().unwrap();
+            let fsb = field.type_as_fixed_size_binary().ok_or_else(missing)?;
             DataType::FixedSizeBinary(fsb.byteWidth())
         }
         crate::Type::FloatingPoint => {
-            let float = field.type_as_floating_point().unwrap();
+            let float = field.type_as_floating_point().ok_or_else(missing)?;
             match float.precision() {
                 crate::Precision::HALF => DataType::Float16,
                 crate::Precision::SINGLE => DataType::Float32,
                 crate::Precision::DOUBLE => DataType::Float64,
-                z => panic!("FloatingPoint type with precision of {z:?} not supported"),
+                z => {
+                    return Err(schema_err(format!(
+                        "FloatingPoint type with precision of {z:?} not supported"
+                    )))
+                }
             }
         }
         crate::Type::Date => {
-            let date = field.type_as_date().unwrap();
+            let date = field.type_as_date().ok_or_else(missing)?;
             match date.unit() {
                 crate::DateUnit::DAY => DataType::Date32,
                 crate::DateUnit::MILLISECOND => DataType::Date64,
-                z => panic!("Date type with unit of {z:?} not supported"),
+                z => {
+                    return Err(schema_err(format!(
+                        "Date type with unit of {z:?} not supported"
+                    )))
+                }
             }
         }
         crate::Type::Time => {
-            let time = field.type_as_time().unwrap();
+            let time = field.type_as_time().ok_or_else(missing)?;
             match (time.bitWidth(), time.unit()) {
                 (32, crate::TimeUnit::SECOND) => DataType::Time32(TimeUnit::Second),
                 (32, crate::TimeUnit::MILLISECOND) => {
@@ -286,14 +346,16 @@ pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> Dat
                 (64, crate::TimeUnit::NANOSECOND) => {
                     DataType::Time64(TimeUnit::Nanosecond)
                 }
-                z => panic!(
-                    "Time type with bit width of {} and unit of {:?} not supported",
-                    z.0, z.1
-                ),
+                z => {
+                    return Err(schema_err(format!(
+                        "Time type with bit width of {} and unit of {:?} not supported",
+                        z.0, z.1
+                    )))
+                }
             }
         }
         crate::Type::Timestamp => {
-            let timestamp = field.type_as_timestamp().unwrap();
+            let timestamp = field.type_as_timestamp().ok_or_else(missing)?;
             let timezone: Option<_> = timestamp.timezone().map(|tz| tz.into());
             match timestamp.unit() {
                 crate::TimeUnit::SECOND => {
@@ -308,11 +370,15 @@ pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> Dat
                 crate::TimeUnit::NANOSECOND => {
                     DataType::Timestamp(TimeUnit::Nanosecond, timezone)
                 }
-                z => panic!("Timestamp type with unit of {z:?} not supported"),
+                z => {
+                    return Err(schema_err(format!(
+                        "Timestamp type with unit of {z:?} not supported"
+                    )))
+                }
             }
         }
         crate::Type::Interval => {
-            let interval = field.type_as_interval().unwrap();
+            let interval = field.type_as_interval().ok_or_else(missing)?;
             match interval.unit() {
                 crate::IntervalUnit::YEAR_MONTH => {
                     DataType::Interval(IntervalUnit::YearMonth)
@@ -323,110 +389,143 @@ pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> Dat
                 crate::IntervalUnit::MONTH_DAY_NANO => {
                     DataType::Interval(IntervalUnit::MonthDayNano)
                 }
-                z => panic!("Interval type with unit of {z:?} unsupported"),
+                z => {
+                    return Err(schema_err(format!(
+                        "Interval type with unit of {z:?} unsupported"
+                    )))
+                }
             }
         }
         crate::Type::Duration => {
-            let duration = field.type_as_duration().unwrap();
+            let duration = field.type_as_duration().ok_or_else(missing)?;
             match duration.unit() {
                 crate::TimeUnit::SECOND => DataType::Duration(TimeUnit::Second),
                 crate::TimeUnit::MILLISECOND => DataType::Duration(TimeUnit::Millisecond),
                 crate::TimeUnit::MICROSECOND => DataType::Duration(TimeUnit::Microsecond),
                 crate::TimeUnit::NANOSECOND => DataType::Duration(TimeUnit::Nanosecond),
-                z => panic!("Duration type with unit of {z:?} unsupported"),
+                z => {
+                    return Err(schema_err(format!(
+                        "Duration type with unit of {z:?} unsupported"
+                    )))
+                }
             }
         }
         crate::Type::List => {
-            let children = field.children().unwrap();
-            if children.len() != 1 {
-                panic!("expect a list to have one child")
-            }
-            DataType::List(Arc::new(children.get(0).into()))
+            let children = children_of(&field, 1, "List")?;
+            DataType::List(Arc::new(try_field_from_fb(children.get(0))?))
         }
         crate::Type::LargeList => {
-            let children = field.children().unwrap();
-            if children.len() != 1 {
-                panic!("expect a large list to have one child")
-            }
-            DataType::LargeList(Arc::new(children.get(0).into()))
+            let children = children_of(&field, 1, "LargeList")?;
+            DataType::LargeList(Arc::new(try_field_from_fb(children.get(0))?))
+        }
+        crate::Type::ListView => {
+            let children = children_of(&field, 1, "ListView")?;
+            DataType::ListView(Arc::new(try_field_from_fb(children.get(0))?))
+        }
+        crate::Type::LargeListView => {
+            let children = children_of(&field, 1, "LargeListView")?;
+            DataType::LargeListView(Arc::new(try_field_from_fb(children.get(0))?))
         }
         crate::Type::FixedSizeList => {
-            let children = field.children().unwrap();
-            if children.len() != 1 {
-                panic!("expect a list to have one child")
-            }
-            let fsl = field.type_as_fixed_size_list().unwrap();
-            DataType::FixedSizeList(Arc::new(children.get(0).into()), fsl.listSize())
+            let children = children_of(&field, 1, "FixedSizeList")?;
+            let fsl = field.type_as_fixed_size_list().ok_or_else(missing)?;
+            DataType::FixedSizeList(
+                Arc::new(try_field_from_fb(children.get(0))?),
+                fsl.listSize(),
+            )
         }
         crate::Type::Struct_ => {
             let fields = match field.children() {
-                Some(children) => children.iter().map(Field::from).collect(),
+                Some(children) => children
+                    .iter()
+                    .map(try_field_from_fb)
+                    .collect::<Result<_, _>>()?,
                 None => Fields::empty(),
             };
             DataType::Struct(fields)
         }
         crate::Type::RunEndEncoded => {
-            let children = field.children().unwrap();
-            if children.len() != 2 {
-                panic!(
-                    "RunEndEncoded type should have exactly two children. Found {}",
-                    children.len()
-                )
-            }
-            let run_ends_field = children.get(0).into();
-            let values_field = children.get(1).into();
+            let children = children_of(&field, 2, "RunEndEncoded")?;
+            let run_ends_field = try_field_from_fb(children.get(0))?;
+            let values_field = try_field_from_fb(children.get(1))?;
             DataType::RunEndEncoded(Arc::new(run_ends_field), Arc::new(values_field))
         }
         crate::Type::Map => {
-            let map = field.type_as_map().unwrap();
-            let children = field.children().unwrap();
-            if children.len() != 1 {
-                panic!("expect a map to have one child")
-            }
-            DataType::Map(Arc::new(children.get(0).into()), map.keysSorted())
+            let map = field.type_as_map().ok_or_else(missing)?;
+            let children = children_of(&field, 1, "Map")?;
+            DataType::Map(
+                Arc::new(try_field_from_fb(children.get(0))?),
+                map.keysSorted(),
+            )
         }
         crate::Type::Decimal => {
-            let fsb = field.type_as_decimal().unwrap();
+            let fsb = field.type_as_decimal().ok_or_else(missing)?;
             let bit_width = fsb.bitWidth();
-            if bit_width == 128 {
-                DataType::Decimal128(
-                    fsb.precision().try_into().unwrap(),
-                    fsb.scale().try_into().unwrap(),
-                )
-            } else if bit_width == 256 {
-                DataType::Decimal256(
-                    fsb.precision().try_into().unwrap(),
-                    fsb.scale().try_into().unwrap(),
-                )
-            } else {
-                panic!("Unexpected decimal bit width {bit_width}")
+            let (precision, scale) =
+                match (u8::try_from(fsb.precision()), i8::try_from(fsb.scale())) {
+                    (Ok(precision), Ok(scale)) => (precision, scale),
+                    _ => {
+                        return Err(schema_err(format!(
+                            "Invalid decimal precision {} and scale {}",
+                            fsb.precision(),
+                            fsb.scale()
+                        )))
+                    }
+                };
+            match bit_width {
+                32 => DataType::Decimal32(precision, scale),
+                64 => DataType::Decimal64(precision, scale),
+                128 => DataType::Decimal128(precision, scale),
+                256 => DataType::Decimal256(precision, scale),
+                _ => {
+                    return Err(schema_err(format!(
+                        "Unexpected decimal bit width {bit_width}"
+                    )))
+                }
             }
         }
         crate::Type::Union => {
-            let union = field.type_as_union().unwrap();
+            let union = field.type_as_union().ok_or_else(missing)?;
 
             let union_mode = match union.mode() {
                 crate::UnionMode::Dense => UnionMode::Dense,
                 crate::UnionMode::Sparse => UnionMode::Sparse,
-                mode => panic!("Unexpected union mode: {mode:?}"),
+                mode => {
+                    return Err(schema_err(format!("Unexpected union mode: {mode:?}")))
+                }
             };
 
             let mut fields = vec![];
             if let Some(children) = field.children() {
                 for i in 0..children.len() {
-                    fields.push(Field::from(children.get(i)));
+                    fields.push(try_field_from_fb(children.get(i))?);
                 }
             };
 
-            let fields = match union.typeIds() {
-                None => UnionFields::new(0_i8..fields.len() as i8, fields),
-                Some(ids) => UnionFields::new(ids.iter().map(|i| i as i8), fields),
+            let ids: Vec<i32> = match union.typeIds() {
+                None => (0..fields.len() as i32).collect(),
+                Some(ids) => ids.iter().collect(),
             };
+            if ids.len() != fields.len() {
+                return Err(schema_err(format!(
+                    "Union has {} type ids but {} children",
+                    ids.len(),
+                    fields.len()
+                )));
+            }
+            let mut seen = 0_u128;
+            for id in &ids {
+                if !(0..128).contains(id) || seen & (1 << id) != 0 {
+                    return Err(schema_err(format!("Invalid union type id {id}")));
+                }
+                seen |= 1 << id;
+            }
+            let fields = UnionFields::new(ids.into_iter().map(|i| i as i8), fields);
 
             DataType::Union(fields, union_mode)
         }
-        t => unimplemented!("Type {:?} not supported", t),
-    }
+        t => return Err(schema_err(format!("Type {t:?} not supported"))),
+    })
 }
 
 pub(crate) struct FBFieldType<'b> {
@@ -692,6 +791,24 @@ pub(crate) fn get_fb_field_type<'a>(
                 children: Some(fbb.create_vector(&[child])),
             }
         }
+        ListView(ref list_type) => {
+            let child = build_field(fbb, list_type);
+            FBFieldType {
+                type_type: crate::Type::ListView,
+                type_: crate::ListViewBuilder::new(fbb).finish().as_union_value(),
+                children: Some(fbb.create_vector(&[child])),
+            }
+        }
+        LargeListView(ref list_type) => {
+            let child = build_field(fbb, list_type);
+            FBFieldType {
+                type_type: crate::Type::LargeListView,
+                type_: crate::LargeListViewBuilder::new(fbb)
+                    .finish()
+                    .as_union_value(),
+                children: Some(fbb.create_vector(&[child])),
+            }
+        }
         FixedSizeList(ref list_type, len) => {
             let child = build_field(fbb, list_type);
             let mut builder = crate::FixedSizeListBuilder::new(fbb);
@@ -742,6 +859,28 @@ pub(crate) fn get_fb_field_type<'a>(
             // type in the DictionaryEncoding metadata in the parent field
             get_fb_field_type(value_type, fbb)
         }
+        Decimal32(precision, scale) => {
+            let mut builder = crate::DecimalBuilder::new(fbb);
+            builder.add_precision(*precision as i32);
+            builder.add_scale(*scale as i32);
+            builder.add_bitWidth(32);
+            FBFieldType {
+                type_type: crate::Type::Decimal,
+                type_: builder.finish().as_union_value(),
+                children: Some(fbb.create_vector(&empty_fields[..])),
+            }
+        }
+        Decimal64(precision, scale) => {
+            let mut builder = crate::DecimalBuilder::new(fbb);
+            builder.add_precision(*precision as i32);
+            builder.add_scale(*scale as i32);
+            builder.add_bitWidth(64);
+            FBFieldType {
+                type_type: crate::Type::Decimal,
+                type_: builder.finish().as_union_value(),
+                children: Some(fbb.create_vector(&empty_fields[..])),
+            }
+        }
         Decimal128(precision, scale) => {
             let mut builder = crate::DecimalBuilder::new(fbb);
             builder.add_precision(*precision as i32);
@@ -905,6 +1044,16 @@ mod tests {
                     Field::new("item", DataType::UInt8, false),
                     true,
                 ),
+                Field::new(
+                    "list_view[u8]",
+                    DataType::ListView(Arc::new(Field::new("item", UInt8, false))),
+                    true,
+                ),
+                Field::new(
+                    "large_list_view[utf8]",
+                    DataType::LargeListView(Arc::new(Field::new("item", Utf8, true))),
+                    false,
+                ),
                 Field::new_list(
                     "list[struct<float32, int32, bool>]",
                     Field::new_struct(
@@ -1032,6 +1181,8 @@ mod tests {
                     true,
                 ),
                 Field::new("decimal<usize, usize>", DataType::Decimal128(10, 6), false),
+                Field::new("decimal32", DataType::Decimal32(9, 2), false),
+                Field::new("decimal64", DataType::Decimal64(18, -2), true),
             ],
             md,
         );
@@ -19,10 +19,16 @@
 //!
 //! [Arrow IPC Format]: https://arrow.apache.org/docs/format/Columnar.html#serialization-and-interprocess-communication-ipc
 
+pub mod bytes;
 pub mod convert;
 pub mod reader;
 pub mod writer;
 
+#[cfg(feature = "async")]
+pub mod async_reader;
+#[cfg(feature = "async")]
+pub mod async_writer;
+
 mod compression;
 
 #[allow(clippy::redundant_closure)]
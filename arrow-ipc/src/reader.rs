@@ -25,17 +25,150 @@ use flatbuffers::VectorIter;
 use std::collections::HashMap;
 use std::fmt;
 use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::ops::{ControlFlow, Range};
 use std::sync::Arc;
 
 use arrow_array::*;
 use arrow_buffer::{Buffer, MutableBuffer};
-use arrow_data::ArrayData;
+use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::*;
+use arrow_select::concat::concat;
 
 use crate::compression::CompressionCodec;
 use crate::{FieldNode, MetadataVersion, CONTINUATION_MARKER};
 use DataType::*;
 
+/// The maximum nesting depth of the arrays read by the IPC readers
+pub const MAX_NESTING_DEPTH: usize = 64;
+
+/// An error encountered decoding malformed or malicious IPC data
+///
+/// The IPC readers return this error wrapped in [`ArrowError::ExternalError`], allowing
+/// it to be inspected with [`std::error::Error::downcast_ref`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IpcError {
+    /// The schema is invalid or contains unsupported types
+    InvalidSchema(String),
+    /// A length, such as that of a message, body or footer, is negative or exceeds
+    /// the available data
+    InvalidLength {
+        /// What the length describes
+        context: &'static str,
+        /// The length found
+        length: i64,
+    },
+    /// A field node has a negative length or null count, or more nulls than values
+    InvalidFieldNode {
+        /// The field the node belongs to
+        field: String,
+        /// The length of the node
+        length: i64,
+        /// The null count of the node
+        null_count: i64,
+    },
+    /// A buffer lies outside of the body of the message
+    BufferOutOfBounds {
+        /// The offset of the buffer within the body
+        offset: i64,
+        /// The length of the buffer
+        length: i64,
+        /// The length of the body
+        body_length: usize,
+    },
+    /// A buffer is too short for the array it belongs to
+    BufferTooShort {
+        /// The minimum length in bytes
+        expected: usize,
+        /// The actual length in bytes
+        actual: usize,
+    },
+    /// A record batch contains fewer buffers than required by the schema
+    MissingBuffer,
+    /// A flatbuffer member required to decode the message is missing
+    MissingMember(&'static str),
+    /// The arrays are nested deeper than [`MAX_NESTING_DEPTH`]
+    NestingTooDeep,
+}
+
+impl fmt::Display for IpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSchema(msg) => write!(f, "Invalid IPC schema: {msg}"),
+            Self::InvalidLength { context, length } => {
+                write!(f, "Invalid {context} length {length}")
+            }
+            Self::InvalidFieldNode {
+                field,
+                length,
+                null_count,
+            } => write!(
+                f,
+                "Invalid field node for {field} with length {length} and null count {null_count}"
+            ),
+            Self::BufferOutOfBounds {
+                offset,
+                length,
+                body_length,
+            } => write!(
+                f,
+                "Buffer at offset {offset} with length {length} exceeds body of {body_length} bytes"
+            ),
+            Self::BufferTooShort { expected, actual } => write!(
+                f,
+                "Buffer of {actual} bytes is shorter than the expected {expected} bytes"
+            ),
+            Self::MissingBuffer => write!(f, "Record batch is missing buffers"),
+            Self::MissingMember(member) => write!(f, "IPC message is missing {member}"),
+            Self::NestingTooDeep => write!(
+                f,
+                "Arrays are nested deeper than the maximum of {MAX_NESTING_DEPTH}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for IpcError {}
+
+impl From<IpcError> for ArrowError {
+    fn from(value: IpcError) -> Self {
+        ArrowError::ExternalError(Box::new(value))
+    }
+}
+
+/// Validates `length` is non-negative and at most `max`, returning it as a `usize`
+pub(crate) fn checked_length(
+    context: &'static str,
+    length: i64,
+    max: usize,
+) -> Result<usize, IpcError> {
+    usize::try_from(length)
+        .ok()
+        .filter(|l| *l <= max)
+        .ok_or(IpcError::InvalidLength { context, length })
+}
+
+/// The size of the chunks read by [`read_exact_buffer`]
+const READ_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Reads exactly `len` bytes from `reader` into a [`MutableBuffer`]
+///
+/// The buffer grows as data is read, so a corrupt length cannot cause a large
+/// allocation unless the corresponding data is actually present
+fn read_exact_buffer<R: Read>(
+    reader: &mut R,
+    len: usize,
+) -> Result<MutableBuffer, ArrowError> {
+    let mut buf = MutableBuffer::new(len.min(READ_CHUNK_SIZE));
+    while buf.len() < len {
+        let start = buf.len();
+        let end = start + (len - start).min(READ_CHUNK_SIZE);
+        buf.resize(end, 0);
+        reader.read_exact(&mut buf[start..end])?;
+    }
+    Ok(buf)
+}
+
 /// Read a buffer based on offset and length
 /// From <https://github.com/apache/arrow/blob/6a936c4ff5007045e86f65f1a6b6c3c955ad5103/format/Message.fbs#L58>
 /// Each constituent buffer is first compressed with the indicated
@@ -50,12 +183,28 @@ fn read_buffer(
     a_data: &Buffer,
     compression_codec: Option<CompressionCodec>,
 ) -> Result<Buffer, ArrowError> {
-    let start_offset = buf.offset() as usize;
-    let buf_data = a_data.slice_with_length(start_offset, buf.length() as usize);
+    let out_of_bounds = || IpcError::BufferOutOfBounds {
+        offset: buf.offset(),
+        length: buf.length(),
+        body_length: a_data.len(),
+    };
+    let start_offset = usize::try_from(buf.offset()).map_err(|_| out_of_bounds())?;
+    let length = usize::try_from(buf.length()).map_err(|_| out_of_bounds())?;
+    start_offset
+        .checked_add(length)
+        .filter(|end| *end <= a_data.len())
+        .ok_or_else(out_of_bounds)?;
+    let buf_data = a_data.slice_with_length(start_offset, length);
     // corner case: empty buffer
-    match (buf_data.is_empty(), compression_codec) {
-        (true, _) | (_, None) => Ok(buf_data),
-        (false, Some(decompressor)) => decompressor.decompress_to_buffer(&buf_data),
+    let buffer = match (buf_data.is_empty(), compression_codec) {
+        (true, _) | (_, None) => buf_data,
+        (false, Some(decompressor)) => decompressor.decompress_to_buffer(&buf_data)?,
+    };
+    // Buffers written by conforming writers are 8-byte aligned, copy any that are
+    // not as arrays cannot be constructed from insufficiently aligned buffers
+    match buffer.as_ptr().align_offset(8) {
+        0 => Ok(buffer),
+        _ => Ok(Buffer::from_slice_ref(buffer.as_slice())),
     }
 }
 
@@ -69,6 +218,16 @@ fn read_buffer(
 ///     - read the buffer as 64-bit (signed integer or float), and
 ///     - cast the 64-bit array to the appropriate data type
 fn create_array(reader: &mut ArrayReader, field: &Field) -> Result<ArrayRef, ArrowError> {
+    reader.enter()?;
+    let result = create_array_impl(reader, field);
+    reader.depth -= 1;
+    result
+}
+
+fn create_array_impl(
+    reader: &mut ArrayReader,
+    field: &Field,
+) -> Result<ArrayRef, ArrowError> {
     let data_type = field.data_type();
     match data_type {
         Utf8 | Binary | LargeBinary | LargeUtf8 => create_primitive_array(
@@ -79,44 +238,69 @@ fn create_array(reader: &mut ArrayReader, field: &Field) -> Result<ArrayRef, Arr
                 reader.next_buffer()?,
                 reader.next_buffer()?,
             ],
+            reader.skip_validation,
         ),
         FixedSizeBinary(_) => create_primitive_array(
             reader.next_node(field)?,
             data_type,
             &[reader.next_buffer()?, reader.next_buffer()?],
+            reader.skip_validation,
         ),
         List(ref list_field) | LargeList(ref list_field) | Map(ref list_field, _) => {
             let list_node = reader.next_node(field)?;
             let list_buffers = [reader.next_buffer()?, reader.next_buffer()?];
             let values = create_array(reader, list_field)?;
-            create_list_array(list_node, data_type, &list_buffers, values)
+            create_list_array(
+                list_node,
+                data_type,
+                &list_buffers,
+                values,
+                reader.skip_validation,
+            )
+        }
+        ListView(ref list_field) | LargeListView(ref list_field) => {
+            let list_node = reader.next_node(field)?;
+            let list_buffers = [
+                reader.next_buffer()?,
+                reader.next_buffer()?,
+                reader.next_buffer()?,
+            ];
+            let values = create_array(reader, list_field)?;
+            create_list_array(
+                list_node,
+                data_type,
+                &list_buffers,
+                values,
+                reader.skip_validation,
+            )
         }
         FixedSizeList(ref list_field, _) => {
             let list_node = reader.next_node(field)?;
             let list_buffers = [reader.next_buffer()?];
             let values = create_array(reader, list_field)?;
-            create_list_array(list_node, data_type, &list_buffers, values)
+            create_list_array(
+                list_node,
+                data_type,
+                &list_buffers,
+                values,
+                reader.skip_validation,
+            )
         }
         Struct(struct_fields) => {
             let struct_node = reader.next_node(field)?;
             let null_buffer = reader.next_buffer()?;
 
             // read the arrays for each field
-            let mut struct_arrays = vec![];
-            // TODO investigate whether just knowing the number of buffers could
-            // still work
+            let mut children = vec![];
             for struct_field in struct_fields {
-                let child = create_array(reader, struct_field)?;
-                struct_arrays.push((struct_field.clone(), child));
+                children.push(create_array(reader, struct_field)?.into_data());
             }
-            let null_count = struct_node.null_count() as usize;
-            let struct_array = if null_count > 0 {
-                // create struct array from fields, arrays and null data
-                StructArray::from((struct_arrays, null_buffer))
-            } else {
-                StructArray::from(struct_arrays)
-            };
-            Ok(Arc::new(struct_array))
+            let null_buffer = (struct_node.null_count() > 0).then_some(null_buffer);
+            let builder = ArrayData::builder(data_type.clone())
+                .len(struct_node.length() as usize)
+                .child_data(children)
+                .null_bit_buffer(null_buffer);
+            Ok(make_array(build_array(builder, reader.skip_validation)?))
         }
         RunEndEncoded(run_ends_field, values_field) => {
             let run_node = reader.next_node(field)?;
@@ -124,12 +308,12 @@ fn create_array(reader: &mut ArrayReader, field: &Field) -> Result<ArrayRef, Arr
             let values = create_array(reader, values_field)?;
 
             let run_array_length = run_node.length() as usize;
-            let data = ArrayData::builder(data_type.clone())
+            let builder = ArrayData::builder(data_type.clone())
                 .len(run_array_length)
                 .offset(0)
                 .add_child_data(run_ends.into_data())
-                .add_child_data(values.into_data())
-                .build()?;
+                .add_child_data(values.into_data());
+            let data = build_array(builder, reader.skip_validation)?;
 
             Ok(make_array(data))
         }
@@ -154,6 +338,7 @@ fn create_array(reader: &mut ArrayReader, field: &Field) -> Result<ArrayRef, Arr
                 data_type,
                 &index_buffers,
                 value_array.clone(),
+                reader.skip_validation,
             )
         }
         Union(fields, mode) => {
@@ -166,12 +351,17 @@ fn create_array(reader: &mut ArrayReader, field: &Field) -> Result<ArrayRef, Arr
                 reader.next_buffer()?;
             }
 
-            let type_ids: Buffer = reader.next_buffer()?[..len].into();
+            let type_ids: Buffer = checked_prefix(&reader.next_buffer()?, len)?.into();
 
             let value_offsets = match mode {
                 UnionMode::Dense => {
                     let buffer = reader.next_buffer()?;
-                    Some(buffer[..len * 4].into())
+                    let offsets_len =
+                        len.checked_mul(4).ok_or(IpcError::InvalidLength {
+                            context: "union",
+                            length: union_node.length(),
+                        })?;
+                    Some(checked_prefix(&buffer, offsets_len)?.into())
                 }
                 UnionMode::Sparse => None,
             };
@@ -211,27 +401,49 @@ fn create_array(reader: &mut ArrayReader, field: &Field) -> Result<ArrayRef, Arr
             reader.next_node(field)?,
             data_type,
             &[reader.next_buffer()?, reader.next_buffer()?],
+            reader.skip_validation,
         ),
     }
 }
 
+/// Returns the first `len` bytes of `buffer`, returning an error if it is too short
+fn checked_prefix(buffer: &Buffer, len: usize) -> Result<&[u8], IpcError> {
+    buffer.get(..len).ok_or(IpcError::BufferTooShort {
+        expected: len,
+        actual: buffer.len(),
+    })
+}
+
+/// Builds the [`ArrayData`] from `builder`, skipping validation if `skip_validation`
+fn build_array(
+    builder: ArrayDataBuilder,
+    skip_validation: bool,
+) -> Result<ArrayData, ArrowError> {
+    match skip_validation {
+        // SAFETY: only set by the unsafe with_skip_validation of the readers,
+        // whose caller asserts that the data is valid
+        true => Ok(unsafe { builder.build_unchecked() }),
+        false => builder.build(),
+    }
+}
+
 /// Reads the correct number of buffers based on data type and null_count, and creates a
 /// primitive array ref
 fn create_primitive_array(
     field_node: &FieldNode,
     data_type: &DataType,
     buffers: &[Buffer],
+    skip_validation: bool,
 ) -> Result<ArrayRef, ArrowError> {
     let length = field_node.length() as usize;
     let null_buffer = (field_node.null_count() > 0).then_some(buffers[0].clone());
-    let array_data = match data_type {
+    let builder = match data_type {
         Utf8 | Binary | LargeBinary | LargeUtf8 => {
             // read 3 buffers: null buffer (optional), offsets buffer and data buffer
             ArrayData::builder(data_type.clone())
                 .len(length)
                 .buffers(buffers[1..3].to_vec())
                 .null_bit_buffer(null_buffer)
-                .build()?
         }
         Int8
         | Int16
@@ -245,20 +457,22 @@ fn create_primitive_array(
         | Interval(IntervalUnit::DayTime)
         | FixedSizeBinary(_)
         | Boolean
+        | Decimal32(_, _)
         | Int64
         | UInt64
+        | Float16
         | Float32
         | Float64
         | Time64(_)
         | Timestamp(_, _)
         | Date64
+        | Decimal64(_, _)
         | Duration(_) => {
             // read 2 buffers: null buffer (optional) and data buffer
             ArrayData::builder(data_type.clone())
                 .len(length)
                 .add_buffer(buffers[1].clone())
                 .null_bit_buffer(null_buffer)
-                .build()?
         }
         Interval(IntervalUnit::MonthDayNano) | Decimal128(_, _) => {
             let buffer = get_aligned_buffer::<i128>(&buffers[1], length);
@@ -268,7 +482,6 @@ fn create_primitive_array(
                 .len(length)
                 .add_buffer(buffer)
                 .null_bit_buffer(null_buffer)
-                .build()?
         }
         Decimal256(_, _) => {
             let buffer = get_aligned_buffer::<i256>(&buffers[1], length);
@@ -278,12 +491,15 @@ fn create_primitive_array(
                 .len(length)
                 .add_buffer(buffer)
                 .null_bit_buffer(null_buffer)
-                .build()?
         }
-        t => unreachable!("Data type {:?} either unsupported or not primitive", t),
+        t => {
+            return Err(ArrowError::IoError(format!(
+                "Data type {t:?} either unsupported or not primitive"
+            )))
+        }
     };
 
-    Ok(make_array(array_data))
+    Ok(make_array(build_array(builder, skip_validation)?))
 }
 
 /// Checks if given `Buffer` is properly aligned with `T`.
@@ -296,7 +512,9 @@ fn get_aligned_buffer<T>(buffer: &Buffer, length: usize) -> Buffer {
     // e.g. 8 bytes, but on some platform (e.g. ARM) i128 requires 16 bytes alignment.
     // We need to copy the buffer as fallback.
     if align_offset != 0 {
-        let len_in_bytes = (length * std::mem::size_of::<T>()).min(buffer.len());
+        let len_in_bytes = length
+            .saturating_mul(std::mem::size_of::<T>())
+            .min(buffer.len());
         let slice = &buffer.as_slice()[0..len_in_bytes];
         Buffer::from_slice_ref(slice)
     } else {
@@ -311,6 +529,7 @@ fn create_list_array(
     data_type: &DataType,
     buffers: &[Buffer],
     child_array: ArrayRef,
+    skip_validation: bool,
 ) -> Result<ArrayRef, ArrowError> {
     let null_buffer = (field_node.null_count() > 0).then_some(buffers[0].clone());
     let length = field_node.length() as usize;
@@ -322,6 +541,13 @@ fn create_list_array(
             .add_child_data(child_data)
             .null_bit_buffer(null_buffer),
 
+        ListView(_) | LargeListView(_) => ArrayData::builder(data_type.clone())
+            .len(length)
+            .add_buffer(buffers[1].clone())
+            .add_buffer(buffers[2].clone())
+            .add_child_data(child_data)
+            .null_bit_buffer(null_buffer),
+
         FixedSizeList(_, _) => ArrayData::builder(data_type.clone())
             .len(length)
             .add_child_data(child_data)
@@ -329,7 +555,7 @@ fn create_list_array(
 
         _ => unreachable!("Cannot create list or map array from {:?}", data_type),
     };
-    Ok(make_array(builder.build()?))
+    Ok(make_array(build_array(builder, skip_validation)?))
 }
 
 /// Reads the correct number of buffers based on list type and null_count, and creates a
@@ -339,6 +565,7 @@ fn create_dictionary_array(
     data_type: &DataType,
     buffers: &[Buffer],
     value_array: ArrayRef,
+    skip_validation: bool,
 ) -> Result<ArrayRef, ArrowError> {
     if let Dictionary(_, _) = *data_type {
         let null_buffer = (field_node.null_count() > 0).then_some(buffers[0].clone());
@@ -348,7 +575,7 @@ fn create_dictionary_array(
             .add_child_data(value_array.into_data())
             .null_bit_buffer(null_buffer);
 
-        Ok(make_array(builder.build()?))
+        Ok(make_array(build_array(builder, skip_validation)?))
     } else {
         unreachable!("Cannot create dictionary array from {:?}", data_type)
     }
@@ -368,50 +595,88 @@ struct ArrayReader<'a> {
     nodes: VectorIter<'a, FieldNode>,
     /// The buffers comprising this array
     buffers: VectorIter<'a, crate::Buffer>,
+    /// Skip validation of the decoded arrays
+    skip_validation: bool,
+    /// The current nesting depth
+    depth: usize,
 }
 
 impl<'a> ArrayReader<'a> {
     fn next_buffer(&mut self) -> Result<Buffer, ArrowError> {
-        read_buffer(self.buffers.next().unwrap(), self.data, self.compression)
+        let buffer = self.buffers.next().ok_or(IpcError::MissingBuffer)?;
+        read_buffer(buffer, self.data, self.compression)
     }
 
-    fn skip_buffer(&mut self) {
-        self.buffers.next().unwrap();
+    fn skip_buffer(&mut self) -> Result<(), ArrowError> {
+        self.buffers.next().ok_or(IpcError::MissingBuffer)?;
+        Ok(())
     }
 
     fn next_node(&mut self, field: &Field) -> Result<&'a FieldNode, ArrowError> {
-        self.nodes.next().ok_or_else(|| {
+        let node = self.nodes.next().ok_or_else(|| {
             ArrowError::IoError(format!(
                 "Invalid data for schema. {} refers to node not found in schema",
                 field
             ))
-        })
+        })?;
+        if node.length() < 0 || node.null_count() < 0 || node.null_count() > node.length()
+        {
+            return Err(IpcError::InvalidFieldNode {
+                field: field.name().to_string(),
+                length: node.length(),
+                null_count: node.null_count(),
+            }
+            .into());
+        }
+        Ok(node)
+    }
+
+    /// Increments the nesting depth, returning an error if it exceeds [`MAX_NESTING_DEPTH`]
+    fn enter(&mut self) -> Result<(), ArrowError> {
+        self.depth += 1;
+        match self.depth > MAX_NESTING_DEPTH {
+            true => Err(IpcError::NestingTooDeep.into()),
+            false => Ok(()),
+        }
     }
 
     fn skip_field(&mut self, field: &Field) -> Result<(), ArrowError> {
+        self.enter()?;
+        let result = self.skip_field_impl(field);
+        self.depth -= 1;
+        result
+    }
+
+    fn skip_field_impl(&mut self, field: &Field) -> Result<(), ArrowError> {
         self.next_node(field)?;
 
         match field.data_type() {
             Utf8 | Binary | LargeBinary | LargeUtf8 => {
                 for _ in 0..3 {
-                    self.skip_buffer()
+                    self.skip_buffer()?
                 }
             }
             FixedSizeBinary(_) => {
-                self.skip_buffer();
-                self.skip_buffer();
+                self.skip_buffer()?;
+                self.skip_buffer()?;
             }
             List(list_field) | LargeList(list_field) | Map(list_field, _) => {
-                self.skip_buffer();
-                self.skip_buffer();
+                self.skip_buffer()?;
+                self.skip_buffer()?;
+                self.skip_field(list_field)?;
+            }
+            ListView(list_field) | LargeListView(list_field) => {
+                for _ in 0..3 {
+                    self.skip_buffer()?
+                }
                 self.skip_field(list_field)?;
             }
             FixedSizeList(list_field, _) => {
-                self.skip_buffer();
+                self.skip_buffer()?;
                 self.skip_field(list_field)?;
             }
             Struct(struct_fields) => {
-                self.skip_buffer();
+                self.skip_buffer()?;
 
                 // skip for each field
                 for struct_field in struct_fields {
@@ -423,14 +688,14 @@ impl<'a> ArrayReader<'a> {
                 self.skip_field(values_field)?;
             }
             Dictionary(_, _) => {
-                self.skip_buffer(); // Nulls
-                self.skip_buffer(); // Indices
+                self.skip_buffer()?; // Nulls
+                self.skip_buffer()?; // Indices
             }
             Union(fields, mode) => {
-                self.skip_buffer(); // Nulls
+                self.skip_buffer()?; // Nulls
 
                 match mode {
-                    UnionMode::Dense => self.skip_buffer(),
+                    UnionMode::Dense => self.skip_buffer()?,
                     UnionMode::Sparse => {}
                 };
 
@@ -440,8 +705,8 @@ impl<'a> ArrayReader<'a> {
             }
             Null => {} // No buffer increases
             _ => {
-                self.skip_buffer();
-                self.skip_buffer();
+                self.skip_buffer()?;
+                self.skip_buffer()?;
             }
         };
         Ok(())
@@ -456,6 +721,26 @@ pub fn read_record_batch(
     dictionaries_by_id: &HashMap<i64, ArrayRef>,
     projection: Option<&[usize]>,
     metadata: &MetadataVersion,
+) -> Result<RecordBatch, ArrowError> {
+    read_record_batch_impl(
+        buf,
+        batch,
+        schema,
+        dictionaries_by_id,
+        projection,
+        metadata,
+        false,
+    )
+}
+
+fn read_record_batch_impl(
+    buf: &Buffer,
+    batch: crate::RecordBatch,
+    schema: SchemaRef,
+    dictionaries_by_id: &HashMap<i64, ArrayRef>,
+    projection: Option<&[usize]>,
+    metadata: &MetadataVersion,
+    skip_validation: bool,
 ) -> Result<RecordBatch, ArrowError> {
     let buffers = batch.buffers().ok_or_else(|| {
         ArrowError::IoError("Unable to get buffers from IPC RecordBatch".to_string())
@@ -475,9 +760,12 @@ pub fn read_record_batch(
         data: buf,
         nodes: field_nodes.iter(),
         buffers: buffers.iter(),
+        skip_validation,
+        depth: 0,
     };
 
-    let options = RecordBatchOptions::new().with_row_count(Some(batch.length() as usize));
+    let row_count = checked_length("record batch", batch.length(), usize::MAX)?;
+    let options = RecordBatchOptions::new().with_row_count(Some(row_count));
 
     if let Some(projection) = projection {
         let mut arrays = vec![];
@@ -508,6 +796,75 @@ pub fn read_record_batch(
     }
 }
 
+/// The maximum gap between the buffers of projected columns that is read, instead of
+/// seeking past it, see [`projected_ranges`]
+const MAX_PROJECTION_GAP: usize = 64;
+
+/// Returns the byte ranges within the body of `batch` containing the buffers of the
+/// columns in `projection`, coalescing ranges separated by small gaps
+fn projected_ranges(
+    batch: crate::RecordBatch,
+    schema: &Schema,
+    projection: &[usize],
+) -> Result<Vec<Range<usize>>, ArrowError> {
+    let buffers = batch.buffers().ok_or_else(|| {
+        ArrowError::IoError("Unable to get buffers from IPC RecordBatch".to_string())
+    })?;
+    let field_nodes = batch.nodes().ok_or_else(|| {
+        ArrowError::IoError("Unable to get field nodes from IPC RecordBatch".to_string())
+    })?;
+
+    // Only the nodes and buffers are used to skip fields
+    let data = Buffer::from_vec(Vec::<u8>::new());
+    let dictionaries_by_id = HashMap::new();
+    let mut reader = ArrayReader {
+        dictionaries_by_id: &dictionaries_by_id,
+        compression: None,
+        version: MetadataVersion::V5,
+        data: &data,
+        nodes: field_nodes.iter(),
+        buffers: buffers.iter(),
+        skip_validation: false,
+        depth: 0,
+    };
+
+    let mut ranges: Vec<Range<usize>> = vec![];
+    for (idx, field) in schema.fields().iter().enumerate() {
+        let start = buffers.len() - reader.buffers.len();
+        reader.skip_field(field)?;
+        if !projection.contains(&idx) {
+            continue;
+        }
+        let end = buffers.len() - reader.buffers.len();
+        for buffer in buffers.iter().skip(start).take(end - start) {
+            let out_of_bounds = || IpcError::BufferOutOfBounds {
+                offset: buffer.offset(),
+                length: buffer.length(),
+                body_length: usize::MAX,
+            };
+            let offset = usize::try_from(buffer.offset()).map_err(|_| out_of_bounds())?;
+            let end = usize::try_from(buffer.length())
+                .ok()
+                .and_then(|length| offset.checked_add(length))
+                .ok_or_else(out_of_bounds)?;
+            let range = offset..end;
+            if range.is_empty() {
+                continue;
+            }
+            match ranges.last_mut() {
+                Some(last)
+                    if range.start >= last.start
+                        && range.start <= last.end + MAX_PROJECTION_GAP =>
+                {
+                    last.end = last.end.max(range.end)
+                }
+                _ => ranges.push(range),
+            }
+        }
+    }
+    Ok(ranges)
+}
+
 /// Read the dictionary from the buffer and provided metadata,
 /// updating the `dictionaries_by_id` with the resulting dictionary
 pub fn read_dictionary(
@@ -517,12 +874,17 @@ pub fn read_dictionary(
     dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
     metadata: &crate::MetadataVersion,
 ) -> Result<(), ArrowError> {
-    if batch.isDelta() {
-        return Err(ArrowError::IoError(
-            "delta dictionary batches not supported".to_string(),
-        ));
-    }
+    read_dictionary_impl(buf, batch, schema, dictionaries_by_id, metadata, false)
+}
 
+fn read_dictionary_impl(
+    buf: &Buffer,
+    batch: crate::DictionaryBatch,
+    schema: &Schema,
+    dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
+    metadata: &crate::MetadataVersion,
+    skip_validation: bool,
+) -> Result<(), ArrowError> {
     let id = batch.id();
     let fields_using_this_dictionary = schema.fields_with_dict_id(id);
     let first_field = fields_using_this_dictionary.first().ok_or_else(|| {
@@ -538,13 +900,16 @@ pub fn read_dictionary(
             let value = value_type.as_ref().clone();
             let schema = Schema::new(vec![Field::new("", value, true)]);
             // Read a single column
-            let record_batch = read_record_batch(
+            let record_batch = read_record_batch_impl(
                 buf,
-                batch.data().unwrap(),
+                batch
+                    .data()
+                    .ok_or(IpcError::MissingMember("dictionary batch data"))?,
                 Arc::new(schema),
                 dictionaries_by_id,
                 None,
                 metadata,
+                skip_validation,
             )?;
             Some(record_batch.column(0).clone())
         }
@@ -557,7 +922,19 @@ pub fn read_dictionary(
     // We don't currently record the isOrdered field. This could be general
     // attributes of arrays.
     // Add (possibly multiple) array refs to the dictionaries array.
-    dictionaries_by_id.insert(id, dictionary_values.clone());
+    let dictionary_values = match batch.isDelta() {
+        true => {
+            // A delta dictionary batch appends its values to the existing dictionary
+            let existing = dictionaries_by_id.get(&id).ok_or_else(|| {
+                ArrowError::IoError(format!(
+                    "delta dictionary batch for id {id} without a preceding dictionary"
+                ))
+            })?;
+            concat(&[existing.as_ref(), dictionary_values.as_ref()])?
+        }
+        false => dictionary_values,
+    };
+    dictionaries_by_id.insert(id, dictionary_values);
 
     Ok(())
 }
@@ -567,6 +944,9 @@ pub struct FileReader<R: Read + Seek> {
     /// Buffered file reader that supports reading and seeking
     reader: BufReader<R>,
 
+    /// The length of the file in bytes
+    file_len: usize,
+
     /// The schema that is read from the file header
     schema: SchemaRef,
 
@@ -581,6 +961,9 @@ pub struct FileReader<R: Read + Seek> {
     /// The total number of blocks, which may contain record batches and other types
     total_blocks: usize,
 
+    /// The index of the block after the last block that should be read
+    end_block: usize,
+
     /// Optional dictionaries for each schema field.
     ///
     /// Dictionaries may be appended to in the streaming format.
@@ -592,8 +975,14 @@ pub struct FileReader<R: Read + Seek> {
     /// User defined metadata
     custom_metadata: HashMap<String, String>,
 
+    /// User defined metadata of the message containing the last record batch read
+    batch_custom_metadata: HashMap<String, String>,
+
     /// Optional projection and projected_schema
     projection: Option<(Vec<usize>, Schema)>,
+
+    /// Skip validation of the arrays read from the file
+    skip_validation: bool,
 }
 
 impl<R: Read + Seek> fmt::Debug for FileReader<R> {
@@ -604,9 +993,11 @@ impl<R: Read + Seek> fmt::Debug for FileReader<R> {
             .field("blocks", &self.blocks)
             .field("current_block", &self.current_block)
             .field("total_blocks", &self.total_blocks)
+            .field("end_block", &self.end_block)
             .field("dictionaries_by_id", &self.dictionaries_by_id)
             .field("metadata_version", &self.metadata_version)
             .field("projection", &self.projection)
+            .field("skip_validation", &self.skip_validation)
             .finish()
     }
 }
@@ -636,14 +1027,19 @@ impl<R: Read + Seek> FileReader<R> {
                 "Arrow file does not contain correct footer".to_string(),
             ));
         }
+        let file_len =
+            usize::try_from(reader.seek(SeekFrom::End(0))?).unwrap_or(usize::MAX);
+
         // read footer length
         let mut footer_size: [u8; 4] = [0; 4];
         reader.seek(SeekFrom::End(-10))?;
         reader.read_exact(&mut footer_size)?;
         let footer_len = i32::from_le_bytes(footer_size);
+        let footer_len =
+            checked_length("footer", footer_len as i64, file_len.saturating_sub(10))?;
 
         // read footer
-        let mut footer_data = vec![0; footer_len as usize];
+        let mut footer_data = vec![0; footer_len];
         reader.seek(SeekFrom::End(-10 - footer_len as i64))?;
         reader.read_exact(&mut footer_data)?;
 
@@ -659,18 +1055,12 @@ impl<R: Read + Seek> FileReader<R> {
 
         let total_blocks = blocks.len();
 
-        let ipc_schema = footer.schema().unwrap();
-        let schema = crate::convert::fb_to_schema(ipc_schema);
+        let ipc_schema = footer.schema().ok_or_else(|| {
+            ArrowError::IoError("Unable to get schema from IPC Footer".to_string())
+        })?;
+        let schema = crate::convert::try_fb_to_schema(ipc_schema)?;
 
-        let mut custom_metadata = HashMap::new();
-        if let Some(fb_custom_metadata) = footer.custom_metadata() {
-            for kv in fb_custom_metadata.into_iter() {
-                custom_metadata.insert(
-                    kv.key().unwrap().to_string(),
-                    kv.value().unwrap().to_string(),
-                );
-            }
-        }
+        let custom_metadata = footer_custom_metadata(&footer);
 
         // Create an array of optional dictionary value arrays, one per field.
         let mut dictionaries_by_id = HashMap::new();
@@ -683,10 +1073,10 @@ impl<R: Read + Seek> FileReader<R> {
                 if message_size == CONTINUATION_MARKER {
                     reader.read_exact(&mut message_size)?;
                 }
-                let footer_len = i32::from_le_bytes(message_size);
-                let mut block_data = vec![0; footer_len as usize];
-
-                reader.read_exact(&mut block_data)?;
+                let message_len = i32::from_le_bytes(message_size);
+                let message_len =
+                    checked_length("message", message_len as i64, file_len)?;
+                let block_data = read_exact_buffer(&mut reader, message_len)?;
 
                 let message = crate::root_as_message(&block_data[..]).map_err(|err| {
                     ArrowError::IoError(format!("Unable to get root as message: {err:?}"))
@@ -694,15 +1084,15 @@ impl<R: Read + Seek> FileReader<R> {
 
                 match message.header_type() {
                     crate::MessageHeader::DictionaryBatch => {
-                        let batch = message.header_as_dictionary_batch().unwrap();
+                        let batch = message
+                            .header_as_dictionary_batch()
+                            .ok_or(IpcError::MissingMember("dictionary batch header"))?;
 
                         // read the block that makes up the dictionary batch into a buffer
-                        let mut buf =
-                            MutableBuffer::from_len_zeroed(message.bodyLength() as usize);
-                        reader.seek(SeekFrom::Start(
-                            block.offset() as u64 + block.metaDataLength() as u64,
-                        ))?;
-                        reader.read_exact(&mut buf)?;
+                        let body_len =
+                            checked_length("body", message.bodyLength(), file_len)?;
+                        reader.seek(SeekFrom::Start(body_start(block)?))?;
+                        let buf = read_exact_buffer(&mut reader, body_len)?;
 
                         read_dictionary(
                             &buf.into(),
@@ -730,22 +1120,48 @@ impl<R: Read + Seek> FileReader<R> {
 
         Ok(Self {
             reader,
+            file_len,
             schema: Arc::new(schema),
             blocks: blocks.iter().copied().collect(),
             current_block: 0,
             total_blocks,
+            end_block: total_blocks,
             dictionaries_by_id,
             metadata_version: footer.version(),
             custom_metadata,
+            batch_custom_metadata: HashMap::new(),
             projection,
+            skip_validation: false,
         })
     }
 
+    /// Skip validation of the record batches read from the file
+    ///
+    /// This avoids the cost of validating the offsets, UTF-8 data and dictionary keys
+    /// of potentially large arrays. Dictionaries are always validated.
+    ///
+    /// # Safety
+    ///
+    /// The file must have been written by a trusted writer, and contain valid Arrow
+    /// data, otherwise subsequent operations on the arrays read are undefined behaviour
+    pub unsafe fn with_skip_validation(mut self, skip_validation: bool) -> Self {
+        self.skip_validation = skip_validation;
+        self
+    }
+
     /// Return user defined customized metadata
     pub fn custom_metadata(&self) -> &HashMap<String, String> {
         &self.custom_metadata
     }
 
+    /// Returns the user defined metadata of the message containing the last record
+    /// batch read, as written by [`FileWriter::write_with_custom_metadata`]
+    ///
+    /// [`FileWriter::write_with_custom_metadata`]: crate::writer::FileWriter::write_with_custom_metadata
+    pub fn batch_custom_metadata(&self) -> &HashMap<String, String> {
+        &self.batch_custom_metadata
+    }
+
     /// Return the number of batches in the file
     pub fn num_batches(&self) -> usize {
         self.total_blocks
@@ -771,6 +1187,55 @@ impl<R: Read + Seek> FileReader<R> {
         }
     }
 
+    /// Restrict reading to the record batches with indices within `range`
+    ///
+    /// Sets the current block to the start of `range`, with the record batches
+    /// outside of `range` skipped without being read
+    pub fn set_batch_range(&mut self, range: Range<usize>) -> Result<(), ArrowError> {
+        if range.start > range.end || range.end > self.total_blocks {
+            return Err(ArrowError::IoError(format!(
+                "Cannot set batch range to {:?} from {} total batches",
+                range, self.total_blocks
+            )));
+        }
+        self.current_block = range.start;
+        self.end_block = range.end;
+        Ok(())
+    }
+
+    /// Reads the body of the record batch `batch` in `block`
+    ///
+    /// If a projection is set, only the buffers of the projected columns are read,
+    /// with the rest of the body left zeroed
+    fn read_body(
+        &mut self,
+        block: &crate::Block,
+        batch: crate::RecordBatch,
+        body_len: usize,
+    ) -> Result<Buffer, ArrowError> {
+        let body_start = body_start(block)?;
+        let projection = match &self.projection {
+            Some((projection, _)) => projection,
+            None => {
+                self.reader.seek(SeekFrom::Start(body_start))?;
+                return Ok(read_exact_buffer(&mut self.reader, body_len)?.into());
+            }
+        };
+        let mut buf = MutableBuffer::from_len_zeroed(body_len);
+
+        for range in projected_ranges(batch, &self.schema, projection)? {
+            if range.end > body_len {
+                return Err(ArrowError::IoError(format!(
+                    "Buffer range {range:?} exceeds record batch body of {body_len} bytes"
+                )));
+            }
+            self.reader
+                .seek(SeekFrom::Start(body_start + range.start as u64))?;
+            self.reader.read_exact(&mut buf[range])?;
+        }
+        Ok(buf.into())
+    }
+
     fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
         let block = self.blocks[self.current_block];
         self.current_block += 1;
@@ -784,15 +1249,393 @@ impl<R: Read + Seek> FileReader<R> {
             self.reader.read_exact(&mut meta_buf)?;
         }
         let meta_len = i32::from_le_bytes(meta_buf);
+        let meta_len = checked_length("message", meta_len as i64, self.file_len)?;
 
-        let mut block_data = vec![0; meta_len as usize];
-        self.reader.read_exact(&mut block_data)?;
+        let block_data = read_exact_buffer(&mut self.reader, meta_len)?;
         let message = crate::root_as_message(&block_data[..]).map_err(|err| {
             ArrowError::IoError(format!("Unable to get root as footer: {err:?}"))
         })?;
 
         // some old test data's footer metadata is not set, so we account for that
-        if self.metadata_version != crate::MetadataVersion::V1
+        if self.metadata_version != crate::MetadataVersion::V1
+            && message.version() != self.metadata_version
+        {
+            return Err(ArrowError::IoError(
+                "Could not read IPC message as metadata versions mismatch".to_string(),
+            ));
+        }
+
+        match message.header_type() {
+            crate::MessageHeader::Schema => Err(ArrowError::IoError(
+                "Not expecting a schema when messages are read".to_string(),
+            )),
+            crate::MessageHeader::RecordBatch => {
+                let batch = message.header_as_record_batch().ok_or_else(|| {
+                    ArrowError::IoError(
+                        "Unable to read IPC message as record batch".to_string(),
+                    )
+                })?;
+                // read the block that makes up the record batch into a buffer
+                let body_len =
+                    checked_length("body", message.bodyLength(), self.file_len)?;
+                let buf = self.read_body(&block, batch, body_len)?;
+                self.batch_custom_metadata = message_custom_metadata(&message);
+
+                read_record_batch_impl(
+                    &buf,
+                    batch,
+                    self.schema(),
+                    &self.dictionaries_by_id,
+                    self.projection.as_ref().map(|x| x.0.as_ref()),
+                    &message.version(),
+                    self.skip_validation,
+                ).map(Some)
+            }
+            crate::MessageHeader::NONE => {
+                Ok(None)
+            }
+            t => Err(ArrowError::IoError(format!(
+                "Reading types other than record batches not yet supported, unable to read {t:?}"
+            ))),
+        }
+    }
+
+    /// Gets a reference to the underlying reader.
+    ///
+    /// It is inadvisable to directly read from the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        self.reader.get_ref()
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// It is inadvisable to directly read from the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        self.reader.get_mut()
+    }
+}
+
+impl<R: Read + Seek> Iterator for FileReader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // get current block
+        if self.current_block < self.end_block {
+            self.maybe_next().transpose()
+        } else {
+            None
+        }
+    }
+}
+
+impl<R: Read + Seek> RecordBatchReader for FileReader<R> {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+/// Returns the offset of the body of `block` within the file
+fn body_start(block: &crate::Block) -> Result<u64, IpcError> {
+    u64::try_from(block.offset())
+        .ok()
+        .zip(u64::try_from(block.metaDataLength()).ok())
+        .and_then(|(offset, meta_len)| offset.checked_add(meta_len))
+        .ok_or(IpcError::InvalidLength {
+            context: "block",
+            length: block.offset(),
+        })
+}
+
+/// Returns the user defined metadata of `footer`
+pub(crate) fn footer_custom_metadata(footer: &crate::Footer) -> HashMap<String, String> {
+    custom_metadata_from_fb(footer.custom_metadata())
+}
+
+/// Returns the user defined metadata of `message`
+pub(crate) fn message_custom_metadata(
+    message: &crate::Message,
+) -> HashMap<String, String> {
+    custom_metadata_from_fb(message.custom_metadata())
+}
+
+/// Collects flatbuffer key-value pairs, skipping entries missing a key or value
+fn custom_metadata_from_fb(
+    fb_custom_metadata: Option<
+        flatbuffers::Vector<flatbuffers::ForwardsUOffset<crate::KeyValue>>,
+    >,
+) -> HashMap<String, String> {
+    let mut custom_metadata = HashMap::new();
+    if let Some(fb_custom_metadata) = fb_custom_metadata {
+        for kv in fb_custom_metadata.into_iter() {
+            if let (Some(k), Some(v)) = (kv.key(), kv.value()) {
+                custom_metadata.insert(k.to_string(), v.to_string());
+            }
+        }
+    }
+    custom_metadata
+}
+
+/// Returns the [`crate::Message`] and body of `block` within `buffer`
+pub(crate) fn read_block<'a>(
+    buffer: &'a Buffer,
+    block: &crate::Block,
+) -> Result<(crate::Message<'a>, Buffer), ArrowError> {
+    let invalid = || {
+        ArrowError::IoError(format!(
+            "Invalid block {block:?} for Arrow file of {} bytes",
+            buffer.len()
+        ))
+    };
+    let offset = usize::try_from(block.offset()).map_err(|_| invalid())?;
+    let meta_len = usize::try_from(block.metaDataLength()).map_err(|_| invalid())?;
+    let body_len = usize::try_from(block.bodyLength()).map_err(|_| invalid())?;
+
+    let meta_end = offset
+        .checked_add(meta_len)
+        .filter(|end| *end <= buffer.len())
+        .ok_or_else(invalid)?;
+    let body_end = meta_end
+        .checked_add(body_len)
+        .filter(|end| *end <= buffer.len())
+        .ok_or_else(invalid)?;
+
+    let mut meta = &buffer[offset..meta_end];
+    if meta.starts_with(&CONTINUATION_MARKER) {
+        meta = &meta[4..];
+    }
+    let message_len = match meta.get(..4) {
+        Some(len) => i32::from_le_bytes(len.try_into().unwrap()),
+        None => return Err(invalid()),
+    };
+    let message = usize::try_from(message_len)
+        .ok()
+        .and_then(|len| meta.get(4..4 + len))
+        .ok_or_else(invalid)?;
+
+    let message = crate::root_as_message(message).map_err(|err| {
+        ArrowError::IoError(format!("Unable to get root as message: {err:?}"))
+    })?;
+    let body = buffer.slice_with_length(meta_end, body_end - meta_end);
+    Ok((message, body))
+}
+
+/// Arrow File reader for a file held in memory as a [`Buffer`]
+///
+/// Unlike [`FileReader`], which copies the data of each batch out of the underlying
+/// reader, arrays read by this reader reference the provided [`Buffer`] directly, only
+/// copying data that is compressed or insufficiently aligned. When combined with a
+/// memory-mapped file, see `try_new_mmap` which requires the `memmap2` feature, this
+/// allows reading files larger than memory without loading them into memory first.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use arrow_buffer::Buffer;
+/// # use arrow_ipc::reader::FileBufferReader;
+/// # use arrow_ipc::writer::FileWriter;
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+/// let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+///
+/// let mut writer = FileWriter::try_new(vec![], &batch.schema()).unwrap();
+/// writer.write(&batch).unwrap();
+/// let data = writer.into_inner().unwrap();
+///
+/// let reader = FileBufferReader::try_new(Buffer::from_vec(data), None).unwrap();
+/// let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(batches, vec![batch]);
+/// ```
+#[derive(Debug)]
+pub struct FileBufferReader {
+    /// The buffer containing the file
+    buffer: Buffer,
+
+    /// The schema that is read from the file footer
+    schema: SchemaRef,
+
+    /// The record batch blocks in the file
+    blocks: Vec<crate::Block>,
+
+    /// A counter to keep track of the current block that should be read
+    current_block: usize,
+
+    /// Dictionaries for each schema field
+    dictionaries_by_id: HashMap<i64, ArrayRef>,
+
+    /// Metadata version
+    metadata_version: MetadataVersion,
+
+    /// User defined metadata
+    custom_metadata: HashMap<String, String>,
+
+    /// User defined metadata of the message containing the last record batch read
+    batch_custom_metadata: HashMap<String, String>,
+
+    /// Optional projection and projected_schema
+    projection: Option<(Vec<usize>, Schema)>,
+
+    /// Skip validation of the arrays read from the file
+    skip_validation: bool,
+}
+
+impl FileBufferReader {
+    /// Try to create a new reader for the Arrow file contained in `buffer`
+    ///
+    /// Returns errors if the file does not meet the Arrow Format header and footer
+    /// requirements
+    pub fn try_new(
+        buffer: Buffer,
+        projection: Option<Vec<usize>>,
+    ) -> Result<Self, ArrowError> {
+        let data = buffer.as_slice();
+        if data.len() < 16 || data[..6] != super::ARROW_MAGIC {
+            return Err(ArrowError::IoError(
+                "Arrow file does not contain correct header".to_string(),
+            ));
+        }
+        if data[data.len() - 6..] != super::ARROW_MAGIC {
+            return Err(ArrowError::IoError(
+                "Arrow file does not contain correct footer".to_string(),
+            ));
+        }
+
+        // read footer length
+        let footer_end = data.len() - 10;
+        let footer_len =
+            i32::from_le_bytes(data[footer_end..footer_end + 4].try_into().unwrap());
+        let footer_start = usize::try_from(footer_len)
+            .ok()
+            .and_then(|len| footer_end.checked_sub(len))
+            .ok_or_else(|| {
+                ArrowError::IoError(format!("Invalid Arrow footer length {footer_len}"))
+            })?;
+
+        let footer =
+            crate::root_as_footer(&data[footer_start..footer_end]).map_err(|err| {
+                ArrowError::IoError(format!("Unable to get root as footer: {err:?}"))
+            })?;
+
+        let blocks = footer.recordBatches().ok_or_else(|| {
+            ArrowError::IoError(
+                "Unable to get record batches from IPC Footer".to_string(),
+            )
+        })?;
+
+        let ipc_schema = footer.schema().ok_or_else(|| {
+            ArrowError::IoError("Unable to get schema from IPC Footer".to_string())
+        })?;
+        let schema = crate::convert::try_fb_to_schema(ipc_schema)?;
+        let custom_metadata = footer_custom_metadata(&footer);
+
+        let mut dictionaries_by_id = HashMap::new();
+        if let Some(dictionaries) = footer.dictionaries() {
+            for block in dictionaries {
+                let (message, body) = read_block(&buffer, block)?;
+                match message.header_as_dictionary_batch() {
+                    Some(batch) => read_dictionary(
+                        &body,
+                        batch,
+                        &schema,
+                        &mut dictionaries_by_id,
+                        &message.version(),
+                    )?,
+                    None => {
+                        return Err(ArrowError::IoError(format!(
+                            "Expecting DictionaryBatch in dictionary blocks, found {:?}.",
+                            message.header_type()
+                        )));
+                    }
+                }
+            }
+        }
+
+        let projection = match projection {
+            Some(projection_indices) => {
+                let schema = schema.project(&projection_indices)?;
+                Some((projection_indices, schema))
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            schema: Arc::new(schema),
+            blocks: blocks.iter().copied().collect(),
+            current_block: 0,
+            dictionaries_by_id,
+            metadata_version: footer.version(),
+            custom_metadata,
+            batch_custom_metadata: HashMap::new(),
+            projection,
+            skip_validation: false,
+            buffer,
+        })
+    }
+
+    /// Skip validation of the record batches read from the file
+    ///
+    /// This avoids the cost of validating the offsets, UTF-8 data and dictionary keys
+    /// of potentially large arrays. Dictionaries are always validated.
+    ///
+    /// # Safety
+    ///
+    /// The file must have been written by a trusted writer, and contain valid Arrow
+    /// data, otherwise subsequent operations on the arrays read are undefined behaviour
+    pub unsafe fn with_skip_validation(mut self, skip_validation: bool) -> Self {
+        self.skip_validation = skip_validation;
+        self
+    }
+
+    /// Return user defined customized metadata
+    pub fn custom_metadata(&self) -> &HashMap<String, String> {
+        &self.custom_metadata
+    }
+
+    /// Returns the user defined metadata of the message containing the last record
+    /// batch read, as written by [`FileWriter::write_with_custom_metadata`]
+    ///
+    /// [`FileWriter::write_with_custom_metadata`]: crate::writer::FileWriter::write_with_custom_metadata
+    pub fn batch_custom_metadata(&self) -> &HashMap<String, String> {
+        &self.batch_custom_metadata
+    }
+
+    /// Return the number of batches in the file
+    pub fn num_batches(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Return the schema of the file
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Return the [`Buffer`] containing the file
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// Read a specific record batch
+    ///
+    /// Sets the current block to the index, allowing random reads
+    pub fn set_index(&mut self, index: usize) -> Result<(), ArrowError> {
+        if index >= self.blocks.len() {
+            Err(ArrowError::IoError(format!(
+                "Cannot set batch to index {} from {} total batches",
+                index,
+                self.blocks.len()
+            )))
+        } else {
+            self.current_block = index;
+            Ok(())
+        }
+    }
+
+    fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        let block = self.blocks[self.current_block];
+        self.current_block += 1;
+
+        let (message, body) = read_block(&self.buffer, &block)?;
+
+        // some old test data's footer metadata is not set, so we account for that
+        if self.metadata_version != MetadataVersion::V1
             && message.version() != self.metadata_version
         {
             return Err(ArrowError::IoError(
@@ -810,53 +1653,60 @@ impl<R: Read + Seek> FileReader<R> {
                         "Unable to read IPC message as record batch".to_string(),
                     )
                 })?;
-                // read the block that makes up the record batch into a buffer
-                let mut buf = MutableBuffer::from_len_zeroed(message.bodyLength() as usize);
-                self.reader.seek(SeekFrom::Start(
-                    block.offset() as u64 + block.metaDataLength() as u64,
-                ))?;
-                self.reader.read_exact(&mut buf)?;
-
-                read_record_batch(
-                    &buf.into(),
+                self.batch_custom_metadata = message_custom_metadata(&message);
+                read_record_batch_impl(
+                    &body,
                     batch,
                     self.schema(),
                     &self.dictionaries_by_id,
                     self.projection.as_ref().map(|x| x.0.as_ref()),
-                    &message.version()
-
-                ).map(Some)
-            }
-            crate::MessageHeader::NONE => {
-                Ok(None)
+                    &message.version(),
+                    self.skip_validation,
+                )
+                .map(Some)
             }
+            crate::MessageHeader::NONE => Ok(None),
             t => Err(ArrowError::IoError(format!(
                 "Reading types other than record batches not yet supported, unable to read {t:?}"
             ))),
         }
     }
+}
 
-    /// Gets a reference to the underlying reader.
+#[cfg(feature = "memmap2")]
+impl FileBufferReader {
+    /// Memory-map `file` and create a [`FileBufferReader`] for it, whose arrays
+    /// reference the mapped memory directly
     ///
-    /// It is inadvisable to directly read from the underlying reader.
-    pub fn get_ref(&self) -> &R {
-        self.reader.get_ref()
-    }
-
-    /// Gets a mutable reference to the underlying reader.
+    /// The mapping is kept alive for as long as the reader, or any array read from
+    /// it, is alive.
     ///
-    /// It is inadvisable to directly read from the underlying reader.
-    pub fn get_mut(&mut self) -> &mut R {
-        self.reader.get_mut()
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this or any other process, for
+    /// as long as the mapping is alive. Modifications would be visible through the
+    /// immutable arrays read, and accessing a truncated region may terminate the
+    /// process, both of which are undefined behaviour
+    pub unsafe fn try_new_mmap(
+        file: &std::fs::File,
+        projection: Option<Vec<usize>>,
+    ) -> Result<Self, ArrowError> {
+        // SAFETY: the caller guarantees the file is not modified while mapped
+        let mmap = unsafe { memmap2::Mmap::map(file)? };
+        let ptr = std::ptr::NonNull::from(&mmap[..]).cast::<u8>();
+        let len = mmap.len();
+        // SAFETY: ptr is valid for len bytes for as long as the mapping is alive
+        let buffer = unsafe { Buffer::from_custom_allocation(ptr, len, Arc::new(mmap)) };
+        Self::try_new(buffer, projection)
     }
 }
 
-impl<R: Read + Seek> Iterator for FileReader<R> {
+impl Iterator for FileBufferReader {
     type Item = Result<RecordBatch, ArrowError>;
 
     fn next(&mut self) -> Option<Self::Item> {
         // get current block
-        if self.current_block < self.total_blocks {
+        if self.current_block < self.blocks.len() {
             self.maybe_next().transpose()
         } else {
             None
@@ -864,7 +1714,7 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
     }
 }
 
-impl<R: Read + Seek> RecordBatchReader for FileReader<R> {
+impl RecordBatchReader for FileBufferReader {
     fn schema(&self) -> SchemaRef {
         self.schema.clone()
     }
@@ -890,6 +1740,18 @@ pub struct StreamReader<R: Read> {
 
     /// Optional projection
     projection: Option<(Vec<usize>, Schema)>,
+
+    /// The index of the next record batch in the stream
+    batch_index: usize,
+
+    /// The indices of the record batches that should be read
+    batch_range: Range<usize>,
+
+    /// Skip validation of the arrays read from the stream
+    skip_validation: bool,
+
+    /// User defined metadata of the message containing the last record batch read
+    batch_custom_metadata: HashMap<String, String>,
 }
 
 impl<R: Read> fmt::Debug for StreamReader<R> {
@@ -900,6 +1762,9 @@ impl<R: Read> fmt::Debug for StreamReader<R> {
             .field("dictionaries_by_id", &self.dictionaries_by_id)
             .field("finished", &self.finished)
             .field("projection", &self.projection)
+            .field("batch_index", &self.batch_index)
+            .field("batch_range", &self.batch_range)
+            .field("skip_validation", &self.skip_validation)
             .finish()
     }
 }
@@ -937,9 +1802,9 @@ impl<R: Read> StreamReader<R> {
             }
             i32::from_le_bytes(meta_size)
         };
+        let meta_len = checked_length("message", meta_len as i64, usize::MAX)?;
 
-        let mut meta_buffer = vec![0; meta_len as usize];
-        reader.read_exact(&mut meta_buffer)?;
+        let meta_buffer = read_exact_buffer(&mut reader, meta_len)?;
 
         let message = crate::root_as_message(meta_buffer.as_slice()).map_err(|err| {
             ArrowError::IoError(format!("Unable to get root as message: {err:?}"))
@@ -948,7 +1813,7 @@ impl<R: Read> StreamReader<R> {
         let ipc_schema: crate::Schema = message.header_as_schema().ok_or_else(|| {
             ArrowError::IoError("Unable to read IPC message as schema".to_string())
         })?;
-        let schema = crate::convert::fb_to_schema(ipc_schema);
+        let schema = crate::convert::try_fb_to_schema(ipc_schema)?;
 
         // Create an array of optional dictionary value arrays, one per field.
         let dictionaries_by_id = HashMap::new();
@@ -966,9 +1831,27 @@ impl<R: Read> StreamReader<R> {
             finished: false,
             dictionaries_by_id,
             projection,
+            batch_index: 0,
+            batch_range: 0..usize::MAX,
+            skip_validation: false,
+            batch_custom_metadata: HashMap::new(),
         })
     }
 
+    /// Skip validation of the record batches read from the stream
+    ///
+    /// This avoids the cost of validating the offsets, UTF-8 data and dictionary keys
+    /// of potentially large arrays. Dictionaries are always validated.
+    ///
+    /// # Safety
+    ///
+    /// The stream must have been written by a trusted writer, and contain valid Arrow
+    /// data, otherwise subsequent operations on the arrays read are undefined behaviour
+    pub unsafe fn with_skip_validation(mut self, skip_validation: bool) -> Self {
+        self.skip_validation = skip_validation;
+        self
+    }
+
     /// Return the schema of the stream
     pub fn schema(&self) -> SchemaRef {
         self.schema.clone()
@@ -979,10 +1862,49 @@ impl<R: Read> StreamReader<R> {
         self.finished
     }
 
+    /// Returns the user defined metadata of the message containing the last record
+    /// batch read, as written by [`StreamWriter::write_with_custom_metadata`]
+    ///
+    /// [`StreamWriter::write_with_custom_metadata`]: crate::writer::StreamWriter::write_with_custom_metadata
+    pub fn batch_custom_metadata(&self) -> &HashMap<String, String> {
+        &self.batch_custom_metadata
+    }
+
+    /// Restrict reading to the record batches with indices within `range`, where
+    /// indices are relative to the start of the stream
+    ///
+    /// The record batches before `range` are skipped without being decoded, and the
+    /// stream is finished after the end of `range`. Dictionary batches are always read
+    pub fn set_batch_range(&mut self, range: Range<usize>) -> Result<(), ArrowError> {
+        if range.start > range.end || range.start < self.batch_index {
+            return Err(ArrowError::IoError(format!(
+                "Cannot set batch range to {:?} after reading {} batches",
+                range, self.batch_index
+            )));
+        }
+        self.batch_range = range;
+        Ok(())
+    }
+
     fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
-        if self.finished {
-            return Ok(None);
+        loop {
+            if self.finished {
+                return Ok(None);
+            }
+            if self.batch_index >= self.batch_range.end {
+                self.finished = true;
+                return Ok(None);
+            }
+            if let ControlFlow::Break(batch) = self.next_message()? {
+                return Ok(batch);
+            }
         }
+    }
+
+    /// Reads the next message, breaking with the [`RecordBatch`] read or `None` if the
+    /// stream has ended, or continuing if the message did not contain a record batch
+    /// to read
+    fn next_message(&mut self) -> Result<ControlFlow<Option<RecordBatch>>, ArrowError> {
         // determine metadata length
         let mut meta_size: [u8; 4] = [0; 4];
 
@@ -994,7 +1916,7 @@ impl<R: Read> StreamReader<R> {
                     // valid according to:
                     // https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format
                     self.finished = true;
-                    Ok(None)
+                    Ok(ControlFlow::Break(None))
                 } else {
                     Err(ArrowError::from(e))
                 };
@@ -1013,14 +1935,13 @@ impl<R: Read> StreamReader<R> {
         if meta_len == 0 {
             // the stream has ended, mark the reader as finished
             self.finished = true;
-            return Ok(None);
+            return Ok(ControlFlow::Break(None));
         }
 
-        let mut meta_buffer = vec![0; meta_len as usize];
-        self.reader.read_exact(&mut meta_buffer)?;
+        let meta_len = checked_length("message", meta_len as i64, usize::MAX)?;
+        let meta_buffer = read_exact_buffer(&mut self.reader, meta_len)?;
 
-        let vecs = &meta_buffer.to_vec();
-        let message = crate::root_as_message(vecs).map_err(|err| {
+        let message = crate::root_as_message(&meta_buffer).map_err(|err| {
             ArrowError::IoError(format!("Unable to get root as message: {err:?}"))
         })?;
 
@@ -1034,11 +1955,28 @@ impl<R: Read> StreamReader<R> {
                         "Unable to read IPC message as record batch".to_string(),
                     )
                 })?;
+                let body_len = checked_length("body", message.bodyLength(), usize::MAX)?;
+                let index = self.batch_index;
+                self.batch_index += 1;
+                if index < self.batch_range.start {
+                    // skip the block that makes up the record batch
+                    let skipped = std::io::copy(
+                        &mut self.reader.by_ref().take(body_len as u64),
+                        &mut std::io::sink(),
+                    )?;
+                    if skipped != body_len as u64 {
+                        return Err(ArrowError::IoError(format!(
+                            "Expected record batch body of {body_len} bytes, found {skipped}"
+                        )));
+                    }
+                    return Ok(ControlFlow::Continue(()));
+                }
+
                 // read the block that makes up the record batch into a buffer
-                let mut buf = MutableBuffer::from_len_zeroed(message.bodyLength() as usize);
-                self.reader.read_exact(&mut buf)?;
+                let buf = read_exact_buffer(&mut self.reader, body_len)?;
+                self.batch_custom_metadata = message_custom_metadata(&message);
 
-                read_record_batch(&buf.into(), batch, self.schema(), &self.dictionaries_by_id, self.projection.as_ref().map(|x| x.0.as_ref()), &message.version()).map(Some)
+                read_record_batch_impl(&buf.into(), batch, self.schema(), &self.dictionaries_by_id, self.projection.as_ref().map(|x| x.0.as_ref()), &message.version(), self.skip_validation).map(|batch| ControlFlow::Break(Some(batch)))
             }
             crate::MessageHeader::DictionaryBatch => {
                 let batch = message.header_as_dictionary_batch().ok_or_else(|| {
@@ -1047,18 +1985,18 @@ impl<R: Read> StreamReader<R> {
                     )
                 })?;
                 // read the block that makes up the dictionary batch into a buffer
-                let mut buf = MutableBuffer::from_len_zeroed(message.bodyLength() as usize);
-                self.reader.read_exact(&mut buf)?;
+                let body_len = checked_length("body", message.bodyLength(), usize::MAX)?;
+                let buf = read_exact_buffer(&mut self.reader, body_len)?;
 
                 read_dictionary(
                     &buf.into(), batch, &self.schema, &mut self.dictionaries_by_id, &message.version()
                 )?;
 
                 // read the next message until we encounter a RecordBatch
-                self.maybe_next()
+                Ok(ControlFlow::Continue(()))
             }
             crate::MessageHeader::NONE => {
-                Ok(None)
+                Ok(ControlFlow::Break(None))
             }
             t => Err(ArrowError::IoError(
                 format!("Reading types other than record batches not yet supported, unable to read {t:?} ")
@@ -1376,6 +2314,60 @@ mod tests {
         reader.next().unwrap().unwrap()
     }
 
+    #[test]
+    fn test_roundtrip_decimal32_decimal64() {
+        let decimal32 = Decimal32Array::from(vec![Some(12345), None, Some(-99)])
+            .with_precision_and_scale(7, 2)
+            .unwrap();
+        let decimal64 = Decimal64Array::from(vec![None, Some(1), Some(i64::MAX)])
+            .with_precision_and_scale(18, -3)
+            .unwrap();
+        let batch = RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(decimal32) as ArrayRef),
+            ("b", Arc::new(decimal64) as ArrayRef),
+        ])
+        .unwrap();
+
+        assert_eq!(roundtrip_ipc(&batch), batch);
+        assert_eq!(roundtrip_ipc_stream(&batch), batch);
+    }
+
+    #[test]
+    fn test_roundtrip_list_view() {
+        let values = Arc::new(Int32Array::from(vec![Some(1), None, Some(3), Some(4)]));
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        let list_view = ListViewArray::new(
+            field,
+            vec![2, 0, 0, 1].into(),
+            vec![2, 3, 0, 1].into(),
+            values,
+            Some(vec![true, true, false, true].into()),
+        );
+
+        let values = Arc::new(StringArray::from(vec!["a", "bb", "ccc"]));
+        let field = Arc::new(Field::new("item", DataType::Utf8, false));
+        let large_list_view = LargeListViewArray::new(
+            field,
+            vec![1, 0, 0, 2].into(),
+            vec![2, 1, 3, 0].into(),
+            values,
+            None,
+        );
+
+        let batch = RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(list_view) as ArrayRef),
+            ("b", Arc::new(large_list_view) as ArrayRef),
+        ])
+        .unwrap();
+
+        assert_eq!(roundtrip_ipc(&batch), batch);
+        assert_eq!(roundtrip_ipc_stream(&batch), batch);
+
+        let sliced = batch.slice(1, 2);
+        assert_eq!(roundtrip_ipc(&sliced), sliced);
+        assert_eq!(roundtrip_ipc_stream(&sliced), sliced);
+    }
+
     #[test]
     fn test_roundtrip_with_custom_metadata() {
         let schema = Schema::new(vec![Field::new("dummy", DataType::Float64, false)]);
@@ -1395,6 +2387,118 @@ mod tests {
         assert_eq!(reader.custom_metadata(), &test_metadata);
     }
 
+    #[test]
+    fn test_roundtrip_with_batch_custom_metadata() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from(vec![1, 2])) as _,
+        )])
+        .unwrap();
+        let metadata = |watermark: &str| {
+            HashMap::from([
+                ("producer".to_string(), "test".to_string()),
+                ("watermark".to_string(), watermark.to_string()),
+            ])
+        };
+
+        let mut buf = Vec::new();
+        let mut writer =
+            crate::writer::FileWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        writer
+            .write_with_custom_metadata(&batch, &metadata("1"))
+            .unwrap();
+        writer.write(&batch).unwrap();
+        writer
+            .write_with_custom_metadata(&batch, &metadata("3"))
+            .unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut reader =
+            FileReader::try_new(std::io::Cursor::new(buf.clone()), None).unwrap();
+        assert!(reader.custom_metadata().is_empty());
+        assert!(reader.batch_custom_metadata().is_empty());
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+        assert_eq!(reader.batch_custom_metadata(), &metadata("1"));
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+        assert!(reader.batch_custom_metadata().is_empty());
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+        assert_eq!(reader.batch_custom_metadata(), &metadata("3"));
+
+        let mut reader = FileBufferReader::try_new(Buffer::from_vec(buf), None).unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+        assert_eq!(reader.batch_custom_metadata(), &metadata("1"));
+
+        let mut buf = Vec::new();
+        let mut writer =
+            crate::writer::StreamWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        writer
+            .write_with_custom_metadata(&batch, &metadata("1"))
+            .unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut reader = StreamReader::try_new(buf.as_slice(), None).unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+        assert_eq!(reader.batch_custom_metadata(), &metadata("1"));
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+        assert!(reader.batch_custom_metadata().is_empty());
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_roundtrip_extension_types() {
+        use arrow_array::cast::AsArray;
+        use arrow_schema::extension::{FixedShapeTensor, Json, Uuid};
+
+        let tensor = FixedShapeTensor::try_new_with_value_type(
+            DataType::Float32,
+            vec![2, 2],
+            Some(vec!["x".to_string(), "y".to_string()]),
+            None,
+        )
+        .unwrap();
+        let item = Arc::new(Field::new("item", DataType::Float32, false));
+        let values = Float32Array::from_iter_values((0..8).map(|x| x as f32));
+        let tensors = FixedSizeListArray::new(item, 4, Arc::new(values), None);
+
+        let uuids =
+            FixedSizeBinaryArray::try_from_iter([[0_u8; 16], [1; 16]].into_iter())
+                .unwrap();
+
+        let json =
+            Field::new("json", DataType::Utf8, true).with_extension_type(Json::default());
+        let json = StructArray::from(vec![(
+            Arc::new(json),
+            Arc::new(StringArray::from(vec![r#"{"a": 1}"#, "[]"])) as ArrayRef,
+        )]);
+
+        let schema = Schema::new(vec![
+            Field::new("tensor", tensors.data_type().clone(), false)
+                .with_extension_type(tensor.clone()),
+            Field::new("uuid", DataType::FixedSizeBinary(16), false)
+                .with_extension_type(Uuid),
+            Field::new("nested", json.data_type().clone(), false),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(tensors), Arc::new(uuids), Arc::new(json)],
+        )
+        .unwrap();
+
+        for result in [roundtrip_ipc(&batch), roundtrip_ipc_stream(&batch)] {
+            assert_eq!(result, batch);
+
+            let schema = result.schema();
+            let decoded = schema.field(0).try_extension_type::<FixedShapeTensor>();
+            assert_eq!(decoded.unwrap(), tensor);
+            schema.field(1).try_extension_type::<Uuid>().unwrap();
+            let nested = result.column(2).as_struct();
+            nested.fields()[0].try_extension_type::<Json>().unwrap();
+        }
+    }
+
     #[test]
     fn test_roundtrip_nested_dict() {
         let inner: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
@@ -1704,4 +2808,443 @@ mod tests {
         let output_batch = roundtrip_ipc_stream(&input_batch);
         assert_eq!(input_batch, output_batch);
     }
+
+    fn file_buffer_test_batches() -> Vec<RecordBatch> {
+        let dict: DictionaryArray<Int32Type> =
+            vec!["a", "b", "a", "c"].into_iter().collect();
+        let batch = RecordBatch::try_from_iter([
+            (
+                "int",
+                Arc::new(Int64Array::from(vec![Some(1), None, Some(3), Some(4)])) as _,
+            ),
+            (
+                "str",
+                Arc::new(StringArray::from(vec!["a", "bb", "", "ccc"])) as _,
+            ),
+            ("dict", Arc::new(dict) as _),
+        ])
+        .unwrap();
+        vec![batch.slice(0, 3), batch]
+    }
+
+    fn write_file(batches: &[RecordBatch]) -> Vec<u8> {
+        let mut writer =
+            crate::writer::FileWriter::try_new(vec![], &batches[0].schema()).unwrap();
+        for batch in batches {
+            writer.write(batch).unwrap();
+        }
+        writer.into_inner().unwrap()
+    }
+
+    #[test]
+    fn test_file_buffer_reader() {
+        let batches = file_buffer_test_batches();
+        let buffer = Buffer::from_slice_ref(write_file(&batches));
+        let range = buffer.as_ptr_range();
+
+        let reader = FileBufferReader::try_new(buffer.clone(), None).unwrap();
+        assert_eq!(reader.num_batches(), 2);
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+
+        // The arrays should reference the buffer without copying
+        for batch in &read {
+            for column in batch.columns() {
+                for b in column.to_data().buffers() {
+                    assert!(range.contains(&b.as_ptr()));
+                }
+            }
+        }
+
+        let mut reader =
+            FileBufferReader::try_new(buffer.clone(), Some(vec![2, 0])).unwrap();
+        reader.set_index(1).unwrap();
+        let read = reader.next().unwrap().unwrap();
+        assert_eq!(read, batches[1].project(&[2, 0]).unwrap());
+        assert!(reader.next().is_none());
+
+        // SAFETY: the file was written above and is valid
+        let reader = unsafe {
+            FileBufferReader::try_new(buffer.clone(), None)
+                .unwrap()
+                .with_skip_validation(true)
+        };
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+    }
+
+    #[test]
+    fn test_file_buffer_reader_invalid() {
+        let data = write_file(&file_buffer_test_batches());
+
+        let err = FileBufferReader::try_new(Buffer::from_slice_ref(&data[1..]), None)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Io error: Arrow file does not contain correct header"
+        );
+
+        let truncated = Buffer::from_slice_ref(&data[..data.len() - 1]);
+        let err = FileBufferReader::try_new(truncated, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Io error: Arrow file does not contain correct footer"
+        );
+
+        let mut corrupt = data;
+        let footer_len = corrupt.len() - 10;
+        corrupt[footer_len..footer_len + 4].copy_from_slice(&i32::MAX.to_le_bytes());
+        let err = FileBufferReader::try_new(Buffer::from_vec(corrupt), None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("Io error: Invalid Arrow footer length {}", i32::MAX)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "memmap2")]
+    fn test_file_buffer_reader_mmap() {
+        use std::io::Write;
+
+        let batches = file_buffer_test_batches();
+        let mut file = tempfile::tempfile().unwrap();
+        file.write_all(&write_file(&batches)).unwrap();
+
+        // SAFETY: the temporary file is not modified while mapped
+        let reader = unsafe { FileBufferReader::try_new_mmap(&file, None) }.unwrap();
+        drop(file);
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+    }
+
+    /// Returns batches with a dictionary column, whose dictionary changes between
+    /// batches if `replace_dictionary`
+    fn batch_range_test_batches(replace_dictionary: bool) -> Vec<RecordBatch> {
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        (0..5)
+            .map(|i| {
+                let len = i % 3 + 1;
+                let keys = Int32Array::from_iter_values(0..len as i32);
+                let dict = match replace_dictionary {
+                    true => DictionaryArray::try_new(keys, values.slice(0, len)),
+                    false => DictionaryArray::try_new(keys, values.clone()),
+                }
+                .unwrap();
+                RecordBatch::try_from_iter([
+                    ("int", Arc::new(Int32Array::from(vec![i as i32; len])) as _),
+                    (
+                        "str",
+                        Arc::new(StringArray::from(vec!["x".repeat(i); len])) as _,
+                    ),
+                    ("dict", Arc::new(dict) as _),
+                ])
+                .unwrap()
+            })
+            .collect()
+    }
+
+    /// A [`Read`] that counts the number of bytes read
+    struct CountingReader<R> {
+        inner: R,
+        read: usize,
+    }
+
+    impl<R: Read> Read for CountingReader<R> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let read = self.inner.read(buf)?;
+            self.read += read;
+            Ok(read)
+        }
+    }
+
+    impl<R: Seek> Seek for CountingReader<R> {
+        fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+            self.inner.seek(pos)
+        }
+    }
+
+    #[test]
+    fn test_file_reader_projection_skips_reads() {
+        let int = Int32Array::from_iter_values(0..100_000);
+        let str = StringArray::from_iter_values((0..100_000).map(|x| x.to_string()));
+        let batch = RecordBatch::try_from_iter([
+            ("int", Arc::new(int) as _),
+            ("str", Arc::new(str) as _),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::new();
+        let mut writer =
+            crate::writer::FileWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let read_with = |projection| {
+            let reader = CountingReader {
+                inner: std::io::Cursor::new(&buf),
+                read: 0,
+            };
+            let mut reader = FileReader::try_new(reader, projection).unwrap();
+            reader.get_mut().read = 0;
+            let read = reader.next().unwrap().unwrap();
+            (read, reader.get_ref().read)
+        };
+
+        let (read, full) = read_with(None);
+        assert_eq!(read, batch);
+
+        // Only the buffers of the projected column should be read
+        let (read, projected) = read_with(Some(vec![0]));
+        assert_eq!(read, batch.project(&[0]).unwrap());
+        assert!(projected >= 400_000, "{projected}");
+        assert!(projected < full - 800_000, "{projected} vs {full}");
+
+        let (read, projected) = read_with(Some(vec![1]));
+        assert_eq!(read, batch.project(&[1]).unwrap());
+        assert!(projected < full - 350_000, "{projected} vs {full}");
+    }
+
+    #[test]
+    fn test_file_reader_batch_range() {
+        let batches = batch_range_test_batches(false);
+        let mut buf = Vec::new();
+        let mut writer =
+            crate::writer::FileWriter::try_new(&mut buf, &batches[0].schema()).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut reader = FileReader::try_new(std::io::Cursor::new(&buf), None).unwrap();
+        reader.set_batch_range(1..3).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, &batches[1..3]);
+
+        let mut reader =
+            FileReader::try_new(std::io::Cursor::new(&buf), Some(vec![2])).unwrap();
+        reader.set_batch_range(3..5).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let expected: Vec<_> = batches[3..]
+            .iter()
+            .map(|b| b.project(&[2]).unwrap())
+            .collect();
+        assert_eq!(read, expected);
+
+        let mut reader = FileReader::try_new(std::io::Cursor::new(&buf), None).unwrap();
+        let err = reader.set_batch_range(2..6).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Io error: Cannot set batch range to 2..6 from 5 total batches"
+        );
+        reader.set_batch_range(2..2).unwrap();
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_stream_reader_batch_range() {
+        let batches = batch_range_test_batches(true);
+        let mut buf = Vec::new();
+        let mut writer =
+            crate::writer::StreamWriter::try_new(&mut buf, &batches[0].schema()).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut reader = StreamReader::try_new(buf.as_slice(), None).unwrap();
+        reader.set_batch_range(2..4).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, &batches[2..4]);
+
+        let mut reader = StreamReader::try_new(buf.as_slice(), Some(vec![2, 1])).unwrap();
+        reader.set_batch_range(4..10).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, vec![batches[4].project(&[2, 1]).unwrap()]);
+
+        let mut reader = StreamReader::try_new(buf.as_slice(), None).unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), batches[0]);
+        let err = reader.set_batch_range(0..2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Io error: Cannot set batch range to 0..2 after reading 1 batches"
+        );
+        reader.set_batch_range(1..2).unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), batches[1]);
+        assert!(reader.next().is_none());
+        assert!(reader.is_finished());
+    }
+
+    /// Downcasts the [`IpcError`] wrapped by `err`
+    fn ipc_error(err: &ArrowError) -> &IpcError {
+        match err {
+            ArrowError::ExternalError(e) => e.downcast_ref().unwrap(),
+            _ => panic!("expected IpcError, got {err}"),
+        }
+    }
+
+    #[test]
+    fn test_read_buffer_out_of_bounds() {
+        let data = Buffer::from_vec(vec![0_u8; 16]);
+        let err = read_buffer(&crate::Buffer::new(8, 16), &data, None).unwrap_err();
+        assert_eq!(
+            ipc_error(&err),
+            &IpcError::BufferOutOfBounds {
+                offset: 8,
+                length: 16,
+                body_length: 16
+            }
+        );
+        let err = read_buffer(&crate::Buffer::new(-1, 1), &data, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "External error: Buffer at offset -1 with length 1 exceeds body of 16 bytes"
+        );
+        assert_eq!(
+            read_buffer(&crate::Buffer::new(8, 8), &data, None)
+                .unwrap()
+                .len(),
+            8
+        );
+    }
+
+    #[test]
+    fn test_stream_reader_invalid_lengths() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        )])
+        .unwrap();
+        let mut buf = Vec::new();
+        let mut writer =
+            crate::writer::StreamWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        // Negative metadata length
+        let mut data = buf.clone();
+        data[4..8].copy_from_slice(&(-8_i32).to_le_bytes());
+        let err = StreamReader::try_new(data.as_slice(), None).unwrap_err();
+        assert_eq!(
+            ipc_error(&err),
+            &IpcError::InvalidLength {
+                context: "message",
+                length: -8
+            }
+        );
+
+        // A metadata length larger than the stream fails without allocating it
+        let mut data = buf.clone();
+        data[4..8].copy_from_slice(&i32::MAX.to_le_bytes());
+        let err = StreamReader::try_new(data.as_slice(), None).unwrap_err();
+        assert!(matches!(err, ArrowError::IoError(_)), "{err}");
+    }
+
+    #[test]
+    fn test_nesting_too_deep() {
+        // A batch with enough field nodes and buffers to decode a deeply nested struct
+        let columns = (0..MAX_NESTING_DEPTH + 1).map(|i| {
+            let array = Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef;
+            (format!("c{i}"), array)
+        });
+        let batch = RecordBatch::try_from_iter(columns).unwrap();
+        let mut buf = Vec::new();
+        let mut writer =
+            crate::writer::StreamWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        // Skip the schema message, and decode the record batch message
+        let message_len = |offset: usize| {
+            i32::from_le_bytes(buf[offset + 4..offset + 8].try_into().unwrap()) as usize
+        };
+        let start = 8 + message_len(0);
+        let meta_end = start + 8 + message_len(start);
+        let message = crate::root_as_message(&buf[start + 8..meta_end]).unwrap();
+        let body = Buffer::from(&buf[meta_end..meta_end + message.bodyLength() as usize]);
+
+        let mut field = Field::new("item", DataType::Int32, true);
+        for _ in 0..MAX_NESTING_DEPTH {
+            field = Field::new("item", DataType::Struct(vec![field].into()), true);
+        }
+        let err = read_record_batch(
+            &body,
+            message.header_as_record_batch().unwrap(),
+            Arc::new(Schema::new(vec![field])),
+            &HashMap::new(),
+            None,
+            &message.version(),
+        )
+        .unwrap_err();
+        assert_eq!(ipc_error(&err), &IpcError::NestingTooDeep);
+    }
+
+    #[test]
+    fn test_corrupt_ipc_does_not_panic() {
+        let mut union_builder = UnionBuilder::new_dense();
+        union_builder.append::<Int32Type>("a", 1).unwrap();
+        union_builder.append::<Float64Type>("b", 10.1).unwrap();
+        union_builder.append_null::<Float64Type>("b").unwrap();
+        let union = union_builder.build().unwrap();
+
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![Some(3)]),
+        ]);
+        let strings = StringArray::from(vec![Some("foo"), None, Some("baz")]);
+        let dict: DictionaryArray<Int8Type> = vec!["x", "y", "x"].into_iter().collect();
+        let struct_array = StructArray::from(vec![(
+            Arc::new(Field::new("s", DataType::Utf8, true)),
+            Arc::new(strings.clone()) as ArrayRef,
+        )]);
+
+        let batch = RecordBatch::try_from_iter([
+            ("union", Arc::new(union) as ArrayRef),
+            ("list", Arc::new(list) as ArrayRef),
+            ("strings", Arc::new(strings) as ArrayRef),
+            ("dict", Arc::new(dict) as ArrayRef),
+            ("struct", Arc::new(struct_array) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut stream = Vec::new();
+        let mut writer =
+            crate::writer::StreamWriter::try_new(&mut stream, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut file = Vec::new();
+        let mut writer =
+            crate::writer::FileWriter::try_new(&mut file, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        // Corrupting any single byte must produce an error or a valid batch, not a panic
+        for data in [&stream, &file] {
+            for idx in 0..data.len() {
+                for value in [0, 0x7F, 0x80, 0xFF] {
+                    let mut data = data.clone();
+                    data[idx] = value;
+
+                    if let Ok(reader) = StreamReader::try_new(data.as_slice(), None) {
+                        reader.for_each(drop);
+                    }
+                    let cursor = std::io::Cursor::new(data.as_slice());
+                    if let Ok(reader) = FileReader::try_new(cursor, None) {
+                        reader.for_each(drop);
+                    }
+                    if let Ok(reader) = FileBufferReader::try_new(data.into(), None) {
+                        reader.for_each(drop);
+                    }
+                }
+            }
+        }
+    }
 }
@@ -22,7 +22,7 @@
 
 use std::cmp::min;
 use std::collections::HashMap;
-use std::io::{BufWriter, Write};
+use std::io::{BufWriter, IoSlice, Write};
 
 use flatbuffers::FlatBufferBuilder;
 
@@ -58,6 +58,8 @@ pub struct IpcWriteOptions {
     /// Compression, if desired. Will result in a runtime error
     /// if the corresponding feature is not enabled
     batch_compression_type: Option<crate::CompressionType>,
+    /// How dictionaries that change between batches are written
+    dictionary_handling: DictionaryHandling,
 }
 
 impl IpcWriteOptions {
@@ -80,6 +82,17 @@ impl IpcWriteOptions {
         }
         Ok(self)
     }
+
+    /// Configures how dictionaries that change between batches are written,
+    /// see [`DictionaryHandling`]
+    pub fn with_dictionary_handling(
+        mut self,
+        dictionary_handling: DictionaryHandling,
+    ) -> Self {
+        self.dictionary_handling = dictionary_handling;
+        self
+    }
+
     /// Try create IpcWriteOptions, checking for incompatible settings
     pub fn try_new(
         alignment: usize,
@@ -102,6 +115,7 @@ impl IpcWriteOptions {
                 write_legacy_ipc_format,
                 metadata_version,
                 batch_compression_type: None,
+                dictionary_handling: DictionaryHandling::default(),
             }),
             crate::MetadataVersion::V5 => {
                 if write_legacy_ipc_format {
@@ -115,6 +129,7 @@ impl IpcWriteOptions {
                         write_legacy_ipc_format,
                         metadata_version,
                         batch_compression_type: None,
+                        dictionary_handling: DictionaryHandling::default(),
                     })
                 }
             }
@@ -132,10 +147,30 @@ impl Default for IpcWriteOptions {
             write_legacy_ipc_format: false,
             metadata_version: crate::MetadataVersion::V5,
             batch_compression_type: None,
+            dictionary_handling: DictionaryHandling::default(),
         }
     }
 }
 
+/// Controls how a dictionary that changes between batches is written
+///
+/// Note: the IPC file format does not support replacing a dictionary, and so
+/// [`FileWriter`] returns an error if a dictionary changes other than by appending
+/// values with [`DictionaryHandling::Delta`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DictionaryHandling {
+    /// Write the full dictionary whenever it changes, replacing the previous dictionary
+    #[default]
+    Resend,
+    /// If the new dictionary only appends values to the previous dictionary, write
+    /// only these values as a delta dictionary batch, otherwise write the full dictionary
+    ///
+    /// This is typically the case when the dictionary arrays are produced by the same
+    /// dictionary builder, e.g. with
+    /// [`finish_cloned`](arrow_array::builder::GenericByteDictionaryBuilder::finish_cloned)
+    Delta,
+}
+
 #[derive(Debug, Default)]
 /// Handles low level details of encoding [`Array`] and [`Schema`] into the
 /// [Arrow IPC Format].
@@ -202,7 +237,7 @@ impl IpcDataGenerator {
     fn _encode_dictionaries(
         &self,
         column: &ArrayRef,
-        encoded_dictionaries: &mut Vec<EncodedData>,
+        encoded_dictionaries: &mut Vec<VectoredEncodedData>,
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
     ) -> Result<(), ArrowError> {
@@ -258,6 +293,26 @@ impl IpcDataGenerator {
                     write_options,
                 )?;
             }
+            DataType::ListView(field) => {
+                let list = column.as_list_view::<i32>();
+                self.encode_dictionaries(
+                    field,
+                    list.values(),
+                    encoded_dictionaries,
+                    dictionary_tracker,
+                    write_options,
+                )?;
+            }
+            DataType::LargeListView(field) => {
+                let list = column.as_list_view::<i64>();
+                self.encode_dictionaries(
+                    field,
+                    list.values(),
+                    encoded_dictionaries,
+                    dictionary_tracker,
+                    write_options,
+                )?;
+            }
             DataType::FixedSizeList(field, _) => {
                 let list = column
                     .as_any()
@@ -322,7 +377,7 @@ impl IpcDataGenerator {
         &self,
         field: &Field,
         column: &ArrayRef,
-        encoded_dictionaries: &mut Vec<EncodedData>,
+        encoded_dictionaries: &mut Vec<VectoredEncodedData>,
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
     ) -> Result<(), ArrowError> {
@@ -343,14 +398,30 @@ impl IpcDataGenerator {
                     write_options,
                 )?;
 
-                let emit = dictionary_tracker.insert(dict_id, column)?;
+                let update = dictionary_tracker.insert_column(
+                    dict_id,
+                    column,
+                    write_options.dictionary_handling,
+                )?;
 
-                if emit {
-                    encoded_dictionaries.push(self.dictionary_batch_to_bytes(
-                        dict_id,
-                        dict_values,
-                        write_options,
-                    )?);
+                match update {
+                    DictionaryUpdate::None => {}
+                    DictionaryUpdate::New | DictionaryUpdate::Replaced => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            dict_values,
+                            write_options,
+                            false,
+                        )?);
+                    }
+                    DictionaryUpdate::Delta(delta) => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            &delta,
+                            write_options,
+                            true,
+                        )?);
+                    }
                 }
             }
             _ => self._encode_dictionaries(
@@ -373,6 +444,60 @@ impl IpcDataGenerator {
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
     ) -> Result<(Vec<EncodedData>, EncodedData), ArrowError> {
+        self.encoded_batch_with_custom_metadata(
+            batch,
+            dictionary_tracker,
+            write_options,
+            &HashMap::new(),
+        )
+    }
+
+    /// Encodes a batch like [`Self::encoded_batch`], attaching `custom_metadata` to
+    /// the record batch message. Dictionary batches are encoded without metadata
+    pub fn encoded_batch_with_custom_metadata(
+        &self,
+        batch: &RecordBatch,
+        dictionary_tracker: &mut DictionaryTracker,
+        write_options: &IpcWriteOptions,
+        custom_metadata: &HashMap<String, String>,
+    ) -> Result<(Vec<EncodedData>, EncodedData), ArrowError> {
+        let (encoded_dictionaries, encoded_message) = self
+            .encoded_batch_vectored_with_custom_metadata(
+                batch,
+                dictionary_tracker,
+                write_options,
+                custom_metadata,
+            )?;
+        let encoded_dictionaries = encoded_dictionaries.into_iter().map(Into::into);
+        Ok((encoded_dictionaries.collect(), encoded_message.into()))
+    }
+
+    /// Encodes a batch like [`Self::encoded_batch`], returning [`VectoredEncodedData`]
+    /// that references the buffers of `batch` rather than copying them into a
+    /// contiguous buffer. See [`write_message_vectored`]
+    pub fn encoded_batch_vectored(
+        &self,
+        batch: &RecordBatch,
+        dictionary_tracker: &mut DictionaryTracker,
+        write_options: &IpcWriteOptions,
+    ) -> Result<(Vec<VectoredEncodedData>, VectoredEncodedData), ArrowError> {
+        self.encoded_batch_vectored_with_custom_metadata(
+            batch,
+            dictionary_tracker,
+            write_options,
+            &HashMap::new(),
+        )
+    }
+
+    /// Encodes a batch like [`Self::encoded_batch_vectored`], attaching `custom_metadata`
+    /// to the record batch message. Dictionary batches are encoded without metadata
+    pub fn encoded_batch_vectored_with_custom_metadata(
+        &self,
+        batch: &RecordBatch,
+        dictionary_tracker: &mut DictionaryTracker,
+        write_options: &IpcWriteOptions,
+        custom_metadata: &HashMap<String, String>,
+    ) -> Result<(Vec<VectoredEncodedData>, VectoredEncodedData), ArrowError> {
         let schema = batch.schema();
         let mut encoded_dictionaries = Vec::with_capacity(schema.all_fields().len());
 
@@ -387,7 +512,8 @@ impl IpcDataGenerator {
             )?;
         }
 
-        let encoded_message = self.record_batch_to_bytes(batch, write_options)?;
+        let encoded_message =
+            self.record_batch_to_bytes(batch, write_options, custom_metadata)?;
         Ok((encoded_dictionaries, encoded_message))
     }
 
@@ -397,12 +523,13 @@ impl IpcDataGenerator {
         &self,
         batch: &RecordBatch,
         write_options: &IpcWriteOptions,
-    ) -> Result<EncodedData, ArrowError> {
+        custom_metadata: &HashMap<String, String>,
+    ) -> Result<VectoredEncodedData, ArrowError> {
         let mut fbb = FlatBufferBuilder::new();
 
         let mut nodes: Vec<crate::FieldNode> = vec![];
         let mut buffers: Vec<crate::Buffer> = vec![];
-        let mut arrow_data: Vec<u8> = vec![];
+        let mut arrow_data: Vec<Buffer> = vec![];
         let mut offset = 0;
 
         // get the type of compression
@@ -433,9 +560,7 @@ impl IpcDataGenerator {
             )?;
         }
         // pad the tail of body data
-        let len = arrow_data.len();
-        let pad_len = pad_to_8(len as u32);
-        arrow_data.extend_from_slice(&vec![0u8; pad_len][..]);
+        let body_len = offset as usize + write_padding(&mut arrow_data, offset as usize);
 
         // write data
         let buffers = fbb.create_vector(&buffers);
@@ -451,35 +576,41 @@ impl IpcDataGenerator {
             let b = batch_builder.finish();
             b.as_union_value()
         };
+        let fb_custom_metadata = (!custom_metadata.is_empty())
+            .then(|| crate::convert::metadata_to_fb(&mut fbb, custom_metadata));
         // create an crate::Message
         let mut message = crate::MessageBuilder::new(&mut fbb);
         message.add_version(write_options.metadata_version);
         message.add_header_type(crate::MessageHeader::RecordBatch);
-        message.add_bodyLength(arrow_data.len() as i64);
+        message.add_bodyLength(body_len as i64);
         message.add_header(root);
+        if let Some(fb_custom_metadata) = fb_custom_metadata {
+            message.add_custom_metadata(fb_custom_metadata);
+        }
         let root = message.finish();
         fbb.finish(root, None);
         let finished_data = fbb.finished_data();
 
-        Ok(EncodedData {
+        Ok(VectoredEncodedData {
             ipc_message: finished_data.to_vec(),
             arrow_data,
         })
     }
 
     /// Write dictionary values into two sets of bytes, one for the header (crate::Message) and the
-    /// other for the data
+    /// other for the data. If `is_delta` the values are appended to the existing dictionary
     fn dictionary_batch_to_bytes(
         &self,
         dict_id: i64,
         array_data: &ArrayData,
         write_options: &IpcWriteOptions,
-    ) -> Result<EncodedData, ArrowError> {
+        is_delta: bool,
+    ) -> Result<VectoredEncodedData, ArrowError> {
         let mut fbb = FlatBufferBuilder::new();
 
         let mut nodes: Vec<crate::FieldNode> = vec![];
         let mut buffers: Vec<crate::Buffer> = vec![];
-        let mut arrow_data: Vec<u8> = vec![];
+        let mut arrow_data: Vec<Buffer> = vec![];
 
         // get the type of compression
         let batch_compression_type = write_options.batch_compression_type;
@@ -495,7 +626,7 @@ impl IpcDataGenerator {
             .map(|batch_compression_type| batch_compression_type.try_into())
             .transpose()?;
 
-        write_array_data(
+        let offset = write_array_data(
             array_data,
             &mut buffers,
             &mut arrow_data,
@@ -508,9 +639,7 @@ impl IpcDataGenerator {
         )?;
 
         // pad the tail of body data
-        let len = arrow_data.len();
-        let pad_len = pad_to_8(len as u32);
-        arrow_data.extend_from_slice(&vec![0u8; pad_len][..]);
+        let body_len = offset as usize + write_padding(&mut arrow_data, offset as usize);
 
         // write data
         let buffers = fbb.create_vector(&buffers);
@@ -531,6 +660,7 @@ impl IpcDataGenerator {
             let mut batch_builder = crate::DictionaryBatchBuilder::new(&mut fbb);
             batch_builder.add_id(dict_id);
             batch_builder.add_data(root);
+            batch_builder.add_isDelta(is_delta);
             batch_builder.finish().as_union_value()
         };
 
@@ -538,7 +668,7 @@ impl IpcDataGenerator {
             let mut message_builder = crate::MessageBuilder::new(&mut fbb);
             message_builder.add_version(write_options.metadata_version);
             message_builder.add_header_type(crate::MessageHeader::DictionaryBatch);
-            message_builder.add_bodyLength(arrow_data.len() as i64);
+            message_builder.add_bodyLength(body_len as i64);
             message_builder.add_header(root);
             message_builder.finish()
         };
@@ -546,7 +676,7 @@ impl IpcDataGenerator {
         fbb.finish(root, None);
         let finished_data = fbb.finished_data();
 
-        Ok(EncodedData {
+        Ok(VectoredEncodedData {
             ipc_message: finished_data.to_vec(),
             arrow_data,
         })
@@ -628,6 +758,19 @@ fn into_zero_offset_run_array<R: RunEndIndexType>(
     Ok(array_data.into())
 }
 
+/// The change to a dictionary, as determined by [`DictionaryTracker::insert_column`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum DictionaryUpdate {
+    /// The dictionary is unchanged, and does not need to be written
+    None,
+    /// The dictionary has not been seen before
+    New,
+    /// The dictionary has changed, and replaces the previous dictionary
+    Replaced,
+    /// The dictionary has been appended to, containing the appended values
+    Delta(ArrayData),
+}
+
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
 /// multiple times. Can optionally error if an update to an existing dictionary is attempted, which
 /// isn't allowed in the `FileWriter`.
@@ -661,32 +804,70 @@ impl DictionaryTracker {
         dict_id: i64,
         column: &ArrayRef,
     ) -> Result<bool, ArrowError> {
+        let update = self.insert_column(dict_id, column, DictionaryHandling::Resend)?;
+        Ok(update != DictionaryUpdate::None)
+    }
+
+    /// Keep track of the dictionary with the given ID and values, returning the
+    /// [`DictionaryUpdate`] that needs to be written. Behavior:
+    ///
+    /// * If this ID has not been written, return [`DictionaryUpdate::New`]
+    /// * If this ID has been written already and has the same data, return
+    ///   [`DictionaryUpdate::None`]
+    /// * If `dictionary_handling` is [`DictionaryHandling::Delta`], and the dictionary
+    ///   only appends values to the previous dictionary, return a [`DictionaryUpdate::Delta`]
+    ///   containing these values
+    /// * Otherwise, if this tracker is configured to return an error on replacement, return
+    ///   an error, else return [`DictionaryUpdate::Replaced`]
+    pub fn insert_column(
+        &mut self,
+        dict_id: i64,
+        column: &ArrayRef,
+        dictionary_handling: DictionaryHandling,
+    ) -> Result<DictionaryUpdate, ArrowError> {
         let dict_data = column.to_data();
         let dict_values = &dict_data.child_data()[0];
 
         // If a dictionary with this id was already emitted, check if it was the same.
-        if let Some(last) = self.written.get(&dict_id) {
-            if ArrayData::ptr_eq(&last.child_data()[0], dict_values) {
-                // Same dictionary values => no need to emit it again
-                return Ok(false);
-            }
-            if self.error_on_replacement {
-                // If error on replacement perform a logical comparison
-                if last.child_data()[0] == *dict_values {
-                    // Same dictionary values => no need to emit it again
-                    return Ok(false);
-                }
-                return Err(ArrowError::InvalidArgumentError(
-                    "Dictionary replacement detected when writing IPC file format. \
-                     Arrow IPC files only support a single dictionary for a given field \
-                     across all batches."
-                        .to_string(),
-                ));
+        let last = match self.written.get(&dict_id) {
+            Some(last) => &last.child_data()[0],
+            None => {
+                self.written.insert(dict_id, dict_data);
+                return Ok(DictionaryUpdate::New);
             }
+        };
+        if ArrayData::ptr_eq(last, dict_values) {
+            // Same dictionary values => no need to emit it again
+            return Ok(DictionaryUpdate::None);
+        }
+
+        let logical =
+            self.error_on_replacement || dictionary_handling == DictionaryHandling::Delta;
+        if logical && last == dict_values {
+            // Same dictionary values => no need to emit it again
+            return Ok(DictionaryUpdate::None);
+        }
+
+        if dictionary_handling == DictionaryHandling::Delta
+            && dict_values.len() > last.len()
+            && dict_values.slice(0, last.len()) == *last
+        {
+            let delta = dict_values.slice(last.len(), dict_values.len() - last.len());
+            self.written.insert(dict_id, dict_data);
+            return Ok(DictionaryUpdate::Delta(delta));
+        }
+
+        if self.error_on_replacement {
+            return Err(ArrowError::InvalidArgumentError(
+                "Dictionary replacement detected when writing IPC file format. \
+                 Arrow IPC files only support a single dictionary for a given field \
+                 across all batches."
+                    .to_string(),
+            ));
         }
 
         self.written.insert(dict_id, dict_data);
-        Ok(true)
+        Ok(DictionaryUpdate::Replaced)
     }
 }
 
@@ -756,21 +937,39 @@ impl<W: Write> FileWriter<W> {
 
     /// Write a record batch to the file
     pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.write_with_custom_metadata(batch, &HashMap::new())
+    }
+
+    /// Write a record batch to the file, attaching `custom_metadata` to its message
+    ///
+    /// The metadata can be read back with [`FileReader::batch_custom_metadata`]
+    ///
+    /// [`FileReader::batch_custom_metadata`]: crate::reader::FileReader::batch_custom_metadata
+    pub fn write_with_custom_metadata(
+        &mut self,
+        batch: &RecordBatch,
+        custom_metadata: &HashMap<String, String>,
+    ) -> Result<(), ArrowError> {
         if self.finished {
             return Err(ArrowError::IoError(
                 "Cannot write record batch to file writer as it is closed".to_string(),
             ));
         }
 
-        let (encoded_dictionaries, encoded_message) = self.data_gen.encoded_batch(
-            batch,
-            &mut self.dictionary_tracker,
-            &self.write_options,
-        )?;
+        let (encoded_dictionaries, encoded_message) =
+            self.data_gen.encoded_batch_vectored_with_custom_metadata(
+                batch,
+                &mut self.dictionary_tracker,
+                &self.write_options,
+                custom_metadata,
+            )?;
 
-        for encoded_dictionary in encoded_dictionaries {
-            let (meta, data) =
-                write_message(&mut self.writer, encoded_dictionary, &self.write_options)?;
+        for encoded_dictionary in &encoded_dictionaries {
+            let (meta, data) = write_message_vectored(
+                &mut self.writer,
+                encoded_dictionary,
+                &self.write_options,
+            )?;
 
             let block =
                 crate::Block::new(self.block_offsets as i64, meta as i32, data as i64);
@@ -778,8 +977,11 @@ impl<W: Write> FileWriter<W> {
             self.block_offsets += meta + data;
         }
 
-        let (meta, data) =
-            write_message(&mut self.writer, encoded_message, &self.write_options)?;
+        let (meta, data) = write_message_vectored(
+            &mut self.writer,
+            &encoded_message,
+            &self.write_options,
+        )?;
         // add a record block for the footer
         let block = crate::Block::new(
             self.block_offsets as i64,
@@ -832,6 +1034,12 @@ impl<W: Write> FileWriter<W> {
         Ok(())
     }
 
+    /// Flush any data buffered by this writer to the underlying writer
+    pub fn flush(&mut self) -> Result<(), ArrowError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
     /// Gets a reference to the underlying writer.
     pub fn get_ref(&self) -> &W {
         self.writer.get_ref()
@@ -908,6 +1116,19 @@ impl<W: Write> StreamWriter<W> {
 
     /// Write a record batch to the stream
     pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.write_with_custom_metadata(batch, &HashMap::new())
+    }
+
+    /// Write a record batch to the stream, attaching `custom_metadata` to its message
+    ///
+    /// The metadata can be read back with [`StreamReader::batch_custom_metadata`]
+    ///
+    /// [`StreamReader::batch_custom_metadata`]: crate::reader::StreamReader::batch_custom_metadata
+    pub fn write_with_custom_metadata(
+        &mut self,
+        batch: &RecordBatch,
+        custom_metadata: &HashMap<String, String>,
+    ) -> Result<(), ArrowError> {
         if self.finished {
             return Err(ArrowError::IoError(
                 "Cannot write record batch to stream writer as it is closed".to_string(),
@@ -916,14 +1137,23 @@ impl<W: Write> StreamWriter<W> {
 
         let (encoded_dictionaries, encoded_message) = self
             .data_gen
-            .encoded_batch(batch, &mut self.dictionary_tracker, &self.write_options)
+            .encoded_batch_vectored_with_custom_metadata(
+                batch,
+                &mut self.dictionary_tracker,
+                &self.write_options,
+                custom_metadata,
+            )
             .expect("StreamWriter is configured to not error on dictionary replacement");
 
-        for encoded_dictionary in encoded_dictionaries {
-            write_message(&mut self.writer, encoded_dictionary, &self.write_options)?;
+        for encoded_dictionary in &encoded_dictionaries {
+            write_message_vectored(
+                &mut self.writer,
+                encoded_dictionary,
+                &self.write_options,
+            )?;
         }
 
-        write_message(&mut self.writer, encoded_message, &self.write_options)?;
+        write_message_vectored(&mut self.writer, &encoded_message, &self.write_options)?;
         Ok(())
     }
 
@@ -942,6 +1172,12 @@ impl<W: Write> StreamWriter<W> {
         Ok(())
     }
 
+    /// Flush any data buffered by this writer to the underlying writer
+    pub fn flush(&mut self) -> Result<(), ArrowError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
     /// Gets a reference to the underlying writer.
     pub fn get_ref(&self) -> &W {
         self.writer.get_ref()
@@ -1018,13 +1254,67 @@ pub struct EncodedData {
     /// Arrow buffers to be written, should be an empty vec for schema messages
     pub arrow_data: Vec<u8>,
 }
+
+/// Stores the encoded data like [`EncodedData`], with the Arrow data as the sequence of
+/// buffers that make up the message body
+///
+/// The buffers reference the data of the encoded arrays where possible, avoiding the
+/// copy into a contiguous buffer, and can be written with [`write_message_vectored`]
+#[derive(Debug, Clone)]
+pub struct VectoredEncodedData {
+    /// An encoded crate::Message
+    pub ipc_message: Vec<u8>,
+    /// Arrow buffers to be written in order, should be empty for schema messages
+    pub arrow_data: Vec<Buffer>,
+}
+
+impl VectoredEncodedData {
+    /// Returns the total length of the Arrow data in bytes
+    pub fn arrow_data_len(&self) -> usize {
+        self.arrow_data.iter().map(|b| b.len()).sum()
+    }
+
+    /// Returns the Arrow data as a sequence of [`IoSlice`]
+    pub fn arrow_data_slices(&self) -> Vec<IoSlice<'_>> {
+        self.arrow_data.iter().map(|b| IoSlice::new(b)).collect()
+    }
+}
+
+impl From<VectoredEncodedData> for EncodedData {
+    fn from(value: VectoredEncodedData) -> Self {
+        let mut arrow_data = Vec::with_capacity(value.arrow_data_len());
+        value
+            .arrow_data
+            .iter()
+            .for_each(|b| arrow_data.extend_from_slice(b));
+        Self {
+            ipc_message: value.ipc_message,
+            arrow_data,
+        }
+    }
+}
+
 /// Write a message's IPC data and buffers, returning metadata and buffer data lengths written
 pub fn write_message<W: Write>(
-    mut writer: W,
+    writer: W,
     encoded: EncodedData,
     write_options: &IpcWriteOptions,
 ) -> Result<(usize, usize), ArrowError> {
-    let arrow_data_len = encoded.arrow_data.len();
+    let encoded = VectoredEncodedData {
+        ipc_message: encoded.ipc_message,
+        arrow_data: vec![Buffer::from_vec(encoded.arrow_data)],
+    };
+    write_message_vectored(writer, &encoded, write_options)
+}
+
+/// Write a message's IPC data and buffers like [`write_message`], passing them to
+/// [`Write::write_vectored`] rather than first copying them into a contiguous buffer
+pub fn write_message_vectored<W: Write>(
+    mut writer: W,
+    encoded: &VectoredEncodedData,
+    write_options: &IpcWriteOptions,
+) -> Result<(usize, usize), ArrowError> {
+    let arrow_data_len = encoded.arrow_data_len();
     if arrow_data_len % 8 != 0 {
         return Err(ArrowError::MemoryError(
             "Arrow data not aligned".to_string(),
@@ -1032,8 +1322,7 @@ pub fn write_message<W: Write>(
     }
 
     let a = write_options.alignment - 1;
-    let buffer = encoded.ipc_message;
-    let flatbuf_size = buffer.len();
+    let flatbuf_size = encoded.ipc_message.len();
     let prefix_size = if write_options.write_legacy_ipc_format {
         4
     } else {
@@ -1042,42 +1331,73 @@ pub fn write_message<W: Write>(
     let aligned_size = (flatbuf_size + prefix_size + a) & !a;
     let padding_bytes = aligned_size - flatbuf_size - prefix_size;
 
+    let mut prefix = Vec::with_capacity(prefix_size);
     write_continuation(
-        &mut writer,
+        &mut prefix,
         write_options,
         (aligned_size - prefix_size) as i32,
     )?;
+    let padding = vec![0; padding_bytes];
+
+    // write the prefix, the flatbuf, its padding and then the arrow data
+    let mut slices = vec![
+        prefix.as_slice(),
+        encoded.ipc_message.as_slice(),
+        padding.as_slice(),
+    ];
+    slices.extend(encoded.arrow_data.iter().map(|b| b.as_slice()));
+    write_all_vectored(&mut writer, &slices)?;
+    writer.flush()?;
 
-    // write the flatbuf
-    if flatbuf_size > 0 {
-        writer.write_all(&buffer)?;
-    }
-    // write padding
-    writer.write_all(&vec![0; padding_bytes])?;
-
-    // write arrow data
-    let body_len = if arrow_data_len > 0 {
-        write_body_buffers(&mut writer, &encoded.arrow_data)?
-    } else {
-        0
-    };
-
-    Ok((aligned_size, body_len))
+    Ok((aligned_size, arrow_data_len))
 }
 
-fn write_body_buffers<W: Write>(mut writer: W, data: &[u8]) -> Result<usize, ArrowError> {
-    let len = data.len() as u32;
-    let pad_len = pad_to_8(len) as u32;
-    let total_len = len + pad_len;
+/// Write all of `bufs` to `writer` with [`Write::write_vectored`]
+fn write_all_vectored<W: Write>(
+    mut writer: W,
+    mut bufs: &[&[u8]],
+) -> std::io::Result<()> {
+    // The number of bytes of the first buffer already written
+    let mut written = 0;
+    loop {
+        while let Some(first) = bufs.first() {
+            if written < first.len() {
+                break;
+            }
+            bufs = &bufs[1..];
+            written = 0;
+        }
+        if bufs.is_empty() {
+            return Ok(());
+        }
 
-    // write body buffer
-    writer.write_all(data)?;
-    if pad_len > 0 {
-        writer.write_all(&vec![0u8; pad_len as usize][..])?;
+        let slices: Vec<_> = std::iter::once(&bufs[0][written..])
+            .chain(bufs[1..].iter().copied())
+            .map(IoSlice::new)
+            .collect();
+        match writer.write_vectored(&slices) {
+            Ok(0) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::WriteZero,
+                    "failed to write whole buffer",
+                ))
+            }
+            Ok(mut n) => {
+                while n > 0 {
+                    let remaining = bufs[0].len() - written;
+                    if n < remaining {
+                        written += n;
+                        break;
+                    }
+                    n -= remaining;
+                    bufs = &bufs[1..];
+                    written = 0;
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
     }
-
-    writer.flush()?;
-    Ok(total_len as usize)
 }
 
 /// Write a record batch to the writer, writing the message size before the message
@@ -1185,7 +1505,7 @@ fn get_byte_array_buffers<O: OffsetSizeTrait>(data: &ArrayData) -> (Buffer, Buff
 fn write_array_data(
     array_data: &ArrayData,
     buffers: &mut Vec<crate::Buffer>,
-    arrow_data: &mut Vec<u8>,
+    arrow_data: &mut Vec<Buffer>,
     nodes: &mut Vec<crate::FieldNode>,
     offset: i64,
     num_rows: usize,
@@ -1214,37 +1534,22 @@ fn write_array_data(
             Some(buffer) => buffer.inner().sliced(),
         };
 
-        offset = write_buffer(
-            null_buffer.as_slice(),
-            buffers,
-            arrow_data,
-            offset,
-            compression_codec,
-        )?;
+        offset =
+            write_buffer(&null_buffer, buffers, arrow_data, offset, compression_codec)?;
     }
 
     let data_type = array_data.data_type();
     if matches!(data_type, DataType::Binary | DataType::Utf8) {
         let (offsets, values) = get_byte_array_buffers::<i32>(array_data);
         for buffer in [offsets, values] {
-            offset = write_buffer(
-                buffer.as_slice(),
-                buffers,
-                arrow_data,
-                offset,
-                compression_codec,
-            )?;
+            offset =
+                write_buffer(&buffer, buffers, arrow_data, offset, compression_codec)?;
         }
     } else if matches!(data_type, DataType::LargeBinary | DataType::LargeUtf8) {
         let (offsets, values) = get_byte_array_buffers::<i64>(array_data);
         for buffer in [offsets, values] {
-            offset = write_buffer(
-                buffer.as_slice(),
-                buffers,
-                arrow_data,
-                offset,
-                compression_codec,
-            )?;
+            offset =
+                write_buffer(&buffer, buffers, arrow_data, offset, compression_codec)?;
         }
     } else if DataType::is_numeric(data_type)
         || DataType::is_temporal(data_type)
@@ -1265,18 +1570,30 @@ fn write_array_data(
         if buffer_need_truncate(array_data.offset(), buffer, spec, min_length) {
             let byte_offset = array_data.offset() * byte_width;
             let buffer_length = min(min_length, buffer.len() - byte_offset);
-            let buffer_slice =
-                &buffer.as_slice()[byte_offset..(byte_offset + buffer_length)];
+            let buffer_slice = buffer.slice_with_length(byte_offset, buffer_length);
             offset = write_buffer(
-                buffer_slice,
+                &buffer_slice,
                 buffers,
                 arrow_data,
                 offset,
                 compression_codec,
             )?;
         } else {
+            offset =
+                write_buffer(buffer, buffers, arrow_data, offset, compression_codec)?;
+        }
+    } else if matches!(
+        data_type,
+        DataType::ListView(_) | DataType::LargeListView(_)
+    ) {
+        // The offsets and sizes of a list view refer to the whole values array, and
+        // so only need slicing to the extent of this array
+        let byte_width = get_buffer_element_width(&layout(data_type).buffers[0]);
+        let start = array_data.offset() * byte_width;
+        let len = array_data.len() * byte_width;
+        for buffer in array_data.buffers() {
             offset = write_buffer(
-                buffer.as_slice(),
+                &buffer.slice_with_length(start, len),
                 buffers,
                 arrow_data,
                 offset,
@@ -1340,8 +1657,8 @@ fn write_array_data(
     Ok(offset)
 }
 
-/// Write a buffer into `arrow_data`, a vector of bytes, and adds its
-/// [`crate::Buffer`] to `buffers`. Returns the new offset in `arrow_data`
+/// Append a buffer to `arrow_data`, the buffers of the message body, and adds its
+/// [`crate::Buffer`] to `buffers`. Returns the new offset in the message body
 ///
 ///
 /// From <https://github.com/apache/arrow/blob/6a936c4ff5007045e86f65f1a6b6c3c955ad5103/format/Message.fbs#L58>
@@ -1353,16 +1670,21 @@ fn write_array_data(
 /// follows is not compressed, which can be useful for cases where
 /// compression does not yield appreciable savings.
 fn write_buffer(
-    buffer: &[u8],                    // input
+    buffer: &Buffer,                  // input
     buffers: &mut Vec<crate::Buffer>, // output buffer descriptors
-    arrow_data: &mut Vec<u8>,         // output stream
+    arrow_data: &mut Vec<Buffer>,     // output stream
     offset: i64,                      // current output stream offset
     compression_codec: Option<CompressionCodec>,
 ) -> Result<i64, ArrowError> {
     let len: i64 = match compression_codec {
-        Some(compressor) => compressor.compress_to_vec(buffer, arrow_data)?,
+        Some(compressor) => {
+            let mut compressed = Vec::new();
+            let len = compressor.compress_to_vec(buffer, &mut compressed)?;
+            arrow_data.push(Buffer::from_vec(compressed));
+            len
+        }
         None => {
-            arrow_data.extend_from_slice(buffer);
+            arrow_data.push(buffer.clone());
             buffer.len()
         }
     }
@@ -1376,12 +1698,21 @@ fn write_buffer(
     // make new index entry
     buffers.push(crate::Buffer::new(offset, len));
     // padding and make offset 8 bytes aligned
-    let pad_len = pad_to_8(len as u32) as i64;
-    arrow_data.extend_from_slice(&vec![0u8; pad_len as usize][..]);
+    let pad_len = write_padding(arrow_data, len as usize) as i64;
 
     Ok(offset + len + pad_len)
 }
 
+/// Append the zeros needed to pad `len` bytes to an 8-byte boundary to `arrow_data`,
+/// returning the number of bytes appended
+fn write_padding(arrow_data: &mut Vec<Buffer>, len: usize) -> usize {
+    let pad_len = pad_to_8(len as u32);
+    if pad_len > 0 {
+        arrow_data.push(Buffer::from_vec(vec![0u8; pad_len]));
+    }
+    pad_len
+}
+
 /// Calculate an 8-byte boundary and return the number of bytes needed to pad to 8 bytes
 #[inline]
 fn pad_to_8(len: u32) -> usize {
@@ -1395,7 +1726,9 @@ mod tests {
     use std::sync::Arc;
 
     use arrow_array::builder::UnionBuilder;
-    use arrow_array::builder::{ListBuilder, PrimitiveRunBuilder, UInt32Builder};
+    use arrow_array::builder::{
+        ListBuilder, PrimitiveRunBuilder, StringDictionaryBuilder, UInt32Builder,
+    };
     use arrow_array::types::*;
     use arrow_schema::DataType;
 
@@ -1740,6 +2073,138 @@ mod tests {
         assert!(dict_tracker.written.contains_key(&2));
     }
 
+    fn dictionary_batches(
+        batch: &RecordBatch,
+        tracker: &mut DictionaryTracker,
+        options: &IpcWriteOptions,
+    ) -> Vec<(usize, bool)> {
+        let (dictionaries, _) = IpcDataGenerator {}
+            .encoded_batch(batch, tracker, options)
+            .unwrap();
+        dictionaries
+            .iter()
+            .map(|d| {
+                let message = crate::root_as_message(&d.ipc_message).unwrap();
+                let dictionary = message.header_as_dictionary_batch().unwrap();
+                (
+                    dictionary.data().unwrap().length() as usize,
+                    dictionary.isDelta(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_dictionary_delta() {
+        let mut builder = StringDictionaryBuilder::<Int32Type>::new();
+        builder.extend(["a", "b", "a"].map(Some));
+        let a = builder.finish_cloned();
+        builder.extend(["c", "a", "d"].map(Some));
+        let b = builder.finish_cloned();
+        builder.extend([Some("b")]);
+        let c = builder.finish_cloned();
+        let d: DictionaryArray<Int32Type> = vec!["x", "a"].into_iter().collect();
+
+        let schema = Arc::new(Schema::new(vec![Field::new_dict(
+            "dict",
+            a.data_type().clone(),
+            true,
+            0,
+            false,
+        )]));
+        let batches: Vec<_> = [a, b, c, d]
+            .into_iter()
+            .map(|x| RecordBatch::try_new(schema.clone(), vec![Arc::new(x)]).unwrap())
+            .collect();
+
+        let delta = IpcWriteOptions::default()
+            .with_dictionary_handling(DictionaryHandling::Delta);
+        let mut tracker = DictionaryTracker::new(false);
+        let written: Vec<_> = batches
+            .iter()
+            .map(|b| dictionary_batches(b, &mut tracker, &delta))
+            .collect();
+        assert_eq!(
+            written,
+            vec![vec![(2, false)], vec![(2, true)], vec![], vec![(2, false)]]
+        );
+
+        let resend = IpcWriteOptions::default();
+        let mut tracker = DictionaryTracker::new(false);
+        let written: Vec<_> = batches
+            .iter()
+            .map(|b| dictionary_batches(b, &mut tracker, &resend))
+            .collect();
+        assert_eq!(
+            written,
+            vec![
+                vec![(2, false)],
+                vec![(4, false)],
+                vec![(4, false)],
+                vec![(2, false)]
+            ]
+        );
+
+        for options in [delta, resend] {
+            let mut buf = Vec::new();
+            let mut writer =
+                StreamWriter::try_new_with_options(&mut buf, &schema, options).unwrap();
+            for batch in &batches {
+                writer.write(batch).unwrap();
+            }
+            writer.finish().unwrap();
+            drop(writer);
+
+            let reader = StreamReader::try_new(Cursor::new(buf), None).unwrap();
+            let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+            assert_eq!(read, batches);
+        }
+    }
+
+    #[test]
+    fn test_file_dictionary_delta() {
+        let mut builder = StringDictionaryBuilder::<Int32Type>::new();
+        builder.extend(["a", "b", "a"].map(Some));
+        let a = builder.finish_cloned();
+        builder.extend([Some("c"), None, Some("a")]);
+        let b = builder.finish_cloned();
+        let c: DictionaryArray<Int32Type> = vec!["x", "a"].into_iter().collect();
+
+        let schema = Arc::new(Schema::new(vec![Field::new_dict(
+            "dict",
+            a.data_type().clone(),
+            true,
+            0,
+            false,
+        )]));
+        let batch = |x: DictionaryArray<Int32Type>| {
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(x)]).unwrap()
+        };
+        let batches = vec![batch(a), batch(b)];
+
+        let options = IpcWriteOptions::default()
+            .with_dictionary_handling(DictionaryHandling::Delta);
+        let mut buf = Vec::new();
+        let mut writer =
+            FileWriter::try_new_with_options(&mut buf, &schema, options.clone()).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+
+        let reader = FileReader::try_new(Cursor::new(buf), None).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+
+        // Replacing a dictionary is not supported by the file format
+        let mut writer =
+            FileWriter::try_new_with_options(Vec::new(), &schema, options).unwrap();
+        writer.write(&batches[0]).unwrap();
+        let err = writer.write(&batch(c)).unwrap_err().to_string();
+        assert!(err.contains("Dictionary replacement detected"), "{err}");
+    }
+
     fn write_union_file(options: IpcWriteOptions) {
         let schema = Schema::new(vec![Field::new_union(
             "union",
@@ -2161,4 +2626,212 @@ mod tests {
         let batch2 = reader.next().unwrap().unwrap();
         assert_eq!(batch, batch2);
     }
+
+    #[test]
+    #[cfg(all(feature = "lz4", feature = "zstd"))]
+    fn test_compression_roundtrip() {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4)]),
+            Some(vec![Some(5), Some(6), Some(7)]),
+        ]);
+        let list_view = ListViewArray::new(
+            Arc::new(Field::new("item", DataType::Int32, true)),
+            vec![2, 0, 0, 1, 3].into(),
+            vec![2, 3, 0, 1, 1].into(),
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(3), Some(4)])),
+            Some(vec![true, true, false, true, true].into()),
+        );
+        let structs = StructArray::from(vec![
+            (
+                Arc::new(Field::new("a", DataType::Boolean, true)),
+                Arc::new(BooleanArray::from(vec![
+                    Some(true),
+                    None,
+                    Some(false),
+                    Some(true),
+                    None,
+                ])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", DataType::LargeBinary, false)),
+                Arc::new(LargeBinaryArray::from_vec(vec![
+                    b"a".as_ref(),
+                    b"",
+                    b"ccc",
+                    b"dd",
+                    b"e",
+                ])) as ArrayRef,
+            ),
+        ]);
+        let dict: DictionaryArray<Int32Type> =
+            vec![Some("a"), None, Some("b"), Some("a"), Some("c")]
+                .into_iter()
+                .collect();
+        let fixed = FixedSizeBinaryArray::try_from_iter(
+            vec![[1_u8, 2], [3, 4], [5, 6], [7, 8], [9, 0]].into_iter(),
+        )
+        .unwrap();
+        let decimal =
+            Decimal128Array::from(vec![Some(1), None, Some(-300), Some(4), Some(5)])
+                .with_precision_and_scale(10, 2)
+                .unwrap();
+        let mut run_builder = PrimitiveRunBuilder::<Int32Type, Int32Type>::new();
+        run_builder.extend([Some(1), Some(1), None, Some(2), Some(2)]);
+        let runs = run_builder.finish();
+
+        let batch = RecordBatch::try_from_iter(vec![
+            (
+                "int",
+                Arc::new(Int32Array::from(vec![
+                    Some(1),
+                    None,
+                    Some(3),
+                    Some(4),
+                    None,
+                ])) as ArrayRef,
+            ),
+            (
+                "str",
+                Arc::new(StringArray::from(vec!["foo", "bar", "", "baz", "qux"])) as _,
+            ),
+            ("decimal", Arc::new(decimal) as _),
+            ("fixed", Arc::new(fixed) as _),
+            ("list", Arc::new(list) as _),
+            ("list_view", Arc::new(list_view) as _),
+            ("struct", Arc::new(structs) as _),
+            ("dict", Arc::new(dict) as _),
+            ("runs", Arc::new(runs) as _),
+        ])
+        .unwrap();
+
+        let mut union_builder = UnionBuilder::new_dense();
+        union_builder.append::<Int32Type>("a", 1).unwrap();
+        union_builder.append::<Float64Type>("b", 3.4).unwrap();
+        union_builder.append_null::<Int32Type>("a").unwrap();
+        let union = union_builder.build().unwrap();
+        let union_batch =
+            RecordBatch::try_from_iter(vec![("union", Arc::new(union) as ArrayRef)])
+                .unwrap();
+
+        // Logical comparison of sliced run arrays is not supported
+        let projection: Vec<_> = (0..batch.num_columns() - 1).collect();
+        let sliced = batch.project(&projection).unwrap().slice(1, 3);
+
+        for compression in [
+            crate::CompressionType::LZ4_FRAME,
+            crate::CompressionType::ZSTD,
+        ] {
+            let options = IpcWriteOptions::try_new(8, false, MetadataVersion::V5)
+                .unwrap()
+                .try_with_compression(Some(compression))
+                .unwrap();
+
+            for batch in [batch.clone(), sliced.clone(), union_batch.clone()] {
+                let schema = batch.schema();
+                let mut writer =
+                    StreamWriter::try_new_with_options(vec![], &schema, options.clone())
+                        .unwrap();
+                writer.write(&batch).unwrap();
+                writer.finish().unwrap();
+                let data = writer.into_inner().unwrap();
+                let mut reader = StreamReader::try_new(Cursor::new(data), None).unwrap();
+                assert_eq!(reader.next().unwrap().unwrap(), batch);
+
+                let mut writer =
+                    FileWriter::try_new_with_options(vec![], &schema, options.clone())
+                        .unwrap();
+                writer.write(&batch).unwrap();
+                writer.finish().unwrap();
+                let data = writer.into_inner().unwrap();
+                let mut reader = FileReader::try_new(Cursor::new(data), None).unwrap();
+                assert_eq!(reader.next().unwrap().unwrap(), batch);
+            }
+        }
+    }
+
+    /// A writer that writes at most `limit` bytes on each call
+    struct ShortWriter {
+        data: Vec<u8>,
+        limit: usize,
+    }
+
+    impl Write for ShortWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let n = buf.len().min(self.limit);
+            self.data.extend_from_slice(&buf[..n]);
+            Ok(n)
+        }
+
+        fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> std::io::Result<usize> {
+            let mut written = 0;
+            for buf in bufs {
+                written += self.write(&buf[..buf.len().min(self.limit - written)])?;
+                if written == self.limit {
+                    break;
+                }
+            }
+            Ok(written)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_message_vectored() {
+        let values = StringArray::from(vec![Some("foo"), None, Some("barbaz")]);
+        let dict: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+        let batch = RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+            ("b", Arc::new(values) as ArrayRef),
+            ("c", Arc::new(dict) as ArrayRef),
+        ])
+        .unwrap()
+        .slice(1, 2);
+
+        let options = IpcWriteOptions::default();
+        let gen = IpcDataGenerator::default();
+        let (dicts, message) = gen
+            .encoded_batch_vectored(&batch, &mut DictionaryTracker::new(false), &options)
+            .unwrap();
+        let (expected_dicts, expected) = gen
+            .encoded_batch(&batch, &mut DictionaryTracker::new(false), &options)
+            .unwrap();
+        assert_eq!(dicts.len(), 1);
+        assert_eq!(expected_dicts.len(), 1);
+
+        // The values of the int32 column are not copied
+        let values = batch.column(0).to_data().buffers()[0].as_ptr();
+        assert!(message.arrow_data.iter().any(|b| b.as_ptr() == values));
+        let slices = message.arrow_data_slices();
+        let len: usize = slices.iter().map(|s| s.len()).sum();
+        assert_eq!(len, message.arrow_data_len());
+
+        let mut expected_out = vec![];
+        let expected_copy = EncodedData {
+            ipc_message: expected.ipc_message.clone(),
+            arrow_data: expected.arrow_data.clone(),
+        };
+        let expected_written =
+            write_message(&mut expected_out, expected_copy, &options).unwrap();
+
+        for limit in [1, 3, 64, usize::MAX] {
+            let mut out = ShortWriter {
+                data: vec![],
+                limit,
+            };
+            let written = write_message_vectored(&mut out, &message, &options).unwrap();
+            assert_eq!(written, expected_written);
+            assert_eq!(out.data, expected_out);
+            assert_eq!(out.data.len(), written.0 + written.1);
+        }
+
+        let converted = EncodedData::from(message);
+        assert_eq!(converted.ipc_message, expected.ipc_message);
+        assert_eq!(converted.arrow_data, expected.arrow_data);
+    }
 }
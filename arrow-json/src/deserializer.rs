@@ -0,0 +1,631 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Deserialize the rows of a [`RecordBatch`] or [`StructArray`] into Rust types
+//! implementing [`Deserialize`]
+//!
+//! This is the counterpart to [`Decoder::serialize`] and [`to_record_batch`], reading
+//! values directly from the arrow arrays without an intermediate JSON representation.
+//!
+//! ```
+//! # use arrow_json::deserializer::from_record_batch;
+//! # use arrow_json::reader::to_record_batch;
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Row<'a> {
+//!     id: i64,
+//!     name: &'a str,
+//!     tags: Vec<String>,
+//!     score: Option<f64>,
+//! }
+//!
+//! let rows = vec![
+//!     Row { id: 1, name: "a", tags: vec!["x".to_string()], score: Some(1.5) },
+//!     Row { id: 2, name: "b", tags: vec![], score: None },
+//! ];
+//!
+//! let batch = to_record_batch(&rows).unwrap();
+//! assert_eq!(batch.num_rows(), 2);
+//!
+//! // Strings can be borrowed from the RecordBatch
+//! let decoded: Vec<Row<'_>> = from_record_batch(&batch).unwrap();
+//! assert_eq!(decoded, rows);
+//! ```
+//!
+//! Values are mapped to the [serde data model] as follows:
+//!
+//! * Structs and rows are maps from field name to value, with null fields omitted
+//! * Lists are sequences, and maps with string keys are maps
+//! * Dictionaries are deserialized as their values
+//! * Temporal and decimal values are deserialized as strings, formatted as
+//!   by [`ArrayFormatter`]
+//! * Null values are `None` or unit
+//!
+//! [`Decoder::serialize`]: crate::reader::Decoder::serialize
+//! [`to_record_batch`]: crate::reader::to_record_batch
+//! [serde data model]: https://serde.rs/data-model.html
+
+use std::fmt::Display;
+use std::ops::Range;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::ArrowNativeType;
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_schema::{ArrowError, DataType, FieldRef};
+use serde::de::value::BorrowedStrDeserializer;
+use serde::de::{
+    self, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde::{forward_to_deserialize_any, Deserialize};
+
+/// Deserializes each row of `batch` into a `T`
+pub fn from_record_batch<'a, T: Deserialize<'a>>(
+    batch: &'a RecordBatch,
+) -> Result<Vec<T>, ArrowError> {
+    let schema = batch.schema();
+    (0..batch.num_rows())
+        .map(|idx| {
+            let row = RowDeserializer {
+                fields: schema.fields(),
+                columns: batch.columns(),
+                idx,
+            };
+            T::deserialize(row).map_err(|e| e.into_arrow(idx))
+        })
+        .collect()
+}
+
+/// Deserializes each element of `array` into a `T`
+///
+/// Null elements can be deserialized into an [`Option`]
+pub fn from_struct_array<'a, T: Deserialize<'a>>(
+    array: &'a StructArray,
+) -> Result<Vec<T>, ArrowError> {
+    (0..array.len())
+        .map(|idx| {
+            let value = ValueDeserializer { array, idx };
+            T::deserialize(value).map_err(|e| e.into_arrow(idx))
+        })
+        .collect()
+}
+
+/// The error returned by the deserializers in this module
+#[derive(Debug)]
+struct Error(String);
+
+impl Error {
+    fn into_arrow(self, row: usize) -> ArrowError {
+        ArrowError::JsonError(format!("failed to deserialize row {row}: {}", self.0))
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl de::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+impl From<ArrowError> for Error {
+    fn from(value: ArrowError) -> Self {
+        Self(value.to_string())
+    }
+}
+
+/// Deserializes a row of `columns` as a map from field name to value
+struct RowDeserializer<'a, 'f> {
+    fields: &'f [FieldRef],
+    columns: &'a [ArrayRef],
+    idx: usize,
+}
+
+impl<'a, 'f> Deserializer<'a> for RowDeserializer<'a, 'f> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_map(StructAccess {
+            fields: self.fields,
+            columns: self.columns,
+            idx: self.idx,
+            next: 0,
+        })
+    }
+
+    forward_to_deserialize_any! {
+        <W: Visitor<'a>>
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+macro_rules! dictionary_key_helper {
+    ($t:ty, $array:ident, $idx:ident) => {{
+        let dictionary = $array.as_dictionary::<$t>();
+        let key = dictionary.keys().value($idx).as_usize();
+        (dictionary.values().as_ref(), key)
+    }};
+}
+
+/// Deserializes the value at `idx` of `array`
+#[derive(Clone, Copy)]
+struct ValueDeserializer<'a> {
+    array: &'a dyn Array,
+    idx: usize,
+}
+
+impl<'a> ValueDeserializer<'a> {
+    fn is_null(&self) -> bool {
+        self.array.data_type() == &DataType::Null || self.array.is_null(self.idx)
+    }
+
+    /// Resolves dictionary encoded values to the referenced dictionary value
+    fn resolve(self) -> Self {
+        match self.array.data_type() {
+            DataType::Dictionary(k, _) if self.array.is_valid(self.idx) => {
+                let (array, idx) = (self.array, self.idx);
+                let (array, idx) = downcast_integer! {
+                    k.as_ref() => (dictionary_key_helper, array, idx),
+                    _ => unreachable!(),
+                };
+                Self { array, idx }.resolve()
+            }
+            _ => self,
+        }
+    }
+
+    fn as_str(&self) -> Option<&'a str> {
+        let array = self.array;
+        match array.data_type() {
+            DataType::Utf8 => Some(array.as_string::<i32>().value(self.idx)),
+            DataType::LargeUtf8 => Some(array.as_string::<i64>().value(self.idx)),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Deserializer<'a> for ValueDeserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value, Error> {
+        let value = self.resolve();
+        if value.is_null() {
+            return visitor.visit_unit();
+        }
+
+        let (array, idx) = (value.array, value.idx);
+        match array.data_type() {
+            DataType::Boolean => visitor.visit_bool(array.as_boolean().value(idx)),
+            DataType::Int8 => {
+                visitor.visit_i8(array.as_primitive::<Int8Type>().value(idx))
+            }
+            DataType::Int16 => {
+                visitor.visit_i16(array.as_primitive::<Int16Type>().value(idx))
+            }
+            DataType::Int32 => {
+                visitor.visit_i32(array.as_primitive::<Int32Type>().value(idx))
+            }
+            DataType::Int64 => {
+                visitor.visit_i64(array.as_primitive::<Int64Type>().value(idx))
+            }
+            DataType::UInt8 => {
+                visitor.visit_u8(array.as_primitive::<UInt8Type>().value(idx))
+            }
+            DataType::UInt16 => {
+                visitor.visit_u16(array.as_primitive::<UInt16Type>().value(idx))
+            }
+            DataType::UInt32 => {
+                visitor.visit_u32(array.as_primitive::<UInt32Type>().value(idx))
+            }
+            DataType::UInt64 => {
+                visitor.visit_u64(array.as_primitive::<UInt64Type>().value(idx))
+            }
+            DataType::Float16 => {
+                visitor.visit_f32(array.as_primitive::<Float16Type>().value(idx).to_f32())
+            }
+            DataType::Float32 => {
+                visitor.visit_f32(array.as_primitive::<Float32Type>().value(idx))
+            }
+            DataType::Float64 => {
+                visitor.visit_f64(array.as_primitive::<Float64Type>().value(idx))
+            }
+            DataType::Utf8 | DataType::LargeUtf8 => {
+                visitor.visit_borrowed_str(value.as_str().unwrap())
+            }
+            DataType::Binary => {
+                visitor.visit_borrowed_bytes(array.as_binary::<i32>().value(idx))
+            }
+            DataType::LargeBinary => {
+                visitor.visit_borrowed_bytes(array.as_binary::<i64>().value(idx))
+            }
+            DataType::FixedSizeBinary(_) => {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<FixedSizeBinaryArray>()
+                    .unwrap();
+                visitor.visit_borrowed_bytes(array.value(idx))
+            }
+            DataType::List(_) => {
+                let list = array.as_list::<i32>();
+                let offsets = list.value_offsets();
+                let range = offsets[idx].as_usize()..offsets[idx + 1].as_usize();
+                visitor.visit_seq(SeqAccessor::new(list.values().as_ref(), range))
+            }
+            DataType::LargeList(_) => {
+                let list = array.as_list::<i64>();
+                let offsets = list.value_offsets();
+                let range = offsets[idx].as_usize()..offsets[idx + 1].as_usize();
+                visitor.visit_seq(SeqAccessor::new(list.values().as_ref(), range))
+            }
+            DataType::FixedSizeList(_, _) => {
+                let list = array.as_fixed_size_list();
+                let start = list.value_offset(idx) as usize;
+                let range = start..start + list.value_length() as usize;
+                visitor.visit_seq(SeqAccessor::new(list.values().as_ref(), range))
+            }
+            DataType::Struct(fields) => {
+                let array = array.as_struct();
+                visitor.visit_map(StructAccess {
+                    fields,
+                    columns: array.columns(),
+                    idx,
+                    next: 0,
+                })
+            }
+            DataType::Map(_, _) => {
+                let map = array.as_map();
+                let offsets = map.value_offsets();
+                let range = offsets[idx].as_usize()..offsets[idx + 1].as_usize();
+                visitor.visit_map(MapAccessor {
+                    keys: map.keys().as_ref(),
+                    values: map.values().as_ref(),
+                    range,
+                    current: 0,
+                })
+            }
+            DataType::Date32
+            | DataType::Date64
+            | DataType::Timestamp(_, _)
+            | DataType::Time32(_)
+            | DataType::Time64(_)
+            | DataType::Duration(_)
+            | DataType::Interval(_)
+            | DataType::Decimal128(_, _)
+            | DataType::Decimal256(_, _) => {
+                let options = FormatOptions::default();
+                let formatter = ArrayFormatter::try_new(array, &options)?;
+                visitor.visit_string(formatter.value(idx).to_string())
+            }
+            d => Err(Error(format!("data type {d} not supported"))),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value, Error> {
+        let value = self.resolve();
+        match value.is_null() {
+            true => visitor.visit_none(),
+            false => visitor.visit_some(value),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'a>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'a>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let value = self.resolve();
+        // Unit variants are encoded as strings
+        match value.as_str().filter(|_| !value.is_null()) {
+            Some(s) => visitor.visit_enum(BorrowedStrDeserializer::new(s)),
+            None => value.deserialize_any(visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        <W: Visitor<'a>>
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+/// Deserializes the values in `range` of `array` as a sequence
+struct SeqAccessor<'a> {
+    array: &'a dyn Array,
+    range: Range<usize>,
+}
+
+impl<'a> SeqAccessor<'a> {
+    fn new(array: &'a dyn Array, range: Range<usize>) -> Self {
+        Self { array, range }
+    }
+}
+
+impl<'a> SeqAccess<'a> for SeqAccessor<'a> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'a>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        self.range
+            .next()
+            .map(|idx| {
+                seed.deserialize(ValueDeserializer {
+                    array: self.array,
+                    idx,
+                })
+            })
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.range.len())
+    }
+}
+
+/// Deserializes the non-null fields of a struct as a map
+struct StructAccess<'a, 'f> {
+    fields: &'f [FieldRef],
+    columns: &'a [ArrayRef],
+    idx: usize,
+    next: usize,
+}
+
+impl<'a, 'f> MapAccess<'a> for StructAccess<'a, 'f> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'a>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        while self.next < self.fields.len() {
+            let value = ValueDeserializer {
+                array: self.columns[self.next].as_ref(),
+                idx: self.idx,
+            };
+            if !value.resolve().is_null() {
+                let name = self.fields[self.next].name().as_str();
+                return seed.deserialize(name.into_deserializer()).map(Some);
+            }
+            self.next += 1;
+        }
+        Ok(None)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'a>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Error> {
+        let array = self.columns[self.next].as_ref();
+        self.next += 1;
+        seed.deserialize(ValueDeserializer {
+            array,
+            idx: self.idx,
+        })
+    }
+}
+
+/// Deserializes the entries in `range` of a [`MapArray`] as a map
+struct MapAccessor<'a> {
+    keys: &'a dyn Array,
+    values: &'a dyn Array,
+    range: Range<usize>,
+    current: usize,
+}
+
+impl<'a> MapAccess<'a> for MapAccessor<'a> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'a>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.range.next() {
+            Some(idx) => {
+                self.current = idx;
+                let key = ValueDeserializer {
+                    array: self.keys,
+                    idx,
+                };
+                seed.deserialize(key).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'a>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Error> {
+        seed.deserialize(ValueDeserializer {
+            array: self.values,
+            idx: self.current,
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.range.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::to_record_batch;
+    use arrow_array::builder::{MapBuilder, StringBuilder, StringDictionaryBuilder};
+    use serde::Serialize;
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Kind {
+        Small,
+        Large,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Inner {
+        a: Vec<Option<i32>>,
+        b: Option<bool>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Row {
+        id: u32,
+        kind: Kind,
+        inner: Option<Inner>,
+        attributes: BTreeMap<String, f64>,
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let rows = vec![
+            Row {
+                id: 1,
+                kind: Kind::Small,
+                inner: Some(Inner {
+                    a: vec![Some(1), None],
+                    b: None,
+                }),
+                attributes: BTreeMap::from([("x".to_string(), 1.5)]),
+            },
+            Row {
+                id: 2,
+                kind: Kind::Large,
+                inner: None,
+                attributes: BTreeMap::new(),
+            },
+        ];
+
+        let batch = to_record_batch(&rows).unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.num_columns(), 4);
+
+        let decoded: Vec<Row> = from_record_batch(&batch).unwrap();
+        assert_eq!(decoded, rows);
+
+        let empty = to_record_batch::<Row>(&[]).unwrap();
+        assert_eq!(empty.num_rows(), 0);
+    }
+
+    #[test]
+    fn test_arrow_types() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Row {
+            dict: Kind,
+            ts: String,
+            map: BTreeMap<String, Option<String>>,
+        }
+
+        let mut dict = StringDictionaryBuilder::<Int8Type>::new();
+        dict.append_value("Large");
+        dict.append_value("Small");
+        let dict = dict.finish();
+
+        let ts = TimestampMillisecondArray::from(vec![0, 1_000]);
+
+        let mut map = MapBuilder::new(None, StringBuilder::new(), StringBuilder::new());
+        map.keys().append_value("a");
+        map.values().append_null();
+        map.append(true).unwrap();
+        map.append(true).unwrap();
+        let map = map.finish();
+
+        let batch = RecordBatch::try_from_iter([
+            ("dict", Arc::new(dict) as ArrayRef),
+            ("ts", Arc::new(ts) as _),
+            ("map", Arc::new(map) as _),
+        ])
+        .unwrap();
+
+        let decoded: Vec<Row> = from_record_batch(&batch).unwrap();
+        assert_eq!(
+            decoded,
+            vec![
+                Row {
+                    dict: Kind::Large,
+                    ts: "1970-01-01T00:00:00".to_string(),
+                    map: BTreeMap::from([("a".to_string(), None)]),
+                },
+                Row {
+                    dict: Kind::Small,
+                    ts: "1970-01-01T00:00:01".to_string(),
+                    map: BTreeMap::new(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_struct_array() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Row<'a> {
+            a: Option<i32>,
+            b: &'a str,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct Strict {
+            _a: i32,
+        }
+
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let strings = StringArray::from(vec!["x", "y", "z"]);
+        let array = StructArray::try_from(vec![
+            ("a", Arc::new(a) as ArrayRef),
+            (
+                "_a",
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])) as _,
+            ),
+            ("b", Arc::new(strings) as _),
+        ])
+        .unwrap();
+
+        let decoded: Vec<Row<'_>> = from_struct_array(&array).unwrap();
+        let expected = vec![
+            Row { a: Some(1), b: "x" },
+            Row { a: None, b: "y" },
+            Row { a: Some(3), b: "z" },
+        ];
+        assert_eq!(decoded, expected);
+
+        let err = from_struct_array::<Strict>(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: failed to deserialize row 1: missing field `_a`"
+        );
+    }
+}
@@ -0,0 +1,88 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::marker::PhantomData;
+
+use arrow_array::builder::GenericByteDictionaryBuilder;
+use arrow_array::types::{ArrowDictionaryKeyType, GenericStringType};
+use arrow_array::{Array, OffsetSizeTrait};
+use arrow_data::ArrayData;
+use arrow_schema::ArrowError;
+
+use crate::reader::tape::{Tape, TapeElement};
+use crate::reader::ArrayDecoder;
+
+const TRUE: &str = "true";
+const FALSE: &str = "false";
+
+// Invariant and Send
+type Phantom<K, O> = PhantomData<fn(K, O) -> (K, O)>;
+
+/// Decodes strings directly into a dictionary encoded array, avoiding
+/// materializing each repeated value
+pub struct StringDictionaryArrayDecoder<K, O> {
+    coerce_primitive: bool,
+    phantom: Phantom<K, O>,
+}
+
+impl<K, O> StringDictionaryArrayDecoder<K, O> {
+    pub fn new(coerce_primitive: bool) -> Self {
+        Self {
+            coerce_primitive,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<K, O> ArrayDecoder for StringDictionaryArrayDecoder<K, O>
+where
+    K: ArrowDictionaryKeyType,
+    O: OffsetSizeTrait,
+{
+    fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData, ArrowError> {
+        let coerce_primitive = self.coerce_primitive;
+
+        let mut builder =
+            GenericByteDictionaryBuilder::<K, GenericStringType<O>>::with_capacity(
+                pos.len(),
+                pos.len(),
+                1024,
+            );
+
+        for p in pos {
+            let value = match tape.get(*p) {
+                TapeElement::String(idx) => tape.get_string(idx),
+                TapeElement::Null => {
+                    builder.append_null();
+                    continue;
+                }
+                TapeElement::True if coerce_primitive => TRUE,
+                TapeElement::False if coerce_primitive => FALSE,
+                TapeElement::Number(idx) if coerce_primitive => tape.get_string(idx),
+                _ => return Err(tape.error(*p, "string")),
+            };
+            builder.append(value).map_err(|_| {
+                ArrowError::JsonError(format!(
+                    "dictionary key overflow decoding {}",
+                    K::DATA_TYPE
+                ))
+            })?;
+        }
+
+        Ok(builder.finish().into_data())
+    }
+}
@@ -130,6 +130,7 @@
 //!
 
 use std::io::BufRead;
+use std::sync::Arc;
 
 use chrono::Utc;
 use serde::Serialize;
@@ -144,6 +145,7 @@ pub use schema::*;
 
 use crate::reader::boolean_array::BooleanArrayDecoder;
 use crate::reader::decimal_array::DecimalArrayDecoder;
+use crate::reader::dictionary_array::StringDictionaryArrayDecoder;
 use crate::reader::list_array::ListArrayDecoder;
 use crate::reader::map_array::MapArrayDecoder;
 use crate::reader::null_array::NullArrayDecoder;
@@ -155,6 +157,7 @@ use crate::reader::timestamp_array::TimestampArrayDecoder;
 
 mod boolean_array;
 mod decimal_array;
+mod dictionary_array;
 mod list_array;
 mod map_array;
 mod null_array;
@@ -171,6 +174,7 @@ pub struct ReaderBuilder {
     batch_size: usize,
     coerce_primitive: bool,
     strict_mode: bool,
+    lenient: bool,
 
     schema: SchemaRef,
 }
@@ -189,6 +193,7 @@ impl ReaderBuilder {
             batch_size: 1024,
             coerce_primitive: false,
             strict_mode: false,
+            lenient: false,
             schema,
         }
     }
@@ -223,6 +228,16 @@ impl ReaderBuilder {
         }
     }
 
+    /// Sets if the decoder should set columns that fail to decode, such as a string
+    /// in a numeric column, to null instead of returning an error
+    ///
+    /// The columns set to null for each [`RecordBatch`] are reported by
+    /// [`Decoder::errors`] and [`Reader::errors`]. Decoding still fails if the input
+    /// is not valid JSON, or a non-nullable column cannot be decoded
+    pub fn with_lenient(self, lenient: bool) -> Self {
+        Self { lenient, ..self }
+    }
+
     /// Create a [`Reader`] with the provided [`BufRead`]
     pub fn build<R: BufRead>(self, reader: R) -> Result<Reader<R>, ArrowError> {
         Ok(Reader {
@@ -233,12 +248,13 @@ impl ReaderBuilder {
 
     /// Create a [`Decoder`]
     pub fn build_decoder(self) -> Result<Decoder, ArrowError> {
-        let decoder = make_decoder(
+        let decoder = StructArrayDecoder::new(
             DataType::Struct(self.schema.fields.clone()),
             self.coerce_primitive,
             self.strict_mode,
             false,
-        )?;
+        )?
+        .with_lenient(self.lenient);
         let num_fields = self.schema.all_fields().len();
 
         Ok(Decoder {
@@ -284,6 +300,13 @@ impl<R: BufRead> Reader<R> {
         }
         self.decoder.flush()
     }
+
+    /// Returns the columns set to null in the last [`RecordBatch`] read
+    ///
+    /// This is always empty unless [`ReaderBuilder::with_lenient`] is set
+    pub fn errors(&self) -> &[DecodeError] {
+        self.decoder.errors()
+    }
 }
 
 impl<R: BufRead> Iterator for Reader<R> {
@@ -342,7 +365,7 @@ impl<R: BufRead> RecordBatchReader for Reader<R> {
 /// ```
 pub struct Decoder {
     tape_decoder: TapeDecoder,
-    decoder: Box<dyn ArrayDecoder>,
+    decoder: StructArrayDecoder,
     batch_size: usize,
     schema: SchemaRef,
 }
@@ -583,6 +606,68 @@ impl Decoder {
             .with_schema(self.schema.clone())?;
         Ok(Some(batch))
     }
+
+    /// Returns the columns set to null in the last [`RecordBatch`] returned by
+    /// [`Self::flush`]
+    ///
+    /// This is always empty unless [`ReaderBuilder::with_lenient`] is set
+    pub fn errors(&self) -> &[DecodeError] {
+        self.decoder.errors()
+    }
+}
+
+/// Serializes `rows` to a [`RecordBatch`], with a schema inferred from the rows
+///
+/// The schema is inferred with [`infer_json_schema_from_serialize`], which requires
+/// serializing each row twice. If the schema is known, using [`Decoder::serialize`]
+/// directly avoids this cost. See [`crate::deserializer`] to convert the rows back
+pub fn to_record_batch<S: Serialize>(rows: &[S]) -> Result<RecordBatch, ArrowError> {
+    let schema = Arc::new(infer_json_schema_from_serialize(rows)?);
+    let mut decoder = ReaderBuilder::new(schema.clone()).build_decoder()?;
+    decoder.serialize(rows)?;
+    Ok(decoder
+        .flush()?
+        .unwrap_or_else(|| RecordBatch::new_empty(schema)))
+}
+
+/// A column value set to null as it could not be decoded, see
+/// [`ReaderBuilder::with_lenient`]
+#[derive(Debug)]
+pub struct DecodeError {
+    row: usize,
+    column: String,
+    error: ArrowError,
+}
+
+impl DecodeError {
+    fn new(row: usize, column: String, error: ArrowError) -> Self {
+        Self { row, column, error }
+    }
+
+    /// Returns the index of the row within its [`RecordBatch`]
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// Returns the name of the column
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+
+    /// Returns the error encountered decoding the value
+    pub fn error(&self) -> &ArrowError {
+        &self.error
+    }
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "row {} column '{}': {}",
+            self.row, self.column, self.error
+        )
+    }
 }
 
 trait ArrayDecoder: Send {
@@ -590,6 +675,14 @@ trait ArrayDecoder: Send {
     fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData, ArrowError>;
 }
 
+macro_rules! string_dictionary_decoder {
+    ($t:ty, $o:ty, $coerce_primitive:expr) => {
+        Ok(Box::new(StringDictionaryArrayDecoder::<$t, $o>::new(
+            $coerce_primitive,
+        )))
+    };
+}
+
 macro_rules! primitive_decoder {
     ($t:ty, $data_type:expr) => {
         Ok(Box::new(PrimitiveArrayDecoder::<$t>::new($data_type)))
@@ -642,6 +735,8 @@ fn make_decoder(
         DataType::Time32(TimeUnit::Millisecond) => primitive_decoder!(Time32MillisecondType, data_type),
         DataType::Time64(TimeUnit::Microsecond) => primitive_decoder!(Time64MicrosecondType, data_type),
         DataType::Time64(TimeUnit::Nanosecond) => primitive_decoder!(Time64NanosecondType, data_type),
+        DataType::Decimal32(p, s) => Ok(Box::new(DecimalArrayDecoder::<Decimal32Type>::new(p, s))),
+        DataType::Decimal64(p, s) => Ok(Box::new(DecimalArrayDecoder::<Decimal64Type>::new(p, s))),
         DataType::Decimal128(p, s) => Ok(Box::new(DecimalArrayDecoder::<Decimal128Type>::new(p, s))),
         DataType::Decimal256(p, s) => Ok(Box::new(DecimalArrayDecoder::<Decimal256Type>::new(p, s))),
         DataType::Boolean => Ok(Box::<BooleanArrayDecoder>::default()),
@@ -654,6 +749,17 @@ fn make_decoder(
             Err(ArrowError::JsonError(format!("{data_type} is not supported by JSON")))
         }
         DataType::Map(_, _) => Ok(Box::new(MapArrayDecoder::new(data_type, coerce_primitive, strict_mode, is_nullable)?)),
+        DataType::Dictionary(ref k, ref v) => match v.as_ref() {
+            DataType::Utf8 => downcast_integer! {
+                k.as_ref() => (string_dictionary_decoder, i32, coerce_primitive),
+                _ => Err(ArrowError::JsonError(format!("{data_type} is not supported by JSON")))
+            },
+            DataType::LargeUtf8 => downcast_integer! {
+                k.as_ref() => (string_dictionary_decoder, i64, coerce_primitive),
+                _ => Err(ArrowError::JsonError(format!("{data_type} is not supported by JSON")))
+            },
+            _ => Err(ArrowError::NotYetImplemented(format!("Support for {data_type} in JSON reader")))
+        },
         d => Err(ArrowError::NotYetImplemented(format!("Support for {d} in JSON reader")))
     }
 }
@@ -2175,4 +2281,126 @@ mod tests {
         let values = batch.column(0).as_primitive::<TimestampSecondType>();
         assert_eq!(values.values(), &[1681319393, -7200]);
     }
+
+    #[test]
+    fn test_lenient() {
+        let buf = r#"
+        {"a": 1, "b": "x", "c": [1, 2]}
+        {"a": 1e100, "b": 2, "c": [3, "four"]}
+        {"a": "three", "b": "y", "c": {"d": 1}}
+        "#;
+
+        // Infer the schema from the first record only
+        let schema = infer_json_schema(Cursor::new(buf.as_bytes()), Some(1)).unwrap();
+        let schema = Arc::new(schema);
+
+        let err = ReaderBuilder::new(schema.clone())
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .read()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: whilst decoding field 'a': failed to parse 1e100 as Int64"
+        );
+
+        let mut reader = ReaderBuilder::new(schema)
+            .with_lenient(true)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+
+        let a = batch.column(0).as_primitive::<Int64Type>();
+        assert_eq!(a.iter().collect::<Vec<_>>(), vec![Some(1), None, None]);
+        let b = batch.column(1).as_string::<i32>();
+        assert_eq!(
+            b.iter().collect::<Vec<_>>(),
+            vec![Some("x"), None, Some("y")]
+        );
+        let c = batch.column(2).as_list::<i32>();
+        assert_eq!(c.null_count(), 2);
+        assert!(c.is_valid(0));
+
+        let errors: Vec<_> = reader
+            .errors()
+            .iter()
+            .map(|e| (e.row(), e.column()))
+            .collect();
+        assert_eq!(
+            errors,
+            vec![(1, "a"), (1, "b"), (1, "c"), (2, "a"), (2, "c")]
+        );
+        assert_eq!(
+            reader.errors()[0].to_string(),
+            "row 1 column 'a': Json error: failed to parse 1e100 as Int64"
+        );
+
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_lenient_non_nullable() {
+        let buf = r#"{"a": "one"}"#;
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let err = ReaderBuilder::new(schema)
+            .with_lenient(true)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .read()
+            .unwrap_err();
+        assert!(err.to_string().contains("unmasked nulls"), "{err}");
+    }
+
+    #[test]
+    fn test_read_string_dictionary() {
+        let buf = r#"
+        {"a": "foo", "b": ["x", "y"]}
+        {"a": null, "b": ["y"]}
+        {"a": "foo", "b": null}
+        {"a": 3, "b": ["x", true]}
+        "#;
+
+        let dict =
+            |k: DataType, v: DataType| DataType::Dictionary(Box::new(k), Box::new(v));
+        let b_item = Field::new("item", dict(DataType::Int8, DataType::LargeUtf8), true);
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", dict(DataType::Int32, DataType::Utf8), true),
+            Field::new("b", DataType::List(Arc::new(b_item)), true),
+        ]));
+
+        let batches = do_read(buf, 1024, true, false, schema);
+        assert_eq!(batches.len(), 1);
+
+        let a = batches[0].column(0).as_dictionary::<Int32Type>();
+        let a_values = a.values().as_string::<i32>();
+        assert_eq!(
+            a_values.iter().collect::<Vec<_>>(),
+            vec![Some("foo"), Some("3")]
+        );
+        let a_keys: Vec<_> = a.keys().iter().collect();
+        assert_eq!(a_keys, vec![Some(0), None, Some(0), Some(1)]);
+
+        let b = batches[0].column(1).as_list::<i32>();
+        assert_eq!(b.null_count(), 1);
+        let b_values = b.values().as_dictionary::<Int8Type>();
+        let b_keys: Vec<_> = b_values.keys().iter().flatten().collect();
+        assert_eq!(b_keys, vec![0, 1, 1, 0, 2]);
+        let b_strings = b_values.values().as_string::<i64>();
+        assert_eq!(b_strings.value(2), "true");
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "a",
+            dict(DataType::Int32, DataType::Utf8),
+            true,
+        )]));
+        let err = ReaderBuilder::new(schema)
+            .build(Cursor::new(r#"{"a": 1}"#.as_bytes()))
+            .unwrap()
+            .read()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: whilst decoding field 'a': expected string got 1"
+        );
+    }
 }
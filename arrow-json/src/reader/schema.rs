@@ -18,6 +18,7 @@
 use arrow_schema::{ArrowError, DataType, Field, Fields, Schema};
 use indexmap::map::IndexMap as HashMap;
 use indexmap::set::IndexSet as HashSet;
+use serde::Serialize;
 use serde_json::Value;
 use std::borrow::Borrow;
 use std::io::{BufRead, Seek};
@@ -272,6 +273,22 @@ pub fn infer_json_schema<R: BufRead>(
     infer_json_schema_from_iterator(ValueIter::new(reader, max_read_records))
 }
 
+/// Infer the fields of the JSON representation of `rows`
+///
+/// The resulting schema can be used with [`Decoder::serialize`] to convert `rows`
+/// to a [`RecordBatch`], as done by [`to_record_batch`]
+///
+/// [`Decoder::serialize`]: super::Decoder::serialize
+/// [`RecordBatch`]: arrow_array::RecordBatch
+/// [`to_record_batch`]: super::to_record_batch
+pub fn infer_json_schema_from_serialize<S: Serialize>(
+    rows: &[S],
+) -> Result<Schema, ArrowError> {
+    infer_json_schema_from_iterator(rows.iter().map(|row| {
+        serde_json::to_value(row).map_err(|e| ArrowError::JsonError(e.to_string()))
+    }))
+}
+
 fn set_object_scalar_field_type(
     field_types: &mut HashMap<String, InferredType>,
     key: &str,
@@ -16,17 +16,19 @@
 // under the License.
 
 use crate::reader::tape::{Tape, TapeElement};
-use crate::reader::{make_decoder, ArrayDecoder};
+use crate::reader::{make_decoder, ArrayDecoder, DecodeError};
 use arrow_array::builder::BooleanBufferBuilder;
 use arrow_buffer::buffer::NullBuffer;
 use arrow_data::{ArrayData, ArrayDataBuilder};
-use arrow_schema::{ArrowError, DataType, Fields};
+use arrow_schema::{ArrowError, DataType, Field, Fields};
 
 pub struct StructArrayDecoder {
     data_type: DataType,
     decoders: Vec<Box<dyn ArrayDecoder>>,
     strict_mode: bool,
     is_nullable: bool,
+    /// If `Some`, fields that fail to decode are set to null and recorded here
+    errors: Option<Vec<DecodeError>>,
 }
 
 impl StructArrayDecoder {
@@ -57,8 +59,20 @@ impl StructArrayDecoder {
             decoders,
             strict_mode,
             is_nullable,
+            errors: None,
         })
     }
+
+    /// Set fields that fail to decode to null, instead of returning an error
+    pub fn with_lenient(mut self, lenient: bool) -> Self {
+        self.errors = lenient.then(Vec::new);
+        self
+    }
+
+    /// Returns the fields set to null by the last call to [`ArrayDecoder::decode`]
+    pub fn errors(&self) -> &[DecodeError] {
+        self.errors.as_deref().unwrap_or_default()
+    }
 }
 
 impl ArrayDecoder for StructArrayDecoder {
@@ -111,22 +125,35 @@ impl ArrayDecoder for StructArrayDecoder {
             }
         }
 
+        if let Some(errors) = self.errors.as_mut() {
+            errors.clear();
+        }
+
         let child_data = self
             .decoders
             .iter_mut()
             .zip(child_pos)
             .zip(fields)
-            .map(|((d, pos), f)| {
-                d.decode(tape, &pos).map_err(|e| match e {
-                    ArrowError::JsonError(s) => ArrowError::JsonError(format!(
-                        "whilst decoding field '{}': {s}",
-                        f.name()
-                    )),
-                    e => e,
-                })
-            })
+            .map(
+                |((d, mut pos), f)| match (d.decode(tape, &pos), &mut self.errors) {
+                    (Err(_), Some(errors)) => {
+                        decode_lenient(d.as_mut(), tape, &mut pos, f, errors)
+                    }
+                    (r, _) => r.map_err(|e| match e {
+                        ArrowError::JsonError(s) => ArrowError::JsonError(format!(
+                            "whilst decoding field '{}': {s}",
+                            f.name()
+                        )),
+                        e => e,
+                    }),
+                },
+            )
             .collect::<Result<Vec<_>, ArrowError>>()?;
 
+        if let Some(errors) = self.errors.as_mut() {
+            errors.sort_by_key(|e| e.row());
+        }
+
         let nulls = nulls.as_mut().map(|x| NullBuffer::new(x.finish()));
 
         for (c, f) in child_data.iter().zip(fields) {
@@ -153,6 +180,25 @@ impl ArrayDecoder for StructArrayDecoder {
     }
 }
 
+/// Decodes each row in `pos` individually, replacing those that fail to decode
+/// with null and recording the error in `errors`
+fn decode_lenient(
+    decoder: &mut dyn ArrayDecoder,
+    tape: &Tape<'_>,
+    pos: &mut [u32],
+    field: &Field,
+    errors: &mut Vec<DecodeError>,
+) -> Result<ArrayData, ArrowError> {
+    for (row, p) in pos.iter_mut().enumerate() {
+        if let Err(e) = decoder.decode(tape, std::slice::from_ref(p)) {
+            errors.push(DecodeError::new(row, field.name().clone(), e));
+            // The first tape element is a null sentinel
+            *p = 0;
+        }
+    }
+    decoder.decode(tape, pos)
+}
+
 fn struct_fields(data_type: &DataType) -> &Fields {
     match &data_type {
         DataType::Struct(f) => f,
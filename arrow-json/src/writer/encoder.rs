@@ -0,0 +1,579 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Encodes arrow arrays directly to JSON bytes, without materializing
+//! intermediate [`serde_json::Value`]s
+
+use std::fmt::Write as _;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{ArrowNativeType, BooleanBuffer, NullBuffer, OffsetBuffer};
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_schema::{ArrowError, DataType, FieldRef};
+
+use crate::JsonSerializable;
+
+/// Options controlling how arrays are encoded as JSON
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EncoderOptions<'a> {
+    /// Write `null` for null fields of objects, instead of omitting them
+    pub explicit_nulls: bool,
+    /// Options used to format temporal, interval and decimal values
+    pub format: FormatOptions<'a>,
+}
+
+/// Encodes the values of an array as JSON
+pub(crate) trait Encoder {
+    /// Appends the JSON encoding of the value at `idx` to `out`
+    ///
+    /// Encoders returned by [`make_encoder`] handle null values, other encoders
+    /// may produce arbitrary output for a null `idx`
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>);
+}
+
+/// Returns an [`Encoder`] for `array`, that encodes null values as `null`
+pub(crate) fn make_encoder<'a>(
+    array: &'a dyn Array,
+    options: &EncoderOptions<'a>,
+) -> Result<Box<dyn Encoder + 'a>, ArrowError> {
+    let (encoder, nulls) = make_encoder_impl(array, options)?;
+    Ok(match nulls.filter(|n| n.null_count() > 0) {
+        Some(nulls) => Box::new(NullableEncoder { encoder, nulls }),
+        None => encoder,
+    })
+}
+
+/// Returns an [`Encoder`] encoding each row of `columns` as a JSON object, with
+/// the keys given by `fields`
+pub(crate) fn make_struct_encoder<'a>(
+    fields: &'a [FieldRef],
+    columns: &'a [ArrayRef],
+    options: &EncoderOptions<'a>,
+) -> Result<Box<dyn Encoder + 'a>, ArrowError> {
+    let encoder = StructEncoder::try_new(fields, columns, options)?;
+    Ok(Box::new(encoder))
+}
+
+macro_rules! primitive_helper {
+    ($t:ty, $array:ident) => {{
+        let array = $array.as_primitive::<$t>();
+        let encoder =
+            PrimitiveEncoder::<<$t as ArrowPrimitiveType>::Native>(array.values());
+        (Box::new(encoder) as _, array.nulls().cloned())
+    }};
+}
+
+macro_rules! dictionary_helper {
+    ($t:ty, $array:ident, $options:ident) => {
+        make_dictionary_encoder($array.as_dictionary::<$t>(), $options)?
+    };
+}
+
+/// Returns an [`Encoder`] for `array` that assumes the value is valid, along
+/// with the logical null mask of `array`
+fn make_encoder_impl<'a>(
+    array: &'a dyn Array,
+    options: &EncoderOptions<'a>,
+) -> Result<(Box<dyn Encoder + 'a>, Option<NullBuffer>), ArrowError> {
+    Ok(downcast_integer! {
+        array.data_type() => (primitive_helper, array),
+        DataType::Float16 => primitive_helper!(Float16Type, array),
+        DataType::Float32 => primitive_helper!(Float32Type, array),
+        DataType::Float64 => primitive_helper!(Float64Type, array),
+        DataType::Null => {
+            let nulls = NullBuffer::new_null(array.len());
+            (Box::new(NullEncoder) as _, Some(nulls))
+        }
+        DataType::Boolean => {
+            let array = array.as_boolean();
+            (Box::new(BooleanEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::Utf8 => {
+            let array = array.as_string::<i32>();
+            (Box::new(StringEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_string::<i64>();
+            (Box::new(StringEncoder(array)) as _, array.nulls().cloned())
+        }
+        DataType::List(_) => {
+            let array = array.as_list::<i32>();
+            let encoder = ListEncoder::try_new(array.offsets(), array.values(), options)?;
+            (Box::new(encoder) as _, array.nulls().cloned())
+        }
+        DataType::LargeList(_) => {
+            let array = array.as_list::<i64>();
+            let encoder = ListEncoder::try_new(array.offsets(), array.values(), options)?;
+            (Box::new(encoder) as _, array.nulls().cloned())
+        }
+        DataType::FixedSizeList(_, _) => {
+            let array = array.as_fixed_size_list();
+            let encoder = FixedSizeListEncoder {
+                array,
+                encoder: make_encoder(array.values().as_ref(), options)?,
+            };
+            (Box::new(encoder) as _, array.nulls().cloned())
+        }
+        DataType::Struct(fields) => {
+            let array = array.as_struct();
+            let encoder = StructEncoder::try_new(fields, array.columns(), options)?;
+            (Box::new(encoder) as _, array.nulls().cloned())
+        }
+        DataType::Map(_, _) => {
+            let array = array.as_map();
+            let encoder = MapEncoder::try_new(array, options)?;
+            (Box::new(encoder) as _, array.nulls().cloned())
+        }
+        DataType::Dictionary(k, _) => downcast_integer! {
+            k.as_ref() => (dictionary_helper, array, options),
+            _ => unreachable!(),
+        },
+        DataType::Union(_, _) => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            make_union_encoder(array, options)?
+        }
+        DataType::Date32
+        | DataType::Date64
+        | DataType::Timestamp(_, _)
+        | DataType::Time32(_)
+        | DataType::Time64(_)
+        | DataType::Duration(_)
+        | DataType::Interval(_) => {
+            let encoder = FormatEncoder::try_new(array, options, true)?;
+            (Box::new(encoder) as _, array.nulls().cloned())
+        }
+        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+            let encoder = FormatEncoder::try_new(array, options, false)?;
+            (Box::new(encoder) as _, array.nulls().cloned())
+        }
+        d => {
+            return Err(ArrowError::JsonError(format!(
+                "data type {d:?} not supported by the json writer"
+            )))
+        }
+    })
+}
+
+/// Appends `s` to `out` as an escaped JSON string
+fn encode_string(s: &str, out: &mut Vec<u8>) {
+    // Writing to a Vec cannot fail
+    serde_json::to_writer(out, s).unwrap();
+}
+
+struct NullableEncoder<'a> {
+    encoder: Box<dyn Encoder + 'a>,
+    nulls: NullBuffer,
+}
+
+impl<'a> Encoder for NullableEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        match self.nulls.is_null(idx) {
+            true => out.extend_from_slice(b"null"),
+            false => self.encoder.encode(idx, out),
+        }
+    }
+}
+
+struct NullEncoder;
+
+impl Encoder for NullEncoder {
+    fn encode(&mut self, _idx: usize, out: &mut Vec<u8>) {
+        out.extend_from_slice(b"null")
+    }
+}
+
+struct PrimitiveEncoder<'a, N>(&'a [N]);
+
+impl<'a, N: JsonSerializable + Copy> Encoder for PrimitiveEncoder<'a, N> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        match self.0[idx].into_json_value() {
+            Some(v) => serde_json::to_writer(out, &v).unwrap(),
+            // Non-finite floats have no JSON representation
+            None => out.extend_from_slice(b"null"),
+        }
+    }
+}
+
+struct BooleanEncoder<'a>(&'a BooleanArray);
+
+impl<'a> Encoder for BooleanEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        match self.0.value(idx) {
+            true => out.extend_from_slice(b"true"),
+            false => out.extend_from_slice(b"false"),
+        }
+    }
+}
+
+struct StringEncoder<'a, O: OffsetSizeTrait>(&'a GenericStringArray<O>);
+
+impl<'a, O: OffsetSizeTrait> Encoder for StringEncoder<'a, O> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        encode_string(self.0.value(idx), out)
+    }
+}
+
+/// Encodes values using an [`ArrayFormatter`], optionally as a JSON string
+struct FormatEncoder<'a> {
+    formatter: ArrayFormatter<'a>,
+    quoted: bool,
+    scratch: String,
+}
+
+impl<'a> FormatEncoder<'a> {
+    fn try_new(
+        array: &'a dyn Array,
+        options: &EncoderOptions<'a>,
+        quoted: bool,
+    ) -> Result<Self, ArrowError> {
+        Ok(Self {
+            formatter: ArrayFormatter::try_new(array, &options.format)?,
+            quoted,
+            scratch: String::new(),
+        })
+    }
+}
+
+impl<'a> Encoder for FormatEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        self.scratch.clear();
+        // Formatting errors are written to the output by the formatter
+        let _ = write!(self.scratch, "{}", self.formatter.value(idx));
+        match self.quoted {
+            true => encode_string(&self.scratch, out),
+            false => out.extend_from_slice(self.scratch.as_bytes()),
+        }
+    }
+}
+
+struct ListEncoder<'a, O: OffsetSizeTrait> {
+    offsets: &'a OffsetBuffer<O>,
+    encoder: Box<dyn Encoder + 'a>,
+}
+
+impl<'a, O: OffsetSizeTrait> ListEncoder<'a, O> {
+    fn try_new(
+        offsets: &'a OffsetBuffer<O>,
+        values: &'a ArrayRef,
+        options: &EncoderOptions<'a>,
+    ) -> Result<Self, ArrowError> {
+        let encoder = make_encoder(values.as_ref(), options)?;
+        Ok(Self { offsets, encoder })
+    }
+}
+
+impl<'a, O: OffsetSizeTrait> Encoder for ListEncoder<'a, O> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        let start = self.offsets[idx].as_usize();
+        let end = self.offsets[idx + 1].as_usize();
+        encode_elements(self.encoder.as_mut(), start..end, out)
+    }
+}
+
+struct FixedSizeListEncoder<'a> {
+    array: &'a FixedSizeListArray,
+    encoder: Box<dyn Encoder + 'a>,
+}
+
+impl<'a> Encoder for FixedSizeListEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        let start = self.array.value_offset(idx) as usize;
+        let end = start + self.array.value_length() as usize;
+        encode_elements(self.encoder.as_mut(), start..end, out)
+    }
+}
+
+/// Encodes the values in `range` as a JSON array
+fn encode_elements(
+    encoder: &mut dyn Encoder,
+    range: std::ops::Range<usize>,
+    out: &mut Vec<u8>,
+) {
+    out.push(b'[');
+    for (i, idx) in range.enumerate() {
+        if i != 0 {
+            out.push(b',');
+        }
+        encoder.encode(idx, out);
+    }
+    out.push(b']');
+}
+
+struct FieldEncoder<'a> {
+    /// The escaped JSON key, including quotes
+    key: Vec<u8>,
+    encoder: Box<dyn Encoder + 'a>,
+    nulls: Option<NullBuffer>,
+}
+
+struct StructEncoder<'a> {
+    fields: Vec<FieldEncoder<'a>>,
+    explicit_nulls: bool,
+}
+
+impl<'a> StructEncoder<'a> {
+    fn try_new(
+        fields: &'a [FieldRef],
+        columns: &'a [ArrayRef],
+        options: &EncoderOptions<'a>,
+    ) -> Result<Self, ArrowError> {
+        let fields = fields
+            .iter()
+            .zip(columns)
+            .map(|(field, column)| {
+                let mut key = Vec::with_capacity(field.name().len() + 2);
+                encode_string(field.name(), &mut key);
+                let (encoder, nulls) = make_encoder_impl(column.as_ref(), options)?;
+                Ok(FieldEncoder {
+                    key,
+                    encoder,
+                    nulls: nulls.filter(|n| n.null_count() > 0),
+                })
+            })
+            .collect::<Result<_, ArrowError>>()?;
+
+        Ok(Self {
+            fields,
+            explicit_nulls: options.explicit_nulls,
+        })
+    }
+}
+
+impl<'a> Encoder for StructEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        out.push(b'{');
+        let mut is_first = true;
+        for field in &mut self.fields {
+            let is_null = field
+                .nulls
+                .as_ref()
+                .map(|n| n.is_null(idx))
+                .unwrap_or(false);
+            if is_null && !self.explicit_nulls {
+                continue;
+            }
+            if !is_first {
+                out.push(b',');
+            }
+            is_first = false;
+
+            out.extend_from_slice(&field.key);
+            out.push(b':');
+            match is_null {
+                true => out.extend_from_slice(b"null"),
+                false => field.encoder.encode(idx, out),
+            }
+        }
+        out.push(b'}');
+    }
+}
+
+/// Encodes a [`MapArray`] with string keys as JSON objects
+struct MapEncoder<'a> {
+    offsets: &'a OffsetBuffer<i32>,
+    keys: Box<dyn Encoder + 'a>,
+    values: Box<dyn Encoder + 'a>,
+}
+
+impl<'a> MapEncoder<'a> {
+    fn try_new(
+        array: &'a MapArray,
+        options: &EncoderOptions<'a>,
+    ) -> Result<Self, ArrowError> {
+        let keys = array.keys();
+        // Keys have to be strings to convert to json
+        let keys: Box<dyn Encoder + 'a> = match keys.data_type() {
+            DataType::Utf8 => Box::new(StringEncoder(keys.as_string::<i32>())),
+            DataType::LargeUtf8 => Box::new(StringEncoder(keys.as_string::<i64>())),
+            d => {
+                return Err(ArrowError::JsonError(format!(
+                    "data type {d:?} not supported in map keys for json writer"
+                )))
+            }
+        };
+
+        Ok(Self {
+            offsets: array.offsets(),
+            keys,
+            values: make_encoder(array.values().as_ref(), options)?,
+        })
+    }
+}
+
+impl<'a> Encoder for MapEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        let start = self.offsets[idx].as_usize();
+        let end = self.offsets[idx + 1].as_usize();
+
+        out.push(b'{');
+        for (i, entry) in (start..end).enumerate() {
+            if i != 0 {
+                out.push(b',');
+            }
+            self.keys.encode(entry, out);
+            out.push(b':');
+            self.values.encode(entry, out);
+        }
+        out.push(b'}');
+    }
+}
+
+/// Encodes a [`DictionaryArray`] by encoding the referenced values
+struct DictionaryEncoder<'a, K: ArrowDictionaryKeyType> {
+    keys: &'a [K::Native],
+    encoder: Box<dyn Encoder + 'a>,
+}
+
+impl<'a, K: ArrowDictionaryKeyType> Encoder for DictionaryEncoder<'a, K> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        self.encoder.encode(self.keys[idx].as_usize(), out)
+    }
+}
+
+fn make_dictionary_encoder<'a, K: ArrowDictionaryKeyType>(
+    array: &'a DictionaryArray<K>,
+    options: &EncoderOptions<'a>,
+) -> Result<(Box<dyn Encoder + 'a>, Option<NullBuffer>), ArrowError> {
+    let keys = array.keys();
+    let (encoder, value_nulls) = make_encoder_impl(array.values().as_ref(), options)?;
+
+    // A key referencing a null value is a null
+    let nulls = match value_nulls.filter(|n| n.null_count() > 0) {
+        Some(value_nulls) => {
+            let buffer = BooleanBuffer::collect_bool(keys.len(), |idx| {
+                keys.is_valid(idx) && value_nulls.is_valid(keys.values()[idx].as_usize())
+            });
+            Some(NullBuffer::new(buffer))
+        }
+        None => keys.nulls().cloned(),
+    };
+
+    let encoder = DictionaryEncoder::<K> {
+        keys: keys.values(),
+        encoder,
+    };
+    Ok((Box::new(encoder), nulls))
+}
+
+/// Encodes a [`UnionArray`] as the value of the selected child
+struct UnionEncoder<'a> {
+    array: &'a UnionArray,
+    /// Encoders indexed by type id
+    encoders: Vec<Option<Box<dyn Encoder + 'a>>>,
+}
+
+impl<'a> Encoder for UnionEncoder<'a> {
+    fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+        let type_id = self.array.type_id(idx);
+        let offset = self.array.value_offset(idx);
+        let encoder = self.encoders[type_id as usize].as_mut().unwrap();
+        encoder.encode(offset, out)
+    }
+}
+
+fn make_union_encoder<'a>(
+    array: &'a UnionArray,
+    options: &EncoderOptions<'a>,
+) -> Result<(Box<dyn Encoder + 'a>, Option<NullBuffer>), ArrowError> {
+    let fields = match array.data_type() {
+        DataType::Union(fields, _) => fields,
+        _ => unreachable!(),
+    };
+
+    let mut encoders: Vec<Option<Box<dyn Encoder + 'a>>> = Vec::new();
+    let mut child_nulls: Vec<Option<NullBuffer>> = Vec::new();
+    for (type_id, _) in fields.iter() {
+        let idx = type_id as usize;
+        if encoders.len() <= idx {
+            encoders.resize_with(idx + 1, || None);
+            child_nulls.resize(idx + 1, None);
+        }
+        let (encoder, nulls) = make_encoder_impl(array.child(type_id).as_ref(), options)?;
+        encoders[idx] = Some(encoder);
+        child_nulls[idx] = nulls.filter(|n| n.null_count() > 0);
+    }
+
+    // A union slot is null if the selected child value is null
+    let nulls = child_nulls.iter().any(Option::is_some).then(|| {
+        let buffer = BooleanBuffer::collect_bool(array.len(), |idx| {
+            match &child_nulls[array.type_id(idx) as usize] {
+                Some(nulls) => nulls.is_valid(array.value_offset(idx)),
+                None => true,
+            }
+        });
+        NullBuffer::new(buffer)
+    });
+
+    Ok((Box::new(UnionEncoder { array, encoders }), nulls))
+}
+
+/// Appends a pretty printed copy of the compact JSON in `json` to `out`,
+/// indenting nested values by two spaces
+pub(crate) fn pretty_print(json: &[u8], out: &mut Vec<u8>) {
+    fn newline(indent: usize, out: &mut Vec<u8>) {
+        out.push(b'\n');
+        out.extend(std::iter::repeat(b' ').take(indent * 2));
+    }
+
+    let mut indent = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut iter = json.iter().copied().peekable();
+    while let Some(b) = iter.next() {
+        if in_string {
+            out.push(b);
+            match b {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match b {
+            b'"' => {
+                in_string = true;
+                out.push(b);
+            }
+            b'{' | b'[' => {
+                out.push(b);
+                match iter.peek() {
+                    // Keep empty objects and arrays on a single line
+                    Some(b'}') | Some(b']') => out.push(iter.next().unwrap()),
+                    _ => {
+                        indent += 1;
+                        newline(indent, out);
+                    }
+                }
+            }
+            b'}' | b']' => {
+                indent -= 1;
+                newline(indent, out);
+                out.push(b);
+            }
+            b',' => {
+                out.push(b);
+                newline(indent, out);
+            }
+            b':' => out.extend_from_slice(b": "),
+            _ => out.push(b),
+        }
+    }
+}
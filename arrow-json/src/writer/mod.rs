@@ -92,6 +92,11 @@
 //! let buf = writer.into_inner();
 //! assert_eq!(r#"[{"a":1},{"a":2},{"a":3}]"#, String::from_utf8(buf).unwrap())
 //! ```
+//!
+//! Use [`WriterBuilder`] to write `null` for null values instead of omitting them,
+//! pretty print the output, or override the format of temporal values.
+
+mod encoder;
 
 use std::iter;
 use std::{fmt::Debug, io::Write};
@@ -104,6 +109,7 @@ use arrow_array::cast::*;
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_schema::*;
+use encoder::{make_struct_encoder, pretty_print, EncoderOptions};
 
 use arrow_cast::display::{ArrayFormatter, FormatOptions};
 
@@ -209,6 +215,25 @@ pub fn array_to_json_array(array: &dyn Array) -> Result<Vec<Value>, ArrowError>
                 None => Ok(Value::Null),
             })
             .collect(),
+        DataType::Date32
+        | DataType::Date64
+        | DataType::Timestamp(_, _)
+        | DataType::Time32(_)
+        | DataType::Time64(_)
+        | DataType::Duration(_) => {
+            let options = FormatOptions::default();
+            let formatter = ArrayFormatter::try_new(array, &options)?;
+            Ok((0..array.len())
+                .map(|idx| match array.is_valid(idx) {
+                    true => formatter.value(idx).to_string().into(),
+                    false => Value::Null,
+                })
+                .collect())
+        }
+        DataType::Dictionary(_, value_type) => {
+            let hydrated = arrow_cast::cast::cast(array, value_type)?;
+            array_to_json_array(&hydrated)
+        }
         t => Err(ArrowError::JsonError(format!(
             "data type {t:?} not supported"
         ))),
@@ -352,6 +377,18 @@ fn set_column_for_json_rows(
                 },
             )?;
         }
+        DataType::FixedSizeList(_, _) => {
+            let listarr = as_fixed_size_list_array(array);
+            rows.iter_mut().zip(listarr.iter()).try_for_each(
+                |(row, maybe_value)| -> Result<(), ArrowError> {
+                    if let Some(v) = maybe_value {
+                        let val = array_to_json_array(&v)?;
+                        row.insert(col_name.to_string(), Value::Array(val));
+                    }
+                    Ok(())
+                },
+            )?;
+        }
         DataType::Dictionary(_, value_type) => {
             let hydrated = arrow_cast::cast::cast(&array, value_type)
                 .expect("cannot cast dictionary to underlying values");
@@ -516,6 +553,128 @@ pub type LineDelimitedWriter<W> = Writer<W, LineDelimited>;
 /// A JSON writer which serializes [`RecordBatch`]es to JSON arrays
 pub type ArrayWriter<W> = Writer<W, JsonArray>;
 
+/// JSON writer builder.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Int32Array, RecordBatch};
+/// # use arrow_schema::{DataType, Field, Schema};
+/// use arrow_json::writer::{JsonArray, WriterBuilder};
+///
+/// let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+/// let a = Int32Array::from(vec![Some(1), None]);
+/// let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a)]).unwrap();
+///
+/// let mut writer = WriterBuilder::new()
+///     .with_explicit_nulls(true)
+///     .build::<_, JsonArray>(Vec::new());
+/// writer.write(&batch).unwrap();
+/// writer.finish().unwrap();
+///
+/// let buf = writer.into_inner();
+/// assert_eq!(r#"[{"a":1},{"a":null}]"#, String::from_utf8(buf).unwrap())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct WriterBuilder {
+    /// Whether to write `null` for null fields of objects. Defaults to `false`
+    explicit_nulls: bool,
+    /// Whether to pretty print each row. Defaults to `false`
+    pretty: bool,
+    /// Optional date format for date arrays
+    date_format: Option<String>,
+    /// Optional datetime format for datetime arrays
+    datetime_format: Option<String>,
+    /// Optional timestamp format for timestamp arrays
+    timestamp_format: Option<String>,
+    /// Optional timestamp format for timestamp with timezone arrays
+    timestamp_tz_format: Option<String>,
+    /// Optional time format for time arrays
+    time_format: Option<String>,
+}
+
+impl WriterBuilder {
+    /// Create a new builder for configuring JSON writing options.
+    ///
+    /// To convert a builder into a writer, call [`WriterBuilder::build`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether to write `null` for null fields of objects
+    ///
+    /// When `false`, the default, keys with a null value are omitted from the
+    /// output. Null elements of lists and null values of maps are always written
+    /// as `null`
+    pub fn with_explicit_nulls(mut self, explicit_nulls: bool) -> Self {
+        self.explicit_nulls = explicit_nulls;
+        self
+    }
+
+    /// Set whether to pretty print each row, indenting nested values by two spaces
+    ///
+    /// Pretty printed rows span multiple lines, and so this is best combined
+    /// with [`JsonArray`] output
+    pub fn with_pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    /// Set the JSON file's date format
+    pub fn with_date_format(mut self, format: String) -> Self {
+        self.date_format = Some(format);
+        self
+    }
+
+    /// Set the JSON file's datetime format
+    pub fn with_datetime_format(mut self, format: String) -> Self {
+        self.datetime_format = Some(format);
+        self
+    }
+
+    /// Set the JSON file's time format
+    pub fn with_time_format(mut self, format: String) -> Self {
+        self.time_format = Some(format);
+        self
+    }
+
+    /// Set the JSON file's timestamp format
+    pub fn with_timestamp_format(mut self, format: String) -> Self {
+        self.timestamp_format = Some(format);
+        self
+    }
+
+    /// Set the JSON file's timestamp with timezone format
+    pub fn with_timestamp_tz_format(mut self, tz_format: String) -> Self {
+        self.timestamp_tz_format = Some(tz_format);
+        self
+    }
+
+    /// Create a new [`Writer`] writing to `writer` in the format `F`
+    pub fn build<W: Write, F: JsonFormat>(self, writer: W) -> Writer<W, F> {
+        Writer {
+            writer,
+            started: false,
+            finished: false,
+            format: F::default(),
+            options: self,
+        }
+    }
+
+    fn encoder_options(&self) -> EncoderOptions<'_> {
+        let format = FormatOptions::new()
+            .with_date_format(self.date_format.as_deref())
+            .with_datetime_format(self.datetime_format.as_deref())
+            .with_timestamp_format(self.timestamp_format.as_deref())
+            .with_timestamp_tz_format(self.timestamp_tz_format.as_deref())
+            .with_time_format(self.time_format.as_deref());
+
+        EncoderOptions {
+            explicit_nulls: self.explicit_nulls,
+            format,
+        }
+    }
+}
+
 /// A JSON writer which serializes [`RecordBatch`]es to a stream of
 /// `u8` encoded JSON objects. See the module level documentation for
 /// detailed usage and examples. The specific format of the stream is
@@ -537,6 +696,9 @@ where
 
     /// Determines how the byte stream is formatted
     format: F,
+
+    /// Determines how values are encoded
+    options: WriterBuilder,
 }
 
 impl<W, F> Writer<W, F>
@@ -544,14 +706,9 @@ where
     W: Write,
     F: JsonFormat,
 {
-    /// Construct a new writer
+    /// Construct a new writer, see [`WriterBuilder`] to configure the output
     pub fn new(writer: W) -> Self {
-        Self {
-            writer,
-            started: false,
-            finished: false,
-            format: F::default(),
-        }
+        WriterBuilder::new().build(writer)
     }
 
     /// Write a single JSON row to the output writer
@@ -563,9 +720,12 @@ where
         }
 
         self.format.start_row(&mut self.writer, is_first_row)?;
+        let encoded = match self.options.pretty {
+            true => serde_json::to_vec_pretty(row),
+            false => serde_json::to_vec(row),
+        };
         self.writer.write_all(
-            &serde_json::to_vec(row)
-                .map_err(|error| ArrowError::JsonError(error.to_string()))?,
+            &encoded.map_err(|error| ArrowError::JsonError(error.to_string()))?,
         )?;
         self.format.end_row(&mut self.writer)?;
         Ok(())
@@ -573,16 +733,52 @@ where
 
     /// Convert the `RecordBatch` into JSON rows, and write them to the output
     pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
-        for row in record_batches_to_json_rows(&[batch])? {
-            self.write_row(&Value::Object(row))?;
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        let schema = batch.schema();
+        let options = self.options.encoder_options();
+        let mut encoder =
+            make_struct_encoder(schema.fields(), batch.columns(), &options)?;
+
+        // Buffer rows to avoid many small writes to the underlying writer
+        let mut buffer = Vec::with_capacity(16 * 1024);
+        let mut row = Vec::new();
+
+        let mut is_first_row = !self.started;
+        if !self.started {
+            self.format.start_stream(&mut buffer)?;
+            self.started = true;
         }
+
+        for idx in 0..batch.num_rows() {
+            self.format.start_row(&mut buffer, is_first_row)?;
+            is_first_row = false;
+
+            match self.options.pretty {
+                true => {
+                    row.clear();
+                    encoder.encode(idx, &mut row);
+                    pretty_print(&row, &mut buffer);
+                }
+                false => encoder.encode(idx, &mut buffer),
+            }
+            self.format.end_row(&mut buffer)?;
+
+            if buffer.len() > 8 * 1024 {
+                self.writer.write_all(&buffer)?;
+                buffer.clear();
+            }
+        }
+        self.writer.write_all(&buffer)?;
         Ok(())
     }
 
     /// Convert the [`RecordBatch`] into JSON rows, and write them to the output
     pub fn write_batches(&mut self, batches: &[&RecordBatch]) -> Result<(), ArrowError> {
-        for row in record_batches_to_json_rows(batches)? {
-            self.write_row(&Value::Object(row))?;
+        for batch in batches {
+            self.write(batch)?;
         }
         Ok(())
     }
@@ -626,8 +822,8 @@ mod tests {
 
     use serde_json::json;
 
-    use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
-    use arrow_buffer::{Buffer, ToByteSlice};
+    use arrow_array::builder::{Int32Builder, ListBuilder, MapBuilder, StringBuilder};
+    use arrow_buffer::{Buffer, OffsetBuffer, ToByteSlice};
     use arrow_data::ArrayData;
 
     use crate::reader::*;
@@ -1354,10 +1550,6 @@ mod tests {
             writer.write_batches(&[&batch]).unwrap();
         }
 
-        // NOTE: The last value should technically be {"list": [null]} but it appears
-        // that implementations differ on the treatment of a null struct.
-        // It would be more accurate to return a null struct, so this can be done
-        // as a follow up.
         assert_json_eq(
             &buf,
             r#"{"list":[{"ints":1}]}
@@ -1365,7 +1557,7 @@ mod tests {
 {"list":[]}
 {}
 {"list":[{}]}
-{"list":[{}]}
+{"list":[null]}
 "#,
         );
     }
@@ -1420,7 +1612,7 @@ mod tests {
         assert_json_eq(
             &buf,
             r#"{"map":{"foo":10}}
-{"map":null}
+{}
 {"map":{}}
 {"map":{"bar":20,"baz":30,"qux":40}}
 {"map":{"quux":50}}
@@ -1576,4 +1768,217 @@ mod tests {
 
         assert_eq!(array_to_json_array(&map_array).unwrap(), expected_json);
     }
+
+    fn write_to_string<F: JsonFormat>(
+        builder: WriterBuilder,
+        batch: &RecordBatch,
+    ) -> String {
+        let mut writer = builder.build::<_, F>(Vec::new());
+        writer.write(batch).unwrap();
+        writer.finish().unwrap();
+        String::from_utf8(writer.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn write_fixed_size_list() {
+        let data = vec![
+            Some(vec![Some(0), Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3), None, Some(5)]),
+        ];
+        let list = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(data, 3);
+        let batch = RecordBatch::try_from_iter([("l", Arc::new(list) as _)]).unwrap();
+
+        let buf = write_to_string::<LineDelimited>(WriterBuilder::new(), &batch);
+        assert_json_eq(
+            buf.as_bytes(),
+            r#"{"l":[0,1,2]}
+{}
+{"l":[3,null,5]}
+"#,
+        );
+    }
+
+    #[test]
+    fn write_nested_durations() {
+        let values = DurationMillisecondArray::from(vec![Some(1500), None, Some(20)]);
+        let field = Arc::new(Field::new("item", values.data_type().clone(), true));
+        let offsets = OffsetBuffer::new(vec![0, 2, 3].into());
+        let list = ListArray::new(field, offsets, Arc::new(values), None);
+        let batch = RecordBatch::try_from_iter([("d", Arc::new(list) as _)]).unwrap();
+
+        let buf = write_to_string::<LineDelimited>(WriterBuilder::new(), &batch);
+        assert_json_eq(
+            buf.as_bytes(),
+            r#"{"d":["PT1.500S",null]}
+{"d":["PT0.020S"]}
+"#,
+        );
+    }
+
+    #[test]
+    fn write_union() {
+        let ints = Int32Array::from(vec![Some(1), None]);
+        let strings = StringArray::from(vec!["a"]);
+        let type_ids = Buffer::from_slice_ref([0_i8, 1, 0]);
+        let offsets = Buffer::from_slice_ref([0_i32, 0, 1]);
+        let union = UnionArray::try_new(
+            &[0, 1],
+            type_ids,
+            Some(offsets),
+            vec![
+                (
+                    Field::new("int", DataType::Int32, true),
+                    Arc::new(ints) as _,
+                ),
+                (
+                    Field::new("str", DataType::Utf8, false),
+                    Arc::new(strings) as _,
+                ),
+            ],
+        )
+        .unwrap();
+        let batch = RecordBatch::try_from_iter([("u", Arc::new(union) as _)]).unwrap();
+
+        let buf = write_to_string::<LineDelimited>(WriterBuilder::new(), &batch);
+        assert_eq!(buf, "{\"u\":1}\n{\"u\":\"a\"}\n{}\n");
+    }
+
+    #[test]
+    fn write_dictionary_of_list() {
+        let data = vec![Some(vec![Some(1), Some(2)]), None];
+        let values = ListArray::from_iter_primitive::<Int32Type, _, _>(data);
+        let keys = Int8Array::from(vec![Some(1), Some(0), None, Some(0)]);
+        let dict = DictionaryArray::new(keys, Arc::new(values));
+        let batch = RecordBatch::try_from_iter([("d", Arc::new(dict) as _)]).unwrap();
+
+        let builder = WriterBuilder::new().with_explicit_nulls(true);
+        let buf = write_to_string::<LineDelimited>(builder, &batch);
+        assert_eq!(
+            buf,
+            "{\"d\":null}\n{\"d\":[1,2]}\n{\"d\":null}\n{\"d\":[1,2]}\n"
+        );
+    }
+
+    #[test]
+    fn write_map_with_nested_values() {
+        let mut builder = MapBuilder::new(
+            None,
+            StringBuilder::new(),
+            ListBuilder::new(Int32Builder::new()),
+        );
+        builder.keys().append_value("a");
+        builder.values().append_value([Some(1), None]);
+        builder.keys().append_value("b\"c");
+        builder.values().append_null();
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        let map = builder.finish();
+        let batch = RecordBatch::try_from_iter([("m", Arc::new(map) as _)]).unwrap();
+
+        let builder = WriterBuilder::new().with_explicit_nulls(true);
+        let buf = write_to_string::<JsonArray>(builder, &batch);
+        assert_eq!(buf, r#"[{"m":{"a":[1,null],"b\"c":null}},{"m":null}]"#);
+    }
+
+    #[test]
+    fn write_explicit_nulls() {
+        let ints = Int32Array::from(vec![Some(1), None]);
+        let nulls = NullArray::new(2);
+        let structs = StructArray::new(
+            vec![Field::new("x", DataType::Utf8, true)].into(),
+            vec![Arc::new(StringArray::from(vec![None, Some("y")]))],
+            None,
+        );
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(ints) as ArrayRef),
+            ("b", Arc::new(nulls) as _),
+            ("c", Arc::new(structs) as _),
+        ])
+        .unwrap();
+
+        let buf = write_to_string::<JsonArray>(WriterBuilder::new(), &batch);
+        assert_eq!(buf, r#"[{"a":1,"c":{}},{"c":{"x":"y"}}]"#);
+
+        let builder = WriterBuilder::new().with_explicit_nulls(true);
+        let buf = write_to_string::<JsonArray>(builder, &batch);
+        assert_eq!(
+            buf,
+            r#"[{"a":1,"b":null,"c":{"x":null}},{"a":null,"b":null,"c":{"x":"y"}}]"#
+        );
+    }
+
+    #[test]
+    fn write_pretty() {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![]),
+        ]);
+        let strings = StringArray::from(vec!["{a: [1]}", "\\"]);
+        let batch = RecordBatch::try_from_iter([
+            ("l", Arc::new(list) as ArrayRef),
+            ("s", Arc::new(strings) as _),
+        ])
+        .unwrap();
+
+        let builder = WriterBuilder::new().with_pretty(true);
+        let buf = write_to_string::<JsonArray>(builder, &batch);
+        let expected = r#"[{
+  "l": [
+    1,
+    2
+  ],
+  "s": "{a: [1]}"
+},{
+  "l": [],
+  "s": "\\"
+}]"#;
+        assert_eq!(buf, expected);
+
+        let parsed: Value = serde_json::from_str(&buf).unwrap();
+        assert_eq!(
+            parsed,
+            json!([{"l": [1, 2], "s": "{a: [1]}"}, {"l": [], "s": "\\"}])
+        );
+    }
+
+    #[test]
+    fn write_custom_timestamp_format() {
+        let ts = TimestampSecondArray::from(vec![Some(1_000_000), None]);
+        let date = Date32Array::from(vec![Some(19_000), Some(0)]);
+        let batch = RecordBatch::try_from_iter([
+            ("ts", Arc::new(ts) as ArrayRef),
+            ("date", Arc::new(date) as _),
+        ])
+        .unwrap();
+
+        let builder = WriterBuilder::new()
+            .with_timestamp_format("%Y/%m/%d %H:%M".to_string())
+            .with_date_format("%d.%m.%Y".to_string());
+        let buf = write_to_string::<LineDelimited>(builder, &batch);
+        assert_eq!(
+            buf,
+            "{\"ts\":\"1970/01/12 13:46\",\"date\":\"08.01.2022\"}\n{\"date\":\"01.01.1970\"}\n"
+        );
+    }
+
+    #[test]
+    fn write_decimal() {
+        let decimal = Decimal128Array::from(vec![Some(12345), None, Some(-5)])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let batch = RecordBatch::try_from_iter([("d", Arc::new(decimal) as _)]).unwrap();
+
+        let buf = write_to_string::<JsonArray>(WriterBuilder::new(), &batch);
+        assert_eq!(buf, r#"[{"d":123.45},{},{"d":-0.05}]"#);
+    }
+
+    #[test]
+    fn test_array_to_json_array_for_durations() {
+        let array: ArrayRef = Arc::new(DurationSecondArray::from(vec![Some(120), None]));
+        assert_eq!(
+            array_to_json_array(&array).unwrap(),
+            vec![json!("PT120S"), Value::Null]
+        );
+    }
 }
@@ -0,0 +1,198 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Read ORC files asynchronously into Arrow [`RecordBatch`]
+//!
+//! ```
+//! # use arrow_orc::reader::ArrowReaderBuilder;
+//! # use futures::TryStreamExt;
+//! # async fn read(file: tokio::fs::File) -> Result<(), arrow_schema::ArrowError> {
+//! let stream = ArrowReaderBuilder::try_new_async(file)
+//!     .await?
+//!     .with_batch_size(8192)
+//!     .build_async()?;
+//!
+//! let batches: Vec<_> = stream.try_collect().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::reader::metadata::{decode_tail, tail_length, DEFAULT_TAIL_SIZE};
+use crate::reader::{ArrowReaderBuilder, FileMetadata};
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, SchemaRef};
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use futures::{FutureExt, Stream, StreamExt};
+use std::io::SeekFrom;
+use std::ops::Range;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+
+#[cfg(feature = "object_store")]
+mod store;
+
+#[cfg(feature = "object_store")]
+pub use store::OrcObjectReader;
+
+/// The asynchronous interface used by [`ArrowStreamReader`] to read ORC files
+pub trait AsyncFileReader: Send {
+    /// Returns the length of the file in bytes
+    fn len(&mut self) -> BoxFuture<'_, Result<u64, ArrowError>>;
+
+    /// Returns the bytes of the file within `range`
+    fn get_bytes(
+        &mut self,
+        range: Range<u64>,
+    ) -> BoxFuture<'_, Result<Bytes, ArrowError>>;
+}
+
+impl<T: AsyncRead + AsyncSeek + Unpin + Send> AsyncFileReader for T {
+    fn len(&mut self) -> BoxFuture<'_, Result<u64, ArrowError>> {
+        async move { Ok(self.seek(SeekFrom::End(0)).await?) }.boxed()
+    }
+
+    fn get_bytes(
+        &mut self,
+        range: Range<u64>,
+    ) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+        async move {
+            self.seek(SeekFrom::Start(range.start)).await?;
+            let mut buf = vec![0; (range.end - range.start) as usize];
+            self.read_exact(&mut buf).await?;
+            Ok(buf.into())
+        }
+        .boxed()
+    }
+}
+
+/// Reads the [`FileMetadata`] from `input`
+pub async fn read_metadata_async<T: AsyncFileReader>(
+    input: &mut T,
+) -> Result<FileMetadata, ArrowError> {
+    let file_len = input.len().await?;
+    let len = file_len.min(DEFAULT_TAIL_SIZE);
+    let mut tail = input.get_bytes(file_len - len..file_len).await?;
+    let tail_len = tail_length(&tail, file_len)?;
+    if tail_len > len {
+        tail = input.get_bytes(file_len - tail_len..file_len).await?;
+    }
+    decode_tail(tail)
+}
+
+impl<T: AsyncFileReader + 'static> ArrowReaderBuilder<T> {
+    /// Create a new [`ArrowReaderBuilder`], asynchronously reading the [`FileMetadata`]
+    /// from `input`
+    pub async fn try_new_async(mut input: T) -> Result<Self, ArrowError> {
+        let metadata = read_metadata_async(&mut input).await?;
+        Ok(Self::new(input, metadata))
+    }
+
+    /// Build an [`ArrowStreamReader`] with the provided configuration
+    pub fn build_async(self) -> Result<ArrowStreamReader, ArrowError> {
+        let (input, plan) = self.into_plan()?;
+        let schema = plan.schema().clone();
+
+        let inner = futures::stream::try_unfold(
+            (input, plan),
+            |(mut input, mut plan)| async move {
+                loop {
+                    if let Some(batch) = plan.next_batch() {
+                        return Ok(Some((batch, (input, plan))));
+                    }
+                    let (idx, range) = match plan.next_stripe() {
+                        Some(next) => next,
+                        None => return Ok(None),
+                    };
+                    let data = input.get_bytes(range).await?;
+                    plan.decode_stripe(idx, data)?;
+                }
+            },
+        )
+        .boxed();
+
+        Ok(ArrowStreamReader { schema, inner })
+    }
+}
+
+/// A [`Stream`] of [`RecordBatch`] read asynchronously from an ORC file
+///
+/// Created by [`ArrowReaderBuilder::build_async`]
+pub struct ArrowStreamReader {
+    schema: SchemaRef,
+    inner: BoxStream<'static, Result<RecordBatch, ArrowError>>,
+}
+
+impl std::fmt::Debug for ArrowStreamReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArrowStreamReader")
+            .field("schema", &self.schema)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ArrowStreamReader {
+    /// Returns the schema of the [`RecordBatch`] returned by this stream
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+}
+
+impl Stream for ArrowStreamReader {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::CompressionKind;
+    use crate::reader::ArrowReaderBuilder;
+    use crate::test_util::write_test_file;
+    use futures::TryStreamExt;
+    use std::io::Cursor;
+
+    #[tokio::test]
+    async fn test_async_reader() {
+        let file = write_test_file(CompressionKind::Zlib);
+        let expected: Vec<_> = ArrowReaderBuilder::try_new(file.clone())
+            .unwrap()
+            .with_projection(vec![2, 0])
+            .build()
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let input = Cursor::new(file.to_vec());
+        let builder = ArrowReaderBuilder::try_new_async(input).await.unwrap();
+        assert_eq!(builder.metadata().number_of_rows(), 5);
+
+        let stream = builder.with_projection(vec![2, 0]).build_async().unwrap();
+        assert_eq!(stream.schema().field(0).name(), "tags");
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        assert_eq!(batches, expected);
+        assert_eq!(batches.len(), 2);
+    }
+}
@@ -0,0 +1,87 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::async_reader::AsyncFileReader;
+use arrow_schema::ArrowError;
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use futures::{FutureExt, TryFutureExt};
+use object_store::{ObjectMeta, ObjectStore};
+use std::ops::Range;
+use std::sync::Arc;
+
+/// Implements [`AsyncFileReader`] for an ORC file in object storage
+#[derive(Clone, Debug)]
+pub struct OrcObjectReader {
+    store: Arc<dyn ObjectStore>,
+    meta: ObjectMeta,
+}
+
+impl OrcObjectReader {
+    /// Creates a new [`OrcObjectReader`] for the provided [`ObjectStore`] and [`ObjectMeta`]
+    ///
+    /// [`ObjectMeta`] can be obtained using [`ObjectStore::list`] or [`ObjectStore::head`]
+    pub fn new(store: Arc<dyn ObjectStore>, meta: ObjectMeta) -> Self {
+        Self { store, meta }
+    }
+}
+
+impl AsyncFileReader for OrcObjectReader {
+    fn len(&mut self) -> BoxFuture<'_, Result<u64, ArrowError>> {
+        let len = self.meta.size as u64;
+        async move { Ok(len) }.boxed()
+    }
+
+    fn get_bytes(
+        &mut self,
+        range: Range<u64>,
+    ) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+        let range = range.start as usize..range.end as usize;
+        self.store
+            .get_range(&self.meta.location, range)
+            .map_err(|e| ArrowError::ExternalError(Box::new(e)))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::CompressionKind;
+    use crate::reader::ArrowReaderBuilder;
+    use crate::test_util::write_test_file;
+    use futures::TryStreamExt;
+    use object_store::memory::InMemory;
+    use object_store::path::Path;
+
+    #[tokio::test]
+    async fn test_object_store() {
+        let store = Arc::new(InMemory::new()) as Arc<dyn ObjectStore>;
+        let path = Path::from("test.orc");
+        store
+            .put(&path, write_test_file(CompressionKind::None))
+            .await
+            .unwrap();
+        let meta = store.head(&path).await.unwrap();
+
+        let reader = OrcObjectReader::new(store, meta);
+        let builder = ArrowReaderBuilder::try_new_async(reader).await.unwrap();
+        let batches: Vec<_> = builder.build_async().unwrap().try_collect().await.unwrap();
+        let rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(rows, 5);
+    }
+}
@@ -0,0 +1,174 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::proto::CompressionKind;
+use arrow_schema::ArrowError;
+use bytes::Bytes;
+use std::io::Read;
+
+/// The compression of the streams, footer and metadata of an ORC file
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct Compression {
+    codec: Codec,
+    /// The maximum size of a decompressed chunk
+    block_size: usize,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Codec {
+    Zlib,
+    Snappy,
+    Lz4,
+    Zstd,
+}
+
+impl Compression {
+    /// Returns the [`Compression`] for `kind`, or `None` if uncompressed
+    pub(crate) fn try_new(
+        kind: CompressionKind,
+        block_size: Option<u64>,
+    ) -> Result<Option<Self>, ArrowError> {
+        let codec = match kind {
+            CompressionKind::None => return Ok(None),
+            CompressionKind::Zlib => Codec::Zlib,
+            CompressionKind::Snappy => Codec::Snappy,
+            CompressionKind::Lz4 => Codec::Lz4,
+            CompressionKind::Zstd => Codec::Zstd,
+            CompressionKind::Lzo => {
+                return Err(ArrowError::NotYetImplemented(
+                    "LZO compressed ORC files are not supported".to_string(),
+                ))
+            }
+        };
+        Ok(Some(Self {
+            codec,
+            block_size: block_size.unwrap_or(256 * 1024) as usize,
+        }))
+    }
+
+    /// Decompress a sequence of compressed chunks, each prefixed with a 3 byte header
+    fn decompress(&self, mut input: &[u8]) -> Result<Vec<u8>, ArrowError> {
+        let mut out = Vec::with_capacity(input.len());
+        while !input.is_empty() {
+            if input.len() < 3 {
+                return Err(ArrowError::ParseError(
+                    "Truncated ORC compression chunk header".to_string(),
+                ));
+            }
+            let header =
+                input[0] as usize | (input[1] as usize) << 8 | (input[2] as usize) << 16;
+            let is_original = header & 1 == 1;
+            let len = header >> 1;
+            input = &input[3..];
+            if input.len() < len {
+                return Err(ArrowError::ParseError(
+                    "Truncated ORC compression chunk".to_string(),
+                ));
+            }
+            let (chunk, remaining) = input.split_at(len);
+            input = remaining;
+
+            match is_original {
+                true => out.extend_from_slice(chunk),
+                false => self.decompress_chunk(chunk, &mut out)?,
+            }
+        }
+        Ok(out)
+    }
+
+    fn decompress_chunk(
+        &self,
+        chunk: &[u8],
+        out: &mut Vec<u8>,
+    ) -> Result<(), ArrowError> {
+        match self.codec {
+            Codec::Zlib => {
+                flate2::read::DeflateDecoder::new(chunk).read_to_end(out)?;
+            }
+            Codec::Snappy => {
+                let decompressed = snap::raw::Decoder::new()
+                    .decompress_vec(chunk)
+                    .map_err(|e| ArrowError::ExternalError(Box::new(e)))?;
+                out.extend_from_slice(&decompressed);
+            }
+            Codec::Lz4 => {
+                let start = out.len();
+                out.resize(start + self.block_size, 0);
+                let len = lz4::block::decompress_to_buffer(
+                    chunk,
+                    Some(self.block_size as i32),
+                    &mut out[start..],
+                )?;
+                out.truncate(start + len);
+            }
+            Codec::Zstd => {
+                zstd::stream::copy_decode(chunk, out)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Decompress `data` with `compression`, if any
+pub(crate) fn decompress(
+    compression: Option<&Compression>,
+    data: Bytes,
+) -> Result<Bytes, ArrowError> {
+    match compression {
+        Some(compression) => compression.decompress(&data).map(Bytes::from),
+        None => Ok(data),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_decompress_chunks() {
+        let compression = Compression::try_new(CompressionKind::Zlib, Some(1024))
+            .unwrap()
+            .unwrap();
+
+        let mut encoder = flate2::write::DeflateEncoder::new(
+            Vec::new(),
+            flate2::Compression::default(),
+        );
+        encoder.write_all(b"hello hello hello").unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut data = vec![];
+        // A compressed chunk
+        let header = compressed.len() << 1;
+        data.extend_from_slice(&header.to_le_bytes()[..3]);
+        data.extend_from_slice(&compressed);
+        // An original chunk
+        let header = (6 << 1) | 1;
+        data.extend_from_slice(&(header as u32).to_le_bytes()[..3]);
+        data.extend_from_slice(b" world");
+
+        let out = decompress(Some(&compression), data.into()).unwrap();
+        assert_eq!(out.as_ref(), b"hello hello hello world");
+
+        let err = decompress(Some(&compression), vec![0x10, 0, 0].into()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Truncated ORC compression chunk"
+        );
+    }
+}
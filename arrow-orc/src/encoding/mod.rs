@@ -0,0 +1,226 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Decoders for the [run length encodings] of ORC streams
+//!
+//! [run length encodings]: https://orc.apache.org/specification/ORCv1/#run-length-encoding
+
+use arrow_buffer::{BooleanBuffer, BooleanBufferBuilder};
+use arrow_schema::ArrowError;
+
+mod rle_v1;
+mod rle_v2;
+
+/// The version of the integer run length encoding used by a column
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum RleVersion {
+    V1,
+    V2,
+}
+
+/// Decode `n` integers from `data`, that are zigzag encoded if `signed`
+pub(crate) fn decode_integers(
+    data: &[u8],
+    n: usize,
+    signed: bool,
+    version: RleVersion,
+) -> Result<Vec<i64>, ArrowError> {
+    let mut reader = ByteReader::new(data);
+    let mut out = Vec::with_capacity(n);
+    while out.len() < n {
+        match version {
+            RleVersion::V1 => rle_v1::decode_run(&mut reader, signed, &mut out)?,
+            RleVersion::V2 => rle_v2::decode_run(&mut reader, signed, &mut out)?,
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+/// Decode `n` bytes from the byte run length encoded `data`
+pub(crate) fn decode_bytes(data: &[u8], n: usize) -> Result<Vec<u8>, ArrowError> {
+    let mut reader = ByteReader::new(data);
+    let mut out = Vec::with_capacity(n);
+    while out.len() < n {
+        let header = reader.read_u8()? as i8;
+        match header {
+            0.. => {
+                let value = reader.read_u8()?;
+                out.resize(out.len() + header as usize + 3, value);
+            }
+            _ => {
+                let len = -(header as isize) as usize;
+                out.extend_from_slice(reader.read_slice(len)?);
+            }
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+/// Decode `n` booleans from `data`, encoded as byte run length encoded bytes with the
+/// most significant bit first
+pub(crate) fn decode_booleans(
+    data: &[u8],
+    n: usize,
+) -> Result<BooleanBuffer, ArrowError> {
+    let bytes = decode_bytes(data, (n + 7) / 8)?;
+    let mut builder = BooleanBufferBuilder::new(n);
+    for idx in 0..n {
+        builder.append(bytes[idx / 8] & (0x80 >> (idx % 8)) != 0);
+    }
+    Ok(builder.finish())
+}
+
+/// Decodes a zigzag encoded integer
+#[inline]
+pub(crate) fn zigzag(v: u64) -> i64 {
+    (v >> 1) as i64 ^ -((v & 1) as i64)
+}
+
+/// A cursor over a byte slice
+#[derive(Debug)]
+pub(crate) struct ByteReader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    pub(crate) fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+
+    pub(crate) fn read_u8(&mut self) -> Result<u8, ArrowError> {
+        Ok(self.read_slice(1)?[0])
+    }
+
+    pub(crate) fn read_slice(&mut self, len: usize) -> Result<&'a [u8], ArrowError> {
+        if self.buf.len() < len {
+            return Err(ArrowError::ParseError(
+                "Unexpected end of ORC stream".to_string(),
+            ));
+        }
+        let (ret, remaining) = self.buf.split_at(len);
+        self.buf = remaining;
+        Ok(ret)
+    }
+
+    /// Reads a big-endian unsigned integer of `len` bytes
+    pub(crate) fn read_be(&mut self, len: usize) -> Result<u64, ArrowError> {
+        let bytes = self.read_slice(len)?;
+        Ok(bytes.iter().fold(0, |acc, b| acc << 8 | *b as u64))
+    }
+
+    /// Reads a base 128 varint
+    pub(crate) fn read_varint(&mut self) -> Result<u64, ArrowError> {
+        let mut value = 0_u64;
+        let mut shift = 0;
+        loop {
+            let b = self.read_u8()?;
+            if shift < 64 {
+                value |= ((b & 0x7F) as u64) << shift;
+            }
+            if b & 0x80 == 0 {
+                return Ok(value);
+            }
+            shift += 7;
+        }
+    }
+
+    /// Reads a base 128 varint of arbitrary length as an `i128`, for decimals
+    pub(crate) fn read_varint_i128(&mut self) -> Result<i128, ArrowError> {
+        let mut value = 0_u128;
+        let mut shift = 0;
+        loop {
+            let b = self.read_u8()?;
+            if shift < 128 {
+                value |= ((b & 0x7F) as u128) << shift;
+            }
+            if b & 0x80 == 0 {
+                return Ok((value >> 1) as i128 ^ -((value & 1) as i128));
+            }
+            shift += 7;
+        }
+    }
+
+    /// Reads a varint, that is zigzag encoded if `signed`
+    pub(crate) fn read_vint(&mut self, signed: bool) -> Result<i64, ArrowError> {
+        let v = self.read_varint()?;
+        Ok(match signed {
+            true => zigzag(v),
+            false => v as i64,
+        })
+    }
+}
+
+/// Reads `n` big-endian bit-packed values of `width` bits from `reader`
+pub(crate) fn read_bit_packed(
+    reader: &mut ByteReader<'_>,
+    n: usize,
+    width: usize,
+    out: &mut Vec<u64>,
+) -> Result<(), ArrowError> {
+    let bytes = reader.read_slice((n * width + 7) / 8)?;
+    let mut bit = 0;
+    for _ in 0..n {
+        let mut value = 0_u64;
+        for _ in 0..width {
+            let b = (bytes[bit / 8] >> (7 - bit % 8)) & 1;
+            value = value << 1 | b as u64;
+            bit += 1;
+        }
+        out.push(value);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_rle() {
+        // Examples from the specification
+        let out = decode_bytes(&[0x61, 0x00], 100).unwrap();
+        assert_eq!(out, vec![0; 100]);
+
+        let out = decode_bytes(&[0xfe, 0x44, 0x45], 2).unwrap();
+        assert_eq!(out, vec![0x44, 0x45]);
+    }
+
+    #[test]
+    fn test_boolean_rle() {
+        // Examples from the specification
+        let out = decode_booleans(&[0xff, 0x80], 8).unwrap();
+        assert_eq!(
+            out.iter().collect::<Vec<_>>(),
+            vec![true, false, false, false, false, false, false, false]
+        );
+
+        let out = decode_booleans(&[0x61, 0x00, 0xfe, 0xff, 0x00], 100 * 8 + 10).unwrap();
+        assert_eq!(out.count_set_bits(), 8);
+        assert!(out.iter().skip(800).take(8).all(|x| x));
+    }
+
+    #[test]
+    fn test_bit_packed() {
+        let mut out = vec![];
+        let mut reader = ByteReader::new(&[0b1010_0111, 0b1100_0000]);
+        read_bit_packed(&mut reader, 3, 3, &mut out).unwrap();
+        assert_eq!(out, vec![0b101, 0b001, 0b111]);
+        assert!(reader.buf.is_empty());
+    }
+}
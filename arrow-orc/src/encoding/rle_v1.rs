@@ -0,0 +1,69 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Integer run length encoding, version 1
+
+use super::ByteReader;
+use arrow_schema::ArrowError;
+
+/// Decodes the next run of integers from `reader` into `out`
+pub(super) fn decode_run(
+    reader: &mut ByteReader<'_>,
+    signed: bool,
+    out: &mut Vec<i64>,
+) -> Result<(), ArrowError> {
+    let header = reader.read_u8()? as i8;
+    match header {
+        0.. => {
+            let len = header as usize + 3;
+            let delta = reader.read_u8()? as i8 as i64;
+            let base = reader.read_vint(signed)?;
+            out.extend((0..len as i64).map(|i| base.wrapping_add(i * delta)));
+        }
+        _ => {
+            let len = -(header as isize) as usize;
+            for _ in 0..len {
+                out.push(reader.read_vint(signed)?);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoding::{decode_integers, RleVersion};
+
+    #[test]
+    fn test_rle_v1() {
+        // Examples from the specification
+        let out =
+            decode_integers(&[0x61, 0x00, 0x07], 100, false, RleVersion::V1).unwrap();
+        assert_eq!(out, vec![7; 100]);
+
+        let out =
+            decode_integers(&[0x61, 0xff, 0x64], 100, false, RleVersion::V1).unwrap();
+        assert_eq!(out, (1..=100).rev().collect::<Vec<_>>());
+
+        let data = [0xfb, 0x02, 0x03, 0x06, 0x07, 0xb];
+        let out = decode_integers(&data, 5, false, RleVersion::V1).unwrap();
+        assert_eq!(out, vec![2, 3, 6, 7, 11]);
+
+        let out = decode_integers(&[0xfe, 0x03, 0x04], 2, true, RleVersion::V1).unwrap();
+        assert_eq!(out, vec![-2, 2]);
+    }
+}
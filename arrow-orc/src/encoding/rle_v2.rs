@@ -0,0 +1,215 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Integer run length encoding, version 2
+
+use super::{read_bit_packed, zigzag, ByteReader};
+use arrow_schema::ArrowError;
+
+/// Decodes the 5-bit encoded bit width used by DIRECT, PATCHED_BASE and DELTA runs
+fn decode_width(code: u8) -> usize {
+    match code {
+        0..=23 => code as usize + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+/// Rounds `width` up to the closest bit width supported by [`decode_width`]
+fn closest_fixed_bits(width: usize) -> usize {
+    match width {
+        0..=24 => width.max(1),
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+/// Reads the 9-bit run length spanning the low bit of `header` and the next byte
+fn read_length(header: u8, reader: &mut ByteReader<'_>) -> Result<usize, ArrowError> {
+    let low = reader.read_u8()?;
+    Ok((((header & 1) as usize) << 8 | low as usize) + 1)
+}
+
+/// Decodes the next run of integers from `reader` into `out`
+pub(super) fn decode_run(
+    reader: &mut ByteReader<'_>,
+    signed: bool,
+    out: &mut Vec<i64>,
+) -> Result<(), ArrowError> {
+    let header = reader.read_u8()?;
+    let decode = |v: u64| match signed {
+        true => zigzag(v),
+        false => v as i64,
+    };
+
+    match header >> 6 {
+        // SHORT_REPEAT
+        0 => {
+            let width = ((header >> 3) & 0x07) as usize + 1;
+            let count = (header & 0x07) as usize + 3;
+            let value = decode(reader.read_be(width)?);
+            out.resize(out.len() + count, value);
+        }
+        // DIRECT
+        1 => {
+            let width = decode_width((header >> 1) & 0x1F);
+            let len = read_length(header, reader)?;
+            let mut values = Vec::with_capacity(len);
+            read_bit_packed(reader, len, width, &mut values)?;
+            out.extend(values.into_iter().map(decode));
+        }
+        // PATCHED_BASE
+        2 => {
+            let width = decode_width((header >> 1) & 0x1F);
+            let len = read_length(header, reader)?;
+
+            let b = reader.read_u8()?;
+            let base_width = ((b >> 5) & 0x07) as usize + 1;
+            let patch_width = decode_width(b & 0x1F);
+
+            let b = reader.read_u8()?;
+            let gap_width = ((b >> 5) & 0x07) as usize + 1;
+            let patch_len = (b & 0x1F) as usize;
+
+            // The base is stored with its most significant bit as the sign
+            let base = reader.read_be(base_width)?;
+            let sign_bit = 1 << (base_width * 8 - 1);
+            let base = match base & sign_bit {
+                0 => base as i64,
+                _ => -((base & !sign_bit) as i64),
+            };
+
+            let mut values = Vec::with_capacity(len);
+            read_bit_packed(reader, len, width, &mut values)?;
+
+            let mut patches = Vec::with_capacity(patch_len);
+            let entry_width = closest_fixed_bits(gap_width + patch_width);
+            read_bit_packed(reader, patch_len, entry_width, &mut patches)?;
+
+            let patch_mask = match patch_width {
+                64 => u64::MAX,
+                _ => (1 << patch_width) - 1,
+            };
+            let mut idx = 0;
+            for entry in patches {
+                idx += (entry >> patch_width) as usize;
+                let patch = entry & patch_mask;
+                if patch != 0 {
+                    let value = values.get_mut(idx).ok_or_else(|| {
+                        ArrowError::ParseError("Invalid ORC patch gap".to_string())
+                    })?;
+                    *value |= patch << width;
+                }
+            }
+            out.extend(values.into_iter().map(|v| base.wrapping_add(v as i64)));
+        }
+        // DELTA
+        _ => {
+            let width = match (header >> 1) & 0x1F {
+                0 => 0,
+                code => decode_width(code),
+            };
+            let len = read_length(header, reader)?;
+            let base = reader.read_vint(signed)?;
+            let delta_base = reader.read_vint(true)?;
+
+            out.push(base);
+            if len == 1 {
+                return Ok(());
+            }
+            match width {
+                0 => {
+                    out.extend((1..len as i64).map(|i| base.wrapping_add(i * delta_base)))
+                }
+                _ => {
+                    let mut prev = base.wrapping_add(delta_base);
+                    out.push(prev);
+
+                    let mut deltas = Vec::with_capacity(len - 2);
+                    read_bit_packed(reader, len - 2, width, &mut deltas)?;
+                    for delta in deltas {
+                        prev = match delta_base < 0 {
+                            true => prev.wrapping_sub(delta as i64),
+                            false => prev.wrapping_add(delta as i64),
+                        };
+                        out.push(prev);
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoding::{decode_integers, RleVersion};
+
+    // Examples from the specification
+
+    #[test]
+    fn test_short_repeat() {
+        let out = decode_integers(&[0x0a, 0x27, 0x10], 5, false, RleVersion::V2).unwrap();
+        assert_eq!(out, vec![10000; 5]);
+    }
+
+    #[test]
+    fn test_direct() {
+        let data = [0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef];
+        let out = decode_integers(&data, 4, false, RleVersion::V2).unwrap();
+        assert_eq!(out, vec![23713, 43806, 57005, 48879]);
+    }
+
+    #[test]
+    fn test_patched_base() {
+        let data = [
+            0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c,
+            0x46, 0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe,
+            0xfc, 0xe8,
+        ];
+        let out = decode_integers(&data, 20, false, RleVersion::V2).unwrap();
+        let expected = vec![
+            2030, 2000, 2020, 1000000, 2040, 2050, 2060, 2070, 2080, 2090, 2100, 2110,
+            2120, 2130, 2140, 2150, 2160, 2170, 2180, 2190,
+        ];
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_delta() {
+        let data = [0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46];
+        let out = decode_integers(&data, 10, false, RleVersion::V2).unwrap();
+        assert_eq!(out, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+
+        // Fixed delta of -2 starting at 10
+        let data = [0xc0, 0x04, 0x14, 0x03];
+        let out = decode_integers(&data, 5, true, RleVersion::V2).unwrap();
+        assert_eq!(out, vec![10, 8, 6, 4, 2]);
+    }
+}
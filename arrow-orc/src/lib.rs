@@ -0,0 +1,93 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Read [Apache ORC] files into the [Apache Arrow] memory format
+//!
+//! ```
+//! # use std::fs::File;
+//! # use arrow_orc::reader::ArrowReaderBuilder;
+//! # fn read(file: File) -> Result<(), arrow_schema::ArrowError> {
+//! let reader = ArrowReaderBuilder::try_new(file)?
+//!     // Only read the first and third top-level fields
+//!     .with_projection(vec![0, 2])
+//!     .build()?;
+//!
+//! for batch in reader {
+//!     println!("Read {} rows", batch?.num_rows());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ORC types are mapped to Arrow as follows, union types are not currently supported
+//!
+//! | ORC                       | Arrow                                  |
+//! |---------------------------|----------------------------------------|
+//! | `boolean`                 | [`DataType::Boolean`]                  |
+//! | `tinyint`                 | [`DataType::Int8`]                     |
+//! | `smallint`                | [`DataType::Int16`]                    |
+//! | `int`                     | [`DataType::Int32`]                    |
+//! | `bigint`                  | [`DataType::Int64`]                    |
+//! | `float`                   | [`DataType::Float32`]                  |
+//! | `double`                  | [`DataType::Float64`]                  |
+//! | `string`, `varchar`, `char` | [`DataType::Utf8`]                   |
+//! | `binary`                  | [`DataType::Binary`]                   |
+//! | `timestamp`               | [`DataType::Timestamp`] (nanoseconds)  |
+//! | `timestamp with local time zone` | [`DataType::Timestamp`] (nanoseconds, UTC) |
+//! | `date`                    | [`DataType::Date32`]                   |
+//! | `decimal`                 | [`DataType::Decimal128`]               |
+//! | `array`                   | [`DataType::List`]                     |
+//! | `map`                     | [`DataType::Map`]                      |
+//! | `struct`                  | [`DataType::Struct`]                   |
+//!
+//! With the `async` feature, files can additionally be read asynchronously with
+//! `async_reader`, including from an [`object_store`] with the `object_store` feature
+//!
+//! [Apache ORC]: https://orc.apache.org
+//! [Apache Arrow]: https://arrow.apache.org
+//! [`DataType::Boolean`]: arrow_schema::DataType::Boolean
+//! [`DataType::Int8`]: arrow_schema::DataType::Int8
+//! [`DataType::Int16`]: arrow_schema::DataType::Int16
+//! [`DataType::Int32`]: arrow_schema::DataType::Int32
+//! [`DataType::Int64`]: arrow_schema::DataType::Int64
+//! [`DataType::Float32`]: arrow_schema::DataType::Float32
+//! [`DataType::Float64`]: arrow_schema::DataType::Float64
+//! [`DataType::Utf8`]: arrow_schema::DataType::Utf8
+//! [`DataType::Binary`]: arrow_schema::DataType::Binary
+//! [`DataType::Timestamp`]: arrow_schema::DataType::Timestamp
+//! [`DataType::Date32`]: arrow_schema::DataType::Date32
+//! [`DataType::Decimal128`]: arrow_schema::DataType::Decimal128
+//! [`DataType::List`]: arrow_schema::DataType::List
+//! [`DataType::Map`]: arrow_schema::DataType::Map
+//! [`DataType::Struct`]: arrow_schema::DataType::Struct
+//! [`object_store`]: https://docs.rs/object_store
+
+#![warn(missing_docs)]
+
+mod compression;
+mod encoding;
+#[allow(missing_docs)]
+pub mod proto;
+pub mod reader;
+mod schema;
+pub mod statistics;
+
+#[cfg(feature = "async")]
+pub mod async_reader;
+
+#[cfg(test)]
+mod test_util;
@@ -0,0 +1,396 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::encoding::{decode_booleans, decode_bytes, decode_integers, ByteReader};
+use crate::proto::column_encoding::Kind as EncodingKind;
+use crate::proto::r#type::Kind;
+use crate::proto::stream::Kind as StreamKind;
+use crate::reader::stripe::Stripe;
+use crate::schema::OrcType;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{Buffer, NullBuffer, OffsetBuffer, ScalarBuffer};
+use arrow_schema::{ArrowError, DataType};
+use arrow_select::take::take;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// The number of seconds between the UNIX epoch and the ORC epoch of 2015-01-01
+const ORC_EPOCH_SECONDS: i64 = 1_420_070_400;
+
+/// Decodes `len` rows of the column `ty` from `stripe`
+pub(crate) fn decode_column(
+    stripe: &Stripe<'_>,
+    ty: &OrcType,
+    len: usize,
+) -> Result<ArrayRef, ArrowError> {
+    let nulls = match stripe.stream(ty.column, StreamKind::Present)? {
+        Some(data) => Some(NullBuffer::new(decode_booleans(&data, len)?)),
+        None => None,
+    };
+    let null_count = nulls.as_ref().map(|n| n.null_count()).unwrap_or_default();
+
+    // Only the non-null values are encoded
+    let values = decode_values(stripe, ty, len - null_count)?;
+    match nulls {
+        Some(nulls) if null_count != 0 => spread(values.as_ref(), &nulls),
+        _ => Ok(values),
+    }
+}
+
+/// Spreads `values` across the valid slots of `nulls`
+fn spread(values: &dyn Array, nulls: &NullBuffer) -> Result<ArrayRef, ArrowError> {
+    let mut next = 0;
+    let indices: UInt32Array = nulls
+        .iter()
+        .map(|valid| {
+            valid.then(|| {
+                next += 1;
+                next - 1
+            })
+        })
+        .collect();
+    take(values, &indices, None)
+}
+
+fn decode_values(
+    stripe: &Stripe<'_>,
+    ty: &OrcType,
+    len: usize,
+) -> Result<ArrayRef, ArrowError> {
+    let column = ty.column;
+    Ok(match ty.kind {
+        Kind::Boolean => {
+            let data = stripe.required_stream(column, StreamKind::Data)?;
+            Arc::new(BooleanArray::new(decode_booleans(&data, len)?, None))
+        }
+        Kind::Byte => {
+            let data = stripe.required_stream(column, StreamKind::Data)?;
+            let values = decode_bytes(&data, len)?;
+            Arc::new(Int8Array::from_iter_values(
+                values.into_iter().map(|v| v as i8),
+            ))
+        }
+        Kind::Short => decode_integer::<Int16Type>(stripe, column, len)?,
+        Kind::Int => decode_integer::<Int32Type>(stripe, column, len)?,
+        Kind::Long => decode_integer::<Int64Type>(stripe, column, len)?,
+        Kind::Date => decode_integer::<Date32Type>(stripe, column, len)?,
+        Kind::Float => decode_float::<Float32Type>(stripe, column, len)?,
+        Kind::Double => decode_float::<Float64Type>(stripe, column, len)?,
+        Kind::String | Kind::Varchar | Kind::Char => {
+            decode_bytes_array::<Utf8Type>(stripe, column, len)?
+        }
+        Kind::Binary => decode_bytes_array::<BinaryType>(stripe, column, len)?,
+        Kind::Timestamp | Kind::TimestampInstant => {
+            let values = decode_timestamp(stripe, column, len)?;
+            Arc::new(values.with_data_type(ty.data_type.clone()))
+        }
+        Kind::Decimal => decode_decimal(stripe, ty, len)?,
+        Kind::Struct => {
+            let fields = match &ty.data_type {
+                DataType::Struct(fields) => fields.clone(),
+                _ => unreachable!(),
+            };
+            let children = ty
+                .children
+                .iter()
+                .map(|(_, child)| decode_column(stripe, child, len))
+                .collect::<Result<_, _>>()?;
+            Arc::new(StructArray::try_new(fields, children, None)?)
+        }
+        Kind::List => {
+            let field = match &ty.data_type {
+                DataType::List(field) => field.clone(),
+                _ => unreachable!(),
+            };
+            let offsets = decode_offsets(stripe, column, len)?;
+            let values_len = *offsets.last().unwrap() as usize;
+            let values = decode_column(stripe, &ty.children[0].1, values_len)?;
+            Arc::new(ListArray::try_new(field, offsets, values, None)?)
+        }
+        Kind::Map => {
+            let entries = match &ty.data_type {
+                DataType::Map(entries, _) => entries.clone(),
+                _ => unreachable!(),
+            };
+            let fields = match entries.data_type() {
+                DataType::Struct(fields) => fields.clone(),
+                _ => unreachable!(),
+            };
+            let offsets = decode_offsets(stripe, column, len)?;
+            let values_len = *offsets.last().unwrap() as usize;
+            let keys = decode_column(stripe, &ty.children[0].1, values_len)?;
+            let values = decode_column(stripe, &ty.children[1].1, values_len)?;
+            let entries_array = StructArray::try_new(fields, vec![keys, values], None)?;
+            Arc::new(MapArray::try_new(
+                entries,
+                offsets,
+                entries_array,
+                None,
+                false,
+            )?)
+        }
+        Kind::Union => {
+            return Err(ArrowError::NotYetImplemented(
+                "Reading ORC union types is not supported".to_string(),
+            ))
+        }
+    })
+}
+
+fn decode_integer<T>(
+    stripe: &Stripe<'_>,
+    column: u32,
+    len: usize,
+) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    T::Native: TryFrom<i64>,
+{
+    let data = stripe.required_stream(column, StreamKind::Data)?;
+    let values = decode_integers(&data, len, true, stripe.rle_version(column)?)?;
+    let values = values
+        .into_iter()
+        .map(|v| {
+            T::Native::try_from(v).map_err(|_| {
+                ArrowError::ParseError(format!(
+                    "ORC value {v} out of range for {}",
+                    T::DATA_TYPE
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Arc::new(PrimitiveArray::<T>::new(values.into(), None)))
+}
+
+fn decode_float<T: ArrowPrimitiveType>(
+    stripe: &Stripe<'_>,
+    column: u32,
+    len: usize,
+) -> Result<ArrayRef, ArrowError> {
+    let data = stripe.required_stream(column, StreamKind::Data)?;
+    let byte_len = len * std::mem::size_of::<T::Native>();
+    if data.len() < byte_len {
+        return Err(ArrowError::ParseError(format!(
+            "Truncated ORC float data for column {column}"
+        )));
+    }
+    // Floating point values are stored as little-endian IEEE 754
+    let buffer = Buffer::from_slice_ref(&data[..byte_len]);
+    let values = ScalarBuffer::new(buffer, 0, len);
+    Ok(Arc::new(PrimitiveArray::<T>::new(values, None)))
+}
+
+/// Decodes `len` lengths from the LENGTH stream of `column` into offsets
+fn decode_offsets(
+    stripe: &Stripe<'_>,
+    column: u32,
+    len: usize,
+) -> Result<OffsetBuffer<i32>, ArrowError> {
+    let data = stripe.required_stream(column, StreamKind::Length)?;
+    let lengths = decode_integers(&data, len, false, stripe.rle_version(column)?)?;
+
+    let mut offsets = Vec::with_capacity(len + 1);
+    let mut offset = 0_i32;
+    offsets.push(offset);
+    for length in lengths {
+        offset = i32::try_from(length)
+            .ok()
+            .and_then(|l| offset.checked_add(l))
+            .ok_or_else(|| {
+                ArrowError::ParseError(format!(
+                    "Invalid ORC length {length} for column {column}"
+                ))
+            })?;
+        offsets.push(offset);
+    }
+    Ok(OffsetBuffer::new(offsets.into()))
+}
+
+fn decode_bytes_array<T>(
+    stripe: &Stripe<'_>,
+    column: u32,
+    len: usize,
+) -> Result<ArrayRef, ArrowError>
+where
+    T: ByteArrayType<Offset = i32>,
+{
+    let (values_len, values_kind) = match stripe.encoding(column)? {
+        EncodingKind::Direct | EncodingKind::DirectV2 => (len, StreamKind::Data),
+        EncodingKind::Dictionary | EncodingKind::DictionaryV2 => {
+            (stripe.dictionary_size(column)?, StreamKind::DictionaryData)
+        }
+    };
+
+    let offsets = decode_offsets(stripe, column, values_len)?;
+    let data = stripe.required_stream(column, values_kind)?;
+    let end = *offsets.last().unwrap() as usize;
+    if data.len() < end {
+        return Err(ArrowError::ParseError(format!(
+            "Truncated ORC data for column {column}"
+        )));
+    }
+    let values = Buffer::from_slice_ref(&data[..end]);
+    let values = GenericByteArray::<T>::try_new(offsets, values, None)?;
+    if values_kind == StreamKind::Data {
+        return Ok(Arc::new(values));
+    }
+
+    // The DATA stream of a dictionary encoded column contains indices into the dictionary
+    let data = stripe.required_stream(column, StreamKind::Data)?;
+    let keys = decode_integers(&data, len, false, stripe.rle_version(column)?)?;
+    let keys = keys
+        .into_iter()
+        .map(|k| match (k as u64) < values_len as u64 {
+            true => Ok(k as u32),
+            false => Err(ArrowError::ParseError(format!(
+                "Invalid ORC dictionary index {k} for column {column}"
+            ))),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    take(&values, &UInt32Array::from(keys), None)
+}
+
+/// Decodes the nanoseconds of an ORC timestamp, the low 3 bits of which encode the
+/// number of trailing decimal zeros that were removed
+fn decode_nanos(encoded: i64) -> i64 {
+    let zeros = (encoded & 0x07) as u32;
+    let nanos = encoded >> 3;
+    match zeros {
+        0 => nanos,
+        _ => nanos * 10_i64.pow(zeros + 1),
+    }
+}
+
+/// Converts ORC seconds since the ORC epoch and encoded nanos to UNIX epoch nanoseconds
+fn timestamp_nanos(seconds: i64, nanos: i64) -> Option<i64> {
+    let nanos = decode_nanos(nanos);
+    let mut seconds = seconds.checked_add(ORC_EPOCH_SECONDS)?;
+    // Writers truncate negative seconds towards zero
+    if seconds < 0 && nanos > 999_999 {
+        seconds -= 1;
+    }
+    seconds.checked_mul(1_000_000_000)?.checked_add(nanos)
+}
+
+/// Decodes a timestamp column, treating the writer timezone as UTC
+fn decode_timestamp(
+    stripe: &Stripe<'_>,
+    column: u32,
+    len: usize,
+) -> Result<TimestampNanosecondArray, ArrowError> {
+    let version = stripe.rle_version(column)?;
+    let data = stripe.required_stream(column, StreamKind::Data)?;
+    let seconds = decode_integers(&data, len, true, version)?;
+    let data = stripe.required_stream(column, StreamKind::Secondary)?;
+    let nanos = decode_integers(&data, len, false, version)?;
+
+    let values = seconds
+        .into_iter()
+        .zip(nanos)
+        .map(|(s, n)| {
+            timestamp_nanos(s, n).ok_or_else(|| {
+                ArrowError::ParseError(format!(
+                    "ORC timestamp out of range for column {column}"
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(TimestampNanosecondArray::new(values.into(), None))
+}
+
+/// Rescales a decimal `value` from scale `from` to scale `to`
+fn rescale(value: i128, from: i64, to: i64) -> Option<i128> {
+    match from.cmp(&to) {
+        Ordering::Equal => Some(value),
+        Ordering::Less => {
+            let exp = u32::try_from(to - from).ok()?;
+            value.checked_mul(10_i128.checked_pow(exp)?)
+        }
+        Ordering::Greater => {
+            let exp = u32::try_from(from - to).ok()?;
+            Some(10_i128.checked_pow(exp).map(|d| value / d).unwrap_or(0))
+        }
+    }
+}
+
+fn decode_decimal(
+    stripe: &Stripe<'_>,
+    ty: &OrcType,
+    len: usize,
+) -> Result<ArrayRef, ArrowError> {
+    let (precision, scale) = match ty.data_type {
+        DataType::Decimal128(p, s) => (p, s),
+        _ => unreachable!(),
+    };
+    let column = ty.column;
+
+    // The SECONDARY stream contains the scale of each value
+    let data = stripe.required_stream(column, StreamKind::Secondary)?;
+    let scales = decode_integers(&data, len, true, stripe.rle_version(column)?)?;
+
+    let data = stripe.required_stream(column, StreamKind::Data)?;
+    let mut reader = ByteReader::new(&data);
+    let values = scales
+        .into_iter()
+        .map(|value_scale| {
+            let value = reader.read_varint_i128()?;
+            rescale(value, value_scale, scale as i64).ok_or_else(|| {
+                ArrowError::ParseError(format!(
+                    "ORC decimal out of range for column {column}"
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let array = Decimal128Array::new(values.into(), None)
+        .with_precision_and_scale(precision, scale)?;
+    Ok(Arc::new(array))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp_nanos() {
+        // 2015-01-01T00:00:00
+        assert_eq!(
+            timestamp_nanos(0, 0),
+            Some(ORC_EPOCH_SECONDS * 1_000_000_000)
+        );
+        // 2015-01-01T00:00:01.5, 5 followed by 8 zeros (encoded as 7)
+        assert_eq!(
+            timestamp_nanos(1, 5 << 3 | 7),
+            Some((ORC_EPOCH_SECONDS + 1) * 1_000_000_000 + 500_000_000)
+        );
+        // 1969-12-31T23:59:58.5 is written as -1 seconds and 500 milliseconds
+        assert_eq!(
+            timestamp_nanos(-1 - ORC_EPOCH_SECONDS, 5 << 3 | 7),
+            Some(-1_500_000_000)
+        );
+        assert_eq!(decode_nanos(123456789 << 3), 123456789);
+    }
+
+    #[test]
+    fn test_rescale() {
+        assert_eq!(rescale(12345, 2, 2), Some(12345));
+        assert_eq!(rescale(12345, 2, 4), Some(1234500));
+        assert_eq!(rescale(12345, 3, 1), Some(123));
+        assert_eq!(rescale(i128::MAX, 0, 2), None);
+    }
+}
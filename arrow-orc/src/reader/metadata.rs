@@ -0,0 +1,227 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::compression::{decompress, Compression};
+use crate::proto;
+use crate::schema::OrcType;
+use crate::statistics::ColumnStatistics;
+use arrow_schema::{ArrowError, SchemaRef};
+use bytes::Bytes;
+use prost::Message;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// The magic bytes at the start of an ORC file, and at the end of its postscript
+const MAGIC: &str = "ORC";
+
+/// The number of bytes to speculatively read from the end of a file, this is typically
+/// sufficient to read the entire file tail with a single request
+pub(crate) const DEFAULT_TAIL_SIZE: u64 = 16 * 1024;
+
+/// The metadata of an ORC file, decoded from its file tail
+#[derive(Debug)]
+pub struct FileMetadata {
+    compression: Option<Compression>,
+    root: OrcType,
+    schema: SchemaRef,
+    number_of_rows: u64,
+    stripes: Vec<StripeMetadata>,
+    statistics: Vec<ColumnStatistics>,
+}
+
+impl FileMetadata {
+    /// Returns the Arrow schema of the file
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Returns the total number of rows in the file
+    pub fn number_of_rows(&self) -> u64 {
+        self.number_of_rows
+    }
+
+    /// Returns the metadata of the stripes in the file
+    pub fn stripes(&self) -> &[StripeMetadata] {
+        &self.stripes
+    }
+
+    /// Returns the statistics of the ORC column `column` for the whole file
+    ///
+    /// See [`Self::column_id`] for how to determine the ORC column id of a field
+    pub fn column_statistics(&self, column: usize) -> Option<&ColumnStatistics> {
+        self.statistics.get(column)
+    }
+
+    /// Returns the ORC column id of the top-level field named `name`
+    ///
+    /// ORC assigns column ids to all types in a pre-order traversal of the schema,
+    /// with the root struct as column `0`, as such these do not correspond to the
+    /// indices of the fields in the Arrow schema if the file contains nested types
+    pub fn column_id(&self, name: &str) -> Option<usize> {
+        self.root
+            .children
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, ty)| ty.column as usize)
+    }
+
+    pub(crate) fn root(&self) -> &OrcType {
+        &self.root
+    }
+
+    pub(crate) fn compression(&self) -> Option<&Compression> {
+        self.compression.as_ref()
+    }
+}
+
+/// The metadata of a single stripe in an ORC file
+#[derive(Debug)]
+pub struct StripeMetadata {
+    offset: u64,
+    index_length: u64,
+    data_length: u64,
+    footer_length: u64,
+    number_of_rows: u64,
+    statistics: Vec<ColumnStatistics>,
+}
+
+impl StripeMetadata {
+    /// Returns the offset of the stripe within the file
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the number of rows in the stripe
+    pub fn number_of_rows(&self) -> u64 {
+        self.number_of_rows
+    }
+
+    /// Returns the statistics of the ORC column `column` within this stripe
+    ///
+    /// Returns `None` if the file does not contain stripe statistics
+    pub fn column_statistics(&self, column: usize) -> Option<&ColumnStatistics> {
+        self.statistics.get(column)
+    }
+
+    /// Returns the byte range of the stripe within the file
+    pub(crate) fn range(&self) -> Range<u64> {
+        let len = self.index_length + self.data_length + self.footer_length;
+        self.offset..self.offset + len
+    }
+
+    /// Returns the length of the index and data streams
+    pub(crate) fn streams_length(&self) -> u64 {
+        self.index_length + self.data_length
+    }
+}
+
+fn decode_proto<T: Message + Default>(data: Bytes, name: &str) -> Result<T, ArrowError> {
+    T::decode(data)
+        .map_err(|e| ArrowError::ParseError(format!("Failed to decode ORC {name}: {e}")))
+}
+
+/// Returns the postscript and its length, given the last bytes of a file
+fn decode_postscript(tail: &[u8]) -> Result<(proto::PostScript, usize), ArrowError> {
+    let ps_len = *tail
+        .last()
+        .ok_or_else(|| ArrowError::ParseError("ORC file must not be empty".to_string()))?
+        as usize;
+    if tail.len() < ps_len + 1 {
+        return Err(ArrowError::ParseError(format!(
+            "Invalid ORC postscript length {ps_len}"
+        )));
+    }
+    let start = tail.len() - 1 - ps_len;
+    let data = Bytes::copy_from_slice(&tail[start..tail.len() - 1]);
+    let postscript: proto::PostScript = decode_proto(data, "postscript")?;
+    if postscript.magic() != MAGIC {
+        return Err(ArrowError::ParseError(
+            "Invalid ORC file, missing magic bytes".to_string(),
+        ));
+    }
+    Ok((postscript, ps_len))
+}
+
+/// Returns the length of the file tail, given the last bytes of a file of `file_len`
+pub(crate) fn tail_length(tail: &[u8], file_len: u64) -> Result<u64, ArrowError> {
+    let (postscript, ps_len) = decode_postscript(tail)?;
+    let len =
+        1 + ps_len as u64 + postscript.footer_length() + postscript.metadata_length();
+    if len > file_len {
+        return Err(ArrowError::ParseError(format!(
+            "ORC file tail of {len} bytes exceeds file length of {file_len}"
+        )));
+    }
+    Ok(len)
+}
+
+/// Decodes the [`FileMetadata`] from `tail`, which must contain the entire file tail
+pub(crate) fn decode_tail(tail: Bytes) -> Result<FileMetadata, ArrowError> {
+    let (postscript, ps_len) = decode_postscript(&tail)?;
+    let compression = Compression::try_new(
+        postscript.compression(),
+        postscript.compression_block_size,
+    )?;
+
+    let footer_end = tail.len() - 1 - ps_len;
+    let footer_len = postscript.footer_length() as usize;
+    let metadata_len = postscript.metadata_length() as usize;
+    if footer_end < footer_len + metadata_len {
+        return Err(ArrowError::ParseError(
+            "Truncated ORC file tail".to_string(),
+        ));
+    }
+    let footer_start = footer_end - footer_len;
+    let metadata_start = footer_start - metadata_len;
+
+    let footer = decompress(compression.as_ref(), tail.slice(footer_start..footer_end))?;
+    let footer: proto::Footer = decode_proto(footer, "footer")?;
+
+    let metadata = tail.slice(metadata_start..footer_start);
+    let metadata = decompress(compression.as_ref(), metadata)?;
+    let metadata: proto::Metadata = decode_proto(metadata, "metadata")?;
+
+    let root = OrcType::try_new(&footer.types)?;
+    let schema = Arc::new(root.schema());
+
+    let stripes = footer
+        .stripes
+        .iter()
+        .enumerate()
+        .map(|(idx, stripe)| StripeMetadata {
+            offset: stripe.offset(),
+            index_length: stripe.index_length(),
+            data_length: stripe.data_length(),
+            footer_length: stripe.footer_length(),
+            number_of_rows: stripe.number_of_rows(),
+            statistics: metadata
+                .stripe_stats
+                .get(idx)
+                .map(|s| s.col_stats.iter().map(Into::into).collect())
+                .unwrap_or_default(),
+        })
+        .collect();
+
+    Ok(FileMetadata {
+        compression,
+        root,
+        schema,
+        number_of_rows: footer.number_of_rows(),
+        stripes,
+        statistics: footer.statistics.iter().map(Into::into).collect(),
+    })
+}
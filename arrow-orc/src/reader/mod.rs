@@ -0,0 +1,447 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Read ORC files into Arrow [`RecordBatch`]
+
+use crate::reader::decoder::decode_column;
+use crate::reader::metadata::{decode_tail, tail_length, DEFAULT_TAIL_SIZE};
+use crate::reader::stripe::Stripe;
+use arrow_array::{RecordBatch, RecordBatchOptions, RecordBatchReader};
+use arrow_schema::{ArrowError, SchemaRef};
+use bytes::Bytes;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::ops::Range;
+use std::sync::Arc;
+
+mod decoder;
+pub(crate) mod metadata;
+mod stripe;
+
+pub use metadata::{FileMetadata, StripeMetadata};
+
+/// The default number of rows in each [`RecordBatch`]
+const DEFAULT_BATCH_SIZE: usize = 1024;
+
+/// A source of ORC file bytes, such as a [`File`] or [`Bytes`]
+#[allow(clippy::len_without_is_empty)]
+pub trait ChunkReader {
+    /// Returns the length of the file in bytes
+    fn len(&self) -> u64;
+
+    /// Returns `length` bytes of the file starting at `start`
+    fn get_bytes(&self, start: u64, length: usize) -> Result<Bytes, ArrowError>;
+}
+
+impl ChunkReader for File {
+    fn len(&self) -> u64 {
+        self.metadata().map(|m| m.len()).unwrap_or(0)
+    }
+
+    fn get_bytes(&self, start: u64, length: usize) -> Result<Bytes, ArrowError> {
+        let mut file = self.try_clone()?;
+        file.seek(SeekFrom::Start(start))?;
+        let mut buf = vec![0; length];
+        file.read_exact(&mut buf)?;
+        Ok(buf.into())
+    }
+}
+
+impl ChunkReader for Bytes {
+    fn len(&self) -> u64 {
+        self.len() as u64
+    }
+
+    fn get_bytes(&self, start: u64, length: usize) -> Result<Bytes, ArrowError> {
+        let start = start as usize;
+        match start.checked_add(length) {
+            Some(end) if end <= self.len() => Ok(self.slice(start..end)),
+            _ => Err(ArrowError::ParseError(format!(
+                "Range {start}..{} out of bounds for ORC file of {} bytes",
+                start.saturating_add(length),
+                self.len()
+            ))),
+        }
+    }
+}
+
+/// Reads the [`FileMetadata`] from `reader`
+pub fn read_metadata<R: ChunkReader>(reader: &R) -> Result<FileMetadata, ArrowError> {
+    let file_len = reader.len();
+    let len = file_len.min(DEFAULT_TAIL_SIZE);
+    let mut tail = reader.get_bytes(file_len - len, len as usize)?;
+    let tail_len = tail_length(&tail, file_len)?;
+    if tail_len > len {
+        tail = reader.get_bytes(file_len - tail_len, tail_len as usize)?;
+    }
+    decode_tail(tail)
+}
+
+/// A predicate that returns `true` if a stripe should be read
+type StripeFilter = Box<dyn FnMut(&StripeMetadata) -> bool + Send>;
+
+/// A builder for [`ArrowReader`], and with the `async` feature, an `ArrowStreamReader`
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_orc::reader::ArrowReaderBuilder;
+/// # use arrow_orc::statistics::TypeStatistics;
+/// # fn read(file: std::fs::File) -> Result<(), arrow_schema::ArrowError> {
+/// let builder = ArrowReaderBuilder::try_new(file)?;
+/// let id = builder.metadata().column_id("id").unwrap();
+///
+/// // Only read stripes that may contain rows with an id greater than 100
+/// let reader = builder
+///     .with_batch_size(8192)
+///     .with_stripe_filter(move |stripe| match stripe.column_statistics(id) {
+///         Some(stats) => match stats.type_statistics() {
+///             Some(TypeStatistics::Integer { max, .. }) => *max > 100,
+///             _ => true,
+///         },
+///         None => true,
+///     })
+///     .build()?;
+///
+/// for batch in reader {
+///     println!("{:?}", batch?);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct ArrowReaderBuilder<T> {
+    input: T,
+    metadata: Arc<FileMetadata>,
+    batch_size: usize,
+    projection: Option<Vec<usize>>,
+    stripe_filter: Option<StripeFilter>,
+}
+
+impl<T> ArrowReaderBuilder<T> {
+    pub(crate) fn new(input: T, metadata: FileMetadata) -> Self {
+        Self {
+            input,
+            metadata: Arc::new(metadata),
+            batch_size: DEFAULT_BATCH_SIZE,
+            projection: None,
+            stripe_filter: None,
+        }
+    }
+
+    /// Returns the [`FileMetadata`] of the file
+    pub fn metadata(&self) -> &Arc<FileMetadata> {
+        &self.metadata
+    }
+
+    /// Returns the Arrow schema of the file, before any projection
+    pub fn schema(&self) -> &SchemaRef {
+        self.metadata.schema()
+    }
+
+    /// Set the maximum number of rows in each [`RecordBatch`], defaults to 1024
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self {
+            batch_size: batch_size.max(1),
+            ..self
+        }
+    }
+
+    /// Only read the top-level fields at `projection` indices of [`Self::schema`]
+    pub fn with_projection(self, projection: Vec<usize>) -> Self {
+        Self {
+            projection: Some(projection),
+            ..self
+        }
+    }
+
+    /// Only read the stripes for which `filter` returns `true`
+    ///
+    /// This can be used in combination with [`StripeMetadata::column_statistics`] to
+    /// skip stripes that cannot contain any rows of interest
+    pub fn with_stripe_filter<F>(self, filter: F) -> Self
+    where
+        F: FnMut(&StripeMetadata) -> bool + Send + 'static,
+    {
+        Self {
+            stripe_filter: Some(Box::new(filter)),
+            ..self
+        }
+    }
+
+    /// Returns the input and the [`ReadPlan`] of this builder
+    pub(crate) fn into_plan(self) -> Result<(T, ReadPlan), ArrowError> {
+        let num_fields = self.metadata.schema().fields().len();
+        let projection = self.projection.unwrap_or_else(|| (0..num_fields).collect());
+        let schema = Arc::new(self.metadata.schema().project(&projection)?);
+
+        let stripes = match self.stripe_filter {
+            Some(mut filter) => self
+                .metadata
+                .stripes()
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, stripe)| filter(stripe).then_some(idx))
+                .collect(),
+            None => (0..self.metadata.stripes().len()).collect(),
+        };
+
+        let plan = ReadPlan {
+            metadata: self.metadata,
+            projection,
+            schema,
+            batch_size: self.batch_size,
+            stripes,
+            batches: VecDeque::new(),
+        };
+        Ok((self.input, plan))
+    }
+}
+
+impl<T: ChunkReader> ArrowReaderBuilder<T> {
+    /// Create a new [`ArrowReaderBuilder`], reading the [`FileMetadata`] from `input`
+    pub fn try_new(input: T) -> Result<Self, ArrowError> {
+        let metadata = read_metadata(&input)?;
+        Ok(Self::new(input, metadata))
+    }
+
+    /// Build an [`ArrowReader`] with the provided configuration
+    pub fn build(self) -> Result<ArrowReader<T>, ArrowError> {
+        let (input, plan) = self.into_plan()?;
+        Ok(ArrowReader { input, plan })
+    }
+}
+
+/// The stripes and columns to read from a file
+#[derive(Debug)]
+pub(crate) struct ReadPlan {
+    metadata: Arc<FileMetadata>,
+    projection: Vec<usize>,
+    schema: SchemaRef,
+    batch_size: usize,
+    /// The indices of the stripes remaining to be read
+    stripes: VecDeque<usize>,
+    /// The batches decoded from the current stripe
+    batches: VecDeque<RecordBatch>,
+}
+
+impl ReadPlan {
+    pub(crate) fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Returns the next decoded [`RecordBatch`], if any
+    pub(crate) fn next_batch(&mut self) -> Option<RecordBatch> {
+        self.batches.pop_front()
+    }
+
+    /// Returns the index and byte range of the next stripe to read, if any
+    pub(crate) fn next_stripe(&mut self) -> Option<(usize, Range<u64>)> {
+        let idx = self.stripes.pop_front()?;
+        Some((idx, self.metadata.stripes()[idx].range()))
+    }
+
+    /// Decodes the stripe `idx` from `data`
+    pub(crate) fn decode_stripe(
+        &mut self,
+        idx: usize,
+        data: Bytes,
+    ) -> Result<(), ArrowError> {
+        let metadata = &self.metadata.stripes()[idx];
+        let stripe = Stripe::try_new(data, metadata, self.metadata.compression())?;
+
+        let rows = metadata.number_of_rows() as usize;
+        let root = self.metadata.root();
+        let columns = self
+            .projection
+            .iter()
+            .map(|idx| decode_column(&stripe, &root.children[*idx].1, rows))
+            .collect::<Result<_, _>>()?;
+
+        let options = RecordBatchOptions::new().with_row_count(Some(rows));
+        let batch =
+            RecordBatch::try_new_with_options(self.schema.clone(), columns, &options)?;
+
+        let mut offset = 0;
+        while offset < rows {
+            let len = self.batch_size.min(rows - offset);
+            self.batches.push_back(batch.slice(offset, len));
+            offset += len;
+        }
+        Ok(())
+    }
+}
+
+/// An iterator of [`RecordBatch`] read from an ORC file
+///
+/// Created by [`ArrowReaderBuilder::build`]
+#[derive(Debug)]
+pub struct ArrowReader<T> {
+    input: T,
+    plan: ReadPlan,
+}
+
+impl<T: ChunkReader> ArrowReader<T> {
+    fn read_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        loop {
+            if let Some(batch) = self.plan.next_batch() {
+                return Ok(Some(batch));
+            }
+            let (idx, range) = match self.plan.next_stripe() {
+                Some(next) => next,
+                None => return Ok(None),
+            };
+            let data = self
+                .input
+                .get_bytes(range.start, (range.end - range.start) as usize)?;
+            self.plan.decode_stripe(idx, data)?;
+        }
+    }
+}
+
+impl<T: ChunkReader> Iterator for ArrowReader<T> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_next().transpose()
+    }
+}
+
+impl<T: ChunkReader> RecordBatchReader for ArrowReader<T> {
+    fn schema(&self) -> SchemaRef {
+        self.plan.schema().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::CompressionKind;
+    use crate::statistics::TypeStatistics;
+    use crate::test_util::write_test_file;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int32Type, Int64Type};
+    use arrow_array::{Array, Int64Array, StringArray};
+
+    fn read_all(builder: ArrowReaderBuilder<Bytes>) -> Vec<RecordBatch> {
+        builder.build().unwrap().collect::<Result<_, _>>().unwrap()
+    }
+
+    #[test]
+    fn test_read() {
+        for compression in [CompressionKind::None, CompressionKind::Zlib] {
+            let file = write_test_file(compression);
+            let builder = ArrowReaderBuilder::try_new(file).unwrap();
+            assert_eq!(builder.metadata().number_of_rows(), 5);
+            assert_eq!(builder.metadata().stripes().len(), 2);
+
+            let batches = read_all(builder.with_batch_size(2));
+            let lens: Vec<_> = batches.iter().map(|b| b.num_rows()).collect();
+            assert_eq!(lens, vec![2, 1, 2]);
+
+            let batch = &batches[0];
+            let ids = batch.column(0).as_primitive::<Int64Type>();
+            assert_eq!(ids, &Int64Array::from(vec![1, 2]));
+            let names = batch.column(1).as_string::<i32>();
+            assert_eq!(names, &StringArray::from(vec![Some("a"), None]));
+
+            let tags = batches[1].column(2).as_list::<i32>();
+            assert!(tags.is_valid(0));
+            assert_eq!(tags.value(0).len(), 0);
+
+            let tags = batches[2].column(2).as_list::<i32>();
+            let values = tags.values().as_primitive::<Int32Type>();
+            assert_eq!(values.values(), &[3, 4, 5]);
+            assert_eq!(tags.value_offsets(), &[0, 1, 3]);
+
+            let tags = batches[0].column(2).as_list::<i32>();
+            assert!(tags.is_null(1));
+            assert_eq!(tags.value(0).as_primitive::<Int32Type>().values(), &[1, 2]);
+        }
+    }
+
+    #[test]
+    fn test_projection() {
+        let file = write_test_file(CompressionKind::None);
+        let builder = ArrowReaderBuilder::try_new(file).unwrap();
+        let batches = read_all(builder.with_projection(vec![1]));
+
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].schema().fields().len(), 1);
+        assert_eq!(batches[0].schema().field(0).name(), "name");
+        let names = batches[1].column(0).as_string::<i32>();
+        assert_eq!(names, &StringArray::from(vec!["d", "e"]));
+
+        // Empty projection preserves the row count
+        let builder = ArrowReaderBuilder::try_new(write_test_file(CompressionKind::None));
+        let batches = read_all(builder.unwrap().with_projection(vec![]));
+        let lens: Vec<_> = batches.iter().map(|b| b.num_rows()).collect();
+        assert_eq!(lens, vec![3, 2]);
+
+        let builder = ArrowReaderBuilder::try_new(write_test_file(CompressionKind::None));
+        let err = builder
+            .unwrap()
+            .with_projection(vec![3])
+            .build()
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("project index 3 out of bounds"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_stripe_filter() {
+        let file = write_test_file(CompressionKind::Zlib);
+        let builder = ArrowReaderBuilder::try_new(file).unwrap();
+        let id = builder.metadata().column_id("id").unwrap();
+        assert_eq!(id, 1);
+        assert_eq!(builder.metadata().column_id("tags"), Some(3));
+        assert_eq!(builder.metadata().column_id("missing"), None);
+
+        let stats = builder.metadata().column_statistics(id).unwrap();
+        assert_eq!(
+            stats.type_statistics(),
+            Some(&TypeStatistics::Integer {
+                min: 1,
+                max: 5,
+                sum: Some(15)
+            })
+        );
+
+        let batches = read_all(builder.with_stripe_filter(move |stripe| {
+            match stripe.column_statistics(id).unwrap().type_statistics() {
+                Some(TypeStatistics::Integer { max, .. }) => *max >= 4,
+                _ => true,
+            }
+        }));
+        assert_eq!(batches.len(), 1);
+        let ids = batches[0].column(0).as_primitive::<Int64Type>();
+        assert_eq!(ids.values(), &[4, 5]);
+    }
+
+    #[test]
+    fn test_invalid_file() {
+        let err = read_metadata(&Bytes::from_static(b"")).unwrap_err();
+        assert_eq!(err.to_string(), "Parser error: ORC file must not be empty");
+
+        let err = read_metadata(&Bytes::from_static(b"ORC\x09")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Invalid ORC postscript length 9"
+        );
+    }
+}
@@ -0,0 +1,126 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::compression::{decompress, Compression};
+use crate::encoding::RleVersion;
+use crate::proto;
+use crate::proto::column_encoding::Kind as EncodingKind;
+use crate::proto::stream::Kind as StreamKind;
+use crate::reader::metadata::StripeMetadata;
+use arrow_schema::ArrowError;
+use bytes::Bytes;
+use prost::Message;
+use std::collections::HashMap;
+
+/// The streams of a single stripe
+#[derive(Debug)]
+pub(crate) struct Stripe<'a> {
+    compression: Option<&'a Compression>,
+    /// The compressed streams, keyed by column id and kind
+    streams: HashMap<(u32, StreamKind), Bytes>,
+    encodings: Vec<proto::ColumnEncoding>,
+}
+
+impl<'a> Stripe<'a> {
+    /// Creates a new [`Stripe`] from `data`, the contents of the stripe described by
+    /// `metadata`
+    pub(crate) fn try_new(
+        data: Bytes,
+        metadata: &StripeMetadata,
+        compression: Option<&'a Compression>,
+    ) -> Result<Self, ArrowError> {
+        let streams_len = metadata.streams_length() as usize;
+        if data.len() < streams_len {
+            return Err(ArrowError::ParseError("Truncated ORC stripe".to_string()));
+        }
+
+        let footer = decompress(compression, data.slice(streams_len..))?;
+        let footer = proto::StripeFooter::decode(footer).map_err(|e| {
+            ArrowError::ParseError(format!("Failed to decode ORC stripe footer: {e}"))
+        })?;
+
+        // Streams are stored contiguously, in the order of the stripe footer
+        let mut offset = 0;
+        let mut streams = HashMap::with_capacity(footer.streams.len());
+        for stream in &footer.streams {
+            let end = offset + stream.length() as usize;
+            if end > streams_len {
+                return Err(ArrowError::ParseError(format!(
+                    "ORC stream for column {} exceeds stripe length",
+                    stream.column()
+                )));
+            }
+            streams.insert((stream.column(), stream.kind()), data.slice(offset..end));
+            offset = end;
+        }
+
+        Ok(Self {
+            compression,
+            streams,
+            encodings: footer.columns,
+        })
+    }
+
+    /// Returns the decompressed stream of `kind` for `column`, if any
+    pub(crate) fn stream(
+        &self,
+        column: u32,
+        kind: StreamKind,
+    ) -> Result<Option<Bytes>, ArrowError> {
+        match self.streams.get(&(column, kind)) {
+            Some(data) => decompress(self.compression, data.clone()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the decompressed stream of `kind` for `column`, erroring if not present
+    pub(crate) fn required_stream(
+        &self,
+        column: u32,
+        kind: StreamKind,
+    ) -> Result<Bytes, ArrowError> {
+        self.stream(column, kind)?.ok_or_else(|| {
+            ArrowError::ParseError(format!(
+                "Missing ORC {kind:?} stream for column {column}"
+            ))
+        })
+    }
+
+    fn column_encoding(&self, column: u32) -> Result<&proto::ColumnEncoding, ArrowError> {
+        self.encodings.get(column as usize).ok_or_else(|| {
+            ArrowError::ParseError(format!("Missing ORC encoding for column {column}"))
+        })
+    }
+
+    /// Returns the encoding of `column`
+    pub(crate) fn encoding(&self, column: u32) -> Result<EncodingKind, ArrowError> {
+        Ok(self.column_encoding(column)?.kind())
+    }
+
+    /// Returns the size of the dictionary of a dictionary encoded `column`
+    pub(crate) fn dictionary_size(&self, column: u32) -> Result<usize, ArrowError> {
+        Ok(self.column_encoding(column)?.dictionary_size() as usize)
+    }
+
+    /// Returns the version of the integer run length encoding used by `column`
+    pub(crate) fn rle_version(&self, column: u32) -> Result<RleVersion, ArrowError> {
+        Ok(match self.encoding(column)? {
+            EncodingKind::Direct | EncodingKind::Dictionary => RleVersion::V1,
+            EncodingKind::DirectV2 | EncodingKind::DictionaryV2 => RleVersion::V2,
+        })
+    }
+}
@@ -0,0 +1,260 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversion of the ORC type tree to an Arrow [`Schema`]
+
+use crate::proto;
+use crate::proto::r#type::Kind;
+use arrow_schema::{
+    ArrowError, DataType, Field, Fields, Schema, TimeUnit, DECIMAL128_MAX_PRECISION,
+};
+use std::sync::Arc;
+
+/// The default precision of an ORC decimal without an explicit precision
+const DEFAULT_DECIMAL_PRECISION: u32 = 38;
+/// The default scale of an ORC decimal without an explicit scale
+const DEFAULT_DECIMAL_SCALE: u32 = 10;
+
+/// A node in the ORC type tree, along with its corresponding Arrow [`DataType`]
+#[derive(Debug, Clone)]
+pub(crate) struct OrcType {
+    /// The ORC column id, this is the index of this type in the footer
+    pub(crate) column: u32,
+    pub(crate) kind: Kind,
+    pub(crate) data_type: DataType,
+    /// The fields of a struct, the element of a list, or the key and value of a map
+    pub(crate) children: Vec<(String, OrcType)>,
+}
+
+impl OrcType {
+    /// Parses the flattened list of `types` from the file footer
+    pub(crate) fn try_new(types: &[proto::Type]) -> Result<Self, ArrowError> {
+        let root = Self::parse(types, 0)?;
+        match root.kind {
+            Kind::Struct => Ok(root),
+            k => Err(ArrowError::ParseError(format!(
+                "ORC root type must be a struct, got {k:?}"
+            ))),
+        }
+    }
+
+    fn parse(types: &[proto::Type], column: u32) -> Result<Self, ArrowError> {
+        let ty = types.get(column as usize).ok_or_else(|| {
+            ArrowError::ParseError(format!("ORC type {column} not found"))
+        })?;
+        let kind = ty.kind();
+
+        let mut children = Vec::with_capacity(ty.subtypes.len());
+        for (idx, subtype) in ty.subtypes.iter().enumerate() {
+            // Subtypes always follow their parent, this guards against cycles
+            if *subtype <= column {
+                return Err(ArrowError::ParseError(format!(
+                    "Invalid ORC subtype {subtype} for column {column}"
+                )));
+            }
+            let name = match kind {
+                Kind::Struct => ty.field_names.get(idx).cloned().ok_or_else(|| {
+                    ArrowError::ParseError(format!(
+                        "Missing ORC field name for column {subtype}"
+                    ))
+                })?,
+                Kind::Map if idx == 0 => "keys".to_string(),
+                Kind::Map => "values".to_string(),
+                _ => "item".to_string(),
+            };
+            children.push((name, Self::parse(types, *subtype)?));
+        }
+
+        let expected_children = match kind {
+            Kind::List => Some(1),
+            Kind::Map => Some(2),
+            Kind::Struct | Kind::Union => None,
+            _ => Some(0),
+        };
+        if let Some(expected) = expected_children {
+            if children.len() != expected {
+                return Err(ArrowError::ParseError(format!(
+                    "ORC {kind:?} column {column} must have {expected} subtypes, got {}",
+                    children.len()
+                )));
+            }
+        }
+
+        let data_type = match kind {
+            Kind::Boolean => DataType::Boolean,
+            Kind::Byte => DataType::Int8,
+            Kind::Short => DataType::Int16,
+            Kind::Int => DataType::Int32,
+            Kind::Long => DataType::Int64,
+            Kind::Float => DataType::Float32,
+            Kind::Double => DataType::Float64,
+            Kind::String | Kind::Varchar | Kind::Char => DataType::Utf8,
+            Kind::Binary => DataType::Binary,
+            Kind::Timestamp => DataType::Timestamp(TimeUnit::Nanosecond, None),
+            Kind::TimestampInstant => {
+                DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+            }
+            Kind::Date => DataType::Date32,
+            Kind::Decimal => {
+                let precision = ty.precision.unwrap_or(DEFAULT_DECIMAL_PRECISION);
+                let scale = ty.scale.unwrap_or(DEFAULT_DECIMAL_SCALE);
+                if precision == 0
+                    || precision > DECIMAL128_MAX_PRECISION as u32
+                    || scale > precision
+                {
+                    return Err(ArrowError::ParseError(format!(
+                        "Invalid ORC decimal precision {precision} and scale {scale}"
+                    )));
+                }
+                DataType::Decimal128(precision as u8, scale as i8)
+            }
+            Kind::List => {
+                let (name, child) = &children[0];
+                DataType::List(Arc::new(child.field(name)))
+            }
+            Kind::Map => {
+                let key = children[0].1.field("keys").with_nullable(false);
+                let value = children[1].1.field("values");
+                let entries = Field::new(
+                    "entries",
+                    DataType::Struct(Fields::from(vec![key, value])),
+                    false,
+                );
+                DataType::Map(Arc::new(entries), false)
+            }
+            Kind::Struct => {
+                DataType::Struct(children.iter().map(|(name, c)| c.field(name)).collect())
+            }
+            Kind::Union => {
+                return Err(ArrowError::NotYetImplemented(
+                    "Reading ORC union types is not supported".to_string(),
+                ))
+            }
+        };
+
+        Ok(Self {
+            column,
+            kind,
+            data_type,
+            children,
+        })
+    }
+
+    /// Returns a nullable [`Field`] named `name` for this type
+    pub(crate) fn field(&self, name: &str) -> Field {
+        Field::new(name, self.data_type.clone(), true)
+    }
+
+    /// Returns the [`Schema`] of a struct type
+    pub(crate) fn schema(&self) -> Schema {
+        match &self.data_type {
+            DataType::Struct(fields) => Schema::new(fields.clone()),
+            _ => unreachable!("root must be a struct"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ty(kind: Kind, subtypes: Vec<u32>, field_names: Vec<&str>) -> proto::Type {
+        let mut ty = proto::Type {
+            subtypes,
+            field_names: field_names.into_iter().map(ToString::to_string).collect(),
+            ..Default::default()
+        };
+        ty.set_kind(kind);
+        ty
+    }
+
+    #[test]
+    fn test_schema() {
+        let mut decimal = ty(Kind::Decimal, vec![], vec![]);
+        decimal.precision = Some(10);
+        decimal.scale = Some(2);
+
+        let types = vec![
+            ty(
+                Kind::Struct,
+                vec![1, 2, 4, 7, 8],
+                vec!["a", "b", "c", "d", "e"],
+            ),
+            ty(Kind::Long, vec![], vec![]),
+            ty(Kind::List, vec![3], vec![]),
+            ty(Kind::String, vec![], vec![]),
+            ty(Kind::Map, vec![5, 6], vec![]),
+            ty(Kind::Varchar, vec![], vec![]),
+            ty(Kind::Double, vec![], vec![]),
+            decimal,
+            ty(Kind::TimestampInstant, vec![], vec![]),
+        ];
+
+        let root = OrcType::try_new(&types).unwrap();
+        let schema = root.schema();
+
+        let entries = Field::new(
+            "entries",
+            DataType::Struct(Fields::from(vec![
+                Field::new("keys", DataType::Utf8, false),
+                Field::new("values", DataType::Float64, true),
+            ])),
+            false,
+        );
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new_list("b", Field::new("item", DataType::Utf8, true), true),
+            Field::new("c", DataType::Map(Arc::new(entries), false), true),
+            Field::new("d", DataType::Decimal128(10, 2), true),
+            Field::new(
+                "e",
+                DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into())),
+                true,
+            ),
+        ]);
+        assert_eq!(schema, expected);
+        assert_eq!(root.children[2].1.children[1].1.column, 6);
+    }
+
+    #[test]
+    fn test_invalid_schema() {
+        let types = vec![ty(Kind::Struct, vec![0], vec!["a"])];
+        let err = OrcType::try_new(&types).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Invalid ORC subtype 0 for column 0"
+        );
+
+        let types = vec![ty(Kind::Long, vec![], vec![])];
+        let err = OrcType::try_new(&types).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: ORC root type must be a struct, got Long"
+        );
+
+        let types = vec![
+            ty(Kind::Struct, vec![1], vec!["a"]),
+            ty(Kind::Union, vec![2], vec![]),
+            ty(Kind::Int, vec![], vec![]),
+        ];
+        let err = OrcType::try_new(&types).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: Reading ORC union types is not supported"
+        );
+    }
+}
@@ -0,0 +1,214 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Column statistics recorded in ORC file and stripe metadata
+
+use crate::proto;
+
+/// Statistics of a single ORC column, for either a whole file or a single stripe
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnStatistics {
+    number_of_values: u64,
+    has_null: bool,
+    type_statistics: Option<TypeStatistics>,
+}
+
+impl ColumnStatistics {
+    /// Returns the number of non-null values
+    pub fn number_of_values(&self) -> u64 {
+        self.number_of_values
+    }
+
+    /// Returns `true` if the column contains any nulls
+    pub fn has_null(&self) -> bool {
+        self.has_null
+    }
+
+    /// Returns the type-specific statistics, if any
+    pub fn type_statistics(&self) -> Option<&TypeStatistics> {
+        self.type_statistics.as_ref()
+    }
+}
+
+/// Type-specific statistics of an ORC column
+///
+/// Minimum and maximum values are only recorded if the column contains non-null values
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeStatistics {
+    /// Statistics of integer columns
+    Integer {
+        /// The minimum value
+        min: i64,
+        /// The maximum value
+        max: i64,
+        /// The sum of all values, `None` if it overflowed
+        sum: Option<i64>,
+    },
+    /// Statistics of floating point columns
+    Double {
+        /// The minimum value
+        min: f64,
+        /// The maximum value
+        max: f64,
+        /// The sum of all values
+        sum: Option<f64>,
+    },
+    /// Statistics of string columns
+    String {
+        /// The minimum value, or a lower bound if the minimum was truncated
+        min: String,
+        /// The maximum value, or an upper bound if the maximum was truncated
+        max: String,
+        /// The total length of all values
+        sum: Option<i64>,
+    },
+    /// Statistics of boolean columns
+    Boolean {
+        /// The number of `true` values
+        true_count: u64,
+    },
+    /// Statistics of decimal columns, formatted as strings
+    Decimal {
+        /// The minimum value
+        min: String,
+        /// The maximum value
+        max: String,
+        /// The sum of all values
+        sum: Option<String>,
+    },
+    /// Statistics of date columns, as days since the UNIX epoch
+    Date {
+        /// The minimum value
+        min: i32,
+        /// The maximum value
+        max: i32,
+    },
+    /// Statistics of binary columns
+    Binary {
+        /// The total length of all values
+        sum: Option<i64>,
+    },
+    /// Statistics of timestamp columns, as milliseconds since the UNIX epoch in UTC
+    Timestamp {
+        /// The minimum value
+        min: i64,
+        /// The maximum value
+        max: i64,
+    },
+}
+
+impl From<&proto::ColumnStatistics> for ColumnStatistics {
+    fn from(value: &proto::ColumnStatistics) -> Self {
+        Self {
+            number_of_values: value.number_of_values(),
+            has_null: value.has_null(),
+            type_statistics: type_statistics(value),
+        }
+    }
+}
+
+fn type_statistics(value: &proto::ColumnStatistics) -> Option<TypeStatistics> {
+    if let Some(s) = &value.int_statistics {
+        return Some(TypeStatistics::Integer {
+            min: s.minimum?,
+            max: s.maximum?,
+            sum: s.sum,
+        });
+    }
+    if let Some(s) = &value.double_statistics {
+        return Some(TypeStatistics::Double {
+            min: s.minimum?,
+            max: s.maximum?,
+            sum: s.sum,
+        });
+    }
+    if let Some(s) = &value.string_statistics {
+        return Some(TypeStatistics::String {
+            min: s.minimum.clone().or_else(|| s.lower_bound.clone())?,
+            max: s.maximum.clone().or_else(|| s.upper_bound.clone())?,
+            sum: s.sum,
+        });
+    }
+    if let Some(s) = &value.bucket_statistics {
+        return Some(TypeStatistics::Boolean {
+            true_count: *s.count.first()?,
+        });
+    }
+    if let Some(s) = &value.decimal_statistics {
+        return Some(TypeStatistics::Decimal {
+            min: s.minimum.clone()?,
+            max: s.maximum.clone()?,
+            sum: s.sum.clone(),
+        });
+    }
+    if let Some(s) = &value.date_statistics {
+        return Some(TypeStatistics::Date {
+            min: s.minimum?,
+            max: s.maximum?,
+        });
+    }
+    if let Some(s) = &value.binary_statistics {
+        return Some(TypeStatistics::Binary { sum: s.sum });
+    }
+    if let Some(s) = &value.timestamp_statistics {
+        return Some(TypeStatistics::Timestamp {
+            min: s.minimum_utc.or(s.minimum)?,
+            max: s.maximum_utc.or(s.maximum)?,
+        });
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_statistics() {
+        let stats = proto::ColumnStatistics {
+            number_of_values: Some(10),
+            has_null: Some(true),
+            int_statistics: Some(proto::IntegerStatistics {
+                minimum: Some(-3),
+                maximum: Some(7),
+                sum: None,
+            }),
+            ..Default::default()
+        };
+        let stats = ColumnStatistics::from(&stats);
+        assert_eq!(stats.number_of_values(), 10);
+        assert!(stats.has_null());
+        assert_eq!(
+            stats.type_statistics(),
+            Some(&TypeStatistics::Integer {
+                min: -3,
+                max: 7,
+                sum: None
+            })
+        );
+
+        // All null column has no minimum or maximum
+        let stats = proto::ColumnStatistics {
+            number_of_values: Some(0),
+            has_null: Some(true),
+            string_statistics: Some(Default::default()),
+            ..Default::default()
+        };
+        let stats = ColumnStatistics::from(&stats);
+        assert_eq!(stats.type_statistics(), None);
+    }
+}
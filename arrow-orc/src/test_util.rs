@@ -0,0 +1,238 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A minimal ORC writer used to generate test files
+
+use crate::proto;
+use crate::proto::r#type::Kind;
+use crate::proto::stream::Kind as StreamKind;
+use crate::proto::CompressionKind;
+use bytes::Bytes;
+use prost::Message;
+use std::io::Write;
+
+fn write_varint(mut v: u64, out: &mut Vec<u8>) {
+    while v >= 0x80 {
+        out.push(v as u8 | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+/// Encodes `values` as integer run length encoding v1 literals
+fn int_rle_v1(values: &[i64], signed: bool) -> Vec<u8> {
+    let mut out = vec![];
+    for chunk in values.chunks(128) {
+        out.push(-(chunk.len() as i32) as u8);
+        for v in chunk {
+            let v = match signed {
+                true => ((v << 1) ^ (v >> 63)) as u64,
+                false => *v as u64,
+            };
+            write_varint(v, &mut out);
+        }
+    }
+    out
+}
+
+/// Encodes `values` as byte run length encoding literals
+fn byte_rle(values: &[u8]) -> Vec<u8> {
+    let mut out = vec![];
+    for chunk in values.chunks(128) {
+        out.push(-(chunk.len() as i32) as u8);
+        out.extend_from_slice(chunk);
+    }
+    out
+}
+
+fn boolean_rle(values: &[bool]) -> Vec<u8> {
+    let mut bytes = vec![0; (values.len() + 7) / 8];
+    for (idx, v) in values.iter().enumerate() {
+        if *v {
+            bytes[idx / 8] |= 0x80 >> (idx % 8);
+        }
+    }
+    byte_rle(&bytes)
+}
+
+fn compress(compression: CompressionKind, data: &[u8]) -> Vec<u8> {
+    match compression {
+        CompressionKind::None => data.to_vec(),
+        CompressionKind::Zlib => {
+            let mut encoder = flate2::write::DeflateEncoder::new(
+                Vec::new(),
+                flate2::Compression::default(),
+            );
+            encoder.write_all(data).unwrap();
+            let compressed = encoder.finish().unwrap();
+            let header = compressed.len() << 1;
+            let mut out = header.to_le_bytes()[..3].to_vec();
+            out.extend_from_slice(&compressed);
+            out
+        }
+        _ => unimplemented!(),
+    }
+}
+
+fn ty(kind: Kind, subtypes: Vec<u32>, field_names: Vec<&str>) -> proto::Type {
+    let mut ty = proto::Type {
+        subtypes,
+        field_names: field_names.into_iter().map(ToString::to_string).collect(),
+        ..Default::default()
+    };
+    ty.set_kind(kind);
+    ty
+}
+
+fn int_statistics(values: &[i64]) -> proto::ColumnStatistics {
+    proto::ColumnStatistics {
+        number_of_values: Some(values.len() as u64),
+        has_null: Some(false),
+        int_statistics: Some(proto::IntegerStatistics {
+            minimum: values.iter().min().copied(),
+            maximum: values.iter().max().copied(),
+            sum: Some(values.iter().sum()),
+        }),
+        ..Default::default()
+    }
+}
+
+/// The contents of a stripe of the test file
+struct TestStripe {
+    ids: Vec<i64>,
+    names: Vec<Option<&'static str>>,
+    tags: Vec<Option<Vec<i64>>>,
+}
+
+impl TestStripe {
+    fn streams(&self) -> Vec<(u32, StreamKind, Vec<u8>)> {
+        let present: Vec<_> = self.names.iter().map(Option::is_some).collect();
+        let names: Vec<_> = self.names.iter().flatten().collect();
+        let name_data = names.iter().flat_map(|n| n.bytes()).collect::<Vec<_>>();
+        let name_lengths: Vec<_> = names.iter().map(|n| n.len() as i64).collect();
+
+        let tags_present: Vec<_> = self.tags.iter().map(Option::is_some).collect();
+        let tags: Vec<_> = self.tags.iter().flatten().collect();
+        let tag_lengths: Vec<_> = tags.iter().map(|t| t.len() as i64).collect();
+        let tag_values: Vec<_> = tags.iter().flat_map(|t| t.iter().copied()).collect();
+
+        vec![
+            (1, StreamKind::Data, int_rle_v1(&self.ids, true)),
+            (2, StreamKind::Present, boolean_rle(&present)),
+            (2, StreamKind::Data, name_data),
+            (2, StreamKind::Length, int_rle_v1(&name_lengths, false)),
+            (3, StreamKind::Present, boolean_rle(&tags_present)),
+            (3, StreamKind::Length, int_rle_v1(&tag_lengths, false)),
+            (4, StreamKind::Data, int_rle_v1(&tag_values, true)),
+        ]
+    }
+}
+
+/// Writes an ORC file with the schema `struct<id:bigint,name:string,tags:array<int>>`
+/// containing 5 rows across 2 stripes
+pub(crate) fn write_test_file(compression: CompressionKind) -> Bytes {
+    let stripes = [
+        TestStripe {
+            ids: vec![1, 2, 3],
+            names: vec![Some("a"), None, Some("c")],
+            tags: vec![Some(vec![1, 2]), None, Some(vec![])],
+        },
+        TestStripe {
+            ids: vec![4, 5],
+            names: vec![Some("d"), Some("e")],
+            tags: vec![Some(vec![3]), Some(vec![4, 5])],
+        },
+    ];
+
+    let mut out = b"ORC".to_vec();
+    let mut stripe_infos = vec![];
+    let mut stripe_stats = vec![];
+    for stripe in &stripes {
+        let offset = out.len() as u64;
+        let mut streams = vec![];
+        for (column, kind, data) in stripe.streams() {
+            let data = compress(compression, &data);
+            let mut stream = proto::Stream {
+                column: Some(column),
+                length: Some(data.len() as u64),
+                ..Default::default()
+            };
+            stream.set_kind(kind);
+            streams.push(stream);
+            out.extend_from_slice(&data);
+        }
+        let data_length = out.len() as u64 - offset;
+
+        let mut encoding = proto::ColumnEncoding::default();
+        encoding.set_kind(proto::column_encoding::Kind::Direct);
+        let footer = proto::StripeFooter {
+            streams,
+            columns: vec![encoding; 5],
+            writer_timezone: None,
+        };
+        let footer = compress(compression, &footer.encode_to_vec());
+        out.extend_from_slice(&footer);
+
+        stripe_infos.push(proto::StripeInformation {
+            offset: Some(offset),
+            index_length: Some(0),
+            data_length: Some(data_length),
+            footer_length: Some(footer.len() as u64),
+            number_of_rows: Some(stripe.ids.len() as u64),
+        });
+        stripe_stats.push(proto::StripeStatistics {
+            col_stats: vec![Default::default(), int_statistics(&stripe.ids)],
+        });
+    }
+
+    let metadata = proto::Metadata { stripe_stats };
+    let metadata = compress(compression, &metadata.encode_to_vec());
+    out.extend_from_slice(&metadata);
+
+    let all_ids: Vec<_> = stripes.iter().flat_map(|s| s.ids.iter().copied()).collect();
+    let footer = proto::Footer {
+        header_length: Some(3),
+        content_length: Some(out.len() as u64 - metadata.len() as u64 - 3),
+        stripes: stripe_infos,
+        types: vec![
+            ty(Kind::Struct, vec![1, 2, 3], vec!["id", "name", "tags"]),
+            ty(Kind::Long, vec![], vec![]),
+            ty(Kind::String, vec![], vec![]),
+            ty(Kind::List, vec![4], vec![]),
+            ty(Kind::Int, vec![], vec![]),
+        ],
+        number_of_rows: Some(all_ids.len() as u64),
+        statistics: vec![Default::default(), int_statistics(&all_ids)],
+        ..Default::default()
+    };
+    let footer = compress(compression, &footer.encode_to_vec());
+    out.extend_from_slice(&footer);
+
+    let mut postscript = proto::PostScript {
+        footer_length: Some(footer.len() as u64),
+        compression_block_size: Some(256 * 1024),
+        version: vec![0, 12],
+        metadata_length: Some(metadata.len() as u64),
+        magic: Some("ORC".to_string()),
+        ..Default::default()
+    };
+    postscript.set_compression(compression);
+    let postscript = postscript.encode_to_vec();
+    out.extend_from_slice(&postscript);
+    out.push(postscript.len() as u8);
+    out.into()
+}
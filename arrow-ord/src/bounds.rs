@@ -0,0 +1,233 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Truncated lower and upper bounds for string and binary values
+//!
+//! Statistics, such as those written to Parquet files or used by data skipping
+//! indexes, often limit the size of the minimum and maximum values they store.
+//! Simply truncating the maximum value would produce a value smaller than the
+//! maximum, instead the kernels in this module compute bounds of at most
+//! `max_len` bytes that are guaranteed to be less than or equal to, or greater
+//! than or equal to, the original values in byte-wise lexicographical order.
+//!
+//! ```
+//! # use arrow_array::StringArray;
+//! # use arrow_ord::bounds::string_bounds;
+//! let array = StringArray::from(vec![Some("apple"), None, Some("banana")]);
+//! let bounds = string_bounds(&array, 3).unwrap();
+//! assert_eq!(bounds.lower, "app");
+//! assert_eq!(bounds.upper.as_deref(), Some("bao"));
+//! ```
+
+use arrow_array::{GenericBinaryArray, GenericStringArray, OffsetSizeTrait};
+
+/// Lower and upper bounds of a set of values
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bounds<T> {
+    /// A value less than or equal to all the values
+    pub lower: T,
+    /// A value greater than or equal to all the values, or `None` if no
+    /// such value fits within the maximum length
+    pub upper: Option<T>,
+}
+
+/// Returns the longest prefix of `value` of at most `max_len` bytes, which is
+/// a lower bound for `value`
+pub fn lower_bound_binary(value: &[u8], max_len: usize) -> &[u8] {
+    &value[..value.len().min(max_len)]
+}
+
+/// Returns the smallest value of at most `max_len` bytes that is greater than
+/// or equal to `value`, if any
+///
+/// If `value` is longer than `max_len` it is truncated and its last byte
+/// incremented, dropping any trailing `0xFF` bytes that cannot be incremented.
+/// Returns `None` if all of the first `max_len` bytes are `0xFF`.
+pub fn upper_bound_binary(value: &[u8], max_len: usize) -> Option<Vec<u8>> {
+    if value.len() <= max_len {
+        return Some(value.to_vec());
+    }
+
+    let mut truncated = value[..max_len].to_vec();
+    while let Some(last) = truncated.pop() {
+        if last != u8::MAX {
+            truncated.push(last + 1);
+            return Some(truncated);
+        }
+    }
+    None
+}
+
+/// Returns the longest prefix of `value` of at most `max_len` bytes, which is
+/// a lower bound for `value`
+///
+/// The prefix ends on a character boundary, and so may be shorter than
+/// `max_len` even if `value` is longer.
+pub fn lower_bound_string(value: &str, max_len: usize) -> &str {
+    &value[..floor_char_boundary(value, max_len)]
+}
+
+/// Returns the smallest string of at most `max_len` bytes that is greater than
+/// or equal to `value`, if any
+///
+/// If `value` is longer than `max_len` it is truncated on a character
+/// boundary and its last character incremented to the next valid character.
+/// Trailing characters that cannot be incremented, either because they are
+/// [`char::MAX`] or their successor would exceed `max_len`, are dropped.
+/// Returns `None` if no character can be incremented.
+pub fn upper_bound_string(value: &str, max_len: usize) -> Option<String> {
+    if value.len() <= max_len {
+        return Some(value.to_string());
+    }
+
+    let mut truncated = value[..floor_char_boundary(value, max_len)].to_string();
+    while let Some(last) = truncated.pop() {
+        if let Some(next) = next_char(last) {
+            if truncated.len() + next.len_utf8() <= max_len {
+                truncated.push(next);
+                return Some(truncated);
+            }
+        }
+    }
+    None
+}
+
+/// Returns [`Bounds`] of at most `max_len` bytes for the non-null values of
+/// `array`, or `None` if all the values are null
+///
+/// See [`lower_bound_binary`] and [`upper_bound_binary`]
+pub fn binary_bounds<O: OffsetSizeTrait>(
+    array: &GenericBinaryArray<O>,
+    max_len: usize,
+) -> Option<Bounds<Vec<u8>>> {
+    let (min, max) = min_max(array.iter().flatten())?;
+    Some(Bounds {
+        lower: lower_bound_binary(min, max_len).to_vec(),
+        upper: upper_bound_binary(max, max_len),
+    })
+}
+
+/// Returns [`Bounds`] of at most `max_len` bytes for the non-null values of
+/// `array`, or `None` if all the values are null
+///
+/// See [`lower_bound_string`] and [`upper_bound_string`]
+pub fn string_bounds<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    max_len: usize,
+) -> Option<Bounds<String>> {
+    // UTF-8 preserves the ordering of code points, and so comparing the
+    // strings is equivalent to comparing their bytes
+    let (min, max) = min_max(array.iter().flatten())?;
+    Some(Bounds {
+        lower: lower_bound_string(min, max_len).to_string(),
+        upper: upper_bound_string(max, max_len),
+    })
+}
+
+/// Returns the minimum and maximum of `iter`, or `None` if it is empty
+fn min_max<T: Ord + Copy>(mut iter: impl Iterator<Item = T>) -> Option<(T, T)> {
+    let first = iter.next()?;
+    Some(iter.fold((first, first), |(min, max), v| (min.min(v), max.max(v))))
+}
+
+/// Returns the largest character boundary in `value` less than or equal to `idx`
+fn floor_char_boundary(value: &str, idx: usize) -> usize {
+    if idx >= value.len() {
+        return value.len();
+    }
+    // A character is at most 4 bytes, so there is a boundary within 3 bytes
+    (idx.saturating_sub(3)..=idx)
+        .rev()
+        .find(|i| value.is_char_boundary(*i))
+        .unwrap_or(0)
+}
+
+/// Returns the next valid character after `c`, skipping the surrogate range
+fn next_char(c: char) -> Option<char> {
+    match c {
+        // U+D7FF is followed by the surrogates U+D800 to U+DFFF
+        '\u{D7FF}' => Some('\u{E000}'),
+        c => char::from_u32(c as u32 + 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{BinaryArray, LargeStringArray};
+
+    #[test]
+    fn test_binary_bounds() {
+        assert_eq!(lower_bound_binary(b"abc", 2), b"ab");
+        assert_eq!(lower_bound_binary(b"abc", 5), b"abc");
+
+        assert_eq!(upper_bound_binary(b"abc", 3).unwrap(), b"abc");
+        assert_eq!(upper_bound_binary(b"abc", 2).unwrap(), b"ac");
+        assert_eq!(upper_bound_binary(b"a\xFF\xFFz", 3).unwrap(), b"b");
+        assert_eq!(upper_bound_binary(b"\xFF\xFF\x01", 2), None);
+        assert_eq!(upper_bound_binary(b"abc", 0), None);
+        assert_eq!(upper_bound_binary(b"", 0).unwrap(), b"");
+    }
+
+    #[test]
+    fn test_string_bounds() {
+        assert_eq!(lower_bound_string("abc", 2), "ab");
+        assert_eq!(lower_bound_string("abc", 5), "abc");
+        // "é" is 2 bytes, so cannot be split
+        assert_eq!(lower_bound_string("aéb", 2), "a");
+        assert_eq!(lower_bound_string("aéb", 3), "aé");
+
+        assert_eq!(upper_bound_string("abc", 3).unwrap(), "abc");
+        assert_eq!(upper_bound_string("abc", 2).unwrap(), "ac");
+        assert_eq!(upper_bound_string("aéb", 2).unwrap(), "b");
+        assert_eq!(upper_bound_string("aéb", 3).unwrap(), "aê");
+        assert_eq!(upper_bound_string("abc", 0), None);
+
+        // the successor of U+007F requires 2 bytes, and so is dropped
+        assert_eq!(upper_bound_string("a\u{7F}b", 2).unwrap(), "b");
+        assert_eq!(upper_bound_string("a\u{7F}é", 3).unwrap(), "a\u{80}");
+
+        // U+10FFFF cannot be incremented
+        assert_eq!(upper_bound_string("a\u{10FFFF}b", 5).unwrap(), "b");
+        assert_eq!(upper_bound_string("\u{10FFFF}b", 4), None);
+
+        // surrogates are skipped
+        assert_eq!(upper_bound_string("\u{D7FF}b", 3).unwrap(), "\u{E000}");
+    }
+
+    #[test]
+    fn test_array_bounds() {
+        let array = BinaryArray::from_opt_vec(vec![
+            Some(b"b\xFF\xFF"),
+            None,
+            Some(b"abc"),
+            Some(b"b\xFF"),
+        ]);
+        let bounds = binary_bounds(&array, 2).unwrap();
+        assert_eq!(bounds.lower, b"ab");
+        assert_eq!(bounds.upper.unwrap(), b"c");
+
+        let array = LargeStringArray::from(vec![Some("zzz"), Some("é"), Some("a")]);
+        let bounds = string_bounds(&array, 1).unwrap();
+        assert_eq!(bounds.lower, "a");
+        // "é" is the maximum but no upper bound fits within a single byte
+        assert_eq!(bounds.upper, None);
+
+        let array = LargeStringArray::from(vec![None::<&str>, None]);
+        assert_eq!(string_bounds(&array, 1), None);
+    }
+}
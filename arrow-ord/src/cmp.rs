@@ -0,0 +1,709 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Comparison kernels for [`Datum`], accepting either an [`Array`] or a [`Scalar`]
+//! on either side of the comparison
+//!
+//! ```
+//! # use arrow_array::{BooleanArray, Int32Array, StringArray};
+//! # use arrow_ord::cmp::{eq, lt};
+//! let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+//!
+//! // Compare an array with a scalar
+//! let r = lt(&a, &Int32Array::new_scalar(2)).unwrap();
+//! assert_eq!(r, BooleanArray::from(vec![Some(true), None, Some(false)]));
+//!
+//! // The scalar may be on either side
+//! let s = StringArray::from(vec!["a", "b"]);
+//! let r = eq(&StringArray::new_scalar("b"), &s).unwrap();
+//! assert_eq!(r, BooleanArray::from(vec![false, true]));
+//! ```
+//!
+//! Dictionary encoded arrays are compared by their logical values, and may be compared
+//! with non-dictionary arrays or scalars of their value type.
+//!
+//! For floating values like f32 and f64, these comparisons produce an ordering in accordance
+//! to the totalOrder predicate as defined in the IEEE 754 (2008 revision) floating point
+//! standard. Note that totalOrder treats positive and negative zeros as different.
+//!
+//! [`distinct`] and [`not_distinct`] implement SQL `IS DISTINCT FROM` semantics, treating
+//! null as a value, and additionally support nested types such as lists and structs
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{BooleanBuffer, NullBuffer};
+use arrow_row::{Row, RowConverter, Rows, SortField};
+use arrow_schema::{ArrowError, DataType};
+use arrow_select::take::take;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Copy, Clone)]
+enum Op {
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    Distinct,
+    NotDistinct,
+}
+
+impl Display for Op {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Op::Equal => write!(f, "=="),
+            Op::NotEqual => write!(f, "!="),
+            Op::Less => write!(f, "<"),
+            Op::LessEqual => write!(f, "<="),
+            Op::Greater => write!(f, ">"),
+            Op::GreaterEqual => write!(f, ">="),
+            Op::Distinct => write!(f, "IS DISTINCT FROM"),
+            Op::NotDistinct => write!(f, "IS NOT DISTINCT FROM"),
+        }
+    }
+}
+
+/// Perform `left == right` operation on two [`Datum`]
+pub fn eq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
+    compare_op(Op::Equal, lhs, rhs)
+}
+
+/// Perform `left != right` operation on two [`Datum`]
+pub fn neq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
+    compare_op(Op::NotEqual, lhs, rhs)
+}
+
+/// Perform `left < right` operation on two [`Datum`]
+pub fn lt(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
+    compare_op(Op::Less, lhs, rhs)
+}
+
+/// Perform `left <= right` operation on two [`Datum`]
+pub fn lt_eq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
+    compare_op(Op::LessEqual, lhs, rhs)
+}
+
+/// Perform `left > right` operation on two [`Datum`]
+pub fn gt(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
+    compare_op(Op::Greater, lhs, rhs)
+}
+
+/// Perform `left >= right` operation on two [`Datum`]
+pub fn gt_eq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
+    compare_op(Op::GreaterEqual, lhs, rhs)
+}
+
+/// Perform `left IS DISTINCT FROM right` operation on two [`Datum`]
+///
+/// Unlike [`neq`], null is compared as a value: two nulls are not distinct, and null
+/// is distinct from any non-null value. The result therefore contains no nulls
+///
+/// ```
+/// # use arrow_array::{BooleanArray, Int32Array};
+/// # use arrow_ord::cmp::distinct;
+/// let a = Int32Array::from(vec![Some(1), None, None, Some(4)]);
+/// let b = Int32Array::from(vec![Some(1), None, Some(3), Some(5)]);
+/// let r = distinct(&a, &b).unwrap();
+/// assert_eq!(r, BooleanArray::from(vec![false, false, true, true]));
+/// ```
+pub fn distinct(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
+    compare_op(Op::Distinct, lhs, rhs)
+}
+
+/// Perform `left IS NOT DISTINCT FROM right` operation on two [`Datum`]
+///
+/// Unlike [`eq`], null is compared as a value: two nulls are not distinct, and null
+/// is distinct from any non-null value. The result therefore contains no nulls
+pub fn not_distinct(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+) -> Result<BooleanArray, ArrowError> {
+    compare_op(Op::NotDistinct, lhs, rhs)
+}
+
+/// Returns the logical values of `array`, materializing dictionaries
+fn logical_values(array: &dyn Array) -> Result<Option<ArrayRef>, ArrowError> {
+    Ok(match array.data_type() {
+        DataType::Dictionary(_, _) => downcast_dictionary_array! {
+            array => Some(take(array.values().as_ref(), array.keys(), None)?),
+            t => return Err(ArrowError::NotYetImplemented(format!(
+                "Comparison of dictionary type {t} is not supported"
+            ))),
+        },
+        _ => None,
+    })
+}
+
+fn compare_op(
+    op: Op,
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+) -> Result<BooleanArray, ArrowError> {
+    let (l, l_s) = lhs.get();
+    let (r, r_s) = rhs.get();
+
+    let l_len = l.len();
+    let r_len = r.len();
+    if l_len != r_len && !l_s && !r_s {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot compare arrays of different lengths, got {l_len} vs {r_len}"
+        )));
+    }
+    let len = match l_s {
+        true => r_len,
+        false => l_len,
+    };
+
+    let l_values = logical_values(l)?;
+    let l = l_values.as_deref().unwrap_or(l);
+    let r_values = logical_values(r)?;
+    let r = r_values.as_deref().unwrap_or(r);
+
+    let l_t = l.data_type();
+    let r_t = r.data_type();
+    if l_t != r_t {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid comparison operation: {l_t} {op} {r_t}"
+        )));
+    }
+
+    let distinct = matches!(op, Op::Distinct | Op::NotDistinct);
+    let nulls = match (l_s, r_s) {
+        // Null is compared as a value, and so the result contains no nulls
+        _ if distinct => None,
+        (true, true) | (false, false) => NullBuffer::union(l.nulls(), r.nulls()),
+        (true, false) if l.null_count() == 0 => r.nulls().cloned(),
+        (false, true) if r.null_count() == 0 => l.nulls().cloned(),
+        // A null scalar yields a null result
+        _ => return Ok(BooleanArray::new_null(len)),
+    };
+
+    let values = downcast_primitive_array! {
+        (l, r) => apply(op, l, l_s, r, r_s, len),
+        (DataType::Boolean, DataType::Boolean) => {
+            apply(op, l.as_boolean(), l_s, r.as_boolean(), r_s, len)
+        },
+        (DataType::Utf8, DataType::Utf8) => {
+            apply(op, l.as_string::<i32>(), l_s, r.as_string::<i32>(), r_s, len)
+        },
+        (DataType::LargeUtf8, DataType::LargeUtf8) => {
+            apply(op, l.as_string::<i64>(), l_s, r.as_string::<i64>(), r_s, len)
+        },
+        (DataType::Binary, DataType::Binary) => {
+            apply(op, l.as_binary::<i32>(), l_s, r.as_binary::<i32>(), r_s, len)
+        },
+        (DataType::LargeBinary, DataType::LargeBinary) => {
+            apply(op, l.as_binary::<i64>(), l_s, r.as_binary::<i64>(), r_s, len)
+        },
+        (DataType::FixedSizeBinary(_), DataType::FixedSizeBinary(_)) => {
+            let l = l.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+            let r = r.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+            apply(op, l, l_s, r, r_s, len)
+        },
+        (t, _) => match t {
+            // All values are null, and so not distinct
+            DataType::Null if distinct => {
+                BooleanBuffer::collect_bool(len, |_| matches!(op, Op::NotDistinct))
+            }
+            _ if distinct && t.is_nested() => apply_nested(op, l, l_s, r, r_s, len)?,
+            _ => return Err(ArrowError::NotYetImplemented(format!(
+                "Comparison of {t} is not supported"
+            ))),
+        },
+    };
+
+    let values = match distinct {
+        true => distinct_values(op, values, l, l_s, r, r_s, len),
+        false => values,
+    };
+    Ok(BooleanArray::new(values, nulls))
+}
+
+/// Returns the validity of `array` as a [`BooleanBuffer`] of length `len`, broadcasting
+/// it if `array` is a scalar, or `None` if it contains no nulls
+fn validity(array: &dyn Array, scalar: bool, len: usize) -> Option<BooleanBuffer> {
+    let nulls = array.nulls().filter(|n| n.null_count() > 0)?;
+    Some(match scalar {
+        true => BooleanBuffer::collect_bool(len, |_| nulls.is_valid(0)),
+        false => nulls.inner().clone(),
+    })
+}
+
+/// Combines the result of comparing the values of `l` and `r` with their validity,
+/// where `values` is the result of [`Op::NotEqual`] for [`Op::Distinct`], and of
+/// [`Op::Equal`] for [`Op::NotDistinct`]
+fn distinct_values(
+    op: Op,
+    values: BooleanBuffer,
+    l: &dyn Array,
+    l_s: bool,
+    r: &dyn Array,
+    r_s: bool,
+    len: usize,
+) -> BooleanBuffer {
+    let (l_v, r_v) = match (validity(l, l_s, len), validity(r, r_s, len)) {
+        (None, None) => return values,
+        (l_v, r_v) => {
+            let all_valid = || BooleanBuffer::new_set(len);
+            (l_v.unwrap_or_else(all_valid), r_v.unwrap_or_else(all_valid))
+        }
+    };
+    let both_valid = &(&l_v & &r_v) & &values;
+    match op {
+        // Exactly one side is null, or neither is and the values differ
+        Op::Distinct => &both_valid | &(&l_v ^ &r_v),
+        // Both sides are null, or neither is and the values are equal
+        _ => &both_valid | &!&(&l_v | &r_v),
+    }
+}
+
+/// Applies `op` to arrays of a nested type, comparing their values using the
+/// [row format](arrow_row)
+fn apply_nested(
+    op: Op,
+    l: &dyn Array,
+    l_s: bool,
+    r: &dyn Array,
+    r_s: bool,
+    len: usize,
+) -> Result<BooleanBuffer, ArrowError> {
+    let fields = vec![SortField::new(l.data_type().clone())];
+    if !RowConverter::supports_fields(&fields) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "Comparison of {} is not supported",
+            l.data_type()
+        )));
+    }
+    let mut converter = RowConverter::new(fields)?;
+    let l_rows = converter.convert_columns(&[l.slice(0, l.len())])?;
+    let r_rows = converter.convert_columns(&[r.slice(0, r.len())])?;
+    Ok(apply(op, &l_rows, l_s, &r_rows, r_s, len))
+}
+
+/// Dispatches `op`, expressing each operation in terms of [`ArrayOrd::is_eq`]
+/// and [`ArrayOrd::is_lt`]
+fn apply<T: ArrayOrd>(
+    op: Op,
+    l: T,
+    l_s: bool,
+    r: T,
+    r_s: bool,
+    len: usize,
+) -> BooleanBuffer {
+    match op {
+        Op::Equal => apply_op(l, l_s, r, r_s, len, false, T::is_eq),
+        Op::NotEqual => apply_op(l, l_s, r, r_s, len, true, T::is_eq),
+        Op::Less => apply_op(l, l_s, r, r_s, len, false, T::is_lt),
+        Op::LessEqual => apply_op(r, r_s, l, l_s, len, true, T::is_lt),
+        Op::Greater => apply_op(r, r_s, l, l_s, len, false, T::is_lt),
+        Op::GreaterEqual => apply_op(l, l_s, r, r_s, len, true, T::is_lt),
+        Op::Distinct => apply_op(l, l_s, r, r_s, len, true, T::is_eq),
+        Op::NotDistinct => apply_op(l, l_s, r, r_s, len, false, T::is_eq),
+    }
+}
+
+/// Computes `op(l, r) != neg` for each of the `len` values
+///
+/// On x86_64 this is compiled for AVX2, and dispatched to at runtime if supported by the CPU
+fn apply_op<T: ArrayOrd>(
+    l: T,
+    l_s: bool,
+    r: T,
+    r_s: bool,
+    len: usize,
+    neg: bool,
+    op: impl Fn(T::Item, T::Item) -> bool,
+) -> BooleanBuffer {
+    #[cfg(target_arch = "x86_64")]
+    if std::arch::is_x86_feature_detected!("avx2") {
+        // SAFETY: verified the CPU supports AVX2
+        return unsafe { apply_op_avx2(l, l_s, r, r_s, len, neg, op) };
+    }
+    apply_op_impl(l, l_s, r, r_s, len, neg, op)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn apply_op_avx2<T: ArrayOrd>(
+    l: T,
+    l_s: bool,
+    r: T,
+    r_s: bool,
+    len: usize,
+    neg: bool,
+    op: impl Fn(T::Item, T::Item) -> bool,
+) -> BooleanBuffer {
+    apply_op_impl(l, l_s, r, r_s, len, neg, op)
+}
+
+#[inline(always)]
+fn apply_op_impl<T: ArrayOrd>(
+    l: T,
+    l_s: bool,
+    r: T,
+    r_s: bool,
+    len: usize,
+    neg: bool,
+    op: impl Fn(T::Item, T::Item) -> bool,
+) -> BooleanBuffer {
+    // SAFETY: the lengths of `l` and `r` were validated to be consistent with `len`
+    match (l_s, r_s) {
+        (true, false) => {
+            let v = unsafe { l.value_unchecked(0) };
+            BooleanBuffer::collect_bool(len, |i| unsafe {
+                op(v, r.value_unchecked(i)) != neg
+            })
+        }
+        (false, true) => {
+            let v = unsafe { r.value_unchecked(0) };
+            BooleanBuffer::collect_bool(len, |i| unsafe {
+                op(l.value_unchecked(i), v) != neg
+            })
+        }
+        _ => {
+            assert_eq!(l.len(), r.len());
+            BooleanBuffer::collect_bool(len, |i| unsafe {
+                op(l.value_unchecked(i), r.value_unchecked(i)) != neg
+            })
+        }
+    }
+}
+
+/// An array whose values can be compared with one another
+trait ArrayOrd: Copy {
+    type Item: Copy;
+
+    fn len(&self) -> usize;
+
+    unsafe fn value_unchecked(&self, idx: usize) -> Self::Item;
+
+    fn is_eq(l: Self::Item, r: Self::Item) -> bool;
+
+    fn is_lt(l: Self::Item, r: Self::Item) -> bool;
+}
+
+impl<T: ArrowPrimitiveType> ArrayOrd for &PrimitiveArray<T> {
+    type Item = T::Native;
+
+    fn len(&self) -> usize {
+        Array::len(*self)
+    }
+
+    unsafe fn value_unchecked(&self, idx: usize) -> Self::Item {
+        PrimitiveArray::value_unchecked(self, idx)
+    }
+
+    fn is_eq(l: Self::Item, r: Self::Item) -> bool {
+        l.is_eq(r)
+    }
+
+    fn is_lt(l: Self::Item, r: Self::Item) -> bool {
+        l.is_lt(r)
+    }
+}
+
+impl ArrayOrd for &BooleanArray {
+    type Item = bool;
+
+    fn len(&self) -> usize {
+        Array::len(*self)
+    }
+
+    unsafe fn value_unchecked(&self, idx: usize) -> Self::Item {
+        BooleanArray::value_unchecked(self, idx)
+    }
+
+    fn is_eq(l: Self::Item, r: Self::Item) -> bool {
+        l == r
+    }
+
+    fn is_lt(l: Self::Item, r: Self::Item) -> bool {
+        !l & r
+    }
+}
+
+impl<'a, T: ByteArrayType> ArrayOrd for &'a GenericByteArray<T> {
+    type Item = &'a [u8];
+
+    fn len(&self) -> usize {
+        Array::len(*self)
+    }
+
+    unsafe fn value_unchecked(&self, idx: usize) -> Self::Item {
+        GenericByteArray::value_unchecked(*self, idx).as_ref()
+    }
+
+    fn is_eq(l: Self::Item, r: Self::Item) -> bool {
+        l == r
+    }
+
+    fn is_lt(l: Self::Item, r: Self::Item) -> bool {
+        l < r
+    }
+}
+
+impl<'a> ArrayOrd for &'a FixedSizeBinaryArray {
+    type Item = &'a [u8];
+
+    fn len(&self) -> usize {
+        Array::len(*self)
+    }
+
+    unsafe fn value_unchecked(&self, idx: usize) -> Self::Item {
+        FixedSizeBinaryArray::value_unchecked(self, idx)
+    }
+
+    fn is_eq(l: Self::Item, r: Self::Item) -> bool {
+        l == r
+    }
+
+    fn is_lt(l: Self::Item, r: Self::Item) -> bool {
+        l < r
+    }
+}
+
+impl<'a> ArrayOrd for &'a Rows {
+    type Item = Row<'a>;
+
+    fn len(&self) -> usize {
+        self.num_rows()
+    }
+
+    unsafe fn value_unchecked(&self, idx: usize) -> Self::Item {
+        self.row(idx)
+    }
+
+    fn is_eq(l: Self::Item, r: Self::Item) -> bool {
+        l == r
+    }
+
+    fn is_lt(l: Self::Item, r: Self::Item) -> bool {
+        l < r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_schema::Field;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_primitive() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3), Some(4)]);
+        let b = Int32Array::from(vec![Some(2), Some(2), Some(3), None]);
+
+        let expected = |v: [Option<bool>; 4]| BooleanArray::from(v.to_vec());
+        assert_eq!(
+            eq(&a, &b).unwrap(),
+            expected([Some(false), None, Some(true), None])
+        );
+        assert_eq!(
+            neq(&a, &b).unwrap(),
+            expected([Some(true), None, Some(false), None])
+        );
+        assert_eq!(
+            lt(&a, &b).unwrap(),
+            expected([Some(true), None, Some(false), None])
+        );
+        assert_eq!(
+            lt_eq(&a, &b).unwrap(),
+            expected([Some(true), None, Some(true), None])
+        );
+        assert_eq!(
+            gt(&a, &b).unwrap(),
+            expected([Some(false), None, Some(false), None])
+        );
+        assert_eq!(
+            gt_eq(&a, &b).unwrap(),
+            expected([Some(false), None, Some(true), None])
+        );
+    }
+
+    #[test]
+    fn test_scalar() {
+        let a = Float64Array::from(vec![Some(1.), None, Some(3.), Some(f64::NAN)]);
+        let s = Float64Array::new_scalar(3.);
+
+        let r = lt(&a, &s).unwrap();
+        let expected = vec![Some(true), None, Some(false), Some(false)];
+        assert_eq!(r, BooleanArray::from(expected));
+
+        let r = lt(&s, &a).unwrap();
+        let expected = vec![Some(false), None, Some(false), Some(true)];
+        assert_eq!(r, BooleanArray::from(expected));
+
+        let r = eq(&s, &s).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true]));
+
+        let null = Scalar::new(Arc::new(Float64Array::new_null(1)) as ArrayRef);
+        let r = gt_eq(&a, &null).unwrap();
+        assert_eq!(r.len(), 4);
+        assert_eq!(r.null_count(), 4);
+    }
+
+    #[test]
+    fn test_bytes() {
+        let a = StringArray::from(vec![Some("arrow"), None, Some("a"), Some("")]);
+        let r = gt(&a, &StringArray::new_scalar("a")).unwrap();
+        assert_eq!(
+            r,
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(false)])
+        );
+
+        let a = BinaryArray::from_iter_values([b"ab", b"cd"]);
+        let b = BinaryArray::from_iter_values([b"ab", b"ce"]);
+        assert_eq!(lt_eq(&a, &b).unwrap(), BooleanArray::from(vec![true, true]));
+        assert_eq!(eq(&a, &b).unwrap(), BooleanArray::from(vec![true, false]));
+
+        let a = FixedSizeBinaryArray::try_from_iter([b"ab", b"cd"].into_iter()).unwrap();
+        let r = neq(&FixedSizeBinaryArray::new_scalar(b"cd"), &a).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, false]));
+    }
+
+    #[test]
+    fn test_boolean() {
+        let a = BooleanArray::from(vec![true, false, true, false]);
+        let b = BooleanArray::from(vec![true, true, false, false]);
+        let r = lt(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, true, false, false]));
+        let r = gt_eq(&a, &BooleanArray::new_scalar(true)).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, false, true, false]));
+    }
+
+    #[test]
+    fn test_dictionary() {
+        let a: DictionaryArray<Int8Type> = vec![Some("b"), None, Some("a"), Some("c")]
+            .into_iter()
+            .collect();
+        let r = eq(&a, &StringArray::new_scalar("a")).unwrap();
+        assert_eq!(
+            r,
+            BooleanArray::from(vec![Some(false), None, Some(true), Some(false)])
+        );
+
+        let b = StringArray::from(vec!["b", "b", "b", "b"]);
+        let r = lt(&b, &a).unwrap();
+        assert_eq!(
+            r,
+            BooleanArray::from(vec![Some(false), None, Some(false), Some(true)])
+        );
+    }
+
+    #[test]
+    fn test_distinct() {
+        let a = Int32Array::from(vec![Some(1), None, None, Some(4), Some(5)]);
+        let b = Int32Array::from(vec![Some(1), None, Some(3), None, Some(6)]);
+        let r = distinct(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, false, true, true, true]));
+        let r = not_distinct(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, true, false, false, false]));
+
+        // No nulls
+        let c = Int32Array::from(vec![1, 2, 3, 4, 6]);
+        let r = distinct(&b.slice(4, 1), &c.slice(4, 1)).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false]));
+
+        // Scalars
+        let r = distinct(&a, &Int32Array::new_scalar(4)).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, true, true, false, true]));
+
+        let null = Scalar::new(Arc::new(Int32Array::new_null(1)) as ArrayRef);
+        let r = not_distinct(&null, &a).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, true, true, false, false]));
+        let r = distinct(&null, &null).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false]));
+
+        // NaN is not distinct from NaN
+        let a = Float64Array::from(vec![Some(f64::NAN), None, Some(1.)]);
+        let b = Float64Array::from(vec![Some(f64::NAN), Some(f64::NAN), Some(1.)]);
+        let r = distinct(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, true, false]));
+
+        // Dictionaries are compared by their logical values
+        let a: DictionaryArray<Int8Type> =
+            vec![Some("b"), None, Some("a")].into_iter().collect();
+        let b = StringArray::from(vec![Some("b"), None, None]);
+        let r = not_distinct(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, true, false]));
+
+        let a = NullArray::new(2);
+        let r = not_distinct(&a, &a).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, true]));
+    }
+
+    #[test]
+    fn test_distinct_nested() {
+        let a = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(2)]),
+            None,
+        ]);
+        let b = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            None,
+            Some(vec![Some(3)]),
+            Some(vec![]),
+        ]);
+        let r = distinct(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, false, true, true, true]));
+        let r = not_distinct(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, true, false, false, false]));
+
+        let s = Scalar::new(a.slice(3, 1));
+        let r = not_distinct(&a, &s).unwrap();
+        assert_eq!(
+            r,
+            BooleanArray::from(vec![false, false, false, true, false])
+        );
+
+        let a = StructArray::from(vec![(
+            Arc::new(Field::new("a", DataType::Int32, true)),
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])) as ArrayRef,
+        )]);
+        let b = StructArray::from(vec![(
+            Arc::new(Field::new("a", DataType::Int32, true)),
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(4)])) as ArrayRef,
+        )]);
+        let r = distinct(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, false, true]));
+
+        // Ordering of nested types is not supported
+        let err = lt(&a, &b).unwrap_err().to_string();
+        assert!(err.contains("Comparison of Struct"), "{err}");
+    }
+
+    #[test]
+    fn test_errors() {
+        let a = Int32Array::from(vec![1, 2]);
+        let b = Int32Array::from(vec![1]);
+        let err = eq(&a, &b).unwrap_err().to_string();
+        assert!(err.contains("Cannot compare arrays of different lengths, got 2 vs 1"));
+
+        let b = Int64Array::from(vec![1, 2]);
+        let err = lt(&a, &b).unwrap_err().to_string();
+        assert!(
+            err.contains("Invalid comparison operation: Int32 < Int64"),
+            "{err}"
+        );
+    }
+}
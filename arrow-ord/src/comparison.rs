@@ -513,6 +513,16 @@ macro_rules! dyn_compare_scalar {
                 let left = as_primitive_array::<Float64Type>($LEFT);
                 $OP::<Float64Type>(left, right)
             }
+            DataType::Decimal32(_, _) => {
+                let right = try_to_type!($RIGHT, to_i32)?;
+                let left = as_primitive_array::<Decimal32Type>($LEFT);
+                $OP::<Decimal32Type>(left, right)
+            }
+            DataType::Decimal64(_, _) => {
+                let right = try_to_type!($RIGHT, to_i64)?;
+                let left = as_primitive_array::<Decimal64Type>($LEFT);
+                $OP::<Decimal64Type>(left, right)
+            }
             DataType::Decimal128(_, _) => {
                 let right = try_to_type!($RIGHT, to_i128)?;
                 let left = as_primitive_array::<Decimal128Type>($LEFT);
@@ -1542,6 +1552,12 @@ macro_rules! typed_cmp_dict_non_dict {
                 (DataType::Float64, DataType::Float64) => {
                     typed_dict_non_dict_cmp!($LEFT, $RIGHT, left_key_type.as_ref(), Float64Type, $OP_BOOL, $OP_FLOAT)
                 }
+                (DataType::Decimal32(_, s1), DataType::Decimal32(_, s2)) if s1 == s2 => {
+                    typed_dict_non_dict_cmp!($LEFT, $RIGHT, left_key_type.as_ref(), Decimal32Type, $OP_BOOL, $OP)
+                }
+                (DataType::Decimal64(_, s1), DataType::Decimal64(_, s2)) if s1 == s2 => {
+                    typed_dict_non_dict_cmp!($LEFT, $RIGHT, left_key_type.as_ref(), Decimal64Type, $OP_BOOL, $OP)
+                }
                 (DataType::Decimal128(_, s1), DataType::Decimal128(_, s2)) if s1 == s2 => {
                     typed_dict_non_dict_cmp!($LEFT, $RIGHT, left_key_type.as_ref(), Decimal128Type, $OP_BOOL, $OP)
                 }
@@ -1642,6 +1658,12 @@ macro_rules! typed_compares {
             (DataType::Float64, DataType::Float64) => {
                 cmp_primitive_array::<Float64Type, _>($LEFT, $RIGHT, $OP_FLOAT)
             }
+            (DataType::Decimal32(_, s1), DataType::Decimal32(_, s2)) if s1 == s2 => {
+                cmp_primitive_array::<Decimal32Type, _>($LEFT, $RIGHT, $OP)
+            }
+            (DataType::Decimal64(_, s1), DataType::Decimal64(_, s2)) if s1 == s2 => {
+                cmp_primitive_array::<Decimal64Type, _>($LEFT, $RIGHT, $OP)
+            }
             (DataType::Decimal128(_, s1), DataType::Decimal128(_, s2)) if s1 == s2 => {
                 cmp_primitive_array::<Decimal128Type, _>($LEFT, $RIGHT, $OP)
             }
@@ -1796,6 +1818,12 @@ macro_rules! typed_dict_cmp {
             (DataType::Float64, DataType::Float64) => {
                 cmp_dict::<$KT, Float64Type, _>($LEFT, $RIGHT, $OP_FLOAT)
             }
+            (DataType::Decimal32(_, s1), DataType::Decimal32(_, s2)) if s1 == s2 => {
+                cmp_dict::<$KT, Decimal32Type, _>($LEFT, $RIGHT, $OP)
+            }
+            (DataType::Decimal64(_, s1), DataType::Decimal64(_, s2)) if s1 == s2 => {
+                cmp_dict::<$KT, Decimal64Type, _>($LEFT, $RIGHT, $OP)
+            }
             (DataType::Decimal128(_, s1), DataType::Decimal128(_, s2)) if s1 == s2 => {
                 cmp_dict::<$KT, Decimal128Type, _>($LEFT, $RIGHT, $OP)
             }
@@ -0,0 +1,332 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Set membership kernels, implementing SQL `IN (...)` semantics
+//!
+//! The values of the set are hashed once, and can then be probed by any number of
+//! arrays in time proportional to the length of the array, independent of the
+//! size of the set.
+
+use arrow_array::cast::AsArray;
+use arrow_array::*;
+use arrow_buffer::{BooleanBuffer, NullBuffer, ToByteSlice};
+use arrow_schema::{ArrowError, DataType};
+use arrow_select::take::take;
+use std::collections::HashSet;
+
+/// Returns whether each value of `values` is contained in `set`
+///
+/// Follows SQL `IN` semantics, the result is:
+///
+/// * null if the value is null
+/// * true if the value is equal to a value of `set`
+/// * null if the value is not equal to any value of `set`, and `set` contains null
+/// * false otherwise
+///
+/// `values` and `set` must have the same type, with dictionaries compared by their
+/// logical values. As with [`eq`](crate::cmp::eq), floating point values are equal
+/// if they have the same bit pattern.
+///
+/// To test multiple arrays against the same set, use [`InListSet`] to only build
+/// the set once.
+///
+/// ```
+/// # use arrow_array::{BooleanArray, StringArray};
+/// # use arrow_ord::in_list::in_list;
+/// let values = StringArray::from(vec![Some("a"), Some("b"), None, Some("d")]);
+/// let set = StringArray::from(vec!["d", "a"]);
+/// let r = in_list(&values, &set).unwrap();
+/// assert_eq!(r, BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]));
+/// ```
+pub fn in_list(values: &dyn Array, set: &dyn Array) -> Result<BooleanArray, ArrowError> {
+    InListSet::try_new(set)?.contains(values)
+}
+
+/// A hashed set of values, against which arrays can be tested for membership
+///
+/// See [`in_list`] for how values are compared
+///
+/// Supports primitive, boolean, string, binary, fixed size binary and dictionary arrays
+///
+/// ```
+/// # use arrow_array::{BooleanArray, Int32Array};
+/// # use arrow_ord::in_list::InListSet;
+/// let set = InListSet::try_new(&Int32Array::from(vec![Some(1), Some(3), None])).unwrap();
+/// assert_eq!(set.len(), 2);
+///
+/// let r = set.contains(&Int32Array::from(vec![1, 2, 3])).unwrap();
+/// assert_eq!(r, BooleanArray::from(vec![Some(true), None, Some(true)]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct InListSet {
+    data_type: DataType,
+    values: HashSet<Box<[u8]>>,
+    contains_null: bool,
+}
+
+impl InListSet {
+    /// Create a new [`InListSet`] containing the values of `set`
+    pub fn try_new(set: &dyn Array) -> Result<Self, ArrowError> {
+        if let DataType::Dictionary(_, _) = set.data_type() {
+            let set = downcast_dictionary_array! {
+                set => take(set.values().as_ref(), set.keys(), None)?,
+                t => return Err(ArrowError::NotYetImplemented(format!(
+                    "IN list of dictionary type {t} is not supported"
+                ))),
+            };
+            return Self::try_new(set.as_ref());
+        }
+
+        let mut values = HashSet::new();
+        let nulls = set.nulls();
+        for_each_value(set, |idx, v| {
+            if nulls.map(|n| n.is_valid(idx)).unwrap_or(true) {
+                values.insert(v.into());
+            }
+            false
+        })?;
+
+        Ok(Self {
+            data_type: set.data_type().clone(),
+            values,
+            contains_null: set.null_count() > 0,
+        })
+    }
+
+    /// Returns the number of distinct non-null values in this set
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if this set contains no non-null values
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns true if this set contains null
+    pub fn contains_null(&self) -> bool {
+        self.contains_null
+    }
+
+    /// Returns whether each value of `values` is contained in this set
+    ///
+    /// See [`in_list`] for details
+    pub fn contains(&self, values: &dyn Array) -> Result<BooleanArray, ArrowError> {
+        if let DataType::Dictionary(_, _) = values.data_type() {
+            // Probe each dictionary value once
+            return downcast_dictionary_array! {
+                values => {
+                    let found = self.contains(values.values().as_ref())?;
+                    Ok(take(&found, values.keys(), None)?.as_boolean().clone())
+                },
+                t => Err(ArrowError::NotYetImplemented(format!(
+                    "IN list of dictionary type {t} is not supported"
+                ))),
+            };
+        }
+
+        if values.data_type() != &self.data_type {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot test values of type {} for membership in a set of type {}",
+                values.data_type(),
+                self.data_type
+            )));
+        }
+        if values.data_type() == &DataType::Null {
+            return Ok(BooleanArray::new_null(values.len()));
+        }
+
+        let found = for_each_value(values, |_, v| self.values.contains(v))?;
+        let nulls = match self.contains_null {
+            // Values not found in a set containing null are null
+            true => {
+                NullBuffer::union(values.nulls(), Some(&NullBuffer::new(found.clone())))
+            }
+            false => values.nulls().cloned(),
+        };
+        Ok(BooleanArray::new(found, nulls))
+    }
+}
+
+/// Calls `f` with the index and bytes of each value of `array`, including null
+/// slots, returning the results as a [`BooleanBuffer`]
+fn for_each_value(
+    array: &dyn Array,
+    mut f: impl FnMut(usize, &[u8]) -> bool,
+) -> Result<BooleanBuffer, ArrowError> {
+    let len = array.len();
+
+    macro_rules! primitive_helper {
+        ($t:ty) => {{
+            let values = array.as_primitive::<$t>().values();
+            BooleanBuffer::collect_bool(len, |i| f(i, values[i..i + 1].to_byte_slice()))
+        }};
+    }
+
+    Ok(downcast_primitive! {
+        array.data_type() => (primitive_helper),
+        DataType::Boolean => {
+            let values = array.as_boolean().values();
+            BooleanBuffer::collect_bool(len, |i| f(i, &[values.value(i) as u8]))
+        }
+        DataType::Utf8 => {
+            let array = array.as_string::<i32>();
+            BooleanBuffer::collect_bool(len, |i| f(i, array.value(i).as_bytes()))
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_string::<i64>();
+            BooleanBuffer::collect_bool(len, |i| f(i, array.value(i).as_bytes()))
+        }
+        DataType::Binary => {
+            let array = array.as_binary::<i32>();
+            BooleanBuffer::collect_bool(len, |i| f(i, array.value(i)))
+        }
+        DataType::LargeBinary => {
+            let array = array.as_binary::<i64>();
+            BooleanBuffer::collect_bool(len, |i| f(i, array.value(i)))
+        }
+        DataType::FixedSizeBinary(_) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            BooleanBuffer::collect_bool(len, |i| f(i, array.value(i)))
+        }
+        DataType::Null => BooleanBuffer::new_unset(len),
+        t => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "IN list of type {t} is not supported"
+            )))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::StringDictionaryBuilder;
+    use arrow_array::types::Int32Type;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_in_list_primitive() {
+        let values = Int32Array::from(vec![Some(1), None, Some(3), Some(4), Some(1)]);
+        let set = Int32Array::from(vec![4, 1, 1]);
+        let r = in_list(&values, &set).unwrap();
+        let expected = vec![Some(true), None, Some(false), Some(true), Some(true)];
+        assert_eq!(r, BooleanArray::from(expected));
+
+        let set = Int32Array::from(vec![Some(4), None]);
+        let r = in_list(&values, &set).unwrap();
+        assert_eq!(
+            r,
+            BooleanArray::from(vec![None, None, None, Some(true), None])
+        );
+
+        // Floats are compared by their bit pattern
+        let values = Float64Array::from(vec![0.0, -0.0, f64::NAN, 1.5]);
+        let set = Float64Array::from(vec![f64::NAN, 0.0]);
+        let r = in_list(&values, &set).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, false, true, false]));
+
+        let values = Decimal128Array::from(vec![100, 200])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let set = Decimal128Array::from(vec![200])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let r = in_list(&values, &set).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, true]));
+    }
+
+    #[test]
+    fn test_in_list_bytes() {
+        let values = StringArray::from(vec![Some("foo"), Some("bar"), None, Some("")]);
+        let set = StringArray::from(vec!["", "foo"]);
+        let r = in_list(&values, &set).unwrap();
+        assert_eq!(
+            r,
+            BooleanArray::from(vec![Some(true), Some(false), None, Some(true)])
+        );
+
+        let values = BinaryArray::from(vec![b"ab".as_ref(), b"c"]);
+        let set = BinaryArray::from(vec![b"c".as_ref()]);
+        let r = in_list(&values, &set).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, true]));
+
+        let values = BooleanArray::from(vec![Some(true), Some(false), None]);
+        let set = BooleanArray::from(vec![false]);
+        let r = in_list(&values, &set).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![Some(false), Some(true), None]));
+    }
+
+    #[test]
+    fn test_in_list_dictionary() {
+        let mut builder = StringDictionaryBuilder::<Int32Type>::new();
+        builder.append_value("a");
+        builder.append_null();
+        builder.append_value("b");
+        builder.append_value("a");
+        let values = builder.finish();
+
+        let set = StringArray::from(vec!["a"]);
+        let r = in_list(&values, &set).unwrap();
+        let expected = vec![Some(true), None, Some(false), Some(true)];
+        assert_eq!(r, BooleanArray::from(expected));
+
+        // Unreferenced dictionary values are not part of the set
+        let keys = Int8Array::from(vec![Some(1), None]);
+        let dictionary = Arc::new(StringArray::from(vec!["a", "b"]));
+        let set = DictionaryArray::new(keys, dictionary);
+        let set = InListSet::try_new(&set).unwrap();
+        assert_eq!(set.len(), 1);
+        assert!(set.contains_null());
+
+        let r = set.contains(&values).unwrap();
+        let expected = vec![None, None, Some(true), None];
+        assert_eq!(r, BooleanArray::from(expected));
+    }
+
+    #[test]
+    fn test_in_list_empty() {
+        let values = Int64Array::from(vec![Some(1), None]);
+        let set = InListSet::try_new(&Int64Array::from(Vec::<i64>::new())).unwrap();
+        assert!(set.is_empty());
+        let r = set.contains(&values).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![Some(false), None]));
+
+        let set = InListSet::try_new(&NullArray::new(2)).unwrap();
+        let r = set.contains(&NullArray::new(1)).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![None]));
+    }
+
+    #[test]
+    fn test_in_list_errors() {
+        let values = Int32Array::from(vec![1]);
+        let set = Int64Array::from(vec![1]);
+        let err = in_list(&values, &set).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: Cannot test values of type Int32 for membership in a set of type Int64"
+        );
+
+        let values =
+            ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(1)])]);
+        let err = in_list(&values, &values).unwrap_err().to_string();
+        assert!(err.contains("IN list of type List"), "{err}");
+    }
+}
@@ -43,8 +43,17 @@
 //! ```
 //!
 
+pub mod bounds;
+pub mod cmp;
 pub mod comparison;
+pub mod in_list;
+pub mod list;
+pub mod map;
+pub mod merge;
 pub mod ord;
+#[cfg(feature = "parallel")]
+pub mod parallel;
 pub mod partition;
 pub mod rank;
+pub mod search;
 pub mod sort;
@@ -0,0 +1,268 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Membership tests on the elements of list arrays
+
+use crate::cmp::eq;
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, BooleanArray, Datum, OffsetSizeTrait, UInt64Array};
+use arrow_buffer::{BooleanBuffer, NullBuffer};
+use arrow_schema::{ArrowError, DataType};
+use arrow_select::take::take;
+use std::ops::Range;
+
+/// Returns the values of a list array, and the range of the values of each list
+fn list_ranges(array: &dyn Array) -> Result<(&ArrayRef, Vec<Range<usize>>), ArrowError> {
+    fn offsets<O: OffsetSizeTrait>(offsets: &[O]) -> Vec<Range<usize>> {
+        offsets
+            .windows(2)
+            .map(|w| w[0].as_usize()..w[1].as_usize())
+            .collect()
+    }
+    fn views<O: OffsetSizeTrait>(offsets: &[O], sizes: &[O]) -> Vec<Range<usize>> {
+        offsets
+            .iter()
+            .zip(sizes)
+            .map(|(o, s)| o.as_usize()..o.as_usize() + s.as_usize())
+            .collect()
+    }
+
+    Ok(match array.data_type() {
+        DataType::List(_) => {
+            let list = array.as_list::<i32>();
+            (list.values(), offsets(list.value_offsets()))
+        }
+        DataType::LargeList(_) => {
+            let list = array.as_list::<i64>();
+            (list.values(), offsets(list.value_offsets()))
+        }
+        DataType::ListView(_) => {
+            let list = array.as_list_view::<i32>();
+            (list.values(), views(list.offsets(), list.sizes()))
+        }
+        DataType::LargeListView(_) => {
+            let list = array.as_list_view::<i64>();
+            (list.values(), views(list.offsets(), list.sizes()))
+        }
+        DataType::FixedSizeList(_, size) => {
+            let list = array.as_fixed_size_list();
+            let size = *size as usize;
+            let ranges = (0..list.len())
+                .map(|i| list.value_offset(i) as usize)
+                .map(|offset| offset..offset + size)
+                .collect();
+            (list.values(), ranges)
+        }
+        d => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Expected a list array, got {d}"
+            )))
+        }
+    })
+}
+
+/// Returns whether each list in `array` contains `value`
+///
+/// `value` may be a [`Scalar`](arrow_array::Scalar), testing the same value for every
+/// list, or an array with the same length as `array`, testing a different value for
+/// each list. Elements are compared with [`eq`], and so must be of a type it supports.
+///
+/// The result is null where the list or the value is null, and otherwise true if
+/// any element of the list is equal to the value. Null elements are never equal to
+/// the value.
+///
+/// Supports [`DataType::List`], [`DataType::LargeList`], [`DataType::FixedSizeList`],
+/// [`DataType::ListView`] and [`DataType::LargeListView`] arrays.
+///
+/// ```
+/// # use arrow_array::{BooleanArray, Int32Array, ListArray};
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_ord::list::list_contains;
+/// let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+///     Some(vec![Some(1), Some(2)]),
+///     Some(vec![None]),
+///     None,
+/// ]);
+///
+/// let result = list_contains(&list, &Int32Array::new_scalar(2)).unwrap();
+/// assert_eq!(result, BooleanArray::from(vec![Some(true), Some(false), None]));
+///
+/// let values = Int32Array::from(vec![Some(1), None, Some(1)]);
+/// let result = list_contains(&list, &values).unwrap();
+/// assert_eq!(result, BooleanArray::from(vec![Some(true), None, None]));
+/// ```
+pub fn list_contains(
+    array: &dyn Array,
+    value: &dyn Datum,
+) -> Result<BooleanArray, ArrowError> {
+    let (values, ranges) = list_ranges(array)?;
+    let (value_array, is_scalar) = value.get();
+
+    // The ranges of null lists may not be valid, and so are replaced by empty ranges
+    let list_nulls = array.nulls().cloned();
+    let ranges: Vec<_> = match &list_nulls {
+        Some(n) => ranges
+            .into_iter()
+            .zip(n.iter())
+            .map(|(r, valid)| if valid { r } else { 0..0 })
+            .collect(),
+        None => ranges,
+    };
+
+    let (matches, ranges) = match is_scalar {
+        true => {
+            if value_array.is_null(0) {
+                return Ok(BooleanArray::new_null(array.len()));
+            }
+            (eq(values, value)?, ranges)
+        }
+        false => {
+            if value_array.len() != array.len() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Cannot test {} values against a list array of length {}",
+                    value_array.len(),
+                    array.len()
+                )));
+            }
+            // Compare each element with the value of its list
+            let elements = ranges.iter().cloned().flatten().map(|i| i as u64);
+            let rows = ranges
+                .iter()
+                .enumerate()
+                .flat_map(|(row, r)| std::iter::repeat(row as u64).take(r.len()));
+            let elements = take(values, &UInt64Array::from_iter_values(elements), None)?;
+            let rows = take(value_array, &UInt64Array::from_iter_values(rows), None)?;
+
+            let mut offset = 0;
+            let ranges = ranges
+                .iter()
+                .map(|r| {
+                    offset += r.len();
+                    offset - r.len()..offset
+                })
+                .collect();
+            (eq(&elements, &rows)?, ranges)
+        }
+    };
+
+    let matches = match matches.nulls() {
+        Some(nulls) => nulls.inner() & matches.values(),
+        None => matches.values().clone(),
+    };
+    let contains = ranges
+        .into_iter()
+        .map(|r| matches.slice(r.start, r.len()).count_set_bits() > 0);
+    let nulls = match is_scalar {
+        true => list_nulls,
+        false => NullBuffer::union(list_nulls.as_ref(), value_array.nulls()),
+    };
+    Ok(BooleanArray::new(BooleanBuffer::from_iter(contains), nulls))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{FixedSizeListBuilder, StringBuilder};
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Int32Array, LargeListViewArray, ListArray, StringArray};
+    use arrow_buffer::ScalarBuffer;
+    use arrow_schema::Field;
+    use std::sync::Arc;
+
+    fn list() -> ListArray {
+        ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2), Some(3)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4), None, Some(2)]),
+        ])
+    }
+
+    #[test]
+    fn test_list_contains_scalar() {
+        let list = list();
+        let result = list_contains(&list, &Int32Array::new_scalar(2)).unwrap();
+        let expected =
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        assert_eq!(result, expected);
+
+        let result = list_contains(&list, &Int32Array::new_scalar(4)).unwrap();
+        let expected =
+            BooleanArray::from(vec![Some(false), None, Some(false), Some(true)]);
+        assert_eq!(result, expected);
+
+        let result =
+            list_contains(&list.slice(1, 2), &Int32Array::new_scalar(1)).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![None, Some(false)]));
+
+        let null = arrow_array::Scalar::new(Int32Array::new_null(1));
+        let result = list_contains(&list, &null).unwrap();
+        assert_eq!(result.null_count(), 4);
+    }
+
+    #[test]
+    fn test_list_contains_array() {
+        let list = list();
+        let values = Int32Array::from(vec![Some(3), Some(1), Some(1), None]);
+        let result = list_contains(&list, &values).unwrap();
+        let expected = BooleanArray::from(vec![Some(true), None, Some(false), None]);
+        assert_eq!(result, expected);
+
+        let values = Int32Array::from(vec![Some(5), Some(1), Some(1), Some(2)]);
+        let result = list_contains(&list, &values).unwrap();
+        let expected =
+            BooleanArray::from(vec![Some(false), None, Some(false), Some(true)]);
+        assert_eq!(result, expected);
+
+        let err = list_contains(&list, &Int32Array::from(vec![1])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot test 1 values against a list array of length 4"
+        );
+    }
+
+    #[test]
+    fn test_list_contains_layouts() {
+        let mut builder = FixedSizeListBuilder::new(StringBuilder::new(), 2);
+        builder.values().append_value("a");
+        builder.values().append_value("b");
+        builder.append(true);
+        builder.values().append_value("c");
+        builder.values().append_null();
+        builder.append(true);
+        let fixed = builder.finish();
+        let result = list_contains(&fixed, &StringArray::new_scalar("c")).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![false, true]));
+
+        // [[3], [1, 2]]
+        let values = Arc::new(Int32Array::from(vec![1, 2, 3])) as _;
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        let offsets = ScalarBuffer::from(vec![2_i64, 0]);
+        let sizes = ScalarBuffer::from(vec![1_i64, 2]);
+        let view = LargeListViewArray::new(field, offsets, sizes, values, None);
+        let result = list_contains(&view, &Int32Array::new_scalar(3)).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![true, false]));
+        let result = list_contains(&view, &Int32Array::from(vec![1, 1])).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![false, true]));
+
+        let err = list_contains(&Int32Array::from(vec![1]), &Int32Array::new_scalar(1));
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "Invalid argument error: Expected a list array, got Int32"
+        );
+    }
+}
@@ -0,0 +1,200 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Lookup of values in a [`MapArray`] by key
+
+use crate::cmp::eq;
+use arrow_array::builder::UInt32Builder;
+use arrow_array::{Array, ArrayRef, Datum, MapArray, UInt32Array};
+use arrow_schema::ArrowError;
+use arrow_select::take::take;
+
+/// Returns the value of each map in `map` with the given `key`
+///
+/// `key` may be a [`Scalar`](arrow_array::Scalar), looking up the same key in every map,
+/// or an array with the same length as `map`, looking up a different key in each map.
+/// Keys are compared with [`eq`], and so must be of a type it supports.
+///
+/// The result has the type of the map's values, and is null where the map is null,
+/// the key is null, or the map does not contain the key. If a map contains the key
+/// more than once, the value of the first entry with the key is returned.
+///
+/// ```
+/// # use arrow_array::{Int32Array, StringArray, MapArray, UInt32Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::UInt32Type;
+/// # use arrow_ord::map::map_get;
+/// // [{"a": 1, "b": 2}, {"b": 3}, {}]
+/// let values = UInt32Array::from(vec![1, 2, 3]);
+/// let keys = ["a", "b", "b"].into_iter();
+/// let map = MapArray::new_from_strings(keys, &values, &[0, 2, 3, 3]).unwrap();
+///
+/// let result = map_get(&map, &StringArray::new_scalar("b")).unwrap();
+/// let result = result.as_primitive::<UInt32Type>();
+/// assert_eq!(result, &UInt32Array::from(vec![Some(2), Some(3), None]));
+///
+/// let keys = StringArray::from(vec![Some("a"), None, Some("a")]);
+/// let result = map_get(&map, &keys).unwrap();
+/// let result = result.as_primitive::<UInt32Type>();
+/// assert_eq!(result, &UInt32Array::from(vec![Some(1), None, None]));
+/// ```
+pub fn map_get(map: &MapArray, key: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    let (key_array, is_scalar) = key.get();
+    if !is_scalar && key_array.len() != map.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot look up {} keys in a map array of length {}",
+            key_array.len(),
+            map.len()
+        )));
+    }
+
+    let offsets = map.value_offsets();
+    let start = offsets[0] as usize;
+    let end = offsets[map.len()] as usize;
+    let keys = map.keys().slice(start, end - start);
+
+    let matches = match is_scalar {
+        true => eq(&keys, key)?,
+        false => {
+            // Repeat the key of each map for each of its entries
+            let rows = offsets.windows(2).enumerate().flat_map(|(row, w)| {
+                std::iter::repeat(row as u32).take((w[1] - w[0]) as usize)
+            });
+            let indices = UInt32Array::from_iter_values(rows);
+            eq(&keys, &take(key_array, &indices, None)?)?
+        }
+    };
+    let matches = match matches.nulls() {
+        Some(nulls) => nulls.inner() & matches.values(),
+        None => matches.values().clone(),
+    };
+
+    let mut indices = UInt32Builder::with_capacity(map.len());
+    for (row, w) in offsets.windows(2).enumerate() {
+        let found = match map.is_null(row) {
+            true => None,
+            false => {
+                let (s, e) = (w[0] as usize - start, w[1] as usize - start);
+                matches.slice(s, e - s).set_indices().next().map(|i| i + s)
+            }
+        };
+        indices.append_option(found.map(|i| (i + start) as u32));
+    }
+    take(map.values().as_ref(), &indices.finish(), None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{Int32Builder, ListBuilder, MapBuilder, StringBuilder};
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Int32Array, StringArray};
+
+    fn build_map() -> MapArray {
+        // [{"a": 1, "b": null}, null, {"b": 2, "b": 3}, {}, {"c": 4}]
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.keys().append_value("b");
+        builder.values().append_null();
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.keys().append_value("b");
+        builder.values().append_value(2);
+        builder.keys().append_value("b");
+        builder.values().append_value(3);
+        builder.append(true).unwrap();
+        builder.append(true).unwrap();
+        builder.keys().append_value("c");
+        builder.values().append_value(4);
+        builder.append(true).unwrap();
+        builder.finish()
+    }
+
+    #[test]
+    fn test_map_get_scalar() {
+        let map = build_map();
+        let get = |key: &str| {
+            let result = map_get(&map, &StringArray::new_scalar(key)).unwrap();
+            result.as_primitive::<Int32Type>().clone()
+        };
+        assert_eq!(
+            get("a"),
+            Int32Array::from(vec![Some(1), None, None, None, None])
+        );
+        assert_eq!(
+            get("b"),
+            Int32Array::from(vec![None, None, Some(2), None, None])
+        );
+        assert_eq!(
+            get("c"),
+            Int32Array::from(vec![None, None, None, None, Some(4)])
+        );
+        assert_eq!(get("z").null_count(), 5);
+
+        // Sliced maps only consider their own entries
+        let sliced = map.slice(2, 3);
+        let result = map_get(&sliced, &StringArray::new_scalar("c")).unwrap();
+        let expected = Int32Array::from(vec![None, None, Some(4)]);
+        assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+    }
+
+    #[test]
+    fn test_map_get_array() {
+        let map = build_map();
+        let keys =
+            StringArray::from(vec![Some("b"), Some("a"), Some("b"), Some("a"), None]);
+        let result = map_get(&map, &keys).unwrap();
+        let expected = Int32Array::from(vec![None, None, Some(2), None, None]);
+        assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+
+        let sliced = map.slice(2, 3);
+        let keys = StringArray::from(vec!["b", "b", "c"]);
+        let result = map_get(&sliced, &keys).unwrap();
+        let expected = Int32Array::from(vec![Some(2), None, Some(4)]);
+        assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+
+        let err = map_get(&map, &StringArray::from(vec!["a"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot look up 1 keys in a map array of length 5"
+        );
+    }
+
+    #[test]
+    fn test_map_get_nested_values() {
+        // [{"a": [1, 2]}, {"a": null, "b": [3]}]
+        let values = ListBuilder::new(Int32Builder::new());
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), values);
+        builder.keys().append_value("a");
+        builder.values().append_value([Some(1), Some(2)]);
+        builder.append(true).unwrap();
+        builder.keys().append_value("a");
+        builder.values().append_null();
+        builder.keys().append_value("b");
+        builder.values().append_value([Some(3)]);
+        builder.append(true).unwrap();
+        let map = builder.finish();
+
+        let result = map_get(&map, &StringArray::new_scalar("b")).unwrap();
+        let result = result.as_list::<i32>();
+        assert!(result.is_null(0));
+        assert_eq!(result.value(1).as_primitive::<Int32Type>().values(), &[3]);
+    }
+}
@@ -0,0 +1,433 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines a k-way merge of sorted streams of [`RecordBatch`]
+
+use crate::sort::SortOptions;
+use arrow_array::{RecordBatch, RecordBatchOptions};
+use arrow_row::{RowConverter, Rows, SortField};
+use arrow_schema::{ArrowError, SchemaRef};
+use arrow_select::interleave::interleave;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// A sort key of a [`merge`], identifying a column and the order it is sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MergeKey {
+    /// The index of the column in the schema
+    pub column: usize,
+    /// The order the column is sorted by
+    pub options: SortOptions,
+}
+
+/// Merges `streams` of [`RecordBatch`], each sorted by `keys`, into a single sorted
+/// stream of [`RecordBatch`] containing at most `batch_size` rows
+///
+/// The sort keys are compared using the [row format](arrow_row), with rows of equal
+/// keys yielded in the order of the streams they belong to. Each input [`RecordBatch`]
+/// is retained until all its rows have been yielded.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_ord::merge::{merge, MergeKey};
+/// # use arrow_schema::ArrowError;
+/// #
+/// let batch = |v: Vec<i32>| {
+///     let a = Arc::new(Int32Array::from(v)) as ArrayRef;
+///     Ok(RecordBatch::try_from_iter([("a", a)]).unwrap())
+/// };
+/// let a = vec![batch(vec![1, 4]), batch(vec![5, 9])];
+/// let b = vec![batch(vec![2, 3, 7])];
+/// let schema = a[0].as_ref().unwrap().schema();
+///
+/// let key = MergeKey { column: 0, options: Default::default() };
+/// let streams = vec![a.into_iter(), b.into_iter()];
+/// let merged = merge(schema, streams, &[key], 4).unwrap();
+///
+/// let merged: Vec<_> = merged.collect::<Result<_, ArrowError>>().unwrap();
+/// assert_eq!(merged.len(), 2);
+/// assert_eq!(merged[0].column(0).as_primitive::<Int32Type>().values(), &[1, 2, 3, 4]);
+/// assert_eq!(merged[1].column(0).as_primitive::<Int32Type>().values(), &[5, 7, 9]);
+/// ```
+pub fn merge<I>(
+    schema: SchemaRef,
+    streams: Vec<I>,
+    keys: &[MergeKey],
+    batch_size: usize,
+) -> Result<SortedMerge<I>, ArrowError>
+where
+    I: Iterator<Item = Result<RecordBatch, ArrowError>>,
+{
+    if batch_size == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "Merge batch size must be greater than 0".to_string(),
+        ));
+    }
+
+    let fields = keys
+        .iter()
+        .map(|key| {
+            let field = schema.fields().get(key.column).ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Merge key column {} out of bounds for schema with {} fields",
+                    key.column,
+                    schema.fields().len()
+                ))
+            })?;
+            Ok(SortField::new_with_options(
+                field.data_type().clone(),
+                key.options,
+            ))
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    let cursors = streams.iter().map(|_| None).collect();
+    Ok(SortedMerge {
+        schema,
+        columns: keys.iter().map(|k| k.column).collect(),
+        converter: RowConverter::new(fields)?,
+        batch_size,
+        streams,
+        cursors,
+        batches: vec![],
+        heap: None,
+        indices: Vec::with_capacity(batch_size),
+        done: false,
+    })
+}
+
+/// The position within the current [`RecordBatch`] of a stream
+#[derive(Debug)]
+struct Cursor {
+    /// The index of the batch in [`SortedMerge::batches`]
+    batch: usize,
+    /// The encoded sort keys of the batch
+    rows: Rows,
+    /// The index of the next row
+    offset: usize,
+}
+
+/// An iterator over the merged [`RecordBatch`] of sorted streams, see [`merge`]
+#[derive(Debug)]
+pub struct SortedMerge<I> {
+    schema: SchemaRef,
+    /// The indices of the sort key columns
+    columns: Vec<usize>,
+    converter: RowConverter,
+    batch_size: usize,
+    streams: Vec<I>,
+    /// The cursor of each stream, `None` if the stream is exhausted
+    cursors: Vec<Option<Cursor>>,
+    /// The batches referenced by `indices` or a cursor
+    batches: Vec<RecordBatch>,
+    /// A binary min-heap of the streams with a cursor, `None` until initialized
+    heap: Option<Vec<usize>>,
+    /// The `(batch, row)` of each row of the next output batch
+    indices: Vec<(usize, usize)>,
+    done: bool,
+}
+
+impl<I> SortedMerge<I>
+where
+    I: Iterator<Item = Result<RecordBatch, ArrowError>>,
+{
+    /// Returns the schema of the merged [`RecordBatch`]
+    pub fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+
+    /// Loads the next non-empty batch of `stream`, returning false if it is exhausted
+    fn load(&mut self, stream: usize) -> Result<bool, ArrowError> {
+        loop {
+            let batch = match self.streams[stream].next() {
+                Some(batch) => batch?,
+                None => {
+                    self.cursors[stream] = None;
+                    return Ok(false);
+                }
+            };
+            if batch.num_rows() == 0 {
+                continue;
+            }
+
+            let columns: Vec<_> = self
+                .columns
+                .iter()
+                .map(|c| Arc::clone(batch.column(*c)))
+                .collect();
+            let rows = self.converter.convert_columns(&columns)?;
+
+            self.batches.push(batch);
+            self.cursors[stream] = Some(Cursor {
+                batch: self.batches.len() - 1,
+                rows,
+                offset: 0,
+            });
+            return Ok(true);
+        }
+    }
+
+    /// Returns true if the current row of stream `a` sorts before that of stream `b`
+    fn is_less(&self, a: usize, b: usize) -> bool {
+        let row = |stream: usize| {
+            let cursor = self.cursors[stream].as_ref().unwrap();
+            cursor.rows.row(cursor.offset)
+        };
+        match row(a).cmp(&row(b)) {
+            Ordering::Equal => a < b,
+            ord => ord == Ordering::Less,
+        }
+    }
+
+    fn sift_down(&self, heap: &mut [usize], mut idx: usize) {
+        loop {
+            let left = 2 * idx + 1;
+            if left >= heap.len() {
+                return;
+            }
+            let right = left + 1;
+            let child = match right < heap.len() && self.is_less(heap[right], heap[left])
+            {
+                true => right,
+                false => left,
+            };
+            if !self.is_less(heap[child], heap[idx]) {
+                return;
+            }
+            heap.swap(idx, child);
+            idx = child;
+        }
+    }
+
+    fn next_batch(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        let mut heap = match self.heap.take() {
+            Some(heap) => heap,
+            None => {
+                let mut heap = Vec::with_capacity(self.streams.len());
+                for stream in 0..self.streams.len() {
+                    if self.load(stream)? {
+                        heap.push(stream);
+                    }
+                }
+                for idx in (0..heap.len() / 2).rev() {
+                    self.sift_down(&mut heap, idx);
+                }
+                heap
+            }
+        };
+
+        while self.indices.len() < self.batch_size && !heap.is_empty() {
+            let stream = heap[0];
+            let cursor = self.cursors[stream].as_mut().unwrap();
+            self.indices.push((cursor.batch, cursor.offset));
+            cursor.offset += 1;
+
+            if cursor.offset == cursor.rows.num_rows() && !self.load(stream)? {
+                heap.swap_remove(0);
+            }
+            self.sift_down(&mut heap, 0);
+        }
+        self.heap = Some(heap);
+
+        if self.indices.is_empty() {
+            return Ok(None);
+        }
+
+        let columns = (0..self.schema.fields().len())
+            .map(|c| {
+                let values: Vec<_> =
+                    self.batches.iter().map(|b| b.column(c).as_ref()).collect();
+                interleave(&values, &self.indices)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let options = RecordBatchOptions::new().with_row_count(Some(self.indices.len()));
+        let batch = RecordBatch::try_new_with_options(self.schema(), columns, &options)?;
+        self.indices.clear();
+
+        // Release the batches no longer referenced by a cursor
+        let mut batches = Vec::with_capacity(self.cursors.len());
+        for cursor in self.cursors.iter_mut().flatten() {
+            batches.push(self.batches[cursor.batch].clone());
+            cursor.batch = batches.len() - 1;
+        }
+        self.batches = batches;
+
+        Ok(Some(batch))
+    }
+}
+
+impl<I> Iterator for SortedMerge<I>
+where
+    I: Iterator<Item = Result<RecordBatch, ArrowError>>,
+{
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let next = self.next_batch().transpose();
+        self.done = !matches!(next, Some(Ok(_)));
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::{lexsort, SortColumn};
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Array, ArrayRef, DictionaryArray, Int32Array};
+    use arrow_select::concat::concat_batches;
+    use rand::{thread_rng, Rng};
+
+    /// Sorts `batch` by `keys`
+    fn sort_batch(batch: &RecordBatch, keys: &[MergeKey]) -> RecordBatch {
+        let sort: Vec<_> = keys
+            .iter()
+            .map(|k| SortColumn {
+                values: batch.column(k.column).clone(),
+                options: Some(k.options),
+            })
+            .collect();
+        let columns = lexsort(&sort, None).unwrap();
+        RecordBatch::try_new(batch.schema(), columns).unwrap()
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut rng = thread_rng();
+        let strings = ["a", "b", "c", "d"];
+
+        for options in [
+            SortOptions::default(),
+            SortOptions {
+                descending: true,
+                nulls_first: false,
+            },
+        ] {
+            let keys = [
+                MergeKey { column: 0, options },
+                MergeKey { column: 1, options },
+            ];
+
+            // Generate streams of sorted batches, including empty batches and streams
+            let mut streams = vec![];
+            let mut all = vec![];
+            for _ in 0..5 {
+                let len = rng.gen_range(0..50);
+                let a: Int32Array = (0..len)
+                    .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..10)))
+                    .collect();
+                let b: DictionaryArray<Int32Type> =
+                    (0..len).map(|_| strings[rng.gen_range(0..4)]).collect();
+                // Explicitly nullable, as a batch may not contain any nulls
+                let batch = RecordBatch::try_from_iter_with_nullable([
+                    ("a", Arc::new(a) as ArrayRef, true),
+                    ("b", Arc::new(b) as ArrayRef, false),
+                ])
+                .unwrap();
+                let sorted = sort_batch(&batch, &keys);
+
+                let mut batches = vec![];
+                let mut offset = 0;
+                while offset < len {
+                    let slice_len = rng.gen_range(0..=len - offset);
+                    batches.push(Ok(sorted.slice(offset, slice_len)));
+                    offset += slice_len;
+                }
+                streams.push(batches.into_iter());
+                all.push(batch);
+            }
+
+            let schema = all[0].schema();
+            let expected = sort_batch(&concat_batches(&schema, &all).unwrap(), &keys);
+
+            let merged = merge(schema.clone(), streams, &keys, 7).unwrap();
+            let merged: Vec<_> = merged.collect::<Result<_, _>>().unwrap();
+            assert!(merged.iter().all(|b| b.num_rows() <= 7));
+            let merged = concat_batches(&schema, &merged).unwrap();
+            assert_eq!(merged, expected);
+        }
+    }
+
+    #[test]
+    fn test_merge_stable() {
+        let batch = |a: Vec<i32>, b: Vec<i32>| {
+            let a = Arc::new(Int32Array::from(a)) as ArrayRef;
+            let b = Arc::new(Int32Array::from(b)) as ArrayRef;
+            Ok(RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap())
+        };
+        let s1 = vec![batch(vec![1, 2, 2], vec![0, 0, 1])];
+        let s2 = vec![batch(vec![1], vec![10]), batch(vec![2, 3], vec![11, 12])];
+        let schema = s1[0].as_ref().unwrap().schema();
+
+        let key = MergeKey {
+            column: 0,
+            options: SortOptions::default(),
+        };
+        let streams = vec![s1.into_iter(), s2.into_iter()];
+        let merged = merge(schema.clone(), streams, &[key], 100).unwrap();
+        let merged: Vec<_> = merged.collect::<Result<_, _>>().unwrap();
+        assert_eq!(merged.len(), 1);
+
+        let b = merged[0].column(1).as_any().downcast_ref::<Int32Array>();
+        assert_eq!(b.unwrap().values(), &[0, 10, 0, 1, 11, 12]);
+    }
+
+    #[test]
+    fn test_merge_error() {
+        let a = Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef;
+        let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+        let schema = batch.schema();
+        let key = MergeKey {
+            column: 0,
+            options: SortOptions::default(),
+        };
+
+        let err = merge(schema.clone(), vec![std::iter::empty()], &[key], 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Merge batch size must be greater than 0"
+        );
+
+        let out_of_bounds = MergeKey { column: 1, ..key };
+        let err = merge(
+            schema.clone(),
+            vec![std::iter::empty()],
+            &[out_of_bounds],
+            1,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Merge key column 1 out of bounds for schema with 1 fields"
+        );
+
+        let s1 = vec![Ok(batch.clone()), Ok(batch)];
+        let s2 = vec![Err(ArrowError::ComputeError("failed".to_string()))];
+        let streams = vec![s1.into_iter(), s2.into_iter()];
+        let mut merged = merge(schema, streams, &[key], 1).unwrap();
+        let err = merged.next().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "Compute error: failed");
+        assert!(merged.next().is_none());
+    }
+}
@@ -46,9 +46,12 @@ fn compare_boolean(left: &dyn Array, right: &dyn Array) -> DynComparator {
     Box::new(move |i, j| left.value(i).cmp(&right.value(j)))
 }
 
-fn compare_string(left: &dyn Array, right: &dyn Array) -> DynComparator {
-    let left: StringArray = StringArray::from(left.to_data());
-    let right: StringArray = StringArray::from(right.to_data());
+fn compare_string<O: OffsetSizeTrait>(
+    left: &dyn Array,
+    right: &dyn Array,
+) -> DynComparator {
+    let left = GenericStringArray::<O>::from(left.to_data());
+    let right = GenericStringArray::<O>::from(right.to_data());
 
     Box::new(move |i, j| left.value(i).cmp(right.value(j)))
 }
@@ -125,6 +128,39 @@ where
     })
 }
 
+/// Compares the values of two [`UnionArray`] of the same type, first by type id
+/// and then by the value within the corresponding child
+fn compare_union(
+    left: &dyn Array,
+    right: &dyn Array,
+) -> Result<DynComparator, ArrowError> {
+    let left = left.as_any().downcast_ref::<UnionArray>().unwrap().clone();
+    let right = right.as_any().downcast_ref::<UnionArray>().unwrap().clone();
+    let fields = match left.data_type() {
+        DataType::Union(fields, _) => fields,
+        _ => unreachable!(),
+    };
+
+    let max_id = fields.iter().map(|(id, _)| id).max().unwrap_or_default();
+    let mut comparators: Vec<Option<DynComparator>> =
+        (0..=max_id).map(|_| None).collect();
+    for (id, _) in fields.iter() {
+        let cmp = build_compare(left.child(id).as_ref(), right.child(id).as_ref())?;
+        comparators[id as usize] = Some(cmp);
+    }
+
+    Ok(Box::new(move |i, j| {
+        let (left_id, right_id) = (left.type_id(i), right.type_id(j));
+        match left_id.cmp(&right_id) {
+            Ordering::Equal => {
+                let cmp = comparators[left_id as usize].as_ref().unwrap();
+                cmp(left.value_offset(i), right.value_offset(j))
+            }
+            o => o,
+        }
+    }))
+}
+
 macro_rules! cmp_dict_primitive_helper {
     ($t:ty, $key_type_lhs:expr, $left:expr, $right:expr) => {
         cmp_dict_primitive::<$t>($key_type_lhs, $left, $right)?
@@ -172,6 +208,12 @@ pub fn build_compare(
         (Float16, Float16) => compare_primitives::<Float16Type>(left, right),
         (Float32, Float32) => compare_primitives::<Float32Type>(left, right),
         (Float64, Float64) => compare_primitives::<Float64Type>(left, right),
+        (Decimal32(_, _), Decimal32(_, _)) => {
+            compare_primitives::<Decimal32Type>(left, right)
+        }
+        (Decimal64(_, _), Decimal64(_, _)) => {
+            compare_primitives::<Decimal64Type>(left, right)
+        }
         (Decimal128(_, _), Decimal128(_, _)) => {
             compare_primitives::<Decimal128Type>(left, right)
         }
@@ -225,8 +267,8 @@ pub fn build_compare(
         (Duration(Nanosecond), Duration(Nanosecond)) => {
             compare_primitives::<DurationNanosecondType>(left, right)
         }
-        (Utf8, Utf8) => compare_string(left, right),
-        (LargeUtf8, LargeUtf8) => compare_string(left, right),
+        (Utf8, Utf8) => compare_string::<i32>(left, right),
+        (LargeUtf8, LargeUtf8) => compare_string::<i64>(left, right),
         (
             Dictionary(key_type_lhs, value_type_lhs),
             Dictionary(key_type_rhs, value_type_rhs),
@@ -262,6 +304,7 @@ pub fn build_compare(
                 }
             }
         }
+        (Union(_, _), Union(_, _)) => compare_union(left, right)?,
         (FixedSizeBinary(_), FixedSizeBinary(_)) => {
             let left: FixedSizeBinaryArray = left.to_data().into();
             let right: FixedSizeBinaryArray = right.to_data().into();
@@ -279,6 +322,7 @@ pub fn build_compare(
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use arrow_array::builder::UnionBuilder;
     use arrow_array::{FixedSizeBinaryArray, Float64Array, Int32Array};
     use arrow_buffer::i256;
     use half::f16;
@@ -354,6 +398,16 @@ pub mod tests {
         assert_eq!(Ordering::Equal, (cmp)(1, 1));
     }
 
+    #[test]
+    fn test_large_string() {
+        let array = LargeStringArray::from(vec!["b", "a"]);
+
+        let cmp = build_compare(&array, &array).unwrap();
+
+        assert_eq!(Ordering::Greater, (cmp)(0, 1));
+        assert_eq!(Ordering::Equal, (cmp)(1, 1));
+    }
+
     #[test]
     fn test_f64_zeros() {
         let array = Float64Array::from(vec![-0.0, 0.0]);
@@ -377,6 +431,23 @@ pub mod tests {
         assert_eq!(Ordering::Greater, (cmp)(0, 2));
     }
 
+    #[test]
+    fn test_decimal32_decimal64() {
+        let array = Decimal32Array::from(vec![5, 2, 3])
+            .with_precision_and_scale(9, 2)
+            .unwrap();
+        let cmp = build_compare(&array, &array).unwrap();
+        assert_eq!(Ordering::Less, (cmp)(1, 0));
+        assert_eq!(Ordering::Greater, (cmp)(0, 2));
+
+        let array = Decimal64Array::from(vec![-5, 2, i64::MAX])
+            .with_precision_and_scale(18, 2)
+            .unwrap();
+        let cmp = build_compare(&array, &array).unwrap();
+        assert_eq!(Ordering::Less, (cmp)(0, 1));
+        assert_eq!(Ordering::Greater, (cmp)(2, 1));
+    }
+
     #[test]
     fn test_decimali256() {
         let array = vec![
@@ -562,4 +633,26 @@ pub mod tests {
         assert_eq!(Ordering::Greater, (cmp)(3, 1));
         assert_eq!(Ordering::Greater, (cmp)(3, 2));
     }
+
+    #[test]
+    fn test_union() {
+        let mut builder = UnionBuilder::new_dense();
+        builder.append::<Int32Type>("a", 1).unwrap();
+        builder.append::<Float64Type>("b", 2.5).unwrap();
+        builder.append::<Int32Type>("a", 3).unwrap();
+        let array1 = builder.build().unwrap();
+
+        let mut builder = UnionBuilder::new_dense();
+        builder.append::<Int32Type>("a", 2).unwrap();
+        builder.append::<Float64Type>("b", 2.5).unwrap();
+        let array2 = builder.build().unwrap();
+
+        let cmp = build_compare(&array1, &array2).unwrap();
+
+        assert_eq!(Ordering::Less, (cmp)(0, 0));
+        assert_eq!(Ordering::Greater, (cmp)(2, 0));
+        assert_eq!(Ordering::Equal, (cmp)(1, 1));
+        assert_eq!(Ordering::Less, (cmp)(2, 1));
+        assert_eq!(Ordering::Greater, (cmp)(1, 0));
+    }
 }
@@ -0,0 +1,211 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Parallel ordering kernels, using the [rayon] thread pool
+//!
+//! See [`arrow_select::parallel`] for how inputs are split into chunks
+
+use crate::ord::{build_compare, DynComparator};
+use crate::sort::{sort_to_indices, SortOptions};
+use arrow_array::{Array, UInt32Array};
+use arrow_buffer::NullBuffer;
+use arrow_schema::ArrowError;
+use rayon::prelude::*;
+use std::cmp::Ordering;
+
+pub use arrow_select::parallel::{ParallelOptions, DEFAULT_PARALLEL_THRESHOLD};
+
+/// Parallel version of [`sort_to_indices`]
+///
+/// Chunks of `values` are sorted in parallel, and the sorted chunks then merged in
+/// parallel, if `values` has at least [`ParallelOptions::threshold`] rows. Types not
+/// supported by [`build_compare`] are sorted by a single thread.
+///
+/// As with [`sort_to_indices`] the relative order of equal values is unspecified.
+///
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_ord::parallel::{par_sort_to_indices, ParallelOptions};
+/// let array = Int32Array::from_iter_values((0..100_000).rev());
+/// let options = ParallelOptions::new().with_threshold(10_000);
+/// let indices = par_sort_to_indices(&array, None, Some(3), &options).unwrap();
+/// assert_eq!(indices.values(), &[99_999, 99_998, 99_997]);
+/// ```
+pub fn par_sort_to_indices(
+    values: &dyn Array,
+    options: Option<SortOptions>,
+    limit: Option<usize>,
+    parallel: &ParallelOptions,
+) -> Result<UInt32Array, ArrowError> {
+    let (chunks, cmp) = match parallel.chunks(values.len()) {
+        Some(chunks) => match build_compare(values, values) {
+            Ok(cmp) => (chunks, cmp),
+            Err(_) => return sort_to_indices(values, options, limit),
+        },
+        None => return sort_to_indices(values, options, limit),
+    };
+    let sort_options = options.unwrap_or_default();
+
+    let runs = chunks
+        .into_par_iter()
+        .map(|r| {
+            let chunk = values.slice(r.start, r.len());
+            let indices = sort_to_indices(chunk.as_ref(), options, limit)?;
+            let offset = r.start as u32;
+            Ok(indices.values().iter().map(|i| i + offset).collect())
+        })
+        .collect::<Result<Vec<Vec<u32>>, ArrowError>>()?;
+
+    let compare = RowComparator {
+        cmp,
+        nulls: values.nulls(),
+        options: sort_options,
+    };
+    let limit = limit.unwrap_or(values.len());
+    let mut runs = runs;
+    while runs.len() > 1 {
+        runs = runs
+            .par_chunks(2)
+            .map(|pair| match pair {
+                [a, b] => compare.merge(a, b, limit),
+                [a] => a.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+    Ok(UInt32Array::from(runs.pop().unwrap_or_default()))
+}
+
+/// Compares rows of an array according to [`SortOptions`]
+struct RowComparator<'a> {
+    cmp: DynComparator,
+    nulls: Option<&'a NullBuffer>,
+    options: SortOptions,
+}
+
+impl RowComparator<'_> {
+    fn compare(&self, a: u32, b: u32) -> Ordering {
+        let (a, b) = (a as usize, b as usize);
+        let (a_valid, b_valid) = match self.nulls {
+            Some(n) => (n.is_valid(a), n.is_valid(b)),
+            None => (true, true),
+        };
+        match (a_valid, b_valid) {
+            (true, true) => match self.options.descending {
+                true => (self.cmp)(a, b).reverse(),
+                false => (self.cmp)(a, b),
+            },
+            (false, false) => Ordering::Equal,
+            (false, true) => match self.options.nulls_first {
+                true => Ordering::Less,
+                false => Ordering::Greater,
+            },
+            (true, false) => match self.options.nulls_first {
+                true => Ordering::Greater,
+                false => Ordering::Less,
+            },
+        }
+    }
+
+    /// Merges the sorted runs `a` and `b`, returning at most `limit` indices
+    fn merge(&self, a: &[u32], b: &[u32], limit: usize) -> Vec<u32> {
+        let len = limit.min(a.len() + b.len());
+        let mut out = Vec::with_capacity(len);
+        let (mut a, mut b) = (a.iter().peekable(), b.iter().peekable());
+        while out.len() < len {
+            let next = match (a.peek(), b.peek()) {
+                (Some(x), Some(y)) => match self.compare(**x, **y) {
+                    Ordering::Greater => b.next(),
+                    _ => a.next(),
+                },
+                (Some(_), None) => a.next(),
+                (None, _) => b.next(),
+            };
+            out.push(*next.unwrap());
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{ArrayRef, BinaryArray, Float64Array, StringArray};
+    use arrow_select::take::take;
+    use rand::prelude::*;
+    use rayon::ThreadPoolBuilder;
+    use std::sync::Arc;
+
+    /// Checks `par_sort_to_indices` sorts `values` in the same order as `sort_to_indices`
+    fn test_par_sort(values: ArrayRef) {
+        let pool = ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+        let parallel = ParallelOptions::new().with_threshold(100);
+        for (descending, nulls_first) in
+            [(false, false), (false, true), (true, false), (true, true)]
+        {
+            let options = Some(SortOptions {
+                descending,
+                nulls_first,
+            });
+            for limit in [None, Some(10), Some(values.len() + 1)] {
+                let expected = sort_to_indices(&values, options, limit).unwrap();
+                let actual = pool
+                    .install(|| par_sort_to_indices(&values, options, limit, &parallel))
+                    .unwrap();
+                assert_eq!(actual.len(), expected.len());
+                // Equal values may be ordered differently, so compare the sorted values
+                let expected = take(&values, &expected, None).unwrap();
+                let actual = take(&values, &actual, None).unwrap();
+                assert_eq!(actual.as_ref(), expected.as_ref());
+            }
+        }
+    }
+
+    #[test]
+    fn test_par_sort_to_indices() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let floats: Float64Array = (0..1000)
+            .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(-10.0..10.0)))
+            .collect();
+        test_par_sort(Arc::new(floats));
+
+        let strings: StringArray = (0..1000)
+            .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..50).to_string()))
+            .collect();
+        test_par_sort(Arc::new(strings));
+
+        // Small inputs are sorted by a single thread
+        let values = Float64Array::from(vec![Some(2.), None, Some(1.)]);
+        let parallel = ParallelOptions::new();
+        let indices = par_sort_to_indices(&values, None, None, &parallel).unwrap();
+        assert_eq!(indices.values(), &[1, 2, 0]);
+    }
+
+    #[test]
+    fn test_par_sort_unsupported() {
+        // Binary arrays are not supported by build_compare
+        let values: BinaryArray =
+            (0..1000_u32).map(|i| Some((i % 7).to_be_bytes())).collect();
+        let pool = ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+        let parallel = ParallelOptions::new().with_threshold(100);
+        let expected = sort_to_indices(&values, None, None).unwrap();
+        let actual = pool
+            .install(|| par_sort_to_indices(&values, None, None, &parallel))
+            .unwrap();
+        assert_eq!(actual, expected);
+    }
+}
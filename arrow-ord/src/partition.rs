@@ -15,10 +15,15 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines partition kernel for `ArrayRef`
+//! Defines partition kernels for `ArrayRef`
 
-use crate::sort::{LexicographicalComparator, SortColumn};
-use arrow_schema::ArrowError;
+use crate::ord::{build_compare, DynComparator};
+use crate::sort::{LexicographicalComparator, SortColumn, SortOptions};
+use arrow_array::cast::AsArray;
+use arrow_array::types::ByteArrayType;
+use arrow_array::*;
+use arrow_buffer::{ArrowNativeType, NullBuffer, ToByteSlice};
+use arrow_schema::{ArrowError, DataType};
 use std::cmp::Ordering;
 use std::ops::Range;
 
@@ -223,10 +228,342 @@ impl<'a> Iterator for LexicographicalPartitionIterator<'a> {
     }
 }
 
+/// The assignment of the rows of a batch to partitions, as computed by
+/// [`range_partition`] or [`hash_partition`]
+#[derive(Debug, Clone)]
+pub struct Partitions {
+    ids: UInt32Array,
+    num_partitions: usize,
+}
+
+impl Partitions {
+    /// Returns the number of partitions
+    pub fn num_partitions(&self) -> usize {
+        self.num_partitions
+    }
+
+    /// Returns the partition of each row
+    pub fn ids(&self) -> &UInt32Array {
+        &self.ids
+    }
+
+    /// Returns the partition of each row
+    pub fn into_ids(self) -> UInt32Array {
+        self.ids
+    }
+
+    /// Returns the indices of the rows grouped by partition, and the offsets of each
+    /// partition within these indices
+    ///
+    /// The rows of partition `i` are given by `indices[offsets[i]..offsets[i + 1]]`, in
+    /// the order they occur in the input, and can be materialized with
+    /// [`take`](arrow_select::take::take) followed by a slice
+    pub fn take_indices(&self) -> (UInt32Array, Vec<usize>) {
+        let ids = self.ids.values();
+        let mut offsets = vec![0_usize; self.num_partitions + 1];
+        ids.iter().for_each(|id| offsets[*id as usize + 1] += 1);
+        for i in 1..offsets.len() {
+            offsets[i] += offsets[i - 1];
+        }
+
+        let mut next = offsets.clone();
+        let mut indices = vec![0_u32; ids.len()];
+        for (idx, id) in ids.iter().enumerate() {
+            let slot = &mut next[*id as usize];
+            indices[*slot] = idx as u32;
+            *slot += 1;
+        }
+        (indices.into(), offsets)
+    }
+}
+
+/// Returns the common length of `lengths`, returning an error if they are not all
+/// equal or this exceeds the range of [`u32`]
+fn partition_num_rows(
+    mut lengths: impl Iterator<Item = usize>,
+) -> Result<usize, ArrowError> {
+    let num_rows = lengths.next().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(
+            "Partition requires at least one column".to_string(),
+        )
+    })?;
+    if lengths.any(|len| len != num_rows) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Partition columns have different row counts".to_string(),
+        ));
+    }
+    if num_rows > u32::MAX as usize {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot partition {num_rows} rows, exceeds u32::MAX"
+        )));
+    }
+    Ok(num_rows)
+}
+
+/// Assigns each row of the sort key `columns` to a partition, given the sorted partition
+/// `bounds` of each column
+///
+/// `bounds` contains one array per column, each of length `n`, where the values at index
+/// `i` across the arrays give the lower bound of partition `i + 1`. These must be sorted
+/// according to the [`SortOptions`] of `columns`. The rows are assigned to `n + 1`
+/// partitions, with a row equal to a bound assigned to the partition it begins.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array};
+/// # use arrow_ord::partition::range_partition;
+/// # use arrow_ord::sort::SortColumn;
+/// let values = Arc::new(Int32Array::from(vec![5, 1, 10, 7, 3])) as ArrayRef;
+/// let bounds = Arc::new(Int32Array::from(vec![3, 7])) as ArrayRef;
+/// let columns = [SortColumn { values, options: None }];
+///
+/// let partitions = range_partition(&columns, &[bounds]).unwrap();
+/// assert_eq!(partitions.num_partitions(), 3);
+/// assert_eq!(partitions.ids().values(), &[1, 0, 2, 2, 1]);
+///
+/// let (indices, offsets) = partitions.take_indices();
+/// assert_eq!(indices.values(), &[1, 0, 4, 2, 3]);
+/// assert_eq!(offsets, &[0, 1, 3, 5]);
+/// ```
+pub fn range_partition(
+    columns: &[SortColumn],
+    bounds: &[ArrayRef],
+) -> Result<Partitions, ArrowError> {
+    if columns.len() != bounds.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Expected bounds for {} columns, got {}",
+            columns.len(),
+            bounds.len()
+        )));
+    }
+    let num_rows = partition_num_rows(columns.iter().map(|c| c.values.len()))?;
+    let num_bounds = partition_num_rows(bounds.iter().map(|b| b.len()))?;
+
+    let items = columns
+        .iter()
+        .zip(bounds)
+        .map(|(column, bound)| {
+            let values = column.values.as_ref();
+            Ok((
+                values.nulls(),
+                bound.nulls(),
+                build_compare(values, bound.as_ref())?,
+                column.options.unwrap_or_default(),
+            ))
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    let ids: Vec<u32> = (0..num_rows)
+        .map(|row| {
+            // The number of bounds less than or equal to row
+            partition_point(0, num_bounds, |bound| {
+                compare_bound(&items, row, bound) != Ordering::Less
+            }) as u32
+        })
+        .collect();
+
+    Ok(Partitions {
+        ids: ids.into(),
+        num_partitions: num_bounds + 1,
+    })
+}
+
+type BoundCompareItem<'a> = (
+    Option<&'a NullBuffer>,
+    Option<&'a NullBuffer>,
+    DynComparator,
+    SortOptions,
+);
+
+/// Compares `row` with `bound`, see [`LexicographicalComparator::compare`]
+fn compare_bound(items: &[BoundCompareItem<'_>], row: usize, bound: usize) -> Ordering {
+    for (row_nulls, bound_nulls, comparator, options) in items {
+        let row_valid = row_nulls.map(|n| n.is_valid(row)).unwrap_or(true);
+        let bound_valid = bound_nulls.map(|n| n.is_valid(bound)).unwrap_or(true);
+        match (row_valid, bound_valid) {
+            (true, true) => match comparator(row, bound) {
+                Ordering::Equal => continue,
+                order if options.descending => return order.reverse(),
+                order => return order,
+            },
+            (false, true) => {
+                return match options.nulls_first {
+                    true => Ordering::Less,
+                    false => Ordering::Greater,
+                }
+            }
+            (true, false) => {
+                return match options.nulls_first {
+                    true => Ordering::Greater,
+                    false => Ordering::Less,
+                }
+            }
+            (false, false) => continue,
+        }
+    }
+    Ordering::Equal
+}
+
+/// Assigns each row to one of `num_partitions` partitions by hashing the values
+/// of `columns`
+///
+/// Equal rows are assigned to the same partition, including across batches and
+/// regardless of whether a column is dictionary encoded. The hash is deterministic,
+/// and so consistent across processes using the same version of this crate.
+///
+/// Floating point values are hashed by their bit representation, and so `-0.0` and
+/// `0.0`, or NaNs with different payloads, may be assigned to different partitions
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, StringArray};
+/// # use arrow_ord::partition::hash_partition;
+/// let values = Arc::new(StringArray::from(vec!["a", "b", "a", "c"])) as ArrayRef;
+/// let partitions = hash_partition(&[values], 4).unwrap();
+///
+/// let ids = partitions.ids();
+/// assert_eq!(ids.value(0), ids.value(2));
+/// assert!(ids.values().iter().all(|id| *id < 4));
+/// ```
+pub fn hash_partition(
+    columns: &[ArrayRef],
+    num_partitions: usize,
+) -> Result<Partitions, ArrowError> {
+    if num_partitions == 0 || num_partitions > u32::MAX as usize {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid number of partitions: {num_partitions}"
+        )));
+    }
+    let num_rows = partition_num_rows(columns.iter().map(|c| c.len()))?;
+
+    let mut hashes = vec![HASH_SEED; num_rows];
+    for column in columns {
+        let column_hashes = hash_array(column.as_ref())?;
+        hashes
+            .iter_mut()
+            .zip(column_hashes)
+            .for_each(|(h, v)| *h = combine_hash(*h, v));
+    }
+
+    let ids: Vec<u32> = hashes
+        .into_iter()
+        .map(|h| ((finalize_hash(h) as u128 * num_partitions as u128) >> 64) as u32)
+        .collect();
+
+    Ok(Partitions {
+        ids: ids.into(),
+        num_partitions,
+    })
+}
+
+const HASH_SEED: u64 = 0x243F_6A88_85A3_08D3;
+const HASH_MUL: u64 = 0x517C_C1B7_2722_0A95;
+const NULL_HASH: u64 = 0x9E37_79B9_7F4A_7C15;
+
+#[inline]
+fn combine_hash(h: u64, v: u64) -> u64 {
+    (h.rotate_left(5) ^ v).wrapping_mul(HASH_MUL)
+}
+
+#[inline]
+fn finalize_hash(mut h: u64) -> u64 {
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xC4CE_B9FE_1A85_EC53);
+    h ^ (h >> 33)
+}
+
+#[inline]
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut h = combine_hash(HASH_SEED, bytes.len() as u64);
+    let mut chunks = bytes.chunks_exact(8);
+    for chunk in &mut chunks {
+        h = combine_hash(h, u64::from_le_bytes(chunk.try_into().unwrap()));
+    }
+    let remainder = chunks.remainder();
+    if !remainder.is_empty() {
+        let mut buf = [0_u8; 8];
+        buf[..remainder.len()].copy_from_slice(remainder);
+        h = combine_hash(h, u64::from_le_bytes(buf));
+    }
+    h
+}
+
+/// Returns the hash of each of `values`, or [`NULL_HASH`] if null
+fn hash_values<'a>(
+    nulls: Option<&NullBuffer>,
+    values: impl Iterator<Item = &'a [u8]>,
+) -> Vec<u64> {
+    match nulls.filter(|n| n.null_count() > 0) {
+        Some(n) => values
+            .enumerate()
+            .map(|(i, v)| match n.is_valid(i) {
+                true => hash_bytes(v),
+                false => NULL_HASH,
+            })
+            .collect(),
+        None => values.map(hash_bytes).collect(),
+    }
+}
+
+fn hash_bytes_array<T: ByteArrayType>(array: &GenericByteArray<T>) -> Vec<u64> {
+    let values = (0..array.len()).map(|i| -> &[u8] { array.value(i).as_ref() });
+    hash_values(array.nulls(), values)
+}
+
+/// Returns the hash of each value of `array`
+fn hash_array(array: &dyn Array) -> Result<Vec<u64>, ArrowError> {
+    Ok(downcast_primitive_array! {
+        array => {
+            let values = array.values().iter().map(|v| v.to_byte_slice());
+            hash_values(array.nulls(), values)
+        }
+        DataType::Null => vec![NULL_HASH; array.len()],
+        DataType::Boolean => {
+            let array = array.as_boolean();
+            let values = array.values().iter().map(|v| match v {
+                true => &[1_u8][..],
+                false => &[0_u8][..],
+            });
+            hash_values(array.nulls(), values)
+        }
+        DataType::Utf8 => hash_bytes_array(array.as_string::<i32>()),
+        DataType::LargeUtf8 => hash_bytes_array(array.as_string::<i64>()),
+        DataType::Binary => hash_bytes_array(array.as_binary::<i32>()),
+        DataType::LargeBinary => hash_bytes_array(array.as_binary::<i64>()),
+        DataType::FixedSizeBinary(_) => {
+            let array = array.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+            let values = (0..array.len()).map(|i| array.value(i));
+            hash_values(array.nulls(), values)
+        }
+        DataType::Dictionary(_, _) => downcast_dictionary_array! {
+            array => {
+                let values = hash_array(array.values().as_ref())?;
+                array
+                    .keys()
+                    .iter()
+                    .map(|k| match k {
+                        Some(k) => values[k.as_usize()],
+                        None => NULL_HASH,
+                    })
+                    .collect()
+            }
+            _ => unreachable!()
+        },
+        d => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Hash partitioning of {d} is not supported"
+            )))
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::sort::SortOptions;
+    use arrow_array::types::Int8Type;
     use arrow_array::*;
     use arrow_schema::DataType;
     use std::sync::Arc;
@@ -439,4 +776,105 @@ mod tests {
             results.collect::<Vec<_>>()
         );
     }
+
+    #[test]
+    fn test_range_partition() {
+        let a = Arc::new(Int32Array::from(vec![
+            Some(1),
+            None,
+            Some(5),
+            Some(5),
+            Some(9),
+        ])) as ArrayRef;
+        let b = Arc::new(StringArray::from(vec!["z", "a", "a", "m", "a"])) as ArrayRef;
+        let columns = vec![
+            SortColumn {
+                values: a,
+                options: Some(SortOptions {
+                    descending: true,
+                    nulls_first: true,
+                }),
+            },
+            SortColumn {
+                values: b,
+                options: None,
+            },
+        ];
+
+        // Partitions: [.., (5, "m")), [(5, "m"), (1, "a")), [(1, "a"), ..]
+        let bounds = vec![
+            Arc::new(Int32Array::from(vec![5, 1])) as ArrayRef,
+            Arc::new(StringArray::from(vec!["m", "a"])) as ArrayRef,
+        ];
+        let partitions = range_partition(&columns, &bounds).unwrap();
+        assert_eq!(partitions.num_partitions(), 3);
+        assert_eq!(partitions.ids().values(), &[2, 0, 0, 1, 0]);
+
+        let (indices, offsets) = partitions.take_indices();
+        assert_eq!(indices.values(), &[1, 2, 4, 3, 0]);
+        assert_eq!(offsets, vec![0, 3, 4, 5]);
+
+        // No bounds yields a single partition
+        let empty = vec![
+            Arc::new(Int32Array::from(Vec::<i32>::new())) as ArrayRef,
+            Arc::new(StringArray::from(Vec::<&str>::new())) as ArrayRef,
+        ];
+        let partitions = range_partition(&columns, &empty).unwrap();
+        assert_eq!(partitions.ids().values(), &[0; 5]);
+
+        let err = range_partition(&columns, &bounds[..1]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected bounds for 2 columns, got 1"
+        );
+    }
+
+    #[test]
+    fn test_hash_partition() {
+        let strings = vec![Some("a"), None, Some("b"), Some("a"), None, Some("c")];
+        let a = Arc::new(StringArray::from(strings.clone())) as ArrayRef;
+        let dict: DictionaryArray<Int8Type> = strings.into_iter().collect();
+        let dict = Arc::new(dict) as ArrayRef;
+        let b = Arc::new(Int64Array::from(vec![1, 2, 3, 1, 2, 3])) as ArrayRef;
+
+        let partitions = hash_partition(&[a.clone(), b.clone()], 7).unwrap();
+        assert_eq!(partitions.num_partitions(), 7);
+        let ids = partitions.ids();
+        assert!(ids.values().iter().all(|x| *x < 7));
+        assert_eq!(ids.value(0), ids.value(3));
+        assert_eq!(ids.value(1), ids.value(4));
+
+        // Dictionary encoding does not change the assignment
+        let dict_partitions = hash_partition(&[dict, b.clone()], 7).unwrap();
+        assert_eq!(dict_partitions.ids(), ids);
+
+        // Sliced arrays are hashed consistently
+        let sliced = hash_partition(&[a.slice(3, 3), b.slice(3, 3)], 7).unwrap();
+        assert_eq!(sliced.ids().values(), &ids.values()[3..]);
+
+        let (indices, offsets) = partitions.take_indices();
+        assert_eq!(offsets.len(), 8);
+        assert_eq!(offsets[7], 6);
+        for p in 0..7 {
+            for idx in &indices.values()[offsets[p]..offsets[p + 1]] {
+                assert_eq!(ids.value(*idx as usize), p as u32);
+            }
+        }
+
+        // Values are distributed across partitions
+        let values = Arc::new(Int32Array::from_iter_values(0..1000)) as ArrayRef;
+        let (_, offsets) = hash_partition(&[values], 4).unwrap().take_indices();
+        for p in 0..4 {
+            let size = offsets[p + 1] - offsets[p];
+            assert!(size > 150 && size < 350, "{size}");
+        }
+
+        let err = hash_partition(&[a, b.slice(1, 2)], 4).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Partition columns have different row counts"
+        );
+        assert!(hash_partition(&[], 4).is_err());
+        assert!(hash_partition(&[b], 0).is_err());
+    }
 }
@@ -15,10 +15,15 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::sort::SortColumn;
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
-use arrow_array::{downcast_primitive_array, Array, ArrowNativeTypeOp, GenericByteArray};
+use arrow_array::{
+    downcast_primitive_array, Array, ArrayRef, ArrowNativeTypeOp, Float64Array,
+    GenericByteArray, UInt64Array,
+};
 use arrow_buffer::NullBuffer;
+use arrow_row::{RowConverter, SortField};
 use arrow_schema::{ArrowError, DataType, SortOptions};
 use std::cmp::Ordering;
 
@@ -50,6 +55,117 @@ pub fn rank(
     Ok(ranks)
 }
 
+/// Assigns the SQL `RANK` of each row of `columns`, based on its position in the
+/// lexicographically sorted order
+///
+/// Ranks start at 1, and rows that compare equal are assigned the lowest of their
+/// ranks, leaving gaps in the overall rank assignment. Nulls compare equal to each
+/// other, and are ordered according to the [`SortOptions`] of each column.
+///
+/// Note: this differs from [`rank`], which assigns equal values the highest of
+/// their ranks
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, StringArray};
+/// # use arrow_ord::rank::lexrank;
+/// # use arrow_ord::sort::SortColumn;
+/// let a = Arc::new(Int32Array::from(vec![2, 1, 2, 1])) as ArrayRef;
+/// let b = Arc::new(StringArray::from(vec!["b", "a", "b", "c"])) as ArrayRef;
+/// let columns = [a, b].map(|values| SortColumn { values, options: None });
+/// let ranks = lexrank(&columns).unwrap();
+/// assert_eq!(ranks.values(), &[3, 1, 3, 2]);
+/// ```
+pub fn lexrank(columns: &[SortColumn]) -> Result<UInt64Array, ArrowError> {
+    let (sorted, new_group) = sorted_groups(columns)?;
+    let mut out = vec![0; sorted.len()];
+    let mut rank = 0;
+    for (i, (idx, new_group)) in sorted.iter().zip(new_group).enumerate() {
+        if new_group {
+            rank = i as u64 + 1;
+        }
+        out[*idx] = rank;
+    }
+    Ok(out.into())
+}
+
+/// Assigns the SQL `DENSE_RANK` of each row of `columns`, based on its position in
+/// the lexicographically sorted order
+///
+/// Ranks start at 1, and rows that compare equal are assigned the same rank, with
+/// no gaps in the overall rank assignment. See [`lexrank`] for how rows are compared.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array};
+/// # use arrow_ord::rank::dense_rank;
+/// # use arrow_ord::sort::SortColumn;
+/// let values = Arc::new(Int32Array::from(vec![Some(5), None, Some(1), Some(5)])) as ArrayRef;
+/// let ranks = dense_rank(&[SortColumn { values, options: None }]).unwrap();
+/// assert_eq!(ranks.values(), &[3, 1, 2, 3]);
+/// ```
+pub fn dense_rank(columns: &[SortColumn]) -> Result<UInt64Array, ArrowError> {
+    let (sorted, new_group) = sorted_groups(columns)?;
+    let mut out = vec![0; sorted.len()];
+    let mut rank = 0;
+    for (idx, new_group) in sorted.iter().zip(new_group) {
+        rank += new_group as u64;
+        out[*idx] = rank;
+    }
+    Ok(out.into())
+}
+
+/// Assigns the SQL `PERCENT_RANK` of each row of `columns`, that is
+/// `(rank - 1) / (num_rows - 1)` where `rank` is computed by [`lexrank`]
+///
+/// The percent rank is between 0 and 1 inclusive, and is 0 if `columns`
+/// contain a single row.
+pub fn percent_rank(columns: &[SortColumn]) -> Result<Float64Array, ArrowError> {
+    let ranks = lexrank(columns)?;
+    let denominator = ranks.len().saturating_sub(1).max(1) as f64;
+    Ok(ranks
+        .values()
+        .iter()
+        .map(|rank| (rank - 1) as f64 / denominator)
+        .collect::<Vec<_>>()
+        .into())
+}
+
+/// Sorts the rows of `columns`, returning the sorted row indices and whether each
+/// sorted row starts a new group of equal rows
+fn sorted_groups(columns: &[SortColumn]) -> Result<(Vec<usize>, Vec<bool>), ArrowError> {
+    if columns.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Rank requires at least one column".to_string(),
+        ));
+    }
+    let row_count = columns[0].values.len();
+    if columns.iter().any(|c| c.values.len() != row_count) {
+        return Err(ArrowError::ComputeError(
+            "rank columns have different row counts".to_string(),
+        ));
+    }
+
+    let fields = columns
+        .iter()
+        .map(|c| {
+            let options = c.options.unwrap_or_default();
+            SortField::new_with_options(c.values.data_type().clone(), options)
+        })
+        .collect();
+    let arrays: Vec<ArrayRef> = columns.iter().map(|c| c.values.clone()).collect();
+    let mut converter = RowConverter::new(fields)?;
+    let rows = converter.convert_columns(&arrays)?;
+
+    let mut sorted: Vec<usize> = (0..row_count).collect();
+    sorted.sort_unstable_by(|a, b| rows.row(*a).cmp(&rows.row(*b)));
+
+    let new_group = (0..row_count)
+        .map(|i| i == 0 || rows.row(sorted[i]) != rows.row(sorted[i - 1]))
+        .collect();
+    Ok((sorted, new_group))
+}
+
 #[inline(never)]
 fn primitive_rank<T: ArrowNativeTypeOp>(
     values: &[T],
@@ -134,6 +250,7 @@ where
 mod tests {
     use super::*;
     use arrow_array::*;
+    use std::sync::Arc;
 
     #[test]
     fn test_primitive() {
@@ -192,4 +309,77 @@ mod tests {
         let res = rank(&values, None).unwrap();
         assert_eq!(res, &[3, 1, 4, 3]);
     }
+
+    #[test]
+    fn test_lexrank() {
+        let a = Arc::new(Int32Array::from(vec![
+            Some(1),
+            None,
+            Some(1),
+            Some(0),
+            None,
+            Some(1),
+        ])) as ArrayRef;
+        let b =
+            Arc::new(StringArray::from(vec!["x", "y", "x", "z", "y", "w"])) as ArrayRef;
+        let descending = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+
+        let columns = [
+            SortColumn {
+                values: a.clone(),
+                options: None,
+            },
+            SortColumn {
+                values: b.clone(),
+                options: None,
+            },
+        ];
+        // Sorted: (null, y), (null, y), (0, z), (1, w), (1, x), (1, x)
+        assert_eq!(lexrank(&columns).unwrap().values(), &[5, 1, 5, 3, 1, 4]);
+        assert_eq!(dense_rank(&columns).unwrap().values(), &[4, 1, 4, 2, 1, 3]);
+        let percent = percent_rank(&columns).unwrap();
+        assert_eq!(percent.values(), &[0.8, 0., 0.8, 0.4, 0., 0.6]);
+
+        let columns = [SortColumn {
+            values: a,
+            options: Some(descending),
+        }];
+        // Sorted: 1, 1, 1, 0, null, null
+        assert_eq!(lexrank(&columns).unwrap().values(), &[1, 5, 1, 4, 5, 1]);
+        assert_eq!(dense_rank(&columns).unwrap().values(), &[1, 3, 1, 2, 3, 1]);
+
+        let single = [SortColumn {
+            values: Arc::new(Int32Array::from(vec![42])),
+            options: None,
+        }];
+        assert_eq!(lexrank(&single).unwrap().values(), &[1]);
+        assert_eq!(percent_rank(&single).unwrap().values(), &[0.]);
+
+        let empty = [SortColumn {
+            values: Arc::new(Int32Array::from(Vec::<i32>::new())),
+            options: None,
+        }];
+        assert!(dense_rank(&empty).unwrap().is_empty());
+
+        let err = lexrank(&[]).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: Rank requires at least one column"
+        );
+
+        let mismatched = [
+            SortColumn {
+                values: Arc::new(Int32Array::from(vec![1])),
+                options: None,
+            },
+            SortColumn {
+                values: b,
+                options: None,
+            },
+        ];
+        assert!(lexrank(&mismatched).is_err());
+    }
 }
@@ -0,0 +1,270 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Binary search of lexicographically sorted columns
+//!
+//! For searching data already converted to the [row format](arrow_row), the
+//! [`Row`](arrow_row::Row)s can be compared directly with the search key.
+
+use crate::ord::{build_compare, DynComparator};
+use crate::sort::{SortColumn, SortOptions};
+use arrow_array::{Array, ArrayRef};
+use arrow_buffer::NullBuffer;
+use arrow_schema::ArrowError;
+use std::cmp::Ordering;
+use std::ops::Range;
+
+/// Searches lexicographically sorted columns for rows of a set of key columns
+///
+/// The columns must be sorted according to their [`SortOptions`], for example
+/// with [`lexsort`](crate::sort::lexsort), otherwise the results are unspecified.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, StringArray};
+/// # use arrow_ord::search::LexicographicalSearch;
+/// # use arrow_ord::sort::SortColumn;
+/// let a = Arc::new(Int32Array::from(vec![1, 1, 1, 2, 3])) as ArrayRef;
+/// let b = Arc::new(StringArray::from(vec!["a", "b", "b", "a", "c"])) as ArrayRef;
+/// let columns = [a, b].map(|values| SortColumn { values, options: None });
+///
+/// let key_a = Arc::new(Int32Array::from(vec![1, 2, 0])) as ArrayRef;
+/// let key_b = Arc::new(StringArray::from(vec!["b", "b", "z"])) as ArrayRef;
+/// let keys = [key_a, key_b];
+/// let search = LexicographicalSearch::try_new(&columns, &keys).unwrap();
+///
+/// assert_eq!(search.equal_range(0), 1..3);
+/// assert_eq!(search.binary_search(1), Err(4));
+/// assert_eq!(search.lower_bound(2), 0);
+/// ```
+pub struct LexicographicalSearch<'a> {
+    len: usize,
+    compare_items: Vec<SearchCompareItem<'a>>,
+}
+
+/// The nulls of a sorted column and key column, with a comparator between them
+type SearchCompareItem<'a> = (
+    Option<&'a NullBuffer>,
+    Option<&'a NullBuffer>,
+    DynComparator,
+    SortOptions,
+);
+
+impl<'a> LexicographicalSearch<'a> {
+    /// Create a new [`LexicographicalSearch`] of the sorted `columns` for the rows
+    /// of `keys`, which must contain an array of the same type for each column
+    pub fn try_new(
+        columns: &'a [SortColumn],
+        keys: &'a [ArrayRef],
+    ) -> Result<Self, ArrowError> {
+        if columns.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "Search requires at least one column".to_string(),
+            ));
+        }
+        if columns.len() != keys.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Search requires a key for each column, expected {} got {}",
+                columns.len(),
+                keys.len()
+            )));
+        }
+        let len = columns[0].values.len();
+        if columns.iter().any(|c| c.values.len() != len) {
+            return Err(ArrowError::ComputeError(
+                "Search columns have different row counts".to_string(),
+            ));
+        }
+
+        let compare_items = columns
+            .iter()
+            .zip(keys)
+            .map(|(column, key)| {
+                let values = column.values.as_ref();
+                Ok((
+                    values.nulls(),
+                    key.nulls(),
+                    build_compare(values, key.as_ref())?,
+                    column.options.unwrap_or_default(),
+                ))
+            })
+            .collect::<Result<_, ArrowError>>()?;
+
+        Ok(Self { len, compare_items })
+    }
+
+    /// Compares row `row` of the sorted columns with row `key` of the keys,
+    /// according to the sort order of the columns
+    pub fn compare(&self, row: usize, key: usize) -> Ordering {
+        for (nulls, key_nulls, comparator, options) in &self.compare_items {
+            let row_valid = nulls.map(|n| n.is_valid(row)).unwrap_or(true);
+            let key_valid = key_nulls.map(|n| n.is_valid(key)).unwrap_or(true);
+
+            let order = match (row_valid, key_valid) {
+                (true, true) => match comparator(row, key) {
+                    Ordering::Equal => continue,
+                    order if options.descending => order.reverse(),
+                    order => order,
+                },
+                (false, true) if options.nulls_first => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (true, false) if options.nulls_first => Ordering::Greater,
+                (true, false) => Ordering::Less,
+                (false, false) => continue,
+            };
+            return order;
+        }
+        Ordering::Equal
+    }
+
+    /// Returns the index of the first row of the sorted columns for which `pred`
+    /// returns false, see [`slice::partition_point`]
+    ///
+    /// `pred` must return true for all rows preceding this index, and false for
+    /// all subsequent rows, otherwise the result is unspecified
+    pub fn partition_point(&self, mut pred: impl FnMut(usize) -> bool) -> usize {
+        let (mut low, mut high) = (0, self.len);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match pred(mid) {
+                true => low = mid + 1,
+                false => high = mid,
+            }
+        }
+        low
+    }
+
+    /// Returns the index of the first row greater than or equal to row `key` of the keys
+    pub fn lower_bound(&self, key: usize) -> usize {
+        self.partition_point(|row| self.compare(row, key).is_lt())
+    }
+
+    /// Returns the index of the first row greater than row `key` of the keys
+    pub fn upper_bound(&self, key: usize) -> usize {
+        self.partition_point(|row| self.compare(row, key).is_le())
+    }
+
+    /// Returns the range of rows equal to row `key` of the keys
+    pub fn equal_range(&self, key: usize) -> Range<usize> {
+        self.lower_bound(key)..self.upper_bound(key)
+    }
+
+    /// Searches for row `key` of the keys, see [`slice::binary_search`]
+    ///
+    /// If a matching row is found returns [`Result::Ok`] containing the index of
+    /// the first such row, otherwise returns [`Result::Err`] containing the index
+    /// at which the key could be inserted while maintaining the sort order
+    pub fn binary_search(&self, key: usize) -> Result<usize, usize> {
+        let idx = self.lower_bound(key);
+        match idx < self.len && self.compare(idx, key).is_eq() {
+            true => Ok(idx),
+            false => Err(idx),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::lexsort;
+    use arrow_array::{Int32Array, StringArray};
+    use rand::{thread_rng, Rng};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_search_nulls_descending() {
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let values = Int32Array::from(vec![Some(5), Some(3), Some(3), None, None]);
+        let columns = [SortColumn {
+            values: Arc::new(values),
+            options: Some(options),
+        }];
+        let keys =
+            [Arc::new(Int32Array::from(vec![Some(3), None, Some(9), Some(0)])) as _];
+        let search = LexicographicalSearch::try_new(&columns, &keys).unwrap();
+
+        assert_eq!(search.equal_range(0), 1..3);
+        assert_eq!(search.equal_range(1), 3..5);
+        assert_eq!(search.binary_search(2), Err(0));
+        assert_eq!(search.binary_search(3), Err(3));
+        assert_eq!(search.partition_point(|row| row < 2), 2);
+    }
+
+    #[test]
+    fn test_search_random() {
+        let mut rng = thread_rng();
+        let options = SortOptions {
+            descending: false,
+            nulls_first: true,
+        };
+        let a: Int32Array = (0..100)
+            .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..5)))
+            .collect();
+        let b: StringArray = (0..100)
+            .map(|_| Some(["a", "b", "c"][rng.gen_range(0..3)]))
+            .collect();
+        let columns =
+            [Arc::new(a) as ArrayRef, Arc::new(b) as ArrayRef].map(|values| SortColumn {
+                values,
+                options: Some(options),
+            });
+        let sorted = lexsort(&columns, None).unwrap();
+        let sorted: Vec<_> = sorted
+            .into_iter()
+            .map(|values| SortColumn {
+                values,
+                options: Some(options),
+            })
+            .collect();
+
+        let keys = [
+            Arc::new(Int32Array::from(vec![None, Some(1), Some(4), Some(6)])) as ArrayRef,
+            Arc::new(StringArray::from(vec!["a", "b", "c", "a"])) as ArrayRef,
+        ];
+        let search = LexicographicalSearch::try_new(&sorted, &keys).unwrap();
+        for key in 0..keys[0].len() {
+            let range = search.equal_range(key);
+            for row in 0..sorted[0].values.len() {
+                let expected = match row {
+                    r if r < range.start => Ordering::Less,
+                    r if r < range.end => Ordering::Equal,
+                    _ => Ordering::Greater,
+                };
+                assert_eq!(search.compare(row, key), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_search_errors() {
+        let columns = [SortColumn {
+            values: Arc::new(Int32Array::from(vec![1, 2])),
+            options: None,
+        }];
+        let keys = [Arc::new(StringArray::from(vec!["a"])) as ArrayRef];
+        assert!(LexicographicalSearch::try_new(&columns, &keys).is_err());
+
+        let err = LexicographicalSearch::try_new(&columns, &[]).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Search requires a key for each column, expected 1 got 0"
+        );
+    }
+}
@@ -45,6 +45,9 @@ pub use arrow_schema::SortOptions;
 /// Note: this is an unstable_sort, meaning it may not preserve the
 /// order of equal elements.
 ///
+/// Inputs that are already sorted, or sorted in the reverse order, are detected
+/// and returned without performing a full sort, see [`is_sorted`]
+///
 /// # Example
 /// ```rust
 /// # use std::sync::Arc;
@@ -58,6 +61,15 @@ pub fn sort(
     values: &dyn Array,
     options: Option<SortOptions>,
 ) -> Result<ArrayRef, ArrowError> {
+    match presorted(values, &options.unwrap_or_default()) {
+        Some(false) => return Ok(values.slice(0, values.len())),
+        Some(true) => {
+            let indices = presorted_indices(values.len(), true, None);
+            return take(values, &indices, None);
+        }
+        None => {}
+    }
+
     downcast_primitive_array!(
         values => sort_native_type(values, options),
         DataType::RunEndEncoded(_, _) => sort_run(values, options, None),
@@ -200,9 +212,132 @@ fn partition_validity(array: &dyn Array) -> (Vec<u32>, Vec<u32>) {
     }
 }
 
+/// Returns whether `array` is sorted according to `options`
+///
+/// Values are ordered as by [`sort`], with nulls ordered according to the
+/// `nulls_first` flag of `options`, and floats ordered using IEEE 754 totalOrder.
+/// Equal adjacent values are permitted, see [`is_sorted_strict`].
+///
+/// Returns an error if comparing the values of `array` is not supported.
+///
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_ord::sort::{is_sorted, SortOptions};
+/// let array = Int32Array::from(vec![None, Some(1), Some(1), Some(3)]);
+/// assert!(is_sorted(&array, None).unwrap());
+///
+/// let options = SortOptions { descending: false, nulls_first: false };
+/// assert!(!is_sorted(&array, Some(options)).unwrap());
+/// ```
+pub fn is_sorted(
+    array: &dyn Array,
+    options: Option<SortOptions>,
+) -> Result<bool, ArrowError> {
+    sorted_by(array, &options.unwrap_or_default(), false)
+}
+
+/// Returns whether `array` is strictly sorted according to `options`
+///
+/// This is like [`is_sorted`], except that no two values may be equal, including
+/// two nulls.
+///
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_ord::sort::{is_sorted_strict, SortOptions};
+/// let array = Int32Array::from(vec![None, Some(1), Some(3)]);
+/// assert!(is_sorted_strict(&array, None).unwrap());
+///
+/// let array = Int32Array::from(vec![None, Some(1), Some(1), Some(3)]);
+/// assert!(!is_sorted_strict(&array, None).unwrap());
+/// ```
+pub fn is_sorted_strict(
+    array: &dyn Array,
+    options: Option<SortOptions>,
+) -> Result<bool, ArrowError> {
+    sorted_by(array, &options.unwrap_or_default(), true)
+}
+
+fn sorted_by(
+    array: &dyn Array,
+    options: &SortOptions,
+    strict: bool,
+) -> Result<bool, ArrowError> {
+    let len = array.len();
+    let null_count = array.null_count();
+    let (start, end) = match options.nulls_first {
+        true => (null_count, len),
+        false => (0, len - null_count),
+    };
+
+    // The nulls must all be before or after the valid values
+    if let Some(nulls) = array.nulls().filter(|n| n.null_count() > 0) {
+        if strict && null_count > 1 {
+            return Ok(false);
+        }
+        let valid = nulls.inner().slice(start, end - start).count_set_bits();
+        if valid != end - start {
+            return Ok(false);
+        }
+    }
+
+    let ordered = move |ord: Ordering| {
+        let ord = if options.descending {
+            ord.reverse()
+        } else {
+            ord
+        };
+        match strict {
+            true => ord == Ordering::Less,
+            false => ord != Ordering::Greater,
+        }
+    };
+
+    downcast_primitive_array! {
+        array => {
+            let values = &array.values()[start..end];
+            Ok(values.windows(2).all(|w| ordered(w[0].compare(w[1]))))
+        }
+        _ => {
+            let cmp = build_compare(array, array)?;
+            Ok((start + 1..end).all(|i| ordered(cmp(i - 1, i))))
+        }
+    }
+}
+
+/// Returns `Some(false)` if `values` are already sorted according to `options`,
+/// `Some(true)` if reversing them would sort them, and otherwise `None`
+fn presorted(values: &dyn Array, options: &SortOptions) -> Option<bool> {
+    if values.len() < 2 {
+        return Some(false);
+    }
+    if sorted_by(values, options, false).unwrap_or(false) {
+        return Some(false);
+    }
+    let reversed = SortOptions {
+        descending: !options.descending,
+        nulls_first: !options.nulls_first,
+    };
+    // Reversing reorders equal values, and so is only done if there are none
+    sorted_by(values, &reversed, true)
+        .unwrap_or(false)
+        .then_some(true)
+}
+
+/// Returns the first `limit` indices that sort presorted values of length `len`
+fn presorted_indices(len: usize, reverse: bool, limit: Option<usize>) -> UInt32Array {
+    let limit = limit.map(|l| l.min(len)).unwrap_or(len);
+    match reverse {
+        false => UInt32Array::from_iter_values(0..limit as u32),
+        true => UInt32Array::from_iter_values(((len - limit) as u32..len as u32).rev()),
+    }
+}
+
 /// Sort elements from `ArrayRef` into an unsigned integer (`UInt32Array`) of indices.
 /// For floating point arrays any NaN values are considered to be greater than any other non-null value.
 /// `limit` is an option for [partial_sort].
+///
+/// Inputs that are already sorted, or sorted in the reverse order, are detected
+/// and their indices returned without performing a full sort, see [`is_sorted`]
 pub fn sort_to_indices(
     values: &dyn Array,
     options: Option<SortOptions>,
@@ -210,9 +345,19 @@ pub fn sort_to_indices(
 ) -> Result<UInt32Array, ArrowError> {
     let options = options.unwrap_or_default();
 
+    if let Some(reverse) = presorted(values, &options) {
+        return Ok(presorted_indices(values.len(), reverse, limit));
+    }
+
     let (v, n) = partition_validity(values);
 
     Ok(match values.data_type() {
+        DataType::Decimal32(_, _) => {
+            sort_integer::<Decimal32Type>(values, v, n, &options, limit)
+        }
+        DataType::Decimal64(_, _) => {
+            sort_integer::<Decimal64Type>(values, v, n, &options, limit)
+        }
         DataType::Decimal128(_, _) => {
             sort_primitive::<Decimal128Type, _>(values, v, n, cmp, &options, limit)
         }
@@ -220,30 +365,14 @@ pub fn sort_to_indices(
             sort_primitive::<Decimal256Type, _>(values, v, n, cmp, &options, limit)
         }
         DataType::Boolean => sort_boolean(values, v, n, &options, limit),
-        DataType::Int8 => {
-            sort_primitive::<Int8Type, _>(values, v, n, cmp, &options, limit)
-        }
-        DataType::Int16 => {
-            sort_primitive::<Int16Type, _>(values, v, n, cmp, &options, limit)
-        }
-        DataType::Int32 => {
-            sort_primitive::<Int32Type, _>(values, v, n, cmp, &options, limit)
-        }
-        DataType::Int64 => {
-            sort_primitive::<Int64Type, _>(values, v, n, cmp, &options, limit)
-        }
-        DataType::UInt8 => {
-            sort_primitive::<UInt8Type, _>(values, v, n, cmp, &options, limit)
-        }
-        DataType::UInt16 => {
-            sort_primitive::<UInt16Type, _>(values, v, n, cmp, &options, limit)
-        }
-        DataType::UInt32 => {
-            sort_primitive::<UInt32Type, _>(values, v, n, cmp, &options, limit)
-        }
-        DataType::UInt64 => {
-            sort_primitive::<UInt64Type, _>(values, v, n, cmp, &options, limit)
-        }
+        DataType::Int8 => sort_integer::<Int8Type>(values, v, n, &options, limit),
+        DataType::Int16 => sort_integer::<Int16Type>(values, v, n, &options, limit),
+        DataType::Int32 => sort_integer::<Int32Type>(values, v, n, &options, limit),
+        DataType::Int64 => sort_integer::<Int64Type>(values, v, n, &options, limit),
+        DataType::UInt8 => sort_integer::<UInt8Type>(values, v, n, &options, limit),
+        DataType::UInt16 => sort_integer::<UInt16Type>(values, v, n, &options, limit),
+        DataType::UInt32 => sort_integer::<UInt32Type>(values, v, n, &options, limit),
+        DataType::UInt64 => sort_integer::<UInt64Type>(values, v, n, &options, limit),
         DataType::Float16 => sort_primitive::<Float16Type, _>(
             values,
             v,
@@ -268,47 +397,37 @@ pub fn sort_to_indices(
             &options,
             limit,
         ),
-        DataType::Date32 => {
-            sort_primitive::<Date32Type, _>(values, v, n, cmp, &options, limit)
-        }
-        DataType::Date64 => {
-            sort_primitive::<Date64Type, _>(values, v, n, cmp, &options, limit)
-        }
+        DataType::Date32 => sort_integer::<Date32Type>(values, v, n, &options, limit),
+        DataType::Date64 => sort_integer::<Date64Type>(values, v, n, &options, limit),
         DataType::Time32(TimeUnit::Second) => {
-            sort_primitive::<Time32SecondType, _>(values, v, n, cmp, &options, limit)
+            sort_integer::<Time32SecondType>(values, v, n, &options, limit)
         }
         DataType::Time32(TimeUnit::Millisecond) => {
-            sort_primitive::<Time32MillisecondType, _>(values, v, n, cmp, &options, limit)
+            sort_integer::<Time32MillisecondType>(values, v, n, &options, limit)
         }
         DataType::Time64(TimeUnit::Microsecond) => {
-            sort_primitive::<Time64MicrosecondType, _>(values, v, n, cmp, &options, limit)
+            sort_integer::<Time64MicrosecondType>(values, v, n, &options, limit)
         }
         DataType::Time64(TimeUnit::Nanosecond) => {
-            sort_primitive::<Time64NanosecondType, _>(values, v, n, cmp, &options, limit)
+            sort_integer::<Time64NanosecondType>(values, v, n, &options, limit)
         }
         DataType::Timestamp(TimeUnit::Second, _) => {
-            sort_primitive::<TimestampSecondType, _>(values, v, n, cmp, &options, limit)
+            sort_integer::<TimestampSecondType>(values, v, n, &options, limit)
         }
         DataType::Timestamp(TimeUnit::Millisecond, _) => {
-            sort_primitive::<TimestampMillisecondType, _>(
-                values, v, n, cmp, &options, limit,
-            )
+            sort_integer::<TimestampMillisecondType>(values, v, n, &options, limit)
         }
         DataType::Timestamp(TimeUnit::Microsecond, _) => {
-            sort_primitive::<TimestampMicrosecondType, _>(
-                values, v, n, cmp, &options, limit,
-            )
+            sort_integer::<TimestampMicrosecondType>(values, v, n, &options, limit)
         }
         DataType::Timestamp(TimeUnit::Nanosecond, _) => {
-            sort_primitive::<TimestampNanosecondType, _>(
-                values, v, n, cmp, &options, limit,
-            )
+            sort_integer::<TimestampNanosecondType>(values, v, n, &options, limit)
         }
         DataType::Interval(IntervalUnit::YearMonth) => {
-            sort_primitive::<IntervalYearMonthType, _>(values, v, n, cmp, &options, limit)
+            sort_integer::<IntervalYearMonthType>(values, v, n, &options, limit)
         }
         DataType::Interval(IntervalUnit::DayTime) => {
-            sort_primitive::<IntervalDayTimeType, _>(values, v, n, cmp, &options, limit)
+            sort_integer::<IntervalDayTimeType>(values, v, n, &options, limit)
         }
         DataType::Interval(IntervalUnit::MonthDayNano) => {
             sort_primitive::<IntervalMonthDayNanoType, _>(
@@ -316,22 +435,16 @@ pub fn sort_to_indices(
             )
         }
         DataType::Duration(TimeUnit::Second) => {
-            sort_primitive::<DurationSecondType, _>(values, v, n, cmp, &options, limit)
+            sort_integer::<DurationSecondType>(values, v, n, &options, limit)
         }
         DataType::Duration(TimeUnit::Millisecond) => {
-            sort_primitive::<DurationMillisecondType, _>(
-                values, v, n, cmp, &options, limit,
-            )
+            sort_integer::<DurationMillisecondType>(values, v, n, &options, limit)
         }
         DataType::Duration(TimeUnit::Microsecond) => {
-            sort_primitive::<DurationMicrosecondType, _>(
-                values, v, n, cmp, &options, limit,
-            )
+            sort_integer::<DurationMicrosecondType>(values, v, n, &options, limit)
         }
         DataType::Duration(TimeUnit::Nanosecond) => {
-            sort_primitive::<DurationNanosecondType, _>(
-                values, v, n, cmp, &options, limit,
-            )
+            sort_integer::<DurationNanosecondType>(values, v, n, &options, limit)
         }
         DataType::Utf8 => sort_string::<i32>(values, v, n, &options, limit),
         DataType::LargeUtf8 => sort_string::<i64>(values, v, n, &options, limit),
@@ -536,6 +649,28 @@ where
     sort_primitive_inner(values.len(), null_indices, cmp, options, limit, valids)
 }
 
+/// Sort integer values, see [`sort_integer_inner`]
+fn sort_integer<T>(
+    values: &dyn Array,
+    value_indices: Vec<u32>,
+    null_indices: Vec<u32>,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> UInt32Array
+where
+    T: ArrowPrimitiveType,
+    T::Native: RadixKey + Ord,
+{
+    let valids = {
+        let values = values.as_primitive::<T>();
+        value_indices
+            .into_iter()
+            .map(|index| (index, values.value(index as usize)))
+            .collect::<Vec<(u32, T::Native)>>()
+    };
+    sort_integer_inner(values.len(), null_indices, options, limit, valids)
+}
+
 /// Given a list of indices that yield a sorted order, returns the ordered
 /// rank of each index
 ///
@@ -570,7 +705,7 @@ fn sort_dictionary<K: ArrowDictionaryKeyType>(
         })
         .collect::<Vec<(u32, u32)>>();
 
-    sort_primitive_inner::<_, _>(keys.len(), null_indices, cmp, &options, limit, valids)
+    sort_integer_inner(keys.len(), null_indices, &options, limit, valids)
 }
 
 // sort is instantiated a lot so we only compile this inner version for each native type
@@ -587,8 +722,6 @@ where
     T: PartialOrd,
     F: Fn(T, T) -> Ordering,
 {
-    let valids_len = valids.len();
-    let nulls_len = nulls.len();
     let mut len = value_len;
 
     if let Some(limit) = limit {
@@ -596,6 +729,119 @@ where
     }
 
     sort_valids(options.descending, &mut valids, len, cmp);
+    sorted_indices(len, &nulls, &valids, options)
+}
+
+/// The number of valid values from which [`sort_integer_inner`] uses [`radix_sort`]
+const RADIX_SORT_THRESHOLD: usize = 4096;
+
+/// Sort integer values, with a [`radix_sort`] if there are at least
+/// [`RADIX_SORT_THRESHOLD`] valid values and no limit, otherwise comparing them
+fn sort_integer_inner<T>(
+    value_len: usize,
+    nulls: Vec<u32>,
+    options: &SortOptions,
+    limit: Option<usize>,
+    mut valids: Vec<(u32, T)>,
+) -> UInt32Array
+where
+    T: ArrowNativeType + RadixKey + Ord,
+{
+    let no_limit = limit.map(|l| l >= value_len).unwrap_or(true);
+    if no_limit && valids.len() >= RADIX_SORT_THRESHOLD {
+        radix_sort(&mut valids, options.descending);
+        return sorted_indices(value_len, &nulls, &valids, options);
+    }
+    sort_primitive_inner(value_len, nulls, cmp, options, limit, valids)
+}
+
+/// A type that can be sorted by [`radix_sort`]
+trait RadixKey: Copy {
+    /// The number of bytes in the key
+    const BYTES: usize;
+
+    /// Returns the byte `i` of an unsigned key with the same ordering as `self`,
+    /// where byte 0 is the least significant
+    fn byte(self, i: usize) -> u8;
+}
+
+macro_rules! radix_key_unsigned {
+    ($($t:ty),+) => {
+        $(impl RadixKey for $t {
+            const BYTES: usize = std::mem::size_of::<$t>();
+
+            #[inline]
+            fn byte(self, i: usize) -> u8 {
+                (self >> (i * 8)) as u8
+            }
+        })+
+    };
+}
+
+macro_rules! radix_key_signed {
+    ($($t:ty => $u:ty),+) => {
+        $(impl RadixKey for $t {
+            const BYTES: usize = std::mem::size_of::<$t>();
+
+            #[inline]
+            fn byte(self, i: usize) -> u8 {
+                // Flip the sign bit so negative values order before positive values
+                ((self as $u ^ (1 << (<$u>::BITS - 1))) >> (i * 8)) as u8
+            }
+        })+
+    };
+}
+
+radix_key_unsigned!(u8, u16, u32, u64);
+radix_key_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64);
+
+/// Sorts `valids` by value with a least significant digit radix sort
+///
+/// The sort is stable, and so values that are equal remain in the order of their
+/// indices. Bytes that are the same for all values are skipped.
+fn radix_sort<T: RadixKey>(valids: &mut Vec<(u32, T)>, descending: bool) {
+    let len = valids.len();
+    let mut counts = vec![[0_usize; 256]; T::BYTES];
+    for (_, v) in valids.iter() {
+        for (i, counts) in counts.iter_mut().enumerate() {
+            counts[v.byte(i) as usize] += 1;
+        }
+    }
+
+    let mut scratch = valids.clone();
+    for (i, counts) in counts.iter().enumerate() {
+        if counts.contains(&len) {
+            continue;
+        }
+
+        // Compute the offset in the output of the first value with each byte
+        let mut offsets = [0_usize; 256];
+        let mut offset = 0;
+        for byte in 0..256 {
+            let byte = if descending { 255 - byte } else { byte };
+            offsets[byte] = offset;
+            offset += counts[byte];
+        }
+
+        for v in valids.iter() {
+            let byte = v.1.byte(i) as usize;
+            scratch[offsets[byte]] = *v;
+            offsets[byte] += 1;
+        }
+        std::mem::swap(valids, &mut scratch);
+    }
+}
+
+/// Returns the first `len` indices of the sorted values `valids`, and the `nulls`,
+/// ordered according to `options`
+fn sorted_indices<T>(
+    len: usize,
+    nulls: &[u32],
+    valids: &[(u32, T)],
+    options: &SortOptions,
+) -> UInt32Array {
+    let valids_len = valids.len();
+    let nulls_len = nulls.len();
 
     // collect results directly into a buffer instead of a vec to avoid another aligned allocation
     let result_capacity = len * std::mem::size_of::<u32>();
@@ -4208,6 +4454,190 @@ mod tests {
         assert_eq!(&d[0..last], &before[0..last]);
     }
 
+    #[test]
+    fn test_is_sorted() {
+        let nulls_last = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        let descending = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+
+        let array = Int32Array::from(vec![None, None, Some(1), Some(2), Some(2)]);
+        assert!(is_sorted(&array, None).unwrap());
+        assert!(!is_sorted_strict(&array, None).unwrap());
+        assert!(!is_sorted(&array, Some(nulls_last)).unwrap());
+        assert!(!is_sorted(&array, Some(descending)).unwrap());
+        assert!(is_sorted_strict(&array.slice(1, 3), None).unwrap());
+
+        let array = Float64Array::from(vec![Some(f64::NAN), Some(1.0), Some(-0.0), None]);
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        assert!(is_sorted_strict(&array, Some(options)).unwrap());
+        assert!(!is_sorted(&array, Some(descending)).unwrap());
+
+        let array = StringArray::from(vec![Some("a"), Some("b"), Some("b"), None]);
+        assert!(is_sorted(&array, Some(nulls_last)).unwrap());
+        assert!(!is_sorted_strict(&array, Some(nulls_last)).unwrap());
+        let array = LargeStringArray::from(vec!["c", "b", "a"]);
+        assert!(is_sorted_strict(&array, Some(descending)).unwrap());
+
+        let array = BooleanArray::from(vec![false, true, true]);
+        assert!(is_sorted(&array, None).unwrap());
+        assert!(is_sorted(&Int32Array::from(Vec::<i32>::new()), None).unwrap());
+
+        let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            Some(vec![Some(2)]),
+        ]);
+        assert!(is_sorted(&array, None).is_err());
+    }
+
+    #[test]
+    fn test_sort_presorted() {
+        let array = Int32Array::from(vec![None, Some(1), Some(1), Some(4)]);
+        let indices = sort_to_indices(&array, None, None).unwrap();
+        assert_eq!(indices.values(), &[0, 1, 2, 3]);
+        let indices = sort_to_indices(&array, None, Some(2)).unwrap();
+        assert_eq!(indices.values(), &[0, 1]);
+        let sorted = sort(&array, None).unwrap();
+        assert_eq!(sorted.as_ref(), &array);
+
+        // Reverse sorted inputs are reversed
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let array = StringArray::from(vec![None, Some("a"), Some("b"), Some("c")]);
+        let indices = sort_to_indices(&array, Some(options), None).unwrap();
+        assert_eq!(indices.values(), &[3, 2, 1, 0]);
+        let indices = sort_to_indices(&array, Some(options), Some(3)).unwrap();
+        assert_eq!(indices.values(), &[3, 2, 1]);
+        let sorted = sort(&array, Some(options)).unwrap();
+        let expected = StringArray::from(vec![Some("c"), Some("b"), Some("a"), None]);
+        assert_eq!(sorted.as_ref(), &expected);
+
+        // Reversing would reorder equal values, and so a full sort is performed
+        let array = Int32Array::from(vec![3, 2, 2, 1]);
+        let indices = sort_to_indices(&array, None, None).unwrap();
+        let sorted = take(&array, &indices, None).unwrap();
+        assert_eq!(sorted.as_ref(), &Int32Array::from(vec![1, 2, 2, 3]));
+    }
+
+    /// Checks the result of sorting a large array, which uses a radix sort, against
+    /// sorting it with a comparison sort
+    fn test_radix_sort<T>(array: &PrimitiveArray<T>)
+    where
+        T: ArrowPrimitiveType,
+        T::Native: Ord,
+    {
+        for (descending, nulls_first) in
+            [(false, false), (false, true), (true, false), (true, true)]
+        {
+            let options = SortOptions {
+                descending,
+                nulls_first,
+            };
+            let indices = sort_to_indices(array, Some(options), None).unwrap();
+            assert_eq!(indices.len(), array.len());
+            let sorted = take(array, &indices, None).unwrap();
+            let sorted = sorted.as_primitive::<T>();
+
+            let mut expected: Vec<_> = array.iter().collect();
+            expected.sort_by(|a, b| match (a, b) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) if nulls_first => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) if nulls_first => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) if descending => b.cmp(a),
+                (Some(a), Some(b)) => a.cmp(b),
+            });
+            assert_eq!(sorted.iter().collect::<Vec<_>>(), expected);
+
+            // Equal values remain in the order of their indices
+            let stable = indices.values().windows(2).all(|w| {
+                let (a, b) = (w[0] as usize, w[1] as usize);
+                array.is_null(a) || array.value(a) != array.value(b) || a < b
+            });
+            assert!(stable);
+        }
+    }
+
+    #[test]
+    fn test_sort_radix() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let len = RADIX_SORT_THRESHOLD * 2;
+
+        let array: Int32Array = (0..len)
+            .map(|_| rng.gen_bool(0.9).then(|| rng.gen::<i32>()))
+            .collect();
+        test_radix_sort(&array);
+        test_radix_sort(&array.slice(5, RADIX_SORT_THRESHOLD));
+
+        // Values with a small range, that share most bytes
+        let array: Int64Array = (0..len)
+            .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(-100..100)))
+            .collect();
+        test_radix_sort(&array);
+
+        let array: UInt64Array = (0..len).map(|_| Some(rng.gen::<u64>())).collect();
+        test_radix_sort(&array);
+
+        let array: UInt8Array = (0..len).map(|_| Some(rng.gen::<u8>())).collect();
+        test_radix_sort(&array);
+
+        let array: Int16Array = (0..len).map(|_| Some(rng.gen::<i16>())).collect();
+        test_radix_sort(&array);
+
+        let array: TimestampNanosecondArray =
+            (0..len).map(|_| Some(rng.gen::<i64>())).collect();
+        test_radix_sort(&array);
+
+        // A limit uses a comparison sort
+        let options = SortOptions::default();
+        let limited = sort_to_indices(&array, Some(options), Some(10)).unwrap();
+        let full = sort_to_indices(&array, Some(options), None).unwrap();
+        assert_eq!(limited.values(), &full.values()[..10]);
+    }
+
+    #[test]
+    fn test_sort_radix_dictionary() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let values = StringArray::from(vec!["c", "a", "d", "b"]);
+        let keys: Int32Array = (0..RADIX_SORT_THRESHOLD * 2)
+            .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..4)))
+            .collect();
+        let dict = DictionaryArray::new(keys, Arc::new(values));
+
+        for descending in [false, true] {
+            let options = SortOptions {
+                descending,
+                nulls_first: true,
+            };
+            let indices = sort_to_indices(&dict, Some(options), None).unwrap();
+            let sorted = take(&dict, &indices, None).unwrap();
+            let sorted = sorted.as_dictionary::<Int32Type>();
+            let sorted = sorted.downcast_dict::<StringArray>().unwrap();
+            let sorted: Vec<_> = sorted.into_iter().collect();
+
+            let mut expected: Vec<_> = dict
+                .downcast_dict::<StringArray>()
+                .unwrap()
+                .into_iter()
+                .collect();
+            expected.sort_by(|a, b| match (a, b) {
+                (Some(a), Some(b)) if descending => b.cmp(a),
+                _ => a.cmp(b),
+            });
+            assert_eq!(sorted, expected);
+        }
+    }
+
     #[test]
     fn test_sort_int8_dicts() {
         let keys =
@@ -0,0 +1,70 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Convert between length-delimited [protobuf] messages and the [Apache Arrow] memory
+//! format, using the message definitions in a [`FileDescriptorSet`]
+//!
+//! A [`FileDescriptorSet`] can be generated with `protoc --descriptor_set_out`, or
+//! with [`prost_build::Config::file_descriptor_set_path`]
+//!
+//! Protobuf fields are mapped to Arrow as follows, recursive messages are not supported
+//!
+//! | Protobuf                          | Arrow                        |
+//! |-----------------------------------|------------------------------|
+//! | `double`                          | [`DataType::Float64`]        |
+//! | `float`                           | [`DataType::Float32`]        |
+//! | `int32`, `sint32`, `sfixed32`     | [`DataType::Int32`]          |
+//! | `int64`, `sint64`, `sfixed64`     | [`DataType::Int64`]          |
+//! | `uint32`, `fixed32`               | [`DataType::UInt32`]         |
+//! | `uint64`, `fixed64`               | [`DataType::UInt64`]         |
+//! | `bool`                            | [`DataType::Boolean`]        |
+//! | `string`                          | [`DataType::Utf8`]           |
+//! | `bytes`                           | [`DataType::Binary`]         |
+//! | `enum`                            | [`DataType::Int32`]          |
+//! | message                           | [`DataType::Struct`]         |
+//! | `repeated`                        | [`DataType::List`]           |
+//! | `map`                             | [`DataType::Map`]            |
+//!
+//! Message fields, fields in a `oneof` and fields with explicit presence, such as
+//! `optional` fields, are nullable. Other fields are non-nullable, with absent
+//! values decoded as the protobuf default value
+//!
+//! [protobuf]: https://protobuf.dev
+//! [Apache Arrow]: https://arrow.apache.org
+//! [`FileDescriptorSet`]: prost_types::FileDescriptorSet
+//! [`DataType::Float64`]: arrow_schema::DataType::Float64
+//! [`DataType::Float32`]: arrow_schema::DataType::Float32
+//! [`DataType::Int32`]: arrow_schema::DataType::Int32
+//! [`DataType::Int64`]: arrow_schema::DataType::Int64
+//! [`DataType::UInt32`]: arrow_schema::DataType::UInt32
+//! [`DataType::UInt64`]: arrow_schema::DataType::UInt64
+//! [`DataType::Boolean`]: arrow_schema::DataType::Boolean
+//! [`DataType::Utf8`]: arrow_schema::DataType::Utf8
+//! [`DataType::Binary`]: arrow_schema::DataType::Binary
+//! [`DataType::Struct`]: arrow_schema::DataType::Struct
+//! [`DataType::List`]: arrow_schema::DataType::List
+//! [`DataType::Map`]: arrow_schema::DataType::Map
+//! [`prost_build::Config::file_descriptor_set_path`]: https://docs.rs/prost-build/latest/prost_build/struct.Config.html#method.file_descriptor_set_path
+
+#![warn(missing_docs)]
+
+pub mod reader;
+mod schema;
+mod wire;
+pub mod writer;
+
+pub use schema::message_schema;
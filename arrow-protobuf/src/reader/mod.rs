@@ -0,0 +1,380 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Read length-delimited protobuf messages into Arrow [`RecordBatch`]
+//!
+//! Each message is prefixed by its length encoded as a varint, as written by
+//! `writeDelimitedTo` in the protobuf Java and C++ libraries
+//!
+//! ```
+//! # use std::io::BufReader;
+//! # use arrow_protobuf::reader::ReaderBuilder;
+//! # use prost_types::FileDescriptorSet;
+//! # fn read(descriptors: &FileDescriptorSet, file: std::fs::File) -> Result<(), arrow_schema::ArrowError> {
+//! let reader = ReaderBuilder::try_new(descriptors, "my.package.Message")?
+//!     .with_batch_size(8192)
+//!     .build(BufReader::new(file))?;
+//!
+//! for batch in reader {
+//!     println!("Read {} messages", batch?.num_rows());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::reader::record::decode_struct;
+use crate::schema::MessageType;
+use arrow_array::{RecordBatch, RecordBatchOptions, RecordBatchReader};
+use arrow_schema::{ArrowError, SchemaRef};
+use prost_types::FileDescriptorSet;
+use std::io::BufRead;
+use std::sync::Arc;
+
+mod record;
+
+/// A builder for [`Reader`] and [`Decoder`]
+#[derive(Debug)]
+pub struct ReaderBuilder {
+    batch_size: usize,
+    message: MessageType,
+    schema: SchemaRef,
+}
+
+impl ReaderBuilder {
+    /// Create a new [`ReaderBuilder`] for the message named `message` in `descriptors`
+    ///
+    /// `message` is the fully qualified name of the message, e.g. `my.package.Message`,
+    /// see [`message_schema`](crate::message_schema) for how messages are mapped to Arrow
+    pub fn try_new(
+        descriptors: &FileDescriptorSet,
+        message: &str,
+    ) -> Result<Self, ArrowError> {
+        let message = MessageType::try_new(descriptors, message)?;
+        Ok(Self {
+            batch_size: 1024,
+            schema: Arc::new(message.schema()),
+            message,
+        })
+    }
+
+    /// Returns the schema of the [`RecordBatch`] that will be read
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Sets the batch size in rows to read
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self { batch_size, ..self }
+    }
+
+    /// Create a [`Reader`] with the provided [`BufRead`]
+    pub fn build<R: BufRead>(self, reader: R) -> Result<Reader<R>, ArrowError> {
+        Ok(Reader {
+            reader,
+            decoder: self.build_decoder()?,
+        })
+    }
+
+    /// Create a [`Decoder`]
+    pub fn build_decoder(self) -> Result<Decoder, ArrowError> {
+        Ok(Decoder {
+            message: self.message,
+            schema: self.schema,
+            batch_size: self.batch_size,
+            buffer: vec![],
+            ends: vec![],
+            prefix: 0,
+            prefix_shift: 0,
+            remaining: None,
+        })
+    }
+}
+
+/// Reads length-delimited protobuf messages directly into Arrow [`RecordBatch`]
+pub struct Reader<R> {
+    reader: R,
+    decoder: Decoder,
+}
+
+impl<R> std::fmt::Debug for Reader<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Reader")
+            .field("decoder", &self.decoder)
+            .finish()
+    }
+}
+
+impl<R: BufRead> Reader<R> {
+    /// Reads the next [`RecordBatch`] returning `Ok(None)` if EOF
+    fn read(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        loop {
+            let buf = self.reader.fill_buf()?;
+            if buf.is_empty() {
+                if self.decoder.has_partial() {
+                    return Err(ArrowError::ParseError(
+                        "Unexpected EOF reading protobuf message".to_string(),
+                    ));
+                }
+                break;
+            }
+            let read = buf.len();
+
+            let decoded = self.decoder.decode(buf)?;
+            self.reader.consume(decoded);
+            if decoded != read {
+                break;
+            }
+        }
+        self.decoder.flush()
+    }
+}
+
+impl<R: BufRead> Iterator for Reader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
+
+impl<R: BufRead> RecordBatchReader for Reader<R> {
+    fn schema(&self) -> SchemaRef {
+        self.decoder.schema.clone()
+    }
+}
+
+/// A low-level interface for reading length-delimited protobuf messages from a
+/// byte stream, such as a gRPC stream or a message queue
+///
+/// See [`Reader`] for a higher-level interface for interface with [`BufRead`]
+pub struct Decoder {
+    message: MessageType,
+    schema: SchemaRef,
+    batch_size: usize,
+    /// The bytes of the buffered messages, including any partially read message
+    buffer: Vec<u8>,
+    /// The end offsets within `buffer` of the complete messages
+    ends: Vec<usize>,
+    /// The partially decoded length prefix of the next message
+    prefix: u64,
+    prefix_shift: u32,
+    /// The number of bytes remaining in the current message, if reading a message
+    remaining: Option<usize>,
+}
+
+impl std::fmt::Debug for Decoder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Decoder")
+            .field("schema", &self.schema)
+            .field("batch_size", &self.batch_size)
+            .finish()
+    }
+}
+
+impl Decoder {
+    /// Read length-delimited messages from `buf`, returning the number of bytes read
+    ///
+    /// This method returns once `batch_size` messages have been read since the
+    /// last call to [`Self::flush`], or `buf` is exhausted. Any remaining bytes
+    /// should be included in the next call to [`Self::decode`]
+    ///
+    /// There is no requirement that `buf` contains a whole number of messages, facilitating
+    /// integration with arbitrary byte streams, such as that yielded by [`BufRead`]
+    pub fn decode(&mut self, buf: &[u8]) -> Result<usize, ArrowError> {
+        let mut read = 0;
+        while read < buf.len() && self.ends.len() < self.batch_size {
+            match self.remaining {
+                Some(remaining) => {
+                    let len = remaining.min(buf.len() - read);
+                    self.buffer.extend_from_slice(&buf[read..read + len]);
+                    read += len;
+                    self.set_remaining(remaining - len);
+                }
+                None => {
+                    if self.prefix_shift >= 64 {
+                        return Err(ArrowError::ParseError(
+                            "Protobuf message length overflow".to_string(),
+                        ));
+                    }
+                    let byte = buf[read];
+                    read += 1;
+                    self.prefix |= ((byte & 0x7F) as u64) << self.prefix_shift;
+                    self.prefix_shift += 7;
+                    if byte & 0x80 == 0 {
+                        let len = self.prefix as usize;
+                        self.prefix = 0;
+                        self.prefix_shift = 0;
+                        self.set_remaining(len);
+                    }
+                }
+            }
+        }
+        Ok(read)
+    }
+
+    fn set_remaining(&mut self, remaining: usize) {
+        match remaining {
+            0 => {
+                self.ends.push(self.buffer.len());
+                self.remaining = None;
+            }
+            _ => self.remaining = Some(remaining),
+        }
+    }
+
+    /// Returns `true` if a message has been partially read
+    fn has_partial(&self) -> bool {
+        self.remaining.is_some() || self.prefix_shift != 0
+    }
+
+    /// Returns the number of complete messages buffered
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns `true` if there are no complete messages buffered
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Flushes the complete messages read so far to a [`RecordBatch`]
+    ///
+    /// Returns `Ok(None)` if no complete messages have been read since the last call,
+    /// any partially read message is retained
+    pub fn flush(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        let last = match self.ends.last() {
+            Some(last) => *last,
+            None => return Ok(None),
+        };
+
+        let mut start = 0;
+        let messages: Vec<_> = self
+            .ends
+            .iter()
+            .map(|end| {
+                let message = &self.buffer[start..*end];
+                start = *end;
+                Some(message)
+            })
+            .collect();
+        let array = decode_struct(&self.message, &messages)?;
+        let rows = messages.len();
+
+        self.buffer.drain(..last);
+        self.ends.clear();
+
+        let options = RecordBatchOptions::new().with_row_count(Some(rows));
+        let columns = array.columns().to_vec();
+        RecordBatch::try_new_with_options(self.schema.clone(), columns, &options)
+            .map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::tests::person_descriptors;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Float64Type, Int32Type, Int64Type};
+    use arrow_array::Array;
+    use arrow_cast::pretty::pretty_format_batches;
+
+    /// Encodes a message, prefixed by its length
+    fn delimited(message: &[u8]) -> Vec<u8> {
+        let mut out = vec![message.len() as u8];
+        out.extend_from_slice(message);
+        out
+    }
+
+    #[test]
+    fn test_decode() {
+        let mut data = vec![];
+        // id: 1, name: "a", values: [1, -1] (packed), address: { city: "x" }
+        data.extend(delimited(&[
+            0x08, 0x01, 0x12, 0x01, b'a', 0x22, 0x02, 0x02, 0x01, 0x2a, 0x03, 0x0a, 0x01,
+            b'x',
+        ]));
+        // An empty message
+        data.extend(delimited(&[]));
+        // id: 2, id: 3 (last wins), score: 1.5, values: [2] (unpacked),
+        // counts: { "k": 7 }, tags: ["t1", "t2"], unknown field 15
+        data.extend(delimited(&[
+            0x08, 0x02, 0x08, 0x03, 0x19, 0, 0, 0, 0, 0, 0, 0xf8, 0x3f, 0x20, 0x04, 0x3a,
+            0x05, 0x0a, 0x01, b'k', 0x10, 0x07, 0x32, 0x02, b't', b'1', 0x32, 0x02, b't',
+            b'2', 0x78, 0x05,
+        ]));
+
+        let builder =
+            ReaderBuilder::try_new(&person_descriptors(), "test.Person").unwrap();
+        let mut reader = builder.with_batch_size(2).build(data.as_slice()).unwrap();
+        let b1 = reader.next().unwrap().unwrap();
+        let b2 = reader.next().unwrap().unwrap();
+        assert!(reader.next().is_none());
+        assert_eq!(b1.num_rows(), 2);
+        assert_eq!(b2.num_rows(), 1);
+
+        let ids = b1.column(0).as_primitive::<Int64Type>();
+        assert_eq!(ids.values(), &[1, 0]);
+        let scores = b1.column(2).as_primitive::<Float64Type>();
+        assert_eq!(scores.null_count(), 2);
+        let values = b1.column(3).as_list::<i32>();
+        assert_eq!(
+            values.value(0).as_primitive::<Int32Type>().values(),
+            &[1, -1]
+        );
+        assert_eq!(values.value(1).len(), 0);
+        let address = b1.column(4).as_struct();
+        assert!(address.is_valid(0) && address.is_null(1));
+
+        let expected = [
+            "+----+------+-------+--------+---------+----------+--------+----------+",
+            "| id | name | score | values | address | tags     | counts | previous |",
+            "+----+------+-------+--------+---------+----------+--------+----------+",
+            "| 3  |      | 1.5   | [2]    |         | [t1, t2] | {k: 7} | []       |",
+            "+----+------+-------+--------+---------+----------+--------+----------+",
+        ];
+        let formatted = pretty_format_batches(&[b2]).unwrap().to_string();
+        assert_eq!(formatted, expected.join("\n"));
+    }
+
+    #[test]
+    fn test_decoder_partial() {
+        let builder =
+            ReaderBuilder::try_new(&person_descriptors(), "test.Person").unwrap();
+        let mut decoder = builder.build_decoder().unwrap();
+
+        let data = delimited(&[0x08, 0x96, 0x01]);
+        assert_eq!(decoder.decode(&data[..2]).unwrap(), 2);
+        assert!(decoder.is_empty());
+        assert!(decoder.flush().unwrap().is_none());
+
+        assert_eq!(decoder.decode(&data[2..]).unwrap(), 2);
+        assert_eq!(decoder.len(), 1);
+        let batch = decoder.flush().unwrap().unwrap();
+        assert_eq!(batch.column(0).as_primitive::<Int64Type>().values(), &[150]);
+
+        let mut reader = ReaderBuilder::try_new(&person_descriptors(), "test.Person")
+            .unwrap()
+            .build(&data[..3])
+            .unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Unexpected EOF reading protobuf message"
+        );
+    }
+}
@@ -0,0 +1,248 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::schema::{Cardinality, FieldKind, FieldType, MessageType, ScalarKind};
+use crate::wire::{numeric_values, FieldIter, WireValue};
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{NullBuffer, OffsetBuffer};
+use arrow_schema::{ArrowError, DataType};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The values of a single field across a set of messages
+#[derive(Debug, Clone)]
+struct FieldValues<'a> {
+    /// The offsets into `values` of each message
+    offsets: Vec<usize>,
+    values: Vec<WireValue<'a>>,
+}
+
+impl<'a> FieldValues<'a> {
+    fn row(&self, idx: usize) -> &[WireValue<'a>] {
+        &self.values[self.offsets[idx]..self.offsets[idx + 1]]
+    }
+}
+
+/// Decodes the encoded `messages` of type `ty`, with `None` indicating a null message
+pub(crate) fn decode_struct(
+    ty: &MessageType,
+    messages: &[Option<&[u8]>],
+) -> Result<StructArray, ArrowError> {
+    let index: HashMap<_, _> = ty
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(idx, field)| (field.number, idx))
+        .collect();
+
+    let mut columns = vec![
+        FieldValues {
+            offsets: vec![0],
+            values: vec![],
+        };
+        ty.fields.len()
+    ];
+    for message in messages {
+        if let Some(message) = message {
+            for field in FieldIter::new(message) {
+                let (number, value) = field?;
+                // Unknown fields are ignored
+                if let Some(idx) = index.get(&number) {
+                    columns[*idx].values.push(value);
+                }
+            }
+        }
+        columns
+            .iter_mut()
+            .for_each(|c| c.offsets.push(c.values.len()));
+    }
+
+    let arrays = ty
+        .fields
+        .iter()
+        .zip(&columns)
+        .map(|(field, values)| decode_field(field, values, messages.len()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let nulls = messages
+        .iter()
+        .any(Option::is_none)
+        .then(|| NullBuffer::from_iter(messages.iter().map(Option::is_some)));
+    StructArray::try_new(ty.arrow_fields.clone(), arrays, nulls)
+}
+
+fn offset(len: usize) -> Result<i32, ArrowError> {
+    i32::try_from(len).map_err(|_| {
+        ArrowError::ParseError("Protobuf repeated field offset overflow".to_string())
+    })
+}
+
+fn decode_field(
+    field: &FieldType,
+    values: &FieldValues<'_>,
+    num_rows: usize,
+) -> Result<ArrayRef, ArrowError> {
+    let repeated = field.cardinality != Cardinality::Singular;
+    // Absent values of non-nullable fields are decoded as the default value
+    let nullable = field.field.is_nullable();
+
+    let mut offsets = Vec::with_capacity(num_rows + 1);
+    offsets.push(0_i32);
+
+    let numeric = match &field.kind {
+        FieldKind::Scalar(kind) => kind.numeric_encoding().map(|e| (*kind, e)),
+        FieldKind::Message(_) => None,
+    };
+    let array = match numeric {
+        Some((kind, encoding)) => {
+            let mut items = Vec::with_capacity(num_rows);
+            let mut row_values = vec![];
+            for row in 0..num_rows {
+                row_values.clear();
+                for value in values.row(row) {
+                    numeric_values(value, encoding, &mut row_values)?;
+                }
+                match repeated {
+                    true => {
+                        items.extend(row_values.iter().map(|v| Some(*v)));
+                        offsets.push(offset(items.len())?);
+                    }
+                    // The last value of a singular field wins
+                    false => match (row_values.last(), nullable) {
+                        (None, false) => items.push(Some(0)),
+                        (v, _) => items.push(v.copied()),
+                    },
+                }
+            }
+            numeric_array(kind, &items)
+        }
+        None => {
+            let mut items = Vec::with_capacity(num_rows);
+            for row in 0..num_rows {
+                let mut last = None;
+                for value in values.row(row) {
+                    let bytes = match value {
+                        WireValue::LengthDelimited(b) => *b,
+                        v => {
+                            return Err(ArrowError::ParseError(format!(
+                                "Invalid protobuf wire value {v:?} for field {}",
+                                field.field.name()
+                            )))
+                        }
+                    };
+                    match repeated {
+                        true => items.push(Some(bytes)),
+                        false => last = Some(bytes),
+                    }
+                }
+                match repeated {
+                    true => offsets.push(offset(items.len())?),
+                    false if last.is_none() && !nullable => items.push(Some(&[][..])),
+                    false => items.push(last),
+                }
+            }
+            match &field.kind {
+                FieldKind::Scalar(kind) => bytes_array(*kind, &items)?,
+                FieldKind::Message(m) => Arc::new(decode_struct(m, &items)?),
+            }
+        }
+    };
+
+    let offsets = OffsetBuffer::new(offsets.into());
+    Ok(match (field.cardinality, field.field.data_type()) {
+        (Cardinality::Singular, _) => array,
+        (Cardinality::Repeated, DataType::List(item)) => {
+            Arc::new(ListArray::try_new(item.clone(), offsets, array, None)?)
+        }
+        (Cardinality::Map, DataType::Map(entries, _)) => {
+            let entries_array = array.as_struct().clone();
+            Arc::new(MapArray::try_new(
+                entries.clone(),
+                offsets,
+                entries_array,
+                None,
+                false,
+            )?)
+        }
+        _ => unreachable!(),
+    })
+}
+
+#[inline]
+fn zigzag(v: u64) -> i64 {
+    (v >> 1) as i64 ^ -((v & 1) as i64)
+}
+
+fn primitive<T: ArrowPrimitiveType>(
+    items: &[Option<u64>],
+    f: impl Fn(u64) -> T::Native,
+) -> ArrayRef {
+    Arc::new(PrimitiveArray::<T>::from_iter(
+        items.iter().map(|v| v.map(&f)),
+    ))
+}
+
+fn numeric_array(kind: ScalarKind, items: &[Option<u64>]) -> ArrayRef {
+    match kind {
+        ScalarKind::Double => primitive::<Float64Type>(items, f64::from_bits),
+        ScalarKind::Float => {
+            primitive::<Float32Type>(items, |v| f32::from_bits(v as u32))
+        }
+        ScalarKind::Int64 | ScalarKind::SFixed64 => {
+            primitive::<Int64Type>(items, |v| v as i64)
+        }
+        ScalarKind::SInt64 => primitive::<Int64Type>(items, zigzag),
+        ScalarKind::UInt64 | ScalarKind::Fixed64 => primitive::<UInt64Type>(items, |v| v),
+        // Negative int32 values are sign-extended to 64 bits
+        ScalarKind::Int32 | ScalarKind::Enum => {
+            primitive::<Int32Type>(items, |v| v as i64 as i32)
+        }
+        ScalarKind::SFixed32 => primitive::<Int32Type>(items, |v| v as u32 as i32),
+        ScalarKind::SInt32 => primitive::<Int32Type>(items, |v| zigzag(v) as i32),
+        ScalarKind::UInt32 | ScalarKind::Fixed32 => {
+            primitive::<UInt32Type>(items, |v| v as u32)
+        }
+        ScalarKind::Bool => Arc::new(BooleanArray::from_iter(
+            items.iter().map(|v| v.map(|v| v != 0)),
+        )),
+        ScalarKind::String | ScalarKind::Bytes => unreachable!(),
+    }
+}
+
+fn bytes_array(
+    kind: ScalarKind,
+    items: &[Option<&[u8]>],
+) -> Result<ArrayRef, ArrowError> {
+    Ok(match kind {
+        ScalarKind::String => {
+            let strings = items
+                .iter()
+                .map(|v| v.map(std::str::from_utf8).transpose())
+                .collect::<Result<StringArray, _>>()
+                .map_err(|e| {
+                    ArrowError::ParseError(format!(
+                        "Invalid UTF-8 in protobuf string: {e}"
+                    ))
+                })?;
+            Arc::new(strings)
+        }
+        ScalarKind::Bytes => Arc::new(BinaryArray::from_iter(items.iter().copied())),
+        _ => unreachable!(),
+    })
+}
@@ -0,0 +1,471 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Resolution of protobuf message descriptors to Arrow types
+
+use arrow_schema::{ArrowError, DataType, Field, Fields, Schema};
+use prost_types::field_descriptor_proto::{Label, Type};
+use prost_types::{DescriptorProto, FileDescriptorSet};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The scalar type of a protobuf field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ScalarKind {
+    Double,
+    Float,
+    Int64,
+    UInt64,
+    Int32,
+    Fixed64,
+    Fixed32,
+    Bool,
+    String,
+    Bytes,
+    UInt32,
+    Enum,
+    SFixed32,
+    SFixed64,
+    SInt32,
+    SInt64,
+}
+
+/// How a numeric value is encoded on the wire
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NumericEncoding {
+    Varint,
+    Fixed32,
+    Fixed64,
+}
+
+impl ScalarKind {
+    /// Returns the encoding of a numeric kind, or `None` for length-delimited kinds
+    pub(crate) fn numeric_encoding(&self) -> Option<NumericEncoding> {
+        match self {
+            Self::Int64
+            | Self::UInt64
+            | Self::Int32
+            | Self::Bool
+            | Self::UInt32
+            | Self::Enum
+            | Self::SInt32
+            | Self::SInt64 => Some(NumericEncoding::Varint),
+            Self::Float | Self::Fixed32 | Self::SFixed32 => {
+                Some(NumericEncoding::Fixed32)
+            }
+            Self::Double | Self::Fixed64 | Self::SFixed64 => {
+                Some(NumericEncoding::Fixed64)
+            }
+            Self::String | Self::Bytes => None,
+        }
+    }
+
+    pub(crate) fn data_type(&self) -> DataType {
+        match self {
+            Self::Double => DataType::Float64,
+            Self::Float => DataType::Float32,
+            Self::Int64 | Self::SFixed64 | Self::SInt64 => DataType::Int64,
+            Self::UInt64 | Self::Fixed64 => DataType::UInt64,
+            Self::Int32 | Self::SFixed32 | Self::SInt32 | Self::Enum => DataType::Int32,
+            Self::UInt32 | Self::Fixed32 => DataType::UInt32,
+            Self::Bool => DataType::Boolean,
+            Self::String => DataType::Utf8,
+            Self::Bytes => DataType::Binary,
+        }
+    }
+}
+
+/// The type of the values of a protobuf field
+#[derive(Debug, Clone)]
+pub(crate) enum FieldKind {
+    Scalar(ScalarKind),
+    Message(MessageType),
+}
+
+/// Whether a field is singular, repeated, or a map
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Cardinality {
+    Singular,
+    Repeated,
+    /// A repeated field of map entry messages, with a key field `1` and value field `2`
+    Map,
+}
+
+/// A resolved protobuf field
+#[derive(Debug, Clone)]
+pub(crate) struct FieldType {
+    pub(crate) number: u32,
+    pub(crate) kind: FieldKind,
+    pub(crate) cardinality: Cardinality,
+    pub(crate) field: Field,
+}
+
+/// A resolved protobuf message
+#[derive(Debug, Clone)]
+pub(crate) struct MessageType {
+    pub(crate) fields: Vec<FieldType>,
+    pub(crate) arrow_fields: Fields,
+}
+
+impl MessageType {
+    /// Resolves the message named `name` within `descriptors`
+    ///
+    /// `name` is the fully qualified name of the message, e.g. `my.package.Message`
+    pub(crate) fn try_new(
+        descriptors: &FileDescriptorSet,
+        name: &str,
+    ) -> Result<Self, ArrowError> {
+        let mut resolver = Resolver::default();
+        for file in &descriptors.file {
+            let prefix = match file.package() {
+                "" => String::new(),
+                package => format!(".{package}"),
+            };
+            let proto3 = file.syntax() == "proto3";
+            for message in &file.message_type {
+                resolver.register(&prefix, message, proto3);
+            }
+        }
+
+        let name = match name.starts_with('.') {
+            true => name.to_string(),
+            false => format!(".{name}"),
+        };
+        resolver.resolve(&name)
+    }
+
+    /// Returns the Arrow [`Schema`] of this message
+    pub(crate) fn schema(&self) -> Schema {
+        Schema::new(self.arrow_fields.clone())
+    }
+}
+
+#[derive(Default)]
+struct Resolver<'a> {
+    /// Message descriptors keyed by fully qualified name, and whether they are proto3
+    messages: HashMap<String, (&'a DescriptorProto, bool)>,
+    /// The messages currently being resolved, used to detect recursion
+    stack: Vec<String>,
+}
+
+impl<'a> Resolver<'a> {
+    fn register(&mut self, prefix: &str, message: &'a DescriptorProto, proto3: bool) {
+        let name = format!("{prefix}.{}", message.name());
+        for nested in &message.nested_type {
+            self.register(&name, nested, proto3);
+        }
+        self.messages.insert(name, (message, proto3));
+    }
+
+    fn resolve(&mut self, name: &str) -> Result<MessageType, ArrowError> {
+        let (message, proto3) = *self.messages.get(name).ok_or_else(|| {
+            ArrowError::SchemaError(format!("Protobuf message {name} not found"))
+        })?;
+        if self.stack.iter().any(|n| n == name) {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Recursive protobuf message {name} is not supported"
+            )));
+        }
+        self.stack.push(name.to_string());
+
+        let fields = message
+            .field
+            .iter()
+            .map(|field| {
+                let number = u32::try_from(field.number()).map_err(|_| {
+                    ArrowError::SchemaError(format!(
+                        "Invalid protobuf field number {}",
+                        field.number()
+                    ))
+                })?;
+
+                let kind = match field.r#type() {
+                    Type::Double => FieldKind::Scalar(ScalarKind::Double),
+                    Type::Float => FieldKind::Scalar(ScalarKind::Float),
+                    Type::Int64 => FieldKind::Scalar(ScalarKind::Int64),
+                    Type::Uint64 => FieldKind::Scalar(ScalarKind::UInt64),
+                    Type::Int32 => FieldKind::Scalar(ScalarKind::Int32),
+                    Type::Fixed64 => FieldKind::Scalar(ScalarKind::Fixed64),
+                    Type::Fixed32 => FieldKind::Scalar(ScalarKind::Fixed32),
+                    Type::Bool => FieldKind::Scalar(ScalarKind::Bool),
+                    Type::String => FieldKind::Scalar(ScalarKind::String),
+                    Type::Bytes => FieldKind::Scalar(ScalarKind::Bytes),
+                    Type::Uint32 => FieldKind::Scalar(ScalarKind::UInt32),
+                    Type::Enum => FieldKind::Scalar(ScalarKind::Enum),
+                    Type::Sfixed32 => FieldKind::Scalar(ScalarKind::SFixed32),
+                    Type::Sfixed64 => FieldKind::Scalar(ScalarKind::SFixed64),
+                    Type::Sint32 => FieldKind::Scalar(ScalarKind::SInt32),
+                    Type::Sint64 => FieldKind::Scalar(ScalarKind::SInt64),
+                    Type::Message => FieldKind::Message(self.resolve(field.type_name())?),
+                    Type::Group => {
+                        return Err(ArrowError::NotYetImplemented(format!(
+                            "Protobuf group field {} is not supported",
+                            field.name()
+                        )))
+                    }
+                };
+
+                let is_map_entry = |name: &str| {
+                    self.messages
+                        .get(name)
+                        .and_then(|(m, _)| m.options.as_ref())
+                        .map(|o| o.map_entry())
+                        .unwrap_or_default()
+                };
+                let cardinality = match field.label() {
+                    Label::Repeated if is_map_entry(field.type_name()) => {
+                        Cardinality::Map
+                    }
+                    Label::Repeated => Cardinality::Repeated,
+                    _ => Cardinality::Singular,
+                };
+
+                // Absent proto3 scalars are indistinguishable from their default value,
+                // unless explicitly optional, in which case they are part of a oneof
+                let nullable = match (&kind, field.label()) {
+                    (_, Label::Repeated) => false,
+                    (FieldKind::Message(_), _) => true,
+                    (_, _) if field.oneof_index.is_some() => true,
+                    (_, Label::Optional) => !proto3,
+                    (_, Label::Required) => false,
+                };
+
+                let item_type = match &kind {
+                    FieldKind::Scalar(s) => s.data_type(),
+                    FieldKind::Message(m) => DataType::Struct(m.arrow_fields.clone()),
+                };
+                let data_type = match cardinality {
+                    Cardinality::Singular => item_type,
+                    Cardinality::Repeated => {
+                        DataType::List(Arc::new(Field::new("item", item_type, false)))
+                    }
+                    Cardinality::Map => {
+                        let entries = Field::new("entries", item_type, false);
+                        DataType::Map(Arc::new(entries), false)
+                    }
+                };
+
+                Ok(FieldType {
+                    number,
+                    kind,
+                    cardinality,
+                    field: Field::new(field.name(), data_type, nullable),
+                })
+            })
+            .collect::<Result<Vec<_>, ArrowError>>()?;
+
+        self.stack.pop();
+        let arrow_fields = fields.iter().map(|f| f.field.clone()).collect();
+        Ok(MessageType {
+            fields,
+            arrow_fields,
+        })
+    }
+}
+
+/// Returns the Arrow [`Schema`] of the protobuf message named `message` in `descriptors`
+///
+/// Messages are mapped to [`DataType::Struct`], repeated fields to [`DataType::List`],
+/// map fields to [`DataType::Map`] and enumerations to their [`DataType::Int32`] value
+pub fn message_schema(
+    descriptors: &FileDescriptorSet,
+    message: &str,
+) -> Result<Schema, ArrowError> {
+    Ok(MessageType::try_new(descriptors, message)?.schema())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use prost_types::{FieldDescriptorProto, FileDescriptorProto, MessageOptions};
+
+    pub(crate) fn field(
+        name: &str,
+        number: i32,
+        ty: Type,
+        label: Label,
+        type_name: Option<&str>,
+    ) -> FieldDescriptorProto {
+        let mut field = FieldDescriptorProto {
+            name: Some(name.to_string()),
+            number: Some(number),
+            type_name: type_name.map(ToString::to_string),
+            ..Default::default()
+        };
+        field.set_type(ty);
+        field.set_label(label);
+        field
+    }
+
+    /// Returns the descriptors of
+    ///
+    /// ```protobuf
+    /// syntax = "proto3";
+    /// package test;
+    ///
+    /// message Person {
+    ///   message Address {
+    ///     string city = 1;
+    ///     uint32 zip = 2;
+    ///   }
+    ///   int64 id = 1;
+    ///   string name = 2;
+    ///   optional double score = 3;
+    ///   repeated sint32 values = 4;
+    ///   Address address = 5;
+    ///   repeated string tags = 6;
+    ///   map<string, int32> counts = 7;
+    ///   repeated Address previous = 8;
+    /// }
+    /// ```
+    pub(crate) fn person_descriptors() -> FileDescriptorSet {
+        let address = DescriptorProto {
+            name: Some("Address".to_string()),
+            field: vec![
+                field("city", 1, Type::String, Label::Optional, None),
+                field("zip", 2, Type::Uint32, Label::Optional, None),
+            ],
+            ..Default::default()
+        };
+        let counts_entry = DescriptorProto {
+            name: Some("CountsEntry".to_string()),
+            field: vec![
+                field("key", 1, Type::String, Label::Optional, None),
+                field("value", 2, Type::Int32, Label::Optional, None),
+            ],
+            options: Some(MessageOptions {
+                map_entry: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let mut score = field("score", 3, Type::Double, Label::Optional, None);
+        score.oneof_index = Some(0);
+        score.proto3_optional = Some(true);
+
+        let person = DescriptorProto {
+            name: Some("Person".to_string()),
+            field: vec![
+                field("id", 1, Type::Int64, Label::Optional, None),
+                field("name", 2, Type::String, Label::Optional, None),
+                score,
+                field("values", 4, Type::Sint32, Label::Repeated, None),
+                field(
+                    "address",
+                    5,
+                    Type::Message,
+                    Label::Optional,
+                    Some(".test.Person.Address"),
+                ),
+                field("tags", 6, Type::String, Label::Repeated, None),
+                field(
+                    "counts",
+                    7,
+                    Type::Message,
+                    Label::Repeated,
+                    Some(".test.Person.CountsEntry"),
+                ),
+                field(
+                    "previous",
+                    8,
+                    Type::Message,
+                    Label::Repeated,
+                    Some(".test.Person.Address"),
+                ),
+            ],
+            nested_type: vec![address, counts_entry],
+            oneof_decl: vec![Default::default()],
+            ..Default::default()
+        };
+
+        FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("person.proto".to_string()),
+                package: Some("test".to_string()),
+                message_type: vec![person],
+                syntax: Some("proto3".to_string()),
+                ..Default::default()
+            }],
+        }
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = message_schema(&person_descriptors(), "test.Person").unwrap();
+
+        let address = Fields::from(vec![
+            Field::new("city", DataType::Utf8, false),
+            Field::new("zip", DataType::UInt32, false),
+        ]);
+        let entries = Field::new(
+            "entries",
+            DataType::Struct(Fields::from(vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Int32, false),
+            ])),
+            false,
+        );
+        let expected = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, false),
+            Field::new("score", DataType::Float64, true),
+            Field::new_list("values", Field::new("item", DataType::Int32, false), false),
+            Field::new("address", DataType::Struct(address.clone()), true),
+            Field::new_list("tags", Field::new("item", DataType::Utf8, false), false),
+            Field::new("counts", DataType::Map(Arc::new(entries), false), false),
+            Field::new_list(
+                "previous",
+                Field::new("item", DataType::Struct(address), false),
+                false,
+            ),
+        ]);
+        assert_eq!(schema, expected);
+
+        let err = message_schema(&person_descriptors(), "test.Missing").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Protobuf message .test.Missing not found"
+        );
+    }
+
+    #[test]
+    fn test_recursive() {
+        let node = DescriptorProto {
+            name: Some("Node".to_string()),
+            field: vec![field(
+                "next",
+                1,
+                Type::Message,
+                Label::Optional,
+                Some(".Node"),
+            )],
+            ..Default::default()
+        };
+        let descriptors = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                message_type: vec![node],
+                ..Default::default()
+            }],
+        };
+        let err = message_schema(&descriptors, "Node").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: Recursive protobuf message .Node is not supported"
+        );
+    }
+}
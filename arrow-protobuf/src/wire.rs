@@ -0,0 +1,276 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The protobuf [wire format](https://protobuf.dev/programming-guides/encoding/)
+
+use crate::schema::NumericEncoding;
+use arrow_schema::ArrowError;
+
+const WIRE_VARINT: u8 = 0;
+const WIRE_FIXED64: u8 = 1;
+const WIRE_LEN: u8 = 2;
+const WIRE_FIXED32: u8 = 5;
+
+/// An encoded field value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WireValue<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    LengthDelimited(&'a [u8]),
+    Fixed32(u32),
+}
+
+fn eof() -> ArrowError {
+    ArrowError::ParseError("Unexpected end of protobuf message".to_string())
+}
+
+/// Reads a varint from the start of `buf`, returning the value and the number of bytes read
+pub(crate) fn read_varint(buf: &[u8]) -> Result<(u64, usize), ArrowError> {
+    let mut value = 0_u64;
+    for (idx, byte) in buf.iter().take(10).enumerate() {
+        value |= ((byte & 0x7F) as u64) << (idx * 7);
+        if byte & 0x80 == 0 {
+            return Ok((value, idx + 1));
+        }
+    }
+    match buf.len() < 10 {
+        true => Err(eof()),
+        false => Err(ArrowError::ParseError(
+            "Protobuf varint overflow".to_string(),
+        )),
+    }
+}
+
+fn split<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8], ArrowError> {
+    if buf.len() < len {
+        return Err(eof());
+    }
+    let (ret, remaining) = buf.split_at(len);
+    *buf = remaining;
+    Ok(ret)
+}
+
+fn next_varint(buf: &mut &[u8]) -> Result<u64, ArrowError> {
+    let (value, len) = read_varint(buf)?;
+    *buf = &buf[len..];
+    Ok(value)
+}
+
+/// An iterator over the fields of an encoded message
+#[derive(Debug)]
+pub(crate) struct FieldIter<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> FieldIter<'a> {
+    pub(crate) fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+
+    fn read_field(&mut self) -> Result<(u32, WireValue<'a>), ArrowError> {
+        let tag = next_varint(&mut self.buf)?;
+        let number = (tag >> 3) as u32;
+        let value = match (tag & 0x07) as u8 {
+            WIRE_VARINT => WireValue::Varint(next_varint(&mut self.buf)?),
+            WIRE_FIXED64 => {
+                let bytes = split(&mut self.buf, 8)?;
+                WireValue::Fixed64(u64::from_le_bytes(bytes.try_into().unwrap()))
+            }
+            WIRE_LEN => {
+                let len = next_varint(&mut self.buf)? as usize;
+                WireValue::LengthDelimited(split(&mut self.buf, len)?)
+            }
+            WIRE_FIXED32 => {
+                let bytes = split(&mut self.buf, 4)?;
+                WireValue::Fixed32(u32::from_le_bytes(bytes.try_into().unwrap()))
+            }
+            wire_type => {
+                return Err(ArrowError::ParseError(format!(
+                    "Unsupported protobuf wire type {wire_type} for field {number}"
+                )))
+            }
+        };
+        Ok((number, value))
+    }
+}
+
+impl<'a> Iterator for FieldIter<'a> {
+    type Item = Result<(u32, WireValue<'a>), ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.is_empty() {
+            return None;
+        }
+        let result = self.read_field();
+        if result.is_err() {
+            self.buf = &[];
+        }
+        Some(result)
+    }
+}
+
+/// Appends the numeric values of `value` to `out`, expanding packed repeated values
+pub(crate) fn numeric_values(
+    value: &WireValue<'_>,
+    encoding: NumericEncoding,
+    out: &mut Vec<u64>,
+) -> Result<(), ArrowError> {
+    match (*value, encoding) {
+        (WireValue::Varint(v), NumericEncoding::Varint) => out.push(v),
+        (WireValue::Fixed32(v), NumericEncoding::Fixed32) => out.push(v as u64),
+        (WireValue::Fixed64(v), NumericEncoding::Fixed64) => out.push(v),
+        (WireValue::LengthDelimited(mut buf), _) => {
+            while !buf.is_empty() {
+                let value = match encoding {
+                    NumericEncoding::Varint => next_varint(&mut buf)?,
+                    NumericEncoding::Fixed32 => {
+                        let bytes = split(&mut buf, 4)?;
+                        u32::from_le_bytes(bytes.try_into().unwrap()) as u64
+                    }
+                    NumericEncoding::Fixed64 => {
+                        let bytes = split(&mut buf, 8)?;
+                        u64::from_le_bytes(bytes.try_into().unwrap())
+                    }
+                };
+                out.push(value);
+            }
+        }
+        (value, _) => {
+            return Err(ArrowError::ParseError(format!(
+                "Invalid protobuf wire value {value:?} for {encoding:?} field"
+            )))
+        }
+    }
+    Ok(())
+}
+
+/// Appends a varint to `out`
+pub(crate) fn write_varint(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Appends a field tag to `out`
+fn write_tag(number: u32, wire_type: u8, out: &mut Vec<u8>) {
+    write_varint((number as u64) << 3 | wire_type as u64, out)
+}
+
+/// Appends a numeric field to `out`
+pub(crate) fn write_numeric(
+    number: u32,
+    encoding: NumericEncoding,
+    value: u64,
+    out: &mut Vec<u8>,
+) {
+    match encoding {
+        NumericEncoding::Varint => {
+            write_tag(number, WIRE_VARINT, out);
+            write_varint(value, out);
+        }
+        NumericEncoding::Fixed32 => {
+            write_tag(number, WIRE_FIXED32, out);
+            out.extend_from_slice(&(value as u32).to_le_bytes());
+        }
+        NumericEncoding::Fixed64 => {
+            write_tag(number, WIRE_FIXED64, out);
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+}
+
+/// Appends a packed repeated numeric field to `out`
+pub(crate) fn write_packed(
+    number: u32,
+    encoding: NumericEncoding,
+    values: impl IntoIterator<Item = u64>,
+    out: &mut Vec<u8>,
+) {
+    let mut buf = vec![];
+    for value in values {
+        match encoding {
+            NumericEncoding::Varint => write_varint(value, &mut buf),
+            NumericEncoding::Fixed32 => {
+                buf.extend_from_slice(&(value as u32).to_le_bytes())
+            }
+            NumericEncoding::Fixed64 => buf.extend_from_slice(&value.to_le_bytes()),
+        }
+    }
+    write_bytes(number, &buf, out)
+}
+
+/// Appends a length-delimited field to `out`
+pub(crate) fn write_bytes(number: u32, value: &[u8], out: &mut Vec<u8>) {
+    write_tag(number, WIRE_LEN, out);
+    write_varint(value.len() as u64, out);
+    out.extend_from_slice(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fields() {
+        // From the protobuf encoding guide
+        let data = [
+            0x08, 0x96, 0x01, 0x12, 0x07, b't', b'e', b's', b't', b'i', b'n', b'g',
+        ];
+        let fields = FieldIter::new(&data)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            fields,
+            vec![
+                (1, WireValue::Varint(150)),
+                (2, WireValue::LengthDelimited(b"testing"))
+            ]
+        );
+
+        let mut out = vec![];
+        write_numeric(1, NumericEncoding::Varint, 150, &mut out);
+        write_bytes(2, b"testing", &mut out);
+        assert_eq!(out, data);
+
+        let err = FieldIter::new(&[0x12, 0x07, b't'])
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Unexpected end of protobuf message"
+        );
+    }
+
+    #[test]
+    fn test_packed() {
+        // From the protobuf encoding guide
+        let data = [0x32, 0x06, 0x03, 0x8E, 0x02, 0x9E, 0xA7, 0x05];
+        let (number, value) = FieldIter::new(&data).next().unwrap().unwrap();
+        assert_eq!(number, 6);
+
+        let mut out = vec![];
+        numeric_values(&value, NumericEncoding::Varint, &mut out).unwrap();
+        assert_eq!(out, vec![3, 270, 86942]);
+
+        let mut encoded = vec![];
+        write_packed(6, NumericEncoding::Varint, out, &mut encoded);
+        assert_eq!(encoded, data);
+    }
+}
@@ -0,0 +1,380 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Write Arrow [`RecordBatch`] as length-delimited protobuf messages
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{Int64Array, RecordBatch};
+//! # use arrow_protobuf::writer::Writer;
+//! # use prost_types::FileDescriptorSet;
+//! # fn write(descriptors: &FileDescriptorSet, batch: &RecordBatch) -> Result<Vec<u8>, arrow_schema::ArrowError> {
+//! let mut writer = Writer::try_new(vec![], descriptors, "my.package.Message")?;
+//! writer.write(batch)?;
+//! let buf = writer.into_inner();
+//! # Ok(buf)
+//! # }
+//! ```
+
+use crate::schema::{Cardinality, FieldKind, FieldType, MessageType, ScalarKind};
+use crate::wire::{write_bytes, write_numeric, write_packed, write_varint};
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::{Array, ArrayRef, RecordBatch};
+use arrow_schema::ArrowError;
+use prost_types::FileDescriptorSet;
+use std::io::Write;
+
+/// Writes Arrow [`RecordBatch`] as length-delimited protobuf messages
+///
+/// Columns are matched to message fields by name, fields without a corresponding
+/// column and null values are omitted from the encoded messages
+#[derive(Debug)]
+pub struct Writer<W: Write> {
+    writer: W,
+    message: MessageType,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> Writer<W> {
+    /// Create a new [`Writer`] writing the message named `message` in `descriptors`
+    /// to `writer`
+    pub fn try_new(
+        writer: W,
+        descriptors: &FileDescriptorSet,
+        message: &str,
+    ) -> Result<Self, ArrowError> {
+        Ok(Self {
+            writer,
+            message: MessageType::try_new(descriptors, message)?,
+            buffer: vec![],
+        })
+    }
+
+    /// Write a [`RecordBatch`], encoding each row as a message
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        for field in batch.schema().fields() {
+            if !self
+                .message
+                .fields
+                .iter()
+                .any(|f| f.field.name() == field.name())
+            {
+                return Err(ArrowError::SchemaError(format!(
+                    "Column {} not found in protobuf message",
+                    field.name()
+                )));
+            }
+        }
+
+        let columns = self
+            .message
+            .fields
+            .iter()
+            .map(|f| batch.column_by_name(f.field.name()))
+            .collect();
+        let encoder = MessageEncoder::try_new(&self.message, columns)?;
+
+        let mut message = vec![];
+        self.buffer.clear();
+        for row in 0..batch.num_rows() {
+            message.clear();
+            encoder.encode(row, &mut message);
+            write_varint(message.len() as u64, &mut self.buffer);
+            self.buffer.extend_from_slice(&message);
+        }
+        self.writer.write_all(&self.buffer)?;
+        Ok(())
+    }
+
+    /// Unwraps this [`Writer`], returning the underlying writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Encodes rows of a set of columns as a message
+struct MessageEncoder<'a> {
+    fields: Vec<FieldEncoder<'a>>,
+}
+
+impl<'a> MessageEncoder<'a> {
+    fn try_new(
+        ty: &'a MessageType,
+        columns: Vec<Option<&'a ArrayRef>>,
+    ) -> Result<Self, ArrowError> {
+        let fields = ty
+            .fields
+            .iter()
+            .zip(columns)
+            .filter_map(|(f, c)| c.map(|c| FieldEncoder::try_new(f, c.as_ref())))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { fields })
+    }
+
+    fn encode(&self, row: usize, out: &mut Vec<u8>) {
+        self.fields.iter().for_each(|f| f.encode(row, out))
+    }
+}
+
+/// Encodes a single field of a message
+struct FieldEncoder<'a> {
+    field: &'a FieldType,
+    array: &'a dyn Array,
+    /// The values of a repeated or map field, or `array` for a singular field
+    values: &'a dyn Array,
+    /// The offsets of a repeated or map field
+    offsets: &'a [i32],
+    /// The encoder of a message field
+    child: Option<MessageEncoder<'a>>,
+}
+
+impl<'a> FieldEncoder<'a> {
+    fn try_new(field: &'a FieldType, array: &'a dyn Array) -> Result<Self, ArrowError> {
+        let invalid = || {
+            ArrowError::SchemaError(format!(
+                "Expected {} for protobuf field {}, got {}",
+                field.field.data_type(),
+                field.field.name(),
+                array.data_type()
+            ))
+        };
+
+        let (values, offsets): (&dyn Array, &[i32]) = match field.cardinality {
+            Cardinality::Singular => (array, &[]),
+            Cardinality::Repeated => {
+                let list = array.as_list_opt::<i32>().ok_or_else(invalid)?;
+                (list.values().as_ref(), list.value_offsets())
+            }
+            Cardinality::Map => {
+                let map = array.as_map_opt().ok_or_else(invalid)?;
+                (map.entries(), map.value_offsets())
+            }
+        };
+
+        let child = match &field.kind {
+            FieldKind::Scalar(kind) => {
+                if values.data_type() != &kind.data_type() {
+                    return Err(invalid());
+                }
+                None
+            }
+            FieldKind::Message(m) => {
+                let s = values.as_struct_opt().ok_or_else(invalid)?;
+                let columns = m
+                    .fields
+                    .iter()
+                    .map(|f| s.column_by_name(f.field.name()))
+                    .collect();
+                Some(MessageEncoder::try_new(m, columns)?)
+            }
+        };
+
+        Ok(Self {
+            field,
+            array,
+            values,
+            offsets,
+            child,
+        })
+    }
+
+    fn encode(&self, row: usize, out: &mut Vec<u8>) {
+        if self.array.is_null(row) {
+            return;
+        }
+        match self.field.cardinality {
+            Cardinality::Singular => self.encode_value(row, out),
+            Cardinality::Repeated | Cardinality::Map => {
+                let start = self.offsets[row] as usize;
+                let end = self.offsets[row + 1] as usize;
+                let numeric = match &self.field.kind {
+                    FieldKind::Scalar(kind) => {
+                        kind.numeric_encoding().map(|e| (*kind, e))
+                    }
+                    FieldKind::Message(_) => None,
+                };
+                match numeric {
+                    // Repeated numeric fields are packed
+                    Some((kind, encoding)) if end > start => {
+                        let values = (start..end)
+                            .filter(|idx| self.values.is_valid(*idx))
+                            .map(|idx| numeric_value(kind, self.values, idx));
+                        write_packed(self.field.number, encoding, values, out)
+                    }
+                    Some(_) => {}
+                    None => (start..end).for_each(|idx| self.encode_value(idx, out)),
+                }
+            }
+        }
+    }
+
+    fn encode_value(&self, idx: usize, out: &mut Vec<u8>) {
+        if self.values.is_null(idx) {
+            return;
+        }
+        let number = self.field.number;
+        match &self.field.kind {
+            FieldKind::Scalar(ScalarKind::String) => {
+                let value = self.values.as_string::<i32>().value(idx);
+                write_bytes(number, value.as_bytes(), out)
+            }
+            FieldKind::Scalar(ScalarKind::Bytes) => {
+                write_bytes(number, self.values.as_binary::<i32>().value(idx), out)
+            }
+            FieldKind::Scalar(kind) => {
+                let encoding = kind.numeric_encoding().unwrap();
+                write_numeric(
+                    number,
+                    encoding,
+                    numeric_value(*kind, self.values, idx),
+                    out,
+                )
+            }
+            FieldKind::Message(_) => {
+                let mut message = vec![];
+                self.child.as_ref().unwrap().encode(idx, &mut message);
+                write_bytes(number, &message, out)
+            }
+        }
+    }
+}
+
+/// Returns the value at `idx` of `values` as the raw value of a numeric protobuf field
+fn numeric_value(kind: ScalarKind, values: &dyn Array, idx: usize) -> u64 {
+    match kind {
+        ScalarKind::Double => values.as_primitive::<Float64Type>().value(idx).to_bits(),
+        ScalarKind::Float => {
+            values.as_primitive::<Float32Type>().value(idx).to_bits() as u64
+        }
+        ScalarKind::Int64 | ScalarKind::SFixed64 => {
+            values.as_primitive::<Int64Type>().value(idx) as u64
+        }
+        ScalarKind::SInt64 => {
+            let v = values.as_primitive::<Int64Type>().value(idx);
+            ((v << 1) ^ (v >> 63)) as u64
+        }
+        ScalarKind::UInt64 | ScalarKind::Fixed64 => {
+            values.as_primitive::<UInt64Type>().value(idx)
+        }
+        // Negative int32 values are sign-extended to 64 bits
+        ScalarKind::Int32 | ScalarKind::Enum => {
+            values.as_primitive::<Int32Type>().value(idx) as i64 as u64
+        }
+        ScalarKind::SFixed32 => {
+            values.as_primitive::<Int32Type>().value(idx) as u32 as u64
+        }
+        ScalarKind::SInt32 => {
+            let v = values.as_primitive::<Int32Type>().value(idx);
+            ((v << 1) ^ (v >> 31)) as u32 as u64
+        }
+        ScalarKind::UInt32 | ScalarKind::Fixed32 => {
+            values.as_primitive::<UInt32Type>().value(idx) as u64
+        }
+        ScalarKind::Bool => values.as_boolean().value(idx) as u64,
+        ScalarKind::String | ScalarKind::Bytes => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderBuilder;
+    use crate::schema::tests::person_descriptors;
+    use arrow_array::{Int64Array, StringArray};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_round_trip() {
+        let mut data = vec![];
+        // id: -1, name: "a", values: [1, -1], address: { city: "x", zip: 10 },
+        // previous: [{ zip: 1 }, {}]
+        data.extend([
+            31, 0x08, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01, 0x12,
+            0x01, b'a', 0x22, 0x02, 0x02, 0x01, 0x2a, 0x05, 0x0a, 0x01, b'x', 0x10, 0x0a,
+            0x42, 0x02, 0x10, 0x01, 0x42, 0x00,
+        ]);
+        // An empty message
+        data.push(0);
+        // id: 3, score: 1.5, counts: { "k": 7 }, tags: ["t1", "t2"]
+        data.extend([
+            26, 0x08, 0x03, 0x19, 0, 0, 0, 0, 0, 0, 0xf8, 0x3f, 0x3a, 0x05, 0x0a, 0x01,
+            b'k', 0x10, 0x07, 0x32, 0x02, b't', b'1', 0x32, 0x02, b't', b'2',
+        ]);
+
+        let descriptors = person_descriptors();
+        let builder = ReaderBuilder::try_new(&descriptors, "test.Person").unwrap();
+        let batch = builder
+            .build(data.as_slice())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(batch.num_rows(), 3);
+
+        let mut writer = Writer::try_new(vec![], &descriptors, "test.Person").unwrap();
+        writer.write(&batch).unwrap();
+        let buf = writer.into_inner();
+
+        let builder = ReaderBuilder::try_new(&descriptors, "test.Person").unwrap();
+        let read = builder
+            .build(buf.as_slice())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(read, batch);
+    }
+
+    #[test]
+    fn test_projected() {
+        let descriptors = person_descriptors();
+        let batch = RecordBatch::try_from_iter([
+            ("name", Arc::new(StringArray::from(vec!["a"])) as ArrayRef),
+            ("id", Arc::new(Int64Array::from(vec![150])) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut writer = Writer::try_new(vec![], &descriptors, "test.Person").unwrap();
+        writer.write(&batch).unwrap();
+        let buf = writer.into_inner();
+        assert_eq!(buf, &[6, 0x08, 0x96, 0x01, 0x12, 0x01, b'a']);
+
+        let batch = RecordBatch::try_from_iter([(
+            "unknown",
+            Arc::new(Int64Array::from(vec![1])) as ArrayRef,
+        )])
+        .unwrap();
+        let mut writer = Writer::try_new(vec![], &descriptors, "test.Person").unwrap();
+        let err = writer.write(&batch).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Schema error: Column unknown not found in protobuf message"
+        );
+
+        let batch = RecordBatch::try_from_iter([(
+            "id",
+            Arc::new(StringArray::from(vec!["1"])) as ArrayRef,
+        )])
+        .unwrap();
+        let err = writer.write(&batch).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Schema error: Expected Int64 for protobuf field id, got Utf8"
+        );
+    }
+}
@@ -409,6 +409,131 @@ def test_record_batch_reader():
     got_batches = list(b)
     assert got_batches == batches
 
+def test_boxed_reader():
+    """
+    Python -> Rust -> Python
+    """
+    schema = pa.schema([('ints', pa.list_(pa.int32()))], metadata={b'key1': b'value1'})
+    batches = [
+        pa.record_batch([[[1], [2, 42]]], schema),
+        pa.record_batch([[None, [], [5, 6]]], schema),
+    ]
+    a = pa.RecordBatchReader.from_batches(schema, batches)
+    b = rust.round_trip_boxed_reader(a)
+
+    assert b.schema == schema
+    got_batches = list(b)
+    assert got_batches == batches
+
+def test_table():
+    """
+    Python -> Rust -> Python
+    """
+    schema = pa.schema([('ints', pa.list_(pa.int32()))], metadata={b'key1': b'value1'})
+    batches = [
+        pa.record_batch([[[1], [2, 42]]], schema),
+        pa.record_batch([[None, [], [5, 6]]], schema),
+    ]
+    a = pa.Table.from_batches(batches, schema)
+    b = rust.round_trip_table(a)
+
+    assert b.schema == schema
+    assert b == a
+    # the chunks are preserved rather than concatenated
+    assert b.column(0).num_chunks == 2
+    assert b.to_batches() == batches
+
+def test_empty_table():
+    schema = pa.schema([('ints', pa.int32())])
+    a = pa.Table.from_batches([], schema)
+    b = rust.round_trip_table(a)
+
+    assert b.schema == schema
+    assert b.num_rows == 0
+
+class PyCapsuleWrapper:
+    """
+    Wraps a pyarrow object, only exposing its Arrow PyCapsule Interface, to
+    test objects from libraries other than pyarrow
+    """
+
+    def __init__(self, obj):
+        self.obj = obj
+
+    def __arrow_c_schema__(self):
+        return self.obj.__arrow_c_schema__()
+
+    def __arrow_c_array__(self, requested_schema=None):
+        return self.obj.__arrow_c_array__(requested_schema)
+
+    def __arrow_c_stream__(self, requested_schema=None):
+        return self.obj.__arrow_c_stream__(requested_schema)
+
+
+_requires_pycapsule = pytest.mark.skipif(
+    not hasattr(pa.Array, "__arrow_c_array__"),
+    reason="pyarrow does not implement the Arrow PyCapsule Interface",
+)
+
+
+@_requires_pycapsule
+def test_pycapsule_schema():
+    schema = pa.schema([('ints', pa.list_(pa.int32()))], metadata={b'key1': b'value1'})
+    assert rust.round_trip_type(PyCapsuleWrapper(pa.int64())) == pa.int64()
+    field = pa.field('ints', pa.int32(), metadata={b'key1': b'value1'})
+    assert rust.round_trip_field(PyCapsuleWrapper(field)) == field
+    assert rust.round_trip_schema(PyCapsuleWrapper(schema)) == schema
+
+
+@_requires_pycapsule
+def test_pycapsule_array():
+    a = pa.array([1, None, 3], type=pa.int64())
+    b = rust.round_trip_array(PyCapsuleWrapper(a))
+    b.validate(full=True)
+    assert a.to_pylist() == b.to_pylist()
+    assert a.type == b.type
+    del a
+    del b
+
+
+@_requires_pycapsule
+def test_pycapsule_record_batch():
+    schema = pa.schema([('ints', pa.list_(pa.int32()))], metadata={b'key1': b'value1'})
+    a = pa.record_batch([[[1], [2, 42]]], schema)
+    b = rust.round_trip_record_batch(PyCapsuleWrapper(a))
+    assert a == b
+    assert b.schema == schema
+    del a
+    del b
+
+
+@_requires_pycapsule
+def test_pycapsule_record_batch_reader():
+    schema = pa.schema([('ints', pa.list_(pa.int32()))], metadata={b'key1': b'value1'})
+    batches = [
+        pa.record_batch([[[1], [2, 42]]], schema),
+        pa.record_batch([[None, [], [5, 6]]], schema),
+    ]
+    a = pa.RecordBatchReader.from_batches(schema, batches)
+    b = rust.round_trip_record_batch_reader(PyCapsuleWrapper(a))
+
+    assert b.schema == schema
+    assert list(b) == batches
+
+
+@_requires_pycapsule
+def test_pycapsule_table():
+    schema = pa.schema([('ints', pa.int32())])
+    batches = [
+        pa.record_batch([[1, 2]], schema),
+        pa.record_batch([[3, None]], schema),
+    ]
+    a = pa.Table.from_batches(batches, schema)
+    b = rust.round_trip_table(PyCapsuleWrapper(a))
+    assert b == a
+    assert b.column(0).num_chunks == 2
+
+
 def test_reject_other_classes():
     # Arbitrary type that is not a PyArrow type
     not_pyarrow = ["hello"]
@@ -430,3 +555,6 @@ def test_reject_other_classes():
     
     with pytest.raises(TypeError, match="Expected instance of pyarrow.lib.RecordBatchReader, got builtins.list"):
         rust.round_trip_record_batch_reader(not_pyarrow)
+
+    with pytest.raises(TypeError, match="Expected instance of pyarrow.lib.Table, got builtins.list"):
+        rust.round_trip_table(not_pyarrow)
@@ -27,17 +27,18 @@ use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::{ArrowError, DataType, SortOptions};
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Computes the dictionary mapping for the given dictionary values
 pub fn compute_dictionary_mapping(
     interner: &mut OrderPreservingInterner,
-    values: &ArrayRef,
+    values: &dyn Array,
 ) -> Vec<Option<Interned>> {
     downcast_primitive_array! {
         values => interner
             .intern(values.iter().map(|x| x.map(|x| x.encode()))),
         DataType::Binary => {
-            let iter = as_generic_binary_array::<i64>(values).iter();
+            let iter = as_generic_binary_array::<i32>(values).iter();
             interner.intern(iter)
         }
         DataType::LargeBinary => {
@@ -86,8 +87,21 @@ pub fn encode_dictionary<K: ArrowDictionaryKeyType>(
     normalized_keys: &[Option<&[u8]>],
     opts: SortOptions,
 ) {
-    for (offset, k) in offsets.iter_mut().skip(1).zip(column.keys()) {
-        match k.and_then(|k| normalized_keys[k.as_usize()]) {
+    let keys = column.keys().iter();
+    let keys = keys.map(|k| k.and_then(|k| normalized_keys[k.as_usize()]));
+    encode_interned(data, offsets, keys, opts)
+}
+
+/// Interned values are encoded in the same way as dictionary values, with each
+/// value encoded as the corresponding normalized key
+pub fn encode_interned<'a, I: Iterator<Item = Option<&'a [u8]>>>(
+    data: &mut [u8],
+    offsets: &mut [usize],
+    normalized_keys: I,
+    opts: SortOptions,
+) {
+    for (offset, k) in offsets.iter_mut().skip(1).zip(normalized_keys) {
+        match k {
             Some(normalized_key) => {
                 let end_offset = *offset + 1 + normalized_key.len();
                 data[*offset] = 1;
@@ -126,14 +140,6 @@ pub unsafe fn decode_dictionary<K: ArrowDictionaryKeyType>(
     let len = rows.len();
     let mut dictionary: HashMap<Interned, K::Native> = HashMap::with_capacity(len);
 
-    let null_sentinel = null_sentinel(options);
-
-    // If descending, the null terminator will have been negated
-    let null_terminator = match options.descending {
-        true => 0xFF,
-        false => 0_u8,
-    };
-
     let mut null_builder = BooleanBufferBuilder::new(len);
     let mut keys = BufferBuilder::<K::Native>::new(len);
     let mut values = Vec::with_capacity(len);
@@ -141,34 +147,14 @@ pub unsafe fn decode_dictionary<K: ArrowDictionaryKeyType>(
     let mut key_scratch = Vec::new();
 
     for row in rows {
-        if row[0] == null_sentinel {
-            null_builder.append(false);
-            null_count += 1;
-            *row = &row[1..];
-            keys.append(K::Native::default());
-            continue;
-        }
-
-        let key_offset = row
-            .iter()
-            .skip(1)
-            .position(|x| *x == null_terminator)
-            .unwrap();
-
-        // Extract the normalized key including the null terminator
-        let key = &row[1..key_offset + 2];
-        *row = &row[key_offset + 2..];
-
-        let interned = match options.descending {
-            true => {
-                // If options.descending the normalized key will have been
-                // negated we must first reverse this
-                key_scratch.clear();
-                key_scratch.extend_from_slice(key);
-                key_scratch.iter_mut().for_each(|o| *o = !*o);
-                interner.lookup(&key_scratch).unwrap()
+        let interned = match decode_key(interner, options, row, &mut key_scratch) {
+            Some(interned) => interned,
+            None => {
+                null_builder.append(false);
+                null_count += 1;
+                keys.append(K::Native::default());
+                continue;
             }
-            false => interner.lookup(key).unwrap(),
         };
 
         let k = match dictionary.entry(interned) {
@@ -210,6 +196,84 @@ pub unsafe fn decode_dictionary<K: ArrowDictionaryKeyType>(
     Ok(DictionaryArray::from(builder.build_unchecked()))
 }
 
+/// Decodes the interned value at the start of `row`, advancing `row` past it
+///
+/// Returns `None` if the value is null
+fn decode_key(
+    interner: &OrderPreservingInterner,
+    options: SortOptions,
+    row: &mut &[u8],
+    key_scratch: &mut Vec<u8>,
+) -> Option<Interned> {
+    if row[0] == null_sentinel(options) {
+        *row = &row[1..];
+        return None;
+    }
+
+    // If descending, the null terminator will have been negated
+    let null_terminator = match options.descending {
+        true => 0xFF,
+        false => 0_u8,
+    };
+
+    let key_offset = row
+        .iter()
+        .skip(1)
+        .position(|x| *x == null_terminator)
+        .unwrap();
+
+    // Extract the normalized key including the null terminator
+    let key = &row[1..key_offset + 2];
+    *row = &row[key_offset + 2..];
+
+    let interned = match options.descending {
+        true => {
+            // If options.descending the normalized key will have been
+            // negated we must first reverse this
+            key_scratch.clear();
+            key_scratch.extend_from_slice(key);
+            key_scratch.iter_mut().for_each(|o| *o = !*o);
+            interner.lookup(key_scratch).unwrap()
+        }
+        false => interner.lookup(key).unwrap(),
+    };
+    Some(interned)
+}
+
+/// Decodes an array of `data_type` from interned values
+///
+/// # Safety
+///
+/// `interner` must contain valid data for the provided `data_type`
+pub unsafe fn decode_interned(
+    interner: &OrderPreservingInterner,
+    data_type: &DataType,
+    options: SortOptions,
+    rows: &mut [&[u8]],
+) -> ArrayRef {
+    let mut key_scratch = Vec::new();
+    let values: Vec<_> = rows
+        .iter_mut()
+        .map(|row| {
+            decode_key(interner, options, row, &mut key_scratch)
+                .map(|interned| interner.value(interned))
+        })
+        .collect();
+
+    let to_str = |v: Option<&[u8]>| v.map(|v| std::str::from_utf8_unchecked(v));
+    match data_type {
+        DataType::Binary => Arc::new(BinaryArray::from_iter(values)),
+        DataType::LargeBinary => Arc::new(LargeBinaryArray::from_iter(values)),
+        DataType::Utf8 => {
+            Arc::new(values.into_iter().map(to_str).collect::<StringArray>())
+        }
+        DataType::LargeUtf8 => {
+            Arc::new(values.into_iter().map(to_str).collect::<LargeStringArray>())
+        }
+        _ => unreachable!(),
+    }
+}
+
 /// Decodes a binary array from dictionary values
 ///
 /// # Safety
@@ -136,8 +136,8 @@ use arrow_data::ArrayDataBuilder;
 use arrow_schema::*;
 
 use crate::dictionary::{
-    compute_dictionary_mapping, decode_dictionary, encode_dictionary,
-    encode_dictionary_values,
+    compute_dictionary_mapping, decode_dictionary, decode_interned, encode_dictionary,
+    encode_dictionary_values, encode_interned,
 };
 use crate::fixed::{decode_bool, decode_fixed_size_binary, decode_primitive};
 use crate::interner::OrderPreservingInterner;
@@ -319,6 +319,10 @@ mod variable;
 ///      Input                  Row Format
 /// ```
 ///
+/// The same encoding can optionally be used for non-dictionary string and binary columns,
+/// see [`SortField::intern_values`]. This can substantially reduce the size of the rows
+/// for low-cardinality columns, at the cost of retaining every distinct value in memory.
+///
 /// ## Struct Encoding
 ///
 /// A null is encoded as a `0_u8`.
@@ -435,6 +439,10 @@ enum Codec {
     ///
     /// Used when not preserving dictionary encoding
     DictionaryValues(RowConverter, OwnedRow),
+    /// The interner used to encode non-dictionary values, and its memory limit
+    ///
+    /// Used when interning values
+    Interned(OrderPreservingInterner, usize),
     /// A row converter for the child fields
     /// and the encoding of a row containing only nulls
     Struct(RowConverter, OwnedRow),
@@ -444,6 +452,18 @@ enum Codec {
 
 impl Codec {
     fn new(sort_field: &SortField) -> Result<Self, ArrowError> {
+        if let Some(limit) = sort_field.intern_limit {
+            if matches!(
+                sort_field.data_type,
+                DataType::Binary
+                    | DataType::LargeBinary
+                    | DataType::Utf8
+                    | DataType::LargeUtf8
+            ) {
+                return Ok(Self::Interned(Default::default(), limit));
+            }
+        }
+
         match &sort_field.data_type {
             DataType::Dictionary(_, values) => match sort_field.preserve_dictionaries {
                 true => Ok(Self::Dictionary(Default::default())),
@@ -518,7 +538,7 @@ impl Codec {
                     _ => unreachable!()
                 };
 
-                let mapping = compute_dictionary_mapping(interner, values)
+                let mapping = compute_dictionary_mapping(interner, values.as_ref())
                     .into_iter()
                     .map(|maybe_interned| {
                         maybe_interned.map(|interned| interner.normalized_key(interned))
@@ -536,6 +556,23 @@ impl Codec {
                 let rows = converter.convert_columns(&[values.clone()])?;
                 Ok(Encoder::DictionaryValues(rows, nulls.row()))
             }
+            Codec::Interned(interner, limit) => {
+                let mapping = compute_dictionary_mapping(interner, array);
+                if interner.size() > *limit {
+                    return Err(ArrowError::MemoryError(format!(
+                        "Interned values exceed memory limit of {limit} bytes"
+                    )));
+                }
+
+                let mapping = mapping
+                    .into_iter()
+                    .map(|maybe_interned| {
+                        maybe_interned.map(|interned| interner.normalized_key(interned))
+                    })
+                    .collect();
+
+                Ok(Encoder::Interned(mapping))
+            }
             Codec::Struct(converter, null) => {
                 let v = as_struct_array(array);
                 let rows = converter.convert_columns(v.columns())?;
@@ -557,6 +594,7 @@ impl Codec {
         match self {
             Codec::Stateless => 0,
             Codec::Dictionary(interner) => interner.size(),
+            Codec::Interned(interner, _) => interner.size(),
             Codec::DictionaryValues(converter, nulls) => {
                 converter.size() + nulls.data.len()
             }
@@ -574,6 +612,8 @@ enum Encoder<'a> {
     Dictionary(Vec<Option<&'a [u8]>>),
     /// The encoding of the child array and the encoding of a null row
     DictionaryValues(Rows, Row<'a>),
+    /// The normalized key of each value
+    Interned(Vec<Option<&'a [u8]>>),
     /// The row encoding of the child arrays and the encoding of a null row
     ///
     /// It is necessary to encode to a temporary [`Rows`] to avoid serializing
@@ -593,6 +633,8 @@ pub struct SortField {
     data_type: DataType,
     /// Preserve dictionaries
     preserve_dictionaries: bool,
+    /// The memory limit for interned values, if interning
+    intern_limit: Option<usize>,
 }
 
 impl SortField {
@@ -607,6 +649,7 @@ impl SortField {
             options,
             data_type,
             preserve_dictionaries: true,
+            intern_limit: None,
         }
     }
 
@@ -629,6 +672,84 @@ impl SortField {
         }
     }
 
+    /// Encode string and binary values using an order-preserving mapping, as is done for
+    /// preserved dictionaries, retaining at most `limit` bytes of interned state
+    ///
+    /// For low-cardinality columns this can substantially reduce the size of the encoded
+    /// rows, and the cost of comparing them. The values are decoded back to their original
+    /// type by [`RowConverter::convert_rows`].
+    ///
+    /// Each distinct value is retained by the [`RowConverter`] until it is dropped. Once the
+    /// interned values exceed `limit` bytes, as reported by [`RowConverter::size`], encoding
+    /// returns [`ArrowError::MemoryError`]. Rows already encoded remain valid, however, any
+    /// further rows must be encoded by a new [`RowConverter`], and are not comparable with
+    /// those produced by this one. An external sort might, for example, spill its sorted
+    /// rows and start a new run.
+    ///
+    /// This has no effect on types other than `Utf8`, `LargeUtf8`, `Binary` and `LargeBinary`
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, StringArray};
+    /// # use arrow_row::{RowConverter, SortField};
+    /// # use arrow_schema::DataType;
+    /// #
+    /// let field = SortField::new(DataType::Utf8).intern_values(1024 * 1024);
+    /// let mut converter = RowConverter::new(vec![field]).unwrap();
+    ///
+    /// let array = Arc::new(StringArray::from(vec!["a long repeated value"; 3])) as ArrayRef;
+    /// let rows = converter.convert_columns(&[Arc::clone(&array)]).unwrap();
+    /// // Each row contains a short key, instead of the full value
+    /// assert!(rows.row(0).as_ref().len() < 8);
+    ///
+    /// let back = converter.convert_rows(&rows).unwrap();
+    /// assert_eq!(back[0].as_ref(), array.as_ref());
+    /// ```
+    pub fn intern_values(self, limit: usize) -> Self {
+        Self {
+            intern_limit: Some(limit),
+            ..self
+        }
+    }
+
+    /// Returns the length in bytes of the encoding of every value of this field, or
+    /// `None` if the length depends on the value
+    ///
+    /// Primitive, boolean and fixed size binary types have a fixed length encoding, as
+    /// do dictionaries that are not preserved with such values, and structs of such
+    /// types. Preserved dictionaries, interned values, and variable length types do not.
+    pub fn encoded_width(&self) -> Option<usize> {
+        if self.intern_limit.is_some()
+            && matches!(
+                self.data_type,
+                DataType::Binary
+                    | DataType::LargeBinary
+                    | DataType::Utf8
+                    | DataType::LargeUtf8
+            )
+        {
+            return None;
+        }
+
+        use fixed::FixedLengthEncoding;
+
+        let child = |d: &DataType| Self::new_with_options(d.clone(), self.options);
+        match &self.data_type {
+            DataType::Null => Some(0),
+            DataType::Boolean => Some(bool::ENCODED_LEN),
+            DataType::FixedSizeBinary(len) => Some(1 + len.to_usize()?),
+            DataType::Dictionary(_, values) => match self.preserve_dictionaries {
+                true => None,
+                false => child(values).encoded_width(),
+            },
+            DataType::Struct(f) => {
+                let widths = f.iter().map(|x| child(x.data_type()).encoded_width());
+                Some(1 + widths.sum::<Option<usize>>()?)
+            }
+            d => Some(1 + d.primitive_width()?),
+        }
+    }
+
     /// Return size of this instance in bytes.
     ///
     /// Includes the size of `Self`.
@@ -654,6 +775,65 @@ impl RowConverter {
         })
     }
 
+    /// Create a new [`RowConverter`] whose rows all have the same length
+    ///
+    /// Dictionaries are not preserved, and values are not interned, see
+    /// [`SortField::preserve_dictionaries`] and [`SortField::intern_values`]. Returns an
+    /// error if any of the fields still lacks a fixed length encoding, as reported by
+    /// [`SortField::encoded_width`].
+    ///
+    /// Fixed length rows can be sorted with strategies, such as radix sort, that the
+    /// variable length encoding prevents, with [`RowConverter::row_width`] giving the
+    /// length of every row.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{DictionaryArray, Int32Array};
+    /// # use arrow_array::types::Int8Type;
+    /// # use arrow_row::{RowConverter, SortField};
+    /// # use arrow_schema::DataType;
+    /// #
+    /// let dict_type = DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Int32));
+    /// let fields = vec![SortField::new(DataType::Int32), SortField::new(dict_type)];
+    /// let mut converter = RowConverter::new_fixed_width(fields).unwrap();
+    /// assert_eq!(converter.row_width(), Some(10));
+    ///
+    /// let values = Arc::new(Int32Array::from(vec![5, 6]));
+    /// let dict = DictionaryArray::<Int8Type>::try_new(vec![1, 0, 1].into(), values).unwrap();
+    /// let columns = [Arc::new(Int32Array::from(vec![1, 2, 3])) as _, Arc::new(dict) as _];
+    /// let rows = converter.convert_columns(&columns).unwrap();
+    /// assert!(rows.iter().all(|row| row.as_ref().len() == 10));
+    ///
+    /// let err = RowConverter::new_fixed_width(vec![SortField::new(DataType::Utf8)]);
+    /// assert!(err.is_err());
+    /// ```
+    pub fn new_fixed_width(fields: Vec<SortField>) -> Result<Self, ArrowError> {
+        let fields: Vec<_> = fields
+            .into_iter()
+            .map(|f| SortField {
+                preserve_dictionaries: false,
+                intern_limit: None,
+                ..f
+            })
+            .collect();
+
+        if let Some(f) = fields.iter().find(|f| f.encoded_width().is_none()) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Fixed width row format not supported for: {}",
+                f.data_type
+            )));
+        }
+        Self::new(fields)
+    }
+
+    /// Returns the length in bytes of every row produced by this [`RowConverter`], or
+    /// `None` if the length of a row depends on its values
+    ///
+    /// See [`SortField::encoded_width`] and [`RowConverter::new_fixed_width`]
+    pub fn row_width(&self) -> Option<usize> {
+        self.fields.iter().map(|f| f.encoded_width()).sum()
+    }
+
     /// Check if the given fields are supported by the row format.
     pub fn supports_fields(fields: &[SortField]) -> bool {
         fields.iter().all(|x| Self::supports_datatype(&x.data_type))
@@ -726,29 +906,8 @@ impl RowConverter {
             "rows were not produced by this RowConverter"
         );
 
-        if columns.len() != self.fields.len() {
-            return Err(ArrowError::InvalidArgumentError(format!(
-                "Incorrect number of arrays provided to RowConverter, expected {} got {}",
-                self.fields.len(),
-                columns.len()
-            )));
-        }
-
-        let encoders = columns
-            .iter()
-            .zip(&mut self.codecs)
-            .zip(self.fields.iter())
-            .map(|((column, codec), field)| {
-                if !column.data_type().equals_datatype(&field.data_type) {
-                    return Err(ArrowError::InvalidArgumentError(format!(
-                        "RowConverter column schema mismatch, expected {} got {}",
-                        field.data_type,
-                        column.data_type()
-                    )));
-                }
-                codec.encoder(column.as_ref())
-            })
-            .collect::<Result<Vec<_>, _>>()?;
+        let fields = Arc::clone(&self.fields);
+        let encoders = self.encoders(columns)?;
 
         let write_offset = rows.num_rows();
         let lengths = row_lengths(columns, &encoders);
@@ -780,8 +939,7 @@ impl RowConverter {
         // encoders not assuming a zero-initialized buffer
         rows.buffer.resize(cur_offset, 0);
 
-        for ((column, field), encoder) in
-            columns.iter().zip(self.fields.iter()).zip(encoders)
+        for ((column, field), encoder) in columns.iter().zip(fields.iter()).zip(encoders)
         {
             // We encode a column at a time to minimise dispatch overheads
             encode_column(
@@ -803,6 +961,160 @@ impl RowConverter {
         Ok(())
     }
 
+    /// Returns the exact number of bytes needed to encode `columns` as rows
+    ///
+    /// This can be used to bound the memory used when converting data, for example to
+    /// decide when to spill during an external sort, before encoding it with
+    /// [`RowConverter::append`] or [`RowConverter::encode_to_slice`].
+    ///
+    /// Note: this may update the state of this [`RowConverter`] for dictionary encoded
+    /// columns, in the same way as encoding `columns` would
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::StringArray;
+    /// # use arrow_row::{RowConverter, SortField};
+    /// # use arrow_schema::DataType;
+    /// #
+    /// let mut converter = RowConverter::new(vec![SortField::new(DataType::Utf8)]).unwrap();
+    /// let columns = [Arc::new(StringArray::from(vec!["hello", "world"])) as _];
+    ///
+    /// let size = converter.encoded_size(&columns).unwrap();
+    /// let rows = converter.convert_columns(&columns).unwrap();
+    /// assert_eq!(size, rows.row(0).as_ref().len() + rows.row(1).as_ref().len());
+    /// ```
+    pub fn encoded_size(&mut self, columns: &[ArrayRef]) -> Result<usize, ArrowError> {
+        let encoders = self.encoders(columns)?;
+        let lengths = row_lengths(columns, &encoders);
+        lengths.into_iter().try_fold(0_usize, |acc, l| {
+            acc.checked_add(l).ok_or_else(|| {
+                ArrowError::ComputeError("overflow computing encoded size".to_string())
+            })
+        })
+    }
+
+    /// Returns the number of bytes needed to encode each row of `columns`
+    ///
+    /// Like [`RowConverter::encoded_size`], this can be used to plan the encoding of
+    /// `columns`, for example to split a batch into chunks with a bounded size, or to
+    /// choose a sort strategy based on the distribution of row lengths
+    ///
+    /// Note: this may update the state of this [`RowConverter`] for dictionary encoded
+    /// columns, in the same way as encoding `columns` would
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::StringArray;
+    /// # use arrow_row::{RowConverter, SortField};
+    /// # use arrow_schema::DataType;
+    /// #
+    /// let mut converter = RowConverter::new(vec![SortField::new(DataType::Utf8)]).unwrap();
+    /// let columns = [Arc::new(StringArray::from(vec!["a", "a long string value"])) as _];
+    ///
+    /// let lengths = converter.encoded_lengths(&columns).unwrap();
+    /// let rows = converter.convert_columns(&columns).unwrap();
+    /// assert_eq!(lengths, vec![rows.row(0).as_ref().len(), rows.row(1).as_ref().len()]);
+    /// ```
+    pub fn encoded_lengths(
+        &mut self,
+        columns: &[ArrayRef],
+    ) -> Result<Vec<usize>, ArrowError> {
+        let encoders = self.encoders(columns)?;
+        Ok(row_lengths(columns, &encoders))
+    }
+
+    /// Encode `columns` as rows into the provided `data`, returning the offsets of
+    /// the encoded rows
+    ///
+    /// Row `i` is written to `data[offsets[i]..offsets[i + 1]]`, and can be read back
+    /// with a [`RowParser`]. This allows rows to be encoded directly into memory not
+    /// managed by a [`Rows`], such as a memory-mapped file, without an intermediate copy.
+    /// The required size of `data` can be determined with [`RowConverter::encoded_size`].
+    ///
+    /// Returns an error if `data` is too small to contain the encoded rows, in which
+    /// case the contents of `data` are unspecified
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{Int32Array, StringArray};
+    /// # use arrow_row::{RowConverter, SortField};
+    /// # use arrow_schema::DataType;
+    /// #
+    /// let mut converter = RowConverter::new(vec![SortField::new(DataType::Int32)]).unwrap();
+    /// let columns = [Arc::new(Int32Array::from(vec![3, 1, 2])) as _];
+    ///
+    /// let mut data = vec![0; converter.encoded_size(&columns).unwrap()];
+    /// let offsets = converter.encode_to_slice(&columns, &mut data).unwrap();
+    ///
+    /// let parser = converter.parser();
+    /// let row = |i: usize| parser.parse(&data[offsets[i]..offsets[i + 1]]);
+    /// assert!(row(1) < row(2) && row(2) < row(0));
+    /// ```
+    pub fn encode_to_slice(
+        &mut self,
+        columns: &[ArrayRef],
+        data: &mut [u8],
+    ) -> Result<Vec<usize>, ArrowError> {
+        let fields = Arc::clone(&self.fields);
+        let encoders = self.encoders(columns)?;
+        let lengths = row_lengths(columns, &encoders);
+
+        // See RowConverter::append for a description of how offsets are initialized
+        let mut offsets = Vec::with_capacity(lengths.len() + 1);
+        offsets.push(0);
+        let mut cur_offset = 0_usize;
+        for l in lengths {
+            offsets.push(cur_offset);
+            cur_offset = cur_offset.checked_add(l).ok_or_else(|| {
+                ArrowError::ComputeError("overflow computing encoded size".to_string())
+            })?;
+        }
+
+        if cur_offset > data.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Encoding rows requires {cur_offset} bytes, but only {} provided",
+                data.len()
+            )));
+        }
+
+        // Encoders assume the buffer is zero-initialized, e.g. for padding
+        let data = &mut data[..cur_offset];
+        data.fill(0);
+        for ((column, field), encoder) in columns.iter().zip(fields.iter()).zip(encoders)
+        {
+            encode_column(data, &mut offsets, column.as_ref(), field.options, &encoder)
+        }
+        Ok(offsets)
+    }
+
+    /// Validates `columns` against the fields of this [`RowConverter`], returning an
+    /// [`Encoder`] for each column
+    fn encoders(&mut self, columns: &[ArrayRef]) -> Result<Vec<Encoder<'_>>, ArrowError> {
+        if columns.len() != self.fields.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Incorrect number of arrays provided to RowConverter, expected {} got {}",
+                self.fields.len(),
+                columns.len()
+            )));
+        }
+
+        columns
+            .iter()
+            .zip(&mut self.codecs)
+            .zip(self.fields.iter())
+            .map(|((column, codec), field)| {
+                if !column.data_type().equals_datatype(&field.data_type) {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "RowConverter column schema mismatch, expected {} got {}",
+                        field.data_type,
+                        column.data_type()
+                    )));
+                }
+                codec.encoder(column.as_ref())
+            })
+            .collect()
+    }
+
     /// Convert [`Rows`] columns into [`ArrayRef`]
     ///
     /// # Panics
@@ -988,6 +1300,12 @@ impl Rows {
         self.offsets.len() - 1
     }
 
+    /// Returns the length in bytes of every [`Row`] in this [`Rows`], if they all
+    /// have the same length, see [`RowConverter::row_width`]
+    pub fn row_width(&self) -> Option<usize> {
+        self.config.fields.iter().map(|f| f.encoded_width()).sum()
+    }
+
     /// Returns an iterator over the [`Row`] in this [`Rows`]
     pub fn iter(&self) -> RowsIter<'_> {
         self.into_iter()
@@ -1246,6 +1564,14 @@ fn row_lengths(cols: &[ArrayRef], encoders: &[Encoder]) -> Vec<usize> {
                     _ => unreachable!(),
                 }
             }
+            Encoder::Interned(keys) => {
+                for (k, length) in keys.iter().zip(lengths.iter_mut()) {
+                    match k {
+                        Some(k) => *length += k.len() + 1,
+                        None => *length += 1,
+                    }
+                }
+            }
             Encoder::DictionaryValues(values, null) => {
                 downcast_dictionary_array! {
                     array => {
@@ -1334,6 +1660,9 @@ fn encode_column(
                 _ => unreachable!()
             }
         }
+        Encoder::Interned(keys) => {
+            encode_interned(data, offsets, keys.iter().copied(), opts)
+        }
         Encoder::Struct(rows, null) => {
             let array = as_struct_array(column);
             let null_sentinel = null_sentinel(opts);
@@ -1414,6 +1743,9 @@ unsafe fn decode_column(
                 _ => unreachable!()
             }
         }
+        Codec::Interned(interner, _) => {
+            decode_interned(interner, &field.data_type, options, rows)
+        }
         Codec::DictionaryValues(converter, _) => {
             let cols = converter.convert_raw(rows, validate_utf8)?;
             cols.into_iter().next().unwrap()
@@ -1449,17 +1781,11 @@ unsafe fn decode_column(
 mod tests {
     use std::sync::Arc;
 
-    use rand::distributions::uniform::SampleUniform;
-    use rand::distributions::{Distribution, Standard};
-    use rand::{thread_rng, Rng};
-
     use arrow_array::builder::*;
     use arrow_array::types::*;
     use arrow_array::*;
     use arrow_buffer::i256;
     use arrow_buffer::Buffer;
-    use arrow_cast::display::array_value_to_string;
-    use arrow_ord::sort::{LexicographicalComparator, SortColumn, SortOptions};
 
     use super::*;
 
@@ -2224,203 +2550,263 @@ mod tests {
         }
     }
 
-    fn generate_primitive_array<K>(len: usize, valid_percent: f64) -> PrimitiveArray<K>
-    where
-        K: ArrowPrimitiveType,
-        Standard: Distribution<K::Native>,
-    {
-        let mut rng = thread_rng();
-        (0..len)
-            .map(|_| rng.gen_bool(valid_percent).then(|| rng.gen()))
-            .collect()
-    }
+    #[test]
+    fn test_encode_to_slice() {
+        let mut converter = RowConverter::new(vec![
+            SortField::new(DataType::Utf8),
+            SortField::new(DataType::Dictionary(
+                Box::new(DataType::Int32),
+                Box::new(DataType::Utf8),
+            )),
+            SortField::new(DataType::Int64),
+        ])
+        .unwrap();
 
-    fn generate_strings<O: OffsetSizeTrait>(
-        len: usize,
-        valid_percent: f64,
-    ) -> GenericStringArray<O> {
-        let mut rng = thread_rng();
-        (0..len)
-            .map(|_| {
-                rng.gen_bool(valid_percent).then(|| {
-                    let len = rng.gen_range(0..100);
-                    let bytes = (0..len).map(|_| rng.gen_range(0..128)).collect();
-                    String::from_utf8(bytes).unwrap()
-                })
-            })
-            .collect()
-    }
+        let dict: DictionaryArray<Int32Type> =
+            vec![Some("a"), None, Some("b"), Some("a")]
+                .into_iter()
+                .collect();
+        let columns = [
+            Arc::new(StringArray::from(vec![
+                Some("foo"),
+                None,
+                Some(""),
+                Some("bar"),
+            ])) as ArrayRef,
+            Arc::new(dict) as ArrayRef,
+            Arc::new(Int64Array::from(vec![1, 2, 3, 4])) as ArrayRef,
+        ];
 
-    fn generate_dictionary<K>(
-        values: ArrayRef,
-        len: usize,
-        valid_percent: f64,
-    ) -> DictionaryArray<K>
-    where
-        K: ArrowDictionaryKeyType,
-        K::Native: SampleUniform,
-    {
-        let mut rng = thread_rng();
-        let min_key = K::Native::from_usize(0).unwrap();
-        let max_key = K::Native::from_usize(values.len()).unwrap();
-        let keys: PrimitiveArray<K> = (0..len)
-            .map(|_| {
-                rng.gen_bool(valid_percent)
-                    .then(|| rng.gen_range(min_key..max_key))
-            })
-            .collect();
+        let size = converter.encoded_size(&columns).unwrap();
+        let rows = converter.convert_columns(&columns).unwrap();
+        assert_eq!(size, rows.iter().map(|r| r.as_ref().len()).sum::<usize>());
 
-        let data_type = DataType::Dictionary(
-            Box::new(K::DATA_TYPE),
-            Box::new(values.data_type().clone()),
-        );
+        // Encode with an offset into a larger buffer
+        let mut data = vec![0xFF; size + 10];
+        let offsets = converter.encode_to_slice(&columns, &mut data[5..]).unwrap();
+        assert_eq!(offsets.len(), 5);
+        assert_eq!(offsets[4], size);
 
-        let data = keys
-            .into_data()
-            .into_builder()
-            .data_type(data_type)
-            .add_child_data(values.to_data())
-            .build()
+        let parser = converter.parser();
+        for (i, row) in rows.iter().enumerate() {
+            let parsed = parser.parse(&data[5 + offsets[i]..5 + offsets[i + 1]]);
+            assert_eq!(parsed, row);
+        }
+
+        let back = converter
+            .convert_rows(
+                (0..4).map(|i| parser.parse(&data[5 + offsets[i]..5 + offsets[i + 1]])),
+            )
             .unwrap();
+        for (a, b) in back.iter().zip(&columns) {
+            assert_eq!(a.as_ref(), b.as_ref());
+        }
+
+        let err = converter
+            .encode_to_slice(&columns, &mut data[..size - 1])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(&format!("requires {size} bytes")), "{err}");
 
-        DictionaryArray::from(data)
+        let err = converter
+            .encoded_size(&columns[..2])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("expected 3 got 2"), "{err}");
     }
 
-    fn generate_fixed_size_binary(
-        len: usize,
-        valid_percent: f64,
-    ) -> FixedSizeBinaryArray {
-        let mut rng = thread_rng();
-        let width = rng.gen_range(0..20);
-        let mut builder = FixedSizeBinaryBuilder::new(width);
+    #[test]
+    fn test_intern_values() {
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let mut converter = RowConverter::new(vec![
+            SortField::new(DataType::Utf8).intern_values(1024 * 1024),
+            SortField::new_with_options(DataType::LargeBinary, options)
+                .intern_values(1024 * 1024),
+        ])
+        .unwrap();
 
-        let mut b = vec![0; width as usize];
-        for _ in 0..len {
-            match rng.gen_bool(valid_percent) {
-                true => {
-                    b.iter_mut().for_each(|x| *x = rng.gen());
-                    builder.append_value(&b).unwrap();
-                }
-                false => builder.append_null(),
-            }
-        }
+        let a = Arc::new(StringArray::from(vec![
+            Some("hello world"),
+            None,
+            Some("apple"),
+            Some("hello world"),
+            Some(""),
+        ])) as ArrayRef;
+        let b = Arc::new(LargeBinaryArray::from_opt_vec(vec![
+            Some(b"foo".as_ref()),
+            Some(b"bar".as_ref()),
+            None,
+            Some(b"bar".as_ref()),
+            Some(b"foo".as_ref()),
+        ])) as ArrayRef;
+        let columns = [a, b];
 
-        builder.finish()
-    }
-
-    fn generate_column(len: usize) -> ArrayRef {
-        let mut rng = thread_rng();
-        match rng.gen_range(0..10) {
-            0 => Arc::new(generate_primitive_array::<Int32Type>(len, 0.8)),
-            1 => Arc::new(generate_primitive_array::<UInt32Type>(len, 0.8)),
-            2 => Arc::new(generate_primitive_array::<Int64Type>(len, 0.8)),
-            3 => Arc::new(generate_primitive_array::<UInt64Type>(len, 0.8)),
-            4 => Arc::new(generate_primitive_array::<Float32Type>(len, 0.8)),
-            5 => Arc::new(generate_primitive_array::<Float64Type>(len, 0.8)),
-            6 => Arc::new(generate_strings::<i32>(len, 0.8)),
-            7 => Arc::new(generate_dictionary::<Int64Type>(
-                // Cannot test dictionaries containing null values because of #2687
-                Arc::new(generate_strings::<i32>(rng.gen_range(1..len), 1.0)),
-                len,
-                0.8,
-            )),
-            8 => Arc::new(generate_dictionary::<Int64Type>(
-                // Cannot test dictionaries containing null values because of #2687
-                Arc::new(generate_primitive_array::<Int64Type>(
-                    rng.gen_range(1..len),
-                    1.0,
-                )),
-                len,
-                0.8,
-            )),
-            9 => Arc::new(generate_fixed_size_binary(len, 0.8)),
-            _ => unreachable!(),
-        }
-    }
+        let rows = converter.convert_columns(&columns).unwrap();
+        assert!(rows.row(4) < rows.row(2));
+        assert!(rows.row(2) < rows.row(0));
+        assert!(rows.row(0) < rows.row(3));
+        assert!(rows.row(1) < rows.row(4));
 
-    fn print_row(cols: &[SortColumn], row: usize) -> String {
-        let t: Vec<_> = cols
-            .iter()
-            .map(|x| array_value_to_string(&x.values, row).unwrap())
-            .collect();
-        t.join(",")
-    }
+        // Interned values are much smaller than the encoded values
+        let mut plain = RowConverter::new(vec![
+            SortField::new(DataType::Utf8),
+            SortField::new_with_options(DataType::LargeBinary, options),
+        ])
+        .unwrap();
+        let plain_rows = plain.convert_columns(&columns).unwrap();
+        assert!(rows.size() < plain_rows.size());
 
-    fn print_col_types(cols: &[SortColumn]) -> String {
-        let t: Vec<_> = cols
-            .iter()
-            .map(|x| x.values.data_type().to_string())
-            .collect();
-        t.join(",")
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(back[0].as_ref(), columns[0].as_ref());
+        assert_eq!(back[1].as_ref(), columns[1].as_ref());
+
+        // Subsequent batches are comparable with the previous ones
+        let a2 = Arc::new(StringArray::from(vec!["banana"])) as ArrayRef;
+        let b2 = Arc::new(LargeBinaryArray::from_vec(vec![b"foo".as_ref()])) as ArrayRef;
+        let rows2 = converter.convert_columns(&[a2, b2]).unwrap();
+        assert!(rows.row(2) < rows2.row(0));
+        assert!(rows2.row(0) < rows.row(0));
     }
 
     #[test]
-    #[cfg_attr(miri, ignore)]
-    fn fuzz_test() {
-        for _ in 0..100 {
-            let mut rng = thread_rng();
-            let num_columns = rng.gen_range(1..5);
-            let len = rng.gen_range(5..100);
-            let arrays: Vec<_> = (0..num_columns).map(|_| generate_column(len)).collect();
-
-            let options: Vec<_> = (0..num_columns)
-                .map(|_| SortOptions {
-                    descending: rng.gen_bool(0.5),
-                    nulls_first: rng.gen_bool(0.5),
-                })
-                .collect();
+    fn test_fixed_width_rows() {
+        let struct_type = DataType::Struct(
+            vec![
+                Field::new("a", DataType::Boolean, true),
+                Field::new("b", DataType::FixedSizeBinary(3), true),
+            ]
+            .into(),
+        );
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Int16));
+        let fields = vec![
+            SortField::new(DataType::Int64),
+            SortField::new(struct_type.clone()),
+            SortField::new(dict_type.clone()),
+            SortField::new(DataType::Null),
+        ];
 
-            let sort_columns: Vec<_> = options
-                .iter()
-                .zip(&arrays)
-                .map(|(o, c)| SortColumn {
-                    values: Arc::clone(c),
-                    options: Some(*o),
-                })
-                .collect();
+        // Preserved dictionaries have a variable length encoding
+        assert_eq!(fields[0].encoded_width(), Some(9));
+        assert_eq!(fields[1].encoded_width(), Some(1 + 2 + 4));
+        assert_eq!(fields[2].encoded_width(), None);
+        assert_eq!(fields[3].encoded_width(), Some(0));
+        let converter = RowConverter::new(fields.clone()).unwrap();
+        assert_eq!(converter.row_width(), None);
+
+        let mut converter = RowConverter::new_fixed_width(fields).unwrap();
+        assert_eq!(converter.row_width(), Some(9 + 7 + 3));
+
+        let mut builder = StructBuilder::from_fields(
+            vec![
+                Field::new("a", DataType::Boolean, true),
+                Field::new("b", DataType::FixedSizeBinary(3), true),
+            ],
+            3,
+        );
+        builder
+            .field_builder::<BooleanBuilder>(0)
+            .unwrap()
+            .append_value(true);
+        builder
+            .field_builder::<FixedSizeBinaryBuilder>(1)
+            .unwrap()
+            .append_value(b"abc")
+            .unwrap();
+        builder.append(true);
+        builder
+            .field_builder::<BooleanBuilder>(0)
+            .unwrap()
+            .append_null();
+        builder
+            .field_builder::<FixedSizeBinaryBuilder>(1)
+            .unwrap()
+            .append_null();
+        builder.append(false);
+        builder
+            .field_builder::<BooleanBuilder>(0)
+            .unwrap()
+            .append_value(false);
+        builder
+            .field_builder::<FixedSizeBinaryBuilder>(1)
+            .unwrap()
+            .append_value(b"def")
+            .unwrap();
+        builder.append(true);
 
-            let preserve: Vec<_> = (0..num_columns).map(|_| rng.gen_bool(0.5)).collect();
+        let dict: DictionaryArray<Int32Type> = DictionaryArray::try_new(
+            Int32Array::from(vec![Some(1), None, Some(0)]),
+            Arc::new(Int16Array::from(vec![7, 3])),
+        )
+        .unwrap();
+        let columns = [
+            Arc::new(Int64Array::from(vec![Some(1), None, Some(3)])) as ArrayRef,
+            Arc::new(builder.finish()) as ArrayRef,
+            Arc::new(dict) as ArrayRef,
+            Arc::new(NullArray::new(3)) as ArrayRef,
+        ];
 
-            let comparator = LexicographicalComparator::try_new(&sort_columns).unwrap();
+        let lengths = converter.encoded_lengths(&columns).unwrap();
+        assert_eq!(lengths, vec![19; 3]);
 
-            let columns = options
-                .into_iter()
-                .zip(&arrays)
-                .zip(&preserve)
-                .map(|((o, a), p)| {
-                    SortField::new_with_options(a.data_type().clone(), o)
-                        .preserve_dictionaries(*p)
-                })
-                .collect();
+        let rows = converter.convert_columns(&columns).unwrap();
+        assert_eq!(rows.row_width(), Some(19));
+        assert!(rows.iter().all(|r| r.as_ref().len() == 19));
 
-            let mut converter = RowConverter::new(columns).unwrap();
-            let rows = converter.convert_columns(&arrays).unwrap();
-
-            for i in 0..len {
-                for j in 0..len {
-                    let row_i = rows.row(i);
-                    let row_j = rows.row(j);
-                    let row_cmp = row_i.cmp(&row_j);
-                    let lex_cmp = comparator.compare(i, j);
-                    assert_eq!(
-                        row_cmp,
-                        lex_cmp,
-                        "({:?} vs {:?}) vs ({:?} vs {:?}) for types {}",
-                        print_row(&sort_columns, i),
-                        print_row(&sort_columns, j),
-                        row_i,
-                        row_j,
-                        print_col_types(&sort_columns)
-                    );
-                }
-            }
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(back[0].as_ref(), columns[0].as_ref());
+        assert_eq!(back[1].as_ref(), columns[1].as_ref());
+        // Dictionaries are decoded to their values
+        let values = Int16Array::from(vec![Some(3), None, Some(7)]);
+        assert_eq!(back[2].as_ref(), &values);
+
+        let err = RowConverter::new_fixed_width(vec![
+            SortField::new(DataType::Int32),
+            SortField::new(DataType::Utf8),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Fixed width row format not supported for: Utf8"
+        );
+    }
 
-            let back = converter.convert_rows(&rows).unwrap();
-            for ((actual, expected), preserve) in back.iter().zip(&arrays).zip(preserve) {
-                actual.to_data().validate_full().unwrap();
-                dictionary_eq(preserve, actual, expected)
-            }
-        }
+    #[test]
+    fn test_encoded_lengths() {
+        let mut converter = RowConverter::new(vec![
+            SortField::new(DataType::Utf8),
+            SortField::new(DataType::Int32),
+        ])
+        .unwrap();
+        let columns = [
+            Arc::new(StringArray::from(vec![Some("foo"), None, Some("")])) as ArrayRef,
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        ];
+        let lengths = converter.encoded_lengths(&columns).unwrap();
+        let rows = converter.convert_columns(&columns).unwrap();
+        let expected: Vec<_> = rows.iter().map(|r| r.as_ref().len()).collect();
+        assert_eq!(lengths, expected);
+        assert_eq!(rows.row_width(), None);
+
+        let err = converter.encoded_lengths(&columns[..1]).unwrap_err();
+        assert!(err.to_string().contains("Incorrect number of arrays"));
+    }
+
+    #[test]
+    fn test_intern_values_limit() {
+        let field = SortField::new(DataType::Utf8).intern_values(64);
+        let mut converter = RowConverter::new(vec![field]).unwrap();
+
+        let values: Vec<_> = (0..1000).map(|x| format!("value {x}")).collect();
+        let array = Arc::new(StringArray::from(values)) as ArrayRef;
+        let err = converter.convert_columns(&[array]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Memory error: Interned values exceed memory limit of 64 bytes"
+        );
     }
 
     #[test]
@@ -0,0 +1,309 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`FixedShapeTensor`] canonical extension type
+
+use serde::{Deserialize, Serialize};
+
+use super::{invalid_metadata, unsupported_data_type};
+use crate::extension::ExtensionType;
+use crate::{ArrowError, DataType, Field};
+
+/// The metadata of the [`FixedShapeTensor`] extension type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixedShapeTensorMetadata {
+    /// The physical shape of the contained tensors
+    shape: Vec<usize>,
+    /// The explicit names of the tensor dimensions, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dim_names: Option<Vec<String>>,
+    /// The indices of the desired ordering of the original dimensions, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permutation: Option<Vec<usize>>,
+}
+
+impl FixedShapeTensorMetadata {
+    /// Creates a new [`FixedShapeTensorMetadata`], returning an error if the
+    /// length of `dim_names` or `permutation` does not match that of `shape`,
+    /// or if `permutation` is not a permutation of `0..shape.len()`
+    pub fn try_new(
+        shape: Vec<usize>,
+        dim_names: Option<Vec<String>>,
+        permutation: Option<Vec<usize>>,
+    ) -> Result<Self, ArrowError> {
+        let metadata = Self {
+            shape,
+            dim_names,
+            permutation,
+        };
+        metadata.validate()?;
+        Ok(metadata)
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_dimensions(
+            "FixedShapeTensor",
+            self.shape.len(),
+            self.dim_names.as_deref(),
+            self.permutation.as_deref(),
+        )
+    }
+
+    /// Returns the physical shape of the contained tensors
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Returns the names of the tensor dimensions, if any
+    pub fn dim_names(&self) -> Option<&[String]> {
+        self.dim_names.as_deref()
+    }
+
+    /// Returns the permutation of the tensor dimensions, if any
+    pub fn permutation(&self) -> Option<&[usize]> {
+        self.permutation.as_deref()
+    }
+
+    /// Returns the number of dimensions of the contained tensors
+    pub fn dimensions(&self) -> usize {
+        self.shape.len()
+    }
+
+    /// Returns the number of elements in each of the contained tensors
+    pub fn list_size(&self) -> usize {
+        self.shape.iter().product()
+    }
+}
+
+/// Validates the optional `dim_names` and `permutation` of a tensor with
+/// `dimensions` dimensions
+pub(super) fn validate_dimensions(
+    name: &str,
+    dimensions: usize,
+    dim_names: Option<&[String]>,
+    permutation: Option<&[usize]>,
+) -> Result<(), ArrowError> {
+    if let Some(dim_names) = dim_names {
+        if dim_names.len() != dimensions {
+            return Err(invalid_metadata(
+                name,
+                format!(
+                    "expected {dimensions} dimension names, found {}",
+                    dim_names.len()
+                ),
+            ));
+        }
+    }
+
+    if let Some(permutation) = permutation {
+        let mut sorted = permutation.to_vec();
+        sorted.sort_unstable();
+        if !sorted.into_iter().eq(0..dimensions) {
+            return Err(invalid_metadata(
+                name,
+                format!(
+                    "{permutation:?} is not a permutation of {dimensions} dimensions"
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The fixed shape tensor [canonical extension type], representing
+/// multidimensional arrays of a fixed shape, stored as a
+/// [`DataType::FixedSizeList`] of the product of the dimensions
+///
+/// [canonical extension type]: https://arrow.apache.org/docs/format/CanonicalExtensions.html#fixed-shape-tensor
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixedShapeTensor {
+    value_type: DataType,
+    metadata: FixedShapeTensorMetadata,
+}
+
+impl FixedShapeTensor {
+    /// Creates a new [`FixedShapeTensor`] with elements of `value_type`
+    pub fn try_new_with_value_type(
+        value_type: DataType,
+        shape: Vec<usize>,
+        dim_names: Option<Vec<String>>,
+        permutation: Option<Vec<usize>>,
+    ) -> Result<Self, ArrowError> {
+        let metadata = FixedShapeTensorMetadata::try_new(shape, dim_names, permutation)?;
+        Ok(Self {
+            value_type,
+            metadata,
+        })
+    }
+
+    /// Returns the type of the elements of the contained tensors
+    pub fn value_type(&self) -> &DataType {
+        &self.value_type
+    }
+
+    /// Returns the storage [`DataType`] of this extension type
+    pub fn storage_type(&self) -> Result<DataType, ArrowError> {
+        let size = i32::try_from(self.metadata.list_size()).map_err(|_| {
+            ArrowError::InvalidArgumentError(
+                "FixedShapeTensor list size exceeds i32::MAX".to_string(),
+            )
+        })?;
+        let field = Field::new("item", self.value_type.clone(), false);
+        Ok(DataType::FixedSizeList(field.into(), size))
+    }
+}
+
+impl ExtensionType for FixedShapeTensor {
+    const NAME: &'static str = "arrow.fixed_shape_tensor";
+
+    type Metadata = FixedShapeTensorMetadata;
+
+    fn metadata(&self) -> &Self::Metadata {
+        &self.metadata
+    }
+
+    fn serialize_metadata(&self) -> Option<String> {
+        Some(serde_json::to_string(&self.metadata).expect("metadata serialization"))
+    }
+
+    fn deserialize_metadata(
+        metadata: Option<&str>,
+    ) -> Result<Self::Metadata, ArrowError> {
+        let metadata = metadata.ok_or_else(|| {
+            invalid_metadata("FixedShapeTensor", "expected metadata, found none")
+        })?;
+        let metadata: FixedShapeTensorMetadata = serde_json::from_str(metadata)
+            .map_err(|e| invalid_metadata("FixedShapeTensor", e))?;
+        metadata.validate()?;
+        Ok(metadata)
+    }
+
+    fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+        match data_type {
+            DataType::FixedSizeList(field, size)
+                if field.data_type() == &self.value_type
+                    && *size as usize == self.metadata.list_size() =>
+            {
+                Ok(())
+            }
+            data_type => Err(unsupported_data_type("FixedShapeTensor", data_type)),
+        }
+    }
+
+    fn try_new(
+        data_type: &DataType,
+        metadata: Self::Metadata,
+    ) -> Result<Self, ArrowError> {
+        match data_type {
+            DataType::FixedSizeList(field, _) => {
+                let tensor = Self {
+                    value_type: field.data_type().clone(),
+                    metadata,
+                };
+                tensor.supports_data_type(data_type)?;
+                Ok(tensor)
+            }
+            data_type => Err(unsupported_data_type("FixedShapeTensor", data_type)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extension::{EXTENSION_TYPE_METADATA_KEY, EXTENSION_TYPE_NAME_KEY};
+    use std::collections::HashMap;
+
+    #[test]
+    fn valid() {
+        let tensor = FixedShapeTensor::try_new_with_value_type(
+            DataType::Float32,
+            vec![100, 200, 500],
+            Some(vec!["C".to_string(), "H".to_string(), "W".to_string()]),
+            Some(vec![2, 0, 1]),
+        )
+        .unwrap();
+        let field = Field::new("", tensor.storage_type().unwrap(), false)
+            .with_extension_type(tensor.clone());
+        assert_eq!(
+            field.extension_type_metadata(),
+            Some(
+                r#"{"shape":[100,200,500],"dim_names":["C","H","W"],"permutation":[2,0,1]}"#
+            )
+        );
+        assert_eq!(
+            field.try_extension_type::<FixedShapeTensor>().unwrap(),
+            tensor
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let field = Field::new(
+            "",
+            DataType::FixedSizeList(Field::new("item", DataType::Int8, false).into(), 6),
+            false,
+        )
+        .with_metadata(HashMap::from([
+            (
+                EXTENSION_TYPE_NAME_KEY.to_string(),
+                FixedShapeTensor::NAME.to_string(),
+            ),
+            (
+                EXTENSION_TYPE_METADATA_KEY.to_string(),
+                r#"{"shape":[2,3]}"#.to_string(),
+            ),
+        ]));
+        let tensor = field.try_extension_type::<FixedShapeTensor>().unwrap();
+        assert_eq!(tensor.value_type(), &DataType::Int8);
+        assert_eq!(tensor.metadata().shape(), &[2, 3]);
+        assert_eq!(tensor.metadata().dim_names(), None);
+        assert_eq!(tensor.metadata().permutation(), None);
+    }
+
+    #[test]
+    fn invalid() {
+        let err = FixedShapeTensorMetadata::try_new(vec![2, 3], None, Some(vec![0, 0]))
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("is not a permutation of 2 dimensions"));
+
+        let err =
+            FixedShapeTensorMetadata::try_new(vec![2], Some(vec![]), None).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected 1 dimension names, found 0"));
+
+        let tensor = FixedShapeTensor::try_new_with_value_type(
+            DataType::Int8,
+            vec![2, 3],
+            None,
+            None,
+        )
+        .unwrap();
+        let data_type =
+            DataType::FixedSizeList(Field::new("item", DataType::Int8, false).into(), 5);
+        let err = tensor.supports_data_type(&data_type).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("FixedShapeTensor data type mismatch"));
+
+        let err = FixedShapeTensor::deserialize_metadata(None).unwrap_err();
+        assert!(err.to_string().contains("expected metadata, found none"));
+    }
+}
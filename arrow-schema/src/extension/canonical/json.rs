@@ -0,0 +1,115 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`Json`] canonical extension type
+
+use super::{invalid_metadata, unsupported_data_type};
+use crate::extension::ExtensionType;
+use crate::{ArrowError, DataType};
+
+/// The metadata of the [`Json`] extension type
+///
+/// The specification defines no metadata fields, but requires that the
+/// metadata, if present, is a valid JSON object
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct JsonMetadata;
+
+/// The JSON [canonical extension type], representing UTF-8 encoded JSON
+/// values as defined by [RFC8259], stored as a [`DataType::Utf8`] or
+/// [`DataType::LargeUtf8`]
+///
+/// [canonical extension type]: https://arrow.apache.org/docs/format/CanonicalExtensions.html#json
+/// [RFC8259]: https://datatracker.ietf.org/doc/html/rfc8259
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Json(JsonMetadata);
+
+impl ExtensionType for Json {
+    const NAME: &'static str = "arrow.json";
+
+    type Metadata = JsonMetadata;
+
+    fn metadata(&self) -> &Self::Metadata {
+        &self.0
+    }
+
+    fn serialize_metadata(&self) -> Option<String> {
+        Some("{}".to_string())
+    }
+
+    fn deserialize_metadata(
+        metadata: Option<&str>,
+    ) -> Result<Self::Metadata, ArrowError> {
+        match metadata {
+            None | Some("") => Ok(JsonMetadata),
+            Some(metadata) => {
+                serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(
+                    metadata,
+                )
+                .map_err(|e| invalid_metadata("Json", e))?;
+                Ok(JsonMetadata)
+            }
+        }
+    }
+
+    fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+        match data_type {
+            DataType::Utf8 | DataType::LargeUtf8 => Ok(()),
+            data_type => Err(unsupported_data_type("Json", data_type)),
+        }
+    }
+
+    fn try_new(
+        data_type: &DataType,
+        metadata: Self::Metadata,
+    ) -> Result<Self, ArrowError> {
+        let json = Self(metadata);
+        json.supports_data_type(data_type)?;
+        Ok(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extension::{EXTENSION_TYPE_METADATA_KEY, EXTENSION_TYPE_NAME_KEY};
+    use crate::Field;
+    use std::collections::HashMap;
+
+    #[test]
+    fn valid() {
+        let field = Field::new("", DataType::LargeUtf8, true)
+            .with_extension_type(Json::default());
+        assert_eq!(field.extension_type_metadata(), Some("{}"));
+        field.try_extension_type::<Json>().unwrap();
+    }
+
+    #[test]
+    fn invalid_metadata() {
+        let field = Field::new("", DataType::Utf8, true).with_metadata(HashMap::from([
+            (EXTENSION_TYPE_NAME_KEY.to_string(), Json::NAME.to_string()),
+            (EXTENSION_TYPE_METADATA_KEY.to_string(), "[1]".to_string()),
+        ]));
+        let err = field.try_extension_type::<Json>().unwrap_err();
+        assert!(err.to_string().contains("Json metadata is invalid"));
+    }
+
+    #[test]
+    fn invalid_data_type() {
+        let err = Json::try_new(&DataType::Binary, JsonMetadata).unwrap_err();
+        assert!(err.to_string().contains("Json data type mismatch"));
+    }
+}
@@ -0,0 +1,43 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The [canonical extension types] defined by the Arrow specification
+//!
+//! [canonical extension types]: https://arrow.apache.org/docs/format/CanonicalExtensions.html
+
+mod fixed_shape_tensor;
+pub use fixed_shape_tensor::{FixedShapeTensor, FixedShapeTensorMetadata};
+mod json;
+pub use json::{Json, JsonMetadata};
+mod uuid;
+pub use uuid::Uuid;
+mod variable_shape_tensor;
+pub use variable_shape_tensor::{VariableShapeTensor, VariableShapeTensorMetadata};
+
+use crate::ArrowError;
+
+/// Returns an error for an unsupported storage `data_type` of the extension type `name`
+fn unsupported_data_type(name: &str, data_type: &crate::DataType) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "{name} data type mismatch, found {data_type}"
+    ))
+}
+
+/// Returns an error for invalid serialized metadata of the extension type `name`
+fn invalid_metadata(name: &str, reason: impl std::fmt::Display) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!("{name} metadata is invalid: {reason}"))
+}
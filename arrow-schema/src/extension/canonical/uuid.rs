@@ -0,0 +1,112 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`Uuid`] canonical extension type
+
+use super::unsupported_data_type;
+use crate::extension::ExtensionType;
+use crate::{ArrowError, DataType};
+
+/// The UUID [canonical extension type], representing a 128-bit universally
+/// unique identifier stored as a [`DataType::FixedSizeBinary`] of 16 bytes
+///
+/// [canonical extension type]: https://arrow.apache.org/docs/format/CanonicalExtensions.html#uuid
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Uuid;
+
+impl ExtensionType for Uuid {
+    const NAME: &'static str = "arrow.uuid";
+
+    type Metadata = ();
+
+    fn metadata(&self) -> &Self::Metadata {
+        &()
+    }
+
+    fn serialize_metadata(&self) -> Option<String> {
+        None
+    }
+
+    fn deserialize_metadata(
+        metadata: Option<&str>,
+    ) -> Result<Self::Metadata, ArrowError> {
+        match metadata {
+            None | Some("") => Ok(()),
+            Some(_) => Err(ArrowError::InvalidArgumentError(
+                "Uuid extension type expects no metadata".to_string(),
+            )),
+        }
+    }
+
+    fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+        match data_type {
+            DataType::FixedSizeBinary(16) => Ok(()),
+            data_type => Err(unsupported_data_type("Uuid", data_type)),
+        }
+    }
+
+    fn try_new(
+        data_type: &DataType,
+        _metadata: Self::Metadata,
+    ) -> Result<Self, ArrowError> {
+        Self.supports_data_type(data_type).map(|_| Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extension::EXTENSION_TYPE_NAME_KEY;
+    use crate::Field;
+    use std::collections::HashMap;
+
+    #[test]
+    fn valid() {
+        let field = Field::new("", DataType::FixedSizeBinary(16), false)
+            .with_extension_type(Uuid);
+        assert_eq!(field.extension_type_name(), Some(Uuid::NAME));
+        assert_eq!(field.extension_type_metadata(), None);
+        field.try_extension_type::<Uuid>().unwrap();
+    }
+
+    #[test]
+    fn invalid_data_type() {
+        let err = Field::new("", DataType::FixedSizeBinary(8), false)
+            .try_with_extension_type(Uuid)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Uuid data type mismatch, found FixedSizeBinary(8)"
+        );
+    }
+
+    #[test]
+    fn missing_name() {
+        let err = Field::new("", DataType::FixedSizeBinary(16), false)
+            .try_extension_type::<Uuid>()
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Field extension type name missing"));
+
+        let field = Field::new("", DataType::FixedSizeBinary(16), false).with_metadata(
+            HashMap::from([(EXTENSION_TYPE_NAME_KEY.to_string(), "foo".to_string())]),
+        );
+        let err = field.try_extension_type::<Uuid>().unwrap_err();
+        assert!(err.to_string().contains("expected arrow.uuid, found foo"));
+    }
+}
@@ -0,0 +1,261 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`VariableShapeTensor`] canonical extension type
+
+use serde::{Deserialize, Serialize};
+
+use super::fixed_shape_tensor::validate_dimensions;
+use super::{invalid_metadata, unsupported_data_type};
+use crate::extension::ExtensionType;
+use crate::{ArrowError, DataType, Field, Fields};
+
+/// The metadata of the [`VariableShapeTensor`] extension type
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VariableShapeTensorMetadata {
+    /// The explicit names of the tensor dimensions, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dim_names: Option<Vec<String>>,
+    /// The indices of the desired ordering of the original dimensions, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permutation: Option<Vec<usize>>,
+    /// The sizes of the dimensions that are the same across all tensors, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uniform_shape: Option<Vec<Option<i32>>>,
+}
+
+impl VariableShapeTensorMetadata {
+    /// Creates a new [`VariableShapeTensorMetadata`]
+    pub fn new(
+        dim_names: Option<Vec<String>>,
+        permutation: Option<Vec<usize>>,
+        uniform_shape: Option<Vec<Option<i32>>>,
+    ) -> Self {
+        Self {
+            dim_names,
+            permutation,
+            uniform_shape,
+        }
+    }
+
+    /// Returns the names of the tensor dimensions, if any
+    pub fn dim_names(&self) -> Option<&[String]> {
+        self.dim_names.as_deref()
+    }
+
+    /// Returns the permutation of the tensor dimensions, if any
+    pub fn permutation(&self) -> Option<&[usize]> {
+        self.permutation.as_deref()
+    }
+
+    /// Returns the sizes of the uniform dimensions, with `None` for dimensions
+    /// that vary between tensors, if any
+    pub fn uniform_shape(&self) -> Option<&[Option<i32>]> {
+        self.uniform_shape.as_deref()
+    }
+
+    fn validate(&self, dimensions: usize) -> Result<(), ArrowError> {
+        validate_dimensions(
+            "VariableShapeTensor",
+            dimensions,
+            self.dim_names.as_deref(),
+            self.permutation.as_deref(),
+        )?;
+        if let Some(uniform_shape) = &self.uniform_shape {
+            if uniform_shape.len() != dimensions {
+                return Err(invalid_metadata(
+                    "VariableShapeTensor",
+                    format!(
+                        "expected {dimensions} uniform shape dimensions, found {}",
+                        uniform_shape.len()
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The variable shape tensor [canonical extension type], representing
+/// multidimensional arrays with the same number of dimensions but varying
+/// shapes
+///
+/// The storage type is a [`DataType::Struct`] with a `data` field of type
+/// [`DataType::List`] containing the flattened tensor elements, and a `shape`
+/// field of type [`DataType::FixedSizeList`] of [`DataType::Int32`] with one
+/// element per dimension
+///
+/// [canonical extension type]: https://arrow.apache.org/docs/format/CanonicalExtensions.html#variable-shape-tensor
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariableShapeTensor {
+    value_type: DataType,
+    dimensions: usize,
+    metadata: VariableShapeTensorMetadata,
+}
+
+impl VariableShapeTensor {
+    /// Creates a new [`VariableShapeTensor`] with `dimensions` dimensions of
+    /// elements of `value_type`
+    pub fn try_new_with_value_type(
+        value_type: DataType,
+        dimensions: usize,
+        metadata: VariableShapeTensorMetadata,
+    ) -> Result<Self, ArrowError> {
+        metadata.validate(dimensions)?;
+        Ok(Self {
+            value_type,
+            dimensions,
+            metadata,
+        })
+    }
+
+    /// Returns the type of the elements of the contained tensors
+    pub fn value_type(&self) -> &DataType {
+        &self.value_type
+    }
+
+    /// Returns the number of dimensions of the contained tensors
+    pub fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    /// Returns the storage [`DataType`] of this extension type
+    pub fn storage_type(&self) -> DataType {
+        let shape = Field::new("item", DataType::Int32, false);
+        DataType::Struct(Fields::from(vec![
+            Field::new_list(
+                "data",
+                Field::new("item", self.value_type.clone(), false),
+                false,
+            ),
+            Field::new(
+                "shape",
+                DataType::FixedSizeList(shape.into(), self.dimensions as i32),
+                false,
+            ),
+        ]))
+    }
+}
+
+impl ExtensionType for VariableShapeTensor {
+    const NAME: &'static str = "arrow.variable_shape_tensor";
+
+    type Metadata = VariableShapeTensorMetadata;
+
+    fn metadata(&self) -> &Self::Metadata {
+        &self.metadata
+    }
+
+    fn serialize_metadata(&self) -> Option<String> {
+        Some(serde_json::to_string(&self.metadata).expect("metadata serialization"))
+    }
+
+    fn deserialize_metadata(
+        metadata: Option<&str>,
+    ) -> Result<Self::Metadata, ArrowError> {
+        match metadata {
+            None | Some("") => Ok(VariableShapeTensorMetadata::default()),
+            Some(metadata) => serde_json::from_str(metadata)
+                .map_err(|e| invalid_metadata("VariableShapeTensor", e)),
+        }
+    }
+
+    fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+        match storage_parts(data_type) {
+            Some((value_type, dimensions))
+                if value_type == &self.value_type && dimensions == self.dimensions =>
+            {
+                Ok(())
+            }
+            _ => Err(unsupported_data_type("VariableShapeTensor", data_type)),
+        }
+    }
+
+    fn try_new(
+        data_type: &DataType,
+        metadata: Self::Metadata,
+    ) -> Result<Self, ArrowError> {
+        let (value_type, dimensions) = storage_parts(data_type)
+            .ok_or_else(|| unsupported_data_type("VariableShapeTensor", data_type))?;
+        Self::try_new_with_value_type(value_type.clone(), dimensions, metadata)
+    }
+}
+
+/// Returns the value type and number of dimensions of a variable shape
+/// tensor storage type, or `None` if `data_type` is not a valid storage type
+fn storage_parts(data_type: &DataType) -> Option<(&DataType, usize)> {
+    let fields = match data_type {
+        DataType::Struct(fields) if fields.len() == 2 => fields,
+        _ => return None,
+    };
+    let value_type = match fields.find("data")?.1.data_type() {
+        DataType::List(field) => field.data_type(),
+        _ => return None,
+    };
+    match fields.find("shape")?.1.data_type() {
+        DataType::FixedSizeList(field, size) if field.data_type() == &DataType::Int32 => {
+            Some((value_type, *size as usize))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        let metadata = VariableShapeTensorMetadata::new(
+            Some(vec!["H".to_string(), "W".to_string()]),
+            None,
+            Some(vec![Some(400), None]),
+        );
+        let tensor =
+            VariableShapeTensor::try_new_with_value_type(DataType::Float64, 2, metadata)
+                .unwrap();
+        let field = Field::new("", tensor.storage_type(), false)
+            .with_extension_type(tensor.clone());
+        assert_eq!(
+            field.extension_type_metadata(),
+            Some(r#"{"dim_names":["H","W"],"uniform_shape":[400,null]}"#)
+        );
+        let decoded = field.try_extension_type::<VariableShapeTensor>().unwrap();
+        assert_eq!(decoded, tensor);
+        assert_eq!(decoded.dimensions(), 2);
+    }
+
+    #[test]
+    fn invalid() {
+        let metadata = VariableShapeTensorMetadata::new(None, None, Some(vec![None]));
+        let err =
+            VariableShapeTensor::try_new_with_value_type(DataType::Int8, 2, metadata)
+                .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected 2 uniform shape dimensions, found 1"));
+
+        let err = VariableShapeTensor::try_new(
+            &DataType::Int8,
+            VariableShapeTensorMetadata::default(),
+        )
+        .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("VariableShapeTensor data type mismatch"));
+    }
+}
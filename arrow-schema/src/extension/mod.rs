@@ -0,0 +1,209 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Extension types
+//!
+//! An [extension type] is a user-defined type built on top of a standard Arrow
+//! [`DataType`], known as its storage type. Extension types are identified by
+//! the [`EXTENSION_TYPE_NAME_KEY`] and [`EXTENSION_TYPE_METADATA_KEY`] entries
+//! of a [`Field`]'s metadata, which are preserved by the IPC format.
+//!
+//! [extension type]: https://arrow.apache.org/docs/format/Columnar.html#extension-types
+
+#[cfg(feature = "canonical_extension_types")]
+mod canonical;
+#[cfg(feature = "canonical_extension_types")]
+pub use canonical::*;
+
+use crate::{ArrowError, DataType, Field};
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+/// The field metadata key for the extension type name
+pub const EXTENSION_TYPE_NAME_KEY: &str = "ARROW:extension:name";
+
+/// The field metadata key for the serialized extension type metadata
+pub const EXTENSION_TYPE_METADATA_KEY: &str = "ARROW:extension:metadata";
+
+/// An Arrow [extension type]
+///
+/// Implementations describe how to validate the storage [`DataType`] of a
+/// [`Field`] annotated with [`Self::NAME`], and how to serialize the type's
+/// metadata to and from the [`EXTENSION_TYPE_METADATA_KEY`] entry.
+///
+/// See [`Field::try_extension_type`] and [`Field::with_extension_type`]
+///
+/// [extension type]: https://arrow.apache.org/docs/format/Columnar.html#extension-types
+pub trait ExtensionType: Sized {
+    /// The name identifying this extension type
+    ///
+    /// Names beginning with `arrow.` are reserved for [canonical extension types]
+    ///
+    /// [canonical extension types]: https://arrow.apache.org/docs/format/CanonicalExtensions.html
+    const NAME: &'static str;
+
+    /// The metadata of this extension type
+    type Metadata;
+
+    /// Returns the metadata of this extension type
+    fn metadata(&self) -> &Self::Metadata;
+
+    /// Returns the serialized representation of the metadata of this extension
+    /// type, or `None` if it has no metadata
+    fn serialize_metadata(&self) -> Option<String>;
+
+    /// Deserializes the metadata of this extension type from the value of the
+    /// [`EXTENSION_TYPE_METADATA_KEY`] entry, if any
+    fn deserialize_metadata(metadata: Option<&str>)
+        -> Result<Self::Metadata, ArrowError>;
+
+    /// Returns an error if `data_type` is not a supported storage type for
+    /// this extension type
+    fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError>;
+
+    /// Creates this extension type for the provided storage `data_type` and `metadata`,
+    /// returning an error if they are not supported
+    fn try_new(
+        data_type: &DataType,
+        metadata: Self::Metadata,
+    ) -> Result<Self, ArrowError>;
+}
+
+/// Validates the storage type and serialized metadata of an extension type
+type ExtensionValidator =
+    fn(data_type: &DataType, metadata: Option<&str>) -> Result<(), ArrowError>;
+
+fn validate_extension_type<E: ExtensionType>(
+    data_type: &DataType,
+    metadata: Option<&str>,
+) -> Result<(), ArrowError> {
+    E::try_new(data_type, E::deserialize_metadata(metadata)?).map(|_| ())
+}
+
+/// A registry of known [`ExtensionType`], used to validate extension types
+/// found in the metadata of a [`Field`]
+///
+/// [`ExtensionTypeRegistry::default`] contains the canonical extension types
+/// when the `canonical_extension_types` feature is enabled. Additional types
+/// can be added with [`ExtensionTypeRegistry::register`].
+///
+/// ```
+/// # use arrow_schema::{ArrowError, DataType, Field};
+/// # use arrow_schema::extension::{ExtensionType, ExtensionTypeRegistry};
+/// struct Fruit;
+///
+/// impl ExtensionType for Fruit {
+///     const NAME: &'static str = "example.fruit";
+///     type Metadata = ();
+///
+///     fn metadata(&self) -> &Self::Metadata {
+///         &()
+///     }
+///
+///     fn serialize_metadata(&self) -> Option<String> {
+///         None
+///     }
+///
+///     fn deserialize_metadata(_: Option<&str>) -> Result<Self::Metadata, ArrowError> {
+///         Ok(())
+///     }
+///
+///     fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+///         match data_type {
+///             DataType::Utf8 => Ok(()),
+///             d => Err(ArrowError::InvalidArgumentError(format!("{d} not supported"))),
+///         }
+///     }
+///
+///     fn try_new(data_type: &DataType, _: ()) -> Result<Self, ArrowError> {
+///         Fruit.supports_data_type(data_type).map(|_| Fruit)
+///     }
+/// }
+///
+/// let mut registry = ExtensionTypeRegistry::new();
+/// registry.register::<Fruit>();
+///
+/// let field = Field::new("fruit", DataType::Utf8, true).with_extension_type(Fruit);
+/// registry.validate(&field).unwrap();
+///
+/// let invalid = field.with_data_type(DataType::Int32);
+/// assert!(registry.validate(&invalid).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExtensionTypeRegistry {
+    validators: HashMap<String, ExtensionValidator>,
+}
+
+impl Default for ExtensionTypeRegistry {
+    fn default() -> Self {
+        #[allow(unused_mut)]
+        let mut registry = Self::new();
+        #[cfg(feature = "canonical_extension_types")]
+        {
+            registry.register::<Uuid>();
+            registry.register::<Json>();
+            registry.register::<FixedShapeTensor>();
+            registry.register::<VariableShapeTensor>();
+        }
+        registry
+    }
+}
+
+impl ExtensionTypeRegistry {
+    /// Creates a new empty [`ExtensionTypeRegistry`]
+    pub fn new() -> Self {
+        Self {
+            validators: HashMap::new(),
+        }
+    }
+
+    /// Registers the [`ExtensionType`] `E`, replacing any existing extension
+    /// type with the same name
+    pub fn register<E: ExtensionType>(&mut self) {
+        self.validators
+            .insert(E::NAME.to_string(), validate_extension_type::<E>);
+    }
+
+    /// Returns true if an extension type named `name` is registered
+    pub fn contains(&self, name: &str) -> bool {
+        self.validators.contains_key(name)
+    }
+
+    /// Returns an iterator over the names of the registered extension types
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.validators.keys().map(|x| x.as_str())
+    }
+
+    /// Validates the extension type annotation of `field`, if any
+    ///
+    /// Returns an error if `field` is annotated with a registered extension
+    /// type, but its storage type or metadata are invalid for that extension
+    /// type. Fields without an extension type, or with an unregistered
+    /// extension type, are accepted.
+    pub fn validate(&self, field: &Field) -> Result<(), ArrowError> {
+        let name = match field.extension_type_name() {
+            Some(name) => name,
+            None => return Ok(()),
+        };
+        match self.validators.get(name) {
+            Some(validator) => {
+                validator(field.data_type(), field.extension_type_metadata())
+            }
+            None => Ok(()),
+        }
+    }
+}
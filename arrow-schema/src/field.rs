@@ -22,6 +22,9 @@ use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use crate::datatype::DataType;
+use crate::extension::{
+    ExtensionType, EXTENSION_TYPE_METADATA_KEY, EXTENSION_TYPE_NAME_KEY,
+};
 use crate::schema::SchemaBuilder;
 use crate::{Fields, UnionFields, UnionMode};
 
@@ -266,12 +269,106 @@ impl Field {
         self
     }
 
+    /// Removes the metadata of this `Field` and any nested child fields, including
+    /// extension type annotations, and returns self
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use std::sync::Arc;
+    /// # use arrow_schema::{DataType, Field};
+    /// let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+    /// let item = Field::new("item", DataType::Int32, true).with_metadata(metadata.clone());
+    /// let field = Field::new("c1", DataType::List(Arc::new(item)), true).with_metadata(metadata);
+    ///
+    /// let expected = Field::new("c1", DataType::new_list(DataType::Int32, true), true);
+    /// assert_eq!(field.without_metadata(), expected);
+    /// ```
+    pub fn without_metadata(mut self) -> Self {
+        self.metadata.clear();
+        self.data_type = data_type_without_metadata(self.data_type);
+        self
+    }
+
     /// Returns the immutable reference to the `Field`'s optional custom metadata.
     #[inline]
     pub const fn metadata(&self) -> &HashMap<String, String> {
         &self.metadata
     }
 
+    /// Returns the name of the [`ExtensionType`] of this [`Field`], if any
+    ///
+    /// This is the value of the [`EXTENSION_TYPE_NAME_KEY`] metadata entry
+    pub fn extension_type_name(&self) -> Option<&str> {
+        self.metadata
+            .get(EXTENSION_TYPE_NAME_KEY)
+            .map(|x| x.as_str())
+    }
+
+    /// Returns the serialized metadata of the [`ExtensionType`] of this [`Field`], if any
+    ///
+    /// This is the value of the [`EXTENSION_TYPE_METADATA_KEY`] metadata entry
+    pub fn extension_type_metadata(&self) -> Option<&str> {
+        self.metadata
+            .get(EXTENSION_TYPE_METADATA_KEY)
+            .map(|x| x.as_str())
+    }
+
+    /// Returns the [`ExtensionType`] `E` of this [`Field`]
+    ///
+    /// Returns an error if this field is not annotated with `E`, or if the
+    /// data type or metadata of this field is not supported by `E`
+    pub fn try_extension_type<E: ExtensionType>(&self) -> Result<E, ArrowError> {
+        match self.extension_type_name() {
+            Some(name) if name == E::NAME => {}
+            Some(name) => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Field extension type name mismatch, expected {}, found {name}",
+                    E::NAME
+                )))
+            }
+            None => {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Field extension type name missing".to_string(),
+                ))
+            }
+        }
+        let metadata = E::deserialize_metadata(self.extension_type_metadata())?;
+        E::try_new(self.data_type(), metadata)
+    }
+
+    /// Annotates this [`Field`] with the [`ExtensionType`] `extension_type`,
+    /// returning an error if its data type is not supported by the extension type
+    ///
+    /// Any existing extension type annotation is replaced
+    pub fn try_with_extension_type<E: ExtensionType>(
+        mut self,
+        extension_type: E,
+    ) -> Result<Self, ArrowError> {
+        extension_type.supports_data_type(self.data_type())?;
+
+        self.metadata
+            .insert(EXTENSION_TYPE_NAME_KEY.to_string(), E::NAME.to_string());
+        match extension_type.serialize_metadata() {
+            Some(metadata) => {
+                self.metadata
+                    .insert(EXTENSION_TYPE_METADATA_KEY.to_string(), metadata);
+            }
+            None => {
+                self.metadata.remove(EXTENSION_TYPE_METADATA_KEY);
+            }
+        }
+        Ok(self)
+    }
+
+    /// Annotates this [`Field`] with the [`ExtensionType`] `extension_type`
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Self::try_with_extension_type`] returns an error
+    pub fn with_extension_type<E: ExtensionType>(self, extension_type: E) -> Self {
+        self.try_with_extension_type(extension_type).unwrap()
+    }
+
     /// Returns an immutable reference to the `Field`'s name.
     #[inline]
     pub const fn name(&self) -> &String {
@@ -349,6 +446,8 @@ impl Field {
             }
             DataType::List(field)
             | DataType::LargeList(field)
+            | DataType::ListView(field)
+            | DataType::LargeListView(field)
             | DataType::FixedSizeList(field, _)
             | DataType::Map(field, _) => field.fields(),
             DataType::Dictionary(_, value_field) => Field::_fields(value_field.as_ref()),
@@ -389,7 +488,9 @@ impl Field {
 
     /// Merge this field into self if it is compatible.
     ///
-    /// Struct fields are merged recursively.
+    /// Struct fields are merged recursively, as are the child fields of list and
+    /// map types. A field of [`DataType::Null`] can be merged with a field of any
+    /// type, yielding a nullable field of the other type.
     ///
     /// NOTE: `self` may be updated to a partial / unexpected state in case of merge failure.
     ///
@@ -400,6 +501,10 @@ impl Field {
     /// let mut field = Field::new("c1", DataType::Int64, false);
     /// assert!(field.try_merge(&Field::new("c1", DataType::Int64, true)).is_ok());
     /// assert!(field.is_nullable());
+    ///
+    /// let mut field = Field::new("c2", DataType::Null, true);
+    /// assert!(field.try_merge(&Field::new("c2", DataType::Utf8, false)).is_ok());
+    /// assert_eq!(field, Field::new("c2", DataType::Utf8, true));
     /// ```
     pub fn try_merge(&mut self, from: &Field) -> Result<(), ArrowError> {
         if from.dict_id != self.dict_id {
@@ -437,6 +542,17 @@ impl Field {
             }
             _ => {}
         }
+        // Null is compatible with all types
+        if from.data_type == DataType::Null {
+            self.nullable = true;
+            return Ok(());
+        }
+        if self.data_type == DataType::Null {
+            self.data_type = from.data_type.clone();
+            self.nullable = true;
+            return Ok(());
+        }
+        let from_child = nested_child(&self.data_type, &from.data_type);
         match &mut self.data_type {
             DataType::Struct(nested_fields) => match &from.data_type {
                 DataType::Struct(from_nested_fields) => {
@@ -461,6 +577,20 @@ impl Field {
                     ));
                 }
             },
+            DataType::List(field)
+            | DataType::LargeList(field)
+            | DataType::ListView(field)
+            | DataType::LargeListView(field)
+            | DataType::FixedSizeList(field, _)
+            | DataType::Map(field, _) => match from_child {
+                Some(from_field) => Arc::make_mut(field).try_merge(from_field)?,
+                None => {
+                    return Err(ArrowError::SchemaError(
+                        format!("Fail to merge schema field '{}' because the from data_type = {} does not equal {}",
+                            self.name, from.data_type, self.data_type)
+                    ));
+                }
+            },
             DataType::Null
             | DataType::Boolean
             | DataType::Int8
@@ -483,15 +613,13 @@ impl Field {
             | DataType::Binary
             | DataType::LargeBinary
             | DataType::Interval(_)
-            | DataType::LargeList(_)
-            | DataType::List(_)
-            | DataType::Map(_, _)
             | DataType::Dictionary(_, _)
             | DataType::RunEndEncoded(_, _)
-            | DataType::FixedSizeList(_, _)
             | DataType::FixedSizeBinary(_)
             | DataType::Utf8
             | DataType::LargeUtf8
+            | DataType::Decimal32(_, _)
+            | DataType::Decimal64(_, _)
             | DataType::Decimal128(_, _)
             | DataType::Decimal256(_, _) => {
                 if self.data_type != from.data_type {
@@ -548,6 +676,48 @@ impl std::fmt::Display for Field {
     }
 }
 
+/// Returns `data_type` with the metadata of any nested child fields removed
+fn data_type_without_metadata(data_type: DataType) -> DataType {
+    let strip = |f: &FieldRef| Arc::new(f.as_ref().clone().without_metadata());
+    match data_type {
+        DataType::List(f) => DataType::List(strip(&f)),
+        DataType::LargeList(f) => DataType::LargeList(strip(&f)),
+        DataType::ListView(f) => DataType::ListView(strip(&f)),
+        DataType::LargeListView(f) => DataType::LargeListView(strip(&f)),
+        DataType::FixedSizeList(f, size) => DataType::FixedSizeList(strip(&f), size),
+        DataType::Map(f, sorted) => DataType::Map(strip(&f), sorted),
+        DataType::Struct(fields) => DataType::Struct(fields.iter().map(strip).collect()),
+        DataType::Union(fields, mode) => {
+            let (type_ids, fields): (Vec<_>, Vec<_>) =
+                fields.iter().map(|(id, f)| (id, strip(f))).unzip();
+            DataType::Union(UnionFields::new(type_ids, fields), mode)
+        }
+        DataType::RunEndEncoded(run_ends, values) => {
+            DataType::RunEndEncoded(strip(&run_ends), strip(&values))
+        }
+        DataType::Dictionary(key, value) => {
+            DataType::Dictionary(key, Box::new(data_type_without_metadata(*value)))
+        }
+        d => d,
+    }
+}
+
+/// Returns the child field of `from` if it is a list or map type that can be merged
+/// with `to`, see [`Field::try_merge`]
+fn nested_child<'a>(to: &DataType, from: &'a DataType) -> Option<&'a FieldRef> {
+    match (to, from) {
+        (DataType::List(_), DataType::List(f))
+        | (DataType::LargeList(_), DataType::LargeList(f))
+        | (DataType::ListView(_), DataType::ListView(f))
+        | (DataType::LargeListView(_), DataType::LargeListView(f)) => Some(f),
+        (DataType::FixedSizeList(_, a), DataType::FixedSizeList(f, b)) if a == b => {
+            Some(f)
+        }
+        (DataType::Map(_, a), DataType::Map(f, b)) if a == b => Some(f),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -580,6 +750,82 @@ mod test {
         assert_eq!("Schema error: Fail to merge schema field 'c1' because the from data_type = Float32 does not equal Int64", result);
     }
 
+    #[test]
+    fn test_merge_null() {
+        let mut field = Field::new("c1", DataType::Int64, false);
+        field
+            .try_merge(&Field::new("c1", DataType::Null, true))
+            .unwrap();
+        assert_eq!(field, Field::new("c1", DataType::Int64, true));
+
+        let mut field = Field::new("c1", DataType::Null, true);
+        field
+            .try_merge(&Field::new("c1", DataType::Int64, false))
+            .unwrap();
+        assert_eq!(field, Field::new("c1", DataType::Int64, true));
+    }
+
+    #[test]
+    fn test_merge_nested_child() {
+        let list = |nullable| {
+            let item = Field::new("item", DataType::Int32, nullable);
+            Field::new("c1", DataType::List(Arc::new(item)), false)
+        };
+        let mut field = list(false);
+        field.try_merge(&list(true)).unwrap();
+        assert_eq!(field, list(true));
+
+        let map = |value: DataType| {
+            let entries = Field::new(
+                "entries",
+                DataType::Struct(Fields::from(vec![
+                    Field::new("keys", DataType::Utf8, false),
+                    Field::new("values", value, true),
+                ])),
+                false,
+            );
+            Field::new("c1", DataType::Map(Arc::new(entries), false), true)
+        };
+        let mut field = map(DataType::Null);
+        field.try_merge(&map(DataType::Float64)).unwrap();
+        assert_eq!(field, map(DataType::Float64));
+
+        let item = Arc::new(Field::new("item", DataType::Int32, true));
+        let mut field = Field::new("c1", DataType::FixedSizeList(item.clone(), 2), true);
+        let other = Field::new("c1", DataType::FixedSizeList(item.clone(), 3), true);
+        let err = field.try_merge(&other).unwrap_err().to_string();
+        assert!(err.contains("does not equal"), "{err}");
+
+        let mut field = Field::new("c1", DataType::List(item.clone()), true);
+        let other = Field::new("c1", DataType::LargeList(item), true);
+        assert!(field.try_merge(&other).is_err());
+    }
+
+    #[test]
+    fn test_without_metadata() {
+        let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+        let field = |name: &str, data_type: DataType| {
+            Field::new(name, data_type, true).with_metadata(metadata.clone())
+        };
+        let nested = |field: &dyn Fn(&str, DataType) -> Field| {
+            let s = DataType::Struct(Fields::from(vec![
+                field("a", DataType::Int32),
+                field("b", DataType::Utf8),
+            ]));
+            let union = DataType::Union(
+                UnionFields::new([0, 1], [field("a", DataType::Int32), field("b", s)]),
+                UnionMode::Dense,
+            );
+            let list = DataType::LargeList(Arc::new(field("item", union)));
+            let dict = DataType::Dictionary(Box::new(DataType::Int8), Box::new(list));
+            field("c1", dict)
+        };
+
+        let stripped = nested(&field).without_metadata();
+        let expected = nested(&|name, data_type| Field::new(name, data_type, true));
+        assert_eq!(stripped, expected);
+    }
+
     #[test]
     fn test_fields_with_dict_id() {
         let dict1 = Field::new_dict(
@@ -0,0 +1,929 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Serde support for the JSON representation of [`Schema`], [`Field`] and [`DataType`]
+//! used by the
+//! [Arrow integration tests], and understood by the other Arrow implementations
+//!
+//! The [`serde`] implementations derived for [`Schema`], [`Field`] and [`DataType`]
+//! use a Rust specific representation. This module instead provides the cross-language
+//! representation, either by wrapping a value in [`IntegrationJson`], or with the
+//! `serialize` and `deserialize` functions of [`schema`], [`field`] and [`data_type`]
+//! for use with `#[serde(with = "...")]`
+//!
+//! ```
+//! # use arrow_schema::{DataType, Field, Schema};
+//! # use arrow_schema::integration_json::IntegrationJson;
+//! let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+//!
+//! let json = serde_json::to_string(&IntegrationJson(&schema)).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"fields":[{"name":"a","nullable":true,"type":{"name":"int","bitWidth":32,"isSigned":true},"children":[]}]}"#
+//! );
+//!
+//! let IntegrationJson(decoded) = serde_json::from_str::<IntegrationJson<Schema>>(&json).unwrap();
+//! assert_eq!(decoded, schema);
+//! ```
+//!
+//! As the type of a field is identified by its `name` property, this representation
+//! requires a self-describing format such as JSON
+//!
+//! [Arrow integration tests]: https://arrow.apache.org/docs/format/Integration.html#json-test-data-format
+
+use crate::{
+    DataType, Field, FieldRef, Fields, IntervalUnit, Schema, TimeUnit, UnionFields,
+    UnionMode,
+};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Wraps a [`Schema`], [`Field`] or [`DataType`], or a reference to one, to serialize and deserialize
+/// it in the integration JSON format
+///
+/// See the [module documentation](self) for more information
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegrationJson<T>(pub T);
+
+macro_rules! integration_json_impl {
+    ($t:ty, $module:ident) => {
+        impl Serialize for IntegrationJson<$t> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $module::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'a> Serialize for IntegrationJson<&'a $t> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $module::serialize(self.0, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for IntegrationJson<$t> {
+            fn deserialize<D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                $module::deserialize(deserializer).map(Self)
+            }
+        }
+    };
+}
+
+integration_json_impl!(Schema, schema);
+integration_json_impl!(Field, field);
+integration_json_impl!(DataType, data_type);
+
+/// Serialize and deserialize a [`Schema`] in the integration JSON format
+pub mod schema {
+    use super::*;
+
+    /// Serialize `schema` in the integration JSON format
+    pub fn serialize<S: Serializer>(
+        schema: &Schema,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        JsonSchema::try_from(schema)
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+
+    /// Deserialize a [`Schema`] from the integration JSON format
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Schema, D::Error> {
+        JsonSchema::deserialize(deserializer)?
+            .try_into()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Serialize and deserialize a [`Field`] in the integration JSON format
+pub mod field {
+    use super::*;
+
+    /// Serialize `field` in the integration JSON format
+    pub fn serialize<S: Serializer>(
+        field: &Field,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        JsonField::try_from(field)
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+
+    /// Deserialize a [`Field`] from the integration JSON format
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Field, D::Error> {
+        JsonField::deserialize(deserializer)?
+            .try_into()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Serialize and deserialize a [`DataType`] in the integration JSON format
+///
+/// In this format the children of a nested type, such as the fields of a
+/// [`DataType::Struct`], are stored on the enclosing field. Nested and dictionary
+/// types can therefore only be serialized as part of a [`Field`], and will return
+/// an error here.
+pub mod data_type {
+    use super::*;
+
+    /// Serialize `data_type` in the integration JSON format
+    pub fn serialize<S: Serializer>(
+        data_type: &DataType,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if data_type.is_nested() || matches!(data_type, DataType::RunEndEncoded(_, _)) {
+            return Err(serde::ser::Error::custom(format!(
+                "Nested type {data_type} must be serialized as part of a field"
+            )));
+        }
+        JsonType::try_from(data_type)
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+
+    /// Deserialize a [`DataType`] from the integration JSON format
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DataType, D::Error> {
+        let field = JsonField {
+            name: String::new(),
+            nullable: true,
+            data_type: JsonType::deserialize(deserializer)?,
+            children: vec![],
+            dictionary: None,
+            metadata: JsonMetadata::default(),
+        };
+        Field::try_from(field)
+            .map(|f| f.data_type().clone())
+            .map_err(D::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsonSchema {
+    fields: Vec<JsonField>,
+    #[serde(default, skip_serializing_if = "JsonMetadata::is_empty")]
+    metadata: JsonMetadata,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsonField {
+    name: String,
+    nullable: bool,
+    #[serde(rename = "type")]
+    data_type: JsonType,
+    #[serde(default)]
+    children: Vec<JsonField>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    dictionary: Option<JsonDictionary>,
+    #[serde(default, skip_serializing_if = "JsonMetadata::is_empty")]
+    metadata: JsonMetadata,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonDictionary {
+    id: i64,
+    index_type: JsonType,
+    #[serde(default)]
+    is_ordered: bool,
+}
+
+/// Metadata is written as a list of key-value pairs, but may also be read as an object
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum JsonMetadata {
+    List(Vec<JsonKeyValue>),
+    Map(HashMap<String, String>),
+}
+
+impl Default for JsonMetadata {
+    fn default() -> Self {
+        Self::List(vec![])
+    }
+}
+
+impl JsonMetadata {
+    fn is_empty(&self) -> bool {
+        match self {
+            Self::List(l) => l.is_empty(),
+            Self::Map(m) => m.is_empty(),
+        }
+    }
+
+    fn into_map(self) -> HashMap<String, String> {
+        match self {
+            Self::List(l) => l.into_iter().map(|kv| (kv.key, kv.value)).collect(),
+            Self::Map(m) => m,
+        }
+    }
+}
+
+impl From<&HashMap<String, String>> for JsonMetadata {
+    fn from(metadata: &HashMap<String, String>) -> Self {
+        let mut list: Vec<_> = metadata
+            .iter()
+            .map(|(key, value)| JsonKeyValue {
+                key: key.clone(),
+                value: value.clone(),
+            })
+            .collect();
+        // Sort for a deterministic output
+        list.sort_unstable_by(|a, b| a.key.cmp(&b.key));
+        Self::List(list)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsonKeyValue {
+    key: String,
+    value: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "name", rename_all = "lowercase")]
+enum JsonType {
+    Null,
+    Bool,
+    Int {
+        #[serde(rename = "bitWidth")]
+        bit_width: u16,
+        #[serde(rename = "isSigned")]
+        is_signed: bool,
+    },
+    FloatingPoint {
+        precision: JsonPrecision,
+    },
+    Binary,
+    LargeBinary,
+    FixedSizeBinary {
+        #[serde(rename = "byteWidth")]
+        byte_width: i32,
+    },
+    Utf8,
+    LargeUtf8,
+    Decimal {
+        precision: u8,
+        scale: i8,
+        #[serde(rename = "bitWidth", default = "default_decimal_bit_width")]
+        bit_width: u16,
+    },
+    Date {
+        unit: JsonDateUnit,
+    },
+    Time {
+        unit: JsonTimeUnit,
+        #[serde(rename = "bitWidth")]
+        bit_width: u16,
+    },
+    Timestamp {
+        unit: JsonTimeUnit,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        timezone: Option<String>,
+    },
+    Interval {
+        unit: JsonIntervalUnit,
+    },
+    Duration {
+        unit: JsonTimeUnit,
+    },
+    List,
+    LargeList,
+    ListView,
+    LargeListView,
+    FixedSizeList {
+        #[serde(rename = "listSize")]
+        list_size: i32,
+    },
+    Struct,
+    Union {
+        mode: JsonUnionMode,
+        #[serde(rename = "typeIds")]
+        type_ids: Vec<i8>,
+    },
+    Map {
+        #[serde(rename = "keysSorted", default)]
+        keys_sorted: bool,
+    },
+    RunEndEncoded,
+}
+
+fn default_decimal_bit_width() -> u16 {
+    128
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+enum JsonPrecision {
+    Half,
+    Single,
+    Double,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+enum JsonDateUnit {
+    Day,
+    Millisecond,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+enum JsonTimeUnit {
+    Second,
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+}
+
+impl From<&TimeUnit> for JsonTimeUnit {
+    fn from(unit: &TimeUnit) -> Self {
+        match unit {
+            TimeUnit::Second => Self::Second,
+            TimeUnit::Millisecond => Self::Millisecond,
+            TimeUnit::Microsecond => Self::Microsecond,
+            TimeUnit::Nanosecond => Self::Nanosecond,
+        }
+    }
+}
+
+impl From<JsonTimeUnit> for TimeUnit {
+    fn from(unit: JsonTimeUnit) -> Self {
+        match unit {
+            JsonTimeUnit::Second => Self::Second,
+            JsonTimeUnit::Millisecond => Self::Millisecond,
+            JsonTimeUnit::Microsecond => Self::Microsecond,
+            JsonTimeUnit::Nanosecond => Self::Nanosecond,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum JsonIntervalUnit {
+    YearMonth,
+    DayTime,
+    MonthDayNano,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+enum JsonUnionMode {
+    Sparse,
+    Dense,
+}
+
+impl TryFrom<&Schema> for JsonSchema {
+    type Error = String;
+
+    fn try_from(schema: &Schema) -> Result<Self, Self::Error> {
+        Ok(Self {
+            fields: schema
+                .fields()
+                .iter()
+                .map(|f| JsonField::try_from(f.as_ref()))
+                .collect::<Result<_, _>>()?,
+            metadata: schema.metadata().into(),
+        })
+    }
+}
+
+impl TryFrom<JsonSchema> for Schema {
+    type Error = String;
+
+    fn try_from(schema: JsonSchema) -> Result<Self, Self::Error> {
+        let fields = schema
+            .fields
+            .into_iter()
+            .map(Field::try_from)
+            .collect::<Result<Fields, _>>()?;
+        Ok(Schema::new_with_metadata(
+            fields,
+            schema.metadata.into_map(),
+        ))
+    }
+}
+
+impl TryFrom<&Field> for JsonField {
+    type Error = String;
+
+    fn try_from(field: &Field) -> Result<Self, Self::Error> {
+        let (data_type, dictionary) = match field.data_type() {
+            DataType::Dictionary(key, value) => {
+                let dictionary = JsonDictionary {
+                    id: field.dict_id().unwrap_or_default(),
+                    index_type: JsonType::try_from(key.as_ref())?,
+                    is_ordered: field.dict_is_ordered().unwrap_or_default(),
+                };
+                (value.as_ref(), Some(dictionary))
+            }
+            d => (d, None),
+        };
+
+        let children: Vec<&Field> = match data_type {
+            DataType::Struct(fields) => fields.iter().map(|f| f.as_ref()).collect(),
+            DataType::Union(fields, _) => {
+                fields.iter().map(|(_, f)| f.as_ref()).collect()
+            }
+            DataType::List(f)
+            | DataType::LargeList(f)
+            | DataType::ListView(f)
+            | DataType::LargeListView(f)
+            | DataType::FixedSizeList(f, _)
+            | DataType::Map(f, _) => vec![f.as_ref()],
+            DataType::RunEndEncoded(run_ends, values) => {
+                vec![run_ends.as_ref(), values.as_ref()]
+            }
+            _ => vec![],
+        };
+
+        Ok(Self {
+            name: field.name().clone(),
+            nullable: field.is_nullable(),
+            data_type: JsonType::try_from(data_type)?,
+            children: children
+                .into_iter()
+                .map(JsonField::try_from)
+                .collect::<Result<_, _>>()?,
+            dictionary,
+            metadata: field.metadata().into(),
+        })
+    }
+}
+
+impl TryFrom<JsonField> for Field {
+    type Error = String;
+
+    fn try_from(field: JsonField) -> Result<Self, Self::Error> {
+        let name = field.name;
+        let metadata = field.metadata.into_map();
+        let mut children = field
+            .children
+            .into_iter()
+            .map(|f| Field::try_from(f).map(Arc::new))
+            .collect::<Result<Vec<FieldRef>, _>>()?;
+
+        let mut child = |expected: usize| match children.len() == expected {
+            true => Ok(children.remove(0)),
+            false => Err(format!(
+                "Expected {expected} children for field \"{name}\", found {}",
+                children.len()
+            )),
+        };
+
+        let data_type = match field.data_type {
+            JsonType::Null => DataType::Null,
+            JsonType::Bool => DataType::Boolean,
+            JsonType::Int {
+                bit_width,
+                is_signed,
+            } => int_type(bit_width, is_signed)?,
+            JsonType::FloatingPoint { precision } => match precision {
+                JsonPrecision::Half => DataType::Float16,
+                JsonPrecision::Single => DataType::Float32,
+                JsonPrecision::Double => DataType::Float64,
+            },
+            JsonType::Binary => DataType::Binary,
+            JsonType::LargeBinary => DataType::LargeBinary,
+            JsonType::FixedSizeBinary { byte_width } => {
+                DataType::FixedSizeBinary(byte_width)
+            }
+            JsonType::Utf8 => DataType::Utf8,
+            JsonType::LargeUtf8 => DataType::LargeUtf8,
+            JsonType::Decimal {
+                precision,
+                scale,
+                bit_width,
+            } => match bit_width {
+                32 => DataType::Decimal32(precision, scale),
+                64 => DataType::Decimal64(precision, scale),
+                128 => DataType::Decimal128(precision, scale),
+                256 => DataType::Decimal256(precision, scale),
+                _ => return Err(format!("Unsupported decimal bit width {bit_width}")),
+            },
+            JsonType::Date { unit } => match unit {
+                JsonDateUnit::Day => DataType::Date32,
+                JsonDateUnit::Millisecond => DataType::Date64,
+            },
+            JsonType::Time { unit, bit_width } => match bit_width {
+                32 => DataType::Time32(unit.into()),
+                64 => DataType::Time64(unit.into()),
+                _ => return Err(format!("Unsupported time bit width {bit_width}")),
+            },
+            JsonType::Timestamp { unit, timezone } => {
+                DataType::Timestamp(unit.into(), timezone.map(Into::into))
+            }
+            JsonType::Interval { unit } => DataType::Interval(match unit {
+                JsonIntervalUnit::YearMonth => IntervalUnit::YearMonth,
+                JsonIntervalUnit::DayTime => IntervalUnit::DayTime,
+                JsonIntervalUnit::MonthDayNano => IntervalUnit::MonthDayNano,
+            }),
+            JsonType::Duration { unit } => DataType::Duration(unit.into()),
+            JsonType::List => DataType::List(child(1)?),
+            JsonType::LargeList => DataType::LargeList(child(1)?),
+            JsonType::ListView => DataType::ListView(child(1)?),
+            JsonType::LargeListView => DataType::LargeListView(child(1)?),
+            JsonType::FixedSizeList { list_size } => {
+                DataType::FixedSizeList(child(1)?, list_size)
+            }
+            JsonType::Map { keys_sorted } => DataType::Map(child(1)?, keys_sorted),
+            JsonType::RunEndEncoded => {
+                let run_ends = child(2)?;
+                DataType::RunEndEncoded(run_ends, children.remove(0))
+            }
+            JsonType::Struct => DataType::Struct(children.into()),
+            JsonType::Union { mode, type_ids } => {
+                if type_ids.len() != children.len() {
+                    return Err(format!(
+                        "Expected {} children for union field \"{name}\", found {}",
+                        type_ids.len(),
+                        children.len()
+                    ));
+                }
+                let mode = match mode {
+                    JsonUnionMode::Sparse => UnionMode::Sparse,
+                    JsonUnionMode::Dense => UnionMode::Dense,
+                };
+                DataType::Union(UnionFields::new(type_ids, children), mode)
+            }
+        };
+
+        let field = match field.dictionary {
+            Some(dictionary) => {
+                let key = match dictionary.index_type {
+                    JsonType::Int {
+                        bit_width,
+                        is_signed,
+                    } => int_type(bit_width, is_signed)?,
+                    _ => {
+                        return Err("Dictionary index type must be an integer".to_string())
+                    }
+                };
+                let data_type = DataType::Dictionary(Box::new(key), Box::new(data_type));
+                Field::new_dict(
+                    name,
+                    data_type,
+                    field.nullable,
+                    dictionary.id,
+                    dictionary.is_ordered,
+                )
+            }
+            None => Field::new(name, data_type, field.nullable),
+        };
+        Ok(field.with_metadata(metadata))
+    }
+}
+
+fn int_type(bit_width: u16, is_signed: bool) -> Result<DataType, String> {
+    Ok(match (bit_width, is_signed) {
+        (8, true) => DataType::Int8,
+        (16, true) => DataType::Int16,
+        (32, true) => DataType::Int32,
+        (64, true) => DataType::Int64,
+        (8, false) => DataType::UInt8,
+        (16, false) => DataType::UInt16,
+        (32, false) => DataType::UInt32,
+        (64, false) => DataType::UInt64,
+        _ => return Err(format!("Unsupported integer bit width {bit_width}")),
+    })
+}
+
+impl TryFrom<&DataType> for JsonType {
+    type Error = String;
+
+    fn try_from(data_type: &DataType) -> Result<Self, Self::Error> {
+        let int = |bit_width, is_signed| JsonType::Int {
+            bit_width,
+            is_signed,
+        };
+        let decimal = |precision, scale, bit_width| JsonType::Decimal {
+            precision,
+            scale,
+            bit_width,
+        };
+        Ok(match data_type {
+            DataType::Null => JsonType::Null,
+            DataType::Boolean => JsonType::Bool,
+            DataType::Int8 => int(8, true),
+            DataType::Int16 => int(16, true),
+            DataType::Int32 => int(32, true),
+            DataType::Int64 => int(64, true),
+            DataType::UInt8 => int(8, false),
+            DataType::UInt16 => int(16, false),
+            DataType::UInt32 => int(32, false),
+            DataType::UInt64 => int(64, false),
+            DataType::Float16 => JsonType::FloatingPoint {
+                precision: JsonPrecision::Half,
+            },
+            DataType::Float32 => JsonType::FloatingPoint {
+                precision: JsonPrecision::Single,
+            },
+            DataType::Float64 => JsonType::FloatingPoint {
+                precision: JsonPrecision::Double,
+            },
+            DataType::Binary => JsonType::Binary,
+            DataType::LargeBinary => JsonType::LargeBinary,
+            DataType::FixedSizeBinary(byte_width) => JsonType::FixedSizeBinary {
+                byte_width: *byte_width,
+            },
+            DataType::Utf8 => JsonType::Utf8,
+            DataType::LargeUtf8 => JsonType::LargeUtf8,
+            DataType::Decimal32(p, s) => decimal(*p, *s, 32),
+            DataType::Decimal64(p, s) => decimal(*p, *s, 64),
+            DataType::Decimal128(p, s) => decimal(*p, *s, 128),
+            DataType::Decimal256(p, s) => decimal(*p, *s, 256),
+            DataType::Date32 => JsonType::Date {
+                unit: JsonDateUnit::Day,
+            },
+            DataType::Date64 => JsonType::Date {
+                unit: JsonDateUnit::Millisecond,
+            },
+            DataType::Time32(unit) => JsonType::Time {
+                unit: unit.into(),
+                bit_width: 32,
+            },
+            DataType::Time64(unit) => JsonType::Time {
+                unit: unit.into(),
+                bit_width: 64,
+            },
+            DataType::Timestamp(unit, tz) => JsonType::Timestamp {
+                unit: unit.into(),
+                timezone: tz.as_deref().map(String::from),
+            },
+            DataType::Interval(unit) => JsonType::Interval {
+                unit: match unit {
+                    IntervalUnit::YearMonth => JsonIntervalUnit::YearMonth,
+                    IntervalUnit::DayTime => JsonIntervalUnit::DayTime,
+                    IntervalUnit::MonthDayNano => JsonIntervalUnit::MonthDayNano,
+                },
+            },
+            DataType::Duration(unit) => JsonType::Duration { unit: unit.into() },
+            DataType::List(_) => JsonType::List,
+            DataType::LargeList(_) => JsonType::LargeList,
+            DataType::ListView(_) => JsonType::ListView,
+            DataType::LargeListView(_) => JsonType::LargeListView,
+            DataType::FixedSizeList(_, list_size) => JsonType::FixedSizeList {
+                list_size: *list_size,
+            },
+            DataType::Struct(_) => JsonType::Struct,
+            DataType::Union(fields, mode) => JsonType::Union {
+                mode: match mode {
+                    UnionMode::Sparse => JsonUnionMode::Sparse,
+                    UnionMode::Dense => JsonUnionMode::Dense,
+                },
+                type_ids: fields.iter().map(|(id, _)| id).collect(),
+            },
+            DataType::Map(_, keys_sorted) => JsonType::Map {
+                keys_sorted: *keys_sorted,
+            },
+            DataType::RunEndEncoded(_, _) => JsonType::RunEndEncoded,
+            DataType::Dictionary(_, _) => {
+                return Err(format!(
+                    "Nested dictionary type {data_type} is not supported, \
+                     dictionaries must be the type of a field"
+                ))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(schema: &Schema) -> Schema {
+        let json = serde_json::to_string(&IntegrationJson(schema)).unwrap();
+        serde_json::from_str::<IntegrationJson<Schema>>(&json)
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let union_fields = UnionFields::new(
+            vec![2, 5],
+            vec![
+                Field::new("i", DataType::Int8, true),
+                Field::new("s", DataType::LargeUtf8, true),
+            ],
+        );
+        let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+
+        let schema = Schema::new_with_metadata(
+            vec![
+                Field::new("null", DataType::Null, true),
+                Field::new("bool", DataType::Boolean, false),
+                Field::new("u16", DataType::UInt16, true).with_metadata(metadata.clone()),
+                Field::new("f16", DataType::Float16, true),
+                Field::new("fsb", DataType::FixedSizeBinary(3), true),
+                Field::new("d32", DataType::Decimal32(9, 2), true),
+                Field::new("d256", DataType::Decimal256(40, -3), true),
+                Field::new("date", DataType::Date64, true),
+                Field::new("time", DataType::Time64(TimeUnit::Nanosecond), true),
+                Field::new(
+                    "ts",
+                    DataType::Timestamp(TimeUnit::Microsecond, Some("+01:00".into())),
+                    true,
+                ),
+                Field::new("interval", DataType::Interval(IntervalUnit::DayTime), true),
+                Field::new("duration", DataType::Duration(TimeUnit::Second), true),
+                Field::new_list("list", Field::new("item", DataType::Binary, true), true),
+                Field::new(
+                    "fsl",
+                    DataType::FixedSizeList(
+                        Arc::new(Field::new("item", DataType::Int64, false)),
+                        4,
+                    ),
+                    true,
+                ),
+                Field::new_map(
+                    "map",
+                    "entries",
+                    Field::new("key", DataType::Utf8, false),
+                    Field::new("value", DataType::Float64, true),
+                    true,
+                    true,
+                ),
+                Field::new(
+                    "union",
+                    DataType::Union(union_fields, UnionMode::Dense),
+                    true,
+                ),
+                Field::new(
+                    "ree",
+                    DataType::RunEndEncoded(
+                        Arc::new(Field::new("run_ends", DataType::Int32, false)),
+                        Arc::new(Field::new("values", DataType::Utf8, true)),
+                    ),
+                    true,
+                ),
+                Field::new_dict(
+                    "dict",
+                    DataType::Dictionary(
+                        Box::new(DataType::Int16),
+                        Box::new(DataType::List(Arc::new(Field::new(
+                            "item",
+                            DataType::Utf8,
+                            true,
+                        )))),
+                    ),
+                    true,
+                    3,
+                    true,
+                ),
+            ],
+            metadata,
+        );
+
+        assert_eq!(round_trip(&schema), schema);
+    }
+
+    #[test]
+    fn test_format() {
+        let field = Field::new_dict(
+            "d",
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+            true,
+            1,
+            false,
+        )
+        .with_metadata(HashMap::from([("k".to_string(), "v".to_string())]));
+
+        let json = serde_json::to_value(IntegrationJson(&field)).unwrap();
+        let expected = serde_json::json!({
+            "name": "d",
+            "nullable": true,
+            "type": {"name": "utf8"},
+            "children": [],
+            "dictionary": {
+                "id": 1,
+                "indexType": {"name": "int", "bitWidth": 8, "isSigned": true},
+                "isOrdered": false
+            },
+            "metadata": [{"key": "k", "value": "v"}]
+        });
+        assert_eq!(json, expected);
+
+        let field = Field::new("ts", DataType::Timestamp(TimeUnit::Second, None), false);
+        let json = serde_json::to_value(IntegrationJson(&field)).unwrap();
+        assert_eq!(
+            json["type"],
+            serde_json::json!({"name": "timestamp", "unit": "SECOND"})
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        // Metadata may be an object, and children and dictionary ordering omitted
+        let json = r#"{
+            "fields": [
+                {
+                    "name": "a",
+                    "nullable": false,
+                    "type": {"name": "decimal", "precision": 10, "scale": 2},
+                    "metadata": {"k": "v"}
+                },
+                {
+                    "name": "b",
+                    "nullable": true,
+                    "type": {"name": "utf8"},
+                    "dictionary": {
+                        "id": 0,
+                        "indexType": {"name": "int", "bitWidth": 32, "isSigned": false}
+                    }
+                }
+            ],
+            "metadata": [{"key": "x", "value": "y"}]
+        }"#;
+        let schema = serde_json::from_str::<IntegrationJson<Schema>>(json)
+            .unwrap()
+            .0;
+
+        let metadata = |k: &str, v: &str| HashMap::from([(k.to_string(), v.to_string())]);
+        let expected = Schema::new_with_metadata(
+            vec![
+                Field::new("a", DataType::Decimal128(10, 2), false)
+                    .with_metadata(metadata("k", "v")),
+                Field::new_dict(
+                    "b",
+                    DataType::Dictionary(
+                        Box::new(DataType::UInt32),
+                        Box::new(DataType::Utf8),
+                    ),
+                    true,
+                    0,
+                    false,
+                ),
+            ],
+            metadata("x", "y"),
+        );
+        assert_eq!(schema, expected);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let cases = [
+            (
+                r#"{"name": "a", "nullable": true, "type": {"name": "list"}}"#,
+                "Expected 1 children for field \"a\", found 0",
+            ),
+            (
+                r#"{"name": "a", "nullable": true, "type": {"name": "int", "bitWidth": 7, "isSigned": true}}"#,
+                "Unsupported integer bit width 7",
+            ),
+            (
+                r#"{"name": "a", "nullable": true, "type": {"name": "foo"}}"#,
+                "unknown variant `foo`",
+            ),
+        ];
+        for (json, expected) in cases {
+            let err = serde_json::from_str::<IntegrationJson<Field>>(json).unwrap_err();
+            assert!(err.to_string().contains(expected), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_data_type() {
+        let data_type = DataType::Time32(TimeUnit::Millisecond);
+        let json = serde_json::to_string(&IntegrationJson(&data_type)).unwrap();
+        assert_eq!(
+            json,
+            r#"{"name":"time","unit":"MILLISECOND","bitWidth":32}"#
+        );
+        let decoded = serde_json::from_str::<IntegrationJson<DataType>>(&json).unwrap();
+        assert_eq!(decoded.0, data_type);
+
+        let data_type = DataType::new_list(DataType::Int32, true);
+        let err = serde_json::to_string(&IntegrationJson(&data_type)).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("must be serialized as part of a field"));
+
+        let empty =
+            serde_json::from_str::<IntegrationJson<DataType>>(r#"{"name":"struct"}"#);
+        assert_eq!(empty.unwrap().0, DataType::Struct(Fields::empty()));
+        let err = serde_json::from_str::<IntegrationJson<DataType>>(r#"{"name":"list"}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("Expected 1 children"), "{err}");
+    }
+}
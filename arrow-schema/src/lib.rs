@@ -21,6 +21,7 @@ mod datatype;
 pub use datatype::*;
 mod error;
 pub use error::*;
+pub mod extension;
 mod field;
 pub use field::*;
 mod fields;
@@ -32,6 +33,9 @@ use std::ops;
 #[cfg(feature = "ffi")]
 pub mod ffi;
 
+#[cfg(feature = "serde")]
+pub mod integration_json;
+
 /// Options that define the sort order of a given column
 #[derive(Clone, Hash, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub struct SortOptions {
@@ -258,6 +258,17 @@ impl Schema {
         Ok(out_fields.finish().with_metadata(out_meta))
     }
 
+    /// Removes the metadata of this `Schema` and all its fields, including
+    /// extension type annotations, see [`Field::without_metadata`]
+    pub fn without_metadata(self) -> Self {
+        let fields: Fields = self
+            .fields
+            .iter()
+            .map(|f| f.as_ref().clone().without_metadata())
+            .collect();
+        Self::new(fields)
+    }
+
     /// Returns an immutable reference of the vector of `Field` instances.
     #[inline]
     pub const fn fields(&self) -> &Fields {
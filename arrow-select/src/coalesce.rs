@@ -0,0 +1,273 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines [`BatchCoalescer`] for combining small [`RecordBatch`]es into larger ones
+//!
+//! Example:
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+//! # use arrow_select::coalesce::BatchCoalescer;
+//! let a = Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef;
+//! let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+//!
+//! let mut coalescer = BatchCoalescer::new(batch.schema(), 4);
+//! coalescer.push_batch(batch.clone()).unwrap();
+//! assert!(coalescer.next_completed_batch().is_none());
+//!
+//! coalescer.push_batch(batch).unwrap();
+//! let completed = coalescer.next_completed_batch().unwrap();
+//! assert_eq!(completed.num_rows(), 4);
+//!
+//! coalescer.finish_buffered_batch().unwrap();
+//! let completed = coalescer.next_completed_batch().unwrap();
+//! assert_eq!(completed.num_rows(), 2);
+//! ```
+
+use crate::concat::concat_batches;
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, SchemaRef};
+use std::collections::VecDeque;
+
+/// Concatenates a stream of [`RecordBatch`]es into batches of `target_batch_size` rows
+///
+/// Operators such as filters and joins frequently produce many small batches, which
+/// are inefficient to process downstream. [`BatchCoalescer`] buffers such batches,
+/// emitting a completed batch each time `target_batch_size` rows have been received.
+///
+/// Input batches are sliced as necessary, and only copied when combined with other
+/// buffered rows. An input batch that exactly fills a completed batch with nothing
+/// buffered is emitted as is, without copying. As buffered rows are copied into a new
+/// batch, completed batches do not retain references to the, potentially much larger,
+/// buffers of the input batches they were formed from.
+///
+/// Completed batches are retrieved with [`Self::next_completed_batch`], and any
+/// remaining buffered rows can be flushed with [`Self::finish_buffered_batch`].
+#[derive(Debug)]
+pub struct BatchCoalescer {
+    schema: SchemaRef,
+    target_batch_size: usize,
+    buffered: Vec<RecordBatch>,
+    buffered_rows: usize,
+    completed: VecDeque<RecordBatch>,
+}
+
+impl BatchCoalescer {
+    /// Create a new [`BatchCoalescer`] producing batches of `target_batch_size` rows
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target_batch_size` is 0
+    pub fn new(schema: SchemaRef, target_batch_size: usize) -> Self {
+        assert!(target_batch_size > 0, "target_batch_size must be non-zero");
+        Self {
+            schema,
+            target_batch_size,
+            buffered: vec![],
+            buffered_rows: 0,
+            completed: VecDeque::new(),
+        }
+    }
+
+    /// Returns the schema of the batches produced by this [`BatchCoalescer`]
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Returns the number of rows in each completed batch
+    pub fn target_batch_size(&self) -> usize {
+        self.target_batch_size
+    }
+
+    /// Returns the number of rows buffered and not yet part of a completed batch
+    pub fn buffered_rows(&self) -> usize {
+        self.buffered_rows
+    }
+
+    /// Push a [`RecordBatch`] into this [`BatchCoalescer`]
+    ///
+    /// Returns an error if the schema of `batch` does not match [`Self::schema`]
+    pub fn push_batch(&mut self, batch: RecordBatch) -> Result<(), ArrowError> {
+        if batch.schema() != self.schema {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "BatchCoalescer expected batch with schema {:?}, got {:?}",
+                self.schema,
+                batch.schema()
+            )));
+        }
+
+        let mut offset = 0;
+        while offset < batch.num_rows() {
+            let remaining = batch.num_rows() - offset;
+            let len = remaining.min(self.target_batch_size - self.buffered_rows);
+
+            if self.buffered_rows == 0 && len == self.target_batch_size {
+                self.completed.push_back(batch.slice(offset, len));
+            } else {
+                self.buffered.push(batch.slice(offset, len));
+                self.buffered_rows += len;
+                if self.buffered_rows == self.target_batch_size {
+                    self.finish_buffered_batch()?;
+                }
+            }
+            offset += len;
+        }
+        Ok(())
+    }
+
+    /// Concatenates any buffered rows into a completed batch, even if it contains
+    /// fewer than `target_batch_size` rows
+    ///
+    /// This should be called once the input has been exhausted
+    pub fn finish_buffered_batch(&mut self) -> Result<(), ArrowError> {
+        if self.buffered.is_empty() {
+            return Ok(());
+        }
+        let batch = concat_batches(&self.schema, &self.buffered)?;
+        self.buffered.clear();
+        self.buffered_rows = 0;
+        self.completed.push_back(batch);
+        Ok(())
+    }
+
+    /// Returns true if there are no buffered rows and no completed batches
+    pub fn is_empty(&self) -> bool {
+        self.buffered.is_empty() && self.completed.is_empty()
+    }
+
+    /// Returns true if there is at least one completed batch
+    pub fn has_completed_batch(&self) -> bool {
+        !self.completed.is_empty()
+    }
+
+    /// Removes and returns the next completed batch, if any
+    pub fn next_completed_batch(&mut self) -> Option<RecordBatch> {
+        self.completed.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int32Type, Int8Type};
+    use arrow_array::{Array, ArrayRef, DictionaryArray, Int32Array, StringArray};
+    use std::sync::Arc;
+
+    fn int_batch(range: std::ops::Range<i32>) -> RecordBatch {
+        let a = Arc::new(Int32Array::from_iter_values(range)) as ArrayRef;
+        RecordBatch::try_from_iter([("a", a)]).unwrap()
+    }
+
+    fn drain(coalescer: &mut BatchCoalescer) -> Vec<Vec<i32>> {
+        std::iter::from_fn(|| coalescer.next_completed_batch())
+            .map(|b| b.column(0).as_primitive::<Int32Type>().values().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn test_coalesce_small_batches() {
+        let schema = int_batch(0..0).schema();
+        let mut coalescer = BatchCoalescer::new(schema, 5);
+        assert!(coalescer.is_empty());
+
+        coalescer.push_batch(int_batch(0..2)).unwrap();
+        coalescer.push_batch(int_batch(2..4)).unwrap();
+        assert!(!coalescer.has_completed_batch());
+        assert_eq!(coalescer.buffered_rows(), 4);
+
+        coalescer.push_batch(int_batch(4..7)).unwrap();
+        assert_eq!(coalescer.buffered_rows(), 2);
+        assert_eq!(drain(&mut coalescer), vec![vec![0, 1, 2, 3, 4]]);
+
+        coalescer.finish_buffered_batch().unwrap();
+        assert_eq!(drain(&mut coalescer), vec![vec![5, 6]]);
+        assert!(coalescer.is_empty());
+
+        // Finishing with nothing buffered is a no-op
+        coalescer.finish_buffered_batch().unwrap();
+        assert!(coalescer.is_empty());
+    }
+
+    #[test]
+    fn test_coalesce_large_batch() {
+        let schema = int_batch(0..0).schema();
+        let mut coalescer = BatchCoalescer::new(schema, 3);
+
+        coalescer.push_batch(int_batch(0..1)).unwrap();
+        coalescer.push_batch(int_batch(1..9)).unwrap();
+        coalescer.push_batch(int_batch(9..9)).unwrap();
+        assert_eq!(
+            drain(&mut coalescer),
+            vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8]]
+        );
+        assert!(coalescer.is_empty());
+    }
+
+    #[test]
+    fn test_coalesce_passthrough() {
+        let batch = int_batch(0..4);
+        let mut coalescer = BatchCoalescer::new(batch.schema(), 4);
+        coalescer.push_batch(batch.clone()).unwrap();
+
+        let completed = coalescer.next_completed_batch().unwrap();
+        assert_eq!(completed, batch);
+        // Buffers are shared with the input
+        assert_eq!(
+            completed.column(0).to_data().buffers()[0].as_ptr(),
+            batch.column(0).to_data().buffers()[0].as_ptr()
+        );
+    }
+
+    #[test]
+    fn test_coalesce_dictionary() {
+        let a: DictionaryArray<Int8Type> = vec!["a", "b", "a"].into_iter().collect();
+        let b: DictionaryArray<Int8Type> = vec!["c", "a"].into_iter().collect();
+        let batch_a =
+            RecordBatch::try_from_iter([("d", Arc::new(a) as ArrayRef)]).unwrap();
+        let batch_b =
+            RecordBatch::try_from_iter([("d", Arc::new(b) as ArrayRef)]).unwrap();
+
+        let mut coalescer = BatchCoalescer::new(batch_a.schema(), 4);
+        coalescer.push_batch(batch_a).unwrap();
+        coalescer.push_batch(batch_b).unwrap();
+        coalescer.finish_buffered_batch().unwrap();
+
+        let values: Vec<_> = std::iter::from_fn(|| coalescer.next_completed_batch())
+            .flat_map(|b| {
+                let dict = b.column(0).as_dictionary::<Int8Type>();
+                let values = dict.values().as_string::<i32>().clone();
+                dict.keys()
+                    .iter()
+                    .map(|k| values.value(k.unwrap() as usize).to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(values, vec!["a", "b", "a", "c", "a"]);
+    }
+
+    #[test]
+    fn test_coalesce_schema_mismatch() {
+        let mut coalescer = BatchCoalescer::new(int_batch(0..0).schema(), 2);
+        let s = Arc::new(StringArray::from(vec!["a"])) as ArrayRef;
+        let batch = RecordBatch::try_from_iter([("a", s)]).unwrap();
+        let err = coalescer.push_batch(batch).unwrap_err();
+        assert!(err.to_string().contains("BatchCoalescer expected batch"));
+        assert_eq!(coalescer.buffered_rows(), 0);
+    }
+}
@@ -132,6 +132,7 @@ pub fn concat_batches<'a>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use arrow_array::builder::UnionBuilder;
     use arrow_array::cast::AsArray;
     use arrow_schema::{Field, Schema};
     use std::sync::Arc;
@@ -718,4 +719,42 @@ mod tests {
         assert_eq!(data.buffers()[1].len(), 200);
         assert_eq!(data.buffers()[1].capacity(), 256); // Nearest multiple of 64
     }
+
+    fn union_values(union: &UnionArray) -> Vec<f64> {
+        (0..union.len())
+            .map(|i| {
+                let value = union.value(i);
+                match union.type_id(i) {
+                    0 => value.as_primitive::<Int32Type>().value(0) as f64,
+                    _ => value.as_primitive::<Float64Type>().value(0),
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_concat_union() {
+        for dense in [true, false] {
+            let new_builder = || match dense {
+                true => UnionBuilder::new_dense(),
+                false => UnionBuilder::new_sparse(),
+            };
+            let mut builder = new_builder();
+            builder.append::<Int32Type>("a", 1).unwrap();
+            builder.append::<Float64Type>("b", 2.5).unwrap();
+            let a = builder.build().unwrap();
+
+            let mut builder = new_builder();
+            builder.append::<Int32Type>("a", 3).unwrap();
+            builder.append::<Float64Type>("b", 4.5).unwrap();
+            builder.append::<Int32Type>("a", 5).unwrap();
+            let b = builder.build().unwrap();
+
+            let c = concat(&[&a, &b.slice(1, 2)]).unwrap();
+            let c = c.as_any().downcast_ref::<UnionArray>().unwrap();
+            assert_eq!(c.data_type(), a.data_type());
+            assert_eq!(c.type_ids(), &[0, 1, 1, 0]);
+            assert_eq!(union_values(c), vec![1., 2.5, 4.5, 5.]);
+        }
+    }
 }
@@ -17,10 +17,17 @@
 
 //! Arrow selection kernels
 
+pub mod coalesce;
 pub mod concat;
 pub mod filter;
 pub mod interleave;
+pub mod list;
 pub mod nullif;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod split;
+pub mod structs;
 pub mod take;
+pub mod unique;
 pub mod window;
 pub mod zip;
@@ -0,0 +1,551 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels for selecting the elements of list arrays
+//!
+//! These support [`DataType::List`], [`DataType::LargeList`], [`DataType::FixedSizeList`],
+//! [`DataType::ListView`] and [`DataType::LargeListView`] arrays.
+//!
+//! ```
+//! # use arrow_array::{Int32Array, ListArray};
+//! # use arrow_array::cast::AsArray;
+//! # use arrow_array::types::Int32Type;
+//! # use arrow_select::list::{list_extract, list_slice};
+//! let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+//!     Some(vec![Some(1), Some(2), Some(3)]),
+//!     Some(vec![Some(4)]),
+//!     None,
+//! ]);
+//!
+//! // Negative indices count from the end of each list
+//! let last = list_extract(&list, -1).unwrap();
+//! let expected = Int32Array::from(vec![Some(3), Some(4), None]);
+//! assert_eq!(last.as_primitive::<Int32Type>(), &expected);
+//!
+//! let sliced = list_slice(&list, 1, None).unwrap();
+//! let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+//!     Some(vec![Some(2), Some(3)]),
+//!     Some(vec![]),
+//!     None,
+//! ]);
+//! assert_eq!(sliced.as_list::<i32>(), &expected);
+//! ```
+
+use arrow_array::cast::AsArray;
+use arrow_array::{
+    Array, ArrayRef, FixedSizeListArray, GenericListArray, GenericListViewArray,
+    OffsetSizeTrait, UInt64Array,
+};
+use arrow_buffer::{NullBuffer, OffsetBuffer, ScalarBuffer};
+use arrow_schema::{ArrowError, DataType, FieldRef};
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::take::take;
+
+/// The elements of each list of a list array, as ranges of its values
+struct ListRanges<'a> {
+    field: &'a FieldRef,
+    values: &'a ArrayRef,
+    nulls: Option<&'a NullBuffer>,
+    ranges: Vec<Range<usize>>,
+}
+
+impl<'a> ListRanges<'a> {
+    fn try_new(array: &'a dyn Array) -> Result<Self, ArrowError> {
+        let (field, values, ranges) = match array.data_type() {
+            DataType::List(field) => {
+                let list = array.as_list::<i32>();
+                (field, list.values(), list_ranges(list))
+            }
+            DataType::LargeList(field) => {
+                let list = array.as_list::<i64>();
+                (field, list.values(), list_ranges(list))
+            }
+            DataType::ListView(field) => {
+                let list = array.as_list_view::<i32>();
+                (field, list.values(), list_view_ranges(list))
+            }
+            DataType::LargeListView(field) => {
+                let list = array.as_list_view::<i64>();
+                (field, list.values(), list_view_ranges(list))
+            }
+            DataType::FixedSizeList(field, size) => {
+                let list = array.as_fixed_size_list();
+                let size = *size as usize;
+                let ranges = (0..list.len())
+                    .map(|i| list.value_offset(i) as usize)
+                    .map(|offset| offset..offset + size)
+                    .collect();
+                (field, list.values(), ranges)
+            }
+            d => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Expected a list array, got {d}"
+                )))
+            }
+        };
+        Ok(Self {
+            field,
+            values,
+            nulls: array.nulls(),
+            ranges,
+        })
+    }
+
+    fn is_valid(&self, i: usize) -> bool {
+        self.nulls.map(|n| n.is_valid(i)).unwrap_or(true)
+    }
+
+    /// Returns the ranges of the valid lists, with null lists as empty ranges
+    ///
+    /// The ranges of null lists may not be within the bounds of the values, for
+    /// example those of a [`DataType::ListView`], and so must not be used
+    fn valid_ranges(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        self.ranges
+            .iter()
+            .enumerate()
+            .map(|(i, r)| match self.is_valid(i) {
+                true => r.clone(),
+                false => 0..0,
+            })
+    }
+}
+
+fn list_ranges<O: OffsetSizeTrait>(list: &GenericListArray<O>) -> Vec<Range<usize>> {
+    list.offsets()
+        .windows(2)
+        .map(|w| w[0].as_usize()..w[1].as_usize())
+        .collect()
+}
+
+fn list_view_ranges<O: OffsetSizeTrait>(
+    list: &GenericListViewArray<O>,
+) -> Vec<Range<usize>> {
+    let offsets = list.offsets().iter();
+    let sizes = list.sizes().iter();
+    offsets
+        .zip(sizes)
+        .map(|(o, s)| o.as_usize()..o.as_usize() + s.as_usize())
+        .collect()
+}
+
+/// Returns the elements of `values` in `ranges`, in order
+///
+/// This is zero-copy if the ranges are contiguous
+fn take_ranges(
+    values: &dyn Array,
+    ranges: impl Iterator<Item = Range<usize>> + Clone,
+) -> Result<ArrayRef, ArrowError> {
+    let mut contiguous = ranges.clone().filter(|r| !r.is_empty());
+    if let Some(first) = contiguous.next() {
+        let mut end = first.end;
+        if contiguous.all(|r| std::mem::replace(&mut end, r.end) == r.start) {
+            return Ok(values.slice(first.start, end - first.start));
+        }
+    } else {
+        return Ok(values.slice(0, 0));
+    }
+    let indices = UInt64Array::from_iter_values(ranges.flatten().map(|i| i as u64));
+    take(values, &indices, None)
+}
+
+/// Returns the offsets for lists of `lengths`, or an error on overflow
+fn offsets_from_lengths<O: OffsetSizeTrait>(
+    lengths: impl Iterator<Item = usize>,
+) -> Result<OffsetBuffer<O>, ArrowError> {
+    let mut offsets = Vec::with_capacity(lengths.size_hint().0 + 1);
+    let mut acc = 0_usize;
+    offsets.push(O::usize_as(0));
+    for len in lengths {
+        acc += len;
+        let offset = O::from_usize(acc)
+            .ok_or_else(|| ArrowError::ComputeError("offset overflow".to_string()))?;
+        offsets.push(offset);
+    }
+    Ok(OffsetBuffer::new(offsets.into()))
+}
+
+/// Resolve a possibly negative `index` relative to a list of length `len`
+fn resolve_index(index: i64, len: usize) -> i64 {
+    match index < 0 {
+        true => len as i64 + index,
+        false => index,
+    }
+}
+
+/// Returns the element at `index` of each list in `array`
+///
+/// Indices are zero-based, and negative indices count back from the end of each
+/// list, with `-1` the last element. The result is null where the list is null, or
+/// `index` is out of bounds for the list.
+///
+/// Returns an error if `array` is not a list array
+pub fn list_extract(array: &dyn Array, index: i64) -> Result<ArrayRef, ArrowError> {
+    let lists = ListRanges::try_new(array)?;
+    let indices: UInt64Array = lists
+        .ranges
+        .iter()
+        .enumerate()
+        .map(|(i, r)| {
+            let idx = resolve_index(index, r.len());
+            let valid = lists.is_valid(i) && (0..r.len() as i64).contains(&idx);
+            valid.then(|| (r.start as i64 + idx) as u64)
+        })
+        .collect();
+    take(lists.values.as_ref(), &indices, None)
+}
+
+/// Returns the elements of each list in `array` from `start` up to, but not including,
+/// `end`, or to the end of the list if `end` is `None`
+///
+/// Indices are zero-based, and negative indices count back from the end of each
+/// list. Indices beyond the bounds of a list are clamped to it, and so a list is
+/// empty if `start` is not before `end`. Null lists remain null.
+///
+/// The result has the same type as `array`. [`DataType::ListView`] and
+/// [`DataType::LargeListView`] results share the values of `array`, whilst other
+/// types are copied unless the selected elements are contiguous.
+///
+/// Returns an error if `array` is not a list array
+pub fn list_slice(
+    array: &dyn Array,
+    start: i64,
+    end: Option<i64>,
+) -> Result<ArrayRef, ArrowError> {
+    let lists = ListRanges::try_new(array)?;
+    let slice = |r: &Range<usize>| {
+        let len = r.len() as i64;
+        let s = resolve_index(start, r.len()).clamp(0, len) as usize;
+        let e = end.map(|e| resolve_index(e, r.len())).unwrap_or(len);
+        let e = (e.clamp(0, len) as usize).max(s);
+        r.start + s..r.start + e
+    };
+    let ranges: Vec<_> = lists
+        .ranges
+        .iter()
+        .enumerate()
+        .map(|(i, r)| match lists.is_valid(i) {
+            true => slice(r),
+            false => 0..0,
+        })
+        .collect();
+    let nulls = lists.nulls.cloned();
+    let field = lists.field.clone();
+
+    Ok(match array.data_type() {
+        DataType::List(_) => {
+            Arc::new(slice_list::<i32>(field, lists.values, &ranges, nulls)?)
+        }
+        DataType::LargeList(_) => {
+            Arc::new(slice_list::<i64>(field, lists.values, &ranges, nulls)?)
+        }
+        DataType::ListView(_) => {
+            Arc::new(slice_list_view::<i32>(field, lists.values, &ranges, nulls)?)
+        }
+        DataType::LargeListView(_) => {
+            Arc::new(slice_list_view::<i64>(field, lists.values, &ranges, nulls)?)
+        }
+        DataType::FixedSizeList(_, size) => {
+            // All lists have the same length, and so the same length once sliced
+            let size = slice(&(0..*size as usize)).len();
+            let ranges = lists.ranges.iter().map(slice);
+            let values = take_ranges(lists.values.as_ref(), ranges)?;
+            Arc::new(FixedSizeListArray::try_new(
+                field,
+                size as i32,
+                values,
+                nulls,
+            )?)
+        }
+        _ => unreachable!(),
+    })
+}
+
+fn slice_list<O: OffsetSizeTrait>(
+    field: FieldRef,
+    values: &dyn Array,
+    ranges: &[Range<usize>],
+    nulls: Option<NullBuffer>,
+) -> Result<GenericListArray<O>, ArrowError> {
+    let offsets = offsets_from_lengths(ranges.iter().map(|r| r.len()))?;
+    let values = take_ranges(values, ranges.iter().cloned())?;
+    GenericListArray::try_new(field, offsets, values, nulls)
+}
+
+fn slice_list_view<O: OffsetSizeTrait>(
+    field: FieldRef,
+    values: &ArrayRef,
+    ranges: &[Range<usize>],
+    nulls: Option<NullBuffer>,
+) -> Result<GenericListViewArray<O>, ArrowError> {
+    let offsets: ScalarBuffer<O> = ranges.iter().map(|r| O::usize_as(r.start)).collect();
+    let sizes: ScalarBuffer<O> = ranges.iter().map(|r| O::usize_as(r.len())).collect();
+    GenericListViewArray::try_new(field, offsets, sizes, values.clone(), nulls)
+}
+
+/// Flattens a list of lists into a list, concatenating the lists within each list
+///
+/// Null lists within a list are skipped, and a null list remains null. The result is
+/// a [`DataType::LargeList`] if `array` is a [`DataType::LargeList`] or
+/// [`DataType::LargeListView`], and a [`DataType::List`] otherwise.
+///
+/// ```
+/// # use arrow_array::ListArray;
+/// # use arrow_array::builder::{Int32Builder, ListBuilder};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::list::flatten;
+/// // [[[1, 2], null, [3]], [[]], null]
+/// let mut builder = ListBuilder::new(ListBuilder::new(Int32Builder::new()));
+/// builder.values().append_value([Some(1), Some(2)]);
+/// builder.values().append_null();
+/// builder.values().append_value([Some(3)]);
+/// builder.append(true);
+/// builder.values().append(true);
+/// builder.append(true);
+/// builder.append(false);
+/// let array = builder.finish();
+///
+/// let flattened = flatten(&array).unwrap();
+/// let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+///     Some(vec![Some(1), Some(2), Some(3)]),
+///     Some(vec![]),
+///     None,
+/// ]);
+/// assert_eq!(flattened.as_list::<i32>(), &expected);
+/// ```
+///
+/// Returns an error if `array` is not a list array of list arrays
+pub fn flatten(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    let outer = ListRanges::try_new(array)?;
+    let inner = ListRanges::try_new(outer.values.as_ref())?;
+
+    let inner_ranges: Vec<_> = inner.valid_ranges().collect();
+    let lengths = outer
+        .valid_ranges()
+        .map(|r| inner_ranges[r].iter().map(|r| r.len()).sum::<usize>());
+    let element_ranges: Vec<_> = outer
+        .valid_ranges()
+        .flat_map(|r| inner_ranges[r].iter().cloned())
+        .collect();
+    let values = take_ranges(inner.values.as_ref(), element_ranges.into_iter())?;
+    let field = inner.field.clone();
+    let nulls = outer.nulls.cloned();
+
+    Ok(match array.data_type() {
+        DataType::LargeList(_) | DataType::LargeListView(_) => {
+            let offsets = offsets_from_lengths::<i64>(lengths)?;
+            Arc::new(GenericListArray::try_new(field, offsets, values, nulls)?)
+        }
+        _ => {
+            let offsets = offsets_from_lengths::<i32>(lengths)?;
+            Arc::new(GenericListArray::try_new(field, offsets, values, nulls)?)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{FixedSizeListBuilder, Int32Builder, ListBuilder};
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Int32Array, LargeListArray, ListArray, ListViewArray};
+    use arrow_schema::Field;
+
+    fn list() -> ListArray {
+        ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2), Some(3)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4), None]),
+        ])
+    }
+
+    fn list_view() -> ListViewArray {
+        // [[3, 4], [1], null, [1, 2, 3]] with overlapping, out of order views
+        let values = Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as ArrayRef;
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        let offsets = ScalarBuffer::from(vec![2, 0, 0, 0]);
+        let sizes = ScalarBuffer::from(vec![2, 1, 0, 3]);
+        let nulls = NullBuffer::from(vec![true, true, false, true]);
+        ListViewArray::new(field, offsets, sizes, values, Some(nulls))
+    }
+
+    fn fixed_size_list() -> FixedSizeListArray {
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(), 3);
+        builder.values().append_slice(&[1, 2, 3]);
+        builder.append(true);
+        builder.values().append_slice(&[0, 0, 0]);
+        builder.append(false);
+        builder.values().append_slice(&[4, 5, 6]);
+        builder.append(true);
+        builder.finish()
+    }
+
+    fn ints(array: &ArrayRef) -> Vec<Option<i32>> {
+        array.as_primitive::<Int32Type>().iter().collect()
+    }
+
+    #[test]
+    fn test_list_extract() {
+        let list = list();
+        assert_eq!(
+            ints(&list_extract(&list, 0).unwrap()),
+            vec![Some(1), None, None, Some(4)]
+        );
+        assert_eq!(
+            ints(&list_extract(&list, -2).unwrap()),
+            vec![Some(2), None, None, Some(4)]
+        );
+        assert_eq!(
+            ints(&list_extract(&list, 1).unwrap()),
+            vec![Some(2), None, None, None]
+        );
+        assert_eq!(ints(&list_extract(&list, 3).unwrap()), vec![None; 4]);
+        assert_eq!(ints(&list_extract(&list, -4).unwrap()), vec![None; 4]);
+
+        let sliced = list.slice(1, 3);
+        assert_eq!(
+            ints(&list_extract(&sliced, -1).unwrap()),
+            vec![None, None, None]
+        );
+
+        let large =
+            LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![
+                Some(7),
+            ])]);
+        assert_eq!(ints(&list_extract(&large, 0).unwrap()), vec![Some(7)]);
+
+        let view = list_view();
+        assert_eq!(
+            ints(&list_extract(&view, -1).unwrap()),
+            vec![Some(4), Some(1), None, Some(3)]
+        );
+
+        let fixed = fixed_size_list();
+        assert_eq!(
+            ints(&list_extract(&fixed, 1).unwrap()),
+            vec![Some(2), None, Some(5)]
+        );
+
+        let err = list_extract(&Int32Array::from(vec![1]), 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected a list array, got Int32"
+        );
+    }
+
+    #[test]
+    fn test_list_slice() {
+        let list = list();
+        let sliced = list_slice(&list, 1, Some(-1)).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(2)]),
+            None,
+            Some(vec![]),
+            Some(vec![]),
+        ]);
+        assert_eq!(sliced.as_list::<i32>(), &expected);
+
+        // Out of bounds indices are clamped
+        let sliced = list_slice(&list, -10, Some(10)).unwrap();
+        assert_eq!(sliced.as_list::<i32>(), &list);
+        let sliced = list_slice(&list, 2, Some(1)).unwrap();
+        assert_eq!(sliced.as_list::<i32>().values().len(), 0);
+
+        let view = list_view();
+        let sliced = list_slice(&view, 1, None).unwrap();
+        let sliced = sliced.as_list_view::<i32>();
+        assert_eq!(sliced.values(), view.values());
+        assert_eq!(sliced.sizes(), &[1, 0, 0, 2]);
+        assert_eq!(sliced.offsets(), &[3, 1, 0, 1]);
+        assert!(sliced.is_null(2));
+
+        let fixed = fixed_size_list();
+        let sliced = list_slice(&fixed, -2, None).unwrap();
+        let sliced = sliced.as_fixed_size_list();
+        assert_eq!(sliced.value_length(), 2);
+        assert_eq!(
+            ints(sliced.values()),
+            vec![Some(2), Some(3), Some(0), Some(0), Some(5), Some(6)]
+        );
+        assert!(sliced.is_null(1));
+    }
+
+    #[test]
+    fn test_list_slice_contiguous() {
+        let list = list();
+        let sliced = list_slice(&list, 0, None).unwrap();
+        let sliced = sliced.as_list::<i32>();
+        let ptr = |a: &ArrayRef| a.to_data().buffers()[0].as_ptr();
+        assert_eq!(ptr(sliced.values()), ptr(list.values()));
+    }
+
+    #[test]
+    fn test_flatten() {
+        // [[[1, 2], null], null, [[], [3]], []]
+        let mut builder = ListBuilder::new(ListBuilder::new(Int32Builder::new()));
+        builder.values().append_value([Some(1), Some(2)]);
+        builder.values().append_null();
+        builder.append(true);
+        builder.append(false);
+        builder.values().append(true);
+        builder.values().append_value([Some(3)]);
+        builder.append(true);
+        builder.append(true);
+        let array = builder.finish();
+
+        let flattened = flatten(&array).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3)]),
+            Some(vec![]),
+        ]);
+        assert_eq!(flattened.as_list::<i32>(), &expected);
+
+        let sliced = flatten(&array.slice(2, 2)).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(3)]),
+            Some(vec![]),
+        ]);
+        assert_eq!(sliced.as_list::<i32>(), &expected);
+
+        let err = flatten(&list()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected a list array, got Int32"
+        );
+    }
+
+    #[test]
+    fn test_flatten_large() {
+        let inner = Arc::new(list()) as ArrayRef;
+        let field = Arc::new(Field::new("item", inner.data_type().clone(), true));
+        let offsets = OffsetBuffer::<i64>::from_lengths([2, 2]);
+        let outer = LargeListArray::new(field, offsets, inner, None);
+
+        let flattened = flatten(&outer).unwrap();
+        let flattened = flattened.as_list::<i64>();
+        assert_eq!(flattened.value_offsets(), &[0, 3, 5]);
+        assert_eq!(
+            ints(flattened.values()),
+            vec![Some(1), Some(2), Some(3), Some(4), None]
+        );
+    }
+}
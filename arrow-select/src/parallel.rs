@@ -0,0 +1,224 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Parallel selection kernels, using the [rayon] thread pool
+//!
+//! Inputs with at least [`ParallelOptions::threshold`] rows are split into one chunk
+//! per thread of the current rayon thread pool, each chunk is processed by the
+//! single-threaded kernel, and the results are concatenated. Smaller inputs are
+//! processed by the single-threaded kernel directly.
+
+use crate::concat::concat;
+use crate::filter::filter;
+use crate::take::{take, TakeOptions};
+use arrow_array::{Array, ArrayRef, ArrowPrimitiveType, BooleanArray, PrimitiveArray};
+use arrow_schema::ArrowError;
+use rayon::prelude::*;
+use std::ops::Range;
+
+/// The default value of [`ParallelOptions::threshold`]
+pub const DEFAULT_PARALLEL_THRESHOLD: usize = 64 * 1024;
+
+/// Options that define when kernels are run in parallel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParallelOptions {
+    /// The minimum number of rows for which a kernel is run in parallel
+    pub threshold: usize,
+}
+
+impl Default for ParallelOptions {
+    fn default() -> Self {
+        Self {
+            threshold: DEFAULT_PARALLEL_THRESHOLD,
+        }
+    }
+}
+
+impl ParallelOptions {
+    /// Create new [`ParallelOptions`] with [`DEFAULT_PARALLEL_THRESHOLD`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the minimum number of rows for which a kernel is run in parallel
+    pub fn with_threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Returns the ranges into which `len` rows should be split to be processed in
+    /// parallel, or `None` if they should be processed by a single thread
+    ///
+    /// Must be called from the thread pool that will process the ranges
+    pub fn chunks(&self, len: usize) -> Option<Vec<Range<usize>>> {
+        let threads = rayon::current_num_threads();
+        if len < self.threshold || len < 2 || threads < 2 {
+            return None;
+        }
+        let chunk_len = (len + threads - 1) / threads;
+        let chunks = (0..len)
+            .step_by(chunk_len)
+            .map(|start| start..len.min(start + chunk_len))
+            .collect();
+        Some(chunks)
+    }
+}
+
+/// Concatenates the results of processing each chunk
+fn concat_chunks(chunks: Vec<ArrayRef>) -> Result<ArrayRef, ArrowError> {
+    let chunks: Vec<_> = chunks.iter().map(|a| a.as_ref()).collect();
+    concat(&chunks)
+}
+
+/// Parallel version of [`filter`]
+///
+/// Returns the same result as [`filter`], filtering chunks of `values` in parallel
+/// if it has at least [`ParallelOptions::threshold`] rows
+///
+/// ```
+/// # use arrow_array::{BooleanArray, Int32Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::parallel::{par_filter, ParallelOptions};
+/// let array = Int32Array::from_iter_values(0..100_000);
+/// let predicate = BooleanArray::from_iter((0..100_000).map(|i| Some(i % 3 == 0)));
+/// let options = ParallelOptions::new().with_threshold(10_000);
+/// let c = par_filter(&array, &predicate, &options).unwrap();
+/// let c = c.as_primitive::<Int32Type>();
+/// assert_eq!(c.len(), 33_334);
+/// assert_eq!(c.value(1), 3);
+/// ```
+pub fn par_filter(
+    values: &dyn Array,
+    predicate: &BooleanArray,
+    options: &ParallelOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let chunks = match options.chunks(values.len()) {
+        Some(chunks) if predicate.len() == values.len() => chunks,
+        _ => return filter(values, predicate),
+    };
+
+    let filtered = chunks
+        .into_par_iter()
+        .map(|r| {
+            let predicate = predicate.slice(r.start, r.len());
+            filter(&values.slice(r.start, r.len()), &predicate)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    concat_chunks(filtered)
+}
+
+/// Parallel version of [`take`]
+///
+/// Returns the same result as [`take`], taking chunks of `indices` in parallel
+/// if it has at least [`ParallelOptions::threshold`] rows
+pub fn par_take<IndexType: ArrowPrimitiveType>(
+    values: &dyn Array,
+    indices: &PrimitiveArray<IndexType>,
+    take_options: Option<TakeOptions>,
+    options: &ParallelOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let chunks = match options.chunks(indices.len()) {
+        Some(chunks) => chunks,
+        None => return take(values, indices, take_options),
+    };
+
+    let taken = chunks
+        .into_par_iter()
+        .map(|r| {
+            let indices = indices.slice(r.start, r.len());
+            take(values, &indices, take_options.clone())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    concat_chunks(taken)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Int32Array, StringArray, UInt32Array};
+    use rand::prelude::*;
+    use rayon::ThreadPoolBuilder;
+
+    fn pool() -> rayon::ThreadPool {
+        ThreadPoolBuilder::new().num_threads(4).build().unwrap()
+    }
+
+    #[test]
+    fn test_chunks() {
+        let options = ParallelOptions::new().with_threshold(10);
+        pool().install(|| {
+            assert_eq!(options.chunks(9), None);
+            assert_eq!(options.chunks(10).unwrap(), vec![0..3, 3..6, 6..9, 9..10]);
+            assert_eq!(options.chunks(16).unwrap(), vec![0..4, 4..8, 8..12, 12..16]);
+        });
+        let single = ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+        single.install(|| assert_eq!(options.chunks(100), None));
+    }
+
+    #[test]
+    fn test_par_filter() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let values: StringArray = (0..1000)
+            .map(|i| rng.gen_bool(0.9).then(|| i.to_string()))
+            .collect();
+        let predicate: BooleanArray = (0..1000)
+            .map(|_| rng.gen_bool(0.9).then(|| rng.gen()))
+            .collect();
+
+        let options = ParallelOptions::new().with_threshold(100);
+        let expected = filter(&values, &predicate).unwrap();
+        let actual = pool().install(|| par_filter(&values, &predicate, &options));
+        assert_eq!(actual.unwrap().as_ref(), expected.as_ref());
+
+        // Predicates of a different length are handled as by filter
+        let values = values.slice(0, 999);
+        let err = pool()
+            .install(|| par_filter(&values, &predicate, &options))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Filter predicate of length 1000 is larger than target array of length 999"
+        );
+    }
+
+    #[test]
+    fn test_par_take() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let values: Int32Array = (0..1000)
+            .map(|_| rng.gen_bool(0.9).then(|| rng.gen()))
+            .collect();
+        let indices: UInt32Array = (0..5000)
+            .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..1000)))
+            .collect();
+
+        let options = ParallelOptions::new().with_threshold(100);
+        let expected = take(&values, &indices, None).unwrap();
+        let actual = pool().install(|| par_take(&values, &indices, None, &options));
+        assert_eq!(actual.unwrap().as_ref(), expected.as_ref());
+
+        let indices = UInt32Array::from_iter_values((0..1000).rev().chain([1000]));
+        let take_options = Some(TakeOptions { check_bounds: true });
+        let err = pool()
+            .install(|| par_take(&values, &indices, take_options, &options))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Array index out of bounds, cannot get item at index 1000 from 1000 entries"
+        );
+    }
+}
@@ -0,0 +1,344 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines [`BatchSplitter`] and [`SplitRecordBatchReader`] for limiting the size of
+//! [`RecordBatch`]es
+//!
+//! Example:
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, Int32Array, RecordBatch, RecordBatchIterator};
+//! # use arrow_select::split::{BatchSplitter, SplitRecordBatchReader};
+//! let a = Arc::new(Int32Array::from_iter_values(0..10)) as ArrayRef;
+//! let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+//! let schema = batch.schema();
+//!
+//! let reader = RecordBatchIterator::new([Ok(batch)], schema);
+//! let splitter = BatchSplitter::new().with_max_rows(4);
+//! let reader = SplitRecordBatchReader::new(reader, splitter);
+//!
+//! let rows: Vec<_> = reader.map(|b| b.unwrap().num_rows()).collect();
+//! assert_eq!(rows, vec![4, 4, 2]);
+//! ```
+
+use arrow_array::{RecordBatch, RecordBatchReader};
+use arrow_schema::{ArrowError, SchemaRef};
+use std::collections::VecDeque;
+
+/// Splits [`RecordBatch`]es into batches of at most `max_rows` rows and `max_bytes`
+/// bytes
+///
+/// Batches are split by slicing, and so the batches produced share buffers with the
+/// input without copying. The size of a batch is that returned by
+/// [`RecordBatch::get_slice_memory_size`], which only accounts for the rows within
+/// the slice.
+///
+/// A batch that already satisfies both limits is returned as is. As a single row
+/// cannot be split, a row larger than `max_bytes` is returned in a batch on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchSplitter {
+    max_rows: usize,
+    max_bytes: usize,
+}
+
+impl Default for BatchSplitter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BatchSplitter {
+    /// Create a new [`BatchSplitter`] with no limits
+    pub fn new() -> Self {
+        Self {
+            max_rows: usize::MAX,
+            max_bytes: usize::MAX,
+        }
+    }
+
+    /// Limit the number of rows in each batch to `max_rows`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_rows` is 0
+    pub fn with_max_rows(mut self, max_rows: usize) -> Self {
+        assert!(max_rows > 0, "max_rows must be non-zero");
+        self.max_rows = max_rows;
+        self
+    }
+
+    /// Limit the size of each batch to `max_bytes`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_bytes` is 0
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        assert!(max_bytes > 0, "max_bytes must be non-zero");
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Returns the maximum number of rows in each batch
+    pub fn max_rows(&self) -> usize {
+        self.max_rows
+    }
+
+    /// Returns the maximum size in bytes of each batch
+    pub fn max_bytes(&self) -> usize {
+        self.max_bytes
+    }
+
+    /// Split `batch` into slices satisfying the limits of this [`BatchSplitter`]
+    ///
+    /// Returns an error if the size of `batch` cannot be determined, see
+    /// [`RecordBatch::get_slice_memory_size`]
+    pub fn split(&self, batch: &RecordBatch) -> Result<Vec<RecordBatch>, ArrowError> {
+        let num_rows = batch.num_rows();
+        if num_rows <= self.max_rows && self.fits(batch)? {
+            return Ok(vec![batch.clone()]);
+        }
+
+        let mut batches = vec![];
+        let mut offset = 0;
+        while offset < num_rows {
+            let len = self.next_len(batch, offset)?;
+            batches.push(batch.slice(offset, len));
+            offset += len;
+        }
+        Ok(batches)
+    }
+
+    fn fits(&self, batch: &RecordBatch) -> Result<bool, ArrowError> {
+        if self.max_bytes == usize::MAX {
+            return Ok(true);
+        }
+        Ok(batch.get_slice_memory_size()? <= self.max_bytes)
+    }
+
+    /// Returns the number of rows of the next slice of `batch` starting at `offset`
+    fn next_len(&self, batch: &RecordBatch, offset: usize) -> Result<usize, ArrowError> {
+        let mut len = (batch.num_rows() - offset).min(self.max_rows);
+        if self.max_bytes == usize::MAX {
+            return Ok(len);
+        }
+
+        loop {
+            let size = batch.slice(offset, len).get_slice_memory_size()?;
+            if size <= self.max_bytes || len == 1 {
+                return Ok(len);
+            }
+            // Shrink in proportion to the excess, always making progress
+            let scaled = (len as u128 * self.max_bytes as u128 / size as u128) as usize;
+            len = scaled.clamp(1, len - 1);
+        }
+    }
+}
+
+/// A [`RecordBatchReader`] that splits the batches of another [`RecordBatchReader`]
+/// using a [`BatchSplitter`]
+///
+/// This is useful when writing to a destination with a limit on the size of each
+/// message, such as Arrow Flight or an HTTP API.
+#[derive(Debug)]
+pub struct SplitRecordBatchReader<R> {
+    reader: R,
+    splitter: BatchSplitter,
+    pending: VecDeque<RecordBatch>,
+}
+
+impl<R: RecordBatchReader> SplitRecordBatchReader<R> {
+    /// Create a new [`SplitRecordBatchReader`] splitting the batches of `reader`
+    pub fn new(reader: R, splitter: BatchSplitter) -> Self {
+        Self {
+            reader,
+            splitter,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Returns the [`BatchSplitter`] used by this reader
+    pub fn splitter(&self) -> &BatchSplitter {
+        &self.splitter
+    }
+
+    /// Consumes this reader, returning the underlying reader
+    ///
+    /// Any split batches not yet returned are discarded
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: RecordBatchReader> Iterator for SplitRecordBatchReader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(batch) = self.pending.pop_front() {
+                return Some(Ok(batch));
+            }
+            let batch = match self.reader.next()? {
+                Ok(batch) => batch,
+                Err(e) => return Some(Err(e)),
+            };
+            match self.splitter.split(&batch) {
+                Ok(batches) => self.pending.extend(batches),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+impl<R: RecordBatchReader> RecordBatchReader for SplitRecordBatchReader<R> {
+    fn schema(&self) -> SchemaRef {
+        self.reader.schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{ArrayRef, Int32Array, RecordBatchIterator, StringArray};
+    use std::sync::Arc;
+
+    fn int_batch(range: std::ops::Range<i32>) -> RecordBatch {
+        let a = Arc::new(Int32Array::from_iter_values(range)) as ArrayRef;
+        RecordBatch::try_from_iter([("a", a)]).unwrap()
+    }
+
+    fn values(batches: &[RecordBatch]) -> Vec<Vec<i32>> {
+        batches
+            .iter()
+            .map(|b| b.column(0).as_primitive::<Int32Type>().values().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn test_split_max_rows() {
+        let batch = int_batch(0..7);
+        let splitter = BatchSplitter::new().with_max_rows(3);
+        let split = splitter.split(&batch).unwrap();
+        assert_eq!(values(&split), vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+
+        // Slices share the buffers of the input
+        let ptr = batch.column(0).to_data().buffers()[0].as_ptr();
+        let split_ptr = split[0].column(0).to_data().buffers()[0].as_ptr();
+        assert_eq!(split_ptr, ptr);
+    }
+
+    #[test]
+    fn test_split_passthrough() {
+        let batch = int_batch(0..4);
+        let splitter = BatchSplitter::new().with_max_rows(4).with_max_bytes(16);
+        assert_eq!(splitter.split(&batch).unwrap(), vec![batch]);
+
+        let empty = int_batch(0..0);
+        let splitter = BatchSplitter::new().with_max_rows(1);
+        assert_eq!(splitter.split(&empty).unwrap(), vec![empty]);
+    }
+
+    #[test]
+    fn test_split_max_bytes() {
+        let batch = int_batch(0..10);
+        let splitter = BatchSplitter::new().with_max_bytes(12);
+        let split = splitter.split(&batch).unwrap();
+        assert_eq!(split.iter().map(|b| b.num_rows()).sum::<usize>(), 10);
+        for b in &split {
+            assert!(b.get_slice_memory_size().unwrap() <= 12);
+        }
+        assert_eq!(split[0].num_rows(), 3);
+
+        let splitter = splitter.with_max_rows(2);
+        let split = splitter.split(&batch).unwrap();
+        assert!(split.iter().all(|b| b.num_rows() == 2));
+    }
+
+    #[test]
+    fn test_split_max_bytes_skewed() {
+        let large = "x".repeat(100);
+        let strings = StringArray::from(vec!["a", "b", large.as_str(), "c", "d", "e"]);
+        let batch =
+            RecordBatch::try_from_iter([("s", Arc::new(strings) as ArrayRef)]).unwrap();
+
+        let splitter = BatchSplitter::new().with_max_bytes(40);
+        let split = splitter.split(&batch).unwrap();
+
+        let strings: Vec<Vec<&str>> = split
+            .iter()
+            .map(|b| b.column(0).as_string::<i32>().iter().flatten().collect())
+            .collect();
+        assert_eq!(strings.concat(), vec!["a", "b", &large, "c", "d", "e"]);
+        for (b, s) in split.iter().zip(&strings) {
+            // Only the oversized row may exceed the limit, alone in its batch
+            if s.contains(&large.as_str()) {
+                assert_eq!(b.num_rows(), 1);
+            } else {
+                assert!(b.get_slice_memory_size().unwrap() <= 40);
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_reader() {
+        let batches = [int_batch(0..5), int_batch(5..6), int_batch(6..10)];
+        let schema = batches[0].schema();
+        let reader =
+            RecordBatchIterator::new(batches.into_iter().map(Ok), schema.clone());
+        let splitter = BatchSplitter::new().with_max_rows(2);
+        let reader = SplitRecordBatchReader::new(reader, splitter);
+        assert_eq!(reader.schema(), schema);
+
+        let split = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(
+            values(&split),
+            vec![
+                vec![0, 1],
+                vec![2, 3],
+                vec![4],
+                vec![5],
+                vec![6, 7],
+                vec![8, 9]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_reader_error() {
+        let schema = int_batch(0..0).schema();
+        let batches = vec![
+            Ok(int_batch(0..3)),
+            Err(ArrowError::ComputeError("failed".to_string())),
+        ];
+        let reader = RecordBatchIterator::new(batches, schema);
+        let mut reader =
+            SplitRecordBatchReader::new(reader, BatchSplitter::new().with_max_rows(2));
+
+        assert_eq!(reader.next().unwrap().unwrap().num_rows(), 2);
+        assert_eq!(reader.next().unwrap().unwrap().num_rows(), 1);
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "max_rows must be non-zero")]
+    fn test_split_zero_rows() {
+        BatchSplitter::new().with_max_rows(0);
+    }
+}
@@ -0,0 +1,299 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels for extracting the fields of, and constructing, [`StructArray`]s
+//!
+//! The children of a [`StructArray`] may contain arbitrary values where the struct
+//! itself is null. These kernels therefore propagate the nulls of a struct to its
+//! children, so that a child is null wherever any of its parents is null.
+
+use arrow_array::cast::AsArray;
+use arrow_array::{make_array, Array, ArrayRef, StructArray};
+use arrow_buffer::NullBuffer;
+use arrow_data::ArrayDataBuilder;
+use arrow_schema::{ArrowError, DataType, Field, Fields};
+use std::sync::Arc;
+
+/// Returns `array` with its nulls combined with `nulls`, so that the result is null
+/// where either `array` or `nulls` is null
+///
+/// Returns an error if `array` has no validity buffer, such as a [`DataType::Union`]
+fn propagate_nulls(
+    array: &ArrayRef,
+    nulls: Option<&NullBuffer>,
+) -> Result<ArrayRef, ArrowError> {
+    let nulls = match nulls.filter(|n| n.null_count() > 0) {
+        Some(nulls) => nulls,
+        None => return Ok(array.clone()),
+    };
+    match array.data_type() {
+        DataType::Null => return Ok(array.clone()),
+        DataType::Union(_, _) | DataType::RunEndEncoded(_, _) => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot propagate nulls to an array of type {}",
+                array.data_type()
+            )))
+        }
+        _ => {}
+    }
+
+    let combined = NullBuffer::union(array.nulls(), Some(nulls));
+    let data = array.to_data().into_builder().nulls(combined);
+    // SAFETY:
+    // Only added nulls to an array with a validity buffer
+    Ok(make_array(unsafe { data.build_unchecked() }))
+}
+
+/// Returns the field of `array` at the path of field names `path`, separated by `.`
+///
+/// For example `"a.b"` returns the field `b` of the struct field `a` of `array`. The
+/// result is null where the field is null or where any of its parent structs is null.
+///
+/// See [`struct_extract_path`] for field names containing `.`
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, StructArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_buffer::NullBuffer;
+/// # use arrow_select::structs::{make_struct, struct_extract};
+/// let b = Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef;
+/// let inner = make_struct(vec![("b", b)], None).unwrap();
+/// let nulls = NullBuffer::from(vec![true, false, true]);
+/// let outer = make_struct(vec![("a", Arc::new(inner) as ArrayRef)], Some(nulls)).unwrap();
+///
+/// let result = struct_extract(&outer, "a.b").unwrap();
+/// let expected = Int32Array::from(vec![Some(1), None, Some(3)]);
+/// assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+/// ```
+pub fn struct_extract(array: &dyn Array, path: &str) -> Result<ArrayRef, ArrowError> {
+    let path: Vec<_> = path.split('.').collect();
+    struct_extract_path(array, &path)
+}
+
+/// Returns the field of `array` at the path of field names `path`
+///
+/// The result is null where the field is null or where any of its parent structs is
+/// null. Returns an error if `path` is empty, or does not refer to a field.
+pub fn struct_extract_path(
+    array: &dyn Array,
+    path: &[&str],
+) -> Result<ArrayRef, ArrowError> {
+    let (first, rest) = path.split_first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError("Cannot extract an empty path".to_string())
+    })?;
+
+    let mut nulls = array.nulls().cloned();
+    let mut current = child_by_name(array, first)?;
+    for name in rest {
+        nulls = NullBuffer::union(nulls.as_ref(), current.nulls());
+        current = child_by_name(current.as_ref(), name)?;
+    }
+    propagate_nulls(&current, nulls.as_ref())
+}
+
+fn child_by_name(array: &dyn Array, name: &str) -> Result<ArrayRef, ArrowError> {
+    let s = array.as_struct_opt().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "Cannot extract field {name:?} from non-struct type {}",
+            array.data_type()
+        ))
+    })?;
+    s.column_by_name(name).cloned().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "Field {name:?} not found in {}",
+            array.data_type()
+        ))
+    })
+}
+
+/// Creates a [`StructArray`] from named `columns`, with optional struct-level `nulls`
+///
+/// The nulls of the struct are propagated to the columns, so that each column is null
+/// wherever the struct is null. As with [`StructArray::try_from`], a field is nullable
+/// if its column has a validity buffer.
+///
+/// Returns an error if the columns and `nulls` do not all have the same length
+pub fn make_struct(
+    columns: Vec<(&str, ArrayRef)>,
+    nulls: Option<NullBuffer>,
+) -> Result<StructArray, ArrowError> {
+    let len = match (&nulls, columns.first()) {
+        (Some(n), _) => n.len(),
+        (None, Some((_, c))) => c.len(),
+        (None, None) => 0,
+    };
+    if let Some((name, c)) = columns.iter().find(|(_, c)| c.len() != len) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Incorrect array length for struct field {name:?}, expected {len} got {}",
+            c.len()
+        )));
+    }
+    if columns.is_empty() {
+        // A struct with no fields takes its length from `nulls`
+        let data = ArrayDataBuilder::new(DataType::Struct(Fields::empty()))
+            .len(len)
+            .nulls(nulls)
+            .build()?;
+        return Ok(StructArray::from(data));
+    }
+
+    let (fields, arrays) = columns
+        .into_iter()
+        .map(|(name, column)| {
+            let column = propagate_nulls(&column, nulls.as_ref())?;
+            let field =
+                Field::new(name, column.data_type().clone(), column.nulls().is_some());
+            Ok((Arc::new(field), column))
+        })
+        .collect::<Result<(Vec<_>, Vec<_>), ArrowError>>()?;
+    StructArray::try_new(fields.into(), arrays, nulls)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Int32Array, NullArray, StringArray};
+
+    /// `{a: {b: int, c: string}, d: int}` with nulls at every level
+    fn nested() -> StructArray {
+        let b =
+            Arc::new(Int32Array::from(vec![Some(1), Some(2), None, Some(4)])) as ArrayRef;
+        let c = Arc::new(StringArray::from(vec!["w", "x", "y", "z"])) as ArrayRef;
+        let inner = StructArray::new(
+            vec![
+                Field::new("b", DataType::Int32, true),
+                Field::new("c", DataType::Utf8, false),
+            ]
+            .into(),
+            vec![b, c],
+            Some(NullBuffer::from(vec![true, false, true, true])),
+        );
+        let d = Arc::new(Int32Array::from(vec![5, 6, 7, 8])) as ArrayRef;
+        let inner = Arc::new(inner) as ArrayRef;
+        StructArray::new(
+            vec![
+                Field::new("a", inner.data_type().clone(), true),
+                Field::new("d", DataType::Int32, false),
+            ]
+            .into(),
+            vec![inner, d],
+            Some(NullBuffer::from(vec![true, true, true, false])),
+        )
+    }
+
+    #[test]
+    fn test_struct_extract() {
+        let array = nested();
+
+        let b = struct_extract(&array, "a.b").unwrap();
+        let expected = Int32Array::from(vec![Some(1), None, None, None]);
+        assert_eq!(b.as_primitive::<Int32Type>(), &expected);
+
+        let c = struct_extract(&array, "a.c").unwrap();
+        let expected = StringArray::from(vec![Some("w"), None, Some("y"), None]);
+        assert_eq!(c.as_string::<i32>(), &expected);
+
+        let d = struct_extract(&array, "d").unwrap();
+        let expected = Int32Array::from(vec![Some(5), Some(6), Some(7), None]);
+        assert_eq!(d.as_primitive::<Int32Type>(), &expected);
+
+        let a = struct_extract(&array, "a").unwrap();
+        assert_eq!(a.null_count(), 2);
+        // The extracted struct's own children are unchanged
+        assert_eq!(a.as_struct().column(0).null_count(), 1);
+
+        let sliced = struct_extract(&array.slice(1, 3), "a.b").unwrap();
+        let expected = Int32Array::from(vec![None, None, None]);
+        assert_eq!(sliced.as_primitive::<Int32Type>(), &expected);
+    }
+
+    #[test]
+    fn test_struct_extract_path() {
+        let x = Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef;
+        let array = make_struct(vec![("x.y", x)], None).unwrap();
+        let result = struct_extract_path(&array, &["x.y"]).unwrap();
+        assert_eq!(result.as_primitive::<Int32Type>().values(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_struct_extract_errors() {
+        let array = nested();
+        let err = struct_extract(&array, "a.z").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Field \"z\" not found in Struct([Field { name: \"b\", data_type: Int32, nullable: true, dict_id: 0, dict_is_ordered: false, metadata: {} }, Field { name: \"c\", data_type: Utf8, nullable: false, dict_id: 0, dict_is_ordered: false, metadata: {} }])"
+        );
+
+        let err = struct_extract(&array, "d.e").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot extract field \"e\" from non-struct type Int32"
+        );
+
+        let err = struct_extract_path(&array, &[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot extract an empty path"
+        );
+    }
+
+    #[test]
+    fn test_make_struct() {
+        let a = Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef;
+        let b = Arc::new(StringArray::from(vec![Some("x"), None, Some("z")])) as ArrayRef;
+        let n = Arc::new(NullArray::new(3)) as ArrayRef;
+
+        let array = make_struct(vec![("a", a.clone()), ("b", b.clone())], None).unwrap();
+        assert!(!array.fields()[0].is_nullable());
+        assert!(array.fields()[1].is_nullable());
+        assert_eq!(array.column(0), &a);
+
+        let nulls = NullBuffer::from(vec![true, true, false]);
+        let array = make_struct(vec![("a", a), ("b", b), ("n", n)], Some(nulls)).unwrap();
+        assert_eq!(array.null_count(), 1);
+        assert!(array.fields()[0].is_nullable());
+        let expected = Int32Array::from(vec![Some(1), Some(2), None]);
+        assert_eq!(array.column(0).as_primitive::<Int32Type>(), &expected);
+        let expected = StringArray::from(vec![Some("x"), None, None]);
+        assert_eq!(array.column(1).as_string::<i32>(), &expected);
+
+        let empty = make_struct(vec![], Some(NullBuffer::new_null(2))).unwrap();
+        assert_eq!(empty.len(), 2);
+        assert_eq!(empty.null_count(), 2);
+    }
+
+    #[test]
+    fn test_make_struct_length_mismatch() {
+        let a = Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef;
+        let b = Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef;
+        let err = make_struct(vec![("a", a.clone()), ("b", b)], None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Incorrect array length for struct field \"b\", expected 3 got 2"
+        );
+
+        let nulls = NullBuffer::new_valid(2);
+        let err = make_struct(vec![("a", a)], Some(nulls)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Incorrect array length for struct field \"a\", expected 2 got 3"
+        );
+    }
+}
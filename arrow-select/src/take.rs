@@ -19,16 +19,17 @@
 
 use std::sync::Arc;
 
-use arrow_array::builder::BufferBuilder;
+use arrow_array::builder::{BooleanBufferBuilder, BufferBuilder};
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::{
     bit_util, ArrowNativeType, BooleanBuffer, Buffer, MutableBuffer, NullBuffer,
-    ScalarBuffer,
+    OffsetBuffer, ScalarBuffer,
 };
+use arrow_data::transform::MutableArrayData;
 use arrow_data::{ArrayData, ArrayDataBuilder};
-use arrow_schema::{ArrowError, DataType, FieldRef};
+use arrow_schema::{ArrowError, DataType, Field, UnionMode};
 
 use num::{One, Zero};
 
@@ -88,32 +89,7 @@ fn take_impl<IndexType: ArrowPrimitiveType>(
 ) -> Result<ArrayRef, ArrowError> {
     let options = options.unwrap_or_default();
     if options.check_bounds {
-        let len = values.len();
-        if indices.null_count() > 0 {
-            indices.iter().flatten().try_for_each(|index| {
-                let ix = index.to_usize().ok_or_else(|| {
-                    ArrowError::ComputeError("Cast to usize failed".to_string())
-                })?;
-                if ix >= len {
-                    return Err(ArrowError::ComputeError(
-                        format!("Array index out of bounds, cannot get item at index {ix} from {len} entries"))
-                    );
-                }
-                Ok(())
-            })?;
-        } else {
-            indices.values().iter().try_for_each(|index| {
-                let ix = index.to_usize().ok_or_else(|| {
-                    ArrowError::ComputeError("Cast to usize failed".to_string())
-                })?;
-                if ix >= len {
-                    return Err(ArrowError::ComputeError(
-                        format!("Array index out of bounds, cannot get item at index {ix} from {len} entries"))
-                    );
-                }
-                Ok(())
-            })?
-        }
+        validate_indices(indices, values.len())?;
     }
 
     downcast_primitive_array! {
@@ -134,6 +110,12 @@ fn take_impl<IndexType: ArrowPrimitiveType>(
         DataType::LargeList(_) => {
             Ok(Arc::new(take_list::<_, Int64Type>(values.as_list(), indices)?))
         }
+        DataType::ListView(_) => {
+            Ok(Arc::new(take_list_view::<i32, _>(values.as_list_view(), indices)))
+        }
+        DataType::LargeListView(_) => {
+            Ok(Arc::new(take_list_view::<i64, _>(values.as_list_view(), indices)))
+        }
         DataType::FixedSizeList(_, length) => {
             let values = values
                 .as_any()
@@ -158,8 +140,6 @@ fn take_impl<IndexType: ArrowPrimitiveType>(
                 .iter()
                 .map(|a| take_impl(a.as_ref(), indices, Some(options.clone())))
                 .collect::<Result<Vec<ArrayRef>, _>>()?;
-            let fields: Vec<(FieldRef, ArrayRef)> =
-                fields.iter().cloned().zip(arrays).collect();
 
             // Create the null bit buffer.
             let is_valid: Buffer = indices
@@ -172,8 +152,11 @@ fn take_impl<IndexType: ArrowPrimitiveType>(
                     }
                 })
                 .collect();
+            let nulls = NullBuffer::new(BooleanBuffer::new(is_valid, 0, indices.len()));
 
-            Ok(Arc::new(StructArray::from((fields, is_valid))) as ArrayRef)
+            // Reuse the fields of the input, preserving any field metadata
+            let array = StructArray::try_new(fields.clone(), arrays, Some(nulls))?;
+            Ok(Arc::new(array) as ArrayRef)
         }
         DataType::Dictionary(_, _) => downcast_dictionary_array! {
             values => Ok(Arc::new(take_dict(values, indices)?)),
@@ -196,6 +179,10 @@ fn take_impl<IndexType: ArrowPrimitiveType>(
                 .unwrap();
             Ok(Arc::new(take_fixed_size_binary(values, indices, *size)?))
         }
+        DataType::Union(_, _) => {
+            let values = values.as_any().downcast_ref::<UnionArray>().unwrap();
+            Ok(Arc::new(take_union(values, indices)?))
+        }
         DataType::Null => {
             // Take applied to a null array produces a null array.
             if values.len() >= indices.len() {
@@ -211,6 +198,114 @@ fn take_impl<IndexType: ArrowPrimitiveType>(
     }
 }
 
+/// Take elements by index from [Array] without checking that `indices` are in bounds
+///
+/// This is equivalent to [`take`], but skips the bounds checks performed for every
+/// index where possible. It is intended for use where the indices are already known
+/// to be valid, for example because they were computed from `values`, or were checked
+/// once with [`validate_indices`] before being used to take from several arrays
+///
+/// # Safety
+///
+/// All non-null `indices` must be less than `values.len()`
+///
+/// ```
+/// # use arrow_array::{Int32Array, UInt32Array};
+/// # use arrow_select::take::{take_unchecked, validate_indices};
+/// let values = Int32Array::from(vec![10, 20, 30]);
+/// let indices = UInt32Array::from(vec![Some(2), None, Some(0)]);
+/// validate_indices(&indices, values.len()).unwrap();
+///
+/// // SAFETY: indices were validated above
+/// let taken = unsafe { take_unchecked(&values, &indices) }.unwrap();
+/// assert_eq!(taken.as_ref(), &Int32Array::from(vec![Some(30), None, Some(10)]));
+/// ```
+pub unsafe fn take_unchecked<IndexType: ArrowPrimitiveType>(
+    values: &dyn Array,
+    indices: &PrimitiveArray<IndexType>,
+) -> Result<ArrayRef, ArrowError> {
+    downcast_primitive_array! {
+        values => Ok(Arc::new(take_primitive_unchecked(values, indices))),
+        DataType::Boolean => {
+            let values = values.as_boolean();
+            let val_buf = take_bits_unchecked(values.values(), indices);
+            let null_buf = take_nulls_unchecked(values.nulls(), indices);
+            Ok(Arc::new(BooleanArray::new(val_buf, null_buf)))
+        }
+        DataType::ListView(_) => {
+            Ok(Arc::new(take_list_view_unchecked::<i32, _>(values.as_list_view(), indices)))
+        }
+        DataType::LargeListView(_) => {
+            Ok(Arc::new(take_list_view_unchecked::<i64, _>(values.as_list_view(), indices)))
+        }
+        DataType::Struct(fields) => {
+            let array = values.as_struct();
+            let columns = array
+                .columns()
+                .iter()
+                .map(|a| take_unchecked(a.as_ref(), indices))
+                .collect::<Result<Vec<_>, _>>()?;
+            let nulls = take_nulls_unchecked(array.nulls(), indices);
+            Ok(Arc::new(StructArray::try_new(fields.clone(), columns, nulls)?))
+        }
+        DataType::Dictionary(_, _) => downcast_dictionary_array! {
+            values => {
+                let keys = take_primitive_unchecked(values.keys(), indices);
+                Ok(Arc::new(DictionaryArray::new_unchecked(keys, values.values().clone())))
+            }
+            t => unimplemented!("Take not supported for dictionary type {:?}", t)
+        }
+        _ => take_impl(values, indices, None),
+    }
+}
+
+/// Returns an error if any non-null index in `indices` is not less than `len`
+///
+/// This is the check performed by [`take`] when [`TakeOptions::check_bounds`] is set,
+/// and can be used to validate indices once before calling [`take_unchecked`]
+///
+/// ```
+/// # use arrow_array::UInt32Array;
+/// # use arrow_select::take::validate_indices;
+/// let indices = UInt32Array::from(vec![Some(2), None, Some(0)]);
+/// assert!(validate_indices(&indices, 3).is_ok());
+/// assert!(validate_indices(&indices, 2).is_err());
+/// ```
+pub fn validate_indices<IndexType: ArrowPrimitiveType>(
+    indices: &PrimitiveArray<IndexType>,
+    len: usize,
+) -> Result<(), ArrowError> {
+    let in_bounds = |index: &IndexType::Native| match index.to_usize() {
+        Some(ix) => ix < len,
+        None => false,
+    };
+
+    // Check all the indices without branching, so this can be vectorized, and
+    // only locate the offending index to report if this fails
+    let valid = match indices.nulls().filter(|n| n.null_count() > 0) {
+        Some(n) => n
+            .valid_indices()
+            .all(|idx| in_bounds(&indices.values()[idx])),
+        None => indices
+            .values()
+            .iter()
+            .fold(true, |valid, index| valid & in_bounds(index)),
+    };
+    if valid {
+        return Ok(());
+    }
+
+    indices.iter().flatten().try_for_each(|index| {
+        let ix = maybe_usize::<IndexType::Native>(index)?;
+        if ix >= len {
+            return Err(ArrowError::ComputeError(format!(
+                "Array index out of bounds, cannot get item at index {ix} from {len} entries"
+            )));
+        }
+        Ok(())
+    })
+}
+
 /// Options that define how `take` should behave
 #[derive(Clone, Debug, Default)]
 pub struct TakeOptions {
@@ -289,6 +384,95 @@ fn take_native<T: ArrowNativeType, I: ArrowPrimitiveType>(
     }
 }
 
+/// `take` implementation for all primitive arrays without bounds checks
+///
+/// # Safety
+///
+/// All non-null `indices` must be less than `values.len()`
+unsafe fn take_primitive_unchecked<T, I>(
+    values: &PrimitiveArray<T>,
+    indices: &PrimitiveArray<I>,
+) -> PrimitiveArray<T>
+where
+    T: ArrowPrimitiveType,
+    I: ArrowPrimitiveType,
+{
+    let values_buf = take_native_unchecked(values.values(), indices);
+    let nulls = take_nulls_unchecked(values.nulls(), indices);
+    PrimitiveArray::new(values_buf, nulls).with_data_type(values.data_type().clone())
+}
+
+/// # Safety
+///
+/// All non-null `indices` must be less than the length of `values`
+#[inline(never)]
+unsafe fn take_nulls_unchecked<I: ArrowPrimitiveType>(
+    values: Option<&NullBuffer>,
+    indices: &PrimitiveArray<I>,
+) -> Option<NullBuffer> {
+    match values.filter(|n| n.null_count() > 0) {
+        Some(n) => {
+            let buffer = take_bits_unchecked(n.inner(), indices);
+            Some(NullBuffer::new(buffer)).filter(|n| n.null_count() > 0)
+        }
+        None => indices.nulls().cloned(),
+    }
+}
+
+/// # Safety
+///
+/// All non-null `indices` must be less than `values.len()`
+#[inline(never)]
+unsafe fn take_native_unchecked<T: ArrowNativeType, I: ArrowPrimitiveType>(
+    values: &[T],
+    indices: &PrimitiveArray<I>,
+) -> ScalarBuffer<T> {
+    match indices.nulls().filter(|n| n.null_count() > 0) {
+        Some(n) => indices
+            .values()
+            .iter()
+            .enumerate()
+            .map(|(idx, index)| match n.is_valid(idx) {
+                true => *values.get_unchecked(index.as_usize()),
+                false => T::default(),
+            })
+            .collect(),
+        None => indices
+            .values()
+            .iter()
+            .map(|index| *values.get_unchecked(index.as_usize()))
+            .collect(),
+    }
+}
+
+/// # Safety
+///
+/// All non-null `indices` must be less than `values.len()`
+#[inline(never)]
+unsafe fn take_bits_unchecked<I: ArrowPrimitiveType>(
+    values: &BooleanBuffer,
+    indices: &PrimitiveArray<I>,
+) -> BooleanBuffer {
+    let len = indices.len();
+    let mut output_buffer = MutableBuffer::new_null(len);
+    let output_slice = output_buffer.as_slice_mut();
+
+    match indices.nulls().filter(|n| n.null_count() > 0) {
+        Some(nulls) => nulls.valid_indices().for_each(|idx| {
+            let index = indices.values().get_unchecked(idx).as_usize();
+            if values.value_unchecked(index) {
+                bit_util::set_bit(output_slice, idx);
+            }
+        }),
+        None => indices.values().iter().enumerate().for_each(|(i, index)| {
+            if values.value_unchecked(index.as_usize()) {
+                bit_util::set_bit(output_slice, i);
+            }
+        }),
+    }
+    BooleanBuffer::new(output_buffer.into(), 0, indices.len())
+}
+
 #[inline(never)]
 fn take_bits<I: ArrowPrimitiveType>(
     values: &BooleanBuffer,
@@ -418,8 +602,10 @@ where
     OffsetType::Native: OffsetSizeTrait,
     PrimitiveArray<OffsetType>: From<Vec<OffsetType::Native>>,
 {
-    // TODO: Some optimizations can be done here such as if it is
-    // taking the whole list or a contiguous sublist
+    if is_flat(&values.value_type()) {
+        return take_list_ranges(values, indices);
+    }
+
     let (list_indices, offsets, null_buf) =
         take_value_indices_from_list::<IndexType, OffsetType>(values, indices)?;
 
@@ -438,6 +624,176 @@ where
     Ok(GenericListArray::<OffsetType::Native>::from(list_data))
 }
 
+/// Returns `true` if `data_type` has no children, and so its values can be copied
+/// in bulk by [`MutableArrayData`] without recursing into nested arrays
+fn is_flat(data_type: &DataType) -> bool {
+    data_type.is_primitive()
+        || matches!(
+            data_type,
+            DataType::Null
+                | DataType::Boolean
+                | DataType::Utf8
+                | DataType::LargeUtf8
+                | DataType::Binary
+                | DataType::LargeBinary
+                | DataType::FixedSizeBinary(_)
+        )
+}
+
+/// `take` implementation for list arrays with non-nested values
+///
+/// Rather than computing and taking the index of every child value, the
+/// contiguous range of values of each list is copied in bulk
+fn take_list_ranges<I: ArrowPrimitiveType, O: OffsetSizeTrait>(
+    values: &GenericListArray<O>,
+    indices: &PrimitiveArray<I>,
+) -> Result<GenericListArray<O>, ArrowError> {
+    let offsets = values.value_offsets();
+    let child = values.values().to_data();
+    let mut taken = MutableArrayData::new(vec![&child], false, 0);
+
+    let mut new_offsets = Vec::with_capacity(indices.len() + 1);
+    new_offsets.push(O::usize_as(0));
+    let mut nulls = BooleanBufferBuilder::new(indices.len());
+
+    for (i, index) in indices.values().iter().enumerate() {
+        let valid = match indices.is_valid(i) {
+            true => {
+                let ix = maybe_usize::<I::Native>(*index)?;
+                let (start, end) = (offsets[ix].as_usize(), offsets[ix + 1].as_usize());
+                taken.extend(0, start, end);
+                values.is_valid(ix)
+            }
+            false => false,
+        };
+        nulls.append(valid);
+        new_offsets
+            .push(O::from_usize(taken.len()).ok_or_else(|| {
+                ArrowError::ComputeError("offset overflow".to_string())
+            })?);
+    }
+
+    let field = match values.data_type() {
+        DataType::List(f) | DataType::LargeList(f) => f.clone(),
+        _ => unreachable!(),
+    };
+    let nulls = Some(NullBuffer::new(nulls.finish())).filter(|n| n.null_count() > 0);
+    // SAFETY: offsets are monotonically increasing and end at the taken length
+    let offsets = unsafe { OffsetBuffer::new_unchecked(new_offsets.into()) };
+    GenericListArray::try_new(field, offsets, make_array(taken.freeze()), nulls)
+}
+
+/// `take` implementation for `GenericListViewArray`
+///
+/// As list views may refer to arbitrary ranges of their values, only the offsets
+/// and sizes need to be taken, with the values shared with the input array
+fn take_list_view<O: OffsetSizeTrait, I: ArrowPrimitiveType>(
+    values: &GenericListViewArray<O>,
+    indices: &PrimitiveArray<I>,
+) -> GenericListViewArray<O> {
+    let nulls = take_nulls(values.nulls(), indices);
+    let offsets = take_native(values.offsets(), indices);
+    let sizes = take_native(values.sizes(), indices);
+
+    let field = match values.data_type() {
+        DataType::ListView(f) | DataType::LargeListView(f) => f.clone(),
+        _ => unreachable!(),
+    };
+    GenericListViewArray::new(field, offsets, sizes, values.values().clone(), nulls)
+}
+
+/// `take` implementation for `GenericListViewArray` without bounds checks
+///
+/// # Safety
+///
+/// All non-null `indices` must be less than `values.len()`
+unsafe fn take_list_view_unchecked<O: OffsetSizeTrait, I: ArrowPrimitiveType>(
+    values: &GenericListViewArray<O>,
+    indices: &PrimitiveArray<I>,
+) -> GenericListViewArray<O> {
+    let nulls = take_nulls_unchecked(values.nulls(), indices);
+    let offsets = take_native_unchecked(values.offsets(), indices);
+    let sizes = take_native_unchecked(values.sizes(), indices);
+
+    let field = match values.data_type() {
+        DataType::ListView(f) | DataType::LargeListView(f) => f.clone(),
+        _ => unreachable!(),
+    };
+    GenericListViewArray::new(field, offsets, sizes, values.values().clone(), nulls)
+}
+
+/// `take` implementation for `UnionArray`
+///
+/// Unions have no validity of their own, a null index instead produces a null
+/// value in the child of the first type id of the union
+fn take_union<I: ArrowPrimitiveType>(
+    values: &UnionArray,
+    indices: &PrimitiveArray<I>,
+) -> Result<UnionArray, ArrowError> {
+    let (fields, mode) = match values.data_type() {
+        DataType::Union(fields, mode) => (fields, mode),
+        _ => unreachable!(),
+    };
+    let field_type_ids: Vec<i8> = fields.iter().map(|(id, _)| id).collect();
+    let null_type_id = field_type_ids.first().copied().unwrap_or_default();
+
+    let type_ids: Vec<i8> = indices
+        .iter()
+        .map(|idx| match idx {
+            Some(idx) => values.type_id(idx.as_usize()),
+            None => null_type_id,
+        })
+        .collect();
+
+    let (offsets, children) = match mode {
+        UnionMode::Sparse => {
+            let children = fields
+                .iter()
+                .map(|(id, f)| {
+                    let child = take_impl(values.child(id).as_ref(), indices, None)?;
+                    Ok((Field::clone(f), child))
+                })
+                .collect::<Result<Vec<_>, ArrowError>>()?;
+            (None, children)
+        }
+        UnionMode::Dense => {
+            let max_id = field_type_ids.iter().copied().max().unwrap_or_default();
+            let mut child_indices = vec![vec![]; max_id as usize + 1];
+            let mut offsets = Vec::with_capacity(indices.len());
+
+            for (idx, type_id) in indices.iter().zip(&type_ids) {
+                let child_idx = idx.map(|idx| values.value_offset(idx.as_usize()) as u32);
+                let v = &mut child_indices[*type_id as usize];
+                offsets.push(v.len() as i32);
+                v.push(child_idx);
+            }
+
+            let children = fields
+                .iter()
+                .map(|(id, f)| {
+                    let indices = UInt32Array::from(std::mem::take(
+                        &mut child_indices[id as usize],
+                    ));
+                    let child = take_impl(values.child(id).as_ref(), &indices, None)?;
+                    Ok((Field::clone(f), child))
+                })
+                .collect::<Result<Vec<_>, ArrowError>>()?;
+            (Some(Buffer::from_vec(offsets)), children)
+        }
+    };
+
+    // SAFETY:
+    // The type ids are taken from `values`, and the offsets index into the taken children
+    Ok(unsafe {
+        UnionArray::new_unchecked(
+            &field_type_ids,
+            Buffer::from_vec(type_ids),
+            offsets,
+            children,
+        )
+    })
+}
+
 /// `take` implementation for `FixedSizeListArray`
 ///
 /// Calculates the index and indexed offset for the inner array,
@@ -448,6 +804,10 @@ fn take_fixed_size_list<IndexType: ArrowPrimitiveType>(
     indices: &PrimitiveArray<IndexType>,
     length: <UInt32Type as ArrowPrimitiveType>::Native,
 ) -> Result<FixedSizeListArray, ArrowError> {
+    if is_flat(&values.value_type()) {
+        return take_fixed_size_list_ranges(values, indices);
+    }
+
     let list_indices = take_value_indices_from_fixed_size_list(values, indices, length)?;
     let taken = take_impl::<UInt32Type>(values.values().as_ref(), &list_indices, None)?;
 
@@ -476,6 +836,41 @@ fn take_fixed_size_list<IndexType: ArrowPrimitiveType>(
     Ok(FixedSizeListArray::from(list_data))
 }
 
+/// `take` implementation for `FixedSizeListArray` with non-nested values
+///
+/// Copies the values of each list in bulk, see [`take_list_ranges`]
+fn take_fixed_size_list_ranges<I: ArrowPrimitiveType>(
+    values: &FixedSizeListArray,
+    indices: &PrimitiveArray<I>,
+) -> Result<FixedSizeListArray, ArrowError> {
+    let size = values.value_length() as usize;
+    let child = values.values().to_data();
+    let mut taken = MutableArrayData::new(vec![&child], true, indices.len() * size);
+    let mut nulls = BooleanBufferBuilder::new(indices.len());
+
+    for (i, index) in indices.values().iter().enumerate() {
+        match indices.is_valid(i) {
+            true => {
+                let ix = maybe_usize::<I::Native>(*index)?;
+                let start = values.value_offset(ix) as usize;
+                taken.extend(0, start, start + size);
+                nulls.append(values.is_valid(ix));
+            }
+            false => {
+                taken.extend_nulls(size);
+                nulls.append(false);
+            }
+        }
+    }
+
+    let field = match values.data_type() {
+        DataType::FixedSizeList(f, _) => f.clone(),
+        _ => unreachable!(),
+    };
+    let nulls = Some(NullBuffer::new(nulls.finish())).filter(|n| n.null_count() > 0);
+    FixedSizeListArray::try_new(field, size as i32, make_array(taken.freeze()), nulls)
+}
+
 fn take_fixed_size_binary<IndexType: ArrowPrimitiveType>(
     values: &FixedSizeBinaryArray,
     indices: &PrimitiveArray<IndexType>,
@@ -681,6 +1076,7 @@ mod tests {
     use super::*;
     use arrow_array::builder::*;
     use arrow_schema::{Field, Fields, TimeUnit};
+    use std::collections::HashMap;
 
     fn test_take_decimal_arrays(
         data: Vec<Option<i128>>,
@@ -1521,6 +1917,93 @@ mod tests {
         test_take_list!(i64, LargeList, LargeListArray);
     }
 
+    fn _test_take_list_view<O: OffsetSizeTrait>() {
+        let mut builder = GenericListViewBuilder::<O, _>::new(Int32Builder::new());
+        builder.append_value([Some(0), Some(1)]);
+        builder.append_null();
+        builder.append_value([Some(2), None, Some(3)]);
+        builder.append_value([]);
+        let list = builder.finish();
+
+        let indices = UInt32Array::from(vec![Some(2), None, Some(0), Some(1), Some(2)]);
+        let taken = take(&list, &indices, None).unwrap();
+        let taken = taken.as_list_view::<O>();
+
+        let mut builder = GenericListViewBuilder::<O, _>::new(Int32Builder::new());
+        builder.append_value([Some(2), None, Some(3)]);
+        builder.append_null();
+        builder.append_value([Some(0), Some(1)]);
+        builder.append_null();
+        builder.append_value([Some(2), None, Some(3)]);
+        let expected = builder.finish();
+        assert_eq!(taken, &expected);
+
+        // the values are shared with the input
+        assert_eq!(taken.values(), list.values());
+        assert_eq!(taken.offsets(), &[2, 0, 0, 2, 2].map(O::usize_as));
+    }
+
+    #[test]
+    fn test_take_list_view() {
+        _test_take_list_view::<i32>();
+        _test_take_list_view::<i64>();
+    }
+
+    fn _test_take_union(mut builder: UnionBuilder) {
+        builder.append::<Int32Type>("a", 1).unwrap();
+        builder.append::<Float64Type>("b", 3.2).unwrap();
+        builder.append_null::<Int32Type>("a").unwrap();
+        builder.append::<Int32Type>("a", 34).unwrap();
+        let union = builder.build().unwrap();
+        let union = union.slice(1, 3);
+
+        let indices = UInt32Array::from(vec![Some(2), None, Some(0), Some(1), Some(2)]);
+        let taken = take(&union, &indices, None).unwrap();
+        let taken = taken.as_any().downcast_ref::<UnionArray>().unwrap();
+
+        assert_eq!(taken.data_type(), union.data_type());
+        assert_eq!(taken.len(), 5);
+        assert_eq!(taken.type_ids(), &[0, 0, 1, 0, 0]);
+
+        let values: Vec<_> = (0..taken.len())
+            .map(|i| {
+                let value = taken.value(i);
+                match taken.type_id(i) {
+                    0 => value
+                        .as_primitive::<Int32Type>()
+                        .iter()
+                        .next()
+                        .unwrap()
+                        .map(f64::from),
+                    _ => value.as_primitive::<Float64Type>().iter().next().unwrap(),
+                }
+            })
+            .collect();
+        assert_eq!(values, vec![Some(34.), None, Some(3.2), None, Some(34.)]);
+    }
+
+    #[test]
+    fn test_take_union_sparse() {
+        _test_take_union(UnionBuilder::new_sparse());
+    }
+
+    #[test]
+    fn test_take_union_dense() {
+        _test_take_union(UnionBuilder::new_dense());
+        // Children only contain the taken values
+        let mut builder = UnionBuilder::new_dense();
+        builder.append::<Int32Type>("a", 1).unwrap();
+        builder.append::<Int32Type>("a", 2).unwrap();
+        builder.append::<Float64Type>("b", 3.0).unwrap();
+        let union = builder.build().unwrap();
+
+        let taken = take(&union, &UInt32Array::from(vec![1, 1]), None).unwrap();
+        let taken = taken.as_any().downcast_ref::<UnionArray>().unwrap();
+        assert_eq!(taken.offsets().unwrap(), &[0, 1]);
+        assert_eq!(taken.child(0).as_primitive::<Int32Type>().values(), &[2, 2]);
+        assert_eq!(taken.child(1).len(), 0);
+    }
+
     #[test]
     fn test_take_list_with_value_nulls() {
         test_take_list_with_value_nulls!(i32, List, ListArray);
@@ -1703,6 +2186,25 @@ mod tests {
         assert_eq!(&expected, actual);
     }
 
+    #[test]
+    fn test_take_struct_field_metadata() {
+        let metadata = HashMap::from([(
+            "ARROW:extension:name".to_string(),
+            "arrow.uuid".to_string(),
+        )]);
+        let field = Field::new("a", DataType::FixedSizeBinary(2), true)
+            .with_metadata(metadata.clone());
+        let fields = Fields::from(vec![field]);
+        let child = FixedSizeBinaryArray::from(vec![Some(&[1_u8, 2][..]), None]);
+        let array = StructArray::new(fields.clone(), vec![Arc::new(child)], None);
+
+        let index = UInt32Array::from(vec![Some(1), None, Some(0)]);
+        let actual = take(&array, &index, None).unwrap();
+        assert_eq!(actual.as_struct().fields(), &fields);
+        assert_eq!(actual.as_struct().fields()[0].metadata(), &metadata);
+        assert_eq!(actual.null_count(), 1);
+    }
+
     #[test]
     fn test_take_out_of_bounds() {
         let index = UInt32Array::from(vec![Some(3), None, Some(1), Some(3), Some(6)]);
@@ -1938,4 +2440,137 @@ mod tests {
         let values = r.as_string::<i32>().iter().collect::<Vec<_>>();
         assert_eq!(&values, &[Some("foo"), None, None, None])
     }
+
+    #[test]
+    fn test_validate_indices() {
+        let indices = Int32Array::new(
+            vec![1, 2, 400, -1].into(),
+            Some(NullBuffer::from(vec![true, true, false, false])),
+        );
+        validate_indices(&indices, 3).unwrap();
+
+        let err = validate_indices(&indices, 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Array index out of bounds, cannot get item at index 2 from 2 entries"
+        );
+
+        let indices = Int32Array::from(vec![0, -1, 1]);
+        let err = validate_indices(&indices, 3).unwrap_err();
+        assert_eq!(err.to_string(), "Compute error: Cast to usize failed");
+
+        let indices = UInt64Array::from(vec![0, 5, 2]);
+        assert!(validate_indices(&indices, 5).is_err());
+        validate_indices(&indices, 6).unwrap();
+        validate_indices(&UInt64Array::from(Vec::<u64>::new()), 0).unwrap();
+    }
+
+    #[test]
+    fn test_take_unchecked() {
+        let struct_array = StructArray::from(vec![
+            (
+                Arc::new(Field::new("a", DataType::Boolean, true)),
+                Arc::new(BooleanArray::from(vec![
+                    Some(true),
+                    None,
+                    Some(false),
+                    None,
+                ])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", DataType::Int64, false)),
+                Arc::new(Int64Array::from(vec![1, 2, 3, 4])) as ArrayRef,
+            ),
+        ]);
+        let dictionary: DictionaryArray<Int8Type> =
+            vec![Some("a"), None, Some("b"), Some("a")]
+                .into_iter()
+                .collect();
+        let list_view = ListViewArray::new(
+            Arc::new(Field::new("item", DataType::Int32, true)),
+            ScalarBuffer::from(vec![0, 1, 0, 2]),
+            ScalarBuffer::from(vec![2, 1, 0, 1]),
+            Arc::new(Int32Array::from(vec![1, 2, 3])),
+            Some(NullBuffer::from(vec![true, true, false, true])),
+        );
+
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(3), Some(4)])),
+            Arc::new(BooleanArray::from(vec![true, false, true, true])),
+            Arc::new(StringArray::from(vec![
+                Some("a"),
+                None,
+                Some("ccc"),
+                Some(""),
+            ])),
+            Arc::new(struct_array),
+            Arc::new(dictionary),
+            Arc::new(list_view),
+        ];
+
+        let indices = [
+            UInt32Array::from(vec![3, 0, 0, 2, 1]),
+            UInt32Array::new(
+                vec![2, 400, 1, 0, 400].into(),
+                Some(NullBuffer::from(vec![true, false, true, true, false])),
+            ),
+        ];
+
+        for array in &arrays {
+            for indices in &indices {
+                let expected = take(array.as_ref(), indices, None).unwrap();
+                let actual = unsafe { take_unchecked(array.as_ref(), indices) }.unwrap();
+                assert_eq!(actual.as_ref(), expected.as_ref());
+            }
+        }
+    }
+
+    #[test]
+    fn test_take_list_null_indices() {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(3), None]),
+        ]);
+        let indices = UInt32Array::new(
+            vec![3, 400, 1, 0, 2].into(),
+            Some(NullBuffer::from(vec![true, false, true, true, true])),
+        );
+        let taken = take(&list, &indices, None).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(3), None]),
+            None,
+            None,
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![]),
+        ]);
+        assert_eq!(taken.as_list::<i32>(), &expected);
+
+        let list = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+            vec![
+                Some(vec![Some(1), Some(2)]),
+                None,
+                Some(vec![Some(3), None]),
+            ],
+            2,
+        );
+        let taken = take(&list, &indices.slice(1, 4), None).unwrap();
+        let taken = taken.as_fixed_size_list();
+        assert_eq!(taken.len(), 4);
+        assert_eq!(taken.values().len(), 8);
+        let actual: Vec<_> = taken
+            .iter()
+            .map(|x| x.map(|x| x.as_primitive::<Int32Type>().iter().collect::<Vec<_>>()))
+            .collect();
+        assert_eq!(
+            actual,
+            vec![
+                None,
+                None,
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![Some(3), None]),
+            ]
+        );
+    }
 }
@@ -0,0 +1,425 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the unique kernel, returning the distinct values of an array
+
+use crate::take::take;
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{ArrowNativeType, ToByteSlice};
+use arrow_schema::{ArrowError, DataType};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Options that define how [`unique_with_options`] should behave
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UniqueOptions {
+    /// If true, the input is assumed to be sorted such that equal values are
+    /// adjacent, allowing the distinct values to be found without hashing
+    ///
+    /// If the input is not sorted the results are unspecified
+    pub sorted: bool,
+    /// If true, compute [`Unique::inverse`]
+    pub inverse: bool,
+}
+
+/// The result of [`unique_with_options`]
+#[derive(Debug, Clone)]
+pub struct Unique {
+    /// The distinct values, in order of first occurrence
+    pub values: ArrayRef,
+    /// The index of the first occurrence of each of [`Self::values`] in the input
+    pub indices: UInt32Array,
+    /// For each value of the input, the index of the equal value in
+    /// [`Self::values`], if [`UniqueOptions::inverse`] is set
+    ///
+    /// `take(values, inverse)` reconstructs the input
+    pub inverse: Option<UInt32Array>,
+}
+
+/// Returns the distinct values of `array`, in order of first occurrence
+///
+/// Null values are considered equal to each other, and so appear at most once.
+/// Floating point values are compared by their bit pattern, and so `-0.0` and
+/// `0.0`, or NaNs with different payloads, are distinct.
+///
+/// ```
+/// # use arrow_array::{Int32Array, cast::AsArray, types::Int32Type};
+/// # use arrow_select::unique::unique;
+/// let array = Int32Array::from(vec![Some(3), None, Some(1), Some(3), None]);
+/// let values = unique(&array).unwrap();
+/// let values = values.as_primitive::<Int32Type>();
+/// assert_eq!(values, &Int32Array::from(vec![Some(3), None, Some(1)]));
+/// ```
+pub fn unique(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    Ok(unique_with_options(array, UniqueOptions::default())?.values)
+}
+
+/// Returns the distinct values of `array` according to the provided [`UniqueOptions`]
+///
+/// Supports primitive, boolean, string, binary, fixed size binary and dictionary
+/// arrays. Dictionaries are compared by their logical values, and so duplicate
+/// values within the dictionary are considered equal.
+///
+/// See [`unique`] for how values are compared
+///
+/// ```
+/// # use arrow_array::{StringArray, UInt32Array, cast::AsArray};
+/// # use arrow_select::unique::{unique_with_options, UniqueOptions};
+/// let array = StringArray::from(vec!["b", "b", "c", "a", "a"]);
+/// let options = UniqueOptions { sorted: false, inverse: true };
+/// let unique = unique_with_options(&array, options).unwrap();
+///
+/// assert_eq!(unique.values.as_string::<i32>(), &StringArray::from(vec!["b", "c", "a"]));
+/// assert_eq!(unique.indices, UInt32Array::from(vec![0, 2, 3]));
+/// assert_eq!(unique.inverse.unwrap(), UInt32Array::from(vec![0, 0, 1, 2, 2]));
+/// ```
+pub fn unique_with_options(
+    array: &dyn Array,
+    options: UniqueOptions,
+) -> Result<Unique, ArrowError> {
+    let groups = unique_groups(array, options)?;
+    let indices = UInt32Array::from(groups.indices);
+    let values = take(array, &indices, None)?;
+    Ok(Unique {
+        values,
+        indices,
+        inverse: groups.inverse.map(UInt32Array::from),
+    })
+}
+
+/// The first occurrence of each distinct value, and optionally the inverse mapping
+struct Groups {
+    indices: Vec<u32>,
+    inverse: Option<Vec<u32>>,
+}
+
+fn unique_groups(
+    array: &dyn Array,
+    options: UniqueOptions,
+) -> Result<Groups, ArrowError> {
+    if u32::try_from(array.len()).is_err() {
+        return Err(ArrowError::ComputeError(format!(
+            "Cannot compute unique of array with {} values, exceeding u32::MAX",
+            array.len()
+        )));
+    }
+
+    let nulls = array.nulls();
+    let is_valid = |i: usize| nulls.map(|n| n.is_valid(i)).unwrap_or(true);
+
+    macro_rules! primitive_helper {
+        ($t:ty) => {{
+            let values = array.as_primitive::<$t>().values();
+            grouped(array.len(), options, is_valid, |i| {
+                values[i..i + 1].to_byte_slice()
+            })
+        }};
+    }
+
+    let groups = downcast_primitive! {
+        array.data_type() => (primitive_helper),
+        DataType::Null => match array.len() {
+            0 => grouped(0, options, |_| false, |_| ()),
+            len => Groups {
+                indices: vec![0],
+                inverse: options.inverse.then(|| vec![0; len]),
+            },
+        },
+        DataType::Boolean => {
+            let values = array.as_boolean().values();
+            grouped(array.len(), options, is_valid, |i| values.value(i))
+        }
+        DataType::Utf8 => {
+            let array = array.as_string::<i32>();
+            grouped(array.len(), options, is_valid, |i| array.value(i))
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_string::<i64>();
+            grouped(array.len(), options, is_valid, |i| array.value(i))
+        }
+        DataType::Binary => {
+            let array = array.as_binary::<i32>();
+            grouped(array.len(), options, is_valid, |i| array.value(i))
+        }
+        DataType::LargeBinary => {
+            let array = array.as_binary::<i64>();
+            grouped(array.len(), options, is_valid, |i| array.value(i))
+        }
+        DataType::FixedSizeBinary(_) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            grouped(array.len(), options, is_valid, |i| array.value(i))
+        }
+        DataType::Dictionary(_, _) => downcast_dictionary_array! {
+            array => dictionary_groups(array, options)?,
+            _ => unreachable!(),
+        },
+        d => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "unique not supported for {d}"
+            )))
+        }
+    };
+    Ok(groups)
+}
+
+/// Groups the keys of a dictionary by the logical value they reference
+fn dictionary_groups<K: ArrowDictionaryKeyType>(
+    array: &DictionaryArray<K>,
+    options: UniqueOptions,
+) -> Result<Groups, ArrowError> {
+    let values = array.values();
+    // Map each dictionary value to the first equal value, such that keys
+    // referencing equal values are considered equal. The dictionary values
+    // are not sorted even if the keys are, so always hash them
+    let value_options = UniqueOptions {
+        sorted: false,
+        inverse: true,
+    };
+    let value_groups = unique_groups(values.as_ref(), value_options)?
+        .inverse
+        .unwrap();
+
+    let keys = array.keys();
+    let key_nulls = keys.nulls();
+    let value_nulls = values.nulls();
+    let keys = keys.values();
+
+    let is_valid = |i: usize| {
+        key_nulls.map(|n| n.is_valid(i)).unwrap_or(true)
+            && value_nulls
+                .map(|n| n.is_valid(keys[i].as_usize()))
+                .unwrap_or(true)
+    };
+    Ok(grouped(array.len(), options, is_valid, |i| {
+        value_groups[keys[i].as_usize()]
+    }))
+}
+
+/// Groups the `len` values identified by `key`, where `is_valid` returns
+/// false for null values
+fn grouped<K: Hash + Eq>(
+    len: usize,
+    options: UniqueOptions,
+    is_valid: impl Fn(usize) -> bool,
+    key: impl Fn(usize) -> K,
+) -> Groups {
+    let mut indices: Vec<u32> = Vec::new();
+    let mut inverse = options.inverse.then(|| Vec::with_capacity(len));
+
+    match options.sorted {
+        true => {
+            // Equal values are adjacent, so compare each value with its predecessor
+            let mut previous: Option<Option<K>> = None;
+            for i in 0..len {
+                let current = is_valid(i).then(|| key(i));
+                if previous.as_ref() != Some(&current) {
+                    indices.push(i as u32);
+                    previous = Some(current);
+                }
+                if let Some(inverse) = inverse.as_mut() {
+                    inverse.push(indices.len() as u32 - 1);
+                }
+            }
+        }
+        false => {
+            let mut groups: HashMap<K, u32> = HashMap::new();
+            let mut null_group = None;
+            for i in 0..len {
+                let next = indices.len() as u32;
+                let group = match is_valid(i) {
+                    true => *groups.entry(key(i)).or_insert(next),
+                    false => *null_group.get_or_insert(next),
+                };
+                if group == next {
+                    indices.push(i as u32);
+                }
+                if let Some(inverse) = inverse.as_mut() {
+                    inverse.push(group);
+                }
+            }
+        }
+    }
+
+    Groups { indices, inverse }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::StringDictionaryBuilder;
+    use rand::{thread_rng, Rng};
+    use std::sync::Arc;
+
+    /// Checks `unique` against a naive implementation with and without the sorted path
+    fn check_unique(array: &dyn Array) {
+        let options = UniqueOptions {
+            sorted: false,
+            inverse: true,
+        };
+        let unique = unique_with_options(array, options).unwrap();
+        let inverse = unique.inverse.unwrap();
+        assert_eq!(inverse.len(), array.len());
+        let reconstructed = take(unique.values.as_ref(), &inverse, None).unwrap();
+        assert_eq!(reconstructed.to_data(), array.to_data());
+
+        let sorted = take(
+            array,
+            &UInt32Array::from_iter_values(sort_indices(&inverse)),
+            None,
+        )
+        .unwrap();
+        let options = UniqueOptions {
+            sorted: true,
+            inverse: false,
+        };
+        let sorted_unique = unique_with_options(sorted.as_ref(), options).unwrap();
+        assert_eq!(sorted_unique.values.len(), unique.values.len());
+        assert!(sorted_unique.inverse.is_none());
+    }
+
+    /// Returns indices grouping equal values together, ordered by their group
+    fn sort_indices(inverse: &UInt32Array) -> Vec<u32> {
+        let mut indices: Vec<u32> = (0..inverse.len() as u32).collect();
+        indices.sort_by_key(|i| inverse.value(*i as usize));
+        indices
+    }
+
+    #[test]
+    fn test_unique_primitive() {
+        let array =
+            Float64Array::from(vec![Some(1.0), Some(-0.0), None, Some(0.0), Some(1.0)]);
+        let options = UniqueOptions {
+            sorted: false,
+            inverse: true,
+        };
+        let unique = unique_with_options(&array, options).unwrap();
+        assert_eq!(
+            unique.values.as_primitive::<Float64Type>(),
+            &Float64Array::from(vec![Some(1.0), Some(-0.0), None, Some(0.0)])
+        );
+        assert_eq!(unique.indices, UInt32Array::from(vec![0, 1, 2, 3]));
+        assert_eq!(
+            unique.inverse.unwrap(),
+            UInt32Array::from(vec![0, 1, 2, 3, 0])
+        );
+
+        let mut rng = thread_rng();
+        let array: Int64Array = (0..1000)
+            .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..50)))
+            .collect();
+        check_unique(&array);
+        check_unique(&array.slice(100, 500));
+        check_unique(&Int64Array::from(Vec::<i64>::new()));
+    }
+
+    #[test]
+    fn test_unique_sorted() {
+        let array =
+            Int32Array::from(vec![None, None, Some(1), Some(1), Some(2), Some(5)]);
+        let options = UniqueOptions {
+            sorted: true,
+            inverse: true,
+        };
+        let unique = unique_with_options(&array, options).unwrap();
+        assert_eq!(
+            unique.values.as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![None, Some(1), Some(2), Some(5)])
+        );
+        assert_eq!(unique.indices, UInt32Array::from(vec![0, 2, 4, 5]));
+        assert_eq!(
+            unique.inverse.unwrap(),
+            UInt32Array::from(vec![0, 0, 1, 1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_unique_bytes() {
+        let array = StringArray::from(vec![Some("a"), None, Some("bb"), Some("a"), None]);
+        let values = unique(&array).unwrap();
+        assert_eq!(
+            values.as_string::<i32>(),
+            &StringArray::from(vec![Some("a"), None, Some("bb")])
+        );
+        check_unique(&array);
+
+        let array = LargeBinaryArray::from_iter_values(["x", "yz", "x"]);
+        let values = unique(&array).unwrap();
+        assert_eq!(
+            values.as_binary::<i64>(),
+            &LargeBinaryArray::from_iter_values(["x", "yz"])
+        );
+
+        let array = BooleanArray::from(vec![Some(true), None, Some(true), Some(false)]);
+        let values = unique(&array).unwrap();
+        assert_eq!(
+            values.as_boolean(),
+            &BooleanArray::from(vec![Some(true), None, Some(false)])
+        );
+
+        let array = NullArray::new(3);
+        assert_eq!(unique(&array).unwrap().len(), 1);
+        check_unique(&array);
+    }
+
+    #[test]
+    fn test_unique_dictionary() {
+        // Values "a" and "b" are duplicated within the dictionary
+        let values =
+            StringArray::from(vec![Some("a"), Some("b"), Some("a"), None, Some("b")]);
+        let keys =
+            Int32Array::from(vec![Some(0), Some(2), None, Some(3), Some(1), Some(4)]);
+        let array = DictionaryArray::try_new(keys, Arc::new(values)).unwrap();
+
+        let options = UniqueOptions {
+            sorted: false,
+            inverse: true,
+        };
+        let unique = unique_with_options(&array, options).unwrap();
+        assert_eq!(unique.indices, UInt32Array::from(vec![0, 2, 4]));
+        assert_eq!(
+            unique.inverse.unwrap(),
+            UInt32Array::from(vec![0, 0, 1, 1, 2, 2])
+        );
+        let values = unique.values.as_dictionary::<Int32Type>();
+        let values: Vec<_> = values
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(values, vec![Some("a"), None, Some("b")]);
+
+        let mut builder = StringDictionaryBuilder::<Int8Type>::new();
+        builder.extend(["x", "y", "x", "z"].into_iter().map(Some));
+        builder.append_null();
+        check_unique(&builder.finish());
+    }
+
+    #[test]
+    fn test_unique_unsupported() {
+        let array =
+            arrow_array::ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(
+                vec![Some(1)],
+            )]);
+        let err = unique(&array).unwrap_err().to_string();
+        assert!(err.contains("unique not supported for List"), "{err}");
+    }
+}
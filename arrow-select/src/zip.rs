@@ -15,10 +15,16 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::concat::concat;
 use crate::filter::SlicesIterator;
+use crate::take::take;
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
 use arrow_array::*;
+use arrow_buffer::{ArrowNativeType, BooleanBuffer};
 use arrow_data::transform::MutableArrayData;
-use arrow_schema::ArrowError;
+use arrow_schema::{ArrowError, DataType};
+use std::sync::Arc;
 
 /// Zip two arrays by some boolean mask. Where the mask evaluates `true` values of `truthy`
 /// are taken, where the mask evaluates `false` values of `falsy` are taken.
@@ -27,6 +33,11 @@ use arrow_schema::ArrowError;
 /// * `mask` - Boolean values used to determine from which array to take the values.
 /// * `truthy` - Values of this array are taken if mask evaluates `true`
 /// * `falsy` - Values of this array are taken if mask evaluates `false`
+///
+/// Arrays of any type are supported, including nested types such as structs, lists,
+/// list views and maps, which are zipped recursively. Dictionary arrays with different
+/// dictionaries are zipped by combining their dictionaries, retaining only the values
+/// referenced by the result if combining them entirely would overflow the key type.
 pub fn zip(
     mask: &BooleanArray,
     truthy: &dyn Array,
@@ -42,6 +53,29 @@ pub fn zip(
             "all arrays should have the same length".into(),
         ));
     }
+
+    macro_rules! dictionary_helper {
+        ($t:ty, $mask:ident, $truthy:ident, $falsy:ident) => {
+            zip_dictionary::<$t>($mask, $truthy.as_dictionary(), $falsy.as_dictionary())
+        };
+    }
+
+    if let DataType::Dictionary(key, _) = truthy.data_type() {
+        downcast_integer! {
+            key.as_ref() => (dictionary_helper, mask, truthy, falsy),
+            _ => unreachable!("illegal dictionary key type {key}")
+        }
+    } else {
+        zip_fallback(mask, truthy, falsy)
+    }
+}
+
+/// Zips `truthy` and `falsy` using [`MutableArrayData`]
+fn zip_fallback(
+    mask: &BooleanArray,
+    truthy: &dyn Array,
+    falsy: &dyn Array,
+) -> Result<ArrayRef, ArrowError> {
     let falsy = falsy.to_data();
     let truthy = truthy.to_data();
 
@@ -71,9 +105,190 @@ pub fn zip(
     Ok(make_array(data))
 }
 
+/// Returns, for each row, the first non-null value of `args`, or null if all are null
+///
+/// This is equivalent to the SQL `COALESCE` function, and generalizes [`zip`] to any
+/// number of arrays or [`Scalar`]s of the same type. Arrays are only read until
+/// every row has been resolved, and so trailing arguments are skipped if the leading
+/// arguments contain no nulls, in which case no data is copied.
+///
+/// The length of the result is that of the array arguments, which must all have the
+/// same length, or 1 if all the arguments are scalars.
+///
+/// ```
+/// # use arrow_array::{cast::AsArray, types::Int32Type, Int32Array, Scalar};
+/// # use arrow_select::zip::coalesce;
+/// let a = Int32Array::from(vec![Some(1), None, None]);
+/// let b = Int32Array::from(vec![Some(4), Some(5), None]);
+/// let c = Scalar::new(Int32Array::from(vec![0]));
+/// let result = coalesce(&[&a, &b, &c]).unwrap();
+/// assert_eq!(result.as_primitive::<Int32Type>(), &Int32Array::from(vec![1, 5, 0]));
+/// ```
+pub fn coalesce(args: &[&dyn Datum]) -> Result<ArrayRef, ArrowError> {
+    let args: Vec<_> = args.iter().map(|a| a.get()).collect();
+    let (first, _) = args.first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError("coalesce requires at least one argument".into())
+    })?;
+
+    if args.iter().any(|(a, _)| a.data_type() != first.data_type()) {
+        return Err(ArrowError::InvalidArgumentError(
+            "arguments need to have the same data type".into(),
+        ));
+    }
+
+    let mut arrays = args.iter().filter(|(_, scalar)| !scalar);
+    let len = match arrays.next() {
+        Some((array, _)) => array.len(),
+        None => 1,
+    };
+    if arrays.any(|(array, _)| array.len() != len) {
+        return Err(ArrowError::InvalidArgumentError(
+            "all arrays should have the same length".into(),
+        ));
+    }
+
+    if first.data_type() == &DataType::Null {
+        return Ok(new_null_array(&DataType::Null, len));
+    }
+
+    // Fast path for a leading array without nulls
+    if let (array, false) = args[0] {
+        if array.null_count() == 0 {
+            return Ok(make_array(array.to_data()));
+        }
+    }
+
+    // The rows not yet resolved by a preceding argument
+    let mut unresolved = BooleanBuffer::new_set(len);
+    // The arguments that contribute to the result, and the rows they resolve
+    let mut selected = vec![];
+    for (array, scalar) in &args {
+        let valid = match (scalar, array.nulls()) {
+            (true, _) if array.is_null(0) => continue,
+            (true, _) | (false, None) => {
+                selected.push((*array, *scalar, unresolved));
+                break;
+            }
+            (false, Some(nulls)) => nulls.inner().clone(),
+        };
+
+        let resolved = &unresolved & &valid;
+        if resolved.count_set_bits() != 0 {
+            unresolved = &unresolved & &!&valid;
+            selected.push((*array, *scalar, resolved));
+        }
+        if unresolved.count_set_bits() == 0 {
+            break;
+        }
+    }
+
+    // Merge the rows resolved by each argument into slices ordered by row
+    let mut slices: Vec<_> = selected
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, (_, _, rows))| rows.set_slices().map(move |(s, e)| (s, e, idx)))
+        .collect();
+    slices.sort_unstable();
+
+    let data: Vec<_> = selected
+        .iter()
+        .map(|(array, _, _)| array.to_data())
+        .collect();
+    let mut mutable = MutableArrayData::new(data.iter().collect(), true, len);
+
+    let mut filled = 0;
+    for (start, end, idx) in slices {
+        if start > filled {
+            mutable.extend_nulls(start - filled);
+        }
+        match selected[idx].1 {
+            true => (start..end).for_each(|_| mutable.extend(idx, 0, 1)),
+            false => mutable.extend(idx, start, end),
+        }
+        filled = end;
+    }
+    if filled < len {
+        mutable.extend_nulls(len - filled);
+    }
+
+    Ok(make_array(mutable.freeze()))
+}
+
+/// Zips two dictionary arrays
+///
+/// [`MutableArrayData`] concatenates the dictionaries of `truthy` and `falsy` if they
+/// differ, which may overflow `K`. In this case only the dictionary values referenced
+/// by the result are retained.
+fn zip_dictionary<K: ArrowDictionaryKeyType>(
+    mask: &BooleanArray,
+    truthy: &DictionaryArray<K>,
+    falsy: &DictionaryArray<K>,
+) -> Result<ArrayRef, ArrowError> {
+    let combined_len = truthy.values().len() + falsy.values().len();
+    let same_values = truthy.values().to_data().ptr_eq(&falsy.values().to_data());
+    if same_values || K::Native::from_usize(combined_len).is_some() {
+        return zip_fallback(mask, truthy, falsy);
+    }
+
+    // As for `SlicesIterator`, the validity of the mask is ignored
+    let mask = mask.values();
+    let mut truthy_used = vec![false; truthy.values().len()];
+    let mut falsy_used = vec![false; falsy.values().len()];
+    for i in 0..mask.len() {
+        let (array, used) = match mask.value(i) {
+            true => (truthy, &mut truthy_used),
+            false => (falsy, &mut falsy_used),
+        };
+        if array.is_valid(i) {
+            used[array.keys().value(i).as_usize()] = true;
+        }
+    }
+
+    // Map the keys of truthy and falsy to the keys of the combined dictionary
+    let mut next_key = 0;
+    let mut remap = |used: &[bool]| {
+        let mut indices = Vec::new();
+        let mut keys = vec![0; used.len()];
+        for (idx, _) in used.iter().enumerate().filter(|(_, used)| **used) {
+            indices.push(idx as u64);
+            keys[idx] = next_key;
+            next_key += 1;
+        }
+        (UInt64Array::from(indices), keys)
+    };
+    let (truthy_indices, truthy_keys) = remap(&truthy_used);
+    let (falsy_indices, falsy_keys) = remap(&falsy_used);
+    if K::Native::from_usize(next_key).is_none() {
+        return Err(ArrowError::DictionaryKeyOverflowError);
+    }
+
+    let values = concat(&[
+        take(truthy.values().as_ref(), &truthy_indices, None)?.as_ref(),
+        take(falsy.values().as_ref(), &falsy_indices, None)?.as_ref(),
+    ])?;
+
+    let keys: PrimitiveArray<K> = (0..mask.len())
+        .map(|i| {
+            let (array, keys) = match mask.value(i) {
+                true => (truthy, &truthy_keys),
+                false => (falsy, &falsy_keys),
+            };
+            array.is_valid(i).then(|| {
+                let key = keys[array.keys().value(i).as_usize()];
+                // Cannot overflow as checked above
+                K::Native::from_usize(key).unwrap()
+            })
+        })
+        .collect();
+
+    Ok(Arc::new(DictionaryArray::try_new(keys, values)?))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+    use arrow_schema::Field;
 
     #[test]
     fn test_zip_kernel() {
@@ -85,4 +300,248 @@ mod test {
         let expected = Int32Array::from(vec![Some(5), None, Some(6), Some(7), Some(1)]);
         assert_eq!(actual, &expected);
     }
+
+    #[test]
+    fn test_zip_struct() {
+        let field = Arc::new(Field::new("a", DataType::Int32, true));
+        let a = StructArray::new(
+            vec![field.clone()].into(),
+            vec![Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]))],
+            Some(vec![true, true, false].into()),
+        );
+        let b = StructArray::new(
+            vec![field].into(),
+            vec![Arc::new(Int32Array::from(vec![Some(4), Some(5), None]))],
+            None,
+        );
+        let mask = BooleanArray::from(vec![true, false, true]);
+        let out = zip(&mask, &a, &b).unwrap();
+        out.to_data().validate_full().unwrap();
+
+        let actual = out.as_struct();
+        assert!(actual.is_valid(0));
+        assert!(actual.is_valid(1));
+        assert!(actual.is_null(2));
+        let values = actual.column(0).as_primitive::<Int32Type>();
+        assert_eq!(values.slice(0, 2), Int32Array::from(vec![Some(1), Some(5)]));
+    }
+
+    #[test]
+    fn test_zip_list() {
+        let a = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            None,
+            Some(vec![Some(3), None]),
+        ]);
+        let b = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            None,
+            Some(vec![]),
+            Some(vec![Some(6)]),
+        ]);
+        let mask = BooleanArray::from(vec![false, false, true]);
+        let out = zip(&mask, &a, &b).unwrap();
+        out.to_data().validate_full().unwrap();
+
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            None,
+            Some(vec![]),
+            Some(vec![Some(3), None]),
+        ]);
+        assert_eq!(out.as_list::<i32>(), &expected);
+    }
+
+    #[test]
+    fn test_zip_list_view() {
+        let a = ListViewArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(3)]),
+        ]);
+        let b = ListViewArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(4)]),
+            None,
+        ]);
+        let mask = BooleanArray::from(vec![true, false]);
+        let out = zip(&mask, &a, &b).unwrap();
+        out.to_data().validate_full().unwrap();
+
+        let actual = out.as_list_view::<i32>();
+        assert_eq!(actual.len(), 2);
+        assert_eq!(
+            actual.value(0).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![1, 2])
+        );
+        assert!(actual.is_null(1));
+    }
+
+    #[test]
+    fn test_zip_map() {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        let a = builder.finish();
+
+        builder.keys().append_value("b");
+        builder.values().append_value(2);
+        builder.append(true).unwrap();
+        builder.keys().append_value("c");
+        builder.values().append_value(3);
+        builder.append(true).unwrap();
+        let b = builder.finish();
+
+        let mask = BooleanArray::from(vec![false, false]);
+        let out = zip(&mask, &a, &b).unwrap();
+        out.to_data().validate_full().unwrap();
+        assert_eq!(out.as_map(), &b);
+
+        let mask = BooleanArray::from(vec![true, false]);
+        let out = zip(&mask, &a, &b).unwrap();
+        out.to_data().validate_full().unwrap();
+        let actual = out.as_map();
+        assert_eq!(actual.value(0).column(0).as_string::<i32>().value(0), "a");
+        assert_eq!(actual.value(1).column(0).as_string::<i32>().value(0), "c");
+    }
+
+    #[test]
+    fn test_zip_dictionary() {
+        let a: DictionaryArray<Int8Type> = vec![Some("a"), None, Some("b"), Some("a")]
+            .into_iter()
+            .collect();
+        let b: DictionaryArray<Int8Type> = vec![Some("c"), Some("d"), None, Some("a")]
+            .into_iter()
+            .collect();
+        let mask = BooleanArray::from(vec![true, false, true, false]);
+        let out = zip(&mask, &a, &b).unwrap();
+        out.to_data().validate_full().unwrap();
+
+        let actual = out.as_dictionary::<Int8Type>();
+        let values: Vec<_> = actual
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(values, vec![Some("a"), Some("d"), Some("b"), Some("a")]);
+    }
+
+    #[test]
+    fn test_zip_dictionary_overflow() {
+        // The combined dictionaries have 200 values, which overflows Int8
+        let a_values: Vec<_> = (0..100).map(|i| format!("a{i}")).collect();
+        let b_values: Vec<_> = (0..100).map(|i| format!("b{i}")).collect();
+        let a: DictionaryArray<Int8Type> = a_values.iter().map(|s| s.as_str()).collect();
+        let b: DictionaryArray<Int8Type> = b_values.iter().map(|s| s.as_str()).collect();
+
+        let mask = BooleanArray::from_iter((0..100).map(|i| Some(i % 2 == 0)));
+        let out = zip(&mask, &a, &b).unwrap();
+        out.to_data().validate_full().unwrap();
+
+        let actual = out.as_dictionary::<Int8Type>();
+        // only the referenced values are retained
+        assert_eq!(actual.values().len(), 100);
+        let values = actual.downcast_dict::<StringArray>().unwrap();
+        for (i, v) in values.into_iter().enumerate() {
+            let expected = match i % 2 {
+                0 => &a_values[i],
+                _ => &b_values[i],
+            };
+            assert_eq!(v, Some(expected.as_str()));
+        }
+
+        // the referenced values alone overflow Int8
+        let a: DictionaryArray<Int8Type> = a_values.iter().map(|s| s.as_str()).collect();
+        let b: DictionaryArray<Int8Type> = b_values.iter().map(|s| s.as_str()).collect();
+        let a = concat(&[&a, &a]).unwrap();
+        let b = concat(&[&b, &b]).unwrap();
+        let mask = BooleanArray::from_iter((0..200).map(|i| Some(i < 100)));
+        let err = zip(&mask, &a, &b).unwrap_err();
+        assert!(matches!(err, ArrowError::DictionaryKeyOverflowError));
+    }
+
+    #[test]
+    fn test_coalesce() {
+        let a = Int32Array::from(vec![Some(1), None, None, None]);
+        let b = Int32Array::from(vec![Some(5), Some(6), None, None]);
+        let c = Int32Array::from(vec![None, Some(7), Some(8), None]);
+        let out = coalesce(&[&a, &b, &c]).unwrap();
+        let expected = Int32Array::from(vec![Some(1), Some(6), Some(8), None]);
+        assert_eq!(out.as_primitive::<Int32Type>(), &expected);
+
+        let out = coalesce(&[&c, &a]).unwrap();
+        let expected = Int32Array::from(vec![Some(1), Some(7), Some(8), None]);
+        assert_eq!(out.as_primitive::<Int32Type>(), &expected);
+
+        // a leading array without nulls is returned without copying
+        let d = Int32Array::from(vec![1, 2, 3, 4]);
+        let out = coalesce(&[&d, &a]).unwrap();
+        assert_eq!(
+            out.to_data().buffers()[0].as_ptr(),
+            d.values().inner().as_ptr()
+        );
+    }
+
+    #[test]
+    fn test_coalesce_scalar() {
+        let a = StringArray::from(vec![Some("a"), None, Some("c"), None]);
+        let b = StringArray::from(vec![None, None, None, Some("d")]);
+        let default = StringArray::new_scalar("z");
+        let null = Scalar::new(StringArray::new_null(1));
+
+        let out = coalesce(&[&a, &null, &default, &b]).unwrap();
+        let expected = StringArray::from(vec!["a", "z", "c", "z"]);
+        assert_eq!(out.as_string::<i32>(), &expected);
+
+        let out = coalesce(&[&a, &b, &default]).unwrap();
+        let expected = StringArray::from(vec!["a", "z", "c", "d"]);
+        assert_eq!(out.as_string::<i32>(), &expected);
+
+        // a leading scalar resolves all rows
+        let out = coalesce(&[&default, &a]).unwrap();
+        let expected = StringArray::from(vec!["z"; 4]);
+        assert_eq!(out.as_string::<i32>(), &expected);
+
+        let out = coalesce(&[&null, &default]).unwrap();
+        assert_eq!(out.as_string::<i32>(), &StringArray::from(vec!["z"]));
+
+        let out = coalesce(&[&null, &a]).unwrap();
+        assert_eq!(out.as_string::<i32>(), &a);
+    }
+
+    #[test]
+    fn test_coalesce_nested() {
+        let a = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            None,
+            Some(vec![Some(1)]),
+            None,
+        ]);
+        let b = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(2), None]),
+            Some(vec![]),
+            None,
+        ]);
+        let out = coalesce(&[&a, &b]).unwrap();
+        out.to_data().validate_full().unwrap();
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(2), None]),
+            Some(vec![Some(1)]),
+            None,
+        ]);
+        assert_eq!(out.as_list::<i32>(), &expected);
+    }
+
+    #[test]
+    fn test_coalesce_errors() {
+        let err = coalesce(&[]).unwrap_err();
+        assert!(err.to_string().contains("at least one argument"), "{err}");
+
+        let a = Int32Array::from(vec![1, 2]);
+        let b = Int64Array::from(vec![1, 2]);
+        let err = coalesce(&[&a, &b]).unwrap_err();
+        assert!(err.to_string().contains("same data type"), "{err}");
+
+        let b = Int32Array::from(vec![1]);
+        let err = coalesce(&[&a, &b]).unwrap_err();
+        assert!(err.to_string().contains("same length"), "{err}");
+    }
 }
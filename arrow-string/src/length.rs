@@ -88,6 +88,21 @@ where
     unary_offsets!(array, T::DATA_TYPE, |x| x)
 }
 
+fn length_list_view<O, T>(array: &dyn Array) -> ArrayRef
+where
+    O: OffsetSizeTrait,
+    T: ArrowPrimitiveType<Native = O>,
+{
+    let array = array.as_list_view::<O>();
+    let sizes = array.sizes().clone();
+    Arc::new(PrimitiveArray::<T>::new(sizes, array.nulls().cloned()))
+}
+
+fn length_map(array: &dyn Array) -> ArrayRef {
+    let array = array.as_map();
+    unary_offsets!(array, DataType::Int32, |x| x)
+}
+
 fn length_list_fixed_size(array: &dyn Array, length: i32) -> ArrayRef {
     let array = array.as_fixed_size_list();
     let length_list = array.len();
@@ -152,9 +167,11 @@ where
 
 /// Returns an array of Int32/Int64 denoting the length of each value in the array.
 /// For list array, length is the number of elements in each list.
+/// For map array, length is the number of entries in each map.
 /// For string array and binary array, length is the number of bytes of each value.
 ///
-/// * this only accepts ListArray/LargeListArray, StringArray/LargeStringArray, BinaryArray/LargeBinaryArray, and FixedSizeListArray,
+/// * this only accepts ListArray/LargeListArray, ListViewArray/LargeListViewArray, FixedSizeListArray,
+///   MapArray, StringArray/LargeStringArray and BinaryArray/LargeBinaryArray,
 ///   or DictionaryArray with above Arrays as values
 /// * length of null is null.
 pub fn length(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
@@ -176,6 +193,9 @@ pub fn length(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
         }
         DataType::List(_) => Ok(length_list::<i32, Int32Type>(array)),
         DataType::LargeList(_) => Ok(length_list::<i64, Int64Type>(array)),
+        DataType::ListView(_) => Ok(length_list_view::<i32, Int32Type>(array)),
+        DataType::LargeListView(_) => Ok(length_list_view::<i64, Int64Type>(array)),
+        DataType::Map(_, _) => Ok(length_map(array)),
         DataType::Utf8 => Ok(length_string::<i32, Int32Type>(array)),
         DataType::LargeUtf8 => Ok(length_string::<i64, Int64Type>(array)),
         DataType::Binary => Ok(length_binary::<i32, Int32Type>(array)),
@@ -224,7 +244,7 @@ pub fn bit_length(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
 mod tests {
     use super::*;
     use arrow_array::cast::AsArray;
-    use arrow_buffer::NullBuffer;
+    use arrow_buffer::{NullBuffer, ScalarBuffer};
     use arrow_schema::Field;
 
     fn double_vec<T: Clone>(v: Vec<T>) -> Vec<T> {
@@ -737,4 +757,42 @@ mod tests {
         assert!(lengths.is_null(1));
         assert_eq!(lengths.value(2), 3);
     }
+
+    #[test]
+    fn test_list_view_length() {
+        let values = Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef;
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        let offsets = ScalarBuffer::from(vec![0, 1, 0]);
+        let sizes = ScalarBuffer::from(vec![3, 2, 0]);
+        let nulls = NullBuffer::from(vec![true, true, false]);
+        let list = ListViewArray::new(
+            field.clone(),
+            offsets,
+            sizes,
+            values.clone(),
+            Some(nulls),
+        );
+
+        let lengths = length(&list).unwrap();
+        let expected = Int32Array::from(vec![Some(3), Some(2), None]);
+        assert_eq!(lengths.as_primitive::<Int32Type>(), &expected);
+
+        let offsets = ScalarBuffer::from(vec![2_i64, 0]);
+        let sizes = ScalarBuffer::from(vec![1_i64, 2]);
+        let list = LargeListViewArray::new(field, offsets, sizes, values, None);
+        let lengths = length(&list.slice(1, 1)).unwrap();
+        assert_eq!(lengths.as_primitive::<Int64Type>().values(), &[2]);
+    }
+
+    #[test]
+    fn test_map_length() {
+        let keys = vec!["a", "b", "c"];
+        let values = UInt32Array::from(vec![0, 1, 2]);
+        let map =
+            MapArray::new_from_strings(keys.into_iter(), &values, &[0, 2, 2, 3]).unwrap();
+
+        let lengths = length(&map.slice(1, 2)).unwrap();
+        let expected = Int32Array::from(vec![0, 1]);
+        assert_eq!(lengths.as_primitive::<Int32Type>(), &expected);
+    }
 }
@@ -22,6 +22,7 @@ use arrow_buffer::NullBuffer;
 use arrow_data::ArrayDataBuilder;
 use arrow_schema::*;
 use arrow_select::take::take;
+use memchr::memmem::Finder;
 use regex::Regex;
 use std::collections::HashMap;
 
@@ -305,10 +306,10 @@ fn like_scalar_op<'a, F: Fn(bool) -> bool, L: ArrayAccessor<Item = &'a str>>(
         && !right.ends_with("\\%")
         && !right[1..right.len() - 1].contains(is_like_pattern)
     {
-        let contains = &right[1..right.len() - 1];
+        let finder = Finder::new(&right[1..right.len() - 1]);
 
         Ok(BooleanArray::from_unary(left, |item| {
-            op(item.contains(contains))
+            op(finder.find(item.as_bytes()).is_some())
         }))
     } else {
         let re_pattern = replace_like_wildcards(right)?;
@@ -483,6 +484,16 @@ fn ilike_scalar_op<O: OffsetSizeTrait, F: Fn(bool) -> bool>(
                     && ends_str.eq_ignore_ascii_case(&item[start..]);
                 op(result)
             }));
+        } else if right.starts_with('%')
+            && right.ends_with('%')
+            && !right.ends_with("\\%")
+            && !right[1..right.len() - 1].contains(is_like_pattern)
+        {
+            // fast path, can use a case insensitive contains
+            let contains = &right.as_bytes()[1..right.len() - 1];
+            return Ok(BooleanArray::from_unary(left, |item| {
+                op(contains_ignore_ascii_case(item.as_bytes(), contains))
+            }));
         }
     }
 
@@ -561,6 +572,14 @@ fn is_like_pattern(c: char) -> bool {
     c == '%' || c == '_'
 }
 
+/// Returns true if `haystack` contains `needle`, ignoring ASCII case
+fn contains_ignore_ascii_case(haystack: &[u8], needle: &[u8]) -> bool {
+    needle.is_empty()
+        || haystack
+            .windows(needle.len())
+            .any(|window| window.eq_ignore_ascii_case(needle))
+}
+
 /// Evaluate regex `op(left)` matching `right` on [`StringArray`] / [`LargeStringArray`]
 ///
 /// If `negate_regex` is true, the regex expression will be negated. (for example, with `not like`)
@@ -710,7 +729,9 @@ fn contains_scalar<'a, L: ArrayAccessor<Item = &'a str>>(
     left: L,
     right: &str,
 ) -> Result<BooleanArray, ArrowError> {
-    compare_op_scalar(left, |item| item.contains(right))
+    // Construct the searcher once, rather than for every value
+    let finder = Finder::new(right);
+    compare_op_scalar(left, |item| finder.find(item.as_bytes()).is_some())
 }
 
 /// Perform SQL `CONTAINS(left, right)` operation on [`StringArray`] /
@@ -1122,6 +1143,36 @@ mod tests {
         vec![true, true, false, false]
     );
 
+    test_utf8_scalar!(
+        test_utf8_array_ilike_scalar_contains,
+        test_utf8_array_ilike_scalar_dyn_contains,
+        vec!["arRow", "parrows", "ARR", "", "xarrow"],
+        "%aRRow%",
+        ilike_utf8_scalar,
+        ilike_utf8_scalar_dyn,
+        [true, true, false, false, true]
+    );
+
+    test_utf8_scalar!(
+        test_utf8_array_ilike_scalar_contains_empty,
+        test_utf8_array_ilike_scalar_dyn_contains_empty,
+        vec!["arrow", ""],
+        "%%",
+        ilike_utf8_scalar,
+        ilike_utf8_scalar_dyn,
+        [true, true]
+    );
+
+    test_utf8_scalar!(
+        test_utf8_array_nilike_scalar_contains,
+        test_utf8_array_nilike_scalar_dyn_contains,
+        vec!["arRow", "parrows", "ARR", "", "xarrow"],
+        "%aRRow%",
+        nilike_utf8_scalar,
+        nilike_utf8_scalar_dyn,
+        [false, false, true, true, false]
+    );
+
     test_utf8_scalar!(
         test_utf8_array_ilike_scalar_start,
         test_utf8_array_ilike_scalar_dyn_start,
@@ -18,8 +18,13 @@
 //! Defines kernel to extract a substring of an Array
 //! Supported array types:
 //! [GenericStringArray], [GenericBinaryArray], [FixedSizeBinaryArray], [DictionaryArray]
+//!
+//! [`substring`] counts offsets in bytes, whereas [`substring_by_char`],
+//! [`substring_by_char_dyn`], [`left`] and [`right`] count offsets in chars
+//! as required by SQL
 
 use arrow_array::builder::BufferBuilder;
+use arrow_array::cast::AsArray;
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, Buffer, MutableBuffer};
@@ -27,6 +32,7 @@ use arrow_data::ArrayData;
 use arrow_schema::{ArrowError, DataType};
 use num::Zero;
 use std::cmp::Ordering;
+use std::ops::Range;
 use std::sync::Arc;
 
 /// Returns an [`ArrayRef`] with substrings of all the elements in `array`.
@@ -185,24 +191,126 @@ pub fn substring_by_char<OffsetSize: OffsetSizeTrait>(
     start: i64,
     length: Option<u64>,
 ) -> Result<GenericStringArray<OffsetSize>, ArrowError> {
+    Ok(string_substring(array, char_substring_range(start, length)))
+}
+
+/// Returns an [`ArrayRef`] with substrings of all the elements in `array`, where
+/// `start` and `length` are counted by char
+///
+/// This is equivalent to [`substring_by_char`], but also supports
+/// [`DictionaryArray`] with [`GenericStringArray`] values.
+///
+/// ```
+/// # use arrow_array::{cast::AsArray, StringArray};
+/// # use arrow_string::substring::substring_by_char_dyn;
+/// let array = StringArray::from(vec![Some("arrow"), None, Some("Γ ⊢x:T")]);
+/// let result = substring_by_char_dyn(&array, -3, Some(2)).unwrap();
+/// assert_eq!(result.as_string::<i32>(), &StringArray::from(vec![Some("ro"), None, Some("x:")]));
+/// ```
+pub fn substring_by_char_dyn(
+    array: &dyn Array,
+    start: i64,
+    length: Option<u64>,
+) -> Result<ArrayRef, ArrowError> {
+    let range = char_substring_range(start, length);
+    string_kernel_dyn(array, "substring_by_char", &range)
+}
+
+/// Returns an [`ArrayRef`] with the first `n` chars of all the elements in `array`,
+/// as for the SQL `LEFT` function
+///
+/// If `n` is negative, all but the last `-n` chars are returned.
+///
+/// Supports [`GenericStringArray`] and [`DictionaryArray`] with [`GenericStringArray`] values.
+///
+/// ```
+/// # use arrow_array::{cast::AsArray, StringArray};
+/// # use arrow_string::substring::left;
+/// let array = StringArray::from(vec![Some("arrow"), None, Some("Γ ⊢x:T")]);
+/// let result = left(&array, 2).unwrap();
+/// assert_eq!(result.as_string::<i32>(), &StringArray::from(vec![Some("ar"), None, Some("Γ ")]));
+///
+/// let result = left(&array, -2).unwrap();
+/// assert_eq!(result.as_string::<i32>(), &StringArray::from(vec![Some("arr"), None, Some("Γ ⊢x")]));
+/// ```
+pub fn left(array: &dyn Array, n: i64) -> Result<ArrayRef, ArrowError> {
+    string_kernel_dyn(array, "left", &|val| {
+        let end = match n >= 0 {
+            true => n as usize,
+            false => char_count(val).saturating_sub(n.unsigned_abs() as usize),
+        };
+        0..char_offset(val, end)
+    })
+}
+
+/// Returns an [`ArrayRef`] with the last `n` chars of all the elements in `array`,
+/// as for the SQL `RIGHT` function
+///
+/// If `n` is negative, all but the first `-n` chars are returned.
+///
+/// Supports [`GenericStringArray`] and [`DictionaryArray`] with [`GenericStringArray`] values.
+///
+/// ```
+/// # use arrow_array::{cast::AsArray, StringArray};
+/// # use arrow_string::substring::right;
+/// let array = StringArray::from(vec![Some("arrow"), None, Some("Γ ⊢x:T")]);
+/// let result = right(&array, 2).unwrap();
+/// assert_eq!(result.as_string::<i32>(), &StringArray::from(vec![Some("ow"), None, Some(":T")]));
+///
+/// let result = right(&array, -2).unwrap();
+/// assert_eq!(result.as_string::<i32>(), &StringArray::from(vec![Some("row"), None, Some("⊢x:T")]));
+/// ```
+pub fn right(array: &dyn Array, n: i64) -> Result<ArrayRef, ArrowError> {
+    string_kernel_dyn(array, "right", &|val| {
+        let start = match n >= 0 {
+            true => char_count(val).saturating_sub(n as usize),
+            false => n.unsigned_abs() as usize,
+        };
+        char_offset(val, start)..val.len()
+    })
+}
+
+/// Applies [`string_substring`] with `range` to a [`GenericStringArray`] or a
+/// [`DictionaryArray`] with [`GenericStringArray`] values
+fn string_kernel_dyn(
+    array: &dyn Array,
+    name: &str,
+    range: &dyn Fn(&str) -> Range<usize>,
+) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Utf8 => Ok(Arc::new(string_substring(array.as_string::<i32>(), range))),
+        DataType::LargeUtf8 => {
+            Ok(Arc::new(string_substring(array.as_string::<i64>(), range)))
+        }
+        DataType::Dictionary(_, _) => downcast_dictionary_array!(
+            array => {
+                let values = string_kernel_dyn(array.values(), name, range)?;
+                Ok(Arc::new(array.with_values(values.as_ref())))
+            }
+            t => unreachable!("Should be DictionaryArray but got: {t}")
+        ),
+        t => Err(ArrowError::ComputeError(format!(
+            "{name} does not support type {t:?}"
+        ))),
+    }
+}
+
+/// Returns a [`GenericStringArray`] containing the substring of each value of `array`
+/// at the byte range returned by `range`, which must be on char boundaries
+fn string_substring<OffsetSize: OffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    range: impl Fn(&str) -> Range<usize>,
+) -> GenericStringArray<OffsetSize> {
     let mut vals = BufferBuilder::<u8>::new({
         let offsets = array.value_offsets();
         (offsets[array.len()] - offsets[0]).to_usize().unwrap()
     });
     let mut new_offsets = BufferBuilder::<OffsetSize>::new(array.len() + 1);
     new_offsets.append(OffsetSize::zero());
-    let length = length.map(|len| len.to_usize().unwrap());
 
     array.iter().for_each(|val| {
         if let Some(val) = val {
-            let char_count = val.chars().count();
-            let start = if start >= 0 {
-                start.to_usize().unwrap()
-            } else {
-                char_count - (-start).to_usize().unwrap().min(char_count)
-            };
-            let (start_offset, end_offset) = get_start_end_offset(val, start, length);
-            vals.append_slice(&val.as_bytes()[start_offset..end_offset]);
+            vals.append_slice(&val.as_bytes()[range(val)]);
         }
         new_offsets.append(OffsetSize::from_usize(vals.len()).unwrap());
     });
@@ -217,34 +325,51 @@ pub fn substring_by_char<OffsetSize: OffsetSizeTrait>(
             vec![],
         )
     };
-    Ok(GenericStringArray::<OffsetSize>::from(data))
+    GenericStringArray::<OffsetSize>::from(data)
 }
 
-/// * `val` - string
-/// * `start` - the start char index of the substring
-/// * `length` - the char length of the substring
-///
-/// Return the `start` and `end` offset (by byte) of the substring
-fn get_start_end_offset(
-    val: &str,
-    start: usize,
-    length: Option<usize>,
-) -> (usize, usize) {
-    let len = val.len();
-    let mut offset_char_iter = val.char_indices();
-    let start_offset = offset_char_iter
-        .nth(start)
-        .map_or(len, |(offset, _)| offset);
-    let end_offset = length.map_or(len, |length| {
-        if length > 0 {
-            offset_char_iter
-                .nth(length - 1)
-                .map_or(len, |(offset, _)| offset)
-        } else {
-            start_offset
-        }
-    });
-    (start_offset, end_offset)
+/// Returns a function returning the byte range of the substring of a value
+/// starting at char `start`, counting from the end if negative, with at most
+/// `length` chars
+fn char_substring_range(
+    start: i64,
+    length: Option<u64>,
+) -> impl Fn(&str) -> Range<usize> {
+    let length = length.map(|len| len.to_usize().unwrap());
+    move |val| {
+        let start = match start >= 0 {
+            true => start.to_usize().unwrap(),
+            false => char_count(val).saturating_sub(start.unsigned_abs() as usize),
+        };
+        let start_offset = char_offset(val, start);
+        let end_offset = length.map_or(val.len(), |length| {
+            start_offset + char_offset(&val[start_offset..], length)
+        });
+        start_offset..end_offset
+    }
+}
+
+/// Returns the number of chars in `val`
+fn char_count(val: &str) -> usize {
+    match val.is_ascii() {
+        true => val.len(),
+        false => val.chars().count(),
+    }
+}
+
+/// Returns the byte offset of the char at index `n` of `val`, or the length
+/// of `val` if it has `n` or fewer chars
+fn char_offset(val: &str, n: usize) -> usize {
+    if n >= val.len() {
+        // A char is at least one byte
+        val.len()
+    } else if val.is_ascii() {
+        n
+    } else {
+        val.char_indices()
+            .nth(n)
+            .map_or(val.len(), |(offset, _)| offset)
+    }
 }
 
 fn byte_substring<T: ByteArrayType>(
@@ -900,6 +1025,82 @@ mod tests {
         generic_string_by_char_with_non_zero_offset::<i64>()
     }
 
+    fn generic_string_left_right<O: OffsetSizeTrait>() {
+        let input = vec![Some("hello"), None, Some("Γ ⊢x:T"), Some("")];
+        let array = GenericStringArray::<O>::from(input);
+
+        let cases = [
+            (0, vec![Some(""), None, Some(""), Some("")]),
+            (2, vec![Some("he"), None, Some("Γ "), Some("")]),
+            (10, vec![Some("hello"), None, Some("Γ ⊢x:T"), Some("")]),
+            (-2, vec![Some("hel"), None, Some("Γ ⊢x"), Some("")]),
+            (-10, vec![Some(""), None, Some(""), Some("")]),
+            (i64::MIN, vec![Some(""), None, Some(""), Some("")]),
+        ];
+        for (n, expected) in cases {
+            let result = left(&array, n).unwrap();
+            let expected = GenericStringArray::<O>::from(expected);
+            assert_eq!(result.as_string::<O>(), &expected, "left({n})");
+        }
+
+        let cases = [
+            (0, vec![Some(""), None, Some(""), Some("")]),
+            (2, vec![Some("lo"), None, Some(":T"), Some("")]),
+            (10, vec![Some("hello"), None, Some("Γ ⊢x:T"), Some("")]),
+            (-2, vec![Some("llo"), None, Some("⊢x:T"), Some("")]),
+            (-10, vec![Some(""), None, Some(""), Some("")]),
+            (i64::MIN, vec![Some(""), None, Some(""), Some("")]),
+        ];
+        for (n, expected) in cases {
+            let result = right(&array, n).unwrap();
+            let expected = GenericStringArray::<O>::from(expected);
+            assert_eq!(result.as_string::<O>(), &expected, "right({n})");
+        }
+    }
+
+    #[test]
+    fn string_left_right() {
+        generic_string_left_right::<i32>()
+    }
+
+    #[test]
+    fn large_string_left_right() {
+        generic_string_left_right::<i64>()
+    }
+
+    #[test]
+    fn substring_by_char_dyn_dictionary() {
+        let array: DictionaryArray<Int8Type> =
+            vec![Some("Γ ⊢x:T"), None, Some("arrow"), Some("Γ ⊢x:T")]
+                .into_iter()
+                .collect();
+
+        let result = substring_by_char_dyn(&array, -3, Some(2)).unwrap();
+        let result = result.as_dictionary::<Int8Type>();
+        assert_eq!(result.keys(), array.keys());
+        let actual: Vec<_> = result
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(actual, vec![Some("x:"), None, Some("ro"), Some("x:")]);
+
+        let result = left(&array, 1).unwrap();
+        let actual: Vec<_> = result
+            .as_dictionary::<Int8Type>()
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(actual, vec![Some("Γ"), None, Some("a"), Some("Γ")]);
+
+        let err = right(&BinaryArray::from_vec(vec![b"a"]), 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: right does not support type Binary"
+        );
+    }
+
     #[test]
     fn dictionary() {
         _dictionary::<Int8Type>();
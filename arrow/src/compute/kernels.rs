@@ -18,12 +18,14 @@
 //! Computation kernels on Arrow Arrays
 
 pub use arrow_arith::{
-    aggregate, arithmetic, arity, bitwise, boolean, numeric, temporal,
+    aggregate, arithmetic, arity, bitwise, boolean, math, numeric, temporal, vector,
 };
 pub use arrow_cast::cast;
 pub use arrow_cast::parse as cast_utils;
-pub use arrow_ord::{partition, rank, sort};
-pub use arrow_select::{concat, filter, interleave, nullif, take, window, zip};
+pub use arrow_ord::{cmp, in_list, map, merge, partition, rank, search, sort};
+pub use arrow_select::{
+    concat, filter, interleave, nullif, structs, take, unique, window, zip,
+};
 pub use arrow_string::{concat_elements, length, regexp, substring};
 
 /// Comparison kernels for `Array`s.
@@ -32,3 +34,16 @@ pub mod comparison {
     pub use arrow_string::like::*;
     pub use arrow_string::regexp::{regexp_is_match_utf8, regexp_is_match_utf8_scalar};
 }
+
+/// Kernels for list arrays.
+pub mod list {
+    pub use arrow_ord::list::*;
+    pub use arrow_select::list::*;
+}
+
+/// Parallel kernels using rayon
+#[cfg(feature = "parallel")]
+pub mod parallel {
+    pub use arrow_ord::parallel::*;
+    pub use arrow_select::parallel::*;
+}
@@ -18,6 +18,7 @@
 //! Computation kernels on Arrow Arrays
 
 pub mod kernels;
+pub mod registry;
 
 pub use self::kernels::aggregate::*;
 pub use self::kernels::arithmetic::*;
@@ -35,4 +36,5 @@ pub use self::kernels::regexp::*;
 pub use self::kernels::sort::*;
 pub use self::kernels::take::*;
 pub use self::kernels::temporal::*;
+pub use self::kernels::unique::*;
 pub use self::kernels::window::*;
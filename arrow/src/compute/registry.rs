@@ -0,0 +1,399 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A registry of compute kernels that can be looked up and invoked by name
+//!
+//! The kernels in [`kernels`](super::kernels) are ordinary Rust functions, resolved
+//! at compile time. [`FunctionRegistry`] instead maps a function name and the
+//! [`DataType`] of its arguments to a [`Kernel`] at runtime, allowing kernels to be
+//! invoked dynamically, for example from an expression evaluator or across an FFI
+//! boundary, and allowing embedders to register their own kernels.
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow::array::{ArrayRef, Int32Array};
+//! # use arrow::compute::registry::{FunctionRegistry, Signature};
+//! # use arrow::datatypes::DataType;
+//! let mut registry = FunctionRegistry::new_with_defaults();
+//!
+//! let a = Int32Array::from(vec![1, 2, 3]);
+//! let b = Int32Array::new_scalar(10);
+//! let result = registry.call("add", &[&a, &b]).unwrap();
+//! assert_eq!(result.as_ref(), &Int32Array::from(vec![11, 12, 13]));
+//!
+//! // Register a custom kernel for a single type
+//! registry.register(
+//!     "double",
+//!     Signature::Exact(vec![DataType::Int32]),
+//!     Arc::new(|args| arrow::compute::kernels::numeric::add(args[0], args[0])),
+//! );
+//! let result = registry.call("double", &[&a]).unwrap();
+//! assert_eq!(result.as_ref(), &Int32Array::from(vec![2, 4, 6]));
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, BooleanArray, Datum};
+
+use crate::compute::kernels::{boolean, cmp, length, nullif, numeric};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// A type-erased compute kernel
+///
+/// A kernel is invoked with its arguments, each of which may be an array or a
+/// [`Scalar`](arrow_array::Scalar), and returns an array. Kernels taking a single
+/// argument return an array of length 1 when invoked with a scalar.
+pub type Kernel = Arc<dyn Fn(&[&dyn Datum]) -> Result<ArrayRef> + Send + Sync>;
+
+type BinaryFn<T> = fn(&dyn Datum, &dyn Datum) -> Result<T>;
+type UnaryFn<T> = fn(&dyn Array) -> Result<T>;
+type LogicalFn = fn(&BooleanArray, &BooleanArray) -> Result<BooleanArray>;
+
+/// The arguments accepted by a [`Kernel`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Signature {
+    /// Arguments of exactly these types
+    Exact(Vec<DataType>),
+    /// This number of arguments of any type, to be validated by the kernel itself
+    Any(usize),
+}
+
+impl Signature {
+    /// Returns true if arguments of the types `args` are accepted by this signature
+    pub fn matches(&self, args: &[&DataType]) -> bool {
+        match self {
+            Self::Exact(types) => {
+                types.len() == args.len() && types.iter().zip(args).all(|(a, b)| a == *b)
+            }
+            Self::Any(n) => *n == args.len(),
+        }
+    }
+}
+
+/// A registry of [`Kernel`] keyed by function name and [`Signature`]
+///
+/// A function may have several kernels registered, with different signatures.
+/// When invoked, the most recently registered kernel whose signature matches the
+/// arguments is used, and so kernels registered by an embedder take precedence
+/// over those registered by [`FunctionRegistry::new_with_defaults`].
+#[derive(Default, Clone)]
+pub struct FunctionRegistry {
+    functions: HashMap<String, Vec<(Signature, Kernel)>>,
+}
+
+impl Debug for FunctionRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut map = f.debug_map();
+        for (name, kernels) in &self.functions {
+            let signatures: Vec<_> = kernels.iter().map(|(s, _)| s).collect();
+            map.entry(name, &signatures);
+        }
+        map.finish()
+    }
+}
+
+impl FunctionRegistry {
+    /// Create a new, empty [`FunctionRegistry`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new [`FunctionRegistry`] with the built-in kernels registered
+    ///
+    /// This registers the following functions:
+    ///
+    /// * `add`, `sub`, `mul`, `div`, `rem` and their `_wrapping` variants, see [`numeric`]
+    /// * `neg` and `neg_wrapping`
+    /// * `eq`, `neq`, `lt`, `lt_eq`, `gt` and `gt_eq`, see [`cmp`]
+    /// * `and`, `or`, `not`, `is_null` and `is_not_null`, see [`boolean`]
+    /// * `length`, see [`length`]
+    /// * `nullif`, see [`nullif`]
+    pub fn new_with_defaults() -> Self {
+        let mut registry = Self::new();
+
+        let binary: [(&str, BinaryFn<ArrayRef>); 8] = [
+            ("add", numeric::add),
+            ("add_wrapping", numeric::add_wrapping),
+            ("sub", numeric::sub),
+            ("sub_wrapping", numeric::sub_wrapping),
+            ("mul", numeric::mul),
+            ("mul_wrapping", numeric::mul_wrapping),
+            ("div", numeric::div),
+            ("rem", numeric::rem),
+        ];
+        for (name, f) in binary {
+            registry.register(name, Signature::Any(2), Arc::new(move |a| f(a[0], a[1])));
+        }
+
+        let cmp: [(&str, BinaryFn<BooleanArray>); 6] = [
+            ("eq", cmp::eq),
+            ("neq", cmp::neq),
+            ("lt", cmp::lt),
+            ("lt_eq", cmp::lt_eq),
+            ("gt", cmp::gt),
+            ("gt_eq", cmp::gt_eq),
+        ];
+        for (name, f) in cmp {
+            let kernel = move |a: &[&dyn Datum]| Ok(Arc::new(f(a[0], a[1])?) as _);
+            registry.register(name, Signature::Any(2), Arc::new(kernel));
+        }
+
+        let unary: [(&str, UnaryFn<ArrayRef>); 3] = [
+            ("neg", numeric::neg),
+            ("neg_wrapping", numeric::neg_wrapping),
+            ("length", length::length),
+        ];
+        for (name, f) in unary {
+            registry.register(
+                name,
+                Signature::Any(1),
+                Arc::new(move |a| f(a[0].get().0)),
+            );
+        }
+
+        let nulls: [(&str, UnaryFn<BooleanArray>); 2] = [
+            ("is_null", boolean::is_null),
+            ("is_not_null", boolean::is_not_null),
+        ];
+        for (name, f) in nulls {
+            let kernel = move |a: &[&dyn Datum]| Ok(Arc::new(f(a[0].get().0)?) as _);
+            registry.register(name, Signature::Any(1), Arc::new(kernel));
+        }
+
+        let logical: [(&str, LogicalFn); 2] =
+            [("and", boolean::and), ("or", boolean::or)];
+        for (name, f) in logical {
+            let kernel = move |a: &[&dyn Datum]| {
+                let (l, r) = (a[0].get().0.as_boolean(), a[1].get().0.as_boolean());
+                Ok(Arc::new(f(l, r)?) as _)
+            };
+            let signature = Signature::Exact(vec![DataType::Boolean, DataType::Boolean]);
+            registry.register(name, signature, Arc::new(kernel));
+        }
+
+        registry.register(
+            "not",
+            Signature::Exact(vec![DataType::Boolean]),
+            Arc::new(|a| Ok(Arc::new(boolean::not(a[0].get().0.as_boolean())?))),
+        );
+
+        registry.register(
+            "nullif",
+            Signature::Any(2),
+            Arc::new(|a| {
+                let right = a[1].get().0.as_boolean_opt().ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(
+                        "nullif expects a boolean mask".to_string(),
+                    )
+                })?;
+                nullif::nullif(a[0].get().0, right)
+            }),
+        );
+
+        registry
+    }
+
+    /// Register `kernel` as an implementation of the function `name` for arguments
+    /// matching `signature`
+    ///
+    /// If a kernel with an equal signature is already registered for `name`, it is
+    /// replaced by `kernel`.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        signature: Signature,
+        kernel: Kernel,
+    ) {
+        let kernels = self.functions.entry(name.into()).or_default();
+        kernels.retain(|(s, _)| s != &signature);
+        kernels.push((signature, kernel));
+    }
+
+    /// Remove all kernels registered for the function `name`, returning true if
+    /// any were registered
+    pub fn deregister(&mut self, name: &str) -> bool {
+        self.functions.remove(name).is_some()
+    }
+
+    /// Returns true if any kernel is registered for the function `name`
+    pub fn contains(&self, name: &str) -> bool {
+        self.functions.contains_key(name)
+    }
+
+    /// Returns an iterator over the names of the registered functions
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.functions.keys().map(|s| s.as_str())
+    }
+
+    /// Returns the kernel registered for the function `name` that accepts arguments
+    /// of the types `args`, if any
+    pub fn resolve(&self, name: &str, args: &[&DataType]) -> Option<&Kernel> {
+        let kernels = self.functions.get(name)?;
+        kernels
+            .iter()
+            .rev()
+            .find(|(s, _)| s.matches(args))
+            .map(|(_, k)| k)
+    }
+
+    /// Invoke the function `name` with `args`
+    ///
+    /// Returns an error if no kernel is registered for `name` that accepts `args`,
+    /// or if the kernel itself returns an error.
+    pub fn call(&self, name: &str, args: &[&dyn Datum]) -> Result<ArrayRef> {
+        let arrays: Vec<_> = args.iter().map(|a| a.get().0).collect();
+        let types: Vec<_> = arrays.iter().map(|a| a.data_type()).collect();
+        match self.resolve(name, &types) {
+            Some(kernel) => kernel(args),
+            None if !self.contains(name) => Err(ArrowError::InvalidArgumentError(
+                format!("Unknown function: {name}"),
+            )),
+            None => {
+                let types: Vec<_> = types.iter().map(|t| t.to_string()).collect();
+                Err(ArrowError::InvalidArgumentError(format!(
+                    "No kernel for function {name} matching arguments ({})",
+                    types.join(", ")
+                )))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Int32Array, Int64Array, StringArray};
+
+    #[test]
+    fn test_defaults() {
+        let registry = FunctionRegistry::new_with_defaults();
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let b = Int32Array::from(vec![3, 2, 1]);
+
+        let result = registry.call("mul", &[&a, &b]).unwrap();
+        assert_eq!(
+            result.as_ref(),
+            &Int32Array::from(vec![Some(3), None, Some(3)])
+        );
+
+        let result = registry
+            .call("lt", &[&a, &Int32Array::new_scalar(2)])
+            .unwrap();
+        let expected = BooleanArray::from(vec![Some(true), None, Some(false)]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let result = registry.call("is_null", &[&a]).unwrap();
+        assert_eq!(
+            result.as_ref(),
+            &BooleanArray::from(vec![false, true, false])
+        );
+
+        let result = registry.call("not", &[&result]).unwrap();
+        assert_eq!(
+            result.as_ref(),
+            &BooleanArray::from(vec![true, false, true])
+        );
+
+        let result = registry.call("nullif", &[&b, &result]).unwrap();
+        assert_eq!(
+            result.as_ref(),
+            &Int32Array::from(vec![None, Some(2), None])
+        );
+
+        let s = StringArray::from(vec!["a", "bcd"]);
+        let result = registry.call("length", &[&s]).unwrap();
+        assert_eq!(result.as_ref(), &Int32Array::from(vec![1, 3]));
+
+        let mut names: Vec<_> = registry.names().collect();
+        names.sort_unstable();
+        assert!(names.contains(&"add") && names.contains(&"gt_eq"));
+    }
+
+    #[test]
+    fn test_errors() {
+        let registry = FunctionRegistry::new_with_defaults();
+        let a = Int32Array::from(vec![1, 2]);
+
+        let err = registry.call("foo", &[&a]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Unknown function: foo"
+        );
+
+        let err = registry.call("and", &[&a, &a]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: No kernel for function and matching arguments (Int32, Int32)"
+        );
+
+        let err = registry.call("add", &[&a]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: No kernel for function add matching arguments (Int32)"
+        );
+
+        // Errors from the kernel itself are returned
+        let b = Int64Array::from(vec![1, 2]);
+        registry.call("add", &[&a, &b]).unwrap_err();
+    }
+
+    #[test]
+    fn test_register() {
+        let mut registry = FunctionRegistry::new_with_defaults();
+        let a = Int32Array::from(vec![1, 2]);
+        let s = StringArray::from(vec!["a", "b"]);
+
+        // A custom kernel for a specific type takes precedence over the default
+        registry.register(
+            "add",
+            Signature::Exact(vec![DataType::Utf8, DataType::Utf8]),
+            Arc::new(|args| {
+                let l = args[0].get().0.as_string::<i32>();
+                let r = args[1].get().0.as_string::<i32>();
+                Ok(Arc::new(
+                    crate::compute::kernels::concat_elements::concat_elements_utf8(l, r)?,
+                ))
+            }),
+        );
+        let result = registry.call("add", &[&s, &s]).unwrap();
+        assert_eq!(result.as_ref(), &StringArray::from(vec!["aa", "bb"]));
+        let result = registry.call("add", &[&a, &a]).unwrap();
+        assert_eq!(result.as_ref(), &Int32Array::from(vec![2, 4]));
+
+        // Registering the same signature replaces the existing kernel
+        let kernel: Kernel = Arc::new(|_| Ok(Arc::new(Int32Array::from(vec![0]))));
+        registry.register("add", Signature::Any(2), kernel.clone());
+        let result = registry.call("add", &[&a, &a]).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(registry.functions["add"].len(), 2);
+
+        assert!(registry.contains("add"));
+        assert!(registry.deregister("add"));
+        assert!(!registry.contains("add"));
+        assert!(!registry.deregister("add"));
+
+        let registry = FunctionRegistry::new();
+        assert_eq!(registry.names().count(), 0);
+        assert!(registry
+            .resolve("eq", &[&DataType::Int32, &DataType::Int32])
+            .is_none());
+    }
+}
@@ -172,6 +172,14 @@ fn bit_width(data_type: &DataType, i: usize) -> Result<usize> {
                 "The datatype \"{data_type:?}\" expects 3 buffers, but requested {i}. Please verify that the C data interface is correctly implemented."
             )))
         }
+        // List views have an offsets buffer and a sizes buffer of the same width
+        (DataType::ListView(_), 1) | (DataType::ListView(_), 2) => i32::BITS as _,
+        (DataType::LargeListView(_), 1) | (DataType::LargeListView(_), 2) => i64::BITS as _,
+        (DataType::ListView(_), _) | (DataType::LargeListView(_), _) => {
+            return Err(ArrowError::CDataInterface(format!(
+                "The datatype \"{data_type:?}\" expects 3 buffers, but requested {i}. Please verify that the C data interface is correctly implemented."
+            )))
+        }
         // type ids. UnionArray doesn't have null bitmap so buffer index begins with 0.
         (DataType::Union(_, _), 0) => i8::BITS as _,
         // Only DenseUnion has 2nd buffer
@@ -228,19 +236,67 @@ pub fn to_ffi(data: &ArrayData) -> Result<(FFI_ArrowArray, FFI_ArrowSchema)> {
     Ok((array, schema))
 }
 
+/// The validation performed when importing an array from the C Data Interface
+///
+/// See [`from_ffi_with_validation`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ValidationLevel {
+    /// Perform no validation of the imported array
+    ///
+    /// This is only sound if the producer is trusted to export arrays that
+    /// agree with both the C Data Interface and the Arrow specification, as
+    /// invalid arrays may result in undefined behaviour when accessed.
+    None,
+    /// Check that the number of buffers and children agree with the data type,
+    /// and that the buffers are large enough for the array
+    ///
+    /// This is cheap, as it does not inspect the contents of the buffers,
+    /// see [`ArrayData::validate`]
+    #[default]
+    Structure,
+    /// Perform all the checks of [`ValidationLevel::Structure`], and also
+    /// validate the contents of the buffers, such as offsets, UTF-8 data and
+    /// dictionary keys
+    ///
+    /// This requires a scan of the data, see [`ArrayData::validate_full`]
+    Full,
+}
+
 /// Import [ArrayData] from the C Data Interface
 ///
+/// This performs [`ValidationLevel::Structure`] validation of the imported
+/// array, use [`from_ffi_with_validation`] to configure this
+///
 /// # Safety
 ///
 /// This struct assumes that the incoming data agrees with the C data interface.
 pub fn from_ffi(array: FFI_ArrowArray, schema: &FFI_ArrowSchema) -> Result<ArrayData> {
+    from_ffi_with_validation(array, schema, ValidationLevel::default())
+}
+
+/// Import [ArrayData] from the C Data Interface, performing the validation
+/// specified by `validation`
+///
+/// Returns [`ArrowError::CDataInterface`] describing the invalid array,
+/// including the path of any invalid child, if validation fails
+///
+/// # Safety
+///
+/// This function assumes that the incoming data agrees with the C data interface,
+/// and, for [`ValidationLevel::None`], with the Arrow specification.
+pub fn from_ffi_with_validation(
+    array: FFI_ArrowArray,
+    schema: &FFI_ArrowSchema,
+    validation: ValidationLevel,
+) -> Result<ArrayData> {
     let array = Arc::new(array);
     let tmp = ArrowArray {
         array: &array,
         schema,
         owner: &array,
+        validation,
     };
-    tmp.consume()
+    tmp.consume("")
 }
 
 #[derive(Debug)]
@@ -248,16 +304,23 @@ struct ArrowArray<'a> {
     array: &'a FFI_ArrowArray,
     schema: &'a FFI_ArrowSchema,
     owner: &'a Arc<FFI_ArrowArray>,
+    validation: ValidationLevel,
 }
 
 impl<'a> ArrowArray<'a> {
-    fn consume(self) -> Result<ArrayData> {
+    /// Consumes this array, `path` identifies this array within the
+    /// imported array for error messages, and is empty for the root
+    fn consume(self, path: &str) -> Result<ArrayData> {
         let dt = DataType::try_from(self.schema)?;
         let len = self.array.len();
         let offset = self.array.offset();
         let null_count = self.array.null_count();
 
         let data_layout = layout(&dt);
+        if self.validation != ValidationLevel::None {
+            self.validate_structure(&dt, data_layout.can_contain_null_mask)
+                .map_err(|e| invalid_array(path, e))?;
+        }
         let buffers = self.buffers(data_layout.can_contain_null_mask, &dt)?;
 
         let null_bit_buffer = if data_layout.can_contain_null_mask {
@@ -269,19 +332,23 @@ impl<'a> ArrowArray<'a> {
         let mut child_data = (0..self.array.num_children())
             .map(|i| {
                 let child = self.child(i);
-                child.consume()
+                let path = child_path(path, i, child.schema.name());
+                child.consume(&path)
             })
             .collect::<Result<Vec<_>>>()?;
 
-        if let Some(d) = self.dictionary() {
+        if let Some(d) = self.dictionary()? {
             // For dictionary type there should only be a single child, so we don't need to worry if
             // there are other children added above.
             assert!(child_data.is_empty());
-            child_data.push(d.consume()?);
+            let path = match path {
+                "" => "dictionary".to_string(),
+                path => format!("{path}.dictionary"),
+            };
+            child_data.push(d.consume(&path)?);
         }
 
-        // Should FFI be checking validity?
-        Ok(unsafe {
+        let data = unsafe {
             ArrayData::new_unchecked(
                 dt,
                 len,
@@ -291,7 +358,72 @@ impl<'a> ArrowArray<'a> {
                 buffers,
                 child_data,
             )
-        })
+        };
+
+        // Children have been validated by the recursive calls to consume,
+        // so there is no need for ArrayData::validate_full
+        match self.validation {
+            ValidationLevel::None => Ok(()),
+            ValidationLevel::Structure => data.validate(),
+            ValidationLevel::Full => data.validate_data(),
+        }
+        .map_err(|e| invalid_array(path, e))?;
+
+        Ok(data)
+    }
+
+    /// Checks the buffers and children of the C array agree with `dt`, this
+    /// must be called before reading any buffers as [`Self::buffer_len`]
+    /// dereferences the offsets of variable length arrays
+    fn validate_structure(
+        &self,
+        dt: &DataType,
+        can_contain_null_mask: bool,
+    ) -> Result<()> {
+        let expected_buffers = layout(dt).buffers.len() + can_contain_null_mask as usize;
+        if self.array.num_buffers() != expected_buffers {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Expected {expected_buffers} buffers in array of type {dt:?}, got {}",
+                self.array.num_buffers()
+            )));
+        }
+
+        let schema_children = self.schema.children().count();
+        if self.array.num_children() != schema_children {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Array has {} children but its schema has {schema_children}",
+                self.array.num_children(),
+            )));
+        }
+
+        if self.array.null_count() > 0
+            && can_contain_null_mask
+            && self.array.buffer(0).is_null()
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Array has a null count of {} but no validity buffer",
+                self.array.null_count()
+            )));
+        }
+
+        let offsets_buffer = match dt {
+            DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Binary
+            | DataType::LargeBinary
+            | DataType::List(_)
+            | DataType::LargeList(_)
+            | DataType::Map(_, _) => Some(1),
+            _ => None,
+        };
+        if let Some(i) = offsets_buffer {
+            if !self.array.is_empty() && self.array.buffer(i).is_null() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The offsets buffer of a non-empty array of type {dt:?} is null"
+                )));
+            }
+        }
+        Ok(())
     }
 
     /// returns all buffers, as organized by Rust (i.e. null buffer is skipped if it's present
@@ -348,6 +480,17 @@ impl<'a> ArrowArray<'a> {
                 debug_assert_eq!(bits % 8, 0);
                 (length + 1) * (bits / 8)
             }
+            (DataType::Utf8, 2) | (DataType::Binary, 2)
+                if self.array.buffer(1).is_null() =>
+            {
+                // an empty array may omit its offsets, and therefore has no data
+                0
+            }
+            (DataType::LargeUtf8, 2) | (DataType::LargeBinary, 2)
+                if self.array.buffer(1).is_null() =>
+            {
+                0
+            }
             (DataType::Utf8, 2) | (DataType::Binary, 2) => {
                 // the len of the data buffer (buffer 2) equals the last value of the offset buffer (buffer 1)
                 let len = self.buffer_len(1, dt)?;
@@ -394,18 +537,48 @@ impl<'a> ArrowArray<'a> {
             array: self.array.child(index),
             schema: self.schema.child(index),
             owner: self.owner,
+            validation: self.validation,
         }
     }
 
-    fn dictionary(&self) -> Option<ArrowArray> {
+    fn dictionary(&self) -> Result<Option<ArrowArray>> {
         match (self.array.dictionary(), self.schema.dictionary()) {
-            (Some(array), Some(schema)) => Some(ArrowArray {
+            (Some(array), Some(schema)) => Ok(Some(ArrowArray {
                 array,
                 schema,
                 owner: self.owner,
-            }),
-            (None, None) => None,
-            _ => panic!("Dictionary should both be set or not set in FFI_ArrowArray and FFI_ArrowSchema")
+                validation: self.validation,
+            })),
+            (None, None) => Ok(None),
+            _ => Err(ArrowError::CDataInterface(
+                "Dictionary should both be set or not set in FFI_ArrowArray and FFI_ArrowSchema".to_string()
+            )),
+        }
+    }
+}
+
+/// Returns the path of child `index` named `name` of the array at `path`
+fn child_path(path: &str, index: usize, name: &str) -> String {
+    let child = match name {
+        "" => format!("[{index}]"),
+        name => format!("[{index}] '{name}'"),
+    };
+    match path {
+        "" => format!("child{child}"),
+        path => format!("{path}.child{child}"),
+    }
+}
+
+/// Returns an error for the invalid imported array at `path`
+fn invalid_array(path: &str, e: ArrowError) -> ArrowError {
+    let e = match e {
+        ArrowError::InvalidArgumentError(e) => e,
+        e => e.to_string(),
+    };
+    match path {
+        "" => ArrowError::CDataInterface(format!("Invalid imported array: {e}")),
+        path => {
+            ArrowError::CDataInterface(format!("Invalid imported array at {path}: {e}"))
         }
     }
 }
@@ -420,7 +593,8 @@ mod tests {
     use arrow_array::builder::UnionBuilder;
     use arrow_array::cast::AsArray;
     use arrow_array::types::{Float64Type, Int32Type};
-    use arrow_array::{StructArray, UnionArray};
+    use arrow_array::{GenericListViewArray, StructArray, UnionArray};
+    use arrow_buffer::ScalarBuffer;
 
     use crate::array::{
         make_array, Array, ArrayData, BooleanArray, Decimal128Array, DictionaryArray,
@@ -640,6 +814,47 @@ mod tests {
         test_generic_list::<i64>()
     }
 
+    fn test_generic_list_view<Offset: OffsetSizeTrait>() -> Result<()> {
+        // [[5, 6, 7], null, [0, 1], [6, 7]] with overlapping and out of order values
+        let field = Arc::new(Field::new("item", DataType::Int32, false));
+        let offsets = [5_usize, 0, 0, 6].map(Offset::usize_as);
+        let sizes = [3_usize, 0, 2, 2].map(Offset::usize_as);
+        let original = GenericListViewArray::<Offset>::new(
+            field,
+            ScalarBuffer::from(offsets.to_vec()),
+            ScalarBuffer::from(sizes.to_vec()),
+            Arc::new(Int32Array::from(vec![0, 1, 2, 3, 4, 5, 6, 7])),
+            Some(vec![true, false, true, true].into()),
+        )
+        .slice(1, 3);
+
+        // export it
+        let (array, schema) = to_ffi(&original.to_data())?;
+
+        // (simulate consumer) import it
+        let data = from_ffi(array, &schema)?;
+        let array = make_array(data);
+
+        // verify
+        let array = array.as_list_view::<Offset>();
+        assert_eq!(array, &original);
+        assert!(array.is_null(0));
+        assert_eq!(array.value(2).as_primitive::<Int32Type>().values(), &[6, 7]);
+
+        // (drop/release)
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_view() -> Result<()> {
+        test_generic_list_view::<i32>()
+    }
+
+    #[test]
+    fn test_large_list_view() -> Result<()> {
+        test_generic_list_view::<i64>()
+    }
+
     fn test_generic_binary<Offset: OffsetSizeTrait>() -> Result<()> {
         // create an array natively
         let array: Vec<Option<&[u8]>> = vec![Some(b"a"), None, Some(b"aaa")];
@@ -1164,4 +1379,90 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_import_validation() {
+        // a string array containing invalid UTF-8, which is structurally valid
+        let strings = unsafe {
+            ArrayData::new_unchecked(
+                DataType::Utf8,
+                2,
+                None,
+                None,
+                0,
+                vec![
+                    Buffer::from_slice_ref([0_i32, 1, 3]),
+                    Buffer::from(b"a\xFF\xFE"),
+                ],
+                vec![],
+            )
+        };
+        let field = Field::new("b", DataType::Utf8, true);
+        let struct_array =
+            StructArray::from(vec![(Arc::new(field), make_array(strings))]);
+        let data = struct_array.into_data();
+
+        let (array, schema) = to_ffi(&data).unwrap();
+        let imported =
+            from_ffi_with_validation(array, &schema, ValidationLevel::None).unwrap();
+        assert_eq!(imported, data);
+
+        let (array, schema) = to_ffi(&data).unwrap();
+        let imported = from_ffi(array, &schema).unwrap();
+        assert_eq!(imported, data);
+
+        let (array, schema) = to_ffi(&data).unwrap();
+        let err =
+            from_ffi_with_validation(array, &schema, ValidationLevel::Full).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "C Data interface error: Invalid imported array at child[0] 'b': \
+             Invalid UTF8 sequence at string index 1 (1..3): invalid utf-8 sequence of 1 bytes from index 0"
+        );
+    }
+
+    #[test]
+    fn test_import_validation_structure() {
+        // a struct array with a child shorter than itself
+        let child = Int32Array::from(vec![1]).into_data();
+        let data = unsafe {
+            ArrayData::new_unchecked(
+                DataType::Struct(vec![Field::new("a", DataType::Int32, false)].into()),
+                3,
+                None,
+                None,
+                0,
+                vec![],
+                vec![child],
+            )
+        };
+
+        let (array, schema) = to_ffi(&data).unwrap();
+        let imported =
+            from_ffi_with_validation(array, &schema, ValidationLevel::None).unwrap();
+        assert_eq!(imported.len(), 3);
+
+        let (array, schema) = to_ffi(&data).unwrap();
+        let err = from_ffi(array, &schema).unwrap_err().to_string();
+        assert!(
+            err.starts_with("C Data interface error: Invalid imported array: "),
+            "{err}"
+        );
+        assert!(err.contains("has length smaller than expected"), "{err}");
+    }
+
+    #[test]
+    fn test_import_validation_buffers() {
+        let data = Int32Array::from(vec![1, 2, 3]).into_data();
+        let (array, _) = to_ffi(&data).unwrap();
+
+        // the producer claims an Int32Array is a string array
+        let schema = FFI_ArrowSchema::try_from(DataType::Utf8).unwrap();
+        let err = from_ffi(array, &schema).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "C Data interface error: Invalid imported array: \
+             Expected 3 buffers in array of type Utf8, got 2"
+        );
+    }
 }
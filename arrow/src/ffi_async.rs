@@ -0,0 +1,544 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Contains declarations to bind to the [Async Device Stream Interface](https://arrow.apache.org/docs/format/CDeviceDataInterface.html#async-device-stream-interface).
+//!
+//! Unlike the [C Stream Interface](crate::ffi_stream), where the consumer pulls
+//! arrays from the producer, the async interface is push-based: the consumer
+//! provides a [FFI_ArrowAsyncDeviceStreamHandler] whose callbacks are invoked by
+//! the producer, with the consumer applying backpressure by requesting arrays
+//! via the [FFI_ArrowAsyncProducer].
+//!
+//! [export_reader_into_async_handler] exports a [RecordBatchReader] to a
+//! consumer's handler, with the arrays residing in CPU memory.
+
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr::addr_of_mut;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::Mutex;
+
+use crate::array::{Array, StructArray};
+use crate::error::ArrowError;
+use crate::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
+use crate::ffi_device::{ArrowDeviceType, FFI_ArrowDeviceArray};
+use crate::record_batch::RecordBatchReader;
+
+const ENOMEM: i32 = 12;
+const EIO: i32 = 5;
+const EINVAL: i32 = 22;
+const ENOSYS: i32 = 78;
+
+/// ABI-compatible struct for `ArrowAsyncTask` from the Async Device Stream Interface
+///
+/// The consumer must call `extract_data` exactly once to take ownership of the
+/// array of the task
+#[repr(C)]
+#[derive(Debug)]
+pub struct FFI_ArrowAsyncTask {
+    pub extract_data: Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_ArrowAsyncTask,
+            out: *mut FFI_ArrowDeviceArray,
+        ) -> c_int,
+    >,
+    pub private_data: *mut c_void,
+}
+
+/// ABI-compatible struct for `ArrowAsyncProducer` from the Async Device Stream Interface
+///
+/// This is valid until the `release` callback of the handler is called
+#[repr(C)]
+#[derive(Debug)]
+pub struct FFI_ArrowAsyncProducer {
+    pub device_type: ArrowDeviceType,
+    pub request: Option<unsafe extern "C" fn(arg1: *mut FFI_ArrowAsyncProducer, n: i64)>,
+    pub cancel: Option<unsafe extern "C" fn(arg1: *mut FFI_ArrowAsyncProducer)>,
+    pub additional_metadata: *const c_char,
+    pub private_data: *mut c_void,
+}
+
+/// ABI-compatible struct for `ArrowAsyncDeviceStreamHandler` from the Async Device Stream Interface
+///
+/// This is owned by the consumer, and released by the producer once the
+/// stream has completed, failed or been cancelled
+#[repr(C)]
+#[derive(Debug)]
+pub struct FFI_ArrowAsyncDeviceStreamHandler {
+    pub on_schema: Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_ArrowAsyncDeviceStreamHandler,
+            stream_schema: *mut FFI_ArrowSchema,
+        ) -> c_int,
+    >,
+    pub on_next_task: Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_ArrowAsyncDeviceStreamHandler,
+            task: *mut FFI_ArrowAsyncTask,
+            metadata: *const c_char,
+        ) -> c_int,
+    >,
+    pub on_error: Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_ArrowAsyncDeviceStreamHandler,
+            code: c_int,
+            message: *const c_char,
+            metadata: *const c_char,
+        ),
+    >,
+    pub release:
+        Option<unsafe extern "C" fn(arg1: *mut FFI_ArrowAsyncDeviceStreamHandler)>,
+    pub producer: *mut FFI_ArrowAsyncProducer,
+    pub private_data: *mut c_void,
+}
+
+// The callback used to move the array out of an exported task
+unsafe extern "C" fn extract_data(
+    task: *mut FFI_ArrowAsyncTask,
+    out: *mut FFI_ArrowDeviceArray,
+) -> c_int {
+    let task = &mut *task;
+    if task.private_data.is_null() {
+        return EINVAL;
+    }
+    let array = Box::from_raw(task.private_data as *mut FFI_ArrowDeviceArray);
+    task.private_data = std::ptr::null_mut();
+    std::ptr::write_unaligned(out, *array);
+    0
+}
+
+struct ProducerPrivateData {
+    batch_reader: Mutex<Box<dyn RecordBatchReader + Send>>,
+    handler: *mut FFI_ArrowAsyncDeviceStreamHandler,
+    /// The number of arrays requested but not yet produced
+    pending: AtomicI64,
+    /// Set while a thread is producing arrays, ensuring callbacks made
+    /// by the consumer from within the handler do not re-enter production
+    producing: AtomicBool,
+    cancelled: AtomicBool,
+    /// Set if the consumer requested a non-positive number of arrays
+    invalid_request: AtomicBool,
+}
+
+impl ProducerPrivateData {
+    /// Produces the pending arrays, returning `true` if the stream has
+    /// finished and the handler has been released
+    unsafe fn produce(&self) -> bool {
+        let handler = self.handler;
+        loop {
+            if self.cancelled.load(Ordering::Acquire) {
+                self.finish();
+                return true;
+            }
+
+            if self.invalid_request.load(Ordering::Acquire) {
+                let err = ArrowError::InvalidArgumentError(
+                    "the number of requested arrays must be positive".to_string(),
+                );
+                self.fail(&err);
+                return true;
+            }
+
+            if self.pending.load(Ordering::Acquire) <= 0 {
+                return false;
+            }
+
+            let next = self.batch_reader.lock().unwrap().next();
+            match next {
+                Some(Ok(batch)) => {
+                    self.pending.fetch_sub(1, Ordering::AcqRel);
+
+                    let struct_array = StructArray::from(batch);
+                    let array = FFI_ArrowArray::new(&struct_array.to_data());
+                    let array = Box::new(FFI_ArrowDeviceArray::new_cpu(array));
+                    let mut task = FFI_ArrowAsyncTask {
+                        extract_data: Some(extract_data),
+                        private_data: Box::into_raw(array) as *mut c_void,
+                    };
+
+                    let on_next_task = (*handler).on_next_task.unwrap();
+                    let ret_code =
+                        on_next_task(handler, addr_of_mut!(task), std::ptr::null());
+                    if ret_code != 0 {
+                        self.finish();
+                        return true;
+                    }
+                }
+                Some(Err(err)) => {
+                    self.fail(&err);
+                    return true;
+                }
+                None => {
+                    // A null task indicates the end of the stream
+                    let on_next_task = (*handler).on_next_task.unwrap();
+                    on_next_task(handler, std::ptr::null_mut(), std::ptr::null());
+                    self.finish();
+                    return true;
+                }
+            }
+        }
+    }
+
+    /// Reports `err` to the handler and releases it
+    unsafe fn fail(&self, err: &ArrowError) {
+        report_error(self.handler, err);
+        self.finish();
+    }
+
+    /// Releases the handler, after which no other callbacks may be invoked
+    unsafe fn finish(&self) {
+        release_handler(self.handler);
+    }
+}
+
+unsafe fn report_error(
+    handler: *mut FFI_ArrowAsyncDeviceStreamHandler,
+    err: &ArrowError,
+) {
+    if let Some(on_error) = (*handler).on_error {
+        // Interior nul bytes would truncate the message, so replace them
+        let message = CString::new(err.to_string().replace('\0', " ")).unwrap();
+        on_error(
+            handler,
+            get_error_code(err),
+            message.as_ptr(),
+            std::ptr::null(),
+        );
+    }
+}
+
+unsafe fn release_handler(handler: *mut FFI_ArrowAsyncDeviceStreamHandler) {
+    if let Some(release) = (*handler).release {
+        release(handler);
+    }
+}
+
+unsafe fn get_private_data<'a>(
+    producer: *mut FFI_ArrowAsyncProducer,
+) -> &'a ProducerPrivateData {
+    &*((*producer).private_data as *const ProducerPrivateData)
+}
+
+/// Frees a producer allocated by [export_reader_into_async_handler]
+unsafe fn free_producer(producer: *mut FFI_ArrowAsyncProducer) {
+    let producer = Box::from_raw(producer);
+    drop(Box::from_raw(
+        producer.private_data as *mut ProducerPrivateData,
+    ));
+}
+
+/// Produces any pending arrays, unless another caller is already doing so
+unsafe fn drive(producer: *mut FFI_ArrowAsyncProducer) {
+    let data = get_private_data(producer);
+    loop {
+        let acquired = data.producing.compare_exchange(
+            false,
+            true,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        );
+        if acquired.is_err() {
+            // The current producer will observe the updated state
+            return;
+        }
+
+        if data.produce() {
+            free_producer(producer);
+            return;
+        }
+        data.producing.store(false, Ordering::Release);
+
+        // Check for requests made after `produce` returned, but before
+        // `producing` was cleared, that would otherwise be lost
+        let pending = data.pending.load(Ordering::Acquire) > 0;
+        if !pending
+            && !data.cancelled.load(Ordering::Acquire)
+            && !data.invalid_request.load(Ordering::Acquire)
+        {
+            return;
+        }
+    }
+}
+
+// The callback used by the consumer to request `n` more arrays
+unsafe extern "C" fn request(producer: *mut FFI_ArrowAsyncProducer, n: i64) {
+    let data = get_private_data(producer);
+    match n > 0 {
+        true => {
+            let _ = data.pending.fetch_update(
+                Ordering::AcqRel,
+                Ordering::Acquire,
+                |pending| Some(pending.saturating_add(n)),
+            );
+        }
+        false => data.invalid_request.store(true, Ordering::Release),
+    }
+    drive(producer)
+}
+
+// The callback used by the consumer to cancel the stream
+unsafe extern "C" fn cancel(producer: *mut FFI_ArrowAsyncProducer) {
+    get_private_data(producer)
+        .cancelled
+        .store(true, Ordering::Release);
+    drive(producer)
+}
+
+fn get_error_code(err: &ArrowError) -> i32 {
+    match err {
+        ArrowError::NotYetImplemented(_) => ENOSYS,
+        ArrowError::MemoryError(_) => ENOMEM,
+        ArrowError::IoError(_) => EIO,
+        _ => EINVAL,
+    }
+}
+
+/// Exports a record batch reader to the async stream handler provided by the consumer.
+///
+/// This sets the `producer` of the handler and invokes its `on_schema` callback,
+/// after which batches are only read from `reader` as they are requested by the
+/// consumer. The handler is released once the stream has completed, failed, or
+/// been cancelled.
+///
+/// # Safety
+/// Assumes that the pointer represents a valid async stream handler, whose
+/// callbacks follow the Async Device Stream Interface.
+pub unsafe fn export_reader_into_async_handler(
+    reader: Box<dyn RecordBatchReader + Send>,
+    handler: *mut FFI_ArrowAsyncDeviceStreamHandler,
+) {
+    let mut schema = match FFI_ArrowSchema::try_from(reader.schema().as_ref()) {
+        Ok(schema) => schema,
+        Err(err) => {
+            report_error(handler, &err);
+            release_handler(handler);
+            return;
+        }
+    };
+
+    let private_data = Box::new(ProducerPrivateData {
+        batch_reader: Mutex::new(reader),
+        handler,
+        pending: AtomicI64::new(0),
+        producing: AtomicBool::new(false),
+        cancelled: AtomicBool::new(false),
+        invalid_request: AtomicBool::new(false),
+    });
+    let producer = Box::into_raw(Box::new(FFI_ArrowAsyncProducer {
+        device_type: ArrowDeviceType::CPU,
+        request: Some(request),
+        cancel: Some(cancel),
+        additional_metadata: std::ptr::null(),
+        private_data: Box::into_raw(private_data) as *mut c_void,
+    }));
+    (*handler).producer = producer;
+
+    // Hold `producing` so that requests made from within `on_schema` are
+    // only acted upon once the schema has been delivered
+    let data = get_private_data(producer);
+    data.producing.store(true, Ordering::Release);
+
+    // The consumer takes ownership of the schema, any schema not moved
+    // by the consumer is released when dropped
+    let on_schema = (*handler).on_schema.unwrap();
+    if on_schema(handler, addr_of_mut!(schema)) != 0 {
+        release_handler(handler);
+        free_producer(producer);
+        return;
+    }
+
+    data.producing.store(false, Ordering::Release);
+    drive(producer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::ffi::CStr;
+    use std::sync::Arc;
+
+    use crate::array::Int32Array;
+    use crate::datatypes::{DataType, Field, Schema};
+    use crate::ffi::from_ffi;
+    use crate::record_batch::RecordBatch;
+    use arrow_array::RecordBatchIterator;
+
+    /// The state of a test consumer
+    #[derive(Default)]
+    struct Consumer {
+        schema: Option<Schema>,
+        batches: Vec<RecordBatch>,
+        error: Option<(c_int, String)>,
+        finished: bool,
+        released: bool,
+        /// Cancel the stream after receiving this many batches
+        cancel_after: Option<usize>,
+        /// Fail `on_schema`
+        reject_schema: bool,
+    }
+
+    unsafe fn consumer<'a>(
+        handler: *mut FFI_ArrowAsyncDeviceStreamHandler,
+    ) -> &'a mut Consumer {
+        &mut *((*handler).private_data as *mut Consumer)
+    }
+
+    unsafe extern "C" fn on_schema(
+        handler: *mut FFI_ArrowAsyncDeviceStreamHandler,
+        schema: *mut FFI_ArrowSchema,
+    ) -> c_int {
+        let state = consumer(handler);
+        if state.reject_schema {
+            return EINVAL;
+        }
+        let schema = std::ptr::replace(schema, FFI_ArrowSchema::empty());
+        state.schema = Some(Schema::try_from(&schema).unwrap());
+
+        let producer = (*handler).producer;
+        (*producer).request.unwrap()(producer, 1);
+        0
+    }
+
+    unsafe extern "C" fn on_next_task(
+        handler: *mut FFI_ArrowAsyncDeviceStreamHandler,
+        task: *mut FFI_ArrowAsyncTask,
+        _metadata: *const c_char,
+    ) -> c_int {
+        let state = consumer(handler);
+        if task.is_null() {
+            state.finished = true;
+            return 0;
+        }
+
+        let mut array = FFI_ArrowDeviceArray::empty();
+        assert_eq!((*task).extract_data.unwrap()(task, &mut array), 0);
+        assert_eq!(array.device_type(), ArrowDeviceType::CPU);
+
+        let schema = state.schema.as_ref().unwrap();
+        let ffi_schema = FFI_ArrowSchema::try_from(schema).unwrap();
+        let data = from_ffi(array.try_into_cpu_array().unwrap(), &ffi_schema).unwrap();
+        state
+            .batches
+            .push(RecordBatch::from(StructArray::from(data)));
+
+        // Request the next batch from within the callback
+        let producer = (*handler).producer;
+        match state.cancel_after {
+            Some(n) if state.batches.len() >= n => (*producer).cancel.unwrap()(producer),
+            _ => (*producer).request.unwrap()(producer, 1),
+        }
+        0
+    }
+
+    unsafe extern "C" fn on_error(
+        handler: *mut FFI_ArrowAsyncDeviceStreamHandler,
+        code: c_int,
+        message: *const c_char,
+        _metadata: *const c_char,
+    ) {
+        let message = CStr::from_ptr(message).to_string_lossy().into_owned();
+        consumer(handler).error = Some((code, message));
+    }
+
+    unsafe extern "C" fn release(handler: *mut FFI_ArrowAsyncDeviceStreamHandler) {
+        consumer(handler).released = true;
+        (*handler).release = None;
+    }
+
+    fn run(
+        batches: Vec<crate::error::Result<RecordBatch>>,
+        mut state: Consumer,
+    ) -> Consumer {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let reader = RecordBatchIterator::new(batches, schema);
+
+        let mut handler = FFI_ArrowAsyncDeviceStreamHandler {
+            on_schema: Some(on_schema),
+            on_next_task: Some(on_next_task),
+            on_error: Some(on_error),
+            release: Some(release),
+            producer: std::ptr::null_mut(),
+            private_data: addr_of_mut!(state) as *mut c_void,
+        };
+        unsafe { export_reader_into_async_handler(Box::new(reader), &mut handler) };
+        assert!(handler.release.is_none());
+        state
+    }
+
+    fn test_batch(values: Vec<i32>) -> RecordBatch {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        RecordBatch::try_new(schema, vec![Arc::new(Int32Array::from(values))]).unwrap()
+    }
+
+    #[test]
+    fn test_async_export() {
+        let batches = vec![test_batch(vec![1, 2]), test_batch(vec![3])];
+        let input = batches.iter().cloned().map(Ok).collect();
+        let state = run(input, Consumer::default());
+
+        assert_eq!(state.schema.unwrap(), batches[0].schema().as_ref().clone());
+        assert_eq!(state.batches, batches);
+        assert!(state.finished);
+        assert!(state.released);
+        assert!(state.error.is_none());
+    }
+
+    #[test]
+    fn test_async_export_error() {
+        let input = vec![
+            Ok(test_batch(vec![1])),
+            Err(ArrowError::ComputeError("failed".to_string())),
+        ];
+        let state = run(input, Consumer::default());
+
+        assert_eq!(state.batches.len(), 1);
+        assert!(!state.finished);
+        assert!(state.released);
+        assert_eq!(
+            state.error,
+            Some((EINVAL, "Compute error: failed".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_async_export_cancel() {
+        let input = (0..5).map(|i| Ok(test_batch(vec![i]))).collect();
+        let consumer = Consumer {
+            cancel_after: Some(2),
+            ..Default::default()
+        };
+        let state = run(input, consumer);
+
+        assert_eq!(state.batches.len(), 2);
+        assert!(!state.finished);
+        assert!(state.released);
+        assert!(state.error.is_none());
+    }
+
+    #[test]
+    fn test_async_export_schema_rejected() {
+        let consumer = Consumer {
+            reject_schema: true,
+            ..Default::default()
+        };
+        let state = run(vec![Ok(test_batch(vec![1]))], consumer);
+
+        assert!(state.batches.is_empty());
+        assert!(state.released);
+    }
+}
@@ -0,0 +1,581 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Contains declarations to bind to the [C Device Data Interface](https://arrow.apache.org/docs/format/CDeviceDataInterface.html).
+//!
+//! The device interface extends the [C Data Interface](crate::ffi) and
+//! [C Stream Interface](crate::ffi_stream) with information about the device
+//! on which the data of an array resides. This is handled by
+//! [FFI_ArrowDeviceArray] and [FFI_ArrowDeviceArrayStream].
+//!
+//! Currently only arrays residing in CPU memory can be imported and exported.
+//!
+//! ```rust
+//! # use arrow::array::{Array, Int32Array};
+//! # use arrow::error::Result;
+//! # use arrow::ffi_device::{from_ffi_device, to_ffi_device, ArrowDeviceType};
+//! # fn main() -> Result<()> {
+//! let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+//!
+//! // Export it
+//! let (out_array, out_schema) = to_ffi_device(&array.to_data())?;
+//! assert_eq!(out_array.device_type(), ArrowDeviceType::CPU);
+//!
+//! // Import it
+//! let data = from_ffi_device(out_array, &out_schema)?;
+//! assert_eq!(Int32Array::from(data), array);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::ffi::{CStr, CString};
+use std::fmt::{Display, Formatter};
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr::addr_of;
+use std::sync::Arc;
+
+use crate::array::{Array, ArrayData, StructArray};
+use crate::datatypes::{Schema, SchemaRef};
+use crate::error::{ArrowError, Result};
+use crate::ffi::{from_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+use crate::record_batch::{RecordBatch, RecordBatchReader};
+
+const ENOMEM: i32 = 12;
+const EIO: i32 = 5;
+const EINVAL: i32 = 22;
+const ENOSYS: i32 = 78;
+
+/// The type of device on which the data of an array resides
+///
+/// This is an open set of values, see
+/// <https://arrow.apache.org/docs/format/CDeviceDataInterface.html#c.ArrowDeviceType>
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ArrowDeviceType(pub i32);
+
+impl ArrowDeviceType {
+    /// CPU device, same as using the C Data Interface without device information
+    pub const CPU: Self = Self(1);
+    /// CUDA GPU device
+    pub const CUDA: Self = Self(2);
+    /// Pinned CUDA CPU memory
+    pub const CUDA_HOST: Self = Self(3);
+    /// OpenCL device
+    pub const OPENCL: Self = Self(4);
+    /// Vulkan buffer for next-gen graphics
+    pub const VULKAN: Self = Self(7);
+    /// Metal for Apple GPU
+    pub const METAL: Self = Self(8);
+    /// Verilog simulator buffer
+    pub const VPI: Self = Self(9);
+    /// ROCm GPUs for AMD GPUs
+    pub const ROCM: Self = Self(10);
+    /// Pinned ROCm CPU memory
+    pub const ROCM_HOST: Self = Self(11);
+    /// Reserved for extension
+    pub const EXT_DEV: Self = Self(12);
+    /// CUDA managed/unified memory
+    pub const CUDA_MANAGED: Self = Self(13);
+    /// Unified shared memory allocated on an Intel oneAPI non-partitioned device
+    pub const ONEAPI: Self = Self(14);
+    /// GPU support for next-gen WebGPU standard
+    pub const WEBGPU: Self = Self(15);
+    /// Qualcomm Hexagon DSP
+    pub const HEXAGON: Self = Self(16);
+}
+
+impl Display for ArrowDeviceType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::CPU => "CPU",
+            Self::CUDA => "CUDA",
+            Self::CUDA_HOST => "CUDA_HOST",
+            Self::OPENCL => "OPENCL",
+            Self::VULKAN => "VULKAN",
+            Self::METAL => "METAL",
+            Self::VPI => "VPI",
+            Self::ROCM => "ROCM",
+            Self::ROCM_HOST => "ROCM_HOST",
+            Self::EXT_DEV => "EXT_DEV",
+            Self::CUDA_MANAGED => "CUDA_MANAGED",
+            Self::ONEAPI => "ONEAPI",
+            Self::WEBGPU => "WEBGPU",
+            Self::HEXAGON => "HEXAGON",
+            Self(other) => return write!(f, "unknown device type {other}"),
+        };
+        f.write_str(name)
+    }
+}
+
+/// ABI-compatible struct for `ArrowDeviceArray` from C Device Data Interface
+/// See <https://arrow.apache.org/docs/format/CDeviceDataInterface.html#structure-definitions>
+///
+/// The contained [FFI_ArrowArray] is released when this struct is dropped
+#[repr(C)]
+#[derive(Debug)]
+pub struct FFI_ArrowDeviceArray {
+    /// The array, whose buffers reside on the device
+    pub array: FFI_ArrowArray,
+    /// The device id, identifying a device if multiple of `device_type` exist
+    pub device_id: i64,
+    /// The type of device on which the buffers of `array` reside
+    pub device_type: ArrowDeviceType,
+    /// An optional event that must be waited on before accessing the data
+    pub sync_event: *mut c_void,
+    /// Reserved bytes for future expansion, must be zeroed
+    pub reserved: [i64; 3],
+}
+
+unsafe impl Send for FFI_ArrowDeviceArray {}
+
+impl FFI_ArrowDeviceArray {
+    /// Creates a new [FFI_ArrowDeviceArray] for `array` residing in CPU memory
+    pub fn new_cpu(array: FFI_ArrowArray) -> Self {
+        Self {
+            array,
+            device_id: -1,
+            device_type: ArrowDeviceType::CPU,
+            sync_event: std::ptr::null_mut(),
+            reserved: [0; 3],
+        }
+    }
+
+    /// Creates a new empty [FFI_ArrowDeviceArray]. Used to import from the C Device Data Interface.
+    pub fn empty() -> Self {
+        Self::new_cpu(FFI_ArrowArray::empty())
+    }
+
+    /// Whether the array has been released
+    pub fn is_released(&self) -> bool {
+        self.array.is_released()
+    }
+
+    /// The type of device on which the data of this array resides
+    pub fn device_type(&self) -> ArrowDeviceType {
+        self.device_type
+    }
+
+    /// The id of the device on which the data of this array resides
+    pub fn device_id(&self) -> i64 {
+        self.device_id
+    }
+
+    /// Returns the contained [FFI_ArrowArray], returning an error if the
+    /// data of the array is not accessible from the CPU
+    pub fn try_into_cpu_array(self) -> Result<FFI_ArrowArray> {
+        if self.device_type != ArrowDeviceType::CPU {
+            return Err(ArrowError::CDataInterface(format!(
+                "Importing arrays residing on device type {} is not supported",
+                self.device_type
+            )));
+        }
+        Ok(self.array)
+    }
+}
+
+/// Export to the C Device Data Interface, with the data residing in CPU memory
+pub fn to_ffi_device(
+    data: &ArrayData,
+) -> Result<(FFI_ArrowDeviceArray, FFI_ArrowSchema)> {
+    let array = FFI_ArrowDeviceArray::new_cpu(FFI_ArrowArray::new(data));
+    let schema = FFI_ArrowSchema::try_from(data.data_type())?;
+    Ok((array, schema))
+}
+
+/// Import [ArrayData] from the C Device Data Interface
+///
+/// Returns an error if the data does not reside in CPU memory
+///
+/// # Safety
+///
+/// This function assumes that the incoming data agrees with the C Device Data Interface.
+pub fn from_ffi_device(
+    array: FFI_ArrowDeviceArray,
+    schema: &FFI_ArrowSchema,
+) -> Result<ArrayData> {
+    from_ffi(array.try_into_cpu_array()?, schema)
+}
+
+/// ABI-compatible struct for `ArrowDeviceArrayStream` from C Device Data Interface
+/// See <https://arrow.apache.org/docs/format/CDeviceDataInterface.html#device-stream-interface>
+#[repr(C)]
+#[derive(Debug)]
+pub struct FFI_ArrowDeviceArrayStream {
+    pub device_type: ArrowDeviceType,
+    pub get_schema: Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_ArrowDeviceArrayStream,
+            out: *mut FFI_ArrowSchema,
+        ) -> c_int,
+    >,
+    pub get_next: Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_ArrowDeviceArrayStream,
+            out: *mut FFI_ArrowDeviceArray,
+        ) -> c_int,
+    >,
+    pub get_last_error: Option<
+        unsafe extern "C" fn(arg1: *mut FFI_ArrowDeviceArrayStream) -> *const c_char,
+    >,
+    pub release: Option<unsafe extern "C" fn(arg1: *mut FFI_ArrowDeviceArrayStream)>,
+    pub private_data: *mut c_void,
+}
+
+unsafe impl Send for FFI_ArrowDeviceArrayStream {}
+
+struct DeviceStreamPrivateData {
+    batch_reader: Box<dyn RecordBatchReader + Send>,
+    last_error: Option<CString>,
+}
+
+impl DeviceStreamPrivateData {
+    fn set_error(&mut self, err: &ArrowError) -> c_int {
+        // Interior nul bytes would truncate the message, so replace them
+        let message = err.to_string().replace('\0', " ");
+        self.last_error = CString::new(message).ok();
+        get_error_code(err)
+    }
+}
+
+// callback used to drop [FFI_ArrowDeviceArrayStream] when it is exported.
+unsafe extern "C" fn release_device_stream(stream: *mut FFI_ArrowDeviceArrayStream) {
+    if stream.is_null() {
+        return;
+    }
+    let stream = &mut *stream;
+
+    stream.get_schema = None;
+    stream.get_next = None;
+    stream.get_last_error = None;
+
+    let private_data = Box::from_raw(stream.private_data as *mut DeviceStreamPrivateData);
+    drop(private_data);
+
+    stream.release = None;
+}
+
+unsafe fn get_private_data<'a>(
+    stream: *mut FFI_ArrowDeviceArrayStream,
+) -> &'a mut DeviceStreamPrivateData {
+    &mut *((*stream).private_data as *mut DeviceStreamPrivateData)
+}
+
+// The callback used to get the stream schema
+unsafe extern "C" fn get_device_schema(
+    stream: *mut FFI_ArrowDeviceArrayStream,
+    out: *mut FFI_ArrowSchema,
+) -> c_int {
+    let private_data = get_private_data(stream);
+    let schema = private_data.batch_reader.schema();
+
+    match FFI_ArrowSchema::try_from(schema.as_ref()) {
+        Ok(schema) => {
+            std::ptr::copy(addr_of!(schema), out, 1);
+            std::mem::forget(schema);
+            0
+        }
+        Err(ref err) => private_data.set_error(err),
+    }
+}
+
+// The callback used to get the next array
+unsafe extern "C" fn get_device_next(
+    stream: *mut FFI_ArrowDeviceArrayStream,
+    out: *mut FFI_ArrowDeviceArray,
+) -> c_int {
+    let private_data = get_private_data(stream);
+
+    match private_data.batch_reader.next() {
+        None => {
+            // Marks ArrowDeviceArray released to indicate reaching the end of stream.
+            std::ptr::write(out, FFI_ArrowDeviceArray::empty());
+            0
+        }
+        Some(Ok(batch)) => {
+            let struct_array = StructArray::from(batch);
+            let array = FFI_ArrowArray::new(&struct_array.to_data());
+            std::ptr::write_unaligned(out, FFI_ArrowDeviceArray::new_cpu(array));
+            0
+        }
+        Some(Err(ref err)) => private_data.set_error(err),
+    }
+}
+
+// The callback used to get the error from the last operation on the stream
+unsafe extern "C" fn get_device_last_error(
+    stream: *mut FFI_ArrowDeviceArrayStream,
+) -> *const c_char {
+    match &get_private_data(stream).last_error {
+        Some(err) => err.as_ptr(),
+        None => std::ptr::null(),
+    }
+}
+
+impl Drop for FFI_ArrowDeviceArrayStream {
+    fn drop(&mut self) {
+        match self.release {
+            None => (),
+            Some(release) => unsafe { release(self) },
+        };
+    }
+}
+
+impl FFI_ArrowDeviceArrayStream {
+    /// Creates a new [FFI_ArrowDeviceArrayStream] yielding the batches of
+    /// `batch_reader`, which reside in CPU memory
+    pub fn new(batch_reader: Box<dyn RecordBatchReader + Send>) -> Self {
+        let private_data = Box::new(DeviceStreamPrivateData {
+            batch_reader,
+            last_error: None,
+        });
+
+        Self {
+            device_type: ArrowDeviceType::CPU,
+            get_schema: Some(get_device_schema),
+            get_next: Some(get_device_next),
+            get_last_error: Some(get_device_last_error),
+            release: Some(release_device_stream),
+            private_data: Box::into_raw(private_data) as *mut c_void,
+        }
+    }
+
+    /// Creates a new empty [FFI_ArrowDeviceArrayStream]. Used to import from the C Device Data Interface.
+    pub fn empty() -> Self {
+        Self {
+            device_type: ArrowDeviceType::CPU,
+            get_schema: None,
+            get_next: None,
+            get_last_error: None,
+            release: None,
+            private_data: std::ptr::null_mut(),
+        }
+    }
+}
+
+fn get_error_code(err: &ArrowError) -> i32 {
+    match err {
+        ArrowError::NotYetImplemented(_) => ENOSYS,
+        ArrowError::MemoryError(_) => ENOMEM,
+        ArrowError::IoError(_) => EIO,
+        _ => EINVAL,
+    }
+}
+
+/// A [RecordBatchReader] which imports arrays from a [FFI_ArrowDeviceArrayStream]
+/// whose data resides in CPU memory
+#[derive(Debug)]
+pub struct ArrowDeviceArrayStreamReader {
+    stream: FFI_ArrowDeviceArrayStream,
+    schema: SchemaRef,
+}
+
+impl ArrowDeviceArrayStreamReader {
+    /// Creates a new [ArrowDeviceArrayStreamReader] from a [FFI_ArrowDeviceArrayStream]
+    ///
+    /// Returns an error if the stream does not yield arrays residing in CPU memory
+    pub fn try_new(mut stream: FFI_ArrowDeviceArrayStream) -> Result<Self> {
+        if stream.release.is_none() {
+            return Err(ArrowError::CDataInterface(
+                "input stream is already released".to_string(),
+            ));
+        }
+
+        if stream.device_type != ArrowDeviceType::CPU {
+            return Err(ArrowError::CDataInterface(format!(
+                "Importing streams of device type {} is not supported",
+                stream.device_type
+            )));
+        }
+
+        let mut schema = FFI_ArrowSchema::empty();
+        let get_schema = stream.get_schema.ok_or_else(|| {
+            ArrowError::CDataInterface("input stream has no get_schema".to_string())
+        })?;
+        let ret_code = unsafe { get_schema(&mut stream, &mut schema) };
+        if ret_code != 0 {
+            let message = Self::last_error(&mut stream);
+            return Err(ArrowError::CDataInterface(format!(
+                "Cannot get schema from input stream. Error code: {ret_code}: {message}"
+            )));
+        }
+        let schema = Arc::new(Schema::try_from(&schema)?);
+
+        Ok(Self { stream, schema })
+    }
+
+    /// Creates a new [ArrowDeviceArrayStreamReader] from a raw pointer of [FFI_ArrowDeviceArrayStream]
+    ///
+    /// This function moves the content from the raw pointer, marking it as released
+    ///
+    /// # Safety
+    /// This function dereferences a raw pointer of [FFI_ArrowDeviceArrayStream].
+    pub unsafe fn from_raw(raw_stream: *mut FFI_ArrowDeviceArrayStream) -> Result<Self> {
+        let stream = std::ptr::replace(raw_stream, FFI_ArrowDeviceArrayStream::empty());
+        Self::try_new(stream)
+    }
+
+    fn last_error(stream: &mut FFI_ArrowDeviceArrayStream) -> String {
+        let get_last_error = match stream.get_last_error {
+            Some(get_last_error) => get_last_error,
+            None => return String::new(),
+        };
+        unsafe {
+            let ptr = get_last_error(stream);
+            match ptr.is_null() {
+                true => String::new(),
+                false => CStr::from_ptr(ptr).to_string_lossy().into_owned(),
+            }
+        }
+    }
+
+    fn next_batch(&mut self) -> Result<Option<RecordBatch>> {
+        let get_next = self.stream.get_next.ok_or_else(|| {
+            ArrowError::CDataInterface("input stream has no get_next".to_string())
+        })?;
+
+        let mut array = FFI_ArrowDeviceArray::empty();
+        let ret_code = unsafe { get_next(&mut self.stream, &mut array) };
+        if ret_code != 0 {
+            let message = Self::last_error(&mut self.stream);
+            return Err(ArrowError::CDataInterface(format!(
+                "Cannot get next array from input stream. Error code: {ret_code}: {message}"
+            )));
+        }
+
+        // The end of stream has been reached
+        if array.is_released() {
+            return Ok(None);
+        }
+
+        let schema = FFI_ArrowSchema::try_from(self.schema.as_ref())?;
+        let data = from_ffi_device(array, &schema)?;
+        Ok(Some(RecordBatch::from(StructArray::from(data))))
+    }
+}
+
+impl Iterator for ArrowDeviceArrayStreamReader {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_batch().transpose()
+    }
+}
+
+impl RecordBatchReader for ArrowDeviceArrayStreamReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+/// Exports a record batch reader to raw pointer of the C Device Stream Interface provided by the consumer.
+///
+/// # Safety
+/// Assumes that the pointer represents valid C Device Stream Interfaces, both in memory
+/// representation and lifetime via the `release` mechanism.
+pub unsafe fn export_reader_into_raw_device(
+    reader: Box<dyn RecordBatchReader + Send>,
+    out_stream: *mut FFI_ArrowDeviceArrayStream,
+) {
+    let stream = FFI_ArrowDeviceArrayStream::new(reader);
+    std::ptr::write_unaligned(out_stream, stream);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::array::{Int32Array, StringArray};
+    use crate::datatypes::{DataType, Field};
+    use arrow_array::RecordBatchIterator;
+
+    #[test]
+    fn test_device_array_round_trip() {
+        let array = StringArray::from(vec![Some("a"), None, Some("c")]);
+        let (ffi_array, ffi_schema) = to_ffi_device(&array.to_data()).unwrap();
+        assert_eq!(ffi_array.device_type(), ArrowDeviceType::CPU);
+        assert_eq!(ffi_array.device_id(), -1);
+        assert!(ffi_array.sync_event.is_null());
+
+        let data = from_ffi_device(ffi_array, &ffi_schema).unwrap();
+        assert_eq!(StringArray::from(data), array);
+    }
+
+    #[test]
+    fn test_device_array_unsupported_device() {
+        let array = Int32Array::from(vec![1, 2, 3]);
+        let (mut ffi_array, ffi_schema) = to_ffi_device(&array.to_data()).unwrap();
+        ffi_array.device_type = ArrowDeviceType::CUDA;
+        ffi_array.device_id = 0;
+
+        let err = from_ffi_device(ffi_array, &ffi_schema).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "C Data interface error: Importing arrays residing on device type CUDA is not supported"
+        );
+        assert_eq!(ArrowDeviceType(100).to_string(), "unknown device type 100");
+    }
+
+    #[test]
+    fn test_device_stream_round_trip() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let array = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![array]).unwrap();
+        let batches = vec![Ok(batch.clone()), Ok(batch.clone())];
+        let reader = RecordBatchIterator::new(batches, schema.clone());
+
+        let mut ffi_stream = FFI_ArrowDeviceArrayStream::empty();
+        unsafe { export_reader_into_raw_device(Box::new(reader), &mut ffi_stream) };
+        assert_eq!(ffi_stream.device_type, ArrowDeviceType::CPU);
+
+        let reader =
+            unsafe { ArrowDeviceArrayStreamReader::from_raw(&mut ffi_stream) }.unwrap();
+        assert!(ffi_stream.release.is_none());
+        assert_eq!(reader.schema(), schema);
+
+        let produced = reader.collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(produced, vec![batch.clone(), batch]);
+    }
+
+    #[test]
+    fn test_device_stream_error() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let batches = vec![Err(ArrowError::ComputeError("\0failed".to_string()))];
+        let reader = RecordBatchIterator::new(batches, schema);
+
+        let stream = FFI_ArrowDeviceArrayStream::new(Box::new(reader));
+        let mut reader = ArrowDeviceArrayStreamReader::try_new(stream).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "C Data interface error: Cannot get next array from input stream. Error code: 22: Compute error:  failed"
+        );
+    }
+
+    #[test]
+    fn test_device_stream_unsupported_device() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let reader = RecordBatchIterator::new(vec![], schema);
+
+        let mut stream = FFI_ArrowDeviceArrayStream::new(Box::new(reader));
+        stream.device_type = ArrowDeviceType::METAL;
+        let err = ArrowDeviceArrayStreamReader::try_new(stream).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "C Data interface error: Importing streams of device type METAL is not supported"
+        );
+    }
+}
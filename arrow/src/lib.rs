@@ -297,13 +297,16 @@
 //!
 //! * [`arrow-arith`][arrow_arith] - arithmetic kernels
 //! * [`arrow-array`][arrow_array] - type-safe arrow array abstractions
+//! * [`arrow-avro`][arrow_avro] - read/write Avro to arrow format
 //! * [`arrow-buffer`][arrow_buffer] - buffer abstractions for arrow arrays
 //! * [`arrow-cast`][arrow_cast] - cast kernels for arrow arrays
 //! * [`arrow-csv`][arrow_csv] - read/write CSV to arrow format
 //! * [`arrow-data`][arrow_data] - the underlying data of arrow arrays
 //! * [`arrow-ipc`][arrow_ipc] - read/write IPC to arrow format
 //! * [`arrow-json`][arrow_json] - read/write JSON to arrow format
+//! * [`arrow-orc`][arrow_orc] - read ORC to arrow format
 //! * [`arrow-ord`][arrow_ord] - ordering kernels for arrow arrays
+//! * [`arrow-protobuf`][arrow_protobuf] - read/write length-delimited protobuf to arrow format
 //! * [`arrow-row`][arrow_row] - comparable row format
 //! * [`arrow-schema`][arrow_schema] - the logical types for arrow arrays
 //! * [`arrow-select`][arrow_select] - selection kernels for arrow arrays
@@ -357,6 +360,8 @@ pub use arrow_array::{downcast_dictionary_array, downcast_primitive_array};
 pub use arrow_buffer::{alloc, buffer};
 
 pub mod array;
+#[cfg(feature = "avro")]
+pub use arrow_avro as avro;
 pub mod compute;
 #[cfg(feature = "csv")]
 pub use arrow_csv as csv;
@@ -365,11 +370,19 @@ pub mod error;
 #[cfg(feature = "ffi")]
 pub mod ffi;
 #[cfg(feature = "ffi")]
+pub mod ffi_async;
+#[cfg(feature = "ffi")]
+pub mod ffi_device;
+#[cfg(feature = "ffi")]
 pub mod ffi_stream;
 #[cfg(feature = "ipc")]
 pub use arrow_ipc as ipc;
 #[cfg(feature = "json")]
 pub use arrow_json as json;
+#[cfg(feature = "orc")]
+pub use arrow_orc as orc;
+#[cfg(feature = "protobuf")]
+pub use arrow_protobuf as protobuf;
 #[cfg(feature = "pyarrow")]
 pub mod pyarrow;
 
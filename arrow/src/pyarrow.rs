@@ -19,6 +19,20 @@
 //! [C Data Interface](https://arrow.apache.org/docs/format/CDataInterface.html)
 //! and [pyo3](https://docs.rs/pyo3/latest/pyo3/).
 //! For underlying implementation, see the [ffi] module.
+//!
+//! Streams of record batches can be passed without materializing them, as a
+//! `pyarrow.RecordBatchReader` is converted to and from an
+//! [`ArrowArrayStreamReader`] or any boxed [`RecordBatchReader`]. Likewise, the
+//! chunks of a `pyarrow.Table` are converted to the [`RecordBatch`]es of a
+//! [`Table`] without copying or concatenating them.
+//!
+//! Python objects are imported using the [Arrow PyCapsule Interface] if they
+//! implement it, i.e. define `__arrow_c_schema__`, `__arrow_c_array__` or
+//! `__arrow_c_stream__`, allowing objects from libraries other than pyarrow,
+//! such as polars, to be converted without requiring pyarrow. Exporting
+//! objects to Python still creates pyarrow objects.
+//!
+//! [Arrow PyCapsule Interface]: https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html
 
 use std::convert::{From, TryFrom};
 use std::ptr::{addr_of, addr_of_mut};
@@ -28,17 +42,19 @@ use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::ffi::Py_uintptr_t;
 use pyo3::import_exception;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyTuple};
+use pyo3::types::{PyCapsule, PyDict, PyList, PyTuple};
 
-use crate::array::{make_array, Array, ArrayData};
-use crate::datatypes::{DataType, Field, Schema};
+use arrow_array::RecordBatchIterator;
+
+use crate::array::{make_array, Array, ArrayData, StructArray};
+use crate::datatypes::{DataType, Field, Schema, SchemaRef};
 use crate::error::ArrowError;
 use crate::ffi;
 use crate::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
 use crate::ffi_stream::{
     export_reader_into_raw, ArrowArrayStreamReader, FFI_ArrowArrayStream,
 };
-use crate::record_batch::RecordBatch;
+use crate::record_batch::{RecordBatch, RecordBatchOptions, RecordBatchReader};
 
 import_exception!(pyarrow, ArrowException);
 pub type PyArrowException = ArrowException;
@@ -84,8 +100,82 @@ fn validate_class(expected: &str, value: &PyAny) -> PyResult<()> {
     Ok(())
 }
 
+/// Checks `capsule` has the name `name`, as required by the
+/// [Arrow PyCapsule Interface](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html)
+fn validate_pycapsule(capsule: &PyCapsule, name: &str) -> PyResult<()> {
+    let capsule_name = match capsule.name()? {
+        Some(capsule_name) => capsule_name.to_string_lossy(),
+        None => {
+            return Err(PyValueError::new_err(format!(
+                "Expected PyCapsule to have name '{name}'"
+            )))
+        }
+    };
+    if capsule_name != name {
+        return Err(PyValueError::new_err(format!(
+            "Expected name '{name}' in PyCapsule, instead got '{capsule_name}'"
+        )));
+    }
+    Ok(())
+}
+
+/// Imports the schema of an object implementing `__arrow_c_schema__`
+fn schema_from_pycapsule<T>(value: &PyAny) -> PyResult<T>
+where
+    T: for<'a> TryFrom<&'a FFI_ArrowSchema, Error = ArrowError>,
+{
+    let capsule = value.call_method0("__arrow_c_schema__")?;
+    let capsule = capsule.downcast::<PyCapsule>()?;
+    validate_pycapsule(capsule, "arrow_schema")?;
+
+    // The schema is released by the capsule's destructor
+    let schema = unsafe { &*(capsule.pointer() as *const FFI_ArrowSchema) };
+    T::try_from(schema).map_err(to_py_err)
+}
+
+/// Imports an object implementing `__arrow_c_array__`, returning the array
+/// and the schema it was exported with
+fn array_from_pycapsule(value: &PyAny) -> PyResult<(ArrayData, Schema)> {
+    let tuple = value.call_method0("__arrow_c_array__")?;
+    let tuple = tuple.downcast::<PyTuple>()?;
+    if tuple.len() != 2 {
+        return Err(PyTypeError::new_err(
+            "Expected __arrow_c_array__ to return a tuple of (schema, array) capsules",
+        ));
+    }
+
+    let schema_capsule = tuple.get_item(0)?.downcast::<PyCapsule>()?;
+    let array_capsule = tuple.get_item(1)?.downcast::<PyCapsule>()?;
+    validate_pycapsule(schema_capsule, "arrow_schema")?;
+    validate_pycapsule(array_capsule, "arrow_array")?;
+
+    let schema = unsafe { &*(schema_capsule.pointer() as *const FFI_ArrowSchema) };
+    // Move the array out of the capsule, leaving a released array behind
+    // so that the capsule's destructor does not release it
+    let array = unsafe {
+        std::ptr::replace(
+            array_capsule.pointer() as *mut FFI_ArrowArray,
+            FFI_ArrowArray::empty(),
+        )
+    };
+
+    let data = ffi::from_ffi(array, schema).map_err(to_py_err)?;
+    // Top-level arrays may not be structs, so only the field metadata can
+    // be retained for them
+    let schema = match data.data_type() {
+        DataType::Struct(_) => Schema::try_from(schema),
+        _ => Field::try_from(schema).map(|f| Schema::new(vec![f])),
+    }
+    .map_err(to_py_err)?;
+    Ok((data, schema))
+}
+
 impl FromPyArrow for DataType {
     fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        if value.hasattr("__arrow_c_schema__")? {
+            return schema_from_pycapsule(value);
+        }
+
         validate_class("DataType", value)?;
 
         let c_schema = FFI_ArrowSchema::empty();
@@ -110,6 +200,10 @@ impl ToPyArrow for DataType {
 
 impl FromPyArrow for Field {
     fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        if value.hasattr("__arrow_c_schema__")? {
+            return schema_from_pycapsule(value);
+        }
+
         validate_class("Field", value)?;
 
         let c_schema = FFI_ArrowSchema::empty();
@@ -134,6 +228,10 @@ impl ToPyArrow for Field {
 
 impl FromPyArrow for Schema {
     fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        if value.hasattr("__arrow_c_schema__")? {
+            return schema_from_pycapsule(value);
+        }
+
         validate_class("Schema", value)?;
 
         let c_schema = FFI_ArrowSchema::empty();
@@ -158,6 +256,11 @@ impl ToPyArrow for Schema {
 
 impl FromPyArrow for ArrayData {
     fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        if value.hasattr("__arrow_c_array__")? {
+            let (data, _) = array_from_pycapsule(value)?;
+            return Ok(data);
+        }
+
         validate_class("Array", value)?;
 
         // prepare a pointer to receive the Array struct
@@ -216,6 +319,33 @@ impl<T: ToPyArrow> ToPyArrow for Vec<T> {
 
 impl FromPyArrow for RecordBatch {
     fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        if value.hasattr("__arrow_c_array__")? {
+            let (data, schema) = array_from_pycapsule(value)?;
+            if !matches!(data.data_type(), DataType::Struct(_)) {
+                return Err(PyValueError::new_err(format!(
+                    "Expected a struct array to import as a RecordBatch, got {}",
+                    data.data_type()
+                )));
+            }
+
+            let array = StructArray::from(data);
+            if array.null_count() != 0 {
+                return Err(PyValueError::new_err(
+                    "Cannot import a struct array containing nulls as a RecordBatch",
+                ));
+            }
+
+            let row_count = array.len();
+            let (_, columns, _) = array.into_parts();
+            let options = RecordBatchOptions::new().with_row_count(Some(row_count));
+            return RecordBatch::try_new_with_options(
+                Arc::new(schema),
+                columns,
+                &options,
+            )
+            .map_err(to_py_err);
+        }
+
         validate_class("RecordBatch", value)?;
         // TODO(kszucs): implement the FFI conversions in arrow-rs for RecordBatches
         let schema = value.getattr("schema")?;
@@ -258,6 +388,19 @@ impl ToPyArrow for RecordBatch {
 
 impl FromPyArrow for ArrowArrayStreamReader {
     fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        if value.hasattr("__arrow_c_stream__")? {
+            let capsule = value.call_method0("__arrow_c_stream__")?;
+            let capsule = capsule.downcast::<PyCapsule>()?;
+            validate_pycapsule(capsule, "arrow_array_stream")?;
+
+            // Moves the stream out of the capsule, leaving a released stream
+            // behind so that the capsule's destructor does not release it
+            let stream = capsule.pointer() as *mut FFI_ArrowArrayStream;
+            let stream_reader = unsafe { ArrowArrayStreamReader::from_raw(stream) }
+                .map_err(|err| PyValueError::new_err(err.to_string()))?;
+            return Ok(stream_reader);
+        }
+
         validate_class("RecordBatchReader", value)?;
 
         // prepare a pointer to receive the stream struct
@@ -278,9 +421,26 @@ impl FromPyArrow for ArrowArrayStreamReader {
 }
 
 impl IntoPyArrow for ArrowArrayStreamReader {
+    fn into_pyarrow(self, py: Python) -> PyResult<PyObject> {
+        let reader: Box<dyn RecordBatchReader + Send> = Box::new(self);
+        reader.into_pyarrow(py)
+    }
+}
+
+impl FromPyArrow for Box<dyn RecordBatchReader + Send> {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        let stream_reader = ArrowArrayStreamReader::from_pyarrow(value)?;
+        Ok(Box::new(stream_reader))
+    }
+}
+
+/// Export a [`RecordBatchReader`] as a `pyarrow.RecordBatchReader`
+///
+/// Record batches are read from `self` as they are consumed by Python
+impl IntoPyArrow for Box<dyn RecordBatchReader + Send> {
     fn into_pyarrow(self, py: Python) -> PyResult<PyObject> {
         let mut stream = FFI_ArrowArrayStream::empty();
-        unsafe { export_reader_into_raw(Box::new(self), &mut stream) };
+        unsafe { export_reader_into_raw(self, &mut stream) };
 
         let stream_ptr = (&mut stream) as *mut FFI_ArrowArrayStream;
         let module = py.import("pyarrow")?;
@@ -292,6 +452,87 @@ impl IntoPyArrow for ArrowArrayStreamReader {
     }
 }
 
+/// A collection of [`RecordBatch`] with a common schema, corresponding
+/// to a `pyarrow.Table`
+///
+/// Each record batch corresponds to a chunk of the columns of the table,
+/// and is converted without copying or concatenating the chunks.
+#[derive(Debug, Clone)]
+pub struct Table {
+    record_batches: Vec<RecordBatch>,
+    schema: SchemaRef,
+}
+
+impl Table {
+    /// Create a new [`Table`] from `record_batches`, returning an error if
+    /// the schema of any record batch does not match `schema`
+    pub fn try_new(
+        record_batches: Vec<RecordBatch>,
+        schema: SchemaRef,
+    ) -> Result<Self, ArrowError> {
+        for batch in &record_batches {
+            if batch.schema() != schema {
+                return Err(ArrowError::SchemaError(format!(
+                    "All record batches must have the schema of the table, expected {schema:?} got {:?}",
+                    batch.schema()
+                )));
+            }
+        }
+        Ok(Self {
+            record_batches,
+            schema,
+        })
+    }
+
+    /// Returns the record batches of this table
+    pub fn record_batches(&self) -> &[RecordBatch] {
+        &self.record_batches
+    }
+
+    /// Returns the schema of this table
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Returns the record batches and schema of this table
+    pub fn into_inner(self) -> (Vec<RecordBatch>, SchemaRef) {
+        (self.record_batches, self.schema)
+    }
+}
+
+/// Objects implementing `__arrow_c_stream__` are imported by reading all of
+/// their record batches
+impl FromPyArrow for Table {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        let reader = match value.hasattr("__arrow_c_stream__")? {
+            true => ArrowArrayStreamReader::from_pyarrow(value)?,
+            false => {
+                validate_class("Table", value)?;
+                // `to_reader` yields the chunks of the table without copying them
+                let reader = value.call_method0("to_reader")?;
+                ArrowArrayStreamReader::from_pyarrow(reader)?
+            }
+        };
+        let schema = reader.schema();
+        let record_batches = reader.collect::<Result<Vec<_>, _>>().map_err(to_py_err)?;
+
+        Table::try_new(record_batches, schema).map_err(to_py_err)
+    }
+}
+
+impl IntoPyArrow for Table {
+    fn into_pyarrow(self, py: Python) -> PyResult<PyObject> {
+        let (record_batches, schema) = self.into_inner();
+        let reader: Box<dyn RecordBatchReader + Send> = Box::new(
+            RecordBatchIterator::new(record_batches.into_iter().map(Ok), schema),
+        );
+
+        let reader = reader.into_pyarrow(py)?;
+        let table = reader.call_method0(py, "read_all")?;
+        Ok(table)
+    }
+}
+
 /// A newtype wrapper around a `T: PyArrowConvert` that implements
 /// [`FromPyObject`] and [`IntoPy`] allowing usage with pyo3 macros
 #[derive(Debug)]
@@ -31,8 +31,13 @@ fn compute_row_major_strides<T: ArrowPrimitiveType>(
     shape: &[usize],
 ) -> Result<Vec<usize>> {
     let mut remaining_bytes = mem::size_of::<T::Native>();
+    let mut strides = Vec::<usize>::new();
+
+    // Computed from the innermost dimension, which avoids dividing by
+    // zero-length dimensions
+    for i in shape.iter().rev() {
+        strides.push(remaining_bytes);
 
-    for i in shape {
         if let Some(val) = remaining_bytes.checked_mul(*i) {
             remaining_bytes = val;
         } else {
@@ -42,12 +47,7 @@ fn compute_row_major_strides<T: ArrowPrimitiveType>(
         }
     }
 
-    let mut strides = Vec::<usize>::new();
-    for i in shape {
-        remaining_bytes /= *i;
-        strides.push(remaining_bytes);
-    }
-
+    strides.reverse();
     Ok(strides)
 }
 
@@ -73,6 +73,28 @@ fn compute_column_major_strides<T: ArrowPrimitiveType>(
     Ok(strides)
 }
 
+/// Returns the number of bytes spanned by a tensor with the given `shape` and `strides`
+fn compute_span<T: ArrowPrimitiveType>(shape: &[usize], strides: &[usize]) -> usize {
+    if shape.contains(&0) {
+        return 0;
+    }
+    let last: usize = shape.iter().zip(strides).map(|(d, s)| (d - 1) * s).sum();
+    last + mem::size_of::<T::Native>()
+}
+
+/// Returns the byte offsets of the elements of a tensor with the given `shape` and
+/// `strides`, in row major logical order
+fn compute_logical_offsets(shape: &[usize], strides: &[usize]) -> Vec<usize> {
+    let mut offsets = vec![0];
+    for (dim, stride) in shape.iter().zip(strides) {
+        offsets = offsets
+            .iter()
+            .flat_map(|o| (0..*dim).map(move |i| o + i * stride))
+            .collect();
+    }
+    offsets
+}
+
 /// Tensor of primitive types
 #[derive(Debug)]
 pub struct Tensor<'a, T: ArrowPrimitiveType> {
@@ -311,6 +333,189 @@ impl<'a, T: ArrowPrimitiveType> Tensor<'a, T> {
             }
         }
     }
+
+    /// Creates a view of `buffer` with the provided `shape` and `strides`, slicing
+    /// `buffer` to the bytes spanned by the view starting at `offset`
+    fn new_view(
+        &self,
+        offset: usize,
+        shape: Vec<usize>,
+        strides: Vec<usize>,
+        names: Option<Vec<&'a str>>,
+    ) -> Self {
+        let span = compute_span::<T>(&shape, &strides);
+        let buffer = match span {
+            0 => self.buffer.slice_with_length(0, 0),
+            _ => self.buffer.slice_with_length(offset, span),
+        };
+        Self {
+            data_type: T::DATA_TYPE,
+            buffer,
+            shape: Some(shape),
+            strides: Some(strides),
+            names,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the shape and strides of this tensor, or an error if it has no shape
+    fn shape_and_strides(&self) -> Result<(&[usize], &[usize])> {
+        match (&self.shape, &self.strides) {
+            (Some(shape), Some(strides)) => Ok((shape, strides)),
+            _ => Err(ArrowError::InvalidArgumentError(
+                "operation requires a tensor with a shape".to_string(),
+            )),
+        }
+    }
+
+    /// Returns the value at the provided `index`, or `None` if `index` is out of bounds
+    ///
+    /// A tensor with no shape contains a single value at index `[]`
+    pub fn value(&self, index: &[usize]) -> Option<T::Native> {
+        let offset = match (&self.shape, &self.strides) {
+            (Some(shape), Some(strides)) => {
+                if index.len() != shape.len()
+                    || index.iter().zip(shape).any(|(i, d)| i >= d)
+                {
+                    return None;
+                }
+                index.iter().zip(strides).map(|(i, s)| i * s).sum()
+            }
+            _ if index.is_empty() => 0,
+            _ => return None,
+        };
+        let values = self.buffer.typed_data::<T::Native>();
+        Some(values[offset / mem::size_of::<T::Native>()])
+    }
+
+    /// Returns a zero-copy view of `length` elements of dimension `axis`,
+    /// starting at `offset`
+    ///
+    /// The returned tensor shares the underlying [`Buffer`] of this tensor, and
+    /// may therefore not be contiguous
+    pub fn slice(&self, axis: usize, offset: usize, length: usize) -> Result<Self> {
+        let (shape, strides) = self.shape_and_strides()?;
+        if axis >= shape.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "axis {axis} out of bounds for tensor with {} dimensions",
+                shape.len()
+            )));
+        }
+        if offset.saturating_add(length) > shape[axis] {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "slice of length {length} at offset {offset} out of bounds for dimension of size {}",
+                shape[axis]
+            )));
+        }
+        let mut new_shape = shape.to_vec();
+        new_shape[axis] = length;
+        let byte_offset = offset * strides[axis];
+        Ok(self.new_view(byte_offset, new_shape, strides.to_vec(), self.names.clone()))
+    }
+
+    /// Returns a zero-copy view of this tensor with its dimensions reordered,
+    /// such that dimension `i` of the returned tensor is dimension `axes[i]` of
+    /// this tensor
+    pub fn permute(&self, axes: &[usize]) -> Result<Self> {
+        let (shape, strides) = self.shape_and_strides()?;
+        let mut seen = vec![false; shape.len()];
+        let valid = axes.len() == shape.len()
+            && axes
+                .iter()
+                .all(|a| *a < seen.len() && !std::mem::replace(&mut seen[*a], true));
+        if !valid {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "{axes:?} is not a permutation of the {} dimensions of the tensor",
+                shape.len()
+            )));
+        }
+        let new_shape = axes.iter().map(|a| shape[*a]).collect();
+        let new_strides = axes.iter().map(|a| strides[*a]).collect();
+        let names = self
+            .names
+            .as_ref()
+            .map(|n| axes.iter().map(|a| n[*a]).collect());
+        Ok(self.new_view(0, new_shape, new_strides, names))
+    }
+
+    /// Returns a zero-copy view of this tensor with the order of its dimensions reversed
+    pub fn transpose(&self) -> Result<Self> {
+        let axes: Vec<_> = (0..self.ndim()).rev().collect();
+        self.permute(&axes)
+    }
+
+    /// Returns a copy of this tensor with a row major memory layout
+    pub fn to_row_major(&self) -> Result<Self> {
+        let (shape, strides) = self.shape_and_strides()?;
+        let buffer = self.gather(&compute_logical_offsets(shape, strides));
+        Self::new_row_major(buffer, Some(shape.to_vec()), self.names.clone())
+    }
+
+    /// Returns a copy of this tensor with a column major memory layout
+    pub fn to_column_major(&self) -> Result<Self> {
+        let (shape, strides) = self.shape_and_strides()?;
+        let rev_shape: Vec<_> = shape.iter().rev().copied().collect();
+        let rev_strides: Vec<_> = strides.iter().rev().copied().collect();
+        let buffer = self.gather(&compute_logical_offsets(&rev_shape, &rev_strides));
+        Self::new_column_major(buffer, Some(shape.to_vec()), self.names.clone())
+    }
+
+    /// Copies the values at the provided byte `offsets` into a new [`Buffer`]
+    fn gather(&self, offsets: &[usize]) -> Buffer {
+        let values = self.buffer.typed_data::<T::Native>();
+        let size = mem::size_of::<T::Native>();
+        let iter = offsets.iter().map(|o| values[o / size]);
+        Buffer::from_vec(iter.collect::<Vec<_>>())
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<'a, T: ArrowPrimitiveType> Tensor<'a, T> {
+    /// Returns a zero-copy [`ndarray::ArrayViewD`] of this tensor
+    pub fn to_ndarray(&self) -> Result<ndarray::ArrayViewD<'_, T::Native>> {
+        use ndarray::ShapeBuilder;
+
+        let size = mem::size_of::<T::Native>();
+        let (shape, strides) = match (&self.shape, &self.strides) {
+            (Some(shape), Some(strides)) => {
+                (shape.clone(), strides.iter().map(|s| s / size).collect())
+            }
+            _ => (vec![], vec![]),
+        };
+        let values = self.buffer.typed_data::<T::Native>();
+        let dim = ndarray::IxDyn(&shape).strides(ndarray::IxDyn(&strides));
+        ndarray::ArrayViewD::from_shape(dim, values)
+            .map_err(|e| ArrowError::InvalidArgumentError(e.to_string()))
+    }
+
+    /// Creates a new tensor from an [`ndarray::ArrayD`]
+    ///
+    /// This is zero-copy if `array` is contiguous in row major or column major
+    /// layout, otherwise the values are copied into a new row major tensor
+    pub fn from_ndarray(array: ndarray::ArrayD<T::Native>) -> Result<Self> {
+        let shape = array.shape().to_vec();
+        let row_major = array.is_standard_layout();
+        let column_major = !row_major && array.t().is_standard_layout();
+
+        if !row_major && !column_major {
+            let buffer = Buffer::from_vec(array.iter().copied().collect::<Vec<_>>());
+            return Self::new_row_major(buffer, Some(shape), None);
+        }
+
+        // An owned array may be a view into a larger allocation
+        let size = mem::size_of::<T::Native>();
+        let (ptr, len) = (array.as_ptr(), array.len());
+        let vec = array.into_raw_vec();
+        let offset = (ptr as usize - vec.as_ptr() as usize) / size;
+        let buffer = match offset == 0 && vec.len() == len {
+            true => Buffer::from_vec(vec),
+            false => Buffer::from_slice_ref(&vec[offset..offset + len]),
+        };
+        match row_major {
+            true => Self::new_row_major(buffer, Some(shape), None),
+            false => Self::new_column_major(buffer, Some(shape), None),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -512,4 +717,144 @@ mod tests {
             panic!("the input stride does not match the selected shape")
         }
     }
+
+    fn int32_tensor(shape: Vec<usize>) -> Int32Tensor<'static> {
+        let len: usize = shape.iter().product();
+        let buf = Buffer::from_vec((0..len as i32).collect::<Vec<_>>());
+        Int32Tensor::new_row_major(buf, Some(shape), None).unwrap()
+    }
+
+    fn values(tensor: &Int32Tensor) -> Vec<i32> {
+        let row_major = tensor.to_row_major().unwrap();
+        row_major.data().typed_data::<i32>().to_vec()
+    }
+
+    #[test]
+    fn test_value() {
+        let tensor = int32_tensor(vec![2, 3]);
+        assert_eq!(tensor.value(&[0, 0]), Some(0));
+        assert_eq!(tensor.value(&[1, 2]), Some(5));
+        assert_eq!(tensor.value(&[2, 0]), None);
+        assert_eq!(tensor.value(&[0]), None);
+
+        let tensor =
+            Int32Tensor::try_new(Buffer::from_vec(vec![7]), None, None, None).unwrap();
+        assert_eq!(tensor.value(&[]), Some(7));
+    }
+
+    #[test]
+    fn test_slice() {
+        let tensor = int32_tensor(vec![3, 4]);
+
+        let sliced = tensor.slice(0, 1, 2).unwrap();
+        assert_eq!(sliced.shape(), Some(&vec![2, 4]));
+        assert!(sliced.is_row_major().unwrap());
+        assert_eq!(values(&sliced), vec![4, 5, 6, 7, 8, 9, 10, 11]);
+
+        let sliced = tensor.slice(1, 1, 2).unwrap();
+        assert_eq!(sliced.shape(), Some(&vec![3, 2]));
+        assert_eq!(sliced.strides(), Some(&vec![16, 4]));
+        assert!(!sliced.is_contiguous().unwrap());
+        assert_eq!(values(&sliced), vec![1, 2, 5, 6, 9, 10]);
+
+        // Slices of slices
+        let sliced = sliced.slice(0, 2, 1).unwrap();
+        assert_eq!(values(&sliced), vec![9, 10]);
+
+        let empty = tensor.slice(1, 4, 0).unwrap();
+        assert_eq!(empty.size(), 0);
+        assert!(values(&empty).is_empty());
+
+        let err = tensor.slice(1, 3, 2).unwrap_err().to_string();
+        assert!(
+            err.contains("out of bounds for dimension of size 4"),
+            "{err}"
+        );
+        let err = tensor.slice(2, 0, 1).unwrap_err().to_string();
+        assert!(err.contains("axis 2 out of bounds"), "{err}");
+    }
+
+    #[test]
+    fn test_permute() {
+        let buf = Buffer::from_vec((0..24).collect::<Vec<i32>>());
+        let names = Some(vec!["a", "b", "c"]);
+        let tensor = Int32Tensor::new_row_major(buf, Some(vec![2, 3, 4]), names).unwrap();
+
+        let permuted = tensor.permute(&[2, 0, 1]).unwrap();
+        assert_eq!(permuted.shape(), Some(&vec![4, 2, 3]));
+        assert_eq!(permuted.strides(), Some(&vec![4, 48, 16]));
+        assert_eq!(permuted.names(), Some(&vec!["c", "a", "b"]));
+        assert_eq!(permuted.value(&[3, 1, 2]), tensor.value(&[1, 2, 3]));
+
+        let transposed = tensor.transpose().unwrap();
+        assert_eq!(transposed.shape(), Some(&vec![4, 3, 2]));
+        assert!(transposed.is_column_major().unwrap());
+
+        for axes in [&[0, 1][..], &[0, 0, 1], &[0, 1, 3]] {
+            let err = tensor.permute(axes).unwrap_err().to_string();
+            assert!(err.contains("is not a permutation"), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_layout_conversion() {
+        let tensor = int32_tensor(vec![2, 3]);
+
+        let column_major = tensor.to_column_major().unwrap();
+        assert!(column_major.is_column_major().unwrap());
+        assert_eq!(column_major.data().typed_data::<i32>(), &[0, 3, 1, 4, 2, 5]);
+        assert_eq!(column_major.value(&[1, 2]), Some(5));
+
+        let row_major = column_major.to_row_major().unwrap();
+        assert!(row_major.is_row_major().unwrap());
+        assert_eq!(row_major.data().typed_data::<i32>(), &[0, 1, 2, 3, 4, 5]);
+
+        let transposed = tensor.transpose().unwrap().to_row_major().unwrap();
+        assert_eq!(transposed.shape(), Some(&vec![3, 2]));
+        assert_eq!(transposed.data().typed_data::<i32>(), &[0, 3, 1, 4, 2, 5]);
+
+        let scalar =
+            Int32Tensor::try_new(Buffer::from_vec(vec![7]), None, None, None).unwrap();
+        assert!(scalar.to_row_major().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "ndarray")]
+    fn test_ndarray() {
+        let tensor = int32_tensor(vec![2, 3]);
+        let transposed = tensor.transpose().unwrap();
+        let view = transposed.to_ndarray().unwrap();
+        assert_eq!(view.shape(), &[3, 2]);
+        assert_eq!(view[[2, 1]], 5);
+
+        let sliced = tensor.slice(1, 1, 2).unwrap();
+        let view = sliced.to_ndarray().unwrap();
+        assert_eq!(view.iter().copied().collect::<Vec<_>>(), vec![1, 2, 4, 5]);
+
+        let array =
+            ndarray::ArrayD::from_shape_vec(vec![2, 3], (0..6).collect()).unwrap();
+        let ptr = array.as_ptr();
+        let tensor = Int32Tensor::from_ndarray(array).unwrap();
+        assert!(tensor.is_row_major().unwrap());
+        assert_eq!(tensor.data().as_ptr() as *const i32, ptr);
+
+        let array =
+            ndarray::ArrayD::from_shape_vec(vec![2, 3], (0..6).collect()).unwrap();
+        let tensor = Int32Tensor::from_ndarray(array.reversed_axes()).unwrap();
+        assert!(tensor.is_column_major().unwrap());
+        assert_eq!(tensor.value(&[2, 1]), Some(5));
+
+        // An owned array sliced to a sub-range of its allocation
+        let array = ndarray::Array2::from_shape_vec((3, 3), (0..9).collect()).unwrap();
+        let sliced = array.slice_move(ndarray::s![1.., ..]).into_dyn();
+        let tensor = Int32Tensor::from_ndarray(sliced).unwrap();
+        assert!(tensor.is_row_major().unwrap());
+        assert_eq!(tensor.data().typed_data::<i32>(), &[3, 4, 5, 6, 7, 8]);
+
+        let array = ndarray::Array2::from_shape_vec((3, 3), (0..9).collect()).unwrap();
+        let sliced = array.slice_move(ndarray::s![.., 1..]).into_dyn();
+        let tensor = Int32Tensor::from_ndarray(sliced).unwrap();
+        assert!(tensor.is_row_major().unwrap());
+        assert_eq!(tensor.data().typed_data::<i32>(), &[1, 2, 4, 5, 7, 8]);
+    }
 }
@@ -0,0 +1,121 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The tests in this file ensure kernels preserve the metadata of nested
+//! fields, such as extension type annotations
+
+use arrow::array::*;
+use arrow::buffer::OffsetBuffer;
+use arrow::compute::*;
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+use arrow_cast::adapter::SchemaAdapter;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+fn extension_metadata() -> HashMap<String, String> {
+    HashMap::from([("ARROW:extension:name".to_string(), "arrow.uuid".to_string())])
+}
+
+/// Returns a list of structs with an extension type child
+fn nested_array() -> ArrayRef {
+    let uuid = Field::new("uuid", DataType::FixedSizeBinary(2), true)
+        .with_metadata(extension_metadata());
+    let uuids = FixedSizeBinaryArray::from(vec![&[1_u8, 2][..], &[3, 4], &[5, 6]]);
+    let structs = StructArray::from(vec![(Arc::new(uuid), Arc::new(uuids) as ArrayRef)]);
+
+    let item = Field::new("item", structs.data_type().clone(), true)
+        .with_metadata(extension_metadata());
+    let offsets = OffsetBuffer::new(vec![0, 1, 3, 3].into());
+    Arc::new(ListArray::new(
+        Arc::new(item),
+        offsets,
+        Arc::new(structs),
+        None,
+    ))
+}
+
+#[test]
+fn test_kernels_preserve_nested_metadata() {
+    let array = nested_array();
+    let data_type = array.data_type();
+
+    let indices = UInt32Array::from(vec![Some(1), None, Some(0)]);
+    assert_eq!(take(&array, &indices, None).unwrap().data_type(), data_type);
+
+    let predicate = BooleanArray::from(vec![true, false, true]);
+    assert_eq!(filter(&array, &predicate).unwrap().data_type(), data_type);
+
+    let concatenated = concat(&[array.as_ref(), array.as_ref()]).unwrap();
+    assert_eq!(concatenated.data_type(), data_type);
+
+    let interleaved = interleave(&[array.as_ref()], &[(0, 2), (0, 0)]).unwrap();
+    assert_eq!(interleaved.data_type(), data_type);
+
+    assert_eq!(cast(&array, data_type).unwrap().data_type(), data_type);
+}
+
+#[test]
+fn test_record_batch_preserves_metadata() {
+    let array = nested_array();
+    let field = Field::new("c1", array.data_type().clone(), true)
+        .with_metadata(extension_metadata());
+    let other = Field::new("c2", DataType::Int32, false);
+    let schema = Arc::new(Schema::new(vec![field.clone(), other]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![array, Arc::new(Int32Array::from(vec![1, 2, 3]))],
+    )
+    .unwrap();
+
+    let projected = batch.project(&[0]).unwrap();
+    assert_eq!(projected.schema().field(0), &field);
+
+    let filtered =
+        filter_record_batch(&batch, &BooleanArray::from(vec![true, false, true]))
+            .unwrap();
+    assert_eq!(filtered.schema(), schema);
+
+    let concatenated = concat_batches(&schema, [&batch, &batch]).unwrap();
+    assert_eq!(concatenated.schema(), schema);
+
+    let roundtrip = RecordBatch::from(StructArray::from(batch.clone()));
+    assert_eq!(roundtrip.schema(), schema);
+}
+
+#[test]
+fn test_strip_metadata() {
+    let array = nested_array();
+    let field = Field::new("c1", array.data_type().clone(), true)
+        .with_metadata(extension_metadata());
+    let schema = Arc::new(Schema::new(vec![field]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![array.clone()]).unwrap();
+
+    // Metadata is removed by casting to a data type without metadata
+    let stripped = schema.as_ref().clone().without_metadata();
+    let stripped_type = stripped.field(0).data_type();
+    assert!(stripped.field(0).metadata().is_empty());
+    assert_ne!(stripped_type, array.data_type());
+
+    let cast = cast(&array, stripped_type).unwrap();
+    assert_eq!(cast.data_type(), stripped_type);
+
+    let adapter = SchemaAdapter::new(Arc::new(stripped));
+    let adapted = adapter.adapt(&batch).unwrap();
+    assert_eq!(adapted.schema(), adapter.target_schema().clone());
+    assert_eq!(adapted.column(0).as_ref(), cast.as_ref());
+}
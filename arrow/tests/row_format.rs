@@ -0,0 +1,238 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests comparing the ordering of [`arrow_row`] with the lexicographical sort kernels
+
+use arrow_array::builder::*;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::ArrowNativeType;
+use arrow_cast::display::array_value_to_string;
+use arrow_ord::sort::{LexicographicalComparator, SortColumn, SortOptions};
+use arrow_row::{RowConverter, SortField};
+use arrow_schema::DataType;
+use rand::distributions::uniform::SampleUniform;
+use rand::distributions::{Distribution, Standard};
+use rand::{thread_rng, Rng};
+use std::sync::Arc;
+
+fn dictionary_eq(exact: bool, a: &dyn Array, b: &dyn Array) {
+    match b.data_type() {
+        DataType::Dictionary(_, v) if !exact => {
+            assert_eq!(a.data_type(), v.as_ref());
+            let b = arrow_cast::cast(b, v).unwrap();
+            assert_eq!(a, b.as_ref())
+        }
+        _ => assert_eq!(a, b),
+    }
+}
+
+fn generate_primitive_array<K>(len: usize, valid_percent: f64) -> PrimitiveArray<K>
+where
+    K: ArrowPrimitiveType,
+    Standard: Distribution<K::Native>,
+{
+    let mut rng = thread_rng();
+    (0..len)
+        .map(|_| rng.gen_bool(valid_percent).then(|| rng.gen()))
+        .collect()
+}
+
+fn generate_strings<O: OffsetSizeTrait>(
+    len: usize,
+    valid_percent: f64,
+) -> GenericStringArray<O> {
+    let mut rng = thread_rng();
+    (0..len)
+        .map(|_| {
+            rng.gen_bool(valid_percent).then(|| {
+                let len = rng.gen_range(0..100);
+                let bytes = (0..len).map(|_| rng.gen_range(0..128)).collect();
+                String::from_utf8(bytes).unwrap()
+            })
+        })
+        .collect()
+}
+
+fn generate_dictionary<K>(
+    values: ArrayRef,
+    len: usize,
+    valid_percent: f64,
+) -> DictionaryArray<K>
+where
+    K: ArrowDictionaryKeyType,
+    K::Native: SampleUniform,
+{
+    let mut rng = thread_rng();
+    let min_key = K::Native::from_usize(0).unwrap();
+    let max_key = K::Native::from_usize(values.len()).unwrap();
+    let keys: PrimitiveArray<K> = (0..len)
+        .map(|_| {
+            rng.gen_bool(valid_percent)
+                .then(|| rng.gen_range(min_key..max_key))
+        })
+        .collect();
+
+    let data_type = DataType::Dictionary(
+        Box::new(K::DATA_TYPE),
+        Box::new(values.data_type().clone()),
+    );
+
+    let data = keys
+        .into_data()
+        .into_builder()
+        .data_type(data_type)
+        .add_child_data(values.to_data())
+        .build()
+        .unwrap();
+
+    DictionaryArray::from(data)
+}
+
+fn generate_fixed_size_binary(len: usize, valid_percent: f64) -> FixedSizeBinaryArray {
+    let mut rng = thread_rng();
+    let width = rng.gen_range(0..20);
+    let mut builder = FixedSizeBinaryBuilder::new(width);
+
+    let mut b = vec![0; width as usize];
+    for _ in 0..len {
+        match rng.gen_bool(valid_percent) {
+            true => {
+                b.iter_mut().for_each(|x| *x = rng.gen());
+                builder.append_value(&b).unwrap();
+            }
+            false => builder.append_null(),
+        }
+    }
+
+    builder.finish()
+}
+
+fn generate_column(len: usize) -> ArrayRef {
+    let mut rng = thread_rng();
+    match rng.gen_range(0..10) {
+        0 => Arc::new(generate_primitive_array::<Int32Type>(len, 0.8)),
+        1 => Arc::new(generate_primitive_array::<UInt32Type>(len, 0.8)),
+        2 => Arc::new(generate_primitive_array::<Int64Type>(len, 0.8)),
+        3 => Arc::new(generate_primitive_array::<UInt64Type>(len, 0.8)),
+        4 => Arc::new(generate_primitive_array::<Float32Type>(len, 0.8)),
+        5 => Arc::new(generate_primitive_array::<Float64Type>(len, 0.8)),
+        6 => Arc::new(generate_strings::<i32>(len, 0.8)),
+        7 => Arc::new(generate_dictionary::<Int64Type>(
+            // Cannot test dictionaries containing null values because of #2687
+            Arc::new(generate_strings::<i32>(rng.gen_range(1..len), 1.0)),
+            len,
+            0.8,
+        )),
+        8 => Arc::new(generate_dictionary::<Int64Type>(
+            // Cannot test dictionaries containing null values because of #2687
+            Arc::new(generate_primitive_array::<Int64Type>(
+                rng.gen_range(1..len),
+                1.0,
+            )),
+            len,
+            0.8,
+        )),
+        9 => Arc::new(generate_fixed_size_binary(len, 0.8)),
+        _ => unreachable!(),
+    }
+}
+
+fn print_row(cols: &[SortColumn], row: usize) -> String {
+    let t: Vec<_> = cols
+        .iter()
+        .map(|x| array_value_to_string(&x.values, row).unwrap())
+        .collect();
+    t.join(",")
+}
+
+fn print_col_types(cols: &[SortColumn]) -> String {
+    let t: Vec<_> = cols
+        .iter()
+        .map(|x| x.values.data_type().to_string())
+        .collect();
+    t.join(",")
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn fuzz_test() {
+    for _ in 0..100 {
+        let mut rng = thread_rng();
+        let num_columns = rng.gen_range(1..5);
+        let len = rng.gen_range(5..100);
+        let arrays: Vec<_> = (0..num_columns).map(|_| generate_column(len)).collect();
+
+        let options: Vec<_> = (0..num_columns)
+            .map(|_| SortOptions {
+                descending: rng.gen_bool(0.5),
+                nulls_first: rng.gen_bool(0.5),
+            })
+            .collect();
+
+        let sort_columns: Vec<_> = options
+            .iter()
+            .zip(&arrays)
+            .map(|(o, c)| SortColumn {
+                values: Arc::clone(c),
+                options: Some(*o),
+            })
+            .collect();
+
+        let preserve: Vec<_> = (0..num_columns).map(|_| rng.gen_bool(0.5)).collect();
+
+        let comparator = LexicographicalComparator::try_new(&sort_columns).unwrap();
+
+        let columns = options
+            .into_iter()
+            .zip(&arrays)
+            .zip(&preserve)
+            .map(|((o, a), p)| {
+                SortField::new_with_options(a.data_type().clone(), o)
+                    .preserve_dictionaries(*p)
+            })
+            .collect();
+
+        let mut converter = RowConverter::new(columns).unwrap();
+        let rows = converter.convert_columns(&arrays).unwrap();
+
+        for i in 0..len {
+            for j in 0..len {
+                let row_i = rows.row(i);
+                let row_j = rows.row(j);
+                let row_cmp = row_i.cmp(&row_j);
+                let lex_cmp = comparator.compare(i, j);
+                assert_eq!(
+                    row_cmp,
+                    lex_cmp,
+                    "({:?} vs {:?}) vs ({:?} vs {:?}) for types {}",
+                    print_row(&sort_columns, i),
+                    print_row(&sort_columns, j),
+                    row_i,
+                    row_j,
+                    print_col_types(&sort_columns)
+                );
+            }
+        }
+
+        let back = converter.convert_rows(&rows).unwrap();
+        for ((actual, expected), preserve) in back.iter().zip(&arrays).zip(preserve) {
+            actual.to_data().validate_full().unwrap();
+            dictionary_eq(preserve, actual, expected)
+        }
+    }
+}
@@ -15,7 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use arrow_cast::parse::string_to_datetime;
+use arrow::compute::{assume_timezone, convert_timezone, AmbiguousTime, NonexistentTime};
+use arrow_array::cast::AsArray;
+use arrow_array::types::TimestampSecondType;
+use arrow_array::TimestampSecondArray;
+use arrow_cast::parse::{string_to_datetime, string_to_timestamp_nanos};
 use chrono::Utc;
 
 #[test]
@@ -79,3 +83,68 @@ fn test_parse_timezone_invalid() {
         assert_eq!(actual, expected)
     }
 }
+
+/// Returns the seconds since the epoch of the RFC3339 timestamp `s`
+fn seconds(s: &str) -> i64 {
+    string_to_timestamp_nanos(s).unwrap() / 1_000_000_000
+}
+
+#[test]
+fn test_assume_timezone_dst() {
+    let tz = "America/New_York";
+    let local = TimestampSecondArray::from(vec![
+        seconds("2023-01-01T12:00:00"),
+        // Nonexistent, clocks move forward from 02:00 EST to 03:00 EDT
+        seconds("2023-03-12T02:30:00"),
+        // Ambiguous, clocks move back from 02:00 EDT to 01:00 EST
+        seconds("2023-11-05T01:30:00"),
+    ]);
+
+    let assume = |ambiguous, nonexistent| {
+        let a = assume_timezone(&local, tz, ambiguous, nonexistent)?;
+        let a = a.as_primitive::<TimestampSecondType>();
+        assert_eq!(a.timezone(), Some(tz));
+        Ok::<_, arrow_schema::ArrowError>(a.iter().collect::<Vec<_>>())
+    };
+
+    let earliest = assume(AmbiguousTime::Earliest, NonexistentTime::Earliest).unwrap();
+    assert_eq!(
+        earliest,
+        vec![
+            Some(seconds("2023-01-01T17:00:00Z")),
+            Some(seconds("2023-03-12T06:59:59Z")),
+            Some(seconds("2023-11-05T05:30:00Z")),
+        ]
+    );
+
+    let latest = assume(AmbiguousTime::Latest, NonexistentTime::Latest).unwrap();
+    assert_eq!(
+        latest,
+        vec![
+            Some(seconds("2023-01-01T17:00:00Z")),
+            Some(seconds("2023-03-12T07:00:00Z")),
+            Some(seconds("2023-11-05T06:30:00Z")),
+        ]
+    );
+
+    let null = assume(AmbiguousTime::Null, NonexistentTime::Null).unwrap();
+    assert_eq!(
+        null,
+        vec![Some(seconds("2023-01-01T17:00:00Z")), None, None]
+    );
+
+    let err = assume(AmbiguousTime::Earliest, NonexistentTime::Raise).unwrap_err();
+    assert!(err.to_string().contains("does not exist"), "{err}");
+    let err = assume(AmbiguousTime::Raise, NonexistentTime::Earliest).unwrap_err();
+    assert!(err.to_string().contains("is ambiguous"), "{err}");
+
+    // The local time of the instants after the gap are shifted forward
+    let instants =
+        assume_timezone(&local, tz, AmbiguousTime::Latest, NonexistentTime::Latest)
+            .unwrap();
+    let converted = convert_timezone(&instants, None).unwrap();
+    let converted = converted.as_primitive::<TimestampSecondType>();
+    assert_eq!(converted.value(0), local.value(0));
+    assert_eq!(converted.value(1), seconds("2023-03-12T03:00:00"));
+    assert_eq!(converted.value(2), local.value(2));
+}
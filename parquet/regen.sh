@@ -0,0 +1,38 @@
+#!/bin/bash -e
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+# Regenerates src/format.rs from parquet.thrift
+
+THRIFT_VERSION="0.17.0"
+
+DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" >/dev/null 2>&1 && pwd )"
+
+if ! thrift --version | grep -q "$THRIFT_VERSION"; then
+    echo "thrift $THRIFT_VERSION is required, found: $(thrift --version)"
+    exit 1
+fi
+
+OUT_DIR="$(mktemp -d)"
+trap 'rm -rf "$OUT_DIR"' EXIT
+
+thrift --gen rs -out "$OUT_DIR" "$DIR/parquet.thrift"
+
+# The generated processor import is unused and not available without the server feature
+sed -i '/use thrift::server::TProcessor;/d' "$OUT_DIR/parquet.rs"
+
+mv "$OUT_DIR/parquet.rs" "$DIR/src/format.rs"
@@ -356,6 +356,7 @@ mod tests {
             file_metadata.schema_descr(),
             ProjectionMask::all(),
             file_metadata.key_value_metadata(),
+            Default::default(),
         )
         .unwrap();
 
@@ -28,8 +28,8 @@ use crate::errors::{ParquetError, Result};
 use crate::schema::types::ColumnDescPtr;
 use crate::util::memory::ByteBufferPtr;
 use arrow_array::{
-    ArrayRef, Decimal128Array, Decimal256Array, FixedSizeBinaryArray,
-    IntervalDayTimeArray, IntervalYearMonthArray,
+    ArrayRef, Decimal128Array, Decimal256Array, Decimal32Array, Decimal64Array,
+    FixedSizeBinaryArray, IntervalDayTimeArray, IntervalYearMonthArray,
 };
 use arrow_buffer::{i256, Buffer};
 use arrow_data::ArrayDataBuilder;
@@ -63,7 +63,9 @@ pub fn make_fixed_len_byte_array_reader(
     };
     match &data_type {
         ArrowType::FixedSizeBinary(_) => {}
-        ArrowType::Decimal128(_, _) => {
+        ArrowType::Decimal32(_, _)
+        | ArrowType::Decimal64(_, _)
+        | ArrowType::Decimal128(_, _) => {
             if byte_length > 16 {
                 return Err(general_err!(
                     "decimal 128 type too large, must be less than 16 bytes, got {}",
@@ -163,6 +165,24 @@ impl ArrayReader for FixedLenByteArrayReader {
 
         // TODO: An improvement might be to do this conversion on read
         let array: ArrayRef = match &self.data_type {
+            ArrowType::Decimal32(p, s) => {
+                let decimal = binary
+                    .iter()
+                    .map(|opt| Some(i128::from_be_bytes(sign_extend_be(opt?)) as i32))
+                    .collect::<Decimal32Array>()
+                    .with_precision_and_scale(*p, *s)?;
+
+                Arc::new(decimal)
+            }
+            ArrowType::Decimal64(p, s) => {
+                let decimal = binary
+                    .iter()
+                    .map(|opt| Some(i128::from_be_bytes(sign_extend_be(opt?)) as i64))
+                    .collect::<Decimal64Array>()
+                    .with_precision_and_scale(*p, *s)?;
+
+                Arc::new(decimal)
+            }
             ArrowType::Decimal128(p, s) => {
                 let decimal = binary
                     .iter()
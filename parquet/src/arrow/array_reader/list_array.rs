@@ -570,6 +570,7 @@ mod tests {
             schema,
             ProjectionMask::all(),
             file_metadata.key_value_metadata(),
+            Default::default(),
         )
         .unwrap();
 
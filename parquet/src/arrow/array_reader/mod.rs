@@ -39,6 +39,7 @@ mod list_array;
 mod map_array;
 mod null_array;
 mod primitive_array;
+mod row_number;
 mod struct_array;
 
 #[cfg(test)]
@@ -53,6 +54,7 @@ pub use list_array::ListArrayReader;
 pub use map_array::MapArrayReader;
 pub use null_array::NullArrayReader;
 pub use primitive_array::PrimitiveArrayReader;
+pub use row_number::{row_group_ranges, RowNumberReader};
 pub use struct_array::StructArrayReader;
 
 /// Array reader reads parquet data into arrow array.
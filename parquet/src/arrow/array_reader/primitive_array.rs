@@ -24,13 +24,13 @@ use crate::column::page::PageIterator;
 use crate::data_type::{DataType, Int96};
 use crate::errors::{ParquetError, Result};
 use crate::schema::types::ColumnDescPtr;
-use arrow_array::Decimal256Array;
 use arrow_array::{
-    builder::{BooleanBufferBuilder, TimestampNanosecondBufferBuilder},
-    ArrayRef, BooleanArray, Decimal128Array, Float32Array, Float64Array, Int32Array,
-    Int64Array, TimestampNanosecondArray, UInt32Array, UInt64Array,
+    builder::{BooleanBufferBuilder, Int64BufferBuilder},
+    make_array, ArrayRef, BooleanArray, Decimal128Array, Float32Array, Float64Array,
+    Int32Array, Int64Array, UInt32Array, UInt64Array,
 };
-use arrow_buffer::{i256, Buffer};
+use arrow_array::{Decimal256Array, Decimal32Array, Decimal64Array};
+use arrow_buffer::{bit_util, i256, Buffer, MutableBuffer};
 use arrow_data::ArrayDataBuilder;
 use arrow_schema::{DataType as ArrowType, TimeUnit};
 use std::any::Any;
@@ -127,8 +127,10 @@ where
             PhysicalType::FLOAT => ArrowType::Float32,
             PhysicalType::DOUBLE => ArrowType::Float64,
             PhysicalType::INT96 => match target_type {
-                ArrowType::Timestamp(TimeUnit::Nanosecond, _) => target_type.clone(),
-                _ => unreachable!("INT96 must be timestamp nanosecond"),
+                ArrowType::Timestamp(_, _) | ArrowType::FixedSizeBinary(12) => {
+                    target_type.clone()
+                }
+                _ => unreachable!("INT96 must be timestamp or fixed size binary"),
             },
             PhysicalType::BYTE_ARRAY | PhysicalType::FIXED_LEN_BYTE_ARRAY => {
                 unreachable!(
@@ -141,6 +143,7 @@ where
         // The physical types are then cast to Arrow types if necessary
 
         let record_data = self.record_reader.consume_record_data();
+        let nulls = self.record_reader.consume_bitmap_buffer();
         let record_data = match T::get_physical_type() {
             PhysicalType::BOOLEAN => {
                 let mut boolean_buffer = BooleanBufferBuilder::new(record_data.len());
@@ -156,12 +159,7 @@ where
                     unsafe { record_data.as_slice().align_to::<Int96>() };
                 assert!(prefix.is_empty() && suffix.is_empty());
 
-                let mut builder = TimestampNanosecondBufferBuilder::new(slice.len());
-                for v in slice {
-                    builder.append(v.to_nanos())
-                }
-
-                builder.finish()
+                convert_int96(slice, nulls.as_ref(), &arrow_data_type)?
             }
             _ => record_data,
         };
@@ -169,7 +167,7 @@ where
         let array_data = ArrayDataBuilder::new(arrow_data_type)
             .len(self.record_reader.num_values())
             .add_buffer(record_data)
-            .null_bit_buffer(self.record_reader.consume_bitmap_buffer());
+            .null_bit_buffer(nulls);
 
         let array_data = unsafe { array_data.build_unchecked() };
         let array: ArrayRef = match T::get_physical_type() {
@@ -186,7 +184,7 @@ where
             },
             PhysicalType::FLOAT => Arc::new(Float32Array::from(array_data)),
             PhysicalType::DOUBLE => Arc::new(Float64Array::from(array_data)),
-            PhysicalType::INT96 => Arc::new(TimestampNanosecondArray::from(array_data)),
+            PhysicalType::INT96 => make_array(array_data),
             PhysicalType::BYTE_ARRAY | PhysicalType::FIXED_LEN_BYTE_ARRAY => {
                 unreachable!(
                     "PrimitiveArrayReaders don't support complex physical types"
@@ -210,6 +208,60 @@ where
                 let a = arrow_cast::cast(&array, &ArrowType::Date32)?;
                 arrow_cast::cast(&a, target_type)?
             }
+            ArrowType::Decimal32(p, s) => {
+                let array = match array.data_type() {
+                    ArrowType::Int32 => array
+                        .as_any()
+                        .downcast_ref::<Int32Array>()
+                        .unwrap()
+                        .iter()
+                        .collect::<Decimal32Array>(),
+
+                    ArrowType::Int64 => array
+                        .as_any()
+                        .downcast_ref::<Int64Array>()
+                        .unwrap()
+                        .iter()
+                        .map(|v| v.map(|v| v as i32))
+                        .collect::<Decimal32Array>(),
+                    _ => {
+                        return Err(arrow_err!(
+                            "Cannot convert {:?} to decimal",
+                            array.data_type()
+                        ));
+                    }
+                }
+                .with_precision_and_scale(*p, *s)?;
+
+                Arc::new(array) as ArrayRef
+            }
+            ArrowType::Decimal64(p, s) => {
+                let array = match array.data_type() {
+                    ArrowType::Int32 => array
+                        .as_any()
+                        .downcast_ref::<Int32Array>()
+                        .unwrap()
+                        .iter()
+                        .map(|v| v.map(|v| v as i64))
+                        .collect::<Decimal64Array>(),
+
+                    ArrowType::Int64 => array
+                        .as_any()
+                        .downcast_ref::<Int64Array>()
+                        .unwrap()
+                        .iter()
+                        .collect::<Decimal64Array>(),
+                    _ => {
+                        return Err(arrow_err!(
+                            "Cannot convert {:?} to decimal",
+                            array.data_type()
+                        ));
+                    }
+                }
+                .with_precision_and_scale(*p, *s)?;
+
+                Arc::new(array) as ArrayRef
+            }
             ArrowType::Decimal128(p, s) => {
                 let array = match array.data_type() {
                     ArrowType::Int32 => array
@@ -289,6 +341,60 @@ where
     }
 }
 
+/// Converts `values` to the data buffer of an array of `data_type`, returning an
+/// error if a non-null value is outside the range of the timestamp unit
+fn convert_int96(
+    values: &[Int96],
+    nulls: Option<&Buffer>,
+    data_type: &ArrowType,
+) -> Result<Buffer> {
+    match data_type {
+        ArrowType::FixedSizeBinary(_) => {
+            let mut buffer = MutableBuffer::new(values.len() * 12);
+            for v in values {
+                for x in v.data() {
+                    buffer.extend_from_slice(&x.to_le_bytes())
+                }
+            }
+            Ok(buffer.into())
+        }
+        ArrowType::Timestamp(unit, _) => {
+            let (multiplier, divisor) = match unit {
+                TimeUnit::Second => (1, 1_000_000_000),
+                TimeUnit::Millisecond => (1_000, 1_000_000),
+                TimeUnit::Microsecond => (1_000_000, 1_000),
+                TimeUnit::Nanosecond => (1_000_000_000, 1),
+            };
+
+            let mut builder = Int64BufferBuilder::new(values.len());
+            for (idx, v) in values.iter().enumerate() {
+                let valid = nulls
+                    .map(|n| bit_util::get_bit(n.as_slice(), idx))
+                    .unwrap_or(true);
+                if !valid {
+                    builder.append(0);
+                    continue;
+                }
+
+                let (seconds, nanoseconds) = v.to_seconds_and_nanos();
+                let value = seconds
+                    .checked_mul(multiplier)
+                    .and_then(|x| x.checked_add(nanoseconds / divisor))
+                    .ok_or_else(|| {
+                        general_err!(
+                            "INT96 timestamp {} seconds after epoch is out of range for {}",
+                            seconds,
+                            data_type
+                        )
+                    })?;
+                builder.append(value)
+            }
+            Ok(builder.finish())
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
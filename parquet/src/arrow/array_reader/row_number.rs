@@ -0,0 +1,167 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::arrow::array_reader::ArrayReader;
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::ParquetMetaData;
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, Int64Array, StructArray};
+use arrow_schema::{DataType as ArrowType, Field, Fields};
+use std::any::Any;
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// Returns the range of row numbers within the file of each of `row_groups`
+pub fn row_group_ranges(
+    metadata: &ParquetMetaData,
+    row_groups: impl IntoIterator<Item = usize>,
+) -> Vec<Range<i64>> {
+    let mut first_row = 0;
+    let offsets: Vec<_> = metadata
+        .row_groups()
+        .iter()
+        .map(|rg| {
+            let range = first_row..first_row + rg.num_rows();
+            first_row = range.end;
+            range
+        })
+        .collect();
+
+    row_groups.into_iter().map(|i| offsets[i].clone()).collect()
+}
+
+/// An [`ArrayReader`] that appends a non-nullable [`Int64Array`] column containing
+/// the row number within the file of each row to the [`StructArray`] read by
+/// another [`ArrayReader`]
+///
+/// As rows are read or skipped in lockstep with the wrapped [`ArrayReader`], the
+/// row numbers are correct regardless of any row selection
+pub struct RowNumberReader {
+    reader: Box<dyn ArrayReader>,
+    data_type: ArrowType,
+    /// The row numbers of the row groups yet to be read
+    ranges: VecDeque<Range<i64>>,
+    /// The row numbers read but not yet consumed
+    buffer: Vec<i64>,
+}
+
+impl RowNumberReader {
+    /// Creates a new [`RowNumberReader`] appending a column named `name` to the
+    /// rows of `reader`, which reads the row groups with row numbers `ranges`
+    pub fn try_new(
+        reader: Box<dyn ArrayReader>,
+        name: &str,
+        ranges: Vec<Range<i64>>,
+    ) -> Result<Self> {
+        let fields = match reader.get_data_type() {
+            ArrowType::Struct(fields) => fields,
+            _ => return Err(general_err!("Row number reader expects struct")),
+        };
+        if fields.find(name).is_some() {
+            return Err(general_err!(
+                "Row number column '{}' conflicts with an existing column",
+                name
+            ));
+        }
+
+        let field = Field::new(name, ArrowType::Int64, false);
+        let fields: Fields = fields.iter().cloned().chain([Arc::new(field)]).collect();
+
+        Ok(Self {
+            reader,
+            data_type: ArrowType::Struct(fields),
+            ranges: ranges.into(),
+            buffer: vec![],
+        })
+    }
+
+    /// Advances over `count` row numbers, appending them to the buffer if `read`
+    fn advance(&mut self, mut count: usize, read: bool) -> Result<()> {
+        while count != 0 {
+            let range = self.ranges.front_mut().ok_or_else(|| {
+                general_err!("Row number reader exhausted with {} rows remaining", count)
+            })?;
+            let end = range.end.min(range.start + count as i64);
+            if read {
+                self.buffer.extend(range.start..end);
+            }
+            count -= (end - range.start) as usize;
+            range.start = end;
+            if range.is_empty() {
+                self.ranges.pop_front();
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ArrayReader for RowNumberReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn read_records(&mut self, batch_size: usize) -> Result<usize> {
+        let read = self.reader.read_records(batch_size)?;
+        self.advance(read, true)?;
+        Ok(read)
+    }
+
+    fn consume_batch(&mut self) -> Result<ArrayRef> {
+        let array = self.reader.consume_batch()?;
+        let array = array
+            .as_struct_opt()
+            .ok_or_else(|| general_err!("Row number reader expects struct"))?;
+
+        if array.len() != self.buffer.len() {
+            return Err(general_err!(
+                "Row number reader read {} rows, expected {}",
+                self.buffer.len(),
+                array.len()
+            ));
+        }
+
+        let row_numbers = Int64Array::from(std::mem::take(&mut self.buffer));
+        let mut columns = array.columns().to_vec();
+        columns.push(Arc::new(row_numbers));
+
+        let fields = match &self.data_type {
+            ArrowType::Struct(fields) => fields.clone(),
+            _ => unreachable!(),
+        };
+        let array = StructArray::try_new(fields, columns, array.nulls().cloned())?;
+        Ok(Arc::new(array))
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        let skipped = self.reader.skip_records(num_records)?;
+        self.advance(skipped, false)?;
+        Ok(skipped)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        self.reader.get_def_levels()
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        self.reader.get_rep_levels()
+    }
+}
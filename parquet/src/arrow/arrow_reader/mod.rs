@@ -23,10 +23,13 @@ use std::sync::Arc;
 use arrow_array::cast::AsArray;
 use arrow_array::Array;
 use arrow_array::{RecordBatch, RecordBatchReader};
-use arrow_schema::{ArrowError, DataType as ArrowType, Schema, SchemaRef};
+use arrow_schema::{ArrowError, DataType as ArrowType, Schema, SchemaRef, TimeUnit};
 use arrow_select::filter::prep_null_mask_filter;
 
-use crate::arrow::array_reader::{build_array_reader, ArrayReader, FileReaderRowGroups};
+use crate::arrow::array_reader::{
+    build_array_reader, row_group_ranges, ArrayReader, FileReaderRowGroups,
+    RowNumberReader,
+};
 use crate::arrow::schema::{parquet_to_arrow_schema_and_fields, ParquetField};
 use crate::arrow::{FieldLevels, ProjectionMask};
 use crate::errors::{ParquetError, Result};
@@ -72,6 +75,8 @@ pub struct ArrowReaderBuilder<T> {
     pub(crate) limit: Option<usize>,
 
     pub(crate) offset: Option<usize>,
+
+    pub(crate) row_number_column: Option<String>,
 }
 
 impl<T> ArrowReaderBuilder<T> {
@@ -89,6 +94,7 @@ impl<T> ArrowReaderBuilder<T> {
             metadata.file_metadata().schema_descr(),
             ProjectionMask::all(),
             kv_metadata,
+            options.int96_format,
         )?;
 
         Ok(Self {
@@ -103,6 +109,7 @@ impl<T> ArrowReaderBuilder<T> {
             selection: None,
             limit: None,
             offset: None,
+            row_number_column: None,
         })
     }
 
@@ -194,6 +201,24 @@ impl<T> ArrowReaderBuilder<T> {
             ..self
         }
     }
+
+    /// Append a non-nullable [`Int64`] column named `name` to the output, containing
+    /// the position of each row within the file
+    ///
+    /// The row numbers are not affected by [`Self::with_row_groups`],
+    /// [`Self::with_row_selection`], [`Self::with_row_filter`], [`Self::with_offset`]
+    /// or [`Self::with_limit`], allowing rows to be identified after any of these
+    /// have been applied, for example to apply deletion vectors
+    ///
+    /// Returns an error on build if the output contains a column named `name`
+    ///
+    /// [`Int64`]: ArrowType::Int64
+    pub fn with_row_number_column(self, name: impl Into<String>) -> Self {
+        Self {
+            row_number_column: Some(name.into()),
+            ..self
+        }
+    }
 }
 
 /// Options that control how metadata is read for a parquet file
@@ -204,6 +229,7 @@ impl<T> ArrowReaderBuilder<T> {
 pub struct ArrowReaderOptions {
     skip_arrow_metadata: bool,
     pub(crate) page_index: bool,
+    int96_format: Int96Format,
 }
 
 impl ArrowReaderOptions {
@@ -232,6 +258,38 @@ impl ArrowReaderOptions {
     pub fn with_page_index(self, page_index: bool) -> Self {
         Self { page_index, ..self }
     }
+
+    /// Sets the [`Int96Format`] used to read `INT96` columns, defaults to
+    /// [`Int96Format::Timestamp`] with [`TimeUnit::Nanosecond`]
+    pub fn with_int96_format(self, int96_format: Int96Format) -> Self {
+        Self {
+            int96_format,
+            ..self
+        }
+    }
+}
+
+/// How to read parquet `INT96` columns, see [`ArrowReaderOptions::with_int96_format`]
+///
+/// `INT96` is a deprecated physical type used by legacy writers, such as Apache Impala
+/// and Apache Spark, to store timestamps as a Julian day and the nanoseconds within that
+/// day. This can represent timestamps outside the range of [`TimeUnit::Nanosecond`],
+/// approximately the years 1677 to 2262, which can instead be read with a coarser unit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Int96Format {
+    /// Read as an arrow `Timestamp` with the provided [`TimeUnit`]
+    ///
+    /// Any precision finer than the unit is truncated, and reading a value outside
+    /// the range of the unit returns an error
+    Timestamp(TimeUnit),
+    /// Read the raw 12 bytes of each value as `FixedSizeBinary(12)`
+    FixedSizeBinary,
+}
+
+impl Default for Int96Format {
+    fn default() -> Self {
+        Self::Timestamp(TimeUnit::Nanosecond)
+    }
 }
 
 #[doc(hidden)]
@@ -267,6 +325,10 @@ impl<T: ChunkReader + 'static> ArrowReaderBuilder<SyncReader<T>> {
     ///
     /// Note: this will eagerly evaluate any `RowFilter` before returning
     pub fn build(self) -> Result<ParquetRecordBatchReader> {
+        let row_groups = self
+            .row_groups
+            .clone()
+            .unwrap_or_else(|| (0..self.metadata.num_row_groups()).collect());
         let reader = FileReaderRowGroups::new(Arc::new(self.input.0), self.row_groups);
 
         let mut filter = self.filter;
@@ -297,8 +359,12 @@ impl<T: ChunkReader + 'static> ArrowReaderBuilder<SyncReader<T>> {
             }
         }
 
-        let array_reader =
-            build_array_reader(self.fields.as_ref(), &self.projection, &reader)?;
+        let array_reader = with_row_numbers(
+            build_array_reader(self.fields.as_ref(), &self.projection, &reader)?,
+            self.row_number_column.as_deref(),
+            &self.metadata,
+            row_groups,
+        )?;
 
         // If selection is empty, truncate
         if !selects_any(selection.as_ref()) {
@@ -463,6 +529,23 @@ impl ParquetRecordBatchReader {
     }
 }
 
+/// Appends a column named `row_number_column`, if any, to the output of `array_reader`
+/// containing the position of each row within the file, see [`RowNumberReader`]
+pub(crate) fn with_row_numbers(
+    array_reader: Box<dyn ArrayReader>,
+    row_number_column: Option<&str>,
+    metadata: &ParquetMetaData,
+    row_groups: impl IntoIterator<Item = usize>,
+) -> Result<Box<dyn ArrayReader>> {
+    Ok(match row_number_column {
+        Some(name) => {
+            let ranges = row_group_ranges(metadata, row_groups);
+            Box::new(RowNumberReader::try_new(array_reader, name, ranges)?)
+        }
+        None => array_reader,
+    })
+}
+
 /// Returns `true` if `selection` is `None` or selects some rows
 pub(crate) fn selects_any(selection: Option<&RowSelection>) -> bool {
     selection.map(|x| x.selects_any()).unwrap_or(true)
@@ -549,16 +632,18 @@ mod tests {
 
     use arrow_array::builder::*;
     use arrow_array::cast::AsArray;
-    use arrow_array::types::{Decimal128Type, Decimal256Type, DecimalType};
+    use arrow_array::types::{
+        Decimal128Type, Decimal256Type, DecimalType, TimestampMicrosecondType,
+    };
     use arrow_array::*;
     use arrow_array::{RecordBatch, RecordBatchReader};
     use arrow_buffer::{i256, ArrowNativeType, Buffer};
     use arrow_data::ArrayDataBuilder;
-    use arrow_schema::{DataType as ArrowDataType, Field, Fields, Schema};
+    use arrow_schema::{DataType as ArrowDataType, Field, Fields, Schema, TimeUnit};
     use arrow_select::concat::concat_batches;
 
     use crate::arrow::arrow_reader::{
-        ArrowPredicateFn, ArrowReaderOptions, ParquetRecordBatchReader,
+        ArrowPredicateFn, ArrowReaderOptions, Int96Format, ParquetRecordBatchReader,
         ParquetRecordBatchReaderBuilder, RowFilter, RowSelection, RowSelector,
     };
     use crate::arrow::schema::add_encoded_arrow_schema_to_metadata;
@@ -566,8 +651,8 @@ mod tests {
     use crate::basic::{ConvertedType, Encoding, Repetition, Type as PhysicalType};
     use crate::column::reader::decoder::REPETITION_LEVELS_BATCH_SIZE;
     use crate::data_type::{
-        BoolType, ByteArray, ByteArrayType, DataType, FixedLenByteArray,
-        FixedLenByteArrayType, Int32Type, Int64Type, Int96Type,
+        AsBytes, BoolType, ByteArray, ByteArrayType, DataType, FixedLenByteArray,
+        FixedLenByteArrayType, Int32Type, Int64Type, Int96, Int96Type,
     };
     use crate::errors::Result;
     use crate::file::properties::{EnabledStatistics, WriterProperties, WriterVersion};
@@ -823,6 +908,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_int96_format() {
+        let message_type = "message schema { OPTIONAL INT96 ts; }";
+        let schema = Arc::new(parse_message_type(message_type).unwrap());
+
+        // 1970-01-01T00:00:00.000001001 and 3000-01-01T00:00:00
+        let mut epoch = Int96::new();
+        epoch.set_data(1_001, 0, 2_440_588);
+        let mut future = Int96::new();
+        future.set_data(0, 0, 2_816_788);
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer =
+            SerializedFileWriter::new(&mut buf, schema, Default::default()).unwrap();
+        let mut row_group_writer = writer.next_row_group().unwrap();
+        let mut column_writer = row_group_writer.next_column().unwrap().unwrap();
+        column_writer
+            .typed::<Int96Type>()
+            .write_batch(&[epoch, future], Some(&[1, 0, 1]), None)
+            .unwrap();
+        column_writer.close().unwrap();
+        row_group_writer.close().unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let read = |format| {
+            let options = ArrowReaderOptions::new().with_int96_format(format);
+            let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(
+                data.clone(),
+                options,
+            )
+            .unwrap();
+            builder.build().unwrap().next().unwrap()
+        };
+
+        // The default nanosecond unit cannot represent the year 3000
+        let err = read(Int96Format::default()).unwrap_err().to_string();
+        let expected = "INT96 timestamp 32503680000 seconds after epoch is out of range";
+        assert!(err.contains(expected), "{err}");
+
+        let batch = read(Int96Format::Timestamp(TimeUnit::Microsecond)).unwrap();
+        let ts = batch.column(0).as_primitive::<TimestampMicrosecondType>();
+        let expected =
+            TimestampMicrosecondArray::from(vec![Some(1), None, Some(32503680000000000)]);
+        assert_eq!(ts, &expected);
+
+        let batch = read(Int96Format::FixedSizeBinary).unwrap();
+        let raw = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+        assert_eq!(raw.value_length(), 12);
+        assert_eq!(raw.value(0), epoch.as_bytes());
+        assert!(raw.is_null(1));
+        assert_eq!(raw.value(2), future.as_bytes());
+    }
+
     struct RandUtf8Gen {}
 
     impl RandGen<ByteArrayType> for RandUtf8Gen {
@@ -2780,4 +2923,95 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_row_number_column() {
+        let c = Int64Array::from_iter_values(0..100);
+        let b = StringArray::from_iter_values((0..100).map(|x| x.to_string()));
+        let data = RecordBatch::try_from_iter([
+            ("b", Arc::new(b) as ArrayRef),
+            ("c", Arc::new(c) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(30)
+            .build();
+        let mut writer =
+            ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+        let buf = Bytes::from(buf);
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(buf.clone()).unwrap();
+        assert_eq!(builder.metadata().num_row_groups(), 4);
+
+        let predicate = ArrowPredicateFn::new(ProjectionMask::all(), |batch| {
+            let c = batch
+                .column(1)
+                .as_primitive::<arrow_array::types::Int64Type>();
+            Ok(BooleanArray::from_iter(
+                c.iter().map(|x| x.map(|x| x % 3 == 0)),
+            ))
+        });
+
+        // Reads row groups 1 and 3, containing rows 30..60 and 90..100
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(5),
+            RowSelector::select(20),
+            RowSelector::skip(5),
+            RowSelector::select(10),
+        ]);
+        let reader = builder
+            .with_row_groups(vec![1, 3])
+            .with_row_selection(selection)
+            .with_row_filter(RowFilter::new(vec![Box::new(predicate)]))
+            .with_offset(1)
+            .with_limit(6)
+            .with_batch_size(4)
+            .with_row_number_column("row_number")
+            .build()
+            .unwrap();
+
+        let schema = reader.schema();
+        assert_eq!(schema.fields().len(), 3);
+        let field = schema.field(2);
+        assert_eq!(field.name(), "row_number");
+        assert_eq!(field.data_type(), &ArrowDataType::Int64);
+        assert!(!field.is_nullable());
+
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let actual = concat_batches(&schema, &batches).unwrap();
+        assert_eq!(actual.column(1), actual.column(2));
+        let row_numbers = actual
+            .column(2)
+            .as_primitive::<arrow_array::types::Int64Type>();
+        assert_eq!(row_numbers.values(), &[39, 42, 45, 48, 51, 54]);
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(buf.clone()).unwrap();
+        let mask = ProjectionMask::leaves(builder.parquet_schema(), [0]);
+        let reader = builder
+            .with_projection(mask)
+            .with_row_number_column("row_number")
+            .with_batch_size(7)
+            .build()
+            .unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let actual = concat_batches(&batches[0].schema(), &batches).unwrap();
+        assert_eq!(actual.num_columns(), 2);
+        assert_eq!(actual.column(1).as_ref(), data.column(1).as_ref());
+
+        let err = ParquetRecordBatchReaderBuilder::try_new(buf)
+            .unwrap()
+            .with_row_number_column("c")
+            .build()
+            .err()
+            .unwrap()
+            .to_string();
+        assert_eq!(
+            err,
+            "Parquet error: Row number column 'c' conflicts with an existing column"
+        );
+    }
 }
@@ -24,7 +24,8 @@ use crate::data_type::{AsBytes, ByteArray, Int32Type};
 use crate::encodings::encoding::{DeltaBitPackEncoder, Encoder};
 use crate::encodings::rle::RleEncoder;
 use crate::errors::{ParquetError, Result};
-use crate::file::properties::{WriterProperties, WriterVersion};
+use crate::file::geospatial::{GeospatialStatistics, GeospatialStatisticsAccumulator};
+use crate::file::properties::{EnabledStatistics, WriterProperties, WriterVersion};
 use crate::schema::types::ColumnDescPtr;
 use crate::util::bit_util::num_required_bits;
 use crate::util::interner::{Interner, Storage};
@@ -386,6 +387,7 @@ pub struct ByteArrayEncoder {
     min_value: Option<ByteArray>,
     max_value: Option<ByteArray>,
     bloom_filter: Option<Sbbf>,
+    geospatial_statistics: Option<GeospatialStatisticsAccumulator>,
 }
 
 impl ColumnValueEncoder for ByteArrayEncoder {
@@ -413,6 +415,10 @@ impl ColumnValueEncoder for ByteArrayEncoder {
         self.bloom_filter.take()
     }
 
+    fn flush_geospatial_statistics(&mut self) -> Option<GeospatialStatistics> {
+        self.geospatial_statistics.as_mut()?.finish()
+    }
+
     fn try_new(descr: &ColumnDescPtr, props: &WriterProperties) -> Result<Self>
     where
         Self: Sized,
@@ -428,12 +434,18 @@ impl ColumnValueEncoder for ByteArrayEncoder {
             .map(|props| Sbbf::new_with_ndv_fpp(props.ndv, props.fpp))
             .transpose()?;
 
+        let geospatial_statistics = match props.statistics_enabled(descr.path()) {
+            EnabledStatistics::None => None,
+            _ => GeospatialStatisticsAccumulator::try_new(descr),
+        };
+
         Ok(Self {
             fallback,
             dict_encoder: dictionary,
             min_value: None,
             max_value: None,
             bloom_filter,
+            geospatial_statistics,
         })
     }
 
@@ -525,6 +537,12 @@ where
         }
     }
 
+    if let Some(geospatial_statistics) = &mut encoder.geospatial_statistics {
+        for idx in indices {
+            geospatial_statistics.update(values.value(*idx).as_ref());
+        }
+    }
+
     match &mut encoder.dict_encoder {
         Some(dict_encoder) => dict_encoder.encode(values, indices),
         None => encoder.fallback.encode(values, indices),
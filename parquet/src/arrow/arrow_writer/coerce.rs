@@ -0,0 +1,143 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Coercion of Arrow types for a [`CompatibilityProfile`]
+
+use crate::errors::Result;
+use crate::file::properties::CompatibilityProfile;
+use arrow_array::{ArrayRef, RecordBatch, RecordBatchOptions};
+use arrow_cast::{cast_with_options, CastOptions};
+use arrow_schema::{DataType, Field, Fields, Schema, SchemaRef, TimeUnit};
+use std::sync::Arc;
+
+/// The Arrow schemas used to write a [`RecordBatch`] with a [`CompatibilityProfile`]
+pub(super) struct CoercedSchema {
+    /// The schema to cast record batches to before writing, if any
+    ///
+    /// This has the same field names as the input schema, as casts match the
+    /// children of structs by name
+    pub cast: Option<SchemaRef>,
+    /// The schema written to the file
+    pub file: SchemaRef,
+}
+
+/// Returns the [`CoercedSchema`] for writing `schema` with `profile`
+pub(super) fn coerce_schema(
+    schema: &SchemaRef,
+    profile: CompatibilityProfile,
+) -> CoercedSchema {
+    match profile {
+        CompatibilityProfile::Default => CoercedSchema {
+            cast: None,
+            file: schema.clone(),
+        },
+        CompatibilityProfile::Spark => {
+            let cast = map_schema(schema, |d| spark_type(d, false));
+            let file = map_schema(schema, |d| spark_type(d, true));
+            CoercedSchema {
+                cast: (&cast != schema).then_some(cast),
+                file,
+            }
+        }
+    }
+}
+
+/// Casts the columns of `batch` to the types of `schema`, returning an error
+/// if a value cannot be represented by the coerced type
+pub(super) fn cast_batch(batch: &RecordBatch, schema: &SchemaRef) -> Result<RecordBatch> {
+    let options = CastOptions::new().with_safe(false);
+    let columns = batch
+        .columns()
+        .iter()
+        .zip(schema.fields())
+        .map(|(column, field)| cast_with_options(column, field.data_type(), &options))
+        .collect::<Result<Vec<ArrayRef>, _>>()?;
+
+    let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+    Ok(RecordBatch::try_new_with_options(
+        schema.clone(),
+        columns,
+        &options,
+    )?)
+}
+
+fn map_schema(schema: &Schema, f: impl Fn(&DataType) -> DataType) -> SchemaRef {
+    let fields: Fields = schema
+        .fields()
+        .iter()
+        .map(|field| field.as_ref().clone().with_data_type(f(field.data_type())))
+        .collect();
+    Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()))
+}
+
+/// Returns `field` with its type coerced by [`spark_type`], and renamed to `name`
+/// if `rename` is true
+fn spark_field(field: &Field, name: &str, rename: bool) -> Field {
+    let field = field
+        .clone()
+        .with_data_type(spark_type(field.data_type(), rename));
+    match rename {
+        true => field.with_name(name),
+        false => field,
+    }
+}
+
+/// Coerces `data_type` to a type supported by Apache Spark, renaming the children
+/// of lists and maps following its conventions if `rename` is true
+fn spark_type(data_type: &DataType, rename: bool) -> DataType {
+    match data_type {
+        DataType::UInt8 => DataType::Int16,
+        DataType::UInt16 => DataType::Int32,
+        DataType::UInt32 | DataType::UInt64 => DataType::Int64,
+        DataType::Timestamp(_, tz) => {
+            DataType::Timestamp(TimeUnit::Microsecond, tz.clone())
+        }
+        DataType::List(f) => DataType::List(Arc::new(spark_field(f, "element", rename))),
+        DataType::LargeList(f) => {
+            DataType::LargeList(Arc::new(spark_field(f, "element", rename)))
+        }
+        DataType::FixedSizeList(f, size) => {
+            DataType::FixedSizeList(Arc::new(spark_field(f, "element", rename)), *size)
+        }
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|f| spark_field(f, f.name(), rename))
+                .collect(),
+        ),
+        DataType::Map(f, sorted) => match f.data_type() {
+            DataType::Struct(entries) if entries.len() == 2 => {
+                let entries = Fields::from(vec![
+                    spark_field(&entries[0], "key", rename),
+                    spark_field(&entries[1], "value", rename),
+                ]);
+                let entries =
+                    f.as_ref().clone().with_data_type(DataType::Struct(entries));
+                let entries = match rename {
+                    true => entries.with_name("key_value"),
+                    false => entries,
+                };
+                DataType::Map(Arc::new(entries), *sorted)
+            }
+            _ => data_type.clone(),
+        },
+        DataType::Dictionary(k, v) => {
+            DataType::Dictionary(k.clone(), Box::new(spark_type(v, rename)))
+        }
+        _ => data_type.clone(),
+    }
+}
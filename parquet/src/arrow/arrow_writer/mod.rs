@@ -49,9 +49,11 @@ use crate::file::properties::{WriterProperties, WriterPropertiesPtr};
 use crate::file::reader::{ChunkReader, Length};
 use crate::file::writer::SerializedFileWriter;
 use crate::schema::types::{ColumnDescPtr, SchemaDescriptor};
+use coerce::{cast_batch, coerce_schema};
 use levels::{calculate_array_levels, LevelInfo};
 
 mod byte_array;
+mod coerce;
 mod levels;
 
 /// Arrow writer
@@ -93,6 +95,10 @@ pub struct ArrowWriter<W: Write> {
     /// The schema is used to verify that each record batch written has the correct schema
     arrow_schema: SchemaRef,
 
+    /// The schema to cast each record batch to for the
+    /// [`CompatibilityProfile`](crate::file::properties::CompatibilityProfile), if any
+    cast_schema: Option<SchemaRef>,
+
     /// The length of arrays to write to each row group
     max_row_group_size: usize,
 }
@@ -116,14 +122,19 @@ impl<W: Write + Send> ArrowWriter<W> {
     /// The writer will fail if:
     ///  * a `SerializedFileWriter` cannot be created from the ParquetWriter
     ///  * the Arrow schema contains unsupported datatypes such as Unions
+    ///
+    /// The types written are coerced according to [`WriterProperties::compatibility_profile`]
     pub fn try_new(
         writer: W,
         arrow_schema: SchemaRef,
         props: Option<WriterProperties>,
     ) -> Result<Self> {
+        let mut props = props.unwrap_or_default();
+        let coerced = coerce_schema(&arrow_schema, props.compatibility_profile());
+        let arrow_schema = coerced.file;
+
         let schema = arrow_to_parquet_schema(&arrow_schema)?;
         // add serialized arrow schema
-        let mut props = props.unwrap_or_default();
         add_encoded_arrow_schema_to_metadata(&arrow_schema, &mut props);
 
         let max_row_group_size = props.max_row_group_size();
@@ -135,6 +146,7 @@ impl<W: Write + Send> ArrowWriter<W> {
             writer: file_writer,
             in_progress: None,
             arrow_schema,
+            cast_schema: coerced.cast,
             max_row_group_size,
         })
     }
@@ -170,6 +182,17 @@ impl<W: Write + Send> ArrowWriter<W> {
     /// rows, the contents of `batch` will be written to one or more row groups such that all but
     /// the final row group in the file contain [`WriterProperties::max_row_group_size`] rows
     pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        match &self.cast_schema {
+            Some(schema) => {
+                let batch = cast_batch(batch, schema)?;
+                self.write_batch(&batch)
+            }
+            None => self.write_batch(batch),
+        }
+    }
+
+    /// Encodes `batch`, which has already been cast to the coerced types if necessary
+    fn write_batch(&mut self, batch: &RecordBatch) -> Result<()> {
         if batch.num_rows() == 0 {
             return Ok(());
         }
@@ -188,8 +211,8 @@ impl<W: Write + Send> ArrowWriter<W> {
             let to_write = self.max_row_group_size - in_progress.buffered_rows;
             let a = batch.slice(0, to_write);
             let b = batch.slice(to_write, batch.num_rows() - to_write);
-            self.write(&a)?;
-            return self.write(&b);
+            self.write_batch(&a)?;
+            return self.write_batch(&b);
         }
 
         in_progress.write(batch)?;
@@ -541,6 +564,17 @@ fn write_leaf(
                     let array = values.inner().typed_data::<i32>();
                     write_primitive(typed, array, levels)
                 }
+                ArrowDataType::Decimal32(_, _) => {
+                    let array = column.as_primitive::<Decimal32Type>();
+                    write_primitive(typed, array.values(), levels)
+                }
+                ArrowDataType::Decimal64(_, _) => {
+                    // use the int32 to represent the decimal with low precision
+                    let array = column
+                        .as_primitive::<Decimal64Type>()
+                        .unary::<_, Int32Type>(|v| v as i32);
+                    write_primitive(typed, array.values(), levels)
+                }
                 ArrowDataType::Decimal128(_, _) => {
                     // use the int32 to represent the decimal with low precision
                     let array = column
@@ -583,6 +617,16 @@ fn write_leaf(
                     let array = values.inner().typed_data::<i64>();
                     write_primitive(typed, array, levels)
                 }
+                ArrowDataType::Decimal32(_, _) => {
+                    let array = column
+                        .as_primitive::<Decimal32Type>()
+                        .unary::<_, Int64Type>(|v| v as i64);
+                    write_primitive(typed, array.values(), levels)
+                }
+                ArrowDataType::Decimal64(_, _) => {
+                    let array = column.as_primitive::<Decimal64Type>();
+                    write_primitive(typed, array.values(), levels)
+                }
                 ArrowDataType::Decimal128(_, _) => {
                     // use the int64 to represent the decimal with low precision
                     let array = column
@@ -797,7 +841,9 @@ mod tests {
     use crate::data_type::AsBytes;
     use crate::file::metadata::ParquetMetaData;
     use crate::file::page_index::index_reader::read_pages_locations;
-    use crate::file::properties::{ReaderProperties, WriterVersion};
+    use crate::file::properties::{
+        CompatibilityProfile, ReaderProperties, WriterVersion,
+    };
     use crate::file::serialized_reader::ReadOptionsBuilder;
     use crate::file::{
         reader::{FileReader, SerializedFileReader},
@@ -1036,6 +1082,35 @@ mod tests {
         roundtrip(batch_fixed_len_byte_array_decimal, Some(SMALL_SIZE / 2));
     }
 
+    #[test]
+    fn arrow_writer_decimal32_decimal64() {
+        let decimal32 = Decimal32Array::from(vec![Some(12345), None, Some(-1), Some(9)])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        // precision 1 is stored as int64
+        let decimal32_p1 = Decimal32Array::from(vec![Some(1), Some(-9), None, Some(0)])
+            .with_precision_and_scale(1, 0)
+            .unwrap();
+        let decimal64 =
+            Decimal64Array::from(vec![Some(i64::MAX / 10), None, Some(-7), Some(0)])
+                .with_precision_and_scale(18, 4)
+                .unwrap();
+        // precision <= 9 is stored as int32
+        let decimal64_p9 =
+            Decimal64Array::from(vec![Some(999_999_999), None, Some(-7), Some(0)])
+                .with_precision_and_scale(9, 0)
+                .unwrap();
+
+        let batch = RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(decimal32) as ArrayRef),
+            ("b", Arc::new(decimal32_p1) as ArrayRef),
+            ("c", Arc::new(decimal64) as ArrayRef),
+            ("d", Arc::new(decimal64_p9) as ArrayRef),
+        ])
+        .unwrap();
+        roundtrip(batch, Some(SMALL_SIZE / 2));
+    }
+
     #[test]
     fn arrow_writer_complex() {
         // define schema
@@ -2596,6 +2671,119 @@ mod tests {
         assert_eq!(back.column(0).as_ref(), batch.column(0).as_ref());
     }
 
+    #[test]
+    #[cfg(feature = "arrow_canonical_extension_types")]
+    fn test_arrow_writer_extension_types() {
+        use crate::basic::LogicalType;
+        use arrow_schema::extension::{Json, Uuid};
+
+        let schema = Schema::new(vec![
+            Field::new("uuid", DataType::FixedSizeBinary(16), true)
+                .with_extension_type(Uuid),
+            Field::new("json", DataType::Utf8, true).with_extension_type(Json::default()),
+        ]);
+        let uuids = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            vec![Some([1_u8; 16]), None, Some([2_u8; 16])].into_iter(),
+            16,
+        )
+        .unwrap();
+        let json = StringArray::from(vec![Some(r#"{"a":1}"#), Some("[]"), None]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(uuids) as _, Arc::new(json) as _],
+        )
+        .unwrap();
+
+        for file in roundtrip(batch, None) {
+            let reader = SerializedFileReader::new(file).unwrap();
+            let schema = reader.metadata().file_metadata().schema_descr_ptr();
+            assert_eq!(schema.column(1).logical_type(), Some(LogicalType::Json));
+        }
+    }
+
+    #[test]
+    fn test_arrow_writer_spark_compatibility() {
+        let mut map = MapBuilder::new(None, StringBuilder::new(), UInt8Builder::new());
+        map.keys().append_value("a");
+        map.values().append_value(1);
+        map.append(true).unwrap();
+        map.append(false).unwrap();
+        let list = ListArray::from_iter_primitive::<UInt32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+        ]);
+        let ts =
+            TimestampNanosecondArray::from(vec![1_001, -2_000]).with_timezone("+00:00");
+        let batch = RecordBatch::try_from_iter([
+            (
+                "u64",
+                Arc::new(UInt64Array::from(vec![0, i64::MAX as u64])) as ArrayRef,
+            ),
+            ("ts", Arc::new(ts) as _),
+            ("map", Arc::new(map.finish()) as _),
+            ("list", Arc::new(list) as _),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_compatibility_profile(CompatibilityProfile::Spark)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer =
+            ArrowWriter::try_new(&mut buf, batch.schema(), Some(props.clone())).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let reader = SerializedFileReader::new(Bytes::from(buf.clone())).unwrap();
+        let descr = reader.metadata().file_metadata().schema_descr();
+        let paths: Vec<_> = descr.columns().iter().map(|c| c.path().string()).collect();
+        assert_eq!(
+            paths,
+            [
+                "u64",
+                "ts",
+                "map.key_value.key",
+                "map.key_value.value",
+                "list.list.element"
+            ]
+        );
+
+        let mut reader =
+            ParquetRecordBatchReader::try_new(Bytes::from(buf), 1024).unwrap();
+        let read = reader.next().unwrap().unwrap();
+
+        let u64 = read.column(0).as_primitive::<Int64Type>();
+        assert_eq!(u64.values(), &[0, i64::MAX]);
+
+        let ts = read.column(1).as_primitive::<TimestampMicrosecondType>();
+        assert_eq!(ts.values(), &[1, -2]);
+        assert_eq!(ts.timezone(), Some("+00:00"));
+
+        let map = read.column(2).as_map();
+        assert_eq!(map.entries().column_names(), ["key", "value"]);
+        assert_eq!(map.values().as_primitive::<Int16Type>().values(), &[1]);
+        assert!(map.is_null(1));
+
+        let list = read.column(3).as_list::<i32>();
+        let values = list.values().as_primitive::<Int64Type>();
+        assert_eq!(values, &Int64Array::from(vec![Some(1), None]));
+        assert!(list.is_null(1));
+
+        // Values that cannot be represented by the coerced type are an error
+        let batch = RecordBatch::try_from_iter([(
+            "u64",
+            Arc::new(UInt64Array::from(vec![u64::MAX])) as ArrayRef,
+        )])
+        .unwrap();
+        let mut writer =
+            ArrowWriter::try_new(vec![], batch.schema(), Some(props)).unwrap();
+        let err = writer.write(&batch).unwrap_err().to_string();
+        assert!(
+            err.contains("Can't cast value 18446744073709551615 to type Int64"),
+            "{err}"
+        );
+    }
+
     #[test]
     fn in_progress_accounting() {
         // define schema
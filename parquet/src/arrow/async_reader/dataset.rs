@@ -0,0 +1,575 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Formatter;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use arrow_array::{BooleanArray, RecordBatch};
+use arrow_cast::adapter::SchemaAdapter;
+use arrow_schema::{ArrowError, Schema, SchemaRef};
+use futures::stream::{self, BoxStream, Stream};
+use futures::{StreamExt, TryStreamExt};
+use object_store::path::Path;
+use object_store::{ObjectMeta, ObjectStore};
+
+use crate::arrow::arrow_reader::{ArrowPredicateFn, RowFilter};
+use crate::arrow::async_reader::{
+    AsyncFileReader, AsyncReader, HivePartitioning, ParquetObjectReader,
+    ParquetRecordBatchStreamBuilder,
+};
+use crate::arrow::ProjectionMask;
+use crate::errors::{ParquetError, Result};
+
+type DatasetPredicate =
+    Arc<dyn Fn(RecordBatch) -> Result<BooleanArray, ArrowError> + Send + Sync>;
+
+/// A predicate shared by all the files of a [`ParquetDatasetReader`]
+#[derive(Clone)]
+struct DatasetFilter {
+    columns: Vec<String>,
+    predicate: DatasetPredicate,
+}
+
+/// Reads a collection of parquet files in object storage as a single stream of
+/// [`RecordBatch`]
+///
+/// The files are read into a single schema, either provided with [`Self::with_schema`],
+/// or computed by merging the schemas of all files with [`Schema::try_merge`]. Batches
+/// read from each file are mapped to this schema with a [`SchemaAdapter`], such that
+/// columns missing from a file are filled with nulls, and columns of a different type
+/// are cast.
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int64Type;
+/// # use futures::TryStreamExt;
+/// # use object_store::{ObjectStore, path::Path};
+/// # use parquet::arrow::async_reader::ParquetDatasetReader;
+/// # async fn run(store: Arc<dyn ObjectStore>) -> Result<(), Box<dyn std::error::Error>> {
+/// let files = store
+///     .list(Some(&Path::from("dataset")))
+///     .await?
+///     .try_collect::<Vec<_>>()
+///     .await?;
+///
+/// let stream = ParquetDatasetReader::new(store, files)
+///     .with_projection(["id", "name"])
+///     .with_row_filter(["id"], |batch| {
+///         let ids = batch.column(0).as_primitive::<Int64Type>();
+///         Ok(ids.iter().map(|id| id.map(|id| id > 100)).collect())
+///     })
+///     .with_concurrency(4)
+///     .build()
+///     .await?;
+///
+/// let batches = stream.try_collect::<Vec<_>>().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ParquetDatasetReader {
+    store: Arc<dyn ObjectStore>,
+    files: Vec<ObjectMeta>,
+    schema: Option<SchemaRef>,
+    projection: Option<Vec<String>>,
+    filter: Option<DatasetFilter>,
+    partitioning: Option<HivePartitioning>,
+    batch_size: usize,
+    concurrency: usize,
+}
+
+impl std::fmt::Debug for ParquetDatasetReader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParquetDatasetReader")
+            .field("store", &self.store)
+            .field("files", &self.files)
+            .field("schema", &self.schema)
+            .field("projection", &self.projection)
+            .field("filter", &self.filter.as_ref().map(|f| &f.columns))
+            .field("partitioning", &self.partitioning)
+            .field("batch_size", &self.batch_size)
+            .field("concurrency", &self.concurrency)
+            .finish()
+    }
+}
+
+impl ParquetDatasetReader {
+    /// Creates a new [`ParquetDatasetReader`] for the provided [`ObjectStore`] and files
+    ///
+    /// [`ObjectMeta`] can be obtained using [`ObjectStore::list`] or [`ObjectStore::head`]
+    pub fn new(store: Arc<dyn ObjectStore>, files: Vec<ObjectMeta>) -> Self {
+        Self {
+            store,
+            files,
+            schema: None,
+            projection: None,
+            filter: None,
+            partitioning: None,
+            batch_size: 1024,
+            concurrency: 1,
+        }
+    }
+
+    /// Creates a new [`ParquetDatasetReader`] for the files at `paths`, fetching
+    /// their [`ObjectMeta`] with [`ObjectStore::head`]
+    pub async fn try_new_from_paths(
+        store: Arc<dyn ObjectStore>,
+        paths: impl IntoIterator<Item = Path>,
+    ) -> Result<Self> {
+        let files = stream::iter(paths)
+            .map(|path| {
+                let store = Arc::clone(&store);
+                async move { store.head(&path).await }
+            })
+            .buffered(10)
+            .try_collect()
+            .await
+            .map_err(|e| {
+                ParquetError::General(format!("ParquetDatasetReader::head error: {e}"))
+            })?;
+        Ok(Self::new(store, files))
+    }
+
+    /// Read the files into `schema` instead of the merged schema of all files
+    pub fn with_schema(self, schema: SchemaRef) -> Self {
+        Self {
+            schema: Some(schema),
+            ..self
+        }
+    }
+
+    /// Only read the root columns with the provided names, in the provided order
+    pub fn with_projection<I, S>(self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            projection: Some(columns.into_iter().map(Into::into).collect()),
+            ..self
+        }
+    }
+
+    /// Only read rows for which `predicate` returns `true`
+    ///
+    /// `predicate` is evaluated against batches containing the root `columns`, in
+    /// the provided order, mapped to the dataset schema. These columns need not be
+    /// part of the projection. See [`RowFilter`] for more information
+    pub fn with_row_filter<I, S, F>(self, columns: I, predicate: F) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+        F: Fn(RecordBatch) -> Result<BooleanArray, ArrowError> + Send + Sync + 'static,
+    {
+        Self {
+            filter: Some(DatasetFilter {
+                columns: columns.into_iter().map(Into::into).collect(),
+                predicate: Arc::new(predicate),
+            }),
+            ..self
+        }
+    }
+
+    /// Append the partition columns of `partitioning`, parsed from the path of each
+    /// file, to the dataset schema
+    ///
+    /// Partition columns can be used in the projection and row filter, and take
+    /// precedence over any column of the same name within the files
+    pub fn with_partitioning(self, partitioning: HivePartitioning) -> Self {
+        Self {
+            partitioning: Some(partitioning),
+            ..self
+        }
+    }
+
+    /// Set the size of [`RecordBatch`] to produce. Defaults to 1024
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self { batch_size, ..self }
+    }
+
+    /// Set the number of files read concurrently. Defaults to 1
+    ///
+    /// If greater than 1, batches from different files may be interleaved in the
+    /// output, otherwise files are read in the order provided
+    pub fn with_concurrency(self, concurrency: usize) -> Self {
+        Self {
+            concurrency: concurrency.max(1),
+            ..self
+        }
+    }
+
+    /// Fetches the metadata of all files and builds a [`ParquetDatasetStream`]
+    ///
+    /// Returns an error if the schemas of the files cannot be merged, or if a file
+    /// cannot be mapped to the dataset schema
+    pub async fn build(self) -> Result<ParquetDatasetStream> {
+        let store = self.store;
+        let builders: Vec<_> = stream::iter(self.files)
+            .map(|meta| {
+                let location = meta.location.clone();
+                let mut reader = ParquetObjectReader::new(Arc::clone(&store), meta);
+                async move {
+                    let metadata = reader.get_metadata().await?;
+                    let builder = ParquetRecordBatchStreamBuilder::new_builder(
+                        AsyncReader(reader),
+                        metadata,
+                        Default::default(),
+                    )?;
+                    Ok::<_, ParquetError>((location, builder))
+                }
+            })
+            .buffered(self.concurrency)
+            .try_collect()
+            .await?;
+
+        let dataset_schema = match self.schema {
+            Some(schema) => schema,
+            None => {
+                // Ignore schema metadata as it commonly differs between files
+                let schemas = builders
+                    .iter()
+                    .map(|(_, b)| Schema::new(b.schema().fields().clone()));
+                let merged = Schema::try_merge(schemas)?;
+
+                // Fields missing from any file must be nullable
+                let fields: Vec<_> = merged
+                    .fields()
+                    .iter()
+                    .map(|field| {
+                        let missing = builders
+                            .iter()
+                            .any(|(_, b)| b.schema().index_of(field.name()).is_err());
+                        match missing && !field.is_nullable() {
+                            true => Arc::new(field.as_ref().clone().with_nullable(true)),
+                            false => field.clone(),
+                        }
+                    })
+                    .collect();
+                Arc::new(Schema::new(fields))
+            }
+        };
+
+        let dataset_schema = match &self.partitioning {
+            Some(partitioning) => {
+                let mut fields = dataset_schema.fields().to_vec();
+                for field in partitioning.schema().fields() {
+                    if dataset_schema.index_of(field.name()).is_err() {
+                        fields.push(field.clone());
+                    }
+                }
+                let metadata = dataset_schema.metadata().clone();
+                Arc::new(Schema::new_with_metadata(fields, metadata))
+            }
+            None => dataset_schema,
+        };
+        let partitioning = self.partitioning;
+        // The columns of `schema` read from the files
+        let file_columns = |schema: &SchemaRef| match &partitioning {
+            Some(p) => {
+                let fields = schema.fields().iter();
+                let fields = fields.filter(|f| p.schema().index_of(f.name()).is_err());
+                Arc::new(Schema::new(fields.cloned().collect::<Vec<_>>()))
+            }
+            None => schema.clone(),
+        };
+
+        let schema = match &self.projection {
+            Some(columns) => Arc::new(project_by_name(&dataset_schema, columns)?),
+            None => dataset_schema.clone(),
+        };
+        let filter = match self.filter {
+            Some(filter) => {
+                let schema = Arc::new(project_by_name(&dataset_schema, &filter.columns)?);
+                Some((filter, schema))
+            }
+            None => None,
+        };
+
+        let file_schema_target = file_columns(&schema);
+        let adapter = SchemaAdapter::new(file_schema_target.clone());
+        let streams = builders
+            .into_iter()
+            .map(
+                |(location, builder)| -> Result<BoxStream<'static, Result<RecordBatch>>> {
+                    let file_schema = builder.schema().clone();
+                    let partition = match &partitioning {
+                        Some(p) => Some(p.parse(&location)?),
+                        None => None,
+                    };
+
+                    // Read the columns of the dataset schema present in this file
+                    let names = file_schema_target.fields().iter().map(|f| f.name());
+                    let indices = root_indices(&file_schema, names);
+                    let mapping = adapter.map_schema(&file_schema.project(&indices)?)?;
+                    let mask = ProjectionMask::roots(builder.parquet_schema(), indices);
+
+                    let mut builder = builder
+                        .with_projection(mask)
+                        .with_batch_size(self.batch_size);
+
+                    if let Some((filter, filter_schema)) = &filter {
+                        let target = file_columns(filter_schema);
+                        let names = target.fields().iter().map(|f| f.name());
+                        let indices = root_indices(&file_schema, names);
+                        let mapping = SchemaAdapter::new(target)
+                            .map_schema(&file_schema.project(&indices)?)?;
+                        let mask =
+                            ProjectionMask::roots(builder.parquet_schema(), indices);
+                        let predicate = filter.predicate.clone();
+                        let partition = partition.clone();
+                        let filter_schema = filter_schema.clone();
+                        let predicate = ArrowPredicateFn::new(mask, move |batch| {
+                            let batch = mapping.map_batch(&batch)?;
+                            match &partition {
+                                Some(p) => predicate(p.fill(&batch, &filter_schema)?),
+                                None => predicate(batch),
+                            }
+                        });
+                        builder = builder
+                            .with_row_filter(RowFilter::new(vec![Box::new(predicate)]));
+                    }
+
+                    let schema = schema.clone();
+                    let stream =
+                        builder.build()?.map(move |batch| -> Result<RecordBatch> {
+                            let batch = mapping.map_batch(&batch?)?;
+                            match &partition {
+                                Some(p) => p.fill(&batch, &schema),
+                                None => Ok(batch),
+                            }
+                        });
+                    Ok(stream.boxed())
+                },
+            )
+            .collect::<Result<Vec<_>>>()?;
+
+        let inner = match self.concurrency {
+            1 => stream::iter(streams).flatten().boxed(),
+            n => stream::iter(streams).flatten_unordered(n).boxed(),
+        };
+        Ok(ParquetDatasetStream { schema, inner })
+    }
+}
+
+/// Projects `schema` to the fields named `columns`, in the provided order
+fn project_by_name(schema: &Schema, columns: &[String]) -> Result<Schema> {
+    let indices = columns
+        .iter()
+        .map(|c| schema.index_of(c))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(schema.project(&indices)?)
+}
+
+/// Returns the indices of the root fields of `schema` with the provided names, in
+/// ascending order, ignoring any names not present
+fn root_indices<'a>(
+    schema: &Schema,
+    names: impl Iterator<Item = &'a String>,
+) -> Vec<usize> {
+    let mut indices: Vec<_> = names.filter_map(|n| schema.index_of(n).ok()).collect();
+    indices.sort_unstable();
+    indices.dedup();
+    indices
+}
+
+/// An asynchronous stream of [`RecordBatch`] for a dataset, constructed using
+/// [`ParquetDatasetReader`]
+pub struct ParquetDatasetStream {
+    schema: SchemaRef,
+    inner: BoxStream<'static, Result<RecordBatch>>,
+}
+
+impl std::fmt::Debug for ParquetDatasetStream {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParquetDatasetStream")
+            .field("schema", &self.schema)
+            .finish()
+    }
+}
+
+impl ParquetDatasetStream {
+    /// Returns the [`SchemaRef`] of the batches produced by this stream
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+}
+
+impl Stream for ParquetDatasetStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::ArrowWriter;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Float64Type, Int32Type, Int64Type};
+    use arrow_array::{ArrayRef, Float64Array, Int32Array, StringArray};
+    use arrow_schema::{DataType, Field};
+    use bytes::Bytes;
+    use object_store::memory::InMemory;
+
+    async fn put_batch(store: &dyn ObjectStore, path: &str, batch: RecordBatch) {
+        let mut buf = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        store
+            .put(&Path::from(path), Bytes::from(buf))
+            .await
+            .unwrap();
+    }
+
+    async fn test_store() -> Arc<dyn ObjectStore> {
+        let store = Arc::new(InMemory::new());
+
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "z"]));
+        let batch = RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap();
+        put_batch(store.as_ref(), "data/1.parquet", batch).await;
+
+        let c: ArrayRef = Arc::new(Float64Array::from(vec![1.5, 2.5]));
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![4, 5]));
+        let batch = RecordBatch::try_from_iter([("c", c), ("a", a)]).unwrap();
+        put_batch(store.as_ref(), "data/2.parquet", batch).await;
+
+        store
+    }
+
+    #[tokio::test]
+    async fn test_dataset_reader() {
+        let store = test_store().await;
+        let paths = [Path::from("data/1.parquet"), Path::from("data/2.parquet")];
+
+        let reader =
+            ParquetDatasetReader::try_new_from_paths(store.clone(), paths.clone())
+                .await
+                .unwrap();
+        let stream = reader.build().await.unwrap();
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Float64, true),
+        ]);
+        assert_eq!(stream.schema().as_ref(), &expected);
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].column(2).null_count(), 3);
+        assert_eq!(batches[1].column(1).null_count(), 2);
+
+        let reader =
+            ParquetDatasetReader::try_new_from_paths(store.clone(), paths.clone())
+                .await
+                .unwrap()
+                .with_projection(["c", "a"])
+                .with_row_filter(["a"], |batch| {
+                    let a = batch.column(0).as_primitive::<Int32Type>();
+                    Ok(a.iter().map(|v| v.map(|v| v % 2 == 0 || v == 5)).collect())
+                })
+                .with_concurrency(2);
+        let stream = reader.build().await.unwrap();
+        let mut batches: Vec<_> = stream.try_collect().await.unwrap();
+        batches.sort_unstable_by_key(|b| b.num_rows());
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].column(0).null_count(), 1);
+        assert_eq!(
+            batches[0].column(1).as_primitive::<Int32Type>().values(),
+            &[2]
+        );
+        let c = batches[1].column(0).as_primitive::<Float64Type>();
+        assert_eq!(c.values(), &[1.5, 2.5]);
+        assert_eq!(
+            batches[1].column(1).as_primitive::<Int32Type>().values(),
+            &[4, 5]
+        );
+
+        // Cast to a provided schema
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("d", DataType::Utf8, true),
+        ]));
+        let reader =
+            ParquetDatasetReader::try_new_from_paths(store.clone(), paths.clone())
+                .await
+                .unwrap()
+                .with_schema(schema.clone());
+        let stream = reader.build().await.unwrap();
+        assert_eq!(stream.schema(), &schema);
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        let a: Vec<_> = batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int64Type>().values().to_vec())
+            .collect();
+        assert_eq!(a, vec![1, 2, 3, 4, 5]);
+
+        // A non-nullable column missing from a file
+        let schema = Arc::new(Schema::new(vec![Field::new("b", DataType::Utf8, false)]));
+        let reader = ParquetDatasetReader::try_new_from_paths(store, paths)
+            .await
+            .unwrap()
+            .with_schema(schema);
+        let err = reader.build().await.unwrap_err().to_string();
+        assert!(err.contains("non-nullable field 'b' is missing"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_dataset_partitioning() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let paths = [
+            Path::from("t/year=2020/0.parquet"),
+            Path::from("t/year=2021/0.parquet"),
+        ];
+        for (idx, path) in paths.iter().enumerate() {
+            let a: ArrayRef = Arc::new(Int32Array::from(vec![idx as i32; 2]));
+            let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+            put_batch(store.as_ref(), path.as_ref(), batch).await;
+        }
+
+        let partitioning = HivePartitioning::try_infer(&paths).unwrap();
+        let reader = ParquetDatasetReader::try_new_from_paths(store, paths)
+            .await
+            .unwrap()
+            .with_partitioning(partitioning)
+            .with_projection(["year", "a"])
+            .with_row_filter(["year"], |batch| {
+                let year = batch.column(0).as_primitive::<Int64Type>();
+                Ok(year.iter().map(|y| y.map(|y| y == 2021)).collect())
+            });
+        let stream = reader.build().await.unwrap();
+        let expected = Schema::new(vec![
+            Field::new("year", DataType::Int64, true),
+            Field::new("a", DataType::Int32, false),
+        ]);
+        assert_eq!(stream.schema().as_ref(), &expected);
+
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        assert_eq!(batches.len(), 1);
+        let year = batches[0].column(0).as_primitive::<Int64Type>();
+        assert_eq!(year.values(), &[2021, 2021]);
+        let a = batches[0].column(1).as_primitive::<Int32Type>();
+        assert_eq!(a.values(), &[1, 1]);
+    }
+}
@@ -96,8 +96,8 @@ use arrow_schema::SchemaRef;
 
 use crate::arrow::array_reader::{build_array_reader, RowGroups};
 use crate::arrow::arrow_reader::{
-    apply_range, evaluate_predicate, selects_any, ArrowReaderBuilder, ArrowReaderOptions,
-    ParquetRecordBatchReader, RowFilter, RowSelection,
+    apply_range, evaluate_predicate, selects_any, with_row_numbers, ArrowReaderBuilder,
+    ArrowReaderOptions, ParquetRecordBatchReader, RowFilter, RowSelection,
 };
 use crate::arrow::ProjectionMask;
 
@@ -114,11 +114,19 @@ use crate::file::FOOTER_SIZE;
 mod metadata;
 pub use metadata::*;
 
+#[cfg(feature = "object_store")]
+mod dataset;
+#[cfg(feature = "object_store")]
+mod partition;
 #[cfg(feature = "object_store")]
 mod store;
 
 use crate::arrow::schema::ParquetField;
 #[cfg(feature = "object_store")]
+pub use dataset::*;
+#[cfg(feature = "object_store")]
+pub use partition::*;
+#[cfg(feature = "object_store")]
 pub use store::*;
 
 /// The asynchronous interface used by [`ParquetRecordBatchStream`] to read parquet files
@@ -275,6 +283,7 @@ impl<T: AsyncFileReader + Send + 'static> ArrowReaderBuilder<AsyncReader<T>> {
             fields: self.fields,
             limit: self.limit,
             offset: self.offset,
+            row_number_column: self.row_number_column,
         };
 
         Ok(ParquetRecordBatchStream {
@@ -306,6 +315,8 @@ struct ReaderFactory<T> {
     limit: Option<usize>,
 
     offset: Option<usize>,
+
+    row_number_column: Option<String>,
 }
 
 impl<T> ReaderFactory<T>
@@ -401,11 +412,13 @@ where
             .fetch(&mut self.input, &projection, selection.as_ref())
             .await?;
 
-        let reader = ParquetRecordBatchReader::new(
-            batch_size,
+        let array_reader = with_row_numbers(
             build_array_reader(self.fields.as_ref(), &projection, &row_group)?,
-            selection,
-        );
+            self.row_number_column.as_deref(),
+            &self.metadata,
+            [row_group_idx],
+        )?;
+        let reader = ParquetRecordBatchReader::new(batch_size, array_reader, selection);
 
         Ok((self, Some(reader)))
     }
@@ -757,8 +770,8 @@ mod tests {
     use crate::file::properties::WriterProperties;
     use arrow::error::Result as ArrowResult;
     use arrow_array::cast::AsArray;
-    use arrow_array::types::Int32Type;
-    use arrow_array::{Array, ArrayRef, Int32Array, StringArray};
+    use arrow_array::types::{Int32Type, Int64Type};
+    use arrow_array::{Array, ArrayRef, BooleanArray, Int32Array, StringArray};
     use futures::TryStreamExt;
     use rand::{thread_rng, Rng};
     use std::sync::Mutex;
@@ -1312,6 +1325,59 @@ mod tests {
         assert_eq!(col2.values(), &[4, 5]);
     }
 
+    #[tokio::test]
+    async fn test_row_number_column() {
+        let c = Int32Array::from_iter(0..6);
+        let data = RecordBatch::try_from_iter([("c", Arc::new(c) as ArrayRef)]).unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(3)
+            .build();
+        let mut writer =
+            ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+
+        let data: Bytes = buf.into();
+        let metadata = parse_metadata(&data).unwrap();
+        let parquet_schema = metadata.file_metadata().schema_descr_ptr();
+
+        let test = TestReader {
+            data,
+            metadata: Arc::new(metadata),
+            requests: Default::default(),
+        };
+
+        let filter = ArrowPredicateFn::new(
+            ProjectionMask::leaves(&parquet_schema, vec![0]),
+            |batch| {
+                let c = batch.column(0).as_primitive::<Int32Type>();
+                Ok(BooleanArray::from_iter(c.iter().map(|x| x.map(|x| x != 4))))
+            },
+        );
+
+        let stream = ParquetRecordBatchStreamBuilder::new(test)
+            .await
+            .unwrap()
+            .with_row_groups(vec![1])
+            .with_row_filter(RowFilter::new(vec![Box::new(filter)]))
+            .with_row_number_column("row_number")
+            .build()
+            .unwrap();
+
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        assert_eq!(batches.len(), 1);
+
+        let batch = &batches[0];
+        assert_eq!(batch.num_columns(), 2);
+        assert_eq!(batch.schema().field(1).name(), "row_number");
+        let col = batch.column(0).as_primitive::<Int32Type>();
+        assert_eq!(col.values(), &[3, 5]);
+        let row_numbers = batch.column(1).as_primitive::<Int64Type>();
+        assert_eq!(row_numbers.values(), &[3, 5]);
+    }
+
     #[tokio::test]
     async fn test_row_filter_with_index() {
         let testdata = arrow::util::test_util::parquet_test_data();
@@ -1399,6 +1465,7 @@ mod tests {
             metadata.file_metadata().schema_descr(),
             ProjectionMask::all(),
             None,
+            Default::default(),
         )
         .unwrap();
 
@@ -1414,6 +1481,7 @@ mod tests {
             filter: None,
             limit: None,
             offset: None,
+            row_number_column: None,
         };
 
         let mut skip = true;
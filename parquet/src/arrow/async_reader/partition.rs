@@ -0,0 +1,395 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use arrow_array::{
+    new_null_array, ArrayRef, RecordBatch, RecordBatchOptions, StringArray, UInt32Array,
+};
+use arrow_cast::{cast, cast_with_options, CastOptions};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use arrow_select::take::take;
+use object_store::path::Path;
+
+use crate::errors::{ParquetError, Result};
+
+/// The value of a partition column that is `null`, as written by Hive and Spark
+pub const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Returns the `key=value` segments of the directories of `path`, in order
+///
+/// Both keys and values are percent-decoded, as special characters are escaped by
+/// Hive and Spark, e.g. `city=New%20York`. The file name is ignored
+///
+/// ```
+/// # use object_store::path::Path;
+/// # use parquet::arrow::async_reader::parse_hive_partitions;
+/// let path = Path::from("data/year=2023/month=01/part-0.parquet");
+/// let partitions = parse_hive_partitions(&path);
+/// assert_eq!(partitions, vec![
+///     ("year".to_string(), "2023".to_string()),
+///     ("month".to_string(), "01".to_string()),
+/// ]);
+/// ```
+pub fn parse_hive_partitions(path: &Path) -> Vec<(String, String)> {
+    let parts: Vec<_> = path.parts().collect();
+    let directories = &parts[..parts.len().saturating_sub(1)];
+    directories
+        .iter()
+        .filter_map(|part| {
+            let (key, value) = part.as_ref().split_once('=')?;
+            Some((percent_decode(key), percent_decode(value)))
+        })
+        .collect()
+}
+
+/// Decodes `%XX` escapes in `s`, leaving invalid escapes unchanged
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let decoded = match bytes[idx] {
+            b'%' if idx + 2 < bytes.len() => {
+                std::str::from_utf8(&bytes[idx + 1..idx + 3])
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            }
+            _ => None,
+        };
+        match decoded {
+            Some(b) => {
+                out.push(b);
+                idx += 3;
+            }
+            None => {
+                out.push(bytes[idx]);
+                idx += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Hive-style partitioning, where the files of a dataset are stored in directories
+/// named `key=value`, such as `year=2023/month=01/part-0.parquet`
+///
+/// The keys form the columns of the partition [`Schema`], which can be provided
+/// with [`Self::new`] or inferred from a listing with [`Self::try_infer`]
+#[derive(Debug, Clone)]
+pub struct HivePartitioning {
+    schema: SchemaRef,
+}
+
+impl HivePartitioning {
+    /// Creates a new [`HivePartitioning`] with the provided partition schema
+    ///
+    /// Values are parsed from the path by casting them to the type of the field
+    pub fn new(schema: SchemaRef) -> Self {
+        Self { schema }
+    }
+
+    /// Infers the [`HivePartitioning`] of the files at `paths`
+    ///
+    /// Every path must contain the same partition keys, in the same order. The type
+    /// of each partition column is the first of `Int64`, `Float64`, `Boolean` and
+    /// `Date32` that all its values can be parsed as, otherwise `Utf8`. All columns
+    /// are nullable, with [`HIVE_DEFAULT_PARTITION`] parsed as `null`
+    pub fn try_infer<'a>(paths: impl IntoIterator<Item = &'a Path>) -> Result<Self> {
+        let mut keys: Option<Vec<String>> = None;
+        let mut values: Vec<Vec<String>> = vec![];
+
+        for path in paths {
+            let partitions = parse_hive_partitions(path);
+            match &keys {
+                None => {
+                    keys = Some(partitions.iter().map(|(k, _)| k.clone()).collect());
+                    values = vec![vec![]; partitions.len()];
+                }
+                Some(keys) => {
+                    if !partitions.iter().map(|(k, _)| k).eq(keys.iter()) {
+                        return Err(general_err!(
+                            "Inconsistent partition keys for {}, expected {:?}",
+                            path,
+                            keys
+                        ));
+                    }
+                }
+            }
+            for (idx, (_, value)) in partitions.into_iter().enumerate() {
+                if value != HIVE_DEFAULT_PARTITION {
+                    values[idx].push(value);
+                }
+            }
+        }
+
+        let fields: Vec<_> = keys
+            .unwrap_or_default()
+            .into_iter()
+            .zip(values)
+            .map(|(key, values)| Field::new(key, infer_type(values), true))
+            .collect();
+        Ok(Self::new(Arc::new(Schema::new(fields))))
+    }
+
+    /// Returns the partition [`SchemaRef`]
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Parses the [`PartitionValues`] of the file at `path`
+    ///
+    /// Returns an error if a value cannot be parsed as the type of its column, or if
+    /// a non-nullable column is missing from `path`
+    pub fn parse(&self, path: &Path) -> Result<PartitionValues> {
+        let partitions = parse_hive_partitions(path);
+        let options = CastOptions::new().with_safe(false);
+
+        let values =
+            self.schema
+                .fields()
+                .iter()
+                .map(|field| {
+                    let value = partitions
+                        .iter()
+                        .find(|(k, _)| k == field.name())
+                        .map(|(_, v)| v.as_str())
+                        .filter(|v| *v != HIVE_DEFAULT_PARTITION);
+
+                    match value {
+                        Some(value) => {
+                            let array = StringArray::from(vec![value]);
+                            cast_with_options(&array, field.data_type(), &options)
+                                .map_err(|e| {
+                                    general_err!(
+                                "Failed to parse partition value '{}' of {} in {}: {}",
+                                value,
+                                field.name(),
+                                path,
+                                e
+                            )
+                                })
+                        }
+                        None if field.is_nullable() => {
+                            Ok(new_null_array(field.data_type(), 1))
+                        }
+                        None => Err(general_err!(
+                            "Missing partition value for non-nullable {} in {}",
+                            field.name(),
+                            path
+                        )),
+                    }
+                })
+                .collect::<Result<_>>()?;
+
+        Ok(PartitionValues {
+            schema: self.schema.clone(),
+            values,
+        })
+    }
+}
+
+/// Returns the narrowest type all `values` can be parsed as
+fn infer_type(values: Vec<String>) -> DataType {
+    if values.is_empty() {
+        return DataType::Utf8;
+    }
+    if values.iter().all(|v| v.parse::<i64>().is_ok()) {
+        return DataType::Int64;
+    }
+    if values.iter().all(|v| v.parse::<f64>().is_ok()) {
+        return DataType::Float64;
+    }
+    if values
+        .iter()
+        .all(|v| v.eq_ignore_ascii_case("true") || v.eq_ignore_ascii_case("false"))
+    {
+        return DataType::Boolean;
+    }
+
+    let array = StringArray::from(values);
+    let options = CastOptions::new().with_safe(false);
+    match cast_with_options(&array, &DataType::Date32, &options) {
+        Ok(_) => DataType::Date32,
+        Err(_) => DataType::Utf8,
+    }
+}
+
+/// The values of the partition columns of a file, see [`HivePartitioning::parse`]
+#[derive(Debug, Clone)]
+pub struct PartitionValues {
+    schema: SchemaRef,
+    /// A single element array for each field of `schema`
+    values: Vec<ArrayRef>,
+}
+
+impl PartitionValues {
+    /// Returns the partition [`SchemaRef`]
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Returns the value of each partition column, as an array of length 1
+    pub fn values(&self) -> &[ArrayRef] {
+        &self.values
+    }
+
+    /// Returns the value of each partition column repeated `num_rows` times
+    pub fn to_columns(&self, num_rows: usize) -> Result<Vec<ArrayRef>> {
+        let indices = UInt32Array::from(vec![0; num_rows]);
+        Ok(self
+            .values
+            .iter()
+            .map(|v| take(v.as_ref(), &indices, None))
+            .collect::<Result<_, _>>()?)
+    }
+
+    /// Appends the partition columns to `batch`
+    pub fn append_to(&self, batch: &RecordBatch) -> Result<RecordBatch> {
+        let schema = batch.schema();
+        let fields = schema.fields().iter().chain(self.schema.fields().iter());
+        let schema = Schema::new_with_metadata(
+            fields.cloned().collect::<Vec<_>>(),
+            schema.metadata().clone(),
+        );
+
+        let mut columns = batch.columns().to_vec();
+        columns.extend(self.to_columns(batch.num_rows())?);
+        Ok(RecordBatch::try_new(Arc::new(schema), columns)?)
+    }
+
+    /// Returns a [`RecordBatch`] with `schema`, containing the partition columns
+    /// of `schema`, and the remaining columns of `batch` matched by name
+    pub(crate) fn fill(
+        &self,
+        batch: &RecordBatch,
+        schema: &SchemaRef,
+    ) -> Result<RecordBatch> {
+        let indices = UInt32Array::from(vec![0; batch.num_rows()]);
+        let columns = schema
+            .fields()
+            .iter()
+            .map(|field| match self.schema.index_of(field.name()) {
+                Ok(idx) => {
+                    let value = cast(&self.values[idx], field.data_type())?;
+                    Ok(take(value.as_ref(), &indices, None)?)
+                }
+                Err(_) => batch.column_by_name(field.name()).cloned().ok_or_else(|| {
+                    general_err!("Missing column {} in batch", field.name())
+                }),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+        Ok(RecordBatch::try_new_with_options(
+            schema.clone(),
+            columns,
+            &options,
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Date32Type, Int64Type};
+    use arrow_array::{Array, Int32Array};
+
+    #[test]
+    fn test_parse_hive_partitions() {
+        let path = Path::from("data/a=1/b=x=y/c/part=0.parquet");
+        assert_eq!(
+            parse_hive_partitions(&path),
+            vec![
+                ("a".to_string(), "1".to_string()),
+                ("b".to_string(), "x=y".to_string())
+            ]
+        );
+        assert!(parse_hive_partitions(&Path::from("part-0.parquet")).is_empty());
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz%41"), "%zzA");
+        assert_eq!(percent_decode("New%20York%3A"), "New York:");
+    }
+
+    #[test]
+    fn test_infer_partitioning() {
+        let paths = [
+            Path::from("t/i=1/f=1.5/b=true/d=2023-01-02/s=x/0.parquet"),
+            Path::from("t/i=2/f=2/b=FALSE/d=__HIVE_DEFAULT_PARTITION__/s=1/0.parquet"),
+        ];
+        let partitioning = HivePartitioning::try_infer(&paths).unwrap();
+        let expected = Schema::new(vec![
+            Field::new("i", DataType::Int64, true),
+            Field::new("f", DataType::Float64, true),
+            Field::new("b", DataType::Boolean, true),
+            Field::new("d", DataType::Date32, true),
+            Field::new("s", DataType::Utf8, true),
+        ]);
+        assert_eq!(partitioning.schema().as_ref(), &expected);
+
+        let values = partitioning.parse(&paths[1]).unwrap();
+        let values = values.values();
+        assert_eq!(values[0].as_primitive::<Int64Type>().value(0), 2);
+        assert!(!values[2].as_boolean().value(0));
+        assert!(values[3].is_null(0));
+        assert_eq!(values[4].as_string::<i32>().value(0), "1");
+
+        let values = partitioning.parse(&paths[0]).unwrap();
+        let date = values.values()[3].as_primitive::<Date32Type>().value(0);
+        assert_eq!(date, 19359);
+
+        let paths = [Path::from("t/a=1/0.parquet"), Path::from("t/b=1/0.parquet")];
+        let err = HivePartitioning::try_infer(&paths).unwrap_err().to_string();
+        assert!(err.contains("Inconsistent partition keys"), "{err}");
+    }
+
+    #[test]
+    fn test_partition_values() {
+        let schema = Schema::new(vec![
+            Field::new("year", DataType::Int64, false),
+            Field::new("city", DataType::Utf8, true),
+        ]);
+        let partitioning = HivePartitioning::new(Arc::new(schema));
+
+        let err = partitioning
+            .parse(&Path::from("year=abc/0.parquet"))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("Failed to parse partition value 'abc'"),
+            "{err}"
+        );
+
+        let err = partitioning
+            .parse(&Path::from("city=x/0.parquet"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Missing partition value"), "{err}");
+
+        let values = partitioning
+            .parse(&Path::from("year=2020/0.parquet"))
+            .unwrap();
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+        let batch = values.append_to(&batch).unwrap();
+        assert_eq!(batch.num_columns(), 3);
+        let year = batch.column(1).as_primitive::<Int64Type>();
+        assert_eq!(year.values(), &[2020, 2020, 2020]);
+        assert_eq!(batch.column(2).null_count(), 3);
+    }
+}
@@ -0,0 +1,338 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Arrow [`ExtensionType`]s corresponding to parquet logical types
+//!
+//! When reading, fields with these logical types are annotated with the
+//! corresponding extension type, unless the embedded arrow schema specifies
+//! another extension type. When writing, fields annotated with these extension
+//! types are written with the corresponding logical type.
+//!
+//! | Logical Type | Extension Type                       | Storage Type                    |
+//! |--------------|--------------------------------------|---------------------------------|
+//! | `VARIANT`    | [`Variant`]                          | [`DataType::Struct`]            |
+//! | `JSON`       | [`Json`](arrow_schema::extension::Json) | [`DataType::Utf8`], [`DataType::LargeUtf8`] |
+//! | `GEOMETRY`   | `Wkb`                                | [`DataType::Binary`], [`DataType::LargeBinary`] |
+//! | `GEOGRAPHY`  | `Wkb`                                | [`DataType::Binary`], [`DataType::LargeBinary`] |
+//!
+//! The `JSON` mapping requires the `arrow_canonical_extension_types` feature, and
+//! the `GEOMETRY` and `GEOGRAPHY` mappings require the `geospatial` feature
+
+#[cfg(feature = "geospatial")]
+use crate::basic::EdgeInterpolationAlgorithm;
+use crate::basic::LogicalType;
+#[cfg(feature = "geospatial")]
+use crate::errors::Result;
+use arrow_schema::extension::ExtensionType;
+use arrow_schema::{ArrowError, DataType, Field};
+
+/// The parquet Variant extension type, representing semi-structured values
+/// encoded as described by the [Variant specification]
+///
+/// The storage type is a [`DataType::Struct`] with a binary `metadata` and
+/// `value` field, and an optional `typed_value` field containing the shredded
+/// values
+///
+/// [Variant specification]: https://github.com/apache/parquet-format/blob/master/VariantEncoding.md
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Variant;
+
+impl ExtensionType for Variant {
+    const NAME: &'static str = "arrow.parquet.variant";
+
+    type Metadata = ();
+
+    fn metadata(&self) -> &Self::Metadata {
+        &()
+    }
+
+    fn serialize_metadata(&self) -> Option<String> {
+        None
+    }
+
+    fn deserialize_metadata(
+        metadata: Option<&str>,
+    ) -> Result<Self::Metadata, ArrowError> {
+        match metadata {
+            None | Some("") => Ok(()),
+            Some(_) => Err(ArrowError::InvalidArgumentError(
+                "Variant extension type expects no metadata".to_string(),
+            )),
+        }
+    }
+
+    fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+        let fields = match data_type {
+            DataType::Struct(fields) => fields,
+            d => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Variant data type mismatch, expected struct found {d}"
+                )))
+            }
+        };
+
+        let has = |name: &str| fields.find(name).is_some();
+        let valid = has("metadata")
+            && (has("value") || has("typed_value"))
+            && fields.iter().all(|f| match f.name().as_str() {
+                "metadata" | "value" => {
+                    matches!(f.data_type(), DataType::Binary | DataType::LargeBinary)
+                }
+                "typed_value" => true,
+                _ => false,
+            });
+
+        match valid {
+            true => Ok(()),
+            false => Err(ArrowError::InvalidArgumentError(format!(
+                "Variant data type mismatch, expected struct with binary metadata and value fields found {data_type}"
+            ))),
+        }
+    }
+
+    fn try_new(
+        data_type: &DataType,
+        _metadata: Self::Metadata,
+    ) -> Result<Self, ArrowError> {
+        Self.supports_data_type(data_type).map(|_| Self)
+    }
+}
+
+/// The [GeoArrow] `geoarrow.wkb` extension type, representing geospatial features
+/// in the Well-Known Binary (WKB) format
+///
+/// The CRS and edges interpolation algorithm are stored in the JSON extension
+/// metadata, and correspond to the parameters of the parquet `GEOMETRY` and
+/// `GEOGRAPHY` logical types. Planar edges are written as `GEOMETRY`, and any
+/// other edges as `GEOGRAPHY`
+///
+/// [GeoArrow]: https://geoarrow.org/extension-types.html
+#[cfg(feature = "geospatial")]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Wkb(WkbMetadata);
+
+/// The metadata of a [`Wkb`] extension type
+#[cfg(feature = "geospatial")]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct WkbMetadata {
+    /// The coordinate reference system, `None` for the parquet default of `OGC:CRS84`
+    ///
+    /// A PROJJSON object is stored as its serialized JSON string
+    pub crs: Option<String>,
+    /// The edges interpolation algorithm, `None` for planar edges
+    pub edges: Option<EdgeInterpolationAlgorithm>,
+}
+
+#[cfg(feature = "geospatial")]
+impl Wkb {
+    /// Creates a new [`Wkb`] with the provided [`WkbMetadata`]
+    pub fn new(metadata: WkbMetadata) -> Self {
+        Self(metadata)
+    }
+
+    /// Returns the parquet [`LogicalType`] corresponding to this extension type
+    pub fn logical_type(&self) -> LogicalType {
+        match self.0.edges {
+            None => LogicalType::Geometry {
+                crs: self.0.crs.clone(),
+            },
+            Some(algorithm) => LogicalType::Geography {
+                crs: self.0.crs.clone(),
+                algorithm: Some(algorithm),
+            },
+        }
+    }
+
+    /// Returns the [`Wkb`] extension type corresponding to `logical_type`, if any
+    pub fn from_logical_type(logical_type: &LogicalType) -> Option<Self> {
+        let metadata = match logical_type {
+            LogicalType::Geometry { crs } => WkbMetadata {
+                crs: crs.clone(),
+                edges: None,
+            },
+            LogicalType::Geography { crs, algorithm } => WkbMetadata {
+                crs: crs.clone(),
+                edges: Some(algorithm.unwrap_or(EdgeInterpolationAlgorithm::SPHERICAL)),
+            },
+            _ => return None,
+        };
+        Some(Self(metadata))
+    }
+}
+
+#[cfg(feature = "geospatial")]
+fn edges_name(algorithm: EdgeInterpolationAlgorithm) -> Option<&'static str> {
+    Some(match algorithm {
+        EdgeInterpolationAlgorithm::SPHERICAL => "spherical",
+        EdgeInterpolationAlgorithm::VINCENTY => "vincenty",
+        EdgeInterpolationAlgorithm::THOMAS => "thomas",
+        EdgeInterpolationAlgorithm::ANDOYER => "andoyer",
+        EdgeInterpolationAlgorithm::KARNEY => "karney",
+        _ => return None,
+    })
+}
+
+#[cfg(feature = "geospatial")]
+impl ExtensionType for Wkb {
+    const NAME: &'static str = "geoarrow.wkb";
+
+    type Metadata = WkbMetadata;
+
+    fn metadata(&self) -> &Self::Metadata {
+        &self.0
+    }
+
+    fn serialize_metadata(&self) -> Option<String> {
+        use serde_json::{Map, Value};
+
+        let mut map = Map::new();
+        if let Some(crs) = &self.0.crs {
+            // Preserve PROJJSON objects as JSON
+            let crs = match serde_json::from_str::<Value>(crs) {
+                Ok(v @ Value::Object(_)) => v,
+                _ => Value::String(crs.clone()),
+            };
+            map.insert("crs".to_string(), crs);
+        }
+        if let Some(edges) = self.0.edges.and_then(edges_name) {
+            map.insert("edges".to_string(), Value::String(edges.to_string()));
+        }
+        Some(Value::Object(map).to_string())
+    }
+
+    fn deserialize_metadata(
+        metadata: Option<&str>,
+    ) -> Result<Self::Metadata, ArrowError> {
+        use serde_json::Value;
+
+        let err = |msg: String| ArrowError::InvalidArgumentError(msg);
+        let map = match metadata {
+            None | Some("") => return Ok(WkbMetadata::default()),
+            Some(metadata) => match serde_json::from_str::<Value>(metadata) {
+                Ok(Value::Object(map)) => map,
+                _ => {
+                    return Err(err(format!(
+                    "Wkb extension type metadata must be a JSON object, found {metadata}"
+                )))
+                }
+            },
+        };
+
+        let crs = match map.get("crs") {
+            None | Some(Value::Null) => None,
+            Some(Value::String(crs)) => Some(crs.clone()),
+            Some(v @ Value::Object(_)) => Some(v.to_string()),
+            Some(v) => return Err(err(format!("Invalid Wkb crs {v}"))),
+        };
+
+        let edges = match map.get("edges") {
+            None | Some(Value::Null) => None,
+            Some(Value::String(s)) if s == "planar" => None,
+            Some(Value::String(s)) => {
+                let algorithm = EdgeInterpolationAlgorithm::ENUM_VALUES
+                    .iter()
+                    .find(|a| edges_name(**a) == Some(s.as_str()))
+                    .ok_or_else(|| err(format!("Unsupported Wkb edges {s}")))?;
+                Some(*algorithm)
+            }
+            Some(v) => return Err(err(format!("Invalid Wkb edges {v}"))),
+        };
+
+        Ok(WkbMetadata { crs, edges })
+    }
+
+    fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+        match data_type {
+            DataType::Binary | DataType::LargeBinary => Ok(()),
+            d => Err(ArrowError::InvalidArgumentError(format!(
+                "Wkb data type mismatch, expected Binary or LargeBinary found {d}"
+            ))),
+        }
+    }
+
+    fn try_new(
+        data_type: &DataType,
+        metadata: Self::Metadata,
+    ) -> Result<Self, ArrowError> {
+        let wkb = Self(metadata);
+        wkb.supports_data_type(data_type)?;
+        Ok(wkb)
+    }
+}
+
+/// Returns the geospatial [`LogicalType`] of `field` if it is annotated with the
+/// [`Wkb`] extension type, returning an error if the extension type is invalid
+#[cfg(feature = "geospatial")]
+pub(crate) fn geospatial_logical_type(field: &Field) -> Result<Option<LogicalType>> {
+    match field.extension_type_name() {
+        Some(name) if name == Wkb::NAME => {
+            Ok(Some(field.try_extension_type::<Wkb>()?.logical_type()))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Annotates `field` with the [`ExtensionType`] corresponding to `logical_type`, if
+/// any, unless `field` is already annotated with an extension type or its data type
+/// is not supported by the extension type
+pub(crate) fn with_logical_extension_type(
+    field: Field,
+    logical_type: Option<&LogicalType>,
+) -> Field {
+    if field.extension_type_name().is_some() {
+        return field;
+    }
+    match logical_type {
+        Some(LogicalType::Variant)
+            if Variant.supports_data_type(field.data_type()).is_ok() =>
+        {
+            field.with_extension_type(Variant)
+        }
+        #[cfg(feature = "arrow_canonical_extension_types")]
+        Some(LogicalType::Json) => {
+            use arrow_schema::extension::Json;
+            match Json::default().supports_data_type(field.data_type()) {
+                Ok(_) => field.with_extension_type(Json::default()),
+                Err(_) => field,
+            }
+        }
+        #[cfg(feature = "geospatial")]
+        Some(l @ (LogicalType::Geometry { .. } | LogicalType::Geography { .. })) => {
+            match Wkb::from_logical_type(l) {
+                Some(wkb) if wkb.supports_data_type(field.data_type()).is_ok() => {
+                    field.with_extension_type(wkb)
+                }
+                _ => field,
+            }
+        }
+        _ => field,
+    }
+}
+
+/// Returns the [`LogicalType`] corresponding to the [`ExtensionType`] of `field`,
+/// if any
+pub(crate) fn logical_type_of_extension(field: &Field) -> Option<LogicalType> {
+    let name = field.extension_type_name()?;
+    if name == Variant::NAME {
+        return Some(LogicalType::Variant);
+    }
+    #[cfg(feature = "arrow_canonical_extension_types")]
+    if name == arrow_schema::extension::Json::NAME {
+        return Some(LogicalType::Json);
+    }
+    None
+}
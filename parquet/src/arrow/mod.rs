@@ -106,6 +106,7 @@ pub mod arrow_reader;
 pub mod arrow_writer;
 mod buffer;
 mod decoder;
+pub mod extension;
 
 #[cfg(feature = "async")]
 pub mod async_reader;
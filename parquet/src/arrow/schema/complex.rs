@@ -18,9 +18,11 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::arrow::arrow_reader::Int96Format;
+use crate::arrow::extension::with_logical_extension_type;
 use crate::arrow::schema::primitive::convert_primitive;
 use crate::arrow::ProjectionMask;
-use crate::basic::{ConvertedType, Repetition};
+use crate::basic::{ConvertedType, LogicalType, Repetition};
 use crate::errors::ParquetError;
 use crate::errors::Result;
 use crate::schema::types::{SchemaDescriptor, Type, TypePtr};
@@ -127,6 +129,9 @@ struct Visitor {
 
     /// Mask of columns to include
     mask: ProjectionMask,
+
+    /// The arrow representation of `INT96` columns
+    int96_format: Int96Format,
 }
 
 impl Visitor {
@@ -145,7 +150,8 @@ impl Visitor {
         let repetition = get_repetition(primitive_type);
         let (def_level, rep_level, nullable) = context.levels(repetition);
 
-        let arrow_type = convert_primitive(primitive_type, context.data_type)?;
+        let arrow_type =
+            convert_primitive(primitive_type, context.data_type, self.int96_format.clone())?;
 
         let primitive_field = ParquetField {
             rep_level,
@@ -528,7 +534,9 @@ impl Visitor {
 /// Computes the [`Field`] for a child column
 ///
 /// The resulting [`Field`] will have the type dictated by `field`, a name
-/// dictated by the `parquet_type`, and any metadata from `arrow_hint`
+/// dictated by the `parquet_type`, and any metadata from `arrow_hint`. If `arrow_hint`
+/// specifies no extension type, the extension type corresponding to the logical type
+/// of `parquet_type` is used, if any
 fn convert_field(
     parquet_type: &Type,
     field: &ParquetField,
@@ -538,7 +546,13 @@ fn convert_field(
     let data_type = field.arrow_type.clone();
     let nullable = field.nullable;
 
-    match arrow_hint {
+    let info = parquet_type.get_basic_info();
+    let logical_type = match (info.logical_type(), info.converted_type()) {
+        (None, ConvertedType::JSON) => Some(LogicalType::Json),
+        (logical_type, _) => logical_type,
+    };
+
+    let field = match arrow_hint {
         Some(hint) => {
             // If the inferred type is a dictionary, preserve dictionary metadata
             let field = match (&data_type, hint.dict_id(), hint.dict_is_ordered()) {
@@ -551,22 +565,26 @@ fn convert_field(
             field.with_metadata(hint.metadata().clone())
         }
         None => Field::new(name, data_type, nullable),
-    }
+    };
+    with_logical_extension_type(field, logical_type.as_ref())
 }
 
 /// Computes the [`ParquetField`] for the provided [`SchemaDescriptor`] with `leaf_columns` listing
-/// the indexes of leaf columns to project, and `embedded_arrow_schema` the optional
-/// [`Fields`] embedded in the parquet metadata
+/// the indexes of leaf columns to project, `embedded_arrow_schema` the optional
+/// [`Fields`] embedded in the parquet metadata, and `int96_format` the arrow
+/// representation of `INT96` columns
 ///
 /// Note: This does not support out of order column projection
 pub fn convert_schema(
     schema: &SchemaDescriptor,
     mask: ProjectionMask,
     embedded_arrow_schema: Option<&Fields>,
+    int96_format: Int96Format,
 ) -> Result<Option<ParquetField>> {
     let mut visitor = Visitor {
         next_col_idx: 0,
         mask,
+        int96_format,
     };
 
     let context = VisitorContext {
@@ -583,6 +601,7 @@ pub fn convert_type(parquet_type: &TypePtr) -> Result<ParquetField> {
     let mut visitor = Visitor {
         next_col_idx: 0,
         mask: ProjectionMask::all(),
+        int96_format: Default::default(),
     };
 
     let context = VisitorContext {
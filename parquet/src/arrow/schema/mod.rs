@@ -42,6 +42,10 @@ use crate::schema::types::{ColumnDescriptor, SchemaDescriptor, Type, TypePtr};
 mod complex;
 mod primitive;
 
+use crate::arrow::arrow_reader::Int96Format;
+#[cfg(feature = "geospatial")]
+use crate::arrow::extension::geospatial_logical_type;
+use crate::arrow::extension::{logical_type_of_extension, Variant};
 use crate::arrow::ProjectionMask;
 pub(crate) use complex::{ParquetField, ParquetFieldType};
 
@@ -67,14 +71,21 @@ pub fn parquet_to_arrow_schema_by_columns(
     mask: ProjectionMask,
     key_value_metadata: Option<&Vec<KeyValue>>,
 ) -> Result<Schema> {
-    Ok(parquet_to_arrow_schema_and_fields(parquet_schema, mask, key_value_metadata)?.0)
+    Ok(parquet_to_arrow_schema_and_fields(
+        parquet_schema,
+        mask,
+        key_value_metadata,
+        Default::default(),
+    )?
+    .0)
 }
 
-/// Extracts the arrow metadata
+/// Extracts the arrow metadata, reading `INT96` columns with `int96_format`
 pub(crate) fn parquet_to_arrow_schema_and_fields(
     parquet_schema: &SchemaDescriptor,
     mask: ProjectionMask,
     key_value_metadata: Option<&Vec<KeyValue>>,
+    int96_format: Int96Format,
 ) -> Result<(Schema, Option<ParquetField>)> {
     let mut metadata = parse_key_value_metadata(key_value_metadata).unwrap_or_default();
     let maybe_schema = metadata
@@ -90,7 +101,7 @@ pub(crate) fn parquet_to_arrow_schema_and_fields(
     }
 
     let hint = maybe_schema.as_ref().map(|s| s.fields());
-    let field_levels = parquet_to_arrow_field_levels(parquet_schema, mask, hint)?;
+    let field_levels = field_levels(parquet_schema, mask, hint, int96_format)?;
     let schema = Schema::new_with_metadata(field_levels.fields, metadata);
     Ok((schema, field_levels.levels))
 }
@@ -127,7 +138,16 @@ pub fn parquet_to_arrow_field_levels(
     mask: ProjectionMask,
     hint: Option<&Fields>,
 ) -> Result<FieldLevels> {
-    match complex::convert_schema(schema, mask, hint)? {
+    field_levels(schema, mask, hint, Default::default())
+}
+
+fn field_levels(
+    schema: &SchemaDescriptor,
+    mask: ProjectionMask,
+    hint: Option<&Fields>,
+    int96_format: Int96Format,
+) -> Result<FieldLevels> {
+    match complex::convert_schema(schema, mask, hint, int96_format)? {
         Some(field) => match &field.arrow_type {
             DataType::Struct(fields) => Ok(FieldLevels {
                 fields: fields.clone(),
@@ -155,8 +175,8 @@ fn get_arrow_schema_from_metadata(encoded_meta: &str) -> Result<Schema> {
             match arrow_ipc::root_as_message(slice) {
                 Ok(message) => message
                     .header_as_schema()
-                    .map(arrow_ipc::convert::fb_to_schema)
-                    .ok_or_else(|| arrow_err!("the message is not Arrow Schema")),
+                    .ok_or_else(|| arrow_err!("the message is not Arrow Schema"))
+                    .and_then(|s| Ok(arrow_ipc::convert::try_fb_to_schema(s)?)),
                 Err(err) => {
                     // The flatbuffers implementation returns an error on verification error.
                     Err(arrow_err!(
@@ -433,7 +453,12 @@ fn arrow_to_parquet_type(field: &Field) -> Result<Type> {
                 .build()
         }
         DataType::Binary | DataType::LargeBinary => {
+            #[cfg(feature = "geospatial")]
+            let logical_type = geospatial_logical_type(field)?;
+            #[cfg(not(feature = "geospatial"))]
+            let logical_type = None;
             Type::primitive_type_builder(name, PhysicalType::BYTE_ARRAY)
+                .with_logical_type(logical_type)
                 .with_repetition(repetition)
                 .build()
         }
@@ -443,7 +468,9 @@ fn arrow_to_parquet_type(field: &Field) -> Result<Type> {
                 .with_length(*length)
                 .build()
         }
-        DataType::Decimal128(precision, scale)
+        DataType::Decimal32(precision, scale)
+        | DataType::Decimal64(precision, scale)
+        | DataType::Decimal128(precision, scale)
         | DataType::Decimal256(precision, scale) => {
             // Decimal precision determines the Parquet physical type to use.
             // Following the: https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#decimal
@@ -469,8 +496,12 @@ fn arrow_to_parquet_type(field: &Field) -> Result<Type> {
                 .build()
         }
         DataType::Utf8 | DataType::LargeUtf8 => {
+            let logical_type = match logical_type_of_extension(field) {
+                Some(LogicalType::Json) => LogicalType::Json,
+                _ => LogicalType::String,
+            };
             Type::primitive_type_builder(name, PhysicalType::BYTE_ARRAY)
-                .with_logical_type(Some(LogicalType::String))
+                .with_logical_type(Some(logical_type))
                 .with_repetition(repetition)
                 .build()
         }
@@ -497,8 +528,16 @@ fn arrow_to_parquet_type(field: &Field) -> Result<Type> {
                 .iter()
                 .map(|f| arrow_to_parquet_type(f).map(Arc::new))
                 .collect();
+            let logical_type = match logical_type_of_extension(field) {
+                Some(LogicalType::Variant) => {
+                    field.try_extension_type::<Variant>()?;
+                    Some(LogicalType::Variant)
+                }
+                _ => None,
+            };
             Type::group_type_builder(name)
                 .with_fields(&mut fields?)
+                .with_logical_type(logical_type)
                 .with_repetition(repetition)
                 .build()
         }
@@ -534,12 +573,16 @@ fn arrow_to_parquet_type(field: &Field) -> Result<Type> {
         DataType::Union(_, _) => unimplemented!("See ARROW-8817."),
         DataType::Dictionary(_, ref value) => {
             // Dictionary encoding not handled at the schema level
-            let dict_field = Field::new(name, *value.clone(), field.is_nullable());
+            let dict_field = Field::new(name, *value.clone(), field.is_nullable())
+                .with_metadata(field.metadata().clone());
             arrow_to_parquet_type(&dict_field)
         }
         DataType::RunEndEncoded(_, _) => Err(arrow_err!(
             "Converting RunEndEncodedType to parquet not supported",
         )),
+        DataType::ListView(_) | DataType::LargeListView(_) => Err(arrow_err!(
+            "Converting ListViewType to parquet not supported",
+        )),
     }
 }
 
@@ -551,6 +594,8 @@ mod tests {
 
     use arrow::datatypes::{DataType, Field, IntervalUnit, TimeUnit};
 
+    use arrow_schema::extension::{ExtensionType, EXTENSION_TYPE_NAME_KEY};
+
     use crate::file::metadata::KeyValue;
     use crate::{
         arrow::{arrow_reader::ParquetRecordBatchReaderBuilder, ArrowWriter},
@@ -558,6 +603,7 @@ mod tests {
     };
 
     #[test]
+    #[cfg(not(feature = "arrow_canonical_extension_types"))]
     fn test_flat_primitives() {
         let message_type = "
         message test_schema {
@@ -599,6 +645,35 @@ mod tests {
         assert_eq!(&arrow_fields, converted_arrow_schema.fields());
     }
 
+    #[test]
+    #[cfg(feature = "arrow_canonical_extension_types")]
+    fn test_flat_primitives_canonical_extension_types() {
+        use arrow_schema::extension::Json;
+
+        let message_type = "
+        message test_schema {
+            REQUIRED BOOLEAN boolean;
+            REQUIRED INT32   int32;
+            OPTIONAL BINARY  string (UTF8);
+            OPTIONAL BINARY  json (JSON);
+        }
+        ";
+        let parquet_group_type = parse_message_type(message_type).unwrap();
+
+        let parquet_schema = SchemaDescriptor::new(Arc::new(parquet_group_type));
+        let converted_arrow_schema =
+            parquet_to_arrow_schema(&parquet_schema, None).unwrap();
+
+        let arrow_fields = Fields::from(vec![
+            Field::new("boolean", DataType::Boolean, false),
+            Field::new("int32", DataType::Int32, false),
+            Field::new("string", DataType::Utf8, true),
+            Field::new("json", DataType::Utf8, true).with_extension_type(Json::default()),
+        ]);
+
+        assert_eq!(&arrow_fields, converted_arrow_schema.fields());
+    }
+
     #[test]
     fn test_decimal_fields() {
         let message_type = "
@@ -1529,6 +1604,134 @@ mod tests {
         converted_arrow_schema.unwrap();
     }
 
+    #[test]
+    fn test_variant_schema() {
+        let message_type = "
+        message arrow_schema {
+          OPTIONAL GROUP variant (VARIANT) {
+            REQUIRED BINARY metadata;
+            REQUIRED BINARY value;
+          }
+        }
+        ";
+        let parquet_group_type = parse_message_type(message_type).unwrap();
+        let parquet_schema = SchemaDescriptor::new(Arc::new(parquet_group_type.clone()));
+        let arrow_schema = parquet_to_arrow_schema(&parquet_schema, None).unwrap();
+
+        let fields = Fields::from(vec![
+            Field::new("metadata", DataType::Binary, false),
+            Field::new("value", DataType::Binary, false),
+        ]);
+        let expected = Field::new("variant", DataType::Struct(fields), true)
+            .with_extension_type(Variant);
+        assert_eq!(arrow_schema.field(0), &expected);
+
+        let converted = arrow_to_parquet_schema(&arrow_schema).unwrap();
+        assert_eq!(converted.root_schema(), &parquet_group_type);
+
+        let fields = Fields::from(vec![Field::new("a", DataType::Int32, false)]);
+        let invalid = Field::new("variant", DataType::Struct(fields), true).with_metadata(
+            HashMap::from([(
+                EXTENSION_TYPE_NAME_KEY.to_string(),
+                Variant::NAME.to_string(),
+            )]),
+        );
+        let err = arrow_to_parquet_schema(&Schema::new(vec![invalid])).unwrap_err();
+        assert!(err.to_string().contains("Variant data type mismatch"), "{err}");
+    }
+
+    #[test]
+    #[cfg(feature = "arrow_canonical_extension_types")]
+    fn test_json_schema() {
+        use arrow_schema::extension::Json;
+
+        let message_type = "
+        message arrow_schema {
+          OPTIONAL BINARY json (JSON);
+          REQUIRED BINARY string (STRING);
+        }
+        ";
+        let parquet_group_type = parse_message_type(message_type).unwrap();
+        let parquet_schema = SchemaDescriptor::new(Arc::new(parquet_group_type.clone()));
+        let arrow_schema = parquet_to_arrow_schema(&parquet_schema, None).unwrap();
+
+        let expected = Schema::new(vec![
+            Field::new("json", DataType::Utf8, true).with_extension_type(Json::default()),
+            Field::new("string", DataType::Utf8, false),
+        ]);
+        assert_eq!(arrow_schema, expected);
+
+        let converted = arrow_to_parquet_schema(&arrow_schema).unwrap();
+        assert_eq!(converted.root_schema(), &parquet_group_type);
+    }
+
+    #[test]
+    #[cfg(feature = "geospatial")]
+    fn test_geospatial_schema() {
+        use crate::arrow::extension::{Wkb, WkbMetadata};
+        use crate::basic::EdgeInterpolationAlgorithm;
+        use arrow_schema::extension::EXTENSION_TYPE_METADATA_KEY;
+
+        let geometry = LogicalType::Geometry {
+            crs: Some("srid:4326".to_string()),
+        };
+        let geography = LogicalType::Geography {
+            crs: None,
+            algorithm: Some(EdgeInterpolationAlgorithm::KARNEY),
+        };
+        let mut fields: Vec<TypePtr> = [("geometry", geometry), ("geography", geography)]
+            .into_iter()
+            .map(|(name, logical_type)| {
+                let tp = Type::primitive_type_builder(name, PhysicalType::BYTE_ARRAY)
+                    .with_repetition(Repetition::OPTIONAL)
+                    .with_logical_type(Some(logical_type))
+                    .build();
+                Arc::new(tp.unwrap())
+            })
+            .collect();
+        let parquet_group_type = Type::group_type_builder("arrow_schema")
+            .with_fields(&mut fields)
+            .build()
+            .unwrap();
+        let parquet_schema = SchemaDescriptor::new(Arc::new(parquet_group_type.clone()));
+        let arrow_schema = parquet_to_arrow_schema(&parquet_schema, None).unwrap();
+
+        let expected = Schema::new(vec![
+            Field::new("geometry", DataType::Binary, true).with_extension_type(Wkb::new(
+                WkbMetadata {
+                    crs: Some("srid:4326".to_string()),
+                    edges: None,
+                },
+            )),
+            Field::new("geography", DataType::Binary, true).with_extension_type(
+                Wkb::new(WkbMetadata {
+                    crs: None,
+                    edges: Some(EdgeInterpolationAlgorithm::KARNEY),
+                }),
+            ),
+        ]);
+        assert_eq!(arrow_schema, expected);
+        assert_eq!(
+            arrow_schema.field(1).extension_type_metadata(),
+            Some(r#"{"edges":"karney"}"#)
+        );
+
+        let converted = arrow_to_parquet_schema(&arrow_schema).unwrap();
+        assert_eq!(converted.root_schema(), &parquet_group_type);
+
+        let invalid = Field::new("geometry", DataType::Binary, true).with_metadata(
+            HashMap::from([
+                (EXTENSION_TYPE_NAME_KEY.to_string(), Wkb::NAME.to_string()),
+                (
+                    EXTENSION_TYPE_METADATA_KEY.to_string(),
+                    r#"{"edges":"unknown"}"#.to_string(),
+                ),
+            ]),
+        );
+        let err = arrow_to_parquet_schema(&Schema::new(vec![invalid])).unwrap_err();
+        assert!(err.to_string().contains("Unsupported Wkb edges"), "{err}");
+    }
+
     #[test]
     fn test_metadata() {
         let message_type = "
@@ -15,22 +15,27 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::arrow::arrow_reader::Int96Format;
 use crate::basic::{
     ConvertedType, LogicalType, TimeUnit as ParquetTimeUnit, Type as PhysicalType,
 };
 use crate::errors::{ParquetError, Result};
 use crate::schema::types::{BasicTypeInfo, Type};
-use arrow_schema::{DataType, IntervalUnit, TimeUnit, DECIMAL128_MAX_PRECISION};
+use arrow_schema::{
+    DataType, IntervalUnit, TimeUnit, DECIMAL128_MAX_PRECISION, DECIMAL32_MAX_PRECISION,
+    DECIMAL64_MAX_PRECISION,
+};
 
 /// Converts [`Type`] to [`DataType`] with an optional `arrow_type_hint`
-/// provided by the arrow schema
+/// provided by the arrow schema, reading `INT96` columns with `int96_format`
 ///
 /// Note: the values embedded in the schema are advisory,
 pub fn convert_primitive(
     parquet_type: &Type,
     arrow_type_hint: Option<DataType>,
+    int96_format: Int96Format,
 ) -> Result<DataType> {
-    let physical_type = from_parquet(parquet_type)?;
+    let physical_type = from_parquet(parquet_type, int96_format)?;
     Ok(match arrow_type_hint {
         Some(hint) => apply_hint(physical_type, hint),
         None => physical_type,
@@ -65,6 +70,18 @@ fn apply_hint(parquet: DataType, hint: DataType) -> DataType {
         // Promote to Decimal256
         (DataType::Decimal128(_, _), DataType::Decimal256(_, _)) => hint,
 
+        // Read narrow decimals as Decimal32 or Decimal64
+        (DataType::Decimal128(p, _), DataType::Decimal32(_, _))
+            if *p <= DECIMAL32_MAX_PRECISION =>
+        {
+            hint
+        }
+        (DataType::Decimal128(p, _), DataType::Decimal64(_, _))
+            if *p <= DECIMAL64_MAX_PRECISION =>
+        {
+            hint
+        }
+
         // Potentially preserve dictionary encoding
         (_, DataType::Dictionary(_, value)) => {
             // Apply hint to inner type
@@ -81,7 +98,7 @@ fn apply_hint(parquet: DataType, hint: DataType) -> DataType {
     }
 }
 
-fn from_parquet(parquet_type: &Type) -> Result<DataType> {
+fn from_parquet(parquet_type: &Type, int96_format: Int96Format) -> Result<DataType> {
     match parquet_type {
         Type::PrimitiveType {
             physical_type,
@@ -94,7 +111,10 @@ fn from_parquet(parquet_type: &Type) -> Result<DataType> {
             PhysicalType::BOOLEAN => Ok(DataType::Boolean),
             PhysicalType::INT32 => from_int32(basic_info, *scale, *precision),
             PhysicalType::INT64 => from_int64(basic_info, *scale, *precision),
-            PhysicalType::INT96 => Ok(DataType::Timestamp(TimeUnit::Nanosecond, None)),
+            PhysicalType::INT96 => Ok(match int96_format {
+                Int96Format::Timestamp(unit) => DataType::Timestamp(unit, None),
+                Int96Format::FixedSizeBinary => DataType::FixedSizeBinary(12),
+            }),
             PhysicalType::FLOAT => Ok(DataType::Float32),
             PhysicalType::DOUBLE => Ok(DataType::Float64),
             PhysicalType::BYTE_ARRAY => from_byte_array(basic_info, *precision, *scale),
@@ -255,6 +275,8 @@ fn from_byte_array(info: &BasicTypeInfo, precision: i32, scale: i32) -> Result<D
         (Some(LogicalType::Json), _) => Ok(DataType::Utf8),
         (Some(LogicalType::Bson), _) => Ok(DataType::Binary),
         (Some(LogicalType::Enum), _) => Ok(DataType::Binary),
+        (Some(LogicalType::Geometry { .. }), _) => Ok(DataType::Binary),
+        (Some(LogicalType::Geography { .. }), _) => Ok(DataType::Binary),
         (None, ConvertedType::NONE) => Ok(DataType::Binary),
         (None, ConvertedType::JSON) => Ok(DataType::Utf8),
         (None, ConvertedType::BSON) => Ok(DataType::Binary),
@@ -27,8 +27,9 @@ use crate::errors::{ParquetError, Result};
 
 // Re-export crate::format types used in this module
 pub use crate::format::{
-    BsonType, DateType, DecimalType, EnumType, IntType, JsonType, ListType, MapType,
-    NullType, StringType, TimeType, TimeUnit, TimestampType, UUIDType,
+    BsonType, DateType, DecimalType, EdgeInterpolationAlgorithm, EnumType, GeographyType,
+    GeometryType, IntType, JsonType, ListType, MapType, NullType, StringType, TimeType,
+    TimeUnit, TimestampType, UUIDType, VariantType,
 };
 
 // ----------------------------------------------------------------------
@@ -193,6 +194,31 @@ pub enum LogicalType {
     Json,
     Bson,
     Uuid,
+    /// A semi-structured value encoded in a group with a binary `metadata` and
+    /// `value` field, see the [Variant specification]
+    ///
+    /// [Variant specification]: https://github.com/apache/parquet-format/blob/master/VariantEncoding.md
+    Variant,
+    /// Geospatial features in the Well-Known Binary (WKB) format with linear/planar
+    /// edges interpolation, see the [Geospatial specification]
+    ///
+    /// If `crs` is `None` it defaults to `"OGC:CRS84"`
+    ///
+    /// [Geospatial specification]: https://github.com/apache/parquet-format/blob/master/Geospatial.md
+    Geometry {
+        crs: Option<String>,
+    },
+    /// Geospatial features in the Well-Known Binary (WKB) format with an explicit
+    /// edges interpolation algorithm, see the [Geospatial specification]
+    ///
+    /// If `crs` is `None` it defaults to `"OGC:CRS84"`, and if `algorithm` is `None`
+    /// it defaults to [`EdgeInterpolationAlgorithm::SPHERICAL`]
+    ///
+    /// [Geospatial specification]: https://github.com/apache/parquet-format/blob/master/Geospatial.md
+    Geography {
+        crs: Option<String>,
+        algorithm: Option<EdgeInterpolationAlgorithm>,
+    },
 }
 
 // ----------------------------------------------------------------------
@@ -372,13 +398,18 @@ impl ColumnOrder {
                     true => SortOrder::SIGNED,
                     false => SortOrder::UNSIGNED,
                 },
-                LogicalType::Map | LogicalType::List => SortOrder::UNDEFINED,
+                LogicalType::Map | LogicalType::List | LogicalType::Variant => {
+                    SortOrder::UNDEFINED
+                }
                 LogicalType::Decimal { .. } => SortOrder::SIGNED,
                 LogicalType::Date => SortOrder::SIGNED,
                 LogicalType::Time { .. } => SortOrder::SIGNED,
                 LogicalType::Timestamp { .. } => SortOrder::SIGNED,
                 LogicalType::Unknown => SortOrder::UNDEFINED,
                 LogicalType::Uuid => SortOrder::UNSIGNED,
+                LogicalType::Geometry { .. } | LogicalType::Geography { .. } => {
+                    SortOrder::UNDEFINED
+                }
             },
             // Fall back to converted type
             None => Self::get_converted_sort_order(converted_type, physical_type),
@@ -651,6 +682,12 @@ impl From<parquet::LogicalType> for LogicalType {
             parquet::LogicalType::JSON(_) => LogicalType::Json,
             parquet::LogicalType::BSON(_) => LogicalType::Bson,
             parquet::LogicalType::UUID(_) => LogicalType::Uuid,
+            parquet::LogicalType::VARIANT(_) => LogicalType::Variant,
+            parquet::LogicalType::GEOMETRY(t) => LogicalType::Geometry { crs: t.crs },
+            parquet::LogicalType::GEOGRAPHY(t) => LogicalType::Geography {
+                crs: t.crs,
+                algorithm: t.algorithm,
+            },
         }
     }
 }
@@ -691,6 +728,13 @@ impl From<LogicalType> for parquet::LogicalType {
             LogicalType::Json => parquet::LogicalType::JSON(Default::default()),
             LogicalType::Bson => parquet::LogicalType::BSON(Default::default()),
             LogicalType::Uuid => parquet::LogicalType::UUID(Default::default()),
+            LogicalType::Variant => parquet::LogicalType::VARIANT(VariantType::new(None)),
+            LogicalType::Geometry { crs } => {
+                parquet::LogicalType::GEOMETRY(GeometryType { crs })
+            }
+            LogicalType::Geography { crs, algorithm } => {
+                parquet::LogicalType::GEOGRAPHY(GeographyType { crs, algorithm })
+            }
         }
     }
 }
@@ -742,6 +786,9 @@ impl From<Option<LogicalType>> for ConvertedType {
                 LogicalType::Json => ConvertedType::JSON,
                 LogicalType::Bson => ConvertedType::BSON,
                 LogicalType::Uuid => ConvertedType::NONE,
+                LogicalType::Variant => ConvertedType::NONE,
+                LogicalType::Geometry { .. } => ConvertedType::NONE,
+                LogicalType::Geography { .. } => ConvertedType::NONE,
             },
             None => ConvertedType::NONE,
         }
@@ -987,6 +1034,12 @@ impl str::FromStr for LogicalType {
             "JSON" => Ok(LogicalType::Json),
             "BSON" => Ok(LogicalType::Bson),
             "UUID" => Ok(LogicalType::Uuid),
+            "VARIANT" => Ok(LogicalType::Variant),
+            "GEOMETRY" => Ok(LogicalType::Geometry { crs: None }),
+            "GEOGRAPHY" => Ok(LogicalType::Geography {
+                crs: None,
+                algorithm: None,
+            }),
             "UNKNOWN" => Ok(LogicalType::Unknown),
             "INTERVAL" => Err(general_err!(
                 "Interval parquet logical type not yet supported"
@@ -1633,6 +1686,21 @@ mod tests {
             ConvertedType::from(Some(LogicalType::Uuid)),
             ConvertedType::NONE
         );
+        assert_eq!(
+            ConvertedType::from(Some(LogicalType::Variant)),
+            ConvertedType::NONE
+        );
+        assert_eq!(
+            ConvertedType::from(Some(LogicalType::Geometry { crs: None })),
+            ConvertedType::NONE
+        );
+        assert_eq!(
+            ConvertedType::from(Some(LogicalType::Geography {
+                crs: None,
+                algorithm: None
+            })),
+            ConvertedType::NONE
+        );
         assert_eq!(
             ConvertedType::from(Some(LogicalType::Enum)),
             ConvertedType::ENUM
@@ -2018,7 +2086,16 @@ mod tests {
         check_sort_order(signed, SortOrder::SIGNED);
 
         // Undefined comparison
-        let undefined = vec![LogicalType::List, LogicalType::Map];
+        let undefined = vec![
+            LogicalType::List,
+            LogicalType::Map,
+            LogicalType::Variant,
+            LogicalType::Geometry { crs: None },
+            LogicalType::Geography {
+                crs: Some("OGC:CRS84".to_string()),
+                algorithm: Some(EdgeInterpolationAlgorithm::KARNEY),
+            },
+        ];
         check_sort_order(undefined, SortOrder::UNDEFINED);
     }
 
@@ -0,0 +1,242 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Binary file to convert between Parquet and CSV or newline delimited JSON.
+//!
+//! # Install
+//!
+//! `parquet-convert` can be installed using `cargo`:
+//! ```
+//! cargo install parquet --features=cli,convert
+//! ```
+//! After this `parquet-convert` should be available:
+//! ```
+//! parquet-convert XYZ.parquet XYZ.csv
+//! parquet-convert --columns id,name --limit 100 XYZ.parquet XYZ.json
+//! parquet-convert XYZ.csv XYZ.parquet
+//! ```
+//!
+//! The binary can also be built from the source code and run as follows:
+//! ```
+//! cargo run --features=cli,convert --bin parquet-convert XYZ.parquet XYZ.csv
+//! ```
+//!
+//! The format of each file is determined by its extension, unless overridden with
+//! `--input-format` or `--output-format`. When converting to Parquet the schema is
+//! inferred from the input.
+
+use clap::{Parser, ValueEnum};
+use parquet::convert::{
+    parquet_to_text, text_to_parquet, ExportOptions, ImportOptions, TextFormat,
+};
+use parquet::errors::{ParquetError, Result};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Seek, SeekFrom};
+use std::path::Path;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Parquet,
+    Csv,
+    Json,
+}
+
+impl Format {
+    fn from_path(path: &str) -> Result<Self> {
+        let extension = Path::new(path).extension().and_then(|e| e.to_str());
+        match extension.map(|e| e.to_ascii_lowercase()).as_deref() {
+            Some("parquet") => Ok(Self::Parquet),
+            Some("csv") => Ok(Self::Csv),
+            Some("json" | "ndjson" | "jsonl") => Ok(Self::Json),
+            _ => Err(ParquetError::General(format!(
+                "Unable to determine format of {path}, specify it explicitly"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+#[clap(author, version)]
+/// Converts between Parquet and CSV or newline delimited JSON
+struct Args {
+    /// Path to input
+    input: String,
+
+    /// Path to output
+    output: String,
+
+    /// Format of the input, inferred from its extension if not specified
+    #[clap(long)]
+    input_format: Option<Format>,
+
+    /// Format of the output, inferred from its extension if not specified
+    #[clap(long)]
+    output_format: Option<Format>,
+
+    /// Comma separated list of columns to export from Parquet
+    #[clap(long, value_delimiter = ',')]
+    columns: Option<Vec<String>>,
+
+    /// Maximum number of rows to export from Parquet
+    #[clap(long)]
+    limit: Option<usize>,
+
+    /// Number of rows read and written at a time
+    #[clap(long, default_value_t = 1024)]
+    batch_size: usize,
+
+    /// The CSV input has no header row
+    #[clap(long)]
+    no_header: bool,
+
+    /// Field delimiter of CSV input
+    #[clap(long, default_value_t = ',')]
+    delimiter: char,
+}
+
+impl Args {
+    fn run(&self) -> Result<()> {
+        let input_format = match self.input_format {
+            Some(f) => f,
+            None => Format::from_path(&self.input)?,
+        };
+        let output_format = match self.output_format {
+            Some(f) => f,
+            None => Format::from_path(&self.output)?,
+        };
+
+        let output = BufWriter::new(File::create(&self.output)?);
+        let rows = match (input_format, output_format) {
+            (Format::Parquet, Format::Parquet) => {
+                return Err(ParquetError::General(
+                    "Either the input or output must be CSV or JSON".to_string(),
+                ))
+            }
+            (Format::Parquet, format) => self.export(output, text_format(format))?,
+            (format, Format::Parquet) => self.import(output, text_format(format))?,
+            _ => {
+                return Err(ParquetError::General(
+                    "Either the input or output must be Parquet".to_string(),
+                ))
+            }
+        };
+
+        eprintln!("Converted {rows} rows");
+        Ok(())
+    }
+
+    fn export(&self, output: BufWriter<File>, format: TextFormat) -> Result<usize> {
+        let mut options = ExportOptions::new().with_batch_size(self.batch_size);
+        if let Some(columns) = &self.columns {
+            options = options.with_columns(columns.iter().cloned());
+        }
+        if let Some(limit) = self.limit {
+            options = options.with_limit(limit);
+        }
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        runtime.block_on(async {
+            let input = tokio::fs::File::open(&self.input).await?;
+            parquet_to_text(input, output, format, options).await
+        })
+    }
+
+    fn import(&self, output: BufWriter<File>, format: TextFormat) -> Result<usize> {
+        let delimiter = u8::try_from(self.delimiter).map_err(|_| {
+            ParquetError::General(format!("Invalid delimiter {}", self.delimiter))
+        })?;
+
+        let mut input = BufReader::new(File::open(&self.input)?);
+        let schema = match format {
+            TextFormat::Csv => {
+                let (schema, _) = arrow_csv::reader::Format::default()
+                    .with_header(!self.no_header)
+                    .with_delimiter(delimiter)
+                    .infer_schema(&mut input, None)?;
+                schema
+            }
+            TextFormat::Json => {
+                arrow_json::reader::infer_json_schema_from_seekable(&mut input, None)?
+            }
+        };
+        input.seek(SeekFrom::Start(0))?;
+
+        let options = ImportOptions::new()
+            .with_header(!self.no_header)
+            .with_delimiter(delimiter)
+            .with_batch_size(self.batch_size);
+        text_to_parquet(input, output, Arc::new(schema), format, options)
+    }
+}
+
+fn text_format(format: Format) -> TextFormat {
+    match format {
+        Format::Csv => TextFormat::Csv,
+        Format::Json => TextFormat::Json,
+        Format::Parquet => unreachable!(),
+    }
+}
+
+fn main() -> Result<()> {
+    Args::parse().run()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(args: &[&str]) -> Result<()> {
+        let args = Args::try_parse_from(
+            std::iter::once("parquet-convert").chain(args.iter().copied()),
+        )
+        .map_err(|e| ParquetError::General(e.to_string()))?;
+        args.run()
+    }
+
+    #[test]
+    fn test_csv_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = |name: &str| dir.path().join(name).to_str().unwrap().to_string();
+        let (csv, parquet, json) =
+            (path("in.csv"), path("out.parquet"), path("out.json"));
+        std::fs::write(&csv, "id,name\n1,foo\n2,bar\n3,baz\n").unwrap();
+
+        run(&[&csv, &parquet]).unwrap();
+        run(&[&parquet, &json, "--columns", "name", "--limit", "2"]).unwrap();
+
+        let json = std::fs::read_to_string(&json).unwrap();
+        assert_eq!(json, "{\"name\":\"foo\"}\n{\"name\":\"bar\"}\n");
+    }
+
+    #[test]
+    fn test_unknown_format() {
+        let err = run(&["in.txt", "out.parquet"]).unwrap_err().to_string();
+        assert!(
+            err.contains("Unable to determine format of in.txt"),
+            "{err}"
+        );
+
+        let err = run(&["in.csv", "out.json"]).unwrap_err().to_string();
+        assert!(
+            err.contains("Either the input or output must be Parquet"),
+            "{err}"
+        );
+    }
+}
@@ -22,9 +22,10 @@ use crate::column::writer::{
     update_min,
 };
 use crate::data_type::private::ParquetValueType;
-use crate::data_type::DataType;
+use crate::data_type::{AsBytes, DataType};
 use crate::encodings::encoding::{get_encoder, DictEncoder, Encoder};
 use crate::errors::{ParquetError, Result};
+use crate::file::geospatial::{GeospatialStatistics, GeospatialStatisticsAccumulator};
 use crate::file::properties::{EnabledStatistics, WriterProperties};
 use crate::schema::types::{ColumnDescPtr, ColumnDescriptor};
 use crate::util::memory::ByteBufferPtr;
@@ -121,6 +122,10 @@ pub trait ColumnValueEncoder {
     /// will *not* be tracked by the bloom filter as it is empty since. This should be called once
     /// near the end of encoding.
     fn flush_bloom_filter(&mut self) -> Option<Sbbf>;
+
+    /// Returns the geospatial statistics of the values written, if computed for this
+    /// column, resetting them. This should be called once near the end of encoding.
+    fn flush_geospatial_statistics(&mut self) -> Option<GeospatialStatistics>;
 }
 
 pub struct ColumnValueEncoderImpl<T: DataType> {
@@ -132,6 +137,7 @@ pub struct ColumnValueEncoderImpl<T: DataType> {
     min_value: Option<T::T>,
     max_value: Option<T::T>,
     bloom_filter: Option<Sbbf>,
+    geospatial_statistics: Option<GeospatialStatisticsAccumulator>,
 }
 
 impl<T: DataType> ColumnValueEncoderImpl<T> {
@@ -150,6 +156,12 @@ impl<T: DataType> ColumnValueEncoderImpl<T> {
             }
         }
 
+        if let Some(geospatial_statistics) = &mut self.geospatial_statistics {
+            for value in slice {
+                geospatial_statistics.update(value.as_bytes());
+            }
+        }
+
         match &mut self.dict_encoder {
             Some(encoder) => encoder.put(slice),
             _ => self.encoder.put(slice),
@@ -179,6 +191,10 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
         self.bloom_filter.take()
     }
 
+    fn flush_geospatial_statistics(&mut self) -> Option<GeospatialStatistics> {
+        self.geospatial_statistics.as_mut()?.finish()
+    }
+
     fn try_new(descr: &ColumnDescPtr, props: &WriterProperties) -> Result<Self> {
         let dict_supported = props.dictionary_enabled(descr.path())
             && has_dictionary_support(T::get_physical_type(), props);
@@ -198,6 +214,11 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
             .map(|props| Sbbf::new_with_ndv_fpp(props.ndv, props.fpp))
             .transpose()?;
 
+        let geospatial_statistics = match statistics_enabled {
+            EnabledStatistics::None => None,
+            _ => GeospatialStatisticsAccumulator::try_new(descr),
+        };
+
         Ok(Self {
             encoder,
             dict_encoder,
@@ -207,6 +228,7 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
             bloom_filter,
             min_value: None,
             max_value: None,
+            geospatial_statistics,
         })
     }
 
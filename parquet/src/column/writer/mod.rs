@@ -224,6 +224,7 @@ pub struct GenericColumnWriter<'a, E: ColumnValueEncoder> {
     descr: ColumnDescPtr,
     props: WriterPropertiesPtr,
     statistics_enabled: EnabledStatistics,
+    statistics_null_count_enabled: Option<bool>,
 
     page_writer: Box<dyn PageWriter + 'a>,
     codec: Compression,
@@ -258,6 +259,8 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         let encoder = E::try_new(&descr, props.as_ref()).unwrap();
 
         let statistics_enabled = props.statistics_enabled(descr.path());
+        let statistics_null_count_enabled =
+            props.statistics_null_count_enabled(descr.path());
 
         let mut encodings = BTreeSet::new();
         // Used for level information
@@ -267,6 +270,7 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
             descr,
             props,
             statistics_enabled,
+            statistics_null_count_enabled,
             page_writer,
             codec,
             compressor,
@@ -730,13 +734,13 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
             (Some(min), Some(max)) => {
                 update_min(&self.descr, &min, &mut self.column_metrics.min_column_value);
                 update_max(&self.descr, &max, &mut self.column_metrics.max_column_value);
-                Some(Statistics::new(
-                    Some(min),
-                    Some(max),
-                    None,
-                    self.page_metrics.num_page_nulls,
-                    false,
-                ))
+                let null_count = self.page_metrics.num_page_nulls;
+                let statistics =
+                    Statistics::new(Some(min), Some(max), None, null_count, false);
+                Some(statistics.with_null_count(statistics_null_count(
+                    self.statistics_null_count_enabled,
+                    null_count,
+                )))
             }
             _ => None,
         };
@@ -895,13 +899,23 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
             .set_dictionary_page_offset(dict_page_offset);
 
         if self.statistics_enabled != EnabledStatistics::None {
+            // A distinct count provided in the properties takes precedence
+            let distinct_count = self
+                .props
+                .statistics_distinct_count(self.descr.path())
+                .or(self.column_metrics.column_distinct_count);
+            let null_count = self.column_metrics.num_column_nulls;
             let statistics = ValueStatistics::<E::T>::new(
                 self.column_metrics.min_column_value.clone(),
                 self.column_metrics.max_column_value.clone(),
-                self.column_metrics.column_distinct_count,
-                self.column_metrics.num_column_nulls,
+                distinct_count,
+                null_count,
                 false,
-            );
+            )
+            .with_null_count(statistics_null_count(
+                self.statistics_null_count_enabled,
+                null_count,
+            ));
 
             // Some common readers only support the deprecated statistics
             // format so we also write them out if possible
@@ -909,7 +923,9 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
             let statistics = statistics
                 .with_backwards_compatible_min_max(self.descr.sort_order().is_signed())
                 .into();
-            builder = builder.set_statistics(statistics);
+            builder = builder
+                .set_statistics(statistics)
+                .set_geospatial_statistics(self.encoder.flush_geospatial_statistics());
         }
 
         let metadata = builder.build()?;
@@ -1014,6 +1030,16 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
     }
 }
 
+/// Returns the null count to write in statistics, omitting a zero null count unless
+/// explicitly enabled, see [`WriterProperties::statistics_null_count_enabled`]
+fn statistics_null_count(enabled: Option<bool>, null_count: u64) -> Option<u64> {
+    match enabled {
+        Some(true) => Some(null_count),
+        Some(false) => None,
+        None => (null_count != 0).then_some(null_count),
+    }
+}
+
 fn update_min<T: ParquetValueType>(
     descr: &ColumnDescriptor,
     val: &T,
@@ -1709,6 +1735,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_column_writer_geospatial_statistics() {
+        let tpe = SchemaType::primitive_type_builder("geom", Type::BYTE_ARRAY)
+            .with_logical_type(Some(LogicalType::Geometry { crs: None }))
+            .build()
+            .unwrap();
+        let descr = ColumnDescriptor::new(Arc::new(tpe), 0, 0, ColumnPath::from("geom"));
+        let column_writer = get_column_writer(
+            Arc::new(descr),
+            Default::default(),
+            get_test_page_writer(),
+        );
+        let mut writer = get_typed_column_writer::<ByteArrayType>(column_writer);
+
+        let point = |x: f64, y: f64| {
+            let mut wkb = vec![1, 1, 0, 0, 0];
+            wkb.extend_from_slice(&x.to_le_bytes());
+            wkb.extend_from_slice(&y.to_le_bytes());
+            ByteArray::from(wkb)
+        };
+        writer
+            .write_batch(&[point(1., -2.), point(-3., 4.)], None, None)
+            .unwrap();
+
+        let metadata = writer.close().unwrap().metadata;
+        let stats = metadata.geospatial_statistics().unwrap();
+        let bbox = stats.bbox.unwrap();
+        assert_eq!(
+            (bbox.xmin, bbox.xmax, bbox.ymin, bbox.ymax),
+            (-3., 1., -2., 4.)
+        );
+        assert_eq!((bbox.zmin, bbox.mmin), (None, None));
+        assert_eq!(stats.geospatial_types, Some(vec![1]));
+    }
+
     #[test]
     fn test_column_writer_uint32_converted_type_min_max() {
         let page_writer = get_test_page_writer();
@@ -1835,6 +1896,64 @@ mod tests {
         assert!(page_statistics.distinct_count().is_none());
     }
 
+    #[test]
+    fn test_column_writer_statistics_counts() {
+        // A zero null count is only written if explicitly enabled
+        for (null_count_enabled, def_levels, expected_nulls) in [
+            (Some(true), [1, 0, 1, 1], Some(1)),
+            (Some(true), [1, 1, 1, 1], Some(0)),
+            (Some(false), [1, 0, 1, 1], None),
+            (None, [1, 0, 1, 1], Some(1)),
+            (None, [1, 1, 1, 1], None),
+        ] {
+            let mut buf = Vec::with_capacity(100);
+            let mut write = TrackedWrite::new(&mut buf);
+            let page_writer = Box::new(SerializedPageWriter::new(&mut write));
+            let mut builder = WriterProperties::builder()
+                .set_column_statistics_distinct_count(ColumnPath::from("col"), 3);
+            if let Some(enabled) = null_count_enabled {
+                builder = builder.set_statistics_null_count_enabled(enabled);
+            }
+            let mut writer = get_test_column_writer::<Int32Type>(
+                page_writer,
+                1,
+                0,
+                Arc::new(builder.build()),
+            );
+            let num_values = def_levels.iter().sum::<i16>() as usize;
+            writer
+                .write_batch(&[1, 2, 2, 2][..num_values], Some(&def_levels), None)
+                .unwrap();
+            let r = writer.close().unwrap();
+
+            let stats = r.metadata.statistics().unwrap();
+            assert_eq!(stats.distinct_count(), Some(3));
+            assert_eq!(stats.null_count_opt(), expected_nulls);
+
+            // Round trip through thrift
+            let thrift = crate::file::statistics::to_thrift(Some(stats));
+            assert_eq!(thrift.as_ref().unwrap().distinct_count, Some(3));
+            let stats =
+                crate::file::statistics::from_thrift(Type::INT32, thrift).unwrap();
+            assert_eq!(stats.distinct_count(), Some(3));
+            assert_eq!(stats.null_count_opt(), expected_nulls);
+
+            drop(write);
+
+            let reader = SerializedPageReader::new(
+                Arc::new(Bytes::from(buf)),
+                &r.metadata,
+                r.rows_written as usize,
+                None,
+            )
+            .unwrap();
+            let pages = reader.collect::<Result<Vec<_>>>().unwrap();
+            let page_statistics = pages.last().unwrap().statistics().unwrap();
+            assert_eq!(page_statistics.null_count_opt(), expected_nulls);
+            assert_eq!(page_statistics.distinct_count(), None);
+        }
+    }
+
     #[test]
     fn test_disabled_statistics() {
         let mut buf = Vec::with_capacity(100);
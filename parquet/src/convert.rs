@@ -0,0 +1,385 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Streaming conversion between parquet and CSV or newline delimited JSON
+//!
+//! Parquet files are read with the [async reader](crate::arrow::async_reader),
+//! and converted one [`RecordBatch`](arrow_array::RecordBatch) at a time, so
+//! memory usage is bounded by the batch size rather than the file size.
+//!
+//! Requires the `convert` feature.
+//!
+//! ```no_run
+//! # use parquet::convert::{parquet_to_text, ExportOptions, TextFormat};
+//! # async fn run() -> parquet::errors::Result<()> {
+//! let input = tokio::fs::File::open("data.parquet").await?;
+//! let output = std::fs::File::create("data.csv")?;
+//!
+//! let options = ExportOptions::new()
+//!     .with_columns(["id", "name"])
+//!     .with_limit(100);
+//! let rows = parquet_to_text(input, output, TextFormat::Csv, options).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::{BufRead, Write};
+
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, SchemaRef};
+use futures::StreamExt;
+
+use crate::arrow::arrow_reader::RowFilter;
+use crate::arrow::async_reader::{AsyncFileReader, ParquetRecordBatchStreamBuilder};
+use crate::arrow::{ArrowWriter, ProjectionMask};
+use crate::errors::Result;
+use crate::file::properties::WriterProperties;
+
+/// The text formats supported by [`parquet_to_text`] and [`text_to_parquet`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextFormat {
+    /// Comma separated values
+    Csv,
+    /// Newline delimited JSON, with one object per row
+    Json,
+}
+
+/// Options for [`parquet_to_text`]
+#[derive(Default)]
+pub struct ExportOptions {
+    columns: Option<Vec<String>>,
+    filter: Option<RowFilter>,
+    limit: Option<usize>,
+    batch_size: Option<usize>,
+}
+
+impl ExportOptions {
+    /// Create a new [`ExportOptions`] exporting all rows of all columns
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only export the root columns with the provided names, in the provided order
+    pub fn with_columns<I, S>(self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            columns: Some(columns.into_iter().map(Into::into).collect()),
+            ..self
+        }
+    }
+
+    /// Only export the rows selected by `filter`, see
+    /// [`ArrowReaderBuilder::with_row_filter`](crate::arrow::arrow_reader::ArrowReaderBuilder::with_row_filter)
+    pub fn with_row_filter(self, filter: RowFilter) -> Self {
+        Self {
+            filter: Some(filter),
+            ..self
+        }
+    }
+
+    /// Export at most `limit` rows
+    pub fn with_limit(self, limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..self
+        }
+    }
+
+    /// Set the number of rows read and written at a time
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self {
+            batch_size: Some(batch_size),
+            ..self
+        }
+    }
+}
+
+/// Options for [`text_to_parquet`]
+#[derive(Debug, Clone)]
+pub struct ImportOptions {
+    has_header: bool,
+    delimiter: u8,
+    batch_size: usize,
+    properties: Option<WriterProperties>,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        Self {
+            has_header: true,
+            delimiter: b',',
+            batch_size: 1024,
+            properties: None,
+        }
+    }
+}
+
+impl ImportOptions {
+    /// Create a new [`ImportOptions`] for comma separated values with a header row
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether the first line of CSV input is a header, defaults to `true`
+    pub fn with_header(self, has_header: bool) -> Self {
+        Self { has_header, ..self }
+    }
+
+    /// Set the field delimiter of CSV input, defaults to `,`
+    pub fn with_delimiter(self, delimiter: u8) -> Self {
+        Self { delimiter, ..self }
+    }
+
+    /// Set the number of rows read and written at a time, defaults to `1024`
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self { batch_size, ..self }
+    }
+
+    /// Set the [`WriterProperties`] of the written parquet file
+    pub fn with_properties(self, properties: WriterProperties) -> Self {
+        Self {
+            properties: Some(properties),
+            ..self
+        }
+    }
+}
+
+/// A writer of [`TextFormat`]
+enum TextWriter<W: Write> {
+    Csv(arrow_csv::Writer<W>),
+    Json(arrow_json::LineDelimitedWriter<W>),
+}
+
+/// Reads the parquet file `input`, writing its rows to `output` in `format`
+///
+/// CSV output includes a header row. Returns the number of rows written.
+pub async fn parquet_to_text<T, W>(
+    input: T,
+    output: W,
+    format: TextFormat,
+    options: ExportOptions,
+) -> Result<usize>
+where
+    T: AsyncFileReader + Unpin + Send + 'static,
+    W: Write,
+{
+    let mut builder = ParquetRecordBatchStreamBuilder::new(input).await?;
+
+    // The projected columns are read in file order, so record their requested order
+    let mut order = None;
+    if let Some(columns) = &options.columns {
+        let schema = builder.schema();
+        let mut indices = columns
+            .iter()
+            .map(|c| schema.index_of(c))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut sorted = indices.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        indices
+            .iter_mut()
+            .for_each(|i| *i = sorted.binary_search(i).unwrap());
+
+        let mask = ProjectionMask::roots(builder.parquet_schema(), sorted);
+        builder = builder.with_projection(mask);
+        order = Some(indices);
+    }
+    if let Some(filter) = options.filter {
+        builder = builder.with_row_filter(filter);
+    }
+    if let Some(limit) = options.limit {
+        builder = builder.with_limit(limit);
+    }
+    if let Some(batch_size) = options.batch_size {
+        builder = builder.with_batch_size(batch_size);
+    }
+
+    let mut writer = match format {
+        TextFormat::Csv => TextWriter::Csv(arrow_csv::Writer::new(output)),
+        TextFormat::Json => {
+            TextWriter::Json(arrow_json::LineDelimitedWriter::new(output))
+        }
+    };
+
+    let mut stream = builder.build()?;
+    let mut rows = 0;
+    while let Some(batch) = stream.next().await {
+        let mut batch = batch?;
+        if let Some(order) = &order {
+            batch = batch.project(order)?;
+        }
+        match &mut writer {
+            TextWriter::Csv(w) => w.write(&batch)?,
+            TextWriter::Json(w) => w.write(&batch)?,
+        }
+        rows += batch.num_rows();
+    }
+
+    if let TextWriter::Json(w) = &mut writer {
+        w.finish()?;
+    }
+    Ok(rows)
+}
+
+/// Reads rows of `schema` in `format` from `input`, writing them to the parquet
+/// file `output`
+///
+/// Returns the number of rows written.
+pub fn text_to_parquet<R, W>(
+    input: R,
+    output: W,
+    schema: SchemaRef,
+    format: TextFormat,
+    options: ImportOptions,
+) -> Result<usize>
+where
+    R: BufRead,
+    W: Write + Send,
+{
+    let mut writer = ArrowWriter::try_new(output, schema.clone(), options.properties)?;
+
+    let rows = match format {
+        TextFormat::Csv => {
+            let reader = arrow_csv::ReaderBuilder::new(schema)
+                .has_header(options.has_header)
+                .with_delimiter(options.delimiter)
+                .with_batch_size(options.batch_size)
+                .build_buffered(input)?;
+            write_batches(&mut writer, reader)?
+        }
+        TextFormat::Json => {
+            let reader = arrow_json::ReaderBuilder::new(schema)
+                .with_batch_size(options.batch_size)
+                .build(input)?;
+            write_batches(&mut writer, reader)?
+        }
+    };
+    writer.close()?;
+    Ok(rows)
+}
+
+/// Writes `batches` to `writer`, returning the number of rows written
+fn write_batches<W: Write + Send>(
+    writer: &mut ArrowWriter<W>,
+    batches: impl Iterator<Item = Result<RecordBatch, ArrowError>>,
+) -> Result<usize> {
+    let mut rows = 0;
+    for batch in batches {
+        let batch = batch?;
+        writer.write(&batch)?;
+        rows += batch.num_rows();
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::arrow_reader::ParquetRecordBatchReader;
+    use arrow_array::{ArrayRef, Int32Array, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+    use bytes::Bytes;
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    fn test_batch() -> RecordBatch {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "z"]));
+        RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap()
+    }
+
+    fn write_parquet(batch: &RecordBatch) -> Vec<u8> {
+        let mut buf = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(batch).unwrap();
+        writer.close().unwrap();
+        buf
+    }
+
+    #[tokio::test]
+    async fn test_parquet_to_text() {
+        let file = write_parquet(&test_batch());
+
+        let mut csv = vec![];
+        let options = ExportOptions::new().with_columns(["b", "a"]).with_limit(2);
+        let input = Cursor::new(file.clone());
+        let rows = parquet_to_text(input, &mut csv, TextFormat::Csv, options)
+            .await
+            .unwrap();
+        assert_eq!(rows, 2);
+        assert_eq!(String::from_utf8(csv).unwrap(), "b,a\nx,1\ny,\n");
+
+        let mut json = vec![];
+        let input = Cursor::new(file.clone());
+        let rows =
+            parquet_to_text(input, &mut json, TextFormat::Json, Default::default())
+                .await
+                .unwrap();
+        assert_eq!(rows, 3);
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            "{\"a\":1,\"b\":\"x\"}\n{\"b\":\"y\"}\n{\"a\":3,\"b\":\"z\"}\n"
+        );
+
+        let options = ExportOptions::new().with_columns(["c"]);
+        let err = parquet_to_text(Cursor::new(file), vec![], TextFormat::Csv, options)
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Unable to get field named"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_text_to_parquet() {
+        let expected = test_batch();
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+
+        let inputs = [
+            (TextFormat::Csv, "a,b\n1,x\n,y\n3,z\n"),
+            (
+                TextFormat::Json,
+                "{\"a\":1,\"b\":\"x\"}\n{\"b\":\"y\"}\n{\"a\":3,\"b\":\"z\"}\n",
+            ),
+        ];
+        for (format, input) in inputs {
+            let mut file = vec![];
+            let options = ImportOptions::new().with_batch_size(2);
+            let rows = text_to_parquet(
+                input.as_bytes(),
+                &mut file,
+                schema.clone(),
+                format,
+                options,
+            )
+            .unwrap();
+            assert_eq!(rows, 3);
+
+            let reader = ParquetRecordBatchReader::try_new(Bytes::from(file), 1024);
+            let batches = reader.unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+            assert_eq!(batches.len(), 1);
+            assert_eq!(batches[0].columns(), expected.columns());
+        }
+    }
+}
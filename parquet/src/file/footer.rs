@@ -23,7 +23,9 @@ use thrift::protocol::{TCompactInputProtocol, TSerializable};
 use crate::basic::ColumnOrder;
 
 use crate::errors::{ParquetError, Result};
-use crate::file::{metadata::*, reader::ChunkReader, FOOTER_SIZE, PARQUET_MAGIC};
+use crate::file::{
+    metadata::*, reader::ChunkReader, FOOTER_SIZE, PARQUET_ENCRYPTED_MAGIC, PARQUET_MAGIC,
+};
 
 use crate::schema::types::{self, SchemaDescriptor};
 
@@ -36,6 +38,26 @@ use crate::schema::types::{self, SchemaDescriptor};
 /// The reader first reads DEFAULT_FOOTER_SIZE bytes from the end of the file.
 /// If it is not enough according to the length indicated in the footer, it reads more bytes.
 pub fn parse_metadata<R: ChunkReader>(chunk_reader: &R) -> Result<ParquetMetaData> {
+    let start = metadata_start(chunk_reader)?;
+    read_metadata(chunk_reader.get_read(start)?)
+}
+
+/// Like [`parse_metadata`] but tolerates column chunks whose metadata cannot be
+/// decoded, for instance because they are encrypted or use an unknown compression
+/// codec, marking them as unavailable in the returned [`PartialParquetMetaData`].
+///
+/// This is useful for tools that only need the schema and file level metadata.
+/// Files with an encrypted footer still return an error, as no metadata can be
+/// decoded without the footer key.
+pub fn parse_partial_metadata<R: ChunkReader>(
+    chunk_reader: &R,
+) -> Result<PartialParquetMetaData> {
+    let start = metadata_start(chunk_reader)?;
+    read_partial_metadata(chunk_reader.get_read(start)?)
+}
+
+/// Returns the offset of the start of the metadata
+fn metadata_start<R: ChunkReader>(chunk_reader: &R) -> Result<u64> {
     // check file is large enough to hold footer
     let file_size = chunk_reader.len();
     if file_size < (FOOTER_SIZE as u64) {
@@ -61,8 +83,7 @@ pub fn parse_metadata<R: ChunkReader>(chunk_reader: &R) -> Result<ParquetMetaDat
         ));
     }
 
-    let start = file_size - footer_metadata_len as u64;
-    read_metadata(chunk_reader.get_read(start)?)
+    Ok(file_size - footer_metadata_len as u64)
 }
 
 /// Decodes [`ParquetMetaData`] from the provided bytes
@@ -73,9 +94,7 @@ pub fn decode_metadata(metadata_read: &[u8]) -> Result<ParquetMetaData> {
 /// Decodes [`ParquetMetaData`] from the provided [`Read`]
 pub(crate) fn read_metadata<R: Read>(read: R) -> Result<ParquetMetaData> {
     // TODO: row group filtering
-    let mut prot = TCompactInputProtocol::new(read);
-    let t_file_metadata: TFileMetaData = TFileMetaData::read_from_in_protocol(&mut prot)
-        .map_err(|e| ParquetError::General(format!("Could not parse metadata: {e}")))?;
+    let t_file_metadata = read_thrift_metadata(read)?;
     let schema = types::from_thrift(&t_file_metadata.schema)?;
     let schema_descr = Arc::new(SchemaDescriptor::new(schema));
     let mut row_groups = Vec::new();
@@ -95,9 +114,54 @@ pub(crate) fn read_metadata<R: Read>(read: R) -> Result<ParquetMetaData> {
     Ok(ParquetMetaData::new(file_metadata, row_groups))
 }
 
+/// Decodes [`PartialParquetMetaData`] from the provided bytes, see [`parse_partial_metadata`]
+pub fn decode_partial_metadata(metadata_read: &[u8]) -> Result<PartialParquetMetaData> {
+    read_partial_metadata(metadata_read)
+}
+
+/// Decodes [`PartialParquetMetaData`] from the provided [`Read`]
+fn read_partial_metadata<R: Read>(read: R) -> Result<PartialParquetMetaData> {
+    let t_file_metadata = read_thrift_metadata(read)?;
+    let schema = types::from_thrift(&t_file_metadata.schema)?;
+    let schema_descr = Arc::new(SchemaDescriptor::new(schema));
+    let encrypted = t_file_metadata.encryption_algorithm.is_some();
+    let row_groups = t_file_metadata
+        .row_groups
+        .into_iter()
+        .map(|rg| PartialRowGroupMetaData::from_thrift(schema_descr.clone(), rg))
+        .collect::<Result<_>>()?;
+    let column_orders = parse_column_orders(t_file_metadata.column_orders, &schema_descr);
+
+    let file_metadata = FileMetaData::new(
+        t_file_metadata.version,
+        t_file_metadata.num_rows,
+        t_file_metadata.created_by,
+        t_file_metadata.key_value_metadata,
+        schema_descr,
+        column_orders,
+    );
+    Ok(PartialParquetMetaData::new(
+        file_metadata,
+        row_groups,
+        encrypted,
+    ))
+}
+
+/// Decodes the thrift [`TFileMetaData`] from the provided [`Read`]
+fn read_thrift_metadata<R: Read>(read: R) -> Result<TFileMetaData> {
+    let mut prot = TCompactInputProtocol::new(read);
+    TFileMetaData::read_from_in_protocol(&mut prot)
+        .map_err(|e| ParquetError::General(format!("Could not parse metadata: {e}")))
+}
+
 /// Decodes the footer returning the metadata length in bytes
 pub fn decode_footer(slice: &[u8; FOOTER_SIZE]) -> Result<usize> {
     // check this is indeed a parquet file
+    if slice[4..] == PARQUET_ENCRYPTED_MAGIC {
+        return Err(general_err!(
+            "Invalid Parquet file. Encrypted footers are not supported"
+        ));
+    }
     if slice[4..] != PARQUET_MAGIC {
         return Err(general_err!("Invalid Parquet file. Corrupt footer"));
     }
@@ -145,10 +209,14 @@ fn parse_column_orders(
 mod tests {
     use super::*;
     use bytes::Bytes;
+    use thrift::protocol::TCompactOutputProtocol;
 
     use crate::basic::SortOrder;
     use crate::basic::Type;
-    use crate::format::TypeDefinedOrder;
+    use crate::format::{
+        ColumnCryptoMetaData, CompressionCodec, EncryptionWithFooterKey, RowGroup,
+        TypeDefinedOrder,
+    };
     use crate::schema::types::Type as SchemaType;
 
     #[test]
@@ -230,4 +298,112 @@ mod tests {
 
         parse_column_orders(t_column_orders, &schema_descr);
     }
+
+    #[test]
+    fn test_parse_metadata_encrypted_footer() {
+        let data = Bytes::from(vec![0, 0, 0, 0, b'P', b'A', b'R', b'E']);
+        let reader_result = parse_partial_metadata(&data);
+        assert_eq!(
+            reader_result.unwrap_err().to_string(),
+            "Parquet error: Invalid Parquet file. Encrypted footers are not supported"
+        );
+    }
+
+    #[test]
+    fn test_decode_partial_metadata() {
+        let mut fields = vec![
+            Arc::new(
+                SchemaType::primitive_type_builder("a", Type::INT32)
+                    .build()
+                    .unwrap(),
+            ),
+            Arc::new(
+                SchemaType::primitive_type_builder("b", Type::INT32)
+                    .build()
+                    .unwrap(),
+            ),
+            Arc::new(
+                SchemaType::primitive_type_builder("c", Type::INT32)
+                    .build()
+                    .unwrap(),
+            ),
+        ];
+        let schema = SchemaType::group_type_builder("schema")
+            .with_fields(&mut fields)
+            .build()
+            .unwrap();
+        let schema_descr = SchemaDescriptor::new(Arc::new(schema.clone()));
+
+        let mut columns: Vec<_> = schema_descr
+            .columns()
+            .iter()
+            .map(|c| {
+                ColumnChunkMetaData::builder(c.clone())
+                    .set_num_values(10)
+                    .build()
+                    .unwrap()
+                    .to_thrift()
+            })
+            .collect();
+        columns[1].meta_data = None;
+        columns[1].crypto_metadata = Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(
+            EncryptionWithFooterKey::new(),
+        ));
+        columns[2].meta_data.as_mut().unwrap().codec = CompressionCodec(99);
+
+        let row_group = RowGroup::new(columns, 100, 10, None, None, None, None);
+        let t_file_metadata = TFileMetaData::new(
+            1,
+            types::to_thrift(&schema).unwrap(),
+            10,
+            vec![row_group],
+            None,
+            Some("test".to_string()),
+            None,
+            None,
+            None,
+        );
+        let mut buf = Vec::new();
+        let mut protocol = TCompactOutputProtocol::new(&mut buf);
+        t_file_metadata
+            .write_to_out_protocol(&mut protocol)
+            .unwrap();
+
+        // Strict parsing fails
+        decode_metadata(&buf).unwrap_err();
+
+        let metadata = decode_partial_metadata(&buf).unwrap();
+        assert_eq!(metadata.file_metadata().num_rows(), 10);
+        assert_eq!(metadata.file_metadata().created_by(), Some("test"));
+        assert_eq!(metadata.file_metadata().schema_descr().num_columns(), 3);
+        assert!(!metadata.is_encrypted());
+        assert!(!metadata.is_complete());
+        assert_eq!(metadata.num_row_groups(), 1);
+
+        let row_group = metadata.row_group(0);
+        assert_eq!(row_group.num_rows(), 10);
+        assert_eq!(row_group.total_byte_size(), 100);
+
+        let a = row_group.column(0);
+        assert_eq!(a.column_descr().name(), "a");
+        assert_eq!(a.metadata().unwrap().num_values(), 10);
+        assert_eq!(a.unavailable_reason(), None);
+
+        let b = row_group.column(1);
+        assert_eq!(b.column_descr().name(), "b");
+        assert!(b.metadata().is_none());
+        assert_eq!(b.unavailable_reason(), Some(&UnavailableReason::Encrypted));
+
+        let c = row_group.column(2);
+        assert_eq!(
+            c.unavailable_reason(),
+            Some(&UnavailableReason::UnknownCodec(99))
+        );
+
+        let err = metadata.into_metadata().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Metadata for column \"b\" is unavailable: column chunk is encrypted"
+        );
+    }
 }
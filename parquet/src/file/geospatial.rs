@@ -0,0 +1,356 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Statistics for columns with the `GEOMETRY` and `GEOGRAPHY` logical types.
+
+use std::collections::BTreeSet;
+
+use thrift::OrderedFloat;
+
+use crate::basic::LogicalType;
+use crate::format::{
+    BoundingBox as TBoundingBox, GeospatialStatistics as TGeospatialStatistics,
+};
+use crate::schema::types::ColumnDescriptor;
+
+/// The bounds of the coordinates of a column chunk, with optional Z and M axes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub xmin: f64,
+    pub xmax: f64,
+    pub ymin: f64,
+    pub ymax: f64,
+    pub zmin: Option<f64>,
+    pub zmax: Option<f64>,
+    pub mmin: Option<f64>,
+    pub mmax: Option<f64>,
+}
+
+/// Geospatial statistics of a column chunk
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeospatialStatistics {
+    /// The bounding box of the geometries, if known
+    pub bbox: Option<BoundingBox>,
+    /// The distinct ISO WKB geometry type codes of the geometries, e.g. `1001` for
+    /// a `POINT Z`, if known
+    pub geospatial_types: Option<Vec<i32>>,
+}
+
+/// Converts Thrift definition into `GeospatialStatistics`.
+pub fn from_thrift(stats: Option<TGeospatialStatistics>) -> Option<GeospatialStatistics> {
+    let stats = stats?;
+    let bbox = stats.bbox.map(|b| BoundingBox {
+        xmin: b.xmin.into(),
+        xmax: b.xmax.into(),
+        ymin: b.ymin.into(),
+        ymax: b.ymax.into(),
+        zmin: b.zmin.map(Into::into),
+        zmax: b.zmax.map(Into::into),
+        mmin: b.mmin.map(Into::into),
+        mmax: b.mmax.map(Into::into),
+    });
+    // An empty list indicates the types are not known
+    let geospatial_types = stats.geospatial_types.filter(|t| !t.is_empty());
+    Some(GeospatialStatistics {
+        bbox,
+        geospatial_types,
+    })
+}
+
+/// Converts `GeospatialStatistics` into Thrift definition.
+pub fn to_thrift(stats: Option<&GeospatialStatistics>) -> Option<TGeospatialStatistics> {
+    let stats = stats?;
+    let bbox = stats.bbox.map(|b| TBoundingBox {
+        xmin: OrderedFloat::from(b.xmin),
+        xmax: OrderedFloat::from(b.xmax),
+        ymin: OrderedFloat::from(b.ymin),
+        ymax: OrderedFloat::from(b.ymax),
+        zmin: b.zmin.map(OrderedFloat::from),
+        zmax: b.zmax.map(OrderedFloat::from),
+        mmin: b.mmin.map(OrderedFloat::from),
+        mmax: b.mmax.map(OrderedFloat::from),
+    });
+    Some(TGeospatialStatistics {
+        bbox,
+        geospatial_types: Some(stats.geospatial_types.clone().unwrap_or_default()),
+    })
+}
+
+/// The maximum nesting of geometry collections to decode
+const MAX_DEPTH: usize = 32;
+
+/// Accumulates [`GeospatialStatistics`] from WKB encoded geometries
+///
+/// The bounding box is only computed for `GEOMETRY` columns, as the edges of
+/// `GEOGRAPHY` columns may extend beyond the bounds of their vertices
+#[derive(Debug)]
+pub(crate) struct GeospatialStatisticsAccumulator {
+    compute_bbox: bool,
+    /// The (min, max) of the X, Y, Z and M axes
+    bounds: [(f64, f64); 4],
+    types: BTreeSet<i32>,
+    /// False if any value could not be decoded
+    valid: bool,
+}
+
+impl GeospatialStatisticsAccumulator {
+    /// Returns a new [`GeospatialStatisticsAccumulator`] if `descr` has a
+    /// geospatial logical type
+    pub(crate) fn try_new(descr: &ColumnDescriptor) -> Option<Self> {
+        let compute_bbox = match descr.logical_type()? {
+            LogicalType::Geometry { .. } => true,
+            LogicalType::Geography { .. } => false,
+            _ => return None,
+        };
+        Some(Self {
+            compute_bbox,
+            bounds: [(f64::INFINITY, f64::NEG_INFINITY); 4],
+            types: BTreeSet::new(),
+            valid: true,
+        })
+    }
+
+    /// Updates the statistics with the WKB encoded geometry `wkb`
+    pub(crate) fn update(&mut self, mut wkb: &[u8]) {
+        if self.valid && self.update_geometry(&mut wkb, 0).is_none() {
+            self.valid = false;
+        }
+    }
+
+    /// Returns the accumulated [`GeospatialStatistics`], resetting this accumulator
+    ///
+    /// Returns `None` if any value could not be decoded
+    pub(crate) fn finish(&mut self) -> Option<GeospatialStatistics> {
+        let bounds =
+            std::mem::replace(&mut self.bounds, [(f64::INFINITY, f64::NEG_INFINITY); 4]);
+        let types = std::mem::take(&mut self.types);
+        if !std::mem::replace(&mut self.valid, true) {
+            return None;
+        }
+
+        let axis = |i: usize| {
+            let (min, max) = bounds[i];
+            (min <= max).then_some((min, max))
+        };
+        let bbox = match (self.compute_bbox, axis(0), axis(1)) {
+            (true, Some((xmin, xmax)), Some((ymin, ymax))) => Some(BoundingBox {
+                xmin,
+                xmax,
+                ymin,
+                ymax,
+                zmin: axis(2).map(|(min, _)| min),
+                zmax: axis(2).map(|(_, max)| max),
+                mmin: axis(3).map(|(min, _)| min),
+                mmax: axis(3).map(|(_, max)| max),
+            }),
+            _ => None,
+        };
+
+        Some(GeospatialStatistics {
+            bbox,
+            geospatial_types: Some(types.into_iter().collect()),
+        })
+    }
+
+    /// Decodes a WKB geometry from the start of `buf`, advancing `buf` past it
+    fn update_geometry(&mut self, buf: &mut &[u8], depth: usize) -> Option<()> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        let little_endian = match take::<1>(buf)?[0] {
+            0 => false,
+            1 => true,
+            _ => return None,
+        };
+        let code = read_u32(buf, little_endian)?;
+
+        // Support both ISO WKB and the extended WKB flags
+        let mut has_z = code & 0x8000_0000 != 0;
+        let mut has_m = code & 0x4000_0000 != 0;
+        if code & 0x2000_0000 != 0 {
+            // Skip the SRID
+            take::<4>(buf)?;
+        }
+        let code = code & 0x0FFF_FFFF;
+        match code / 1000 {
+            0 => {}
+            1 => has_z = true,
+            2 => has_m = true,
+            3 => (has_z, has_m) = (true, true),
+            _ => return None,
+        }
+        let kind = code % 1000;
+
+        if depth == 0 {
+            let dimension = match (has_z, has_m) {
+                (false, false) => 0,
+                (true, false) => 1000,
+                (false, true) => 2000,
+                (true, true) => 3000,
+            };
+            self.types.insert((kind + dimension) as i32);
+        }
+
+        match kind {
+            1 => self.update_coordinates(buf, little_endian, 1, has_z, has_m),
+            2 => {
+                let count = read_u32(buf, little_endian)?;
+                self.update_coordinates(buf, little_endian, count, has_z, has_m)
+            }
+            3 => {
+                for _ in 0..read_u32(buf, little_endian)? {
+                    let count = read_u32(buf, little_endian)?;
+                    self.update_coordinates(buf, little_endian, count, has_z, has_m)?;
+                }
+                Some(())
+            }
+            4..=7 => {
+                for _ in 0..read_u32(buf, little_endian)? {
+                    self.update_geometry(buf, depth + 1)?;
+                }
+                Some(())
+            }
+            _ => None,
+        }
+    }
+
+    /// Decodes `count` coordinates from the start of `buf`, advancing `buf` past them
+    fn update_coordinates(
+        &mut self,
+        buf: &mut &[u8],
+        little_endian: bool,
+        count: u32,
+        has_z: bool,
+        has_m: bool,
+    ) -> Option<()> {
+        let axes = [Some(0), Some(1), has_z.then_some(2), has_m.then_some(3)];
+        for _ in 0..count {
+            for axis in axes.iter().flatten() {
+                let bytes = take::<8>(buf)?;
+                let value = match little_endian {
+                    true => f64::from_le_bytes(bytes),
+                    false => f64::from_be_bytes(bytes),
+                };
+                // Empty points are encoded with NaN coordinates
+                if value.is_nan() || !self.compute_bbox {
+                    continue;
+                }
+                let (min, max) = &mut self.bounds[*axis];
+                *min = min.min(value);
+                *max = max.max(value);
+            }
+        }
+        Some(())
+    }
+}
+
+/// Takes the first `N` bytes of `buf`, advancing `buf` past them
+fn take<const N: usize>(buf: &mut &[u8]) -> Option<[u8; N]> {
+    let bytes = buf.get(..N)?.try_into().ok()?;
+    *buf = &buf[N..];
+    Some(bytes)
+}
+
+fn read_u32(buf: &mut &[u8], little_endian: bool) -> Option<u32> {
+    let bytes = take::<4>(buf)?;
+    Some(match little_endian {
+        true => u32::from_le_bytes(bytes),
+        false => u32::from_be_bytes(bytes),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic::Type as PhysicalType;
+    use crate::schema::types::{ColumnPath, Type};
+    use std::sync::Arc;
+
+    fn descriptor(logical_type: LogicalType) -> ColumnDescriptor {
+        let tp = Type::primitive_type_builder("geom", PhysicalType::BYTE_ARRAY)
+            .with_logical_type(Some(logical_type))
+            .build()
+            .unwrap();
+        ColumnDescriptor::new(Arc::new(tp), 0, 0, ColumnPath::from("geom"))
+    }
+
+    fn point(x: f64, y: f64) -> Vec<u8> {
+        let mut wkb = vec![1];
+        wkb.extend_from_slice(&1_u32.to_le_bytes());
+        wkb.extend_from_slice(&x.to_le_bytes());
+        wkb.extend_from_slice(&y.to_le_bytes());
+        wkb
+    }
+
+    fn line_string_z(coords: &[[f64; 3]]) -> Vec<u8> {
+        let mut wkb = vec![0];
+        wkb.extend_from_slice(&1002_u32.to_be_bytes());
+        wkb.extend_from_slice(&(coords.len() as u32).to_be_bytes());
+        for c in coords.iter().flatten() {
+            wkb.extend_from_slice(&c.to_be_bytes());
+        }
+        wkb
+    }
+
+    #[test]
+    fn test_accumulate_geometry() {
+        let descr = descriptor(LogicalType::Geometry { crs: None });
+        let mut acc = GeospatialStatisticsAccumulator::try_new(&descr).unwrap();
+        acc.update(&point(1., 2.));
+        acc.update(&point(f64::NAN, f64::NAN));
+        acc.update(&line_string_z(&[[-3., 5., 10.], [0., -1., 20.]]));
+
+        let stats = acc.finish().unwrap();
+        assert_eq!(
+            stats.bbox,
+            Some(BoundingBox {
+                xmin: -3.,
+                xmax: 1.,
+                ymin: -1.,
+                ymax: 5.,
+                zmin: Some(10.),
+                zmax: Some(20.),
+                mmin: None,
+                mmax: None,
+            })
+        );
+        assert_eq!(stats.geospatial_types, Some(vec![1, 1002]));
+
+        // Invalid WKB discards the statistics
+        acc.update(&point(1., 2.));
+        acc.update(&[1, 1, 0]);
+        assert_eq!(acc.finish(), None);
+
+        let thrift = to_thrift(Some(&stats));
+        assert_eq!(from_thrift(thrift), Some(stats));
+    }
+
+    #[test]
+    fn test_accumulate_geography() {
+        let logical_type = LogicalType::Geography {
+            crs: None,
+            algorithm: None,
+        };
+        let mut acc =
+            GeospatialStatisticsAccumulator::try_new(&descriptor(logical_type)).unwrap();
+        acc.update(&point(1., 2.));
+
+        let stats = acc.finish().unwrap();
+        assert_eq!(stats.bbox, None);
+        assert_eq!(stats.geospatial_types, Some(vec![1]));
+    }
+}
@@ -43,6 +43,7 @@ use crate::format::{
 
 use crate::basic::{ColumnOrder, Compression, Encoding, Type};
 use crate::errors::{ParquetError, Result};
+use crate::file::geospatial::{self, GeospatialStatistics};
 use crate::file::page_encoding_stats::{self, PageEncodingStats};
 use crate::file::page_index::index::Index;
 use crate::file::statistics::{self, Statistics};
@@ -444,6 +445,7 @@ pub struct ColumnChunkMetaData {
     statistics: Option<Statistics>,
     encoding_stats: Option<Vec<PageEncodingStats>>,
     bloom_filter_offset: Option<i64>,
+    geospatial_statistics: Option<GeospatialStatistics>,
     offset_index_offset: Option<i64>,
     offset_index_length: Option<i32>,
     column_index_offset: Option<i64>,
@@ -561,6 +563,11 @@ impl ColumnChunkMetaData {
         self.bloom_filter_offset
     }
 
+    /// Returns the geospatial statistics for this column chunk, if any.
+    pub fn geospatial_statistics(&self) -> Option<&GeospatialStatistics> {
+        self.geospatial_statistics.as_ref()
+    }
+
     /// Returns the offset for the column index.
     pub fn column_index_offset(&self) -> Option<i64> {
         self.column_index_offset
@@ -627,6 +634,8 @@ impl ColumnChunkMetaData {
             })
             .transpose()?;
         let bloom_filter_offset = col_metadata.bloom_filter_offset;
+        let geospatial_statistics =
+            geospatial::from_thrift(col_metadata.geospatial_statistics);
         let offset_index_offset = cc.offset_index_offset;
         let offset_index_length = cc.offset_index_length;
         let column_index_offset = cc.column_index_offset;
@@ -647,6 +656,7 @@ impl ColumnChunkMetaData {
             statistics,
             encoding_stats,
             bloom_filter_offset,
+            geospatial_statistics,
             offset_index_offset,
             offset_index_length,
             column_index_offset,
@@ -692,6 +702,9 @@ impl ColumnChunkMetaData {
                 .as_ref()
                 .map(|vec| vec.iter().map(page_encoding_stats::to_thrift).collect()),
             bloom_filter_offset: self.bloom_filter_offset,
+            geospatial_statistics: geospatial::to_thrift(
+                self.geospatial_statistics.as_ref(),
+            ),
         }
     }
 
@@ -722,6 +735,7 @@ impl ColumnChunkMetaDataBuilder {
             statistics: None,
             encoding_stats: None,
             bloom_filter_offset: None,
+            geospatial_statistics: None,
             offset_index_offset: None,
             offset_index_length: None,
             column_index_offset: None,
@@ -807,6 +821,15 @@ impl ColumnChunkMetaDataBuilder {
         self
     }
 
+    /// Sets optional geospatial statistics for this column chunk.
+    pub fn set_geospatial_statistics(
+        mut self,
+        value: Option<GeospatialStatistics>,
+    ) -> Self {
+        self.0.geospatial_statistics = value;
+        self
+    }
+
     /// Sets optional offset index offset in bytes.
     pub fn set_offset_index_offset(mut self, value: Option<i64>) -> Self {
         self.0.offset_index_offset = value;
@@ -837,6 +860,285 @@ impl ColumnChunkMetaDataBuilder {
     }
 }
 
+/// Reason why the metadata of a column chunk is unavailable, see [`PartialParquetMetaData`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UnavailableReason {
+    /// The column chunk is encrypted
+    Encrypted,
+    /// The column chunk has no metadata
+    Missing,
+    /// The column chunk is compressed with a codec not known to this crate
+    UnknownCodec(i32),
+    /// The column chunk uses an encoding not known to this crate
+    UnknownEncoding(i32),
+    /// The column chunk metadata could not be decoded
+    Invalid(String),
+}
+
+impl std::fmt::Display for UnavailableReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnavailableReason::Encrypted => write!(f, "column chunk is encrypted"),
+            UnavailableReason::Missing => write!(f, "column chunk metadata is missing"),
+            UnavailableReason::UnknownCodec(c) => {
+                write!(f, "unknown compression codec: {c}")
+            }
+            UnavailableReason::UnknownEncoding(e) => write!(f, "unknown encoding: {e}"),
+            UnavailableReason::Invalid(e) => {
+                write!(f, "invalid column chunk metadata: {e}")
+            }
+        }
+    }
+}
+
+/// Metadata for a column chunk that may be unavailable, see [`PartialParquetMetaData`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum PartialColumnChunkMetaData {
+    /// The column chunk metadata was decoded successfully
+    Available(Box<ColumnChunkMetaData>),
+    /// The column chunk metadata could not be decoded
+    Unavailable {
+        /// Descriptor of the column this chunk belongs to
+        column_descr: ColumnDescPtr,
+        /// Why the metadata is unavailable
+        reason: UnavailableReason,
+    },
+}
+
+impl PartialColumnChunkMetaData {
+    /// Returns the descriptor of the column this chunk belongs to
+    pub fn column_descr(&self) -> &ColumnDescriptor {
+        match self {
+            Self::Available(c) => c.column_descr(),
+            Self::Unavailable { column_descr, .. } => column_descr,
+        }
+    }
+
+    /// Returns the column chunk metadata if available
+    pub fn metadata(&self) -> Option<&ColumnChunkMetaData> {
+        match self {
+            Self::Available(c) => Some(c.as_ref()),
+            Self::Unavailable { .. } => None,
+        }
+    }
+
+    /// Returns why the column chunk metadata is unavailable, if it is
+    pub fn unavailable_reason(&self) -> Option<&UnavailableReason> {
+        match self {
+            Self::Available(_) => None,
+            Self::Unavailable { reason, .. } => Some(reason),
+        }
+    }
+
+    /// Converts from Thrift, returning [`Self::Unavailable`] instead of an error
+    pub fn from_thrift(column_descr: ColumnDescPtr, cc: ColumnChunk) -> Self {
+        let reason = match &cc.meta_data {
+            _ if cc.crypto_metadata.is_some()
+                || cc.encrypted_column_metadata.is_some() =>
+            {
+                Some(UnavailableReason::Encrypted)
+            }
+            None => Some(UnavailableReason::Missing),
+            Some(m) if Compression::try_from(m.codec).is_err() => {
+                Some(UnavailableReason::UnknownCodec(m.codec.0))
+            }
+            Some(m) => m
+                .encodings
+                .iter()
+                .find(|e| Encoding::try_from(**e).is_err())
+                .map(|e| UnavailableReason::UnknownEncoding(e.0)),
+        };
+        let reason = match reason {
+            Some(reason) => reason,
+            None => match ColumnChunkMetaData::from_thrift(column_descr.clone(), cc) {
+                Ok(c) => return Self::Available(Box::new(c)),
+                Err(e) => UnavailableReason::Invalid(e.to_string()),
+            },
+        };
+        Self::Unavailable {
+            column_descr,
+            reason,
+        }
+    }
+}
+
+/// Metadata for a row group where the metadata of some column chunks may be unavailable,
+/// see [`PartialParquetMetaData`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartialRowGroupMetaData {
+    columns: Vec<PartialColumnChunkMetaData>,
+    num_rows: i64,
+    sorting_columns: Option<Vec<SortingColumn>>,
+    total_byte_size: i64,
+    schema_descr: SchemaDescPtr,
+}
+
+impl PartialRowGroupMetaData {
+    /// Number of columns in this row group.
+    pub fn num_columns(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Returns column chunk metadata for `i`th column.
+    pub fn column(&self, i: usize) -> &PartialColumnChunkMetaData {
+        &self.columns[i]
+    }
+
+    /// Returns slice of column chunk metadata.
+    pub fn columns(&self) -> &[PartialColumnChunkMetaData] {
+        &self.columns
+    }
+
+    /// Number of rows in this row group.
+    pub fn num_rows(&self) -> i64 {
+        self.num_rows
+    }
+
+    /// Returns the sort ordering of the rows in this RowGroup if any
+    pub fn sorting_columns(&self) -> Option<&Vec<SortingColumn>> {
+        self.sorting_columns.as_ref()
+    }
+
+    /// Total byte size of all uncompressed column data in this row group.
+    pub fn total_byte_size(&self) -> i64 {
+        self.total_byte_size
+    }
+
+    /// Returns reference to a schema descriptor.
+    pub fn schema_descr(&self) -> &SchemaDescriptor {
+        self.schema_descr.as_ref()
+    }
+
+    /// Returns true if the metadata of all column chunks is available
+    pub fn is_complete(&self) -> bool {
+        self.columns.iter().all(|c| c.metadata().is_some())
+    }
+
+    /// Converts into [`RowGroupMetaData`], returning an error if the metadata
+    /// of any column chunk is unavailable
+    pub fn into_row_group_metadata(self) -> Result<RowGroupMetaData> {
+        let columns = self
+            .columns
+            .into_iter()
+            .map(|c| match c {
+                PartialColumnChunkMetaData::Available(c) => Ok(*c),
+                PartialColumnChunkMetaData::Unavailable {
+                    column_descr,
+                    reason,
+                } => Err(general_err!(
+                    "Metadata for column {} is unavailable: {}",
+                    column_descr.path(),
+                    reason
+                )),
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(RowGroupMetaData {
+            columns,
+            num_rows: self.num_rows,
+            sorting_columns: self.sorting_columns,
+            total_byte_size: self.total_byte_size,
+            schema_descr: self.schema_descr,
+        })
+    }
+
+    /// Converts from Thrift, marking column chunks that cannot be decoded as unavailable
+    pub fn from_thrift(schema_descr: SchemaDescPtr, rg: RowGroup) -> Result<Self> {
+        if schema_descr.num_columns() != rg.columns.len() {
+            return Err(general_err!(
+                "Row group has {} columns, but schema has {}",
+                rg.columns.len(),
+                schema_descr.num_columns()
+            ));
+        }
+        let columns = rg
+            .columns
+            .into_iter()
+            .zip(schema_descr.columns())
+            .map(|(c, d)| PartialColumnChunkMetaData::from_thrift(d.clone(), c))
+            .collect();
+
+        Ok(Self {
+            columns,
+            num_rows: rg.num_rows,
+            sorting_columns: rg.sorting_columns,
+            total_byte_size: rg.total_byte_size,
+            schema_descr,
+        })
+    }
+}
+
+/// Parquet metadata where the metadata of some column chunks may be unavailable,
+/// for instance because they are encrypted or use an unknown compression codec.
+///
+/// This allows extracting the schema and file level metadata from files that
+/// cannot be fully read, see [`parse_partial_metadata`]
+///
+/// [`parse_partial_metadata`]: crate::file::footer::parse_partial_metadata
+#[derive(Debug, Clone)]
+pub struct PartialParquetMetaData {
+    file_metadata: FileMetaData,
+    row_groups: Vec<PartialRowGroupMetaData>,
+    encrypted: bool,
+}
+
+impl PartialParquetMetaData {
+    /// Creates partial metadata from file metadata and a list of row group metadata
+    pub fn new(
+        file_metadata: FileMetaData,
+        row_groups: Vec<PartialRowGroupMetaData>,
+        encrypted: bool,
+    ) -> Self {
+        Self {
+            file_metadata,
+            row_groups,
+            encrypted,
+        }
+    }
+
+    /// Returns file metadata as reference.
+    pub fn file_metadata(&self) -> &FileMetaData {
+        &self.file_metadata
+    }
+
+    /// Returns number of row groups in this file.
+    pub fn num_row_groups(&self) -> usize {
+        self.row_groups.len()
+    }
+
+    /// Returns row group metadata for `i`th position.
+    pub fn row_group(&self, i: usize) -> &PartialRowGroupMetaData {
+        &self.row_groups[i]
+    }
+
+    /// Returns slice of row groups in this file.
+    pub fn row_groups(&self) -> &[PartialRowGroupMetaData] {
+        &self.row_groups
+    }
+
+    /// Returns true if the file declares an encryption algorithm
+    pub fn is_encrypted(&self) -> bool {
+        self.encrypted
+    }
+
+    /// Returns true if the metadata of all column chunks is available
+    pub fn is_complete(&self) -> bool {
+        self.row_groups.iter().all(|rg| rg.is_complete())
+    }
+
+    /// Converts into [`ParquetMetaData`], returning an error if the metadata
+    /// of any column chunk is unavailable
+    pub fn into_metadata(self) -> Result<ParquetMetaData> {
+        let row_groups = self
+            .row_groups
+            .into_iter()
+            .map(|rg| rg.into_row_group_metadata())
+            .collect::<Result<_>>()?;
+        Ok(ParquetMetaData::new(self.file_metadata, row_groups))
+    }
+}
+
 /// Builder for column index
 pub struct ColumnIndexBuilder {
     null_pages: Vec<bool>,
@@ -954,6 +1256,7 @@ impl OffsetIndexBuilder {
 mod tests {
     use super::*;
     use crate::basic::{Encoding, PageType};
+    use crate::file::geospatial::BoundingBox;
 
     #[test]
     fn test_row_group_metadata_thrift_conversion() {
@@ -1022,6 +1325,19 @@ mod tests {
                 },
             ])
             .set_bloom_filter_offset(Some(6000))
+            .set_geospatial_statistics(Some(GeospatialStatistics {
+                bbox: Some(BoundingBox {
+                    xmin: -1.,
+                    xmax: 1.,
+                    ymin: -2.,
+                    ymax: 2.,
+                    zmin: None,
+                    zmax: None,
+                    mmin: Some(0.),
+                    mmax: Some(10.),
+                }),
+                geospatial_types: Some(vec![1, 2003]),
+            }))
             .set_offset_index_offset(Some(7000))
             .set_offset_index_length(Some(25))
             .set_column_index_offset(Some(8000))
@@ -95,6 +95,7 @@
 //! }
 //! ```
 pub mod footer;
+pub mod geospatial;
 pub mod metadata;
 pub mod page_encoding_stats;
 pub mod page_index;
@@ -107,3 +108,4 @@ pub mod writer;
 /// The length of the parquet footer in bytes
 pub const FOOTER_SIZE: usize = 8;
 const PARQUET_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'1'];
+const PARQUET_ENCRYPTED_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'E'];
@@ -72,6 +72,27 @@ impl WriterVersion {
     }
 }
 
+/// Controls how Arrow types are coerced by the
+/// [`ArrowWriter`](crate::arrow::arrow_writer::ArrowWriter) for compatibility with
+/// other parquet readers
+///
+/// See [`WriterPropertiesBuilder::set_compatibility_profile`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum CompatibilityProfile {
+    /// Write Arrow types using the closest parquet type
+    #[default]
+    Default,
+    /// Write types readable by Apache Spark and Apache Hive
+    ///
+    /// * Unsigned integers are widened to the next larger signed integer type, with
+    ///   `UInt64` written as `Int64`, returning an error for values above `i64::MAX`
+    /// * Timestamps are written with microsecond precision, truncating any nanoseconds
+    /// * List elements are named `element`
+    /// * Map entries are named `key_value`, with fields named `key` and `value`
+    Spark,
+}
+
 /// Reference counted writer properties.
 pub type WriterPropertiesPtr = Arc<WriterProperties>;
 
@@ -124,6 +145,7 @@ pub struct WriterProperties {
     column_properties: HashMap<ColumnPath, ColumnProperties>,
     sorting_columns: Option<Vec<SortingColumn>>,
     column_index_truncate_length: Option<usize>,
+    compatibility_profile: CompatibilityProfile,
 }
 
 impl Default for WriterProperties {
@@ -229,6 +251,11 @@ impl WriterProperties {
         self.column_index_truncate_length
     }
 
+    /// Returns the [`CompatibilityProfile`] used to coerce Arrow types.
+    pub fn compatibility_profile(&self) -> CompatibilityProfile {
+        self.compatibility_profile
+    }
+
     /// Returns encoding for a data page, when dictionary encoding is enabled.
     /// This is not configurable.
     #[inline]
@@ -286,6 +313,30 @@ impl WriterProperties {
             .unwrap_or(DEFAULT_STATISTICS_ENABLED)
     }
 
+    /// Returns `Some(true)` if the null count should always be written in the statistics
+    /// of a column, `Some(false)` if it should never be written. If result is `None`,
+    /// then no setting has been provided, and the null count is only written if it is
+    /// non-zero. Only applicable if statistics are enabled.
+    pub fn statistics_null_count_enabled(&self, col: &ColumnPath) -> Option<bool> {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.statistics_null_count_enabled())
+            .or_else(|| {
+                self.default_column_properties
+                    .statistics_null_count_enabled()
+            })
+    }
+
+    /// Returns the distinct count to write in the column chunk statistics of a column,
+    /// if one was provided with
+    /// [`WriterPropertiesBuilder::set_column_statistics_distinct_count`].
+    /// Only applicable if statistics are enabled.
+    pub fn statistics_distinct_count(&self, col: &ColumnPath) -> Option<u64> {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.statistics_distinct_count())
+    }
+
     /// Returns max size for statistics.
     /// Only applicable if statistics are enabled.
     pub fn max_statistics_size(&self, col: &ColumnPath) -> usize {
@@ -325,6 +376,7 @@ pub struct WriterPropertiesBuilder {
     column_properties: HashMap<ColumnPath, ColumnProperties>,
     sorting_columns: Option<Vec<SortingColumn>>,
     column_index_truncate_length: Option<usize>,
+    compatibility_profile: CompatibilityProfile,
 }
 
 impl WriterPropertiesBuilder {
@@ -343,6 +395,7 @@ impl WriterPropertiesBuilder {
             column_properties: HashMap::new(),
             sorting_columns: None,
             column_index_truncate_length: DEFAULT_COLUMN_INDEX_TRUNCATE_LENGTH,
+            compatibility_profile: CompatibilityProfile::Default,
         }
     }
 
@@ -361,6 +414,7 @@ impl WriterPropertiesBuilder {
             column_properties: self.column_properties,
             sorting_columns: self.sorting_columns,
             column_index_truncate_length: self.column_index_truncate_length,
+            compatibility_profile: self.compatibility_profile,
         }
     }
 
@@ -470,11 +524,29 @@ impl WriterPropertiesBuilder {
     }
 
     /// Sets sorting order of rows in the row group if any
+    ///
+    /// This is written to the metadata of every row group, unless overridden with
+    /// [`SerializedRowGroupWriter::set_sorting_columns`]. The column index of each
+    /// [`SortingColumn`] refers to a leaf column of the schema, and is validated
+    /// when creating the writer.
+    ///
+    /// [`SerializedRowGroupWriter::set_sorting_columns`]: crate::file::writer::SerializedRowGroupWriter::set_sorting_columns
     pub fn set_sorting_columns(mut self, value: Option<Vec<SortingColumn>>) -> Self {
         self.sorting_columns = value;
         self
     }
 
+    /// Sets the [`CompatibilityProfile`] used to coerce Arrow types when writing
+    /// with the [`ArrowWriter`](crate::arrow::arrow_writer::ArrowWriter).
+    ///
+    /// Defaults to [`CompatibilityProfile::Default`], which writes the closest parquet
+    /// type for each Arrow type, but some of these types, such as unsigned integers
+    /// and nanosecond timestamps, cannot be read by older versions of other readers.
+    pub fn set_compatibility_profile(mut self, value: CompatibilityProfile) -> Self {
+        self.compatibility_profile = value;
+        self
+    }
+
     // ----------------------------------------------------------------------
     // Setters for any column (global)
 
@@ -512,6 +584,17 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets flag to enable/disable writing the null count in the statistics of any column.
+    /// Applicable only if statistics are enabled.
+    ///
+    /// If not set, the null count is only written if it is non-zero, which readers
+    /// cannot distinguish from an unknown null count.
+    pub fn set_statistics_null_count_enabled(mut self, value: bool) -> Self {
+        self.default_column_properties
+            .set_statistics_null_count_enabled(value);
+        self
+    }
+
     /// Sets max statistics size for any column.
     /// Applicable only if statistics are enabled.
     pub fn set_max_statistics_size(mut self, value: usize) -> Self {
@@ -595,6 +678,37 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets flag to enable/disable writing the null count in the statistics of a column.
+    /// Takes precedence over globally defined settings.
+    pub fn set_column_statistics_null_count_enabled(
+        mut self,
+        col: ColumnPath,
+        value: bool,
+    ) -> Self {
+        self.get_mut_props(col)
+            .set_statistics_null_count_enabled(value);
+        self
+    }
+
+    /// Sets the distinct count written in the column chunk statistics of a column.
+    /// Applicable only if statistics are enabled.
+    ///
+    /// Parquet writers do not compute distinct counts, instead this allows providing
+    /// an estimate, for example from an external index. As the same value is written
+    /// for each row group, it should be an estimate for the row groups of the file,
+    /// and takes precedence over any value provided to
+    /// [`GenericColumnWriter::write_batch_with_statistics`].
+    ///
+    /// [`GenericColumnWriter::write_batch_with_statistics`]: crate::column::writer::GenericColumnWriter::write_batch_with_statistics
+    pub fn set_column_statistics_distinct_count(
+        mut self,
+        col: ColumnPath,
+        value: u64,
+    ) -> Self {
+        self.get_mut_props(col).set_statistics_distinct_count(value);
+        self
+    }
+
     /// Sets max size for statistics for a column.
     /// Takes precedence over globally defined settings.
     pub fn set_column_max_statistics_size(
@@ -710,6 +824,8 @@ struct ColumnProperties {
     codec: Option<Compression>,
     dictionary_enabled: Option<bool>,
     statistics_enabled: Option<EnabledStatistics>,
+    statistics_null_count_enabled: Option<bool>,
+    statistics_distinct_count: Option<u64>,
     max_statistics_size: Option<usize>,
     /// bloom filter related properties
     bloom_filter_properties: Option<BloomFilterProperties>,
@@ -747,6 +863,16 @@ impl ColumnProperties {
         self.statistics_enabled = Some(enabled);
     }
 
+    /// Sets whether or not the null count is written in the statistics for this column.
+    fn set_statistics_null_count_enabled(&mut self, enabled: bool) {
+        self.statistics_null_count_enabled = Some(enabled);
+    }
+
+    /// Sets the distinct count written in the statistics for this column.
+    fn set_statistics_distinct_count(&mut self, value: u64) {
+        self.statistics_distinct_count = Some(value);
+    }
+
     /// Sets max size for statistics for this column.
     fn set_max_statistics_size(&mut self, value: usize) {
         self.max_statistics_size = Some(value);
@@ -811,6 +937,18 @@ impl ColumnProperties {
         self.statistics_enabled
     }
 
+    /// Returns `Some(true)` if the null count is written in the statistics for this
+    /// column, if disabled then returns `Some(false)`. If result is `None`, then no
+    /// setting has been provided.
+    fn statistics_null_count_enabled(&self) -> Option<bool> {
+        self.statistics_null_count_enabled
+    }
+
+    /// Returns the optional distinct count written in the statistics for this column.
+    fn statistics_distinct_count(&self) -> Option<u64> {
+        self.statistics_distinct_count
+    }
+
     /// Returns optional max size in bytes for statistics.
     fn max_statistics_size(&self) -> Option<usize> {
         self.max_statistics_size
@@ -943,6 +1081,7 @@ mod tests {
         assert_eq!(props.max_row_group_size(), DEFAULT_MAX_ROW_GROUP_SIZE);
         assert_eq!(props.writer_version(), DEFAULT_WRITER_VERSION);
         assert_eq!(props.created_by(), DEFAULT_CREATED_BY);
+        assert_eq!(props.compatibility_profile(), CompatibilityProfile::Default);
         assert_eq!(props.key_value_metadata(), None);
         assert_eq!(props.encoding(&ColumnPath::from("col")), None);
         assert_eq!(
@@ -1130,6 +1269,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_writer_properties_statistics_counts() {
+        let props = WriterProperties::builder().build();
+        let col = ColumnPath::from("col");
+        assert_eq!(props.statistics_null_count_enabled(&col), None);
+        assert_eq!(props.statistics_distinct_count(&col), None);
+
+        let props = WriterProperties::builder()
+            .set_statistics_null_count_enabled(false)
+            .set_column_statistics_null_count_enabled(ColumnPath::from("a"), true)
+            .set_column_statistics_distinct_count(ColumnPath::from("b"), 42)
+            .build();
+        assert_eq!(props.statistics_null_count_enabled(&col), Some(false));
+        let a = ColumnPath::from("a");
+        assert_eq!(props.statistics_null_count_enabled(&a), Some(true));
+        let b = ColumnPath::from("b");
+        assert_eq!(props.statistics_null_count_enabled(&b), Some(false));
+        assert_eq!(props.statistics_distinct_count(&col), None);
+        assert_eq!(
+            props.statistics_distinct_count(&ColumnPath::from("b")),
+            Some(42)
+        );
+    }
+
     #[test]
     fn test_writer_properties_bloom_filter_ndv_fpp_set() {
         assert_eq!(
@@ -115,6 +115,24 @@ macro_rules! statistics_enum_func {
     }};
 }
 
+// Macro to generate builder functions for Statistics.
+macro_rules! statistics_map_func {
+    ($self:ident, $func:ident, $arg:ident) => {{
+        match $self {
+            Statistics::Boolean(typed) => Statistics::Boolean(typed.$func($arg)),
+            Statistics::Int32(typed) => Statistics::Int32(typed.$func($arg)),
+            Statistics::Int64(typed) => Statistics::Int64(typed.$func($arg)),
+            Statistics::Int96(typed) => Statistics::Int96(typed.$func($arg)),
+            Statistics::Float(typed) => Statistics::Float(typed.$func($arg)),
+            Statistics::Double(typed) => Statistics::Double(typed.$func($arg)),
+            Statistics::ByteArray(typed) => Statistics::ByteArray(typed.$func($arg)),
+            Statistics::FixedLenByteArray(typed) => {
+                Statistics::FixedLenByteArray(typed.$func($arg))
+            }
+        }
+    }};
+}
+
 /// Converts Thrift definition into `Statistics`.
 pub fn from_thrift(
     physical_type: Type,
@@ -122,15 +140,17 @@ pub fn from_thrift(
 ) -> Option<Statistics> {
     match thrift_stats {
         Some(stats) => {
-            // Number of nulls recorded, when it is not available, we just mark it as 0.
-            let null_count = stats.null_count.unwrap_or(0);
-            assert!(
-                null_count >= 0,
-                "Statistics null count is negative ({null_count})"
-            );
-
-            // Generic null count.
-            let null_count = null_count as u64;
+            // Number of nulls recorded, if available
+            let null_count_opt = stats.null_count.map(|null_count| {
+                assert!(
+                    null_count >= 0,
+                    "Statistics null count is negative ({null_count})"
+                );
+                null_count as u64
+            });
+
+            // Generic null count, when it is not available, we just mark it as 0.
+            let null_count = null_count_opt.unwrap_or(0);
             // Generic distinct count (count of distinct values occurring)
             let distinct_count = stats.distinct_count.map(|value| value as u64);
             // Whether or not statistics use deprecated min/max fields.
@@ -218,7 +238,8 @@ pub fn from_thrift(
                 ),
             };
 
-            Some(res)
+            // Preserve whether the null count was present
+            Some(res.with_null_count(null_count_opt))
         }
         None => None,
     }
@@ -231,11 +252,7 @@ pub fn to_thrift(stats: Option<&Statistics>) -> Option<TStatistics> {
     let mut thrift_stats = TStatistics {
         max: None,
         min: None,
-        null_count: if stats.has_nulls() {
-            Some(stats.null_count() as i64)
-        } else {
-            None
-        },
+        null_count: stats.null_count_opt().map(|value| value as i64),
         distinct_count: stats.distinct_count().map(|value| value as i64),
         max_value: None,
         min_value: None,
@@ -353,8 +370,26 @@ impl Statistics {
 
     /// Returns number of null values for the column.
     /// Note that this includes all nulls when column is part of the complex type.
+    ///
+    /// Returns `0` if the null count is not known, see [`Self::null_count_opt`]
     pub fn null_count(&self) -> u64 {
-        statistics_enum_func![self, null_count]
+        self.null_count_opt().unwrap_or(0)
+    }
+
+    /// Returns optional number of null values for the column.
+    /// When it is `None`, the writer did not record a null count.
+    pub fn null_count_opt(&self) -> Option<u64> {
+        statistics_enum_func![self, null_count_opt]
+    }
+
+    /// Sets the number of distinct values, overriding any existing value
+    pub fn with_distinct_count(self, distinct_count: Option<u64>) -> Self {
+        statistics_map_func![self, with_distinct_count, distinct_count]
+    }
+
+    /// Sets the number of null values, overriding any existing value
+    pub fn with_null_count(self, null_count: Option<u64>) -> Self {
+        statistics_map_func![self, with_null_count, null_count]
     }
 
     /// Returns `true` if statistics collected any null values, `false` otherwise.
@@ -420,7 +455,8 @@ pub struct ValueStatistics<T> {
     max: Option<T>,
     // Distinct count could be omitted in some cases
     distinct_count: Option<u64>,
-    null_count: u64,
+    // Null count could be omitted by some writers
+    null_count: Option<u64>,
 
     /// If `true` populate the deprecated `min` and `max` fields instead of
     /// `min_value` and `max_value`
@@ -444,12 +480,25 @@ impl<T: ParquetValueType> ValueStatistics<T> {
             min,
             max,
             distinct_count,
-            null_count,
+            null_count: Some(null_count),
             is_min_max_deprecated,
             is_min_max_backwards_compatible: is_min_max_deprecated,
         }
     }
 
+    /// Set the number of distinct values, `None` if not known
+    pub fn with_distinct_count(self, distinct_count: Option<u64>) -> Self {
+        Self {
+            distinct_count,
+            ..self
+        }
+    }
+
+    /// Set the number of null values, `None` if not known
+    pub fn with_null_count(self, null_count: Option<u64>) -> Self {
+        Self { null_count, ..self }
+    }
+
     /// Set whether to write the deprecated `min` and `max` fields
     /// for compatibility with older parquet writers
     ///
@@ -505,8 +554,8 @@ impl<T: ParquetValueType> ValueStatistics<T> {
         self.distinct_count
     }
 
-    /// Returns null count.
-    fn null_count(&self) -> u64 {
+    /// Returns optional null count.
+    fn null_count_opt(&self) -> Option<u64> {
         self.null_count
     }
 
@@ -548,7 +597,11 @@ impl<T: ParquetValueType> fmt::Display for ValueStatistics<T> {
             Some(value) => write!(f, "{value}")?,
             None => write!(f, "N/A")?,
         }
-        write!(f, ", null_count: {}", self.null_count)?;
+        write!(f, ", null_count: ")?;
+        match self.null_count {
+            Some(value) => write!(f, "{value}")?,
+            None => write!(f, "N/A")?,
+        }
         write!(f, ", min_max_deprecated: {}", self.is_min_max_deprecated)?;
         write!(f, "}}")
     }
@@ -563,7 +616,10 @@ impl<T: ParquetValueType> fmt::Debug for ValueStatistics<T> {
             self.min,
             self.max,
             self.distinct_count,
-            self.null_count,
+            match self.null_count {
+                Some(value) => value.to_string(),
+                None => "None".to_string(),
+            },
             self.is_min_max_deprecated,
             self.is_min_max_backwards_compatible
         )
@@ -608,6 +664,34 @@ mod tests {
         from_thrift(Type::INT32, Some(thrift_stats));
     }
 
+    #[test]
+    fn test_statistics_null_count_opt() {
+        let thrift_stats = TStatistics {
+            max: None,
+            min: None,
+            null_count: None,
+            distinct_count: Some(4),
+            max_value: None,
+            min_value: None,
+        };
+
+        let stats = from_thrift(Type::INT32, Some(thrift_stats.clone())).unwrap();
+        assert_eq!(stats.null_count_opt(), None);
+        assert_eq!(stats.null_count(), 0);
+        assert!(!stats.has_nulls());
+        assert_eq!(stats.distinct_count(), Some(4));
+        assert_eq!(to_thrift(Some(&stats)), Some(thrift_stats));
+
+        let stats = Statistics::int32(Some(1), Some(2), None, 0, false);
+        assert_eq!(stats.null_count_opt(), Some(0));
+        assert_eq!(to_thrift(Some(&stats)).unwrap().null_count, Some(0));
+
+        let stats = stats.with_null_count(None).with_distinct_count(Some(2));
+        assert_eq!(stats.null_count_opt(), None);
+        assert_eq!(stats.distinct_count(), Some(2));
+        assert_eq!(to_thrift(Some(&stats)).unwrap().null_count, None);
+    }
+
     #[test]
     fn test_statistics_thrift_none() {
         assert_eq!(from_thrift(Type::INT32, None), None);
@@ -20,7 +20,7 @@
 
 use crate::bloom_filter::Sbbf;
 use crate::format as parquet;
-use crate::format::{ColumnIndex, OffsetIndex, RowGroup};
+use crate::format::{ColumnIndex, OffsetIndex, RowGroup, SortingColumn};
 use std::fmt::Debug;
 use std::io::{BufWriter, IoSlice, Read};
 use std::{io::Write, sync::Arc};
@@ -158,13 +158,20 @@ impl<W: Write> Debug for SerializedFileWriter<W> {
 
 impl<W: Write + Send> SerializedFileWriter<W> {
     /// Creates new file writer.
+    ///
+    /// Returns an error if the [sorting columns] of `properties` are not valid for `schema`
+    ///
+    /// [sorting columns]: crate::file::properties::WriterProperties::sorting_columns
     pub fn new(buf: W, schema: TypePtr, properties: WriterPropertiesPtr) -> Result<Self> {
+        let descr = Arc::new(SchemaDescriptor::new(schema.clone()));
+        validate_sorting_columns(&descr, properties.sorting_columns())?;
+
         let mut buf = TrackedWrite::new(buf);
         Self::start_file(&mut buf)?;
         Ok(Self {
             buf,
-            schema: schema.clone(),
-            descr: Arc::new(SchemaDescriptor::new(schema)),
+            schema,
+            descr,
             props: properties,
             row_groups: vec![],
             bloom_filters: vec![],
@@ -326,6 +333,11 @@ impl<W: Write + Send> SerializedFileWriter<W> {
             None => Some(self.kv_metadatas.clone()),
         };
 
+        // Statistics are computed using the type defined sort order of each column
+        let column_orders = (0..self.descr.num_columns())
+            .map(|_| parquet::ColumnOrder::TYPEORDER(parquet::TypeDefinedOrder::new()))
+            .collect();
+
         let file_metadata = parquet::FileMetaData {
             num_rows,
             row_groups,
@@ -333,7 +345,7 @@ impl<W: Write + Send> SerializedFileWriter<W> {
             version: self.props.writer_version().as_num(),
             schema: types::to_thrift(self.schema.as_ref())?,
             created_by: Some(self.props.created_by().to_owned()),
-            column_orders: None,
+            column_orders: Some(column_orders),
             encryption_algorithm: None,
             footer_signing_key_metadata: None,
         };
@@ -409,6 +421,7 @@ pub struct SerializedRowGroupWriter<'a, W: Write> {
     bloom_filters: Vec<Option<Sbbf>>,
     column_indexes: Vec<Option<ColumnIndex>>,
     offset_indexes: Vec<Option<OffsetIndex>>,
+    sorting_columns: Option<Vec<SortingColumn>>,
     on_close: Option<OnCloseRowGroup<'a>>,
 }
 
@@ -426,9 +439,11 @@ impl<'a, W: Write + Send> SerializedRowGroupWriter<'a, W> {
         on_close: Option<OnCloseRowGroup<'a>>,
     ) -> Self {
         let num_columns = schema_descr.num_columns();
+        let sorting_columns = properties.sorting_columns().cloned();
         Self {
             buf,
             on_close,
+            sorting_columns,
             total_rows_written: None,
             descr: schema_descr,
             props: properties,
@@ -443,6 +458,19 @@ impl<'a, W: Write + Send> SerializedRowGroupWriter<'a, W> {
         }
     }
 
+    /// Sets the sorting order of the rows in this row group, overriding
+    /// [`WriterProperties::sorting_columns`](crate::file::properties::WriterProperties::sorting_columns)
+    ///
+    /// Returns an error if any of the sorting columns are not present in the schema
+    pub fn set_sorting_columns(
+        &mut self,
+        sorting_columns: Option<Vec<SortingColumn>>,
+    ) -> Result<()> {
+        validate_sorting_columns(&self.descr, sorting_columns.as_ref())?;
+        self.sorting_columns = sorting_columns;
+        Ok(())
+    }
+
     /// Advance `self.column_index` returning the next [`ColumnDescPtr`] if any
     fn next_column_desc(&mut self) -> Option<ColumnDescPtr> {
         let ret = self.descr.columns().get(self.column_index)?.clone();
@@ -602,7 +630,7 @@ impl<'a, W: Write + Send> SerializedRowGroupWriter<'a, W> {
                 .set_column_metadata(column_chunks)
                 .set_total_byte_size(self.total_uncompressed_bytes)
                 .set_num_rows(self.total_rows_written.unwrap_or(0) as i64)
-                .set_sorting_columns(self.props.sorting_columns().cloned())
+                .set_sorting_columns(self.sorting_columns.take())
                 .build()?;
 
             let metadata = Arc::new(row_group_metadata);
@@ -632,6 +660,24 @@ impl<'a, W: Write + Send> SerializedRowGroupWriter<'a, W> {
     }
 }
 
+/// Returns an error if any of `sorting_columns` do not refer to a leaf column of `descr`
+fn validate_sorting_columns(
+    descr: &SchemaDescriptor,
+    sorting_columns: Option<&Vec<SortingColumn>>,
+) -> Result<()> {
+    let num_columns = descr.num_columns();
+    for column in sorting_columns.into_iter().flatten() {
+        if column.column_idx < 0 || column.column_idx as usize >= num_columns {
+            return Err(general_err!(
+                "Sorting column index {} out of bounds for schema with {} columns",
+                column.column_idx,
+                num_columns
+            ));
+        }
+    }
+    Ok(())
+}
+
 /// A wrapper around a [`ColumnWriter`] that invokes a callback on [`Self::close`]
 pub struct SerializedColumnWriter<'a> {
     inner: ColumnWriter<'a>,
@@ -684,15 +730,60 @@ impl<'a, W: Write> SerializedPageWriter<'a, W> {
     }
 
     /// Serializes page header into Thrift.
-    /// Returns number of bytes that have been written into the sink.
+    /// Returns the serialized bytes of the header.
     #[inline]
-    fn serialize_page_header(&mut self, header: parquet::PageHeader) -> Result<usize> {
-        let start_pos = self.sink.bytes_written();
+    fn serialize_page_header(&self, header: parquet::PageHeader) -> Result<Vec<u8>> {
+        let mut buf = Vec::with_capacity(64);
         {
-            let mut protocol = TCompactOutputProtocol::new(&mut self.sink);
+            let mut protocol = TCompactOutputProtocol::new(&mut buf);
             header.write_to_out_protocol(&mut protocol)?;
         }
-        Ok(self.sink.bytes_written() - start_pos)
+        Ok(buf)
+    }
+}
+
+/// Writes all of `bufs` to `sink` with [`Write::write_vectored`], avoiding copying
+/// them into a contiguous buffer where the sink supports vectored writes
+fn write_all_vectored<W: Write>(sink: &mut W, mut bufs: &[&[u8]]) -> Result<()> {
+    // The number of bytes of the first buffer already written
+    let mut written = 0;
+    loop {
+        while let Some(first) = bufs.first() {
+            if written < first.len() {
+                break;
+            }
+            bufs = &bufs[1..];
+            written = 0;
+        }
+        if bufs.is_empty() {
+            return Ok(());
+        }
+
+        let slices: Vec<_> = std::iter::once(&bufs[0][written..])
+            .chain(bufs[1..].iter().copied())
+            .map(IoSlice::new)
+            .collect();
+        match sink.write_vectored(&slices) {
+            Ok(0) => {
+                return Err(ParquetError::General(
+                    "failed to write whole buffer".to_string(),
+                ))
+            }
+            Ok(mut n) => {
+                while n > 0 {
+                    let remaining = bufs[0].len() - written;
+                    if n < remaining {
+                        written += n;
+                        break;
+                    }
+                    n -= remaining;
+                    bufs = &bufs[1..];
+                    written = 0;
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
     }
 }
 
@@ -702,8 +793,9 @@ impl<'a, W: Write + Send> PageWriter for SerializedPageWriter<'a, W> {
         let start_pos = self.sink.bytes_written() as u64;
 
         let page_header = page.to_thrift_header();
-        let header_size = self.serialize_page_header(page_header)?;
-        self.sink.write_all(page.data())?;
+        let header = self.serialize_page_header(page_header)?;
+        let header_size = header.len();
+        write_all_vectored(&mut *self.sink, &[header.as_slice(), page.data()])?;
 
         let mut spec = PageWriteSpec::new();
         spec.page_type = page_type;
@@ -737,7 +829,9 @@ mod tests {
     use bytes::Bytes;
     use std::fs::File;
 
-    use crate::basic::{Compression, Encoding, LogicalType, Repetition, Type};
+    use crate::basic::{
+        ColumnOrder, Compression, Encoding, LogicalType, Repetition, SortOrder, Type,
+    };
     use crate::column::page::{Page, PageReader};
     use crate::column::reader::get_typed_column_reader;
     use crate::compression::{create_codec, Codec, CodecOptionsBuilder};
@@ -1003,6 +1097,75 @@ mod tests {
         assert_eq!(expected_result.as_ref(), result[0]);
     }
 
+    #[test]
+    fn test_file_writer_sorting_columns_per_row_group() {
+        let schema = Arc::new(
+            parse_message_type(
+                "message schema { REQUIRED INT32 a; REQUIRED BYTE_ARRAY b (UTF8); }",
+            )
+            .unwrap(),
+        );
+        let sorting = |column_idx| {
+            Some(vec![SortingColumn {
+                column_idx,
+                descending: true,
+                nulls_first: false,
+            }])
+        };
+
+        let props = WriterProperties::builder()
+            .set_sorting_columns(sorting(2))
+            .build();
+        let err = SerializedFileWriter::new(vec![], schema.clone(), Arc::new(props))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Sorting column index 2 out of bounds for schema with 2 columns"
+        );
+
+        let props = WriterProperties::builder()
+            .set_sorting_columns(sorting(0))
+            .build();
+        let mut file = Vec::with_capacity(1024);
+        let mut writer =
+            SerializedFileWriter::new(&mut file, schema, Arc::new(props)).unwrap();
+        for overridden in [None, Some(sorting(1)), Some(None)] {
+            let mut row_group_writer = writer.next_row_group().unwrap();
+            if let Some(sorting_columns) = overridden {
+                row_group_writer
+                    .set_sorting_columns(sorting_columns)
+                    .unwrap();
+            }
+            assert!(row_group_writer.set_sorting_columns(sorting(-1)).is_err());
+            while let Some(col_writer) = row_group_writer.next_column().unwrap() {
+                col_writer.close().unwrap();
+            }
+            row_group_writer.close().unwrap();
+        }
+        writer.close().unwrap();
+
+        let reader = SerializedFileReader::new(Bytes::from(file)).unwrap();
+        let metadata = reader.metadata();
+        let sorting_columns: Vec<_> = metadata
+            .row_groups()
+            .iter()
+            .map(|rg| rg.sorting_columns().cloned())
+            .collect();
+        assert_eq!(sorting_columns, vec![sorting(0), sorting(1), None]);
+
+        let file_metadata = metadata.file_metadata();
+        assert_eq!(file_metadata.column_orders().unwrap().len(), 2);
+        assert_eq!(
+            file_metadata.column_order(0),
+            ColumnOrder::TYPE_DEFINED_ORDER(SortOrder::SIGNED)
+        );
+        assert_eq!(
+            file_metadata.column_order(1),
+            ColumnOrder::TYPE_DEFINED_ORDER(SortOrder::UNSIGNED)
+        );
+    }
+
     #[test]
     fn test_file_writer_empty_row_groups() {
         let file = tempfile::tempfile().unwrap();
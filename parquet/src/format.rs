@@ -631,6 +631,76 @@ impl From<&BoundaryOrder> for i32 {
   }
 }
 
+/// Interpretation for edges of elements of a GEOGRAPHY logical type. In other
+/// words, whether a point between two vertices should be interpolated in
+/// its XY dimensions as if it were a Cartesian line connecting the two
+/// vertices (planar) or the shortest spherical arc between the longitude
+/// and latitude represented by the two vertices (spherical). This value
+/// applies to all non-point geometry objects and is independent of the
+/// coordinate reference system.
+///
+/// Because most systems currently assume planar edges and do not support
+/// spherical edges, planar should be used as the default value.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct EdgeInterpolationAlgorithm(pub i32);
+
+impl EdgeInterpolationAlgorithm {
+  pub const SPHERICAL: EdgeInterpolationAlgorithm = EdgeInterpolationAlgorithm(0);
+  pub const VINCENTY: EdgeInterpolationAlgorithm = EdgeInterpolationAlgorithm(1);
+  pub const THOMAS: EdgeInterpolationAlgorithm = EdgeInterpolationAlgorithm(2);
+  pub const ANDOYER: EdgeInterpolationAlgorithm = EdgeInterpolationAlgorithm(3);
+  pub const KARNEY: EdgeInterpolationAlgorithm = EdgeInterpolationAlgorithm(4);
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::SPHERICAL,
+    Self::VINCENTY,
+    Self::THOMAS,
+    Self::ANDOYER,
+    Self::KARNEY,
+  ];
+}
+
+impl TSerializable for EdgeInterpolationAlgorithm {
+  #[allow(clippy::trivially_copy_pass_by_ref)]
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    o_prot.write_i32(self.0)
+  }
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<EdgeInterpolationAlgorithm> {
+    let enum_value = i_prot.read_i32()?;
+    Ok(EdgeInterpolationAlgorithm::from(enum_value))
+  }
+}
+
+impl From<i32> for EdgeInterpolationAlgorithm {
+  fn from(i: i32) -> Self {
+    match i {
+      0 => EdgeInterpolationAlgorithm::SPHERICAL,
+      1 => EdgeInterpolationAlgorithm::VINCENTY,
+      2 => EdgeInterpolationAlgorithm::THOMAS,
+      3 => EdgeInterpolationAlgorithm::ANDOYER,
+      4 => EdgeInterpolationAlgorithm::KARNEY,
+      _ => EdgeInterpolationAlgorithm(i)
+    }
+  }
+}
+
+impl From<&i32> for EdgeInterpolationAlgorithm {
+  fn from(i: &i32) -> Self {
+    EdgeInterpolationAlgorithm::from(*i)
+  }
+}
+
+impl From<EdgeInterpolationAlgorithm> for i32 {
+  fn from(e: EdgeInterpolationAlgorithm) -> i32 {
+    e.0
+  }
+}
+
+impl From<&EdgeInterpolationAlgorithm> for i32 {
+  fn from(e: &EdgeInterpolationAlgorithm) -> i32 {
+    e.0
+  }
+}
+
 //
 // Statistics
 //
@@ -785,6 +855,244 @@ impl Default for Statistics {
   }
 }
 
+//
+// BoundingBox
+//
+
+/// Bounding box for GEOMETRY or GEOGRAPHY type in the representation of min/max
+/// value pair of coordinates from each axis.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BoundingBox {
+  pub xmin: OrderedFloat<f64>,
+  pub xmax: OrderedFloat<f64>,
+  pub ymin: OrderedFloat<f64>,
+  pub ymax: OrderedFloat<f64>,
+  pub zmin: Option<OrderedFloat<f64>>,
+  pub zmax: Option<OrderedFloat<f64>>,
+  pub mmin: Option<OrderedFloat<f64>>,
+  pub mmax: Option<OrderedFloat<f64>>,
+}
+
+impl BoundingBox {
+  pub fn new<F5, F6, F7, F8>(xmin: OrderedFloat<f64>, xmax: OrderedFloat<f64>, ymin: OrderedFloat<f64>, ymax: OrderedFloat<f64>, zmin: F5, zmax: F6, mmin: F7, mmax: F8) -> BoundingBox where F5: Into<Option<OrderedFloat<f64>>>, F6: Into<Option<OrderedFloat<f64>>>, F7: Into<Option<OrderedFloat<f64>>>, F8: Into<Option<OrderedFloat<f64>>> {
+    BoundingBox {
+      xmin,
+      xmax,
+      ymin,
+      ymax,
+      zmin: zmin.into(),
+      zmax: zmax.into(),
+      mmin: mmin.into(),
+      mmax: mmax.into(),
+    }
+  }
+}
+
+impl TSerializable for BoundingBox {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<BoundingBox> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<OrderedFloat<f64>> = None;
+    let mut f_2: Option<OrderedFloat<f64>> = None;
+    let mut f_3: Option<OrderedFloat<f64>> = None;
+    let mut f_4: Option<OrderedFloat<f64>> = None;
+    let mut f_5: Option<OrderedFloat<f64>> = None;
+    let mut f_6: Option<OrderedFloat<f64>> = None;
+    let mut f_7: Option<OrderedFloat<f64>> = None;
+    let mut f_8: Option<OrderedFloat<f64>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_3 = Some(val);
+        },
+        4 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_4 = Some(val);
+        },
+        5 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_5 = Some(val);
+        },
+        6 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_6 = Some(val);
+        },
+        7 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_7 = Some(val);
+        },
+        8 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_8 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("BoundingBox.xmin", &f_1)?;
+    verify_required_field_exists("BoundingBox.xmax", &f_2)?;
+    verify_required_field_exists("BoundingBox.ymin", &f_3)?;
+    verify_required_field_exists("BoundingBox.ymax", &f_4)?;
+    let ret = BoundingBox {
+      xmin: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      xmax: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      ymin: f_3.expect("auto-generated code should have checked for presence of required fields"),
+      ymax: f_4.expect("auto-generated code should have checked for presence of required fields"),
+      zmin: f_5,
+      zmax: f_6,
+      mmin: f_7,
+      mmax: f_8,
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("BoundingBox");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("xmin", TType::Double, 1))?;
+    o_prot.write_double(self.xmin.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("xmax", TType::Double, 2))?;
+    o_prot.write_double(self.xmax.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("ymin", TType::Double, 3))?;
+    o_prot.write_double(self.ymin.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("ymax", TType::Double, 4))?;
+    o_prot.write_double(self.ymax.into())?;
+    o_prot.write_field_end()?;
+    if let Some(fld_var) = self.zmin {
+      o_prot.write_field_begin(&TFieldIdentifier::new("zmin", TType::Double, 5))?;
+      o_prot.write_double(fld_var.into())?;
+      o_prot.write_field_end()?
+    }
+    if let Some(fld_var) = self.zmax {
+      o_prot.write_field_begin(&TFieldIdentifier::new("zmax", TType::Double, 6))?;
+      o_prot.write_double(fld_var.into())?;
+      o_prot.write_field_end()?
+    }
+    if let Some(fld_var) = self.mmin {
+      o_prot.write_field_begin(&TFieldIdentifier::new("mmin", TType::Double, 7))?;
+      o_prot.write_double(fld_var.into())?;
+      o_prot.write_field_end()?
+    }
+    if let Some(fld_var) = self.mmax {
+      o_prot.write_field_begin(&TFieldIdentifier::new("mmax", TType::Double, 8))?;
+      o_prot.write_double(fld_var.into())?;
+      o_prot.write_field_end()?
+    }
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// GeospatialStatistics
+//
+
+/// Statistics specific to Geometry and Geography logical types
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct GeospatialStatistics {
+  /// A bounding box of geospatial instances
+  pub bbox: Option<BoundingBox>,
+  /// Geospatial type codes of all instances, or an empty list if not known
+  pub geospatial_types: Option<Vec<i32>>,
+}
+
+impl GeospatialStatistics {
+  pub fn new<F1, F2>(bbox: F1, geospatial_types: F2) -> GeospatialStatistics where F1: Into<Option<BoundingBox>>, F2: Into<Option<Vec<i32>>> {
+    GeospatialStatistics {
+      bbox: bbox.into(),
+      geospatial_types: geospatial_types.into(),
+    }
+  }
+}
+
+impl TSerializable for GeospatialStatistics {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<GeospatialStatistics> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<BoundingBox> = None;
+    let mut f_2: Option<Vec<i32>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = BoundingBox::read_from_in_protocol(i_prot)?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<i32> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_0 = i_prot.read_i32()?;
+            val.push(list_elem_0);
+          }
+          i_prot.read_list_end()?;
+          f_2 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    let ret = GeospatialStatistics {
+      bbox: f_1,
+      geospatial_types: f_2,
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("GeospatialStatistics");
+    o_prot.write_struct_begin(&struct_ident)?;
+    if let Some(ref fld_var) = self.bbox {
+      o_prot.write_field_begin(&TFieldIdentifier::new("bbox", TType::Struct, 1))?;
+      fld_var.write_to_out_protocol(o_prot)?;
+      o_prot.write_field_end()?
+    }
+    if let Some(ref fld_var) = self.geospatial_types {
+      o_prot.write_field_begin(&TFieldIdentifier::new("geospatial_types", TType::List, 2))?;
+      o_prot.write_list_begin(&TListIdentifier::new(TType::I32, fld_var.len() as i32))?;
+      for e in fld_var {
+        o_prot.write_i32(*e)?;
+      }
+      o_prot.write_list_end()?;
+      o_prot.write_field_end()?
+    }
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+impl Default for GeospatialStatistics {
+  fn default() -> Self {
+    GeospatialStatistics{
+      bbox: None,
+      geospatial_types: Some(Vec::new()),
+    }
+  }
+}
+
 //
 // StringType
 //
@@ -1762,6 +2070,241 @@ impl Default for BsonType {
   }
 }
 
+//
+// VariantType
+//
+
+/// Embedded Variant logical type annotation
+///
+/// Allowed for group types containing a binary `metadata` and `value` field
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VariantType {
+  pub specification_version: Option<i8>,
+}
+
+impl VariantType {
+  pub fn new<F1>(specification_version: F1) -> VariantType where F1: Into<Option<i8>> {
+    VariantType {
+      specification_version: specification_version.into(),
+    }
+  }
+}
+
+impl TSerializable for VariantType {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<VariantType> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<i8> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_i8()?;
+          f_1 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    let ret = VariantType {
+      specification_version: f_1,
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("VariantType");
+    o_prot.write_struct_begin(&struct_ident)?;
+    if let Some(fld_var) = self.specification_version {
+      o_prot.write_field_begin(&TFieldIdentifier::new("specification_version", TType::I08, 1))?;
+      o_prot.write_i8(fld_var)?;
+      o_prot.write_field_end()?
+    }
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+impl Default for VariantType {
+  fn default() -> Self {
+    VariantType{
+      specification_version: Some(0),
+    }
+  }
+}
+
+//
+// GeometryType
+//
+
+/// Embedded Geometry logical type annotation
+///
+/// Geospatial features in the Well-Known Binary (WKB) format and edges interpolation
+/// is always linear/planar.
+///
+/// A custom CRS can be set by the crs field. If unset, it defaults to "OGC:CRS84",
+/// which means that the geometries must be stored in longitude, latitude based on
+/// the WGS84 datum.
+///
+/// Allowed for physical type: BYTE_ARRAY.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct GeometryType {
+  pub crs: Option<String>,
+}
+
+impl GeometryType {
+  pub fn new<F1>(crs: F1) -> GeometryType where F1: Into<Option<String>> {
+    GeometryType {
+      crs: crs.into(),
+    }
+  }
+}
+
+impl TSerializable for GeometryType {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<GeometryType> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<String> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_string()?;
+          f_1 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    let ret = GeometryType {
+      crs: f_1,
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("GeometryType");
+    o_prot.write_struct_begin(&struct_ident)?;
+    if let Some(ref fld_var) = self.crs {
+      o_prot.write_field_begin(&TFieldIdentifier::new("crs", TType::String, 1))?;
+      o_prot.write_string(fld_var)?;
+      o_prot.write_field_end()?
+    }
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+impl Default for GeometryType {
+  fn default() -> Self {
+    GeometryType{
+      crs: Some("".to_owned()),
+    }
+  }
+}
+
+//
+// GeographyType
+//
+
+/// Embedded Geography logical type annotation
+///
+/// Geospatial features in the WKB format with an explicit (non-linear/non-planar)
+/// edges interpolation algorithm.
+///
+/// A custom geographic CRS can be set by the crs field, where longitudes are
+/// bound by [-180, 180] and latitudes are bound by [-90, 90]. If unset, the CRS
+/// defaults to "OGC:CRS84".
+///
+/// An optional algorithm can be set to correctly interpret edges interpolation
+/// of the geometries. If unset, the algorithm defaults to SPHERICAL.
+///
+/// Allowed for physical type: BYTE_ARRAY.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct GeographyType {
+  pub crs: Option<String>,
+  pub algorithm: Option<EdgeInterpolationAlgorithm>,
+}
+
+impl GeographyType {
+  pub fn new<F1, F2>(crs: F1, algorithm: F2) -> GeographyType where F1: Into<Option<String>>, F2: Into<Option<EdgeInterpolationAlgorithm>> {
+    GeographyType {
+      crs: crs.into(),
+      algorithm: algorithm.into(),
+    }
+  }
+}
+
+impl TSerializable for GeographyType {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<GeographyType> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<String> = None;
+    let mut f_2: Option<EdgeInterpolationAlgorithm> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_string()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = EdgeInterpolationAlgorithm::read_from_in_protocol(i_prot)?;
+          f_2 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    let ret = GeographyType {
+      crs: f_1,
+      algorithm: f_2,
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("GeographyType");
+    o_prot.write_struct_begin(&struct_ident)?;
+    if let Some(ref fld_var) = self.crs {
+      o_prot.write_field_begin(&TFieldIdentifier::new("crs", TType::String, 1))?;
+      o_prot.write_string(fld_var)?;
+      o_prot.write_field_end()?
+    }
+    if let Some(ref fld_var) = self.algorithm {
+      o_prot.write_field_begin(&TFieldIdentifier::new("algorithm", TType::I32, 2))?;
+      fld_var.write_to_out_protocol(o_prot)?;
+      o_prot.write_field_end()?
+    }
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+impl Default for GeographyType {
+  fn default() -> Self {
+    GeographyType{
+      crs: Some("".to_owned()),
+      algorithm: None,
+    }
+  }
+}
+
 //
 // LogicalType
 //
@@ -1781,6 +2324,9 @@ pub enum LogicalType {
   JSON(JsonType),
   BSON(BsonType),
   UUID(UUIDType),
+  VARIANT(VariantType),
+  GEOMETRY(GeometryType),
+  GEOGRAPHY(GeographyType),
 }
 
 impl TSerializable for LogicalType {
@@ -1886,6 +2432,27 @@ impl TSerializable for LogicalType {
           }
           received_field_count += 1;
         },
+        16 => {
+          let val = VariantType::read_from_in_protocol(i_prot)?;
+          if ret.is_none() {
+            ret = Some(LogicalType::VARIANT(val));
+          }
+          received_field_count += 1;
+        },
+        17 => {
+          let val = GeometryType::read_from_in_protocol(i_prot)?;
+          if ret.is_none() {
+            ret = Some(LogicalType::GEOMETRY(val));
+          }
+          received_field_count += 1;
+        },
+        18 => {
+          let val = GeographyType::read_from_in_protocol(i_prot)?;
+          if ret.is_none() {
+            ret = Some(LogicalType::GEOGRAPHY(val));
+          }
+          received_field_count += 1;
+        },
         _ => {
           i_prot.skip(field_ident.field_type)?;
           received_field_count += 1;
@@ -1985,6 +2552,21 @@ impl TSerializable for LogicalType {
         f.write_to_out_protocol(o_prot)?;
         o_prot.write_field_end()?;
       },
+      LogicalType::VARIANT(ref f) => {
+        o_prot.write_field_begin(&TFieldIdentifier::new("VARIANT", TType::Struct, 16))?;
+        f.write_to_out_protocol(o_prot)?;
+        o_prot.write_field_end()?;
+      },
+      LogicalType::GEOMETRY(ref f) => {
+        o_prot.write_field_begin(&TFieldIdentifier::new("GEOMETRY", TType::Struct, 17))?;
+        f.write_to_out_protocol(o_prot)?;
+        o_prot.write_field_end()?;
+      },
+      LogicalType::GEOGRAPHY(ref f) => {
+        o_prot.write_field_begin(&TFieldIdentifier::new("GEOGRAPHY", TType::Struct, 18))?;
+        f.write_to_out_protocol(o_prot)?;
+        o_prot.write_field_end()?;
+      },
     }
     o_prot.write_field_stop()?;
     o_prot.write_struct_end()
@@ -3516,10 +4098,12 @@ pub struct ColumnMetaData {
   pub encoding_stats: Option<Vec<PageEncodingStats>>,
   /// Byte offset from beginning of file to Bloom filter data. *
   pub bloom_filter_offset: Option<i64>,
+  /// Optional statistics specific for Geometry and Geography logical types
+  pub geospatial_statistics: Option<GeospatialStatistics>,
 }
 
 impl ColumnMetaData {
-  pub fn new<F8, F10, F11, F12, F13, F14>(type_: Type, encodings: Vec<Encoding>, path_in_schema: Vec<String>, codec: CompressionCodec, num_values: i64, total_uncompressed_size: i64, total_compressed_size: i64, key_value_metadata: F8, data_page_offset: i64, index_page_offset: F10, dictionary_page_offset: F11, statistics: F12, encoding_stats: F13, bloom_filter_offset: F14) -> ColumnMetaData where F8: Into<Option<Vec<KeyValue>>>, F10: Into<Option<i64>>, F11: Into<Option<i64>>, F12: Into<Option<Statistics>>, F13: Into<Option<Vec<PageEncodingStats>>>, F14: Into<Option<i64>> {
+  pub fn new<F8, F10, F11, F12, F13, F14, F17>(type_: Type, encodings: Vec<Encoding>, path_in_schema: Vec<String>, codec: CompressionCodec, num_values: i64, total_uncompressed_size: i64, total_compressed_size: i64, key_value_metadata: F8, data_page_offset: i64, index_page_offset: F10, dictionary_page_offset: F11, statistics: F12, encoding_stats: F13, bloom_filter_offset: F14, geospatial_statistics: F17) -> ColumnMetaData where F8: Into<Option<Vec<KeyValue>>>, F10: Into<Option<i64>>, F11: Into<Option<i64>>, F12: Into<Option<Statistics>>, F13: Into<Option<Vec<PageEncodingStats>>>, F14: Into<Option<i64>>, F17: Into<Option<GeospatialStatistics>> {
     ColumnMetaData {
       type_,
       encodings,
@@ -3535,6 +4119,7 @@ impl ColumnMetaData {
       statistics: statistics.into(),
       encoding_stats: encoding_stats.into(),
       bloom_filter_offset: bloom_filter_offset.into(),
+      geospatial_statistics: geospatial_statistics.into(),
     }
   }
 }
@@ -3556,6 +4141,7 @@ impl TSerializable for ColumnMetaData {
     let mut f_12: Option<Statistics> = None;
     let mut f_13: Option<Vec<PageEncodingStats>> = None;
     let mut f_14: Option<i64> = None;
+    let mut f_17: Option<GeospatialStatistics> = None;
     loop {
       let field_ident = i_prot.read_field_begin()?;
       if field_ident.field_type == TType::Stop {
@@ -3571,8 +4157,8 @@ impl TSerializable for ColumnMetaData {
           let list_ident = i_prot.read_list_begin()?;
           let mut val: Vec<Encoding> = Vec::with_capacity(list_ident.size as usize);
           for _ in 0..list_ident.size {
-            let list_elem_0 = Encoding::read_from_in_protocol(i_prot)?;
-            val.push(list_elem_0);
+            let list_elem_1 = Encoding::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_1);
           }
           i_prot.read_list_end()?;
           f_2 = Some(val);
@@ -3581,8 +4167,8 @@ impl TSerializable for ColumnMetaData {
           let list_ident = i_prot.read_list_begin()?;
           let mut val: Vec<String> = Vec::with_capacity(list_ident.size as usize);
           for _ in 0..list_ident.size {
-            let list_elem_1 = i_prot.read_string()?;
-            val.push(list_elem_1);
+            let list_elem_2 = i_prot.read_string()?;
+            val.push(list_elem_2);
           }
           i_prot.read_list_end()?;
           f_3 = Some(val);
@@ -3607,8 +4193,8 @@ impl TSerializable for ColumnMetaData {
           let list_ident = i_prot.read_list_begin()?;
           let mut val: Vec<KeyValue> = Vec::with_capacity(list_ident.size as usize);
           for _ in 0..list_ident.size {
-            let list_elem_2 = KeyValue::read_from_in_protocol(i_prot)?;
-            val.push(list_elem_2);
+            let list_elem_3 = KeyValue::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_3);
           }
           i_prot.read_list_end()?;
           f_8 = Some(val);
@@ -3633,8 +4219,8 @@ impl TSerializable for ColumnMetaData {
           let list_ident = i_prot.read_list_begin()?;
           let mut val: Vec<PageEncodingStats> = Vec::with_capacity(list_ident.size as usize);
           for _ in 0..list_ident.size {
-            let list_elem_3 = PageEncodingStats::read_from_in_protocol(i_prot)?;
-            val.push(list_elem_3);
+            let list_elem_4 = PageEncodingStats::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_4);
           }
           i_prot.read_list_end()?;
           f_13 = Some(val);
@@ -3643,6 +4229,10 @@ impl TSerializable for ColumnMetaData {
           let val = i_prot.read_i64()?;
           f_14 = Some(val);
         },
+        17 => {
+          let val = GeospatialStatistics::read_from_in_protocol(i_prot)?;
+          f_17 = Some(val);
+        },
         _ => {
           i_prot.skip(field_ident.field_type)?;
         },
@@ -3673,6 +4263,7 @@ impl TSerializable for ColumnMetaData {
       statistics: f_12,
       encoding_stats: f_13,
       bloom_filter_offset: f_14,
+      geospatial_statistics: f_17,
     };
     Ok(ret)
   }
@@ -3749,6 +4340,11 @@ impl TSerializable for ColumnMetaData {
       o_prot.write_i64(fld_var)?;
       o_prot.write_field_end()?
     }
+    if let Some(ref fld_var) = self.geospatial_statistics {
+      o_prot.write_field_begin(&TFieldIdentifier::new("geospatial_statistics", TType::Struct, 17))?;
+      fld_var.write_to_out_protocol(o_prot)?;
+      o_prot.write_field_end()?
+    }
     o_prot.write_field_stop()?;
     o_prot.write_struct_end()
   }
@@ -3839,8 +4435,8 @@ impl TSerializable for EncryptionWithColumnKey {
           let list_ident = i_prot.read_list_begin()?;
           let mut val: Vec<String> = Vec::with_capacity(list_ident.size as usize);
           for _ in 0..list_ident.size {
-            let list_elem_4 = i_prot.read_string()?;
-            val.push(list_elem_4);
+            let list_elem_5 = i_prot.read_string()?;
+            val.push(list_elem_5);
           }
           i_prot.read_list_end()?;
           f_1 = Some(val);
@@ -4205,8 +4801,8 @@ impl TSerializable for RowGroup {
           let list_ident = i_prot.read_list_begin()?;
           let mut val: Vec<ColumnChunk> = Vec::with_capacity(list_ident.size as usize);
           for _ in 0..list_ident.size {
-            let list_elem_5 = ColumnChunk::read_from_in_protocol(i_prot)?;
-            val.push(list_elem_5);
+            let list_elem_6 = ColumnChunk::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_6);
           }
           i_prot.read_list_end()?;
           f_1 = Some(val);
@@ -4223,8 +4819,8 @@ impl TSerializable for RowGroup {
           let list_ident = i_prot.read_list_begin()?;
           let mut val: Vec<SortingColumn> = Vec::with_capacity(list_ident.size as usize);
           for _ in 0..list_ident.size {
-            let list_elem_6 = SortingColumn::read_from_in_protocol(i_prot)?;
-            val.push(list_elem_6);
+            let list_elem_7 = SortingColumn::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_7);
           }
           i_prot.read_list_end()?;
           f_4 = Some(val);
@@ -4548,8 +5144,8 @@ impl TSerializable for OffsetIndex {
           let list_ident = i_prot.read_list_begin()?;
           let mut val: Vec<PageLocation> = Vec::with_capacity(list_ident.size as usize);
           for _ in 0..list_ident.size {
-            let list_elem_7 = PageLocation::read_from_in_protocol(i_prot)?;
-            val.push(list_elem_7);
+            let list_elem_8 = PageLocation::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_8);
           }
           i_prot.read_list_end()?;
           f_1 = Some(val);
@@ -4645,8 +5241,8 @@ impl TSerializable for ColumnIndex {
           let list_ident = i_prot.read_list_begin()?;
           let mut val: Vec<bool> = Vec::with_capacity(list_ident.size as usize);
           for _ in 0..list_ident.size {
-            let list_elem_8 = i_prot.read_bool()?;
-            val.push(list_elem_8);
+            let list_elem_9 = i_prot.read_bool()?;
+            val.push(list_elem_9);
           }
           i_prot.read_list_end()?;
           f_1 = Some(val);
@@ -4655,8 +5251,8 @@ impl TSerializable for ColumnIndex {
           let list_ident = i_prot.read_list_begin()?;
           let mut val: Vec<Vec<u8>> = Vec::with_capacity(list_ident.size as usize);
           for _ in 0..list_ident.size {
-            let list_elem_9 = i_prot.read_bytes()?;
-            val.push(list_elem_9);
+            let list_elem_10 = i_prot.read_bytes()?;
+            val.push(list_elem_10);
           }
           i_prot.read_list_end()?;
           f_2 = Some(val);
@@ -4665,8 +5261,8 @@ impl TSerializable for ColumnIndex {
           let list_ident = i_prot.read_list_begin()?;
           let mut val: Vec<Vec<u8>> = Vec::with_capacity(list_ident.size as usize);
           for _ in 0..list_ident.size {
-            let list_elem_10 = i_prot.read_bytes()?;
-            val.push(list_elem_10);
+            let list_elem_11 = i_prot.read_bytes()?;
+            val.push(list_elem_11);
           }
           i_prot.read_list_end()?;
           f_3 = Some(val);
@@ -4679,8 +5275,8 @@ impl TSerializable for ColumnIndex {
           let list_ident = i_prot.read_list_begin()?;
           let mut val: Vec<i64> = Vec::with_capacity(list_ident.size as usize);
           for _ in 0..list_ident.size {
-            let list_elem_11 = i_prot.read_i64()?;
-            val.push(list_elem_11);
+            let list_elem_12 = i_prot.read_i64()?;
+            val.push(list_elem_12);
           }
           i_prot.read_list_end()?;
           f_5 = Some(val);
@@ -5117,8 +5713,8 @@ impl TSerializable for FileMetaData {
           let list_ident = i_prot.read_list_begin()?;
           let mut val: Vec<SchemaElement> = Vec::with_capacity(list_ident.size as usize);
           for _ in 0..list_ident.size {
-            let list_elem_12 = SchemaElement::read_from_in_protocol(i_prot)?;
-            val.push(list_elem_12);
+            let list_elem_13 = SchemaElement::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_13);
           }
           i_prot.read_list_end()?;
           f_2 = Some(val);
@@ -5131,8 +5727,8 @@ impl TSerializable for FileMetaData {
           let list_ident = i_prot.read_list_begin()?;
           let mut val: Vec<RowGroup> = Vec::with_capacity(list_ident.size as usize);
           for _ in 0..list_ident.size {
-            let list_elem_13 = RowGroup::read_from_in_protocol(i_prot)?;
-            val.push(list_elem_13);
+            let list_elem_14 = RowGroup::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_14);
           }
           i_prot.read_list_end()?;
           f_4 = Some(val);
@@ -5141,8 +5737,8 @@ impl TSerializable for FileMetaData {
           let list_ident = i_prot.read_list_begin()?;
           let mut val: Vec<KeyValue> = Vec::with_capacity(list_ident.size as usize);
           for _ in 0..list_ident.size {
-            let list_elem_14 = KeyValue::read_from_in_protocol(i_prot)?;
-            val.push(list_elem_14);
+            let list_elem_15 = KeyValue::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_15);
           }
           i_prot.read_list_end()?;
           f_5 = Some(val);
@@ -5155,8 +5751,8 @@ impl TSerializable for FileMetaData {
           let list_ident = i_prot.read_list_begin()?;
           let mut val: Vec<ColumnOrder> = Vec::with_capacity(list_ident.size as usize);
           for _ in 0..list_ident.size {
-            let list_elem_15 = ColumnOrder::read_from_in_protocol(i_prot)?;
-            val.push(list_elem_15);
+            let list_elem_16 = ColumnOrder::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_16);
           }
           i_prot.read_list_end()?;
           f_7 = Some(val);
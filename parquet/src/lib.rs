@@ -85,6 +85,8 @@ pub mod column;
 experimental!(mod compression);
 experimental!(mod encodings);
 pub mod bloom_filter;
+#[cfg(feature = "convert")]
+pub mod convert;
 pub mod file;
 pub mod record;
 pub mod schema;
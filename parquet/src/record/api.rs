@@ -79,6 +79,41 @@ impl Row {
         }
     }
 
+    /// Get the field at `path`, a sequence of field names separated by `.`
+    /// descending through nested groups, e.g. `"a.b.c"`.
+    ///
+    /// Returns an error if a field is not found, or an intermediate field is not a
+    /// group.
+    pub fn get_path(&self, path: &str) -> Result<&Field> {
+        let mut row = self;
+        let mut names = path.split('.').peekable();
+        while let Some(name) = names.next() {
+            let field = row
+                .fields
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, field)| field)
+                .ok_or_else(|| {
+                    general_err!("Field {} of path {} not found", name, path)
+                })?;
+
+            if names.peek().is_none() {
+                return Ok(field);
+            }
+            row = match field {
+                Field::Group(row) => row,
+                _ => {
+                    return Err(general_err!(
+                        "Cannot access {} of path {} as Group",
+                        field.get_type_name(),
+                        path
+                    ))
+                }
+            };
+        }
+        unreachable!("split always returns at least one element")
+    }
+
     #[cfg(any(feature = "json", test))]
     pub fn to_json_value(&self) -> Value {
         Value::Object(
@@ -133,6 +168,30 @@ pub trait RowAccessor {
     fn get_map(&self, i: usize) -> Result<&Map>;
 }
 
+/// Trait for type-safe convenient access to nested fields within a Row by path,
+/// see [`Row::get_path`].
+pub trait RowPathAccessor {
+    fn get_bool_by_path(&self, path: &str) -> Result<bool>;
+    fn get_byte_by_path(&self, path: &str) -> Result<i8>;
+    fn get_short_by_path(&self, path: &str) -> Result<i16>;
+    fn get_int_by_path(&self, path: &str) -> Result<i32>;
+    fn get_long_by_path(&self, path: &str) -> Result<i64>;
+    fn get_ubyte_by_path(&self, path: &str) -> Result<u8>;
+    fn get_ushort_by_path(&self, path: &str) -> Result<u16>;
+    fn get_uint_by_path(&self, path: &str) -> Result<u32>;
+    fn get_ulong_by_path(&self, path: &str) -> Result<u64>;
+    fn get_float_by_path(&self, path: &str) -> Result<f32>;
+    fn get_double_by_path(&self, path: &str) -> Result<f64>;
+    fn get_timestamp_millis_by_path(&self, path: &str) -> Result<i64>;
+    fn get_timestamp_micros_by_path(&self, path: &str) -> Result<i64>;
+    fn get_decimal_by_path(&self, path: &str) -> Result<&Decimal>;
+    fn get_string_by_path(&self, path: &str) -> Result<&String>;
+    fn get_bytes_by_path(&self, path: &str) -> Result<&ByteArray>;
+    fn get_group_by_path(&self, path: &str) -> Result<&Row>;
+    fn get_list_by_path(&self, path: &str) -> Result<&List>;
+    fn get_map_by_path(&self, path: &str) -> Result<&Map>;
+}
+
 /// Trait for formatting fields within a Row.
 ///
 /// # Examples
@@ -236,6 +295,80 @@ impl RowAccessor for Row {
     row_complex_accessor!(get_map, MapInternal, Map);
 }
 
+/// Macro to generate type-safe get_xxx_by_path methods for primitive types,
+/// e.g. `get_bool_by_path`, `get_short_by_path`.
+macro_rules! row_path_primitive_accessor {
+    ($METHOD:ident, $VARIANT:ident, $TY:ty) => {
+        fn $METHOD(&self, path: &str) -> Result<$TY> {
+            match self.get_path(path)? {
+                Field::$VARIANT(v) => Ok(*v),
+                field => Err(general_err!(
+                    "Cannot access {} as {}",
+                    field.get_type_name(),
+                    stringify!($VARIANT)
+                )),
+            }
+        }
+    };
+}
+
+/// Macro to generate type-safe get_xxx_by_path methods for reference types,
+/// e.g. `get_list_by_path`, `get_map_by_path`.
+macro_rules! row_path_complex_accessor {
+    ($METHOD:ident, $VARIANT:ident, $TY:ty) => {
+        fn $METHOD(&self, path: &str) -> Result<&$TY> {
+            match self.get_path(path)? {
+                Field::$VARIANT(v) => Ok(v),
+                field => Err(general_err!(
+                    "Cannot access {} as {}",
+                    field.get_type_name(),
+                    stringify!($VARIANT)
+                )),
+            }
+        }
+    };
+}
+
+impl RowPathAccessor for Row {
+    row_path_primitive_accessor!(get_bool_by_path, Bool, bool);
+
+    row_path_primitive_accessor!(get_byte_by_path, Byte, i8);
+
+    row_path_primitive_accessor!(get_short_by_path, Short, i16);
+
+    row_path_primitive_accessor!(get_int_by_path, Int, i32);
+
+    row_path_primitive_accessor!(get_long_by_path, Long, i64);
+
+    row_path_primitive_accessor!(get_ubyte_by_path, UByte, u8);
+
+    row_path_primitive_accessor!(get_ushort_by_path, UShort, u16);
+
+    row_path_primitive_accessor!(get_uint_by_path, UInt, u32);
+
+    row_path_primitive_accessor!(get_ulong_by_path, ULong, u64);
+
+    row_path_primitive_accessor!(get_float_by_path, Float, f32);
+
+    row_path_primitive_accessor!(get_double_by_path, Double, f64);
+
+    row_path_primitive_accessor!(get_timestamp_millis_by_path, TimestampMillis, i64);
+
+    row_path_primitive_accessor!(get_timestamp_micros_by_path, TimestampMicros, i64);
+
+    row_path_complex_accessor!(get_decimal_by_path, Decimal, Decimal);
+
+    row_path_complex_accessor!(get_string_by_path, Str, String);
+
+    row_path_complex_accessor!(get_bytes_by_path, Bytes, ByteArray);
+
+    row_path_complex_accessor!(get_group_by_path, Group, Row);
+
+    row_path_complex_accessor!(get_list_by_path, ListInternal, List);
+
+    row_path_complex_accessor!(get_map_by_path, MapInternal, Map);
+}
+
 /// Constructs a `Row` from the list of `fields` and returns it.
 #[inline]
 pub fn make_row(fields: Vec<(String, Field)>) -> Row {
@@ -25,7 +25,7 @@ mod triplet;
 pub use self::{
     api::{
         Field, List, ListAccessor, Map, MapAccessor, Row, RowAccessor, RowColumnIter,
-        RowFormatter,
+        RowFormatter, RowPathAccessor,
     },
     record_writer::RecordWriter,
 };
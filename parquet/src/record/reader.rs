@@ -319,6 +319,100 @@ impl TreeBuilder {
     }
 }
 
+/// Returns the projection of `schema` containing only the fields under each of `paths`,
+/// which can be provided to [`RowIter`] to read only the corresponding columns.
+///
+/// Each path is a sequence of field names from the root of `schema`, and may end at
+/// a group to select all of its fields, or at a primitive field. As lists and maps are
+/// decoded as a whole, a path into a `LIST` or `MAP` annotated group selects the
+/// entire group.
+///
+/// ```
+/// use parquet::record::reader::project_paths;
+/// use parquet::schema::parser::parse_message_type;
+/// use parquet::schema::types::ColumnPath;
+///
+/// let schema = parse_message_type(
+///     "message schema { REQUIRED INT32 a; OPTIONAL group b { REQUIRED INT64 c; REQUIRED INT64 d; } }",
+/// )
+/// .unwrap();
+/// let path = ColumnPath::new(vec!["b".to_string(), "d".to_string()]);
+/// let projection = project_paths(&schema, &[path]).unwrap();
+///
+/// let expected = parse_message_type(
+///     "message schema { OPTIONAL group b { REQUIRED INT64 d; } }",
+/// )
+/// .unwrap();
+/// assert_eq!(projection, expected);
+/// ```
+pub fn project_paths(schema: &Type, paths: &[ColumnPath]) -> Result<Type> {
+    let paths: Vec<_> = paths.iter().map(|p| p.parts()).collect();
+    if paths.iter().any(|p| p.is_empty()) {
+        return Err(general_err!("Projected column path must not be empty"));
+    }
+    match project_type(schema, &paths)? {
+        Some(projection) => Ok(projection),
+        None => Err(general_err!("Projection must select at least one column")),
+    }
+}
+
+/// Returns `field` containing only the fields under `paths`, relative to `field`,
+/// or `None` if `paths` is empty
+fn project_type(field: &Type, paths: &[&[String]]) -> Result<Option<Type>> {
+    if paths.is_empty() {
+        return Ok(None);
+    }
+
+    if field.is_primitive() {
+        if let Some(path) = paths.iter().find(|p| !p.is_empty()) {
+            return Err(general_err!(
+                "Cannot project {:?} of primitive field {}",
+                path,
+                field.name()
+            ));
+        }
+        return Ok(Some(field.clone()));
+    }
+
+    let whole = paths.iter().any(|p| p.is_empty())
+        || matches!(
+            field.get_basic_info().converted_type(),
+            ConvertedType::LIST | ConvertedType::MAP | ConvertedType::MAP_KEY_VALUE
+        );
+    if whole {
+        return Ok(Some(field.clone()));
+    }
+
+    if let Some(path) = paths
+        .iter()
+        .find(|p| !field.get_fields().iter().any(|f| f.name() == p[0]))
+    {
+        return Err(general_err!(
+            "Field {} not found in {}",
+            path[0],
+            field.name()
+        ));
+    }
+
+    let mut fields = Vec::new();
+    for child in field.get_fields() {
+        let child_paths: Vec<_> = paths
+            .iter()
+            .copied()
+            .filter(|p| p[0] == child.name())
+            .map(|p| &p[1..])
+            .collect();
+        if let Some(projected) = project_type(child, &child_paths)? {
+            fields.push(Arc::new(projected));
+        }
+    }
+
+    Ok(Some(Type::GroupType {
+        basic_info: field.get_basic_info().clone(),
+        fields,
+    }))
+}
+
 /// Reader tree for record assembly
 pub enum Reader {
     // Primitive reader with type information and triplet iterator
@@ -728,6 +822,22 @@ impl<'a> RowIter<'a> {
         }
     }
 
+    /// Tries to create a iterator of [`Row`](crate::record::Row)s reading only the
+    /// columns under each of `paths`, see [`project_paths`].
+    /// Returns a error if a file reader is not the source of this iterator.
+    pub fn project_paths(self, paths: &[ColumnPath]) -> Result<Self> {
+        let proj = match self.file_reader {
+            Some(ref either) => {
+                let schema = either.reader().metadata().file_metadata().schema();
+                project_paths(schema, paths)?
+            }
+            None => {
+                return Err(general_err!("File reader is required to use projections"))
+            }
+        };
+        self.project(Some(proj))
+    }
+
     /// Helper method to get schema descriptor for projected schema.
     /// If projection is None, then full schema is returned.
     #[inline]
@@ -829,7 +939,7 @@ mod tests {
 
     use crate::errors::Result;
     use crate::file::reader::{FileReader, SerializedFileReader};
-    use crate::record::api::{Field, Row, RowAccessor, RowFormatter};
+    use crate::record::api::{Field, Row, RowAccessor, RowFormatter, RowPathAccessor};
     use crate::schema::parser::parse_message_type;
     use crate::util::test_common::file_util::{get_test_file, get_test_path};
     use std::convert::TryFrom;
@@ -1461,6 +1571,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_file_reader_rows_projection_paths() {
+        let file = get_test_file("nonnullable.impala.parquet");
+        let file_reader = SerializedFileReader::new(file).unwrap();
+        let path =
+            |p: &[&str]| ColumnPath::new(p.iter().map(|s| s.to_string()).collect());
+        let paths = [
+            path(&["ID"]),
+            path(&["nested_Struct", "a"]),
+            path(&["nested_Struct", "c"]),
+            path(&["nested_Struct", "G", "key_value", "key"]),
+        ];
+        let rows: Vec<_> = file_reader
+            .get_row_iter(None)
+            .unwrap()
+            .project_paths(&paths)
+            .unwrap()
+            .map(|row| row.unwrap())
+            .collect();
+
+        let expected_rows = vec![row![
+            ("ID".to_string(), Field::Long(8)),
+            (
+                "nested_Struct".to_string(),
+                group![
+                    ("a".to_string(), Field::Int(-1)),
+                    (
+                        "c".to_string(),
+                        group![(
+                            "D".to_string(),
+                            list![list![group![
+                                ("e".to_string(), Field::Int(-1)),
+                                ("f".to_string(), Field::Str("nonnullable".to_string()))
+                            ]]]
+                        )]
+                    ),
+                    ("G".to_string(), map![])
+                ]
+            )
+        ]];
+        assert_eq!(rows, expected_rows);
+
+        let row = &rows[0];
+        assert_eq!(row.get_long_by_path("ID").unwrap(), 8);
+        assert_eq!(row.get_int_by_path("nested_Struct.a").unwrap(), -1);
+        assert_eq!(row.get_list_by_path("nested_Struct.c.D").unwrap().len(), 1);
+        assert_eq!(row.get_map_by_path("nested_Struct.G").unwrap().len(), 0);
+        assert_eq!(
+            row.get_int_by_path("nested_Struct.B")
+                .unwrap_err()
+                .to_string(),
+            "Parquet error: Field B of path nested_Struct.B not found"
+        );
+        assert_eq!(
+            row.get_int_by_path("ID.a").unwrap_err().to_string(),
+            "Parquet error: Cannot access Long of path ID.a as Group"
+        );
+        assert_eq!(
+            row.get_long_by_path("nested_Struct.a")
+                .unwrap_err()
+                .to_string(),
+            "Parquet error: Cannot access Int as Long"
+        );
+
+        let schema = file_reader.metadata().file_metadata().schema();
+        let err = project_paths(schema, &[path(&["nested_Struct", "x"])]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Field x not found in nested_Struct"
+        );
+        let err = project_paths(schema, &[path(&["ID", "x"])]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Cannot project [\"x\"] of primitive field ID"
+        );
+    }
+
     #[test]
     fn test_row_group_rows_invalid_projection() {
         let schema = "
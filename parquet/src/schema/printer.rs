@@ -273,6 +273,9 @@ fn print_logical_and_converted(
             LogicalType::List => "LIST".to_string(),
             LogicalType::Map => "MAP".to_string(),
             LogicalType::Unknown => "UNKNOWN".to_string(),
+            LogicalType::Variant => "VARIANT".to_string(),
+            LogicalType::Geometry { .. } => "GEOMETRY".to_string(),
+            LogicalType::Geography { .. } => "GEOGRAPHY".to_string(),
         },
         None => {
             // Also print converted type if it is available
@@ -309,7 +309,9 @@ impl<'a> PrimitiveTypeBuilder<'a> {
                 }
                 // Check that logical type and physical type are compatible
                 match (logical_type, self.physical_type) {
-                    (LogicalType::Map, _) | (LogicalType::List, _) => {
+                    (LogicalType::Map, _)
+                    | (LogicalType::List, _)
+                    | (LogicalType::Variant, _) => {
                         return Err(general_err!(
                             "{:?} cannot be applied to a primitive type for field '{}'",
                             logical_type,
@@ -363,6 +365,8 @@ impl<'a> PrimitiveTypeBuilder<'a> {
                     (LogicalType::String, PhysicalType::BYTE_ARRAY) => {}
                     (LogicalType::Json, PhysicalType::BYTE_ARRAY) => {}
                     (LogicalType::Bson, PhysicalType::BYTE_ARRAY) => {}
+                    (LogicalType::Geometry { .. }, PhysicalType::BYTE_ARRAY) => {}
+                    (LogicalType::Geography { .. }, PhysicalType::BYTE_ARRAY) => {}
                     (LogicalType::Uuid, PhysicalType::FIXED_LEN_BYTE_ARRAY) => {}
                     (a, b) => {
                         return Err(general_err!(
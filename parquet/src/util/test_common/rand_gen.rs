@@ -63,8 +63,11 @@ impl RandGen<Int64Type> for Int64Type {
 impl RandGen<Int96Type> for Int96Type {
     fn gen(_: i32) -> Int96 {
         let mut rng = thread_rng();
+        // Julian days and nanoseconds within the range of nanosecond timestamps
+        let day = rng.gen_range(2_340_588..2_540_588);
+        let nanoseconds = rng.gen_range(0..86_400_000_000_000_u64);
         let mut result = Int96::new();
-        result.set_data(rng.gen::<u32>(), rng.gen::<u32>(), rng.gen::<u32>());
+        result.set_data(nanoseconds as u32, (nanoseconds >> 32) as u32, day);
         result
     }
 }